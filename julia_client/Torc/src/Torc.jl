@@ -4,9 +4,18 @@ include("api/APIClient.jl")
 import OpenAPI
 import .APIClient
 
-function make_api(api_url::AbstractString)
-    """Instantiate an OpenAPI object from an API URL."""
-    return APIClient.DefaultApi(OpenAPI.Clients.Client(api_url))
+"""
+Instantiate an OpenAPI object from an API URL.
+
+If `api_token` is set (defaults to the TORC_API_TOKEN environment variable), requests
+authenticate with a server-issued bearer token.
+"""
+function make_api(api_url::AbstractString; api_token = get(ENV, "TORC_API_TOKEN", nothing))
+    if isnothing(api_token) || isempty(api_token)
+        return APIClient.DefaultApi(OpenAPI.Clients.Client(api_url))
+    end
+    headers = Dict{String, String}("Authorization" => "Bearer " * api_token)
+    return APIClient.DefaultApi(OpenAPI.Clients.Client(api_url; headers = headers))
 end
 
 """
@@ -333,6 +333,39 @@ function create_access_group(_api::DefaultApi, response_stream::Channel, body::A
     return OpenAPI.Clients.exec(_ctx, response_stream)
 end
 
+const _returntypes_create_api_token_DefaultApi = Dict{Regex,Type}(
+    Regex("^" * replace("200", "x"=>".") * "\$") => ApiTokenModel,
+    Regex("^" * replace("403", "x"=>".") * "\$") => ForbiddenErrorResponse,
+    Regex("^" * replace("422", "x"=>".") * "\$") => DefaultErrorResponse,
+    Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
+)
+
+function _oacinternal_create_api_token(_api::DefaultApi, body::ApiTokenModel; _mediaType=nothing)
+    _ctx = OpenAPI.Clients.Ctx(_api.client, "POST", _returntypes_create_api_token_DefaultApi, "/api_tokens", [], body)
+    OpenAPI.Clients.set_header_accept(_ctx, ["application/json", ])
+    OpenAPI.Clients.set_header_content_type(_ctx, (_mediaType === nothing) ? ["application/json", ] : [_mediaType])
+    return _ctx
+end
+
+@doc raw"""Create an API token.
+
+Create an API token. The plaintext token is only returned in this response.
+
+Params:
+- body::ApiTokenModel (required)
+
+Return: ApiTokenModel, OpenAPI.Clients.ApiResponse
+"""
+function create_api_token(_api::DefaultApi, body::ApiTokenModel; _mediaType=nothing)
+    _ctx = _oacinternal_create_api_token(_api, body; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx)
+end
+
+function create_api_token(_api::DefaultApi, response_stream::Channel, body::ApiTokenModel; _mediaType=nothing)
+    _ctx = _oacinternal_create_api_token(_api, body; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx, response_stream)
+end
+
 const _returntypes_create_compute_node_DefaultApi = Dict{Regex,Type}(
     Regex("^" * replace("200", "x"=>".") * "\$") => ComputeNodeModel,
     Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
@@ -493,6 +526,40 @@ function create_job(_api::DefaultApi, response_stream::Channel, body::JobModel;
     return OpenAPI.Clients.exec(_ctx, response_stream)
 end
 
+const _returntypes_create_job_log_DefaultApi = Dict{Regex,Type}(
+    Regex("^" * replace("200", "x"=>".") * "\$") => JobLogModel,
+    Regex("^" * replace("403", "x"=>".") * "\$") => ForbiddenErrorResponse,
+    Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
+    Regex("^" * replace("422", "x"=>".") * "\$") => DefaultErrorResponse,
+    Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
+)
+
+function _oacinternal_create_job_log(_api::DefaultApi, body::JobLogModel; _mediaType=nothing)
+    _ctx = OpenAPI.Clients.Ctx(_api.client, "POST", _returntypes_create_job_log_DefaultApi, "/job_logs", [], body)
+    OpenAPI.Clients.set_header_accept(_ctx, ["application/json", ])
+    OpenAPI.Clients.set_header_content_type(_ctx, (_mediaType === nothing) ? ["application/json", ] : [_mediaType])
+    return _ctx
+end
+
+@doc raw"""Store a job log.
+
+Store the stdout or stderr log of a job attempt. Job runners call this on job completion when log upload is enabled. A second upload for the same job, run, attempt, and stream replaces the first. Content larger than the server's job log size limit is rejected.
+
+Params:
+- body::JobLogModel (required)
+
+Return: JobLogModel, OpenAPI.Clients.ApiResponse
+"""
+function create_job_log(_api::DefaultApi, body::JobLogModel; _mediaType=nothing)
+    _ctx = _oacinternal_create_job_log(_api, body; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx)
+end
+
+function create_job_log(_api::DefaultApi, response_stream::Channel, body::JobLogModel; _mediaType=nothing)
+    _ctx = _oacinternal_create_job_log(_api, body; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx, response_stream)
+end
+
 const _returntypes_create_jobs_DefaultApi = Dict{Regex,Type}(
     Regex("^" * replace("200", "x"=>".") * "\$") => CreateJobsResponse,
     Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
@@ -818,6 +885,39 @@ function create_workflow_action(_api::DefaultApi, response_stream::Channel, id::
     return OpenAPI.Clients.exec(_ctx, response_stream)
 end
 
+const _returntypes_create_workflow_schedule_DefaultApi = Dict{Regex,Type}(
+    Regex("^" * replace("200", "x"=>".") * "\$") => WorkflowScheduleModel,
+    Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
+    Regex("^" * replace("422", "x"=>".") * "\$") => DefaultErrorResponse,
+    Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
+)
+
+function _oacinternal_create_workflow_schedule(_api::DefaultApi, body::WorkflowScheduleModel; _mediaType=nothing)
+    _ctx = OpenAPI.Clients.Ctx(_api.client, "POST", _returntypes_create_workflow_schedule_DefaultApi, "/workflow_schedules", [], body)
+    OpenAPI.Clients.set_header_accept(_ctx, ["application/json", ])
+    OpenAPI.Clients.set_header_content_type(_ctx, (_mediaType === nothing) ? ["application/json", ] : [_mediaType])
+    return _ctx
+end
+
+@doc raw"""Create a workflow schedule.
+
+Create a schedule that reinitializes and submits a workflow at the times of a cron expression.
+
+Params:
+- body::WorkflowScheduleModel (required)
+
+Return: WorkflowScheduleModel, OpenAPI.Clients.ApiResponse
+"""
+function create_workflow_schedule(_api::DefaultApi, body::WorkflowScheduleModel; _mediaType=nothing)
+    _ctx = _oacinternal_create_workflow_schedule(_api, body; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx)
+end
+
+function create_workflow_schedule(_api::DefaultApi, response_stream::Channel, body::WorkflowScheduleModel; _mediaType=nothing)
+    _ctx = _oacinternal_create_workflow_schedule(_api, body; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx, response_stream)
+end
+
 const _returntypes_delete_access_group_DefaultApi = Dict{Regex,Type}(
     Regex("^" * replace("200", "x"=>".") * "\$") => AccessGroupModel,
     Regex("^" * replace("403", "x"=>".") * "\$") => ForbiddenErrorResponse,
@@ -1626,6 +1726,40 @@ function delete_workflow(_api::DefaultApi, response_stream::Channel, id::Int64;
     return OpenAPI.Clients.exec(_ctx, response_stream)
 end
 
+const _returntypes_delete_workflow_schedule_DefaultApi = Dict{Regex,Type}(
+    Regex("^" * replace("200", "x"=>".") * "\$") => WorkflowScheduleModel,
+    Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
+    Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
+)
+
+function _oacinternal_delete_workflow_schedule(_api::DefaultApi, id::Int64; body=nothing, _mediaType=nothing)
+    _ctx = OpenAPI.Clients.Ctx(_api.client, "DELETE", _returntypes_delete_workflow_schedule_DefaultApi, "/workflow_schedules/{id}", [], body)
+    OpenAPI.Clients.set_param(_ctx.path, "id", id)  # type Int64
+    OpenAPI.Clients.set_header_accept(_ctx, ["application/json", ])
+    OpenAPI.Clients.set_header_content_type(_ctx, (_mediaType === nothing) ? ["application/json", ] : [_mediaType])
+    return _ctx
+end
+
+@doc raw"""Delete a workflow schedule.
+
+Delete a workflow schedule.
+
+Params:
+- id::Int64 (required)
+- body::Any
+
+Return: WorkflowScheduleModel, OpenAPI.Clients.ApiResponse
+"""
+function delete_workflow_schedule(_api::DefaultApi, id::Int64; body=nothing, _mediaType=nothing)
+    _ctx = _oacinternal_delete_workflow_schedule(_api, id; body=body, _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx)
+end
+
+function delete_workflow_schedule(_api::DefaultApi, response_stream::Channel, id::Int64; body=nothing, _mediaType=nothing)
+    _ctx = _oacinternal_delete_workflow_schedule(_api, id; body=body, _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx, response_stream)
+end
+
 const _returntypes_get_access_group_DefaultApi = Dict{Regex,Type}(
     Regex("^" * replace("200", "x"=>".") * "\$") => AccessGroupModel,
     Regex("^" * replace("404", "x"=>".") * "\$") => DefaultErrorResponse,
@@ -1825,6 +1959,74 @@ function get_job(_api::DefaultApi, response_stream::Channel, id::Int64; _mediaTy
     return OpenAPI.Clients.exec(_ctx, response_stream)
 end
 
+const _returntypes_get_job_log_DefaultApi = Dict{Regex,Type}(
+    Regex("^" * replace("200", "x"=>".") * "\$") => JobLogModel,
+    Regex("^" * replace("403", "x"=>".") * "\$") => ForbiddenErrorResponse,
+    Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
+    Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
+)
+
+function _oacinternal_get_job_log(_api::DefaultApi, id::Int64; _mediaType=nothing)
+    _ctx = OpenAPI.Clients.Ctx(_api.client, "GET", _returntypes_get_job_log_DefaultApi, "/job_logs/{id}", [])
+    OpenAPI.Clients.set_param(_ctx.path, "id", id)  # type Int64
+    OpenAPI.Clients.set_header_accept(_ctx, ["application/json", ])
+    OpenAPI.Clients.set_header_content_type(_ctx, (_mediaType === nothing) ? [] : [_mediaType])
+    return _ctx
+end
+
+@doc raw"""Get a job log by ID.
+
+Retrieve a job log by ID.
+
+Params:
+- id::Int64 (required)
+
+Return: JobLogModel, OpenAPI.Clients.ApiResponse
+"""
+function get_job_log(_api::DefaultApi, id::Int64; _mediaType=nothing)
+    _ctx = _oacinternal_get_job_log(_api, id; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx)
+end
+
+function get_job_log(_api::DefaultApi, response_stream::Channel, id::Int64; _mediaType=nothing)
+    _ctx = _oacinternal_get_job_log(_api, id; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx, response_stream)
+end
+
+const _returntypes_get_job_log_content_DefaultApi = Dict{Regex,Type}(
+    Regex("^" * replace("200", "x"=>".") * "\$") => String,
+    Regex("^" * replace("403", "x"=>".") * "\$") => ForbiddenErrorResponse,
+    Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
+    Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
+)
+
+function _oacinternal_get_job_log_content(_api::DefaultApi, id::Int64; _mediaType=nothing)
+    _ctx = OpenAPI.Clients.Ctx(_api.client, "GET", _returntypes_get_job_log_content_DefaultApi, "/job_logs/{id}/content", [])
+    OpenAPI.Clients.set_param(_ctx.path, "id", id)  # type Int64
+    OpenAPI.Clients.set_header_accept(_ctx, ["text/plain", "application/json", ])
+    OpenAPI.Clients.set_header_content_type(_ctx, (_mediaType === nothing) ? [] : [_mediaType])
+    return _ctx
+end
+
+@doc raw"""Get the content of a job log.
+
+Retrieve the content of a job log as plain text, for example to stream it to a terminal with curl.
+
+Params:
+- id::Int64 (required)
+
+Return: String, OpenAPI.Clients.ApiResponse
+"""
+function get_job_log_content(_api::DefaultApi, id::Int64; _mediaType=nothing)
+    _ctx = _oacinternal_get_job_log_content(_api, id; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx)
+end
+
+function get_job_log_content(_api::DefaultApi, response_stream::Channel, id::Int64; _mediaType=nothing)
+    _ctx = _oacinternal_get_job_log_content(_api, id; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx, response_stream)
+end
+
 const _returntypes_get_latest_event_timestamp_DefaultApi = Dict{Regex,Type}(
     Regex("^" * replace("200", "x"=>".") * "\$") => Any,
     Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
@@ -2221,6 +2423,39 @@ function get_workflow_actions(_api::DefaultApi, response_stream::Channel, id::In
     return OpenAPI.Clients.exec(_ctx, response_stream)
 end
 
+const _returntypes_get_workflow_schedule_DefaultApi = Dict{Regex,Type}(
+    Regex("^" * replace("200", "x"=>".") * "\$") => WorkflowScheduleModel,
+    Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
+    Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
+)
+
+function _oacinternal_get_workflow_schedule(_api::DefaultApi, id::Int64; _mediaType=nothing)
+    _ctx = OpenAPI.Clients.Ctx(_api.client, "GET", _returntypes_get_workflow_schedule_DefaultApi, "/workflow_schedules/{id}", [])
+    OpenAPI.Clients.set_param(_ctx.path, "id", id)  # type Int64
+    OpenAPI.Clients.set_header_accept(_ctx, ["application/json", ])
+    OpenAPI.Clients.set_header_content_type(_ctx, (_mediaType === nothing) ? [] : [_mediaType])
+    return _ctx
+end
+
+@doc raw"""Get a workflow schedule by ID.
+
+Retrieve a workflow schedule by ID.
+
+Params:
+- id::Int64 (required)
+
+Return: WorkflowScheduleModel, OpenAPI.Clients.ApiResponse
+"""
+function get_workflow_schedule(_api::DefaultApi, id::Int64; _mediaType=nothing)
+    _ctx = _oacinternal_get_workflow_schedule(_api, id; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx)
+end
+
+function get_workflow_schedule(_api::DefaultApi, response_stream::Channel, id::Int64; _mediaType=nothing)
+    _ctx = _oacinternal_get_workflow_schedule(_api, id; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx, response_stream)
+end
+
 const _returntypes_get_workflow_status_DefaultApi = Dict{Regex,Type}(
     Regex("^" * replace("200", "x"=>".") * "\$") => WorkflowStatusModel,
     Regex("^" * replace("403", "x"=>".") * "\$") => ForbiddenErrorResponse,
@@ -2392,6 +2627,40 @@ function list_access_groups(_api::DefaultApi, response_stream::Channel; offset=n
     return OpenAPI.Clients.exec(_ctx, response_stream)
 end
 
+const _returntypes_list_api_tokens_DefaultApi = Dict{Regex,Type}(
+    Regex("^" * replace("200", "x"=>".") * "\$") => ListApiTokensResponse,
+    Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
+)
+
+function _oacinternal_list_api_tokens(_api::DefaultApi; offset=nothing, limit=nothing, _mediaType=nothing)
+    _ctx = OpenAPI.Clients.Ctx(_api.client, "GET", _returntypes_list_api_tokens_DefaultApi, "/api_tokens", [])
+    OpenAPI.Clients.set_param(_ctx.query, "offset", offset; style="form", is_explode=true)  # type Int64
+    OpenAPI.Clients.set_param(_ctx.query, "limit", limit; style="form", is_explode=true)  # type Int64
+    OpenAPI.Clients.set_header_accept(_ctx, ["application/json", ])
+    OpenAPI.Clients.set_header_content_type(_ctx, (_mediaType === nothing) ? [] : [_mediaType])
+    return _ctx
+end
+
+@doc raw"""List API tokens.
+
+List API tokens. Non-admin users only see their own tokens.
+
+Params:
+- offset::Int64
+- limit::Int64
+
+Return: ListApiTokensResponse, OpenAPI.Clients.ApiResponse
+"""
+function list_api_tokens(_api::DefaultApi; offset=nothing, limit=nothing, _mediaType=nothing)
+    _ctx = _oacinternal_list_api_tokens(_api; offset=offset, limit=limit, _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx)
+end
+
+function list_api_tokens(_api::DefaultApi, response_stream::Channel; offset=nothing, limit=nothing, _mediaType=nothing)
+    _ctx = _oacinternal_list_api_tokens(_api; offset=offset, limit=limit, _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx, response_stream)
+end
+
 const _returntypes_list_compute_nodes_DefaultApi = Dict{Regex,Type}(
     Regex("^" * replace("200", "x"=>".") * "\$") => ListComputeNodesResponse,
     Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
@@ -2712,6 +2981,52 @@ function list_job_ids(_api::DefaultApi, response_stream::Channel, id::Int64; _me
     return OpenAPI.Clients.exec(_ctx, response_stream)
 end
 
+const _returntypes_list_job_logs_DefaultApi = Dict{Regex,Type}(
+    Regex("^" * replace("200", "x"=>".") * "\$") => ListJobLogsResponse,
+    Regex("^" * replace("403", "x"=>".") * "\$") => ForbiddenErrorResponse,
+    Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
+    Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
+)
+
+function _oacinternal_list_job_logs(_api::DefaultApi, workflow_id::Int64; job_id=nothing, run_id=nothing, attempt_id=nothing, stream=nothing, offset=nothing, limit=nothing, _mediaType=nothing)
+    _ctx = OpenAPI.Clients.Ctx(_api.client, "GET", _returntypes_list_job_logs_DefaultApi, "/job_logs", [])
+    OpenAPI.Clients.set_param(_ctx.query, "workflow_id", workflow_id; style="form", is_explode=true)  # type Int64
+    OpenAPI.Clients.set_param(_ctx.query, "job_id", job_id; style="form", is_explode=true)  # type Int64
+    OpenAPI.Clients.set_param(_ctx.query, "run_id", run_id; style="form", is_explode=true)  # type Int64
+    OpenAPI.Clients.set_param(_ctx.query, "attempt_id", attempt_id; style="form", is_explode=true)  # type Int64
+    OpenAPI.Clients.set_param(_ctx.query, "stream", stream; style="form", is_explode=true)  # type JobLogStream
+    OpenAPI.Clients.set_param(_ctx.query, "offset", offset; style="form", is_explode=true)  # type Int64
+    OpenAPI.Clients.set_param(_ctx.query, "limit", limit; style="form", is_explode=true)  # type Int64
+    OpenAPI.Clients.set_header_accept(_ctx, ["application/json", ])
+    OpenAPI.Clients.set_header_content_type(_ctx, (_mediaType === nothing) ? [] : [_mediaType])
+    return _ctx
+end
+
+@doc raw"""List job logs.
+
+Retrieve the uploaded job logs of a workflow. The content of each log is left empty; fetch it with /job_logs/{id}/content.
+
+Params:
+- workflow_id::Int64 (required)
+- job_id::Int64
+- run_id::Int64
+- attempt_id::Int64
+- stream::JobLogStream
+- offset::Int64
+- limit::Int64
+
+Return: ListJobLogsResponse, OpenAPI.Clients.ApiResponse
+"""
+function list_job_logs(_api::DefaultApi, workflow_id::Int64; job_id=nothing, run_id=nothing, attempt_id=nothing, stream=nothing, offset=nothing, limit=nothing, _mediaType=nothing)
+    _ctx = _oacinternal_list_job_logs(_api, workflow_id; job_id=job_id, run_id=run_id, attempt_id=attempt_id, stream=stream, offset=offset, limit=limit, _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx)
+end
+
+function list_job_logs(_api::DefaultApi, response_stream::Channel, workflow_id::Int64; job_id=nothing, run_id=nothing, attempt_id=nothing, stream=nothing, offset=nothing, limit=nothing, _mediaType=nothing)
+    _ctx = _oacinternal_list_job_logs(_api, workflow_id; job_id=job_id, run_id=run_id, attempt_id=attempt_id, stream=stream, offset=offset, limit=limit, _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx, response_stream)
+end
+
 const _returntypes_list_job_user_data_relationships_DefaultApi = Dict{Regex,Type}(
     Regex("^" * replace("200", "x"=>".") * "\$") => ListJobUserDataRelationshipsResponse,
     Regex("^" * replace("403", "x"=>".") * "\$") => ForbiddenErrorResponse,
@@ -3282,6 +3597,42 @@ function list_workflow_groups(_api::DefaultApi, response_stream::Channel, id::In
     return OpenAPI.Clients.exec(_ctx, response_stream)
 end
 
+const _returntypes_list_workflow_schedules_DefaultApi = Dict{Regex,Type}(
+    Regex("^" * replace("200", "x"=>".") * "\$") => ListWorkflowSchedulesResponse,
+    Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
+)
+
+function _oacinternal_list_workflow_schedules(_api::DefaultApi; workflow_id=nothing, offset=nothing, limit=nothing, _mediaType=nothing)
+    _ctx = OpenAPI.Clients.Ctx(_api.client, "GET", _returntypes_list_workflow_schedules_DefaultApi, "/workflow_schedules", [])
+    OpenAPI.Clients.set_param(_ctx.query, "workflow_id", workflow_id; style="form", is_explode=true)  # type Int64
+    OpenAPI.Clients.set_param(_ctx.query, "offset", offset; style="form", is_explode=true)  # type Int64
+    OpenAPI.Clients.set_param(_ctx.query, "limit", limit; style="form", is_explode=true)  # type Int64
+    OpenAPI.Clients.set_header_accept(_ctx, ["application/json", ])
+    OpenAPI.Clients.set_header_content_type(_ctx, (_mediaType === nothing) ? [] : [_mediaType])
+    return _ctx
+end
+
+@doc raw"""List workflow schedules.
+
+Retrieve workflow schedules, optionally for one workflow.
+
+Params:
+- workflow_id::Int64
+- offset::Int64
+- limit::Int64
+
+Return: ListWorkflowSchedulesResponse, OpenAPI.Clients.ApiResponse
+"""
+function list_workflow_schedules(_api::DefaultApi; workflow_id=nothing, offset=nothing, limit=nothing, _mediaType=nothing)
+    _ctx = _oacinternal_list_workflow_schedules(_api; workflow_id=workflow_id, offset=offset, limit=limit, _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx)
+end
+
+function list_workflow_schedules(_api::DefaultApi, response_stream::Channel; workflow_id=nothing, offset=nothing, limit=nothing, _mediaType=nothing)
+    _ctx = _oacinternal_list_workflow_schedules(_api; workflow_id=workflow_id, offset=offset, limit=limit, _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx, response_stream)
+end
+
 const _returntypes_list_workflows_DefaultApi = Dict{Regex,Type}(
     Regex("^" * replace("200", "x"=>".") * "\$") => ListWorkflowsResponse,
     Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
@@ -3430,6 +3781,41 @@ function process_changed_job_inputs(_api::DefaultApi, response_stream::Channel,
     return OpenAPI.Clients.exec(_ctx, response_stream)
 end
 
+const _returntypes_release_compute_node_DefaultApi = Dict{Regex,Type}(
+    Regex("^" * replace("200", "x"=>".") * "\$") => ComputeNodeModel,
+    Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
+    Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
+)
+
+function _oacinternal_release_compute_node(_api::DefaultApi, id::Int64, job_id::Int64; _mediaType=nothing)
+    _ctx = OpenAPI.Clients.Ctx(_api.client, "DELETE", _returntypes_release_compute_node_DefaultApi, "/compute_nodes/{id}/reservation/{job_id}", [])
+    OpenAPI.Clients.set_param(_ctx.path, "id", id)  # type Int64
+    OpenAPI.Clients.set_param(_ctx.path, "job_id", job_id)  # type Int64
+    OpenAPI.Clients.set_header_accept(_ctx, ["application/json", ])
+    OpenAPI.Clients.set_header_content_type(_ctx, (_mediaType === nothing) ? [] : [_mediaType])
+    return _ctx
+end
+
+@doc raw"""Release a compute node reserved for a job.
+
+Release the reservation of a compute node for a job. A compute node that is reserved for another job is left unchanged.
+
+Params:
+- id::Int64 (required)
+- job_id::Int64 (required)
+
+Return: ComputeNodeModel, OpenAPI.Clients.ApiResponse
+"""
+function release_compute_node(_api::DefaultApi, id::Int64, job_id::Int64; _mediaType=nothing)
+    _ctx = _oacinternal_release_compute_node(_api, id, job_id; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx)
+end
+
+function release_compute_node(_api::DefaultApi, response_stream::Channel, id::Int64, job_id::Int64; _mediaType=nothing)
+    _ctx = _oacinternal_release_compute_node(_api, id, job_id; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx, response_stream)
+end
+
 const _returntypes_remove_user_from_group_DefaultApi = Dict{Regex,Type}(
     Regex("^" * replace("200", "x"=>".") * "\$") => Any,
     Regex("^" * replace("403", "x"=>".") * "\$") => ForbiddenErrorResponse,
@@ -3504,6 +3890,41 @@ function remove_workflow_from_group(_api::DefaultApi, response_stream::Channel,
     return OpenAPI.Clients.exec(_ctx, response_stream)
 end
 
+const _returntypes_reserve_compute_node_DefaultApi = Dict{Regex,Type}(
+    Regex("^" * replace("200", "x"=>".") * "\$") => ComputeNodeModel,
+    Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
+    Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
+)
+
+function _oacinternal_reserve_compute_node(_api::DefaultApi, id::Int64, job_id::Int64; _mediaType=nothing)
+    _ctx = OpenAPI.Clients.Ctx(_api.client, "PUT", _returntypes_reserve_compute_node_DefaultApi, "/compute_nodes/{id}/reservation/{job_id}", [])
+    OpenAPI.Clients.set_param(_ctx.path, "id", id)  # type Int64
+    OpenAPI.Clients.set_param(_ctx.path, "job_id", job_id)  # type Int64
+    OpenAPI.Clients.set_header_accept(_ctx, ["application/json", ])
+    OpenAPI.Clients.set_header_content_type(_ctx, (_mediaType === nothing) ? [] : [_mediaType])
+    return _ctx
+end
+
+@doc raw"""Reserve a compute node for a job.
+
+Reserve a compute node for a multi-node job. Only the reservation is changed. A compute node that is already reserved for another job is left unchanged; check reserved_for_job_id of the returned compute node.
+
+Params:
+- id::Int64 (required)
+- job_id::Int64 (required)
+
+Return: ComputeNodeModel, OpenAPI.Clients.ApiResponse
+"""
+function reserve_compute_node(_api::DefaultApi, id::Int64, job_id::Int64; _mediaType=nothing)
+    _ctx = _oacinternal_reserve_compute_node(_api, id, job_id; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx)
+end
+
+function reserve_compute_node(_api::DefaultApi, response_stream::Channel, id::Int64, job_id::Int64; _mediaType=nothing)
+    _ctx = _oacinternal_reserve_compute_node(_api, id, job_id; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx, response_stream)
+end
+
 const _returntypes_reset_job_status_DefaultApi = Dict{Regex,Type}(
     Regex("^" * replace("200", "x"=>".") * "\$") => ResetJobStatusResponse,
     Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
@@ -3612,6 +4033,41 @@ function retry_job(_api::DefaultApi, response_stream::Channel, id::Int64, run_id
     return OpenAPI.Clients.exec(_ctx, response_stream)
 end
 
+const _returntypes_revoke_api_token_DefaultApi = Dict{Regex,Type}(
+    Regex("^" * replace("200", "x"=>".") * "\$") => ApiTokenModel,
+    Regex("^" * replace("403", "x"=>".") * "\$") => ForbiddenErrorResponse,
+    Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
+    Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
+)
+
+function _oacinternal_revoke_api_token(_api::DefaultApi, id::Int64; body=nothing, _mediaType=nothing)
+    _ctx = OpenAPI.Clients.Ctx(_api.client, "DELETE", _returntypes_revoke_api_token_DefaultApi, "/api_tokens/{id}", [], body)
+    OpenAPI.Clients.set_param(_ctx.path, "id", id)  # type Int64
+    OpenAPI.Clients.set_header_accept(_ctx, ["application/json", ])
+    OpenAPI.Clients.set_header_content_type(_ctx, (_mediaType === nothing) ? ["application/json", ] : [_mediaType])
+    return _ctx
+end
+
+@doc raw"""Revoke an API token.
+
+Revoke an API token.
+
+Params:
+- id::Int64 (required)
+- body::Any
+
+Return: ApiTokenModel, OpenAPI.Clients.ApiResponse
+"""
+function revoke_api_token(_api::DefaultApi, id::Int64; body=nothing, _mediaType=nothing)
+    _ctx = _oacinternal_revoke_api_token(_api, id; body=body, _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx)
+end
+
+function revoke_api_token(_api::DefaultApi, response_stream::Channel, id::Int64; body=nothing, _mediaType=nothing)
+    _ctx = _oacinternal_revoke_api_token(_api, id; body=body, _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx, response_stream)
+end
+
 const _returntypes_start_job_DefaultApi = Dict{Regex,Type}(
     Regex("^" * replace("200", "x"=>".") * "\$") => JobModel,
     Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
@@ -3684,6 +4140,39 @@ function update_compute_node(_api::DefaultApi, response_stream::Channel, id::Int
     return OpenAPI.Clients.exec(_ctx, response_stream)
 end
 
+const _returntypes_update_compute_node_heartbeat_DefaultApi = Dict{Regex,Type}(
+    Regex("^" * replace("200", "x"=>".") * "\$") => ComputeNodeModel,
+    Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
+    Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
+)
+
+function _oacinternal_update_compute_node_heartbeat(_api::DefaultApi, id::Int64; _mediaType=nothing)
+    _ctx = OpenAPI.Clients.Ctx(_api.client, "PUT", _returntypes_update_compute_node_heartbeat_DefaultApi, "/compute_nodes/{id}/heartbeat", [])
+    OpenAPI.Clients.set_param(_ctx.path, "id", id)  # type Int64
+    OpenAPI.Clients.set_header_accept(_ctx, ["application/json", ])
+    OpenAPI.Clients.set_header_content_type(_ctx, (_mediaType === nothing) ? [] : [_mediaType])
+    return _ctx
+end
+
+@doc raw"""Record a compute node heartbeat.
+
+Record a heartbeat from the job runner on a compute node. The response contains the compute node; is_active is false if the server has declared the compute node dead.
+
+Params:
+- id::Int64 (required)
+
+Return: ComputeNodeModel, OpenAPI.Clients.ApiResponse
+"""
+function update_compute_node_heartbeat(_api::DefaultApi, id::Int64; _mediaType=nothing)
+    _ctx = _oacinternal_update_compute_node_heartbeat(_api, id; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx)
+end
+
+function update_compute_node_heartbeat(_api::DefaultApi, response_stream::Channel, id::Int64; _mediaType=nothing)
+    _ctx = _oacinternal_update_compute_node_heartbeat(_api, id; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx, response_stream)
+end
+
 const _returntypes_update_event_DefaultApi = Dict{Regex,Type}(
     Regex("^" * replace("200", "x"=>".") * "\$") => EventModel,
     Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
@@ -4026,6 +4515,41 @@ function update_workflow(_api::DefaultApi, response_stream::Channel, id::Int64,
     return OpenAPI.Clients.exec(_ctx, response_stream)
 end
 
+const _returntypes_update_workflow_schedule_DefaultApi = Dict{Regex,Type}(
+    Regex("^" * replace("200", "x"=>".") * "\$") => WorkflowScheduleModel,
+    Regex("^" * replace("404", "x"=>".") * "\$") => NotFoundErrorResponse,
+    Regex("^" * replace("422", "x"=>".") * "\$") => DefaultErrorResponse,
+    Regex("^" * replace("500", "x"=>".") * "\$") => DefaultErrorResponse,
+)
+
+function _oacinternal_update_workflow_schedule(_api::DefaultApi, id::Int64, body::WorkflowScheduleModel; _mediaType=nothing)
+    _ctx = OpenAPI.Clients.Ctx(_api.client, "PUT", _returntypes_update_workflow_schedule_DefaultApi, "/workflow_schedules/{id}", [], body)
+    OpenAPI.Clients.set_param(_ctx.path, "id", id)  # type Int64
+    OpenAPI.Clients.set_header_accept(_ctx, ["application/json", ])
+    OpenAPI.Clients.set_header_content_type(_ctx, (_mediaType === nothing) ? ["application/json", ] : [_mediaType])
+    return _ctx
+end
+
+@doc raw"""Update a workflow schedule.
+
+Update a workflow schedule. Set is_paused to pause or resume it. The next run time is recomputed from the current time when the cron expression or timezone changes or when the schedule is resumed.
+
+Params:
+- id::Int64 (required)
+- body::WorkflowScheduleModel (required)
+
+Return: WorkflowScheduleModel, OpenAPI.Clients.ApiResponse
+"""
+function update_workflow_schedule(_api::DefaultApi, id::Int64, body::WorkflowScheduleModel; _mediaType=nothing)
+    _ctx = _oacinternal_update_workflow_schedule(_api, id, body; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx)
+end
+
+function update_workflow_schedule(_api::DefaultApi, response_stream::Channel, id::Int64, body::WorkflowScheduleModel; _mediaType=nothing)
+    _ctx = _oacinternal_update_workflow_schedule(_api, id, body; _mediaType=_mediaType)
+    return OpenAPI.Clients.exec(_ctx, response_stream)
+end
+
 const _returntypes_update_workflow_status_DefaultApi = Dict{Regex,Type}(
     Regex("^" * replace("200", "x"=>".") * "\$") => WorkflowStatusModel,
     Regex("^" * replace("403", "x"=>".") * "\$") => ForbiddenErrorResponse,
@@ -4070,11 +4594,13 @@ export claim_jobs_based_on_resources
 export claim_next_jobs
 export complete_job
 export create_access_group
+export create_api_token
 export create_compute_node
 export create_event
 export create_failure_handler
 export create_file
 export create_job
+export create_job_log
 export create_jobs
 export create_local_scheduler
 export create_remote_workers
@@ -4085,6 +4611,7 @@ export create_slurm_scheduler
 export create_user_data
 export create_workflow
 export create_workflow_action
+export create_workflow_schedule
 export delete_access_group
 export delete_all_user_data
 export delete_compute_node
@@ -4109,12 +4636,15 @@ export delete_slurm_scheduler
 export delete_slurm_schedulers
 export delete_user_data
 export delete_workflow
+export delete_workflow_schedule
 export get_access_group
 export get_compute_node
 export get_event
 export get_failure_handler
 export get_file
 export get_job
+export get_job_log
+export get_job_log_content
 export get_latest_event_timestamp
 export get_local_scheduler
 export get_pending_actions
@@ -4127,11 +4657,13 @@ export get_user_data
 export get_version
 export get_workflow
 export get_workflow_actions
+export get_workflow_schedule
 export get_workflow_status
 export initialize_jobs
 export is_workflow_complete
 export is_workflow_uninitialized
 export list_access_groups
+export list_api_tokens
 export list_compute_nodes
 export list_events
 export list_failure_handlers
@@ -4140,6 +4672,7 @@ export list_group_members
 export list_job_dependencies
 export list_job_file_relationships
 export list_job_ids
+export list_job_logs
 export list_job_user_data_relationships
 export list_jobs
 export list_local_schedulers
@@ -4153,17 +4686,22 @@ export list_slurm_schedulers
 export list_user_data
 export list_user_groups
 export list_workflow_groups
+export list_workflow_schedules
 export list_workflows
 export manage_status_change
 export ping
 export process_changed_job_inputs
+export release_compute_node
 export remove_user_from_group
 export remove_workflow_from_group
+export reserve_compute_node
 export reset_job_status
 export reset_workflow_status
 export retry_job
+export revoke_api_token
 export start_job
 export update_compute_node
+export update_compute_node_heartbeat
 export update_event
 export update_file
 export update_job
@@ -4174,4 +4712,5 @@ export update_scheduled_compute_node
 export update_slurm_scheduler
 export update_user_data
 export update_workflow
+export update_workflow_schedule
 export update_workflow_status
@@ -3,6 +3,7 @@
 
 include("models/model_AccessCheckResponse.jl")
 include("models/model_AccessGroupModel.jl")
+include("models/model_ApiTokenModel.jl")
 include("models/model_ClaimAction200Response.jl")
 include("models/model_ClaimActionRequest.jl")
 include("models/model_ClaimJobsBasedOnResourcesResponse.jl")
@@ -10,7 +11,9 @@ include("models/model_ClaimNextJobsResponse.jl")
 include("models/model_ComputeNodeModel.jl")
 include("models/model_ComputeNodeSchedule.jl")
 include("models/model_ComputeNodesResources.jl")
+include("models/model_ContainerRuntime.jl")
 include("models/model_CreateJobsResponse.jl")
+include("models/model_DeadWorkerPolicy.jl")
 include("models/model_DefaultErrorResponse.jl")
 include("models/model_EventModel.jl")
 include("models/model_FailureHandlerModel.jl")
@@ -19,14 +22,18 @@ include("models/model_ForbiddenErrorResponse.jl")
 include("models/model_GetReadyJobRequirementsResponse.jl")
 include("models/model_IsCompleteResponse.jl")
 include("models/model_IsUninitializedResponse.jl")
+include("models/model_JobContainerModel.jl")
 include("models/model_JobDependencyModel.jl")
 include("models/model_JobFileRelationshipModel.jl")
+include("models/model_JobLogModel.jl")
+include("models/model_JobLogStream.jl")
 include("models/model_JobModel.jl")
 include("models/model_JobStatus.jl")
 include("models/model_JobUserDataRelationshipModel.jl")
 include("models/model_JobsModel.jl")
 include("models/model_JobsSortMethod.jl")
 include("models/model_ListAccessGroupsResponse.jl")
+include("models/model_ListApiTokensResponse.jl")
 include("models/model_ListComputeNodesResponse.jl")
 include("models/model_ListEventsResponse.jl")
 include("models/model_ListFailureHandlersResponse.jl")
@@ -34,6 +41,7 @@ include("models/model_ListFilesResponse.jl")
 include("models/model_ListJobDependenciesResponse.jl")
 include("models/model_ListJobFileRelationshipsResponse.jl")
 include("models/model_ListJobIdsResponse.jl")
+include("models/model_ListJobLogsResponse.jl")
 include("models/model_ListJobUserDataRelationshipsResponse.jl")
 include("models/model_ListJobsResponse.jl")
 include("models/model_ListLocalSchedulersResponse.jl")
@@ -45,6 +53,7 @@ include("models/model_ListScheduledComputeNodesResponse.jl")
 include("models/model_ListSlurmSchedulersResponse.jl")
 include("models/model_ListUserDataResponse.jl")
 include("models/model_ListUserGroupMembershipsResponse.jl")
+include("models/model_ListWorkflowSchedulesResponse.jl")
 include("models/model_ListWorkflowsResponse.jl")
 include("models/model_LocalSchedulerModel.jl")
 include("models/model_NotFoundErrorResponse.jl")
@@ -53,6 +62,7 @@ include("models/model_RemoteWorkerModel.jl")
 include("models/model_ResetJobStatusResponse.jl")
 include("models/model_ResourceRequirementsModel.jl")
 include("models/model_ResultModel.jl")
+include("models/model_ScheduleOverlapPolicy.jl")
 include("models/model_ScheduledComputeNodesModel.jl")
 include("models/model_SlurmSchedulerModel.jl")
 include("models/model_UserDataModel.jl")
@@ -60,4 +70,5 @@ include("models/model_UserGroupMembershipModel.jl")
 include("models/model_WorkflowAccessGroupModel.jl")
 include("models/model_WorkflowActionModel.jl")
 include("models/model_WorkflowModel.jl")
+include("models/model_WorkflowScheduleModel.jl")
 include("models/model_WorkflowStatusModel.jl")
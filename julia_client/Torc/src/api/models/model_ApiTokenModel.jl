@@ -0,0 +1,73 @@
+# This file was generated by the Julia OpenAPI Code Generator
+# Do not modify this file directly. Modify the OpenAPI specification instead.
+
+
+@doc raw"""api_token_model
+Server-issued API token for bearer authentication
+
+    ApiTokenModel(;
+        id=nothing,
+        name=nothing,
+        owner=nothing,
+        scopes=nothing,
+        created_at=nothing,
+        expires_at=nothing,
+        revoked=nothing,
+        token=nothing,
+    )
+
+    - id::Int64
+    - name::String
+    - owner::String : User that the token authenticates as
+    - scopes::Vector{String} : Granted scopes. &#39;read&#39; allows GET requests; &#39;write&#39; allows all others.
+    - created_at::String
+    - expires_at::String : Expiration time in UTC (&#39;YYYY-MM-DD HH:MM:SS&#39;). Never expires if unset.
+    - revoked::Bool
+    - token::String : Plaintext token. Only returned when the token is created.
+"""
+Base.@kwdef mutable struct ApiTokenModel <: OpenAPI.APIModel
+    id::Union{Nothing, Int64} = nothing
+    name::Union{Nothing, String} = nothing
+    owner::Union{Nothing, String} = nothing
+    scopes::Union{Nothing, Vector{String}} = nothing
+    created_at::Union{Nothing, String} = nothing
+    expires_at::Union{Nothing, String} = nothing
+    revoked::Union{Nothing, Bool} = nothing
+    token::Union{Nothing, String} = nothing
+
+    function ApiTokenModel(id, name, owner, scopes, created_at, expires_at, revoked, token, )
+        o = new(id, name, owner, scopes, created_at, expires_at, revoked, token, )
+        OpenAPI.validate_properties(o)
+        return o
+    end
+end # type ApiTokenModel
+
+const _property_types_ApiTokenModel = Dict{Symbol,String}(Symbol("id")=>"Int64", Symbol("name")=>"String", Symbol("owner")=>"String", Symbol("scopes")=>"Vector{String}", Symbol("created_at")=>"String", Symbol("expires_at")=>"String", Symbol("revoked")=>"Bool", Symbol("token")=>"String", )
+OpenAPI.property_type(::Type{ ApiTokenModel }, name::Symbol) = Union{Nothing,eval(Base.Meta.parse(_property_types_ApiTokenModel[name]))}
+
+function OpenAPI.check_required(o::ApiTokenModel)
+    o.name === nothing && (return false)
+    true
+end
+
+function OpenAPI.validate_properties(o::ApiTokenModel)
+    OpenAPI.validate_property(ApiTokenModel, Symbol("id"), o.id)
+    OpenAPI.validate_property(ApiTokenModel, Symbol("name"), o.name)
+    OpenAPI.validate_property(ApiTokenModel, Symbol("owner"), o.owner)
+    OpenAPI.validate_property(ApiTokenModel, Symbol("scopes"), o.scopes)
+    OpenAPI.validate_property(ApiTokenModel, Symbol("created_at"), o.created_at)
+    OpenAPI.validate_property(ApiTokenModel, Symbol("expires_at"), o.expires_at)
+    OpenAPI.validate_property(ApiTokenModel, Symbol("revoked"), o.revoked)
+    OpenAPI.validate_property(ApiTokenModel, Symbol("token"), o.token)
+end
+
+function OpenAPI.validate_property(::Type{ ApiTokenModel }, name::Symbol, val)
+
+
+
+
+
+
+
+
+end
@@ -20,6 +20,8 @@
         scheduler_config_id=nothing,
         compute_node_type=nothing,
         scheduler=nothing,
+        last_heartbeat=nothing,
+        reserved_for_job_id=nothing,
     )
 
     - id::Int64
@@ -37,6 +39,8 @@
     - scheduler_config_id::Int64
     - compute_node_type::String
     - scheduler::Any
+    - last_heartbeat::String : Time of the most recent heartbeat from the compute node's job runner (RFC3339).
+    - reserved_for_job_id::Int64 : ID of the multi-node job that this compute node's job runner is reserved for.
 """
 Base.@kwdef mutable struct ComputeNodeModel <: OpenAPI.APIModel
     id::Union{Nothing, Int64} = nothing
@@ -54,15 +58,17 @@ Base.@kwdef mutable struct ComputeNodeModel <: OpenAPI.APIModel
     scheduler_config_id::Union{Nothing, Int64} = nothing
     compute_node_type::Union{Nothing, String} = nothing
     scheduler::Union{Nothing, Any} = nothing
+    last_heartbeat::Union{Nothing, String} = nothing
+    reserved_for_job_id::Union{Nothing, Int64} = nothing
 
-    function ComputeNodeModel(id, workflow_id, hostname, pid, start_time, duration_seconds, is_active, num_cpus, memory_gb, num_gpus, num_nodes, time_limit, scheduler_config_id, compute_node_type, scheduler, )
-        o = new(id, workflow_id, hostname, pid, start_time, duration_seconds, is_active, num_cpus, memory_gb, num_gpus, num_nodes, time_limit, scheduler_config_id, compute_node_type, scheduler, )
+    function ComputeNodeModel(id, workflow_id, hostname, pid, start_time, duration_seconds, is_active, num_cpus, memory_gb, num_gpus, num_nodes, time_limit, scheduler_config_id, compute_node_type, scheduler, last_heartbeat, reserved_for_job_id, )
+        o = new(id, workflow_id, hostname, pid, start_time, duration_seconds, is_active, num_cpus, memory_gb, num_gpus, num_nodes, time_limit, scheduler_config_id, compute_node_type, scheduler, last_heartbeat, reserved_for_job_id, )
         OpenAPI.validate_properties(o)
         return o
     end
 end # type ComputeNodeModel
 
-const _property_types_ComputeNodeModel = Dict{Symbol,String}(Symbol("id")=>"Int64", Symbol("workflow_id")=>"Int64", Symbol("hostname")=>"String", Symbol("pid")=>"Int64", Symbol("start_time")=>"String", Symbol("duration_seconds")=>"Float64", Symbol("is_active")=>"Bool", Symbol("num_cpus")=>"Int64", Symbol("memory_gb")=>"Float64", Symbol("num_gpus")=>"Int64", Symbol("num_nodes")=>"Int64", Symbol("time_limit")=>"String", Symbol("scheduler_config_id")=>"Int64", Symbol("compute_node_type")=>"String", Symbol("scheduler")=>"Any", )
+const _property_types_ComputeNodeModel = Dict{Symbol,String}(Symbol("id")=>"Int64", Symbol("workflow_id")=>"Int64", Symbol("hostname")=>"String", Symbol("pid")=>"Int64", Symbol("start_time")=>"String", Symbol("duration_seconds")=>"Float64", Symbol("is_active")=>"Bool", Symbol("num_cpus")=>"Int64", Symbol("memory_gb")=>"Float64", Symbol("num_gpus")=>"Int64", Symbol("num_nodes")=>"Int64", Symbol("time_limit")=>"String", Symbol("scheduler_config_id")=>"Int64", Symbol("compute_node_type")=>"String", Symbol("scheduler")=>"Any", Symbol("last_heartbeat")=>"String", Symbol("reserved_for_job_id")=>"Int64", )
 OpenAPI.property_type(::Type{ ComputeNodeModel }, name::Symbol) = Union{Nothing,eval(Base.Meta.parse(_property_types_ComputeNodeModel[name]))}
 
 function OpenAPI.check_required(o::ComputeNodeModel)
@@ -94,6 +100,8 @@ function OpenAPI.validate_properties(o::ComputeNodeModel)
     OpenAPI.validate_property(ComputeNodeModel, Symbol("scheduler_config_id"), o.scheduler_config_id)
     OpenAPI.validate_property(ComputeNodeModel, Symbol("compute_node_type"), o.compute_node_type)
     OpenAPI.validate_property(ComputeNodeModel, Symbol("scheduler"), o.scheduler)
+    OpenAPI.validate_property(ComputeNodeModel, Symbol("last_heartbeat"), o.last_heartbeat)
+    OpenAPI.validate_property(ComputeNodeModel, Symbol("reserved_for_job_id"), o.reserved_for_job_id)
 end
 
 function OpenAPI.validate_property(::Type{ ComputeNodeModel }, name::Symbol, val)
@@ -112,4 +120,5 @@ function OpenAPI.validate_property(::Type{ ComputeNodeModel }, name::Symbol, val
 
 
 
+
 end
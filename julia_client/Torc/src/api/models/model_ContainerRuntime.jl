@@ -0,0 +1,9 @@
+# This file was generated by the Julia OpenAPI Code Generator
+# Do not modify this file directly. Modify the OpenAPI specification instead.
+
+
+if !isdefined(@__MODULE__, :ContainerRuntime)
+    const ContainerRuntime = String
+else
+    @warn("Skipping redefinition of ContainerRuntime to String")
+end
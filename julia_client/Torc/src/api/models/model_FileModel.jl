@@ -11,6 +11,8 @@ Data model for files needed or produced by jobs. Can be data or code.
         name=nothing,
         path=nothing,
         st_mtime=nothing,
+        use_checksum=false,
+        content_hash=nothing,
     )
 
     - id::Int64
@@ -18,6 +20,8 @@ Data model for files needed or produced by jobs. Can be data or code.
     - name::String : User-defined name of the file (not necessarily the filename)
     - path::String : Path to the file; can be relative to the execution directory.
     - st_mtime::Float64 : Timestamp of when the file was last modified
+    - use_checksum::Bool : Detect changes to this file by content hash instead of modification time
+    - content_hash::String : Content hash of the file, recorded when use_checksum is enabled
 """
 Base.@kwdef mutable struct FileModel <: OpenAPI.APIModel
     id::Union{Nothing, Int64} = nothing
@@ -25,15 +29,17 @@ Base.@kwdef mutable struct FileModel <: OpenAPI.APIModel
     name::Union{Nothing, String} = nothing
     path::Union{Nothing, String} = nothing
     st_mtime::Union{Nothing, Float64} = nothing
+    use_checksum::Union{Nothing, Bool} = false
+    content_hash::Union{Nothing, String} = nothing
 
-    function FileModel(id, workflow_id, name, path, st_mtime, )
-        o = new(id, workflow_id, name, path, st_mtime, )
+    function FileModel(id, workflow_id, name, path, st_mtime, use_checksum, content_hash, )
+        o = new(id, workflow_id, name, path, st_mtime, use_checksum, content_hash, )
         OpenAPI.validate_properties(o)
         return o
     end
 end # type FileModel
 
-const _property_types_FileModel = Dict{Symbol,String}(Symbol("id")=>"Int64", Symbol("workflow_id")=>"Int64", Symbol("name")=>"String", Symbol("path")=>"String", Symbol("st_mtime")=>"Float64", )
+const _property_types_FileModel = Dict{Symbol,String}(Symbol("id")=>"Int64", Symbol("workflow_id")=>"Int64", Symbol("name")=>"String", Symbol("path")=>"String", Symbol("st_mtime")=>"Float64", Symbol("use_checksum")=>"Bool", Symbol("content_hash")=>"String", )
 OpenAPI.property_type(::Type{ FileModel }, name::Symbol) = Union{Nothing,eval(Base.Meta.parse(_property_types_FileModel[name]))}
 
 function OpenAPI.check_required(o::FileModel)
@@ -49,6 +55,8 @@ function OpenAPI.validate_properties(o::FileModel)
     OpenAPI.validate_property(FileModel, Symbol("name"), o.name)
     OpenAPI.validate_property(FileModel, Symbol("path"), o.path)
     OpenAPI.validate_property(FileModel, Symbol("st_mtime"), o.st_mtime)
+    OpenAPI.validate_property(FileModel, Symbol("use_checksum"), o.use_checksum)
+    OpenAPI.validate_property(FileModel, Symbol("content_hash"), o.content_hash)
 end
 
 function OpenAPI.validate_property(::Type{ FileModel }, name::Symbol, val)
@@ -57,4 +65,6 @@ function OpenAPI.validate_property(::Type{ FileModel }, name::Symbol, val)
 
 
 
+
+
 end
@@ -0,0 +1,58 @@
+# This file was generated by the Julia OpenAPI Code Generator
+# Do not modify this file directly. Modify the OpenAPI specification instead.
+
+
+@doc raw"""job_container_model
+Container in which a job&#39;s command runs
+
+    JobContainerModel(;
+        image=nothing,
+        runtime=nothing,
+        bind_mounts=nothing,
+        gpus=nothing,
+        args=nothing,
+    )
+
+    - image::String : Container image, such as docker://python:3.12, a path to a .sif file, or python:3.12
+    - runtime::ContainerRuntime
+    - bind_mounts::Vector{String} : Host paths to mount in the container, as path or host_path:container_path[:options]
+    - gpus::Bool : Make the host&#39;s NVIDIA GPUs available in the container
+    - args::Vector{String} : Extra arguments passed to the runtime before the image
+"""
+Base.@kwdef mutable struct JobContainerModel <: OpenAPI.APIModel
+    image::Union{Nothing, String} = nothing
+    runtime::Union{Nothing, ContainerRuntime} = nothing
+    bind_mounts::Union{Nothing, Vector{String}} = nothing
+    gpus::Union{Nothing, Bool} = nothing
+    args::Union{Nothing, Vector{String}} = nothing
+
+    function JobContainerModel(image, runtime, bind_mounts, gpus, args, )
+        o = new(image, runtime, bind_mounts, gpus, args, )
+        OpenAPI.validate_properties(o)
+        return o
+    end
+end # type JobContainerModel
+
+const _property_types_JobContainerModel = Dict{Symbol,String}(Symbol("image")=>"String", Symbol("runtime")=>"ContainerRuntime", Symbol("bind_mounts")=>"Vector{String}", Symbol("gpus")=>"Bool", Symbol("args")=>"Vector{String}", )
+OpenAPI.property_type(::Type{ JobContainerModel }, name::Symbol) = Union{Nothing,eval(Base.Meta.parse(_property_types_JobContainerModel[name]))}
+
+function OpenAPI.check_required(o::JobContainerModel)
+    o.image === nothing && (return false)
+    true
+end
+
+function OpenAPI.validate_properties(o::JobContainerModel)
+    OpenAPI.validate_property(JobContainerModel, Symbol("image"), o.image)
+    OpenAPI.validate_property(JobContainerModel, Symbol("runtime"), o.runtime)
+    OpenAPI.validate_property(JobContainerModel, Symbol("bind_mounts"), o.bind_mounts)
+    OpenAPI.validate_property(JobContainerModel, Symbol("gpus"), o.gpus)
+    OpenAPI.validate_property(JobContainerModel, Symbol("args"), o.args)
+end
+
+function OpenAPI.validate_property(::Type{ JobContainerModel }, name::Symbol, val)
+
+
+
+
+
+end
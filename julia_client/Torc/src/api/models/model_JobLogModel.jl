@@ -0,0 +1,87 @@
+# This file was generated by the Julia OpenAPI Code Generator
+# Do not modify this file directly. Modify the OpenAPI specification instead.
+
+
+@doc raw"""job_log_model
+Log written by a job to stdout or stderr, uploaded by the job runner.
+
+    JobLogModel(;
+        id=nothing,
+        workflow_id=nothing,
+        job_id=nothing,
+        run_id=nothing,
+        attempt_id=nothing,
+        stream=nothing,
+        content=nothing,
+        size_bytes=nothing,
+        is_truncated=nothing,
+        timestamp=nothing,
+    )
+
+    - id::Int64 : Database ID of this record.
+    - workflow_id::Int64 : Database ID of the workflow this record is associated with.
+    - job_id::Int64 : Database ID of the job that wrote the log
+    - run_id::Int64 : Workflow run in which the job wrote the log
+    - attempt_id::Int64 : Attempt of the job that wrote the log
+    - stream::JobLogStream
+    - content::String : Text of the log, or its end if the log was truncated
+    - size_bytes::Int64 : Size in bytes of the whole log file on the worker. Defaults to the size of content.
+    - is_truncated::Bool : True if content holds only the end of the log
+    - timestamp::String : Time at which the log was uploaded (RFC3339). Set by the server.
+"""
+Base.@kwdef mutable struct JobLogModel <: OpenAPI.APIModel
+    id::Union{Nothing, Int64} = nothing
+    workflow_id::Union{Nothing, Int64} = nothing
+    job_id::Union{Nothing, Int64} = nothing
+    run_id::Union{Nothing, Int64} = nothing
+    attempt_id::Union{Nothing, Int64} = nothing
+    stream = nothing # spec type: Union{ Nothing, JobLogStream }
+    content::Union{Nothing, String} = nothing
+    size_bytes::Union{Nothing, Int64} = nothing
+    is_truncated::Union{Nothing, Bool} = nothing
+    timestamp::Union{Nothing, String} = nothing
+
+    function JobLogModel(id, workflow_id, job_id, run_id, attempt_id, stream, content, size_bytes, is_truncated, timestamp, )
+        o = new(id, workflow_id, job_id, run_id, attempt_id, stream, content, size_bytes, is_truncated, timestamp, )
+        OpenAPI.validate_properties(o)
+        return o
+    end
+end # type JobLogModel
+
+const _property_types_JobLogModel = Dict{Symbol,String}(Symbol("id")=>"Int64", Symbol("workflow_id")=>"Int64", Symbol("job_id")=>"Int64", Symbol("run_id")=>"Int64", Symbol("attempt_id")=>"Int64", Symbol("stream")=>"JobLogStream", Symbol("content")=>"String", Symbol("size_bytes")=>"Int64", Symbol("is_truncated")=>"Bool", Symbol("timestamp")=>"String", )
+OpenAPI.property_type(::Type{ JobLogModel }, name::Symbol) = Union{Nothing,eval(Base.Meta.parse(_property_types_JobLogModel[name]))}
+
+function OpenAPI.check_required(o::JobLogModel)
+    o.workflow_id === nothing && (return false)
+    o.job_id === nothing && (return false)
+    o.run_id === nothing && (return false)
+    o.stream === nothing && (return false)
+    o.content === nothing && (return false)
+    true
+end
+
+function OpenAPI.validate_properties(o::JobLogModel)
+    OpenAPI.validate_property(JobLogModel, Symbol("id"), o.id)
+    OpenAPI.validate_property(JobLogModel, Symbol("workflow_id"), o.workflow_id)
+    OpenAPI.validate_property(JobLogModel, Symbol("job_id"), o.job_id)
+    OpenAPI.validate_property(JobLogModel, Symbol("run_id"), o.run_id)
+    OpenAPI.validate_property(JobLogModel, Symbol("attempt_id"), o.attempt_id)
+    OpenAPI.validate_property(JobLogModel, Symbol("stream"), o.stream)
+    OpenAPI.validate_property(JobLogModel, Symbol("content"), o.content)
+    OpenAPI.validate_property(JobLogModel, Symbol("size_bytes"), o.size_bytes)
+    OpenAPI.validate_property(JobLogModel, Symbol("is_truncated"), o.is_truncated)
+    OpenAPI.validate_property(JobLogModel, Symbol("timestamp"), o.timestamp)
+end
+
+function OpenAPI.validate_property(::Type{ JobLogModel }, name::Symbol, val)
+
+
+
+
+
+
+
+
+
+
+end
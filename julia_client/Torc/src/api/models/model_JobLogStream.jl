@@ -0,0 +1,9 @@
+# This file was generated by the Julia OpenAPI Code Generator
+# Do not modify this file directly. Modify the OpenAPI specification instead.
+
+
+if !isdefined(@__MODULE__, :JobLogStream)
+    const JobLogStream = String
+else
+    @warn("Skipping redefinition of JobLogStream to String")
+end
@@ -22,6 +22,15 @@
         scheduler_id=nothing,
         failure_handler_id=nothing,
         attempt_id=1,
+        priority=0,
+        env=nothing,
+        working_dir=nothing,
+        shell=nothing,
+        run_if=nothing,
+        concurrency_pool=nothing,
+        container=nothing,
+        not_before=nothing,
+        excluded_hostname=nothing,
     )
 
     - id::Int64
@@ -41,6 +50,15 @@
     - scheduler_id::Int64 : Optional database ID of scheduler needed by this job
     - failure_handler_id::Int64 : Optional database ID of failure handler for this job
     - attempt_id::Int64 : Current retry attempt number (starts at 1)
+    - priority::Int64 : Claim priority. Jobs with higher values are claimed first when the priority sort method is used.
+    - env::Dict{String, String} : Environment variables to set for the job's process
+    - working_dir::String : Directory in which to run the job's command. Defaults to the job runner's working directory.
+    - shell::String : Program used to run the job's command, such as bash, sh, or zsh. Defaults to bash (cmd on Windows).
+    - run_if::String : Condition that must hold for the job to run, such as exit_code(\"prep\") == 0. If it is false, the job is skipped.
+    - concurrency_pool::String : Name of the workflow's concurrency pool that limits how many jobs like this one can be pending or running at the same time.
+    - container::JobContainerModel
+    - not_before::String : Time (RFC 3339) before which a retried job will not be claimed. Set by the server.
+    - excluded_hostname::String : Host where the previous attempt failed. Workers on this host do not claim the job. Set by the server.
 """
 Base.@kwdef mutable struct JobModel <: OpenAPI.APIModel
     id::Union{Nothing, Int64} = nothing
@@ -60,15 +78,24 @@ Base.@kwdef mutable struct JobModel <: OpenAPI.APIModel
     scheduler_id::Union{Nothing, Int64} = nothing
     failure_handler_id::Union{Nothing, Int64} = nothing
     attempt_id::Union{Nothing, Int64} = 1
-
-    function JobModel(id, workflow_id, name, command, invocation_script, status, cancel_on_blocking_job_failure, supports_termination, depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids, resource_requirements_id, scheduler_id, failure_handler_id, attempt_id, )
-        o = new(id, workflow_id, name, command, invocation_script, status, cancel_on_blocking_job_failure, supports_termination, depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids, resource_requirements_id, scheduler_id, failure_handler_id, attempt_id, )
+    priority::Union{Nothing, Int64} = 0
+    env::Union{Nothing, Dict{String, String}} = nothing
+    working_dir::Union{Nothing, String} = nothing
+    shell::Union{Nothing, String} = nothing
+    run_if::Union{Nothing, String} = nothing
+    concurrency_pool::Union{Nothing, String} = nothing
+    container::Union{Nothing, JobContainerModel} = nothing
+    not_before::Union{Nothing, String} = nothing
+    excluded_hostname::Union{Nothing, String} = nothing
+
+    function JobModel(id, workflow_id, name, command, invocation_script, status, cancel_on_blocking_job_failure, supports_termination, depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids, resource_requirements_id, scheduler_id, failure_handler_id, attempt_id, priority, env, working_dir, shell, run_if, concurrency_pool, container, not_before, excluded_hostname, )
+        o = new(id, workflow_id, name, command, invocation_script, status, cancel_on_blocking_job_failure, supports_termination, depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids, resource_requirements_id, scheduler_id, failure_handler_id, attempt_id, priority, env, working_dir, shell, run_if, concurrency_pool, container, not_before, excluded_hostname, )
         OpenAPI.validate_properties(o)
         return o
     end
 end # type JobModel
 
-const _property_types_JobModel = Dict{Symbol,String}(Symbol("id")=>"Int64", Symbol("workflow_id")=>"Int64", Symbol("name")=>"String", Symbol("command")=>"String", Symbol("invocation_script")=>"String", Symbol("status")=>"Any", Symbol("cancel_on_blocking_job_failure")=>"Bool", Symbol("supports_termination")=>"Bool", Symbol("depends_on_job_ids")=>"Vector{Int64}", Symbol("input_file_ids")=>"Vector{Int64}", Symbol("output_file_ids")=>"Vector{Int64}", Symbol("input_user_data_ids")=>"Vector{Int64}", Symbol("output_user_data_ids")=>"Vector{Int64}", Symbol("resource_requirements_id")=>"Int64", Symbol("scheduler_id")=>"Int64", Symbol("failure_handler_id")=>"Int64", Symbol("attempt_id")=>"Int64", )
+const _property_types_JobModel = Dict{Symbol,String}(Symbol("id")=>"Int64", Symbol("workflow_id")=>"Int64", Symbol("name")=>"String", Symbol("command")=>"String", Symbol("invocation_script")=>"String", Symbol("status")=>"Any", Symbol("cancel_on_blocking_job_failure")=>"Bool", Symbol("supports_termination")=>"Bool", Symbol("depends_on_job_ids")=>"Vector{Int64}", Symbol("input_file_ids")=>"Vector{Int64}", Symbol("output_file_ids")=>"Vector{Int64}", Symbol("input_user_data_ids")=>"Vector{Int64}", Symbol("output_user_data_ids")=>"Vector{Int64}", Symbol("resource_requirements_id")=>"Int64", Symbol("scheduler_id")=>"Int64", Symbol("failure_handler_id")=>"Int64", Symbol("attempt_id")=>"Int64", Symbol("priority")=>"Int64", Symbol("env")=>"Dict{String, String}", Symbol("working_dir")=>"String", Symbol("shell")=>"String", Symbol("run_if")=>"String", Symbol("concurrency_pool")=>"String", Symbol("container")=>"JobContainerModel", Symbol("not_before")=>"String", Symbol("excluded_hostname")=>"String", )
 OpenAPI.property_type(::Type{ JobModel }, name::Symbol) = Union{Nothing,eval(Base.Meta.parse(_property_types_JobModel[name]))}
 
 function OpenAPI.check_required(o::JobModel)
@@ -96,6 +123,15 @@ function OpenAPI.validate_properties(o::JobModel)
     OpenAPI.validate_property(JobModel, Symbol("scheduler_id"), o.scheduler_id)
     OpenAPI.validate_property(JobModel, Symbol("failure_handler_id"), o.failure_handler_id)
     OpenAPI.validate_property(JobModel, Symbol("attempt_id"), o.attempt_id)
+    OpenAPI.validate_property(JobModel, Symbol("priority"), o.priority)
+    OpenAPI.validate_property(JobModel, Symbol("env"), o.env)
+    OpenAPI.validate_property(JobModel, Symbol("working_dir"), o.working_dir)
+    OpenAPI.validate_property(JobModel, Symbol("shell"), o.shell)
+    OpenAPI.validate_property(JobModel, Symbol("run_if"), o.run_if)
+    OpenAPI.validate_property(JobModel, Symbol("concurrency_pool"), o.concurrency_pool)
+    OpenAPI.validate_property(JobModel, Symbol("container"), o.container)
+    OpenAPI.validate_property(JobModel, Symbol("not_before"), o.not_before)
+    OpenAPI.validate_property(JobModel, Symbol("excluded_hostname"), o.excluded_hostname)
 end
 
 function OpenAPI.validate_property(::Type{ JobModel }, name::Symbol, val)
@@ -116,4 +152,6 @@ function OpenAPI.validate_property(::Type{ JobModel }, name::Symbol, val)
 
 
 
+
+
 end
@@ -0,0 +1,57 @@
+# This file was generated by the Julia OpenAPI Code Generator
+# Do not modify this file directly. Modify the OpenAPI specification instead.
+
+
+@doc raw"""list_api_tokens_response
+Response for listing API tokens
+
+    ListApiTokensResponse(;
+        items=nothing,
+        offset=nothing,
+        limit=nothing,
+        total_count=nothing,
+        has_more=nothing,
+    )
+
+    - items::Vector{ApiTokenModel}
+    - offset::Int64
+    - limit::Int64
+    - total_count::Int64
+    - has_more::Bool
+"""
+Base.@kwdef mutable struct ListApiTokensResponse <: OpenAPI.APIModel
+    items::Union{Nothing, Vector} = nothing # spec type: Union{ Nothing, Vector{ApiTokenModel} }
+    offset::Union{Nothing, Int64} = nothing
+    limit::Union{Nothing, Int64} = nothing
+    total_count::Union{Nothing, Int64} = nothing
+    has_more::Union{Nothing, Bool} = nothing
+
+    function ListApiTokensResponse(items, offset, limit, total_count, has_more, )
+        o = new(items, offset, limit, total_count, has_more, )
+        OpenAPI.validate_properties(o)
+        return o
+    end
+end # type ListApiTokensResponse
+
+const _property_types_ListApiTokensResponse = Dict{Symbol,String}(Symbol("items")=>"Vector{ApiTokenModel}", Symbol("offset")=>"Int64", Symbol("limit")=>"Int64", Symbol("total_count")=>"Int64", Symbol("has_more")=>"Bool", )
+OpenAPI.property_type(::Type{ ListApiTokensResponse }, name::Symbol) = Union{Nothing,eval(Base.Meta.parse(_property_types_ListApiTokensResponse[name]))}
+
+function OpenAPI.check_required(o::ListApiTokensResponse)
+    true
+end
+
+function OpenAPI.validate_properties(o::ListApiTokensResponse)
+    OpenAPI.validate_property(ListApiTokensResponse, Symbol("items"), o.items)
+    OpenAPI.validate_property(ListApiTokensResponse, Symbol("offset"), o.offset)
+    OpenAPI.validate_property(ListApiTokensResponse, Symbol("limit"), o.limit)
+    OpenAPI.validate_property(ListApiTokensResponse, Symbol("total_count"), o.total_count)
+    OpenAPI.validate_property(ListApiTokensResponse, Symbol("has_more"), o.has_more)
+end
+
+function OpenAPI.validate_property(::Type{ ListApiTokensResponse }, name::Symbol, val)
+
+
+
+
+
+end
@@ -0,0 +1,9 @@
+# This file was generated by the Julia OpenAPI Code Generator
+# Do not modify this file directly. Modify the OpenAPI specification instead.
+
+
+if !isdefined(@__MODULE__, :ScheduleOverlapPolicy)
+    const ScheduleOverlapPolicy = String
+else
+    @warn("Skipping redefinition of ScheduleOverlapPolicy to String")
+end
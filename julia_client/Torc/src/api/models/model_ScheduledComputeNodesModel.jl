@@ -11,6 +11,7 @@
         scheduler_config_id=nothing,
         scheduler_type=nothing,
         status=nothing,
+        array_task_id=nothing,
     )
 
     - id::Int64
@@ -19,6 +20,7 @@
     - scheduler_config_id::Int64
     - scheduler_type::String
     - status::String
+    - array_task_id::Int64 : Index of the allocation within a Slurm job array. scheduler_id holds the array's job ID.
 """
 Base.@kwdef mutable struct ScheduledComputeNodesModel <: OpenAPI.APIModel
     id::Union{Nothing, Int64} = nothing
@@ -27,15 +29,16 @@ Base.@kwdef mutable struct ScheduledComputeNodesModel <: OpenAPI.APIModel
     scheduler_config_id::Union{Nothing, Int64} = nothing
     scheduler_type::Union{Nothing, String} = nothing
     status::Union{Nothing, String} = nothing
+    array_task_id::Union{Nothing, Int64} = nothing
 
-    function ScheduledComputeNodesModel(id, workflow_id, scheduler_id, scheduler_config_id, scheduler_type, status, )
-        o = new(id, workflow_id, scheduler_id, scheduler_config_id, scheduler_type, status, )
+    function ScheduledComputeNodesModel(id, workflow_id, scheduler_id, scheduler_config_id, scheduler_type, status, array_task_id, )
+        o = new(id, workflow_id, scheduler_id, scheduler_config_id, scheduler_type, status, array_task_id, )
         OpenAPI.validate_properties(o)
         return o
     end
 end # type ScheduledComputeNodesModel
 
-const _property_types_ScheduledComputeNodesModel = Dict{Symbol,String}(Symbol("id")=>"Int64", Symbol("workflow_id")=>"Int64", Symbol("scheduler_id")=>"Int64", Symbol("scheduler_config_id")=>"Int64", Symbol("scheduler_type")=>"String", Symbol("status")=>"String", )
+const _property_types_ScheduledComputeNodesModel = Dict{Symbol,String}(Symbol("id")=>"Int64", Symbol("workflow_id")=>"Int64", Symbol("scheduler_id")=>"Int64", Symbol("scheduler_config_id")=>"Int64", Symbol("scheduler_type")=>"String", Symbol("status")=>"String", Symbol("array_task_id")=>"Int64", )
 OpenAPI.property_type(::Type{ ScheduledComputeNodesModel }, name::Symbol) = Union{Nothing,eval(Base.Meta.parse(_property_types_ScheduledComputeNodesModel[name]))}
 
 function OpenAPI.check_required(o::ScheduledComputeNodesModel)
@@ -54,6 +57,7 @@ function OpenAPI.validate_properties(o::ScheduledComputeNodesModel)
     OpenAPI.validate_property(ScheduledComputeNodesModel, Symbol("scheduler_config_id"), o.scheduler_config_id)
     OpenAPI.validate_property(ScheduledComputeNodesModel, Symbol("scheduler_type"), o.scheduler_type)
     OpenAPI.validate_property(ScheduledComputeNodesModel, Symbol("status"), o.status)
+    OpenAPI.validate_property(ScheduledComputeNodesModel, Symbol("array_task_id"), o.array_task_id)
 end
 
 function OpenAPI.validate_property(::Type{ ScheduledComputeNodesModel }, name::Symbol, val)
@@ -63,4 +67,5 @@ function OpenAPI.validate_property(::Type{ ScheduledComputeNodesModel }, name::S
 
 
 
+
 end
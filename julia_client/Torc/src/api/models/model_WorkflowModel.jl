@@ -19,6 +19,13 @@
         resource_monitor_config=nothing,
         slurm_defaults=nothing,
         use_pending_failed=false,
+        enforce_runtime_limits=false,
+        runtime_limit_grace_seconds=60,
+        worker_heartbeat_timeout_seconds=300,
+        dead_worker_policy=nothing,
+        max_concurrent_jobs=nothing,
+        concurrency_pools=nothing,
+        au_budget=nothing,
         status_id=nothing,
     )
 
@@ -36,6 +43,13 @@
     - resource_monitor_config::String : Resource monitoring configuration as JSON string
     - slurm_defaults::String : Default Slurm parameters to apply to all schedulers as JSON string
     - use_pending_failed::Bool : Use PendingFailed status for failed jobs (enables AI-assisted recovery)
+    - enforce_runtime_limits::Bool : Terminate jobs that run longer than the runtime in their resource requirements. Jobs that support termination receive SIGTERM at the limit; all jobs receive SIGKILL after the grace period. Terminated jobs are recorded as failed with return code 124.
+    - runtime_limit_grace_seconds::Int64 : Number of seconds to wait after sending SIGTERM to a job that exceeded its runtime before sending SIGKILL.
+    - worker_heartbeat_timeout_seconds::Int64 : Number of seconds without a heartbeat after which the server declares a job runner dead. 0 disables dead worker detection.
+    - dead_worker_policy::DeadWorkerPolicy
+    - max_concurrent_jobs::Int64 : Maximum number of jobs in the workflow that can be pending or running at the same time, across all compute nodes. Unlimited if not set.
+    - concurrency_pools::Dict{String, Int64} : Named concurrency pools. Maps each pool name to the maximum number of jobs in the pool that can be pending or running at the same time, across all compute nodes.
+    - au_budget::Float64 : Maximum allocation units (AU) that HPC allocations of the workflow may cost. Clients do not submit allocations whose estimated cost would exceed it. Unlimited if not set.
     - status_id::Int64
 """
 Base.@kwdef mutable struct WorkflowModel <: OpenAPI.APIModel
@@ -53,16 +67,23 @@ Base.@kwdef mutable struct WorkflowModel <: OpenAPI.APIModel
     resource_monitor_config::Union{Nothing, String} = nothing
     slurm_defaults::Union{Nothing, String} = nothing
     use_pending_failed::Union{Nothing, Bool} = false
+    enforce_runtime_limits::Union{Nothing, Bool} = false
+    runtime_limit_grace_seconds::Union{Nothing, Int64} = 60
+    worker_heartbeat_timeout_seconds::Union{Nothing, Int64} = 300
+    dead_worker_policy = nothing # spec type: Union{ Nothing, DeadWorkerPolicy }
+    max_concurrent_jobs::Union{Nothing, Int64} = nothing
+    concurrency_pools::Union{Nothing, Dict{String, Int64}} = nothing
+    au_budget::Union{Nothing, Float64} = nothing
     status_id::Union{Nothing, Int64} = nothing
 
-    function WorkflowModel(id, name, user, description, timestamp, compute_node_expiration_buffer_seconds, compute_node_wait_for_new_jobs_seconds, compute_node_ignore_workflow_completion, compute_node_wait_for_healthy_database_minutes, compute_node_min_time_for_new_jobs_seconds, jobs_sort_method, resource_monitor_config, slurm_defaults, use_pending_failed, status_id, )
-        o = new(id, name, user, description, timestamp, compute_node_expiration_buffer_seconds, compute_node_wait_for_new_jobs_seconds, compute_node_ignore_workflow_completion, compute_node_wait_for_healthy_database_minutes, compute_node_min_time_for_new_jobs_seconds, jobs_sort_method, resource_monitor_config, slurm_defaults, use_pending_failed, status_id, )
+    function WorkflowModel(id, name, user, description, timestamp, compute_node_expiration_buffer_seconds, compute_node_wait_for_new_jobs_seconds, compute_node_ignore_workflow_completion, compute_node_wait_for_healthy_database_minutes, compute_node_min_time_for_new_jobs_seconds, jobs_sort_method, resource_monitor_config, slurm_defaults, use_pending_failed, enforce_runtime_limits, runtime_limit_grace_seconds, worker_heartbeat_timeout_seconds, dead_worker_policy, max_concurrent_jobs, concurrency_pools, au_budget, status_id, )
+        o = new(id, name, user, description, timestamp, compute_node_expiration_buffer_seconds, compute_node_wait_for_new_jobs_seconds, compute_node_ignore_workflow_completion, compute_node_wait_for_healthy_database_minutes, compute_node_min_time_for_new_jobs_seconds, jobs_sort_method, resource_monitor_config, slurm_defaults, use_pending_failed, enforce_runtime_limits, runtime_limit_grace_seconds, worker_heartbeat_timeout_seconds, dead_worker_policy, max_concurrent_jobs, concurrency_pools, au_budget, status_id, )
         OpenAPI.validate_properties(o)
         return o
     end
 end # type WorkflowModel
 
-const _property_types_WorkflowModel = Dict{Symbol,String}(Symbol("id")=>"Int64", Symbol("name")=>"String", Symbol("user")=>"String", Symbol("description")=>"String", Symbol("timestamp")=>"String", Symbol("compute_node_expiration_buffer_seconds")=>"Int64", Symbol("compute_node_wait_for_new_jobs_seconds")=>"Int64", Symbol("compute_node_ignore_workflow_completion")=>"Bool", Symbol("compute_node_wait_for_healthy_database_minutes")=>"Int64", Symbol("compute_node_min_time_for_new_jobs_seconds")=>"Int64", Symbol("jobs_sort_method")=>"JobsSortMethod", Symbol("resource_monitor_config")=>"String", Symbol("slurm_defaults")=>"String", Symbol("use_pending_failed")=>"Bool", Symbol("status_id")=>"Int64", )
+const _property_types_WorkflowModel = Dict{Symbol,String}(Symbol("id")=>"Int64", Symbol("name")=>"String", Symbol("user")=>"String", Symbol("description")=>"String", Symbol("timestamp")=>"String", Symbol("compute_node_expiration_buffer_seconds")=>"Int64", Symbol("compute_node_wait_for_new_jobs_seconds")=>"Int64", Symbol("compute_node_ignore_workflow_completion")=>"Bool", Symbol("compute_node_wait_for_healthy_database_minutes")=>"Int64", Symbol("compute_node_min_time_for_new_jobs_seconds")=>"Int64", Symbol("jobs_sort_method")=>"JobsSortMethod", Symbol("resource_monitor_config")=>"String", Symbol("slurm_defaults")=>"String", Symbol("use_pending_failed")=>"Bool", Symbol("enforce_runtime_limits")=>"Bool", Symbol("runtime_limit_grace_seconds")=>"Int64", Symbol("worker_heartbeat_timeout_seconds")=>"Int64", Symbol("dead_worker_policy")=>"DeadWorkerPolicy", Symbol("max_concurrent_jobs")=>"Int64", Symbol("concurrency_pools")=>"Dict{String, Int64}", Symbol("au_budget")=>"Float64", Symbol("status_id")=>"Int64", )
 OpenAPI.property_type(::Type{ WorkflowModel }, name::Symbol) = Union{Nothing,eval(Base.Meta.parse(_property_types_WorkflowModel[name]))}
 
 function OpenAPI.check_required(o::WorkflowModel)
@@ -86,6 +107,13 @@ function OpenAPI.validate_properties(o::WorkflowModel)
     OpenAPI.validate_property(WorkflowModel, Symbol("resource_monitor_config"), o.resource_monitor_config)
     OpenAPI.validate_property(WorkflowModel, Symbol("slurm_defaults"), o.slurm_defaults)
     OpenAPI.validate_property(WorkflowModel, Symbol("use_pending_failed"), o.use_pending_failed)
+    OpenAPI.validate_property(WorkflowModel, Symbol("enforce_runtime_limits"), o.enforce_runtime_limits)
+    OpenAPI.validate_property(WorkflowModel, Symbol("runtime_limit_grace_seconds"), o.runtime_limit_grace_seconds)
+    OpenAPI.validate_property(WorkflowModel, Symbol("worker_heartbeat_timeout_seconds"), o.worker_heartbeat_timeout_seconds)
+    OpenAPI.validate_property(WorkflowModel, Symbol("dead_worker_policy"), o.dead_worker_policy)
+    OpenAPI.validate_property(WorkflowModel, Symbol("max_concurrent_jobs"), o.max_concurrent_jobs)
+    OpenAPI.validate_property(WorkflowModel, Symbol("concurrency_pools"), o.concurrency_pools)
+    OpenAPI.validate_property(WorkflowModel, Symbol("au_budget"), o.au_budget)
     OpenAPI.validate_property(WorkflowModel, Symbol("status_id"), o.status_id)
 end
 
@@ -105,4 +133,7 @@ function OpenAPI.validate_property(::Type{ WorkflowModel }, name::Symbol, val)
 
 
 
+
+
+
 end
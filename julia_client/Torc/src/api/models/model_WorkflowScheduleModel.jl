@@ -0,0 +1,79 @@
+# This file was generated by the Julia OpenAPI Code Generator
+# Do not modify this file directly. Modify the OpenAPI specification instead.
+
+
+@doc raw"""workflow_schedule_model
+Schedule on which the server reinitializes (bumping the run_id) and submits a workflow.
+
+    WorkflowScheduleModel(;
+        id=nothing,
+        workflow_id=nothing,
+        cron_expression=nothing,
+        timezone=nothing,
+        overlap_policy=nothing,
+        working_directory=nothing,
+        is_paused=nothing,
+        next_run_time=nothing,
+        last_run_time=nothing,
+    )
+
+    - id::Int64 : Database ID of this schedule.
+    - workflow_id::Int64 : Database ID of the workflow this schedule is associated with.
+    - cron_expression::String : Cron expression (minute hour day-of-month month day-of-week) with an optional leading seconds field.
+    - timezone::String : IANA time zone in which the cron expression is evaluated.
+    - overlap_policy::ScheduleOverlapPolicy
+    - working_directory::String : Absolute path of an existing directory on the server host in which the server runs the reinitialize and submit commands.
+    - is_paused::Bool : Paused schedules do not fire.
+    - next_run_time::String : Time of the next firing (RFC3339). Set by the server.
+    - last_run_time::String : Time of the most recent firing (RFC3339). Set by the server.
+"""
+Base.@kwdef mutable struct WorkflowScheduleModel <: OpenAPI.APIModel
+    id::Union{Nothing, Int64} = nothing
+    workflow_id::Union{Nothing, Int64} = nothing
+    cron_expression::Union{Nothing, String} = nothing
+    timezone::Union{Nothing, String} = nothing
+    overlap_policy = nothing # spec type: Union{ Nothing, ScheduleOverlapPolicy }
+    working_directory::Union{Nothing, String} = nothing
+    is_paused::Union{Nothing, Bool} = nothing
+    next_run_time::Union{Nothing, String} = nothing
+    last_run_time::Union{Nothing, String} = nothing
+
+    function WorkflowScheduleModel(id, workflow_id, cron_expression, timezone, overlap_policy, working_directory, is_paused, next_run_time, last_run_time, )
+        o = new(id, workflow_id, cron_expression, timezone, overlap_policy, working_directory, is_paused, next_run_time, last_run_time, )
+        OpenAPI.validate_properties(o)
+        return o
+    end
+end # type WorkflowScheduleModel
+
+const _property_types_WorkflowScheduleModel = Dict{Symbol,String}(Symbol("id")=>"Int64", Symbol("workflow_id")=>"Int64", Symbol("cron_expression")=>"String", Symbol("timezone")=>"String", Symbol("overlap_policy")=>"ScheduleOverlapPolicy", Symbol("working_directory")=>"String", Symbol("is_paused")=>"Bool", Symbol("next_run_time")=>"String", Symbol("last_run_time")=>"String", )
+OpenAPI.property_type(::Type{ WorkflowScheduleModel }, name::Symbol) = Union{Nothing,eval(Base.Meta.parse(_property_types_WorkflowScheduleModel[name]))}
+
+function OpenAPI.check_required(o::WorkflowScheduleModel)
+    o.workflow_id === nothing && (return false)
+    o.cron_expression === nothing && (return false)
+    true
+end
+
+function OpenAPI.validate_properties(o::WorkflowScheduleModel)
+    OpenAPI.validate_property(WorkflowScheduleModel, Symbol("id"), o.id)
+    OpenAPI.validate_property(WorkflowScheduleModel, Symbol("workflow_id"), o.workflow_id)
+    OpenAPI.validate_property(WorkflowScheduleModel, Symbol("cron_expression"), o.cron_expression)
+    OpenAPI.validate_property(WorkflowScheduleModel, Symbol("timezone"), o.timezone)
+    OpenAPI.validate_property(WorkflowScheduleModel, Symbol("overlap_policy"), o.overlap_policy)
+    OpenAPI.validate_property(WorkflowScheduleModel, Symbol("working_directory"), o.working_directory)
+    OpenAPI.validate_property(WorkflowScheduleModel, Symbol("is_paused"), o.is_paused)
+    OpenAPI.validate_property(WorkflowScheduleModel, Symbol("next_run_time"), o.next_run_time)
+    OpenAPI.validate_property(WorkflowScheduleModel, Symbol("last_run_time"), o.last_run_time)
+end
+
+function OpenAPI.validate_property(::Type{ WorkflowScheduleModel }, name::Symbol, val)
+
+
+
+
+
+
+
+
+
+end
@@ -0,0 +1,3 @@
+-- Remove runtime limit enforcement columns from workflow table
+ALTER TABLE workflow DROP COLUMN runtime_limit_grace_seconds;
+ALTER TABLE workflow DROP COLUMN enforce_runtime_limits;
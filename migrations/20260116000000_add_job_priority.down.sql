@@ -0,0 +1,2 @@
+-- Remove priority column from job table
+ALTER TABLE job DROP COLUMN priority;
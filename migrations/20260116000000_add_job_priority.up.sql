@@ -0,0 +1,3 @@
+-- Add priority column to job table
+-- Jobs with higher priority are claimed first when the workflow uses the priority sort method
+ALTER TABLE job ADD COLUMN priority INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,3 @@
+-- Remove content hash columns from file table
+ALTER TABLE file DROP COLUMN content_hash;
+ALTER TABLE file DROP COLUMN use_checksum;
@@ -0,0 +1,4 @@
+-- Add content hash columns to file table
+-- When use_checksum is enabled, file changes are detected by content hash instead of st_mtime
+ALTER TABLE file ADD COLUMN use_checksum INTEGER NULL DEFAULT 0;
+ALTER TABLE file ADD COLUMN content_hash TEXT NULL;
@@ -0,0 +1,3 @@
+-- Add a condition that decides whether a job runs
+-- run_if is an expression evaluated by the job runner; when it is false the job is skipped
+ALTER TABLE job ADD COLUMN run_if TEXT NULL;
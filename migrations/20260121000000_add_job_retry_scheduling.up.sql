@@ -0,0 +1,5 @@
+-- Add retry scheduling state to jobs
+-- not_before is a Unix timestamp in milliseconds; ready jobs are not claimed before it
+-- excluded_hostname is the host where the previous attempt failed; it does not claim the job
+ALTER TABLE job ADD COLUMN not_before INTEGER NULL;
+ALTER TABLE job ADD COLUMN excluded_hostname TEXT NULL;
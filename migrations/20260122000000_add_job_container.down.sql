@@ -0,0 +1,2 @@
+-- Remove per-job container settings
+ALTER TABLE job DROP COLUMN container;
@@ -0,0 +1,4 @@
+-- Add per-job container settings
+-- container is a JSON object with the image, runtime, bind mounts, GPU flag, and extra
+-- runtime arguments used to run the job's command in a container
+ALTER TABLE job ADD COLUMN container TEXT NULL;
@@ -0,0 +1,5 @@
+-- Remove server-side concurrency limits
+DROP INDEX IF EXISTS idx_job_workflow_concurrency_pool;
+ALTER TABLE job DROP COLUMN concurrency_pool;
+ALTER TABLE workflow DROP COLUMN concurrency_pools;
+ALTER TABLE workflow DROP COLUMN max_concurrent_jobs;
@@ -0,0 +1,8 @@
+-- Add server-side concurrency limits
+-- max_concurrent_jobs caps the number of pending and running jobs in the workflow
+-- concurrency_pools is a JSON object that maps pool names to their maximum number of pending
+-- and running jobs; job.concurrency_pool names the pool that a job belongs to
+ALTER TABLE workflow ADD COLUMN max_concurrent_jobs INTEGER NULL;
+ALTER TABLE workflow ADD COLUMN concurrency_pools TEXT NULL;
+ALTER TABLE job ADD COLUMN concurrency_pool TEXT NULL;
+CREATE INDEX idx_job_workflow_concurrency_pool ON job(workflow_id, concurrency_pool);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_workflow_schedule_next_run_time;
+DROP INDEX IF EXISTS idx_workflow_schedule_workflow_id;
+DROP TABLE IF EXISTS workflow_schedule;
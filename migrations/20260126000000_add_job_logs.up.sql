@@ -0,0 +1,23 @@
+-- Store job stdout and stderr uploaded by job runners so that users without access to the
+-- worker's filesystem can read them
+-- stream is 'stdout' or 'stderr'
+-- content holds the end of the file when the runner truncated it; size_bytes is the size of
+-- the whole file on the worker
+-- created_at is a Unix timestamp in milliseconds
+CREATE TABLE job_log (
+  id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+  workflow_id INTEGER NOT NULL,
+  job_id INTEGER NOT NULL,
+  run_id INTEGER NOT NULL,
+  attempt_id INTEGER NOT NULL DEFAULT 1,
+  stream TEXT NOT NULL,
+  content TEXT NOT NULL,
+  size_bytes INTEGER NOT NULL,
+  is_truncated INTEGER NOT NULL DEFAULT 0,
+  created_at INTEGER NOT NULL,
+  FOREIGN KEY (workflow_id) REFERENCES workflow(id) ON DELETE CASCADE,
+  FOREIGN KEY (job_id) REFERENCES job(id) ON DELETE CASCADE,
+  UNIQUE (job_id, run_id, attempt_id, stream)
+);
+
+CREATE INDEX idx_job_log_workflow_id ON job_log(workflow_id);
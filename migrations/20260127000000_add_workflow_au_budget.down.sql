@@ -0,0 +1,2 @@
+-- Remove the per-workflow allocation-unit (AU) budget
+ALTER TABLE workflow DROP COLUMN au_budget;
@@ -0,0 +1,3 @@
+-- Add a per-workflow allocation-unit (AU) budget
+-- Clients refuse to submit HPC allocations whose estimated cost would exceed the budget
+ALTER TABLE workflow ADD COLUMN au_budget REAL NULL;
@@ -0,0 +1,2 @@
+-- Remove the multi-node job reservation from compute nodes
+ALTER TABLE compute_node DROP COLUMN reserved_for_job_id;
@@ -0,0 +1,3 @@
+-- Track which multi-node job a compute node's job runner is reserved for
+-- The head node's job runner sets it; the server clears it when the job completes or is reset
+ALTER TABLE compute_node ADD COLUMN reserved_for_job_id INTEGER NULL;
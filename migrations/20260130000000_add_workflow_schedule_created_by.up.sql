@@ -0,0 +1,3 @@
+-- Record the authenticated user that created each workflow schedule
+-- The schedule task only fires schedules on behalf of that user
+ALTER TABLE workflow_schedule ADD COLUMN created_by TEXT NULL;
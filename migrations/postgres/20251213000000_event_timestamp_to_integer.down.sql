@@ -0,0 +1,5 @@
+-- Revert: Convert event.timestamp from BIGINT (milliseconds) back to TEXT (ISO 8601)
+DROP INDEX IF EXISTS idx_event_workflow_timestamp;
+
+ALTER TABLE event ALTER COLUMN timestamp TYPE TEXT
+  USING to_char(to_timestamp(timestamp / 1000.0) AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS.MS"Z"');
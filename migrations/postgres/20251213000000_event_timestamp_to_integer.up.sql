@@ -0,0 +1,6 @@
+-- Migration: Convert event.timestamp from TEXT (ISO 8601) to BIGINT (milliseconds since epoch)
+-- This improves query efficiency for timestamp filtering and ensures consistency with the API.
+ALTER TABLE event ALTER COLUMN timestamp TYPE BIGINT
+  USING (extract(epoch FROM timestamp::timestamptz) * 1000)::BIGINT;
+
+CREATE INDEX idx_event_workflow_timestamp ON event(workflow_id, timestamp);
@@ -0,0 +1,3 @@
+-- Remove active_compute_node_id from job_internal table
+DROP INDEX IF EXISTS idx_job_internal_active_compute_node_id;
+ALTER TABLE job_internal DROP COLUMN active_compute_node_id;
@@ -0,0 +1,2 @@
+-- Remove is_recovery column from workflow_action table
+ALTER TABLE workflow_action DROP COLUMN is_recovery;
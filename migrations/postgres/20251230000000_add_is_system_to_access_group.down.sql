@@ -0,0 +1,2 @@
+-- Remove is_system flag from access_group table
+ALTER TABLE access_group DROP COLUMN is_system;
@@ -0,0 +1,2 @@
+-- Remove slurm_defaults column from workflow table
+ALTER TABLE workflow DROP COLUMN slurm_defaults;
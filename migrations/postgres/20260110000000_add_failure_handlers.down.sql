@@ -0,0 +1,14 @@
+-- ============================================================================
+-- REMOVE FAILURE HANDLERS
+-- ============================================================================
+DROP INDEX IF EXISTS idx_result_workflow_id;
+DROP INDEX IF EXISTS idx_result_job_id;
+ALTER TABLE result DROP CONSTRAINT result_job_id_run_id_attempt_id_key;
+ALTER TABLE result DROP COLUMN attempt_id;
+ALTER TABLE result ADD CONSTRAINT result_job_id_run_id_key UNIQUE (job_id, run_id);
+
+ALTER TABLE job DROP COLUMN attempt_id;
+ALTER TABLE job DROP COLUMN failure_handler_id;
+
+DROP INDEX IF EXISTS idx_failure_handler_workflow_id;
+DROP TABLE IF EXISTS failure_handler;
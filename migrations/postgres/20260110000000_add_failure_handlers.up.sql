@@ -0,0 +1,43 @@
+-- ============================================================================
+-- ADD FAILURE HANDLERS
+-- ============================================================================
+-- This migration adds support for configurable job failure handlers that can
+-- automatically retry jobs based on specific exit codes, with optional
+-- recovery scripts.
+--
+-- Schema Version: 2026-01-10
+-- ============================================================================
+
+-- ----------------------------------------------------------------------------
+-- failure_handler: Configurable failure handling rules for jobs
+-- ----------------------------------------------------------------------------
+-- Rules are stored as JSON for flexibility:
+-- [{"exit_codes": [128, 129], "recovery_script": "./fix.sh", "max_retries": 3}]
+-- ----------------------------------------------------------------------------
+CREATE TABLE failure_handler (
+  id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+  workflow_id BIGINT NOT NULL,
+  name TEXT NOT NULL,
+  rules TEXT NOT NULL,
+  FOREIGN KEY (workflow_id) REFERENCES workflow(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_failure_handler_workflow_id ON failure_handler(workflow_id);
+
+-- Jobs can optionally reference a failure handler for automatic retry behavior
+ALTER TABLE job ADD COLUMN failure_handler_id BIGINT REFERENCES failure_handler(id) ON DELETE SET NULL;
+
+-- Tracks which attempt this job is on (starts at 1, increments on each retry)
+ALTER TABLE job ADD COLUMN attempt_id BIGINT NOT NULL DEFAULT 1;
+
+-- ----------------------------------------------------------------------------
+-- Add attempt_id to result table
+-- ----------------------------------------------------------------------------
+-- The unique constraint becomes UNIQUE(job_id, run_id, attempt_id) to allow
+-- multiple results for the same job across retry attempts.
+ALTER TABLE result ADD COLUMN attempt_id BIGINT NOT NULL DEFAULT 1;
+ALTER TABLE result DROP CONSTRAINT result_job_id_run_id_key;
+ALTER TABLE result ADD CONSTRAINT result_job_id_run_id_attempt_id_key UNIQUE (job_id, run_id, attempt_id);
+
+CREATE INDEX idx_result_job_id ON result(job_id);
+CREATE INDEX idx_result_workflow_id ON result(workflow_id);
@@ -0,0 +1,2 @@
+-- Remove use_pending_failed column from workflow table
+ALTER TABLE workflow DROP COLUMN use_pending_failed;
@@ -0,0 +1,4 @@
+-- Add runtime limit enforcement columns to workflow table
+-- When enabled, job runners terminate jobs that exceed their resource requirements runtime
+ALTER TABLE workflow ADD COLUMN enforce_runtime_limits BIGINT NULL DEFAULT 0;
+ALTER TABLE workflow ADD COLUMN runtime_limit_grace_seconds BIGINT NULL DEFAULT 60;
@@ -0,0 +1,3 @@
+-- Remove API token table
+DROP INDEX IF EXISTS idx_api_token_owner;
+DROP TABLE IF EXISTS api_token;
@@ -0,0 +1,14 @@
+-- Server-issued API tokens for bearer authentication.
+-- Only the SHA-256 hash of each token is stored; the plaintext token is returned once at creation.
+CREATE TABLE api_token (
+  id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+  name TEXT NOT NULL,
+  owner TEXT NOT NULL,
+  token_hash TEXT NOT NULL UNIQUE,
+  scopes TEXT NOT NULL DEFAULT 'read,write',
+  created_at TEXT NOT NULL DEFAULT (to_char(now() AT TIME ZONE 'UTC', 'YYYY-MM-DD HH24:MI:SS')),
+  expires_at TEXT NULL,
+  revoked BIGINT NOT NULL DEFAULT 0
+);
+
+CREATE INDEX idx_api_token_owner ON api_token(owner);
@@ -0,0 +1,4 @@
+-- Remove per-job process environment settings
+ALTER TABLE job DROP COLUMN shell;
+ALTER TABLE job DROP COLUMN working_dir;
+ALTER TABLE job DROP COLUMN env;
@@ -0,0 +1,7 @@
+-- Add per-job process environment settings
+-- env is a JSON object of environment variables set for the job's process
+-- working_dir is the directory the job's command runs in
+-- shell is the program used to run the job's command (default: bash, or cmd on Windows)
+ALTER TABLE job ADD COLUMN env TEXT NULL;
+ALTER TABLE job ADD COLUMN working_dir TEXT NULL;
+ALTER TABLE job ADD COLUMN shell TEXT NULL;
@@ -0,0 +1,2 @@
+-- Remove the job run condition
+ALTER TABLE job DROP COLUMN run_if;
@@ -0,0 +1,3 @@
+-- Remove retry scheduling state from jobs
+ALTER TABLE job DROP COLUMN excluded_hostname;
+ALTER TABLE job DROP COLUMN not_before;
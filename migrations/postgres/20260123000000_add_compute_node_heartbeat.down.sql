@@ -0,0 +1,4 @@
+-- Remove compute node heartbeats
+ALTER TABLE workflow DROP COLUMN dead_worker_policy;
+ALTER TABLE workflow DROP COLUMN worker_heartbeat_timeout_seconds;
+ALTER TABLE compute_node DROP COLUMN last_heartbeat;
@@ -0,0 +1,7 @@
+-- Add compute node heartbeats for dead worker detection
+-- last_heartbeat is a Unix timestamp in milliseconds, set by the job runner's periodic heartbeat
+-- Compute nodes that do not send a heartbeat within the workflow's timeout are marked inactive
+-- and their jobs are failed or returned to ready according to dead_worker_policy
+ALTER TABLE compute_node ADD COLUMN last_heartbeat BIGINT NULL;
+ALTER TABLE workflow ADD COLUMN worker_heartbeat_timeout_seconds BIGINT NULL DEFAULT 300;
+ALTER TABLE workflow ADD COLUMN dead_worker_policy TEXT NULL DEFAULT 'fail';
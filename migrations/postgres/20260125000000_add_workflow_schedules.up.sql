@@ -0,0 +1,21 @@
+-- Add server-managed schedules for recurring workflow runs
+-- cron_expression uses standard cron syntax with an optional leading seconds field and is
+-- evaluated in timezone (an IANA name such as America/Denver)
+-- next_run_time and last_run_time are Unix timestamps in milliseconds
+-- overlap_policy controls what happens when the previous run is still active:
+-- 'skip' drops the firing and 'queue' fires as soon as the previous run finishes
+CREATE TABLE workflow_schedule (
+  id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+  workflow_id BIGINT NOT NULL,
+  cron_expression TEXT NOT NULL,
+  timezone TEXT NOT NULL DEFAULT 'UTC',
+  overlap_policy TEXT NOT NULL DEFAULT 'skip',
+  working_directory TEXT NULL,
+  is_paused BIGINT NOT NULL DEFAULT 0,
+  next_run_time BIGINT NULL,
+  last_run_time BIGINT NULL,
+  FOREIGN KEY (workflow_id) REFERENCES workflow(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_workflow_schedule_workflow_id ON workflow_schedule(workflow_id);
+CREATE INDEX idx_workflow_schedule_next_run_time ON workflow_schedule(next_run_time);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_job_log_workflow_id;
+DROP TABLE IF EXISTS job_log;
@@ -0,0 +1,2 @@
+-- Remove the Slurm job array task index from scheduled compute nodes
+ALTER TABLE scheduled_compute_node DROP COLUMN array_task_id;
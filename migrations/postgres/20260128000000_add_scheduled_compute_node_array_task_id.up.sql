@@ -0,0 +1,3 @@
+-- Track the task index of allocations submitted as a Slurm job array
+-- scheduler_id holds the array's job ID; the allocation's Slurm ID is <scheduler_id>_<array_task_id>
+ALTER TABLE scheduled_compute_node ADD COLUMN array_task_id BIGINT NULL;
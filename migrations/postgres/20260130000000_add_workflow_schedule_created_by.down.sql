@@ -0,0 +1,2 @@
+-- Remove the creator of workflow schedules
+ALTER TABLE workflow_schedule DROP COLUMN created_by;
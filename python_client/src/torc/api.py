@@ -1,5 +1,6 @@
 """Functions to access the Torc Database API."""
 
+import os
 import time
 from collections.abc import Callable, Generator
 from typing import Any
@@ -17,13 +18,16 @@ from torc.openapi_client.models.user_data_model import UserDataModel
 from torc.openapi_client.rest import ApiException
 
 
-def make_api(database_url: str) -> DefaultApi:
+def make_api(database_url: str, api_token: str | None = None) -> DefaultApi:
     """Instantiate an OpenAPI client object from a database URL.
 
     Parameters
     ----------
     database_url : str
         URL of the Torc database API.
+    api_token : str | None, optional
+        Server-issued API token used for bearer authentication. Defaults to the value of the
+        TORC_API_TOKEN environment variable, if set.
 
     Returns
     -------
@@ -32,6 +36,11 @@ def make_api(database_url: str) -> DefaultApi:
     """
     configuration = Configuration()
     configuration.host = database_url
+    token = api_token or os.environ.get("TORC_API_TOKEN")
+    if token:
+        return DefaultApi(
+            ApiClient(configuration, header_name="Authorization", header_value=f"Bearer {token}")
+        )
     return DefaultApi(ApiClient(configuration))
 
 
@@ -30,6 +30,7 @@ __all__ = [
     "ApiException",
     "AccessCheckResponse",
     "AccessGroupModel",
+    "ApiTokenModel",
     "ClaimAction200Response",
     "ClaimActionRequest",
     "ClaimJobsBasedOnResourcesResponse",
@@ -37,7 +38,9 @@ __all__ = [
     "ComputeNodeModel",
     "ComputeNodeSchedule",
     "ComputeNodesResources",
+    "ContainerRuntime",
     "CreateJobsResponse",
+    "DeadWorkerPolicy",
     "DefaultErrorResponse",
     "EventModel",
     "FailureHandlerModel",
@@ -47,13 +50,17 @@ __all__ = [
     "IsCompleteResponse",
     "IsUninitializedResponse",
     "JobDependencyModel",
+    "JobContainerModel",
     "JobFileRelationshipModel",
+    "JobLogModel",
+    "JobLogStream",
     "JobModel",
     "JobStatus",
     "JobUserDataRelationshipModel",
     "JobsModel",
     "JobsSortMethod",
     "ListAccessGroupsResponse",
+    "ListApiTokensResponse",
     "ListComputeNodesResponse",
     "ListEventsResponse",
     "ListFailureHandlersResponse",
@@ -61,6 +68,7 @@ __all__ = [
     "ListJobDependenciesResponse",
     "ListJobFileRelationshipsResponse",
     "ListJobIdsResponse",
+    "ListJobLogsResponse",
     "ListJobUserDataRelationshipsResponse",
     "ListJobsResponse",
     "ListLocalSchedulersResponse",
@@ -72,6 +80,7 @@ __all__ = [
     "ListSlurmSchedulersResponse",
     "ListUserDataResponse",
     "ListUserGroupMembershipsResponse",
+    "ListWorkflowSchedulesResponse",
     "ListWorkflowsResponse",
     "LocalSchedulerModel",
     "NotFoundErrorResponse",
@@ -80,6 +89,7 @@ __all__ = [
     "ResetJobStatusResponse",
     "ResourceRequirementsModel",
     "ResultModel",
+    "ScheduleOverlapPolicy",
     "ScheduledComputeNodesModel",
     "SlurmSchedulerModel",
     "UserDataModel",
@@ -87,6 +97,7 @@ __all__ = [
     "WorkflowAccessGroupModel",
     "WorkflowActionModel",
     "WorkflowModel",
+    "WorkflowScheduleModel",
     "WorkflowStatusModel",
 ]
 
@@ -107,6 +118,7 @@ from torc.openapi_client.exceptions import ApiException as ApiException
 # import models into sdk package
 from torc.openapi_client.models.access_check_response import AccessCheckResponse as AccessCheckResponse
 from torc.openapi_client.models.access_group_model import AccessGroupModel as AccessGroupModel
+from torc.openapi_client.models.api_token_model import ApiTokenModel as ApiTokenModel
 from torc.openapi_client.models.claim_action200_response import ClaimAction200Response as ClaimAction200Response
 from torc.openapi_client.models.claim_action_request import ClaimActionRequest as ClaimActionRequest
 from torc.openapi_client.models.claim_jobs_based_on_resources_response import ClaimJobsBasedOnResourcesResponse as ClaimJobsBasedOnResourcesResponse
@@ -114,7 +126,9 @@ from torc.openapi_client.models.claim_next_jobs_response import ClaimNextJobsRes
 from torc.openapi_client.models.compute_node_model import ComputeNodeModel as ComputeNodeModel
 from torc.openapi_client.models.compute_node_schedule import ComputeNodeSchedule as ComputeNodeSchedule
 from torc.openapi_client.models.compute_nodes_resources import ComputeNodesResources as ComputeNodesResources
+from torc.openapi_client.models.container_runtime import ContainerRuntime as ContainerRuntime
 from torc.openapi_client.models.create_jobs_response import CreateJobsResponse as CreateJobsResponse
+from torc.openapi_client.models.dead_worker_policy import DeadWorkerPolicy as DeadWorkerPolicy
 from torc.openapi_client.models.default_error_response import DefaultErrorResponse as DefaultErrorResponse
 from torc.openapi_client.models.event_model import EventModel as EventModel
 from torc.openapi_client.models.failure_handler_model import FailureHandlerModel as FailureHandlerModel
@@ -123,14 +137,18 @@ from torc.openapi_client.models.forbidden_error_response import ForbiddenErrorRe
 from torc.openapi_client.models.get_ready_job_requirements_response import GetReadyJobRequirementsResponse as GetReadyJobRequirementsResponse
 from torc.openapi_client.models.is_complete_response import IsCompleteResponse as IsCompleteResponse
 from torc.openapi_client.models.is_uninitialized_response import IsUninitializedResponse as IsUninitializedResponse
+from torc.openapi_client.models.job_container_model import JobContainerModel as JobContainerModel
 from torc.openapi_client.models.job_dependency_model import JobDependencyModel as JobDependencyModel
 from torc.openapi_client.models.job_file_relationship_model import JobFileRelationshipModel as JobFileRelationshipModel
+from torc.openapi_client.models.job_log_model import JobLogModel as JobLogModel
+from torc.openapi_client.models.job_log_stream import JobLogStream as JobLogStream
 from torc.openapi_client.models.job_model import JobModel as JobModel
 from torc.openapi_client.models.job_status import JobStatus as JobStatus
 from torc.openapi_client.models.job_user_data_relationship_model import JobUserDataRelationshipModel as JobUserDataRelationshipModel
 from torc.openapi_client.models.jobs_model import JobsModel as JobsModel
 from torc.openapi_client.models.jobs_sort_method import JobsSortMethod as JobsSortMethod
 from torc.openapi_client.models.list_access_groups_response import ListAccessGroupsResponse as ListAccessGroupsResponse
+from torc.openapi_client.models.list_api_tokens_response import ListApiTokensResponse as ListApiTokensResponse
 from torc.openapi_client.models.list_compute_nodes_response import ListComputeNodesResponse as ListComputeNodesResponse
 from torc.openapi_client.models.list_events_response import ListEventsResponse as ListEventsResponse
 from torc.openapi_client.models.list_failure_handlers_response import ListFailureHandlersResponse as ListFailureHandlersResponse
@@ -138,6 +156,7 @@ from torc.openapi_client.models.list_files_response import ListFilesResponse as
 from torc.openapi_client.models.list_job_dependencies_response import ListJobDependenciesResponse as ListJobDependenciesResponse
 from torc.openapi_client.models.list_job_file_relationships_response import ListJobFileRelationshipsResponse as ListJobFileRelationshipsResponse
 from torc.openapi_client.models.list_job_ids_response import ListJobIdsResponse as ListJobIdsResponse
+from torc.openapi_client.models.list_job_logs_response import ListJobLogsResponse as ListJobLogsResponse
 from torc.openapi_client.models.list_job_user_data_relationships_response import ListJobUserDataRelationshipsResponse as ListJobUserDataRelationshipsResponse
 from torc.openapi_client.models.list_jobs_response import ListJobsResponse as ListJobsResponse
 from torc.openapi_client.models.list_local_schedulers_response import ListLocalSchedulersResponse as ListLocalSchedulersResponse
@@ -149,6 +168,7 @@ from torc.openapi_client.models.list_scheduled_compute_nodes_response import Lis
 from torc.openapi_client.models.list_slurm_schedulers_response import ListSlurmSchedulersResponse as ListSlurmSchedulersResponse
 from torc.openapi_client.models.list_user_data_response import ListUserDataResponse as ListUserDataResponse
 from torc.openapi_client.models.list_user_group_memberships_response import ListUserGroupMembershipsResponse as ListUserGroupMembershipsResponse
+from torc.openapi_client.models.list_workflow_schedules_response import ListWorkflowSchedulesResponse as ListWorkflowSchedulesResponse
 from torc.openapi_client.models.list_workflows_response import ListWorkflowsResponse as ListWorkflowsResponse
 from torc.openapi_client.models.local_scheduler_model import LocalSchedulerModel as LocalSchedulerModel
 from torc.openapi_client.models.not_found_error_response import NotFoundErrorResponse as NotFoundErrorResponse
@@ -157,6 +177,7 @@ from torc.openapi_client.models.remote_worker_model import RemoteWorkerModel as
 from torc.openapi_client.models.reset_job_status_response import ResetJobStatusResponse as ResetJobStatusResponse
 from torc.openapi_client.models.resource_requirements_model import ResourceRequirementsModel as ResourceRequirementsModel
 from torc.openapi_client.models.result_model import ResultModel as ResultModel
+from torc.openapi_client.models.schedule_overlap_policy import ScheduleOverlapPolicy as ScheduleOverlapPolicy
 from torc.openapi_client.models.scheduled_compute_nodes_model import ScheduledComputeNodesModel as ScheduledComputeNodesModel
 from torc.openapi_client.models.slurm_scheduler_model import SlurmSchedulerModel as SlurmSchedulerModel
 from torc.openapi_client.models.user_data_model import UserDataModel as UserDataModel
@@ -164,5 +185,6 @@ from torc.openapi_client.models.user_group_membership_model import UserGroupMemb
 from torc.openapi_client.models.workflow_access_group_model import WorkflowAccessGroupModel as WorkflowAccessGroupModel
 from torc.openapi_client.models.workflow_action_model import WorkflowActionModel as WorkflowActionModel
 from torc.openapi_client.models.workflow_model import WorkflowModel as WorkflowModel
+from torc.openapi_client.models.workflow_schedule_model import WorkflowScheduleModel as WorkflowScheduleModel
 from torc.openapi_client.models.workflow_status_model import WorkflowStatusModel as WorkflowStatusModel
 
@@ -21,6 +21,7 @@ from typing import Any, Dict, List, Optional
 from typing_extensions import Annotated
 from torc.openapi_client.models.access_check_response import AccessCheckResponse
 from torc.openapi_client.models.access_group_model import AccessGroupModel
+from torc.openapi_client.models.api_token_model import ApiTokenModel
 from torc.openapi_client.models.claim_action200_response import ClaimAction200Response
 from torc.openapi_client.models.claim_action_request import ClaimActionRequest
 from torc.openapi_client.models.claim_jobs_based_on_resources_response import ClaimJobsBasedOnResourcesResponse
@@ -34,11 +35,14 @@ from torc.openapi_client.models.file_model import FileModel
 from torc.openapi_client.models.get_ready_job_requirements_response import GetReadyJobRequirementsResponse
 from torc.openapi_client.models.is_complete_response import IsCompleteResponse
 from torc.openapi_client.models.is_uninitialized_response import IsUninitializedResponse
+from torc.openapi_client.models.job_log_model import JobLogModel
+from torc.openapi_client.models.job_log_stream import JobLogStream
 from torc.openapi_client.models.job_model import JobModel
 from torc.openapi_client.models.job_status import JobStatus
 from torc.openapi_client.models.jobs_model import JobsModel
 from torc.openapi_client.models.jobs_sort_method import JobsSortMethod
 from torc.openapi_client.models.list_access_groups_response import ListAccessGroupsResponse
+from torc.openapi_client.models.list_api_tokens_response import ListApiTokensResponse
 from torc.openapi_client.models.list_compute_nodes_response import ListComputeNodesResponse
 from torc.openapi_client.models.list_events_response import ListEventsResponse
 from torc.openapi_client.models.list_failure_handlers_response import ListFailureHandlersResponse
@@ -46,6 +50,7 @@ from torc.openapi_client.models.list_files_response import ListFilesResponse
 from torc.openapi_client.models.list_job_dependencies_response import ListJobDependenciesResponse
 from torc.openapi_client.models.list_job_file_relationships_response import ListJobFileRelationshipsResponse
 from torc.openapi_client.models.list_job_ids_response import ListJobIdsResponse
+from torc.openapi_client.models.list_job_logs_response import ListJobLogsResponse
 from torc.openapi_client.models.list_job_user_data_relationships_response import ListJobUserDataRelationshipsResponse
 from torc.openapi_client.models.list_jobs_response import ListJobsResponse
 from torc.openapi_client.models.list_local_schedulers_response import ListLocalSchedulersResponse
@@ -57,6 +62,7 @@ from torc.openapi_client.models.list_scheduled_compute_nodes_response import Lis
 from torc.openapi_client.models.list_slurm_schedulers_response import ListSlurmSchedulersResponse
 from torc.openapi_client.models.list_user_data_response import ListUserDataResponse
 from torc.openapi_client.models.list_user_group_memberships_response import ListUserGroupMembershipsResponse
+from torc.openapi_client.models.list_workflow_schedules_response import ListWorkflowSchedulesResponse
 from torc.openapi_client.models.list_workflows_response import ListWorkflowsResponse
 from torc.openapi_client.models.local_scheduler_model import LocalSchedulerModel
 from torc.openapi_client.models.process_changed_job_inputs_response import ProcessChangedJobInputsResponse
@@ -71,6 +77,7 @@ from torc.openapi_client.models.user_group_membership_model import UserGroupMemb
 from torc.openapi_client.models.workflow_access_group_model import WorkflowAccessGroupModel
 from torc.openapi_client.models.workflow_action_model import WorkflowActionModel
 from torc.openapi_client.models.workflow_model import WorkflowModel
+from torc.openapi_client.models.workflow_schedule_model import WorkflowScheduleModel
 from torc.openapi_client.models.workflow_status_model import WorkflowStatusModel
 
 from torc.openapi_client.api_client import ApiClient, RequestSerialized
@@ -2830,9 +2837,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_compute_node(
+    def create_api_token(
         self,
-        body: Annotated[ComputeNodeModel, Field(description="Compute node")],
+        body: Annotated[ApiTokenModel, Field(description="API token to create")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -2845,13 +2852,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ComputeNodeModel:
-        """Store a compute node.
+    ) -> ApiTokenModel:
+        """Create an API token.
 
-        Store a compute node.
+        Create an API token. The plaintext token is only returned in this response.
 
-        :param body: Compute node (required)
-        :type body: ComputeNodeModel
+        :param body: API token to create (required)
+        :type body: ApiTokenModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -2874,7 +2881,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_compute_node_serialize(
+        _param = self._create_api_token_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -2883,8 +2890,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ComputeNodeModel",
-            '404': "NotFoundErrorResponse",
+            '200': "ApiTokenModel",
+            '403': "ForbiddenErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -2899,9 +2907,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_compute_node_with_http_info(
+    def create_api_token_with_http_info(
         self,
-        body: Annotated[ComputeNodeModel, Field(description="Compute node")],
+        body: Annotated[ApiTokenModel, Field(description="API token to create")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -2914,13 +2922,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ComputeNodeModel]:
-        """Store a compute node.
+    ) -> ApiResponse[ApiTokenModel]:
+        """Create an API token.
 
-        Store a compute node.
+        Create an API token. The plaintext token is only returned in this response.
 
-        :param body: Compute node (required)
-        :type body: ComputeNodeModel
+        :param body: API token to create (required)
+        :type body: ApiTokenModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -2943,7 +2951,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_compute_node_serialize(
+        _param = self._create_api_token_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -2952,8 +2960,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ComputeNodeModel",
-            '404': "NotFoundErrorResponse",
+            '200': "ApiTokenModel",
+            '403': "ForbiddenErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -2968,9 +2977,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_compute_node_without_preload_content(
+    def create_api_token_without_preload_content(
         self,
-        body: Annotated[ComputeNodeModel, Field(description="Compute node")],
+        body: Annotated[ApiTokenModel, Field(description="API token to create")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -2984,12 +2993,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Store a compute node.
+        """Create an API token.
 
-        Store a compute node.
+        Create an API token. The plaintext token is only returned in this response.
 
-        :param body: Compute node (required)
-        :type body: ComputeNodeModel
+        :param body: API token to create (required)
+        :type body: ApiTokenModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -3012,7 +3021,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_compute_node_serialize(
+        _param = self._create_api_token_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -3021,8 +3030,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ComputeNodeModel",
-            '404': "NotFoundErrorResponse",
+            '200': "ApiTokenModel",
+            '403': "ForbiddenErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -3032,7 +3042,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _create_compute_node_serialize(
+    def _create_api_token_serialize(
         self,
         body,
         _request_auth,
@@ -3092,7 +3102,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/compute_nodes',
+            resource_path='/api_tokens',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -3109,9 +3119,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_event(
+    def create_compute_node(
         self,
-        body: Annotated[EventModel, Field(description="Event body")],
+        body: Annotated[ComputeNodeModel, Field(description="Compute node")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -3124,13 +3134,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> EventModel:
-        """Store an event.
+    ) -> ComputeNodeModel:
+        """Store a compute node.
 
-        Store an event.
+        Store a compute node.
 
-        :param body: Event body (required)
-        :type body: EventModel
+        :param body: Compute node (required)
+        :type body: ComputeNodeModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -3153,7 +3163,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_event_serialize(
+        _param = self._create_compute_node_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -3162,7 +3172,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "EventModel",
+            '200': "ComputeNodeModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -3178,9 +3188,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_event_with_http_info(
+    def create_compute_node_with_http_info(
         self,
-        body: Annotated[EventModel, Field(description="Event body")],
+        body: Annotated[ComputeNodeModel, Field(description="Compute node")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -3193,13 +3203,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[EventModel]:
-        """Store an event.
+    ) -> ApiResponse[ComputeNodeModel]:
+        """Store a compute node.
 
-        Store an event.
+        Store a compute node.
 
-        :param body: Event body (required)
-        :type body: EventModel
+        :param body: Compute node (required)
+        :type body: ComputeNodeModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -3222,7 +3232,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_event_serialize(
+        _param = self._create_compute_node_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -3231,7 +3241,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "EventModel",
+            '200': "ComputeNodeModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -3247,9 +3257,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_event_without_preload_content(
+    def create_compute_node_without_preload_content(
         self,
-        body: Annotated[EventModel, Field(description="Event body")],
+        body: Annotated[ComputeNodeModel, Field(description="Compute node")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -3263,12 +3273,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Store an event.
+        """Store a compute node.
 
-        Store an event.
+        Store a compute node.
 
-        :param body: Event body (required)
-        :type body: EventModel
+        :param body: Compute node (required)
+        :type body: ComputeNodeModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -3291,7 +3301,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_event_serialize(
+        _param = self._create_compute_node_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -3300,7 +3310,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "EventModel",
+            '200': "ComputeNodeModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -3311,7 +3321,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _create_event_serialize(
+    def _create_compute_node_serialize(
         self,
         body,
         _request_auth,
@@ -3371,7 +3381,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/events',
+            resource_path='/compute_nodes',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -3388,9 +3398,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_failure_handler(
+    def create_event(
         self,
-        body: Annotated[FailureHandlerModel, Field(description="Failure handler to create")],
+        body: Annotated[EventModel, Field(description="Event body")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -3403,13 +3413,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> FailureHandlerModel:
-        """Create a failure handler.
+    ) -> EventModel:
+        """Store an event.
 
-        Create a failure handler with rules for automatic job retry.
+        Store an event.
 
-        :param body: Failure handler to create (required)
-        :type body: FailureHandlerModel
+        :param body: Event body (required)
+        :type body: EventModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -3432,7 +3442,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_failure_handler_serialize(
+        _param = self._create_event_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -3441,7 +3451,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FailureHandlerModel",
+            '200': "EventModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -3457,9 +3467,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_failure_handler_with_http_info(
+    def create_event_with_http_info(
         self,
-        body: Annotated[FailureHandlerModel, Field(description="Failure handler to create")],
+        body: Annotated[EventModel, Field(description="Event body")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -3472,13 +3482,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[FailureHandlerModel]:
-        """Create a failure handler.
+    ) -> ApiResponse[EventModel]:
+        """Store an event.
 
-        Create a failure handler with rules for automatic job retry.
+        Store an event.
 
-        :param body: Failure handler to create (required)
-        :type body: FailureHandlerModel
+        :param body: Event body (required)
+        :type body: EventModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -3501,7 +3511,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_failure_handler_serialize(
+        _param = self._create_event_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -3510,7 +3520,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FailureHandlerModel",
+            '200': "EventModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -3526,9 +3536,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_failure_handler_without_preload_content(
+    def create_event_without_preload_content(
         self,
-        body: Annotated[FailureHandlerModel, Field(description="Failure handler to create")],
+        body: Annotated[EventModel, Field(description="Event body")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -3542,12 +3552,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Create a failure handler.
+        """Store an event.
 
-        Create a failure handler with rules for automatic job retry.
+        Store an event.
 
-        :param body: Failure handler to create (required)
-        :type body: FailureHandlerModel
+        :param body: Event body (required)
+        :type body: EventModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -3570,7 +3580,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_failure_handler_serialize(
+        _param = self._create_event_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -3579,7 +3589,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FailureHandlerModel",
+            '200': "EventModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -3590,7 +3600,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _create_failure_handler_serialize(
+    def _create_event_serialize(
         self,
         body,
         _request_auth,
@@ -3650,7 +3660,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/failure_handlers',
+            resource_path='/events',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -3667,9 +3677,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_file(
+    def create_failure_handler(
         self,
-        body: Annotated[FileModel, Field(description="file.")],
+        body: Annotated[FailureHandlerModel, Field(description="Failure handler to create")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -3682,13 +3692,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> FileModel:
-        """Store a file.
+    ) -> FailureHandlerModel:
+        """Create a failure handler.
 
-        Store a file.
+        Create a failure handler with rules for automatic job retry.
 
-        :param body: file. (required)
-        :type body: FileModel
+        :param body: Failure handler to create (required)
+        :type body: FailureHandlerModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -3711,7 +3721,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_file_serialize(
+        _param = self._create_failure_handler_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -3720,7 +3730,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FileModel",
+            '200': "FailureHandlerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -3736,9 +3746,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_file_with_http_info(
+    def create_failure_handler_with_http_info(
         self,
-        body: Annotated[FileModel, Field(description="file.")],
+        body: Annotated[FailureHandlerModel, Field(description="Failure handler to create")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -3751,13 +3761,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[FileModel]:
-        """Store a file.
+    ) -> ApiResponse[FailureHandlerModel]:
+        """Create a failure handler.
 
-        Store a file.
+        Create a failure handler with rules for automatic job retry.
 
-        :param body: file. (required)
-        :type body: FileModel
+        :param body: Failure handler to create (required)
+        :type body: FailureHandlerModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -3780,7 +3790,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_file_serialize(
+        _param = self._create_failure_handler_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -3789,7 +3799,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FileModel",
+            '200': "FailureHandlerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -3805,9 +3815,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_file_without_preload_content(
+    def create_failure_handler_without_preload_content(
         self,
-        body: Annotated[FileModel, Field(description="file.")],
+        body: Annotated[FailureHandlerModel, Field(description="Failure handler to create")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -3821,12 +3831,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Store a file.
+        """Create a failure handler.
 
-        Store a file.
+        Create a failure handler with rules for automatic job retry.
 
-        :param body: file. (required)
-        :type body: FileModel
+        :param body: Failure handler to create (required)
+        :type body: FailureHandlerModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -3849,7 +3859,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_file_serialize(
+        _param = self._create_failure_handler_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -3858,7 +3868,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FileModel",
+            '200': "FailureHandlerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -3869,7 +3879,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _create_file_serialize(
+    def _create_failure_handler_serialize(
         self,
         body,
         _request_auth,
@@ -3929,7 +3939,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/files',
+            resource_path='/failure_handlers',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -3946,9 +3956,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_job(
+    def create_file(
         self,
-        body: Annotated[JobModel, Field(description="Job body")],
+        body: Annotated[FileModel, Field(description="file.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -3961,13 +3971,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> JobModel:
-        """Store a job.
+    ) -> FileModel:
+        """Store a file.
 
-        Store a job.
+        Store a file.
 
-        :param body: Job body (required)
-        :type body: JobModel
+        :param body: file. (required)
+        :type body: FileModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -3990,7 +4000,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_job_serialize(
+        _param = self._create_file_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -3999,7 +4009,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "JobModel",
+            '200': "FileModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -4015,9 +4025,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_job_with_http_info(
+    def create_file_with_http_info(
         self,
-        body: Annotated[JobModel, Field(description="Job body")],
+        body: Annotated[FileModel, Field(description="file.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -4030,13 +4040,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[JobModel]:
-        """Store a job.
+    ) -> ApiResponse[FileModel]:
+        """Store a file.
 
-        Store a job.
+        Store a file.
 
-        :param body: Job body (required)
-        :type body: JobModel
+        :param body: file. (required)
+        :type body: FileModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -4059,7 +4069,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_job_serialize(
+        _param = self._create_file_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -4068,7 +4078,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "JobModel",
+            '200': "FileModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -4084,9 +4094,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_job_without_preload_content(
+    def create_file_without_preload_content(
         self,
-        body: Annotated[JobModel, Field(description="Job body")],
+        body: Annotated[FileModel, Field(description="file.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -4100,12 +4110,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Store a job.
+        """Store a file.
 
-        Store a job.
+        Store a file.
 
-        :param body: Job body (required)
-        :type body: JobModel
+        :param body: file. (required)
+        :type body: FileModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -4128,7 +4138,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_job_serialize(
+        _param = self._create_file_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -4137,7 +4147,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "JobModel",
+            '200': "FileModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -4148,7 +4158,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _create_job_serialize(
+    def _create_file_serialize(
         self,
         body,
         _request_auth,
@@ -4208,7 +4218,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/jobs',
+            resource_path='/files',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -4225,9 +4235,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_jobs(
+    def create_job(
         self,
-        body: JobsModel,
+        body: Annotated[JobModel, Field(description="Job body")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -4240,13 +4250,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> CreateJobsResponse:
-        """Create jobs in bulk. Recommended max job count of 10,000.
+    ) -> JobModel:
+        """Store a job.
 
-        Create jobs in bulk. Recommended max job count of 10,000.
+        Store a job.
 
-        :param body: (required)
-        :type body: JobsModel
+        :param body: Job body (required)
+        :type body: JobModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -4269,7 +4279,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_jobs_serialize(
+        _param = self._create_job_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -4278,9 +4288,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "CreateJobsResponse",
+            '200': "JobModel",
             '404': "NotFoundErrorResponse",
-            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -4295,9 +4304,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_jobs_with_http_info(
+    def create_job_with_http_info(
         self,
-        body: JobsModel,
+        body: Annotated[JobModel, Field(description="Job body")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -4310,13 +4319,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[CreateJobsResponse]:
-        """Create jobs in bulk. Recommended max job count of 10,000.
+    ) -> ApiResponse[JobModel]:
+        """Store a job.
 
-        Create jobs in bulk. Recommended max job count of 10,000.
+        Store a job.
 
-        :param body: (required)
-        :type body: JobsModel
+        :param body: Job body (required)
+        :type body: JobModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -4339,7 +4348,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_jobs_serialize(
+        _param = self._create_job_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -4348,9 +4357,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "CreateJobsResponse",
+            '200': "JobModel",
             '404': "NotFoundErrorResponse",
-            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -4365,9 +4373,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_jobs_without_preload_content(
+    def create_job_without_preload_content(
         self,
-        body: JobsModel,
+        body: Annotated[JobModel, Field(description="Job body")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -4381,12 +4389,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Create jobs in bulk. Recommended max job count of 10,000.
+        """Store a job.
 
-        Create jobs in bulk. Recommended max job count of 10,000.
+        Store a job.
 
-        :param body: (required)
-        :type body: JobsModel
+        :param body: Job body (required)
+        :type body: JobModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -4409,7 +4417,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_jobs_serialize(
+        _param = self._create_job_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -4418,9 +4426,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "CreateJobsResponse",
+            '200': "JobModel",
             '404': "NotFoundErrorResponse",
-            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -4430,7 +4437,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _create_jobs_serialize(
+    def _create_job_serialize(
         self,
         body,
         _request_auth,
@@ -4490,7 +4497,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/bulk_jobs',
+            resource_path='/jobs',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -4507,9 +4514,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_local_scheduler(
+    def create_job_log(
         self,
-        body: Annotated[LocalSchedulerModel, Field(description="local compute node configuration.")],
+        body: Annotated[JobLogModel, Field(description="Job log to store")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -4522,13 +4529,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> LocalSchedulerModel:
-        """Store a local scheduler.
+    ) -> JobLogModel:
+        """Store a job log.
 
-        Store a local scheduler. table.
+        Store the stdout or stderr log of a job attempt. Job runners call this on job completion when log upload is enabled. A second upload for the same job, run, attempt, and stream replaces the first. Content larger than the server's job log size limit is rejected.
 
-        :param body: local compute node configuration. (required)
-        :type body: LocalSchedulerModel
+        :param body: Job log to store (required)
+        :type body: JobLogModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -4551,7 +4558,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_local_scheduler_serialize(
+        _param = self._create_job_log_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -4560,7 +4567,10 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "LocalSchedulerModel",
+            '200': "JobLogModel",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -4575,9 +4585,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_local_scheduler_with_http_info(
+    def create_job_log_with_http_info(
         self,
-        body: Annotated[LocalSchedulerModel, Field(description="local compute node configuration.")],
+        body: Annotated[JobLogModel, Field(description="Job log to store")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -4590,13 +4600,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[LocalSchedulerModel]:
-        """Store a local scheduler.
+    ) -> ApiResponse[JobLogModel]:
+        """Store a job log.
 
-        Store a local scheduler. table.
+        Store the stdout or stderr log of a job attempt. Job runners call this on job completion when log upload is enabled. A second upload for the same job, run, attempt, and stream replaces the first. Content larger than the server's job log size limit is rejected.
 
-        :param body: local compute node configuration. (required)
-        :type body: LocalSchedulerModel
+        :param body: Job log to store (required)
+        :type body: JobLogModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -4619,7 +4629,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_local_scheduler_serialize(
+        _param = self._create_job_log_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -4628,7 +4638,10 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "LocalSchedulerModel",
+            '200': "JobLogModel",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -4643,9 +4656,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_local_scheduler_without_preload_content(
+    def create_job_log_without_preload_content(
         self,
-        body: Annotated[LocalSchedulerModel, Field(description="local compute node configuration.")],
+        body: Annotated[JobLogModel, Field(description="Job log to store")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -4659,12 +4672,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Store a local scheduler.
+        """Store a job log.
 
-        Store a local scheduler. table.
+        Store the stdout or stderr log of a job attempt. Job runners call this on job completion when log upload is enabled. A second upload for the same job, run, attempt, and stream replaces the first. Content larger than the server's job log size limit is rejected.
 
-        :param body: local compute node configuration. (required)
-        :type body: LocalSchedulerModel
+        :param body: Job log to store (required)
+        :type body: JobLogModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -4687,7 +4700,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_local_scheduler_serialize(
+        _param = self._create_job_log_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -4696,7 +4709,10 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "LocalSchedulerModel",
+            '200': "JobLogModel",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -4706,7 +4722,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _create_local_scheduler_serialize(
+    def _create_job_log_serialize(
         self,
         body,
         _request_auth,
@@ -4766,7 +4782,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/local_schedulers',
+            resource_path='/job_logs',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -4783,10 +4799,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_remote_workers(
+    def create_jobs(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        workers: Annotated[List[StrictStr], Field(description="List of remote workers to add")],
+        body: JobsModel,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -4799,15 +4814,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> List[RemoteWorkerModel]:
-        """Store remote workers for a workflow.
+    ) -> CreateJobsResponse:
+        """Create jobs in bulk. Recommended max job count of 10,000.
 
-        Store remote workers for a workflow.
+        Create jobs in bulk. Recommended max job count of 10,000.
 
-        :param id: Workflow ID (required)
-        :type id: int
-        :param workers: List of remote workers to add (required)
-        :type workers: List[str]
+        :param body: (required)
+        :type body: JobsModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -4830,9 +4843,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_remote_workers_serialize(
-            id=id,
-            workers=workers,
+        _param = self._create_jobs_serialize(
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -4840,8 +4852,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "List[RemoteWorkerModel]",
-            '404': "DefaultErrorResponse",
+            '200': "CreateJobsResponse",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -4856,10 +4869,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_remote_workers_with_http_info(
+    def create_jobs_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        workers: Annotated[List[StrictStr], Field(description="List of remote workers to add")],
+        body: JobsModel,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -4872,15 +4884,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[List[RemoteWorkerModel]]:
-        """Store remote workers for a workflow.
+    ) -> ApiResponse[CreateJobsResponse]:
+        """Create jobs in bulk. Recommended max job count of 10,000.
 
-        Store remote workers for a workflow.
+        Create jobs in bulk. Recommended max job count of 10,000.
 
-        :param id: Workflow ID (required)
-        :type id: int
-        :param workers: List of remote workers to add (required)
-        :type workers: List[str]
+        :param body: (required)
+        :type body: JobsModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -4903,9 +4913,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_remote_workers_serialize(
-            id=id,
-            workers=workers,
+        _param = self._create_jobs_serialize(
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -4913,8 +4922,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "List[RemoteWorkerModel]",
-            '404': "DefaultErrorResponse",
+            '200': "CreateJobsResponse",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -4929,10 +4939,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_remote_workers_without_preload_content(
+    def create_jobs_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        workers: Annotated[List[StrictStr], Field(description="List of remote workers to add")],
+        body: JobsModel,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -4946,14 +4955,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Store remote workers for a workflow.
+        """Create jobs in bulk. Recommended max job count of 10,000.
 
-        Store remote workers for a workflow.
+        Create jobs in bulk. Recommended max job count of 10,000.
 
-        :param id: Workflow ID (required)
-        :type id: int
-        :param workers: List of remote workers to add (required)
-        :type workers: List[str]
+        :param body: (required)
+        :type body: JobsModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -4976,9 +4983,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_remote_workers_serialize(
-            id=id,
-            workers=workers,
+        _param = self._create_jobs_serialize(
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -4986,8 +4992,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "List[RemoteWorkerModel]",
-            '404': "DefaultErrorResponse",
+            '200': "CreateJobsResponse",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -4997,10 +5004,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _create_remote_workers_serialize(
+    def _create_jobs_serialize(
         self,
-        id,
-        workers,
+        body,
         _request_auth,
         _content_type,
         _headers,
@@ -5010,7 +5016,6 @@ class DefaultApi:
         _host = None
 
         _collection_formats: Dict[str, str] = {
-            'workers': '',
         }
 
         _path_params: Dict[str, str] = {}
@@ -5023,14 +5028,12 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
         # process the header parameters
         # process the form parameters
         # process the body parameter
-        if workers is not None:
-            _body_params = workers
+        if body is not None:
+            _body_params = body
 
 
         # set the HTTP header `Accept`
@@ -5061,7 +5064,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/workflows/{id}/remote_workers',
+            resource_path='/bulk_jobs',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -5078,9 +5081,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_resource_requirements(
+    def create_local_scheduler(
         self,
-        body: Annotated[ResourceRequirementsModel, Field(description="resource requirements.")],
+        body: Annotated[LocalSchedulerModel, Field(description="local compute node configuration.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -5093,13 +5096,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ResourceRequirementsModel:
-        """Store one resource requirements record.
+    ) -> LocalSchedulerModel:
+        """Store a local scheduler.
 
-        Store one resource requirements definition.
+        Store a local scheduler. table.
 
-        :param body: resource requirements. (required)
-        :type body: ResourceRequirementsModel
+        :param body: local compute node configuration. (required)
+        :type body: LocalSchedulerModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -5122,7 +5125,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_resource_requirements_serialize(
+        _param = self._create_local_scheduler_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -5131,9 +5134,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResourceRequirementsModel",
-            '404': "NotFoundErrorResponse",
-            '422': "DefaultErrorResponse",
+            '200': "LocalSchedulerModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -5148,9 +5149,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_resource_requirements_with_http_info(
+    def create_local_scheduler_with_http_info(
         self,
-        body: Annotated[ResourceRequirementsModel, Field(description="resource requirements.")],
+        body: Annotated[LocalSchedulerModel, Field(description="local compute node configuration.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -5163,13 +5164,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ResourceRequirementsModel]:
-        """Store one resource requirements record.
+    ) -> ApiResponse[LocalSchedulerModel]:
+        """Store a local scheduler.
 
-        Store one resource requirements definition.
+        Store a local scheduler. table.
 
-        :param body: resource requirements. (required)
-        :type body: ResourceRequirementsModel
+        :param body: local compute node configuration. (required)
+        :type body: LocalSchedulerModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -5192,7 +5193,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_resource_requirements_serialize(
+        _param = self._create_local_scheduler_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -5201,9 +5202,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResourceRequirementsModel",
-            '404': "NotFoundErrorResponse",
-            '422': "DefaultErrorResponse",
+            '200': "LocalSchedulerModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -5218,9 +5217,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_resource_requirements_without_preload_content(
+    def create_local_scheduler_without_preload_content(
         self,
-        body: Annotated[ResourceRequirementsModel, Field(description="resource requirements.")],
+        body: Annotated[LocalSchedulerModel, Field(description="local compute node configuration.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -5234,12 +5233,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Store one resource requirements record.
+        """Store a local scheduler.
 
-        Store one resource requirements definition.
+        Store a local scheduler. table.
 
-        :param body: resource requirements. (required)
-        :type body: ResourceRequirementsModel
+        :param body: local compute node configuration. (required)
+        :type body: LocalSchedulerModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -5262,7 +5261,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_resource_requirements_serialize(
+        _param = self._create_local_scheduler_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -5271,9 +5270,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResourceRequirementsModel",
-            '404': "NotFoundErrorResponse",
-            '422': "DefaultErrorResponse",
+            '200': "LocalSchedulerModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -5283,7 +5280,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _create_resource_requirements_serialize(
+    def _create_local_scheduler_serialize(
         self,
         body,
         _request_auth,
@@ -5343,7 +5340,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/resource_requirements',
+            resource_path='/local_schedulers',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -5360,9 +5357,10 @@ class DefaultApi:
 
 
     @validate_call
-    def create_result(
+    def create_remote_workers(
         self,
-        body: Annotated[ResultModel, Field(description="result.")],
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        workers: Annotated[List[StrictStr], Field(description="List of remote workers to add")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -5375,13 +5373,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ResultModel:
-        """Store a job result.
+    ) -> List[RemoteWorkerModel]:
+        """Store remote workers for a workflow.
 
-        Store a job result.
+        Store remote workers for a workflow.
 
-        :param body: result. (required)
-        :type body: ResultModel
+        :param id: Workflow ID (required)
+        :type id: int
+        :param workers: List of remote workers to add (required)
+        :type workers: List[str]
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -5404,8 +5404,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_result_serialize(
-            body=body,
+        _param = self._create_remote_workers_serialize(
+            id=id,
+            workers=workers,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -5413,7 +5414,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResultModel",
+            '200': "List[RemoteWorkerModel]",
+            '404': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -5428,9 +5430,10 @@ class DefaultApi:
 
 
     @validate_call
-    def create_result_with_http_info(
+    def create_remote_workers_with_http_info(
         self,
-        body: Annotated[ResultModel, Field(description="result.")],
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        workers: Annotated[List[StrictStr], Field(description="List of remote workers to add")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -5443,13 +5446,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ResultModel]:
-        """Store a job result.
+    ) -> ApiResponse[List[RemoteWorkerModel]]:
+        """Store remote workers for a workflow.
 
-        Store a job result.
+        Store remote workers for a workflow.
 
-        :param body: result. (required)
-        :type body: ResultModel
+        :param id: Workflow ID (required)
+        :type id: int
+        :param workers: List of remote workers to add (required)
+        :type workers: List[str]
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -5472,8 +5477,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_result_serialize(
-            body=body,
+        _param = self._create_remote_workers_serialize(
+            id=id,
+            workers=workers,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -5481,7 +5487,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResultModel",
+            '200': "List[RemoteWorkerModel]",
+            '404': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -5496,9 +5503,10 @@ class DefaultApi:
 
 
     @validate_call
-    def create_result_without_preload_content(
+    def create_remote_workers_without_preload_content(
         self,
-        body: Annotated[ResultModel, Field(description="result.")],
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        workers: Annotated[List[StrictStr], Field(description="List of remote workers to add")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -5512,12 +5520,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Store a job result.
+        """Store remote workers for a workflow.
 
-        Store a job result.
+        Store remote workers for a workflow.
 
-        :param body: result. (required)
-        :type body: ResultModel
+        :param id: Workflow ID (required)
+        :type id: int
+        :param workers: List of remote workers to add (required)
+        :type workers: List[str]
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -5540,8 +5550,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_result_serialize(
-            body=body,
+        _param = self._create_remote_workers_serialize(
+            id=id,
+            workers=workers,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -5549,7 +5560,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResultModel",
+            '200': "List[RemoteWorkerModel]",
+            '404': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -5559,9 +5571,10 @@ class DefaultApi:
         return response_data.response
 
 
-    def _create_result_serialize(
+    def _create_remote_workers_serialize(
         self,
-        body,
+        id,
+        workers,
         _request_auth,
         _content_type,
         _headers,
@@ -5571,6 +5584,7 @@ class DefaultApi:
         _host = None
 
         _collection_formats: Dict[str, str] = {
+            'workers': '',
         }
 
         _path_params: Dict[str, str] = {}
@@ -5583,12 +5597,14 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
         # process the header parameters
         # process the form parameters
         # process the body parameter
-        if body is not None:
-            _body_params = body
+        if workers is not None:
+            _body_params = workers
 
 
         # set the HTTP header `Accept`
@@ -5619,7 +5635,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/results',
+            resource_path='/workflows/{id}/remote_workers',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -5636,9 +5652,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_scheduled_compute_node(
+    def create_resource_requirements(
         self,
-        body: Annotated[ScheduledComputeNodesModel, Field(description="scheduled compute node.")],
+        body: Annotated[ResourceRequirementsModel, Field(description="resource requirements.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -5651,13 +5667,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ScheduledComputeNodesModel:
-        """Store a scheduled compute node.
+    ) -> ResourceRequirementsModel:
+        """Store one resource requirements record.
 
-        Store a scheduled compute node.
+        Store one resource requirements definition.
 
-        :param body: scheduled compute node. (required)
-        :type body: ScheduledComputeNodesModel
+        :param body: resource requirements. (required)
+        :type body: ResourceRequirementsModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -5680,7 +5696,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_scheduled_compute_node_serialize(
+        _param = self._create_resource_requirements_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -5689,7 +5705,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ScheduledComputeNodesModel",
+            '200': "ResourceRequirementsModel",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -5704,9 +5722,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_scheduled_compute_node_with_http_info(
+    def create_resource_requirements_with_http_info(
         self,
-        body: Annotated[ScheduledComputeNodesModel, Field(description="scheduled compute node.")],
+        body: Annotated[ResourceRequirementsModel, Field(description="resource requirements.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -5719,13 +5737,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ScheduledComputeNodesModel]:
-        """Store a scheduled compute node.
+    ) -> ApiResponse[ResourceRequirementsModel]:
+        """Store one resource requirements record.
 
-        Store a scheduled compute node.
+        Store one resource requirements definition.
 
-        :param body: scheduled compute node. (required)
-        :type body: ScheduledComputeNodesModel
+        :param body: resource requirements. (required)
+        :type body: ResourceRequirementsModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -5748,7 +5766,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_scheduled_compute_node_serialize(
+        _param = self._create_resource_requirements_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -5757,7 +5775,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ScheduledComputeNodesModel",
+            '200': "ResourceRequirementsModel",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -5772,9 +5792,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_scheduled_compute_node_without_preload_content(
+    def create_resource_requirements_without_preload_content(
         self,
-        body: Annotated[ScheduledComputeNodesModel, Field(description="scheduled compute node.")],
+        body: Annotated[ResourceRequirementsModel, Field(description="resource requirements.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -5788,12 +5808,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Store a scheduled compute node.
+        """Store one resource requirements record.
 
-        Store a scheduled compute node.
+        Store one resource requirements definition.
 
-        :param body: scheduled compute node. (required)
-        :type body: ScheduledComputeNodesModel
+        :param body: resource requirements. (required)
+        :type body: ResourceRequirementsModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -5816,7 +5836,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_scheduled_compute_node_serialize(
+        _param = self._create_resource_requirements_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -5825,7 +5845,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ScheduledComputeNodesModel",
+            '200': "ResourceRequirementsModel",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -5835,7 +5857,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _create_scheduled_compute_node_serialize(
+    def _create_resource_requirements_serialize(
         self,
         body,
         _request_auth,
@@ -5895,7 +5917,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/scheduled_compute_nodes',
+            resource_path='/resource_requirements',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -5912,9 +5934,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_slurm_scheduler(
+    def create_result(
         self,
-        body: Annotated[SlurmSchedulerModel, Field(description="Slurm compute node configuration.")],
+        body: Annotated[ResultModel, Field(description="result.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -5927,13 +5949,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> SlurmSchedulerModel:
-        """Store a Slurm compute node configuration.
+    ) -> ResultModel:
+        """Store a job result.
 
-        Store a Slurm compute node configuration.
+        Store a job result.
 
-        :param body: Slurm compute node configuration. (required)
-        :type body: SlurmSchedulerModel
+        :param body: result. (required)
+        :type body: ResultModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -5956,7 +5978,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_slurm_scheduler_serialize(
+        _param = self._create_result_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -5965,7 +5987,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "SlurmSchedulerModel",
+            '200': "ResultModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -5980,9 +6002,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_slurm_scheduler_with_http_info(
+    def create_result_with_http_info(
         self,
-        body: Annotated[SlurmSchedulerModel, Field(description="Slurm compute node configuration.")],
+        body: Annotated[ResultModel, Field(description="result.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -5995,13 +6017,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[SlurmSchedulerModel]:
-        """Store a Slurm compute node configuration.
+    ) -> ApiResponse[ResultModel]:
+        """Store a job result.
 
-        Store a Slurm compute node configuration.
+        Store a job result.
 
-        :param body: Slurm compute node configuration. (required)
-        :type body: SlurmSchedulerModel
+        :param body: result. (required)
+        :type body: ResultModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -6024,7 +6046,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_slurm_scheduler_serialize(
+        _param = self._create_result_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -6033,7 +6055,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "SlurmSchedulerModel",
+            '200': "ResultModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -6048,9 +6070,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_slurm_scheduler_without_preload_content(
+    def create_result_without_preload_content(
         self,
-        body: Annotated[SlurmSchedulerModel, Field(description="Slurm compute node configuration.")],
+        body: Annotated[ResultModel, Field(description="result.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -6064,12 +6086,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Store a Slurm compute node configuration.
+        """Store a job result.
 
-        Store a Slurm compute node configuration.
+        Store a job result.
 
-        :param body: Slurm compute node configuration. (required)
-        :type body: SlurmSchedulerModel
+        :param body: result. (required)
+        :type body: ResultModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -6092,7 +6114,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_slurm_scheduler_serialize(
+        _param = self._create_result_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -6101,7 +6123,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "SlurmSchedulerModel",
+            '200': "ResultModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -6111,7 +6133,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _create_slurm_scheduler_serialize(
+    def _create_result_serialize(
         self,
         body,
         _request_auth,
@@ -6171,7 +6193,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/slurm_schedulers',
+            resource_path='/results',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -6188,11 +6210,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_user_data(
+    def create_scheduled_compute_node(
         self,
-        body: Annotated[UserDataModel, Field(description="user data.")],
-        consumer_job_id: Annotated[Optional[StrictInt], Field(description="ID of the job that consumes this user data.")] = None,
-        producer_job_id: Annotated[Optional[StrictInt], Field(description="ID of the job that produces this user data.")] = None,
+        body: Annotated[ScheduledComputeNodesModel, Field(description="scheduled compute node.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -6205,17 +6225,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> UserDataModel:
-        """Store a user data record.
+    ) -> ScheduledComputeNodesModel:
+        """Store a scheduled compute node.
 
-        Store a user data record.
+        Store a scheduled compute node.
 
-        :param body: user data. (required)
-        :type body: UserDataModel
-        :param consumer_job_id: ID of the job that consumes this user data.
-        :type consumer_job_id: int
-        :param producer_job_id: ID of the job that produces this user data.
-        :type producer_job_id: int
+        :param body: scheduled compute node. (required)
+        :type body: ScheduledComputeNodesModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -6238,10 +6254,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_user_data_serialize(
+        _param = self._create_scheduled_compute_node_serialize(
             body=body,
-            consumer_job_id=consumer_job_id,
-            producer_job_id=producer_job_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -6249,8 +6263,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "UserDataModel",
-            '404': "NotFoundErrorResponse",
+            '200': "ScheduledComputeNodesModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -6265,11 +6278,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_user_data_with_http_info(
+    def create_scheduled_compute_node_with_http_info(
         self,
-        body: Annotated[UserDataModel, Field(description="user data.")],
-        consumer_job_id: Annotated[Optional[StrictInt], Field(description="ID of the job that consumes this user data.")] = None,
-        producer_job_id: Annotated[Optional[StrictInt], Field(description="ID of the job that produces this user data.")] = None,
+        body: Annotated[ScheduledComputeNodesModel, Field(description="scheduled compute node.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -6282,17 +6293,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[UserDataModel]:
-        """Store a user data record.
+    ) -> ApiResponse[ScheduledComputeNodesModel]:
+        """Store a scheduled compute node.
 
-        Store a user data record.
+        Store a scheduled compute node.
 
-        :param body: user data. (required)
-        :type body: UserDataModel
-        :param consumer_job_id: ID of the job that consumes this user data.
-        :type consumer_job_id: int
-        :param producer_job_id: ID of the job that produces this user data.
-        :type producer_job_id: int
+        :param body: scheduled compute node. (required)
+        :type body: ScheduledComputeNodesModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -6315,10 +6322,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_user_data_serialize(
+        _param = self._create_scheduled_compute_node_serialize(
             body=body,
-            consumer_job_id=consumer_job_id,
-            producer_job_id=producer_job_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -6326,8 +6331,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "UserDataModel",
-            '404': "NotFoundErrorResponse",
+            '200': "ScheduledComputeNodesModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -6342,11 +6346,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_user_data_without_preload_content(
+    def create_scheduled_compute_node_without_preload_content(
         self,
-        body: Annotated[UserDataModel, Field(description="user data.")],
-        consumer_job_id: Annotated[Optional[StrictInt], Field(description="ID of the job that consumes this user data.")] = None,
-        producer_job_id: Annotated[Optional[StrictInt], Field(description="ID of the job that produces this user data.")] = None,
+        body: Annotated[ScheduledComputeNodesModel, Field(description="scheduled compute node.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -6360,16 +6362,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Store a user data record.
+        """Store a scheduled compute node.
 
-        Store a user data record.
+        Store a scheduled compute node.
 
-        :param body: user data. (required)
-        :type body: UserDataModel
-        :param consumer_job_id: ID of the job that consumes this user data.
-        :type consumer_job_id: int
-        :param producer_job_id: ID of the job that produces this user data.
-        :type producer_job_id: int
+        :param body: scheduled compute node. (required)
+        :type body: ScheduledComputeNodesModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -6392,10 +6390,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_user_data_serialize(
+        _param = self._create_scheduled_compute_node_serialize(
             body=body,
-            consumer_job_id=consumer_job_id,
-            producer_job_id=producer_job_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -6403,8 +6399,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "UserDataModel",
-            '404': "NotFoundErrorResponse",
+            '200': "ScheduledComputeNodesModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -6414,11 +6409,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _create_user_data_serialize(
+    def _create_scheduled_compute_node_serialize(
         self,
         body,
-        consumer_job_id,
-        producer_job_id,
         _request_auth,
         _content_type,
         _headers,
@@ -6441,14 +6434,6 @@ class DefaultApi:
 
         # process the path parameters
         # process the query parameters
-        if consumer_job_id is not None:
-            
-            _query_params.append(('consumer_job_id', consumer_job_id))
-            
-        if producer_job_id is not None:
-            
-            _query_params.append(('producer_job_id', producer_job_id))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -6484,7 +6469,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/user_data',
+            resource_path='/scheduled_compute_nodes',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -6501,9 +6486,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_workflow(
+    def create_slurm_scheduler(
         self,
-        body: Annotated[WorkflowModel, Field(description="Workflow attributes")],
+        body: Annotated[SlurmSchedulerModel, Field(description="Slurm compute node configuration.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -6516,13 +6501,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> WorkflowModel:
-        """Store a workflow.
+    ) -> SlurmSchedulerModel:
+        """Store a Slurm compute node configuration.
 
-        Store a workflow.
+        Store a Slurm compute node configuration.
 
-        :param body: Workflow attributes (required)
-        :type body: WorkflowModel
+        :param body: Slurm compute node configuration. (required)
+        :type body: SlurmSchedulerModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -6545,7 +6530,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_workflow_serialize(
+        _param = self._create_slurm_scheduler_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -6554,7 +6539,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "WorkflowModel",
+            '200': "SlurmSchedulerModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -6569,9 +6554,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_workflow_with_http_info(
+    def create_slurm_scheduler_with_http_info(
         self,
-        body: Annotated[WorkflowModel, Field(description="Workflow attributes")],
+        body: Annotated[SlurmSchedulerModel, Field(description="Slurm compute node configuration.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -6584,13 +6569,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[WorkflowModel]:
-        """Store a workflow.
+    ) -> ApiResponse[SlurmSchedulerModel]:
+        """Store a Slurm compute node configuration.
 
-        Store a workflow.
+        Store a Slurm compute node configuration.
 
-        :param body: Workflow attributes (required)
-        :type body: WorkflowModel
+        :param body: Slurm compute node configuration. (required)
+        :type body: SlurmSchedulerModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -6613,7 +6598,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_workflow_serialize(
+        _param = self._create_slurm_scheduler_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -6622,7 +6607,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "WorkflowModel",
+            '200': "SlurmSchedulerModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -6637,9 +6622,9 @@ class DefaultApi:
 
 
     @validate_call
-    def create_workflow_without_preload_content(
+    def create_slurm_scheduler_without_preload_content(
         self,
-        body: Annotated[WorkflowModel, Field(description="Workflow attributes")],
+        body: Annotated[SlurmSchedulerModel, Field(description="Slurm compute node configuration.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -6653,12 +6638,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Store a workflow.
+        """Store a Slurm compute node configuration.
 
-        Store a workflow.
+        Store a Slurm compute node configuration.
 
-        :param body: Workflow attributes (required)
-        :type body: WorkflowModel
+        :param body: Slurm compute node configuration. (required)
+        :type body: SlurmSchedulerModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -6681,7 +6666,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_workflow_serialize(
+        _param = self._create_slurm_scheduler_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -6690,7 +6675,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "WorkflowModel",
+            '200': "SlurmSchedulerModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -6700,7 +6685,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _create_workflow_serialize(
+    def _create_slurm_scheduler_serialize(
         self,
         body,
         _request_auth,
@@ -6760,7 +6745,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/workflows',
+            resource_path='/slurm_schedulers',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -6777,10 +6762,11 @@ class DefaultApi:
 
 
     @validate_call
-    def create_workflow_action(
+    def create_user_data(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        body: Annotated[WorkflowActionModel, Field(description="Workflow action to create")],
+        body: Annotated[UserDataModel, Field(description="user data.")],
+        consumer_job_id: Annotated[Optional[StrictInt], Field(description="ID of the job that consumes this user data.")] = None,
+        producer_job_id: Annotated[Optional[StrictInt], Field(description="ID of the job that produces this user data.")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -6793,15 +6779,17 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> WorkflowActionModel:
-        """Create a workflow action.
+    ) -> UserDataModel:
+        """Store a user data record.
 
-        Create a workflow action.
+        Store a user data record.
 
-        :param id: Workflow ID (required)
-        :type id: int
-        :param body: Workflow action to create (required)
-        :type body: WorkflowActionModel
+        :param body: user data. (required)
+        :type body: UserDataModel
+        :param consumer_job_id: ID of the job that consumes this user data.
+        :type consumer_job_id: int
+        :param producer_job_id: ID of the job that produces this user data.
+        :type producer_job_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -6824,9 +6812,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_workflow_action_serialize(
-            id=id,
+        _param = self._create_user_data_serialize(
             body=body,
+            consumer_job_id=consumer_job_id,
+            producer_job_id=producer_job_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -6834,8 +6823,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "WorkflowActionModel",
-            '422': "DefaultErrorResponse",
+            '200': "UserDataModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -6850,10 +6839,11 @@ class DefaultApi:
 
 
     @validate_call
-    def create_workflow_action_with_http_info(
+    def create_user_data_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        body: Annotated[WorkflowActionModel, Field(description="Workflow action to create")],
+        body: Annotated[UserDataModel, Field(description="user data.")],
+        consumer_job_id: Annotated[Optional[StrictInt], Field(description="ID of the job that consumes this user data.")] = None,
+        producer_job_id: Annotated[Optional[StrictInt], Field(description="ID of the job that produces this user data.")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -6866,15 +6856,17 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[WorkflowActionModel]:
-        """Create a workflow action.
+    ) -> ApiResponse[UserDataModel]:
+        """Store a user data record.
 
-        Create a workflow action.
+        Store a user data record.
 
-        :param id: Workflow ID (required)
-        :type id: int
-        :param body: Workflow action to create (required)
-        :type body: WorkflowActionModel
+        :param body: user data. (required)
+        :type body: UserDataModel
+        :param consumer_job_id: ID of the job that consumes this user data.
+        :type consumer_job_id: int
+        :param producer_job_id: ID of the job that produces this user data.
+        :type producer_job_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -6897,9 +6889,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_workflow_action_serialize(
-            id=id,
+        _param = self._create_user_data_serialize(
             body=body,
+            consumer_job_id=consumer_job_id,
+            producer_job_id=producer_job_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -6907,8 +6900,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "WorkflowActionModel",
-            '422': "DefaultErrorResponse",
+            '200': "UserDataModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -6923,10 +6916,11 @@ class DefaultApi:
 
 
     @validate_call
-    def create_workflow_action_without_preload_content(
+    def create_user_data_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        body: Annotated[WorkflowActionModel, Field(description="Workflow action to create")],
+        body: Annotated[UserDataModel, Field(description="user data.")],
+        consumer_job_id: Annotated[Optional[StrictInt], Field(description="ID of the job that consumes this user data.")] = None,
+        producer_job_id: Annotated[Optional[StrictInt], Field(description="ID of the job that produces this user data.")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -6940,14 +6934,16 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Create a workflow action.
+        """Store a user data record.
 
-        Create a workflow action.
+        Store a user data record.
 
-        :param id: Workflow ID (required)
-        :type id: int
-        :param body: Workflow action to create (required)
-        :type body: WorkflowActionModel
+        :param body: user data. (required)
+        :type body: UserDataModel
+        :param consumer_job_id: ID of the job that consumes this user data.
+        :type consumer_job_id: int
+        :param producer_job_id: ID of the job that produces this user data.
+        :type producer_job_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -6970,9 +6966,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._create_workflow_action_serialize(
-            id=id,
+        _param = self._create_user_data_serialize(
             body=body,
+            consumer_job_id=consumer_job_id,
+            producer_job_id=producer_job_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -6980,8 +6977,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "WorkflowActionModel",
-            '422': "DefaultErrorResponse",
+            '200': "UserDataModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -6991,10 +6988,11 @@ class DefaultApi:
         return response_data.response
 
 
-    def _create_workflow_action_serialize(
+    def _create_user_data_serialize(
         self,
-        id,
         body,
+        consumer_job_id,
+        producer_job_id,
         _request_auth,
         _content_type,
         _headers,
@@ -7016,9 +7014,15 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
+        if consumer_job_id is not None:
+            
+            _query_params.append(('consumer_job_id', consumer_job_id))
+            
+        if producer_job_id is not None:
+            
+            _query_params.append(('producer_job_id', producer_job_id))
+            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -7054,7 +7058,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/workflows/{id}/actions',
+            resource_path='/user_data',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -7071,10 +7075,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_access_group(
+    def create_workflow(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the access group")],
-        body: Optional[Dict[str, Any]] = None,
+        body: Annotated[WorkflowModel, Field(description="Workflow attributes")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -7087,15 +7090,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> AccessGroupModel:
-        """Delete an access group.
+    ) -> WorkflowModel:
+        """Store a workflow.
 
-        Delete an access group.
+        Store a workflow.
 
-        :param id: ID of the access group (required)
-        :type id: int
-        :param body:
-        :type body: object
+        :param body: Workflow attributes (required)
+        :type body: WorkflowModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -7118,8 +7119,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_access_group_serialize(
-            id=id,
+        _param = self._create_workflow_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -7128,9 +7128,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "AccessGroupModel",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "WorkflowModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -7145,10 +7143,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_access_group_with_http_info(
+    def create_workflow_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the access group")],
-        body: Optional[Dict[str, Any]] = None,
+        body: Annotated[WorkflowModel, Field(description="Workflow attributes")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -7161,15 +7158,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[AccessGroupModel]:
-        """Delete an access group.
+    ) -> ApiResponse[WorkflowModel]:
+        """Store a workflow.
 
-        Delete an access group.
+        Store a workflow.
 
-        :param id: ID of the access group (required)
-        :type id: int
-        :param body:
-        :type body: object
+        :param body: Workflow attributes (required)
+        :type body: WorkflowModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -7192,8 +7187,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_access_group_serialize(
-            id=id,
+        _param = self._create_workflow_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -7202,9 +7196,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "AccessGroupModel",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "WorkflowModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -7219,10 +7211,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_access_group_without_preload_content(
+    def create_workflow_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the access group")],
-        body: Optional[Dict[str, Any]] = None,
+        body: Annotated[WorkflowModel, Field(description="Workflow attributes")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -7236,14 +7227,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete an access group.
+        """Store a workflow.
 
-        Delete an access group.
+        Store a workflow.
 
-        :param id: ID of the access group (required)
-        :type id: int
-        :param body:
-        :type body: object
+        :param body: Workflow attributes (required)
+        :type body: WorkflowModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -7266,8 +7255,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_access_group_serialize(
-            id=id,
+        _param = self._create_workflow_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -7276,9 +7264,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "AccessGroupModel",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "WorkflowModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -7288,9 +7274,8 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_access_group_serialize(
+    def _create_workflow_serialize(
         self,
-        id,
         body,
         _request_auth,
         _content_type,
@@ -7313,8 +7298,6 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
         # process the header parameters
         # process the form parameters
@@ -7350,8 +7333,8 @@ class DefaultApi:
         ]
 
         return self.api_client.param_serialize(
-            method='DELETE',
-            resource_path='/access_groups/{id}',
+            method='POST',
+            resource_path='/workflows',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -7368,10 +7351,10 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_all_user_data(
+    def create_workflow_action(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        body: Optional[Dict[str, Any]] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        body: Annotated[WorkflowActionModel, Field(description="Workflow action to create")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -7384,15 +7367,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> object:
-        """Delete all user data records for one workflow.
+    ) -> WorkflowActionModel:
+        """Create a workflow action.
 
-        Delete all user data records for one workflow.
+        Create a workflow action.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param body:
-        :type body: object
+        :param id: Workflow ID (required)
+        :type id: int
+        :param body: Workflow action to create (required)
+        :type body: WorkflowActionModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -7415,8 +7398,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_all_user_data_serialize(
-            workflow_id=workflow_id,
+        _param = self._create_workflow_action_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -7425,7 +7408,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "WorkflowActionModel",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -7440,10 +7424,10 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_all_user_data_with_http_info(
+    def create_workflow_action_with_http_info(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        body: Optional[Dict[str, Any]] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        body: Annotated[WorkflowActionModel, Field(description="Workflow action to create")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -7456,15 +7440,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[object]:
-        """Delete all user data records for one workflow.
+    ) -> ApiResponse[WorkflowActionModel]:
+        """Create a workflow action.
 
-        Delete all user data records for one workflow.
+        Create a workflow action.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param body:
-        :type body: object
+        :param id: Workflow ID (required)
+        :type id: int
+        :param body: Workflow action to create (required)
+        :type body: WorkflowActionModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -7487,8 +7471,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_all_user_data_serialize(
-            workflow_id=workflow_id,
+        _param = self._create_workflow_action_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -7497,7 +7481,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "WorkflowActionModel",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -7512,10 +7497,10 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_all_user_data_without_preload_content(
+    def create_workflow_action_without_preload_content(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        body: Optional[Dict[str, Any]] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        body: Annotated[WorkflowActionModel, Field(description="Workflow action to create")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -7529,14 +7514,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete all user data records for one workflow.
+        """Create a workflow action.
 
-        Delete all user data records for one workflow.
+        Create a workflow action.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param body:
-        :type body: object
+        :param id: Workflow ID (required)
+        :type id: int
+        :param body: Workflow action to create (required)
+        :type body: WorkflowActionModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -7559,8 +7544,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_all_user_data_serialize(
-            workflow_id=workflow_id,
+        _param = self._create_workflow_action_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -7569,7 +7554,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "WorkflowActionModel",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -7579,9 +7565,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_all_user_data_serialize(
+    def _create_workflow_action_serialize(
         self,
-        workflow_id,
+        id,
         body,
         _request_auth,
         _content_type,
@@ -7604,11 +7590,9 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
-        if workflow_id is not None:
-            
-            _query_params.append(('workflow_id', workflow_id))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -7643,8 +7627,8 @@ class DefaultApi:
         ]
 
         return self.api_client.param_serialize(
-            method='DELETE',
-            resource_path='/user_data',
+            method='POST',
+            resource_path='/workflows/{id}/actions',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -7661,10 +7645,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_compute_node(
+    def create_workflow_schedule(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the compute node")],
-        body: Optional[Dict[str, Any]] = None,
+        body: Annotated[WorkflowScheduleModel, Field(description="Workflow schedule to create")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -7677,15 +7660,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ComputeNodeModel:
-        """Delete a compute node.
+    ) -> WorkflowScheduleModel:
+        """Create a workflow schedule.
 
-        Delete a compute node.
+        Create a schedule that reinitializes and submits a workflow at the times of a cron expression.
 
-        :param id: ID of the compute node (required)
-        :type id: int
-        :param body:
-        :type body: object
+        :param body: Workflow schedule to create (required)
+        :type body: WorkflowScheduleModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -7708,8 +7689,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_compute_node_serialize(
-            id=id,
+        _param = self._create_workflow_schedule_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -7718,7 +7698,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ComputeNodeModel",
+            '200': "WorkflowScheduleModel",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -7733,10 +7715,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_compute_node_with_http_info(
+    def create_workflow_schedule_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the compute node")],
-        body: Optional[Dict[str, Any]] = None,
+        body: Annotated[WorkflowScheduleModel, Field(description="Workflow schedule to create")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -7749,15 +7730,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ComputeNodeModel]:
-        """Delete a compute node.
+    ) -> ApiResponse[WorkflowScheduleModel]:
+        """Create a workflow schedule.
 
-        Delete a compute node.
+        Create a schedule that reinitializes and submits a workflow at the times of a cron expression.
 
-        :param id: ID of the compute node (required)
-        :type id: int
-        :param body:
-        :type body: object
+        :param body: Workflow schedule to create (required)
+        :type body: WorkflowScheduleModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -7780,8 +7759,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_compute_node_serialize(
-            id=id,
+        _param = self._create_workflow_schedule_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -7790,7 +7768,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ComputeNodeModel",
+            '200': "WorkflowScheduleModel",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -7805,10 +7785,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_compute_node_without_preload_content(
+    def create_workflow_schedule_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the compute node")],
-        body: Optional[Dict[str, Any]] = None,
+        body: Annotated[WorkflowScheduleModel, Field(description="Workflow schedule to create")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -7822,14 +7801,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete a compute node.
+        """Create a workflow schedule.
 
-        Delete a compute node.
+        Create a schedule that reinitializes and submits a workflow at the times of a cron expression.
 
-        :param id: ID of the compute node (required)
-        :type id: int
-        :param body:
-        :type body: object
+        :param body: Workflow schedule to create (required)
+        :type body: WorkflowScheduleModel
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -7852,8 +7829,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_compute_node_serialize(
-            id=id,
+        _param = self._create_workflow_schedule_serialize(
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -7862,7 +7838,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ComputeNodeModel",
+            '200': "WorkflowScheduleModel",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -7872,9 +7850,8 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_compute_node_serialize(
+    def _create_workflow_schedule_serialize(
         self,
-        id,
         body,
         _request_auth,
         _content_type,
@@ -7897,8 +7874,6 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
         # process the header parameters
         # process the form parameters
@@ -7934,8 +7909,8 @@ class DefaultApi:
         ]
 
         return self.api_client.param_serialize(
-            method='DELETE',
-            resource_path='/compute_nodes/{id}',
+            method='POST',
+            resource_path='/workflow_schedules',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -7952,9 +7927,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_compute_nodes(
+    def delete_access_group(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the access group")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -7968,13 +7943,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> object:
-        """Delete all compute node records for one workflow.
+    ) -> AccessGroupModel:
+        """Delete an access group.
 
-        Delete all compute node records for one workflow.
+        Delete an access group.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: ID of the access group (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -7999,8 +7974,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_compute_nodes_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_access_group_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -8009,7 +7984,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "AccessGroupModel",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -8024,9 +8001,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_compute_nodes_with_http_info(
+    def delete_access_group_with_http_info(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the access group")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -8040,13 +8017,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[object]:
-        """Delete all compute node records for one workflow.
+    ) -> ApiResponse[AccessGroupModel]:
+        """Delete an access group.
 
-        Delete all compute node records for one workflow.
+        Delete an access group.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: ID of the access group (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -8071,8 +8048,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_compute_nodes_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_access_group_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -8081,7 +8058,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "AccessGroupModel",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -8096,9 +8075,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_compute_nodes_without_preload_content(
+    def delete_access_group_without_preload_content(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the access group")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -8113,12 +8092,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete all compute node records for one workflow.
+        """Delete an access group.
 
-        Delete all compute node records for one workflow.
+        Delete an access group.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: ID of the access group (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -8143,8 +8122,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_compute_nodes_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_access_group_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -8153,7 +8132,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "AccessGroupModel",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -8163,9 +8144,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_compute_nodes_serialize(
+    def _delete_access_group_serialize(
         self,
-        workflow_id,
+        id,
         body,
         _request_auth,
         _content_type,
@@ -8188,11 +8169,9 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
-        if workflow_id is not None:
-            
-            _query_params.append(('workflow_id', workflow_id))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -8228,7 +8207,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/compute_nodes',
+            resource_path='/access_groups/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -8245,9 +8224,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_event(
+    def delete_all_user_data(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the event record.")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -8261,13 +8240,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> EventModel:
-        """Delete an event.
+    ) -> object:
+        """Delete all user data records for one workflow.
 
-        Deletes an event.
+        Delete all user data records for one workflow.
 
-        :param id: ID of the event record. (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -8292,8 +8271,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_event_serialize(
-            id=id,
+        _param = self._delete_all_user_data_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -8302,8 +8281,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "EventModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -8318,9 +8296,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_event_with_http_info(
+    def delete_all_user_data_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the event record.")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -8334,13 +8312,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[EventModel]:
-        """Delete an event.
+    ) -> ApiResponse[object]:
+        """Delete all user data records for one workflow.
 
-        Deletes an event.
+        Delete all user data records for one workflow.
 
-        :param id: ID of the event record. (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -8365,8 +8343,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_event_serialize(
-            id=id,
+        _param = self._delete_all_user_data_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -8375,8 +8353,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "EventModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -8391,9 +8368,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_event_without_preload_content(
+    def delete_all_user_data_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the event record.")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -8408,12 +8385,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete an event.
+        """Delete all user data records for one workflow.
 
-        Deletes an event.
+        Delete all user data records for one workflow.
 
-        :param id: ID of the event record. (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -8438,8 +8415,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_event_serialize(
-            id=id,
+        _param = self._delete_all_user_data_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -8448,8 +8425,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "EventModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -8459,9 +8435,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_event_serialize(
+    def _delete_all_user_data_serialize(
         self,
-        id,
+        workflow_id,
         body,
         _request_auth,
         _content_type,
@@ -8484,9 +8460,11 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -8522,7 +8500,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/events/{id}',
+            resource_path='/user_data',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -8539,9 +8517,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_events(
+    def delete_compute_node(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the compute node")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -8555,13 +8533,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> object:
-        """Delete all events for one workflow.
+    ) -> ComputeNodeModel:
+        """Delete a compute node.
 
-        Delete all events for one workflow.
+        Delete a compute node.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: ID of the compute node (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -8586,8 +8564,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_events_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_compute_node_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -8596,7 +8574,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "ComputeNodeModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -8611,9 +8589,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_events_with_http_info(
+    def delete_compute_node_with_http_info(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the compute node")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -8627,13 +8605,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[object]:
-        """Delete all events for one workflow.
+    ) -> ApiResponse[ComputeNodeModel]:
+        """Delete a compute node.
 
-        Delete all events for one workflow.
+        Delete a compute node.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: ID of the compute node (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -8658,8 +8636,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_events_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_compute_node_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -8668,7 +8646,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "ComputeNodeModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -8683,9 +8661,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_events_without_preload_content(
+    def delete_compute_node_without_preload_content(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the compute node")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -8700,12 +8678,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete all events for one workflow.
+        """Delete a compute node.
 
-        Delete all events for one workflow.
+        Delete a compute node.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: ID of the compute node (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -8730,8 +8708,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_events_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_compute_node_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -8740,7 +8718,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "ComputeNodeModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -8750,9 +8728,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_events_serialize(
+    def _delete_compute_node_serialize(
         self,
-        workflow_id,
+        id,
         body,
         _request_auth,
         _content_type,
@@ -8775,11 +8753,9 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
-        if workflow_id is not None:
-            
-            _query_params.append(('workflow_id', workflow_id))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -8815,7 +8791,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/events',
+            resource_path='/compute_nodes/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -8832,9 +8808,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_failure_handler(
+    def delete_compute_nodes(
         self,
-        id: Annotated[StrictInt, Field(description="Failure handler ID")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -8848,13 +8824,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> FailureHandlerModel:
-        """Delete a failure handler.
+    ) -> object:
+        """Delete all compute node records for one workflow.
 
-        Delete a failure handler.
+        Delete all compute node records for one workflow.
 
-        :param id: Failure handler ID (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -8879,8 +8855,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_failure_handler_serialize(
-            id=id,
+        _param = self._delete_compute_nodes_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -8889,8 +8865,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FailureHandlerModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -8905,9 +8880,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_failure_handler_with_http_info(
+    def delete_compute_nodes_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Failure handler ID")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -8921,13 +8896,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[FailureHandlerModel]:
-        """Delete a failure handler.
+    ) -> ApiResponse[object]:
+        """Delete all compute node records for one workflow.
 
-        Delete a failure handler.
+        Delete all compute node records for one workflow.
 
-        :param id: Failure handler ID (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -8952,8 +8927,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_failure_handler_serialize(
-            id=id,
+        _param = self._delete_compute_nodes_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -8962,8 +8937,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FailureHandlerModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -8978,9 +8952,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_failure_handler_without_preload_content(
+    def delete_compute_nodes_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Failure handler ID")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -8995,12 +8969,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete a failure handler.
+        """Delete all compute node records for one workflow.
 
-        Delete a failure handler.
+        Delete all compute node records for one workflow.
 
-        :param id: Failure handler ID (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -9025,8 +8999,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_failure_handler_serialize(
-            id=id,
+        _param = self._delete_compute_nodes_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -9035,8 +9009,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FailureHandlerModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -9046,9 +9019,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_failure_handler_serialize(
+    def _delete_compute_nodes_serialize(
         self,
-        id,
+        workflow_id,
         body,
         _request_auth,
         _content_type,
@@ -9071,9 +9044,11 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -9109,7 +9084,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/failure_handlers/{id}',
+            resource_path='/compute_nodes',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -9126,9 +9101,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_file(
+    def delete_event(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the file record.")],
+        id: Annotated[StrictInt, Field(description="ID of the event record.")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -9142,12 +9117,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> FileModel:
-        """Delete a file.
+    ) -> EventModel:
+        """Delete an event.
 
-        Delete a file.
+        Deletes an event.
 
-        :param id: ID of the file record. (required)
+        :param id: ID of the event record. (required)
         :type id: int
         :param body:
         :type body: object
@@ -9173,7 +9148,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_file_serialize(
+        _param = self._delete_event_serialize(
             id=id,
             body=body,
             _request_auth=_request_auth,
@@ -9183,7 +9158,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FileModel",
+            '200': "EventModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -9199,9 +9174,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_file_with_http_info(
+    def delete_event_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the file record.")],
+        id: Annotated[StrictInt, Field(description="ID of the event record.")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -9215,12 +9190,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[FileModel]:
-        """Delete a file.
+    ) -> ApiResponse[EventModel]:
+        """Delete an event.
 
-        Delete a file.
+        Deletes an event.
 
-        :param id: ID of the file record. (required)
+        :param id: ID of the event record. (required)
         :type id: int
         :param body:
         :type body: object
@@ -9246,7 +9221,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_file_serialize(
+        _param = self._delete_event_serialize(
             id=id,
             body=body,
             _request_auth=_request_auth,
@@ -9256,7 +9231,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FileModel",
+            '200': "EventModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -9272,9 +9247,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_file_without_preload_content(
+    def delete_event_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the file record.")],
+        id: Annotated[StrictInt, Field(description="ID of the event record.")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -9289,11 +9264,11 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete a file.
+        """Delete an event.
 
-        Delete a file.
+        Deletes an event.
 
-        :param id: ID of the file record. (required)
+        :param id: ID of the event record. (required)
         :type id: int
         :param body:
         :type body: object
@@ -9319,7 +9294,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_file_serialize(
+        _param = self._delete_event_serialize(
             id=id,
             body=body,
             _request_auth=_request_auth,
@@ -9329,7 +9304,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FileModel",
+            '200': "EventModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -9340,7 +9315,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_file_serialize(
+    def _delete_event_serialize(
         self,
         id,
         body,
@@ -9403,7 +9378,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/files/{id}',
+            resource_path='/events/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -9420,7 +9395,7 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_files(
+    def delete_events(
         self,
         workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
@@ -9437,9 +9412,9 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> object:
-        """Delete all files for one workflow.
+        """Delete all events for one workflow.
 
-        Delete all files for one workflow.
+        Delete all events for one workflow.
 
         :param workflow_id: Workflow ID (required)
         :type workflow_id: int
@@ -9467,7 +9442,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_files_serialize(
+        _param = self._delete_events_serialize(
             workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
@@ -9492,7 +9467,7 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_files_with_http_info(
+    def delete_events_with_http_info(
         self,
         workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
@@ -9509,9 +9484,9 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> ApiResponse[object]:
-        """Delete all files for one workflow.
+        """Delete all events for one workflow.
 
-        Delete all files for one workflow.
+        Delete all events for one workflow.
 
         :param workflow_id: Workflow ID (required)
         :type workflow_id: int
@@ -9539,7 +9514,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_files_serialize(
+        _param = self._delete_events_serialize(
             workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
@@ -9564,7 +9539,7 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_files_without_preload_content(
+    def delete_events_without_preload_content(
         self,
         workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
@@ -9581,9 +9556,9 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete all files for one workflow.
+        """Delete all events for one workflow.
 
-        Delete all files for one workflow.
+        Delete all events for one workflow.
 
         :param workflow_id: Workflow ID (required)
         :type workflow_id: int
@@ -9611,7 +9586,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_files_serialize(
+        _param = self._delete_events_serialize(
             workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
@@ -9631,7 +9606,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_files_serialize(
+    def _delete_events_serialize(
         self,
         workflow_id,
         body,
@@ -9696,7 +9671,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/files',
+            resource_path='/events',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -9713,9 +9688,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_job(
+    def delete_failure_handler(
         self,
-        id: Annotated[StrictInt, Field(description="Job ID")],
+        id: Annotated[StrictInt, Field(description="Failure handler ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -9729,12 +9704,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> JobModel:
-        """Delete a job.
+    ) -> FailureHandlerModel:
+        """Delete a failure handler.
 
-        Delete a job.
+        Delete a failure handler.
 
-        :param id: Job ID (required)
+        :param id: Failure handler ID (required)
         :type id: int
         :param body:
         :type body: object
@@ -9760,7 +9735,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_job_serialize(
+        _param = self._delete_failure_handler_serialize(
             id=id,
             body=body,
             _request_auth=_request_auth,
@@ -9770,8 +9745,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "JobModel",
-            '403': "ForbiddenErrorResponse",
+            '200': "FailureHandlerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -9787,9 +9761,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_job_with_http_info(
+    def delete_failure_handler_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Job ID")],
+        id: Annotated[StrictInt, Field(description="Failure handler ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -9803,12 +9777,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[JobModel]:
-        """Delete a job.
+    ) -> ApiResponse[FailureHandlerModel]:
+        """Delete a failure handler.
 
-        Delete a job.
+        Delete a failure handler.
 
-        :param id: Job ID (required)
+        :param id: Failure handler ID (required)
         :type id: int
         :param body:
         :type body: object
@@ -9834,7 +9808,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_job_serialize(
+        _param = self._delete_failure_handler_serialize(
             id=id,
             body=body,
             _request_auth=_request_auth,
@@ -9844,8 +9818,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "JobModel",
-            '403': "ForbiddenErrorResponse",
+            '200': "FailureHandlerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -9861,9 +9834,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_job_without_preload_content(
+    def delete_failure_handler_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Job ID")],
+        id: Annotated[StrictInt, Field(description="Failure handler ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -9878,11 +9851,11 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete a job.
+        """Delete a failure handler.
 
-        Delete a job.
+        Delete a failure handler.
 
-        :param id: Job ID (required)
+        :param id: Failure handler ID (required)
         :type id: int
         :param body:
         :type body: object
@@ -9908,7 +9881,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_job_serialize(
+        _param = self._delete_failure_handler_serialize(
             id=id,
             body=body,
             _request_auth=_request_auth,
@@ -9918,8 +9891,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "JobModel",
-            '403': "ForbiddenErrorResponse",
+            '200': "FailureHandlerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -9930,7 +9902,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_job_serialize(
+    def _delete_failure_handler_serialize(
         self,
         id,
         body,
@@ -9993,7 +9965,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/jobs/{id}',
+            resource_path='/failure_handlers/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -10010,9 +9982,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_jobs(
+    def delete_file(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the file record.")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -10026,13 +9998,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> object:
-        """Delete all jobs for one workflow.
+    ) -> FileModel:
+        """Delete a file.
 
-        Delete all jobs for one workflow.
+        Delete a file.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: ID of the file record. (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -10057,8 +10029,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_jobs_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_file_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -10067,7 +10039,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "FileModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -10082,9 +10055,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_jobs_with_http_info(
+    def delete_file_with_http_info(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the file record.")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -10098,13 +10071,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[object]:
-        """Delete all jobs for one workflow.
+    ) -> ApiResponse[FileModel]:
+        """Delete a file.
 
-        Delete all jobs for one workflow.
+        Delete a file.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: ID of the file record. (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -10129,8 +10102,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_jobs_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_file_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -10139,7 +10112,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "FileModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -10154,9 +10128,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_jobs_without_preload_content(
+    def delete_file_without_preload_content(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the file record.")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -10171,12 +10145,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete all jobs for one workflow.
+        """Delete a file.
 
-        Delete all jobs for one workflow.
+        Delete a file.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: ID of the file record. (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -10201,8 +10175,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_jobs_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_file_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -10211,7 +10185,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "FileModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -10221,9 +10196,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_jobs_serialize(
+    def _delete_file_serialize(
         self,
-        workflow_id,
+        id,
         body,
         _request_auth,
         _content_type,
@@ -10246,11 +10221,9 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
-        if workflow_id is not None:
-            
-            _query_params.append(('workflow_id', workflow_id))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -10286,7 +10259,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/jobs',
+            resource_path='/files/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -10303,9 +10276,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_local_scheduler(
+    def delete_files(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the local compute node configuration record.")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -10319,13 +10292,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> LocalSchedulerModel:
-        """Delete a local scheduler.
+    ) -> object:
+        """Delete all files for one workflow.
 
-        Delete a local scheduler.
+        Delete all files for one workflow.
 
-        :param id: ID of the local compute node configuration record. (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -10350,8 +10323,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_local_scheduler_serialize(
-            id=id,
+        _param = self._delete_files_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -10360,7 +10333,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "LocalSchedulerModel",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -10375,9 +10348,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_local_scheduler_with_http_info(
+    def delete_files_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the local compute node configuration record.")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -10391,13 +10364,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[LocalSchedulerModel]:
-        """Delete a local scheduler.
+    ) -> ApiResponse[object]:
+        """Delete all files for one workflow.
 
-        Delete a local scheduler.
+        Delete all files for one workflow.
 
-        :param id: ID of the local compute node configuration record. (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -10422,8 +10395,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_local_scheduler_serialize(
-            id=id,
+        _param = self._delete_files_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -10432,7 +10405,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "LocalSchedulerModel",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -10447,9 +10420,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_local_scheduler_without_preload_content(
+    def delete_files_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the local compute node configuration record.")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -10464,12 +10437,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete a local scheduler.
+        """Delete all files for one workflow.
 
-        Delete a local scheduler.
+        Delete all files for one workflow.
 
-        :param id: ID of the local compute node configuration record. (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -10494,8 +10467,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_local_scheduler_serialize(
-            id=id,
+        _param = self._delete_files_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -10504,7 +10477,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "LocalSchedulerModel",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -10514,9 +10487,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_local_scheduler_serialize(
+    def _delete_files_serialize(
         self,
-        id,
+        workflow_id,
         body,
         _request_auth,
         _content_type,
@@ -10539,9 +10512,11 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -10577,7 +10552,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/local_schedulers/{id}',
+            resource_path='/files',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -10594,9 +10569,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_local_schedulers(
+    def delete_job(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="Job ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -10610,13 +10585,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> object:
-        """Delete all local schedulers for one workflow.
+    ) -> JobModel:
+        """Delete a job.
 
-        Delete all local schedulers for one workflow.
+        Delete a job.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: Job ID (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -10641,8 +10616,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_local_schedulers_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_job_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -10651,7 +10626,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "JobModel",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -10666,9 +10643,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_local_schedulers_with_http_info(
+    def delete_job_with_http_info(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="Job ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -10682,13 +10659,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[object]:
-        """Delete all local schedulers for one workflow.
+    ) -> ApiResponse[JobModel]:
+        """Delete a job.
 
-        Delete all local schedulers for one workflow.
+        Delete a job.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: Job ID (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -10713,8 +10690,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_local_schedulers_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_job_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -10723,7 +10700,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "JobModel",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -10738,9 +10717,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_local_schedulers_without_preload_content(
+    def delete_job_without_preload_content(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="Job ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -10755,12 +10734,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete all local schedulers for one workflow.
+        """Delete a job.
 
-        Delete all local schedulers for one workflow.
+        Delete a job.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: Job ID (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -10785,8 +10764,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_local_schedulers_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_job_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -10795,7 +10774,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "JobModel",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -10805,9 +10786,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_local_schedulers_serialize(
+    def _delete_job_serialize(
         self,
-        workflow_id,
+        id,
         body,
         _request_auth,
         _content_type,
@@ -10830,11 +10811,9 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
-        if workflow_id is not None:
-            
-            _query_params.append(('workflow_id', workflow_id))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -10870,7 +10849,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/local_schedulers',
+            resource_path='/jobs/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -10887,10 +10866,10 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_remote_worker(
+    def delete_jobs(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        worker: Annotated[StrictStr, Field(description="Worker address (URL-encoded)")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -10903,15 +10882,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> RemoteWorkerModel:
-        """Delete a remote worker from a workflow.
+    ) -> object:
+        """Delete all jobs for one workflow.
 
-        Delete a remote worker from a workflow.
+        Delete all jobs for one workflow.
 
-        :param id: Workflow ID (required)
-        :type id: int
-        :param worker: Worker address (URL-encoded) (required)
-        :type worker: str
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -10934,9 +10913,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_remote_worker_serialize(
-            id=id,
-            worker=worker,
+        _param = self._delete_jobs_serialize(
+            workflow_id=workflow_id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -10944,8 +10923,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "RemoteWorkerModel",
-            '404': "DefaultErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -10960,10 +10938,10 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_remote_worker_with_http_info(
+    def delete_jobs_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        worker: Annotated[StrictStr, Field(description="Worker address (URL-encoded)")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -10976,15 +10954,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[RemoteWorkerModel]:
-        """Delete a remote worker from a workflow.
+    ) -> ApiResponse[object]:
+        """Delete all jobs for one workflow.
 
-        Delete a remote worker from a workflow.
+        Delete all jobs for one workflow.
 
-        :param id: Workflow ID (required)
-        :type id: int
-        :param worker: Worker address (URL-encoded) (required)
-        :type worker: str
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -11007,9 +10985,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_remote_worker_serialize(
-            id=id,
-            worker=worker,
+        _param = self._delete_jobs_serialize(
+            workflow_id=workflow_id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -11017,8 +10995,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "RemoteWorkerModel",
-            '404': "DefaultErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -11033,10 +11010,10 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_remote_worker_without_preload_content(
+    def delete_jobs_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        worker: Annotated[StrictStr, Field(description="Worker address (URL-encoded)")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -11050,14 +11027,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete a remote worker from a workflow.
+        """Delete all jobs for one workflow.
 
-        Delete a remote worker from a workflow.
+        Delete all jobs for one workflow.
 
-        :param id: Workflow ID (required)
-        :type id: int
-        :param worker: Worker address (URL-encoded) (required)
-        :type worker: str
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -11080,9 +11057,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_remote_worker_serialize(
-            id=id,
-            worker=worker,
+        _param = self._delete_jobs_serialize(
+            workflow_id=workflow_id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -11090,8 +11067,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "RemoteWorkerModel",
-            '404': "DefaultErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -11101,10 +11077,10 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_remote_worker_serialize(
+    def _delete_jobs_serialize(
         self,
-        id,
-        worker,
+        workflow_id,
+        body,
         _request_auth,
         _content_type,
         _headers,
@@ -11126,14 +11102,16 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
-        if worker is not None:
-            _path_params['worker'] = worker
         # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
         # process the header parameters
         # process the form parameters
         # process the body parameter
+        if body is not None:
+            _body_params = body
 
 
         # set the HTTP header `Accept`
@@ -11144,6 +11122,19 @@ class DefaultApi:
                 ]
             )
 
+        # set the HTTP header `Content-Type`
+        if _content_type:
+            _header_params['Content-Type'] = _content_type
+        else:
+            _default_content_type = (
+                self.api_client.select_header_content_type(
+                    [
+                        'application/json'
+                    ]
+                )
+            )
+            if _default_content_type is not None:
+                _header_params['Content-Type'] = _default_content_type
 
         # authentication setting
         _auth_settings: List[str] = [
@@ -11151,7 +11142,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/workflows/{id}/remote_workers/{worker}',
+            resource_path='/jobs',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -11168,9 +11159,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_resource_requirement(
+    def delete_local_scheduler(
         self,
-        id: Annotated[StrictInt, Field(description="Resource requirements ID")],
+        id: Annotated[StrictInt, Field(description="ID of the local compute node configuration record.")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -11184,12 +11175,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ResourceRequirementsModel:
-        """Delete a resource requirements record.
+    ) -> LocalSchedulerModel:
+        """Delete a local scheduler.
 
-        Delete a resource requirements record.
+        Delete a local scheduler.
 
-        :param id: Resource requirements ID (required)
+        :param id: ID of the local compute node configuration record. (required)
         :type id: int
         :param body:
         :type body: object
@@ -11215,7 +11206,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_resource_requirement_serialize(
+        _param = self._delete_local_scheduler_serialize(
             id=id,
             body=body,
             _request_auth=_request_auth,
@@ -11225,8 +11216,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResourceRequirementsModel",
-            '404': "NotFoundErrorResponse",
+            '200': "LocalSchedulerModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -11241,9 +11231,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_resource_requirement_with_http_info(
+    def delete_local_scheduler_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Resource requirements ID")],
+        id: Annotated[StrictInt, Field(description="ID of the local compute node configuration record.")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -11257,12 +11247,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ResourceRequirementsModel]:
-        """Delete a resource requirements record.
+    ) -> ApiResponse[LocalSchedulerModel]:
+        """Delete a local scheduler.
 
-        Delete a resource requirements record.
+        Delete a local scheduler.
 
-        :param id: Resource requirements ID (required)
+        :param id: ID of the local compute node configuration record. (required)
         :type id: int
         :param body:
         :type body: object
@@ -11288,7 +11278,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_resource_requirement_serialize(
+        _param = self._delete_local_scheduler_serialize(
             id=id,
             body=body,
             _request_auth=_request_auth,
@@ -11298,8 +11288,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResourceRequirementsModel",
-            '404': "NotFoundErrorResponse",
+            '200': "LocalSchedulerModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -11314,9 +11303,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_resource_requirement_without_preload_content(
+    def delete_local_scheduler_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Resource requirements ID")],
+        id: Annotated[StrictInt, Field(description="ID of the local compute node configuration record.")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -11331,11 +11320,11 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete a resource requirements record.
+        """Delete a local scheduler.
 
-        Delete a resource requirements record.
+        Delete a local scheduler.
 
-        :param id: Resource requirements ID (required)
+        :param id: ID of the local compute node configuration record. (required)
         :type id: int
         :param body:
         :type body: object
@@ -11361,7 +11350,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_resource_requirement_serialize(
+        _param = self._delete_local_scheduler_serialize(
             id=id,
             body=body,
             _request_auth=_request_auth,
@@ -11371,8 +11360,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResourceRequirementsModel",
-            '404': "NotFoundErrorResponse",
+            '200': "LocalSchedulerModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -11382,7 +11370,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_resource_requirement_serialize(
+    def _delete_local_scheduler_serialize(
         self,
         id,
         body,
@@ -11445,7 +11433,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/resource_requirements/{id}',
+            resource_path='/local_schedulers/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -11462,7 +11450,7 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_resource_requirements(
+    def delete_local_schedulers(
         self,
         workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
@@ -11479,9 +11467,9 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> object:
-        """Delete all resource requirements records for one workflow.
+        """Delete all local schedulers for one workflow.
 
-        Delete all resource requirements records for one workflow.
+        Delete all local schedulers for one workflow.
 
         :param workflow_id: Workflow ID (required)
         :type workflow_id: int
@@ -11509,7 +11497,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_resource_requirements_serialize(
+        _param = self._delete_local_schedulers_serialize(
             workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
@@ -11534,7 +11522,7 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_resource_requirements_with_http_info(
+    def delete_local_schedulers_with_http_info(
         self,
         workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
@@ -11551,81 +11539,9 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> ApiResponse[object]:
-        """Delete all resource requirements records for one workflow.
-
-        Delete all resource requirements records for one workflow.
-
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param body:
-        :type body: object
-        :param _request_timeout: timeout setting for this request. If one
-                                 number provided, it will be total request
-                                 timeout. It can also be a pair (tuple) of
-                                 (connection, read) timeouts.
-        :type _request_timeout: int, tuple(int, int), optional
-        :param _request_auth: set to override the auth_settings for an a single
-                              request; this effectively ignores the
-                              authentication in the spec for a single request.
-        :type _request_auth: dict, optional
-        :param _content_type: force content-type for the request.
-        :type _content_type: str, Optional
-        :param _headers: set to override the headers for a single
-                         request; this effectively ignores the headers
-                         in the spec for a single request.
-        :type _headers: dict, optional
-        :param _host_index: set to override the host_index for a single
-                            request; this effectively ignores the host_index
-                            in the spec for a single request.
-        :type _host_index: int, optional
-        :return: Returns the result object.
-        """ # noqa: E501
-
-        _param = self._delete_resource_requirements_serialize(
-            workflow_id=workflow_id,
-            body=body,
-            _request_auth=_request_auth,
-            _content_type=_content_type,
-            _headers=_headers,
-            _host_index=_host_index
-        )
-
-        _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
-            '500': "DefaultErrorResponse",
-        }
-        response_data = self.api_client.call_api(
-            *_param,
-            _request_timeout=_request_timeout
-        )
-        response_data.read()
-        return self.api_client.response_deserialize(
-            response_data=response_data,
-            response_types_map=_response_types_map,
-        )
-
-
-    @validate_call
-    def delete_resource_requirements_without_preload_content(
-        self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        body: Optional[Dict[str, Any]] = None,
-        _request_timeout: Union[
-            None,
-            Annotated[StrictFloat, Field(gt=0)],
-            Tuple[
-                Annotated[StrictFloat, Field(gt=0)],
-                Annotated[StrictFloat, Field(gt=0)]
-            ]
-        ] = None,
-        _request_auth: Optional[Dict[StrictStr, Any]] = None,
-        _content_type: Optional[StrictStr] = None,
-        _headers: Optional[Dict[StrictStr, Any]] = None,
-        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> RESTResponseType:
-        """Delete all resource requirements records for one workflow.
+        """Delete all local schedulers for one workflow.
 
-        Delete all resource requirements records for one workflow.
+        Delete all local schedulers for one workflow.
 
         :param workflow_id: Workflow ID (required)
         :type workflow_id: int
@@ -11653,7 +11569,79 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_resource_requirements_serialize(
+        _param = self._delete_local_schedulers_serialize(
+            workflow_id=workflow_id,
+            body=body,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "object",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def delete_local_schedulers_without_preload_content(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        body: Optional[Dict[str, Any]] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """Delete all local schedulers for one workflow.
+
+        Delete all local schedulers for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param body:
+        :type body: object
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._delete_local_schedulers_serialize(
             workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
@@ -11673,7 +11661,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_resource_requirements_serialize(
+    def _delete_local_schedulers_serialize(
         self,
         workflow_id,
         body,
@@ -11738,7 +11726,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/resource_requirements',
+            resource_path='/local_schedulers',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -11755,10 +11743,10 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_result(
+    def delete_remote_worker(
         self,
-        id: Annotated[StrictInt, Field(description="Results ID")],
-        body: Optional[Dict[str, Any]] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        worker: Annotated[StrictStr, Field(description="Worker address (URL-encoded)")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -11771,15 +11759,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ResultModel:
-        """Delete a job result.
+    ) -> RemoteWorkerModel:
+        """Delete a remote worker from a workflow.
 
-        Delete a job result.
+        Delete a remote worker from a workflow.
 
-        :param id: Results ID (required)
+        :param id: Workflow ID (required)
         :type id: int
-        :param body:
-        :type body: object
+        :param worker: Worker address (URL-encoded) (required)
+        :type worker: str
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -11802,9 +11790,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_result_serialize(
+        _param = self._delete_remote_worker_serialize(
             id=id,
-            body=body,
+            worker=worker,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -11812,8 +11800,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResultModel",
-            '404': "NotFoundErrorResponse",
+            '200': "RemoteWorkerModel",
+            '404': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -11828,10 +11816,10 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_result_with_http_info(
+    def delete_remote_worker_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Results ID")],
-        body: Optional[Dict[str, Any]] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        worker: Annotated[StrictStr, Field(description="Worker address (URL-encoded)")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -11844,15 +11832,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ResultModel]:
-        """Delete a job result.
+    ) -> ApiResponse[RemoteWorkerModel]:
+        """Delete a remote worker from a workflow.
 
-        Delete a job result.
+        Delete a remote worker from a workflow.
 
-        :param id: Results ID (required)
+        :param id: Workflow ID (required)
         :type id: int
-        :param body:
-        :type body: object
+        :param worker: Worker address (URL-encoded) (required)
+        :type worker: str
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -11875,9 +11863,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_result_serialize(
+        _param = self._delete_remote_worker_serialize(
             id=id,
-            body=body,
+            worker=worker,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -11885,8 +11873,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResultModel",
-            '404': "NotFoundErrorResponse",
+            '200': "RemoteWorkerModel",
+            '404': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -11901,10 +11889,10 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_result_without_preload_content(
+    def delete_remote_worker_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Results ID")],
-        body: Optional[Dict[str, Any]] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        worker: Annotated[StrictStr, Field(description="Worker address (URL-encoded)")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -11918,14 +11906,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete a job result.
+        """Delete a remote worker from a workflow.
 
-        Delete a job result.
+        Delete a remote worker from a workflow.
 
-        :param id: Results ID (required)
+        :param id: Workflow ID (required)
         :type id: int
-        :param body:
-        :type body: object
+        :param worker: Worker address (URL-encoded) (required)
+        :type worker: str
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -11948,9 +11936,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_result_serialize(
+        _param = self._delete_remote_worker_serialize(
             id=id,
-            body=body,
+            worker=worker,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -11958,8 +11946,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResultModel",
-            '404': "NotFoundErrorResponse",
+            '200': "RemoteWorkerModel",
+            '404': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -11969,10 +11957,10 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_result_serialize(
+    def _delete_remote_worker_serialize(
         self,
         id,
-        body,
+        worker,
         _request_auth,
         _content_type,
         _headers,
@@ -11996,12 +11984,12 @@ class DefaultApi:
         # process the path parameters
         if id is not None:
             _path_params['id'] = id
+        if worker is not None:
+            _path_params['worker'] = worker
         # process the query parameters
         # process the header parameters
         # process the form parameters
         # process the body parameter
-        if body is not None:
-            _body_params = body
 
 
         # set the HTTP header `Accept`
@@ -12012,19 +12000,6 @@ class DefaultApi:
                 ]
             )
 
-        # set the HTTP header `Content-Type`
-        if _content_type:
-            _header_params['Content-Type'] = _content_type
-        else:
-            _default_content_type = (
-                self.api_client.select_header_content_type(
-                    [
-                        'application/json'
-                    ]
-                )
-            )
-            if _default_content_type is not None:
-                _header_params['Content-Type'] = _default_content_type
 
         # authentication setting
         _auth_settings: List[str] = [
@@ -12032,7 +12007,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/results/{id}',
+            resource_path='/workflows/{id}/remote_workers/{worker}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -12049,9 +12024,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_results(
+    def delete_resource_requirement(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="Resource requirements ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -12065,13 +12040,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> object:
-        """Delete all job results for one workflow.
+    ) -> ResourceRequirementsModel:
+        """Delete a resource requirements record.
 
-        Delete all job results for one workflow.
+        Delete a resource requirements record.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: Resource requirements ID (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -12096,8 +12071,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_results_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_resource_requirement_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -12106,7 +12081,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "ResourceRequirementsModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -12121,9 +12097,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_results_with_http_info(
+    def delete_resource_requirement_with_http_info(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="Resource requirements ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -12137,13 +12113,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[object]:
-        """Delete all job results for one workflow.
+    ) -> ApiResponse[ResourceRequirementsModel]:
+        """Delete a resource requirements record.
 
-        Delete all job results for one workflow.
+        Delete a resource requirements record.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: Resource requirements ID (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -12168,8 +12144,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_results_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_resource_requirement_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -12178,7 +12154,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "ResourceRequirementsModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -12193,9 +12170,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_results_without_preload_content(
+    def delete_resource_requirement_without_preload_content(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="Resource requirements ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -12210,12 +12187,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete all job results for one workflow.
+        """Delete a resource requirements record.
 
-        Delete all job results for one workflow.
+        Delete a resource requirements record.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: Resource requirements ID (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -12240,8 +12217,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_results_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_resource_requirement_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -12250,7 +12227,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "ResourceRequirementsModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -12260,9 +12238,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_results_serialize(
+    def _delete_resource_requirement_serialize(
         self,
-        workflow_id,
+        id,
         body,
         _request_auth,
         _content_type,
@@ -12285,11 +12263,9 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
-        if workflow_id is not None:
-            
-            _query_params.append(('workflow_id', workflow_id))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -12325,7 +12301,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/results',
+            resource_path='/resource_requirements/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -12342,9 +12318,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_scheduled_compute_node(
+    def delete_resource_requirements(
         self,
-        id: Annotated[StrictInt, Field(description="Scheduled compute node ID")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -12358,13 +12334,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ScheduledComputeNodesModel:
-        """Delete a scheduled compute node.
+    ) -> object:
+        """Delete all resource requirements records for one workflow.
 
-        Delete a scheduled compute node.
+        Delete all resource requirements records for one workflow.
 
-        :param id: Scheduled compute node ID (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -12389,8 +12365,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_scheduled_compute_node_serialize(
-            id=id,
+        _param = self._delete_resource_requirements_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -12399,8 +12375,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ScheduledComputeNodesModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -12415,9 +12390,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_scheduled_compute_node_with_http_info(
+    def delete_resource_requirements_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Scheduled compute node ID")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -12431,13 +12406,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ScheduledComputeNodesModel]:
-        """Delete a scheduled compute node.
+    ) -> ApiResponse[object]:
+        """Delete all resource requirements records for one workflow.
 
-        Delete a scheduled compute node.
+        Delete all resource requirements records for one workflow.
 
-        :param id: Scheduled compute node ID (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -12462,8 +12437,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_scheduled_compute_node_serialize(
-            id=id,
+        _param = self._delete_resource_requirements_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -12472,8 +12447,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ScheduledComputeNodesModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -12488,9 +12462,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_scheduled_compute_node_without_preload_content(
+    def delete_resource_requirements_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Scheduled compute node ID")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -12505,12 +12479,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete a scheduled compute node.
+        """Delete all resource requirements records for one workflow.
 
-        Delete a scheduled compute node.
+        Delete all resource requirements records for one workflow.
 
-        :param id: Scheduled compute node ID (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -12535,8 +12509,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_scheduled_compute_node_serialize(
-            id=id,
+        _param = self._delete_resource_requirements_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -12545,8 +12519,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ScheduledComputeNodesModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -12556,9 +12529,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_scheduled_compute_node_serialize(
+    def _delete_resource_requirements_serialize(
         self,
-        id,
+        workflow_id,
         body,
         _request_auth,
         _content_type,
@@ -12581,9 +12554,11 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -12619,7 +12594,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/scheduled_compute_nodes/{id}',
+            resource_path='/resource_requirements',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -12636,9 +12611,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_scheduled_compute_nodes(
+    def delete_result(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="Results ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -12652,13 +12627,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> object:
-        """Delete all scheduled compute node records for one workflow.
+    ) -> ResultModel:
+        """Delete a job result.
 
-        Delete all scheduled compute node records for one workflow.
+        Delete a job result.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: Results ID (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -12683,8 +12658,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_scheduled_compute_nodes_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_result_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -12693,7 +12668,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "ResultModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -12708,9 +12684,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_scheduled_compute_nodes_with_http_info(
+    def delete_result_with_http_info(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="Results ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -12724,13 +12700,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[object]:
-        """Delete all scheduled compute node records for one workflow.
+    ) -> ApiResponse[ResultModel]:
+        """Delete a job result.
 
-        Delete all scheduled compute node records for one workflow.
+        Delete a job result.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: Results ID (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -12755,8 +12731,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_scheduled_compute_nodes_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_result_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -12765,7 +12741,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "ResultModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -12780,9 +12757,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_scheduled_compute_nodes_without_preload_content(
+    def delete_result_without_preload_content(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="Results ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -12797,12 +12774,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete all scheduled compute node records for one workflow.
+        """Delete a job result.
 
-        Delete all scheduled compute node records for one workflow.
+        Delete a job result.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: Results ID (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -12827,8 +12804,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_scheduled_compute_nodes_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_result_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -12837,7 +12814,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "ResultModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -12847,9 +12825,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_scheduled_compute_nodes_serialize(
+    def _delete_result_serialize(
         self,
-        workflow_id,
+        id,
         body,
         _request_auth,
         _content_type,
@@ -12872,11 +12850,9 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
-        if workflow_id is not None:
-            
-            _query_params.append(('workflow_id', workflow_id))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -12912,7 +12888,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/scheduled_compute_nodes',
+            resource_path='/results/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -12929,9 +12905,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_slurm_scheduler(
+    def delete_results(
         self,
-        id: Annotated[StrictInt, Field(description="Slurm compute node configuration ID")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -12945,13 +12921,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> SlurmSchedulerModel:
-        """Delete Slurm compute node configuration.
+    ) -> object:
+        """Delete all job results for one workflow.
 
-        Delete Slurm compute node configuration.
+        Delete all job results for one workflow.
 
-        :param id: Slurm compute node configuration ID (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -12976,8 +12952,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_slurm_scheduler_serialize(
-            id=id,
+        _param = self._delete_results_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -12986,8 +12962,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "SlurmSchedulerModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -13002,9 +12977,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_slurm_scheduler_with_http_info(
+    def delete_results_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Slurm compute node configuration ID")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -13018,13 +12993,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[SlurmSchedulerModel]:
-        """Delete Slurm compute node configuration.
+    ) -> ApiResponse[object]:
+        """Delete all job results for one workflow.
 
-        Delete Slurm compute node configuration.
+        Delete all job results for one workflow.
 
-        :param id: Slurm compute node configuration ID (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -13049,8 +13024,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_slurm_scheduler_serialize(
-            id=id,
+        _param = self._delete_results_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -13059,8 +13034,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "SlurmSchedulerModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -13075,9 +13049,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_slurm_scheduler_without_preload_content(
+    def delete_results_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Slurm compute node configuration ID")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -13092,12 +13066,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete Slurm compute node configuration.
+        """Delete all job results for one workflow.
 
-        Delete Slurm compute node configuration.
+        Delete all job results for one workflow.
 
-        :param id: Slurm compute node configuration ID (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -13122,8 +13096,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_slurm_scheduler_serialize(
-            id=id,
+        _param = self._delete_results_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -13132,8 +13106,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "SlurmSchedulerModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -13143,9 +13116,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_slurm_scheduler_serialize(
+    def _delete_results_serialize(
         self,
-        id,
+        workflow_id,
         body,
         _request_auth,
         _content_type,
@@ -13168,9 +13141,11 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -13206,7 +13181,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/slurm_schedulers/{id}',
+            resource_path='/results',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -13223,9 +13198,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_slurm_schedulers(
+    def delete_scheduled_compute_node(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="Scheduled compute node ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -13239,13 +13214,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> object:
-        """Retrieve all Slurm compute node configurations for one workflow.
+    ) -> ScheduledComputeNodesModel:
+        """Delete a scheduled compute node.
 
-        Retrieve all Slurm compute node configurations for one workflow.
+        Delete a scheduled compute node.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: Scheduled compute node ID (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -13270,8 +13245,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_slurm_schedulers_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_scheduled_compute_node_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -13280,7 +13255,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "ScheduledComputeNodesModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -13295,9 +13271,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_slurm_schedulers_with_http_info(
+    def delete_scheduled_compute_node_with_http_info(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="Scheduled compute node ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -13311,13 +13287,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[object]:
-        """Retrieve all Slurm compute node configurations for one workflow.
+    ) -> ApiResponse[ScheduledComputeNodesModel]:
+        """Delete a scheduled compute node.
 
-        Retrieve all Slurm compute node configurations for one workflow.
+        Delete a scheduled compute node.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: Scheduled compute node ID (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -13342,8 +13318,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_slurm_schedulers_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_scheduled_compute_node_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -13352,7 +13328,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "ScheduledComputeNodesModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -13367,9 +13344,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_slurm_schedulers_without_preload_content(
+    def delete_scheduled_compute_node_without_preload_content(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="Scheduled compute node ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -13384,12 +13361,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve all Slurm compute node configurations for one workflow.
+        """Delete a scheduled compute node.
 
-        Retrieve all Slurm compute node configurations for one workflow.
+        Delete a scheduled compute node.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
+        :param id: Scheduled compute node ID (required)
+        :type id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -13414,8 +13391,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_slurm_schedulers_serialize(
-            workflow_id=workflow_id,
+        _param = self._delete_scheduled_compute_node_serialize(
+            id=id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -13424,7 +13401,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "ScheduledComputeNodesModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -13434,9 +13412,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_slurm_schedulers_serialize(
+    def _delete_scheduled_compute_node_serialize(
         self,
-        workflow_id,
+        id,
         body,
         _request_auth,
         _content_type,
@@ -13459,11 +13437,9 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
-        if workflow_id is not None:
-            
-            _query_params.append(('workflow_id', workflow_id))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -13499,7 +13475,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/slurm_schedulers',
+            resource_path='/scheduled_compute_nodes/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -13516,9 +13492,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_user_data(
+    def delete_scheduled_compute_nodes(
         self,
-        id: Annotated[StrictInt, Field(description="User data record ID")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -13532,13 +13508,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> UserDataModel:
-        """Delete a user data record.
+    ) -> object:
+        """Delete all scheduled compute node records for one workflow.
 
-        Delete a user data record.
+        Delete all scheduled compute node records for one workflow.
 
-        :param id: User data record ID (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -13563,8 +13539,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_user_data_serialize(
-            id=id,
+        _param = self._delete_scheduled_compute_nodes_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -13573,8 +13549,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "UserDataModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -13589,9 +13564,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_user_data_with_http_info(
+    def delete_scheduled_compute_nodes_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="User data record ID")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -13605,13 +13580,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[UserDataModel]:
-        """Delete a user data record.
+    ) -> ApiResponse[object]:
+        """Delete all scheduled compute node records for one workflow.
 
-        Delete a user data record.
+        Delete all scheduled compute node records for one workflow.
 
-        :param id: User data record ID (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -13636,8 +13611,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_user_data_serialize(
-            id=id,
+        _param = self._delete_scheduled_compute_nodes_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -13646,8 +13621,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "UserDataModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -13662,9 +13636,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_user_data_without_preload_content(
+    def delete_scheduled_compute_nodes_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="User data record ID")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -13679,12 +13653,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete a user data record.
+        """Delete all scheduled compute node records for one workflow.
 
-        Delete a user data record.
+        Delete all scheduled compute node records for one workflow.
 
-        :param id: User data record ID (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -13709,8 +13683,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_user_data_serialize(
-            id=id,
+        _param = self._delete_scheduled_compute_nodes_serialize(
+            workflow_id=workflow_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -13719,8 +13693,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "UserDataModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -13730,9 +13703,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_user_data_serialize(
+    def _delete_scheduled_compute_nodes_serialize(
         self,
-        id,
+        workflow_id,
         body,
         _request_auth,
         _content_type,
@@ -13755,9 +13728,11 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -13793,7 +13768,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/user_data/{id}',
+            resource_path='/scheduled_compute_nodes',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -13810,9 +13785,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_workflow(
+    def delete_slurm_scheduler(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID.")],
+        id: Annotated[StrictInt, Field(description="Slurm compute node configuration ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -13826,12 +13801,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> WorkflowModel:
-        """Delete a workflow.
+    ) -> SlurmSchedulerModel:
+        """Delete Slurm compute node configuration.
 
-        Delete a workflow.
+        Delete Slurm compute node configuration.
 
-        :param id: Workflow ID. (required)
+        :param id: Slurm compute node configuration ID (required)
         :type id: int
         :param body:
         :type body: object
@@ -13857,7 +13832,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_workflow_serialize(
+        _param = self._delete_slurm_scheduler_serialize(
             id=id,
             body=body,
             _request_auth=_request_auth,
@@ -13867,8 +13842,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "WorkflowModel",
-            '403': "ForbiddenErrorResponse",
+            '200': "SlurmSchedulerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -13884,9 +13858,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_workflow_with_http_info(
+    def delete_slurm_scheduler_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID.")],
+        id: Annotated[StrictInt, Field(description="Slurm compute node configuration ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -13900,12 +13874,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[WorkflowModel]:
-        """Delete a workflow.
+    ) -> ApiResponse[SlurmSchedulerModel]:
+        """Delete Slurm compute node configuration.
 
-        Delete a workflow.
+        Delete Slurm compute node configuration.
 
-        :param id: Workflow ID. (required)
+        :param id: Slurm compute node configuration ID (required)
         :type id: int
         :param body:
         :type body: object
@@ -13931,7 +13905,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_workflow_serialize(
+        _param = self._delete_slurm_scheduler_serialize(
             id=id,
             body=body,
             _request_auth=_request_auth,
@@ -13941,8 +13915,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "WorkflowModel",
-            '403': "ForbiddenErrorResponse",
+            '200': "SlurmSchedulerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -13958,9 +13931,9 @@ class DefaultApi:
 
 
     @validate_call
-    def delete_workflow_without_preload_content(
+    def delete_slurm_scheduler_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID.")],
+        id: Annotated[StrictInt, Field(description="Slurm compute node configuration ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -13975,11 +13948,11 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Delete a workflow.
+        """Delete Slurm compute node configuration.
 
-        Delete a workflow.
+        Delete Slurm compute node configuration.
 
-        :param id: Workflow ID. (required)
+        :param id: Slurm compute node configuration ID (required)
         :type id: int
         :param body:
         :type body: object
@@ -14005,7 +13978,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._delete_workflow_serialize(
+        _param = self._delete_slurm_scheduler_serialize(
             id=id,
             body=body,
             _request_auth=_request_auth,
@@ -14015,8 +13988,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "WorkflowModel",
-            '403': "ForbiddenErrorResponse",
+            '200': "SlurmSchedulerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -14027,7 +13999,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _delete_workflow_serialize(
+    def _delete_slurm_scheduler_serialize(
         self,
         id,
         body,
@@ -14090,7 +14062,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='DELETE',
-            resource_path='/workflows/{id}',
+            resource_path='/slurm_schedulers/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -14107,9 +14079,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_access_group(
+    def delete_slurm_schedulers(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the access group")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -14122,13 +14095,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> AccessGroupModel:
-        """Get an access group by ID.
+    ) -> object:
+        """Retrieve all Slurm compute node configurations for one workflow.
 
-        Get an access group by ID.
+        Retrieve all Slurm compute node configurations for one workflow.
 
-        :param id: ID of the access group (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -14151,8 +14126,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_access_group_serialize(
-            id=id,
+        _param = self._delete_slurm_schedulers_serialize(
+            workflow_id=workflow_id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -14160,8 +14136,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "AccessGroupModel",
-            '404': "DefaultErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -14176,9 +14151,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_access_group_with_http_info(
+    def delete_slurm_schedulers_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the access group")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -14191,13 +14167,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[AccessGroupModel]:
-        """Get an access group by ID.
+    ) -> ApiResponse[object]:
+        """Retrieve all Slurm compute node configurations for one workflow.
 
-        Get an access group by ID.
+        Retrieve all Slurm compute node configurations for one workflow.
 
-        :param id: ID of the access group (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -14220,8 +14198,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_access_group_serialize(
-            id=id,
+        _param = self._delete_slurm_schedulers_serialize(
+            workflow_id=workflow_id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -14229,8 +14208,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "AccessGroupModel",
-            '404': "DefaultErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -14245,9 +14223,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_access_group_without_preload_content(
+    def delete_slurm_schedulers_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the access group")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -14261,12 +14240,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Get an access group by ID.
+        """Retrieve all Slurm compute node configurations for one workflow.
 
-        Get an access group by ID.
+        Retrieve all Slurm compute node configurations for one workflow.
 
-        :param id: ID of the access group (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -14289,8 +14270,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_access_group_serialize(
-            id=id,
+        _param = self._delete_slurm_schedulers_serialize(
+            workflow_id=workflow_id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -14298,8 +14280,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "AccessGroupModel",
-            '404': "DefaultErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -14309,9 +14290,10 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_access_group_serialize(
+    def _delete_slurm_schedulers_serialize(
         self,
-        id,
+        workflow_id,
+        body,
         _request_auth,
         _content_type,
         _headers,
@@ -14333,12 +14315,16 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
         # process the header parameters
         # process the form parameters
         # process the body parameter
+        if body is not None:
+            _body_params = body
 
 
         # set the HTTP header `Accept`
@@ -14349,14 +14335,27 @@ class DefaultApi:
                 ]
             )
 
+        # set the HTTP header `Content-Type`
+        if _content_type:
+            _header_params['Content-Type'] = _content_type
+        else:
+            _default_content_type = (
+                self.api_client.select_header_content_type(
+                    [
+                        'application/json'
+                    ]
+                )
+            )
+            if _default_content_type is not None:
+                _header_params['Content-Type'] = _default_content_type
 
         # authentication setting
         _auth_settings: List[str] = [
         ]
 
         return self.api_client.param_serialize(
-            method='GET',
-            resource_path='/access_groups/{id}',
+            method='DELETE',
+            resource_path='/slurm_schedulers',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -14373,9 +14372,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_compute_node(
+    def delete_user_data(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the compute node record")],
+        id: Annotated[StrictInt, Field(description="User data record ID")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -14388,13 +14388,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ComputeNodeModel:
-        """Retrieve a compute node by ID.
+    ) -> UserDataModel:
+        """Delete a user data record.
 
-        Retrieve a compute node by ID.
+        Delete a user data record.
 
-        :param id: ID of the compute node record (required)
+        :param id: User data record ID (required)
         :type id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -14417,8 +14419,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_compute_node_serialize(
+        _param = self._delete_user_data_serialize(
             id=id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -14426,7 +14429,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ComputeNodeModel",
+            '200': "UserDataModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -14442,9 +14445,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_compute_node_with_http_info(
+    def delete_user_data_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the compute node record")],
+        id: Annotated[StrictInt, Field(description="User data record ID")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -14457,13 +14461,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ComputeNodeModel]:
-        """Retrieve a compute node by ID.
+    ) -> ApiResponse[UserDataModel]:
+        """Delete a user data record.
 
-        Retrieve a compute node by ID.
+        Delete a user data record.
 
-        :param id: ID of the compute node record (required)
+        :param id: User data record ID (required)
         :type id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -14486,8 +14492,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_compute_node_serialize(
+        _param = self._delete_user_data_serialize(
             id=id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -14495,7 +14502,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ComputeNodeModel",
+            '200': "UserDataModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -14511,9 +14518,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_compute_node_without_preload_content(
+    def delete_user_data_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the compute node record")],
+        id: Annotated[StrictInt, Field(description="User data record ID")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -14527,12 +14535,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve a compute node by ID.
+        """Delete a user data record.
 
-        Retrieve a compute node by ID.
+        Delete a user data record.
 
-        :param id: ID of the compute node record (required)
+        :param id: User data record ID (required)
         :type id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -14555,8 +14565,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_compute_node_serialize(
+        _param = self._delete_user_data_serialize(
             id=id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -14564,7 +14575,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ComputeNodeModel",
+            '200': "UserDataModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -14575,9 +14586,10 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_compute_node_serialize(
+    def _delete_user_data_serialize(
         self,
         id,
+        body,
         _request_auth,
         _content_type,
         _headers,
@@ -14605,6 +14617,8 @@ class DefaultApi:
         # process the header parameters
         # process the form parameters
         # process the body parameter
+        if body is not None:
+            _body_params = body
 
 
         # set the HTTP header `Accept`
@@ -14615,14 +14629,27 @@ class DefaultApi:
                 ]
             )
 
+        # set the HTTP header `Content-Type`
+        if _content_type:
+            _header_params['Content-Type'] = _content_type
+        else:
+            _default_content_type = (
+                self.api_client.select_header_content_type(
+                    [
+                        'application/json'
+                    ]
+                )
+            )
+            if _default_content_type is not None:
+                _header_params['Content-Type'] = _default_content_type
 
         # authentication setting
         _auth_settings: List[str] = [
         ]
 
         return self.api_client.param_serialize(
-            method='GET',
-            resource_path='/compute_nodes/{id}',
+            method='DELETE',
+            resource_path='/user_data/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -14639,9 +14666,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_event(
+    def delete_workflow(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the events record")],
+        id: Annotated[StrictInt, Field(description="Workflow ID.")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -14654,13 +14682,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> EventModel:
-        """Retrieve an event by ID.
+    ) -> WorkflowModel:
+        """Delete a workflow.
 
-        Retrieve an event by ID.
+        Delete a workflow.
 
-        :param id: ID of the events record (required)
+        :param id: Workflow ID. (required)
         :type id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -14683,8 +14713,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_event_serialize(
+        _param = self._delete_workflow_serialize(
             id=id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -14692,7 +14723,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "EventModel",
+            '200': "WorkflowModel",
+            '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -14708,9 +14740,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_event_with_http_info(
+    def delete_workflow_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the events record")],
+        id: Annotated[StrictInt, Field(description="Workflow ID.")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -14723,13 +14756,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[EventModel]:
-        """Retrieve an event by ID.
+    ) -> ApiResponse[WorkflowModel]:
+        """Delete a workflow.
 
-        Retrieve an event by ID.
+        Delete a workflow.
 
-        :param id: ID of the events record (required)
+        :param id: Workflow ID. (required)
         :type id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -14752,8 +14787,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_event_serialize(
+        _param = self._delete_workflow_serialize(
             id=id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -14761,7 +14797,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "EventModel",
+            '200': "WorkflowModel",
+            '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -14777,9 +14814,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_event_without_preload_content(
+    def delete_workflow_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the events record")],
+        id: Annotated[StrictInt, Field(description="Workflow ID.")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -14793,12 +14831,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve an event by ID.
+        """Delete a workflow.
 
-        Retrieve an event by ID.
+        Delete a workflow.
 
-        :param id: ID of the events record (required)
+        :param id: Workflow ID. (required)
         :type id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -14821,8 +14861,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_event_serialize(
+        _param = self._delete_workflow_serialize(
             id=id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -14830,7 +14871,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "EventModel",
+            '200': "WorkflowModel",
+            '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -14841,9 +14883,10 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_event_serialize(
+    def _delete_workflow_serialize(
         self,
         id,
+        body,
         _request_auth,
         _content_type,
         _headers,
@@ -14871,6 +14914,8 @@ class DefaultApi:
         # process the header parameters
         # process the form parameters
         # process the body parameter
+        if body is not None:
+            _body_params = body
 
 
         # set the HTTP header `Accept`
@@ -14881,14 +14926,27 @@ class DefaultApi:
                 ]
             )
 
+        # set the HTTP header `Content-Type`
+        if _content_type:
+            _header_params['Content-Type'] = _content_type
+        else:
+            _default_content_type = (
+                self.api_client.select_header_content_type(
+                    [
+                        'application/json'
+                    ]
+                )
+            )
+            if _default_content_type is not None:
+                _header_params['Content-Type'] = _default_content_type
 
         # authentication setting
         _auth_settings: List[str] = [
         ]
 
         return self.api_client.param_serialize(
-            method='GET',
-            resource_path='/events/{id}',
+            method='DELETE',
+            resource_path='/workflows/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -14905,9 +14963,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_failure_handler(
+    def delete_workflow_schedule(
         self,
-        id: Annotated[StrictInt, Field(description="Failure handler ID")],
+        id: Annotated[StrictInt, Field(description="Workflow schedule ID")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -14920,13 +14979,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> FailureHandlerModel:
-        """Get a failure handler by ID.
+    ) -> WorkflowScheduleModel:
+        """Delete a workflow schedule.
 
-        Retrieve a failure handler by ID.
+        Delete a workflow schedule.
 
-        :param id: Failure handler ID (required)
+        :param id: Workflow schedule ID (required)
         :type id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -14949,8 +15010,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_failure_handler_serialize(
+        _param = self._delete_workflow_schedule_serialize(
             id=id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -14958,7 +15020,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FailureHandlerModel",
+            '200': "WorkflowScheduleModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -14974,9 +15036,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_failure_handler_with_http_info(
+    def delete_workflow_schedule_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Failure handler ID")],
+        id: Annotated[StrictInt, Field(description="Workflow schedule ID")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -14989,13 +15052,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[FailureHandlerModel]:
-        """Get a failure handler by ID.
+    ) -> ApiResponse[WorkflowScheduleModel]:
+        """Delete a workflow schedule.
 
-        Retrieve a failure handler by ID.
+        Delete a workflow schedule.
 
-        :param id: Failure handler ID (required)
+        :param id: Workflow schedule ID (required)
         :type id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -15018,8 +15083,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_failure_handler_serialize(
+        _param = self._delete_workflow_schedule_serialize(
             id=id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -15027,7 +15093,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FailureHandlerModel",
+            '200': "WorkflowScheduleModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -15043,9 +15109,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_failure_handler_without_preload_content(
+    def delete_workflow_schedule_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Failure handler ID")],
+        id: Annotated[StrictInt, Field(description="Workflow schedule ID")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -15059,12 +15126,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Get a failure handler by ID.
+        """Delete a workflow schedule.
 
-        Retrieve a failure handler by ID.
+        Delete a workflow schedule.
 
-        :param id: Failure handler ID (required)
+        :param id: Workflow schedule ID (required)
         :type id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -15087,8 +15156,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_failure_handler_serialize(
+        _param = self._delete_workflow_schedule_serialize(
             id=id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -15096,7 +15166,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FailureHandlerModel",
+            '200': "WorkflowScheduleModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -15107,9 +15177,10 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_failure_handler_serialize(
+    def _delete_workflow_schedule_serialize(
         self,
         id,
+        body,
         _request_auth,
         _content_type,
         _headers,
@@ -15137,6 +15208,8 @@ class DefaultApi:
         # process the header parameters
         # process the form parameters
         # process the body parameter
+        if body is not None:
+            _body_params = body
 
 
         # set the HTTP header `Accept`
@@ -15147,14 +15220,27 @@ class DefaultApi:
                 ]
             )
 
+        # set the HTTP header `Content-Type`
+        if _content_type:
+            _header_params['Content-Type'] = _content_type
+        else:
+            _default_content_type = (
+                self.api_client.select_header_content_type(
+                    [
+                        'application/json'
+                    ]
+                )
+            )
+            if _default_content_type is not None:
+                _header_params['Content-Type'] = _default_content_type
 
         # authentication setting
         _auth_settings: List[str] = [
         ]
 
         return self.api_client.param_serialize(
-            method='GET',
-            resource_path='/failure_handlers/{id}',
+            method='DELETE',
+            resource_path='/workflow_schedules/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -15171,9 +15257,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_file(
+    def get_access_group(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the files record")],
+        id: Annotated[StrictInt, Field(description="ID of the access group")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -15186,12 +15272,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> FileModel:
-        """Retrieve a file.
+    ) -> AccessGroupModel:
+        """Get an access group by ID.
 
-        Retrieve a file.
+        Get an access group by ID.
 
-        :param id: ID of the files record (required)
+        :param id: ID of the access group (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -15215,7 +15301,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_file_serialize(
+        _param = self._get_access_group_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -15224,8 +15310,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FileModel",
-            '404': "NotFoundErrorResponse",
+            '200': "AccessGroupModel",
+            '404': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -15240,9 +15326,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_file_with_http_info(
+    def get_access_group_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the files record")],
+        id: Annotated[StrictInt, Field(description="ID of the access group")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -15255,12 +15341,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[FileModel]:
-        """Retrieve a file.
+    ) -> ApiResponse[AccessGroupModel]:
+        """Get an access group by ID.
 
-        Retrieve a file.
+        Get an access group by ID.
 
-        :param id: ID of the files record (required)
+        :param id: ID of the access group (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -15284,7 +15370,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_file_serialize(
+        _param = self._get_access_group_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -15293,8 +15379,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FileModel",
-            '404': "NotFoundErrorResponse",
+            '200': "AccessGroupModel",
+            '404': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -15309,9 +15395,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_file_without_preload_content(
+    def get_access_group_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the files record")],
+        id: Annotated[StrictInt, Field(description="ID of the access group")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -15325,11 +15411,11 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve a file.
+        """Get an access group by ID.
 
-        Retrieve a file.
+        Get an access group by ID.
 
-        :param id: ID of the files record (required)
+        :param id: ID of the access group (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -15353,7 +15439,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_file_serialize(
+        _param = self._get_access_group_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -15362,8 +15448,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "FileModel",
-            '404': "NotFoundErrorResponse",
+            '200': "AccessGroupModel",
+            '404': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -15373,7 +15459,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_file_serialize(
+    def _get_access_group_serialize(
         self,
         id,
         _request_auth,
@@ -15420,7 +15506,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/files/{id}',
+            resource_path='/access_groups/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -15437,9 +15523,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_job(
+    def get_compute_node(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the job record")],
+        id: Annotated[StrictInt, Field(description="ID of the compute node record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -15452,12 +15538,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> JobModel:
-        """Retrieve a job.
+    ) -> ComputeNodeModel:
+        """Retrieve a compute node by ID.
 
-        Retrieve a job.
+        Retrieve a compute node by ID.
 
-        :param id: ID of the job record (required)
+        :param id: ID of the compute node record (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -15481,7 +15567,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_job_serialize(
+        _param = self._get_compute_node_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -15490,8 +15576,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "JobModel",
-            '403': "ForbiddenErrorResponse",
+            '200': "ComputeNodeModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -15507,9 +15592,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_job_with_http_info(
+    def get_compute_node_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the job record")],
+        id: Annotated[StrictInt, Field(description="ID of the compute node record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -15522,12 +15607,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[JobModel]:
-        """Retrieve a job.
+    ) -> ApiResponse[ComputeNodeModel]:
+        """Retrieve a compute node by ID.
 
-        Retrieve a job.
+        Retrieve a compute node by ID.
 
-        :param id: ID of the job record (required)
+        :param id: ID of the compute node record (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -15551,7 +15636,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_job_serialize(
+        _param = self._get_compute_node_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -15560,8 +15645,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "JobModel",
-            '403': "ForbiddenErrorResponse",
+            '200': "ComputeNodeModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -15577,9 +15661,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_job_without_preload_content(
+    def get_compute_node_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the job record")],
+        id: Annotated[StrictInt, Field(description="ID of the compute node record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -15593,11 +15677,11 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve a job.
+        """Retrieve a compute node by ID.
 
-        Retrieve a job.
+        Retrieve a compute node by ID.
 
-        :param id: ID of the job record (required)
+        :param id: ID of the compute node record (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -15621,7 +15705,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_job_serialize(
+        _param = self._get_compute_node_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -15630,8 +15714,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "JobModel",
-            '403': "ForbiddenErrorResponse",
+            '200': "ComputeNodeModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -15642,7 +15725,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_job_serialize(
+    def _get_compute_node_serialize(
         self,
         id,
         _request_auth,
@@ -15689,7 +15772,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/jobs/{id}',
+            resource_path='/compute_nodes/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -15706,9 +15789,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_latest_event_timestamp(
+    def get_event(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the events record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -15721,12 +15804,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> object:
-        """Return the timestamp of the latest event in ms since the epoch in UTC.
+    ) -> EventModel:
+        """Retrieve an event by ID.
 
-        Return the timestamp of the latest event in ms since the epoch in UTC.
+        Retrieve an event by ID.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the events record (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -15750,7 +15833,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_latest_event_timestamp_serialize(
+        _param = self._get_event_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -15759,7 +15842,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "EventModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -15774,9 +15858,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_latest_event_timestamp_with_http_info(
+    def get_event_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the events record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -15789,12 +15873,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[object]:
-        """Return the timestamp of the latest event in ms since the epoch in UTC.
+    ) -> ApiResponse[EventModel]:
+        """Retrieve an event by ID.
 
-        Return the timestamp of the latest event in ms since the epoch in UTC.
+        Retrieve an event by ID.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the events record (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -15818,7 +15902,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_latest_event_timestamp_serialize(
+        _param = self._get_event_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -15827,7 +15911,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "EventModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -15842,9 +15927,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_latest_event_timestamp_without_preload_content(
+    def get_event_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the events record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -15858,11 +15943,11 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Return the timestamp of the latest event in ms since the epoch in UTC.
+        """Retrieve an event by ID.
 
-        Return the timestamp of the latest event in ms since the epoch in UTC.
+        Retrieve an event by ID.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the events record (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -15886,7 +15971,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_latest_event_timestamp_serialize(
+        _param = self._get_event_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -15895,7 +15980,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "EventModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -15905,7 +15991,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_latest_event_timestamp_serialize(
+    def _get_event_serialize(
         self,
         id,
         _request_auth,
@@ -15952,7 +16038,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}/latest_event_timestamp',
+            resource_path='/events/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -15969,9 +16055,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_local_scheduler(
+    def get_failure_handler(
         self,
-        id: Annotated[StrictInt, Field(description="Scheduler ID")],
+        id: Annotated[StrictInt, Field(description="Failure handler ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -15984,12 +16070,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> LocalSchedulerModel:
-        """Retrieve a local scheduler.
+    ) -> FailureHandlerModel:
+        """Get a failure handler by ID.
 
-        Retrieve a local scheduler.
+        Retrieve a failure handler by ID.
 
-        :param id: Scheduler ID (required)
+        :param id: Failure handler ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -16013,7 +16099,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_local_scheduler_serialize(
+        _param = self._get_failure_handler_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -16022,7 +16108,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "LocalSchedulerModel",
+            '200': "FailureHandlerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -16038,9 +16124,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_local_scheduler_with_http_info(
+    def get_failure_handler_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Scheduler ID")],
+        id: Annotated[StrictInt, Field(description="Failure handler ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -16053,12 +16139,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[LocalSchedulerModel]:
-        """Retrieve a local scheduler.
+    ) -> ApiResponse[FailureHandlerModel]:
+        """Get a failure handler by ID.
 
-        Retrieve a local scheduler.
+        Retrieve a failure handler by ID.
 
-        :param id: Scheduler ID (required)
+        :param id: Failure handler ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -16082,7 +16168,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_local_scheduler_serialize(
+        _param = self._get_failure_handler_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -16091,7 +16177,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "LocalSchedulerModel",
+            '200': "FailureHandlerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -16107,9 +16193,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_local_scheduler_without_preload_content(
+    def get_failure_handler_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Scheduler ID")],
+        id: Annotated[StrictInt, Field(description="Failure handler ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -16123,11 +16209,11 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve a local scheduler.
+        """Get a failure handler by ID.
 
-        Retrieve a local scheduler.
+        Retrieve a failure handler by ID.
 
-        :param id: Scheduler ID (required)
+        :param id: Failure handler ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -16151,7 +16237,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_local_scheduler_serialize(
+        _param = self._get_failure_handler_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -16160,7 +16246,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "LocalSchedulerModel",
+            '200': "FailureHandlerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -16171,7 +16257,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_local_scheduler_serialize(
+    def _get_failure_handler_serialize(
         self,
         id,
         _request_auth,
@@ -16218,7 +16304,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/local_schedulers/{id}',
+            resource_path='/failure_handlers/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -16235,10 +16321,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_pending_actions(
+    def get_file(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        trigger_type: Annotated[Optional[List[StrictStr]], Field(description="Filter by trigger type (can be specified multiple times)")] = None,
+        id: Annotated[StrictInt, Field(description="ID of the files record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -16251,15 +16336,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> List[WorkflowActionModel]:
-        """Get pending workflow actions, optionally filtered by trigger type.
+    ) -> FileModel:
+        """Retrieve a file.
 
-        Get pending (unexecuted) workflow actions for a workflow, optionally filtered by trigger type.
+        Retrieve a file.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the files record (required)
         :type id: int
-        :param trigger_type: Filter by trigger type (can be specified multiple times)
-        :type trigger_type: List[str]
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -16282,9 +16365,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_pending_actions_serialize(
+        _param = self._get_file_serialize(
             id=id,
-            trigger_type=trigger_type,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -16292,7 +16374,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "List[WorkflowActionModel]",
+            '200': "FileModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -16308,10 +16390,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_pending_actions_with_http_info(
+    def get_file_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        trigger_type: Annotated[Optional[List[StrictStr]], Field(description="Filter by trigger type (can be specified multiple times)")] = None,
+        id: Annotated[StrictInt, Field(description="ID of the files record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -16324,15 +16405,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[List[WorkflowActionModel]]:
-        """Get pending workflow actions, optionally filtered by trigger type.
+    ) -> ApiResponse[FileModel]:
+        """Retrieve a file.
 
-        Get pending (unexecuted) workflow actions for a workflow, optionally filtered by trigger type.
+        Retrieve a file.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the files record (required)
         :type id: int
-        :param trigger_type: Filter by trigger type (can be specified multiple times)
-        :type trigger_type: List[str]
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -16355,9 +16434,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_pending_actions_serialize(
+        _param = self._get_file_serialize(
             id=id,
-            trigger_type=trigger_type,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -16365,7 +16443,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "List[WorkflowActionModel]",
+            '200': "FileModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -16381,10 +16459,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_pending_actions_without_preload_content(
+    def get_file_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        trigger_type: Annotated[Optional[List[StrictStr]], Field(description="Filter by trigger type (can be specified multiple times)")] = None,
+        id: Annotated[StrictInt, Field(description="ID of the files record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -16398,14 +16475,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Get pending workflow actions, optionally filtered by trigger type.
+        """Retrieve a file.
 
-        Get pending (unexecuted) workflow actions for a workflow, optionally filtered by trigger type.
+        Retrieve a file.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the files record (required)
         :type id: int
-        :param trigger_type: Filter by trigger type (can be specified multiple times)
-        :type trigger_type: List[str]
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -16428,9 +16503,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_pending_actions_serialize(
+        _param = self._get_file_serialize(
             id=id,
-            trigger_type=trigger_type,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -16438,7 +16512,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "List[WorkflowActionModel]",
+            '200': "FileModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -16449,10 +16523,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_pending_actions_serialize(
+    def _get_file_serialize(
         self,
         id,
-        trigger_type,
         _request_auth,
         _content_type,
         _headers,
@@ -16462,7 +16535,6 @@ class DefaultApi:
         _host = None
 
         _collection_formats: Dict[str, str] = {
-            'trigger_type': 'multi',
         }
 
         _path_params: Dict[str, str] = {}
@@ -16478,10 +16550,6 @@ class DefaultApi:
         if id is not None:
             _path_params['id'] = id
         # process the query parameters
-        if trigger_type is not None:
-            
-            _query_params.append(('trigger_type', trigger_type))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -16502,7 +16570,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}/actions/pending',
+            resource_path='/files/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -16519,10 +16587,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_ready_job_requirements(
+    def get_job(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        scheduler_config_id: Annotated[Optional[StrictInt], Field(description="Limit output to jobs assigned this scheduler.")] = None,
+        id: Annotated[StrictInt, Field(description="ID of the job record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -16535,15 +16602,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> GetReadyJobRequirementsResponse:
-        """Return the resource requirements for jobs with a status of ready.
+    ) -> JobModel:
+        """Retrieve a job.
 
-        Return the resource requirements for jobs with a status of ready.
+        Retrieve a job.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the job record (required)
         :type id: int
-        :param scheduler_config_id: Limit output to jobs assigned this scheduler.
-        :type scheduler_config_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -16566,9 +16631,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_ready_job_requirements_serialize(
+        _param = self._get_job_serialize(
             id=id,
-            scheduler_config_id=scheduler_config_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -16576,7 +16640,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "GetReadyJobRequirementsResponse",
+            '200': "JobModel",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -16591,10 +16657,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_ready_job_requirements_with_http_info(
+    def get_job_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        scheduler_config_id: Annotated[Optional[StrictInt], Field(description="Limit output to jobs assigned this scheduler.")] = None,
+        id: Annotated[StrictInt, Field(description="ID of the job record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -16607,15 +16672,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[GetReadyJobRequirementsResponse]:
-        """Return the resource requirements for jobs with a status of ready.
+    ) -> ApiResponse[JobModel]:
+        """Retrieve a job.
 
-        Return the resource requirements for jobs with a status of ready.
+        Retrieve a job.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the job record (required)
         :type id: int
-        :param scheduler_config_id: Limit output to jobs assigned this scheduler.
-        :type scheduler_config_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -16638,9 +16701,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_ready_job_requirements_serialize(
+        _param = self._get_job_serialize(
             id=id,
-            scheduler_config_id=scheduler_config_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -16648,7 +16710,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "GetReadyJobRequirementsResponse",
+            '200': "JobModel",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -16663,10 +16727,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_ready_job_requirements_without_preload_content(
+    def get_job_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        scheduler_config_id: Annotated[Optional[StrictInt], Field(description="Limit output to jobs assigned this scheduler.")] = None,
+        id: Annotated[StrictInt, Field(description="ID of the job record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -16680,14 +16743,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Return the resource requirements for jobs with a status of ready.
+        """Retrieve a job.
 
-        Return the resource requirements for jobs with a status of ready.
+        Retrieve a job.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the job record (required)
         :type id: int
-        :param scheduler_config_id: Limit output to jobs assigned this scheduler.
-        :type scheduler_config_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -16710,9 +16771,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_ready_job_requirements_serialize(
+        _param = self._get_job_serialize(
             id=id,
-            scheduler_config_id=scheduler_config_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -16720,7 +16780,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "GetReadyJobRequirementsResponse",
+            '200': "JobModel",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -16730,10 +16792,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_ready_job_requirements_serialize(
+    def _get_job_serialize(
         self,
         id,
-        scheduler_config_id,
         _request_auth,
         _content_type,
         _headers,
@@ -16758,10 +16819,6 @@ class DefaultApi:
         if id is not None:
             _path_params['id'] = id
         # process the query parameters
-        if scheduler_config_id is not None:
-            
-            _query_params.append(('scheduler_config_id', scheduler_config_id))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -16782,7 +16839,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}/ready_job_requirements',
+            resource_path='/jobs/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -16799,9 +16856,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_resource_requirements(
+    def get_job_log(
         self,
-        id: Annotated[StrictInt, Field(description="Resource requirements ID")],
+        id: Annotated[StrictInt, Field(description="Job log ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -16814,12 +16871,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ResourceRequirementsModel:
-        """Retrieve one resource requirements record.
+    ) -> JobLogModel:
+        """Get a job log by ID.
 
-        Retrieve one resource requirements record.
+        Retrieve a job log by ID.
 
-        :param id: Resource requirements ID (required)
+        :param id: Job log ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -16843,7 +16900,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_resource_requirements_serialize(
+        _param = self._get_job_log_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -16852,7 +16909,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResourceRequirementsModel",
+            '200': "JobLogModel",
+            '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -16868,9 +16926,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_resource_requirements_with_http_info(
+    def get_job_log_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Resource requirements ID")],
+        id: Annotated[StrictInt, Field(description="Job log ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -16883,12 +16941,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ResourceRequirementsModel]:
-        """Retrieve one resource requirements record.
+    ) -> ApiResponse[JobLogModel]:
+        """Get a job log by ID.
 
-        Retrieve one resource requirements record.
+        Retrieve a job log by ID.
 
-        :param id: Resource requirements ID (required)
+        :param id: Job log ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -16912,7 +16970,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_resource_requirements_serialize(
+        _param = self._get_job_log_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -16921,7 +16979,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResourceRequirementsModel",
+            '200': "JobLogModel",
+            '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -16937,9 +16996,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_resource_requirements_without_preload_content(
+    def get_job_log_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Resource requirements ID")],
+        id: Annotated[StrictInt, Field(description="Job log ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -16953,11 +17012,11 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve one resource requirements record.
+        """Get a job log by ID.
 
-        Retrieve one resource requirements record.
+        Retrieve a job log by ID.
 
-        :param id: Resource requirements ID (required)
+        :param id: Job log ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -16981,7 +17040,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_resource_requirements_serialize(
+        _param = self._get_job_log_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -16990,7 +17049,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResourceRequirementsModel",
+            '200': "JobLogModel",
+            '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -17001,7 +17061,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_resource_requirements_serialize(
+    def _get_job_log_serialize(
         self,
         id,
         _request_auth,
@@ -17048,7 +17108,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/resource_requirements/{id}',
+            resource_path='/job_logs/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -17065,9 +17125,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_result(
+    def get_job_log_content(
         self,
-        id: Annotated[StrictInt, Field(description="Results ID")],
+        id: Annotated[StrictInt, Field(description="Job log ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -17080,12 +17140,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ResultModel:
-        """Retrieve a job result.
+    ) -> str:
+        """Get the content of a job log.
 
-        Retrieve a job result.
+        Retrieve the content of a job log as plain text, for example to stream it to a terminal with curl.
 
-        :param id: Results ID (required)
+        :param id: Job log ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -17109,7 +17169,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_result_serialize(
+        _param = self._get_job_log_content_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -17118,7 +17178,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResultModel",
+            '200': "str",
+            '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -17134,9 +17195,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_result_with_http_info(
+    def get_job_log_content_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Results ID")],
+        id: Annotated[StrictInt, Field(description="Job log ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -17149,12 +17210,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ResultModel]:
-        """Retrieve a job result.
+    ) -> ApiResponse[str]:
+        """Get the content of a job log.
 
-        Retrieve a job result.
+        Retrieve the content of a job log as plain text, for example to stream it to a terminal with curl.
 
-        :param id: Results ID (required)
+        :param id: Job log ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -17178,7 +17239,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_result_serialize(
+        _param = self._get_job_log_content_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -17187,7 +17248,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResultModel",
+            '200': "str",
+            '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -17203,9 +17265,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_result_without_preload_content(
+    def get_job_log_content_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Results ID")],
+        id: Annotated[StrictInt, Field(description="Job log ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -17219,11 +17281,11 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve a job result.
+        """Get the content of a job log.
 
-        Retrieve a job result.
+        Retrieve the content of a job log as plain text, for example to stream it to a terminal with curl.
 
-        :param id: Results ID (required)
+        :param id: Job log ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -17247,7 +17309,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_result_serialize(
+        _param = self._get_job_log_content_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -17256,7 +17318,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResultModel",
+            '200': "str",
+            '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -17267,7 +17330,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_result_serialize(
+    def _get_job_log_content_serialize(
         self,
         id,
         _request_auth,
@@ -17303,6 +17366,7 @@ class DefaultApi:
         if 'Accept' not in _header_params:
             _header_params['Accept'] = self.api_client.select_header_accept(
                 [
+                    'text/plain', 
                     'application/json'
                 ]
             )
@@ -17314,7 +17378,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/results/{id}',
+            resource_path='/job_logs/{id}/content',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -17331,9 +17395,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_scheduled_compute_node(
+    def get_latest_event_timestamp(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the scheduled_compute_nodes record")],
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -17346,12 +17410,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ScheduledComputeNodesModel:
-        """Retrieve a scheduled compute node.
+    ) -> object:
+        """Return the timestamp of the latest event in ms since the epoch in UTC.
 
-        Retrieve a scheduled compute node.
+        Return the timestamp of the latest event in ms since the epoch in UTC.
 
-        :param id: ID of the scheduled_compute_nodes record (required)
+        :param id: Workflow ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -17375,7 +17439,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_scheduled_compute_node_serialize(
+        _param = self._get_latest_event_timestamp_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -17384,8 +17448,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ScheduledComputeNodesModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -17400,9 +17463,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_scheduled_compute_node_with_http_info(
+    def get_latest_event_timestamp_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the scheduled_compute_nodes record")],
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -17415,12 +17478,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ScheduledComputeNodesModel]:
-        """Retrieve a scheduled compute node.
+    ) -> ApiResponse[object]:
+        """Return the timestamp of the latest event in ms since the epoch in UTC.
 
-        Retrieve a scheduled compute node.
+        Return the timestamp of the latest event in ms since the epoch in UTC.
 
-        :param id: ID of the scheduled_compute_nodes record (required)
+        :param id: Workflow ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -17444,7 +17507,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_scheduled_compute_node_serialize(
+        _param = self._get_latest_event_timestamp_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -17453,8 +17516,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ScheduledComputeNodesModel",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -17469,9 +17531,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_scheduled_compute_node_without_preload_content(
+    def get_latest_event_timestamp_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the scheduled_compute_nodes record")],
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -17485,11 +17547,138 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve a scheduled compute node.
+        """Return the timestamp of the latest event in ms since the epoch in UTC.
 
-        Retrieve a scheduled compute node.
+        Return the timestamp of the latest event in ms since the epoch in UTC.
 
-        :param id: ID of the scheduled_compute_nodes record (required)
+        :param id: Workflow ID (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._get_latest_event_timestamp_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "object",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _get_latest_event_timestamp_serialize(
+        self,
+        id,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
+        # process the query parameters
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='GET',
+            resource_path='/workflows/{id}/latest_event_timestamp',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
+    @validate_call
+    def get_local_scheduler(
+        self,
+        id: Annotated[StrictInt, Field(description="Scheduler ID")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> LocalSchedulerModel:
+        """Retrieve a local scheduler.
+
+        Retrieve a local scheduler.
+
+        :param id: Scheduler ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -17513,135 +17702,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_scheduled_compute_node_serialize(
-            id=id,
-            _request_auth=_request_auth,
-            _content_type=_content_type,
-            _headers=_headers,
-            _host_index=_host_index
-        )
-
-        _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ScheduledComputeNodesModel",
-            '404': "NotFoundErrorResponse",
-            '500': "DefaultErrorResponse",
-        }
-        response_data = self.api_client.call_api(
-            *_param,
-            _request_timeout=_request_timeout
-        )
-        return response_data.response
-
-
-    def _get_scheduled_compute_node_serialize(
-        self,
-        id,
-        _request_auth,
-        _content_type,
-        _headers,
-        _host_index,
-    ) -> RequestSerialized:
-
-        _host = None
-
-        _collection_formats: Dict[str, str] = {
-        }
-
-        _path_params: Dict[str, str] = {}
-        _query_params: List[Tuple[str, str]] = []
-        _header_params: Dict[str, Optional[str]] = _headers or {}
-        _form_params: List[Tuple[str, str]] = []
-        _files: Dict[
-            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
-        ] = {}
-        _body_params: Optional[bytes] = None
-
-        # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
-        # process the query parameters
-        # process the header parameters
-        # process the form parameters
-        # process the body parameter
-
-
-        # set the HTTP header `Accept`
-        if 'Accept' not in _header_params:
-            _header_params['Accept'] = self.api_client.select_header_accept(
-                [
-                    'application/json'
-                ]
-            )
-
-
-        # authentication setting
-        _auth_settings: List[str] = [
-        ]
-
-        return self.api_client.param_serialize(
-            method='GET',
-            resource_path='/scheduled_compute_nodes/{id}',
-            path_params=_path_params,
-            query_params=_query_params,
-            header_params=_header_params,
-            body=_body_params,
-            post_params=_form_params,
-            files=_files,
-            auth_settings=_auth_settings,
-            collection_formats=_collection_formats,
-            _host=_host,
-            _request_auth=_request_auth
-        )
-
-
-
-
-    @validate_call
-    def get_slurm_scheduler(
-        self,
-        id: Annotated[StrictInt, Field(description="Slurm compute node configuration ID")],
-        _request_timeout: Union[
-            None,
-            Annotated[StrictFloat, Field(gt=0)],
-            Tuple[
-                Annotated[StrictFloat, Field(gt=0)],
-                Annotated[StrictFloat, Field(gt=0)]
-            ]
-        ] = None,
-        _request_auth: Optional[Dict[StrictStr, Any]] = None,
-        _content_type: Optional[StrictStr] = None,
-        _headers: Optional[Dict[StrictStr, Any]] = None,
-        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> SlurmSchedulerModel:
-        """Retrieve a Slurm compute node configuration.
-
-        Retrieve a Slurm compute node configuration.
-
-        :param id: Slurm compute node configuration ID (required)
-        :type id: int
-        :param _request_timeout: timeout setting for this request. If one
-                                 number provided, it will be total request
-                                 timeout. It can also be a pair (tuple) of
-                                 (connection, read) timeouts.
-        :type _request_timeout: int, tuple(int, int), optional
-        :param _request_auth: set to override the auth_settings for an a single
-                              request; this effectively ignores the
-                              authentication in the spec for a single request.
-        :type _request_auth: dict, optional
-        :param _content_type: force content-type for the request.
-        :type _content_type: str, Optional
-        :param _headers: set to override the headers for a single
-                         request; this effectively ignores the headers
-                         in the spec for a single request.
-        :type _headers: dict, optional
-        :param _host_index: set to override the host_index for a single
-                            request; this effectively ignores the host_index
-                            in the spec for a single request.
-        :type _host_index: int, optional
-        :return: Returns the result object.
-        """ # noqa: E501
-
-        _param = self._get_slurm_scheduler_serialize(
+        _param = self._get_local_scheduler_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -17650,7 +17711,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "SlurmSchedulerModel",
+            '200': "LocalSchedulerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -17666,9 +17727,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_slurm_scheduler_with_http_info(
+    def get_local_scheduler_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Slurm compute node configuration ID")],
+        id: Annotated[StrictInt, Field(description="Scheduler ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -17681,12 +17742,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[SlurmSchedulerModel]:
-        """Retrieve a Slurm compute node configuration.
+    ) -> ApiResponse[LocalSchedulerModel]:
+        """Retrieve a local scheduler.
 
-        Retrieve a Slurm compute node configuration.
+        Retrieve a local scheduler.
 
-        :param id: Slurm compute node configuration ID (required)
+        :param id: Scheduler ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -17710,7 +17771,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_slurm_scheduler_serialize(
+        _param = self._get_local_scheduler_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -17719,7 +17780,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "SlurmSchedulerModel",
+            '200': "LocalSchedulerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -17735,9 +17796,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_slurm_scheduler_without_preload_content(
+    def get_local_scheduler_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Slurm compute node configuration ID")],
+        id: Annotated[StrictInt, Field(description="Scheduler ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -17751,11 +17812,11 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve a Slurm compute node configuration.
+        """Retrieve a local scheduler.
 
-        Retrieve a Slurm compute node configuration.
+        Retrieve a local scheduler.
 
-        :param id: Slurm compute node configuration ID (required)
+        :param id: Scheduler ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -17779,7 +17840,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_slurm_scheduler_serialize(
+        _param = self._get_local_scheduler_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -17788,7 +17849,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "SlurmSchedulerModel",
+            '200': "LocalSchedulerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -17799,7 +17860,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_slurm_scheduler_serialize(
+    def _get_local_scheduler_serialize(
         self,
         id,
         _request_auth,
@@ -17846,7 +17907,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/slurm_schedulers/{id}',
+            resource_path='/local_schedulers/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -17863,9 +17924,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_user_data(
+    def get_pending_actions(
         self,
-        id: Annotated[StrictInt, Field(description="User data record ID")],
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        trigger_type: Annotated[Optional[List[StrictStr]], Field(description="Filter by trigger type (can be specified multiple times)")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -17878,13 +17940,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> UserDataModel:
-        """Retrieve a user data record.
+    ) -> List[WorkflowActionModel]:
+        """Get pending workflow actions, optionally filtered by trigger type.
 
-        Retrieve a user data record.
+        Get pending (unexecuted) workflow actions for a workflow, optionally filtered by trigger type.
 
-        :param id: User data record ID (required)
+        :param id: Workflow ID (required)
         :type id: int
+        :param trigger_type: Filter by trigger type (can be specified multiple times)
+        :type trigger_type: List[str]
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -17907,8 +17971,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_user_data_serialize(
+        _param = self._get_pending_actions_serialize(
             id=id,
+            trigger_type=trigger_type,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -17916,7 +17981,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "UserDataModel",
+            '200': "List[WorkflowActionModel]",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -17932,9 +17997,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_user_data_with_http_info(
+    def get_pending_actions_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="User data record ID")],
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        trigger_type: Annotated[Optional[List[StrictStr]], Field(description="Filter by trigger type (can be specified multiple times)")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -17947,13 +18013,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[UserDataModel]:
-        """Retrieve a user data record.
+    ) -> ApiResponse[List[WorkflowActionModel]]:
+        """Get pending workflow actions, optionally filtered by trigger type.
 
-        Retrieve a user data record.
+        Get pending (unexecuted) workflow actions for a workflow, optionally filtered by trigger type.
 
-        :param id: User data record ID (required)
+        :param id: Workflow ID (required)
         :type id: int
+        :param trigger_type: Filter by trigger type (can be specified multiple times)
+        :type trigger_type: List[str]
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -17976,8 +18044,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_user_data_serialize(
+        _param = self._get_pending_actions_serialize(
             id=id,
+            trigger_type=trigger_type,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -17985,7 +18054,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "UserDataModel",
+            '200': "List[WorkflowActionModel]",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -18001,9 +18070,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_user_data_without_preload_content(
+    def get_pending_actions_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="User data record ID")],
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        trigger_type: Annotated[Optional[List[StrictStr]], Field(description="Filter by trigger type (can be specified multiple times)")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -18017,12 +18087,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve a user data record.
+        """Get pending workflow actions, optionally filtered by trigger type.
 
-        Retrieve a user data record.
+        Get pending (unexecuted) workflow actions for a workflow, optionally filtered by trigger type.
 
-        :param id: User data record ID (required)
+        :param id: Workflow ID (required)
         :type id: int
+        :param trigger_type: Filter by trigger type (can be specified multiple times)
+        :type trigger_type: List[str]
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -18045,8 +18117,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_user_data_serialize(
+        _param = self._get_pending_actions_serialize(
             id=id,
+            trigger_type=trigger_type,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -18054,7 +18127,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "UserDataModel",
+            '200': "List[WorkflowActionModel]",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -18065,9 +18138,10 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_user_data_serialize(
+    def _get_pending_actions_serialize(
         self,
         id,
+        trigger_type,
         _request_auth,
         _content_type,
         _headers,
@@ -18077,6 +18151,7 @@ class DefaultApi:
         _host = None
 
         _collection_formats: Dict[str, str] = {
+            'trigger_type': 'multi',
         }
 
         _path_params: Dict[str, str] = {}
@@ -18092,6 +18167,10 @@ class DefaultApi:
         if id is not None:
             _path_params['id'] = id
         # process the query parameters
+        if trigger_type is not None:
+            
+            _query_params.append(('trigger_type', trigger_type))
+            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -18112,7 +18191,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/user_data/{id}',
+            resource_path='/workflows/{id}/actions/pending',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -18129,8 +18208,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_version(
+    def get_ready_job_requirements(
         self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        scheduler_config_id: Annotated[Optional[StrictInt], Field(description="Limit output to jobs assigned this scheduler.")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -18143,11 +18224,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> object:
-        """Return the version of the service.
+    ) -> GetReadyJobRequirementsResponse:
+        """Return the resource requirements for jobs with a status of ready.
 
-        Return the version of the service.
+        Return the resource requirements for jobs with a status of ready.
 
+        :param id: Workflow ID (required)
+        :type id: int
+        :param scheduler_config_id: Limit output to jobs assigned this scheduler.
+        :type scheduler_config_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -18170,7 +18255,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_version_serialize(
+        _param = self._get_ready_job_requirements_serialize(
+            id=id,
+            scheduler_config_id=scheduler_config_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -18178,7 +18265,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "GetReadyJobRequirementsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -18193,8 +18280,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_version_with_http_info(
+    def get_ready_job_requirements_with_http_info(
         self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        scheduler_config_id: Annotated[Optional[StrictInt], Field(description="Limit output to jobs assigned this scheduler.")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -18207,11 +18296,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[object]:
-        """Return the version of the service.
+    ) -> ApiResponse[GetReadyJobRequirementsResponse]:
+        """Return the resource requirements for jobs with a status of ready.
 
-        Return the version of the service.
+        Return the resource requirements for jobs with a status of ready.
 
+        :param id: Workflow ID (required)
+        :type id: int
+        :param scheduler_config_id: Limit output to jobs assigned this scheduler.
+        :type scheduler_config_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -18234,7 +18327,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_version_serialize(
+        _param = self._get_ready_job_requirements_serialize(
+            id=id,
+            scheduler_config_id=scheduler_config_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -18242,7 +18337,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "GetReadyJobRequirementsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -18257,8 +18352,10 @@ class DefaultApi:
 
 
     @validate_call
-    def get_version_without_preload_content(
+    def get_ready_job_requirements_without_preload_content(
         self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        scheduler_config_id: Annotated[Optional[StrictInt], Field(description="Limit output to jobs assigned this scheduler.")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -18272,10 +18369,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Return the version of the service.
+        """Return the resource requirements for jobs with a status of ready.
 
-        Return the version of the service.
+        Return the resource requirements for jobs with a status of ready.
 
+        :param id: Workflow ID (required)
+        :type id: int
+        :param scheduler_config_id: Limit output to jobs assigned this scheduler.
+        :type scheduler_config_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -18298,7 +18399,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_version_serialize(
+        _param = self._get_ready_job_requirements_serialize(
+            id=id,
+            scheduler_config_id=scheduler_config_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -18306,7 +18409,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "GetReadyJobRequirementsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -18316,8 +18419,10 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_version_serialize(
+    def _get_ready_job_requirements_serialize(
         self,
+        id,
+        scheduler_config_id,
         _request_auth,
         _content_type,
         _headers,
@@ -18339,7 +18444,13 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
+        if scheduler_config_id is not None:
+            
+            _query_params.append(('scheduler_config_id', scheduler_config_id))
+            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -18360,7 +18471,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/version',
+            resource_path='/workflows/{id}/ready_job_requirements',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -18377,9 +18488,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_workflow(
+    def get_resource_requirements(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the workflows record")],
+        id: Annotated[StrictInt, Field(description="Resource requirements ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -18392,12 +18503,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> WorkflowModel:
-        """Retrieve a workflow.
+    ) -> ResourceRequirementsModel:
+        """Retrieve one resource requirements record.
 
-        Retrieve a workflow.
+        Retrieve one resource requirements record.
 
-        :param id: ID of the workflows record (required)
+        :param id: Resource requirements ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -18421,7 +18532,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_workflow_serialize(
+        _param = self._get_resource_requirements_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -18430,8 +18541,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "WorkflowModel",
-            '403': "ForbiddenErrorResponse",
+            '200': "ResourceRequirementsModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -18447,9 +18557,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_workflow_with_http_info(
+    def get_resource_requirements_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the workflows record")],
+        id: Annotated[StrictInt, Field(description="Resource requirements ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -18462,12 +18572,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[WorkflowModel]:
-        """Retrieve a workflow.
+    ) -> ApiResponse[ResourceRequirementsModel]:
+        """Retrieve one resource requirements record.
 
-        Retrieve a workflow.
+        Retrieve one resource requirements record.
 
-        :param id: ID of the workflows record (required)
+        :param id: Resource requirements ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -18491,7 +18601,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_workflow_serialize(
+        _param = self._get_resource_requirements_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -18500,8 +18610,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "WorkflowModel",
-            '403': "ForbiddenErrorResponse",
+            '200': "ResourceRequirementsModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -18517,9 +18626,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_workflow_without_preload_content(
+    def get_resource_requirements_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the workflows record")],
+        id: Annotated[StrictInt, Field(description="Resource requirements ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -18533,11 +18642,11 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve a workflow.
+        """Retrieve one resource requirements record.
 
-        Retrieve a workflow.
+        Retrieve one resource requirements record.
 
-        :param id: ID of the workflows record (required)
+        :param id: Resource requirements ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -18561,7 +18670,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_workflow_serialize(
+        _param = self._get_resource_requirements_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -18570,8 +18679,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "WorkflowModel",
-            '403': "ForbiddenErrorResponse",
+            '200': "ResourceRequirementsModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -18582,7 +18690,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_workflow_serialize(
+    def _get_resource_requirements_serialize(
         self,
         id,
         _request_auth,
@@ -18629,7 +18737,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}',
+            resource_path='/resource_requirements/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -18646,9 +18754,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_workflow_actions(
+    def get_result(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="Results ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -18661,12 +18769,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> List[WorkflowActionModel]:
-        """Get all workflow actions for a workflow.
+    ) -> ResultModel:
+        """Retrieve a job result.
 
-        Get all workflow actions for a workflow.
+        Retrieve a job result.
 
-        :param id: Workflow ID (required)
+        :param id: Results ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -18690,7 +18798,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_workflow_actions_serialize(
+        _param = self._get_result_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -18699,7 +18807,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "List[WorkflowActionModel]",
+            '200': "ResultModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -18715,9 +18823,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_workflow_actions_with_http_info(
+    def get_result_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="Results ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -18730,12 +18838,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[List[WorkflowActionModel]]:
-        """Get all workflow actions for a workflow.
+    ) -> ApiResponse[ResultModel]:
+        """Retrieve a job result.
 
-        Get all workflow actions for a workflow.
+        Retrieve a job result.
 
-        :param id: Workflow ID (required)
+        :param id: Results ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -18759,7 +18867,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_workflow_actions_serialize(
+        _param = self._get_result_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -18768,7 +18876,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "List[WorkflowActionModel]",
+            '200': "ResultModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -18784,9 +18892,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_workflow_actions_without_preload_content(
+    def get_result_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="Results ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -18800,11 +18908,11 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Get all workflow actions for a workflow.
+        """Retrieve a job result.
 
-        Get all workflow actions for a workflow.
+        Retrieve a job result.
 
-        :param id: Workflow ID (required)
+        :param id: Results ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -18828,7 +18936,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_workflow_actions_serialize(
+        _param = self._get_result_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -18837,7 +18945,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "List[WorkflowActionModel]",
+            '200': "ResultModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -18848,7 +18956,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_workflow_actions_serialize(
+    def _get_result_serialize(
         self,
         id,
         _request_auth,
@@ -18895,7 +19003,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}/actions',
+            resource_path='/results/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -18912,9 +19020,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_workflow_status(
+    def get_scheduled_compute_node(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the scheduled_compute_nodes record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -18927,12 +19035,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> WorkflowStatusModel:
-        """Return the workflow status.
+    ) -> ScheduledComputeNodesModel:
+        """Retrieve a scheduled compute node.
 
-        Return the workflow status.
+        Retrieve a scheduled compute node.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the scheduled_compute_nodes record (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -18956,7 +19064,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_workflow_status_serialize(
+        _param = self._get_scheduled_compute_node_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -18965,8 +19073,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "WorkflowStatusModel",
-            '403': "ForbiddenErrorResponse",
+            '200': "ScheduledComputeNodesModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -18982,9 +19089,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_workflow_status_with_http_info(
+    def get_scheduled_compute_node_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the scheduled_compute_nodes record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -18997,12 +19104,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[WorkflowStatusModel]:
-        """Return the workflow status.
+    ) -> ApiResponse[ScheduledComputeNodesModel]:
+        """Retrieve a scheduled compute node.
 
-        Return the workflow status.
+        Retrieve a scheduled compute node.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the scheduled_compute_nodes record (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -19026,7 +19133,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_workflow_status_serialize(
+        _param = self._get_scheduled_compute_node_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -19035,8 +19142,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "WorkflowStatusModel",
-            '403': "ForbiddenErrorResponse",
+            '200': "ScheduledComputeNodesModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -19052,9 +19158,9 @@ class DefaultApi:
 
 
     @validate_call
-    def get_workflow_status_without_preload_content(
+    def get_scheduled_compute_node_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the scheduled_compute_nodes record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -19068,11 +19174,11 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Return the workflow status.
+        """Retrieve a scheduled compute node.
 
-        Return the workflow status.
+        Retrieve a scheduled compute node.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the scheduled_compute_nodes record (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -19096,7 +19202,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._get_workflow_status_serialize(
+        _param = self._get_scheduled_compute_node_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -19105,8 +19211,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "WorkflowStatusModel",
-            '403': "ForbiddenErrorResponse",
+            '200': "ScheduledComputeNodesModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -19117,7 +19222,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _get_workflow_status_serialize(
+    def _get_scheduled_compute_node_serialize(
         self,
         id,
         _request_auth,
@@ -19164,7 +19269,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}/status',
+            resource_path='/scheduled_compute_nodes/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -19181,12 +19286,9 @@ class DefaultApi:
 
 
     @validate_call
-    def initialize_jobs(
+    def get_slurm_scheduler(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        only_uninitialized: Annotated[Optional[StrictBool], Field(description="Only initialize jobs with a status of uninitialized.")] = None,
-        clear_ephemeral_user_data: Annotated[Optional[StrictBool], Field(description="Clear all ephemeral user data.")] = None,
-        body: Optional[Dict[str, Any]] = None,
+        id: Annotated[StrictInt, Field(description="Slurm compute node configuration ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -19199,19 +19301,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> object:
-        """Initialize job relationships based on file and user_data relationships.
+    ) -> SlurmSchedulerModel:
+        """Retrieve a Slurm compute node configuration.
 
-        Initialize job relationships based on file and user_data relationships.
+        Retrieve a Slurm compute node configuration.
 
-        :param id: Workflow ID (required)
+        :param id: Slurm compute node configuration ID (required)
         :type id: int
-        :param only_uninitialized: Only initialize jobs with a status of uninitialized.
-        :type only_uninitialized: bool
-        :param clear_ephemeral_user_data: Clear all ephemeral user data.
-        :type clear_ephemeral_user_data: bool
-        :param body:
-        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -19234,11 +19330,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._initialize_jobs_serialize(
+        _param = self._get_slurm_scheduler_serialize(
             id=id,
-            only_uninitialized=only_uninitialized,
-            clear_ephemeral_user_data=clear_ephemeral_user_data,
-            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -19246,8 +19339,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
-            '403': "ForbiddenErrorResponse",
+            '200': "SlurmSchedulerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -19263,12 +19355,9 @@ class DefaultApi:
 
 
     @validate_call
-    def initialize_jobs_with_http_info(
+    def get_slurm_scheduler_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        only_uninitialized: Annotated[Optional[StrictBool], Field(description="Only initialize jobs with a status of uninitialized.")] = None,
-        clear_ephemeral_user_data: Annotated[Optional[StrictBool], Field(description="Clear all ephemeral user data.")] = None,
-        body: Optional[Dict[str, Any]] = None,
+        id: Annotated[StrictInt, Field(description="Slurm compute node configuration ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -19281,19 +19370,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[object]:
-        """Initialize job relationships based on file and user_data relationships.
+    ) -> ApiResponse[SlurmSchedulerModel]:
+        """Retrieve a Slurm compute node configuration.
 
-        Initialize job relationships based on file and user_data relationships.
+        Retrieve a Slurm compute node configuration.
 
-        :param id: Workflow ID (required)
+        :param id: Slurm compute node configuration ID (required)
         :type id: int
-        :param only_uninitialized: Only initialize jobs with a status of uninitialized.
-        :type only_uninitialized: bool
-        :param clear_ephemeral_user_data: Clear all ephemeral user data.
-        :type clear_ephemeral_user_data: bool
-        :param body:
-        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -19316,11 +19399,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._initialize_jobs_serialize(
+        _param = self._get_slurm_scheduler_serialize(
             id=id,
-            only_uninitialized=only_uninitialized,
-            clear_ephemeral_user_data=clear_ephemeral_user_data,
-            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -19328,8 +19408,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
-            '403': "ForbiddenErrorResponse",
+            '200': "SlurmSchedulerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -19345,12 +19424,9 @@ class DefaultApi:
 
 
     @validate_call
-    def initialize_jobs_without_preload_content(
+    def get_slurm_scheduler_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        only_uninitialized: Annotated[Optional[StrictBool], Field(description="Only initialize jobs with a status of uninitialized.")] = None,
-        clear_ephemeral_user_data: Annotated[Optional[StrictBool], Field(description="Clear all ephemeral user data.")] = None,
-        body: Optional[Dict[str, Any]] = None,
+        id: Annotated[StrictInt, Field(description="Slurm compute node configuration ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -19364,18 +19440,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Initialize job relationships based on file and user_data relationships.
+        """Retrieve a Slurm compute node configuration.
 
-        Initialize job relationships based on file and user_data relationships.
+        Retrieve a Slurm compute node configuration.
 
-        :param id: Workflow ID (required)
+        :param id: Slurm compute node configuration ID (required)
         :type id: int
-        :param only_uninitialized: Only initialize jobs with a status of uninitialized.
-        :type only_uninitialized: bool
-        :param clear_ephemeral_user_data: Clear all ephemeral user data.
-        :type clear_ephemeral_user_data: bool
-        :param body:
-        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -19398,11 +19468,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._initialize_jobs_serialize(
+        _param = self._get_slurm_scheduler_serialize(
             id=id,
-            only_uninitialized=only_uninitialized,
-            clear_ephemeral_user_data=clear_ephemeral_user_data,
-            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -19410,8 +19477,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
-            '403': "ForbiddenErrorResponse",
+            '200': "SlurmSchedulerModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -19422,12 +19488,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _initialize_jobs_serialize(
+    def _get_slurm_scheduler_serialize(
         self,
         id,
-        only_uninitialized,
-        clear_ephemeral_user_data,
-        body,
         _request_auth,
         _content_type,
         _headers,
@@ -19452,19 +19515,9 @@ class DefaultApi:
         if id is not None:
             _path_params['id'] = id
         # process the query parameters
-        if only_uninitialized is not None:
-            
-            _query_params.append(('only_uninitialized', only_uninitialized))
-            
-        if clear_ephemeral_user_data is not None:
-            
-            _query_params.append(('clear_ephemeral_user_data', clear_ephemeral_user_data))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
-        if body is not None:
-            _body_params = body
 
 
         # set the HTTP header `Accept`
@@ -19475,27 +19528,14 @@ class DefaultApi:
                 ]
             )
 
-        # set the HTTP header `Content-Type`
-        if _content_type:
-            _header_params['Content-Type'] = _content_type
-        else:
-            _default_content_type = (
-                self.api_client.select_header_content_type(
-                    [
-                        'application/json'
-                    ]
-                )
-            )
-            if _default_content_type is not None:
-                _header_params['Content-Type'] = _default_content_type
 
         # authentication setting
         _auth_settings: List[str] = [
         ]
 
         return self.api_client.param_serialize(
-            method='POST',
-            resource_path='/workflows/{id}/initialize_jobs',
+            method='GET',
+            resource_path='/slurm_schedulers/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -19512,9 +19552,9 @@ class DefaultApi:
 
 
     @validate_call
-    def is_workflow_complete(
+    def get_user_data(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="User data record ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -19527,12 +19567,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> IsCompleteResponse:
-        """Return true if all jobs in the workflow are complete.
+    ) -> UserDataModel:
+        """Retrieve a user data record.
 
-        Return true if all jobs in the workflow are complete.
+        Retrieve a user data record.
 
-        :param id: Workflow ID (required)
+        :param id: User data record ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -19556,7 +19596,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._is_workflow_complete_serialize(
+        _param = self._get_user_data_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -19565,7 +19605,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "IsCompleteResponse",
+            '200': "UserDataModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -19580,9 +19621,9 @@ class DefaultApi:
 
 
     @validate_call
-    def is_workflow_complete_with_http_info(
+    def get_user_data_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="User data record ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -19595,12 +19636,12 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[IsCompleteResponse]:
-        """Return true if all jobs in the workflow are complete.
+    ) -> ApiResponse[UserDataModel]:
+        """Retrieve a user data record.
 
-        Return true if all jobs in the workflow are complete.
+        Retrieve a user data record.
 
-        :param id: Workflow ID (required)
+        :param id: User data record ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -19624,7 +19665,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._is_workflow_complete_serialize(
+        _param = self._get_user_data_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -19633,7 +19674,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "IsCompleteResponse",
+            '200': "UserDataModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -19648,9 +19690,9 @@ class DefaultApi:
 
 
     @validate_call
-    def is_workflow_complete_without_preload_content(
+    def get_user_data_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="User data record ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -19664,11 +19706,11 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Return true if all jobs in the workflow are complete.
+        """Retrieve a user data record.
 
-        Return true if all jobs in the workflow are complete.
+        Retrieve a user data record.
 
-        :param id: Workflow ID (required)
+        :param id: User data record ID (required)
         :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
@@ -19692,7 +19734,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._is_workflow_complete_serialize(
+        _param = self._get_user_data_serialize(
             id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -19701,7 +19743,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "IsCompleteResponse",
+            '200': "UserDataModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -19711,7 +19754,7 @@ class DefaultApi:
         return response_data.response
 
 
-    def _is_workflow_complete_serialize(
+    def _get_user_data_serialize(
         self,
         id,
         _request_auth,
@@ -19758,7 +19801,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}/is_complete',
+            resource_path='/user_data/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -19775,9 +19818,8 @@ class DefaultApi:
 
 
     @validate_call
-    def is_workflow_uninitialized(
+    def get_version(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -19790,13 +19832,11 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> IsUninitializedResponse:
-        """Return true if all jobs in the workflow are uninitialized or disabled.
+    ) -> object:
+        """Return the version of the service.
 
-        Return true if all jobs in the workflow are uninitialized or disabled.
+        Return the version of the service.
 
-        :param id: Workflow ID (required)
-        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -19819,8 +19859,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._is_workflow_uninitialized_serialize(
-            id=id,
+        _param = self._get_version_serialize(
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -19828,7 +19867,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "IsUninitializedResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -19843,9 +19882,8 @@ class DefaultApi:
 
 
     @validate_call
-    def is_workflow_uninitialized_with_http_info(
+    def get_version_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -19858,13 +19896,11 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[IsUninitializedResponse]:
-        """Return true if all jobs in the workflow are uninitialized or disabled.
+    ) -> ApiResponse[object]:
+        """Return the version of the service.
 
-        Return true if all jobs in the workflow are uninitialized or disabled.
+        Return the version of the service.
 
-        :param id: Workflow ID (required)
-        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -19887,8 +19923,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._is_workflow_uninitialized_serialize(
-            id=id,
+        _param = self._get_version_serialize(
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -19896,7 +19931,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "IsUninitializedResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -19911,9 +19946,8 @@ class DefaultApi:
 
 
     @validate_call
-    def is_workflow_uninitialized_without_preload_content(
+    def get_version_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -19927,12 +19961,10 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Return true if all jobs in the workflow are uninitialized or disabled.
+        """Return the version of the service.
 
-        Return true if all jobs in the workflow are uninitialized or disabled.
+        Return the version of the service.
 
-        :param id: Workflow ID (required)
-        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -19955,8 +19987,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._is_workflow_uninitialized_serialize(
-            id=id,
+        _param = self._get_version_serialize(
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -19964,7 +19995,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "IsUninitializedResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -19974,9 +20005,8 @@ class DefaultApi:
         return response_data.response
 
 
-    def _is_workflow_uninitialized_serialize(
+    def _get_version_serialize(
         self,
-        id,
         _request_auth,
         _content_type,
         _headers,
@@ -19998,8 +20028,6 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
         # process the header parameters
         # process the form parameters
@@ -20021,7 +20049,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}/is_uninitialized',
+            resource_path='/version',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -20038,10 +20066,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_access_groups(
+    def get_workflow(
         self,
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
+        id: Annotated[StrictInt, Field(description="ID of the workflows record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -20054,15 +20081,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListAccessGroupsResponse:
-        """List all access groups.
+    ) -> WorkflowModel:
+        """Retrieve a workflow.
 
-        List all access groups.
+        Retrieve a workflow.
 
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
+        :param id: ID of the workflows record (required)
+        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -20085,9 +20110,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_access_groups_serialize(
-            offset=offset,
-            limit=limit,
+        _param = self._get_workflow_serialize(
+            id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -20095,7 +20119,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListAccessGroupsResponse",
+            '200': "WorkflowModel",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -20110,10 +20136,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_access_groups_with_http_info(
+    def get_workflow_with_http_info(
         self,
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
+        id: Annotated[StrictInt, Field(description="ID of the workflows record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -20126,15 +20151,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListAccessGroupsResponse]:
-        """List all access groups.
+    ) -> ApiResponse[WorkflowModel]:
+        """Retrieve a workflow.
 
-        List all access groups.
+        Retrieve a workflow.
 
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
+        :param id: ID of the workflows record (required)
+        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -20157,9 +20180,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_access_groups_serialize(
-            offset=offset,
-            limit=limit,
+        _param = self._get_workflow_serialize(
+            id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -20167,7 +20189,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListAccessGroupsResponse",
+            '200': "WorkflowModel",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -20182,10 +20206,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_access_groups_without_preload_content(
+    def get_workflow_without_preload_content(
         self,
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
+        id: Annotated[StrictInt, Field(description="ID of the workflows record")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -20199,14 +20222,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """List all access groups.
+        """Retrieve a workflow.
 
-        List all access groups.
+        Retrieve a workflow.
 
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
+        :param id: ID of the workflows record (required)
+        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -20229,9 +20250,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_access_groups_serialize(
-            offset=offset,
-            limit=limit,
+        _param = self._get_workflow_serialize(
+            id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -20239,7 +20259,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListAccessGroupsResponse",
+            '200': "WorkflowModel",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -20249,10 +20271,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_access_groups_serialize(
+    def _get_workflow_serialize(
         self,
-        offset,
-        limit,
+        id,
         _request_auth,
         _content_type,
         _headers,
@@ -20274,15 +20295,9 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
-        if offset is not None:
-            
-            _query_params.append(('offset', offset))
-            
-        if limit is not None:
-            
-            _query_params.append(('limit', limit))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -20303,7 +20318,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/access_groups',
+            resource_path='/workflows/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -20320,16 +20335,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_compute_nodes(
+    def get_workflow_actions(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        hostname: Optional[StrictStr] = None,
-        is_active: Optional[StrictBool] = None,
-        scheduled_compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter by scheduled compute node ID (filters compute nodes created by this scheduler)")] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -20342,27 +20350,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListComputeNodesResponse:
-        """Retrieve all compute node records for one workflow.
+    ) -> List[WorkflowActionModel]:
+        """Get all workflow actions for a workflow.
 
-        Retrieve all compute node records for one workflow.
+        Get all workflow actions for a workflow.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param hostname:
-        :type hostname: str
-        :param is_active:
-        :type is_active: bool
-        :param scheduled_compute_node_id: Filter by scheduled compute node ID (filters compute nodes created by this scheduler)
-        :type scheduled_compute_node_id: int
+        :param id: Workflow ID (required)
+        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -20385,15 +20379,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_compute_nodes_serialize(
-            workflow_id=workflow_id,
-            offset=offset,
-            limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            hostname=hostname,
-            is_active=is_active,
-            scheduled_compute_node_id=scheduled_compute_node_id,
+        _param = self._get_workflow_actions_serialize(
+            id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -20401,7 +20388,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListComputeNodesResponse",
+            '200': "List[WorkflowActionModel]",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -20416,16 +20404,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_compute_nodes_with_http_info(
+    def get_workflow_actions_with_http_info(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        hostname: Optional[StrictStr] = None,
-        is_active: Optional[StrictBool] = None,
-        scheduled_compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter by scheduled compute node ID (filters compute nodes created by this scheduler)")] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -20438,27 +20419,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListComputeNodesResponse]:
-        """Retrieve all compute node records for one workflow.
+    ) -> ApiResponse[List[WorkflowActionModel]]:
+        """Get all workflow actions for a workflow.
 
-        Retrieve all compute node records for one workflow.
+        Get all workflow actions for a workflow.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param hostname:
-        :type hostname: str
-        :param is_active:
-        :type is_active: bool
-        :param scheduled_compute_node_id: Filter by scheduled compute node ID (filters compute nodes created by this scheduler)
-        :type scheduled_compute_node_id: int
+        :param id: Workflow ID (required)
+        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -20481,15 +20448,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_compute_nodes_serialize(
-            workflow_id=workflow_id,
-            offset=offset,
-            limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            hostname=hostname,
-            is_active=is_active,
-            scheduled_compute_node_id=scheduled_compute_node_id,
+        _param = self._get_workflow_actions_serialize(
+            id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -20497,7 +20457,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListComputeNodesResponse",
+            '200': "List[WorkflowActionModel]",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -20512,16 +20473,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_compute_nodes_without_preload_content(
+    def get_workflow_actions_without_preload_content(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        hostname: Optional[StrictStr] = None,
-        is_active: Optional[StrictBool] = None,
-        scheduled_compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter by scheduled compute node ID (filters compute nodes created by this scheduler)")] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -20535,26 +20489,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve all compute node records for one workflow.
+        """Get all workflow actions for a workflow.
 
-        Retrieve all compute node records for one workflow.
+        Get all workflow actions for a workflow.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param hostname:
-        :type hostname: str
-        :param is_active:
-        :type is_active: bool
-        :param scheduled_compute_node_id: Filter by scheduled compute node ID (filters compute nodes created by this scheduler)
-        :type scheduled_compute_node_id: int
+        :param id: Workflow ID (required)
+        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -20577,15 +20517,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_compute_nodes_serialize(
-            workflow_id=workflow_id,
-            offset=offset,
-            limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            hostname=hostname,
-            is_active=is_active,
-            scheduled_compute_node_id=scheduled_compute_node_id,
+        _param = self._get_workflow_actions_serialize(
+            id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -20593,7 +20526,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListComputeNodesResponse",
+            '200': "List[WorkflowActionModel]",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -20603,16 +20537,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_compute_nodes_serialize(
+    def _get_workflow_actions_serialize(
         self,
-        workflow_id,
-        offset,
-        limit,
-        sort_by,
-        reverse_sort,
-        hostname,
-        is_active,
-        scheduled_compute_node_id,
+        id,
         _request_auth,
         _content_type,
         _headers,
@@ -20634,39 +20561,9 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
-        if workflow_id is not None:
-            
-            _query_params.append(('workflow_id', workflow_id))
-            
-        if offset is not None:
-            
-            _query_params.append(('offset', offset))
-            
-        if limit is not None:
-            
-            _query_params.append(('limit', limit))
-            
-        if sort_by is not None:
-            
-            _query_params.append(('sort_by', sort_by))
-            
-        if reverse_sort is not None:
-            
-            _query_params.append(('reverse_sort', reverse_sort))
-            
-        if hostname is not None:
-            
-            _query_params.append(('hostname', hostname))
-            
-        if is_active is not None:
-            
-            _query_params.append(('is_active', is_active))
-            
-        if scheduled_compute_node_id is not None:
-            
-            _query_params.append(('scheduled_compute_node_id', scheduled_compute_node_id))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -20687,7 +20584,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/compute_nodes',
+            resource_path='/workflows/{id}/actions',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -20704,15 +20601,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_events(
+    def get_workflow_schedule(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        category: Optional[StrictStr] = None,
-        after_timestamp: Annotated[Optional[StrictInt], Field(description="Return events after this timestamp (milliseconds since epoch)")] = None,
+        id: Annotated[StrictInt, Field(description="Workflow schedule ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -20725,25 +20616,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListEventsResponse:
-        """Retrieve all events for one workflow.
+    ) -> WorkflowScheduleModel:
+        """Get a workflow schedule by ID.
 
-        Retrieve all events for one workflow.
+        Retrieve a workflow schedule by ID.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param category:
-        :type category: str
-        :param after_timestamp: Return events after this timestamp (milliseconds since epoch)
-        :type after_timestamp: int
+        :param id: Workflow schedule ID (required)
+        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -20766,14 +20645,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_events_serialize(
-            workflow_id=workflow_id,
-            offset=offset,
-            limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            category=category,
-            after_timestamp=after_timestamp,
+        _param = self._get_workflow_schedule_serialize(
+            id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -20781,7 +20654,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListEventsResponse",
+            '200': "WorkflowScheduleModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -20796,15 +20670,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_events_with_http_info(
+    def get_workflow_schedule_with_http_info(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        category: Optional[StrictStr] = None,
-        after_timestamp: Annotated[Optional[StrictInt], Field(description="Return events after this timestamp (milliseconds since epoch)")] = None,
+        id: Annotated[StrictInt, Field(description="Workflow schedule ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -20817,25 +20685,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListEventsResponse]:
-        """Retrieve all events for one workflow.
+    ) -> ApiResponse[WorkflowScheduleModel]:
+        """Get a workflow schedule by ID.
 
-        Retrieve all events for one workflow.
+        Retrieve a workflow schedule by ID.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param category:
-        :type category: str
-        :param after_timestamp: Return events after this timestamp (milliseconds since epoch)
-        :type after_timestamp: int
+        :param id: Workflow schedule ID (required)
+        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -20858,14 +20714,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_events_serialize(
-            workflow_id=workflow_id,
-            offset=offset,
-            limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            category=category,
-            after_timestamp=after_timestamp,
+        _param = self._get_workflow_schedule_serialize(
+            id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -20873,7 +20723,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListEventsResponse",
+            '200': "WorkflowScheduleModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -20888,15 +20739,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_events_without_preload_content(
+    def get_workflow_schedule_without_preload_content(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        category: Optional[StrictStr] = None,
-        after_timestamp: Annotated[Optional[StrictInt], Field(description="Return events after this timestamp (milliseconds since epoch)")] = None,
+        id: Annotated[StrictInt, Field(description="Workflow schedule ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -20910,24 +20755,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve all events for one workflow.
+        """Get a workflow schedule by ID.
 
-        Retrieve all events for one workflow.
+        Retrieve a workflow schedule by ID.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param category:
-        :type category: str
-        :param after_timestamp: Return events after this timestamp (milliseconds since epoch)
-        :type after_timestamp: int
+        :param id: Workflow schedule ID (required)
+        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -20950,14 +20783,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_events_serialize(
-            workflow_id=workflow_id,
-            offset=offset,
-            limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            category=category,
-            after_timestamp=after_timestamp,
+        _param = self._get_workflow_schedule_serialize(
+            id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -20965,7 +20792,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListEventsResponse",
+            '200': "WorkflowScheduleModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -20975,15 +20803,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_events_serialize(
+    def _get_workflow_schedule_serialize(
         self,
-        workflow_id,
-        offset,
-        limit,
-        sort_by,
-        reverse_sort,
-        category,
-        after_timestamp,
+        id,
         _request_auth,
         _content_type,
         _headers,
@@ -21005,35 +20827,9 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
-        if workflow_id is not None:
-            
-            _query_params.append(('workflow_id', workflow_id))
-            
-        if offset is not None:
-            
-            _query_params.append(('offset', offset))
-            
-        if limit is not None:
-            
-            _query_params.append(('limit', limit))
-            
-        if sort_by is not None:
-            
-            _query_params.append(('sort_by', sort_by))
-            
-        if reverse_sort is not None:
-            
-            _query_params.append(('reverse_sort', reverse_sort))
-            
-        if category is not None:
-            
-            _query_params.append(('category', category))
-            
-        if after_timestamp is not None:
-            
-            _query_params.append(('after_timestamp', after_timestamp))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -21054,7 +20850,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/events',
+            resource_path='/workflow_schedules/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -21071,11 +20867,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_failure_handlers(
+    def get_workflow_status(
         self,
         id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -21088,17 +20882,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListFailureHandlersResponse:
-        """List failure handlers for a workflow.
+    ) -> WorkflowStatusModel:
+        """Return the workflow status.
 
-        List all failure handlers for a workflow.
+        Return the workflow status.
 
         :param id: Workflow ID (required)
         :type id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -21121,10 +20911,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_failure_handlers_serialize(
+        _param = self._get_workflow_status_serialize(
             id=id,
-            offset=offset,
-            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -21132,7 +20920,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListFailureHandlersResponse",
+            '200': "WorkflowStatusModel",
+            '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -21148,11 +20937,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_failure_handlers_with_http_info(
+    def get_workflow_status_with_http_info(
         self,
         id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -21165,17 +20952,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListFailureHandlersResponse]:
-        """List failure handlers for a workflow.
+    ) -> ApiResponse[WorkflowStatusModel]:
+        """Return the workflow status.
 
-        List all failure handlers for a workflow.
+        Return the workflow status.
 
         :param id: Workflow ID (required)
         :type id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -21198,10 +20981,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_failure_handlers_serialize(
+        _param = self._get_workflow_status_serialize(
             id=id,
-            offset=offset,
-            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -21209,7 +20990,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListFailureHandlersResponse",
+            '200': "WorkflowStatusModel",
+            '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -21225,11 +21007,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_failure_handlers_without_preload_content(
+    def get_workflow_status_without_preload_content(
         self,
         id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -21243,16 +21023,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """List failure handlers for a workflow.
+        """Return the workflow status.
 
-        List all failure handlers for a workflow.
+        Return the workflow status.
 
         :param id: Workflow ID (required)
         :type id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -21275,10 +21051,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_failure_handlers_serialize(
+        _param = self._get_workflow_status_serialize(
             id=id,
-            offset=offset,
-            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -21286,7 +21060,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListFailureHandlersResponse",
+            '200': "WorkflowStatusModel",
+            '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -21297,11 +21072,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_failure_handlers_serialize(
+    def _get_workflow_status_serialize(
         self,
         id,
-        offset,
-        limit,
         _request_auth,
         _content_type,
         _headers,
@@ -21326,14 +21099,6 @@ class DefaultApi:
         if id is not None:
             _path_params['id'] = id
         # process the query parameters
-        if offset is not None:
-            
-            _query_params.append(('offset', offset))
-            
-        if limit is not None:
-            
-            _query_params.append(('limit', limit))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -21354,7 +21119,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}/failure_handlers',
+            resource_path='/workflows/{id}/status',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -21371,17 +21136,12 @@ class DefaultApi:
 
 
     @validate_call
-    def list_files(
+    def initialize_jobs(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        produced_by_job_id: Annotated[Optional[StrictInt], Field(description="Return files produced by a specific job.")] = None,
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        name: Optional[StrictStr] = None,
-        path: Optional[StrictStr] = None,
-        is_output: Annotated[Optional[StrictBool], Field(description="Filter for files that are outputs of jobs (appear in job_output_file table)")] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        only_uninitialized: Annotated[Optional[StrictBool], Field(description="Only initialize jobs with a status of uninitialized.")] = None,
+        clear_ephemeral_user_data: Annotated[Optional[StrictBool], Field(description="Clear all ephemeral user data.")] = None,
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -21394,29 +21154,19 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListFilesResponse:
-        """Retrieve all files for one workflow.
+    ) -> object:
+        """Initialize job relationships based on file and user_data relationships.
 
-        Retrieve all files for one workflow.
+        Initialize job relationships based on file and user_data relationships.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param produced_by_job_id: Return files produced by a specific job.
-        :type produced_by_job_id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param name:
-        :type name: str
-        :param path:
-        :type path: str
-        :param is_output: Filter for files that are outputs of jobs (appear in job_output_file table)
-        :type is_output: bool
+        :param id: Workflow ID (required)
+        :type id: int
+        :param only_uninitialized: Only initialize jobs with a status of uninitialized.
+        :type only_uninitialized: bool
+        :param clear_ephemeral_user_data: Clear all ephemeral user data.
+        :type clear_ephemeral_user_data: bool
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -21439,16 +21189,11 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_files_serialize(
-            workflow_id=workflow_id,
-            produced_by_job_id=produced_by_job_id,
-            offset=offset,
-            limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            name=name,
-            path=path,
-            is_output=is_output,
+        _param = self._initialize_jobs_serialize(
+            id=id,
+            only_uninitialized=only_uninitialized,
+            clear_ephemeral_user_data=clear_ephemeral_user_data,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -21456,7 +21201,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListFilesResponse",
+            '200': "object",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -21471,17 +21218,12 @@ class DefaultApi:
 
 
     @validate_call
-    def list_files_with_http_info(
+    def initialize_jobs_with_http_info(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        produced_by_job_id: Annotated[Optional[StrictInt], Field(description="Return files produced by a specific job.")] = None,
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        name: Optional[StrictStr] = None,
-        path: Optional[StrictStr] = None,
-        is_output: Annotated[Optional[StrictBool], Field(description="Filter for files that are outputs of jobs (appear in job_output_file table)")] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        only_uninitialized: Annotated[Optional[StrictBool], Field(description="Only initialize jobs with a status of uninitialized.")] = None,
+        clear_ephemeral_user_data: Annotated[Optional[StrictBool], Field(description="Clear all ephemeral user data.")] = None,
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -21494,29 +21236,19 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListFilesResponse]:
-        """Retrieve all files for one workflow.
+    ) -> ApiResponse[object]:
+        """Initialize job relationships based on file and user_data relationships.
 
-        Retrieve all files for one workflow.
+        Initialize job relationships based on file and user_data relationships.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param produced_by_job_id: Return files produced by a specific job.
-        :type produced_by_job_id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param name:
-        :type name: str
-        :param path:
-        :type path: str
-        :param is_output: Filter for files that are outputs of jobs (appear in job_output_file table)
-        :type is_output: bool
+        :param id: Workflow ID (required)
+        :type id: int
+        :param only_uninitialized: Only initialize jobs with a status of uninitialized.
+        :type only_uninitialized: bool
+        :param clear_ephemeral_user_data: Clear all ephemeral user data.
+        :type clear_ephemeral_user_data: bool
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -21539,16 +21271,11 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_files_serialize(
-            workflow_id=workflow_id,
-            produced_by_job_id=produced_by_job_id,
-            offset=offset,
-            limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            name=name,
-            path=path,
-            is_output=is_output,
+        _param = self._initialize_jobs_serialize(
+            id=id,
+            only_uninitialized=only_uninitialized,
+            clear_ephemeral_user_data=clear_ephemeral_user_data,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -21556,8 +21283,10 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListFilesResponse",
-            '500': "DefaultErrorResponse",
+            '200': "object",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
             *_param,
@@ -21571,17 +21300,12 @@ class DefaultApi:
 
 
     @validate_call
-    def list_files_without_preload_content(
+    def initialize_jobs_without_preload_content(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        produced_by_job_id: Annotated[Optional[StrictInt], Field(description="Return files produced by a specific job.")] = None,
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        name: Optional[StrictStr] = None,
-        path: Optional[StrictStr] = None,
-        is_output: Annotated[Optional[StrictBool], Field(description="Filter for files that are outputs of jobs (appear in job_output_file table)")] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        only_uninitialized: Annotated[Optional[StrictBool], Field(description="Only initialize jobs with a status of uninitialized.")] = None,
+        clear_ephemeral_user_data: Annotated[Optional[StrictBool], Field(description="Clear all ephemeral user data.")] = None,
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -21595,28 +21319,18 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve all files for one workflow.
+        """Initialize job relationships based on file and user_data relationships.
 
-        Retrieve all files for one workflow.
+        Initialize job relationships based on file and user_data relationships.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param produced_by_job_id: Return files produced by a specific job.
-        :type produced_by_job_id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param name:
-        :type name: str
-        :param path:
-        :type path: str
-        :param is_output: Filter for files that are outputs of jobs (appear in job_output_file table)
-        :type is_output: bool
+        :param id: Workflow ID (required)
+        :type id: int
+        :param only_uninitialized: Only initialize jobs with a status of uninitialized.
+        :type only_uninitialized: bool
+        :param clear_ephemeral_user_data: Clear all ephemeral user data.
+        :type clear_ephemeral_user_data: bool
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -21639,16 +21353,11 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_files_serialize(
-            workflow_id=workflow_id,
-            produced_by_job_id=produced_by_job_id,
-            offset=offset,
-            limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            name=name,
-            path=path,
-            is_output=is_output,
+        _param = self._initialize_jobs_serialize(
+            id=id,
+            only_uninitialized=only_uninitialized,
+            clear_ephemeral_user_data=clear_ephemeral_user_data,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -21656,7 +21365,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListFilesResponse",
+            '200': "object",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -21666,17 +21377,12 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_files_serialize(
+    def _initialize_jobs_serialize(
         self,
-        workflow_id,
-        produced_by_job_id,
-        offset,
-        limit,
-        sort_by,
-        reverse_sort,
-        name,
-        path,
-        is_output,
+        id,
+        only_uninitialized,
+        clear_ephemeral_user_data,
+        body,
         _request_auth,
         _content_type,
         _headers,
@@ -21698,46 +21404,22 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
-        if workflow_id is not None:
-            
-            _query_params.append(('workflow_id', workflow_id))
-            
-        if produced_by_job_id is not None:
-            
-            _query_params.append(('produced_by_job_id', produced_by_job_id))
-            
-        if offset is not None:
-            
-            _query_params.append(('offset', offset))
-            
-        if limit is not None:
-            
-            _query_params.append(('limit', limit))
-            
-        if sort_by is not None:
-            
-            _query_params.append(('sort_by', sort_by))
-            
-        if reverse_sort is not None:
-            
-            _query_params.append(('reverse_sort', reverse_sort))
-            
-        if name is not None:
-            
-            _query_params.append(('name', name))
-            
-        if path is not None:
+        if only_uninitialized is not None:
             
-            _query_params.append(('path', path))
+            _query_params.append(('only_uninitialized', only_uninitialized))
             
-        if is_output is not None:
+        if clear_ephemeral_user_data is not None:
             
-            _query_params.append(('is_output', is_output))
+            _query_params.append(('clear_ephemeral_user_data', clear_ephemeral_user_data))
             
         # process the header parameters
         # process the form parameters
         # process the body parameter
+        if body is not None:
+            _body_params = body
 
 
         # set the HTTP header `Accept`
@@ -21748,14 +21430,27 @@ class DefaultApi:
                 ]
             )
 
+        # set the HTTP header `Content-Type`
+        if _content_type:
+            _header_params['Content-Type'] = _content_type
+        else:
+            _default_content_type = (
+                self.api_client.select_header_content_type(
+                    [
+                        'application/json'
+                    ]
+                )
+            )
+            if _default_content_type is not None:
+                _header_params['Content-Type'] = _default_content_type
 
         # authentication setting
         _auth_settings: List[str] = [
         ]
 
         return self.api_client.param_serialize(
-            method='GET',
-            resource_path='/files',
+            method='POST',
+            resource_path='/workflows/{id}/initialize_jobs',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -21772,11 +21467,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_group_members(
+    def is_workflow_complete(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the access group")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -21789,17 +21482,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListUserGroupMembershipsResponse:
-        """List members of an access group.
+    ) -> IsCompleteResponse:
+        """Return true if all jobs in the workflow are complete.
 
-        List members of an access group.
+        Return true if all jobs in the workflow are complete.
 
-        :param id: ID of the access group (required)
+        :param id: Workflow ID (required)
         :type id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -21822,10 +21511,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_group_members_serialize(
+        _param = self._is_workflow_complete_serialize(
             id=id,
-            offset=offset,
-            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -21833,8 +21520,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListUserGroupMembershipsResponse",
-            '404': "DefaultErrorResponse",
+            '200': "IsCompleteResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -21849,11 +21535,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_group_members_with_http_info(
+    def is_workflow_complete_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the access group")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -21866,17 +21550,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListUserGroupMembershipsResponse]:
-        """List members of an access group.
+    ) -> ApiResponse[IsCompleteResponse]:
+        """Return true if all jobs in the workflow are complete.
 
-        List members of an access group.
+        Return true if all jobs in the workflow are complete.
 
-        :param id: ID of the access group (required)
+        :param id: Workflow ID (required)
         :type id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -21899,10 +21579,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_group_members_serialize(
+        _param = self._is_workflow_complete_serialize(
             id=id,
-            offset=offset,
-            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -21910,8 +21588,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListUserGroupMembershipsResponse",
-            '404': "DefaultErrorResponse",
+            '200': "IsCompleteResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -21926,11 +21603,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_group_members_without_preload_content(
+    def is_workflow_complete_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the access group")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -21944,16 +21619,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """List members of an access group.
+        """Return true if all jobs in the workflow are complete.
 
-        List members of an access group.
+        Return true if all jobs in the workflow are complete.
 
-        :param id: ID of the access group (required)
+        :param id: Workflow ID (required)
         :type id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -21976,10 +21647,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_group_members_serialize(
+        _param = self._is_workflow_complete_serialize(
             id=id,
-            offset=offset,
-            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -21987,8 +21656,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListUserGroupMembershipsResponse",
-            '404': "DefaultErrorResponse",
+            '200': "IsCompleteResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -21998,11 +21666,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_group_members_serialize(
+    def _is_workflow_complete_serialize(
         self,
         id,
-        offset,
-        limit,
         _request_auth,
         _content_type,
         _headers,
@@ -22027,14 +21693,6 @@ class DefaultApi:
         if id is not None:
             _path_params['id'] = id
         # process the query parameters
-        if offset is not None:
-            
-            _query_params.append(('offset', offset))
-            
-        if limit is not None:
-            
-            _query_params.append(('limit', limit))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -22055,7 +21713,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/access_groups/{id}/members',
+            resource_path='/workflows/{id}/is_complete',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -22072,11 +21730,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_job_dependencies(
+    def is_workflow_uninitialized(
         self,
         id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -22089,17 +21745,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListJobDependenciesResponse:
-        """Retrieve job blocking relationships for a workflow.
+    ) -> IsUninitializedResponse:
+        """Return true if all jobs in the workflow are uninitialized or disabled.
 
-        Retrieve all job blocking relationships for one workflow from the job_depends_on table.
+        Return true if all jobs in the workflow are uninitialized or disabled.
 
         :param id: Workflow ID (required)
         :type id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -22122,10 +21774,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_job_dependencies_serialize(
+        _param = self._is_workflow_uninitialized_serialize(
             id=id,
-            offset=offset,
-            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -22133,9 +21783,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListJobDependenciesResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "IsUninitializedResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -22150,11 +21798,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_job_dependencies_with_http_info(
+    def is_workflow_uninitialized_with_http_info(
         self,
         id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -22167,17 +21813,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListJobDependenciesResponse]:
-        """Retrieve job blocking relationships for a workflow.
+    ) -> ApiResponse[IsUninitializedResponse]:
+        """Return true if all jobs in the workflow are uninitialized or disabled.
 
-        Retrieve all job blocking relationships for one workflow from the job_depends_on table.
+        Return true if all jobs in the workflow are uninitialized or disabled.
 
         :param id: Workflow ID (required)
         :type id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -22200,10 +21842,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_job_dependencies_serialize(
+        _param = self._is_workflow_uninitialized_serialize(
             id=id,
-            offset=offset,
-            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -22211,9 +21851,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListJobDependenciesResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "IsUninitializedResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -22228,11 +21866,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_job_dependencies_without_preload_content(
+    def is_workflow_uninitialized_without_preload_content(
         self,
         id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -22246,16 +21882,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve job blocking relationships for a workflow.
+        """Return true if all jobs in the workflow are uninitialized or disabled.
 
-        Retrieve all job blocking relationships for one workflow from the job_depends_on table.
+        Return true if all jobs in the workflow are uninitialized or disabled.
 
         :param id: Workflow ID (required)
         :type id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -22278,10 +21910,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_job_dependencies_serialize(
+        _param = self._is_workflow_uninitialized_serialize(
             id=id,
-            offset=offset,
-            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -22289,9 +21919,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListJobDependenciesResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "IsUninitializedResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -22301,11 +21929,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_job_dependencies_serialize(
+    def _is_workflow_uninitialized_serialize(
         self,
         id,
-        offset,
-        limit,
         _request_auth,
         _content_type,
         _headers,
@@ -22330,14 +21956,6 @@ class DefaultApi:
         if id is not None:
             _path_params['id'] = id
         # process the query parameters
-        if offset is not None:
-            
-            _query_params.append(('offset', offset))
-            
-        if limit is not None:
-            
-            _query_params.append(('limit', limit))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -22358,7 +21976,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}/job_dependencies',
+            resource_path='/workflows/{id}/is_uninitialized',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -22375,9 +21993,8 @@ class DefaultApi:
 
 
     @validate_call
-    def list_job_file_relationships(
+    def list_access_groups(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
         _request_timeout: Union[
@@ -22392,13 +22009,11 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListJobFileRelationshipsResponse:
-        """Retrieve job-file relationships for a workflow.
+    ) -> ListAccessGroupsResponse:
+        """List all access groups.
 
-        Retrieve all job-file relationships for one workflow from the job_input_file and job_output_file tables.
+        List all access groups.
 
-        :param id: Workflow ID (required)
-        :type id: int
         :param offset:
         :type offset: int
         :param limit:
@@ -22425,8 +22040,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_job_file_relationships_serialize(
-            id=id,
+        _param = self._list_access_groups_serialize(
             offset=offset,
             limit=limit,
             _request_auth=_request_auth,
@@ -22436,9 +22050,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListJobFileRelationshipsResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListAccessGroupsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -22453,9 +22065,8 @@ class DefaultApi:
 
 
     @validate_call
-    def list_job_file_relationships_with_http_info(
+    def list_access_groups_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
         _request_timeout: Union[
@@ -22470,13 +22081,11 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListJobFileRelationshipsResponse]:
-        """Retrieve job-file relationships for a workflow.
+    ) -> ApiResponse[ListAccessGroupsResponse]:
+        """List all access groups.
 
-        Retrieve all job-file relationships for one workflow from the job_input_file and job_output_file tables.
+        List all access groups.
 
-        :param id: Workflow ID (required)
-        :type id: int
         :param offset:
         :type offset: int
         :param limit:
@@ -22503,8 +22112,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_job_file_relationships_serialize(
-            id=id,
+        _param = self._list_access_groups_serialize(
             offset=offset,
             limit=limit,
             _request_auth=_request_auth,
@@ -22514,9 +22122,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListJobFileRelationshipsResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListAccessGroupsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -22531,9 +22137,8 @@ class DefaultApi:
 
 
     @validate_call
-    def list_job_file_relationships_without_preload_content(
+    def list_access_groups_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
         _request_timeout: Union[
@@ -22549,12 +22154,10 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve job-file relationships for a workflow.
+        """List all access groups.
 
-        Retrieve all job-file relationships for one workflow from the job_input_file and job_output_file tables.
+        List all access groups.
 
-        :param id: Workflow ID (required)
-        :type id: int
         :param offset:
         :type offset: int
         :param limit:
@@ -22581,8 +22184,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_job_file_relationships_serialize(
-            id=id,
+        _param = self._list_access_groups_serialize(
             offset=offset,
             limit=limit,
             _request_auth=_request_auth,
@@ -22592,9 +22194,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListJobFileRelationshipsResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListAccessGroupsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -22604,9 +22204,8 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_job_file_relationships_serialize(
+    def _list_access_groups_serialize(
         self,
-        id,
         offset,
         limit,
         _request_auth,
@@ -22630,8 +22229,6 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
         if offset is not None:
             
@@ -22661,7 +22258,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}/job_file_relationships',
+            resource_path='/access_groups',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -22678,9 +22275,10 @@ class DefaultApi:
 
 
     @validate_call
-    def list_job_ids(
+    def list_api_tokens(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -22693,13 +22291,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListJobIdsResponse:
-        """Retrieve all job IDs for one workflow.
+    ) -> ListApiTokensResponse:
+        """List API tokens.
 
-        Retrieve all job IDs for one workflow.
+        List API tokens. Non-admin users only see their own tokens.
 
-        :param id: Workflow ID (required)
-        :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -22722,8 +22322,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_job_ids_serialize(
-            id=id,
+        _param = self._list_api_tokens_serialize(
+            offset=offset,
+            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -22731,7 +22332,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListJobIdsResponse",
+            '200': "ListApiTokensResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -22746,9 +22347,10 @@ class DefaultApi:
 
 
     @validate_call
-    def list_job_ids_with_http_info(
+    def list_api_tokens_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -22761,13 +22363,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListJobIdsResponse]:
-        """Retrieve all job IDs for one workflow.
+    ) -> ApiResponse[ListApiTokensResponse]:
+        """List API tokens.
 
-        Retrieve all job IDs for one workflow.
+        List API tokens. Non-admin users only see their own tokens.
 
-        :param id: Workflow ID (required)
-        :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -22790,8 +22394,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_job_ids_serialize(
-            id=id,
+        _param = self._list_api_tokens_serialize(
+            offset=offset,
+            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -22799,7 +22404,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListJobIdsResponse",
+            '200': "ListApiTokensResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -22814,136 +22419,8 @@ class DefaultApi:
 
 
     @validate_call
-    def list_job_ids_without_preload_content(
-        self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        _request_timeout: Union[
-            None,
-            Annotated[StrictFloat, Field(gt=0)],
-            Tuple[
-                Annotated[StrictFloat, Field(gt=0)],
-                Annotated[StrictFloat, Field(gt=0)]
-            ]
-        ] = None,
-        _request_auth: Optional[Dict[StrictStr, Any]] = None,
-        _content_type: Optional[StrictStr] = None,
-        _headers: Optional[Dict[StrictStr, Any]] = None,
-        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> RESTResponseType:
-        """Retrieve all job IDs for one workflow.
-
-        Retrieve all job IDs for one workflow.
-
-        :param id: Workflow ID (required)
-        :type id: int
-        :param _request_timeout: timeout setting for this request. If one
-                                 number provided, it will be total request
-                                 timeout. It can also be a pair (tuple) of
-                                 (connection, read) timeouts.
-        :type _request_timeout: int, tuple(int, int), optional
-        :param _request_auth: set to override the auth_settings for an a single
-                              request; this effectively ignores the
-                              authentication in the spec for a single request.
-        :type _request_auth: dict, optional
-        :param _content_type: force content-type for the request.
-        :type _content_type: str, Optional
-        :param _headers: set to override the headers for a single
-                         request; this effectively ignores the headers
-                         in the spec for a single request.
-        :type _headers: dict, optional
-        :param _host_index: set to override the host_index for a single
-                            request; this effectively ignores the host_index
-                            in the spec for a single request.
-        :type _host_index: int, optional
-        :return: Returns the result object.
-        """ # noqa: E501
-
-        _param = self._list_job_ids_serialize(
-            id=id,
-            _request_auth=_request_auth,
-            _content_type=_content_type,
-            _headers=_headers,
-            _host_index=_host_index
-        )
-
-        _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListJobIdsResponse",
-            '500': "DefaultErrorResponse",
-        }
-        response_data = self.api_client.call_api(
-            *_param,
-            _request_timeout=_request_timeout
-        )
-        return response_data.response
-
-
-    def _list_job_ids_serialize(
-        self,
-        id,
-        _request_auth,
-        _content_type,
-        _headers,
-        _host_index,
-    ) -> RequestSerialized:
-
-        _host = None
-
-        _collection_formats: Dict[str, str] = {
-        }
-
-        _path_params: Dict[str, str] = {}
-        _query_params: List[Tuple[str, str]] = []
-        _header_params: Dict[str, Optional[str]] = _headers or {}
-        _form_params: List[Tuple[str, str]] = []
-        _files: Dict[
-            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
-        ] = {}
-        _body_params: Optional[bytes] = None
-
-        # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
-        # process the query parameters
-        # process the header parameters
-        # process the form parameters
-        # process the body parameter
-
-
-        # set the HTTP header `Accept`
-        if 'Accept' not in _header_params:
-            _header_params['Accept'] = self.api_client.select_header_accept(
-                [
-                    'application/json'
-                ]
-            )
-
-
-        # authentication setting
-        _auth_settings: List[str] = [
-        ]
-
-        return self.api_client.param_serialize(
-            method='GET',
-            resource_path='/workflows/{id}/job_ids',
-            path_params=_path_params,
-            query_params=_query_params,
-            header_params=_header_params,
-            body=_body_params,
-            post_params=_form_params,
-            files=_files,
-            auth_settings=_auth_settings,
-            collection_formats=_collection_formats,
-            _host=_host,
-            _request_auth=_request_auth
-        )
-
-
-
-
-    @validate_call
-    def list_job_user_data_relationships(
+    def list_api_tokens_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
         _request_timeout: Union[
@@ -22958,13 +22435,11 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListJobUserDataRelationshipsResponse:
-        """Retrieve job-user_data relationships for a workflow.
+    ) -> RESTResponseType:
+        """List API tokens.
 
-        Retrieve all job-user_data relationships for one workflow from the job_input_user_data and job_output_user_data tables.
+        List API tokens. Non-admin users only see their own tokens.
 
-        :param id: Workflow ID (required)
-        :type id: int
         :param offset:
         :type offset: int
         :param limit:
@@ -22991,86 +22466,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_job_user_data_relationships_serialize(
-            id=id,
-            offset=offset,
-            limit=limit,
-            _request_auth=_request_auth,
-            _content_type=_content_type,
-            _headers=_headers,
-            _host_index=_host_index
-        )
-
-        _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListJobUserDataRelationshipsResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
-            '500': "DefaultErrorResponse",
-        }
-        response_data = self.api_client.call_api(
-            *_param,
-            _request_timeout=_request_timeout
-        )
-        response_data.read()
-        return self.api_client.response_deserialize(
-            response_data=response_data,
-            response_types_map=_response_types_map,
-        ).data
-
-
-    @validate_call
-    def list_job_user_data_relationships_with_http_info(
-        self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
-        _request_timeout: Union[
-            None,
-            Annotated[StrictFloat, Field(gt=0)],
-            Tuple[
-                Annotated[StrictFloat, Field(gt=0)],
-                Annotated[StrictFloat, Field(gt=0)]
-            ]
-        ] = None,
-        _request_auth: Optional[Dict[StrictStr, Any]] = None,
-        _content_type: Optional[StrictStr] = None,
-        _headers: Optional[Dict[StrictStr, Any]] = None,
-        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListJobUserDataRelationshipsResponse]:
-        """Retrieve job-user_data relationships for a workflow.
-
-        Retrieve all job-user_data relationships for one workflow from the job_input_user_data and job_output_user_data tables.
-
-        :param id: Workflow ID (required)
-        :type id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
-        :param _request_timeout: timeout setting for this request. If one
-                                 number provided, it will be total request
-                                 timeout. It can also be a pair (tuple) of
-                                 (connection, read) timeouts.
-        :type _request_timeout: int, tuple(int, int), optional
-        :param _request_auth: set to override the auth_settings for an a single
-                              request; this effectively ignores the
-                              authentication in the spec for a single request.
-        :type _request_auth: dict, optional
-        :param _content_type: force content-type for the request.
-        :type _content_type: str, Optional
-        :param _headers: set to override the headers for a single
-                         request; this effectively ignores the headers
-                         in the spec for a single request.
-        :type _headers: dict, optional
-        :param _host_index: set to override the host_index for a single
-                            request; this effectively ignores the host_index
-                            in the spec for a single request.
-        :type _host_index: int, optional
-        :return: Returns the result object.
-        """ # noqa: E501
-
-        _param = self._list_job_user_data_relationships_serialize(
-            id=id,
+        _param = self._list_api_tokens_serialize(
             offset=offset,
             limit=limit,
             _request_auth=_request_auth,
@@ -23080,87 +22476,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListJobUserDataRelationshipsResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
-            '500': "DefaultErrorResponse",
-        }
-        response_data = self.api_client.call_api(
-            *_param,
-            _request_timeout=_request_timeout
-        )
-        response_data.read()
-        return self.api_client.response_deserialize(
-            response_data=response_data,
-            response_types_map=_response_types_map,
-        )
-
-
-    @validate_call
-    def list_job_user_data_relationships_without_preload_content(
-        self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
-        _request_timeout: Union[
-            None,
-            Annotated[StrictFloat, Field(gt=0)],
-            Tuple[
-                Annotated[StrictFloat, Field(gt=0)],
-                Annotated[StrictFloat, Field(gt=0)]
-            ]
-        ] = None,
-        _request_auth: Optional[Dict[StrictStr, Any]] = None,
-        _content_type: Optional[StrictStr] = None,
-        _headers: Optional[Dict[StrictStr, Any]] = None,
-        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> RESTResponseType:
-        """Retrieve job-user_data relationships for a workflow.
-
-        Retrieve all job-user_data relationships for one workflow from the job_input_user_data and job_output_user_data tables.
-
-        :param id: Workflow ID (required)
-        :type id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
-        :param _request_timeout: timeout setting for this request. If one
-                                 number provided, it will be total request
-                                 timeout. It can also be a pair (tuple) of
-                                 (connection, read) timeouts.
-        :type _request_timeout: int, tuple(int, int), optional
-        :param _request_auth: set to override the auth_settings for an a single
-                              request; this effectively ignores the
-                              authentication in the spec for a single request.
-        :type _request_auth: dict, optional
-        :param _content_type: force content-type for the request.
-        :type _content_type: str, Optional
-        :param _headers: set to override the headers for a single
-                         request; this effectively ignores the headers
-                         in the spec for a single request.
-        :type _headers: dict, optional
-        :param _host_index: set to override the host_index for a single
-                            request; this effectively ignores the host_index
-                            in the spec for a single request.
-        :type _host_index: int, optional
-        :return: Returns the result object.
-        """ # noqa: E501
-
-        _param = self._list_job_user_data_relationships_serialize(
-            id=id,
-            offset=offset,
-            limit=limit,
-            _request_auth=_request_auth,
-            _content_type=_content_type,
-            _headers=_headers,
-            _host_index=_host_index
-        )
-
-        _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListJobUserDataRelationshipsResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListApiTokensResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -23170,9 +22486,8 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_job_user_data_relationships_serialize(
+    def _list_api_tokens_serialize(
         self,
-        id,
         offset,
         limit,
         _request_auth,
@@ -23196,8 +22511,6 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
         if offset is not None:
             
@@ -23227,7 +22540,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}/job_user_data_relationships',
+            resource_path='/api_tokens',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -23244,18 +22557,16 @@ class DefaultApi:
 
 
     @validate_call
-    def list_jobs(
+    def list_compute_nodes(
         self,
         workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        status: Annotated[Optional[JobStatus], Field(description="Return jobs with this status.")] = None,
-        needs_file_id: Annotated[Optional[StrictInt], Field(description="Return jobs that need this file as an input.")] = None,
-        upstream_job_id: Annotated[Optional[StrictInt], Field(description="Return jobs that are downstream of this job ID in the workflow graph.")] = None,
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
         sort_by: Optional[StrictStr] = None,
         reverse_sort: Optional[StrictBool] = None,
-        include_relationships: Annotated[Optional[StrictBool], Field(description="Include job relationships (depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids). Default is false for performance.")] = None,
-        active_compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter jobs by the compute node currently running them.")] = None,
+        hostname: Optional[StrictStr] = None,
+        is_active: Optional[StrictBool] = None,
+        scheduled_compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter by scheduled compute node ID (filters compute nodes created by this scheduler)")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -23268,19 +22579,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListJobsResponse:
-        """Retrieve all jobs for one workflow.
+    ) -> ListComputeNodesResponse:
+        """Retrieve all compute node records for one workflow.
 
-        Retrieve all jobs for one workflow.
+        Retrieve all compute node records for one workflow.
 
         :param workflow_id: Workflow ID (required)
         :type workflow_id: int
-        :param status: Return jobs with this status.
-        :type status: JobStatus
-        :param needs_file_id: Return jobs that need this file as an input.
-        :type needs_file_id: int
-        :param upstream_job_id: Return jobs that are downstream of this job ID in the workflow graph.
-        :type upstream_job_id: int
         :param offset:
         :type offset: int
         :param limit:
@@ -23289,10 +22594,12 @@ class DefaultApi:
         :type sort_by: str
         :param reverse_sort:
         :type reverse_sort: bool
-        :param include_relationships: Include job relationships (depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids). Default is false for performance.
-        :type include_relationships: bool
-        :param active_compute_node_id: Filter jobs by the compute node currently running them.
-        :type active_compute_node_id: int
+        :param hostname:
+        :type hostname: str
+        :param is_active:
+        :type is_active: bool
+        :param scheduled_compute_node_id: Filter by scheduled compute node ID (filters compute nodes created by this scheduler)
+        :type scheduled_compute_node_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -23315,17 +22622,15 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_jobs_serialize(
+        _param = self._list_compute_nodes_serialize(
             workflow_id=workflow_id,
-            status=status,
-            needs_file_id=needs_file_id,
-            upstream_job_id=upstream_job_id,
             offset=offset,
             limit=limit,
             sort_by=sort_by,
             reverse_sort=reverse_sort,
-            include_relationships=include_relationships,
-            active_compute_node_id=active_compute_node_id,
+            hostname=hostname,
+            is_active=is_active,
+            scheduled_compute_node_id=scheduled_compute_node_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -23333,9 +22638,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListJobsResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListComputeNodesResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -23350,18 +22653,16 @@ class DefaultApi:
 
 
     @validate_call
-    def list_jobs_with_http_info(
+    def list_compute_nodes_with_http_info(
         self,
         workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        status: Annotated[Optional[JobStatus], Field(description="Return jobs with this status.")] = None,
-        needs_file_id: Annotated[Optional[StrictInt], Field(description="Return jobs that need this file as an input.")] = None,
-        upstream_job_id: Annotated[Optional[StrictInt], Field(description="Return jobs that are downstream of this job ID in the workflow graph.")] = None,
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
         sort_by: Optional[StrictStr] = None,
         reverse_sort: Optional[StrictBool] = None,
-        include_relationships: Annotated[Optional[StrictBool], Field(description="Include job relationships (depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids). Default is false for performance.")] = None,
-        active_compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter jobs by the compute node currently running them.")] = None,
+        hostname: Optional[StrictStr] = None,
+        is_active: Optional[StrictBool] = None,
+        scheduled_compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter by scheduled compute node ID (filters compute nodes created by this scheduler)")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -23374,19 +22675,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListJobsResponse]:
-        """Retrieve all jobs for one workflow.
+    ) -> ApiResponse[ListComputeNodesResponse]:
+        """Retrieve all compute node records for one workflow.
 
-        Retrieve all jobs for one workflow.
+        Retrieve all compute node records for one workflow.
 
         :param workflow_id: Workflow ID (required)
         :type workflow_id: int
-        :param status: Return jobs with this status.
-        :type status: JobStatus
-        :param needs_file_id: Return jobs that need this file as an input.
-        :type needs_file_id: int
-        :param upstream_job_id: Return jobs that are downstream of this job ID in the workflow graph.
-        :type upstream_job_id: int
         :param offset:
         :type offset: int
         :param limit:
@@ -23395,10 +22690,12 @@ class DefaultApi:
         :type sort_by: str
         :param reverse_sort:
         :type reverse_sort: bool
-        :param include_relationships: Include job relationships (depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids). Default is false for performance.
-        :type include_relationships: bool
-        :param active_compute_node_id: Filter jobs by the compute node currently running them.
-        :type active_compute_node_id: int
+        :param hostname:
+        :type hostname: str
+        :param is_active:
+        :type is_active: bool
+        :param scheduled_compute_node_id: Filter by scheduled compute node ID (filters compute nodes created by this scheduler)
+        :type scheduled_compute_node_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -23421,17 +22718,15 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_jobs_serialize(
+        _param = self._list_compute_nodes_serialize(
             workflow_id=workflow_id,
-            status=status,
-            needs_file_id=needs_file_id,
-            upstream_job_id=upstream_job_id,
             offset=offset,
             limit=limit,
             sort_by=sort_by,
             reverse_sort=reverse_sort,
-            include_relationships=include_relationships,
-            active_compute_node_id=active_compute_node_id,
+            hostname=hostname,
+            is_active=is_active,
+            scheduled_compute_node_id=scheduled_compute_node_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -23439,9 +22734,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListJobsResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListComputeNodesResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -23456,18 +22749,16 @@ class DefaultApi:
 
 
     @validate_call
-    def list_jobs_without_preload_content(
+    def list_compute_nodes_without_preload_content(
         self,
         workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        status: Annotated[Optional[JobStatus], Field(description="Return jobs with this status.")] = None,
-        needs_file_id: Annotated[Optional[StrictInt], Field(description="Return jobs that need this file as an input.")] = None,
-        upstream_job_id: Annotated[Optional[StrictInt], Field(description="Return jobs that are downstream of this job ID in the workflow graph.")] = None,
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
         sort_by: Optional[StrictStr] = None,
         reverse_sort: Optional[StrictBool] = None,
-        include_relationships: Annotated[Optional[StrictBool], Field(description="Include job relationships (depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids). Default is false for performance.")] = None,
-        active_compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter jobs by the compute node currently running them.")] = None,
+        hostname: Optional[StrictStr] = None,
+        is_active: Optional[StrictBool] = None,
+        scheduled_compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter by scheduled compute node ID (filters compute nodes created by this scheduler)")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -23481,18 +22772,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve all jobs for one workflow.
+        """Retrieve all compute node records for one workflow.
 
-        Retrieve all jobs for one workflow.
+        Retrieve all compute node records for one workflow.
 
         :param workflow_id: Workflow ID (required)
         :type workflow_id: int
-        :param status: Return jobs with this status.
-        :type status: JobStatus
-        :param needs_file_id: Return jobs that need this file as an input.
-        :type needs_file_id: int
-        :param upstream_job_id: Return jobs that are downstream of this job ID in the workflow graph.
-        :type upstream_job_id: int
         :param offset:
         :type offset: int
         :param limit:
@@ -23501,10 +22786,12 @@ class DefaultApi:
         :type sort_by: str
         :param reverse_sort:
         :type reverse_sort: bool
-        :param include_relationships: Include job relationships (depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids). Default is false for performance.
-        :type include_relationships: bool
-        :param active_compute_node_id: Filter jobs by the compute node currently running them.
-        :type active_compute_node_id: int
+        :param hostname:
+        :type hostname: str
+        :param is_active:
+        :type is_active: bool
+        :param scheduled_compute_node_id: Filter by scheduled compute node ID (filters compute nodes created by this scheduler)
+        :type scheduled_compute_node_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -23527,17 +22814,15 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_jobs_serialize(
+        _param = self._list_compute_nodes_serialize(
             workflow_id=workflow_id,
-            status=status,
-            needs_file_id=needs_file_id,
-            upstream_job_id=upstream_job_id,
             offset=offset,
             limit=limit,
             sort_by=sort_by,
             reverse_sort=reverse_sort,
-            include_relationships=include_relationships,
-            active_compute_node_id=active_compute_node_id,
+            hostname=hostname,
+            is_active=is_active,
+            scheduled_compute_node_id=scheduled_compute_node_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -23545,9 +22830,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListJobsResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListComputeNodesResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -23557,18 +22840,16 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_jobs_serialize(
+    def _list_compute_nodes_serialize(
         self,
         workflow_id,
-        status,
-        needs_file_id,
-        upstream_job_id,
         offset,
         limit,
         sort_by,
         reverse_sort,
-        include_relationships,
-        active_compute_node_id,
+        hostname,
+        is_active,
+        scheduled_compute_node_id,
         _request_auth,
         _content_type,
         _headers,
@@ -23595,18 +22876,6 @@ class DefaultApi:
             
             _query_params.append(('workflow_id', workflow_id))
             
-        if status is not None:
-            
-            _query_params.append(('status', status.value))
-            
-        if needs_file_id is not None:
-            
-            _query_params.append(('needs_file_id', needs_file_id))
-            
-        if upstream_job_id is not None:
-            
-            _query_params.append(('upstream_job_id', upstream_job_id))
-            
         if offset is not None:
             
             _query_params.append(('offset', offset))
@@ -23623,13 +22892,17 @@ class DefaultApi:
             
             _query_params.append(('reverse_sort', reverse_sort))
             
-        if include_relationships is not None:
+        if hostname is not None:
             
-            _query_params.append(('include_relationships', include_relationships))
+            _query_params.append(('hostname', hostname))
             
-        if active_compute_node_id is not None:
+        if is_active is not None:
             
-            _query_params.append(('active_compute_node_id', active_compute_node_id))
+            _query_params.append(('is_active', is_active))
+            
+        if scheduled_compute_node_id is not None:
+            
+            _query_params.append(('scheduled_compute_node_id', scheduled_compute_node_id))
             
         # process the header parameters
         # process the form parameters
@@ -23651,7 +22924,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/jobs',
+            resource_path='/compute_nodes',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -23668,15 +22941,15 @@ class DefaultApi:
 
 
     @validate_call
-    def list_local_schedulers(
+    def list_events(
         self,
         workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
         sort_by: Optional[StrictStr] = None,
         reverse_sort: Optional[StrictBool] = None,
-        memory: Optional[StrictStr] = None,
-        num_cpus: Optional[StrictInt] = None,
+        category: Optional[StrictStr] = None,
+        after_timestamp: Annotated[Optional[StrictInt], Field(description="Return events after this timestamp (milliseconds since epoch)")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -23689,10 +22962,10 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListLocalSchedulersResponse:
-        """Retrieve local schedulers for one workflow.
+    ) -> ListEventsResponse:
+        """Retrieve all events for one workflow.
 
-        Retrieve local schedulers for one workflow.
+        Retrieve all events for one workflow.
 
         :param workflow_id: Workflow ID (required)
         :type workflow_id: int
@@ -23704,10 +22977,10 @@ class DefaultApi:
         :type sort_by: str
         :param reverse_sort:
         :type reverse_sort: bool
-        :param memory:
-        :type memory: str
-        :param num_cpus:
-        :type num_cpus: int
+        :param category:
+        :type category: str
+        :param after_timestamp: Return events after this timestamp (milliseconds since epoch)
+        :type after_timestamp: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -23730,14 +23003,14 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_local_schedulers_serialize(
+        _param = self._list_events_serialize(
             workflow_id=workflow_id,
             offset=offset,
             limit=limit,
             sort_by=sort_by,
             reverse_sort=reverse_sort,
-            memory=memory,
-            num_cpus=num_cpus,
+            category=category,
+            after_timestamp=after_timestamp,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -23745,9 +23018,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListLocalSchedulersResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListEventsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -23762,15 +23033,15 @@ class DefaultApi:
 
 
     @validate_call
-    def list_local_schedulers_with_http_info(
+    def list_events_with_http_info(
         self,
         workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
         sort_by: Optional[StrictStr] = None,
         reverse_sort: Optional[StrictBool] = None,
-        memory: Optional[StrictStr] = None,
-        num_cpus: Optional[StrictInt] = None,
+        category: Optional[StrictStr] = None,
+        after_timestamp: Annotated[Optional[StrictInt], Field(description="Return events after this timestamp (milliseconds since epoch)")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -23783,10 +23054,10 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListLocalSchedulersResponse]:
-        """Retrieve local schedulers for one workflow.
+    ) -> ApiResponse[ListEventsResponse]:
+        """Retrieve all events for one workflow.
 
-        Retrieve local schedulers for one workflow.
+        Retrieve all events for one workflow.
 
         :param workflow_id: Workflow ID (required)
         :type workflow_id: int
@@ -23798,10 +23069,10 @@ class DefaultApi:
         :type sort_by: str
         :param reverse_sort:
         :type reverse_sort: bool
-        :param memory:
-        :type memory: str
-        :param num_cpus:
-        :type num_cpus: int
+        :param category:
+        :type category: str
+        :param after_timestamp: Return events after this timestamp (milliseconds since epoch)
+        :type after_timestamp: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -23824,14 +23095,14 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_local_schedulers_serialize(
+        _param = self._list_events_serialize(
             workflow_id=workflow_id,
             offset=offset,
             limit=limit,
             sort_by=sort_by,
             reverse_sort=reverse_sort,
-            memory=memory,
-            num_cpus=num_cpus,
+            category=category,
+            after_timestamp=after_timestamp,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -23839,9 +23110,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListLocalSchedulersResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListEventsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -23856,15 +23125,15 @@ class DefaultApi:
 
 
     @validate_call
-    def list_local_schedulers_without_preload_content(
+    def list_events_without_preload_content(
         self,
         workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
         sort_by: Optional[StrictStr] = None,
         reverse_sort: Optional[StrictBool] = None,
-        memory: Optional[StrictStr] = None,
-        num_cpus: Optional[StrictInt] = None,
+        category: Optional[StrictStr] = None,
+        after_timestamp: Annotated[Optional[StrictInt], Field(description="Return events after this timestamp (milliseconds since epoch)")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -23878,9 +23147,9 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve local schedulers for one workflow.
+        """Retrieve all events for one workflow.
 
-        Retrieve local schedulers for one workflow.
+        Retrieve all events for one workflow.
 
         :param workflow_id: Workflow ID (required)
         :type workflow_id: int
@@ -23892,10 +23161,10 @@ class DefaultApi:
         :type sort_by: str
         :param reverse_sort:
         :type reverse_sort: bool
-        :param memory:
-        :type memory: str
-        :param num_cpus:
-        :type num_cpus: int
+        :param category:
+        :type category: str
+        :param after_timestamp: Return events after this timestamp (milliseconds since epoch)
+        :type after_timestamp: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -23918,14 +23187,14 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_local_schedulers_serialize(
+        _param = self._list_events_serialize(
             workflow_id=workflow_id,
             offset=offset,
             limit=limit,
             sort_by=sort_by,
             reverse_sort=reverse_sort,
-            memory=memory,
-            num_cpus=num_cpus,
+            category=category,
+            after_timestamp=after_timestamp,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -23933,9 +23202,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListLocalSchedulersResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListEventsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -23945,15 +23212,15 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_local_schedulers_serialize(
+    def _list_events_serialize(
         self,
         workflow_id,
         offset,
         limit,
         sort_by,
         reverse_sort,
-        memory,
-        num_cpus,
+        category,
+        after_timestamp,
         _request_auth,
         _content_type,
         _headers,
@@ -23996,13 +23263,13 @@ class DefaultApi:
             
             _query_params.append(('reverse_sort', reverse_sort))
             
-        if memory is not None:
+        if category is not None:
             
-            _query_params.append(('memory', memory))
+            _query_params.append(('category', category))
             
-        if num_cpus is not None:
+        if after_timestamp is not None:
             
-            _query_params.append(('num_cpus', num_cpus))
+            _query_params.append(('after_timestamp', after_timestamp))
             
         # process the header parameters
         # process the form parameters
@@ -24024,7 +23291,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/local_schedulers',
+            resource_path='/events',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -24041,9 +23308,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_missing_user_data(
+    def list_failure_handlers(
         self,
         id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -24056,13 +23325,17 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListMissingUserDataResponse:
-        """List missing user data that should exist.
+    ) -> ListFailureHandlersResponse:
+        """List failure handlers for a workflow.
 
-        List missing user data that should exist.
+        List all failure handlers for a workflow.
 
         :param id: Workflow ID (required)
         :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -24085,8 +23358,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_missing_user_data_serialize(
+        _param = self._list_failure_handlers_serialize(
             id=id,
+            offset=offset,
+            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -24094,7 +23369,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListMissingUserDataResponse",
+            '200': "ListFailureHandlersResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -24109,9 +23385,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_missing_user_data_with_http_info(
+    def list_failure_handlers_with_http_info(
         self,
         id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -24124,13 +23402,17 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListMissingUserDataResponse]:
-        """List missing user data that should exist.
+    ) -> ApiResponse[ListFailureHandlersResponse]:
+        """List failure handlers for a workflow.
 
-        List missing user data that should exist.
+        List all failure handlers for a workflow.
 
         :param id: Workflow ID (required)
         :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -24153,8 +23435,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_missing_user_data_serialize(
+        _param = self._list_failure_handlers_serialize(
             id=id,
+            offset=offset,
+            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -24162,7 +23446,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListMissingUserDataResponse",
+            '200': "ListFailureHandlersResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -24177,9 +23462,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_missing_user_data_without_preload_content(
+    def list_failure_handlers_without_preload_content(
         self,
         id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -24193,12 +23480,16 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """List missing user data that should exist.
+        """List failure handlers for a workflow.
 
-        List missing user data that should exist.
+        List all failure handlers for a workflow.
 
         :param id: Workflow ID (required)
         :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -24221,8 +23512,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_missing_user_data_serialize(
+        _param = self._list_failure_handlers_serialize(
             id=id,
+            offset=offset,
+            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -24230,7 +23523,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListMissingUserDataResponse",
+            '200': "ListFailureHandlersResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -24240,9 +23534,11 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_missing_user_data_serialize(
+    def _list_failure_handlers_serialize(
         self,
         id,
+        offset,
+        limit,
         _request_auth,
         _content_type,
         _headers,
@@ -24267,6 +23563,14 @@ class DefaultApi:
         if id is not None:
             _path_params['id'] = id
         # process the query parameters
+        if offset is not None:
+            
+            _query_params.append(('offset', offset))
+            
+        if limit is not None:
+            
+            _query_params.append(('limit', limit))
+            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -24287,7 +23591,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}/missing_user_data',
+            resource_path='/workflows/{id}/failure_handlers',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -24304,9 +23608,17 @@ class DefaultApi:
 
 
     @validate_call
-    def list_remote_workers(
+    def list_files(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        produced_by_job_id: Annotated[Optional[StrictInt], Field(description="Return files produced by a specific job.")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        name: Optional[StrictStr] = None,
+        path: Optional[StrictStr] = None,
+        is_output: Annotated[Optional[StrictBool], Field(description="Filter for files that are outputs of jobs (appear in job_output_file table)")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -24319,13 +23631,29 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> List[RemoteWorkerModel]:
-        """List all remote workers for a workflow.
+    ) -> ListFilesResponse:
+        """Retrieve all files for one workflow.
 
-        List all remote workers for a workflow.
+        Retrieve all files for one workflow.
 
-        :param id: Workflow ID (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param produced_by_job_id: Return files produced by a specific job.
+        :type produced_by_job_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param name:
+        :type name: str
+        :param path:
+        :type path: str
+        :param is_output: Filter for files that are outputs of jobs (appear in job_output_file table)
+        :type is_output: bool
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -24348,8 +23676,16 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_remote_workers_serialize(
-            id=id,
+        _param = self._list_files_serialize(
+            workflow_id=workflow_id,
+            produced_by_job_id=produced_by_job_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            name=name,
+            path=path,
+            is_output=is_output,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -24357,8 +23693,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "List[RemoteWorkerModel]",
-            '404': "DefaultErrorResponse",
+            '200': "ListFilesResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -24373,9 +23708,17 @@ class DefaultApi:
 
 
     @validate_call
-    def list_remote_workers_with_http_info(
+    def list_files_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        produced_by_job_id: Annotated[Optional[StrictInt], Field(description="Return files produced by a specific job.")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        name: Optional[StrictStr] = None,
+        path: Optional[StrictStr] = None,
+        is_output: Annotated[Optional[StrictBool], Field(description="Filter for files that are outputs of jobs (appear in job_output_file table)")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -24388,13 +23731,29 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[List[RemoteWorkerModel]]:
-        """List all remote workers for a workflow.
+    ) -> ApiResponse[ListFilesResponse]:
+        """Retrieve all files for one workflow.
 
-        List all remote workers for a workflow.
+        Retrieve all files for one workflow.
 
-        :param id: Workflow ID (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param produced_by_job_id: Return files produced by a specific job.
+        :type produced_by_job_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param name:
+        :type name: str
+        :param path:
+        :type path: str
+        :param is_output: Filter for files that are outputs of jobs (appear in job_output_file table)
+        :type is_output: bool
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -24417,8 +23776,16 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_remote_workers_serialize(
-            id=id,
+        _param = self._list_files_serialize(
+            workflow_id=workflow_id,
+            produced_by_job_id=produced_by_job_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            name=name,
+            path=path,
+            is_output=is_output,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -24426,8 +23793,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "List[RemoteWorkerModel]",
-            '404': "DefaultErrorResponse",
+            '200': "ListFilesResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -24442,9 +23808,17 @@ class DefaultApi:
 
 
     @validate_call
-    def list_remote_workers_without_preload_content(
+    def list_files_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        produced_by_job_id: Annotated[Optional[StrictInt], Field(description="Return files produced by a specific job.")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        name: Optional[StrictStr] = None,
+        path: Optional[StrictStr] = None,
+        is_output: Annotated[Optional[StrictBool], Field(description="Filter for files that are outputs of jobs (appear in job_output_file table)")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -24458,12 +23832,28 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """List all remote workers for a workflow.
+        """Retrieve all files for one workflow.
 
-        List all remote workers for a workflow.
+        Retrieve all files for one workflow.
 
-        :param id: Workflow ID (required)
-        :type id: int
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param produced_by_job_id: Return files produced by a specific job.
+        :type produced_by_job_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param name:
+        :type name: str
+        :param path:
+        :type path: str
+        :param is_output: Filter for files that are outputs of jobs (appear in job_output_file table)
+        :type is_output: bool
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -24486,8 +23876,16 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_remote_workers_serialize(
-            id=id,
+        _param = self._list_files_serialize(
+            workflow_id=workflow_id,
+            produced_by_job_id=produced_by_job_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            name=name,
+            path=path,
+            is_output=is_output,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -24495,8 +23893,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "List[RemoteWorkerModel]",
-            '404': "DefaultErrorResponse",
+            '200': "ListFilesResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -24506,9 +23903,17 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_remote_workers_serialize(
+    def _list_files_serialize(
         self,
-        id,
+        workflow_id,
+        produced_by_job_id,
+        offset,
+        limit,
+        sort_by,
+        reverse_sort,
+        name,
+        path,
+        is_output,
         _request_auth,
         _content_type,
         _headers,
@@ -24530,9 +23935,43 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
+        if produced_by_job_id is not None:
+            
+            _query_params.append(('produced_by_job_id', produced_by_job_id))
+            
+        if offset is not None:
+            
+            _query_params.append(('offset', offset))
+            
+        if limit is not None:
+            
+            _query_params.append(('limit', limit))
+            
+        if sort_by is not None:
+            
+            _query_params.append(('sort_by', sort_by))
+            
+        if reverse_sort is not None:
+            
+            _query_params.append(('reverse_sort', reverse_sort))
+            
+        if name is not None:
+            
+            _query_params.append(('name', name))
+            
+        if path is not None:
+            
+            _query_params.append(('path', path))
+            
+        if is_output is not None:
+            
+            _query_params.append(('is_output', is_output))
+            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -24553,7 +23992,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}/remote_workers',
+            resource_path='/files',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -24570,9 +24009,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_required_existing_files(
+    def list_group_members(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the access group")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -24585,13 +24026,17 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListRequiredExistingFilesResponse:
-        """List files that must exist.
+    ) -> ListUserGroupMembershipsResponse:
+        """List members of an access group.
 
-        List files that must exist.
+        List members of an access group.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the access group (required)
         :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -24614,8 +24059,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_required_existing_files_serialize(
+        _param = self._list_group_members_serialize(
             id=id,
+            offset=offset,
+            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -24623,7 +24070,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListRequiredExistingFilesResponse",
+            '200': "ListUserGroupMembershipsResponse",
+            '404': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -24638,9 +24086,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_required_existing_files_with_http_info(
+    def list_group_members_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the access group")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -24653,13 +24103,17 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListRequiredExistingFilesResponse]:
-        """List files that must exist.
+    ) -> ApiResponse[ListUserGroupMembershipsResponse]:
+        """List members of an access group.
 
-        List files that must exist.
+        List members of an access group.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the access group (required)
         :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -24682,8 +24136,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_required_existing_files_serialize(
+        _param = self._list_group_members_serialize(
             id=id,
+            offset=offset,
+            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -24691,7 +24147,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListRequiredExistingFilesResponse",
+            '200': "ListUserGroupMembershipsResponse",
+            '404': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -24706,9 +24163,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_required_existing_files_without_preload_content(
+    def list_group_members_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        id: Annotated[StrictInt, Field(description="ID of the access group")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -24722,12 +24181,16 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """List files that must exist.
+        """List members of an access group.
 
-        List files that must exist.
+        List members of an access group.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the access group (required)
         :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -24750,8 +24213,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_required_existing_files_serialize(
+        _param = self._list_group_members_serialize(
             id=id,
+            offset=offset,
+            limit=limit,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -24759,7 +24224,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListRequiredExistingFilesResponse",
+            '200': "ListUserGroupMembershipsResponse",
+            '404': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -24769,9 +24235,11 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_required_existing_files_serialize(
+    def _list_group_members_serialize(
         self,
         id,
+        offset,
+        limit,
         _request_auth,
         _content_type,
         _headers,
@@ -24796,6 +24264,14 @@ class DefaultApi:
         if id is not None:
             _path_params['id'] = id
         # process the query parameters
+        if offset is not None:
+            
+            _query_params.append(('offset', offset))
+            
+        if limit is not None:
+            
+            _query_params.append(('limit', limit))
+            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -24816,7 +24292,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}/required_existing_files',
+            resource_path='/access_groups/{id}/members',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -24833,20 +24309,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_resource_requirements(
+    def list_job_dependencies(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        job_id: Annotated[Optional[StrictInt], Field(description="Return the resource requirements for a specific job.")] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        name: Optional[StrictStr] = None,
-        memory: Optional[StrictStr] = None,
-        num_cpus: Optional[StrictInt] = None,
-        num_gpus: Optional[StrictInt] = None,
-        num_nodes: Optional[StrictInt] = None,
-        runtime: Optional[StrictInt] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -24859,35 +24326,3406 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListResourceRequirementsResponse:
-        """Retrieve all resource requirements records for one workflow.
+    ) -> ListJobDependenciesResponse:
+        """Retrieve job blocking relationships for a workflow.
 
-        Retrieve all resource requirements records for one workflow.
+        Retrieve all job blocking relationships for one workflow from the job_depends_on table.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param job_id: Return the resource requirements for a specific job.
-        :type job_id: int
+        :param id: Workflow ID (required)
+        :type id: int
         :param offset:
         :type offset: int
         :param limit:
         :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param name:
-        :type name: str
-        :param memory:
-        :type memory: str
-        :param num_cpus:
-        :type num_cpus: int
-        :param num_gpus:
-        :type num_gpus: int
-        :param num_nodes:
-        :type num_nodes: int
-        :param runtime:
-        :type runtime: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_job_dependencies_serialize(
+            id=id,
+            offset=offset,
+            limit=limit,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobDependenciesResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def list_job_dependencies_with_http_info(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[ListJobDependenciesResponse]:
+        """Retrieve job blocking relationships for a workflow.
+
+        Retrieve all job blocking relationships for one workflow from the job_depends_on table.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_job_dependencies_serialize(
+            id=id,
+            offset=offset,
+            limit=limit,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobDependenciesResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def list_job_dependencies_without_preload_content(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """Retrieve job blocking relationships for a workflow.
+
+        Retrieve all job blocking relationships for one workflow from the job_depends_on table.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_job_dependencies_serialize(
+            id=id,
+            offset=offset,
+            limit=limit,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobDependenciesResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _list_job_dependencies_serialize(
+        self,
+        id,
+        offset,
+        limit,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
+        # process the query parameters
+        if offset is not None:
+            
+            _query_params.append(('offset', offset))
+            
+        if limit is not None:
+            
+            _query_params.append(('limit', limit))
+            
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='GET',
+            resource_path='/workflows/{id}/job_dependencies',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
+    @validate_call
+    def list_job_file_relationships(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ListJobFileRelationshipsResponse:
+        """Retrieve job-file relationships for a workflow.
+
+        Retrieve all job-file relationships for one workflow from the job_input_file and job_output_file tables.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_job_file_relationships_serialize(
+            id=id,
+            offset=offset,
+            limit=limit,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobFileRelationshipsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def list_job_file_relationships_with_http_info(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[ListJobFileRelationshipsResponse]:
+        """Retrieve job-file relationships for a workflow.
+
+        Retrieve all job-file relationships for one workflow from the job_input_file and job_output_file tables.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_job_file_relationships_serialize(
+            id=id,
+            offset=offset,
+            limit=limit,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobFileRelationshipsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def list_job_file_relationships_without_preload_content(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """Retrieve job-file relationships for a workflow.
+
+        Retrieve all job-file relationships for one workflow from the job_input_file and job_output_file tables.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_job_file_relationships_serialize(
+            id=id,
+            offset=offset,
+            limit=limit,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobFileRelationshipsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _list_job_file_relationships_serialize(
+        self,
+        id,
+        offset,
+        limit,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
+        # process the query parameters
+        if offset is not None:
+            
+            _query_params.append(('offset', offset))
+            
+        if limit is not None:
+            
+            _query_params.append(('limit', limit))
+            
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='GET',
+            resource_path='/workflows/{id}/job_file_relationships',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
+    @validate_call
+    def list_job_ids(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ListJobIdsResponse:
+        """Retrieve all job IDs for one workflow.
+
+        Retrieve all job IDs for one workflow.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_job_ids_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobIdsResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def list_job_ids_with_http_info(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[ListJobIdsResponse]:
+        """Retrieve all job IDs for one workflow.
+
+        Retrieve all job IDs for one workflow.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_job_ids_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobIdsResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def list_job_ids_without_preload_content(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """Retrieve all job IDs for one workflow.
+
+        Retrieve all job IDs for one workflow.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_job_ids_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobIdsResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _list_job_ids_serialize(
+        self,
+        id,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
+        # process the query parameters
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='GET',
+            resource_path='/workflows/{id}/job_ids',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
+    @validate_call
+    def list_job_logs(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        job_id: Annotated[Optional[StrictInt], Field(description="Only return logs of this job")] = None,
+        run_id: Annotated[Optional[StrictInt], Field(description="Only return logs of this workflow run")] = None,
+        attempt_id: Annotated[Optional[StrictInt], Field(description="Only return logs of this job attempt")] = None,
+        stream: Annotated[Optional[JobLogStream], Field(description="Only return logs of this stream")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ListJobLogsResponse:
+        """List job logs.
+
+        Retrieve the uploaded job logs of a workflow. The content of each log is left empty; fetch it with /job_logs/{id}/content.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param job_id: Only return logs of this job
+        :type job_id: int
+        :param run_id: Only return logs of this workflow run
+        :type run_id: int
+        :param attempt_id: Only return logs of this job attempt
+        :type attempt_id: int
+        :param stream: Only return logs of this stream
+        :type stream: JobLogStream
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_job_logs_serialize(
+            workflow_id=workflow_id,
+            job_id=job_id,
+            run_id=run_id,
+            attempt_id=attempt_id,
+            stream=stream,
+            offset=offset,
+            limit=limit,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobLogsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def list_job_logs_with_http_info(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        job_id: Annotated[Optional[StrictInt], Field(description="Only return logs of this job")] = None,
+        run_id: Annotated[Optional[StrictInt], Field(description="Only return logs of this workflow run")] = None,
+        attempt_id: Annotated[Optional[StrictInt], Field(description="Only return logs of this job attempt")] = None,
+        stream: Annotated[Optional[JobLogStream], Field(description="Only return logs of this stream")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[ListJobLogsResponse]:
+        """List job logs.
+
+        Retrieve the uploaded job logs of a workflow. The content of each log is left empty; fetch it with /job_logs/{id}/content.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param job_id: Only return logs of this job
+        :type job_id: int
+        :param run_id: Only return logs of this workflow run
+        :type run_id: int
+        :param attempt_id: Only return logs of this job attempt
+        :type attempt_id: int
+        :param stream: Only return logs of this stream
+        :type stream: JobLogStream
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_job_logs_serialize(
+            workflow_id=workflow_id,
+            job_id=job_id,
+            run_id=run_id,
+            attempt_id=attempt_id,
+            stream=stream,
+            offset=offset,
+            limit=limit,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobLogsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def list_job_logs_without_preload_content(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        job_id: Annotated[Optional[StrictInt], Field(description="Only return logs of this job")] = None,
+        run_id: Annotated[Optional[StrictInt], Field(description="Only return logs of this workflow run")] = None,
+        attempt_id: Annotated[Optional[StrictInt], Field(description="Only return logs of this job attempt")] = None,
+        stream: Annotated[Optional[JobLogStream], Field(description="Only return logs of this stream")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """List job logs.
+
+        Retrieve the uploaded job logs of a workflow. The content of each log is left empty; fetch it with /job_logs/{id}/content.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param job_id: Only return logs of this job
+        :type job_id: int
+        :param run_id: Only return logs of this workflow run
+        :type run_id: int
+        :param attempt_id: Only return logs of this job attempt
+        :type attempt_id: int
+        :param stream: Only return logs of this stream
+        :type stream: JobLogStream
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_job_logs_serialize(
+            workflow_id=workflow_id,
+            job_id=job_id,
+            run_id=run_id,
+            attempt_id=attempt_id,
+            stream=stream,
+            offset=offset,
+            limit=limit,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobLogsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _list_job_logs_serialize(
+        self,
+        workflow_id,
+        job_id,
+        run_id,
+        attempt_id,
+        stream,
+        offset,
+        limit,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
+        if job_id is not None:
+            
+            _query_params.append(('job_id', job_id))
+            
+        if run_id is not None:
+            
+            _query_params.append(('run_id', run_id))
+            
+        if attempt_id is not None:
+            
+            _query_params.append(('attempt_id', attempt_id))
+            
+        if stream is not None:
+            
+            _query_params.append(('stream', stream.value))
+            
+        if offset is not None:
+            
+            _query_params.append(('offset', offset))
+            
+        if limit is not None:
+            
+            _query_params.append(('limit', limit))
+            
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='GET',
+            resource_path='/job_logs',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
+    @validate_call
+    def list_job_user_data_relationships(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ListJobUserDataRelationshipsResponse:
+        """Retrieve job-user_data relationships for a workflow.
+
+        Retrieve all job-user_data relationships for one workflow from the job_input_user_data and job_output_user_data tables.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_job_user_data_relationships_serialize(
+            id=id,
+            offset=offset,
+            limit=limit,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobUserDataRelationshipsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def list_job_user_data_relationships_with_http_info(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[ListJobUserDataRelationshipsResponse]:
+        """Retrieve job-user_data relationships for a workflow.
+
+        Retrieve all job-user_data relationships for one workflow from the job_input_user_data and job_output_user_data tables.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_job_user_data_relationships_serialize(
+            id=id,
+            offset=offset,
+            limit=limit,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobUserDataRelationshipsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def list_job_user_data_relationships_without_preload_content(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """Retrieve job-user_data relationships for a workflow.
+
+        Retrieve all job-user_data relationships for one workflow from the job_input_user_data and job_output_user_data tables.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_job_user_data_relationships_serialize(
+            id=id,
+            offset=offset,
+            limit=limit,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobUserDataRelationshipsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _list_job_user_data_relationships_serialize(
+        self,
+        id,
+        offset,
+        limit,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
+        # process the query parameters
+        if offset is not None:
+            
+            _query_params.append(('offset', offset))
+            
+        if limit is not None:
+            
+            _query_params.append(('limit', limit))
+            
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='GET',
+            resource_path='/workflows/{id}/job_user_data_relationships',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
+    @validate_call
+    def list_jobs(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        status: Annotated[Optional[JobStatus], Field(description="Return jobs with this status.")] = None,
+        needs_file_id: Annotated[Optional[StrictInt], Field(description="Return jobs that need this file as an input.")] = None,
+        upstream_job_id: Annotated[Optional[StrictInt], Field(description="Return jobs that are downstream of this job ID in the workflow graph.")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        include_relationships: Annotated[Optional[StrictBool], Field(description="Include job relationships (depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids). Default is false for performance.")] = None,
+        active_compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter jobs by the compute node currently running them.")] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ListJobsResponse:
+        """Retrieve all jobs for one workflow.
+
+        Retrieve all jobs for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param status: Return jobs with this status.
+        :type status: JobStatus
+        :param needs_file_id: Return jobs that need this file as an input.
+        :type needs_file_id: int
+        :param upstream_job_id: Return jobs that are downstream of this job ID in the workflow graph.
+        :type upstream_job_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param include_relationships: Include job relationships (depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids). Default is false for performance.
+        :type include_relationships: bool
+        :param active_compute_node_id: Filter jobs by the compute node currently running them.
+        :type active_compute_node_id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_jobs_serialize(
+            workflow_id=workflow_id,
+            status=status,
+            needs_file_id=needs_file_id,
+            upstream_job_id=upstream_job_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            include_relationships=include_relationships,
+            active_compute_node_id=active_compute_node_id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def list_jobs_with_http_info(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        status: Annotated[Optional[JobStatus], Field(description="Return jobs with this status.")] = None,
+        needs_file_id: Annotated[Optional[StrictInt], Field(description="Return jobs that need this file as an input.")] = None,
+        upstream_job_id: Annotated[Optional[StrictInt], Field(description="Return jobs that are downstream of this job ID in the workflow graph.")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        include_relationships: Annotated[Optional[StrictBool], Field(description="Include job relationships (depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids). Default is false for performance.")] = None,
+        active_compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter jobs by the compute node currently running them.")] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[ListJobsResponse]:
+        """Retrieve all jobs for one workflow.
+
+        Retrieve all jobs for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param status: Return jobs with this status.
+        :type status: JobStatus
+        :param needs_file_id: Return jobs that need this file as an input.
+        :type needs_file_id: int
+        :param upstream_job_id: Return jobs that are downstream of this job ID in the workflow graph.
+        :type upstream_job_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param include_relationships: Include job relationships (depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids). Default is false for performance.
+        :type include_relationships: bool
+        :param active_compute_node_id: Filter jobs by the compute node currently running them.
+        :type active_compute_node_id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_jobs_serialize(
+            workflow_id=workflow_id,
+            status=status,
+            needs_file_id=needs_file_id,
+            upstream_job_id=upstream_job_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            include_relationships=include_relationships,
+            active_compute_node_id=active_compute_node_id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def list_jobs_without_preload_content(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        status: Annotated[Optional[JobStatus], Field(description="Return jobs with this status.")] = None,
+        needs_file_id: Annotated[Optional[StrictInt], Field(description="Return jobs that need this file as an input.")] = None,
+        upstream_job_id: Annotated[Optional[StrictInt], Field(description="Return jobs that are downstream of this job ID in the workflow graph.")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        include_relationships: Annotated[Optional[StrictBool], Field(description="Include job relationships (depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids). Default is false for performance.")] = None,
+        active_compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter jobs by the compute node currently running them.")] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """Retrieve all jobs for one workflow.
+
+        Retrieve all jobs for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param status: Return jobs with this status.
+        :type status: JobStatus
+        :param needs_file_id: Return jobs that need this file as an input.
+        :type needs_file_id: int
+        :param upstream_job_id: Return jobs that are downstream of this job ID in the workflow graph.
+        :type upstream_job_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param include_relationships: Include job relationships (depends_on_job_ids, input_file_ids, output_file_ids, input_user_data_ids, output_user_data_ids). Default is false for performance.
+        :type include_relationships: bool
+        :param active_compute_node_id: Filter jobs by the compute node currently running them.
+        :type active_compute_node_id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_jobs_serialize(
+            workflow_id=workflow_id,
+            status=status,
+            needs_file_id=needs_file_id,
+            upstream_job_id=upstream_job_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            include_relationships=include_relationships,
+            active_compute_node_id=active_compute_node_id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListJobsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _list_jobs_serialize(
+        self,
+        workflow_id,
+        status,
+        needs_file_id,
+        upstream_job_id,
+        offset,
+        limit,
+        sort_by,
+        reverse_sort,
+        include_relationships,
+        active_compute_node_id,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
+        if status is not None:
+            
+            _query_params.append(('status', status.value))
+            
+        if needs_file_id is not None:
+            
+            _query_params.append(('needs_file_id', needs_file_id))
+            
+        if upstream_job_id is not None:
+            
+            _query_params.append(('upstream_job_id', upstream_job_id))
+            
+        if offset is not None:
+            
+            _query_params.append(('offset', offset))
+            
+        if limit is not None:
+            
+            _query_params.append(('limit', limit))
+            
+        if sort_by is not None:
+            
+            _query_params.append(('sort_by', sort_by))
+            
+        if reverse_sort is not None:
+            
+            _query_params.append(('reverse_sort', reverse_sort))
+            
+        if include_relationships is not None:
+            
+            _query_params.append(('include_relationships', include_relationships))
+            
+        if active_compute_node_id is not None:
+            
+            _query_params.append(('active_compute_node_id', active_compute_node_id))
+            
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='GET',
+            resource_path='/jobs',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
+    @validate_call
+    def list_local_schedulers(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        memory: Optional[StrictStr] = None,
+        num_cpus: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ListLocalSchedulersResponse:
+        """Retrieve local schedulers for one workflow.
+
+        Retrieve local schedulers for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param memory:
+        :type memory: str
+        :param num_cpus:
+        :type num_cpus: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_local_schedulers_serialize(
+            workflow_id=workflow_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            memory=memory,
+            num_cpus=num_cpus,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListLocalSchedulersResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def list_local_schedulers_with_http_info(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        memory: Optional[StrictStr] = None,
+        num_cpus: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[ListLocalSchedulersResponse]:
+        """Retrieve local schedulers for one workflow.
+
+        Retrieve local schedulers for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param memory:
+        :type memory: str
+        :param num_cpus:
+        :type num_cpus: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_local_schedulers_serialize(
+            workflow_id=workflow_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            memory=memory,
+            num_cpus=num_cpus,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListLocalSchedulersResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def list_local_schedulers_without_preload_content(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        memory: Optional[StrictStr] = None,
+        num_cpus: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """Retrieve local schedulers for one workflow.
+
+        Retrieve local schedulers for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param memory:
+        :type memory: str
+        :param num_cpus:
+        :type num_cpus: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_local_schedulers_serialize(
+            workflow_id=workflow_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            memory=memory,
+            num_cpus=num_cpus,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListLocalSchedulersResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _list_local_schedulers_serialize(
+        self,
+        workflow_id,
+        offset,
+        limit,
+        sort_by,
+        reverse_sort,
+        memory,
+        num_cpus,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
+        if offset is not None:
+            
+            _query_params.append(('offset', offset))
+            
+        if limit is not None:
+            
+            _query_params.append(('limit', limit))
+            
+        if sort_by is not None:
+            
+            _query_params.append(('sort_by', sort_by))
+            
+        if reverse_sort is not None:
+            
+            _query_params.append(('reverse_sort', reverse_sort))
+            
+        if memory is not None:
+            
+            _query_params.append(('memory', memory))
+            
+        if num_cpus is not None:
+            
+            _query_params.append(('num_cpus', num_cpus))
+            
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='GET',
+            resource_path='/local_schedulers',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
+    @validate_call
+    def list_missing_user_data(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ListMissingUserDataResponse:
+        """List missing user data that should exist.
+
+        List missing user data that should exist.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_missing_user_data_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListMissingUserDataResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def list_missing_user_data_with_http_info(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[ListMissingUserDataResponse]:
+        """List missing user data that should exist.
+
+        List missing user data that should exist.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_missing_user_data_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListMissingUserDataResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def list_missing_user_data_without_preload_content(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """List missing user data that should exist.
+
+        List missing user data that should exist.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_missing_user_data_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListMissingUserDataResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _list_missing_user_data_serialize(
+        self,
+        id,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
+        # process the query parameters
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='GET',
+            resource_path='/workflows/{id}/missing_user_data',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
+    @validate_call
+    def list_remote_workers(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> List[RemoteWorkerModel]:
+        """List all remote workers for a workflow.
+
+        List all remote workers for a workflow.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_remote_workers_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "List[RemoteWorkerModel]",
+            '404': "DefaultErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def list_remote_workers_with_http_info(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[List[RemoteWorkerModel]]:
+        """List all remote workers for a workflow.
+
+        List all remote workers for a workflow.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_remote_workers_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "List[RemoteWorkerModel]",
+            '404': "DefaultErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def list_remote_workers_without_preload_content(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """List all remote workers for a workflow.
+
+        List all remote workers for a workflow.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_remote_workers_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "List[RemoteWorkerModel]",
+            '404': "DefaultErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _list_remote_workers_serialize(
+        self,
+        id,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
+        # process the query parameters
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='GET',
+            resource_path='/workflows/{id}/remote_workers',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
+    @validate_call
+    def list_required_existing_files(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ListRequiredExistingFilesResponse:
+        """List files that must exist.
+
+        List files that must exist.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_required_existing_files_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListRequiredExistingFilesResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def list_required_existing_files_with_http_info(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[ListRequiredExistingFilesResponse]:
+        """List files that must exist.
+
+        List files that must exist.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_required_existing_files_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListRequiredExistingFilesResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def list_required_existing_files_without_preload_content(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """List files that must exist.
+
+        List files that must exist.
+
+        :param id: Workflow ID (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_required_existing_files_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListRequiredExistingFilesResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _list_required_existing_files_serialize(
+        self,
+        id,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
+        # process the query parameters
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='GET',
+            resource_path='/workflows/{id}/required_existing_files',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
+    @validate_call
+    def list_resource_requirements(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        job_id: Annotated[Optional[StrictInt], Field(description="Return the resource requirements for a specific job.")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        name: Optional[StrictStr] = None,
+        memory: Optional[StrictStr] = None,
+        num_cpus: Optional[StrictInt] = None,
+        num_gpus: Optional[StrictInt] = None,
+        num_nodes: Optional[StrictInt] = None,
+        runtime: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ListResourceRequirementsResponse:
+        """Retrieve all resource requirements records for one workflow.
+
+        Retrieve all resource requirements records for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param job_id: Return the resource requirements for a specific job.
+        :type job_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param name:
+        :type name: str
+        :param memory:
+        :type memory: str
+        :param num_cpus:
+        :type num_cpus: int
+        :param num_gpus:
+        :type num_gpus: int
+        :param num_nodes:
+        :type num_nodes: int
+        :param runtime:
+        :type runtime: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_resource_requirements_serialize(
+            workflow_id=workflow_id,
+            job_id=job_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            name=name,
+            memory=memory,
+            num_cpus=num_cpus,
+            num_gpus=num_gpus,
+            num_nodes=num_nodes,
+            runtime=runtime,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListResourceRequirementsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def list_resource_requirements_with_http_info(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        job_id: Annotated[Optional[StrictInt], Field(description="Return the resource requirements for a specific job.")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        name: Optional[StrictStr] = None,
+        memory: Optional[StrictStr] = None,
+        num_cpus: Optional[StrictInt] = None,
+        num_gpus: Optional[StrictInt] = None,
+        num_nodes: Optional[StrictInt] = None,
+        runtime: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[ListResourceRequirementsResponse]:
+        """Retrieve all resource requirements records for one workflow.
+
+        Retrieve all resource requirements records for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param job_id: Return the resource requirements for a specific job.
+        :type job_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param name:
+        :type name: str
+        :param memory:
+        :type memory: str
+        :param num_cpus:
+        :type num_cpus: int
+        :param num_gpus:
+        :type num_gpus: int
+        :param num_nodes:
+        :type num_nodes: int
+        :param runtime:
+        :type runtime: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_resource_requirements_serialize(
+            workflow_id=workflow_id,
+            job_id=job_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            name=name,
+            memory=memory,
+            num_cpus=num_cpus,
+            num_gpus=num_gpus,
+            num_nodes=num_nodes,
+            runtime=runtime,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListResourceRequirementsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def list_resource_requirements_without_preload_content(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        job_id: Annotated[Optional[StrictInt], Field(description="Return the resource requirements for a specific job.")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        name: Optional[StrictStr] = None,
+        memory: Optional[StrictStr] = None,
+        num_cpus: Optional[StrictInt] = None,
+        num_gpus: Optional[StrictInt] = None,
+        num_nodes: Optional[StrictInt] = None,
+        runtime: Optional[StrictInt] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """Retrieve all resource requirements records for one workflow.
+
+        Retrieve all resource requirements records for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param job_id: Return the resource requirements for a specific job.
+        :type job_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param name:
+        :type name: str
+        :param memory:
+        :type memory: str
+        :param num_cpus:
+        :type num_cpus: int
+        :param num_gpus:
+        :type num_gpus: int
+        :param num_nodes:
+        :type num_nodes: int
+        :param runtime:
+        :type runtime: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -24912,17 +27750,1558 @@ class DefaultApi:
 
         _param = self._list_resource_requirements_serialize(
             workflow_id=workflow_id,
-            job_id=job_id,
+            job_id=job_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            name=name,
+            memory=memory,
+            num_cpus=num_cpus,
+            num_gpus=num_gpus,
+            num_nodes=num_nodes,
+            runtime=runtime,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListResourceRequirementsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _list_resource_requirements_serialize(
+        self,
+        workflow_id,
+        job_id,
+        offset,
+        limit,
+        sort_by,
+        reverse_sort,
+        name,
+        memory,
+        num_cpus,
+        num_gpus,
+        num_nodes,
+        runtime,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
+        if job_id is not None:
+            
+            _query_params.append(('job_id', job_id))
+            
+        if offset is not None:
+            
+            _query_params.append(('offset', offset))
+            
+        if limit is not None:
+            
+            _query_params.append(('limit', limit))
+            
+        if sort_by is not None:
+            
+            _query_params.append(('sort_by', sort_by))
+            
+        if reverse_sort is not None:
+            
+            _query_params.append(('reverse_sort', reverse_sort))
+            
+        if name is not None:
+            
+            _query_params.append(('name', name))
+            
+        if memory is not None:
+            
+            _query_params.append(('memory', memory))
+            
+        if num_cpus is not None:
+            
+            _query_params.append(('num_cpus', num_cpus))
+            
+        if num_gpus is not None:
+            
+            _query_params.append(('num_gpus', num_gpus))
+            
+        if num_nodes is not None:
+            
+            _query_params.append(('num_nodes', num_nodes))
+            
+        if runtime is not None:
+            
+            _query_params.append(('runtime', runtime))
+            
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='GET',
+            resource_path='/resource_requirements',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
+    @validate_call
+    def list_results(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        job_id: Annotated[Optional[StrictInt], Field(description="Return the results for a specific job.")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        run_id: Optional[StrictInt] = None,
+        return_code: Optional[StrictInt] = None,
+        status: Optional[JobStatus] = None,
+        all_runs: Annotated[Optional[StrictBool], Field(description="If false (default), only return results in the workflow_result table (current results). If true, return all historical results.")] = None,
+        compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter by compute node ID")] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ListResultsResponse:
+        """Retrieve all job results for one workflow.
+
+        Retrieve all job results for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param job_id: Return the results for a specific job.
+        :type job_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param run_id:
+        :type run_id: int
+        :param return_code:
+        :type return_code: int
+        :param status:
+        :type status: JobStatus
+        :param all_runs: If false (default), only return results in the workflow_result table (current results). If true, return all historical results.
+        :type all_runs: bool
+        :param compute_node_id: Filter by compute node ID
+        :type compute_node_id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_results_serialize(
+            workflow_id=workflow_id,
+            job_id=job_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            run_id=run_id,
+            return_code=return_code,
+            status=status,
+            all_runs=all_runs,
+            compute_node_id=compute_node_id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListResultsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def list_results_with_http_info(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        job_id: Annotated[Optional[StrictInt], Field(description="Return the results for a specific job.")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        run_id: Optional[StrictInt] = None,
+        return_code: Optional[StrictInt] = None,
+        status: Optional[JobStatus] = None,
+        all_runs: Annotated[Optional[StrictBool], Field(description="If false (default), only return results in the workflow_result table (current results). If true, return all historical results.")] = None,
+        compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter by compute node ID")] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[ListResultsResponse]:
+        """Retrieve all job results for one workflow.
+
+        Retrieve all job results for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param job_id: Return the results for a specific job.
+        :type job_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param run_id:
+        :type run_id: int
+        :param return_code:
+        :type return_code: int
+        :param status:
+        :type status: JobStatus
+        :param all_runs: If false (default), only return results in the workflow_result table (current results). If true, return all historical results.
+        :type all_runs: bool
+        :param compute_node_id: Filter by compute node ID
+        :type compute_node_id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_results_serialize(
+            workflow_id=workflow_id,
+            job_id=job_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            run_id=run_id,
+            return_code=return_code,
+            status=status,
+            all_runs=all_runs,
+            compute_node_id=compute_node_id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListResultsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def list_results_without_preload_content(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        job_id: Annotated[Optional[StrictInt], Field(description="Return the results for a specific job.")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        run_id: Optional[StrictInt] = None,
+        return_code: Optional[StrictInt] = None,
+        status: Optional[JobStatus] = None,
+        all_runs: Annotated[Optional[StrictBool], Field(description="If false (default), only return results in the workflow_result table (current results). If true, return all historical results.")] = None,
+        compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter by compute node ID")] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """Retrieve all job results for one workflow.
+
+        Retrieve all job results for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param job_id: Return the results for a specific job.
+        :type job_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param run_id:
+        :type run_id: int
+        :param return_code:
+        :type return_code: int
+        :param status:
+        :type status: JobStatus
+        :param all_runs: If false (default), only return results in the workflow_result table (current results). If true, return all historical results.
+        :type all_runs: bool
+        :param compute_node_id: Filter by compute node ID
+        :type compute_node_id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_results_serialize(
+            workflow_id=workflow_id,
+            job_id=job_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            run_id=run_id,
+            return_code=return_code,
+            status=status,
+            all_runs=all_runs,
+            compute_node_id=compute_node_id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListResultsResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _list_results_serialize(
+        self,
+        workflow_id,
+        job_id,
+        offset,
+        limit,
+        sort_by,
+        reverse_sort,
+        run_id,
+        return_code,
+        status,
+        all_runs,
+        compute_node_id,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
+        if job_id is not None:
+            
+            _query_params.append(('job_id', job_id))
+            
+        if offset is not None:
+            
+            _query_params.append(('offset', offset))
+            
+        if limit is not None:
+            
+            _query_params.append(('limit', limit))
+            
+        if sort_by is not None:
+            
+            _query_params.append(('sort_by', sort_by))
+            
+        if reverse_sort is not None:
+            
+            _query_params.append(('reverse_sort', reverse_sort))
+            
+        if run_id is not None:
+            
+            _query_params.append(('run_id', run_id))
+            
+        if return_code is not None:
+            
+            _query_params.append(('return_code', return_code))
+            
+        if status is not None:
+            
+            _query_params.append(('status', status.value))
+            
+        if all_runs is not None:
+            
+            _query_params.append(('all_runs', all_runs))
+            
+        if compute_node_id is not None:
+            
+            _query_params.append(('compute_node_id', compute_node_id))
+            
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='GET',
+            resource_path='/results',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
+    @validate_call
+    def list_scheduled_compute_nodes(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        scheduler_id: Optional[StrictStr] = None,
+        scheduler_config_id: Optional[StrictStr] = None,
+        status: Optional[StrictStr] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ListScheduledComputeNodesResponse:
+        """Retrieve scheduled compute node records for one workflow.
+
+        Retrieve scheduled compute node records for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param scheduler_id:
+        :type scheduler_id: str
+        :param scheduler_config_id:
+        :type scheduler_config_id: str
+        :param status:
+        :type status: str
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_scheduled_compute_nodes_serialize(
+            workflow_id=workflow_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            scheduler_id=scheduler_id,
+            scheduler_config_id=scheduler_config_id,
+            status=status,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListScheduledComputeNodesResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def list_scheduled_compute_nodes_with_http_info(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        scheduler_id: Optional[StrictStr] = None,
+        scheduler_config_id: Optional[StrictStr] = None,
+        status: Optional[StrictStr] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[ListScheduledComputeNodesResponse]:
+        """Retrieve scheduled compute node records for one workflow.
+
+        Retrieve scheduled compute node records for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param scheduler_id:
+        :type scheduler_id: str
+        :param scheduler_config_id:
+        :type scheduler_config_id: str
+        :param status:
+        :type status: str
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_scheduled_compute_nodes_serialize(
+            workflow_id=workflow_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            scheduler_id=scheduler_id,
+            scheduler_config_id=scheduler_config_id,
+            status=status,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListScheduledComputeNodesResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def list_scheduled_compute_nodes_without_preload_content(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        scheduler_id: Optional[StrictStr] = None,
+        scheduler_config_id: Optional[StrictStr] = None,
+        status: Optional[StrictStr] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """Retrieve scheduled compute node records for one workflow.
+
+        Retrieve scheduled compute node records for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param scheduler_id:
+        :type scheduler_id: str
+        :param scheduler_config_id:
+        :type scheduler_config_id: str
+        :param status:
+        :type status: str
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_scheduled_compute_nodes_serialize(
+            workflow_id=workflow_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            scheduler_id=scheduler_id,
+            scheduler_config_id=scheduler_config_id,
+            status=status,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListScheduledComputeNodesResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _list_scheduled_compute_nodes_serialize(
+        self,
+        workflow_id,
+        offset,
+        limit,
+        sort_by,
+        reverse_sort,
+        scheduler_id,
+        scheduler_config_id,
+        status,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
+        if offset is not None:
+            
+            _query_params.append(('offset', offset))
+            
+        if limit is not None:
+            
+            _query_params.append(('limit', limit))
+            
+        if sort_by is not None:
+            
+            _query_params.append(('sort_by', sort_by))
+            
+        if reverse_sort is not None:
+            
+            _query_params.append(('reverse_sort', reverse_sort))
+            
+        if scheduler_id is not None:
+            
+            _query_params.append(('scheduler_id', scheduler_id))
+            
+        if scheduler_config_id is not None:
+            
+            _query_params.append(('scheduler_config_id', scheduler_config_id))
+            
+        if status is not None:
+            
+            _query_params.append(('status', status))
+            
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='GET',
+            resource_path='/scheduled_compute_nodes',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
+    @validate_call
+    def list_slurm_schedulers(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        name: Optional[StrictStr] = None,
+        account: Optional[StrictStr] = None,
+        gres: Optional[StrictStr] = None,
+        mem: Optional[StrictStr] = None,
+        nodes: Optional[StrictInt] = None,
+        partition: Optional[StrictStr] = None,
+        qos: Optional[StrictStr] = None,
+        tmp: Optional[StrictStr] = None,
+        walltime: Optional[StrictStr] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ListSlurmSchedulersResponse:
+        """Retrieve a Slurm compute node configuration.
+
+        Retrieve a Slurm compute node configuration.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param name:
+        :type name: str
+        :param account:
+        :type account: str
+        :param gres:
+        :type gres: str
+        :param mem:
+        :type mem: str
+        :param nodes:
+        :type nodes: int
+        :param partition:
+        :type partition: str
+        :param qos:
+        :type qos: str
+        :param tmp:
+        :type tmp: str
+        :param walltime:
+        :type walltime: str
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_slurm_schedulers_serialize(
+            workflow_id=workflow_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            name=name,
+            account=account,
+            gres=gres,
+            mem=mem,
+            nodes=nodes,
+            partition=partition,
+            qos=qos,
+            tmp=tmp,
+            walltime=walltime,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListSlurmSchedulersResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def list_slurm_schedulers_with_http_info(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        name: Optional[StrictStr] = None,
+        account: Optional[StrictStr] = None,
+        gres: Optional[StrictStr] = None,
+        mem: Optional[StrictStr] = None,
+        nodes: Optional[StrictInt] = None,
+        partition: Optional[StrictStr] = None,
+        qos: Optional[StrictStr] = None,
+        tmp: Optional[StrictStr] = None,
+        walltime: Optional[StrictStr] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[ListSlurmSchedulersResponse]:
+        """Retrieve a Slurm compute node configuration.
+
+        Retrieve a Slurm compute node configuration.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param name:
+        :type name: str
+        :param account:
+        :type account: str
+        :param gres:
+        :type gres: str
+        :param mem:
+        :type mem: str
+        :param nodes:
+        :type nodes: int
+        :param partition:
+        :type partition: str
+        :param qos:
+        :type qos: str
+        :param tmp:
+        :type tmp: str
+        :param walltime:
+        :type walltime: str
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_slurm_schedulers_serialize(
+            workflow_id=workflow_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            name=name,
+            account=account,
+            gres=gres,
+            mem=mem,
+            nodes=nodes,
+            partition=partition,
+            qos=qos,
+            tmp=tmp,
+            walltime=walltime,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListSlurmSchedulersResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def list_slurm_schedulers_without_preload_content(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        name: Optional[StrictStr] = None,
+        account: Optional[StrictStr] = None,
+        gres: Optional[StrictStr] = None,
+        mem: Optional[StrictStr] = None,
+        nodes: Optional[StrictInt] = None,
+        partition: Optional[StrictStr] = None,
+        qos: Optional[StrictStr] = None,
+        tmp: Optional[StrictStr] = None,
+        walltime: Optional[StrictStr] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """Retrieve a Slurm compute node configuration.
+
+        Retrieve a Slurm compute node configuration.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param name:
+        :type name: str
+        :param account:
+        :type account: str
+        :param gres:
+        :type gres: str
+        :param mem:
+        :type mem: str
+        :param nodes:
+        :type nodes: int
+        :param partition:
+        :type partition: str
+        :param qos:
+        :type qos: str
+        :param tmp:
+        :type tmp: str
+        :param walltime:
+        :type walltime: str
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_slurm_schedulers_serialize(
+            workflow_id=workflow_id,
+            offset=offset,
+            limit=limit,
+            sort_by=sort_by,
+            reverse_sort=reverse_sort,
+            name=name,
+            account=account,
+            gres=gres,
+            mem=mem,
+            nodes=nodes,
+            partition=partition,
+            qos=qos,
+            tmp=tmp,
+            walltime=walltime,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ListSlurmSchedulersResponse",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _list_slurm_schedulers_serialize(
+        self,
+        workflow_id,
+        offset,
+        limit,
+        sort_by,
+        reverse_sort,
+        name,
+        account,
+        gres,
+        mem,
+        nodes,
+        partition,
+        qos,
+        tmp,
+        walltime,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        # process the query parameters
+        if workflow_id is not None:
+            
+            _query_params.append(('workflow_id', workflow_id))
+            
+        if offset is not None:
+            
+            _query_params.append(('offset', offset))
+            
+        if limit is not None:
+            
+            _query_params.append(('limit', limit))
+            
+        if sort_by is not None:
+            
+            _query_params.append(('sort_by', sort_by))
+            
+        if reverse_sort is not None:
+            
+            _query_params.append(('reverse_sort', reverse_sort))
+            
+        if name is not None:
+            
+            _query_params.append(('name', name))
+            
+        if account is not None:
+            
+            _query_params.append(('account', account))
+            
+        if gres is not None:
+            
+            _query_params.append(('gres', gres))
+            
+        if mem is not None:
+            
+            _query_params.append(('mem', mem))
+            
+        if nodes is not None:
+            
+            _query_params.append(('nodes', nodes))
+            
+        if partition is not None:
+            
+            _query_params.append(('partition', partition))
+            
+        if qos is not None:
+            
+            _query_params.append(('qos', qos))
+            
+        if tmp is not None:
+            
+            _query_params.append(('tmp', tmp))
+            
+        if walltime is not None:
+            
+            _query_params.append(('walltime', walltime))
+            
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='GET',
+            resource_path='/slurm_schedulers',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
+    @validate_call
+    def list_user_data(
+        self,
+        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        consumer_job_id: Annotated[Optional[StrictInt], Field(description="Return user data records that are consumed by a specific job.")] = None,
+        producer_job_id: Annotated[Optional[StrictInt], Field(description="Return user data records that are produced by a specific job.")] = None,
+        offset: Optional[StrictInt] = None,
+        limit: Optional[StrictInt] = None,
+        sort_by: Optional[StrictStr] = None,
+        reverse_sort: Optional[StrictBool] = None,
+        name: Optional[StrictStr] = None,
+        is_ephemeral: Optional[StrictBool] = None,
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ListUserDataResponse:
+        """Retrieve all user data records for one workflow.
+
+        Retrieve all user data records for one workflow.
+
+        :param workflow_id: Workflow ID (required)
+        :type workflow_id: int
+        :param consumer_job_id: Return user data records that are consumed by a specific job.
+        :type consumer_job_id: int
+        :param producer_job_id: Return user data records that are produced by a specific job.
+        :type producer_job_id: int
+        :param offset:
+        :type offset: int
+        :param limit:
+        :type limit: int
+        :param sort_by:
+        :type sort_by: str
+        :param reverse_sort:
+        :type reverse_sort: bool
+        :param name:
+        :type name: str
+        :param is_ephemeral:
+        :type is_ephemeral: bool
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._list_user_data_serialize(
+            workflow_id=workflow_id,
+            consumer_job_id=consumer_job_id,
+            producer_job_id=producer_job_id,
             offset=offset,
             limit=limit,
             sort_by=sort_by,
             reverse_sort=reverse_sort,
             name=name,
-            memory=memory,
-            num_cpus=num_cpus,
-            num_gpus=num_gpus,
-            num_nodes=num_nodes,
-            runtime=runtime,
+            is_ephemeral=is_ephemeral,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -24930,7 +29309,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListResourceRequirementsResponse",
+            '200': "ListUserDataResponse",
             '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
@@ -24947,20 +29326,17 @@ class DefaultApi:
 
 
     @validate_call
-    def list_resource_requirements_with_http_info(
+    def list_user_data_with_http_info(
         self,
         workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        job_id: Annotated[Optional[StrictInt], Field(description="Return the resource requirements for a specific job.")] = None,
+        consumer_job_id: Annotated[Optional[StrictInt], Field(description="Return user data records that are consumed by a specific job.")] = None,
+        producer_job_id: Annotated[Optional[StrictInt], Field(description="Return user data records that are produced by a specific job.")] = None,
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
         sort_by: Optional[StrictStr] = None,
         reverse_sort: Optional[StrictBool] = None,
         name: Optional[StrictStr] = None,
-        memory: Optional[StrictStr] = None,
-        num_cpus: Optional[StrictInt] = None,
-        num_gpus: Optional[StrictInt] = None,
-        num_nodes: Optional[StrictInt] = None,
-        runtime: Optional[StrictInt] = None,
+        is_ephemeral: Optional[StrictBool] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -24973,15 +29349,17 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListResourceRequirementsResponse]:
-        """Retrieve all resource requirements records for one workflow.
+    ) -> ApiResponse[ListUserDataResponse]:
+        """Retrieve all user data records for one workflow.
 
-        Retrieve all resource requirements records for one workflow.
+        Retrieve all user data records for one workflow.
 
         :param workflow_id: Workflow ID (required)
         :type workflow_id: int
-        :param job_id: Return the resource requirements for a specific job.
-        :type job_id: int
+        :param consumer_job_id: Return user data records that are consumed by a specific job.
+        :type consumer_job_id: int
+        :param producer_job_id: Return user data records that are produced by a specific job.
+        :type producer_job_id: int
         :param offset:
         :type offset: int
         :param limit:
@@ -24992,16 +29370,8 @@ class DefaultApi:
         :type reverse_sort: bool
         :param name:
         :type name: str
-        :param memory:
-        :type memory: str
-        :param num_cpus:
-        :type num_cpus: int
-        :param num_gpus:
-        :type num_gpus: int
-        :param num_nodes:
-        :type num_nodes: int
-        :param runtime:
-        :type runtime: int
+        :param is_ephemeral:
+        :type is_ephemeral: bool
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -25024,19 +29394,16 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_resource_requirements_serialize(
+        _param = self._list_user_data_serialize(
             workflow_id=workflow_id,
-            job_id=job_id,
+            consumer_job_id=consumer_job_id,
+            producer_job_id=producer_job_id,
             offset=offset,
             limit=limit,
             sort_by=sort_by,
             reverse_sort=reverse_sort,
             name=name,
-            memory=memory,
-            num_cpus=num_cpus,
-            num_gpus=num_gpus,
-            num_nodes=num_nodes,
-            runtime=runtime,
+            is_ephemeral=is_ephemeral,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -25044,7 +29411,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListResourceRequirementsResponse",
+            '200': "ListUserDataResponse",
             '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
@@ -25061,20 +29428,17 @@ class DefaultApi:
 
 
     @validate_call
-    def list_resource_requirements_without_preload_content(
+    def list_user_data_without_preload_content(
         self,
         workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        job_id: Annotated[Optional[StrictInt], Field(description="Return the resource requirements for a specific job.")] = None,
+        consumer_job_id: Annotated[Optional[StrictInt], Field(description="Return user data records that are consumed by a specific job.")] = None,
+        producer_job_id: Annotated[Optional[StrictInt], Field(description="Return user data records that are produced by a specific job.")] = None,
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
         sort_by: Optional[StrictStr] = None,
         reverse_sort: Optional[StrictBool] = None,
         name: Optional[StrictStr] = None,
-        memory: Optional[StrictStr] = None,
-        num_cpus: Optional[StrictInt] = None,
-        num_gpus: Optional[StrictInt] = None,
-        num_nodes: Optional[StrictInt] = None,
-        runtime: Optional[StrictInt] = None,
+        is_ephemeral: Optional[StrictBool] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -25088,14 +29452,16 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve all resource requirements records for one workflow.
+        """Retrieve all user data records for one workflow.
 
-        Retrieve all resource requirements records for one workflow.
+        Retrieve all user data records for one workflow.
 
         :param workflow_id: Workflow ID (required)
         :type workflow_id: int
-        :param job_id: Return the resource requirements for a specific job.
-        :type job_id: int
+        :param consumer_job_id: Return user data records that are consumed by a specific job.
+        :type consumer_job_id: int
+        :param producer_job_id: Return user data records that are produced by a specific job.
+        :type producer_job_id: int
         :param offset:
         :type offset: int
         :param limit:
@@ -25106,16 +29472,8 @@ class DefaultApi:
         :type reverse_sort: bool
         :param name:
         :type name: str
-        :param memory:
-        :type memory: str
-        :param num_cpus:
-        :type num_cpus: int
-        :param num_gpus:
-        :type num_gpus: int
-        :param num_nodes:
-        :type num_nodes: int
-        :param runtime:
-        :type runtime: int
+        :param is_ephemeral:
+        :type is_ephemeral: bool
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -25138,19 +29496,16 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_resource_requirements_serialize(
+        _param = self._list_user_data_serialize(
             workflow_id=workflow_id,
-            job_id=job_id,
+            consumer_job_id=consumer_job_id,
+            producer_job_id=producer_job_id,
             offset=offset,
             limit=limit,
             sort_by=sort_by,
             reverse_sort=reverse_sort,
             name=name,
-            memory=memory,
-            num_cpus=num_cpus,
-            num_gpus=num_gpus,
-            num_nodes=num_nodes,
-            runtime=runtime,
+            is_ephemeral=is_ephemeral,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -25158,7 +29513,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListResourceRequirementsResponse",
+            '200': "ListUserDataResponse",
             '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
@@ -25170,20 +29525,17 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_resource_requirements_serialize(
+    def _list_user_data_serialize(
         self,
         workflow_id,
-        job_id,
+        consumer_job_id,
+        producer_job_id,
         offset,
         limit,
         sort_by,
         reverse_sort,
         name,
-        memory,
-        num_cpus,
-        num_gpus,
-        num_nodes,
-        runtime,
+        is_ephemeral,
         _request_auth,
         _content_type,
         _headers,
@@ -25210,9 +29562,13 @@ class DefaultApi:
             
             _query_params.append(('workflow_id', workflow_id))
             
-        if job_id is not None:
+        if consumer_job_id is not None:
             
-            _query_params.append(('job_id', job_id))
+            _query_params.append(('consumer_job_id', consumer_job_id))
+            
+        if producer_job_id is not None:
+            
+            _query_params.append(('producer_job_id', producer_job_id))
             
         if offset is not None:
             
@@ -25234,25 +29590,9 @@ class DefaultApi:
             
             _query_params.append(('name', name))
             
-        if memory is not None:
-            
-            _query_params.append(('memory', memory))
-            
-        if num_cpus is not None:
-            
-            _query_params.append(('num_cpus', num_cpus))
-            
-        if num_gpus is not None:
-            
-            _query_params.append(('num_gpus', num_gpus))
-            
-        if num_nodes is not None:
-            
-            _query_params.append(('num_nodes', num_nodes))
-            
-        if runtime is not None:
+        if is_ephemeral is not None:
             
-            _query_params.append(('runtime', runtime))
+            _query_params.append(('is_ephemeral', is_ephemeral))
             
         # process the header parameters
         # process the form parameters
@@ -25274,7 +29614,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/resource_requirements',
+            resource_path='/user_data',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -25291,19 +29631,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_results(
+    def list_user_groups(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        job_id: Annotated[Optional[StrictInt], Field(description="Return the results for a specific job.")] = None,
+        user_name: Annotated[StrictStr, Field(description="Username")],
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        run_id: Optional[StrictInt] = None,
-        return_code: Optional[StrictInt] = None,
-        status: Optional[JobStatus] = None,
-        all_runs: Annotated[Optional[StrictBool], Field(description="If false (default), only return results in the workflow_result table (current results). If true, return all historical results.")] = None,
-        compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter by compute node ID")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -25316,33 +29648,17 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListResultsResponse:
-        """Retrieve all job results for one workflow.
+    ) -> ListAccessGroupsResponse:
+        """List groups a user belongs to.
 
-        Retrieve all job results for one workflow.
+        List groups a user belongs to.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param job_id: Return the results for a specific job.
-        :type job_id: int
+        :param user_name: Username (required)
+        :type user_name: str
         :param offset:
         :type offset: int
         :param limit:
         :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param run_id:
-        :type run_id: int
-        :param return_code:
-        :type return_code: int
-        :param status:
-        :type status: JobStatus
-        :param all_runs: If false (default), only return results in the workflow_result table (current results). If true, return all historical results.
-        :type all_runs: bool
-        :param compute_node_id: Filter by compute node ID
-        :type compute_node_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -25365,18 +29681,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_results_serialize(
-            workflow_id=workflow_id,
-            job_id=job_id,
+        _param = self._list_user_groups_serialize(
+            user_name=user_name,
             offset=offset,
             limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            run_id=run_id,
-            return_code=return_code,
-            status=status,
-            all_runs=all_runs,
-            compute_node_id=compute_node_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -25384,9 +29692,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListResultsResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListAccessGroupsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -25401,19 +29707,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_results_with_http_info(
+    def list_user_groups_with_http_info(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        job_id: Annotated[Optional[StrictInt], Field(description="Return the results for a specific job.")] = None,
+        user_name: Annotated[StrictStr, Field(description="Username")],
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        run_id: Optional[StrictInt] = None,
-        return_code: Optional[StrictInt] = None,
-        status: Optional[JobStatus] = None,
-        all_runs: Annotated[Optional[StrictBool], Field(description="If false (default), only return results in the workflow_result table (current results). If true, return all historical results.")] = None,
-        compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter by compute node ID")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -25426,33 +29724,17 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListResultsResponse]:
-        """Retrieve all job results for one workflow.
+    ) -> ApiResponse[ListAccessGroupsResponse]:
+        """List groups a user belongs to.
 
-        Retrieve all job results for one workflow.
+        List groups a user belongs to.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param job_id: Return the results for a specific job.
-        :type job_id: int
+        :param user_name: Username (required)
+        :type user_name: str
         :param offset:
         :type offset: int
         :param limit:
         :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param run_id:
-        :type run_id: int
-        :param return_code:
-        :type return_code: int
-        :param status:
-        :type status: JobStatus
-        :param all_runs: If false (default), only return results in the workflow_result table (current results). If true, return all historical results.
-        :type all_runs: bool
-        :param compute_node_id: Filter by compute node ID
-        :type compute_node_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -25475,18 +29757,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_results_serialize(
-            workflow_id=workflow_id,
-            job_id=job_id,
+        _param = self._list_user_groups_serialize(
+            user_name=user_name,
             offset=offset,
             limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            run_id=run_id,
-            return_code=return_code,
-            status=status,
-            all_runs=all_runs,
-            compute_node_id=compute_node_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -25494,9 +29768,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListResultsResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListAccessGroupsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -25511,19 +29783,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_results_without_preload_content(
+    def list_user_groups_without_preload_content(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        job_id: Annotated[Optional[StrictInt], Field(description="Return the results for a specific job.")] = None,
+        user_name: Annotated[StrictStr, Field(description="Username")],
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        run_id: Optional[StrictInt] = None,
-        return_code: Optional[StrictInt] = None,
-        status: Optional[JobStatus] = None,
-        all_runs: Annotated[Optional[StrictBool], Field(description="If false (default), only return results in the workflow_result table (current results). If true, return all historical results.")] = None,
-        compute_node_id: Annotated[Optional[StrictInt], Field(description="Filter by compute node ID")] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -25537,32 +29801,16 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve all job results for one workflow.
+        """List groups a user belongs to.
 
-        Retrieve all job results for one workflow.
+        List groups a user belongs to.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param job_id: Return the results for a specific job.
-        :type job_id: int
+        :param user_name: Username (required)
+        :type user_name: str
         :param offset:
         :type offset: int
         :param limit:
         :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param run_id:
-        :type run_id: int
-        :param return_code:
-        :type return_code: int
-        :param status:
-        :type status: JobStatus
-        :param all_runs: If false (default), only return results in the workflow_result table (current results). If true, return all historical results.
-        :type all_runs: bool
-        :param compute_node_id: Filter by compute node ID
-        :type compute_node_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -25585,18 +29833,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_results_serialize(
-            workflow_id=workflow_id,
-            job_id=job_id,
+        _param = self._list_user_groups_serialize(
+            user_name=user_name,
             offset=offset,
             limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            run_id=run_id,
-            return_code=return_code,
-            status=status,
-            all_runs=all_runs,
-            compute_node_id=compute_node_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -25604,9 +29844,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListResultsResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListAccessGroupsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -25616,19 +29854,11 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_results_serialize(
+    def _list_user_groups_serialize(
         self,
-        workflow_id,
-        job_id,
+        user_name,
         offset,
         limit,
-        sort_by,
-        reverse_sort,
-        run_id,
-        return_code,
-        status,
-        all_runs,
-        compute_node_id,
         _request_auth,
         _content_type,
         _headers,
@@ -25650,15 +29880,9 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if user_name is not None:
+            _path_params['user_name'] = user_name
         # process the query parameters
-        if workflow_id is not None:
-            
-            _query_params.append(('workflow_id', workflow_id))
-            
-        if job_id is not None:
-            
-            _query_params.append(('job_id', job_id))
-            
         if offset is not None:
             
             _query_params.append(('offset', offset))
@@ -25667,34 +29891,6 @@ class DefaultApi:
             
             _query_params.append(('limit', limit))
             
-        if sort_by is not None:
-            
-            _query_params.append(('sort_by', sort_by))
-            
-        if reverse_sort is not None:
-            
-            _query_params.append(('reverse_sort', reverse_sort))
-            
-        if run_id is not None:
-            
-            _query_params.append(('run_id', run_id))
-            
-        if return_code is not None:
-            
-            _query_params.append(('return_code', return_code))
-            
-        if status is not None:
-            
-            _query_params.append(('status', status.value))
-            
-        if all_runs is not None:
-            
-            _query_params.append(('all_runs', all_runs))
-            
-        if compute_node_id is not None:
-            
-            _query_params.append(('compute_node_id', compute_node_id))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -25715,7 +29911,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/results',
+            resource_path='/users/{user_name}/groups',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -25732,16 +29928,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_scheduled_compute_nodes(
+    def list_workflow_groups(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        scheduler_id: Optional[StrictStr] = None,
-        scheduler_config_id: Optional[StrictStr] = None,
-        status: Optional[StrictStr] = None,
+        id: Annotated[StrictInt, Field(description="ID of the workflow")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -25754,27 +29943,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListScheduledComputeNodesResponse:
-        """Retrieve scheduled compute node records for one workflow.
+    ) -> ListAccessGroupsResponse:
+        """List access groups that have access to a workflow.
 
-        Retrieve scheduled compute node records for one workflow.
+        List access groups that have access to a workflow.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param scheduler_id:
-        :type scheduler_id: str
-        :param scheduler_config_id:
-        :type scheduler_config_id: str
-        :param status:
-        :type status: str
+        :param id: ID of the workflow (required)
+        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -25797,15 +29972,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_scheduled_compute_nodes_serialize(
-            workflow_id=workflow_id,
-            offset=offset,
-            limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            scheduler_id=scheduler_id,
-            scheduler_config_id=scheduler_config_id,
-            status=status,
+        _param = self._list_workflow_groups_serialize(
+            id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -25813,9 +29981,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListScheduledComputeNodesResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListAccessGroupsResponse",
+            '404': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -25830,16 +29997,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_scheduled_compute_nodes_with_http_info(
+    def list_workflow_groups_with_http_info(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        scheduler_id: Optional[StrictStr] = None,
-        scheduler_config_id: Optional[StrictStr] = None,
-        status: Optional[StrictStr] = None,
+        id: Annotated[StrictInt, Field(description="ID of the workflow")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -25852,27 +30012,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListScheduledComputeNodesResponse]:
-        """Retrieve scheduled compute node records for one workflow.
+    ) -> ApiResponse[ListAccessGroupsResponse]:
+        """List access groups that have access to a workflow.
 
-        Retrieve scheduled compute node records for one workflow.
+        List access groups that have access to a workflow.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param scheduler_id:
-        :type scheduler_id: str
-        :param scheduler_config_id:
-        :type scheduler_config_id: str
-        :param status:
-        :type status: str
+        :param id: ID of the workflow (required)
+        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -25895,15 +30041,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_scheduled_compute_nodes_serialize(
-            workflow_id=workflow_id,
-            offset=offset,
-            limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            scheduler_id=scheduler_id,
-            scheduler_config_id=scheduler_config_id,
-            status=status,
+        _param = self._list_workflow_groups_serialize(
+            id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -25911,9 +30050,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListScheduledComputeNodesResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListAccessGroupsResponse",
+            '404': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -25928,16 +30066,9 @@ class DefaultApi:
 
 
     @validate_call
-    def list_scheduled_compute_nodes_without_preload_content(
+    def list_workflow_groups_without_preload_content(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        scheduler_id: Optional[StrictStr] = None,
-        scheduler_config_id: Optional[StrictStr] = None,
-        status: Optional[StrictStr] = None,
+        id: Annotated[StrictInt, Field(description="ID of the workflow")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -25948,29 +30079,15 @@ class DefaultApi:
         ] = None,
         _request_auth: Optional[Dict[StrictStr, Any]] = None,
         _content_type: Optional[StrictStr] = None,
-        _headers: Optional[Dict[StrictStr, Any]] = None,
-        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> RESTResponseType:
-        """Retrieve scheduled compute node records for one workflow.
-
-        Retrieve scheduled compute node records for one workflow.
-
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param scheduler_id:
-        :type scheduler_id: str
-        :param scheduler_config_id:
-        :type scheduler_config_id: str
-        :param status:
-        :type status: str
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """List access groups that have access to a workflow.
+
+        List access groups that have access to a workflow.
+
+        :param id: ID of the workflow (required)
+        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -25993,15 +30110,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_scheduled_compute_nodes_serialize(
-            workflow_id=workflow_id,
-            offset=offset,
-            limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            scheduler_id=scheduler_id,
-            scheduler_config_id=scheduler_config_id,
-            status=status,
+        _param = self._list_workflow_groups_serialize(
+            id=id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -26009,9 +30119,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListScheduledComputeNodesResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListAccessGroupsResponse",
+            '404': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -26021,16 +30130,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_scheduled_compute_nodes_serialize(
+    def _list_workflow_groups_serialize(
         self,
-        workflow_id,
-        offset,
-        limit,
-        sort_by,
-        reverse_sort,
-        scheduler_id,
-        scheduler_config_id,
-        status,
+        id,
         _request_auth,
         _content_type,
         _headers,
@@ -26052,39 +30154,9 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
-        if workflow_id is not None:
-            
-            _query_params.append(('workflow_id', workflow_id))
-            
-        if offset is not None:
-            
-            _query_params.append(('offset', offset))
-            
-        if limit is not None:
-            
-            _query_params.append(('limit', limit))
-            
-        if sort_by is not None:
-            
-            _query_params.append(('sort_by', sort_by))
-            
-        if reverse_sort is not None:
-            
-            _query_params.append(('reverse_sort', reverse_sort))
-            
-        if scheduler_id is not None:
-            
-            _query_params.append(('scheduler_id', scheduler_id))
-            
-        if scheduler_config_id is not None:
-            
-            _query_params.append(('scheduler_config_id', scheduler_config_id))
-            
-        if status is not None:
-            
-            _query_params.append(('status', status))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -26105,7 +30177,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/scheduled_compute_nodes',
+            resource_path='/workflows/{id}/access_groups',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -26122,22 +30194,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_slurm_schedulers(
+    def list_workflow_schedules(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        workflow_id: Annotated[Optional[StrictInt], Field(description="Only return schedules of this workflow")] = None,
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        name: Optional[StrictStr] = None,
-        account: Optional[StrictStr] = None,
-        gres: Optional[StrictStr] = None,
-        mem: Optional[StrictStr] = None,
-        nodes: Optional[StrictInt] = None,
-        partition: Optional[StrictStr] = None,
-        qos: Optional[StrictStr] = None,
-        tmp: Optional[StrictStr] = None,
-        walltime: Optional[StrictStr] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -26150,39 +30211,17 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListSlurmSchedulersResponse:
-        """Retrieve a Slurm compute node configuration.
+    ) -> ListWorkflowSchedulesResponse:
+        """List workflow schedules.
 
-        Retrieve a Slurm compute node configuration.
+        Retrieve workflow schedules, optionally for one workflow.
 
-        :param workflow_id: Workflow ID (required)
+        :param workflow_id: Only return schedules of this workflow
         :type workflow_id: int
         :param offset:
         :type offset: int
         :param limit:
         :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param name:
-        :type name: str
-        :param account:
-        :type account: str
-        :param gres:
-        :type gres: str
-        :param mem:
-        :type mem: str
-        :param nodes:
-        :type nodes: int
-        :param partition:
-        :type partition: str
-        :param qos:
-        :type qos: str
-        :param tmp:
-        :type tmp: str
-        :param walltime:
-        :type walltime: str
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -26205,21 +30244,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_slurm_schedulers_serialize(
+        _param = self._list_workflow_schedules_serialize(
             workflow_id=workflow_id,
             offset=offset,
             limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            name=name,
-            account=account,
-            gres=gres,
-            mem=mem,
-            nodes=nodes,
-            partition=partition,
-            qos=qos,
-            tmp=tmp,
-            walltime=walltime,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -26227,9 +30255,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListSlurmSchedulersResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListWorkflowSchedulesResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -26244,22 +30270,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_slurm_schedulers_with_http_info(
+    def list_workflow_schedules_with_http_info(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        workflow_id: Annotated[Optional[StrictInt], Field(description="Only return schedules of this workflow")] = None,
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        name: Optional[StrictStr] = None,
-        account: Optional[StrictStr] = None,
-        gres: Optional[StrictStr] = None,
-        mem: Optional[StrictStr] = None,
-        nodes: Optional[StrictInt] = None,
-        partition: Optional[StrictStr] = None,
-        qos: Optional[StrictStr] = None,
-        tmp: Optional[StrictStr] = None,
-        walltime: Optional[StrictStr] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -26272,39 +30287,17 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListSlurmSchedulersResponse]:
-        """Retrieve a Slurm compute node configuration.
+    ) -> ApiResponse[ListWorkflowSchedulesResponse]:
+        """List workflow schedules.
 
-        Retrieve a Slurm compute node configuration.
+        Retrieve workflow schedules, optionally for one workflow.
 
-        :param workflow_id: Workflow ID (required)
+        :param workflow_id: Only return schedules of this workflow
         :type workflow_id: int
         :param offset:
         :type offset: int
         :param limit:
         :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param name:
-        :type name: str
-        :param account:
-        :type account: str
-        :param gres:
-        :type gres: str
-        :param mem:
-        :type mem: str
-        :param nodes:
-        :type nodes: int
-        :param partition:
-        :type partition: str
-        :param qos:
-        :type qos: str
-        :param tmp:
-        :type tmp: str
-        :param walltime:
-        :type walltime: str
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -26327,21 +30320,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_slurm_schedulers_serialize(
+        _param = self._list_workflow_schedules_serialize(
             workflow_id=workflow_id,
             offset=offset,
             limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            name=name,
-            account=account,
-            gres=gres,
-            mem=mem,
-            nodes=nodes,
-            partition=partition,
-            qos=qos,
-            tmp=tmp,
-            walltime=walltime,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -26349,9 +30331,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListSlurmSchedulersResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListWorkflowSchedulesResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -26366,22 +30346,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_slurm_schedulers_without_preload_content(
+    def list_workflow_schedules_without_preload_content(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
+        workflow_id: Annotated[Optional[StrictInt], Field(description="Only return schedules of this workflow")] = None,
         offset: Optional[StrictInt] = None,
         limit: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        name: Optional[StrictStr] = None,
-        account: Optional[StrictStr] = None,
-        gres: Optional[StrictStr] = None,
-        mem: Optional[StrictStr] = None,
-        nodes: Optional[StrictInt] = None,
-        partition: Optional[StrictStr] = None,
-        qos: Optional[StrictStr] = None,
-        tmp: Optional[StrictStr] = None,
-        walltime: Optional[StrictStr] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -26395,38 +30364,16 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve a Slurm compute node configuration.
+        """List workflow schedules.
 
-        Retrieve a Slurm compute node configuration.
+        Retrieve workflow schedules, optionally for one workflow.
 
-        :param workflow_id: Workflow ID (required)
+        :param workflow_id: Only return schedules of this workflow
         :type workflow_id: int
         :param offset:
         :type offset: int
         :param limit:
         :type limit: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param name:
-        :type name: str
-        :param account:
-        :type account: str
-        :param gres:
-        :type gres: str
-        :param mem:
-        :type mem: str
-        :param nodes:
-        :type nodes: int
-        :param partition:
-        :type partition: str
-        :param qos:
-        :type qos: str
-        :param tmp:
-        :type tmp: str
-        :param walltime:
-        :type walltime: str
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -26449,21 +30396,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_slurm_schedulers_serialize(
+        _param = self._list_workflow_schedules_serialize(
             workflow_id=workflow_id,
             offset=offset,
             limit=limit,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            name=name,
-            account=account,
-            gres=gres,
-            mem=mem,
-            nodes=nodes,
-            partition=partition,
-            qos=qos,
-            tmp=tmp,
-            walltime=walltime,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -26471,9 +30407,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListSlurmSchedulersResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListWorkflowSchedulesResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -26483,22 +30417,11 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_slurm_schedulers_serialize(
+    def _list_workflow_schedules_serialize(
         self,
         workflow_id,
         offset,
         limit,
-        sort_by,
-        reverse_sort,
-        name,
-        account,
-        gres,
-        mem,
-        nodes,
-        partition,
-        qos,
-        tmp,
-        walltime,
         _request_auth,
         _content_type,
         _headers,
@@ -26533,50 +30456,6 @@ class DefaultApi:
             
             _query_params.append(('limit', limit))
             
-        if sort_by is not None:
-            
-            _query_params.append(('sort_by', sort_by))
-            
-        if reverse_sort is not None:
-            
-            _query_params.append(('reverse_sort', reverse_sort))
-            
-        if name is not None:
-            
-            _query_params.append(('name', name))
-            
-        if account is not None:
-            
-            _query_params.append(('account', account))
-            
-        if gres is not None:
-            
-            _query_params.append(('gres', gres))
-            
-        if mem is not None:
-            
-            _query_params.append(('mem', mem))
-            
-        if nodes is not None:
-            
-            _query_params.append(('nodes', nodes))
-            
-        if partition is not None:
-            
-            _query_params.append(('partition', partition))
-            
-        if qos is not None:
-            
-            _query_params.append(('qos', qos))
-            
-        if tmp is not None:
-            
-            _query_params.append(('tmp', tmp))
-            
-        if walltime is not None:
-            
-            _query_params.append(('walltime', walltime))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -26597,7 +30476,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/slurm_schedulers',
+            resource_path='/workflow_schedules',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -26614,17 +30493,16 @@ class DefaultApi:
 
 
     @validate_call
-    def list_user_data(
+    def list_workflows(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        consumer_job_id: Annotated[Optional[StrictInt], Field(description="Return user data records that are consumed by a specific job.")] = None,
-        producer_job_id: Annotated[Optional[StrictInt], Field(description="Return user data records that are produced by a specific job.")] = None,
         offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
         sort_by: Optional[StrictStr] = None,
         reverse_sort: Optional[StrictBool] = None,
+        limit: Optional[StrictInt] = None,
         name: Optional[StrictStr] = None,
-        is_ephemeral: Optional[StrictBool] = None,
+        user: Optional[StrictStr] = None,
+        description: Optional[StrictStr] = None,
+        is_archived: Optional[StrictBool] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -26637,29 +30515,27 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListUserDataResponse:
-        """Retrieve all user data records for one workflow.
+    ) -> ListWorkflowsResponse:
+        """Retrieve all workflows.
 
-        Retrieve all user data records for one workflow.
+        Retrieve all workflows.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param consumer_job_id: Return user data records that are consumed by a specific job.
-        :type consumer_job_id: int
-        :param producer_job_id: Return user data records that are produced by a specific job.
-        :type producer_job_id: int
         :param offset:
         :type offset: int
-        :param limit:
-        :type limit: int
         :param sort_by:
         :type sort_by: str
         :param reverse_sort:
         :type reverse_sort: bool
+        :param limit:
+        :type limit: int
         :param name:
         :type name: str
-        :param is_ephemeral:
-        :type is_ephemeral: bool
+        :param user:
+        :type user: str
+        :param description:
+        :type description: str
+        :param is_archived:
+        :type is_archived: bool
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -26682,16 +30558,15 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_user_data_serialize(
-            workflow_id=workflow_id,
-            consumer_job_id=consumer_job_id,
-            producer_job_id=producer_job_id,
+        _param = self._list_workflows_serialize(
             offset=offset,
-            limit=limit,
             sort_by=sort_by,
             reverse_sort=reverse_sort,
+            limit=limit,
             name=name,
-            is_ephemeral=is_ephemeral,
+            user=user,
+            description=description,
+            is_archived=is_archived,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -26699,9 +30574,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListUserDataResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListWorkflowsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -26716,17 +30589,16 @@ class DefaultApi:
 
 
     @validate_call
-    def list_user_data_with_http_info(
+    def list_workflows_with_http_info(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        consumer_job_id: Annotated[Optional[StrictInt], Field(description="Return user data records that are consumed by a specific job.")] = None,
-        producer_job_id: Annotated[Optional[StrictInt], Field(description="Return user data records that are produced by a specific job.")] = None,
         offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
         sort_by: Optional[StrictStr] = None,
         reverse_sort: Optional[StrictBool] = None,
+        limit: Optional[StrictInt] = None,
         name: Optional[StrictStr] = None,
-        is_ephemeral: Optional[StrictBool] = None,
+        user: Optional[StrictStr] = None,
+        description: Optional[StrictStr] = None,
+        is_archived: Optional[StrictBool] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -26739,29 +30611,27 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListUserDataResponse]:
-        """Retrieve all user data records for one workflow.
+    ) -> ApiResponse[ListWorkflowsResponse]:
+        """Retrieve all workflows.
 
-        Retrieve all user data records for one workflow.
+        Retrieve all workflows.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param consumer_job_id: Return user data records that are consumed by a specific job.
-        :type consumer_job_id: int
-        :param producer_job_id: Return user data records that are produced by a specific job.
-        :type producer_job_id: int
         :param offset:
         :type offset: int
-        :param limit:
-        :type limit: int
         :param sort_by:
         :type sort_by: str
         :param reverse_sort:
         :type reverse_sort: bool
+        :param limit:
+        :type limit: int
         :param name:
         :type name: str
-        :param is_ephemeral:
-        :type is_ephemeral: bool
+        :param user:
+        :type user: str
+        :param description:
+        :type description: str
+        :param is_archived:
+        :type is_archived: bool
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -26784,16 +30654,15 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_user_data_serialize(
-            workflow_id=workflow_id,
-            consumer_job_id=consumer_job_id,
-            producer_job_id=producer_job_id,
+        _param = self._list_workflows_serialize(
             offset=offset,
-            limit=limit,
             sort_by=sort_by,
             reverse_sort=reverse_sort,
+            limit=limit,
             name=name,
-            is_ephemeral=is_ephemeral,
+            user=user,
+            description=description,
+            is_archived=is_archived,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -26801,9 +30670,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListUserDataResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListWorkflowsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -26818,17 +30685,16 @@ class DefaultApi:
 
 
     @validate_call
-    def list_user_data_without_preload_content(
+    def list_workflows_without_preload_content(
         self,
-        workflow_id: Annotated[StrictInt, Field(description="Workflow ID")],
-        consumer_job_id: Annotated[Optional[StrictInt], Field(description="Return user data records that are consumed by a specific job.")] = None,
-        producer_job_id: Annotated[Optional[StrictInt], Field(description="Return user data records that are produced by a specific job.")] = None,
         offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
         sort_by: Optional[StrictStr] = None,
         reverse_sort: Optional[StrictBool] = None,
+        limit: Optional[StrictInt] = None,
         name: Optional[StrictStr] = None,
-        is_ephemeral: Optional[StrictBool] = None,
+        user: Optional[StrictStr] = None,
+        description: Optional[StrictStr] = None,
+        is_archived: Optional[StrictBool] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -26842,28 +30708,26 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve all user data records for one workflow.
+        """Retrieve all workflows.
 
-        Retrieve all user data records for one workflow.
+        Retrieve all workflows.
 
-        :param workflow_id: Workflow ID (required)
-        :type workflow_id: int
-        :param consumer_job_id: Return user data records that are consumed by a specific job.
-        :type consumer_job_id: int
-        :param producer_job_id: Return user data records that are produced by a specific job.
-        :type producer_job_id: int
         :param offset:
         :type offset: int
-        :param limit:
-        :type limit: int
         :param sort_by:
         :type sort_by: str
         :param reverse_sort:
         :type reverse_sort: bool
+        :param limit:
+        :type limit: int
         :param name:
         :type name: str
-        :param is_ephemeral:
-        :type is_ephemeral: bool
+        :param user:
+        :type user: str
+        :param description:
+        :type description: str
+        :param is_archived:
+        :type is_archived: bool
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -26886,16 +30750,15 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_user_data_serialize(
-            workflow_id=workflow_id,
-            consumer_job_id=consumer_job_id,
-            producer_job_id=producer_job_id,
+        _param = self._list_workflows_serialize(
             offset=offset,
-            limit=limit,
             sort_by=sort_by,
             reverse_sort=reverse_sort,
+            limit=limit,
             name=name,
-            is_ephemeral=is_ephemeral,
+            user=user,
+            description=description,
+            is_archived=is_archived,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -26903,9 +30766,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListUserDataResponse",
-            '403': "ForbiddenErrorResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "ListWorkflowsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -26915,17 +30776,16 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_user_data_serialize(
+    def _list_workflows_serialize(
         self,
-        workflow_id,
-        consumer_job_id,
-        producer_job_id,
         offset,
-        limit,
         sort_by,
         reverse_sort,
+        limit,
         name,
-        is_ephemeral,
+        user,
+        description,
+        is_archived,
         _request_auth,
         _content_type,
         _headers,
@@ -26948,26 +30808,10 @@ class DefaultApi:
 
         # process the path parameters
         # process the query parameters
-        if workflow_id is not None:
-            
-            _query_params.append(('workflow_id', workflow_id))
-            
-        if consumer_job_id is not None:
-            
-            _query_params.append(('consumer_job_id', consumer_job_id))
-            
-        if producer_job_id is not None:
-            
-            _query_params.append(('producer_job_id', producer_job_id))
-            
         if offset is not None:
             
             _query_params.append(('offset', offset))
             
-        if limit is not None:
-            
-            _query_params.append(('limit', limit))
-            
         if sort_by is not None:
             
             _query_params.append(('sort_by', sort_by))
@@ -26976,13 +30820,25 @@ class DefaultApi:
             
             _query_params.append(('reverse_sort', reverse_sort))
             
+        if limit is not None:
+            
+            _query_params.append(('limit', limit))
+            
         if name is not None:
             
             _query_params.append(('name', name))
             
-        if is_ephemeral is not None:
+        if user is not None:
             
-            _query_params.append(('is_ephemeral', is_ephemeral))
+            _query_params.append(('user', user))
+            
+        if description is not None:
+            
+            _query_params.append(('description', description))
+            
+        if is_archived is not None:
+            
+            _query_params.append(('is_archived', is_archived))
             
         # process the header parameters
         # process the form parameters
@@ -27004,7 +30860,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/user_data',
+            resource_path='/workflows',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -27021,11 +30877,12 @@ class DefaultApi:
 
 
     @validate_call
-    def list_user_groups(
+    def manage_status_change(
         self,
-        user_name: Annotated[StrictStr, Field(description="Username")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
+        id: Annotated[StrictInt, Field(description="Job ID")],
+        status: Annotated[JobStatus, Field(description="New job status")],
+        run_id: Annotated[StrictInt, Field(description="Current job run ID")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -27038,17 +30895,19 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListAccessGroupsResponse:
-        """List groups a user belongs to.
+    ) -> JobModel:
+        """Change the status of a job and manage side effects.
 
-        List groups a user belongs to.
+        Change the status of a job and manage side effects.
 
-        :param user_name: Username (required)
-        :type user_name: str
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
+        :param id: Job ID (required)
+        :type id: int
+        :param status: New job status (required)
+        :type status: JobStatus
+        :param run_id: Current job run ID (required)
+        :type run_id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -27071,10 +30930,11 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_user_groups_serialize(
-            user_name=user_name,
-            offset=offset,
-            limit=limit,
+        _param = self._manage_status_change_serialize(
+            id=id,
+            status=status,
+            run_id=run_id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -27082,7 +30942,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListAccessGroupsResponse",
+            '200': "JobModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -27097,11 +30957,12 @@ class DefaultApi:
 
 
     @validate_call
-    def list_user_groups_with_http_info(
+    def manage_status_change_with_http_info(
         self,
-        user_name: Annotated[StrictStr, Field(description="Username")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
+        id: Annotated[StrictInt, Field(description="Job ID")],
+        status: Annotated[JobStatus, Field(description="New job status")],
+        run_id: Annotated[StrictInt, Field(description="Current job run ID")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -27114,17 +30975,19 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListAccessGroupsResponse]:
-        """List groups a user belongs to.
+    ) -> ApiResponse[JobModel]:
+        """Change the status of a job and manage side effects.
 
-        List groups a user belongs to.
+        Change the status of a job and manage side effects.
 
-        :param user_name: Username (required)
-        :type user_name: str
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
+        :param id: Job ID (required)
+        :type id: int
+        :param status: New job status (required)
+        :type status: JobStatus
+        :param run_id: Current job run ID (required)
+        :type run_id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -27147,10 +31010,11 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_user_groups_serialize(
-            user_name=user_name,
-            offset=offset,
-            limit=limit,
+        _param = self._manage_status_change_serialize(
+            id=id,
+            status=status,
+            run_id=run_id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -27158,7 +31022,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListAccessGroupsResponse",
+            '200': "JobModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -27173,11 +31037,12 @@ class DefaultApi:
 
 
     @validate_call
-    def list_user_groups_without_preload_content(
+    def manage_status_change_without_preload_content(
         self,
-        user_name: Annotated[StrictStr, Field(description="Username")],
-        offset: Optional[StrictInt] = None,
-        limit: Optional[StrictInt] = None,
+        id: Annotated[StrictInt, Field(description="Job ID")],
+        status: Annotated[JobStatus, Field(description="New job status")],
+        run_id: Annotated[StrictInt, Field(description="Current job run ID")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -27191,16 +31056,18 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """List groups a user belongs to.
+        """Change the status of a job and manage side effects.
 
-        List groups a user belongs to.
+        Change the status of a job and manage side effects.
 
-        :param user_name: Username (required)
-        :type user_name: str
-        :param offset:
-        :type offset: int
-        :param limit:
-        :type limit: int
+        :param id: Job ID (required)
+        :type id: int
+        :param status: New job status (required)
+        :type status: JobStatus
+        :param run_id: Current job run ID (required)
+        :type run_id: int
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -27223,10 +31090,11 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_user_groups_serialize(
-            user_name=user_name,
-            offset=offset,
-            limit=limit,
+        _param = self._manage_status_change_serialize(
+            id=id,
+            status=status,
+            run_id=run_id,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -27234,7 +31102,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListAccessGroupsResponse",
+            '200': "JobModel",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -27244,11 +31112,12 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_user_groups_serialize(
+    def _manage_status_change_serialize(
         self,
-        user_name,
-        offset,
-        limit,
+        id,
+        status,
+        run_id,
+        body,
         _request_auth,
         _content_type,
         _headers,
@@ -27270,20 +31139,18 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if user_name is not None:
-            _path_params['user_name'] = user_name
+        if id is not None:
+            _path_params['id'] = id
+        if status is not None:
+            _path_params['status'] = status.value
+        if run_id is not None:
+            _path_params['run_id'] = run_id
         # process the query parameters
-        if offset is not None:
-            
-            _query_params.append(('offset', offset))
-            
-        if limit is not None:
-            
-            _query_params.append(('limit', limit))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
+        if body is not None:
+            _body_params = body
 
 
         # set the HTTP header `Accept`
@@ -27294,14 +31161,27 @@ class DefaultApi:
                 ]
             )
 
+        # set the HTTP header `Content-Type`
+        if _content_type:
+            _header_params['Content-Type'] = _content_type
+        else:
+            _default_content_type = (
+                self.api_client.select_header_content_type(
+                    [
+                        'application/json'
+                    ]
+                )
+            )
+            if _default_content_type is not None:
+                _header_params['Content-Type'] = _default_content_type
 
         # authentication setting
         _auth_settings: List[str] = [
         ]
 
         return self.api_client.param_serialize(
-            method='GET',
-            resource_path='/users/{user_name}/groups',
+            method='PUT',
+            resource_path='/jobs/{id}/manage_status_change/{status}/{run_id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -27318,9 +31198,8 @@ class DefaultApi:
 
 
     @validate_call
-    def list_workflow_groups(
+    def ping(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the workflow")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -27333,13 +31212,11 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListAccessGroupsResponse:
-        """List access groups that have access to a workflow.
+    ) -> object:
+        """Check if the service is running.
 
-        List access groups that have access to a workflow.
+        Check if the service is running.
 
-        :param id: ID of the workflow (required)
-        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -27362,8 +31239,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_workflow_groups_serialize(
-            id=id,
+        _param = self._ping_serialize(
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -27371,8 +31247,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListAccessGroupsResponse",
-            '404': "DefaultErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -27387,9 +31262,8 @@ class DefaultApi:
 
 
     @validate_call
-    def list_workflow_groups_with_http_info(
+    def ping_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the workflow")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -27402,13 +31276,11 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListAccessGroupsResponse]:
-        """List access groups that have access to a workflow.
+    ) -> ApiResponse[object]:
+        """Check if the service is running.
 
-        List access groups that have access to a workflow.
+        Check if the service is running.
 
-        :param id: ID of the workflow (required)
-        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -27431,8 +31303,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_workflow_groups_serialize(
-            id=id,
+        _param = self._ping_serialize(
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -27440,8 +31311,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListAccessGroupsResponse",
-            '404': "DefaultErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -27456,9 +31326,8 @@ class DefaultApi:
 
 
     @validate_call
-    def list_workflow_groups_without_preload_content(
+    def ping_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the workflow")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -27472,12 +31341,10 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """List access groups that have access to a workflow.
+        """Check if the service is running.
 
-        List access groups that have access to a workflow.
+        Check if the service is running.
 
-        :param id: ID of the workflow (required)
-        :type id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -27500,8 +31367,7 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_workflow_groups_serialize(
-            id=id,
+        _param = self._ping_serialize(
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -27509,8 +31375,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListAccessGroupsResponse",
-            '404': "DefaultErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -27520,9 +31385,8 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_workflow_groups_serialize(
+    def _ping_serialize(
         self,
-        id,
         _request_auth,
         _content_type,
         _headers,
@@ -27544,8 +31408,6 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
-        if id is not None:
-            _path_params['id'] = id
         # process the query parameters
         # process the header parameters
         # process the form parameters
@@ -27567,7 +31429,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='GET',
-            resource_path='/workflows/{id}/access_groups',
+            resource_path='/ping',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -27584,16 +31446,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_workflows(
+    def process_changed_job_inputs(
         self,
-        offset: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        limit: Optional[StrictInt] = None,
-        name: Optional[StrictStr] = None,
-        user: Optional[StrictStr] = None,
-        description: Optional[StrictStr] = None,
-        is_archived: Optional[StrictBool] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        dry_run: Annotated[Optional[StrictBool], Field(description="If true, report changes but do not change the database.")] = None,
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -27606,27 +31463,17 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListWorkflowsResponse:
-        """Retrieve all workflows.
+    ) -> ProcessChangedJobInputsResponse:
+        """Check for changed job inputs and update status accordingly.
 
-        Retrieve all workflows.
+        Check for changed job inputs and update status accordingly.
 
-        :param offset:
-        :type offset: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param limit:
-        :type limit: int
-        :param name:
-        :type name: str
-        :param user:
-        :type user: str
-        :param description:
-        :type description: str
-        :param is_archived:
-        :type is_archived: bool
+        :param id: Workflow ID (required)
+        :type id: int
+        :param dry_run: If true, report changes but do not change the database.
+        :type dry_run: bool
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -27649,15 +31496,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_workflows_serialize(
-            offset=offset,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            limit=limit,
-            name=name,
-            user=user,
-            description=description,
-            is_archived=is_archived,
+        _param = self._process_changed_job_inputs_serialize(
+            id=id,
+            dry_run=dry_run,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -27665,7 +31507,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListWorkflowsResponse",
+            '200': "ProcessChangedJobInputsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -27680,16 +31522,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_workflows_with_http_info(
+    def process_changed_job_inputs_with_http_info(
         self,
-        offset: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        limit: Optional[StrictInt] = None,
-        name: Optional[StrictStr] = None,
-        user: Optional[StrictStr] = None,
-        description: Optional[StrictStr] = None,
-        is_archived: Optional[StrictBool] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        dry_run: Annotated[Optional[StrictBool], Field(description="If true, report changes but do not change the database.")] = None,
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -27702,27 +31539,17 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListWorkflowsResponse]:
-        """Retrieve all workflows.
+    ) -> ApiResponse[ProcessChangedJobInputsResponse]:
+        """Check for changed job inputs and update status accordingly.
 
-        Retrieve all workflows.
+        Check for changed job inputs and update status accordingly.
 
-        :param offset:
-        :type offset: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param limit:
-        :type limit: int
-        :param name:
-        :type name: str
-        :param user:
-        :type user: str
-        :param description:
-        :type description: str
-        :param is_archived:
-        :type is_archived: bool
+        :param id: Workflow ID (required)
+        :type id: int
+        :param dry_run: If true, report changes but do not change the database.
+        :type dry_run: bool
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -27745,15 +31572,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_workflows_serialize(
-            offset=offset,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            limit=limit,
-            name=name,
-            user=user,
-            description=description,
-            is_archived=is_archived,
+        _param = self._process_changed_job_inputs_serialize(
+            id=id,
+            dry_run=dry_run,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -27761,7 +31583,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListWorkflowsResponse",
+            '200': "ProcessChangedJobInputsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -27776,16 +31598,11 @@ class DefaultApi:
 
 
     @validate_call
-    def list_workflows_without_preload_content(
+    def process_changed_job_inputs_without_preload_content(
         self,
-        offset: Optional[StrictInt] = None,
-        sort_by: Optional[StrictStr] = None,
-        reverse_sort: Optional[StrictBool] = None,
-        limit: Optional[StrictInt] = None,
-        name: Optional[StrictStr] = None,
-        user: Optional[StrictStr] = None,
-        description: Optional[StrictStr] = None,
-        is_archived: Optional[StrictBool] = None,
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        dry_run: Annotated[Optional[StrictBool], Field(description="If true, report changes but do not change the database.")] = None,
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -27799,26 +31616,16 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retrieve all workflows.
+        """Check for changed job inputs and update status accordingly.
 
-        Retrieve all workflows.
+        Check for changed job inputs and update status accordingly.
 
-        :param offset:
-        :type offset: int
-        :param sort_by:
-        :type sort_by: str
-        :param reverse_sort:
-        :type reverse_sort: bool
-        :param limit:
-        :type limit: int
-        :param name:
-        :type name: str
-        :param user:
-        :type user: str
-        :param description:
-        :type description: str
-        :param is_archived:
-        :type is_archived: bool
+        :param id: Workflow ID (required)
+        :type id: int
+        :param dry_run: If true, report changes but do not change the database.
+        :type dry_run: bool
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -27841,15 +31648,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._list_workflows_serialize(
-            offset=offset,
-            sort_by=sort_by,
-            reverse_sort=reverse_sort,
-            limit=limit,
-            name=name,
-            user=user,
-            description=description,
-            is_archived=is_archived,
+        _param = self._process_changed_job_inputs_serialize(
+            id=id,
+            dry_run=dry_run,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -27857,7 +31659,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ListWorkflowsResponse",
+            '200': "ProcessChangedJobInputsResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -27867,16 +31669,11 @@ class DefaultApi:
         return response_data.response
 
 
-    def _list_workflows_serialize(
+    def _process_changed_job_inputs_serialize(
         self,
-        offset,
-        sort_by,
-        reverse_sort,
-        limit,
-        name,
-        user,
-        description,
-        is_archived,
+        id,
+        dry_run,
+        body,
         _request_auth,
         _content_type,
         _headers,
@@ -27898,42 +31695,18 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
         # process the query parameters
-        if offset is not None:
-            
-            _query_params.append(('offset', offset))
-            
-        if sort_by is not None:
-            
-            _query_params.append(('sort_by', sort_by))
-            
-        if reverse_sort is not None:
-            
-            _query_params.append(('reverse_sort', reverse_sort))
-            
-        if limit is not None:
-            
-            _query_params.append(('limit', limit))
-            
-        if name is not None:
-            
-            _query_params.append(('name', name))
-            
-        if user is not None:
-            
-            _query_params.append(('user', user))
-            
-        if description is not None:
-            
-            _query_params.append(('description', description))
-            
-        if is_archived is not None:
+        if dry_run is not None:
             
-            _query_params.append(('is_archived', is_archived))
+            _query_params.append(('dry_run', dry_run))
             
         # process the header parameters
         # process the form parameters
         # process the body parameter
+        if body is not None:
+            _body_params = body
 
 
         # set the HTTP header `Accept`
@@ -27944,14 +31717,27 @@ class DefaultApi:
                 ]
             )
 
+        # set the HTTP header `Content-Type`
+        if _content_type:
+            _header_params['Content-Type'] = _content_type
+        else:
+            _default_content_type = (
+                self.api_client.select_header_content_type(
+                    [
+                        'application/json'
+                    ]
+                )
+            )
+            if _default_content_type is not None:
+                _header_params['Content-Type'] = _default_content_type
 
         # authentication setting
         _auth_settings: List[str] = [
         ]
 
         return self.api_client.param_serialize(
-            method='GET',
-            resource_path='/workflows',
+            method='POST',
+            resource_path='/workflows/{id}/process_changed_job_inputs',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -27968,12 +31754,10 @@ class DefaultApi:
 
 
     @validate_call
-    def manage_status_change(
+    def release_compute_node(
         self,
-        id: Annotated[StrictInt, Field(description="Job ID")],
-        status: Annotated[JobStatus, Field(description="New job status")],
-        run_id: Annotated[StrictInt, Field(description="Current job run ID")],
-        body: Optional[Dict[str, Any]] = None,
+        id: Annotated[StrictInt, Field(description="ID of the compute node.")],
+        job_id: Annotated[StrictInt, Field(description="ID of the job that reserved the compute node.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -27986,19 +31770,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> JobModel:
-        """Change the status of a job and manage side effects.
+    ) -> ComputeNodeModel:
+        """Release a compute node reserved for a job.
 
-        Change the status of a job and manage side effects.
+        Release the reservation of a compute node for a job. A compute node that is reserved for another job is left unchanged.
 
-        :param id: Job ID (required)
+        :param id: ID of the compute node. (required)
         :type id: int
-        :param status: New job status (required)
-        :type status: JobStatus
-        :param run_id: Current job run ID (required)
-        :type run_id: int
-        :param body:
-        :type body: object
+        :param job_id: ID of the job that reserved the compute node. (required)
+        :type job_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -28021,11 +31801,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._manage_status_change_serialize(
+        _param = self._release_compute_node_serialize(
             id=id,
-            status=status,
-            run_id=run_id,
-            body=body,
+            job_id=job_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -28033,7 +31811,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "JobModel",
+            '200': "ComputeNodeModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -28048,12 +31827,10 @@ class DefaultApi:
 
 
     @validate_call
-    def manage_status_change_with_http_info(
+    def release_compute_node_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Job ID")],
-        status: Annotated[JobStatus, Field(description="New job status")],
-        run_id: Annotated[StrictInt, Field(description="Current job run ID")],
-        body: Optional[Dict[str, Any]] = None,
+        id: Annotated[StrictInt, Field(description="ID of the compute node.")],
+        job_id: Annotated[StrictInt, Field(description="ID of the job that reserved the compute node.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -28066,19 +31843,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[JobModel]:
-        """Change the status of a job and manage side effects.
+    ) -> ApiResponse[ComputeNodeModel]:
+        """Release a compute node reserved for a job.
 
-        Change the status of a job and manage side effects.
+        Release the reservation of a compute node for a job. A compute node that is reserved for another job is left unchanged.
 
-        :param id: Job ID (required)
+        :param id: ID of the compute node. (required)
         :type id: int
-        :param status: New job status (required)
-        :type status: JobStatus
-        :param run_id: Current job run ID (required)
-        :type run_id: int
-        :param body:
-        :type body: object
+        :param job_id: ID of the job that reserved the compute node. (required)
+        :type job_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -28101,11 +31874,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._manage_status_change_serialize(
+        _param = self._release_compute_node_serialize(
             id=id,
-            status=status,
-            run_id=run_id,
-            body=body,
+            job_id=job_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -28113,7 +31884,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "JobModel",
+            '200': "ComputeNodeModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -28128,12 +31900,10 @@ class DefaultApi:
 
 
     @validate_call
-    def manage_status_change_without_preload_content(
+    def release_compute_node_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Job ID")],
-        status: Annotated[JobStatus, Field(description="New job status")],
-        run_id: Annotated[StrictInt, Field(description="Current job run ID")],
-        body: Optional[Dict[str, Any]] = None,
+        id: Annotated[StrictInt, Field(description="ID of the compute node.")],
+        job_id: Annotated[StrictInt, Field(description="ID of the job that reserved the compute node.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -28147,18 +31917,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Change the status of a job and manage side effects.
+        """Release a compute node reserved for a job.
 
-        Change the status of a job and manage side effects.
+        Release the reservation of a compute node for a job. A compute node that is reserved for another job is left unchanged.
 
-        :param id: Job ID (required)
+        :param id: ID of the compute node. (required)
         :type id: int
-        :param status: New job status (required)
-        :type status: JobStatus
-        :param run_id: Current job run ID (required)
-        :type run_id: int
-        :param body:
-        :type body: object
+        :param job_id: ID of the job that reserved the compute node. (required)
+        :type job_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -28181,11 +31947,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._manage_status_change_serialize(
+        _param = self._release_compute_node_serialize(
             id=id,
-            status=status,
-            run_id=run_id,
-            body=body,
+            job_id=job_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -28193,7 +31957,8 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "JobModel",
+            '200': "ComputeNodeModel",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -28203,12 +31968,10 @@ class DefaultApi:
         return response_data.response
 
 
-    def _manage_status_change_serialize(
+    def _release_compute_node_serialize(
         self,
         id,
-        status,
-        run_id,
-        body,
+        job_id,
         _request_auth,
         _content_type,
         _headers,
@@ -28232,16 +31995,12 @@ class DefaultApi:
         # process the path parameters
         if id is not None:
             _path_params['id'] = id
-        if status is not None:
-            _path_params['status'] = status.value
-        if run_id is not None:
-            _path_params['run_id'] = run_id
+        if job_id is not None:
+            _path_params['job_id'] = job_id
         # process the query parameters
         # process the header parameters
         # process the form parameters
         # process the body parameter
-        if body is not None:
-            _body_params = body
 
 
         # set the HTTP header `Accept`
@@ -28252,27 +32011,14 @@ class DefaultApi:
                 ]
             )
 
-        # set the HTTP header `Content-Type`
-        if _content_type:
-            _header_params['Content-Type'] = _content_type
-        else:
-            _default_content_type = (
-                self.api_client.select_header_content_type(
-                    [
-                        'application/json'
-                    ]
-                )
-            )
-            if _default_content_type is not None:
-                _header_params['Content-Type'] = _default_content_type
 
         # authentication setting
         _auth_settings: List[str] = [
         ]
 
         return self.api_client.param_serialize(
-            method='PUT',
-            resource_path='/jobs/{id}/manage_status_change/{status}/{run_id}',
+            method='DELETE',
+            resource_path='/compute_nodes/{id}/reservation/{job_id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -28289,8 +32035,11 @@ class DefaultApi:
 
 
     @validate_call
-    def ping(
+    def remove_user_from_group(
         self,
+        id: Annotated[StrictInt, Field(description="ID of the access group")],
+        user_name: Annotated[StrictStr, Field(description="Username to remove")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -28304,10 +32053,16 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> object:
-        """Check if the service is running.
+        """Remove a user from an access group.
 
-        Check if the service is running.
+        Remove a user from an access group.
 
+        :param id: ID of the access group (required)
+        :type id: int
+        :param user_name: Username to remove (required)
+        :type user_name: str
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -28330,7 +32085,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._ping_serialize(
+        _param = self._remove_user_from_group_serialize(
+            id=id,
+            user_name=user_name,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -28339,6 +32097,8 @@ class DefaultApi:
 
         _response_types_map: Dict[str, Optional[str]] = {
             '200': "object",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -28353,8 +32113,11 @@ class DefaultApi:
 
 
     @validate_call
-    def ping_with_http_info(
+    def remove_user_from_group_with_http_info(
         self,
+        id: Annotated[StrictInt, Field(description="ID of the access group")],
+        user_name: Annotated[StrictStr, Field(description="Username to remove")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -28368,10 +32131,16 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> ApiResponse[object]:
-        """Check if the service is running.
+        """Remove a user from an access group.
 
-        Check if the service is running.
+        Remove a user from an access group.
 
+        :param id: ID of the access group (required)
+        :type id: int
+        :param user_name: Username to remove (required)
+        :type user_name: str
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -28394,7 +32163,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._ping_serialize(
+        _param = self._remove_user_from_group_serialize(
+            id=id,
+            user_name=user_name,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -28403,6 +32175,8 @@ class DefaultApi:
 
         _response_types_map: Dict[str, Optional[str]] = {
             '200': "object",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -28417,8 +32191,11 @@ class DefaultApi:
 
 
     @validate_call
-    def ping_without_preload_content(
+    def remove_user_from_group_without_preload_content(
         self,
+        id: Annotated[StrictInt, Field(description="ID of the access group")],
+        user_name: Annotated[StrictStr, Field(description="Username to remove")],
+        body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -28432,10 +32209,16 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Check if the service is running.
+        """Remove a user from an access group.
 
-        Check if the service is running.
+        Remove a user from an access group.
 
+        :param id: ID of the access group (required)
+        :type id: int
+        :param user_name: Username to remove (required)
+        :type user_name: str
+        :param body:
+        :type body: object
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -28458,7 +32241,10 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._ping_serialize(
+        _param = self._remove_user_from_group_serialize(
+            id=id,
+            user_name=user_name,
+            body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -28467,6 +32253,8 @@ class DefaultApi:
 
         _response_types_map: Dict[str, Optional[str]] = {
             '200': "object",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -28476,8 +32264,11 @@ class DefaultApi:
         return response_data.response
 
 
-    def _ping_serialize(
+    def _remove_user_from_group_serialize(
         self,
+        id,
+        user_name,
+        body,
         _request_auth,
         _content_type,
         _headers,
@@ -28499,10 +32290,16 @@ class DefaultApi:
         _body_params: Optional[bytes] = None
 
         # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
+        if user_name is not None:
+            _path_params['user_name'] = user_name
         # process the query parameters
         # process the header parameters
         # process the form parameters
         # process the body parameter
+        if body is not None:
+            _body_params = body
 
 
         # set the HTTP header `Accept`
@@ -28513,14 +32310,27 @@ class DefaultApi:
                 ]
             )
 
+        # set the HTTP header `Content-Type`
+        if _content_type:
+            _header_params['Content-Type'] = _content_type
+        else:
+            _default_content_type = (
+                self.api_client.select_header_content_type(
+                    [
+                        'application/json'
+                    ]
+                )
+            )
+            if _default_content_type is not None:
+                _header_params['Content-Type'] = _default_content_type
 
         # authentication setting
         _auth_settings: List[str] = [
         ]
 
         return self.api_client.param_serialize(
-            method='GET',
-            resource_path='/ping',
+            method='DELETE',
+            resource_path='/access_groups/{id}/members/{user_name}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -28537,10 +32347,10 @@ class DefaultApi:
 
 
     @validate_call
-    def process_changed_job_inputs(
+    def remove_workflow_from_group(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        dry_run: Annotated[Optional[StrictBool], Field(description="If true, report changes but do not change the database.")] = None,
+        id: Annotated[StrictInt, Field(description="ID of the workflow")],
+        group_id: Annotated[StrictInt, Field(description="ID of the access group")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -28554,15 +32364,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ProcessChangedJobInputsResponse:
-        """Check for changed job inputs and update status accordingly.
+    ) -> object:
+        """Revoke an access group's access to a workflow.
 
-        Check for changed job inputs and update status accordingly.
+        Revoke an access group's access to a workflow.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the workflow (required)
         :type id: int
-        :param dry_run: If true, report changes but do not change the database.
-        :type dry_run: bool
+        :param group_id: ID of the access group (required)
+        :type group_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -28587,9 +32397,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._process_changed_job_inputs_serialize(
+        _param = self._remove_workflow_from_group_serialize(
             id=id,
-            dry_run=dry_run,
+            group_id=group_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -28598,7 +32408,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ProcessChangedJobInputsResponse",
+            '200': "object",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -28613,10 +32425,10 @@ class DefaultApi:
 
 
     @validate_call
-    def process_changed_job_inputs_with_http_info(
+    def remove_workflow_from_group_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        dry_run: Annotated[Optional[StrictBool], Field(description="If true, report changes but do not change the database.")] = None,
+        id: Annotated[StrictInt, Field(description="ID of the workflow")],
+        group_id: Annotated[StrictInt, Field(description="ID of the access group")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -28630,15 +32442,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ProcessChangedJobInputsResponse]:
-        """Check for changed job inputs and update status accordingly.
+    ) -> ApiResponse[object]:
+        """Revoke an access group's access to a workflow.
 
-        Check for changed job inputs and update status accordingly.
+        Revoke an access group's access to a workflow.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the workflow (required)
         :type id: int
-        :param dry_run: If true, report changes but do not change the database.
-        :type dry_run: bool
+        :param group_id: ID of the access group (required)
+        :type group_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -28663,9 +32475,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._process_changed_job_inputs_serialize(
+        _param = self._remove_workflow_from_group_serialize(
             id=id,
-            dry_run=dry_run,
+            group_id=group_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -28674,7 +32486,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ProcessChangedJobInputsResponse",
+            '200': "object",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -28689,10 +32503,10 @@ class DefaultApi:
 
 
     @validate_call
-    def process_changed_job_inputs_without_preload_content(
+    def remove_workflow_from_group_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        dry_run: Annotated[Optional[StrictBool], Field(description="If true, report changes but do not change the database.")] = None,
+        id: Annotated[StrictInt, Field(description="ID of the workflow")],
+        group_id: Annotated[StrictInt, Field(description="ID of the access group")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -28707,14 +32521,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Check for changed job inputs and update status accordingly.
+        """Revoke an access group's access to a workflow.
 
-        Check for changed job inputs and update status accordingly.
+        Revoke an access group's access to a workflow.
 
-        :param id: Workflow ID (required)
+        :param id: ID of the workflow (required)
         :type id: int
-        :param dry_run: If true, report changes but do not change the database.
-        :type dry_run: bool
+        :param group_id: ID of the access group (required)
+        :type group_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -28739,9 +32553,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._process_changed_job_inputs_serialize(
+        _param = self._remove_workflow_from_group_serialize(
             id=id,
-            dry_run=dry_run,
+            group_id=group_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -28750,7 +32564,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ProcessChangedJobInputsResponse",
+            '200': "object",
+            '403': "ForbiddenErrorResponse",
+            '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -28760,10 +32576,10 @@ class DefaultApi:
         return response_data.response
 
 
-    def _process_changed_job_inputs_serialize(
+    def _remove_workflow_from_group_serialize(
         self,
         id,
-        dry_run,
+        group_id,
         body,
         _request_auth,
         _content_type,
@@ -28788,11 +32604,9 @@ class DefaultApi:
         # process the path parameters
         if id is not None:
             _path_params['id'] = id
+        if group_id is not None:
+            _path_params['group_id'] = group_id
         # process the query parameters
-        if dry_run is not None:
-            
-            _query_params.append(('dry_run', dry_run))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -28827,8 +32641,8 @@ class DefaultApi:
         ]
 
         return self.api_client.param_serialize(
-            method='POST',
-            resource_path='/workflows/{id}/process_changed_job_inputs',
+            method='DELETE',
+            resource_path='/workflows/{id}/access_groups/{group_id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -28845,11 +32659,10 @@ class DefaultApi:
 
 
     @validate_call
-    def remove_user_from_group(
+    def reserve_compute_node(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the access group")],
-        user_name: Annotated[StrictStr, Field(description="Username to remove")],
-        body: Optional[Dict[str, Any]] = None,
+        id: Annotated[StrictInt, Field(description="ID of the compute node.")],
+        job_id: Annotated[StrictInt, Field(description="ID of the job to reserve the compute node for.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -28862,17 +32675,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> object:
-        """Remove a user from an access group.
+    ) -> ComputeNodeModel:
+        """Reserve a compute node for a job.
 
-        Remove a user from an access group.
+        Reserve a compute node for a multi-node job. Only the reservation is changed. A compute node that is already reserved for another job is left unchanged; check reserved_for_job_id of the returned compute node.
 
-        :param id: ID of the access group (required)
+        :param id: ID of the compute node. (required)
         :type id: int
-        :param user_name: Username to remove (required)
-        :type user_name: str
-        :param body:
-        :type body: object
+        :param job_id: ID of the job to reserve the compute node for. (required)
+        :type job_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -28895,10 +32706,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._remove_user_from_group_serialize(
+        _param = self._reserve_compute_node_serialize(
             id=id,
-            user_name=user_name,
-            body=body,
+            job_id=job_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -28906,8 +32716,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
-            '403': "ForbiddenErrorResponse",
+            '200': "ComputeNodeModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -28923,11 +32732,10 @@ class DefaultApi:
 
 
     @validate_call
-    def remove_user_from_group_with_http_info(
+    def reserve_compute_node_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the access group")],
-        user_name: Annotated[StrictStr, Field(description="Username to remove")],
-        body: Optional[Dict[str, Any]] = None,
+        id: Annotated[StrictInt, Field(description="ID of the compute node.")],
+        job_id: Annotated[StrictInt, Field(description="ID of the job to reserve the compute node for.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -28940,17 +32748,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[object]:
-        """Remove a user from an access group.
+    ) -> ApiResponse[ComputeNodeModel]:
+        """Reserve a compute node for a job.
 
-        Remove a user from an access group.
+        Reserve a compute node for a multi-node job. Only the reservation is changed. A compute node that is already reserved for another job is left unchanged; check reserved_for_job_id of the returned compute node.
 
-        :param id: ID of the access group (required)
+        :param id: ID of the compute node. (required)
         :type id: int
-        :param user_name: Username to remove (required)
-        :type user_name: str
-        :param body:
-        :type body: object
+        :param job_id: ID of the job to reserve the compute node for. (required)
+        :type job_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -28973,10 +32779,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._remove_user_from_group_serialize(
+        _param = self._reserve_compute_node_serialize(
             id=id,
-            user_name=user_name,
-            body=body,
+            job_id=job_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -28984,8 +32789,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
-            '403': "ForbiddenErrorResponse",
+            '200': "ComputeNodeModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -29001,11 +32805,10 @@ class DefaultApi:
 
 
     @validate_call
-    def remove_user_from_group_without_preload_content(
+    def reserve_compute_node_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the access group")],
-        user_name: Annotated[StrictStr, Field(description="Username to remove")],
-        body: Optional[Dict[str, Any]] = None,
+        id: Annotated[StrictInt, Field(description="ID of the compute node.")],
+        job_id: Annotated[StrictInt, Field(description="ID of the job to reserve the compute node for.")],
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -29019,16 +32822,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Remove a user from an access group.
+        """Reserve a compute node for a job.
 
-        Remove a user from an access group.
+        Reserve a compute node for a multi-node job. Only the reservation is changed. A compute node that is already reserved for another job is left unchanged; check reserved_for_job_id of the returned compute node.
 
-        :param id: ID of the access group (required)
+        :param id: ID of the compute node. (required)
         :type id: int
-        :param user_name: Username to remove (required)
-        :type user_name: str
-        :param body:
-        :type body: object
+        :param job_id: ID of the job to reserve the compute node for. (required)
+        :type job_id: int
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -29051,10 +32852,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._remove_user_from_group_serialize(
+        _param = self._reserve_compute_node_serialize(
             id=id,
-            user_name=user_name,
-            body=body,
+            job_id=job_id,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -29062,8 +32862,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
-            '403': "ForbiddenErrorResponse",
+            '200': "ComputeNodeModel",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -29074,11 +32873,10 @@ class DefaultApi:
         return response_data.response
 
 
-    def _remove_user_from_group_serialize(
+    def _reserve_compute_node_serialize(
         self,
         id,
-        user_name,
-        body,
+        job_id,
         _request_auth,
         _content_type,
         _headers,
@@ -29102,14 +32900,12 @@ class DefaultApi:
         # process the path parameters
         if id is not None:
             _path_params['id'] = id
-        if user_name is not None:
-            _path_params['user_name'] = user_name
+        if job_id is not None:
+            _path_params['job_id'] = job_id
         # process the query parameters
         # process the header parameters
         # process the form parameters
         # process the body parameter
-        if body is not None:
-            _body_params = body
 
 
         # set the HTTP header `Accept`
@@ -29120,27 +32916,14 @@ class DefaultApi:
                 ]
             )
 
-        # set the HTTP header `Content-Type`
-        if _content_type:
-            _header_params['Content-Type'] = _content_type
-        else:
-            _default_content_type = (
-                self.api_client.select_header_content_type(
-                    [
-                        'application/json'
-                    ]
-                )
-            )
-            if _default_content_type is not None:
-                _header_params['Content-Type'] = _default_content_type
 
         # authentication setting
         _auth_settings: List[str] = [
         ]
 
         return self.api_client.param_serialize(
-            method='DELETE',
-            resource_path='/access_groups/{id}/members/{user_name}',
+            method='PUT',
+            resource_path='/compute_nodes/{id}/reservation/{job_id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -29157,10 +32940,10 @@ class DefaultApi:
 
 
     @validate_call
-    def remove_workflow_from_group(
+    def reset_job_status(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the workflow")],
-        group_id: Annotated[StrictInt, Field(description="ID of the access group")],
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        failed_only: Annotated[Optional[StrictBool], Field(description="Only reset failed jobs")] = None,
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -29174,15 +32957,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> object:
-        """Revoke an access group's access to a workflow.
+    ) -> ResetJobStatusResponse:
+        """Reset status for jobs to uninitialized.
 
-        Revoke an access group's access to a workflow.
+        Reset status for jobs to uninitialized.
 
-        :param id: ID of the workflow (required)
+        :param id: Workflow ID (required)
         :type id: int
-        :param group_id: ID of the access group (required)
-        :type group_id: int
+        :param failed_only: Only reset failed jobs
+        :type failed_only: bool
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -29207,9 +32990,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._remove_workflow_from_group_serialize(
+        _param = self._reset_job_status_serialize(
             id=id,
-            group_id=group_id,
+            failed_only=failed_only,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -29218,8 +33001,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
-            '403': "ForbiddenErrorResponse",
+            '200': "ResetJobStatusResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -29235,10 +33017,10 @@ class DefaultApi:
 
 
     @validate_call
-    def remove_workflow_from_group_with_http_info(
+    def reset_job_status_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the workflow")],
-        group_id: Annotated[StrictInt, Field(description="ID of the access group")],
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        failed_only: Annotated[Optional[StrictBool], Field(description="Only reset failed jobs")] = None,
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -29252,15 +33034,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[object]:
-        """Revoke an access group's access to a workflow.
+    ) -> ApiResponse[ResetJobStatusResponse]:
+        """Reset status for jobs to uninitialized.
 
-        Revoke an access group's access to a workflow.
+        Reset status for jobs to uninitialized.
 
-        :param id: ID of the workflow (required)
+        :param id: Workflow ID (required)
         :type id: int
-        :param group_id: ID of the access group (required)
-        :type group_id: int
+        :param failed_only: Only reset failed jobs
+        :type failed_only: bool
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -29285,9 +33067,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._remove_workflow_from_group_serialize(
+        _param = self._reset_job_status_serialize(
             id=id,
-            group_id=group_id,
+            failed_only=failed_only,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -29296,8 +33078,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
-            '403': "ForbiddenErrorResponse",
+            '200': "ResetJobStatusResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -29313,10 +33094,10 @@ class DefaultApi:
 
 
     @validate_call
-    def remove_workflow_from_group_without_preload_content(
+    def reset_job_status_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="ID of the workflow")],
-        group_id: Annotated[StrictInt, Field(description="ID of the access group")],
+        id: Annotated[StrictInt, Field(description="Workflow ID")],
+        failed_only: Annotated[Optional[StrictBool], Field(description="Only reset failed jobs")] = None,
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -29331,14 +33112,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Revoke an access group's access to a workflow.
+        """Reset status for jobs to uninitialized.
 
-        Revoke an access group's access to a workflow.
+        Reset status for jobs to uninitialized.
 
-        :param id: ID of the workflow (required)
+        :param id: Workflow ID (required)
         :type id: int
-        :param group_id: ID of the access group (required)
-        :type group_id: int
+        :param failed_only: Only reset failed jobs
+        :type failed_only: bool
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -29363,9 +33144,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._remove_workflow_from_group_serialize(
+        _param = self._reset_job_status_serialize(
             id=id,
-            group_id=group_id,
+            failed_only=failed_only,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -29374,8 +33155,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
-            '403': "ForbiddenErrorResponse",
+            '200': "ResetJobStatusResponse",
             '404': "NotFoundErrorResponse",
             '500': "DefaultErrorResponse",
         }
@@ -29386,10 +33166,10 @@ class DefaultApi:
         return response_data.response
 
 
-    def _remove_workflow_from_group_serialize(
+    def _reset_job_status_serialize(
         self,
         id,
-        group_id,
+        failed_only,
         body,
         _request_auth,
         _content_type,
@@ -29414,9 +33194,11 @@ class DefaultApi:
         # process the path parameters
         if id is not None:
             _path_params['id'] = id
-        if group_id is not None:
-            _path_params['group_id'] = group_id
         # process the query parameters
+        if failed_only is not None:
+            
+            _query_params.append(('failed_only', failed_only))
+            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -29451,8 +33233,8 @@ class DefaultApi:
         ]
 
         return self.api_client.param_serialize(
-            method='DELETE',
-            resource_path='/workflows/{id}/access_groups/{group_id}',
+            method='POST',
+            resource_path='/workflows/{id}/reset_job_status',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -29469,10 +33251,10 @@ class DefaultApi:
 
 
     @validate_call
-    def reset_job_status(
+    def reset_workflow_status(
         self,
         id: Annotated[StrictInt, Field(description="Workflow ID")],
-        failed_only: Annotated[Optional[StrictBool], Field(description="Only reset failed jobs")] = None,
+        force: Annotated[Optional[StrictBool], Field(description="If true, ignore active jobs check and reset anyway")] = None,
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -29486,15 +33268,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ResetJobStatusResponse:
-        """Reset status for jobs to uninitialized.
+    ) -> object:
+        """Reset worklow status.
 
-        Reset status for jobs to uninitialized.
+        Reset workflow status.
 
         :param id: Workflow ID (required)
         :type id: int
-        :param failed_only: Only reset failed jobs
-        :type failed_only: bool
+        :param force: If true, ignore active jobs check and reset anyway
+        :type force: bool
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -29519,9 +33301,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._reset_job_status_serialize(
+        _param = self._reset_workflow_status_serialize(
             id=id,
-            failed_only=failed_only,
+            force=force,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -29530,8 +33312,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResetJobStatusResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -29546,10 +33327,10 @@ class DefaultApi:
 
 
     @validate_call
-    def reset_job_status_with_http_info(
+    def reset_workflow_status_with_http_info(
         self,
         id: Annotated[StrictInt, Field(description="Workflow ID")],
-        failed_only: Annotated[Optional[StrictBool], Field(description="Only reset failed jobs")] = None,
+        force: Annotated[Optional[StrictBool], Field(description="If true, ignore active jobs check and reset anyway")] = None,
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -29563,15 +33344,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ResetJobStatusResponse]:
-        """Reset status for jobs to uninitialized.
+    ) -> ApiResponse[object]:
+        """Reset worklow status.
 
-        Reset status for jobs to uninitialized.
+        Reset workflow status.
 
         :param id: Workflow ID (required)
         :type id: int
-        :param failed_only: Only reset failed jobs
-        :type failed_only: bool
+        :param force: If true, ignore active jobs check and reset anyway
+        :type force: bool
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -29596,9 +33377,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._reset_job_status_serialize(
+        _param = self._reset_workflow_status_serialize(
             id=id,
-            failed_only=failed_only,
+            force=force,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -29607,8 +33388,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResetJobStatusResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -29623,10 +33403,10 @@ class DefaultApi:
 
 
     @validate_call
-    def reset_job_status_without_preload_content(
+    def reset_workflow_status_without_preload_content(
         self,
         id: Annotated[StrictInt, Field(description="Workflow ID")],
-        failed_only: Annotated[Optional[StrictBool], Field(description="Only reset failed jobs")] = None,
+        force: Annotated[Optional[StrictBool], Field(description="If true, ignore active jobs check and reset anyway")] = None,
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -29641,14 +33421,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Reset status for jobs to uninitialized.
+        """Reset worklow status.
 
-        Reset status for jobs to uninitialized.
+        Reset workflow status.
 
         :param id: Workflow ID (required)
         :type id: int
-        :param failed_only: Only reset failed jobs
-        :type failed_only: bool
+        :param force: If true, ignore active jobs check and reset anyway
+        :type force: bool
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -29673,9 +33453,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._reset_job_status_serialize(
+        _param = self._reset_workflow_status_serialize(
             id=id,
-            failed_only=failed_only,
+            force=force,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -29684,8 +33464,7 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "ResetJobStatusResponse",
-            '404': "NotFoundErrorResponse",
+            '200': "object",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -29695,10 +33474,10 @@ class DefaultApi:
         return response_data.response
 
 
-    def _reset_job_status_serialize(
+    def _reset_workflow_status_serialize(
         self,
         id,
-        failed_only,
+        force,
         body,
         _request_auth,
         _content_type,
@@ -29724,9 +33503,9 @@ class DefaultApi:
         if id is not None:
             _path_params['id'] = id
         # process the query parameters
-        if failed_only is not None:
+        if force is not None:
             
-            _query_params.append(('failed_only', failed_only))
+            _query_params.append(('force', force))
             
         # process the header parameters
         # process the form parameters
@@ -29763,7 +33542,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/workflows/{id}/reset_job_status',
+            resource_path='/workflows/{id}/reset_status',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -29780,10 +33559,10 @@ class DefaultApi:
 
 
     @validate_call
-    def reset_workflow_status(
+    def retry_job(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        force: Annotated[Optional[StrictBool], Field(description="If true, ignore active jobs check and reset anyway")] = None,
+        id: Annotated[StrictInt, Field(description="Job ID")],
+        run_id: Annotated[StrictInt, Field(description="Current workflow run ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -29797,15 +33576,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> object:
-        """Reset worklow status.
+    ) -> JobModel:
+        """Retry a failed job.
 
-        Reset workflow status.
+        Retry a failed job by resetting it to ready status and incrementing attempt_id.
 
-        :param id: Workflow ID (required)
+        :param id: Job ID (required)
         :type id: int
-        :param force: If true, ignore active jobs check and reset anyway
-        :type force: bool
+        :param run_id: Current workflow run ID (required)
+        :type run_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -29830,9 +33609,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._reset_workflow_status_serialize(
+        _param = self._retry_job_serialize(
             id=id,
-            force=force,
+            run_id=run_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -29841,7 +33620,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "JobModel",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -29856,10 +33637,10 @@ class DefaultApi:
 
 
     @validate_call
-    def reset_workflow_status_with_http_info(
+    def retry_job_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        force: Annotated[Optional[StrictBool], Field(description="If true, ignore active jobs check and reset anyway")] = None,
+        id: Annotated[StrictInt, Field(description="Job ID")],
+        run_id: Annotated[StrictInt, Field(description="Current workflow run ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -29873,15 +33654,15 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[object]:
-        """Reset worklow status.
+    ) -> ApiResponse[JobModel]:
+        """Retry a failed job.
 
-        Reset workflow status.
+        Retry a failed job by resetting it to ready status and incrementing attempt_id.
 
-        :param id: Workflow ID (required)
+        :param id: Job ID (required)
         :type id: int
-        :param force: If true, ignore active jobs check and reset anyway
-        :type force: bool
+        :param run_id: Current workflow run ID (required)
+        :type run_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -29906,9 +33687,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._reset_workflow_status_serialize(
+        _param = self._retry_job_serialize(
             id=id,
-            force=force,
+            run_id=run_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -29917,7 +33698,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "JobModel",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -29932,10 +33715,10 @@ class DefaultApi:
 
 
     @validate_call
-    def reset_workflow_status_without_preload_content(
+    def retry_job_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Workflow ID")],
-        force: Annotated[Optional[StrictBool], Field(description="If true, ignore active jobs check and reset anyway")] = None,
+        id: Annotated[StrictInt, Field(description="Job ID")],
+        run_id: Annotated[StrictInt, Field(description="Current workflow run ID")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -29950,14 +33733,14 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Reset worklow status.
+        """Retry a failed job.
 
-        Reset workflow status.
+        Retry a failed job by resetting it to ready status and incrementing attempt_id.
 
-        :param id: Workflow ID (required)
+        :param id: Job ID (required)
         :type id: int
-        :param force: If true, ignore active jobs check and reset anyway
-        :type force: bool
+        :param run_id: Current workflow run ID (required)
+        :type run_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -29982,9 +33765,9 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._reset_workflow_status_serialize(
+        _param = self._retry_job_serialize(
             id=id,
-            force=force,
+            run_id=run_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -29993,7 +33776,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "object",
+            '200': "JobModel",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -30003,10 +33788,10 @@ class DefaultApi:
         return response_data.response
 
 
-    def _reset_workflow_status_serialize(
+    def _retry_job_serialize(
         self,
         id,
-        force,
+        run_id,
         body,
         _request_auth,
         _content_type,
@@ -30031,11 +33816,9 @@ class DefaultApi:
         # process the path parameters
         if id is not None:
             _path_params['id'] = id
+        if run_id is not None:
+            _path_params['run_id'] = run_id
         # process the query parameters
-        if force is not None:
-            
-            _query_params.append(('force', force))
-            
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -30071,7 +33854,7 @@ class DefaultApi:
 
         return self.api_client.param_serialize(
             method='POST',
-            resource_path='/workflows/{id}/reset_status',
+            resource_path='/jobs/{id}/retry/{run_id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -30088,10 +33871,9 @@ class DefaultApi:
 
 
     @validate_call
-    def retry_job(
+    def revoke_api_token(
         self,
-        id: Annotated[StrictInt, Field(description="Job ID")],
-        run_id: Annotated[StrictInt, Field(description="Current workflow run ID")],
+        id: Annotated[StrictInt, Field(description="ID of the API token")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -30105,15 +33887,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> JobModel:
-        """Retry a failed job.
+    ) -> ApiTokenModel:
+        """Revoke an API token.
 
-        Retry a failed job by resetting it to ready status and incrementing attempt_id.
+        Revoke an API token.
 
-        :param id: Job ID (required)
+        :param id: ID of the API token (required)
         :type id: int
-        :param run_id: Current workflow run ID (required)
-        :type run_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -30138,9 +33918,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._retry_job_serialize(
+        _param = self._revoke_api_token_serialize(
             id=id,
-            run_id=run_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -30149,9 +33928,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "JobModel",
+            '200': "ApiTokenModel",
+            '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
-            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -30166,10 +33945,9 @@ class DefaultApi:
 
 
     @validate_call
-    def retry_job_with_http_info(
+    def revoke_api_token_with_http_info(
         self,
-        id: Annotated[StrictInt, Field(description="Job ID")],
-        run_id: Annotated[StrictInt, Field(description="Current workflow run ID")],
+        id: Annotated[StrictInt, Field(description="ID of the API token")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -30183,15 +33961,13 @@ class DefaultApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[JobModel]:
-        """Retry a failed job.
+    ) -> ApiResponse[ApiTokenModel]:
+        """Revoke an API token.
 
-        Retry a failed job by resetting it to ready status and incrementing attempt_id.
+        Revoke an API token.
 
-        :param id: Job ID (required)
+        :param id: ID of the API token (required)
         :type id: int
-        :param run_id: Current workflow run ID (required)
-        :type run_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -30216,9 +33992,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._retry_job_serialize(
+        _param = self._revoke_api_token_serialize(
             id=id,
-            run_id=run_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -30227,9 +34002,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "JobModel",
+            '200': "ApiTokenModel",
+            '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
-            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -30244,10 +34019,9 @@ class DefaultApi:
 
 
     @validate_call
-    def retry_job_without_preload_content(
+    def revoke_api_token_without_preload_content(
         self,
-        id: Annotated[StrictInt, Field(description="Job ID")],
-        run_id: Annotated[StrictInt, Field(description="Current workflow run ID")],
+        id: Annotated[StrictInt, Field(description="ID of the API token")],
         body: Optional[Dict[str, Any]] = None,
         _request_timeout: Union[
             None,
@@ -30262,14 +34036,12 @@ class DefaultApi:
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
     ) -> RESTResponseType:
-        """Retry a failed job.
+        """Revoke an API token.
 
-        Retry a failed job by resetting it to ready status and incrementing attempt_id.
+        Revoke an API token.
 
-        :param id: Job ID (required)
+        :param id: ID of the API token (required)
         :type id: int
-        :param run_id: Current workflow run ID (required)
-        :type run_id: int
         :param body:
         :type body: object
         :param _request_timeout: timeout setting for this request. If one
@@ -30294,9 +34066,8 @@ class DefaultApi:
         :return: Returns the result object.
         """ # noqa: E501
 
-        _param = self._retry_job_serialize(
+        _param = self._revoke_api_token_serialize(
             id=id,
-            run_id=run_id,
             body=body,
             _request_auth=_request_auth,
             _content_type=_content_type,
@@ -30305,9 +34076,9 @@ class DefaultApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            '200': "JobModel",
+            '200': "ApiTokenModel",
+            '403': "ForbiddenErrorResponse",
             '404': "NotFoundErrorResponse",
-            '422': "DefaultErrorResponse",
             '500': "DefaultErrorResponse",
         }
         response_data = self.api_client.call_api(
@@ -30317,10 +34088,9 @@ class DefaultApi:
         return response_data.response
 
 
-    def _retry_job_serialize(
+    def _revoke_api_token_serialize(
         self,
         id,
-        run_id,
         body,
         _request_auth,
         _content_type,
@@ -30345,8 +34115,6 @@ class DefaultApi:
         # process the path parameters
         if id is not None:
             _path_params['id'] = id
-        if run_id is not None:
-            _path_params['run_id'] = run_id
         # process the query parameters
         # process the header parameters
         # process the form parameters
@@ -30382,8 +34150,8 @@ class DefaultApi:
         ]
 
         return self.api_client.param_serialize(
-            method='POST',
-            resource_path='/jobs/{id}/retry/{run_id}',
+            method='DELETE',
+            resource_path='/api_tokens/{id}',
             path_params=_path_params,
             query_params=_query_params,
             header_params=_header_params,
@@ -31017,6 +34785,272 @@ class DefaultApi:
 
 
 
+    @validate_call
+    def update_compute_node_heartbeat(
+        self,
+        id: Annotated[StrictInt, Field(description="ID of the compute node.")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ComputeNodeModel:
+        """Record a compute node heartbeat.
+
+        Record a heartbeat from the job runner on a compute node. The response contains the compute node; is_active is false if the server has declared the compute node dead.
+
+        :param id: ID of the compute node. (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._update_compute_node_heartbeat_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ComputeNodeModel",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def update_compute_node_heartbeat_with_http_info(
+        self,
+        id: Annotated[StrictInt, Field(description="ID of the compute node.")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[ComputeNodeModel]:
+        """Record a compute node heartbeat.
+
+        Record a heartbeat from the job runner on a compute node. The response contains the compute node; is_active is false if the server has declared the compute node dead.
+
+        :param id: ID of the compute node. (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._update_compute_node_heartbeat_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ComputeNodeModel",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def update_compute_node_heartbeat_without_preload_content(
+        self,
+        id: Annotated[StrictInt, Field(description="ID of the compute node.")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """Record a compute node heartbeat.
+
+        Record a heartbeat from the job runner on a compute node. The response contains the compute node; is_active is false if the server has declared the compute node dead.
+
+        :param id: ID of the compute node. (required)
+        :type id: int
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._update_compute_node_heartbeat_serialize(
+            id=id,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "ComputeNodeModel",
+            '404': "NotFoundErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _update_compute_node_heartbeat_serialize(
+        self,
+        id,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
+        # process the query parameters
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='PUT',
+            resource_path='/compute_nodes/{id}/heartbeat',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
     @validate_call
     def update_event(
         self,
@@ -33963,6 +37997,303 @@ class DefaultApi:
 
 
 
+    @validate_call
+    def update_workflow_schedule(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow schedule ID")],
+        body: Annotated[WorkflowScheduleModel, Field(description="Updated workflow schedule")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> WorkflowScheduleModel:
+        """Update a workflow schedule.
+
+        Update a workflow schedule. Set is_paused to pause or resume it. The next run time is recomputed from the current time when the cron expression or timezone changes or when the schedule is resumed.
+
+        :param id: Workflow schedule ID (required)
+        :type id: int
+        :param body: Updated workflow schedule (required)
+        :type body: WorkflowScheduleModel
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._update_workflow_schedule_serialize(
+            id=id,
+            body=body,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "WorkflowScheduleModel",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        ).data
+
+
+    @validate_call
+    def update_workflow_schedule_with_http_info(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow schedule ID")],
+        body: Annotated[WorkflowScheduleModel, Field(description="Updated workflow schedule")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> ApiResponse[WorkflowScheduleModel]:
+        """Update a workflow schedule.
+
+        Update a workflow schedule. Set is_paused to pause or resume it. The next run time is recomputed from the current time when the cron expression or timezone changes or when the schedule is resumed.
+
+        :param id: Workflow schedule ID (required)
+        :type id: int
+        :param body: Updated workflow schedule (required)
+        :type body: WorkflowScheduleModel
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._update_workflow_schedule_serialize(
+            id=id,
+            body=body,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "WorkflowScheduleModel",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        response_data.read()
+        return self.api_client.response_deserialize(
+            response_data=response_data,
+            response_types_map=_response_types_map,
+        )
+
+
+    @validate_call
+    def update_workflow_schedule_without_preload_content(
+        self,
+        id: Annotated[StrictInt, Field(description="Workflow schedule ID")],
+        body: Annotated[WorkflowScheduleModel, Field(description="Updated workflow schedule")],
+        _request_timeout: Union[
+            None,
+            Annotated[StrictFloat, Field(gt=0)],
+            Tuple[
+                Annotated[StrictFloat, Field(gt=0)],
+                Annotated[StrictFloat, Field(gt=0)]
+            ]
+        ] = None,
+        _request_auth: Optional[Dict[StrictStr, Any]] = None,
+        _content_type: Optional[StrictStr] = None,
+        _headers: Optional[Dict[StrictStr, Any]] = None,
+        _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
+    ) -> RESTResponseType:
+        """Update a workflow schedule.
+
+        Update a workflow schedule. Set is_paused to pause or resume it. The next run time is recomputed from the current time when the cron expression or timezone changes or when the schedule is resumed.
+
+        :param id: Workflow schedule ID (required)
+        :type id: int
+        :param body: Updated workflow schedule (required)
+        :type body: WorkflowScheduleModel
+        :param _request_timeout: timeout setting for this request. If one
+                                 number provided, it will be total request
+                                 timeout. It can also be a pair (tuple) of
+                                 (connection, read) timeouts.
+        :type _request_timeout: int, tuple(int, int), optional
+        :param _request_auth: set to override the auth_settings for an a single
+                              request; this effectively ignores the
+                              authentication in the spec for a single request.
+        :type _request_auth: dict, optional
+        :param _content_type: force content-type for the request.
+        :type _content_type: str, Optional
+        :param _headers: set to override the headers for a single
+                         request; this effectively ignores the headers
+                         in the spec for a single request.
+        :type _headers: dict, optional
+        :param _host_index: set to override the host_index for a single
+                            request; this effectively ignores the host_index
+                            in the spec for a single request.
+        :type _host_index: int, optional
+        :return: Returns the result object.
+        """ # noqa: E501
+
+        _param = self._update_workflow_schedule_serialize(
+            id=id,
+            body=body,
+            _request_auth=_request_auth,
+            _content_type=_content_type,
+            _headers=_headers,
+            _host_index=_host_index
+        )
+
+        _response_types_map: Dict[str, Optional[str]] = {
+            '200': "WorkflowScheduleModel",
+            '404': "NotFoundErrorResponse",
+            '422': "DefaultErrorResponse",
+            '500': "DefaultErrorResponse",
+        }
+        response_data = self.api_client.call_api(
+            *_param,
+            _request_timeout=_request_timeout
+        )
+        return response_data.response
+
+
+    def _update_workflow_schedule_serialize(
+        self,
+        id,
+        body,
+        _request_auth,
+        _content_type,
+        _headers,
+        _host_index,
+    ) -> RequestSerialized:
+
+        _host = None
+
+        _collection_formats: Dict[str, str] = {
+        }
+
+        _path_params: Dict[str, str] = {}
+        _query_params: List[Tuple[str, str]] = []
+        _header_params: Dict[str, Optional[str]] = _headers or {}
+        _form_params: List[Tuple[str, str]] = []
+        _files: Dict[
+            str, Union[str, bytes, List[str], List[bytes], List[Tuple[str, bytes]]]
+        ] = {}
+        _body_params: Optional[bytes] = None
+
+        # process the path parameters
+        if id is not None:
+            _path_params['id'] = id
+        # process the query parameters
+        # process the header parameters
+        # process the form parameters
+        # process the body parameter
+        if body is not None:
+            _body_params = body
+
+
+        # set the HTTP header `Accept`
+        if 'Accept' not in _header_params:
+            _header_params['Accept'] = self.api_client.select_header_accept(
+                [
+                    'application/json'
+                ]
+            )
+
+        # set the HTTP header `Content-Type`
+        if _content_type:
+            _header_params['Content-Type'] = _content_type
+        else:
+            _default_content_type = (
+                self.api_client.select_header_content_type(
+                    [
+                        'application/json'
+                    ]
+                )
+            )
+            if _default_content_type is not None:
+                _header_params['Content-Type'] = _default_content_type
+
+        # authentication setting
+        _auth_settings: List[str] = [
+        ]
+
+        return self.api_client.param_serialize(
+            method='PUT',
+            resource_path='/workflow_schedules/{id}',
+            path_params=_path_params,
+            query_params=_query_params,
+            header_params=_header_params,
+            body=_body_params,
+            post_params=_form_params,
+            files=_files,
+            auth_settings=_auth_settings,
+            collection_formats=_collection_formats,
+            _host=_host,
+            _request_auth=_request_auth
+        )
+
+
+
+
     @validate_call
     def update_workflow_status(
         self,
@@ -15,6 +15,7 @@
 # import models into model package
 from torc.openapi_client.models.access_check_response import AccessCheckResponse
 from torc.openapi_client.models.access_group_model import AccessGroupModel
+from torc.openapi_client.models.api_token_model import ApiTokenModel
 from torc.openapi_client.models.claim_action200_response import ClaimAction200Response
 from torc.openapi_client.models.claim_action_request import ClaimActionRequest
 from torc.openapi_client.models.claim_jobs_based_on_resources_response import ClaimJobsBasedOnResourcesResponse
@@ -22,7 +23,9 @@ from torc.openapi_client.models.claim_next_jobs_response import ClaimNextJobsRes
 from torc.openapi_client.models.compute_node_model import ComputeNodeModel
 from torc.openapi_client.models.compute_node_schedule import ComputeNodeSchedule
 from torc.openapi_client.models.compute_nodes_resources import ComputeNodesResources
+from torc.openapi_client.models.container_runtime import ContainerRuntime
 from torc.openapi_client.models.create_jobs_response import CreateJobsResponse
+from torc.openapi_client.models.dead_worker_policy import DeadWorkerPolicy
 from torc.openapi_client.models.default_error_response import DefaultErrorResponse
 from torc.openapi_client.models.event_model import EventModel
 from torc.openapi_client.models.failure_handler_model import FailureHandlerModel
@@ -31,14 +34,18 @@ from torc.openapi_client.models.forbidden_error_response import ForbiddenErrorRe
 from torc.openapi_client.models.get_ready_job_requirements_response import GetReadyJobRequirementsResponse
 from torc.openapi_client.models.is_complete_response import IsCompleteResponse
 from torc.openapi_client.models.is_uninitialized_response import IsUninitializedResponse
+from torc.openapi_client.models.job_container_model import JobContainerModel
 from torc.openapi_client.models.job_dependency_model import JobDependencyModel
 from torc.openapi_client.models.job_file_relationship_model import JobFileRelationshipModel
+from torc.openapi_client.models.job_log_model import JobLogModel
+from torc.openapi_client.models.job_log_stream import JobLogStream
 from torc.openapi_client.models.job_model import JobModel
 from torc.openapi_client.models.job_status import JobStatus
 from torc.openapi_client.models.job_user_data_relationship_model import JobUserDataRelationshipModel
 from torc.openapi_client.models.jobs_model import JobsModel
 from torc.openapi_client.models.jobs_sort_method import JobsSortMethod
 from torc.openapi_client.models.list_access_groups_response import ListAccessGroupsResponse
+from torc.openapi_client.models.list_api_tokens_response import ListApiTokensResponse
 from torc.openapi_client.models.list_compute_nodes_response import ListComputeNodesResponse
 from torc.openapi_client.models.list_events_response import ListEventsResponse
 from torc.openapi_client.models.list_failure_handlers_response import ListFailureHandlersResponse
@@ -46,6 +53,7 @@ from torc.openapi_client.models.list_files_response import ListFilesResponse
 from torc.openapi_client.models.list_job_dependencies_response import ListJobDependenciesResponse
 from torc.openapi_client.models.list_job_file_relationships_response import ListJobFileRelationshipsResponse
 from torc.openapi_client.models.list_job_ids_response import ListJobIdsResponse
+from torc.openapi_client.models.list_job_logs_response import ListJobLogsResponse
 from torc.openapi_client.models.list_job_user_data_relationships_response import ListJobUserDataRelationshipsResponse
 from torc.openapi_client.models.list_jobs_response import ListJobsResponse
 from torc.openapi_client.models.list_local_schedulers_response import ListLocalSchedulersResponse
@@ -57,6 +65,7 @@ from torc.openapi_client.models.list_scheduled_compute_nodes_response import Lis
 from torc.openapi_client.models.list_slurm_schedulers_response import ListSlurmSchedulersResponse
 from torc.openapi_client.models.list_user_data_response import ListUserDataResponse
 from torc.openapi_client.models.list_user_group_memberships_response import ListUserGroupMembershipsResponse
+from torc.openapi_client.models.list_workflow_schedules_response import ListWorkflowSchedulesResponse
 from torc.openapi_client.models.list_workflows_response import ListWorkflowsResponse
 from torc.openapi_client.models.local_scheduler_model import LocalSchedulerModel
 from torc.openapi_client.models.not_found_error_response import NotFoundErrorResponse
@@ -65,6 +74,7 @@ from torc.openapi_client.models.remote_worker_model import RemoteWorkerModel
 from torc.openapi_client.models.reset_job_status_response import ResetJobStatusResponse
 from torc.openapi_client.models.resource_requirements_model import ResourceRequirementsModel
 from torc.openapi_client.models.result_model import ResultModel
+from torc.openapi_client.models.schedule_overlap_policy import ScheduleOverlapPolicy
 from torc.openapi_client.models.scheduled_compute_nodes_model import ScheduledComputeNodesModel
 from torc.openapi_client.models.slurm_scheduler_model import SlurmSchedulerModel
 from torc.openapi_client.models.user_data_model import UserDataModel
@@ -72,5 +82,6 @@ from torc.openapi_client.models.user_group_membership_model import UserGroupMemb
 from torc.openapi_client.models.workflow_access_group_model import WorkflowAccessGroupModel
 from torc.openapi_client.models.workflow_action_model import WorkflowActionModel
 from torc.openapi_client.models.workflow_model import WorkflowModel
+from torc.openapi_client.models.workflow_schedule_model import WorkflowScheduleModel
 from torc.openapi_client.models.workflow_status_model import WorkflowStatusModel
 
@@ -0,0 +1,101 @@
+# coding: utf-8
+
+"""
+    torc
+
+    Defines the API for the torc service.
+
+    The version of the OpenAPI document: v0.7.0
+    Generated by OpenAPI Generator (https://openapi-generator.tech)
+
+    Do not edit the class manually.
+"""  # noqa: E501
+
+
+from __future__ import annotations
+import pprint
+import re  # noqa: F401
+import json
+
+from pydantic import BaseModel, ConfigDict, Field, StrictBool, StrictInt, StrictStr
+from typing import Any, ClassVar, Dict, List, Optional
+from typing import Optional, Set
+from typing_extensions import Self
+
+class ApiTokenModel(BaseModel):
+    """
+    Server-issued API token for bearer authentication
+    """ # noqa: E501
+    id: Optional[StrictInt] = None
+    name: StrictStr
+    owner: Optional[StrictStr] = Field(default=None, description="User that the token authenticates as")
+    scopes: Optional[List[StrictStr]] = Field(default=None, description="Granted scopes. 'read' allows GET requests; 'write' allows all others.")
+    created_at: Optional[StrictStr] = None
+    expires_at: Optional[StrictStr] = Field(default=None, description="Expiration time in UTC ('YYYY-MM-DD HH:MM:SS'). Never expires if unset.")
+    revoked: Optional[StrictBool] = None
+    token: Optional[StrictStr] = Field(default=None, description="Plaintext token. Only returned when the token is created.")
+    __properties: ClassVar[List[str]] = ["id", "name", "owner", "scopes", "created_at", "expires_at", "revoked", "token"]
+
+    model_config = ConfigDict(
+        populate_by_name=True,
+        validate_assignment=True,
+        protected_namespaces=(),
+    )
+
+
+    def to_str(self) -> str:
+        """Returns the string representation of the model using alias"""
+        return pprint.pformat(self.model_dump(by_alias=True))
+
+    def to_json(self) -> str:
+        """Returns the JSON representation of the model using alias"""
+        # TODO: pydantic v2: use .model_dump_json(by_alias=True, exclude_unset=True) instead
+        return json.dumps(self.to_dict())
+
+    @classmethod
+    def from_json(cls, json_str: str) -> Optional[Self]:
+        """Create an instance of ApiTokenModel from a JSON string"""
+        return cls.from_dict(json.loads(json_str))
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the dictionary representation of the model using alias.
+
+        This has the following differences from calling pydantic's
+        `self.model_dump(by_alias=True)`:
+
+        * `None` is only added to the output dict for nullable fields that
+          were set at model initialization. Other fields with value `None`
+          are ignored.
+        """
+        excluded_fields: Set[str] = set([
+        ])
+
+        _dict = self.model_dump(
+            by_alias=True,
+            exclude=excluded_fields,
+            exclude_none=True,
+        )
+        return _dict
+
+    @classmethod
+    def from_dict(cls, obj: Optional[Dict[str, Any]]) -> Optional[Self]:
+        """Create an instance of ApiTokenModel from a dict"""
+        if obj is None:
+            return None
+
+        if not isinstance(obj, dict):
+            return cls.model_validate(obj)
+
+        _obj = cls.model_validate({
+            "id": obj.get("id"),
+            "name": obj.get("name"),
+            "owner": obj.get("owner"),
+            "scopes": obj.get("scopes"),
+            "created_at": obj.get("created_at"),
+            "expires_at": obj.get("expires_at"),
+            "revoked": obj.get("revoked"),
+            "token": obj.get("token")
+        })
+        return _obj
+
+
@@ -17,7 +17,7 @@ import pprint
 import re  # noqa: F401
 import json
 
-from pydantic import BaseModel, ConfigDict, StrictBool, StrictFloat, StrictInt, StrictStr
+from pydantic import BaseModel, ConfigDict, Field, StrictBool, StrictFloat, StrictInt, StrictStr
 from typing import Any, ClassVar, Dict, List, Optional, Union
 from typing import Optional, Set
 from typing_extensions import Self
@@ -41,7 +41,9 @@ class ComputeNodeModel(BaseModel):
     scheduler_config_id: Optional[StrictInt] = None
     compute_node_type: StrictStr
     scheduler: Optional[Dict[str, Any]] = None
-    __properties: ClassVar[List[str]] = ["id", "workflow_id", "hostname", "pid", "start_time", "duration_seconds", "is_active", "num_cpus", "memory_gb", "num_gpus", "num_nodes", "time_limit", "scheduler_config_id", "compute_node_type", "scheduler"]
+    last_heartbeat: Optional[StrictStr] = Field(default=None, description="Time of the most recent heartbeat from the compute node's job runner (RFC3339).")
+    reserved_for_job_id: Optional[StrictInt] = Field(default=None, description="ID of the multi-node job that this compute node's job runner is reserved for.")
+    __properties: ClassVar[List[str]] = ["id", "workflow_id", "hostname", "pid", "start_time", "duration_seconds", "is_active", "num_cpus", "memory_gb", "num_gpus", "num_nodes", "time_limit", "scheduler_config_id", "compute_node_type", "scheduler", "last_heartbeat", "reserved_for_job_id"]
 
     model_config = ConfigDict(
         populate_by_name=True,
@@ -108,7 +110,9 @@ class ComputeNodeModel(BaseModel):
             "time_limit": obj.get("time_limit"),
             "scheduler_config_id": obj.get("scheduler_config_id"),
             "compute_node_type": obj.get("compute_node_type"),
-            "scheduler": obj.get("scheduler")
+            "scheduler": obj.get("scheduler"),
+            "last_heartbeat": obj.get("last_heartbeat"),
+            "reserved_for_job_id": obj.get("reserved_for_job_id")
         })
         return _obj
 
@@ -17,7 +17,7 @@ import pprint
 import re  # noqa: F401
 import json
 
-from pydantic import BaseModel, ConfigDict, Field, StrictFloat, StrictInt, StrictStr
+from pydantic import BaseModel, ConfigDict, Field, StrictBool, StrictFloat, StrictInt, StrictStr
 from typing import Any, ClassVar, Dict, List, Optional, Union
 from typing import Optional, Set
 from typing_extensions import Self
@@ -31,7 +31,9 @@ class FileModel(BaseModel):
     name: StrictStr = Field(description="User-defined name of the file (not necessarily the filename)")
     path: StrictStr = Field(description="Path to the file; can be relative to the execution directory.")
     st_mtime: Optional[Union[StrictFloat, StrictInt]] = Field(default=None, description="Timestamp of when the file was last modified")
-    __properties: ClassVar[List[str]] = ["id", "workflow_id", "name", "path", "st_mtime"]
+    use_checksum: Optional[StrictBool] = Field(default=False, description="Detect changes to this file by content hash instead of modification time")
+    content_hash: Optional[StrictStr] = Field(default=None, description="Content hash of the file, recorded when use_checksum is enabled")
+    __properties: ClassVar[List[str]] = ["id", "workflow_id", "name", "path", "st_mtime", "use_checksum", "content_hash"]
 
     model_config = ConfigDict(
         populate_by_name=True,
@@ -88,7 +90,9 @@ class FileModel(BaseModel):
             "workflow_id": obj.get("workflow_id"),
             "name": obj.get("name"),
             "path": obj.get("path"),
-            "st_mtime": obj.get("st_mtime")
+            "st_mtime": obj.get("st_mtime"),
+            "use_checksum": obj.get("use_checksum") if obj.get("use_checksum") is not None else False,
+            "content_hash": obj.get("content_hash")
         })
         return _obj
 
@@ -0,0 +1,96 @@
+# coding: utf-8
+
+"""
+    torc
+
+    Defines the API for the torc service.
+
+    The version of the OpenAPI document: v0.7.0
+    Generated by OpenAPI Generator (https://openapi-generator.tech)
+
+    Do not edit the class manually.
+"""  # noqa: E501
+
+
+from __future__ import annotations
+import pprint
+import re  # noqa: F401
+import json
+
+from pydantic import BaseModel, ConfigDict, Field, StrictBool, StrictStr
+from typing import Any, ClassVar, Dict, List, Optional
+from torc.openapi_client.models.container_runtime import ContainerRuntime
+from typing import Optional, Set
+from typing_extensions import Self
+
+class JobContainerModel(BaseModel):
+    """
+    Container in which a job's command runs
+    """ # noqa: E501
+    image: StrictStr = Field(description="Container image, such as docker://python:3.12, a path to a .sif file, or python:3.12")
+    runtime: Optional[ContainerRuntime] = ContainerRuntime.APPTAINER
+    bind_mounts: Optional[List[StrictStr]] = Field(default=None, description="Host paths to mount in the container, as path or host_path:container_path[:options]")
+    gpus: Optional[StrictBool] = Field(default=None, description="Make the host's NVIDIA GPUs available in the container")
+    args: Optional[List[StrictStr]] = Field(default=None, description="Extra arguments passed to the runtime before the image")
+    __properties: ClassVar[List[str]] = ["image", "runtime", "bind_mounts", "gpus", "args"]
+
+    model_config = ConfigDict(
+        populate_by_name=True,
+        validate_assignment=True,
+        protected_namespaces=(),
+    )
+
+
+    def to_str(self) -> str:
+        """Returns the string representation of the model using alias"""
+        return pprint.pformat(self.model_dump(by_alias=True))
+
+    def to_json(self) -> str:
+        """Returns the JSON representation of the model using alias"""
+        # TODO: pydantic v2: use .model_dump_json(by_alias=True, exclude_unset=True) instead
+        return json.dumps(self.to_dict())
+
+    @classmethod
+    def from_json(cls, json_str: str) -> Optional[Self]:
+        """Create an instance of JobContainerModel from a JSON string"""
+        return cls.from_dict(json.loads(json_str))
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the dictionary representation of the model using alias.
+
+        This has the following differences from calling pydantic's
+        `self.model_dump(by_alias=True)`:
+
+        * `None` is only added to the output dict for nullable fields that
+          were set at model initialization. Other fields with value `None`
+          are ignored.
+        """
+        excluded_fields: Set[str] = set([
+        ])
+
+        _dict = self.model_dump(
+            by_alias=True,
+            exclude=excluded_fields,
+            exclude_none=True,
+        )
+        return _dict
+
+    @classmethod
+    def from_dict(cls, obj: Optional[Dict[str, Any]]) -> Optional[Self]:
+        """Create an instance of JobContainerModel from a dict"""
+        if obj is None:
+            return None
+
+        if not isinstance(obj, dict):
+            return cls.model_validate(obj)
+
+        _obj = cls.model_validate({
+            "image": obj.get("image"),
+            "runtime": obj.get("runtime") if obj.get("runtime") is not None else ContainerRuntime.APPTAINER,
+            "bind_mounts": obj.get("bind_mounts"),
+            "gpus": obj.get("gpus"),
+            "args": obj.get("args")
+        })
+        return _obj
+
+
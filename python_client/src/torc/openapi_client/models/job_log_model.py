@@ -0,0 +1,106 @@
+# coding: utf-8
+
+"""
+    torc
+
+    Defines the API for the torc service.
+
+    The version of the OpenAPI document: v0.7.0
+    Generated by OpenAPI Generator (https://openapi-generator.tech)
+
+    Do not edit the class manually.
+"""  # noqa: E501
+
+
+from __future__ import annotations
+import pprint
+import re  # noqa: F401
+import json
+
+from pydantic import BaseModel, ConfigDict, Field, StrictBool, StrictInt, StrictStr
+from typing import Any, ClassVar, Dict, List, Optional
+from torc.openapi_client.models.job_log_stream import JobLogStream
+from typing import Optional, Set
+from typing_extensions import Self
+
+class JobLogModel(BaseModel):
+    """
+    Log written by a job to stdout or stderr, uploaded by the job runner.
+    """ # noqa: E501
+    id: Optional[StrictInt] = Field(default=None, description="Database ID of this record.")
+    workflow_id: StrictInt = Field(description="Database ID of the workflow this record is associated with.")
+    job_id: StrictInt = Field(description="Database ID of the job that wrote the log")
+    run_id: StrictInt = Field(description="Workflow run in which the job wrote the log")
+    attempt_id: Optional[StrictInt] = Field(default=1, description="Attempt of the job that wrote the log")
+    stream: JobLogStream
+    content: StrictStr = Field(description="Text of the log, or its end if the log was truncated")
+    size_bytes: Optional[StrictInt] = Field(default=None, description="Size in bytes of the whole log file on the worker. Defaults to the size of content.")
+    is_truncated: Optional[StrictBool] = Field(default=False, description="True if content holds only the end of the log")
+    timestamp: Optional[StrictStr] = Field(default=None, description="Time at which the log was uploaded (RFC3339). Set by the server.")
+    __properties: ClassVar[List[str]] = ["id", "workflow_id", "job_id", "run_id", "attempt_id", "stream", "content", "size_bytes", "is_truncated", "timestamp"]
+
+    model_config = ConfigDict(
+        populate_by_name=True,
+        validate_assignment=True,
+        protected_namespaces=(),
+    )
+
+
+    def to_str(self) -> str:
+        """Returns the string representation of the model using alias"""
+        return pprint.pformat(self.model_dump(by_alias=True))
+
+    def to_json(self) -> str:
+        """Returns the JSON representation of the model using alias"""
+        # TODO: pydantic v2: use .model_dump_json(by_alias=True, exclude_unset=True) instead
+        return json.dumps(self.to_dict())
+
+    @classmethod
+    def from_json(cls, json_str: str) -> Optional[Self]:
+        """Create an instance of JobLogModel from a JSON string"""
+        return cls.from_dict(json.loads(json_str))
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the dictionary representation of the model using alias.
+
+        This has the following differences from calling pydantic's
+        `self.model_dump(by_alias=True)`:
+
+        * `None` is only added to the output dict for nullable fields that
+          were set at model initialization. Other fields with value `None`
+          are ignored.
+        """
+        excluded_fields: Set[str] = set([
+        ])
+
+        _dict = self.model_dump(
+            by_alias=True,
+            exclude=excluded_fields,
+            exclude_none=True,
+        )
+        return _dict
+
+    @classmethod
+    def from_dict(cls, obj: Optional[Dict[str, Any]]) -> Optional[Self]:
+        """Create an instance of JobLogModel from a dict"""
+        if obj is None:
+            return None
+
+        if not isinstance(obj, dict):
+            return cls.model_validate(obj)
+
+        _obj = cls.model_validate({
+            "id": obj.get("id"),
+            "workflow_id": obj.get("workflow_id"),
+            "job_id": obj.get("job_id"),
+            "run_id": obj.get("run_id"),
+            "attempt_id": obj.get("attempt_id") if obj.get("attempt_id") is not None else 1,
+            "stream": obj.get("stream"),
+            "content": obj.get("content"),
+            "size_bytes": obj.get("size_bytes"),
+            "is_truncated": obj.get("is_truncated") if obj.get("is_truncated") is not None else False,
+            "timestamp": obj.get("timestamp")
+        })
+        return _obj
+
+
@@ -0,0 +1,37 @@
+# coding: utf-8
+
+"""
+    torc
+
+    Defines the API for the torc service.
+
+    The version of the OpenAPI document: v0.7.0
+    Generated by OpenAPI Generator (https://openapi-generator.tech)
+
+    Do not edit the class manually.
+"""  # noqa: E501
+
+
+from __future__ import annotations
+import json
+from enum import Enum
+from typing_extensions import Self
+
+
+class JobLogStream(str, Enum):
+    """
+    Output stream of a job log.
+    """
+
+    """
+    allowed enum values
+    """
+    STDOUT = 'stdout'
+    STDERR = 'stderr'
+
+    @classmethod
+    def from_json(cls, json_str: str) -> Self:
+        """Create an instance of JobLogStream from a JSON string"""
+        return cls(json.loads(json_str))
+
+
@@ -19,6 +19,7 @@ import json
 
 from pydantic import BaseModel, ConfigDict, Field, StrictBool, StrictInt, StrictStr
 from typing import Any, ClassVar, Dict, List, Optional
+from torc.openapi_client.models.job_container_model import JobContainerModel
 from typing import Optional, Set
 from typing_extensions import Self
 
@@ -43,7 +44,16 @@ class JobModel(BaseModel):
     scheduler_id: Optional[StrictInt] = Field(default=None, description="Optional database ID of scheduler needed by this job")
     failure_handler_id: Optional[StrictInt] = Field(default=None, description="Optional database ID of failure handler for this job")
     attempt_id: Optional[StrictInt] = Field(default=1, description="Current retry attempt number (starts at 1)")
-    __properties: ClassVar[List[str]] = ["id", "workflow_id", "name", "command", "invocation_script", "status", "cancel_on_blocking_job_failure", "supports_termination", "depends_on_job_ids", "input_file_ids", "output_file_ids", "input_user_data_ids", "output_user_data_ids", "resource_requirements_id", "scheduler_id", "failure_handler_id", "attempt_id"]
+    priority: Optional[StrictInt] = Field(default=0, description="Claim priority. Jobs with higher values are claimed first when the priority sort method is used.")
+    env: Optional[Dict[str, StrictStr]] = Field(default=None, description="Environment variables to set for the job's process")
+    working_dir: Optional[StrictStr] = Field(default=None, description="Directory in which to run the job's command. Defaults to the job runner's working directory.")
+    shell: Optional[StrictStr] = Field(default=None, description="Program used to run the job's command, such as bash, sh, or zsh. Defaults to bash (cmd on Windows).")
+    run_if: Optional[StrictStr] = Field(default=None, description="Condition that must hold for the job to run, such as exit_code(\"prep\") == 0. If it is false, the job is skipped.")
+    concurrency_pool: Optional[StrictStr] = Field(default=None, description="Name of the workflow's concurrency pool that limits how many jobs like this one can be pending or running at the same time.")
+    container: Optional[JobContainerModel] = None
+    not_before: Optional[StrictStr] = Field(default=None, description="Time (RFC 3339) before which a retried job will not be claimed. Set by the server.")
+    excluded_hostname: Optional[StrictStr] = Field(default=None, description="Host where the previous attempt failed. Workers on this host do not claim the job. Set by the server.")
+    __properties: ClassVar[List[str]] = ["id", "workflow_id", "name", "command", "invocation_script", "status", "cancel_on_blocking_job_failure", "supports_termination", "depends_on_job_ids", "input_file_ids", "output_file_ids", "input_user_data_ids", "output_user_data_ids", "resource_requirements_id", "scheduler_id", "failure_handler_id", "attempt_id", "priority", "env", "working_dir", "shell", "run_if", "concurrency_pool", "container", "not_before", "excluded_hostname"]
 
     model_config = ConfigDict(
         populate_by_name=True,
@@ -84,6 +94,9 @@ class JobModel(BaseModel):
             exclude=excluded_fields,
             exclude_none=True,
         )
+        # override the default output from pydantic by calling `to_dict()` of container
+        if self.container:
+            _dict['container'] = self.container.to_dict()
         # set to None if status (nullable) is None
         # and model_fields_set contains the field
         if self.status is None and "status" in self.model_fields_set:
@@ -117,7 +130,16 @@ class JobModel(BaseModel):
             "resource_requirements_id": obj.get("resource_requirements_id"),
             "scheduler_id": obj.get("scheduler_id"),
             "failure_handler_id": obj.get("failure_handler_id"),
-            "attempt_id": obj.get("attempt_id") if obj.get("attempt_id") is not None else 1
+            "attempt_id": obj.get("attempt_id") if obj.get("attempt_id") is not None else 1,
+            "priority": obj.get("priority") if obj.get("priority") is not None else 0,
+            "env": obj.get("env"),
+            "working_dir": obj.get("working_dir"),
+            "shell": obj.get("shell"),
+            "run_if": obj.get("run_if"),
+            "concurrency_pool": obj.get("concurrency_pool"),
+            "container": JobContainerModel.from_dict(obj["container"]) if obj.get("container") is not None else None,
+            "not_before": obj.get("not_before"),
+            "excluded_hostname": obj.get("excluded_hostname")
         })
         return _obj
 
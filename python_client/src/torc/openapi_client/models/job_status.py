@@ -36,6 +36,7 @@ class JobStatus(str, Enum):
     CANCELED = 'canceled'
     TERMINATED = 'terminated'
     DISABLED = 'disabled'
+    SKIPPED = 'skipped'
 
     @classmethod
     def from_json(cls, json_str: str) -> Self:
@@ -28,6 +28,7 @@ class JobsSortMethod(str, Enum):
     """
     GPUS_RUNTIME_MEMORY = 'gpus_runtime_memory'
     GPUS_MEMORY_RUNTIME = 'gpus_memory_runtime'
+    PRIORITY = 'priority'
     NONE = 'none'
 
     @classmethod
@@ -0,0 +1,37 @@
+# coding: utf-8
+
+"""
+    torc
+
+    Defines the API for the torc service.
+
+    The version of the OpenAPI document: v0.7.0
+    Generated by OpenAPI Generator (https://openapi-generator.tech)
+
+    Do not edit the class manually.
+"""  # noqa: E501
+
+
+from __future__ import annotations
+import json
+from enum import Enum
+from typing_extensions import Self
+
+
+class ScheduleOverlapPolicy(str, Enum):
+    """
+    What to do when a workflow schedule fires while the previous run is still active. skip drops the firing; queue starts the run as soon as the previous run finishes.
+    """
+
+    """
+    allowed enum values
+    """
+    SKIP = 'skip'
+    QUEUE = 'queue'
+
+    @classmethod
+    def from_json(cls, json_str: str) -> Self:
+        """Create an instance of ScheduleOverlapPolicy from a JSON string"""
+        return cls(json.loads(json_str))
+
+
@@ -17,7 +17,7 @@ import pprint
 import re  # noqa: F401
 import json
 
-from pydantic import BaseModel, ConfigDict, StrictInt, StrictStr
+from pydantic import BaseModel, ConfigDict, Field, StrictInt, StrictStr
 from typing import Any, ClassVar, Dict, List, Optional
 from typing import Optional, Set
 from typing_extensions import Self
@@ -32,7 +32,8 @@ class ScheduledComputeNodesModel(BaseModel):
     scheduler_config_id: StrictInt
     scheduler_type: StrictStr
     status: StrictStr
-    __properties: ClassVar[List[str]] = ["id", "workflow_id", "scheduler_id", "scheduler_config_id", "scheduler_type", "status"]
+    array_task_id: Optional[StrictInt] = Field(default=None, description="Index of the allocation within a Slurm job array. scheduler_id holds the array's job ID.")
+    __properties: ClassVar[List[str]] = ["id", "workflow_id", "scheduler_id", "scheduler_config_id", "scheduler_type", "status", "array_task_id"]
 
     model_config = ConfigDict(
         populate_by_name=True,
@@ -90,7 +91,8 @@ class ScheduledComputeNodesModel(BaseModel):
             "scheduler_id": obj.get("scheduler_id"),
             "scheduler_config_id": obj.get("scheduler_config_id"),
             "scheduler_type": obj.get("scheduler_type"),
-            "status": obj.get("status")
+            "status": obj.get("status"),
+            "array_task_id": obj.get("array_task_id")
         })
         return _obj
 
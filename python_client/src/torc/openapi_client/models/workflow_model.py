@@ -17,8 +17,9 @@ import pprint
 import re  # noqa: F401
 import json
 
-from pydantic import BaseModel, ConfigDict, Field, StrictBool, StrictInt, StrictStr
-from typing import Any, ClassVar, Dict, List, Optional
+from pydantic import BaseModel, ConfigDict, Field, StrictBool, StrictFloat, StrictInt, StrictStr
+from typing import Any, ClassVar, Dict, List, Optional, Union
+from torc.openapi_client.models.dead_worker_policy import DeadWorkerPolicy
 from torc.openapi_client.models.jobs_sort_method import JobsSortMethod
 from typing import Optional, Set
 from typing_extensions import Self
@@ -41,8 +42,15 @@ class WorkflowModel(BaseModel):
     resource_monitor_config: Optional[StrictStr] = Field(default=None, description="Resource monitoring configuration as JSON string")
     slurm_defaults: Optional[StrictStr] = Field(default=None, description="Default Slurm parameters to apply to all schedulers as JSON string")
     use_pending_failed: Optional[StrictBool] = Field(default=False, description="Use PendingFailed status for failed jobs (enables AI-assisted recovery)")
+    enforce_runtime_limits: Optional[StrictBool] = Field(default=False, description="Terminate jobs that run longer than the runtime in their resource requirements. Jobs that support termination receive SIGTERM at the limit; all jobs receive SIGKILL after the grace period. Terminated jobs are recorded as failed with return code 124.")
+    runtime_limit_grace_seconds: Optional[StrictInt] = Field(default=60, description="Number of seconds to wait after sending SIGTERM to a job that exceeded its runtime before sending SIGKILL.")
+    worker_heartbeat_timeout_seconds: Optional[StrictInt] = Field(default=300, description="Number of seconds without a heartbeat after which the server declares a job runner dead. 0 disables dead worker detection.")
+    dead_worker_policy: Optional[DeadWorkerPolicy] = DeadWorkerPolicy.FAIL
+    max_concurrent_jobs: Optional[StrictInt] = Field(default=None, description="Maximum number of jobs in the workflow that can be pending or running at the same time, across all compute nodes. Unlimited if not set.")
+    concurrency_pools: Optional[Dict[str, StrictInt]] = Field(default=None, description="Named concurrency pools. Maps each pool name to the maximum number of jobs in the pool that can be pending or running at the same time, across all compute nodes.")
+    au_budget: Optional[Union[StrictFloat, StrictInt]] = Field(default=None, description="Maximum allocation units (AU) that HPC allocations of the workflow may cost. Clients do not submit allocations whose estimated cost would exceed it. Unlimited if not set.")
     status_id: Optional[StrictInt] = None
-    __properties: ClassVar[List[str]] = ["id", "name", "user", "description", "timestamp", "compute_node_expiration_buffer_seconds", "compute_node_wait_for_new_jobs_seconds", "compute_node_ignore_workflow_completion", "compute_node_wait_for_healthy_database_minutes", "compute_node_min_time_for_new_jobs_seconds", "jobs_sort_method", "resource_monitor_config", "slurm_defaults", "use_pending_failed", "status_id"]
+    __properties: ClassVar[List[str]] = ["id", "name", "user", "description", "timestamp", "compute_node_expiration_buffer_seconds", "compute_node_wait_for_new_jobs_seconds", "compute_node_ignore_workflow_completion", "compute_node_wait_for_healthy_database_minutes", "compute_node_min_time_for_new_jobs_seconds", "jobs_sort_method", "resource_monitor_config", "slurm_defaults", "use_pending_failed", "enforce_runtime_limits", "runtime_limit_grace_seconds", "worker_heartbeat_timeout_seconds", "dead_worker_policy", "max_concurrent_jobs", "concurrency_pools", "au_budget", "status_id"]
 
     model_config = ConfigDict(
         populate_by_name=True,
@@ -109,6 +117,13 @@ class WorkflowModel(BaseModel):
             "resource_monitor_config": obj.get("resource_monitor_config"),
             "slurm_defaults": obj.get("slurm_defaults"),
             "use_pending_failed": obj.get("use_pending_failed") if obj.get("use_pending_failed") is not None else False,
+            "enforce_runtime_limits": obj.get("enforce_runtime_limits") if obj.get("enforce_runtime_limits") is not None else False,
+            "runtime_limit_grace_seconds": obj.get("runtime_limit_grace_seconds") if obj.get("runtime_limit_grace_seconds") is not None else 60,
+            "worker_heartbeat_timeout_seconds": obj.get("worker_heartbeat_timeout_seconds") if obj.get("worker_heartbeat_timeout_seconds") is not None else 300,
+            "dead_worker_policy": obj.get("dead_worker_policy") if obj.get("dead_worker_policy") is not None else DeadWorkerPolicy.FAIL,
+            "max_concurrent_jobs": obj.get("max_concurrent_jobs"),
+            "concurrency_pools": obj.get("concurrency_pools"),
+            "au_budget": obj.get("au_budget"),
             "status_id": obj.get("status_id")
         })
         return _obj
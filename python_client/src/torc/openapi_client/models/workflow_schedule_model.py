@@ -0,0 +1,104 @@
+# coding: utf-8
+
+"""
+    torc
+
+    Defines the API for the torc service.
+
+    The version of the OpenAPI document: v0.7.0
+    Generated by OpenAPI Generator (https://openapi-generator.tech)
+
+    Do not edit the class manually.
+"""  # noqa: E501
+
+
+from __future__ import annotations
+import pprint
+import re  # noqa: F401
+import json
+
+from pydantic import BaseModel, ConfigDict, Field, StrictBool, StrictInt, StrictStr
+from typing import Any, ClassVar, Dict, List, Optional
+from torc.openapi_client.models.schedule_overlap_policy import ScheduleOverlapPolicy
+from typing import Optional, Set
+from typing_extensions import Self
+
+class WorkflowScheduleModel(BaseModel):
+    """
+    Schedule on which the server reinitializes (bumping the run_id) and submits a workflow.
+    """ # noqa: E501
+    id: Optional[StrictInt] = Field(default=None, description="Database ID of this schedule.")
+    workflow_id: StrictInt = Field(description="Database ID of the workflow this schedule is associated with.")
+    cron_expression: StrictStr = Field(description="Cron expression (minute hour day-of-month month day-of-week) with an optional leading seconds field.")
+    timezone: Optional[StrictStr] = Field(default='UTC', description="IANA time zone in which the cron expression is evaluated.")
+    overlap_policy: Optional[ScheduleOverlapPolicy] = ScheduleOverlapPolicy.SKIP
+    working_directory: Optional[StrictStr] = Field(default=None, description="Absolute path of an existing directory on the server host in which the server runs the reinitialize and submit commands.")
+    is_paused: Optional[StrictBool] = Field(default=False, description="Paused schedules do not fire.")
+    next_run_time: Optional[StrictStr] = Field(default=None, description="Time of the next firing (RFC3339). Set by the server.")
+    last_run_time: Optional[StrictStr] = Field(default=None, description="Time of the most recent firing (RFC3339). Set by the server.")
+    __properties: ClassVar[List[str]] = ["id", "workflow_id", "cron_expression", "timezone", "overlap_policy", "working_directory", "is_paused", "next_run_time", "last_run_time"]
+
+    model_config = ConfigDict(
+        populate_by_name=True,
+        validate_assignment=True,
+        protected_namespaces=(),
+    )
+
+
+    def to_str(self) -> str:
+        """Returns the string representation of the model using alias"""
+        return pprint.pformat(self.model_dump(by_alias=True))
+
+    def to_json(self) -> str:
+        """Returns the JSON representation of the model using alias"""
+        # TODO: pydantic v2: use .model_dump_json(by_alias=True, exclude_unset=True) instead
+        return json.dumps(self.to_dict())
+
+    @classmethod
+    def from_json(cls, json_str: str) -> Optional[Self]:
+        """Create an instance of WorkflowScheduleModel from a JSON string"""
+        return cls.from_dict(json.loads(json_str))
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the dictionary representation of the model using alias.
+
+        This has the following differences from calling pydantic's
+        `self.model_dump(by_alias=True)`:
+
+        * `None` is only added to the output dict for nullable fields that
+          were set at model initialization. Other fields with value `None`
+          are ignored.
+        """
+        excluded_fields: Set[str] = set([
+        ])
+
+        _dict = self.model_dump(
+            by_alias=True,
+            exclude=excluded_fields,
+            exclude_none=True,
+        )
+        return _dict
+
+    @classmethod
+    def from_dict(cls, obj: Optional[Dict[str, Any]]) -> Optional[Self]:
+        """Create an instance of WorkflowScheduleModel from a dict"""
+        if obj is None:
+            return None
+
+        if not isinstance(obj, dict):
+            return cls.model_validate(obj)
+
+        _obj = cls.model_validate({
+            "id": obj.get("id"),
+            "workflow_id": obj.get("workflow_id"),
+            "cron_expression": obj.get("cron_expression"),
+            "timezone": obj.get("timezone") if obj.get("timezone") is not None else 'UTC',
+            "overlap_policy": obj.get("overlap_policy") if obj.get("overlap_policy") is not None else ScheduleOverlapPolicy.SKIP,
+            "working_directory": obj.get("working_directory"),
+            "is_paused": obj.get("is_paused") if obj.get("is_paused") is not None else False,
+            "next_run_time": obj.get("next_run_time"),
+            "last_run_time": obj.get("last_run_time")
+        })
+        return _obj
+
+
@@ -21,7 +21,9 @@ use crate::client::commands::reports::ReportCommands;
 use crate::client::commands::resource_requirements::ResourceRequirementsCommands;
 use crate::client::commands::results::ResultCommands;
 use crate::client::commands::scheduled_compute_nodes::ScheduledComputeNodeCommands;
+use crate::client::commands::schedules::ScheduleCommands;
 use crate::client::commands::slurm::{GroupByStrategy, SlurmCommands};
+use crate::client::commands::tokens::TokenCommands;
 use crate::client::commands::user_data::UserDataCommands;
 use crate::client::commands::workflows::WorkflowCommands;
 use crate::plot_resources_cmd;
@@ -56,6 +58,7 @@ const HELP_TEMPLATE: &str = "\
   \x1b[1;36mresource-requirements\x1b[0m    Resource requirements management
   \x1b[1;36mresults\x1b[0m                  Result management commands
   \x1b[1;36mfailure-handlers\x1b[0m         Failure handler management
+  \x1b[1;36mschedules\x1b[0m                Recurring workflow run schedules
   \x1b[1;36mcompute-nodes\x1b[0m            Compute node management
   \x1b[1;36mscheduled-compute-nodes\x1b[0m  Scheduled compute node management
   \x1b[1;36mtui\x1b[0m                      Interactive terminal UI
@@ -97,6 +100,9 @@ pub struct Cli {
     /// Prompt for password securely (alternative to --password or TORC_PASSWORD)
     #[arg(long)]
     pub prompt_password: bool,
+    /// API token for bearer authentication (create one with `torc tokens create`)
+    #[arg(long, env = "TORC_API_TOKEN", hide_env_values = true)]
+    pub api_token: Option<String>,
     /// Skip checking server version compatibility
     #[arg(long)]
     pub skip_version_check: bool,
@@ -155,6 +161,13 @@ EXAMPLES:
         /// Skip validation checks (e.g., scheduler node requirements). Use with caution.
         #[arg(long, default_value = "false")]
         skip_checks: bool,
+        /// Copy job input files from, and output files back to, this host
+        /// ([user@]hostname[:port]) over SSH
+        #[arg(long)]
+        stage_from: Option<String>,
+        /// Directory on the staging host that relative file paths are resolved against
+        #[arg(long, requires = "stage_from")]
+        stage_root: Option<String>,
     },
     /// Submit a workflow to scheduler (create from spec file or submit existing workflow by ID)
     ///
@@ -707,6 +720,12 @@ EXAMPLES:
         #[command(subcommand)]
         command: FailureHandlerCommands,
     },
+    /// Recurring workflow runs started by the server on a cron schedule
+    #[command(hide = true)]
+    Schedules {
+        #[command(subcommand)]
+        command: ScheduleCommands,
+    },
 
     // =========================================================================
     // Configuration & Utilities - Setup and miscellaneous
@@ -717,6 +736,12 @@ EXAMPLES:
         #[command(subcommand)]
         command: AccessGroupCommands,
     },
+    /// Manage API tokens for bearer authentication
+    #[command(hide = true)]
+    Tokens {
+        #[command(subcommand)]
+        command: TokenCommands,
+    },
     /// Manage configuration files and settings
     #[command(hide = true)]
     Config {
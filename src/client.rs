@@ -12,6 +12,7 @@
 pub mod apis;
 pub mod async_cli_command;
 pub mod commands;
+pub mod container;
 pub mod errors;
 
 // Re-export config from the top-level module for backwards compatibility
@@ -19,8 +20,10 @@ pub mod errors;
 pub use crate::config;
 pub mod execution_plan;
 pub mod hpc;
+pub mod job_condition;
 pub mod job_runner;
 pub mod log_paths;
+pub mod multi_node;
 pub mod parameter_expansion;
 pub mod remote;
 pub mod report_models;
@@ -37,8 +40,9 @@ pub mod workflow_spec;
 pub use apis::configuration::Configuration;
 pub use apis::default_api;
 pub use hpc::{
-    HpcDetection, HpcInterface, HpcJobInfo, HpcJobStats, HpcJobStatus, HpcManager, HpcPartition,
-    HpcProfile, HpcProfileRegistry, HpcType, SlurmInterface, create_hpc_interface,
+    FakeHpcInterface, HpcDetection, HpcInterface, HpcJobInfo, HpcJobStats, HpcJobStatus,
+    HpcManager, HpcPartition, HpcProfile, HpcProfileRegistry, HpcType, PbsInterface,
+    SlurmInterface, create_hpc_interface,
 };
 pub use job_runner::JobRunner;
 // JobModel is re-exported from models (which re-exports from crate::models)
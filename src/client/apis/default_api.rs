@@ -96,6 +96,78 @@ pub enum DeleteFailureHandlerError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`create_workflow_schedule`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CreateWorkflowScheduleError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_workflow_schedule`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetWorkflowScheduleError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`list_workflow_schedules`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ListWorkflowSchedulesError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`update_workflow_schedule`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum UpdateWorkflowScheduleError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`delete_workflow_schedule`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DeleteWorkflowScheduleError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`create_job_log`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CreateJobLogError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_job_log`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetJobLogError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`list_job_logs`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ListJobLogsError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_job_log_content`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetJobLogContentError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`retry_job`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -544,6 +616,33 @@ pub enum UpdateComputeNodeError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`update_compute_node_heartbeat`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum UpdateComputeNodeHeartbeatError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`reserve_compute_node`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ReserveComputeNodeError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`release_compute_node`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ReleaseComputeNodeError {
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`update_event`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -880,6 +979,34 @@ pub enum CheckWorkflowAccessError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`create_api_token`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CreateApiTokenError {
+    Status403(models::ErrorResponse),
+    Status422(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`list_api_tokens`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ListApiTokensError {
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`revoke_api_token`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RevokeApiTokenError {
+    Status403(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    Status500(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 /// Store a compute node.
 pub fn create_compute_node(
     configuration: &configuration::Configuration,
@@ -893,7 +1020,9 @@ pub fn create_compute_node(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -949,7 +1078,9 @@ pub fn create_event(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1005,7 +1136,9 @@ pub fn create_file(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1061,7 +1194,9 @@ pub fn create_job(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1117,7 +1252,9 @@ pub fn create_jobs(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1173,7 +1310,9 @@ pub fn create_local_scheduler(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1229,7 +1368,9 @@ pub fn create_resource_requirements(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1285,7 +1426,9 @@ pub fn create_result(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1341,7 +1484,9 @@ pub fn create_scheduled_compute_node(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1397,7 +1542,9 @@ pub fn create_slurm_scheduler(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1463,7 +1610,9 @@ pub fn create_user_data(
     if let Some(ref param_value) = p_producer_job_id {
         req_builder = req_builder.query(&[("producer_job_id", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1519,7 +1668,9 @@ pub fn create_workflow(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1579,7 +1730,9 @@ pub fn cancel_workflow(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1647,7 +1800,9 @@ pub fn complete_job(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1706,7 +1861,9 @@ pub fn delete_compute_nodes(
         .request(reqwest::Method::DELETE, &uri_str);
 
     req_builder = req_builder.query(&[("workflow_id", &p_workflow_id.to_string())]);
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1765,7 +1922,9 @@ pub fn delete_events(
         .request(reqwest::Method::DELETE, &uri_str);
 
     req_builder = req_builder.query(&[("workflow_id", &p_workflow_id.to_string())]);
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1824,7 +1983,9 @@ pub fn delete_files(
         .request(reqwest::Method::DELETE, &uri_str);
 
     req_builder = req_builder.query(&[("workflow_id", &p_workflow_id.to_string())]);
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1883,7 +2044,9 @@ pub fn delete_jobs(
         .request(reqwest::Method::DELETE, &uri_str);
 
     req_builder = req_builder.query(&[("workflow_id", &p_workflow_id.to_string())]);
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -1942,7 +2105,9 @@ pub fn delete_local_schedulers(
         .request(reqwest::Method::DELETE, &uri_str);
 
     req_builder = req_builder.query(&[("workflow_id", &p_workflow_id.to_string())]);
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -2001,7 +2166,9 @@ pub fn delete_all_resource_requirements(
         .request(reqwest::Method::DELETE, &uri_str);
 
     req_builder = req_builder.query(&[("workflow_id", &p_workflow_id.to_string())]);
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -2061,7 +2228,9 @@ pub fn delete_results(
         .request(reqwest::Method::DELETE, &uri_str);
 
     req_builder = req_builder.query(&[("workflow_id", &p_workflow_id.to_string())]);
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -2120,7 +2289,9 @@ pub fn delete_scheduled_compute_nodes(
         .request(reqwest::Method::DELETE, &uri_str);
 
     req_builder = req_builder.query(&[("workflow_id", &p_workflow_id.to_string())]);
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -2179,7 +2350,9 @@ pub fn delete_slurm_schedulers(
         .request(reqwest::Method::DELETE, &uri_str);
 
     req_builder = req_builder.query(&[("workflow_id", &p_workflow_id.to_string())]);
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -2238,7 +2411,9 @@ pub fn delete_all_user_data(
         .request(reqwest::Method::DELETE, &uri_str);
 
     req_builder = req_builder.query(&[("workflow_id", &p_workflow_id.to_string())]);
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -2292,13 +2467,17 @@ pub fn get_compute_node(
     let uri_str = format!("{}/compute_nodes/{id}", configuration.base_path, id = p_id);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -2355,13 +2534,17 @@ pub fn get_dot_graph(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -2411,13 +2594,17 @@ pub fn get_event(
     let uri_str = format!("{}/events/{id}", configuration.base_path, id = p_id);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -2467,13 +2654,17 @@ pub fn get_file(
     let uri_str = format!("{}/files/{id}", configuration.base_path, id = p_id);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -2523,13 +2714,17 @@ pub fn get_job(
     let uri_str = format!("{}/jobs/{id}", configuration.base_path, id = p_id);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -2583,13 +2778,17 @@ pub fn get_local_scheduler(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -2648,13 +2847,17 @@ pub fn get_ready_job_requirements(
     if let Some(ref param_value) = p_scheduler_config_id {
         req_builder = req_builder.query(&[("scheduler_config_id", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -2708,13 +2911,17 @@ pub fn get_resource_requirements(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -2764,13 +2971,17 @@ pub fn get_result(
     let uri_str = format!("{}/results/{id}", configuration.base_path, id = p_id);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -2824,13 +3035,17 @@ pub fn get_scheduled_compute_node(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -2884,13 +3099,17 @@ pub fn get_slurm_scheduler(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -2940,13 +3159,17 @@ pub fn get_user_data(
     let uri_str = format!("{}/user_data/{id}", configuration.base_path, id = p_id);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -2992,13 +3215,17 @@ pub fn get_version(
     let uri_str = format!("{}/version", configuration.base_path);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -3048,13 +3275,17 @@ pub fn get_workflow(
     let uri_str = format!("{}/workflows/{id}", configuration.base_path, id = p_id);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -3108,13 +3339,17 @@ pub fn get_workflow_status(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -3182,7 +3417,9 @@ pub fn initialize_jobs(
     if let Some(ref param_value) = p_clear_ephemeral_user_data {
         req_builder = req_builder.query(&[("clear_ephemeral_user_data", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -3240,13 +3477,17 @@ pub fn is_workflow_complete(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -3300,13 +3541,17 @@ pub fn is_workflow_uninitialized(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -3392,13 +3637,17 @@ pub fn list_compute_nodes(
     if let Some(ref param_value) = p_scheduled_compute_node_id {
         req_builder = req_builder.query(&[("scheduled_compute_node_id", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -3479,13 +3728,17 @@ pub fn list_events(
     if let Some(ref param_value) = p_after_timestamp {
         req_builder = req_builder.query(&[("after_timestamp", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -3576,13 +3829,17 @@ pub fn list_files(
     if let Some(ref param_value) = p_is_output {
         req_builder = req_builder.query(&[("is_output", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -3636,13 +3893,17 @@ pub fn list_job_ids(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -3738,13 +3999,17 @@ pub fn list_jobs(
     if let Some(ref param_value) = p_active_compute_node_id {
         req_builder = req_builder.query(&[("active_compute_node_id", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -3807,13 +4072,17 @@ pub fn list_job_dependencies(
     if let Some(ref param_value) = p_limit {
         req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -3876,13 +4145,17 @@ pub fn list_job_file_relationships(
     if let Some(ref param_value) = p_limit {
         req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -3946,13 +4219,17 @@ pub fn list_job_user_data_relationships(
     if let Some(ref param_value) = p_limit {
         req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -4033,13 +4310,17 @@ pub fn list_local_schedulers(
     if let Some(ref param_value) = p_num_cpus {
         req_builder = req_builder.query(&[("num_cpus", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -4093,13 +4374,17 @@ pub fn list_missing_user_data(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -4153,13 +4438,17 @@ pub fn list_required_existing_files(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -4265,13 +4554,17 @@ pub fn list_resource_requirements(
     if let Some(ref param_value) = p_runtime {
         req_builder = req_builder.query(&[("runtime", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -4372,13 +4665,17 @@ pub fn list_results(
     if let Some(ref param_value) = p_compute_node_id {
         req_builder = req_builder.query(&[("compute_node_id", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -4464,13 +4761,17 @@ pub fn list_scheduled_compute_nodes(
     if let Some(ref param_value) = p_status {
         req_builder = req_builder.query(&[("status", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -4586,13 +4887,17 @@ pub fn list_slurm_schedulers(
     if let Some(ref param_value) = p_walltime {
         req_builder = req_builder.query(&[("walltime", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -4683,13 +4988,17 @@ pub fn list_user_data(
     if let Some(ref param_value) = p_is_ephemeral {
         req_builder = req_builder.query(&[("is_ephemeral", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -4777,13 +5086,17 @@ pub fn list_workflows(
     if let Some(ref param_value) = p_is_archived {
         req_builder = req_builder.query(&[("is_archived", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -4845,7 +5158,9 @@ pub fn manage_status_change(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -4901,7 +5216,9 @@ pub fn update_compute_node(
     let uri_str = format!("{}/compute_nodes/{id}", configuration.base_path, id = p_id);
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -4944,6 +5261,194 @@ pub fn update_compute_node(
     }
 }
 
+/// Record a heartbeat from a compute node. The response's `is_active` is false if the server
+/// has declared the compute node dead.
+pub fn update_compute_node_heartbeat(
+    configuration: &configuration::Configuration,
+    id: i64,
+) -> Result<models::ComputeNodeModel, Error<UpdateComputeNodeHeartbeatError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_id = id;
+
+    let uri_str = format!(
+        "{}/compute_nodes/{id}/heartbeat",
+        configuration.base_path,
+        id = p_id
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
+
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
+        req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req)?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text()?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `models::ComputeNodeModel`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `models::ComputeNodeModel`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text()?;
+        let entity: Option<UpdateComputeNodeHeartbeatError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Reserve a compute node for a job. A compute node that is already reserved for another job
+/// is left unchanged; check `reserved_for_job_id` of the returned compute node.
+pub fn reserve_compute_node(
+    configuration: &configuration::Configuration,
+    id: i64,
+    job_id: i64,
+) -> Result<models::ComputeNodeModel, Error<ReserveComputeNodeError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_id = id;
+    let p_job_id = job_id;
+
+    let uri_str = format!(
+        "{}/compute_nodes/{id}/reservation/{job_id}",
+        configuration.base_path,
+        id = p_id,
+        job_id = p_job_id
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
+
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
+        req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req)?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text()?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `models::ComputeNodeModel`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `models::ComputeNodeModel`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text()?;
+        let entity: Option<ReserveComputeNodeError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Release a compute node reserved for a job. A compute node that is reserved for another job
+/// is left unchanged.
+pub fn release_compute_node(
+    configuration: &configuration::Configuration,
+    id: i64,
+    job_id: i64,
+) -> Result<models::ComputeNodeModel, Error<ReleaseComputeNodeError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_id = id;
+    let p_job_id = job_id;
+
+    let uri_str = format!(
+        "{}/compute_nodes/{id}/reservation/{job_id}",
+        configuration.base_path,
+        id = p_id,
+        job_id = p_job_id
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::DELETE, &uri_str);
+
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
+        req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req)?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text()?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `models::ComputeNodeModel`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `models::ComputeNodeModel`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text()?;
+        let entity: Option<ReleaseComputeNodeError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
 /// Update an event.
 pub fn update_event(
     configuration: &configuration::Configuration,
@@ -4957,7 +5462,9 @@ pub fn update_event(
     let uri_str = format!("{}/events/{id}", configuration.base_path, id = p_id);
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5013,7 +5520,9 @@ pub fn update_file(
     let uri_str = format!("{}/files/{id}", configuration.base_path, id = p_id);
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5069,7 +5578,9 @@ pub fn update_job(
     let uri_str = format!("{}/jobs/{id}", configuration.base_path, id = p_id);
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5129,7 +5640,9 @@ pub fn update_local_scheduler(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5189,7 +5702,9 @@ pub fn update_resource_requirements(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5245,7 +5760,9 @@ pub fn update_result(
     let uri_str = format!("{}/results/{id}", configuration.base_path, id = p_id);
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5305,7 +5822,9 @@ pub fn update_scheduled_compute_node(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5365,7 +5884,9 @@ pub fn update_slurm_scheduler(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5421,7 +5942,9 @@ pub fn update_user_data(
     let uri_str = format!("{}/user_data/{id}", configuration.base_path, id = p_id);
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5477,7 +6000,9 @@ pub fn update_workflow(
     let uri_str = format!("{}/workflows/{id}", configuration.base_path, id = p_id);
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5537,7 +6062,9 @@ pub fn update_workflow_status(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5587,13 +6114,17 @@ pub fn ping(
     let uri_str = format!("{}/ping", configuration.base_path);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -5664,7 +6195,9 @@ pub fn claim_jobs_based_on_resources(
     if let Some(ref param_value) = p_strict_scheduler_match {
         req_builder = req_builder.query(&[("strict_scheduler_match", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5731,7 +6264,9 @@ pub fn claim_next_jobs(
     if let Some(ref param_value) = p_limit {
         req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5798,7 +6333,9 @@ pub fn process_changed_job_inputs(
     if let Some(ref param_value) = p_dry_run {
         req_builder = req_builder.query(&[("dry_run", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5856,7 +6393,9 @@ pub fn delete_compute_node(
         .client
         .request(reqwest::Method::DELETE, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5914,7 +6453,9 @@ pub fn delete_event(
         .client
         .request(reqwest::Method::DELETE, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -5972,7 +6513,9 @@ pub fn delete_file(
         .client
         .request(reqwest::Method::DELETE, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -6030,7 +6573,9 @@ pub fn delete_job(
         .client
         .request(reqwest::Method::DELETE, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -6092,7 +6637,9 @@ pub fn delete_local_scheduler(
         .client
         .request(reqwest::Method::DELETE, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -6154,7 +6701,9 @@ pub fn delete_resource_requirements(
         .client
         .request(reqwest::Method::DELETE, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -6212,7 +6761,9 @@ pub fn delete_result(
         .client
         .request(reqwest::Method::DELETE, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -6274,7 +6825,9 @@ pub fn delete_scheduled_compute_node(
         .client
         .request(reqwest::Method::DELETE, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -6336,7 +6889,9 @@ pub fn delete_slurm_scheduler(
         .client
         .request(reqwest::Method::DELETE, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -6394,7 +6949,9 @@ pub fn delete_user_data(
         .client
         .request(reqwest::Method::DELETE, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -6452,7 +7009,9 @@ pub fn delete_workflow(
         .client
         .request(reqwest::Method::DELETE, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -6519,7 +7078,9 @@ pub fn reset_job_status(
     if let Some(ref param_value) = p_failed_only {
         req_builder = req_builder.query(&[("failed_only", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -6586,7 +7147,9 @@ pub fn reset_workflow_status(
     if let Some(ref param_value) = p_force {
         req_builder = req_builder.query(&[("force", &param_value.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -6652,7 +7215,9 @@ pub fn start_job(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -6750,7 +7315,9 @@ pub fn create_workflow_action(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -6807,13 +7374,17 @@ pub fn get_workflow_actions(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -6887,13 +7458,17 @@ pub fn get_pending_actions(
 
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
 
@@ -6953,7 +7528,9 @@ pub fn claim_action(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7041,7 +7618,9 @@ pub fn create_remote_workers(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7098,7 +7677,9 @@ pub fn list_remote_workers(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7161,7 +7742,9 @@ pub fn delete_remote_worker(
         .client
         .request(reqwest::Method::DELETE, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7215,7 +7798,9 @@ pub fn create_access_group(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7268,7 +7853,9 @@ pub fn get_access_group(
     let uri_str = format!("{}/access_groups/{id}", configuration.base_path, id = p_id);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7325,7 +7912,9 @@ pub fn list_access_groups(
     if let Some(ref s) = limit {
         req_builder = req_builder.query(&[("limit", s.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7379,7 +7968,9 @@ pub fn delete_access_group(
         .client
         .request(reqwest::Method::DELETE, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7439,7 +8030,9 @@ pub fn add_user_to_group(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7503,7 +8096,9 @@ pub fn remove_user_from_group(
         .client
         .request(reqwest::Method::DELETE, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7567,7 +8162,9 @@ pub fn list_group_members(
     if let Some(ref s) = limit {
         req_builder = req_builder.query(&[("limit", s.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7633,7 +8230,9 @@ pub fn list_user_groups(
     if let Some(ref s) = limit {
         req_builder = req_builder.query(&[("limit", s.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7694,7 +8293,9 @@ pub fn add_workflow_to_group(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7755,7 +8356,9 @@ pub fn remove_workflow_from_group(
         .client
         .request(reqwest::Method::DELETE, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7819,7 +8422,9 @@ pub fn list_workflow_groups(
     if let Some(ref s) = limit {
         req_builder = req_builder.query(&[("limit", s.to_string())]);
     }
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7880,7 +8485,9 @@ pub fn check_workflow_access(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7932,7 +8539,9 @@ pub fn create_failure_handler(
         .client
         .request(reqwest::Method::POST, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -7983,7 +8592,9 @@ pub fn get_failure_handler(
     let uri_str = format!("{}/failure_handlers/{id}", configuration.base_path, id = id);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -8046,7 +8657,9 @@ pub fn list_failure_handlers(
         req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
     }
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -8088,12 +8701,526 @@ pub fn list_failure_handlers(
     }
 }
 
-/// Retry a failed job
-pub fn retry_job(
+/// Create a workflow schedule
+pub fn create_workflow_schedule(
     configuration: &configuration::Configuration,
-    id: i64,
-    run_id: i64,
-    max_retries: i32,
+    body: models::WorkflowScheduleModel,
+) -> Result<models::WorkflowScheduleModel, Error<CreateWorkflowScheduleError>> {
+    let uri_str = format!("{}/workflow_schedules", configuration.base_path);
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
+        req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    req_builder = req_builder.json(&body);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req)?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text()?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `models::WorkflowScheduleModel`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `models::WorkflowScheduleModel`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text()?;
+        let entity: Option<CreateWorkflowScheduleError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Get a workflow schedule by ID
+pub fn get_workflow_schedule(
+    configuration: &configuration::Configuration,
+    id: i64,
+) -> Result<models::WorkflowScheduleModel, Error<GetWorkflowScheduleError>> {
+    let uri_str = format!(
+        "{}/workflow_schedules/{id}",
+        configuration.base_path,
+        id = id
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
+        req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req)?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text()?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `models::WorkflowScheduleModel`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `models::WorkflowScheduleModel`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text()?;
+        let entity: Option<GetWorkflowScheduleError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// List workflow schedules, optionally for one workflow
+pub fn list_workflow_schedules(
+    configuration: &configuration::Configuration,
+    workflow_id: Option<i64>,
+    offset: Option<i64>,
+    limit: Option<i64>,
+) -> Result<models::ListWorkflowSchedulesResponse, Error<ListWorkflowSchedulesError>> {
+    let uri_str = format!("{}/workflow_schedules", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref param_value) = workflow_id {
+        req_builder = req_builder.query(&[("workflow_id", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = offset {
+        req_builder = req_builder.query(&[("offset", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = limit {
+        req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
+    }
+
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
+        req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req)?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text()?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `models::ListWorkflowSchedulesResponse`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `models::ListWorkflowSchedulesResponse`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text()?;
+        let entity: Option<ListWorkflowSchedulesError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Update a workflow schedule
+pub fn update_workflow_schedule(
+    configuration: &configuration::Configuration,
+    id: i64,
+    body: models::WorkflowScheduleModel,
+) -> Result<models::WorkflowScheduleModel, Error<UpdateWorkflowScheduleError>> {
+    let uri_str = format!(
+        "{}/workflow_schedules/{id}",
+        configuration.base_path,
+        id = id
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
+
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
+        req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    req_builder = req_builder.json(&body);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req)?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text()?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `models::WorkflowScheduleModel`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `models::WorkflowScheduleModel`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text()?;
+        let entity: Option<UpdateWorkflowScheduleError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Delete a workflow schedule
+pub fn delete_workflow_schedule(
+    configuration: &configuration::Configuration,
+    id: i64,
+    body: Option<serde_json::Value>,
+) -> Result<models::WorkflowScheduleModel, Error<DeleteWorkflowScheduleError>> {
+    let uri_str = format!(
+        "{}/workflow_schedules/{id}",
+        configuration.base_path,
+        id = id
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::DELETE, &uri_str);
+
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
+        req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    req_builder = req_builder.json(&body);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req)?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text()?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `models::WorkflowScheduleModel`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `models::WorkflowScheduleModel`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text()?;
+        let entity: Option<DeleteWorkflowScheduleError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Store the stdout or stderr log of a job attempt
+pub fn create_job_log(
+    configuration: &configuration::Configuration,
+    body: models::JobLogModel,
+) -> Result<models::JobLogModel, Error<CreateJobLogError>> {
+    let uri_str = format!("{}/job_logs", configuration.base_path);
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
+        req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    req_builder = req_builder.json(&body);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req)?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text()?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `models::JobLogModel`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `models::JobLogModel`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text()?;
+        let entity: Option<CreateJobLogError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Get a job log by ID
+pub fn get_job_log(
+    configuration: &configuration::Configuration,
+    id: i64,
+) -> Result<models::JobLogModel, Error<GetJobLogError>> {
+    let uri_str = format!("{}/job_logs/{id}", configuration.base_path, id = id);
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
+        req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req)?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text()?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `models::JobLogModel`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `models::JobLogModel`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text()?;
+        let entity: Option<GetJobLogError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// List the uploaded job logs of a workflow
+pub fn list_job_logs(
+    configuration: &configuration::Configuration,
+    workflow_id: i64,
+    job_id: Option<i64>,
+    run_id: Option<i64>,
+    attempt_id: Option<i64>,
+    stream: Option<models::JobLogStream>,
+    offset: Option<i64>,
+    limit: Option<i64>,
+) -> Result<models::ListJobLogsResponse, Error<ListJobLogsError>> {
+    let uri_str = format!("{}/job_logs", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    req_builder = req_builder.query(&[("workflow_id", &workflow_id.to_string())]);
+    if let Some(ref param_value) = job_id {
+        req_builder = req_builder.query(&[("job_id", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = run_id {
+        req_builder = req_builder.query(&[("run_id", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = attempt_id {
+        req_builder = req_builder.query(&[("attempt_id", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = stream {
+        req_builder = req_builder.query(&[("stream", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = offset {
+        req_builder = req_builder.query(&[("offset", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = limit {
+        req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
+    }
+
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
+        req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req)?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text()?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `models::ListJobLogsResponse`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `models::ListJobLogsResponse`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text()?;
+        let entity: Option<ListJobLogsError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Get the content of a job log as plain text
+pub fn get_job_log_content(
+    configuration: &configuration::Configuration,
+    id: i64,
+) -> Result<String, Error<GetJobLogContentError>> {
+    let uri_str = format!("{}/job_logs/{id}/content", configuration.base_path, id = id);
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
+        req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req)?;
+
+    let status = resp.status();
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(resp.text()?)
+    } else {
+        let content = resp.text()?;
+        let entity: Option<GetJobLogContentError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Retry a failed job
+pub fn retry_job(
+    configuration: &configuration::Configuration,
+    id: i64,
+    run_id: i64,
+    max_retries: i32,
+    delay_seconds: Option<f64>,
+    avoid_compute_node_id: Option<i64>,
 ) -> Result<models::JobModel, Error<RetryJobError>> {
     let uri_str = format!(
         "{}/jobs/{id}/retry/{run_id}",
@@ -8105,8 +9232,16 @@ pub fn retry_job(
         .client
         .request(reqwest::Method::POST, &uri_str)
         .query(&[("max_retries", max_retries)]);
+    if let Some(ref param_value) = delay_seconds {
+        req_builder = req_builder.query(&[("delay_seconds", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = avoid_compute_node_id {
+        req_builder = req_builder.query(&[("avoid_compute_node_id", &param_value.to_string())]);
+    }
 
-    if let Some(ref auth) = configuration.basic_auth {
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
         req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
     }
     if let Some(ref user_agent) = configuration.user_agent {
@@ -8147,3 +9282,175 @@ pub fn retry_job(
         }))
     }
 }
+
+/// Create an API token. The plaintext token is only returned in this response.
+pub fn create_api_token(
+    configuration: &configuration::Configuration,
+    body: models::ApiTokenModel,
+) -> Result<models::ApiTokenModel, Error<CreateApiTokenError>> {
+    let p_body = body;
+
+    let uri_str = format!("{}/api_tokens", configuration.base_path);
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
+        req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    req_builder = req_builder.json(&p_body);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req)?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text()?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `models::ApiTokenModel`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `models::ApiTokenModel`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text()?;
+        let entity: Option<CreateApiTokenError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// List API tokens. Non-administrators only see their own tokens.
+pub fn list_api_tokens(
+    configuration: &configuration::Configuration,
+    offset: Option<i64>,
+    limit: Option<i64>,
+) -> Result<models::ListApiTokensResponse, Error<ListApiTokensError>> {
+    let uri_str = format!("{}/api_tokens", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref s) = offset {
+        req_builder = req_builder.query(&[("offset", s.to_string())]);
+    }
+    if let Some(ref s) = limit {
+        req_builder = req_builder.query(&[("limit", s.to_string())]);
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
+        req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req)?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text()?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `models::ListApiTokensResponse`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `models::ListApiTokensResponse`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text()?;
+        let entity: Option<ListApiTokensError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Revoke an API token.
+pub fn revoke_api_token(
+    configuration: &configuration::Configuration,
+    id: i64,
+) -> Result<models::ApiTokenModel, Error<RevokeApiTokenError>> {
+    let p_id = id;
+
+    let uri_str = format!("{}/api_tokens/{id}", configuration.base_path, id = p_id);
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::DELETE, &uri_str);
+
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token);
+    } else if let Some(ref auth) = configuration.basic_auth {
+        req_builder = req_builder.basic_auth(&auth.0, auth.1.as_ref());
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req)?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text()?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `models::ApiTokenModel`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `models::ApiTokenModel`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text()?;
+        let entity: Option<RevokeApiTokenError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
@@ -23,22 +23,63 @@
 //!
 //! After calling `terminate()` or `cancel()`, call `wait_for_completion()` to wait
 //! for the process to exit and capture its exit code.
+//!
+//! # Runtime Limits
+//!
+//! If a runtime limit is set with `set_runtime_limit()`, `enforce_runtime_limit()` sends
+//! SIGTERM (or SIGKILL, for jobs without `supports_termination`) once the process exceeds
+//! the limit, and SIGKILL if it is still running after the grace period. The job is then
+//! recorded as failed with [`RUNTIME_LIMIT_RETURN_CODE`].
+//!
+//! # Process Settings
+//!
+//! The job's `env` variables are added to the process environment, followed by the
+//! `TORC_*` variables, which take precedence. The command runs in `working_dir` when set,
+//! using `shell` (default: `bash -c`, or `cmd /C` on Windows). If the process cannot be
+//! started with these settings, the job fails with [`START_FAILURE_RETURN_CODE`] and the
+//! reason is written to its stderr file.
+//!
+//! # Containers
+//!
+//! Jobs with a `container` run their command through the container runtime, as described in
+//! [`crate::client::container`]. The job's `env` and the `TORC_*` variables are passed into
+//! the container. Podman and Docker start the container's processes outside of the job
+//! runner's process tree, so resource monitoring starts once
+//! [`AsyncCliCommand::start_container_monitoring`] finds the container's PID, and
+//! `cancel()` also removes the container.
+//!
+//! # Multi-Node Jobs
+//!
+//! A job with a [`MultiNodeLaunch`] runs its command through the launcher, as described in
+//! [`crate::client::multi_node`]. Its hostfile is written next to its stdout and stderr files.
 
-use crate::client::log_paths::{get_job_stderr_path, get_job_stdout_path};
+use crate::client::container;
+use crate::client::log_paths::{get_job_hostfile_path, get_job_stderr_path, get_job_stdout_path};
+use crate::client::multi_node::MultiNodeLaunch;
 use crate::client::resource_monitor::ResourceMonitor;
 use crate::models::{JobModel, JobStatus, ResultModel};
 use chrono::{DateTime, Utc};
-use log::{self, debug, error, info};
+use log::{self, debug, error, info, warn};
 use std::fs::File;
 use std::io::BufWriter;
 use std::path::Path;
-use std::process::{Child, Stdio};
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
 
 #[cfg(unix)]
 use std::os::unix::process::ExitStatusExt;
 
 const JOB_STDIO_DIR: &str = "job_stdio";
 
+/// Return code recorded for jobs that were terminated for exceeding their runtime limit.
+/// This matches the exit code used by GNU `timeout`.
+pub const RUNTIME_LIMIT_RETURN_CODE: i64 = 124;
+
+/// Return code recorded for jobs whose process could not be started, for example because
+/// `working_dir` does not exist or `shell` cannot be found. This matches the exit code a
+/// shell uses for a command that cannot be found.
+pub const START_FAILURE_RETURN_CODE: i64 = 127;
+
 #[allow(dead_code)]
 pub struct AsyncCliCommand {
     pub job: JobModel,
@@ -47,6 +88,10 @@ pub struct AsyncCliCommand {
     run_id: Option<i64>,
     handle: Option<Child>,
     pid: Option<u32>,
+    /// Name of the Podman or Docker container running the job.
+    container_name: Option<String>,
+    /// PID registered with the resource monitor.
+    monitored_pid: Option<u32>,
     pub is_running: bool,
     start_time: DateTime<Utc>,
     completion_time: Option<DateTime<Utc>>,
@@ -56,6 +101,14 @@ pub struct AsyncCliCommand {
     status: JobStatus,
     stdout_fp: Option<BufWriter<File>>,
     stderr_fp: Option<BufWriter<File>>,
+    /// Monotonic start time, used for runtime limit enforcement.
+    start_instant: Instant,
+    runtime_limit: Option<Duration>,
+    /// Set when the job was signaled for exceeding its runtime limit.
+    runtime_limit_signal_time: Option<Instant>,
+    runtime_limit_sigkill_sent: bool,
+    /// Nodes reserved for a multi-node job and its launcher.
+    multi_node: Option<MultiNodeLaunch>,
 }
 
 impl AsyncCliCommand {
@@ -69,6 +122,8 @@ impl AsyncCliCommand {
             run_id: None,
             handle: None,
             pid: None,
+            container_name: None,
+            monitored_pid: None,
             is_running: false,
             start_time: Utc::now(),
             completion_time: None,
@@ -78,9 +133,24 @@ impl AsyncCliCommand {
             status,
             stdout_fp: None,
             stderr_fp: None,
+            start_instant: Instant::now(),
+            runtime_limit: None,
+            runtime_limit_signal_time: None,
+            runtime_limit_sigkill_sent: false,
+            multi_node: None,
         }
     }
 
+    /// Start the job on the nodes reserved for it instead of only on this node.
+    pub fn set_multi_node_launch(&mut self, launch: MultiNodeLaunch) {
+        self.multi_node = Some(launch);
+    }
+
+    /// Return the nodes reserved for a multi-node job.
+    pub fn multi_node_launch(&self) -> Option<&MultiNodeLaunch> {
+        self.multi_node.as_ref()
+    }
+
     pub fn start(
         &mut self,
         output_dir: &Path,
@@ -112,47 +182,207 @@ impl AsyncCliCommand {
         self.stdout_fp = Some(BufWriter::new(stdout_file));
         self.stderr_fp = Some(BufWriter::new(stderr_file));
 
-        let mut cmd = crate::client::utils::shell_command();
-
-        let command_str = if let Some(ref invocation_script) = self.job.invocation_script {
+        let mut command_str = if let Some(ref invocation_script) = self.job.invocation_script {
             format!("{} {}", invocation_script, self.job.command)
         } else {
             self.job.command.clone()
         };
-        let child = cmd
-            .arg(&command_str)
-            .env("TORC_WORKFLOW_ID", workflow_id_str)
-            .env("TORC_JOB_ID", job_id_str)
-            .env("TORC_JOB_NAME", &self.job.name)
-            .env("TORC_OUTPUT_DIR", output_dir.to_string_lossy().to_string())
-            .env("TORC_ATTEMPT_ID", attempt_id_str)
-            .env("TORC_API_URL", api_url)
+        // The job may run in another directory, so give it an absolute output directory.
+        let output_dir_env = std::path::absolute(output_dir)?;
+        let mut env: Vec<(String, String)> = self
+            .job
+            .env
+            .iter()
+            .flatten()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        env.extend([
+            ("TORC_WORKFLOW_ID".to_string(), workflow_id_str),
+            ("TORC_JOB_ID".to_string(), job_id_str),
+            ("TORC_JOB_NAME".to_string(), self.job.name.clone()),
+            (
+                "TORC_OUTPUT_DIR".to_string(),
+                output_dir_env.to_string_lossy().to_string(),
+            ),
+            ("TORC_ATTEMPT_ID".to_string(), attempt_id_str),
+            ("TORC_API_URL".to_string(), api_url.to_string()),
+        ]);
+        if let Some(ref launch) = self.multi_node {
+            // The launcher would run inside the container instead of starting it on each node.
+            if self.job.container.is_some() {
+                let message = "multi-node jobs cannot run in a container";
+                return self.fail_to_start(workflow_id, run_id, &stderr_path, message);
+            }
+            let hostfile = get_job_hostfile_path(
+                &output_dir_env,
+                workflow_id,
+                self.job_id,
+                run_id,
+                attempt_id,
+            );
+            std::fs::write(&hostfile, launch.hostfile_contents())?;
+            env.extend(launch.env(&hostfile));
+            command_str = launch.wrap_command(&command_str, &hostfile);
+        }
+        let container_name = self.job.container.as_ref().and_then(|c| {
+            container::is_engine_runtime(c.runtime).then(|| {
+                format!(
+                    "torc_{}_{}_{}_{}",
+                    workflow_id, self.job_id, run_id, attempt_id
+                )
+            })
+        });
+        let build_result = match self.job.container {
+            Some(ref spec) => self.build_container_command(
+                spec,
+                &command_str,
+                &output_dir_env,
+                &env,
+                container_name.as_deref().unwrap_or_default(),
+            ),
+            None => self.build_command(&command_str).map(|mut cmd| {
+                cmd.envs(env.iter().map(|(key, value)| (key, value)));
+                cmd
+            }),
+        };
+        let mut cmd = match build_result {
+            Ok(cmd) => cmd,
+            Err(e) => return self.fail_to_start(workflow_id, run_id, &stderr_path, &e),
+        };
+        let spawn_result = cmd
             .stdout(Stdio::from(File::create(&stdout_path)?))
             .stderr(Stdio::from(File::create(&stderr_path)?))
-            .spawn()?;
+            .spawn();
+        let child = match spawn_result {
+            Ok(child) => child,
+            Err(e) => {
+                let message = format!("failed to start job process: {}", e);
+                return self.fail_to_start(workflow_id, run_id, &stderr_path, &message);
+            }
+        };
 
         let pid = child.id();
         self.pid = Some(pid);
+        self.container_name = container_name;
         self.handle = Some(child);
         self.workflow_id = Some(workflow_id);
         self.run_id = Some(run_id);
         self.is_running = true;
         self.start_time = Utc::now();
+        self.start_instant = Instant::now();
         self.status = JobStatus::Running;
         debug!(
             "Job process started workflow_id={} job_id={} pid={}",
             workflow_id, self.job_id, pid
         );
 
-        // Start resource monitoring if enabled
-        if let Some(monitor) = resource_monitor {
+        // Start resource monitoring if enabled. Podman and Docker containers are monitored
+        // once their PID is known; see start_container_monitoring.
+        if let Some(monitor) = resource_monitor
+            && self.container_name.is_none()
+        {
             monitor.start_monitoring(pid, self.job_id, self.job.name.clone())?;
+            self.monitored_pid = Some(pid);
         }
 
         // TODO: CPU Affinity
         Ok(())
     }
 
+    /// Build the process for the command string from the job's shell, working_dir, and env.
+    fn build_command(&self, command_str: &str) -> Result<Command, String> {
+        let mut cmd = crate::client::utils::shell_command_for(self.job.shell.as_deref())?;
+        cmd.arg(command_str);
+        if let Some(ref working_dir) = self.job.working_dir {
+            if !Path::new(working_dir).is_dir() {
+                return Err(format!("working_dir '{}' is not a directory", working_dir));
+            }
+            cmd.current_dir(working_dir);
+        }
+        Ok(cmd)
+    }
+
+    /// Build the process that runs the command string in the job's container.
+    fn build_container_command(
+        &self,
+        spec: &crate::models::JobContainerModel,
+        command_str: &str,
+        output_dir: &Path,
+        env: &[(String, String)],
+        name: &str,
+    ) -> Result<Command, String> {
+        let working_dir = match self.job.working_dir {
+            Some(ref working_dir) => {
+                if !Path::new(working_dir).is_dir() {
+                    return Err(format!("working_dir '{}' is not a directory", working_dir));
+                }
+                std::path::absolute(working_dir)
+            }
+            None => std::env::current_dir(),
+        }
+        .map_err(|e| format!("failed to resolve working directory: {}", e))?;
+        container::container_command(
+            spec,
+            self.job.shell.as_deref(),
+            command_str,
+            &working_dir,
+            output_dir,
+            env,
+            name,
+        )
+    }
+
+    /// Start resource monitoring for a job running in a Podman or Docker container.
+    ///
+    /// The container's processes are not children of the job process, so monitoring starts
+    /// once the container engine reports the container's PID. Call this while the job is
+    /// running; it does nothing for other jobs or once monitoring has started.
+    pub fn start_container_monitoring(&mut self, resource_monitor: Option<&ResourceMonitor>) {
+        let (Some(monitor), Some(name), Some(spec)) = (
+            resource_monitor,
+            self.container_name.as_ref(),
+            self.job.container.as_ref(),
+        ) else {
+            return;
+        };
+        if !self.is_running || self.monitored_pid.is_some() {
+            return;
+        }
+        if let Some(pid) = container::container_pid(spec.runtime, name) {
+            match monitor.start_monitoring(pid, self.job_id, self.job.name.clone()) {
+                Ok(()) => {
+                    debug!(
+                        "Monitoring container {} for job {} (PID {})",
+                        name, self.job_id, pid
+                    );
+                    self.monitored_pid = Some(pid);
+                }
+                Err(e) => warn!("Failed to monitor container {}: {}", name, e),
+            }
+        }
+    }
+
+    /// Record a job whose process could not be started as failed, with the reason in its
+    /// stderr file. The job runner reports it like any other completed job.
+    fn fail_to_start(
+        &mut self,
+        workflow_id: i64,
+        run_id: i64,
+        stderr_path: &str,
+        message: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        error!(
+            "Job process could not be started workflow_id={} job_id={}: {}",
+            workflow_id, self.job_id, message
+        );
+        std::fs::write(stderr_path, format!("torc: {}\n", message))?;
+        self.workflow_id = Some(workflow_id);
+        self.run_id = Some(run_id);
+        self.start_time = Utc::now();
+        self.start_instant = Instant::now();
+        self.handle_completion(START_FAILURE_RETURN_CODE, JobStatus::Failed)
+    }
+
     pub fn check_status(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         if !self.is_running || self.handle.is_none() {
             return Ok(());
@@ -164,6 +394,10 @@ impl AsyncCliCommand {
                     // Process is still running
                 }
                 Some(exit_status) => {
+                    if self.exceeded_runtime_limit() {
+                        return self
+                            .handle_completion(RUNTIME_LIMIT_RETURN_CODE, JobStatus::Failed);
+                    }
                     let return_code = exit_status.code().unwrap_or(-1);
                     let status = if return_code == 0 {
                         JobStatus::Completed
@@ -200,7 +434,7 @@ impl AsyncCliCommand {
         // to the shared HashMap and returns them. Using get_metrics() won't work because
         // metrics are only transferred when StopMonitoring command is processed.
         let (peak_mem, avg_mem, peak_cpu, avg_cpu) = if let Some(monitor) = resource_monitor {
-            if let Some(pid) = self.pid {
+            if let Some(pid) = self.monitored_pid {
                 if let Some(metrics) = monitor.stop_monitoring(pid) {
                     (
                         Some(metrics.peak_memory_bytes as i64),
@@ -256,6 +490,13 @@ impl AsyncCliCommand {
     /// let exit_code = async_cmd.wait_for_completion()?;
     /// ```
     pub fn cancel(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        // Killing the runtime process does not stop a Podman or Docker container.
+        if let (Some(name), Some(spec)) = (&self.container_name, &self.job.container)
+            && self.is_running
+            && let Err(e) = container::kill_container(spec.runtime, name)
+        {
+            warn!("Failed to remove container {}: {}", name, e);
+        }
         if let Some(ref mut child) = self.handle {
             child.kill()?;
         }
@@ -347,6 +588,78 @@ impl AsyncCliCommand {
         self.send_sigterm()
     }
 
+    /// Sets the maximum time the job may run before [`enforce_runtime_limit()`] terminates it.
+    pub fn set_runtime_limit(&mut self, limit: Duration) {
+        self.runtime_limit = Some(limit);
+    }
+
+    /// Returns true if the job was signaled for exceeding its runtime limit.
+    pub fn exceeded_runtime_limit(&self) -> bool {
+        self.runtime_limit_signal_time.is_some()
+    }
+
+    /// Terminates the job if it has run longer than its runtime limit.
+    ///
+    /// Jobs with `supports_termination = true` receive SIGTERM when they exceed the limit and
+    /// SIGKILL if they are still running `grace_period` later. Other jobs receive SIGKILL
+    /// immediately. This method does not wait for the process; the next call to
+    /// [`check_status()`] records the job as failed with [`RUNTIME_LIMIT_RETURN_CODE`].
+    ///
+    /// Returns true if a signal was sent.
+    pub fn enforce_runtime_limit(
+        &mut self,
+        grace_period: Duration,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        if !self.is_running || self.handle.is_none() || self.runtime_limit_sigkill_sent {
+            return Ok(false);
+        }
+        let Some(limit) = self.runtime_limit else {
+            return Ok(false);
+        };
+
+        match self.runtime_limit_signal_time {
+            None => {
+                if self.start_instant.elapsed() <= limit {
+                    return Ok(false);
+                }
+                self.runtime_limit_signal_time = Some(Instant::now());
+                if self.job.supports_termination.unwrap_or(false) {
+                    info!(
+                        "Job exceeded runtime limit, sending SIGTERM workflow_id={} job_id={} runtime_limit_s={}",
+                        self.workflow_id.unwrap_or(0),
+                        self.job_id,
+                        limit.as_secs()
+                    );
+                    self.send_sigterm()?;
+                } else {
+                    info!(
+                        "Job exceeded runtime limit, sending SIGKILL workflow_id={} job_id={} runtime_limit_s={}",
+                        self.workflow_id.unwrap_or(0),
+                        self.job_id,
+                        limit.as_secs()
+                    );
+                    self.runtime_limit_sigkill_sent = true;
+                    self.cancel()?;
+                }
+                Ok(true)
+            }
+            Some(signal_time) => {
+                if signal_time.elapsed() < grace_period {
+                    return Ok(false);
+                }
+                info!(
+                    "Job still running after runtime limit grace period, sending SIGKILL workflow_id={} job_id={} grace_period_s={}",
+                    self.workflow_id.unwrap_or(0),
+                    self.job_id,
+                    grace_period.as_secs()
+                );
+                self.runtime_limit_sigkill_sent = true;
+                self.cancel()?;
+                Ok(true)
+            }
+        }
+    }
+
     // Force the job to completion with a return code and status. Does not send anything
     // to the process.
     // pub fn force_complete(mut self, return_code: i64, status: JobStatus) -> Result<(), Box<dyn std::error::Error>>  {
@@ -17,8 +17,10 @@ pub mod reports;
 pub mod resource_requirements;
 pub mod results;
 pub mod scheduled_compute_nodes;
+pub mod schedules;
 pub mod slurm;
 pub mod table_format;
+pub mod tokens;
 pub mod user_data;
 pub mod watch;
 pub mod workflow_export;
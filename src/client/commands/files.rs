@@ -54,6 +54,9 @@ pub enum FileCommands {
 EXAMPLES:
     # Create a file record
     torc files create 123 --name input_data --path /data/input.csv
+
+    # Detect changes by content hash instead of modification time
+    torc files create 123 --name input_data --path /data/input.csv --use-checksum
 ")]
     Create {
         /// Create the file in this workflow.
@@ -65,6 +68,9 @@ EXAMPLES:
         /// Path of the file
         #[arg(short, long, required = true)]
         path: String,
+        /// Detect changes by content hash (SHA-256) instead of modification time
+        #[arg(long)]
+        use_checksum: bool,
     },
     /// List files
     #[command(after_long_help = "\
@@ -155,6 +161,7 @@ pub fn handle_file_commands(config: &Configuration, command: &FileCommands, form
             workflow_id,
             name,
             path,
+            use_checksum,
         } => {
             let user_name = get_env_user_name();
             let wf_id = workflow_id.unwrap_or_else(|| {
@@ -164,7 +171,8 @@ pub fn handle_file_commands(config: &Configuration, command: &FileCommands, form
                 })
             });
 
-            let file = models::FileModel::new(wf_id, name.clone(), path.clone());
+            let mut file = models::FileModel::new(wf_id, name.clone(), path.clone());
+            file.use_checksum = Some(*use_checksum);
 
             match default_api::create_file(config, file) {
                 Ok(created_file) => {
@@ -10,8 +10,12 @@ use tabled::Tabled;
 use super::output::print_json;
 
 use crate::client::commands::slurm::{parse_memory_mb, parse_walltime_secs};
-use crate::client::hpc::{HpcDetection, HpcPartition, HpcProfile, HpcProfileRegistry};
-use crate::config::{ClientHpcConfig, HpcPartitionConfig, HpcProfileConfig, TorcConfig};
+use crate::client::hpc::{
+    ChargeFactors, HpcDetection, HpcPartition, HpcProfile, HpcProfileRegistry,
+};
+use crate::config::{
+    ClientHpcConfig, ConfigPaths, HpcPartitionConfig, HpcProfileConfig, TorcConfig,
+};
 
 use super::table_format::display_table_with_count;
 
@@ -22,6 +26,19 @@ pub fn create_registry_with_config_public(hpc_config: &ClientHpcConfig) -> HpcPr
     create_registry_with_config(hpc_config)
 }
 
+/// Charge factors of the named HPC profile, or of the detected one if no name is given.
+///
+/// Falls back to the default charge factors if the profile is unknown or no system is detected.
+pub fn resolve_charge_factors(profile_name: Option<&str>) -> ChargeFactors {
+    let torc_config = TorcConfig::load().unwrap_or_default();
+    let registry = create_registry_with_config(&torc_config.client.hpc);
+    let profile = match profile_name {
+        Some(name) => registry.get(name),
+        None => registry.detect(),
+    };
+    profile.map(ChargeFactors::from_profile).unwrap_or_default()
+}
+
 /// Create an HPC profile registry with built-in profiles and user-defined profiles from config
 fn create_registry_with_config(hpc_config: &ClientHpcConfig) -> HpcProfileRegistry {
     let mut registry = HpcProfileRegistry::with_builtin_profiles();
@@ -84,16 +101,16 @@ fn config_to_partition(config: &HpcPartitionConfig) -> HpcPartition {
         cpus_per_node: config.cpus_per_node,
         memory_mb: config.memory_mb,
         max_walltime_secs: config.max_walltime_secs,
-        max_nodes: None,
+        max_nodes: config.max_nodes,
         max_nodes_per_user: None,
-        min_nodes: None,
+        min_nodes: config.min_nodes,
         gpus_per_node: config.gpus_per_node,
         gpu_type: config.gpu_type.clone(),
         gpu_memory_gb: config.gpu_memory_gb,
         local_disk_gb: None,
         shared: config.shared,
         requires_explicit_request: config.requires_explicit_request,
-        default_qos: None,
+        default_qos: config.default_qos.clone(),
         features: vec![],
     }
 }
@@ -112,6 +129,9 @@ EXAMPLES:
 
     # Find matching partitions for resources
     torc hpc match kestrel --cpus 8 --memory 32g
+
+    # Discover the current Slurm cluster and save it as a profile
+    torc hpc discover
 ")]
 pub enum HpcCommands {
     /// List known HPC system profiles
@@ -232,6 +252,66 @@ EXAMPLES:
         #[arg(long)]
         skip_stdby: bool,
     },
+
+    /// Discover an HPC profile from Slurm and add it to the user config
+    ///
+    /// Queries sinfo and `scontrol show partition` for partitions, walltime limits,
+    /// memory, CPUs, GPUs (GRES), and node counts. The resulting profile includes
+    /// detection rules so that torc recognizes the cluster automatically.
+    #[command(after_long_help = "\
+EXAMPLES:
+    # Discover the current cluster and add it to ~/.config/torc/config.toml
+    torc hpc discover
+
+    # Preview the profile without writing it
+    torc hpc discover --dry-run
+
+    # Replace a previously discovered profile
+    torc hpc discover --name my_cluster --force
+
+    # Build a profile from recorded command output
+    sinfo -e -o '%P|%c|%m|%l|%G|%D' --noheader > sinfo.txt
+    scontrol show partition > partitions.txt
+    torc hpc discover --name my_cluster --cluster-name my_cluster \\
+        --sinfo-output sinfo.txt --scontrol-output partitions.txt
+")]
+    Discover {
+        /// Profile name (defaults to the Slurm cluster name or hostname)
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Display name for the profile
+        #[arg(long)]
+        display_name: Option<String>,
+
+        /// Slurm cluster name used for detection (defaults to the name reported by Slurm)
+        #[arg(long)]
+        cluster_name: Option<String>,
+
+        /// Skip standby partitions (names ending in -stdby)
+        #[arg(long)]
+        skip_stdby: bool,
+
+        /// Print the profile instead of writing it to the config file
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Replace an existing profile with the same name
+        #[arg(long)]
+        force: bool,
+
+        /// Config file to update (defaults to the user config file)
+        #[arg(long)]
+        config_file: Option<std::path::PathBuf>,
+
+        /// Read recorded sinfo output (format "%P|%c|%m|%l|%G|%D") instead of running sinfo
+        #[arg(long, requires = "scontrol_output")]
+        sinfo_output: Option<std::path::PathBuf>,
+
+        /// Read recorded `scontrol show partition` output instead of running scontrol
+        #[arg(long, requires = "sinfo_output")]
+        scontrol_output: Option<std::path::PathBuf>,
+    },
 }
 
 #[derive(Tabled)]
@@ -681,9 +761,119 @@ pub fn handle_hpc_commands(command: &HpcCommands, format: &str) {
                 std::process::exit(1);
             }
         },
+
+        HpcCommands::Discover {
+            name,
+            display_name,
+            cluster_name,
+            skip_stdby,
+            dry_run,
+            force,
+            config_file,
+            sinfo_output,
+            scontrol_output,
+        } => {
+            let info = match (sinfo_output, scontrol_output) {
+                (Some(sinfo_path), Some(scontrol_path)) => {
+                    SlurmClusterInfo::from_files(sinfo_path, scontrol_path)
+                }
+                _ => SlurmClusterInfo::query(),
+            };
+            let mut info = match info {
+                Ok(info) => info,
+                Err(e) => {
+                    eprintln!("Failed to query Slurm: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            if cluster_name.is_some() {
+                info.cluster_name = cluster_name.clone();
+            }
+
+            let name = name.clone().unwrap_or_else(|| info.default_profile_name());
+            let display = display_name
+                .clone()
+                .unwrap_or_else(|| default_display_name(&name));
+            let profile = match info.build_profile(&display, *skip_stdby) {
+                Ok(profile) => profile,
+                Err(e) => {
+                    eprintln!("Failed to discover profile: {}", e);
+                    std::process::exit(1);
+                }
+            };
+
+            if profile.detect_env_var.is_none() && profile.detect_hostname.is_none() {
+                eprintln!(
+                    "Warning: could not derive detection rules for '{}'. \
+                     Pass --cluster-name or select the profile by name.",
+                    name
+                );
+            }
+
+            if *dry_run {
+                if format == "json" {
+                    print_json(
+                        &serde_json::json!({ "name": name, "profile": profile }),
+                        "HPC profile",
+                    );
+                } else {
+                    print!("{}", profile_to_toml(&name, &profile));
+                }
+                return;
+            }
+
+            let path = match config_file.clone().or_else(|| ConfigPaths::new().user) {
+                Some(path) => path,
+                None => {
+                    eprintln!("Error: Could not determine user config directory");
+                    std::process::exit(1);
+                }
+            };
+
+            match write_profile_to_config(&path, &name, &profile, *force) {
+                Ok(replaced) => {
+                    if format == "json" {
+                        print_json(
+                            &serde_json::json!({
+                                "name": name,
+                                "config_file": path.display().to_string(),
+                                "replaced": replaced,
+                                "partition_count": profile.partitions.len(),
+                                "profile": profile,
+                            }),
+                            "HPC profile",
+                        );
+                    } else {
+                        println!(
+                            "{} profile '{}' with {} partitions in {}",
+                            if replaced { "Replaced" } else { "Added" },
+                            name,
+                            profile.partitions.len(),
+                            path.display()
+                        );
+                        if let Some(ref env_var) = profile.detect_env_var {
+                            println!("  Detection: environment variable {}", env_var);
+                        }
+                        if let Some(ref pattern) = profile.detect_hostname {
+                            println!("  Detection: hostname matches {}", pattern);
+                        }
+                        println!();
+                        println!("Review it with: torc hpc show {}", name);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Failed to write profile: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
     }
 }
 
+/// sinfo output format used for profile discovery
+/// %P = partition, %c = cpus, %m = memory, %l = timelimit, %G = gres, %D = nodes
+pub const SINFO_FORMAT: &str = "%P|%c|%m|%l|%G|%D";
+
 /// Information about a partition gathered from sinfo
 #[derive(Debug)]
 pub struct SinfoPartition {
@@ -692,163 +882,283 @@ pub struct SinfoPartition {
     pub memory_mb: u64,
     pub timelimit_secs: u64,
     pub gres: Option<String>,
+    /// Number of nodes with this configuration
+    pub nodes: u32,
 }
 
 /// Additional partition info from scontrol
 #[derive(Debug, Default)]
-struct ScontrolPartitionInfo {
-    min_nodes: Option<u32>,
-    max_nodes: Option<u32>,
-    oversubscribe: Option<String>,
-    default_qos: Option<String>,
+pub struct ScontrolPartitionInfo {
+    pub min_nodes: Option<u32>,
+    pub max_nodes: Option<u32>,
+    pub total_nodes: Option<u32>,
+    pub oversubscribe: Option<String>,
+    pub default_qos: Option<String>,
 }
 
-/// Generate an HPC profile from the current Slurm cluster
-fn generate_profile_from_slurm(
-    name: Option<String>,
-    display_name: Option<String>,
-    skip_stdby: bool,
-) -> Result<String, String> {
-    // Get cluster name
-    let cluster_name = name.unwrap_or_else(|| {
-        std::env::var("SLURM_CLUSTER_NAME")
-            .or_else(|_| {
-                // Try to get from scontrol
-                Command::new("scontrol")
-                    .args(["show", "config"])
-                    .output()
-                    .ok()
-                    .and_then(|out| {
-                        String::from_utf8(out.stdout).ok().and_then(|s| {
-                            s.lines()
-                                .find(|l| l.starts_with("ClusterName"))
-                                .and_then(|l| l.split('=').nth(1))
-                                .map(|s| s.trim().to_string())
-                        })
-                    })
-                    .ok_or(())
-            })
-            .unwrap_or_else(|_| {
-                // Fall back to hostname
-                hostname::get()
-                    .map(|h| h.to_string_lossy().to_string())
-                    .unwrap_or_else(|_| "unknown".to_string())
-            })
-    });
-
-    let display = display_name.unwrap_or_else(|| {
-        // Capitalize first letter
-        let mut chars = cluster_name.chars();
-        match chars.next() {
-            None => cluster_name.clone(),
-            Some(c) => c.to_uppercase().chain(chars).collect(),
-        }
-    });
+/// Slurm command output used to build an HPC profile
+///
+/// Profiles are built from the raw text of `sinfo` and `scontrol show partition`
+/// so that recorded output can be used in place of a live cluster.
+#[derive(Debug, Default)]
+pub struct SlurmClusterInfo {
+    /// Slurm cluster name (ClusterName), if known
+    pub cluster_name: Option<String>,
+    /// Hostname of the machine the output was captured on
+    pub hostname: Option<String>,
+    /// Output of `sinfo -e -o "%P|%c|%m|%l|%G|%D" --noheader`
+    pub sinfo_output: String,
+    /// Output of `scontrol show partition`
+    pub scontrol_output: String,
+}
 
-    // Get partition info from sinfo
-    let sinfo_partitions = parse_sinfo_output()?;
+impl SlurmClusterInfo {
+    /// Query the current Slurm cluster
+    pub fn query() -> Result<Self, String> {
+        Ok(Self {
+            cluster_name: slurm_cluster_name(),
+            hostname: hostname::get()
+                .ok()
+                .map(|h| h.to_string_lossy().to_string()),
+            sinfo_output: run_slurm_command("sinfo", &["-e", "-o", SINFO_FORMAT, "--noheader"])?,
+            scontrol_output: run_slurm_command("scontrol", &["show", "partition"])?,
+        })
+    }
 
-    if sinfo_partitions.is_empty() {
-        return Err("No partitions found. Is Slurm available on this system?".to_string());
+    /// Load recorded `sinfo` and `scontrol show partition` output from files
+    pub fn from_files(
+        sinfo_path: &std::path::Path,
+        scontrol_path: &std::path::Path,
+    ) -> Result<Self, String> {
+        let read = |path: &std::path::Path| {
+            std::fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+        };
+        Ok(Self {
+            cluster_name: None,
+            hostname: None,
+            sinfo_output: read(sinfo_path)?,
+            scontrol_output: read(scontrol_path)?,
+        })
     }
 
-    // Group partitions by name (Slurm reports each node type separately)
-    let mut partition_map: HashMap<String, Vec<&SinfoPartition>> = HashMap::new();
-    for sp in &sinfo_partitions {
-        partition_map.entry(sp.name.clone()).or_default().push(sp);
+    /// Profile name to use when none is given (cluster name, then short hostname)
+    pub fn default_profile_name(&self) -> String {
+        self.cluster_name
+            .clone()
+            .or_else(|| {
+                self.hostname
+                    .as_ref()
+                    .and_then(|h| h.split('.').next())
+                    .filter(|h| !h.is_empty())
+                    .map(|h| h.to_string())
+            })
+            .unwrap_or_else(|| "unknown".to_string())
     }
 
-    // Deduplicate and merge partition info
-    let mut partitions = Vec::new();
-    let mut seen_names: Vec<String> = partition_map.keys().cloned().collect();
-    seen_names.sort(); // Consistent ordering
+    /// Build a complete profile configuration, including detection rules
+    pub fn build_profile(
+        &self,
+        display_name: &str,
+        skip_stdby: bool,
+    ) -> Result<HpcProfileConfig, String> {
+        let sinfo_partitions = parse_sinfo_string(&self.sinfo_output)?;
 
-    for name in seen_names {
-        // Skip standby partitions if requested
-        if skip_stdby && name.ends_with("-stdby") {
-            continue;
+        if sinfo_partitions.is_empty() {
+            return Err("No partitions found. Is Slurm available on this system?".to_string());
         }
-        let group = partition_map.get(&name).unwrap();
-
-        // Get scontrol info (same for all nodes in partition)
-        let scontrol_info = parse_scontrol_partition(&name).unwrap_or_default();
-
-        // Merge partition info from all node types:
-        // - CPUs: use minimum (guaranteed on all nodes)
-        // - Memory: use minimum (guaranteed on all nodes)
-        // - Walltime: should be same, use max to be safe
-        // - GPUs: if any node has GPUs, capture that info
-        let mut min_cpus = u32::MAX;
-        let mut min_memory = u64::MAX;
-        let mut max_walltime = 0u64;
-        let mut gpus_per_node: Option<u32> = None;
-        let mut gpu_type: Option<String> = None;
-
-        for sp in group {
-            min_cpus = min_cpus.min(sp.cpus);
-            min_memory = min_memory.min(sp.memory_mb);
-            max_walltime = max_walltime.max(sp.timelimit_secs);
-
-            // Capture GPU info if present
-            let (gp, gt) = parse_gres(&sp.gres);
-            if gp.is_some() {
-                gpus_per_node = gp;
-                gpu_type = gt;
-            }
+
+        let scontrol_partitions = parse_scontrol_partitions_string(&self.scontrol_output);
+        let no_scontrol_info = ScontrolPartitionInfo::default();
+
+        // Group partitions by name (Slurm reports each node type separately)
+        let mut partition_map: HashMap<String, Vec<&SinfoPartition>> = HashMap::new();
+        for sp in &sinfo_partitions {
+            partition_map.entry(sp.name.clone()).or_default().push(sp);
         }
 
-        // Fallback: infer GPU info from partition name if GRES wasn't reported
-        if gpus_per_node.is_none()
-            && let Some((inferred_count, inferred_type)) = infer_gpu_from_name(&name)
-        {
-            gpus_per_node = Some(inferred_count);
-            gpu_type = Some(inferred_type);
+        // Deduplicate and merge partition info
+        let mut partitions = Vec::new();
+        let mut seen_names: Vec<String> = partition_map.keys().cloned().collect();
+        seen_names.sort(); // Consistent ordering
+
+        for name in seen_names {
+            // Skip standby partitions if requested
+            if skip_stdby && name.ends_with("-stdby") {
+                continue;
+            }
+            let group = partition_map.get(&name).unwrap();
+            let scontrol_info = scontrol_partitions.get(&name).unwrap_or(&no_scontrol_info);
+
+            // Merge partition info from all node types:
+            // - CPUs: use minimum (guaranteed on all nodes)
+            // - Memory: use minimum (guaranteed on all nodes)
+            // - Walltime: should be same, use max to be safe
+            // - GPUs: if any node has GPUs, capture that info
+            // - Nodes: sum across node types
+            let mut min_cpus = u32::MAX;
+            let mut min_memory = u64::MAX;
+            let mut max_walltime = 0u64;
+            let mut gpus_per_node: Option<u32> = None;
+            let mut gpu_type: Option<String> = None;
+            let mut node_count = 0u32;
+
+            for sp in group {
+                min_cpus = min_cpus.min(sp.cpus);
+                min_memory = min_memory.min(sp.memory_mb);
+                max_walltime = max_walltime.max(sp.timelimit_secs);
+                node_count += sp.nodes;
+
+                // Capture GPU info if present
+                let (gp, gt) = parse_gres(&sp.gres);
+                if gp.is_some() {
+                    gpus_per_node = gp;
+                    gpu_type = gt;
+                }
+            }
+
+            // Fallback: infer GPU info from partition name if GRES wasn't reported
+            if gpus_per_node.is_none()
+                && let Some((inferred_count, inferred_type)) = infer_gpu_from_name(&name)
+            {
+                gpus_per_node = Some(inferred_count);
+                gpu_type = Some(inferred_type);
+            }
+
+            // Determine if shared based on OverSubscribe setting or partition name
+            let shared = scontrol_info.oversubscribe.as_ref().is_some_and(|o| {
+                o.to_lowercase().contains("yes") || o.to_lowercase().contains("force")
+            }) || name.to_lowercase().contains("shared")
+                || gpus_per_node.is_some(); // GPU partitions are typically shared
+
+            // A job can use at most the per-job limit or every node in the partition
+            let total_nodes = scontrol_info
+                .total_nodes
+                .filter(|n| *n > 0)
+                .or(Some(node_count).filter(|n| *n > 0));
+            let max_nodes = match (scontrol_info.max_nodes, total_nodes) {
+                (Some(limit), Some(total)) => Some(limit.min(total)),
+                (limit, total) => limit.or(total),
+            };
+
+            partitions.push(HpcPartitionConfig {
+                name,
+                description: String::new(),
+                cpus_per_node: min_cpus,
+                memory_mb: min_memory,
+                max_walltime_secs: max_walltime,
+                max_nodes,
+                min_nodes: scontrol_info.min_nodes.filter(|n| *n > 1),
+                gpus_per_node,
+                gpu_type,
+                gpu_memory_gb: None,
+                shared,
+                requires_explicit_request: false,
+                default_qos: scontrol_info.default_qos.clone(),
+            });
         }
 
-        // Determine if shared based on OverSubscribe setting or partition name
-        let shared = scontrol_info.oversubscribe.as_ref().is_some_and(|o| {
-            o.to_lowercase().contains("yes") || o.to_lowercase().contains("force")
-        }) || name.to_lowercase().contains("shared")
-            || gpus_per_node.is_some(); // GPU partitions are typically shared
+        if partitions.is_empty() {
+            return Err("All partitions were skipped".to_string());
+        }
 
-        let partition = HpcPartitionConfig {
-            name,
-            description: String::new(),
-            cpus_per_node: min_cpus,
-            memory_mb: min_memory,
-            max_walltime_secs: max_walltime,
-            gpus_per_node,
-            gpu_type,
-            gpu_memory_gb: None,
-            shared,
-            requires_explicit_request: false,
+        let description = match &self.cluster_name {
+            Some(cluster) => format!("Generated from Slurm cluster '{}'", cluster),
+            None => "Generated from Slurm partition information".to_string(),
         };
 
-        partitions.push(partition);
+        Ok(HpcProfileConfig {
+            display_name: display_name.to_string(),
+            description,
+            detect_env_var: self
+                .cluster_name
+                .as_ref()
+                .map(|c| format!("SLURM_CLUSTER_NAME={}", c)),
+            detect_hostname: self
+                .hostname
+                .as_deref()
+                .and_then(hostname_detection_pattern),
+            default_account: None,
+            // Same charge factors as a hand-written profile that omits them
+            charge_factor_cpu: 1.0,
+            charge_factor_gpu: 10.0,
+            partitions,
+        })
+    }
+}
+
+/// Default display name for a profile: the name with its first letter capitalized
+fn default_display_name(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        None => name.to_string(),
+        Some(c) => c.to_uppercase().chain(chars).collect(),
     }
+}
 
-    // Generate TOML output
-    generate_toml_profile(&cluster_name, &display, &partitions)
+/// Get the Slurm cluster name from the environment or `scontrol show config`
+fn slurm_cluster_name() -> Option<String> {
+    std::env::var("SLURM_CLUSTER_NAME")
+        .ok()
+        .filter(|s| !s.is_empty())
+        .or_else(|| {
+            Command::new("scontrol")
+                .args(["show", "config"])
+                .output()
+                .ok()
+                .and_then(|out| {
+                    String::from_utf8(out.stdout).ok().and_then(|s| {
+                        s.lines()
+                            .find(|l| l.starts_with("ClusterName"))
+                            .and_then(|l| l.split('=').nth(1))
+                            .map(|s| s.trim().to_string())
+                    })
+                })
+                .filter(|s| !s.is_empty())
+        })
 }
 
-/// Parse output from sinfo command
-fn parse_sinfo_output() -> Result<Vec<SinfoPartition>, String> {
-    // Run sinfo with specific format
-    // %P = partition, %c = cpus, %m = memory, %l = timelimit, %G = gres, %D = nodes
-    let output = Command::new("sinfo")
-        .args(["-e", "-o", "%P|%c|%m|%l|%G|%D", "--noheader"])
+/// Hostname regex matching every host in the same domain
+/// (e.g., "login1.cluster.edu" -> ".*\.cluster\.edu")
+fn hostname_detection_pattern(hostname: &str) -> Option<String> {
+    let (_, domain) = hostname.split_once('.')?;
+    if domain.is_empty() {
+        return None;
+    }
+    Some(format!(".*\\.{}", domain.replace('.', "\\.")))
+}
+
+/// Run a Slurm command and return its stdout
+fn run_slurm_command(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(program)
+        .args(args)
         .output()
-        .map_err(|e| format!("Failed to run sinfo: {}", e))?;
+        .map_err(|e| format!("Failed to run {}: {}", program, e))?;
 
     if !output.status.success() {
         return Err(format!(
-            "sinfo failed: {}",
+            "{} failed: {}",
+            program,
             String::from_utf8_lossy(&output.stderr)
         ));
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    parse_sinfo_string(&stdout)
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Generate an HPC profile from the current Slurm cluster
+fn generate_profile_from_slurm(
+    name: Option<String>,
+    display_name: Option<String>,
+    skip_stdby: bool,
+) -> Result<String, String> {
+    let info = SlurmClusterInfo::query()?;
+    let name = name.unwrap_or_else(|| info.default_profile_name());
+    let display = display_name.unwrap_or_else(|| default_display_name(&name));
+    let profile = info.build_profile(&display, skip_stdby)?;
+
+    // Generate TOML output
+    Ok(generate_toml_profile(&name, &profile))
 }
 
 /// Parse sinfo output string into partition info
@@ -879,12 +1189,15 @@ pub fn parse_sinfo_string(input: &str) -> Result<Vec<SinfoPartition>, String> {
             Some(parts[4].to_string())
         };
 
+        let nodes: u32 = parts[5].trim().parse().unwrap_or(0);
+
         partitions.push(SinfoPartition {
             name,
             cpus,
             memory_mb,
             timelimit_secs,
             gres,
+            nodes,
         });
     }
 
@@ -932,32 +1245,35 @@ fn parse_time_component(s: &str) -> u64 {
     }
 }
 
-/// Parse scontrol show partition output for additional info
-fn parse_scontrol_partition(partition_name: &str) -> Result<ScontrolPartitionInfo, String> {
-    let output = Command::new("scontrol")
-        .args(["show", "partition", partition_name])
-        .output()
-        .map_err(|e| format!("Failed to run scontrol: {}", e))?;
+/// Parse `scontrol show partition` output into info keyed by partition name
+pub fn parse_scontrol_partitions_string(input: &str) -> HashMap<String, ScontrolPartitionInfo> {
+    let mut partitions = HashMap::new();
+    let mut current: Option<(&str, HashMap<&str, &str>)> = None;
 
-    if !output.status.success() {
-        return Err(format!(
-            "scontrol failed: {}",
-            String::from_utf8_lossy(&output.stderr)
-        ));
-    }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut info = ScontrolPartitionInfo::default();
-
-    // Parse key=value pairs
-    let mut kv_map: HashMap<&str, &str> = HashMap::new();
-    for line in stdout.lines() {
-        for part in line.split_whitespace() {
-            if let Some((key, value)) = part.split_once('=') {
-                kv_map.insert(key, value);
+    // Each record starts with PartitionName=<name>, followed by key=value pairs
+    for part in input.split_whitespace() {
+        let Some((key, value)) = part.split_once('=') else {
+            continue;
+        };
+        if key == "PartitionName" {
+            if let Some((name, kv_map)) = current.take() {
+                partitions.insert(name.to_string(), scontrol_info_from_map(&kv_map));
             }
+            current = Some((value, HashMap::new()));
+        } else if let Some((_, kv_map)) = current.as_mut() {
+            kv_map.insert(key, value);
         }
     }
+    if let Some((name, kv_map)) = current {
+        partitions.insert(name.to_string(), scontrol_info_from_map(&kv_map));
+    }
+
+    partitions
+}
+
+/// Extract partition info from the key=value pairs of one scontrol record
+fn scontrol_info_from_map(kv_map: &HashMap<&str, &str>) -> ScontrolPartitionInfo {
+    let mut info = ScontrolPartitionInfo::default();
 
     if let Some(v) = kv_map.get("MinNodes") {
         info.min_nodes = v.parse().ok();
@@ -967,6 +1283,9 @@ fn parse_scontrol_partition(partition_name: &str) -> Result<ScontrolPartitionInf
     {
         info.max_nodes = v.parse().ok();
     }
+    if let Some(v) = kv_map.get("TotalNodes") {
+        info.total_nodes = v.parse().ok();
+    }
     if let Some(v) = kv_map.get("OverSubscribe") {
         info.oversubscribe = Some(v.to_string());
     }
@@ -976,7 +1295,7 @@ fn parse_scontrol_partition(partition_name: &str) -> Result<ScontrolPartitionInf
         info.default_qos = Some(v.to_string());
     }
 
-    Ok(info)
+    info
 }
 
 /// Infer GPU info from partition name when GRES isn't reported by Slurm.
@@ -1061,17 +1380,13 @@ fn parse_gres(gres: &Option<String>) -> (Option<u32>, Option<String>) {
 }
 
 /// Generate TOML configuration for the profile
-fn generate_toml_profile(
-    name: &str,
-    display_name: &str,
-    partitions: &[HpcPartitionConfig],
-) -> Result<String, String> {
+fn generate_toml_profile(name: &str, profile: &HpcProfileConfig) -> String {
     let mut output = String::new();
 
     // Header comment
     output.push_str(&format!(
         "# HPC profile for {} generated from Slurm\n",
-        display_name
+        profile.display_name
     ));
     output.push_str(&format!(
         "# Generated: {}\n",
@@ -1081,6 +1396,7 @@ fn generate_toml_profile(
     output.push_str("# To use this profile, add it to your torc config file:\n");
     output.push_str("#   ~/.config/torc/config.toml (Linux/macOS)\n");
     output.push_str("#   %APPDATA%\\torc\\config.toml (Windows)\n");
+    output.push_str("# or run 'torc hpc discover' to add it automatically.\n");
     output.push_str("#\n");
     output.push_str("# You may want to review and adjust:\n");
     output.push_str(
@@ -1090,30 +1406,60 @@ fn generate_toml_profile(
     output.push_str("#   - description: add human-readable descriptions\n");
     output.push('\n');
 
+    output.push_str(&profile_to_toml(name, profile));
+    output
+}
+
+/// Serialize a profile as `[client.hpc.custom_profiles.<name>]` TOML tables
+pub fn profile_to_toml(name: &str, profile: &HpcProfileConfig) -> String {
+    let mut output = String::new();
+    let key = toml_key(name);
+
     // Profile header
-    output.push_str(&format!("[client.hpc.custom_profiles.{}]\n", name));
-    output.push_str(&format!("display_name = \"{}\"\n", display_name));
-
-    // Try to generate hostname detection pattern
-    if let Ok(hostname) = hostname::get() {
-        let hostname = hostname.to_string_lossy();
-        // Extract domain pattern (e.g., "node01.cluster.edu" -> ".*\\.cluster\\.edu")
-        if let Some(dot_pos) = hostname.find('.') {
-            let domain = &hostname[dot_pos + 1..];
-            let pattern = format!(".*\\\\.{}", domain.replace('.', "\\\\."));
-            output.push_str(&format!("detect_hostname = \"{}\"\n", pattern));
-        }
+    output.push_str(&format!("[client.hpc.custom_profiles.{}]\n", key));
+    output.push_str(&format!(
+        "display_name = {}\n",
+        toml_string(&profile.display_name)
+    ));
+    if !profile.description.is_empty() {
+        output.push_str(&format!(
+            "description = {}\n",
+            toml_string(&profile.description)
+        ));
+    }
+    if let Some(ref env_var) = profile.detect_env_var {
+        output.push_str(&format!("detect_env_var = {}\n", toml_string(env_var)));
+    }
+    if let Some(ref pattern) = profile.detect_hostname {
+        output.push_str(&format!("detect_hostname = {}\n", toml_string(pattern)));
     }
+    if let Some(ref account) = profile.default_account {
+        output.push_str(&format!("default_account = {}\n", toml_string(account)));
+    }
+    output.push_str(&format!(
+        "charge_factor_cpu = {:?}\n",
+        profile.charge_factor_cpu
+    ));
+    output.push_str(&format!(
+        "charge_factor_gpu = {:?}\n",
+        profile.charge_factor_gpu
+    ));
 
     output.push('\n');
 
     // Partitions
-    for partition in partitions {
+    for partition in &profile.partitions {
         output.push_str(&format!(
             "[[client.hpc.custom_profiles.{}.partitions]]\n",
-            name
+            key
         ));
-        output.push_str(&format!("name = \"{}\"\n", partition.name));
+        output.push_str(&format!("name = {}\n", toml_string(&partition.name)));
+        if !partition.description.is_empty() {
+            output.push_str(&format!(
+                "description = {}\n",
+                toml_string(&partition.description)
+            ));
+        }
         output.push_str(&format!("cpus_per_node = {}\n", partition.cpus_per_node));
         output.push_str(&format!("memory_mb = {}\n", partition.memory_mb));
         output.push_str(&format!(
@@ -1121,20 +1467,128 @@ fn generate_toml_profile(
             partition.max_walltime_secs
         ));
 
+        if let Some(max_nodes) = partition.max_nodes {
+            output.push_str(&format!("max_nodes = {}\n", max_nodes));
+        }
+        if let Some(min_nodes) = partition.min_nodes {
+            output.push_str(&format!("min_nodes = {}\n", min_nodes));
+        }
         if let Some(gpus) = partition.gpus_per_node {
             output.push_str(&format!("gpus_per_node = {}\n", gpus));
         }
         if let Some(ref gpu_type) = partition.gpu_type {
-            output.push_str(&format!("gpu_type = \"{}\"\n", gpu_type));
+            output.push_str(&format!("gpu_type = {}\n", toml_string(gpu_type)));
+        }
+        if let Some(gpu_memory_gb) = partition.gpu_memory_gb {
+            output.push_str(&format!("gpu_memory_gb = {}\n", gpu_memory_gb));
         }
         if partition.shared {
             output.push_str("shared = true\n");
         }
+        if partition.requires_explicit_request {
+            output.push_str("requires_explicit_request = true\n");
+        }
+        if let Some(ref qos) = partition.default_qos {
+            output.push_str(&format!("default_qos = {}\n", toml_string(qos)));
+        }
 
         output.push('\n');
     }
 
-    Ok(output)
+    output
+}
+
+/// Quote a string as a TOML value
+fn toml_string(s: &str) -> String {
+    toml::Value::String(s.to_string()).to_string()
+}
+
+/// Format a TOML table key, quoting it if it is not a valid bare key
+fn toml_key(key: &str) -> String {
+    if !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        key.to_string()
+    } else {
+        toml_string(key)
+    }
+}
+
+/// Add a custom profile to a torc config file, creating the file if needed.
+///
+/// New profiles are appended so that existing content and comments are preserved.
+/// Replacing an existing profile requires `force`; the old profile tables are removed and the
+/// new profile is appended, leaving the rest of the file untouched.
+/// Returns true if an existing profile was replaced.
+pub fn write_profile_to_config(
+    path: &std::path::Path,
+    name: &str,
+    profile: &HpcProfileConfig,
+    force: bool,
+) -> Result<bool, String> {
+    let existing = if path.exists() {
+        std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?
+    } else {
+        String::new()
+    };
+    let mut document: toml_edit::DocumentMut = existing
+        .parse()
+        .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+
+    let profile_exists = document
+        .get("client")
+        .and_then(|v| v.get("hpc"))
+        .and_then(|v| v.get("custom_profiles"))
+        .and_then(|v| v.get(name))
+        .is_some();
+
+    if profile_exists && !force {
+        return Err(format!(
+            "Profile '{}' already exists in {}. Use --force to replace it.",
+            name,
+            path.display()
+        ));
+    }
+
+    // Removing the old profile keeps everything else, including comments, as written
+    let mut contents = if profile_exists {
+        if let Some(profiles) = document["client"]["hpc"]["custom_profiles"].as_table_like_mut() {
+            profiles.remove(name);
+        }
+        document.to_string().trim_end().to_string()
+    } else {
+        existing
+    };
+    if !contents.is_empty() {
+        if !contents.ends_with('\n') {
+            contents.push('\n');
+        }
+        contents.push('\n');
+    }
+    contents.push_str(&format!(
+        "# HPC profile discovered from Slurm on {}\n",
+        chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
+    ));
+    contents.push_str(&profile_to_toml(name, profile));
+
+    // Appending can conflict with inline tables; never write a broken config
+    contents
+        .parse::<toml::Table>()
+        .map_err(|e| format!("Failed to add profile to {}: {}", path.display(), e))?;
+
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(path, contents)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    Ok(profile_exists)
 }
 
 #[cfg(test)]
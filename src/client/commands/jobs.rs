@@ -439,9 +439,10 @@ pub fn handle_job_commands(config: &Configuration, command: &JobCommands, format
                     "canceled" => Some(models::JobStatus::Canceled),
                     "terminated" => Some(models::JobStatus::Terminated),
                     "disabled" => Some(models::JobStatus::Disabled),
+                    "skipped" => Some(models::JobStatus::Skipped),
                     _ => {
                         eprintln!(
-                            "Invalid status: {}. Valid values are: uninitialized, blocked, ready, pending, running, completed, failed, canceled, terminated, disabled",
+                            "Invalid status: {}. Valid values are: uninitialized, blocked, ready, pending, running, completed, failed, canceled, terminated, disabled, skipped",
                             status_str
                         );
                         std::process::exit(1);
@@ -5,6 +5,8 @@
 use crate::client::apis::configuration::Configuration;
 use crate::client::apis::default_api;
 use crate::client::commands::{get_env_user_name, print_error, select_workflow_interactively};
+use crate::client::log_paths::{get_job_stderr_path, get_job_stdout_path};
+use crate::models::{JobLogModel, JobLogStream};
 use flate2::Compression;
 use flate2::read::GzDecoder;
 use flate2::write::GzEncoder;
@@ -26,6 +28,10 @@ EXAMPLES:
     # Analyze logs for errors
     torc logs analyze wf123.tar.gz
     torc logs analyze ./output --workflow-id 123
+
+    # Read logs uploaded to the server
+    torc logs show 42 --stream stderr
+    torc logs download 123 --output-dir ./remote_output
 ")]
 pub enum LogCommands {
     /// Bundle all log files for a workflow into a compressed tarball
@@ -59,6 +65,49 @@ EXAMPLES:
         #[arg(short, long)]
         workflow_id: Option<i64>,
     },
+    /// Print a job log that the job runner uploaded to the server
+    #[command(after_long_help = "\
+Job runners upload logs only when client.run.upload_logs is enabled.
+
+EXAMPLES:
+    torc logs show 42
+    torc logs show 42 --stream stderr --tail 50
+    torc logs show 42 --run-id 2 --attempt-id 1
+")]
+    Show {
+        /// Job ID
+        #[arg()]
+        job_id: i64,
+        /// Output stream to print
+        #[arg(short, long, default_value = "stdout", value_parser = ["stdout", "stderr"])]
+        stream: String,
+        /// Workflow run (default: latest uploaded run)
+        #[arg(long)]
+        run_id: Option<i64>,
+        /// Job attempt (default: latest uploaded attempt)
+        #[arg(long)]
+        attempt_id: Option<i64>,
+        /// Print only the last N lines
+        #[arg(long)]
+        tail: Option<usize>,
+    },
+    /// Download the job logs uploaded to the server into a local output directory
+    #[command(after_long_help = "\
+The logs are written to <output-dir>/job_stdio with the same file names that job runners use,
+so that `torc logs analyze`, `torc logs bundle`, and the TUI can read them.
+
+EXAMPLES:
+    torc logs download 123
+    torc logs download 123 --output-dir ./remote_output
+")]
+    Download {
+        /// Workflow ID to download logs for
+        #[arg()]
+        workflow_id: Option<i64>,
+        /// Directory to write the logs to
+        #[arg(short, long, default_value = "output")]
+        output_dir: PathBuf,
+    },
 }
 
 /// Handle log commands
@@ -74,6 +123,179 @@ pub fn handle_log_commands(config: &Configuration, command: &LogCommands) {
         LogCommands::Analyze { path, workflow_id } => {
             analyze_path(path, *workflow_id);
         }
+        LogCommands::Show {
+            job_id,
+            stream,
+            run_id,
+            attempt_id,
+            tail,
+        } => {
+            show_job_log(config, *job_id, stream, *run_id, *attempt_id, *tail);
+        }
+        LogCommands::Download {
+            workflow_id,
+            output_dir,
+        } => {
+            download_job_logs(config, *workflow_id, output_dir);
+        }
+    }
+}
+
+/// Fetch all uploaded logs of a workflow that match the filters, without their content.
+fn fetch_job_logs(
+    config: &Configuration,
+    workflow_id: i64,
+    job_id: Option<i64>,
+    stream: Option<JobLogStream>,
+) -> Vec<JobLogModel> {
+    let mut logs = Vec::new();
+    let mut offset = 0;
+    loop {
+        let response = match default_api::list_job_logs(
+            config,
+            workflow_id,
+            job_id,
+            None,
+            None,
+            stream,
+            Some(offset),
+            None,
+        ) {
+            Ok(response) => response,
+            Err(e) => {
+                print_error("listing job logs", &e);
+                std::process::exit(1);
+            }
+        };
+        let items = response.items.unwrap_or_default();
+        offset += items.len() as i64;
+        logs.extend(items);
+        if !response.has_more {
+            return logs;
+        }
+    }
+}
+
+/// Fetch the content of an uploaded log.
+fn fetch_job_log_content(config: &Configuration, job_log: &JobLogModel) -> String {
+    match default_api::get_job_log_content(config, job_log.id.unwrap_or_default()) {
+        Ok(content) => content,
+        Err(e) => {
+            print_error("getting job log content", &e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Print a job log uploaded to the server
+fn show_job_log(
+    config: &Configuration,
+    job_id: i64,
+    stream: &str,
+    run_id: Option<i64>,
+    attempt_id: Option<i64>,
+    tail: Option<usize>,
+) {
+    let job = match default_api::get_job(config, job_id) {
+        Ok(job) => job,
+        Err(e) => {
+            print_error("getting job", &e);
+            std::process::exit(1);
+        }
+    };
+    let stream: JobLogStream = stream.parse().unwrap_or_default();
+    let job_log = fetch_job_logs(config, job.workflow_id, Some(job_id), Some(stream))
+        .into_iter()
+        .filter(|log| run_id.is_none_or(|id| log.run_id == id))
+        .filter(|log| attempt_id.is_none_or(|id| log.attempt_id == Some(id)))
+        .max_by_key(|log| (log.run_id, log.attempt_id));
+    let Some(job_log) = job_log else {
+        eprintln!(
+            "No {} log uploaded for job {}. Job runners upload logs only when \
+            client.run.upload_logs is enabled.",
+            stream, job_id
+        );
+        std::process::exit(1);
+    };
+
+    let content = fetch_job_log_content(config, &job_log);
+    if job_log.is_truncated.unwrap_or(false) {
+        eprintln!(
+            "Note: the log was truncated on upload; showing the last {} of {} bytes",
+            content.len(),
+            job_log.size_bytes.unwrap_or_default()
+        );
+    }
+    let content = match tail {
+        Some(n) => {
+            let lines: Vec<&str> = content.lines().collect();
+            let mut text = lines[lines.len().saturating_sub(n)..].join("\n");
+            text.push('\n');
+            text
+        }
+        None => content,
+    };
+    print!("{}", content);
+}
+
+/// Download the job logs uploaded to the server into the job_stdio layout of an output directory
+fn download_job_logs(config: &Configuration, workflow_id: Option<i64>, output_dir: &Path) {
+    let user = get_env_user_name();
+    let wf_id = match workflow_id {
+        Some(id) => id,
+        None => match select_workflow_interactively(config, &user) {
+            Ok(id) => id,
+            Err(e) => {
+                eprintln!("Error selecting workflow: {}", e);
+                std::process::exit(1);
+            }
+        },
+    };
+
+    let logs = fetch_job_logs(config, wf_id, None, None);
+    if let Err(e) = std::fs::create_dir_all(output_dir.join("job_stdio")) {
+        eprintln!("Error creating output directory: {}", e);
+        std::process::exit(1);
+    }
+    let mut num_truncated = 0;
+    for job_log in &logs {
+        let attempt_id = job_log.attempt_id.unwrap_or(1);
+        let path = match job_log.stream {
+            JobLogStream::Stdout => get_job_stdout_path(
+                output_dir,
+                wf_id,
+                job_log.job_id,
+                job_log.run_id,
+                attempt_id,
+            ),
+            JobLogStream::Stderr => get_job_stderr_path(
+                output_dir,
+                wf_id,
+                job_log.job_id,
+                job_log.run_id,
+                attempt_id,
+            ),
+        };
+        if let Err(e) = std::fs::write(&path, fetch_job_log_content(config, job_log)) {
+            eprintln!("Error writing {}: {}", path, e);
+            std::process::exit(1);
+        }
+        if job_log.is_truncated.unwrap_or(false) {
+            num_truncated += 1;
+        }
+    }
+
+    println!(
+        "Downloaded {} job logs for workflow {} to {}",
+        logs.len(),
+        wf_id,
+        output_dir.join("job_stdio").display()
+    );
+    if num_truncated > 0 {
+        println!(
+            "{} logs were truncated on upload and contain only the end of the job output",
+            num_truncated
+        );
     }
 }
 
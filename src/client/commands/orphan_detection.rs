@@ -1,7 +1,7 @@
-//! Orphan detection and cleanup for Slurm workflows.
+//! Orphan detection and cleanup for HPC (Slurm, PBS) workflows.
 //!
 //! This module provides shared logic for detecting and failing orphaned jobs
-//! that are stuck in "running" status after their Slurm allocation terminated.
+//! that are stuck in "running" status after their HPC allocation terminated.
 //!
 //! Used by:
 //! - `torc watch` - continuous monitoring with automatic orphan detection
@@ -11,6 +11,7 @@
 use chrono::Utc;
 use log::{debug, info, warn};
 use serde::Serialize;
+use std::collections::HashMap;
 
 use crate::client::apis::configuration::Configuration;
 use crate::client::apis::default_api;
@@ -18,9 +19,9 @@ use crate::client::commands::pagination::{
     ComputeNodeListParams, JobListParams, ScheduledComputeNodeListParams, paginate_compute_nodes,
     paginate_jobs, paginate_scheduled_compute_nodes,
 };
-use crate::client::hpc::common::HpcJobStatus;
+use crate::client::hpc::common::{HpcJobStatus, HpcType};
+use crate::client::hpc::create_hpc_interface;
 use crate::client::hpc::hpc_interface::HpcInterface;
-use crate::client::hpc::slurm_interface::SlurmInterface;
 use crate::models;
 
 /// Return code used when failing jobs orphaned by an ungraceful job runner termination.
@@ -30,6 +31,33 @@ use crate::models;
 /// - Easy to identify in logs and results
 pub const ORPHANED_JOB_RETURN_CODE: i64 = -128;
 
+/// HPC interfaces created on demand for each scheduler type found in a workflow.
+///
+/// Creating an interface can fail (e.g., `USER` is not set), in which case nodes of that
+/// scheduler type are skipped rather than treated as orphaned.
+#[derive(Default)]
+pub(crate) struct HpcInterfaceCache {
+    interfaces: HashMap<HpcType, Option<Box<dyn HpcInterface>>>,
+}
+
+impl HpcInterfaceCache {
+    /// Get the interface for a scheduled compute node's `scheduler_type`.
+    /// Returns None for non-HPC scheduler types or if the interface could not be created.
+    pub(crate) fn get(&mut self, scheduler_type: &str) -> Option<&dyn HpcInterface> {
+        let hpc_type = HpcType::from_scheduler_type(scheduler_type)?;
+        self.interfaces
+            .entry(hpc_type)
+            .or_insert_with(|| match create_hpc_interface(hpc_type) {
+                Ok(interface) => Some(interface),
+                Err(e) => {
+                    warn!("Could not create {} interface: {}", hpc_type, e);
+                    None
+                }
+            })
+            .as_deref()
+    }
+}
+
 /// Result of orphan cleanup operation
 #[derive(Debug, Clone, Serialize)]
 pub struct OrphanCleanupResult {
@@ -126,7 +154,7 @@ fn fail_orphaned_slurm_jobs(
         .map_err(|e| format!("Failed to get workflow status: {}", e))?;
     let run_id = workflow_status.run_id;
 
-    // Get all scheduled compute nodes with status="active" and scheduler_type="slurm"
+    // Get all scheduled compute nodes with status="active" and an HPC scheduler_type
     let scheduled_nodes = paginate_scheduled_compute_nodes(
         config,
         workflow_id,
@@ -134,36 +162,31 @@ fn fail_orphaned_slurm_jobs(
     )
     .map_err(|e| format!("Failed to list scheduled compute nodes: {}", e))?;
 
-    // Filter for Slurm scheduler type
+    // Filter for HPC scheduler types (slurm, pbs)
     let slurm_nodes: Vec<_> = scheduled_nodes
         .iter()
-        .filter(|node| node.scheduler_type.to_lowercase() == "slurm")
+        .filter(|node| HpcType::from_scheduler_type(&node.scheduler_type).is_some())
         .collect();
 
     if slurm_nodes.is_empty() {
         return Ok((0, Vec::new()));
     }
 
-    // Create SlurmInterface to check job status
-    let slurm = match SlurmInterface::new() {
-        Ok(s) => s,
-        Err(e) => {
-            warn!("Could not create SlurmInterface: {}", e);
-            return Ok((0, Vec::new()));
-        }
-    };
-
+    let mut interfaces = HpcInterfaceCache::default();
     let mut total_failed = 0;
     let mut details = Vec::new();
 
     for scheduled_node in slurm_nodes {
-        let slurm_job_id = scheduled_node.scheduler_id.to_string();
+        let slurm_job_id = scheduled_node.hpc_job_id();
         let scheduled_compute_node_id = match scheduled_node.id {
             Some(id) => id,
             None => continue,
         };
 
-        // Check Slurm status
+        // Check the scheduler's status for the allocation
+        let Some(slurm) = interfaces.get(&scheduled_node.scheduler_type) else {
+            continue;
+        };
         let slurm_status = match slurm.get_status(&slurm_job_id) {
             Ok(info) => info.status,
             Err(e) => {
@@ -362,38 +385,30 @@ fn cleanup_dead_pending_slurm_jobs(
     )
     .map_err(|e| format!("Failed to list pending scheduled compute nodes: {}", e))?;
 
-    // Filter for Slurm scheduler type
+    // Filter for HPC scheduler types (slurm, pbs)
     let slurm_nodes: Vec<_> = scheduled_nodes
         .iter()
-        .filter(|node| node.scheduler_type.to_lowercase() == "slurm")
+        .filter(|node| HpcType::from_scheduler_type(&node.scheduler_type).is_some())
         .collect();
 
     if slurm_nodes.is_empty() {
         return Ok(0);
     }
 
-    // Create SlurmInterface to check job status
-    let slurm = match SlurmInterface::new() {
-        Ok(s) => s,
-        Err(e) => {
-            debug!(
-                "Could not create SlurmInterface for pending job check: {}",
-                e
-            );
-            return Ok(0);
-        }
-    };
-
+    let mut interfaces = HpcInterfaceCache::default();
     let mut total_cleaned = 0;
 
     for scheduled_node in slurm_nodes {
-        let slurm_job_id = scheduled_node.scheduler_id.to_string();
+        let slurm_job_id = scheduled_node.hpc_job_id();
         let scheduled_compute_node_id = match scheduled_node.id {
             Some(id) => id,
             None => continue,
         };
 
-        // Check Slurm status
+        // Check the scheduler's status for the allocation
+        let Some(slurm) = interfaces.get(&scheduled_node.scheduler_type) else {
+            continue;
+        };
         let slurm_status = match slurm.get_status(&slurm_job_id) {
             Ok(info) => info.status,
             Err(e) => {
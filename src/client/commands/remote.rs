@@ -138,6 +138,21 @@ EXAMPLES:
         /// Skip version check (not recommended)
         #[arg(long, default_value = "false")]
         skip_version_check: bool,
+
+        /// Copy each job's input files to the worker before it runs and its output files
+        /// back after it completes. Use when workers do not share a filesystem.
+        #[arg(long, default_value = "false")]
+        stage_files: bool,
+
+        /// Host that workers copy staged files from and to ([user@]hostname[:port]).
+        /// Defaults to this machine.
+        #[arg(long, requires = "stage_files")]
+        staging_host: Option<String>,
+
+        /// Directory on the staging host that relative file paths are resolved against.
+        /// Defaults to the current directory.
+        #[arg(long, requires = "stage_files")]
+        stage_root: Option<String>,
     },
 
     /// Check status of remote workers
@@ -268,8 +283,19 @@ pub fn handle_remote_commands(config: &Configuration, command: &RemoteCommands)
             memory_gb,
             num_gpus,
             skip_version_check,
+            stage_files,
+            staging_host,
+            stage_root,
         } => {
             let wf_id = resolve_workflow_id(config, *workflow_id);
+            let staging = if *stage_files {
+                Some(resolve_staging(
+                    staging_host.as_deref(),
+                    stage_root.as_deref(),
+                ))
+            } else {
+                None
+            };
             handle_run(
                 config,
                 wf_id,
@@ -282,6 +308,7 @@ pub fn handle_remote_commands(config: &Configuration, command: &RemoteCommands)
                 *memory_gb,
                 *num_gpus,
                 *skip_version_check,
+                staging,
             );
         }
         RemoteCommands::Status {
@@ -388,6 +415,7 @@ fn handle_run(
     memory_gb: Option<f64>,
     num_gpus: Option<i64>,
     skip_version_check: bool,
+    staging: Option<(String, String)>,
 ) {
     // If a workers file is provided, add those workers to the database first
     if let Some(worker_file) = workers_file {
@@ -450,6 +478,10 @@ fn handle_run(
     // Initialize workflow on manager if needed (before starting workers to avoid race condition)
     initialize_workflow_if_needed(config, workflow_id);
 
+    if let Some((host, root)) = &staging {
+        println!("Workers will stage job files from {}:{}", host, root);
+    }
+
     // Start workers
     println!("Starting workers...");
 
@@ -469,6 +501,7 @@ fn handle_run(
                 num_cpus,
                 memory_gb,
                 num_gpus,
+                staging.as_ref(),
             )
         },
         max_parallel_ssh,
@@ -496,6 +529,39 @@ fn handle_run(
     }
 }
 
+/// Resolve the staging host and root directory passed to workers by `--stage-files`.
+///
+/// Defaults to the current user on this machine and the current directory.
+fn resolve_staging(staging_host: Option<&str>, stage_root: Option<&str>) -> (String, String) {
+    let host = match staging_host {
+        Some(host) => host.to_string(),
+        None => {
+            let hostname = hostname::get()
+                .ok()
+                .and_then(|h| h.into_string().ok())
+                .unwrap_or_else(|| {
+                    eprintln!("Error: could not determine hostname; pass --staging-host");
+                    std::process::exit(1);
+                });
+            format!("{}@{}", get_env_user_name(), hostname)
+        }
+    };
+    if let Err(e) = parse_single_worker(&host) {
+        eprintln!("Error: invalid staging host '{}': {}", host, e);
+        std::process::exit(1);
+    }
+    let root = match stage_root {
+        Some(root) => root.to_string(),
+        None => std::env::current_dir()
+            .map(|dir| dir.to_string_lossy().to_string())
+            .unwrap_or_else(|e| {
+                eprintln!("Error: could not determine current directory: {}", e);
+                std::process::exit(1);
+            }),
+    };
+    (host, root)
+}
+
 /// Start a single worker on a remote machine.
 #[allow(clippy::too_many_arguments)]
 fn start_remote_worker(
@@ -508,6 +574,7 @@ fn start_remote_worker(
     num_cpus: Option<i64>,
     memory_gb: Option<f64>,
     num_gpus: Option<i64>,
+    staging: Option<&(String, String)>,
 ) -> RemoteOperationResult {
     // Create output directory on remote
     let mkdir_cmd = format!("mkdir -p {}", output_dir);
@@ -537,17 +604,39 @@ fn start_remote_worker(
     if let Some(max) = max_parallel_jobs {
         torc_cmd.push_str(&format!(" --max-parallel-jobs {}", max));
     }
+    if let Some((host, root)) = staging {
+        let (host, root) = match (shlex::try_quote(host), shlex::try_quote(root)) {
+            (Ok(host), Ok(root)) => (host, root),
+            _ => {
+                return RemoteOperationResult::failure(
+                    worker.clone(),
+                    format!("Invalid staging host '{}' or root '{}'", host, root),
+                );
+            }
+        };
+        torc_cmd.push_str(&format!(" --stage-from {} --stage-root {}", host, root));
+    }
 
     // PID file and log file paths
     let pid_file = format!("{}/torc_worker_{}.pid", output_dir, workflow_id);
     let log_file = format!("{}/torc_worker_{}.log", output_dir, workflow_id);
 
     // Start with nohup, redirect output, save PID
-    // Use bash -c to ensure proper shell handling
-    let start_cmd = format!(
-        "bash -c 'nohup {} > {} 2>&1 & echo $! > {}; disown'",
+    // Use bash -c to ensure proper shell handling; the script is quoted as a whole so that
+    // quoted arguments inside it survive
+    let script = format!(
+        "nohup {} > {} 2>&1 & echo $! > {}; disown",
         torc_cmd, log_file, pid_file
     );
+    let start_cmd = match shlex::try_quote(&script) {
+        Ok(script) => format!("bash -c {}", script),
+        Err(e) => {
+            return RemoteOperationResult::failure(
+                worker.clone(),
+                format!("Invalid worker command: {}", e),
+            );
+        }
+    };
 
     debug!(
         "Starting worker on {}: {}",
@@ -1,5 +1,6 @@
 use crate::client::apis::configuration::Configuration;
 use crate::client::apis::default_api;
+use crate::client::async_cli_command::RUNTIME_LIMIT_RETURN_CODE;
 use crate::client::commands::output::print_json;
 use crate::client::commands::{
     get_env_user_name, pagination, print_error, select_workflow_interactively,
@@ -367,6 +368,13 @@ fn check_resource_utilization(
                 }
             }
 
+            // Return code 124 is recorded by the job runner when it terminates a job for
+            // exceeding its runtime (workflow option enforce_runtime_limits)
+            if result.return_code == RUNTIME_LIMIT_RETURN_CODE {
+                likely_timeout = true;
+                timeout_reason = Some("runtime_limit_124".to_string());
+            }
+
             // Check for OOM via return code 137 (128 + SIGKILL)
             // When Slurm OOM-kills a job, resource metrics may not be recorded
             // but return code 137 is a strong indicator - unless it's a timeout
@@ -737,9 +745,14 @@ fn generate_results_report(
                         // For slurm runner, extract slurm job ID from scheduler JSON
                         if let Some(scheduler_value) = &compute_node.scheduler
                             && let Some(slurm_job_id_val) = scheduler_value.get("slurm_job_id")
-                            && let Some(slurm_job_id_str) = slurm_job_id_val.as_str()
+                            && let Some(slurm_job_id_str) = match slurm_job_id_val {
+                                // Job array tasks are recorded as "<array_job_id>_<task_id>"
+                                serde_json::Value::String(s) => Some(s.clone()),
+                                serde_json::Value::Number(n) => Some(n.to_string()),
+                                _ => None,
+                            }
                         {
-                            slurm_job_id = Some(slurm_job_id_str.to_string());
+                            slurm_job_id = Some(slurm_job_id_str.clone());
 
                             // Build slurm job runner log path
                             // Use hostname as node_id and pid as task_pid for the log path
@@ -749,7 +762,7 @@ fn generate_results_report(
                             let log_path = get_slurm_job_runner_log_file(
                                 output_dir.to_path_buf(),
                                 wf_id,
-                                slurm_job_id_str,
+                                &slurm_job_id_str,
                                 node_id,
                                 task_pid,
                             );
@@ -758,9 +771,9 @@ fn generate_results_report(
 
                             // Add slurm stdout/stderr paths
                             let stdout_path =
-                                get_slurm_stdout_path(output_dir, wf_id, slurm_job_id_str);
+                                get_slurm_stdout_path(output_dir, wf_id, &slurm_job_id_str);
                             let stderr_path =
-                                get_slurm_stderr_path(output_dir, wf_id, slurm_job_id_str);
+                                get_slurm_stderr_path(output_dir, wf_id, &slurm_job_id_str);
                             check_log_file_exists(&stdout_path, "slurm stdout", job_id);
                             check_log_file_exists(&stderr_path, "slurm stderr", job_id);
                             slurm_stdout = Some(stdout_path);
@@ -919,6 +932,7 @@ fn generate_summary(config: &Configuration, workflow_id: Option<i64>, format: &s
     let mut terminated_count = 0;
     let mut disabled_count = 0;
     let mut pending_failed_count = 0;
+    let mut skipped_count = 0;
 
     for job in &jobs {
         match job.status {
@@ -933,6 +947,7 @@ fn generate_summary(config: &Configuration, workflow_id: Option<i64>, format: &s
             Some(models::JobStatus::Terminated) => terminated_count += 1,
             Some(models::JobStatus::Disabled) => disabled_count += 1,
             Some(models::JobStatus::PendingFailed) => pending_failed_count += 1,
+            Some(models::JobStatus::Skipped) => skipped_count += 1,
             None => {}
         }
     }
@@ -1005,6 +1020,7 @@ fn generate_summary(config: &Configuration, workflow_id: Option<i64>, format: &s
                 "terminated": terminated_count,
                 "disabled": disabled_count,
                 "pending_failed": pending_failed_count,
+                "skipped": skipped_count,
             },
             "total_exec_time_minutes": total_exec_time_minutes,
             "total_exec_time_formatted": format_duration(total_exec_time_minutes * 60.0),
@@ -1066,6 +1082,9 @@ fn generate_summary(config: &Configuration, workflow_id: Option<i64>, format: &s
         if pending_failed_count > 0 {
             println!("  PendingFailed: {} ⏳", pending_failed_count);
         }
+        if skipped_count > 0 {
+            println!("  Skipped:       {}", skipped_count);
+        }
         println!();
         println!(
             "Total Execution Time: {}",
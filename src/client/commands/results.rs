@@ -118,7 +118,7 @@ pub enum ResultCommands {
         /// Show only failed jobs (non-zero return code)
         #[arg(long)]
         failed: bool,
-        /// Filter by job status (uninitialized, blocked, canceled, terminated, done, ready, scheduled, running, pending, disabled)
+        /// Filter by job status (uninitialized, blocked, canceled, terminated, done, ready, scheduled, running, pending, disabled, skipped)
         #[arg(short, long)]
         status: Option<String>,
         /// Maximum number of results to return
@@ -206,9 +206,10 @@ pub fn handle_result_commands(config: &Configuration, command: &ResultCommands,
                     "canceled" => models::JobStatus::Canceled,
                     "terminated" => models::JobStatus::Terminated,
                     "disabled" => models::JobStatus::Disabled,
+                    "skipped" => models::JobStatus::Skipped,
                     _ => {
                         eprintln!(
-                            "Invalid status: {}. Valid values are: uninitialized, blocked, ready, pending, running, completed, failed, canceled, terminated, disabled",
+                            "Invalid status: {}. Valid values are: uninitialized, blocked, ready, pending, running, completed, failed, canceled, terminated, disabled, skipped",
                             status_str
                         );
                         std::process::exit(1);
@@ -13,7 +13,7 @@ struct ScheduledComputeNodeTableRow {
     #[tabled(rename = "ID")]
     id: i64,
     #[tabled(rename = "Scheduler ID")]
-    scheduler_id: i64,
+    scheduler_id: String,
     #[tabled(rename = "Config ID")]
     scheduler_config_id: i64,
     #[tabled(rename = "Type")]
@@ -26,7 +26,7 @@ impl From<&models::ScheduledComputeNodesModel> for ScheduledComputeNodeTableRow
     fn from(node: &models::ScheduledComputeNodesModel) -> Self {
         ScheduledComputeNodeTableRow {
             id: node.id.unwrap_or(-1),
-            scheduler_id: node.scheduler_id,
+            scheduler_id: node.hpc_job_id(),
             scheduler_config_id: node.scheduler_config_id,
             scheduler_type: node.scheduler_type.clone(),
             status: node.status.clone(),
@@ -59,7 +59,7 @@ pub enum ScheduledComputeNodeCommands {
         /// Reverse sort order
         #[arg(short, long, default_value = "false")]
         reverse_sort: bool,
-        /// Filter by scheduler ID
+        /// Filter by scheduler ID (use <array_job_id>_<task_id> for a Slurm job array task)
         #[arg(long)]
         scheduler_id: Option<String>,
         /// Filter by scheduler config ID
@@ -91,7 +91,7 @@ pub fn handle_scheduled_compute_node_commands(
                     } else {
                         println!("Scheduled Compute Node ID {}:", id);
                         println!("  Workflow ID: {}", node.workflow_id);
-                        println!("  Scheduler ID: {}", node.scheduler_id);
+                        println!("  Scheduler ID: {}", node.hpc_job_id());
                         println!("  Scheduler Config ID: {}", node.scheduler_config_id);
                         println!("  Scheduler Type: {}", node.scheduler_type);
                         println!("  Status: {}", node.status);
@@ -0,0 +1,271 @@
+//! Commands for recurring workflow runs that the server starts on a cron schedule
+
+use crate::client::apis::configuration::Configuration;
+use crate::client::apis::default_api;
+use crate::client::commands::output::{print_if_json, print_wrapped_if_json};
+use crate::client::commands::{print_error, table_format::display_table_with_count};
+use crate::models;
+use tabled::Tabled;
+
+#[derive(Tabled)]
+struct ScheduleTableRow {
+    #[tabled(rename = "ID")]
+    id: i64,
+    #[tabled(rename = "Workflow ID")]
+    workflow_id: i64,
+    #[tabled(rename = "Cron")]
+    cron_expression: String,
+    #[tabled(rename = "Timezone")]
+    timezone: String,
+    #[tabled(rename = "Overlap")]
+    overlap_policy: String,
+    #[tabled(rename = "Paused")]
+    is_paused: bool,
+    #[tabled(rename = "Next Run")]
+    next_run_time: String,
+    #[tabled(rename = "Last Run")]
+    last_run_time: String,
+}
+
+#[derive(clap::Subcommand)]
+pub enum ScheduleCommands {
+    /// Create a schedule that reinitializes and submits a workflow at recurring times
+    #[command(after_long_help = "\
+EXAMPLES:
+    # Every night at 02:30 in Denver
+    torc schedules create 42 '30 2 * * *' --timezone America/Denver
+
+    # Every 15 minutes, queuing a run if the previous one is still active
+    torc schedules create 42 '*/15 * * * *' --overlap-policy queue
+")]
+    Create {
+        /// ID of the workflow to run
+        #[arg()]
+        workflow_id: i64,
+        /// Cron expression (minute hour day-of-month month day-of-week), with an optional
+        /// leading seconds field
+        #[arg()]
+        cron: String,
+        /// IANA time zone in which the cron expression is evaluated
+        #[arg(long, default_value = "UTC")]
+        timezone: String,
+        /// What to do when the schedule fires while the previous run is still active
+        #[arg(long, default_value = "skip", value_parser = ["skip", "queue"])]
+        overlap_policy: String,
+        /// Directory in which the server runs the reinitialize and submit commands
+        /// (defaults to the current directory)
+        #[arg(long)]
+        working_directory: Option<String>,
+    },
+    /// List workflow schedules
+    List {
+        /// Only list schedules of this workflow
+        #[arg()]
+        workflow_id: Option<i64>,
+        /// Maximum number of schedules to return
+        #[arg(short, long, default_value = "10000")]
+        limit: i64,
+        /// Offset for pagination (0-based)
+        #[arg(long, default_value = "0")]
+        offset: i64,
+    },
+    /// Get a specific schedule by ID
+    Get {
+        /// ID of the schedule
+        #[arg()]
+        id: i64,
+    },
+    /// Pause a schedule so that it stops firing
+    Pause {
+        /// ID of the schedule
+        #[arg()]
+        id: i64,
+    },
+    /// Resume a paused schedule. The next run time is computed from the current time.
+    Resume {
+        /// ID of the schedule
+        #[arg()]
+        id: i64,
+    },
+    /// Delete a schedule
+    Delete {
+        /// ID of the schedule
+        #[arg()]
+        id: i64,
+    },
+}
+
+pub fn handle_schedule_commands(config: &Configuration, command: &ScheduleCommands, format: &str) {
+    match command {
+        ScheduleCommands::Create {
+            workflow_id,
+            cron,
+            timezone,
+            overlap_policy,
+            working_directory,
+        } => {
+            // The server requires an absolute path
+            let working_directory = match working_directory {
+                Some(dir) => std::fs::canonicalize(dir),
+                None => std::env::current_dir(),
+            };
+            let working_directory = match working_directory {
+                Ok(dir) => dir.to_string_lossy().to_string(),
+                Err(e) => {
+                    eprintln!("Error resolving working directory: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            let mut schedule = models::WorkflowScheduleModel::new(*workflow_id, cron.clone());
+            schedule.timezone = Some(timezone.clone());
+            schedule.overlap_policy = overlap_policy.parse().ok();
+            schedule.working_directory = Some(working_directory);
+
+            match default_api::create_workflow_schedule(config, schedule) {
+                Ok(schedule) => {
+                    if print_if_json(format, &schedule, "schedule") {
+                        // JSON was printed
+                    } else {
+                        println!("Successfully created schedule:");
+                        print_schedule_details(&schedule);
+                    }
+                }
+                Err(e) => {
+                    print_error("creating schedule", &e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        ScheduleCommands::List {
+            workflow_id,
+            limit,
+            offset,
+        } => {
+            match default_api::list_workflow_schedules(
+                config,
+                *workflow_id,
+                Some(*offset),
+                Some(*limit),
+            ) {
+                Ok(response) => {
+                    let schedules = response.items.unwrap_or_default();
+                    if print_wrapped_if_json(format, "schedules", &schedules, "schedules") {
+                        // JSON was printed
+                    } else if schedules.is_empty() {
+                        println!("No schedules found");
+                    } else {
+                        let rows: Vec<ScheduleTableRow> = schedules
+                            .iter()
+                            .map(|schedule| ScheduleTableRow {
+                                id: schedule.id.unwrap_or(-1),
+                                workflow_id: schedule.workflow_id,
+                                cron_expression: schedule.cron_expression.clone(),
+                                timezone: schedule.timezone.clone().unwrap_or_default(),
+                                overlap_policy: schedule
+                                    .overlap_policy
+                                    .map(|p| p.to_string())
+                                    .unwrap_or_default(),
+                                is_paused: schedule.is_paused.unwrap_or(false),
+                                next_run_time: schedule.next_run_time.clone().unwrap_or_default(),
+                                last_run_time: schedule.last_run_time.clone().unwrap_or_default(),
+                            })
+                            .collect();
+                        display_table_with_count(&rows, "schedules");
+                    }
+                }
+                Err(e) => {
+                    print_error("listing schedules", &e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        ScheduleCommands::Get { id } => match default_api::get_workflow_schedule(config, *id) {
+            Ok(schedule) => {
+                if print_if_json(format, &schedule, "schedule") {
+                    // JSON was printed
+                } else {
+                    print_schedule_details(&schedule);
+                }
+            }
+            Err(e) => {
+                print_error("getting schedule", &e);
+                std::process::exit(1);
+            }
+        },
+        ScheduleCommands::Pause { id } => set_paused(config, *id, true, format),
+        ScheduleCommands::Resume { id } => set_paused(config, *id, false, format),
+        ScheduleCommands::Delete { id } => {
+            match default_api::delete_workflow_schedule(config, *id, None) {
+                Ok(schedule) => {
+                    if print_if_json(format, &schedule, "schedule") {
+                        // JSON was printed
+                    } else {
+                        println!("Successfully deleted schedule {}", id);
+                    }
+                }
+                Err(e) => {
+                    print_error("deleting schedule", &e);
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+}
+
+fn set_paused(config: &Configuration, id: i64, is_paused: bool, format: &str) {
+    let action = if is_paused { "pausing" } else { "resuming" };
+    let mut schedule = match default_api::get_workflow_schedule(config, id) {
+        Ok(schedule) => schedule,
+        Err(e) => {
+            print_error(&format!("{} schedule", action), &e);
+            std::process::exit(1);
+        }
+    };
+    schedule.is_paused = Some(is_paused);
+
+    match default_api::update_workflow_schedule(config, id, schedule) {
+        Ok(schedule) => {
+            if print_if_json(format, &schedule, "schedule") {
+                // JSON was printed
+            } else if is_paused {
+                println!("Successfully paused schedule {}", id);
+            } else {
+                println!(
+                    "Successfully resumed schedule {}. Next run: {}",
+                    id,
+                    schedule.next_run_time.unwrap_or_default()
+                );
+            }
+        }
+        Err(e) => {
+            print_error(&format!("{} schedule", action), &e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn print_schedule_details(schedule: &models::WorkflowScheduleModel) {
+    println!("  ID: {}", schedule.id.unwrap_or(-1));
+    println!("  Workflow ID: {}", schedule.workflow_id);
+    println!("  Cron expression: {}", schedule.cron_expression);
+    println!(
+        "  Timezone: {}",
+        schedule.timezone.as_deref().unwrap_or("UTC")
+    );
+    println!(
+        "  Overlap policy: {}",
+        schedule.overlap_policy.unwrap_or_default()
+    );
+    if let Some(dir) = &schedule.working_directory {
+        println!("  Working directory: {}", dir);
+    }
+    println!("  Paused: {}", schedule.is_paused.unwrap_or(false));
+    println!(
+        "  Next run: {}",
+        schedule.next_run_time.as_deref().unwrap_or("-")
+    );
+    println!(
+        "  Last run: {}",
+        schedule.last_run_time.as_deref().unwrap_or("-")
+    );
+}
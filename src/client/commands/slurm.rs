@@ -25,6 +25,7 @@ const SLURM_HELP_TEMPLATE: &str = "\
 \x1b[1;32mDiagnostics:\x1b[0m
   \x1b[1;36mparse-logs\x1b[0m       Parse Slurm logs for error messages
   \x1b[1;36msacct\x1b[0m            Show Slurm accounting info for allocations
+  \x1b[1;36mcost\x1b[0m             Show AU cost of allocations against the budget
 {after-help}";
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -39,7 +40,7 @@ use std::process::Command;
 use crate::client::apis::configuration::Configuration;
 use crate::client::apis::default_api;
 use crate::client::commands::get_env_user_name;
-use crate::client::commands::hpc::create_registry_with_config_public;
+use crate::client::commands::hpc::{create_registry_with_config_public, resolve_charge_factors};
 use crate::client::commands::pagination::{
     ComputeNodeListParams, JobListParams, ResourceRequirementsListParams, ResultListParams,
     ScheduledComputeNodeListParams, SlurmSchedulersListParams, paginate_compute_nodes,
@@ -49,8 +50,13 @@ use crate::client::commands::pagination::{
 use crate::client::commands::{
     print_error, select_workflow_interactively, table_format::display_table_with_count,
 };
-use crate::client::hpc::HpcProfile;
+use crate::client::execution_plan::ExecutionPlan;
+use crate::client::hpc::cost::{
+    AllocationCost, AllocationShape, AuUsage, SchedulerCostEstimate, check_au_budget,
+    compute_node_au, estimate_scheduler_plan, sacct_au, total_au,
+};
 use crate::client::hpc::hpc_interface::HpcInterface;
+use crate::client::hpc::{ChargeFactors, HpcProfile, HpcType, create_hpc_interface};
 use crate::client::utils;
 use crate::client::workflow_graph::WorkflowGraph;
 use crate::client::workflow_manager::WorkflowManager;
@@ -116,6 +122,128 @@ impl std::fmt::Display for WalltimeStrategy {
     }
 }
 
+/// Type of Slurm dependency that chained allocations have on the allocations of earlier stages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SlurmDependencyType {
+    /// Start after the earlier allocations end successfully (default)
+    ///
+    /// Slurm cancels the chained allocation if an earlier allocation fails, e.g., because
+    /// it reached its walltime.
+    #[default]
+    #[value(name = "afterok")]
+    AfterOk,
+
+    /// Start after the earlier allocations end, regardless of how they end
+    #[value(name = "afterany")]
+    AfterAny,
+}
+
+impl std::fmt::Display for SlurmDependencyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SlurmDependencyType::AfterOk => write!(f, "afterok"),
+            SlurmDependencyType::AfterAny => write!(f, "afterany"),
+        }
+    }
+}
+
+impl std::str::FromStr for SlurmDependencyType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "afterok" => Ok(SlurmDependencyType::AfterOk),
+            "afterany" => Ok(SlurmDependencyType::AfterAny),
+            _ => Err(format!(
+                "Invalid dependency type '{}'. Expected 'afterok' or 'afterany'",
+                s
+            )),
+        }
+    }
+}
+
+/// Allocations of other schedulers that new allocations must wait for in the Slurm queue
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllocationDependency {
+    pub dependency_type: SlurmDependencyType,
+    /// IDs of the slurm_schedulers records whose allocations must end first
+    pub scheduler_ids: Vec<i64>,
+}
+
+impl AllocationDependency {
+    /// Read the dependency from the `depends_on_scheduler_ids` and `dependency_type` fields
+    /// of a schedule_nodes action config. Returns None if the action has no dependency.
+    pub fn from_action_config(action_config: &serde_json::Value) -> Result<Option<Self>, String> {
+        let scheduler_ids: Vec<i64> = match action_config.get("depends_on_scheduler_ids") {
+            Some(ids) => ids
+                .as_array()
+                .ok_or("'depends_on_scheduler_ids' must be an array")?
+                .iter()
+                .map(|id| {
+                    id.as_i64()
+                        .ok_or("'depends_on_scheduler_ids' must contain integers")
+                })
+                .collect::<Result<_, _>>()?,
+            None => Vec::new(),
+        };
+        if scheduler_ids.is_empty() {
+            return Ok(None);
+        }
+        let dependency_type = match action_config.get("dependency_type") {
+            Some(value) => value
+                .as_str()
+                .ok_or("'dependency_type' must be a string")?
+                .parse()?,
+            None => SlurmDependencyType::default(),
+        };
+        Ok(Some(AllocationDependency {
+            dependency_type,
+            scheduler_ids,
+        }))
+    }
+}
+
+/// Order schedule_nodes actions so that every action comes after the actions that submit
+/// the allocations it depends on. Otherwise, the original order is kept. Actions in a
+/// dependency cycle are moved to the end.
+pub fn order_by_allocation_dependencies(
+    actions: Vec<models::WorkflowActionModel>,
+) -> Vec<models::WorkflowActionModel> {
+    let scheduler_id = |action: &models::WorkflowActionModel| {
+        action
+            .action_config
+            .get("scheduler_id")
+            .and_then(|v| v.as_i64())
+    };
+    let depends_on = |action: &models::WorkflowActionModel| {
+        AllocationDependency::from_action_config(&action.action_config)
+            .ok()
+            .flatten()
+            .map(|dependency| dependency.scheduler_ids)
+            .unwrap_or_default()
+    };
+
+    let mut ordered = Vec::with_capacity(actions.len());
+    let mut remaining = actions;
+    while !remaining.is_empty() {
+        let unsubmitted: Vec<i64> = remaining.iter().filter_map(scheduler_id).collect();
+        let (ready, blocked): (Vec<_>, Vec<_>) = remaining.into_iter().partition(|action| {
+            let own_id = scheduler_id(action);
+            depends_on(action)
+                .iter()
+                .all(|id| Some(*id) == own_id || !unsubmitted.contains(id))
+        });
+        if ready.is_empty() {
+            ordered.extend(blocked);
+            break;
+        }
+        ordered.extend(ready);
+        remaining = blocked;
+    }
+    ordered
+}
+
 #[derive(Tabled)]
 struct SlurmSchedulerTableRow {
     #[tabled(rename = "ID")]
@@ -221,6 +349,9 @@ EXAMPLES:
 
     # Get Slurm accounting info
     torc slurm sacct 123
+
+    # Compare AU cost of allocations with the workflow's budget
+    torc slurm cost 123
 ")]
 pub enum SlurmCommands {
     /// Add a Slurm config to the database
@@ -358,12 +489,18 @@ EXAMPLES:
 
     # Keep submission scripts for debugging
     torc slurm schedule-nodes 123 --keep-submission-scripts --num-hpc-jobs 4
+
+    # Submit to a PBS Pro / Torque cluster
+    torc slurm schedule-nodes 123 --hpc-type pbs --num-hpc-jobs 2
 "
     )]
     ScheduleNodes {
         /// Workflow ID
         #[arg()]
         workflow_id: Option<i64>,
+        /// HPC scheduler to submit to (slurm, pbs, or fake)
+        #[arg(long, default_value = "slurm")]
+        hpc_type: HpcType,
         /// Job prefix for the Slurm job names
         #[arg(short, long, default_value = "worker")]
         job_prefix: String,
@@ -388,6 +525,9 @@ EXAMPLES:
         /// Start one worker per node
         #[arg(long, default_value = "false")]
         start_one_worker_per_node: bool,
+        /// Submit the HPC jobs as one Slurm job array (one sbatch call and queue entry)
+        #[arg(long, default_value = "false")]
+        job_array: bool,
     },
     /// Parse Slurm log files for known error messages
     #[command(hide = true)]
@@ -422,6 +562,26 @@ EXAMPLES:
         #[arg(long, default_value = "false")]
         save_json: bool,
     },
+    /// Show the AU cost of a workflow's allocations against its budget
+    #[command(
+        hide = true,
+        after_long_help = "\
+EXAMPLES:
+    torc slurm cost 123
+    torc slurm cost 123 --profile kestrel --sacct
+"
+    )]
+    Cost {
+        /// Workflow ID
+        #[arg()]
+        workflow_id: Option<i64>,
+        /// HPC profile that provides the charge factors (default: detect the current system)
+        #[arg(long)]
+        profile: Option<String>,
+        /// Compute actual costs from sacct instead of compute node durations
+        #[arg(long, default_value = "false")]
+        sacct: bool,
+    },
     /// Generate Slurm schedulers for a workflow based on job resource requirements
     #[command(
         hide = true,
@@ -438,6 +598,9 @@ EXAMPLES:
 
     # Group by partition instead of resource requirements
     torc slurm generate --account myproject --group-by partition workflow.yaml
+
+    # Submit all stages at workflow start, chained with Slurm dependencies
+    torc slurm generate --account myproject --dependency-chain afterok workflow.yaml
 "
     )]
     Generate {
@@ -496,6 +659,20 @@ EXAMPLES:
         #[arg(long)]
         no_actions: bool,
 
+        /// Submit the allocations of all stages when the workflow starts, chained with Slurm
+        /// dependencies
+        ///
+        /// By default, the allocations of later stages are submitted when their jobs become
+        /// ready. With this option, each stage's allocations are submitted upfront with
+        /// --dependency=<type>:<job IDs of the previous stage's allocations>, so that queue
+        /// wait overlaps the earlier stages.
+        ///
+        /// - afterok: Start after the previous allocations end successfully. Slurm cancels
+        ///   the chained allocations if one of them fails, e.g., by reaching its walltime.
+        /// - afterany: Start after the previous allocations end, regardless of how they end.
+        #[arg(long, value_enum, conflicts_with = "no_actions")]
+        dependency_chain: Option<SlurmDependencyType>,
+
         /// Overwrite existing schedulers in the workflow
         #[arg(long)]
         overwrite: bool,
@@ -518,6 +695,11 @@ EXAMPLES:
         #[arg()]
         workflow_id: i64,
 
+        /// HPC scheduler to submit to (slurm, pbs, or fake). Defaults to the scheduler type of
+        /// the workflow's existing allocations, or slurm if there are none.
+        #[arg(long)]
+        hpc_type: Option<HpcType>,
+
         /// Slurm account to use (defaults to account from existing schedulers)
         #[arg(short, long)]
         account: Option<String>,
@@ -749,6 +931,7 @@ pub fn generate_schedulers_for_workflow(
         scheduler_count: plan.schedulers.len(),
         action_count: plan.actions.len(),
         warnings,
+        cost_estimates: estimate_scheduler_plan(&plan, &ChargeFactors::from_profile(profile)),
     })
 }
 
@@ -757,6 +940,68 @@ pub struct GenerateResult {
     pub scheduler_count: usize,
     pub action_count: usize,
     pub warnings: Vec<String>,
+    /// Estimated AU cost of the allocations of each generated scheduler
+    pub cost_estimates: Vec<SchedulerCostEstimate>,
+}
+
+/// Chain the schedule_nodes actions of a workflow spec with Slurm dependencies.
+///
+/// Converts the on_jobs_ready schedule_nodes actions (e.g., the ones created by
+/// [`generate_schedulers_for_workflow`]) into on_workflow_start actions whose allocations
+/// depend on the allocations of the closest earlier stage of the workflow's
+/// [`ExecutionPlan`]. All allocations are then submitted when the workflow starts and wait in
+/// the queue while the earlier stages run.
+///
+/// Returns the number of chained actions.
+pub fn chain_scheduler_actions(
+    spec: &mut WorkflowSpec,
+    dependency_type: SlurmDependencyType,
+) -> Result<usize, String> {
+    // Actions reference expanded job names.
+    let mut expanded = spec.clone();
+    expanded
+        .expand_parameters()
+        .map_err(|e| format!("Failed to expand parameters: {}", e))?;
+    let plan = ExecutionPlan::from_spec(&expanded)
+        .map_err(|e| format!("Failed to build execution plan: {}", e))?;
+
+    // Map each scheduler to the schedulers of the closest earlier stage with allocations.
+    // A scheduler that is used in several stages is placed at the first one.
+    let mut upstream: HashMap<String, Vec<String>> = HashMap::new();
+    let mut previous: Vec<String> = Vec::new();
+    for stage in plan.to_stages() {
+        let mut current: Vec<String> = Vec::new();
+        for allocation in &stage.scheduler_allocations {
+            let name = &allocation.scheduler;
+            if upstream.contains_key(name) {
+                continue;
+            }
+            let depends_on = previous.iter().filter(|p| *p != name).cloned().collect();
+            upstream.insert(name.clone(), depends_on);
+            current.push(name.clone());
+        }
+        if !current.is_empty() {
+            previous = current;
+        }
+    }
+
+    let mut num_chained = 0;
+    for action in spec.actions.iter_mut().flatten() {
+        if action.action_type != "schedule_nodes" || action.trigger_type != "on_jobs_ready" {
+            continue;
+        }
+        let Some(depends_on) = action.scheduler.as_ref().and_then(|s| upstream.get(s)) else {
+            continue;
+        };
+        if depends_on.is_empty() {
+            continue;
+        }
+        action.trigger_type = "on_workflow_start".to_string();
+        action.depends_on_schedulers = Some(depends_on.clone());
+        action.dependency_type = Some(dependency_type.to_string());
+        num_chained += 1;
+    }
+    Ok(num_chained)
 }
 
 /// Parse memory string like "100g", "512m", "1024" (MB) into MB
@@ -1091,6 +1336,7 @@ pub fn handle_slurm_commands(config: &Configuration, command: &SlurmCommands, fo
         }
         SlurmCommands::ScheduleNodes {
             workflow_id,
+            hpc_type,
             job_prefix,
             keep_submission_scripts,
             max_parallel_jobs,
@@ -1099,6 +1345,7 @@ pub fn handle_slurm_commands(config: &Configuration, command: &SlurmCommands, fo
             poll_interval,
             scheduler_config_id,
             start_one_worker_per_node,
+            job_array,
         } => {
             let user_name = get_env_user_name();
             let wf_id = workflow_id.unwrap_or_else(|| {
@@ -1160,8 +1407,9 @@ pub fn handle_slurm_commands(config: &Configuration, command: &SlurmCommands, fo
                 })
             });
 
-            match schedule_slurm_nodes(
+            match schedule_hpc_nodes(
                 config,
+                *hpc_type,
                 wf_id,
                 sched_config_id,
                 *num_hpc_jobs,
@@ -1170,13 +1418,15 @@ pub fn handle_slurm_commands(config: &Configuration, command: &SlurmCommands, fo
                 *poll_interval,
                 *max_parallel_jobs,
                 *start_one_worker_per_node,
+                *job_array,
+                None,
                 *keep_submission_scripts,
             ) {
                 Ok(()) => {
-                    eprintln!("Successfully running {} Slurm job(s)", num_hpc_jobs);
+                    eprintln!("Successfully running {} {} job(s)", num_hpc_jobs, hpc_type);
                 }
                 Err(e) => {
-                    eprintln!("Error scheduling Slurm nodes: {}", e);
+                    eprintln!("Error scheduling {} nodes: {}", hpc_type, e);
                     std::process::exit(1);
                 }
             }
@@ -1209,6 +1459,20 @@ pub fn handle_slurm_commands(config: &Configuration, command: &SlurmCommands, fo
             });
             run_sacct_for_workflow(config, wf_id, output_dir, *save_json, format);
         }
+        SlurmCommands::Cost {
+            workflow_id,
+            profile,
+            sacct,
+        } => {
+            let user_name = get_env_user_name();
+            let wf_id = workflow_id.unwrap_or_else(|| {
+                select_workflow_interactively(config, &user_name).unwrap_or_else(|e| {
+                    eprintln!("Error selecting workflow: {}", e);
+                    std::process::exit(1);
+                })
+            });
+            run_cost_for_workflow(config, wf_id, profile.as_deref(), *sacct, format);
+        }
         SlurmCommands::Generate {
             workflow_file,
             account,
@@ -1219,6 +1483,7 @@ pub fn handle_slurm_commands(config: &Configuration, command: &SlurmCommands, fo
             walltime_strategy,
             walltime_multiplier,
             no_actions,
+            dependency_chain,
             overwrite,
             dry_run,
         } => {
@@ -1237,6 +1502,7 @@ pub fn handle_slurm_commands(config: &Configuration, command: &SlurmCommands, fo
                 *walltime_strategy,
                 *walltime_multiplier,
                 *no_actions,
+                *dependency_chain,
                 *overwrite,
                 *dry_run,
                 format,
@@ -1244,6 +1510,7 @@ pub fn handle_slurm_commands(config: &Configuration, command: &SlurmCommands, fo
         }
         SlurmCommands::Regenerate {
             workflow_id,
+            hpc_type,
             account,
             profile: profile_name,
             single_allocation,
@@ -1261,9 +1528,12 @@ pub fn handle_slurm_commands(config: &Configuration, command: &SlurmCommands, fo
                 eprintln!("Error: --walltime-multiplier must be greater than 0");
                 std::process::exit(1);
             }
+            let hpc_type =
+                hpc_type.unwrap_or_else(|| detect_workflow_hpc_type(config, *workflow_id));
             handle_regenerate(
                 config,
                 *workflow_id,
+                hpc_type,
                 account.as_deref(),
                 profile_name.as_deref(),
                 *single_allocation,
@@ -1313,6 +1583,80 @@ pub fn schedule_slurm_nodes(
     start_one_worker_per_node: bool,
     keep_submission_scripts: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    schedule_hpc_nodes(
+        config,
+        HpcType::Slurm,
+        workflow_id,
+        scheduler_config_id,
+        num_hpc_jobs,
+        job_prefix,
+        output,
+        poll_interval,
+        max_parallel_jobs,
+        start_one_worker_per_node,
+        false,
+        None,
+        keep_submission_scripts,
+    )
+}
+
+/// Determine which HPC scheduler a workflow's allocations were submitted to.
+///
+/// Looks at the workflow's scheduled compute nodes and returns the first HPC scheduler
+/// type found. Falls back to Slurm if the workflow has no HPC allocations yet.
+pub fn detect_workflow_hpc_type(config: &Configuration, workflow_id: i64) -> HpcType {
+    match paginate_scheduled_compute_nodes(
+        config,
+        workflow_id,
+        ScheduledComputeNodeListParams::new(),
+    ) {
+        Ok(nodes) => nodes
+            .iter()
+            .find_map(|node| HpcType::from_scheduler_type(&node.scheduler_type))
+            .unwrap_or(HpcType::Slurm),
+        Err(e) => {
+            warn!("Failed to list scheduled compute nodes: {}", e);
+            HpcType::Slurm
+        }
+    }
+}
+
+/// Schedule compute nodes for a workflow on any supported HPC scheduler
+///
+/// The scheduler configuration is read from the workflow's `slurm_schedulers` record
+/// and translated by the `HpcInterface` for `hpc_type` (e.g., `--time` becomes
+/// `-l walltime` for PBS). The created scheduled compute nodes record `hpc_type` as
+/// their scheduler type so that orphan detection can query the right scheduler.
+///
+/// With `use_job_array`, the allocations are submitted to Slurm as one job array instead of
+/// one job each. Every array task still gets its own scheduled compute node.
+///
+/// With `dependency`, the allocations stay queued until the allocations of the given
+/// schedulers end (`--dependency=afterok:<job_ids>` or `afterany`). Allocations that have
+/// already completed are not part of the dependency.
+#[allow(clippy::too_many_arguments)]
+pub fn schedule_hpc_nodes(
+    config: &Configuration,
+    hpc_type: HpcType,
+    workflow_id: i64,
+    scheduler_config_id: i64,
+    num_hpc_jobs: i32,
+    job_prefix: &str,
+    output: &str,
+    poll_interval: i32,
+    max_parallel_jobs: Option<i32>,
+    start_one_worker_per_node: bool,
+    use_job_array: bool,
+    dependency: Option<&AllocationDependency>,
+    keep_submission_scripts: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if use_job_array && hpc_type != HpcType::Slurm {
+        return Err(format!("Job arrays are not supported for {}", hpc_type).into());
+    }
+    if dependency.is_some() && hpc_type != HpcType::Slurm {
+        return Err(format!("Allocation dependencies are not supported for {}", hpc_type).into());
+    }
+
     let scheduler = match utils::send_with_retries(
         config,
         || default_api::get_slurm_scheduler(config, scheduler_config_id),
@@ -1336,10 +1680,10 @@ pub fn schedule_slurm_nodes(
         }
     };
 
-    let slurm_interface = match crate::client::hpc::slurm_interface::SlurmInterface::new() {
+    let hpc_interface = match create_hpc_interface(hpc_type) {
         Ok(interface) => interface,
         Err(e) => {
-            return Err(format!("Failed to create Slurm interface: {}", e).into());
+            return Err(format!("Failed to create {} interface: {}", hpc_type, e).into());
         }
     };
 
@@ -1389,17 +1733,76 @@ pub fn schedule_slurm_nodes(
         config_map.insert("extra".to_string(), extra.clone());
     }
 
+    // Refuse to submit allocations that could push the workflow over its AU budget
+    if let Some(budget) = workflow.au_budget {
+        let charge_factors = resolve_charge_factors(None);
+        let requested_au = AllocationShape::new(
+            scheduler.nodes,
+            &scheduler.walltime,
+            scheduler.gres.as_deref(),
+        )
+        .estimated_au(&charge_factors)?
+            * num_hpc_jobs as f64;
+        let allocations =
+            get_workflow_allocation_costs(config, workflow_id, &charge_factors, false)?;
+        let usage = AuUsage::from_allocations(&allocations);
+        check_au_budget(Some(budget), usage.committed_au, requested_au)?;
+        info!(
+            "Workflow {} AU budget check passed: {:.2} committed + {:.2} requested <= {:.2}",
+            workflow_id, usage.committed_au, requested_au, budget
+        );
+    }
+
+    if let Some(dependency) = dependency {
+        match resolve_allocation_dependency(config, workflow_id, dependency)? {
+            Some(slurm_dependency) => {
+                info!(
+                    "Allocations of scheduler {} depend on {}",
+                    scheduler_config_id, slurm_dependency
+                );
+                config_map.insert("dependency".to_string(), slurm_dependency);
+                // Cancel the allocations if the dependency can never be satisfied.
+                config_map.insert("kill_on_invalid_dep".to_string(), "yes".to_string());
+            }
+            None => {
+                info!(
+                    "Schedulers {:?} have no queued or running allocations; submitting \
+                     allocations of scheduler {} without a dependency",
+                    dependency.scheduler_ids, scheduler_config_id
+                );
+            }
+        }
+    }
+
     std::fs::create_dir_all(output)?;
 
+    if use_job_array && num_hpc_jobs > 1 {
+        return submit_hpc_job_array(
+            config,
+            hpc_interface.as_ref(),
+            hpc_type,
+            workflow_id,
+            scheduler_config_id,
+            num_hpc_jobs,
+            job_prefix,
+            output,
+            poll_interval,
+            max_parallel_jobs,
+            start_one_worker_per_node,
+            keep_submission_scripts,
+            config_map,
+        );
+    }
+
     for _job_num in 1..num_hpc_jobs + 1 {
-        // Create the scheduled compute node record first so we can use its ID in the Slurm job name.
-        // This allows us to correlate the Slurm job with the scheduled compute node record.
-        // We use scheduler_id=0 as a placeholder since we don't have the Slurm job ID yet.
+        // Create the scheduled compute node record first so we can use its ID in the HPC job name.
+        // This allows us to correlate the HPC job with the scheduled compute node record.
+        // We use scheduler_id=0 as a placeholder since we don't have the HPC job ID yet.
         let scheduled_compute_node = models::ScheduledComputeNodesModel::new(
             workflow_id,
             0, // Placeholder - will be updated after submission
             scheduler_config_id,
-            "slurm".to_string(),
+            hpc_type.to_string(),
             "pending".to_string(),
         );
         let created_scn = match utils::send_with_retries(
@@ -1421,7 +1824,7 @@ pub fn schedule_slurm_nodes(
         let job_name = format!("{}_{}", job_prefix, scn_id);
         let script_path = format!("{}/{}.sh", output, job_name);
 
-        if let Err(e) = slurm_interface.create_submission_script(
+        if let Err(e) = hpc_interface.create_submission_script(
             &job_name,
             &config.base_path,
             workflow_id,
@@ -1447,7 +1850,7 @@ pub fn schedule_slurm_nodes(
             return Err(e.into());
         }
 
-        match slurm_interface.submit(Path::new(&script_path)) {
+        match hpc_interface.submit(Path::new(&script_path)) {
             Ok((return_code, slurm_job_id, stderr)) => {
                 if return_code != 0 {
                     error!("Error submitting job: {}", stderr);
@@ -1464,12 +1867,12 @@ pub fn schedule_slurm_nodes(
                     }
                     return Err(format!("Job submission failed: {}", stderr).into());
                 }
-                let slurm_job_id_int: i64 = slurm_job_id
-                    .parse()
-                    .unwrap_or_else(|_| panic!("Failed to parse Slurm job ID {}", slurm_job_id));
+                let slurm_job_id_int: i64 = slurm_job_id.parse().unwrap_or_else(|_| {
+                    panic!("Failed to parse {} job ID {}", hpc_type, slurm_job_id)
+                });
                 info!(
-                    "Submitted Slurm job name={} with ID={} (scheduled_compute_node_id={})",
-                    job_name, slurm_job_id_int, scn_id
+                    "Submitted {} job name={} with ID={} (scheduled_compute_node_id={})",
+                    hpc_type, job_name, slurm_job_id_int, scn_id
                 );
 
                 // Update the scheduled compute node with the actual Slurm job ID
@@ -1487,8 +1890,8 @@ pub fn schedule_slurm_nodes(
                     WAIT_FOR_HEALTHY_DATABASE_MINUTES,
                 ) {
                     error!(
-                        "Failed to update scheduled compute node with Slurm job ID: {}",
-                        e
+                        "Failed to update scheduled compute node with {} job ID: {}",
+                        hpc_type, e
                     );
                 }
 
@@ -1519,17 +1922,190 @@ pub fn schedule_slurm_nodes(
     Ok(())
 }
 
-/// Create a ComputeNodesResources instance by reading information from the Slurm environment
+/// Build the Slurm `--dependency` value for allocations that must wait for the queued and
+/// running allocations of the schedulers in `dependency`.
+///
+/// Returns None if none of those schedulers has such allocations. The tasks of a job array
+/// share one entry.
+fn resolve_allocation_dependency(
+    config: &Configuration,
+    workflow_id: i64,
+    dependency: &AllocationDependency,
+) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let nodes = paginate_scheduled_compute_nodes(
+        config,
+        workflow_id,
+        ScheduledComputeNodeListParams::new(),
+    )
+    .map_err(|e| format!("Failed to list scheduled compute nodes: {}", e))?;
+    let mut job_ids: Vec<i64> = nodes
+        .iter()
+        .filter(|node| {
+            node.scheduler_type == "slurm"
+                && node.status != "complete"
+                && node.scheduler_id > 0
+                && dependency.scheduler_ids.contains(&node.scheduler_config_id)
+        })
+        .map(|node| node.scheduler_id)
+        .collect();
+    job_ids.sort_unstable();
+    job_ids.dedup();
+    if job_ids.is_empty() {
+        return Ok(None);
+    }
+    let job_ids: Vec<String> = job_ids.iter().map(|id| id.to_string()).collect();
+    Ok(Some(format!(
+        "{}:{}",
+        dependency.dependency_type,
+        job_ids.join(":")
+    )))
+}
+
+/// Submit `num_tasks` identical allocations as one job array.
+///
+/// A scheduled compute node is created for each array task before submission. After
+/// submission, all of them record the array's job ID as their `scheduler_id` and the task
+/// index as their `array_task_id`. If the submission fails, the records are deleted.
+#[allow(clippy::too_many_arguments)]
+fn submit_hpc_job_array(
+    config: &Configuration,
+    hpc_interface: &dyn HpcInterface,
+    hpc_type: HpcType,
+    workflow_id: i64,
+    scheduler_config_id: i64,
+    num_tasks: i32,
+    job_prefix: &str,
+    output: &str,
+    poll_interval: i32,
+    max_parallel_jobs: Option<i32>,
+    start_one_worker_per_node: bool,
+    keep_submission_scripts: bool,
+    mut config_map: HashMap<String, String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let delete_records = |records: &[models::ScheduledComputeNodesModel], reason: &str| {
+        for scn_id in records.iter().filter_map(|scn| scn.id) {
+            if let Err(e) = utils::send_with_retries(
+                config,
+                || default_api::delete_scheduled_compute_node(config, scn_id, None),
+                WAIT_FOR_HEALTHY_DATABASE_MINUTES,
+            ) {
+                error!(
+                    "Failed to delete scheduled compute node {} after {}: {}",
+                    scn_id, reason, e
+                );
+            }
+        }
+    };
+
+    let mut created_scns = Vec::with_capacity(num_tasks as usize);
+    for task_id in 0..num_tasks {
+        let mut scheduled_compute_node = models::ScheduledComputeNodesModel::new(
+            workflow_id,
+            0, // Placeholder - will be updated after submission
+            scheduler_config_id,
+            hpc_type.to_string(),
+            "pending".to_string(),
+        );
+        scheduled_compute_node.array_task_id = Some(task_id as i64);
+        match utils::send_with_retries(
+            config,
+            || default_api::create_scheduled_compute_node(config, scheduled_compute_node.clone()),
+            WAIT_FOR_HEALTHY_DATABASE_MINUTES,
+        ) {
+            Ok(scn) => created_scns.push(scn),
+            Err(e) => {
+                error!("Failed to create scheduled compute node: {}", e);
+                delete_records(&created_scns, "record creation failure");
+                return Err(format!("Failed to create scheduled compute node: {}", e).into());
+            }
+        }
+    }
+
+    // Name the array after the scheduled compute node of its first task
+    let first_scn_id = created_scns[0]
+        .id
+        .expect("Created scheduled compute node should have an ID");
+    let job_name = format!("{}_{}", job_prefix, first_scn_id);
+    let script_path = format!("{}/{}.sh", output, job_name);
+    config_map.insert("array".to_string(), format!("0-{}", num_tasks - 1));
+
+    if let Err(e) = hpc_interface.create_submission_script(
+        &job_name,
+        &config.base_path,
+        workflow_id,
+        output,
+        poll_interval,
+        max_parallel_jobs,
+        Path::new(&script_path),
+        &config_map,
+        start_one_worker_per_node,
+    ) {
+        error!("Error creating submission script: {}", e);
+        delete_records(&created_scns, "script creation failure");
+        return Err(e.into());
+    }
+
+    let array_job_id = match hpc_interface.submit(Path::new(&script_path)) {
+        Ok((0, job_id, _)) => job_id,
+        Ok((_, _, stderr)) => {
+            error!("Error submitting job array: {}", stderr);
+            delete_records(&created_scns, "submission failure");
+            return Err(format!("Job submission failed: {}", stderr).into());
+        }
+        Err(e) => {
+            error!("Error submitting job array: {}", e);
+            delete_records(&created_scns, "submission error");
+            return Err(e.into());
+        }
+    };
+    let array_job_id_int: i64 = array_job_id
+        .parse()
+        .unwrap_or_else(|_| panic!("Failed to parse {} job ID {}", hpc_type, array_job_id));
+    info!(
+        "Submitted {} job array name={} with ID={} and {} tasks",
+        hpc_type, job_name, array_job_id_int, num_tasks
+    );
+
+    // Update the scheduled compute nodes with the array's job ID
+    for created_scn in &created_scns {
+        let scn_id = created_scn
+            .id
+            .expect("Created scheduled compute node should have an ID");
+        let mut updated_scn = created_scn.clone();
+        updated_scn.scheduler_id = array_job_id_int;
+        if let Err(e) = utils::send_with_retries(
+            config,
+            || default_api::update_scheduled_compute_node(config, scn_id, updated_scn.clone()),
+            WAIT_FOR_HEALTHY_DATABASE_MINUTES,
+        ) {
+            error!(
+                "Failed to update scheduled compute node {} with {} job ID {}: {}",
+                scn_id,
+                hpc_type,
+                updated_scn.hpc_job_id(),
+                e
+            );
+        }
+    }
+
+    if !keep_submission_scripts && let Err(e) = std::fs::remove_file(&script_path) {
+        error!("Failed to remove submission script: {}", e);
+    }
+
+    Ok(())
+}
+
+/// Create a ComputeNodesResources instance by reading information from the HPC environment
 ///
 /// # Arguments
-/// * `interface` - SlurmInterface instance to query for system resources
+/// * `interface` - HPC interface (Slurm, PBS) to query for system resources
 /// * `scheduler_config_id` - The scheduler configuration ID to use
 /// * `is_subtask` - If true, use CPUs per task instead of CPUs per node
 ///
 /// # Returns
-/// A ComputeNodesResources instance populated with Slurm environment data
+/// A ComputeNodesResources instance populated with HPC environment data
 pub fn create_node_resources(
-    interface: &crate::client::hpc::slurm_interface::SlurmInterface,
+    interface: &dyn HpcInterface,
     scheduler_config_id: Option<i64>,
     is_subtask: bool,
 ) -> models::ComputeNodesResources {
@@ -1840,7 +2416,8 @@ fn extract_node_from_line(line: &str) -> Option<String> {
 /// Returns (workflow_id, slurm_job_id) if successful
 fn extract_slurm_job_id_from_filename(filename: &str) -> Option<(i64, String)> {
     // Pattern: slurm_output_wf1234_sl12345.o or slurm_output_wf1234_sl12345.e
-    let re = Regex::new(r"slurm_output_wf(\d+)_sl(\d+)\.[oe]$").ok()?;
+    // Job array tasks: slurm_output_wf1234_sl12345_7.o
+    let re = Regex::new(r"slurm_output_wf(\d+)_sl(\d+(?:_\d+)?)\.[oe]$").ok()?;
     re.captures(filename).and_then(|caps| {
         let wf_id = caps.get(1)?.as_str().parse::<i64>().ok()?;
         let slurm_id = caps.get(2)?.as_str().to_string();
@@ -1880,7 +2457,7 @@ fn build_slurm_to_jobs_map(
     let scn_to_slurm: HashMap<i64, String> = scheduled_nodes
         .iter()
         .filter(|scn| scn.scheduler_type == "slurm")
-        .filter_map(|scn| scn.id.map(|id| (id, scn.scheduler_id.to_string())))
+        .filter_map(|scn| scn.id.map(|id| (id, scn.hpc_job_id())))
         .collect();
 
     if scn_to_slurm.is_empty() {
@@ -2026,10 +2603,8 @@ pub fn parse_slurm_logs(
         .collect();
 
     // Build set of valid Slurm job IDs for this workflow
-    let valid_slurm_job_ids: std::collections::HashSet<String> = scheduled_nodes
-        .iter()
-        .map(|n| n.scheduler_id.to_string())
-        .collect();
+    let valid_slurm_job_ids: std::collections::HashSet<String> =
+        scheduled_nodes.iter().map(|n| n.hpc_job_id()).collect();
 
     if valid_slurm_job_ids.is_empty() {
         if format == "json" {
@@ -2407,7 +2982,7 @@ fn parse_sacct_json_to_rows(
                 .to_string();
 
             rows.push(SacctSummaryRow {
-                slurm_job_id: slurm_job_id.to_string(),
+                slurm_job_id: sacct_hpc_job_id(job).unwrap_or_else(|| slurm_job_id.to_string()),
                 job_step,
                 state,
                 exit_code,
@@ -2422,6 +2997,31 @@ fn parse_sacct_json_to_rows(
     rows
 }
 
+/// Return the HPC job ID of a job record in `sacct --json` output.
+///
+/// Tasks of a job array are identified as `<array_job_id>_<array_task_id>`. Depending on the
+/// Slurm version, the task ID is a number or an object with a `number` field.
+pub fn sacct_hpc_job_id(job: &serde_json::Value) -> Option<String> {
+    let array = job.get("array");
+    let array_job_id = array
+        .and_then(|a| a.get("job_id"))
+        .and_then(|id| id.as_i64())
+        .filter(|id| *id != 0);
+    let array_task_id = array.and_then(|a| a.get("task_id")).and_then(|task| {
+        task.as_i64().or_else(|| {
+            let set = task.get("set").and_then(|s| s.as_bool()).unwrap_or(true);
+            task.get("number").and_then(|n| n.as_i64()).filter(|_| set)
+        })
+    });
+    match (array_job_id, array_task_id) {
+        (Some(job_id), Some(task_id)) => Some(format!("{}_{}", job_id, task_id)),
+        _ => job
+            .get("job_id")
+            .and_then(|id| id.as_i64())
+            .map(|id| id.to_string()),
+    }
+}
+
 /// Format duration in seconds to human-readable format
 fn format_duration_seconds(secs: i64) -> String {
     if secs < 60 {
@@ -2502,9 +3102,11 @@ pub fn run_sacct_for_workflow(
 
     let mut all_summary_rows: Vec<SacctSummaryRow> = Vec::new();
     let mut errors: Vec<String> = Vec::new();
+    let charge_factors = resolve_charge_factors(None);
+    let mut total_au_consumed = 0.0;
 
     for node in &nodes {
-        let slurm_job_id = node.scheduler_id.to_string();
+        let slurm_job_id = node.hpc_job_id();
 
         info!("Running sacct for Slurm job ID: {}", slurm_job_id);
 
@@ -2524,6 +3126,7 @@ pub fn run_sacct_for_workflow(
                             // Extract summary rows
                             let rows = parse_sacct_json_to_rows(&sacct_json, &slurm_job_id);
                             all_summary_rows.extend(rows);
+                            total_au_consumed += sacct_au(&sacct_json, &charge_factors);
 
                             // Optionally save full JSON to file
                             if save_json {
@@ -2570,6 +3173,7 @@ pub fn run_sacct_for_workflow(
         let output = serde_json::json!({
             "workflow_id": workflow_id,
             "total_slurm_jobs": nodes.len(),
+            "total_au": total_au_consumed,
             "summary": all_summary_rows,
             "errors": errors,
         });
@@ -2585,6 +3189,7 @@ pub fn run_sacct_for_workflow(
 
         if !all_summary_rows.is_empty() {
             display_table_with_count(&all_summary_rows, "job steps");
+            println!("\nTotal AU consumed: {:.2}", total_au_consumed);
         }
 
         if !errors.is_empty() {
@@ -2600,6 +3205,165 @@ pub fn run_sacct_for_workflow(
     }
 }
 
+/// Run `sacct --json` for one HPC job and return its AU cost
+fn sacct_au_for_job(slurm_job_id: &str, charge_factors: &ChargeFactors) -> Result<f64, String> {
+    let output = Command::new("sacct")
+        .args(["-j", slurm_job_id, "--json"])
+        .output()
+        .map_err(|e| format!("Failed to execute sacct: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "sacct failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    let sacct_json: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Invalid sacct JSON output: {}", e))?;
+    Ok(sacct_au(&sacct_json, charge_factors))
+}
+
+/// Get the estimated and actual AU cost of each HPC allocation submitted for a workflow.
+///
+/// Estimates assume that allocations run for the full walltime of their scheduler. Actual
+/// costs come from the compute nodes that ran in the allocations, or from `sacct` if
+/// `use_sacct` is true. Returns an error if the walltime of an allocation's scheduler cannot be
+/// parsed, so that budget checks never count such allocations as free.
+pub fn get_workflow_allocation_costs(
+    config: &Configuration,
+    workflow_id: i64,
+    charge_factors: &ChargeFactors,
+    use_sacct: bool,
+) -> Result<Vec<AllocationCost>, String> {
+    let scheduled_nodes = paginate_scheduled_compute_nodes(
+        config,
+        workflow_id,
+        ScheduledComputeNodeListParams::new(),
+    )
+    .map_err(|e| format!("Failed to list scheduled compute nodes: {}", e))?;
+    if scheduled_nodes.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let estimates: HashMap<i64, Result<f64, String>> =
+        paginate_slurm_schedulers(config, workflow_id, SlurmSchedulersListParams::new())
+            .map_err(|e| format!("Failed to list Slurm schedulers: {}", e))?
+            .iter()
+            .filter_map(|s| {
+                let shape = AllocationShape::new(s.nodes, &s.walltime, s.gres.as_deref());
+                Some((s.id?, shape.estimated_au(charge_factors)))
+            })
+            .collect();
+
+    let mut actual_costs = if use_sacct {
+        HashMap::new()
+    } else {
+        let compute_nodes =
+            paginate_compute_nodes(config, workflow_id, ComputeNodeListParams::new())
+                .map_err(|e| format!("Failed to list compute nodes: {}", e))?;
+        compute_node_au(&compute_nodes, charge_factors)
+    };
+
+    let mut allocations = Vec::new();
+    for node in &scheduled_nodes {
+        let hpc_job_id = node.hpc_job_id();
+        // Allocations still being submitted have no HPC job ID yet.
+        if use_sacct && node.scheduler_id != 0 {
+            match sacct_au_for_job(&hpc_job_id, charge_factors) {
+                Ok(au) => {
+                    actual_costs.insert(hpc_job_id.clone(), au);
+                }
+                Err(e) => warn!("Failed to get sacct data for job {}: {}", hpc_job_id, e),
+            }
+        }
+        let estimated_au = match estimates.get(&node.scheduler_config_id) {
+            Some(Ok(au)) => *au,
+            Some(Err(e)) => {
+                return Err(format!(
+                    "Cannot estimate the AU cost of allocation {} of scheduler {}: {}",
+                    hpc_job_id, node.scheduler_config_id, e
+                ));
+            }
+            None => 0.0,
+        };
+        allocations.push(AllocationCost {
+            actual_au: actual_costs.get(&hpc_job_id).copied(),
+            hpc_job_id,
+            scheduler_config_id: node.scheduler_config_id,
+            status: node.status.clone(),
+            estimated_au,
+        });
+    }
+    Ok(allocations)
+}
+
+/// Report the AU cost of a workflow's HPC allocations against its budget
+pub fn run_cost_for_workflow(
+    config: &Configuration,
+    workflow_id: i64,
+    profile_name: Option<&str>,
+    use_sacct: bool,
+    format: &str,
+) {
+    let workflow = match default_api::get_workflow(config, workflow_id) {
+        Ok(w) => w,
+        Err(e) => {
+            print_error("getting workflow", &e);
+            std::process::exit(1);
+        }
+    };
+    let charge_factors = resolve_charge_factors(profile_name);
+    let allocations =
+        match get_workflow_allocation_costs(config, workflow_id, &charge_factors, use_sacct) {
+            Ok(a) => a,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        };
+    let usage = AuUsage::from_allocations(&allocations);
+    let remaining_au = workflow.au_budget.map(|budget| budget - usage.committed_au);
+
+    if format == "json" {
+        print_json(
+            &serde_json::json!({
+                "workflow_id": workflow_id,
+                "charge_factors": charge_factors,
+                "au_budget": workflow.au_budget,
+                "num_allocations": usage.num_allocations,
+                "consumed_au": usage.consumed_au,
+                "committed_au": usage.committed_au,
+                "remaining_au": remaining_au,
+                "allocations": allocations,
+            }),
+            "Slurm cost",
+        );
+        return;
+    }
+
+    println!("AU Cost for Workflow {}\n", workflow_id);
+    if allocations.is_empty() {
+        println!("No HPC allocations have been submitted");
+    } else {
+        display_table_with_count(&allocations, "allocations");
+    }
+    println!();
+    println!(
+        "Charge factors: {:.2} AU per node-hour (CPU), {:.2} AU per node-hour (GPU)",
+        charge_factors.cpu, charge_factors.gpu
+    );
+    println!("Consumed:  {:.2} AU", usage.consumed_au);
+    println!("Committed: {:.2} AU", usage.committed_au);
+    match (workflow.au_budget, remaining_au) {
+        (Some(budget), Some(remaining)) => {
+            println!(
+                "Budget:    {:.2} AU ({:.2} AU remaining)",
+                budget, remaining
+            )
+        }
+        _ => println!("Budget:    none"),
+    }
+}
+
 /// Handle the generate command - generates Slurm schedulers for a workflow
 #[allow(clippy::too_many_arguments)]
 fn handle_generate(
@@ -2612,6 +3376,7 @@ fn handle_generate(
     walltime_strategy: WalltimeStrategy,
     walltime_multiplier: f64,
     no_actions: bool,
+    dependency_chain: Option<SlurmDependencyType>,
     force: bool,
     dry_run: bool,
     format: &str,
@@ -2687,6 +3452,13 @@ fn handle_generate(
         }
     };
 
+    if let Some(dependency_type) = dependency_chain
+        && let Err(e) = chain_scheduler_actions(&mut spec, dependency_type)
+    {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+
     // In dry run mode, show what would be generated without writing to output
     if dry_run {
         #[derive(Serialize)]
@@ -2698,6 +3470,8 @@ fn handle_generate(
             profile_display_name: &'a str,
             slurm_schedulers: &'a Option<Vec<crate::client::workflow_spec::SlurmSchedulerSpec>>,
             actions: &'a Option<Vec<crate::client::workflow_spec::WorkflowActionSpec>>,
+            cost_estimates: &'a [SchedulerCostEstimate],
+            total_estimated_au: f64,
             warnings: &'a [String],
         }
 
@@ -2709,6 +3483,8 @@ fn handle_generate(
             profile_display_name: &profile.display_name,
             slurm_schedulers: &spec.slurm_schedulers,
             actions: &spec.actions,
+            cost_estimates: &result.cost_estimates,
+            total_estimated_au: total_au(&result.cost_estimates),
             warnings: &result.warnings,
         };
 
@@ -2736,6 +3512,23 @@ fn handle_generate(
                     result.action_count
                 );
             }
+            if !result.cost_estimates.is_empty() {
+                println!();
+                println!("Estimated cost (each allocation runs for its full walltime):");
+                display_table_with_count(&result.cost_estimates, "schedulers");
+                println!(
+                    "Total estimated cost: {:.2} AU",
+                    dry_run_result.total_estimated_au
+                );
+                if let Some(budget) = spec.au_budget
+                    && dry_run_result.total_estimated_au > budget
+                {
+                    println!(
+                        "Warning: the estimated cost exceeds the workflow's AU budget of {:.2}",
+                        budget
+                    );
+                }
+            }
             println!();
             println!("Profile: {} ({})", profile.display_name, profile.name);
 
@@ -2889,6 +3682,9 @@ pub struct PlannedSchedulerInfo {
     pub job_count: usize,
     pub job_names: Vec<String>,
     pub has_dependencies: bool,
+    /// Estimated AU cost of all allocations of the scheduler
+    #[serde(default)]
+    pub estimated_au: f64,
 }
 
 /// Dry run result for regenerate command
@@ -2901,6 +3697,8 @@ pub struct RegenerateDryRunResult {
     pub profile_display_name: String,
     pub planned_schedulers: Vec<PlannedSchedulerInfo>,
     pub total_allocations: i64,
+    #[serde(default)]
+    pub total_estimated_au: f64,
     pub would_submit: bool,
     pub warnings: Vec<String>,
 }
@@ -2927,6 +3725,7 @@ pub struct SchedulerInfo {
 fn handle_regenerate(
     config: &Configuration,
     workflow_id: i64,
+    hpc_type: HpcType,
     account: Option<&str>,
     profile_name: Option<&str>,
     single_allocation: bool,
@@ -3021,6 +3820,7 @@ fn handle_regenerate(
                         profile_display_name: profile.display_name.clone(),
                         planned_schedulers: Vec::new(),
                         total_allocations: 0,
+                        total_estimated_au: 0.0,
                         would_submit: submit,
                         warnings: vec!["No pending jobs found".to_string()],
                     },
@@ -3197,6 +3997,7 @@ fn handle_regenerate(
                         profile_display_name: profile.display_name.clone(),
                         planned_schedulers: Vec::new(),
                         total_allocations: 0,
+                        total_estimated_au: 0.0,
                         would_submit: submit,
                         warnings: warnings.clone(),
                     },
@@ -3229,6 +4030,7 @@ fn handle_regenerate(
     // In dry run mode, show what would be created without making changes
     if dry_run {
         // Build planned scheduler info without IDs (since nothing is created)
+        let charge_factors = ChargeFactors::from_profile(profile);
         let planned_schedulers: Vec<PlannedSchedulerInfo> = plan
             .schedulers
             .iter()
@@ -3243,10 +4045,14 @@ fn handle_regenerate(
                 job_count: p.job_count,
                 job_names: p.job_names.clone(),
                 has_dependencies: p.has_dependencies,
+                estimated_au: AllocationShape::new(p.nodes, &p.walltime, p.gres.as_deref())
+                    .estimated_au(&charge_factors)
+                    .map_or(0.0, |au| au * p.num_allocations as f64),
             })
             .collect();
 
         let total_allocations: i64 = plan.schedulers.iter().map(|p| p.num_allocations).sum();
+        let total_estimated_au: f64 = planned_schedulers.iter().map(|p| p.estimated_au).sum();
 
         let dry_run_result = RegenerateDryRunResult {
             dry_run: true,
@@ -3256,6 +4062,7 @@ fn handle_regenerate(
             profile_display_name: profile.display_name.clone(),
             planned_schedulers,
             total_allocations,
+            total_estimated_au,
             would_submit: submit,
             warnings: warnings.clone(),
         };
@@ -3285,9 +4092,14 @@ fn handle_regenerate(
                 if let Some(mem) = &sched.mem {
                     println!("    Memory: {}", mem);
                 }
+                println!("    Estimated cost: {:.2} AU", sched.estimated_au);
             }
             println!();
             println!("Total allocations: {}", dry_run_result.total_allocations);
+            println!(
+                "Total estimated cost: {:.2} AU",
+                dry_run_result.total_estimated_au
+            );
             if submit {
                 println!("[DRY RUN] Would submit allocations immediately");
             }
@@ -3428,7 +4240,7 @@ fn handle_regenerate(
         }
 
         let action_config = serde_json::json!({
-            "scheduler_type": "slurm",
+            "scheduler_type": hpc_type.to_string(),
             "scheduler_id": scheduler_id,
             "num_allocations": action.num_allocations,
             "start_one_worker_per_node": start_one_worker_per_node,
@@ -3495,8 +4307,9 @@ fn handle_regenerate(
 
             let start_one_worker_per_node = scheduler_info.nodes > 1;
 
-            match schedule_slurm_nodes(
+            match schedule_hpc_nodes(
                 config,
+                hpc_type,
                 workflow_id,
                 scheduler_info.id,
                 scheduler_info.num_allocations as i32,
@@ -3505,6 +4318,8 @@ fn handle_regenerate(
                 poll_interval,
                 None, // max_parallel_jobs
                 start_one_worker_per_node,
+                false, // use_job_array
+                None,  // dependency
                 false, // keep_submission_scripts
             ) {
                 Ok(()) => {
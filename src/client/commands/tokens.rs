@@ -0,0 +1,168 @@
+//! API token management commands for bearer authentication
+
+use crate::client::apis::configuration::Configuration;
+use crate::client::apis::default_api;
+use crate::client::commands::output::{print_if_json, print_json_wrapped};
+use crate::client::commands::table_format::display_table_with_count;
+use crate::models::ApiTokenModel;
+use tabled::Tabled;
+
+// ============================================================================
+// Table display types
+// ============================================================================
+
+#[derive(Tabled)]
+struct TokenTableRow {
+    #[tabled(rename = "ID")]
+    id: i64,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Owner")]
+    owner: String,
+    #[tabled(rename = "Scopes")]
+    scopes: String,
+    #[tabled(rename = "Created")]
+    created_at: String,
+    #[tabled(rename = "Expires")]
+    expires_at: String,
+    #[tabled(rename = "Revoked")]
+    revoked: bool,
+}
+
+// ============================================================================
+// CLI Command definitions
+// ============================================================================
+
+#[derive(clap::Subcommand)]
+pub enum TokenCommands {
+    /// Create an API token. The token is only displayed once; store it securely.
+    #[command(after_long_help = "\
+EXAMPLES:
+    torc tokens create slurm-runner --expires-in-days 30
+    torc tokens create ci --scopes read
+    export TORC_API_TOKEN=<token>
+")]
+    Create {
+        /// Descriptive name of the token
+        #[arg()]
+        name: String,
+        /// User that the token authenticates as (admin only; defaults to the current user)
+        #[arg(long)]
+        owner: Option<String>,
+        /// Comma-separated scopes: read (GET requests) and/or write (all other requests)
+        #[arg(long, value_delimiter = ',', default_value = "read,write")]
+        scopes: Vec<String>,
+        /// Number of days until the token expires. Tokens never expire if not set.
+        #[arg(long)]
+        expires_in_days: Option<i64>,
+    },
+    /// List API tokens. Non-administrators only see their own tokens.
+    List {
+        /// Maximum number of tokens to return
+        #[arg(short, long, default_value = "100")]
+        limit: i64,
+        /// Offset for pagination
+        #[arg(short, long, default_value = "0")]
+        offset: i64,
+    },
+    /// Revoke an API token
+    Revoke {
+        /// ID of the token to revoke
+        #[arg()]
+        id: i64,
+    },
+}
+
+// ============================================================================
+// Command handler
+// ============================================================================
+
+pub fn handle_token_commands(config: &Configuration, command: &TokenCommands, format: &str) {
+    match command {
+        TokenCommands::Create {
+            name,
+            owner,
+            scopes,
+            expires_in_days,
+        } => {
+            let mut token = ApiTokenModel::new(name.clone());
+            token.owner = owner.clone();
+            token.scopes = Some(scopes.clone());
+            token.expires_at = expires_in_days.map(|days| {
+                (chrono::Utc::now() + chrono::Duration::days(days))
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            });
+
+            match default_api::create_api_token(config, token) {
+                Ok(token) => {
+                    if print_if_json(format, &token, "token") {
+                        // JSON was printed
+                    } else {
+                        println!("Successfully created API token:");
+                        println!("  ID: {}", token.id.unwrap_or(-1));
+                        println!("  Name: {}", token.name);
+                        println!("  Owner: {}", token.owner.unwrap_or_default());
+                        println!("  Scopes: {}", token.scopes.unwrap_or_default().join(","));
+                        if let Some(expires_at) = &token.expires_at {
+                            println!("  Expires: {} UTC", expires_at);
+                        }
+                        println!("  Token: {}", token.token.unwrap_or_default());
+                        println!();
+                        println!("This token will not be shown again. Set it with:");
+                        println!("  export TORC_API_TOKEN=<token>");
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Error creating API token: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        TokenCommands::List { limit, offset } => {
+            match default_api::list_api_tokens(config, Some(*offset), Some(*limit)) {
+                Ok(response) => {
+                    if format == "json" {
+                        print_json_wrapped("tokens", &response.items, "tokens");
+                    } else if response.items.is_empty() {
+                        println!("No API tokens found");
+                    } else {
+                        let rows: Vec<TokenTableRow> = response
+                            .items
+                            .iter()
+                            .map(|t| TokenTableRow {
+                                id: t.id.unwrap_or(-1),
+                                name: t.name.clone(),
+                                owner: t.owner.clone().unwrap_or_default(),
+                                scopes: t.scopes.clone().unwrap_or_default().join(","),
+                                created_at: t.created_at.clone().unwrap_or_default(),
+                                expires_at: t.expires_at.clone().unwrap_or_default(),
+                                revoked: t.revoked.unwrap_or(false),
+                            })
+                            .collect();
+                        display_table_with_count(&rows, "API tokens");
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Error listing API tokens: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        TokenCommands::Revoke { id } => match default_api::revoke_api_token(config, *id) {
+            Ok(token) => {
+                if print_if_json(format, &token, "token") {
+                    // JSON was printed
+                } else {
+                    println!("Successfully revoked API token:");
+                    println!("  ID: {}", token.id.unwrap_or(-1));
+                    println!("  Name: {}", token.name);
+                }
+            }
+            Err(e) => {
+                eprintln!("Error revoking API token: {}", e);
+                std::process::exit(1);
+            }
+        },
+    }
+}
@@ -20,7 +20,7 @@ use super::recover::{
 use crate::client::report_models::ResourceUtilizationReport;
 
 // Use shared orphan detection logic
-use super::orphan_detection::cleanup_orphaned_jobs;
+use super::orphan_detection::{HpcInterfaceCache, cleanup_orphaned_jobs};
 // Re-export for backwards compatibility
 pub use super::orphan_detection::ORPHANED_JOB_RETURN_CODE;
 
@@ -38,8 +38,6 @@ where
 }
 use crate::client::commands::pagination::{JobListParams, paginate_jobs};
 use crate::client::hpc::common::HpcJobStatus;
-use crate::client::hpc::hpc_interface::HpcInterface;
-use crate::client::hpc::slurm_interface::SlurmInterface;
 use crate::client::log_paths::get_watch_log_file;
 
 // Note: ORPHANED_JOB_RETURN_CODE is now imported from orphan_detection module
@@ -204,13 +202,15 @@ fn has_any_scheduled_compute_nodes(config: &Configuration, workflow_id: i64) ->
     false
 }
 
-/// Check if there is at least one valid Slurm allocation (pending or running in Slurm).
+/// Check if there is at least one valid HPC allocation (pending or running in Slurm or PBS).
 ///
 /// This is used to optimize the poll loop: if we have valid allocations, we can skip
 /// the expensive per-allocation orphan detection and just sleep.
 ///
-/// Returns true if at least one Slurm allocation is still valid (queued or running).
+/// Returns true if at least one HPC allocation is still valid (queued or running).
 fn has_valid_slurm_allocation(config: &Configuration, workflow_id: i64) -> bool {
+    let mut interfaces = HpcInterfaceCache::default();
+
     // Get scheduled compute nodes with status="pending" or "active"
     // We'll sample one from each category to check
 
@@ -233,20 +233,17 @@ fn has_valid_slurm_allocation(config: &Configuration, workflow_id: i64) -> bool
         && let Some(nodes) = response.items
     {
         for node in nodes {
-            if node.scheduler_type.to_lowercase() == "slurm" {
-                // Check if this Slurm job is still running
-                if let Ok(slurm) = SlurmInterface::new() {
-                    let slurm_job_id = node.scheduler_id.to_string();
-                    if let Ok(info) = slurm.get_status(&slurm_job_id)
-                        && (info.status == HpcJobStatus::Running
-                            || info.status == HpcJobStatus::Queued)
-                    {
-                        debug!(
-                            "Found valid active Slurm allocation {} (status: {:?})",
-                            slurm_job_id, info.status
-                        );
-                        return true;
-                    }
+            // Check if this HPC job is still running
+            if let Some(hpc) = interfaces.get(&node.scheduler_type) {
+                let slurm_job_id = node.hpc_job_id();
+                if let Ok(info) = hpc.get_status(&slurm_job_id)
+                    && (info.status == HpcJobStatus::Running || info.status == HpcJobStatus::Queued)
+                {
+                    debug!(
+                        "Found valid active {} allocation {} (status: {:?})",
+                        node.scheduler_type, slurm_job_id, info.status
+                    );
+                    return true;
                 }
             }
         }
@@ -271,27 +268,24 @@ fn has_valid_slurm_allocation(config: &Configuration, workflow_id: i64) -> bool
         && let Some(nodes) = response.items
     {
         for node in nodes {
-            if node.scheduler_type.to_lowercase() == "slurm" {
-                // Check if this Slurm job is still queued
-                if let Ok(slurm) = SlurmInterface::new() {
-                    let slurm_job_id = node.scheduler_id.to_string();
-                    if let Ok(info) = slurm.get_status(&slurm_job_id)
-                        && (info.status == HpcJobStatus::Running
-                            || info.status == HpcJobStatus::Queued)
-                    {
-                        debug!(
-                            "Found valid pending Slurm allocation {} (status: {:?})",
-                            slurm_job_id, info.status
-                        );
-                        return true;
-                    }
+            // Check if this HPC job is still queued
+            if let Some(hpc) = interfaces.get(&node.scheduler_type) {
+                let slurm_job_id = node.hpc_job_id();
+                if let Ok(info) = hpc.get_status(&slurm_job_id)
+                    && (info.status == HpcJobStatus::Running || info.status == HpcJobStatus::Queued)
+                {
+                    debug!(
+                        "Found valid pending {} allocation {} (status: {:?})",
+                        node.scheduler_type, slurm_job_id, info.status
+                    );
+                    return true;
                 }
             }
         }
     }
 
-    // No valid Slurm allocations found
-    debug!("No valid Slurm allocations found");
+    // No valid HPC allocations found
+    debug!("No valid HPC allocations found");
     false
 }
 
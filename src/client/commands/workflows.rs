@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fs;
 use std::io::{self, Read, Write};
 
@@ -45,7 +46,7 @@ const WORKFLOWS_HELP_TEMPLATE: &str = "\
 
 use crate::client::apis::configuration::Configuration;
 use crate::client::apis::default_api;
-use crate::client::commands::hpc::create_registry_with_config_public;
+use crate::client::commands::hpc::{create_registry_with_config_public, resolve_charge_factors};
 use crate::client::commands::pagination::{
     EventListParams, FileListParams, JobListParams, ResourceRequirementsListParams,
     ResultListParams, ScheduledComputeNodeListParams, SlurmSchedulersListParams,
@@ -63,6 +64,9 @@ use crate::client::commands::{
     get_env_user_name, print_error, select_workflow_interactively,
     table_format::display_table_with_count,
 };
+use crate::client::hpc::cost::{
+    AllocationShape, SchedulerCostEstimate, estimate_execution_plan, total_au,
+};
 use crate::client::hpc::hpc_interface::HpcInterface;
 use crate::client::workflow_manager::WorkflowManager;
 use crate::client::workflow_spec::WorkflowSpec;
@@ -749,6 +753,17 @@ fn show_execution_plan_from_spec(file_path: &str, format: &str) {
     // Build execution plan
     match crate::client::execution_plan::ExecutionPlan::from_spec(&spec) {
         Ok(plan) => {
+            let shapes: HashMap<String, AllocationShape> = spec
+                .slurm_schedulers
+                .iter()
+                .flatten()
+                .filter_map(|s| {
+                    let shape = AllocationShape::new(s.nodes, &s.walltime, s.gres.as_deref());
+                    Some((s.name.clone()?, shape))
+                })
+                .collect();
+            let cost_estimates =
+                estimate_execution_plan(&plan, &shapes, &resolve_charge_factors(None));
             if format == "json" {
                 // For JSON output, use the new DAG-based event structure
                 let events_json: Vec<serde_json::Value> = plan.events.values().map(|event| {
@@ -779,6 +794,7 @@ fn show_execution_plan_from_spec(file_path: &str, format: &str) {
                     "root_events": plan.root_events,
                     "leaf_events": plan.leaf_events,
                     "events": events_json,
+                    "estimated_au": total_au(&cost_estimates),
                 });
 
                 match serde_json::to_string_pretty(&output) {
@@ -796,6 +812,7 @@ fn show_execution_plan_from_spec(file_path: &str, format: &str) {
                 }
                 println!("Total Jobs: {}", spec.jobs.len());
                 plan.display();
+                display_execution_plan_cost(&cost_estimates, spec.au_budget);
             }
         }
         Err(e) => {
@@ -875,6 +892,15 @@ fn show_execution_plan_from_database(config: &Configuration, workflow_id: i64, f
         &resource_requirements,
     ) {
         Ok(plan) => {
+            let shapes: HashMap<String, AllocationShape> = slurm_schedulers
+                .iter()
+                .filter_map(|s| {
+                    let shape = AllocationShape::new(s.nodes, &s.walltime, s.gres.as_deref());
+                    Some((s.name.clone()?, shape))
+                })
+                .collect();
+            let cost_estimates =
+                estimate_execution_plan(&plan, &shapes, &resolve_charge_factors(None));
             if format == "json" {
                 // For JSON output, use the new DAG-based event structure
                 let events_json: Vec<serde_json::Value> = plan.events.values().map(|event| {
@@ -906,6 +932,7 @@ fn show_execution_plan_from_database(config: &Configuration, workflow_id: i64, f
                     "root_events": plan.root_events,
                     "leaf_events": plan.leaf_events,
                     "events": events_json,
+                    "estimated_au": total_au(&cost_estimates),
                 });
 
                 match serde_json::to_string_pretty(&output) {
@@ -923,6 +950,7 @@ fn show_execution_plan_from_database(config: &Configuration, workflow_id: i64, f
                 }
                 println!("Total Jobs: {}", jobs.len());
                 plan.display();
+                display_execution_plan_cost(&cost_estimates, workflow.au_budget);
             }
         }
         Err(e) => {
@@ -932,6 +960,23 @@ fn show_execution_plan_from_database(config: &Configuration, workflow_id: i64, f
     }
 }
 
+/// Display the estimated AU cost of the allocations in an execution plan
+fn display_execution_plan_cost(estimates: &[SchedulerCostEstimate], au_budget: Option<f64>) {
+    if estimates.is_empty() {
+        return;
+    }
+    let total = total_au(estimates);
+    println!("\nEstimated Cost (each allocation runs for its full walltime):");
+    display_table_with_count(estimates, "schedulers");
+    println!("Total estimated cost: {:.2} AU", total);
+    if let Some(budget) = au_budget {
+        println!("AU budget: {:.2}", budget);
+        if total > budget {
+            println!("Warning: the estimated cost exceeds the workflow's AU budget");
+        }
+    }
+}
+
 fn handle_execution_plan(config: &Configuration, spec_or_id: &str, format: &str) {
     // Try to parse as workflow ID first, otherwise treat as file path
     if let Ok(workflow_id) = spec_or_id.parse::<i64>() {
@@ -1102,64 +1147,77 @@ fn handle_cancel(config: &Configuration, workflow_id: &Option<i64>, format: &str
     let mut canceled_jobs = Vec::new();
     let mut errors = Vec::new();
 
+    // Group the tasks of each Slurm job array so that the whole array is canceled at once.
+    let mut slurm_jobs: Vec<(i64, Vec<models::ScheduledComputeNodesModel>)> = Vec::new();
     for node in nodes {
-        if node.scheduler_type == "slurm" {
-            match crate::client::hpc::slurm_interface::SlurmInterface::new() {
-                Ok(slurm_interface) => {
-                    let job_id_str = node.scheduler_id.to_string();
-                    match slurm_interface.cancel_job(&job_id_str) {
-                        Ok(_) => {
-                            canceled_jobs.push(node.scheduler_id);
-                            if format != "json" {
-                                println!("  Canceled Slurm job: {}", node.scheduler_id);
-                            }
-                            // Update the ScheduledComputeNode status to "complete"
-                            if let Some(node_id) = node.id {
-                                let updated_node = models::ScheduledComputeNodesModel::new(
-                                    node.workflow_id,
-                                    node.scheduler_id,
-                                    node.scheduler_config_id,
-                                    node.scheduler_type.clone(),
-                                    "complete".to_string(),
-                                );
-                                if let Err(e) = default_api::update_scheduled_compute_node(
-                                    config,
-                                    node_id,
-                                    updated_node,
-                                ) {
-                                    let error_msg =
-                                        format!("Failed to update node {} status: {}", node_id, e);
-                                    errors.push(error_msg.clone());
-                                    if format != "json" {
-                                        eprintln!("  {}", error_msg);
-                                    }
-                                } else if format != "json" {
-                                    println!(
-                                        "  Updated node {} status to complete",
-                                        node.scheduler_id
-                                    );
+        if node.scheduler_type != "slurm" {
+            continue;
+        }
+        match slurm_jobs
+            .iter_mut()
+            .find(|(job_id, _)| node.array_task_id.is_some() && *job_id == node.scheduler_id)
+        {
+            Some((_, tasks)) => tasks.push(node),
+            None => slurm_jobs.push((node.scheduler_id, vec![node])),
+        }
+    }
+
+    for (scheduler_id, job_nodes) in slurm_jobs {
+        match crate::client::hpc::slurm_interface::SlurmInterface::new() {
+            Ok(slurm_interface) => {
+                let job_id_str = scheduler_id.to_string();
+                match slurm_interface.cancel_job(&job_id_str) {
+                    Ok(_) => {
+                        canceled_jobs.push(scheduler_id);
+                        if format != "json" {
+                            println!("  Canceled Slurm job: {}", scheduler_id);
+                        }
+                        // Update the ScheduledComputeNode status to "complete"
+                        for node in &job_nodes {
+                            let Some(node_id) = node.id else {
+                                continue;
+                            };
+                            let updated_node = models::ScheduledComputeNodesModel::new(
+                                node.workflow_id,
+                                node.scheduler_id,
+                                node.scheduler_config_id,
+                                node.scheduler_type.clone(),
+                                "complete".to_string(),
+                            );
+                            if let Err(e) = default_api::update_scheduled_compute_node(
+                                config,
+                                node_id,
+                                updated_node,
+                            ) {
+                                let error_msg =
+                                    format!("Failed to update node {} status: {}", node_id, e);
+                                errors.push(error_msg.clone());
+                                if format != "json" {
+                                    eprintln!("  {}", error_msg);
                                 }
+                            } else if format != "json" {
+                                println!("  Updated node {} status to complete", node.hpc_job_id());
                             }
                         }
-                        Err(e) => {
-                            let error_msg =
-                                format!("Failed to cancel Slurm job {}: {}", node.scheduler_id, e);
-                            errors.push(error_msg.clone());
-                            if format != "json" {
-                                eprintln!("  {}", error_msg);
-                            }
+                    }
+                    Err(e) => {
+                        let error_msg =
+                            format!("Failed to cancel Slurm job {}: {}", scheduler_id, e);
+                        errors.push(error_msg.clone());
+                        if format != "json" {
+                            eprintln!("  {}", error_msg);
                         }
                     }
                 }
-                Err(e) => {
-                    let error_msg = format!(
-                        "Failed to create SlurmInterface for job {}: {}",
-                        node.scheduler_id, e
-                    );
-                    errors.push(error_msg.clone());
-                    if format != "json" {
-                        eprintln!("  {}", error_msg);
-                    }
+            }
+            Err(e) => {
+                let error_msg = format!(
+                    "Failed to create SlurmInterface for job {}: {}",
+                    scheduler_id, e
+                );
+                errors.push(error_msg.clone());
+                if format != "json" {
+                    eprintln!("  {}", error_msg);
                 }
             }
         }
@@ -1750,6 +1808,9 @@ fn handle_run(
         cpu_affinity_cpus_per_job: None,
         log_level: "info".to_string(),
         password,
+        api_token: config.bearer_access_token.clone(),
+        stage_from: None,
+        stage_root: None,
     };
 
     crate::run_jobs_cmd::run(&args);
@@ -2666,6 +2727,12 @@ fn handle_create_slurm(
                 for warning in &result.warnings {
                     eprintln!("  Warning: {}", warning);
                 }
+                if !result.cost_estimates.is_empty() {
+                    eprintln!(
+                        "Estimated cost if all allocations run for their full walltime: {:.2} AU",
+                        total_au(&result.cost_estimates)
+                    );
+                }
             }
         }
         Err(e) => {
@@ -0,0 +1,362 @@
+//! Container execution for workflow jobs.
+//!
+//! A job with a `container` runs its command inside the container image using Apptainer
+//! (or Singularity), Podman, or Docker. The command is wrapped as follows:
+//!
+//! - **Apptainer/Singularity**: `apptainer exec [--nv] [--bind ...] --pwd <dir> [args] <image>
+//!   <shell> -c <command>`. The runtime process and everything it starts are children of
+//!   the job runner, so resource monitoring and signals work as they do for bare commands.
+//!   Environment variables are passed with the `APPTAINERENV_`/`SINGULARITYENV_` prefixes
+//!   so that they are set even with `--cleanenv`.
+//! - **Podman/Docker**: `podman run --rm --name <name> [GPU flag] -v <dir>:<dir> -w <dir>
+//!   [-v ...] [-e NAME ...] [args] <image> <shell> -c <command>`. The container's
+//!   processes are started by the container engine rather than the job runner, so the job
+//!   runner looks up the container's main PID for resource monitoring and removes the
+//!   container by name when the job is killed. SIGTERM is forwarded to the container by the
+//!   runtime.
+//!
+//! The job's working directory (default: the job runner's working directory) and the output
+//! directory are mounted at the same paths in the container. The command runs with the
+//! job's `shell`, or `sh` if none is set, because many images do not include bash.
+
+use crate::client::utils::shell_words;
+use crate::models::{ContainerRuntime, JobContainerModel};
+use log::debug;
+use std::path::Path;
+use std::process::Command;
+
+/// Shell used to run the command in the container when the job does not set one.
+const DEFAULT_CONTAINER_SHELL: &str = "sh";
+
+/// Returns true if the runtime's containers are started by a container engine rather than
+/// as children of the runtime process.
+pub fn is_engine_runtime(runtime: ContainerRuntime) -> bool {
+    matches!(runtime, ContainerRuntime::Podman | ContainerRuntime::Docker)
+}
+
+/// Build the process that runs `command_str` in the job's container.
+///
+/// `working_dir` and `output_dir` must be absolute. `env` holds the variables to set in
+/// the container; later entries take precedence. `name` is the container name used by
+/// Podman and Docker.
+pub fn container_command(
+    container: &JobContainerModel,
+    shell: Option<&str>,
+    command_str: &str,
+    working_dir: &Path,
+    output_dir: &Path,
+    env: &[(String, String)],
+    name: &str,
+) -> Result<Command, String> {
+    if container.image.trim().is_empty() {
+        return Err("container image must not be empty".to_string());
+    }
+    let runtime = container.runtime;
+    let working_dir_str = working_dir.to_string_lossy().to_string();
+    let output_dir_str = output_dir.to_string_lossy().to_string();
+    let mount_output_dir = !output_dir.starts_with(working_dir);
+
+    let mut cmd = Command::new(runtime.to_string());
+    cmd.current_dir(working_dir);
+    match runtime {
+        ContainerRuntime::Apptainer | ContainerRuntime::Singularity => {
+            cmd.arg("exec");
+            if container.gpus.unwrap_or(false) {
+                cmd.arg("--nv");
+            }
+            for mount in container.bind_mounts.iter().flatten() {
+                cmd.arg("--bind").arg(mount);
+            }
+            // Apptainer mounts the current directory by default.
+            if mount_output_dir {
+                cmd.arg("--bind").arg(&output_dir_str);
+            }
+            cmd.arg("--pwd").arg(&working_dir_str);
+            let prefix = match runtime {
+                ContainerRuntime::Singularity => "SINGULARITYENV_",
+                _ => "APPTAINERENV_",
+            };
+            for (key, value) in env {
+                cmd.env(key, value);
+                cmd.env(format!("{}{}", prefix, key), value);
+            }
+        }
+        ContainerRuntime::Podman | ContainerRuntime::Docker => {
+            cmd.args(["run", "--rm", "--name", name]);
+            if container.gpus.unwrap_or(false) {
+                match runtime {
+                    ContainerRuntime::Podman => cmd.args(["--device", "nvidia.com/gpu=all"]),
+                    _ => cmd.args(["--gpus", "all"]),
+                };
+            }
+            // Docker runs containers as root by default; run as the job runner's user so
+            // that output files have the same owner as they would without a container.
+            #[cfg(unix)]
+            if runtime == ContainerRuntime::Docker {
+                // SAFETY: getuid and getgid cannot fail.
+                let (uid, gid) = unsafe { (libc::getuid(), libc::getgid()) };
+                cmd.arg("--user").arg(format!("{}:{}", uid, gid));
+            }
+            cmd.arg("-v")
+                .arg(format!("{}:{}", working_dir_str, working_dir_str));
+            cmd.arg("-w").arg(&working_dir_str);
+            if mount_output_dir {
+                cmd.arg("-v")
+                    .arg(format!("{}:{}", output_dir_str, output_dir_str));
+            }
+            for mount in container.bind_mounts.iter().flatten() {
+                cmd.arg("-v").arg(engine_bind_mount(mount));
+            }
+            // Pass variables by name so that their values are read from the runtime's
+            // environment rather than appearing on its command line.
+            let mut names = Vec::new();
+            for (key, value) in env {
+                cmd.env(key, value);
+                if !names.contains(key) {
+                    names.push(key.clone());
+                }
+            }
+            for key in names {
+                cmd.arg("-e").arg(key);
+            }
+        }
+    }
+    cmd.args(container.args.iter().flatten());
+    cmd.arg(&container.image);
+    cmd.args(shell_words(shell.unwrap_or(DEFAULT_CONTAINER_SHELL))?);
+    cmd.arg(command_str);
+    debug!("Container command: {:?}", cmd);
+    Ok(cmd)
+}
+
+/// Podman and Docker treat a bare path as a named volume, so mount it at the same path.
+fn engine_bind_mount(mount: &str) -> String {
+    if mount.contains(':') {
+        mount.to_string()
+    } else {
+        format!("{}:{}", mount, mount)
+    }
+}
+
+/// Look up the host PID of a Podman or Docker container's main process.
+///
+/// Returns `None` if the container has not started yet or has already exited.
+pub fn container_pid(runtime: ContainerRuntime, name: &str) -> Option<u32> {
+    let output = Command::new(runtime.to_string())
+        .args(["inspect", "--format", "{{.State.Pid}}", name])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse::<u32>()
+        .ok()
+        .filter(|pid| *pid > 0)
+}
+
+/// Kill and remove a Podman or Docker container.
+pub fn kill_container(runtime: ContainerRuntime, name: &str) -> Result<(), String> {
+    let output = Command::new(runtime.to_string())
+        .args(["rm", "--force", name])
+        .output()
+        .map_err(|e| format!("failed to run {}: {}", runtime, e))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(cmd: &Command) -> Vec<String> {
+        cmd.get_args()
+            .map(|a| a.to_string_lossy().to_string())
+            .collect()
+    }
+
+    fn env_value(cmd: &Command, key: &str) -> Option<String> {
+        cmd.get_envs()
+            .find(|(k, _)| *k == key)
+            .and_then(|(_, v)| v.map(|v| v.to_string_lossy().to_string()))
+    }
+
+    fn container(runtime: ContainerRuntime) -> JobContainerModel {
+        JobContainerModel {
+            image: "python:3.12".to_string(),
+            runtime,
+            bind_mounts: Some(vec![
+                "/data".to_string(),
+                "/scratch:/tmp/scratch:ro".to_string(),
+            ]),
+            gpus: Some(true),
+            args: Some(vec!["--network=none".to_string()]),
+        }
+    }
+
+    #[test]
+    fn test_apptainer_command() {
+        let env = vec![("TORC_JOB_ID".to_string(), "7".to_string())];
+        let cmd = container_command(
+            &container(ContainerRuntime::Apptainer),
+            None,
+            "python run.py",
+            Path::new("/work"),
+            Path::new("/work/output"),
+            &env,
+            "torc_1_7_1",
+        )
+        .unwrap();
+        assert_eq!(cmd.get_program(), "apptainer");
+        assert_eq!(
+            args(&cmd),
+            vec![
+                "exec",
+                "--nv",
+                "--bind",
+                "/data",
+                "--bind",
+                "/scratch:/tmp/scratch:ro",
+                "--pwd",
+                "/work",
+                "--network=none",
+                "python:3.12",
+                "sh",
+                "-c",
+                "python run.py",
+            ]
+        );
+        assert_eq!(
+            env_value(&cmd, "APPTAINERENV_TORC_JOB_ID"),
+            Some("7".to_string())
+        );
+        assert_eq!(env_value(&cmd, "TORC_JOB_ID"), Some("7".to_string()));
+    }
+
+    #[test]
+    fn test_singularity_env_prefix_and_output_mount() {
+        let mut spec = JobContainerModel::new("image.sif".to_string());
+        spec.runtime = ContainerRuntime::Singularity;
+        let env = vec![("TORC_JOB_ID".to_string(), "7".to_string())];
+        let cmd = container_command(
+            &spec,
+            Some("bash -l"),
+            "echo hi",
+            Path::new("/work"),
+            Path::new("/output"),
+            &env,
+            "torc_1_7_1",
+        )
+        .unwrap();
+        assert_eq!(cmd.get_program(), "singularity");
+        assert_eq!(
+            args(&cmd),
+            vec![
+                "exec",
+                "--bind",
+                "/output",
+                "--pwd",
+                "/work",
+                "image.sif",
+                "bash",
+                "-l",
+                "-c",
+                "echo hi",
+            ]
+        );
+        assert_eq!(
+            env_value(&cmd, "SINGULARITYENV_TORC_JOB_ID"),
+            Some("7".to_string())
+        );
+    }
+
+    #[test]
+    fn test_podman_command() {
+        let env = vec![
+            ("MODE".to_string(), "fast".to_string()),
+            ("TORC_JOB_ID".to_string(), "7".to_string()),
+        ];
+        let cmd = container_command(
+            &container(ContainerRuntime::Podman),
+            None,
+            "python run.py",
+            Path::new("/work"),
+            Path::new("/output"),
+            &env,
+            "torc_1_7_1",
+        )
+        .unwrap();
+        assert_eq!(cmd.get_program(), "podman");
+        assert_eq!(
+            args(&cmd),
+            vec![
+                "run",
+                "--rm",
+                "--name",
+                "torc_1_7_1",
+                "--device",
+                "nvidia.com/gpu=all",
+                "-v",
+                "/work:/work",
+                "-w",
+                "/work",
+                "-v",
+                "/output:/output",
+                "-v",
+                "/data:/data",
+                "-v",
+                "/scratch:/tmp/scratch:ro",
+                "-e",
+                "MODE",
+                "-e",
+                "TORC_JOB_ID",
+                "--network=none",
+                "python:3.12",
+                "sh",
+                "-c",
+                "python run.py",
+            ]
+        );
+        assert_eq!(env_value(&cmd, "MODE"), Some("fast".to_string()));
+    }
+
+    #[test]
+    fn test_docker_gpus_and_user() {
+        let cmd = container_command(
+            &container(ContainerRuntime::Docker),
+            None,
+            "nvidia-smi",
+            Path::new("/work"),
+            Path::new("/work/output"),
+            &[],
+            "torc_1_7_1",
+        )
+        .unwrap();
+        let args = args(&cmd);
+        assert_eq!(cmd.get_program(), "docker");
+        assert!(args.windows(2).any(|w| w == ["--gpus", "all"]));
+        #[cfg(unix)]
+        assert!(args.iter().any(|a| a == "--user"));
+        // The output directory is inside the working directory, so it is not mounted again.
+        assert!(!args.iter().any(|a| a == "/work/output:/work/output"));
+    }
+
+    #[test]
+    fn test_empty_image() {
+        let spec = JobContainerModel::new(" ".to_string());
+        let result = container_command(
+            &spec,
+            None,
+            "true",
+            Path::new("/work"),
+            Path::new("/work"),
+            &[],
+            "torc_1_7_1",
+        );
+        assert!(result.is_err());
+    }
+}
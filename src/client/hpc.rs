@@ -1,22 +1,29 @@
 //! HPC (High Performance Computing) management functionality
 //!
-//! This module provides abstractions for working with HPC schedulers like Slurm.
+//! This module provides abstractions for working with HPC schedulers like Slurm and PBS.
 //! It includes traits for HPC interfaces and concrete implementations for different
-//! scheduler types.
+//! scheduler types, plus a simulated scheduler for testing without a cluster.
 //!
 //! It also provides HPC system profiles for known HPC systems (like NREL Kestrel)
-//! that include partition configurations, resource limits, and auto-detection.
+//! that include partition configurations, resource limits, and auto-detection, and
+//! allocation unit (AU) cost accounting based on the profiles' charge factors.
 
 pub mod common;
+pub mod cost;
+pub mod fake_interface;
 pub mod hpc_interface;
 pub mod hpc_manager;
 pub mod kestrel;
+pub mod pbs_interface;
 pub mod profiles;
 pub mod slurm_interface;
 
 pub use common::{HpcJobInfo, HpcJobStats, HpcJobStatus, HpcType};
+pub use cost::ChargeFactors;
+pub use fake_interface::FakeHpcInterface;
 pub use hpc_interface::HpcInterface;
 pub use hpc_manager::HpcManager;
+pub use pbs_interface::PbsInterface;
 pub use profiles::{HpcDetection, HpcPartition, HpcProfile, HpcProfileRegistry};
 pub use slurm_interface::SlurmInterface;
 
@@ -26,7 +33,7 @@ use anyhow::Result;
 pub fn create_hpc_interface(hpc_type: HpcType) -> Result<Box<dyn HpcInterface>> {
     match hpc_type {
         HpcType::Slurm => Ok(Box::new(SlurmInterface::new()?)),
-        HpcType::Pbs => Err(anyhow::anyhow!("PBS support not yet implemented")),
-        HpcType::Fake => Err(anyhow::anyhow!("Fake HPC support not yet implemented")),
+        HpcType::Pbs => Ok(Box::new(PbsInterface::new()?)),
+        HpcType::Fake => Ok(Box::new(FakeHpcInterface::new()?)),
     }
 }
@@ -83,7 +83,7 @@ pub struct HpcJobStats {
 }
 
 /// HPC types supported
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum HpcType {
     /// PBS/Torque scheduler
@@ -104,6 +104,14 @@ impl std::fmt::Display for HpcType {
     }
 }
 
+impl HpcType {
+    /// Map a `scheduler_type` string from a workflow action or scheduled compute node to
+    /// the HPC type that can submit it. Returns `None` for non-HPC schedulers like "local".
+    pub fn from_scheduler_type(scheduler_type: &str) -> Option<Self> {
+        scheduler_type.parse().ok()
+    }
+}
+
 impl std::str::FromStr for HpcType {
     type Err = anyhow::Error;
 
@@ -0,0 +1,348 @@
+//! Allocation unit (AU) accounting for HPC allocations
+//!
+//! HPC centers charge allocations in allocation units: node-hours multiplied by a charge
+//! factor that depends on whether the nodes have GPUs. The charge factors come from the
+//! HPC profile (`charge_factor_cpu` and `charge_factor_gpu`).
+//!
+//! Estimates assume that an allocation runs for its full walltime, which is the most it can
+//! cost. Actual costs use elapsed times reported by `sacct` or recorded for compute nodes.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tabled::Tabled;
+
+use crate::client::commands::slurm::parse_walltime_secs;
+use crate::client::execution_plan::ExecutionPlan;
+use crate::client::hpc::HpcProfile;
+use crate::client::scheduler_plan::SchedulerPlan;
+use crate::models::ComputeNodeModel;
+
+/// AU charged per node-hour for CPU and GPU allocations
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ChargeFactors {
+    pub cpu: f64,
+    pub gpu: f64,
+}
+
+impl Default for ChargeFactors {
+    fn default() -> Self {
+        Self {
+            cpu: 1.0,
+            gpu: 10.0,
+        }
+    }
+}
+
+impl ChargeFactors {
+    pub fn from_profile(profile: &HpcProfile) -> Self {
+        Self {
+            cpu: profile.charge_factor_cpu,
+            gpu: profile.charge_factor_gpu,
+        }
+    }
+
+    /// Charge factor for an allocation with or without GPUs
+    pub fn for_allocation(&self, uses_gpus: bool) -> f64 {
+        if uses_gpus { self.gpu } else { self.cpu }
+    }
+}
+
+/// Nodes, walltime, and GPU request of an allocation
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllocationShape {
+    pub nodes: i64,
+    pub walltime: String,
+    pub gres: Option<String>,
+}
+
+impl AllocationShape {
+    pub fn new(nodes: i64, walltime: &str, gres: Option<&str>) -> Self {
+        Self {
+            nodes,
+            walltime: walltime.to_string(),
+            gres: gres.map(String::from),
+        }
+    }
+
+    /// Estimated AU cost of one allocation that runs for its full walltime
+    pub fn estimated_au(&self, factors: &ChargeFactors) -> Result<f64, String> {
+        let seconds = parse_walltime_secs(&self.walltime)?;
+        Ok(allocation_au(
+            factors,
+            self.nodes,
+            seconds as f64,
+            gres_requests_gpus(self.gres.as_deref()),
+        ))
+    }
+}
+
+/// Return true if a Slurm gres string requests GPUs, e.g. "gpu:2" or "gpu:h100:4".
+pub fn gres_requests_gpus(gres: Option<&str>) -> bool {
+    let Some(gres) = gres else {
+        return false;
+    };
+    gres.split(',').any(|entry| {
+        let parts: Vec<&str> = entry.trim().split(':').collect();
+        if parts[0] != "gpu" {
+            return false;
+        }
+        // "gpu" alone requests one GPU; otherwise the count is the last field.
+        parts.len() == 1
+            || parts[parts.len() - 1]
+                .parse::<u64>()
+                .map_or(true, |n| n > 0)
+    })
+}
+
+/// AU cost of `nodes` nodes held for `seconds` seconds
+pub fn allocation_au(factors: &ChargeFactors, nodes: i64, seconds: f64, uses_gpus: bool) -> f64 {
+    nodes.max(0) as f64 * seconds.max(0.0) / 3600.0 * factors.for_allocation(uses_gpus)
+}
+
+/// Estimated cost of the allocations of one scheduler
+#[derive(Debug, Clone, Serialize, Deserialize, Tabled)]
+pub struct SchedulerCostEstimate {
+    #[tabled(rename = "Scheduler")]
+    pub scheduler: String,
+    #[tabled(rename = "Nodes")]
+    pub nodes: i64,
+    #[tabled(rename = "Walltime")]
+    pub walltime: String,
+    #[tabled(rename = "GPUs")]
+    pub uses_gpus: bool,
+    #[tabled(rename = "Allocations")]
+    pub num_allocations: i64,
+    #[tabled(rename = "AU/Allocation", format("{:.2}", self.au_per_allocation))]
+    pub au_per_allocation: f64,
+    #[tabled(rename = "Total AU", format("{:.2}", self.total_au))]
+    pub total_au: f64,
+}
+
+impl SchedulerCostEstimate {
+    pub fn new(
+        scheduler: &str,
+        shape: &AllocationShape,
+        num_allocations: i64,
+        factors: &ChargeFactors,
+    ) -> Result<Self, String> {
+        let au_per_allocation = shape.estimated_au(factors)?;
+        Ok(Self {
+            scheduler: scheduler.to_string(),
+            nodes: shape.nodes,
+            walltime: shape.walltime.clone(),
+            uses_gpus: gres_requests_gpus(shape.gres.as_deref()),
+            num_allocations,
+            au_per_allocation,
+            total_au: au_per_allocation * num_allocations as f64,
+        })
+    }
+}
+
+/// Estimate the cost of the allocations in a scheduler plan. Schedulers with unparseable
+/// walltimes are skipped.
+pub fn estimate_scheduler_plan(
+    plan: &SchedulerPlan,
+    factors: &ChargeFactors,
+) -> Vec<SchedulerCostEstimate> {
+    plan.schedulers
+        .iter()
+        .filter_map(|sched| {
+            let shape = AllocationShape::new(sched.nodes, &sched.walltime, sched.gres.as_deref());
+            SchedulerCostEstimate::new(&sched.name, &shape, sched.num_allocations, factors).ok()
+        })
+        .collect()
+}
+
+/// Estimate the cost of all scheduler allocations in an execution plan, keyed by scheduler
+/// name. Allocations of schedulers missing from `shapes` are skipped.
+pub fn estimate_execution_plan(
+    plan: &ExecutionPlan,
+    shapes: &HashMap<String, AllocationShape>,
+    factors: &ChargeFactors,
+) -> Vec<SchedulerCostEstimate> {
+    let mut allocations: HashMap<&str, i64> = HashMap::new();
+    for event in plan.events.values() {
+        for alloc in &event.scheduler_allocations {
+            *allocations.entry(alloc.scheduler.as_str()).or_default() += alloc.num_allocations;
+        }
+    }
+    let mut estimates: Vec<SchedulerCostEstimate> = allocations
+        .into_iter()
+        .filter_map(|(name, num_allocations)| {
+            let shape = shapes.get(name)?;
+            SchedulerCostEstimate::new(name, shape, num_allocations, factors).ok()
+        })
+        .collect();
+    estimates.sort_by(|a, b| a.scheduler.cmp(&b.scheduler));
+    estimates
+}
+
+/// Sum the total cost of a set of estimates
+pub fn total_au(estimates: &[SchedulerCostEstimate]) -> f64 {
+    estimates.iter().map(|e| e.total_au).sum()
+}
+
+/// Actual AU consumed by the allocations in `sacct --json` output.
+///
+/// Only top-level job records are charged; job steps run inside their allocation.
+pub fn sacct_au(sacct_json: &serde_json::Value, factors: &ChargeFactors) -> f64 {
+    let Some(jobs) = sacct_json.get("jobs").and_then(|j| j.as_array()) else {
+        return 0.0;
+    };
+    jobs.iter()
+        .map(|job| {
+            let elapsed = job
+                .get("time")
+                .and_then(|t| t.get("elapsed"))
+                .and_then(|e| e.as_f64())
+                .unwrap_or(0.0);
+            let allocated = job
+                .get("tres")
+                .and_then(|t| t.get("allocated"))
+                .and_then(|a| a.as_array());
+            let tres_count = |tres_type: &str, name: &str| {
+                allocated.and_then(|items| {
+                    items.iter().find_map(|item| {
+                        let matches = item.get("type").and_then(|t| t.as_str()) == Some(tres_type)
+                            && item.get("name").and_then(|n| n.as_str()).unwrap_or("") == name;
+                        if matches {
+                            item.get("count").and_then(|c| c.as_i64())
+                        } else {
+                            None
+                        }
+                    })
+                })
+            };
+            let nodes = job
+                .get("allocation_nodes")
+                .and_then(|n| n.as_i64())
+                .or_else(|| tres_count("node", ""))
+                .unwrap_or(1);
+            let uses_gpus = tres_count("gres", "gpu").is_some_and(|n| n > 0);
+            allocation_au(factors, nodes, elapsed, uses_gpus)
+        })
+        .sum()
+}
+
+/// Actual AU consumed by HPC allocations, computed from the compute nodes that ran in them.
+///
+/// Compute nodes are grouped by the HPC job ID in their `scheduler` field. An allocation is
+/// charged for its longest-running compute node on all of its nodes. Active compute nodes are
+/// charged up to now. Returns AU keyed by HPC job ID.
+pub fn compute_node_au(
+    compute_nodes: &[ComputeNodeModel],
+    factors: &ChargeFactors,
+) -> HashMap<String, f64> {
+    #[derive(Default)]
+    struct Allocation<'a> {
+        seconds: f64,
+        num_nodes: i64,
+        hostnames: HashSet<&'a str>,
+        uses_gpus: bool,
+    }
+
+    let now = Utc::now();
+    let mut allocations: HashMap<String, Allocation> = HashMap::new();
+    for node in compute_nodes {
+        let Some(job_id) = node
+            .scheduler
+            .as_ref()
+            .and_then(|s| s.get("slurm_job_id"))
+            .and_then(|id| match id {
+                serde_json::Value::String(s) => Some(s.clone()),
+                serde_json::Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            })
+        else {
+            continue;
+        };
+        let seconds = node.duration_seconds.unwrap_or_else(|| {
+            DateTime::parse_from_rfc3339(&node.start_time)
+                .map(|start| (now - start.with_timezone(&Utc)).num_seconds() as f64)
+                .unwrap_or(0.0)
+        });
+        let allocation = allocations.entry(job_id).or_default();
+        allocation.seconds = allocation.seconds.max(seconds);
+        allocation.num_nodes = allocation.num_nodes.max(node.num_nodes);
+        allocation.hostnames.insert(node.hostname.as_str());
+        allocation.uses_gpus |= node.num_gpus > 0;
+    }
+
+    allocations
+        .into_iter()
+        .map(|(job_id, a)| {
+            let nodes = a.num_nodes.max(a.hostnames.len() as i64);
+            (
+                job_id,
+                allocation_au(factors, nodes, a.seconds, a.uses_gpus),
+            )
+        })
+        .collect()
+}
+
+/// A submitted HPC allocation of a workflow
+#[derive(Debug, Clone, Serialize, Deserialize, Tabled)]
+pub struct AllocationCost {
+    #[tabled(rename = "HPC Job")]
+    pub hpc_job_id: String,
+    #[tabled(rename = "Scheduler Config")]
+    pub scheduler_config_id: i64,
+    #[tabled(rename = "Status")]
+    pub status: String,
+    #[tabled(rename = "Estimated AU", format("{:.2}", self.estimated_au))]
+    pub estimated_au: f64,
+    #[tabled(
+        rename = "Actual AU",
+        format("{}", self.actual_au.map_or("-".to_string(), |au| format!("{:.2}", au)))
+    )]
+    pub actual_au: Option<f64>,
+}
+
+impl AllocationCost {
+    /// AU that the allocation counts against a budget: its actual cost once it has
+    /// completed, otherwise its full-walltime estimate.
+    pub fn committed_au(&self) -> f64 {
+        match self.actual_au {
+            Some(actual) if self.status == "complete" => actual,
+            _ => self.estimated_au,
+        }
+    }
+}
+
+/// AU usage of a workflow's allocations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuUsage {
+    pub num_allocations: usize,
+    /// AU consumed so far by allocations with known costs
+    pub consumed_au: f64,
+    /// AU that submitted allocations can cost in total
+    pub committed_au: f64,
+}
+
+impl AuUsage {
+    pub fn from_allocations(allocations: &[AllocationCost]) -> Self {
+        Self {
+            num_allocations: allocations.len(),
+            consumed_au: allocations.iter().filter_map(|a| a.actual_au).sum(),
+            committed_au: allocations.iter().map(|a| a.committed_au()).sum(),
+        }
+    }
+}
+
+/// Check that submitting allocations costing `requested_au` keeps the workflow within its
+/// budget.
+pub fn check_au_budget(
+    budget: Option<f64>,
+    committed_au: f64,
+    requested_au: f64,
+) -> Result<(), String> {
+    match budget {
+        Some(budget) if committed_au + requested_au > budget => Err(format!(
+            "Submitting allocations estimated at {:.2} AU would exceed the workflow's AU budget \
+             ({:.2} of {:.2} AU already committed)",
+            requested_au, committed_au, budget
+        )),
+        _ => Ok(()),
+    }
+}
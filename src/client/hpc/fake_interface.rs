@@ -0,0 +1,669 @@
+//! Simulated HPC scheduler for testing without a cluster
+//!
+//! `FakeHpcInterface` "submits" allocations by running the submission script as a detached
+//! local process. The queue is kept in a state directory so that separate torc processes
+//! (the CLI that submits, `torc watch`, and the job runners inside allocations) all see the
+//! same jobs.
+//!
+//! Each allocation goes through the same lifecycle as on a real cluster:
+//!
+//! 1. It waits in the queue for the configured queue delay.
+//! 2. It runs the submission script with `TORC_FAKE_HPC_*` variables describing the
+//!    allocation, like Slurm does with `SLURM_*` variables.
+//! 3. It ends when the script exits, when it is canceled, when its walltime expires
+//!    (SIGTERM followed by SIGKILL after a grace period), or when a simulated node failure
+//!    kills it with SIGKILL.
+//!
+//! Behavior is configured with environment variables in the submitting process. Submission
+//! scripts can override them per allocation with `#FAKE --queue-delay=` and
+//! `#FAKE --node-failure-after=` directives.
+//!
+//! | Variable                            | Description                                     |
+//! | ----------------------------------- | ----------------------------------------------- |
+//! | `TORC_FAKE_HPC_DIR`                 | State directory (default: `$TMPDIR/torc_fake_hpc_<user>`) |
+//! | `TORC_FAKE_HPC_QUEUE_DELAY`         | Seconds an allocation stays queued (default: 2) |
+//! | `TORC_FAKE_HPC_NODE_FAILURE_AFTER`  | Kill allocations after this many running seconds |
+//! | `TORC_FAKE_HPC_KILL_GRACE`          | Seconds between SIGTERM and SIGKILL at walltime (default: 10) |
+//! | `TORC_FAKE_HPC_CPUS_PER_NODE`       | CPUs reported per simulated node                |
+//! | `TORC_FAKE_HPC_MEMORY_GB_PER_NODE`  | Memory reported per simulated node              |
+//! | `TORC_FAKE_HPC_GPUS_PER_NODE`       | GPUs reported per simulated node (default: 0)   |
+//!
+//! All simulated nodes of an allocation run on the local machine, so by default the local
+//! CPUs and memory are divided evenly between them.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, TimeZone, Utc};
+use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use sysinfo::{RefreshKind, System, SystemExt};
+
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
+
+use super::common::{HpcJobInfo, HpcJobStats, HpcJobStatus};
+use super::hpc_interface::HpcInterface;
+use crate::client::commands::slurm::parse_walltime_secs;
+
+const DEFAULT_QUEUE_DELAY_SECS: u64 = 2;
+const DEFAULT_KILL_GRACE_SECS: u64 = 10;
+const DEFAULT_WALLTIME_SECS: u64 = 3600;
+
+/// End-of-allocation reasons recorded by the wrapper and by `cancel_job`
+const REASON_CANCELLED: &str = "CANCELLED";
+const REASON_TIMEOUT: &str = "TIMEOUT";
+const REASON_NODE_FAIL: &str = "NODE_FAIL";
+
+/// Persistent record of a simulated allocation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FakeJobRecord {
+    job_id: String,
+    name: String,
+    account: String,
+    partition: String,
+    num_nodes: u32,
+    walltime_secs: u64,
+    queue_delay_secs: u64,
+    node_failure_after_secs: Option<u64>,
+    submit_time: DateTime<Utc>,
+    /// PID of the wrapper process, which is also its process group ID
+    pid: u32,
+}
+
+/// The state of a simulated allocation, derived from its record and marker files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FakeJobState {
+    Pending,
+    Running,
+    Finished,
+}
+
+/// Simulated HPC scheduler implementation
+pub struct FakeHpcInterface {
+    state_dir: PathBuf,
+}
+
+impl FakeHpcInterface {
+    /// Create a new fake HPC interface, creating its state directory if needed
+    pub fn new() -> Result<Self> {
+        let state_dir = Self::default_state_dir()?;
+        fs::create_dir_all(&state_dir)
+            .with_context(|| format!("Failed to create fake HPC directory {:?}", state_dir))?;
+        Ok(Self { state_dir })
+    }
+
+    /// Create a fake HPC interface that keeps its queue in `state_dir`
+    pub fn with_state_dir(state_dir: impl Into<PathBuf>) -> Result<Self> {
+        let state_dir = state_dir.into();
+        fs::create_dir_all(&state_dir)
+            .with_context(|| format!("Failed to create fake HPC directory {:?}", state_dir))?;
+        Ok(Self { state_dir })
+    }
+
+    fn default_state_dir() -> Result<PathBuf> {
+        if let Ok(dir) = env::var("TORC_FAKE_HPC_DIR") {
+            return Ok(PathBuf::from(dir));
+        }
+        let user = env::var("USER")
+            .or_else(|_| env::var("USERNAME"))
+            .unwrap_or_else(|_| "unknown".to_string());
+        Ok(env::temp_dir().join(format!("torc_fake_hpc_{}", user)))
+    }
+
+    /// Path of the directory that holds the simulated queue
+    pub fn state_dir(&self) -> &Path {
+        &self.state_dir
+    }
+
+    fn record_path(&self, job_id: &str) -> PathBuf {
+        self.state_dir.join(format!("{}.json", job_id))
+    }
+
+    fn marker_path(&self, job_id: &str, marker: &str) -> PathBuf {
+        self.state_dir.join(format!("{}.{}", job_id, marker))
+    }
+
+    fn read_record(&self, job_id: &str) -> Result<Option<FakeJobRecord>> {
+        let path = self.record_path(job_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read fake job record {:?}", path))?;
+        if contents.is_empty() {
+            // The ID was just reserved by a concurrent submission.
+            return Ok(None);
+        }
+        let record = serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse fake job record {:?}", path))?;
+        Ok(Some(record))
+    }
+
+    fn write_record(&self, record: &FakeJobRecord) -> Result<()> {
+        let path = self.record_path(&record.job_id);
+        fs::write(&path, serde_json::to_string_pretty(record)?)
+            .with_context(|| format!("Failed to write fake job record {:?}", path))
+    }
+
+    fn all_records(&self) -> Result<Vec<FakeJobRecord>> {
+        let mut records = Vec::new();
+        for entry in fs::read_dir(&self.state_dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            if let Some(job_id) = path.file_stem().and_then(|s| s.to_str())
+                && let Some(record) = self.read_record(job_id)?
+            {
+                records.push(record);
+            }
+        }
+        records.sort_by_key(|r| r.job_id.parse::<i64>().unwrap_or(0));
+        Ok(records)
+    }
+
+    /// Reserve the next job ID by atomically creating its record file
+    fn reserve_job_id(&self) -> Result<String> {
+        let mut next = self
+            .all_records()?
+            .iter()
+            .filter_map(|r| r.job_id.parse::<i64>().ok())
+            .max()
+            .map(|id| id + 1)
+            .unwrap_or(1);
+        loop {
+            let job_id = next.to_string();
+            match fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(self.record_path(&job_id))
+            {
+                Ok(_) => return Ok(job_id),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => next += 1,
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    fn state(&self, record: &FakeJobRecord) -> FakeJobState {
+        if self.marker_path(&record.job_id, "exit").exists() || !is_process_alive(record.pid) {
+            FakeJobState::Finished
+        } else if self.marker_path(&record.job_id, "start").exists() {
+            FakeJobState::Running
+        } else {
+            FakeJobState::Pending
+        }
+    }
+
+    fn read_marker(&self, job_id: &str, marker: &str) -> Option<String> {
+        fs::read_to_string(self.marker_path(job_id, marker))
+            .ok()
+            .map(|s| s.trim().to_string())
+    }
+
+    fn marker_time(&self, job_id: &str, marker: &str) -> Option<DateTime<Utc>> {
+        let modified = fs::metadata(self.marker_path(job_id, marker))
+            .ok()?
+            .modified()
+            .ok()?;
+        Some(DateTime::<Utc>::from(modified))
+    }
+
+    /// Slurm-style final state name for a finished allocation
+    fn final_state(&self, job_id: &str) -> String {
+        if let Some(reason) = self.read_marker(job_id, "reason") {
+            return reason;
+        }
+        match self.read_marker(job_id, "exit").as_deref() {
+            Some("0") => "COMPLETED".to_string(),
+            Some(_) => "FAILED".to_string(),
+            None => REASON_NODE_FAIL.to_string(),
+        }
+    }
+
+    /// Parse `#FAKE --key=value` directives from a submission script
+    pub fn parse_directives(script: &str) -> HashMap<String, String> {
+        script
+            .lines()
+            .filter_map(|line| line.strip_prefix("#FAKE --"))
+            .filter_map(|directive| {
+                let (key, value) = directive.split_once('=')?;
+                Some((key.trim().to_string(), value.trim().to_string()))
+            })
+            .collect()
+    }
+
+    /// Build the wrapper that simulates the allocation lifecycle around a submission script
+    fn wrapper_script(
+        &self,
+        record: &FakeJobRecord,
+        script: &Path,
+        stdout_path: &str,
+        stderr_path: &str,
+    ) -> String {
+        let dir = self.state_dir.display();
+        let id = &record.job_id;
+        let walltime = record.walltime_secs;
+        let grace = env_u64("TORC_FAKE_HPC_KILL_GRACE").unwrap_or(DEFAULT_KILL_GRACE_SECS);
+
+        // The watchdog ends the allocation at whichever comes first: walltime or node failure.
+        let watchdog = match record.node_failure_after_secs {
+            Some(fail_after) if fail_after < walltime => format!(
+                "sleep {fail_after}\n    echo {REASON_NODE_FAIL} > \"{dir}/{id}.reason\"\n    kill -KILL 0\n"
+            ),
+            _ => format!(
+                "sleep {walltime}\n    echo {REASON_TIMEOUT} > \"{dir}/{id}.reason\"\n    kill -TERM 0\n    sleep {grace}\n    kill -KILL 0\n"
+            ),
+        };
+
+        format!(
+            "#!/bin/bash\n\
+             # Simulated allocation {id}\n\
+             sleep {delay}\n\
+             date +%s > \"{dir}/{id}.start\"\n\
+             export TORC_FAKE_HPC_JOB_ID={id}\n\
+             export TORC_FAKE_HPC_JOB_NAME=\"{name}\"\n\
+             export TORC_FAKE_HPC_NUM_NODES={nodes}\n\
+             export TORC_FAKE_HPC_END_TIME=$(( $(date +%s) + {walltime} ))\n\
+             bash \"{script}\" > \"{stdout_path}\" 2> \"{stderr_path}\" < /dev/null &\n\
+             child=$!\n\
+             (\n    trap '' TERM\n    {watchdog})&\n\
+             watchdog=$!\n\
+             trap '' TERM\n\
+             wait $child\n\
+             rc=$?\n\
+             kill -KILL $watchdog 2> /dev/null\n\
+             echo $rc > \"{dir}/{id}.exit\"\n",
+            delay = record.queue_delay_secs,
+            name = record.name,
+            nodes = record.num_nodes,
+            script = script.display(),
+        )
+    }
+}
+
+impl HpcInterface for FakeHpcInterface {
+    fn cancel_job(&self, job_id: &str) -> Result<i32> {
+        let Some(record) = self.read_record(job_id)? else {
+            error!(
+                "Failed to cancel fake HPC job {}: job does not exist",
+                job_id
+            );
+            return Ok(1);
+        };
+
+        if self.state(&record) != FakeJobState::Finished {
+            fs::write(self.marker_path(job_id, "reason"), REASON_CANCELLED)?;
+            signal_process_group(record.pid, libc::SIGTERM);
+        }
+        info!("Canceled fake HPC job {}", job_id);
+        Ok(0)
+    }
+
+    fn get_status(&self, job_id: &str) -> Result<HpcJobInfo> {
+        let Some(record) = self.read_record(job_id)? else {
+            return Ok(HpcJobInfo::none());
+        };
+        let status = match self.state(&record) {
+            FakeJobState::Pending => HpcJobStatus::Queued,
+            FakeJobState::Running => HpcJobStatus::Running,
+            FakeJobState::Finished => HpcJobStatus::Complete,
+        };
+        Ok(HpcJobInfo::new(record.job_id, record.name, status))
+    }
+
+    fn get_statuses(&self) -> Result<HashMap<String, HpcJobStatus>> {
+        // Like squeue, only list allocations that are still in the queue.
+        let mut statuses = HashMap::new();
+        for record in self.all_records()? {
+            match self.state(&record) {
+                FakeJobState::Pending => {
+                    statuses.insert(record.job_id, HpcJobStatus::Queued);
+                }
+                FakeJobState::Running => {
+                    statuses.insert(record.job_id, HpcJobStatus::Running);
+                }
+                FakeJobState::Finished => {}
+            }
+        }
+        Ok(statuses)
+    }
+
+    fn create_submission_script(
+        &self,
+        name: &str,
+        server_url: &str,
+        workflow_id: i64,
+        output_path: &str,
+        poll_interval: i32,
+        max_parallel_jobs: Option<i32>,
+        filename: &Path,
+        config: &HashMap<String, String>,
+        start_one_worker_per_node: bool,
+    ) -> Result<()> {
+        let walltime = config
+            .get("walltime")
+            .context("Missing 'walltime' in config")?;
+        let mut script = format!(
+            "#!/bin/bash\n\
+             #FAKE --job-name={}\n\
+             #FAKE --walltime={}\n\
+             #FAKE --nodes={}\n\
+             #FAKE --output={}/slurm_output_wf{}_sl%j.o\n\
+             #FAKE --error={}/slurm_output_wf{}_sl%j.e\n",
+            name,
+            walltime,
+            config.get("nodes").map(|s| s.as_str()).unwrap_or("1"),
+            output_path,
+            workflow_id,
+            output_path,
+            workflow_id,
+        );
+        for key in ["account", "partition"] {
+            if let Some(value) = config.get(key) {
+                script.push_str(&format!("#FAKE --{}={}\n", key, value));
+            }
+        }
+
+        script.push_str(&format!("\nTORC_URL=\"{}\"\n\n", server_url));
+
+        let mut command = format!(
+            "torc-slurm-job-runner $TORC_URL {} {} --hpc-type fake --poll-interval {}",
+            workflow_id, output_path, poll_interval
+        );
+        if let Some(max_jobs) = max_parallel_jobs {
+            command.push_str(&format!(" --max-parallel-jobs {}", max_jobs));
+        }
+
+        if start_one_worker_per_node {
+            script.push_str(
+                "for node_id in $(seq 0 $((TORC_FAKE_HPC_NUM_NODES - 1))); do\n    TORC_FAKE_HPC_NODE_ID=$node_id ",
+            );
+            script.push_str(&command);
+            script.push_str(" --is-subtask --worker-per-node &\ndone\nwait\n");
+        } else {
+            script.push_str(&command);
+            script.push('\n');
+        }
+
+        fs::write(filename, script)
+            .with_context(|| format!("Failed to write submission script to {:?}", filename))?;
+
+        #[cfg(unix)]
+        {
+            let mut perms = fs::metadata(filename)?.permissions();
+            perms.set_mode(0o755);
+            fs::set_permissions(filename, perms)?;
+        }
+
+        debug!("Created submission script: {:?}", filename);
+        Ok(())
+    }
+
+    fn get_current_job_id(&self) -> String {
+        env::var("TORC_FAKE_HPC_JOB_ID").expect("TORC_FAKE_HPC_JOB_ID environment variable not set")
+    }
+
+    fn get_environment_variables(&self) -> HashMap<String, String> {
+        env::vars()
+            .filter(|(k, _)| k.starts_with("TORC_FAKE_HPC_"))
+            .collect()
+    }
+
+    fn get_job_end_time(&self) -> Result<DateTime<Utc>> {
+        let end_time: i64 = env::var("TORC_FAKE_HPC_END_TIME")
+            .context("TORC_FAKE_HPC_END_TIME environment variable not set")?
+            .parse()
+            .context("Failed to parse TORC_FAKE_HPC_END_TIME")?;
+        Utc.timestamp_opt(end_time, 0)
+            .single()
+            .context("Invalid TORC_FAKE_HPC_END_TIME")
+    }
+
+    fn get_job_stats(&self, job_id: &str) -> Result<HpcJobStats> {
+        let record = self
+            .read_record(job_id)?
+            .with_context(|| format!("Fake HPC job {} does not exist", job_id))?;
+
+        let state = self.state(&record);
+        let start = self
+            .marker_time(job_id, "start")
+            .unwrap_or(record.submit_time);
+        let (end, state) = match state {
+            FakeJobState::Pending => (None, "PENDING".to_string()),
+            FakeJobState::Running => (None, "RUNNING".to_string()),
+            FakeJobState::Finished => (
+                self.marker_time(job_id, "exit")
+                    .or_else(|| self.marker_time(job_id, "reason"))
+                    .or(Some(start)),
+                self.final_state(job_id),
+            ),
+        };
+
+        Ok(HpcJobStats {
+            hpc_job_id: record.job_id,
+            name: record.name,
+            start,
+            end,
+            state,
+            account: record.account,
+            partition: record.partition,
+            qos: String::new(),
+        })
+    }
+
+    fn get_local_scratch(&self) -> Result<String> {
+        Ok(env::temp_dir().to_string_lossy().to_string())
+    }
+
+    fn get_memory_gb(&self) -> f64 {
+        if let Some(memory) = env::var("TORC_FAKE_HPC_MEMORY_GB_PER_NODE")
+            .ok()
+            .and_then(|v| v.parse::<f64>().ok())
+        {
+            return memory;
+        }
+        let sys = System::new_with_specifics(RefreshKind::new().with_memory());
+        let total = sys.total_memory() as f64 / (1024.0 * 1024.0 * 1024.0);
+        total / self.get_num_nodes() as f64
+    }
+
+    fn get_node_id(&self) -> String {
+        env::var("TORC_FAKE_HPC_NODE_ID").unwrap_or_else(|_| "0".to_string())
+    }
+
+    fn get_num_cpus(&self) -> usize {
+        if let Some(cpus) = env_u64("TORC_FAKE_HPC_CPUS_PER_NODE") {
+            return cpus as usize;
+        }
+        let total = thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        (total / self.get_num_nodes()).max(1)
+    }
+
+    fn get_num_cpus_per_task(&self) -> usize {
+        // Each simulated node runs one worker.
+        self.get_num_cpus()
+    }
+
+    fn get_num_gpus(&self) -> usize {
+        env_u64("TORC_FAKE_HPC_GPUS_PER_NODE").unwrap_or(0) as usize
+    }
+
+    fn get_num_nodes(&self) -> usize {
+        env_u64("TORC_FAKE_HPC_NUM_NODES").unwrap_or(1).max(1) as usize
+    }
+
+    fn get_task_pid(&self) -> usize {
+        std::process::id() as usize
+    }
+
+    fn is_head_node(&self) -> bool {
+        self.get_node_id() == "0"
+    }
+
+    fn list_active_nodes(&self, job_id: &str) -> Result<Vec<String>> {
+        let record = self
+            .read_record(job_id)?
+            .with_context(|| format!("Fake HPC job {} does not exist", job_id))?;
+        let hostname = hostname::get()?.to_string_lossy().to_string();
+        Ok(vec![hostname; record.num_nodes as usize])
+    }
+
+    fn submit(&self, filename: &Path) -> Result<(i32, String, String)> {
+        let script = fs::read_to_string(filename)
+            .with_context(|| format!("Failed to read submission script {:?}", filename))?;
+        let directives = Self::parse_directives(&script);
+
+        let walltime_secs = match directives.get("walltime") {
+            Some(walltime) => parse_walltime_secs(walltime).map_err(|e| anyhow::anyhow!(e))?,
+            None => DEFAULT_WALLTIME_SECS,
+        };
+        let num_nodes = match directives.get("nodes") {
+            Some(nodes) => nodes
+                .parse()
+                .with_context(|| format!("Invalid nodes directive '{}'", nodes))?,
+            None => 1,
+        };
+        let queue_delay_secs = directive_u64(&directives, "queue-delay")?
+            .or_else(|| env_u64("TORC_FAKE_HPC_QUEUE_DELAY"))
+            .unwrap_or(DEFAULT_QUEUE_DELAY_SECS);
+        let node_failure_after_secs = directive_u64(&directives, "node-failure-after")?
+            .or_else(|| env_u64("TORC_FAKE_HPC_NODE_FAILURE_AFTER"));
+
+        let job_id = self.reserve_job_id()?;
+        let mut record = FakeJobRecord {
+            job_id: job_id.clone(),
+            name: directives
+                .get("job-name")
+                .cloned()
+                .unwrap_or_else(|| "fake_job".to_string()),
+            account: directives.get("account").cloned().unwrap_or_default(),
+            partition: directives.get("partition").cloned().unwrap_or_default(),
+            num_nodes,
+            walltime_secs,
+            queue_delay_secs,
+            node_failure_after_secs,
+            submit_time: Utc::now(),
+            pid: 0,
+        };
+
+        let default_output = self.state_dir.join(format!("{}.o", job_id));
+        let default_error = self.state_dir.join(format!("{}.e", job_id));
+        let stdout_path = directives
+            .get("output")
+            .map(|p| p.replace("%j", &job_id))
+            .unwrap_or_else(|| default_output.display().to_string());
+        let stderr_path = directives
+            .get("error")
+            .map(|p| p.replace("%j", &job_id))
+            .unwrap_or_else(|| default_error.display().to_string());
+
+        // Keep a copy of the script so that the caller can delete theirs after submission.
+        let script_copy = self.marker_path(&job_id, "sh");
+        fs::copy(filename, &script_copy)?;
+        let wrapper_path = self.marker_path(&job_id, "wrapper.sh");
+        fs::write(
+            &wrapper_path,
+            self.wrapper_script(&record, &script_copy, &stdout_path, &stderr_path),
+        )?;
+
+        // Run the allocation in its own process group so that it survives the submitting
+        // process and can be signaled as a whole, like the processes of a real allocation.
+        let mut command = Command::new("bash");
+        command
+            .arg(&wrapper_path)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+        #[cfg(unix)]
+        command.process_group(0);
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(e) => {
+                let _ = fs::remove_file(self.record_path(&job_id));
+                return Ok((
+                    1,
+                    String::new(),
+                    format!("Failed to start allocation: {}", e),
+                ));
+            }
+        };
+        record.pid = child.id();
+        self.write_record(&record)?;
+
+        // Reap the wrapper when it exits so that it does not linger as a zombie while this
+        // process is still alive.
+        thread::spawn(move || {
+            let _ = child.wait();
+        });
+
+        info!(
+            "Submitted fake HPC job {} (queue delay {}s, walltime {}s)",
+            job_id, queue_delay_secs, walltime_secs
+        );
+        Ok((0, job_id, String::new()))
+    }
+}
+
+fn env_u64(name: &str) -> Option<u64> {
+    let value = env::var(name).ok()?;
+    match value.parse() {
+        Ok(v) => Some(v),
+        Err(_) => {
+            warn!("Ignoring invalid value for {}: {}", name, value);
+            None
+        }
+    }
+}
+
+fn directive_u64(directives: &HashMap<String, String>, key: &str) -> Result<Option<u64>> {
+    directives
+        .get(key)
+        .map(|v| {
+            v.parse()
+                .with_context(|| format!("Invalid {} directive '{}'", key, v))
+        })
+        .transpose()
+}
+
+#[cfg(unix)]
+fn is_process_alive(pid: u32) -> bool {
+    if pid == 0 {
+        // The record was reserved but the allocation has not been started yet.
+        return true;
+    }
+    let ret = unsafe { libc::kill(pid as libc::pid_t, 0) };
+    ret == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(not(unix))]
+fn is_process_alive(_pid: u32) -> bool {
+    false
+}
+
+#[cfg(unix)]
+fn signal_process_group(pid: u32, signal: i32) {
+    if pid == 0 {
+        return;
+    }
+    let ret = unsafe { libc::kill(-(pid as libc::pid_t), signal) };
+    if ret != 0 {
+        debug!(
+            "Failed to signal process group {}: {}",
+            pid,
+            std::io::Error::last_os_error()
+        );
+    }
+}
+
+#[cfg(not(unix))]
+fn signal_process_group(_pid: u32, _signal: i32) {}
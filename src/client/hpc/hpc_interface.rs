@@ -17,6 +17,9 @@ use super::common::{HpcJobInfo, HpcJobStats, HpcJobStatus};
 pub trait HpcInterface: Send + Sync {
     /// Cancel a job by ID
     ///
+    /// Tasks of a job array are identified as `<array_job_id>_<array_task_id>`, here and in
+    /// the other methods that take or return job IDs.
+    ///
     /// # Arguments
     /// * `job_id` - The HPC job ID to cancel
     ///
@@ -40,7 +43,7 @@ pub trait HpcInterface: Send + Sync {
     /// Handles transient errors with retries.
     ///
     /// # Returns
-    /// HashMap mapping job_id to HpcJobStatus
+    /// HashMap mapping job_id to HpcJobStatus, with one entry per task of a job array
     fn get_statuses(&self) -> Result<HashMap<String, HpcJobStatus>>;
 
     /// Create a submission script for the HPC scheduler
@@ -53,7 +56,8 @@ pub trait HpcInterface: Send + Sync {
     /// * `poll_interval` - Poll interval in seconds for the job runner
     /// * `max_parallel_jobs` - Optional maximum number of parallel jobs
     /// * `filename` - Path where the submission script should be written
-    /// * `config` - Configuration parameters for the HPC scheduler
+    /// * `config` - Configuration parameters for the HPC scheduler. An `array` entry (e.g.,
+    ///   "0-9") submits the script as a job array, if the scheduler supports them.
     /// * `start_one_worker_per_node` - If true, start a torc worker on each compute node
     #[allow(clippy::too_many_arguments)]
     fn create_submission_script(
@@ -0,0 +1,638 @@
+//! PBS Pro / Torque scheduler interface implementation
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
+use log::{debug, error, info, trace, warn};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::thread;
+use std::time::Duration;
+use sysinfo::{RefreshKind, System, SystemExt};
+
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+use super::common::{HpcJobInfo, HpcJobStats, HpcJobStatus};
+use super::hpc_interface::HpcInterface;
+
+/// Time format used by `qstat -f` for fields like `stime` and `mtime`
+const QSTAT_TIME_FORMAT: &str = "%a %b %e %H:%M:%S %Y";
+
+/// PBS Pro / Torque scheduler implementation
+///
+/// Job IDs returned by `qsub` have the form `<number>.<server>`. Torc stores only the
+/// numeric part (scheduled compute node records use an integer scheduler ID), and all
+/// PBS commands accept the short form.
+pub struct PbsInterface {
+    user: String,
+    qsub_regex: Regex,
+}
+
+impl PbsInterface {
+    /// Create a new PBS interface
+    pub fn new() -> Result<Self> {
+        let user = env::var("USER").or_else(|_| env::var("USERNAME"))?;
+        let qsub_regex = Regex::new(r"^(\d+)(\.\S+)?$")?;
+
+        Ok(Self { user, qsub_regex })
+    }
+
+    /// Map a PBS job state letter to HpcJobStatus
+    ///
+    /// PBS Pro and Torque share most of these codes. `E` (exiting) still holds the
+    /// allocation, so it is treated as running.
+    pub fn map_status(pbs_state: &str) -> HpcJobStatus {
+        match pbs_state {
+            "Q" | "H" | "W" | "T" | "S" | "U" => HpcJobStatus::Queued,
+            "R" | "E" | "B" | "X" => HpcJobStatus::Running,
+            "F" | "C" => HpcJobStatus::Complete,
+            _ => HpcJobStatus::Unknown,
+        }
+    }
+
+    /// Strip the server suffix from a PBS job ID (`1234.pbs01` -> `1234`)
+    pub fn short_job_id(job_id: &str) -> &str {
+        job_id.split('.').next().unwrap_or(job_id)
+    }
+
+    /// Convert a walltime in Slurm notation (`D-HH:MM:SS`, `HH:MM:SS`, `MM:SS`) to the
+    /// `HH:MM:SS` notation required by PBS.
+    pub fn to_pbs_walltime(walltime: &str) -> Result<String> {
+        let walltime = walltime.trim();
+        let (days, rest) = match walltime.split_once('-') {
+            Some((days, rest)) => (
+                days.parse::<u64>()
+                    .with_context(|| format!("Invalid days in walltime '{}'", walltime))?,
+                rest,
+            ),
+            None => (0, walltime),
+        };
+
+        let parts = rest
+            .split(':')
+            .map(|p| p.parse::<u64>())
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .with_context(|| format!("Invalid walltime '{}'", walltime))?;
+        let (hours, minutes, seconds) = match parts.as_slice() {
+            [h] if days > 0 => (*h, 0, 0),
+            [m] => (0, *m, 0),
+            [h, m] if days > 0 => (*h, *m, 0),
+            [m, s] => (0, *m, *s),
+            [h, m, s] => (*h, *m, *s),
+            _ => return Err(anyhow::anyhow!("Invalid walltime '{}'", walltime)),
+        };
+
+        let total = days * 86400 + hours * 3600 + minutes * 60 + seconds;
+        Ok(format!(
+            "{:02}:{:02}:{:02}",
+            total / 3600,
+            (total % 3600) / 60,
+            total % 60
+        ))
+    }
+
+    /// Convert a Slurm memory string (`240G`, `512M`) to PBS notation (`240gb`, `512mb`)
+    fn to_pbs_memory(mem: &str) -> String {
+        let mem = mem.trim().to_lowercase();
+        if mem.ends_with('b') {
+            mem
+        } else if mem.ends_with(['k', 'm', 'g', 't']) {
+            format!("{}b", mem)
+        } else {
+            // Slurm's default unit is megabytes
+            format!("{}mb", mem)
+        }
+    }
+
+    /// Extract the GPU count from a Slurm gres string (`gpu:2`, `gpu:h100:4`)
+    fn gpus_from_gres(gres: &str) -> Option<u32> {
+        gres.split(',')
+            .filter(|entry| entry.starts_with("gpu"))
+            .filter_map(|entry| entry.rsplit(':').next()?.parse::<u32>().ok())
+            .next()
+    }
+
+    /// Parse the `key = value` output of `qstat -f` into a map.
+    ///
+    /// Long values are wrapped onto continuation lines that start with a tab; these are
+    /// joined back onto the previous value.
+    pub fn parse_qstat_full(output: &str) -> HashMap<String, String> {
+        let mut attributes: HashMap<String, String> = HashMap::new();
+        let mut last_key: Option<String> = None;
+        for line in output.lines() {
+            if line.trim().is_empty() || line.starts_with("Job Id:") {
+                continue;
+            }
+            if line.starts_with('\t')
+                && !line.contains(" = ")
+                && let Some(key) = &last_key
+            {
+                if let Some(value) = attributes.get_mut(key) {
+                    value.push_str(line.trim());
+                }
+                continue;
+            }
+            if let Some((key, value)) = line.split_once(" = ") {
+                let key = key.trim().to_string();
+                attributes.insert(key.clone(), value.trim().to_string());
+                last_key = Some(key);
+            }
+        }
+        attributes
+    }
+
+    /// Parse the tabular output of `qstat` or `qstat -u <user>` into (job_id, state) pairs.
+    ///
+    /// Both PBS Pro and Torque print the job ID in the first column and the single-letter
+    /// state in the second-to-last column. Header and separator lines are skipped.
+    pub fn parse_qstat_table(output: &str) -> Vec<(String, String)> {
+        Self::qstat_table_rows(output)
+            .map(|fields| {
+                (
+                    Self::short_job_id(fields[0]).to_string(),
+                    fields[fields.len() - 2].to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn qstat_table_rows(output: &str) -> impl Iterator<Item = Vec<&str>> {
+        output
+            .lines()
+            .map(|line| line.split_whitespace().collect::<Vec<_>>())
+            .filter(|fields| {
+                fields.len() >= 3 && fields[0].starts_with(|c: char| c.is_ascii_digit())
+            })
+    }
+
+    /// Get the qsub executable path (allows for testing with fake binary)
+    fn get_qsub_exec() -> String {
+        env::var("TORC_FAKE_QSUB").unwrap_or_else(|_| "qsub".to_string())
+    }
+
+    /// Get the qstat executable path (allows for testing with fake binary)
+    fn get_qstat_exec() -> String {
+        env::var("TORC_FAKE_QSTAT").unwrap_or_else(|_| "qstat".to_string())
+    }
+
+    /// Get the qdel executable path (allows for testing with fake binary)
+    fn get_qdel_exec() -> String {
+        env::var("TORC_FAKE_QDEL").unwrap_or_else(|_| "qdel".to_string())
+    }
+
+    /// Run a command with retries for transient errors
+    fn run_command_with_retries(
+        &self,
+        cmd: &str,
+        args: &[&str],
+        num_retries: usize,
+        retry_delay_secs: u64,
+        ignore_errors: &[&str],
+    ) -> Result<(i32, String, String)> {
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            trace!("Running command: {} {:?} (attempt {})", cmd, args, attempts);
+
+            let output = Command::new(cmd).args(args).output()?;
+
+            let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            let return_code = output.status.code().unwrap_or(-1);
+
+            let should_ignore = ignore_errors
+                .iter()
+                .any(|err| stderr.contains(err) || stdout.contains(err));
+
+            if return_code == 0 || should_ignore || attempts >= num_retries {
+                return Ok((return_code, stdout, stderr));
+            }
+
+            warn!(
+                "Command failed (attempt {}/{}): {} - {}",
+                attempts, num_retries, return_code, stderr
+            );
+
+            if attempts < num_retries {
+                thread::sleep(Duration::from_secs(retry_delay_secs));
+            }
+        }
+    }
+
+    /// Run `qstat -f` for a job and return its attributes
+    fn qstat_full(&self, job_id: &str, include_finished: bool) -> Result<HashMap<String, String>> {
+        let qstat = Self::get_qstat_exec();
+        let mut args = Vec::new();
+        if include_finished {
+            args.push("-x");
+        }
+        args.extend(["-f", job_id]);
+
+        let output = Command::new(&qstat).args(&args).output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!(
+                "qstat -f failed for job {}: {}",
+                job_id,
+                stderr.trim()
+            ));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(Self::parse_qstat_full(&stdout))
+    }
+
+    fn parse_qstat_time(value: &str) -> Result<DateTime<Utc>> {
+        let naive = NaiveDateTime::parse_from_str(value.trim(), QSTAT_TIME_FORMAT)
+            .with_context(|| format!("Failed to parse qstat timestamp '{}'", value))?;
+        let local_dt = Local
+            .from_local_datetime(&naive)
+            .single()
+            .context("Ambiguous local timestamp")?;
+        Ok(local_dt.with_timezone(&Utc))
+    }
+}
+
+impl HpcInterface for PbsInterface {
+    fn cancel_job(&self, job_id: &str) -> Result<i32> {
+        let qdel = Self::get_qdel_exec();
+        let output = Command::new(&qdel).arg(job_id).output()?;
+
+        let return_code = output.status.code().unwrap_or(-1);
+        if return_code != 0 {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            error!("Failed to cancel PBS job {}: {}", job_id, stderr);
+        } else {
+            info!("Canceled PBS job {}", job_id);
+        }
+
+        Ok(return_code)
+    }
+
+    fn get_status(&self, job_id: &str) -> Result<HpcJobInfo> {
+        let qstat = Self::get_qstat_exec();
+
+        let (return_code, stdout, stderr) = self.run_command_with_retries(
+            &qstat,
+            &[job_id],
+            6,
+            10,
+            &["Unknown Job Id", "Job has finished"],
+        )?;
+
+        if return_code != 0 {
+            if stderr.contains("Unknown Job Id") || stderr.contains("Job has finished") {
+                return Ok(HpcJobInfo::none());
+            }
+
+            return Err(anyhow::anyhow!(
+                "qstat command failed: {} - {}",
+                return_code,
+                stderr
+            ));
+        }
+
+        trace!("qstat output: [{}]", stdout);
+        // The default format is: Job id, Name, User, Time Use, S, Queue
+        let short_id = Self::short_job_id(job_id);
+        match Self::qstat_table_rows(&stdout).find(|f| Self::short_job_id(f[0]) == short_id) {
+            Some(fields) => Ok(HpcJobInfo::new(
+                short_id.to_string(),
+                fields[1].to_string(),
+                Self::map_status(fields[fields.len() - 2]),
+            )),
+            None => Ok(HpcJobInfo::none()),
+        }
+    }
+
+    fn get_statuses(&self) -> Result<HashMap<String, HpcJobStatus>> {
+        let qstat = Self::get_qstat_exec();
+
+        let (return_code, stdout, stderr) =
+            self.run_command_with_retries(&qstat, &["-u", &self.user], 6, 10, &[])?;
+
+        if return_code != 0 {
+            return Err(anyhow::anyhow!(
+                "qstat command failed: {} - {}",
+                return_code,
+                stderr
+            ));
+        }
+
+        trace!("qstat output: [{}]", stdout);
+
+        Ok(Self::parse_qstat_table(&stdout)
+            .into_iter()
+            .map(|(job_id, state)| (job_id, Self::map_status(&state)))
+            .collect())
+    }
+
+    fn create_submission_script(
+        &self,
+        name: &str,
+        server_url: &str,
+        workflow_id: i64,
+        output_path: &str,
+        poll_interval: i32,
+        max_parallel_jobs: Option<i32>,
+        filename: &Path,
+        config: &HashMap<String, String>,
+        start_one_worker_per_node: bool,
+    ) -> Result<()> {
+        let walltime = config
+            .get("walltime")
+            .context("Missing 'walltime' in config")?;
+        let mut script = format!(
+            "#!/bin/bash\n\
+             #PBS -A {}\n\
+             #PBS -N {}\n\
+             #PBS -l walltime={}\n\
+             #PBS -o {}/pbs_output_wf{}_{}.o\n\
+             #PBS -e {}/pbs_output_wf{}_{}.e\n",
+            config
+                .get("account")
+                .context("Missing 'account' in config")?,
+            name,
+            Self::to_pbs_walltime(walltime)?,
+            output_path,
+            workflow_id,
+            name,
+            output_path,
+            workflow_id,
+            name,
+        );
+
+        // Build the select statement from nodes, memory, and GPUs.
+        let mut select = format!(
+            "select={}",
+            config.get("nodes").map(|s| s.as_str()).unwrap_or("1")
+        );
+        if let Some(mem) = config.get("mem") {
+            select.push_str(&format!(":mem={}", Self::to_pbs_memory(mem)));
+        }
+        if let Some(gpus) = config.get("gres").and_then(|g| Self::gpus_from_gres(g)) {
+            select.push_str(&format!(":ngpus={}", gpus));
+        }
+        script.push_str(&format!("#PBS -l {}\n", select));
+
+        if let Some(queue) = config.get("partition") {
+            script.push_str(&format!("#PBS -q {}\n", queue));
+        }
+
+        for key in config.keys() {
+            match key.as_str() {
+                "account" | "walltime" | "nodes" | "mem" | "gres" | "partition" | "extra" => {}
+                _ => debug!("Ignoring scheduler parameter '{}' for PBS", key),
+            }
+        }
+
+        if let Some(extra) = config.get("extra") {
+            script.push_str(&format!("#PBS {}\n", extra));
+        }
+
+        // PBS starts jobs in the user's home directory.
+        script.push_str("\ncd \"$PBS_O_WORKDIR\"\n\n");
+        script.push_str(&format!("TORC_URL=\"{}\"\n\n", server_url));
+
+        let mut command = format!(
+            "torc-slurm-job-runner $TORC_URL {} {} --hpc-type pbs --poll-interval {}",
+            workflow_id, output_path, poll_interval
+        );
+
+        if let Some(max_jobs) = max_parallel_jobs {
+            command.push_str(&format!(" --max-parallel-jobs {}", max_jobs));
+        }
+
+        if start_one_worker_per_node {
+            // pbsdsh starts one copy per node and sets PBS_NODENUM for each task.
+            script.push_str("pbsdsh -u -- bash -lc \"cd $PBS_O_WORKDIR && ");
+            script.push_str(&command);
+            script.push_str(" --is-subtask --worker-per-node\"\n");
+        } else {
+            script.push_str(&command);
+            script.push('\n');
+        }
+
+        fs::write(filename, script)
+            .with_context(|| format!("Failed to write submission script to {:?}", filename))?;
+
+        #[cfg(unix)]
+        {
+            let mut perms = fs::metadata(filename)?.permissions();
+            perms.set_mode(0o755);
+            fs::set_permissions(filename, perms)?;
+        }
+
+        debug!("Created submission script: {:?}", filename);
+        Ok(())
+    }
+
+    fn get_current_job_id(&self) -> String {
+        let job_id = env::var("PBS_JOBID").expect("PBS_JOBID environment variable not set");
+        Self::short_job_id(&job_id).to_string()
+    }
+
+    fn get_environment_variables(&self) -> HashMap<String, String> {
+        env::vars()
+            .filter(|(k, _)| k.starts_with("PBS_") || k == "NCPUS")
+            .collect()
+    }
+
+    fn get_job_end_time(&self) -> Result<DateTime<Utc>> {
+        // Check for fake/test mode
+        if env::var("TORC_FAKE_QSUB").is_ok() {
+            return Ok(Utc::now() + chrono::Duration::days(10));
+        }
+
+        let job_id = self.get_current_job_id();
+        let attributes = self.qstat_full(&job_id, false)?;
+        let walltime = attributes
+            .get("Resource_List.walltime")
+            .context("qstat output does not contain Resource_List.walltime")?;
+        let requested = hms_to_seconds(walltime)?;
+        let used = attributes
+            .get("resources_used.walltime")
+            .map(|w| hms_to_seconds(w))
+            .transpose()?
+            .unwrap_or(0);
+
+        Ok(Utc::now() + chrono::Duration::seconds(requested - used))
+    }
+
+    fn get_job_stats(&self, job_id: &str) -> Result<HpcJobStats> {
+        let attributes = self.qstat_full(job_id, true)?;
+
+        let start = attributes
+            .get("stime")
+            .context("qstat output does not contain a start time")?;
+        let start = Self::parse_qstat_time(start)?;
+        let state = attributes
+            .get("job_state")
+            .cloned()
+            .unwrap_or_else(|| "U".to_string());
+        let end = if Self::map_status(&state) == HpcJobStatus::Complete {
+            attributes
+                .get("obittime")
+                .or_else(|| attributes.get("mtime"))
+                .map(|t| Self::parse_qstat_time(t))
+                .transpose()?
+        } else {
+            None
+        };
+
+        Ok(HpcJobStats {
+            hpc_job_id: Self::short_job_id(job_id).to_string(),
+            name: attributes.get("Job_Name").cloned().unwrap_or_default(),
+            start,
+            end,
+            state,
+            account: attributes.get("Account_Name").cloned().unwrap_or_default(),
+            partition: attributes.get("queue").cloned().unwrap_or_default(),
+            qos: String::new(),
+        })
+    }
+
+    fn get_local_scratch(&self) -> Result<String> {
+        for key in &["TMPDIR", "PBS_TMPDIR"] {
+            if let Ok(value) = env::var(key) {
+                return Ok(value);
+            }
+        }
+
+        Ok(env::temp_dir().to_string_lossy().to_string())
+    }
+
+    fn get_memory_gb(&self) -> f64 {
+        // PBS does not export the memory request, so report what the node has.
+        let sys = System::new_with_specifics(RefreshKind::new().with_memory());
+        sys.total_memory() as f64 / (1024.0 * 1024.0 * 1024.0)
+    }
+
+    fn get_node_id(&self) -> String {
+        env::var("PBS_NODENUM").unwrap_or_else(|_| "0".to_string())
+    }
+
+    fn get_num_cpus(&self) -> usize {
+        for key in &["NCPUS", "PBS_NUM_PPN"] {
+            if let Ok(value) = env::var(key)
+                && let Ok(cpus) = value.parse()
+            {
+                return cpus;
+            }
+        }
+        num_cpus_from_system()
+    }
+
+    fn get_num_cpus_per_task(&self) -> usize {
+        self.get_num_cpus()
+    }
+
+    fn get_num_gpus(&self) -> usize {
+        match env::var("CUDA_VISIBLE_DEVICES") {
+            Ok(devices) if !devices.trim().is_empty() => devices.split(',').count(),
+            _ => 0,
+        }
+    }
+
+    fn get_num_nodes(&self) -> usize {
+        if let Ok(nodes) = env::var("PBS_NUM_NODES")
+            && let Ok(nodes) = nodes.parse()
+        {
+            return nodes;
+        }
+        env::var("PBS_NODEFILE")
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|contents| {
+                contents
+                    .lines()
+                    .map(str::trim)
+                    .filter(|l| !l.is_empty())
+                    .collect::<HashSet<_>>()
+                    .len()
+            })
+            .filter(|n| *n > 0)
+            .unwrap_or(1)
+    }
+
+    fn get_task_pid(&self) -> usize {
+        std::process::id() as usize
+    }
+
+    fn is_head_node(&self) -> bool {
+        self.get_node_id() == "0"
+    }
+
+    fn list_active_nodes(&self, job_id: &str) -> Result<Vec<String>> {
+        // Check for fake/test mode
+        if env::var("TORC_FAKE_QSUB").is_ok() {
+            return Ok(vec![hostname::get()?.to_string_lossy().to_string()]);
+        }
+
+        let attributes = self.qstat_full(job_id, false)?;
+        let exec_host = attributes
+            .get("exec_host")
+            .context("qstat output does not contain exec_host")?;
+
+        // exec_host looks like "node1/0*8+node2/0*8"
+        let mut nodes = Vec::new();
+        for chunk in exec_host.split('+') {
+            let host = chunk.split('/').next().unwrap_or(chunk).to_string();
+            if !nodes.contains(&host) {
+                nodes.push(host);
+            }
+        }
+
+        Ok(nodes)
+    }
+
+    fn submit(&self, filename: &Path) -> Result<(i32, String, String)> {
+        let qsub = Self::get_qsub_exec();
+        let filename_str = filename.to_string_lossy();
+
+        let (return_code, stdout, stderr) =
+            self.run_command_with_retries(&qsub, &[&filename_str], 6, 10, &[])?;
+
+        if return_code != 0 {
+            return Ok((return_code, String::new(), stderr));
+        }
+
+        if let Some(captures) = self.qsub_regex.captures(stdout.trim()) {
+            let job_id = captures.get(1).unwrap().as_str().to_string();
+            Ok((0, job_id, stderr))
+        } else {
+            error!("Failed to parse qsub output: {}", stdout);
+            Ok((
+                1,
+                String::new(),
+                "Failed to parse job ID from qsub output".to_string(),
+            ))
+        }
+    }
+}
+
+/// Convert a PBS `HH:MM:SS` duration (hours may exceed 24) to seconds
+fn hms_to_seconds(value: &str) -> Result<i64> {
+    let parts = value
+        .trim()
+        .split(':')
+        .map(|p| p.parse::<i64>())
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .with_context(|| format!("Invalid PBS duration '{}'", value))?;
+    match parts.as_slice() {
+        [h, m, s] => Ok(h * 3600 + m * 60 + s),
+        [m, s] => Ok(m * 60 + s),
+        [s] => Ok(*s),
+        _ => Err(anyhow::anyhow!("Invalid PBS duration '{}'", value)),
+    }
+}
+
+fn num_cpus_from_system() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
@@ -44,6 +44,17 @@ impl SlurmInterface {
         }
     }
 
+    /// Combine the `arrayjobid` and `arraytaskid` fields of squeue output into a job ID.
+    /// Tasks of a job array are identified as `<array_job_id>_<array_task_id>`; squeue reports
+    /// "N/A" as the task ID of jobs that are not part of an array.
+    fn squeue_job_id(array_job_id: &str, array_task_id: &str) -> String {
+        if array_task_id.parse::<u64>().is_ok() {
+            format!("{}_{}", array_job_id, array_task_id)
+        } else {
+            array_job_id.to_string()
+        }
+    }
+
     /// Get the squeue executable path (allows for testing with fake binary)
     fn get_squeue_exec() -> String {
         env::var("TORC_FAKE_SQUEUE").unwrap_or_else(|_| "squeue".to_string())
@@ -111,13 +122,15 @@ impl HpcInterface for SlurmInterface {
     }
 
     fn get_status(&self, job_id: &str) -> Result<HpcJobInfo> {
-        let field_names = ["jobid", "name", "state"];
+        let field_names = ["arrayjobid", "arraytaskid", "name", "state"];
         let format = field_names.join(",");
         let squeue = Self::get_squeue_exec();
 
         let (return_code, stdout, stderr) = self.run_command_with_retries(
             &squeue,
-            &["-u", &self.user, "--Format", &format, "-h", "-j", job_id],
+            &[
+                "-u", &self.user, "--Format", &format, "-h", "-r", "-j", job_id,
+            ],
             6,
             10,
             &["Invalid job id specified"],
@@ -152,20 +165,21 @@ impl HpcInterface for SlurmInterface {
         }
 
         Ok(HpcJobInfo::new(
-            fields[0].to_string(),
-            fields[1].to_string(),
-            Self::map_status(fields[2]),
+            Self::squeue_job_id(fields[0], fields[1]),
+            fields[2].to_string(),
+            Self::map_status(fields[3]),
         ))
     }
 
     fn get_statuses(&self) -> Result<HashMap<String, HpcJobStatus>> {
-        let field_names = ["jobid", "state"];
+        // -r lists each pending task of a job array on its own line.
+        let field_names = ["arrayjobid", "arraytaskid", "state"];
         let format = field_names.join(",");
         let squeue = Self::get_squeue_exec();
 
         let (return_code, stdout, stderr) = self.run_command_with_retries(
             &squeue,
-            &["-u", &self.user, "--Format", &format, "-h"],
+            &["-u", &self.user, "--Format", &format, "-h", "-r"],
             6,
             10,
             &[],
@@ -194,8 +208,8 @@ impl HpcInterface for SlurmInterface {
                 continue;
             }
 
-            let job_id = fields[0].to_string();
-            let status = Self::map_status(fields[1]);
+            let job_id = Self::squeue_job_id(fields[0], fields[1]);
+            let status = Self::map_status(fields[2]);
             statuses.insert(job_id, status);
         }
 
@@ -214,13 +228,20 @@ impl HpcInterface for SlurmInterface {
         config: &HashMap<String, String>,
         start_one_worker_per_node: bool,
     ) -> Result<()> {
+        // Name the output files of job array tasks by <array_job_id>_<array_task_id>, which is
+        // the ID that the job runner reports for them.
+        let job_id_pattern = if config.contains_key("array") {
+            "%A_%a"
+        } else {
+            "%j"
+        };
         let mut script = format!(
             "#!/bin/bash\n\
              #SBATCH --account={}\n\
              #SBATCH --job-name={}\n\
              #SBATCH --time={}\n\
-             #SBATCH --output={}/slurm_output_wf{}_sl%j.o\n\
-             #SBATCH --error={}/slurm_output_wf{}_sl%j.e\n",
+             #SBATCH --output={}/slurm_output_wf{}_sl{}.o\n\
+             #SBATCH --error={}/slurm_output_wf{}_sl{}.e\n",
             config
                 .get("account")
                 .context("Missing 'account' in config")?,
@@ -230,8 +251,10 @@ impl HpcInterface for SlurmInterface {
                 .context("Missing 'walltime' in config")?,
             output_path,
             workflow_id,
+            job_id_pattern,
             output_path,
-            workflow_id
+            workflow_id,
+            job_id_pattern
         );
 
         // Add other SBATCH parameters
@@ -273,6 +296,7 @@ impl HpcInterface for SlurmInterface {
             // This will have to change if we ever rely on these environment variables.
             script.push_str("unset SLURM_MEM_PER_CPU SLURM_MEM_PER_GPU\n");
             script.push_str("srun ");
+            command.push_str(" --worker-per-node");
         }
         script.push_str(&command);
         script.push('\n');
@@ -293,6 +317,14 @@ impl HpcInterface for SlurmInterface {
     }
 
     fn get_current_job_id(&self) -> String {
+        // Each task of a job array has its own SLURM_JOB_ID, but Torc identifies it by the
+        // array's job ID and the task index.
+        if let (Ok(array_job_id), Ok(array_task_id)) = (
+            env::var("SLURM_ARRAY_JOB_ID"),
+            env::var("SLURM_ARRAY_TASK_ID"),
+        ) {
+            return format!("{}_{}", array_job_id, array_task_id);
+        }
         env::var("SLURM_JOB_ID").expect("SLURM_JOB_ID environment variable not set")
     }
 
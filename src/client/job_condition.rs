@@ -0,0 +1,599 @@
+//! Conditions that decide whether a job runs.
+//!
+//! A job's `run_if` expression is evaluated by the job runner after the job is claimed. If it
+//! evaluates to false, the job is not started and is completed with `JobStatus::Skipped`, which
+//! unblocks its dependents in the same way as `JobStatus::Completed`.
+//!
+//! # Syntax
+//!
+//! - Functions:
+//!   - `exit_code("job")` - return code of an upstream job in the current run
+//!   - `status("job")` - status of an upstream job, such as `"completed"` or `"skipped"`
+//!   - `user_data("name")` - data of a user data object, optionally followed by `.key` or
+//!     `[index]` accessors
+//!   - `file_exists("path")` - whether a path exists, relative to the job's working directory
+//! - Literals: numbers, strings in single or double quotes, `true`, `false`, and `null`
+//! - Comparisons: `==`, `!=`, `<`, `<=`, `>`, `>=`
+//! - Logic: `&&`, `||`, `!`, and parentheses
+//!
+//! ```text
+//! exit_code("preprocess") == 0 && user_data("config").mode == "full"
+//! !file_exists("output/model.pt") || status("train") == "skipped"
+//! ```
+
+use serde_json::Value;
+
+/// Information that a condition needs from the workflow being run
+pub trait ConditionContext {
+    /// Return code of the named upstream job in the current run
+    fn exit_code(&self, job_name: &str) -> Result<i64, String>;
+    /// Status of the named upstream job
+    fn job_status(&self, job_name: &str) -> Result<String, String>;
+    /// Data stored in the named user data object
+    fn user_data(&self, name: &str) -> Result<Value, String>;
+    /// Whether the path exists
+    fn file_exists(&self, path: &str) -> bool;
+}
+
+/// A parsed `run_if` expression
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobCondition {
+    expr: Expr,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Expr {
+    Literal(Value),
+    ExitCode(String),
+    Status(String),
+    UserData(String, Vec<Accessor>),
+    FileExists(String),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Compare(Box<Expr>, CompareOp, Box<Expr>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Accessor {
+    Key(String),
+    Index(usize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(f64),
+    Str(String),
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Dot,
+    Compare(CompareOp),
+    And,
+    Or,
+    Not,
+    Minus,
+}
+
+impl JobCondition {
+    /// Parse a `run_if` expression
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let tokens = tokenize(input)?;
+        if tokens.is_empty() {
+            return Err("Condition is empty".to_string());
+        }
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.parse_or()?;
+        if let Some(token) = parser.peek() {
+            return Err(format!("Unexpected {:?} in condition '{}'", token, input));
+        }
+        Ok(JobCondition { expr })
+    }
+
+    /// Names of the jobs referenced by `exit_code()` and `status()`
+    pub fn referenced_jobs(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        self.expr.visit(&mut |expr| {
+            if let Expr::ExitCode(name) | Expr::Status(name) = expr
+                && !names.contains(name)
+            {
+                names.push(name.clone());
+            }
+        });
+        names
+    }
+
+    /// Names of the user data objects referenced by `user_data()`
+    pub fn referenced_user_data(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        self.expr.visit(&mut |expr| {
+            if let Expr::UserData(name, _) = expr
+                && !names.contains(name)
+            {
+                names.push(name.clone());
+            }
+        });
+        names
+    }
+
+    /// Evaluate the condition. Returns an error if a lookup fails or if the expression does
+    /// not produce a boolean.
+    pub fn evaluate(&self, ctx: &dyn ConditionContext) -> Result<bool, String> {
+        match self.expr.evaluate(ctx)? {
+            Value::Bool(b) => Ok(b),
+            other => Err(format!(
+                "Condition evaluated to {} instead of a boolean",
+                other
+            )),
+        }
+    }
+}
+
+impl Expr {
+    fn visit(&self, f: &mut dyn FnMut(&Expr)) {
+        f(self);
+        match self {
+            Expr::Not(inner) => inner.visit(f),
+            Expr::And(lhs, rhs) | Expr::Or(lhs, rhs) | Expr::Compare(lhs, _, rhs) => {
+                lhs.visit(f);
+                rhs.visit(f);
+            }
+            _ => {}
+        }
+    }
+
+    fn evaluate(&self, ctx: &dyn ConditionContext) -> Result<Value, String> {
+        match self {
+            Expr::Literal(value) => Ok(value.clone()),
+            Expr::ExitCode(name) => Ok(Value::from(ctx.exit_code(name)?)),
+            Expr::Status(name) => Ok(Value::String(ctx.job_status(name)?)),
+            Expr::FileExists(path) => Ok(Value::Bool(ctx.file_exists(path))),
+            Expr::UserData(name, accessors) => {
+                let mut value = ctx.user_data(name)?;
+                for accessor in accessors {
+                    value = match accessor {
+                        Accessor::Key(key) => value.get(key).cloned(),
+                        Accessor::Index(index) => value.get(index).cloned(),
+                    }
+                    .unwrap_or(Value::Null);
+                }
+                Ok(value)
+            }
+            Expr::Not(inner) => Ok(Value::Bool(!as_bool(&inner.evaluate(ctx)?, "!")?)),
+            Expr::And(lhs, rhs) => {
+                if !as_bool(&lhs.evaluate(ctx)?, "&&")? {
+                    return Ok(Value::Bool(false));
+                }
+                Ok(Value::Bool(as_bool(&rhs.evaluate(ctx)?, "&&")?))
+            }
+            Expr::Or(lhs, rhs) => {
+                if as_bool(&lhs.evaluate(ctx)?, "||")? {
+                    return Ok(Value::Bool(true));
+                }
+                Ok(Value::Bool(as_bool(&rhs.evaluate(ctx)?, "||")?))
+            }
+            Expr::Compare(lhs, op, rhs) => {
+                let lhs = lhs.evaluate(ctx)?;
+                let rhs = rhs.evaluate(ctx)?;
+                compare(&lhs, *op, &rhs).map(Value::Bool)
+            }
+        }
+    }
+}
+
+fn as_bool(value: &Value, op: &str) -> Result<bool, String> {
+    value
+        .as_bool()
+        .ok_or_else(|| format!("Operand of '{}' must be a boolean, got {}", op, value))
+}
+
+fn compare(lhs: &Value, op: CompareOp, rhs: &Value) -> Result<bool, String> {
+    let ordering = match (lhs, rhs) {
+        (Value::Number(a), Value::Number(b)) => {
+            let (a, b) = (
+                a.as_f64().unwrap_or(f64::NAN),
+                b.as_f64().unwrap_or(f64::NAN),
+            );
+            a.partial_cmp(&b)
+        }
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        _ => match op {
+            CompareOp::Eq => return Ok(lhs == rhs),
+            CompareOp::Ne => return Ok(lhs != rhs),
+            _ => {
+                return Err(format!(
+                    "Cannot order {} and {}; only numbers and strings can be ordered",
+                    lhs, rhs
+                ));
+            }
+        },
+    };
+    let Some(ordering) = ordering else {
+        return Ok(op == CompareOp::Ne);
+    };
+    Ok(match op {
+        CompareOp::Eq => ordering.is_eq(),
+        CompareOp::Ne => ordering.is_ne(),
+        CompareOp::Lt => ordering.is_lt(),
+        CompareOp::Le => ordering.is_le(),
+        CompareOp::Gt => ordering.is_gt(),
+        CompareOp::Ge => ordering.is_ge(),
+    })
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let chars: Vec<char> = input.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' | ')' | '[' | ']' | '.' | '-' => {
+                tokens.push(match c {
+                    '(' => Token::LParen,
+                    ')' => Token::RParen,
+                    '[' => Token::LBracket,
+                    ']' => Token::RBracket,
+                    '.' => Token::Dot,
+                    _ => Token::Minus,
+                });
+                i += 1;
+            }
+            '=' | '!' | '<' | '>' if next == Some('=') => {
+                tokens.push(Token::Compare(match c {
+                    '=' => CompareOp::Eq,
+                    '!' => CompareOp::Ne,
+                    '<' => CompareOp::Le,
+                    _ => CompareOp::Ge,
+                }));
+                i += 2;
+            }
+            '<' => {
+                tokens.push(Token::Compare(CompareOp::Lt));
+                i += 1;
+            }
+            '>' => {
+                tokens.push(Token::Compare(CompareOp::Gt));
+                i += 1;
+            }
+            '!' => {
+                tokens.push(Token::Not);
+                i += 1;
+            }
+            '&' if next == Some('&') => {
+                tokens.push(Token::And);
+                i += 2;
+            }
+            '|' if next == Some('|') => {
+                tokens.push(Token::Or);
+                i += 2;
+            }
+            '"' | '\'' => {
+                let mut value = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => {
+                            return Err(format!("Unterminated string in condition '{}'", input));
+                        }
+                        Some(&ch) if ch == c => break,
+                        Some('\\') => {
+                            let escaped = chars.get(i + 1).ok_or_else(|| {
+                                format!("Unterminated string in condition '{}'", input)
+                            })?;
+                            value.push(*escaped);
+                            i += 2;
+                        }
+                        Some(&ch) => {
+                            value.push(ch);
+                            i += 1;
+                        }
+                    }
+                }
+                tokens.push(Token::Str(value));
+                i += 1;
+            }
+            c if c.is_ascii_digit() => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let number = text
+                    .parse::<f64>()
+                    .map_err(|_| format!("Invalid number '{}' in condition", text))?;
+                tokens.push(Token::Number(number));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push(Token::Ident(chars[start..i].iter().collect()));
+            }
+            _ => {
+                return Err(format!(
+                    "Unexpected character '{}' in condition '{}'",
+                    c, input
+                ));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), String> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            Some(token) => Err(format!("Expected {:?}, found {:?}", expected, token)),
+            None => Err(format!("Expected {:?} at end of condition", expected)),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, String> {
+        let mut expr = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            expr = Expr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, String> {
+        let mut expr = self.parse_comparison()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            expr = Expr::And(Box::new(expr), Box::new(self.parse_comparison()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expr, String> {
+        let lhs = self.parse_unary()?;
+        if let Some(Token::Compare(op)) = self.peek().cloned() {
+            self.pos += 1;
+            let rhs = self.parse_unary()?;
+            return Ok(Expr::Compare(Box::new(lhs), op, Box::new(rhs)));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, String> {
+        match self.peek() {
+            Some(Token::Not) => {
+                self.pos += 1;
+                Ok(Expr::Not(Box::new(self.parse_unary()?)))
+            }
+            Some(Token::Minus) => {
+                self.pos += 1;
+                match self.next() {
+                    Some(Token::Number(n)) => Ok(Expr::Literal(number_value(-n))),
+                    _ => Err("'-' must be followed by a number".to_string()),
+                }
+            }
+            _ => self.parse_primary(),
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::LParen) => {
+                let expr = self.parse_or()?;
+                self.expect(Token::RParen)?;
+                Ok(expr)
+            }
+            Some(Token::Number(n)) => Ok(Expr::Literal(number_value(n))),
+            Some(Token::Str(s)) => Ok(Expr::Literal(Value::String(s))),
+            Some(Token::Ident(ident)) => match ident.as_str() {
+                "true" => Ok(Expr::Literal(Value::Bool(true))),
+                "false" => Ok(Expr::Literal(Value::Bool(false))),
+                "null" => Ok(Expr::Literal(Value::Null)),
+                "exit_code" => Ok(Expr::ExitCode(self.parse_string_argument(&ident)?)),
+                "status" => Ok(Expr::Status(self.parse_string_argument(&ident)?)),
+                "file_exists" => Ok(Expr::FileExists(self.parse_string_argument(&ident)?)),
+                "user_data" => {
+                    let name = self.parse_string_argument(&ident)?;
+                    Ok(Expr::UserData(name, self.parse_accessors()?))
+                }
+                _ => Err(format!(
+                    "Unknown function or value '{}' in condition",
+                    ident
+                )),
+            },
+            Some(token) => Err(format!("Unexpected {:?} in condition", token)),
+            None => Err("Unexpected end of condition".to_string()),
+        }
+    }
+
+    fn parse_string_argument(&mut self, function: &str) -> Result<String, String> {
+        self.expect(Token::LParen)
+            .map_err(|_| format!("{}() must be called with a string argument", function))?;
+        let Some(Token::Str(arg)) = self.next() else {
+            return Err(format!("{}() requires a string argument", function));
+        };
+        self.expect(Token::RParen)
+            .map_err(|_| format!("{}() takes exactly one argument", function))?;
+        Ok(arg)
+    }
+
+    fn parse_accessors(&mut self) -> Result<Vec<Accessor>, String> {
+        let mut accessors = Vec::new();
+        loop {
+            match self.peek() {
+                Some(Token::Dot) => {
+                    self.pos += 1;
+                    match self.next() {
+                        Some(Token::Ident(key)) => accessors.push(Accessor::Key(key)),
+                        _ => return Err("'.' must be followed by a key name".to_string()),
+                    }
+                }
+                Some(Token::LBracket) => {
+                    self.pos += 1;
+                    match self.next() {
+                        Some(Token::Str(key)) => accessors.push(Accessor::Key(key)),
+                        Some(Token::Number(n)) if n >= 0.0 && n.fract() == 0.0 => {
+                            accessors.push(Accessor::Index(n as usize))
+                        }
+                        _ => return Err("'[' must be followed by a string or index".to_string()),
+                    }
+                    self.expect(Token::RBracket)?;
+                }
+                _ => return Ok(accessors),
+            }
+        }
+    }
+}
+
+fn number_value(n: f64) -> Value {
+    if n.fract() == 0.0 && n.abs() < i64::MAX as f64 {
+        Value::from(n as i64)
+    } else {
+        Value::from(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    struct MockContext {
+        exit_codes: HashMap<String, i64>,
+        user_data: HashMap<String, Value>,
+        files: Vec<String>,
+    }
+
+    impl ConditionContext for MockContext {
+        fn exit_code(&self, job_name: &str) -> Result<i64, String> {
+            self.exit_codes
+                .get(job_name)
+                .copied()
+                .ok_or_else(|| format!("No result for job '{}'", job_name))
+        }
+
+        fn job_status(&self, job_name: &str) -> Result<String, String> {
+            Ok(match self.exit_code(job_name)? {
+                0 => "completed".to_string(),
+                _ => "failed".to_string(),
+            })
+        }
+
+        fn user_data(&self, name: &str) -> Result<Value, String> {
+            self.user_data
+                .get(name)
+                .cloned()
+                .ok_or_else(|| format!("No user data '{}'", name))
+        }
+
+        fn file_exists(&self, path: &str) -> bool {
+            self.files.iter().any(|f| f == path)
+        }
+    }
+
+    fn context() -> MockContext {
+        MockContext {
+            exit_codes: HashMap::from([("prep".to_string(), 0), ("check".to_string(), 3)]),
+            user_data: HashMap::from([(
+                "config".to_string(),
+                json!({"mode": "full", "threshold": 0.5, "stages": ["a", "b"]}),
+            )]),
+            files: vec!["out/data.csv".to_string()],
+        }
+    }
+
+    fn eval(condition: &str) -> Result<bool, String> {
+        JobCondition::parse(condition)?.evaluate(&context())
+    }
+
+    #[test]
+    fn test_exit_code_and_status() {
+        assert!(eval(r#"exit_code("prep") == 0"#).unwrap());
+        assert!(eval("exit_code('check') != 0 && exit_code('check') >= 3").unwrap());
+        assert!(!eval("exit_code('check') < -1").unwrap());
+        assert!(eval(r#"status("check") == "failed""#).unwrap());
+    }
+
+    #[test]
+    fn test_user_data_access() {
+        assert!(eval(r#"user_data("config").mode == "full""#).unwrap());
+        assert!(eval(r#"user_data("config")["threshold"] > 0.25"#).unwrap());
+        assert!(eval(r#"user_data("config").stages[1] == 'b'"#).unwrap());
+        assert!(eval(r#"user_data("config").missing == null"#).unwrap());
+    }
+
+    #[test]
+    fn test_logic_and_file_exists() {
+        assert!(eval(r#"file_exists("out/data.csv") && !file_exists("out/model.pt")"#).unwrap());
+        assert!(eval(r#"false || (true && !(exit_code("prep") == 1))"#).unwrap());
+        // Short-circuiting skips lookups that would fail
+        assert!(!eval(r#"false && exit_code("missing") == 0"#).unwrap());
+    }
+
+    #[test]
+    fn test_evaluation_errors() {
+        assert!(eval(r#"exit_code("missing") == 0"#).is_err());
+        assert!(eval(r#"exit_code("prep")"#).is_err());
+        assert!(eval(r#"user_data("config") < 1"#).is_err());
+        assert!(eval(r#"!exit_code("prep")"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for condition in [
+            "",
+            "exit_code(prep) == 0",
+            "exit_code('prep' == 0",
+            "unknown('x')",
+            "1 == 1 1",
+            "'unterminated",
+            "1 = 1",
+        ] {
+            assert!(
+                JobCondition::parse(condition).is_err(),
+                "Expected parse error for '{}'",
+                condition
+            );
+        }
+    }
+
+    #[test]
+    fn test_referenced_names() {
+        let condition = JobCondition::parse(
+            r#"exit_code("a") == 0 && (status("b") == "completed" || user_data("cfg").x) && exit_code("a") < 2"#,
+        )
+        .unwrap();
+        assert_eq!(condition.referenced_jobs(), vec!["a", "b"]);
+        assert_eq!(condition.referenced_user_data(), vec!["cfg"]);
+    }
+}
@@ -59,29 +59,71 @@
 //!
 //! Jobs without this flag (or with `supports_termination = false`) will be killed immediately
 //! with SIGKILL, which doesn't allow cleanup but ensures rapid shutdown.
+//!
+//! # Runtime Limits
+//!
+//! If the workflow sets `enforce_runtime_limits`, the JobRunner also terminates individual jobs
+//! that run longer than the `runtime` of their resource requirements. The same
+//! `supports_termination` rules apply, except that jobs receiving SIGTERM are sent SIGKILL if
+//! they are still running after `runtime_limit_grace_seconds`. These jobs are recorded as
+//! failed with return code 124
+//! ([`RUNTIME_LIMIT_RETURN_CODE`](crate::client::async_cli_command::RUNTIME_LIMIT_RETURN_CODE))
+//! so that failure handlers and `torc recover` can identify them. Limits are checked once per `job_completion_poll_interval`.
+//!
+//! # Heartbeats
+//!
+//! If the workflow sets `worker_heartbeat_timeout_seconds` (default 300), a background thread
+//! sends a heartbeat for the compute node every quarter of the timeout (at most every 60
+//! seconds). The server declares compute nodes that stop sending heartbeats dead and fails or
+//! resets their running jobs. If a heartbeat response shows that the server already did so
+//! (for example, after a long network outage), the JobRunner kills its jobs without reporting
+//! their results and exits.
+//!
+//! # Multi-Node Jobs
+//!
+//! The head node's job runner of a `start_one_worker_per_node` allocation may be enabled with
+//! [`JobRunner::enable_multi_node_jobs`]. It then claims jobs whose resource requirements have
+//! `num_nodes > 1` (the other job runners of the allocation only report one node), and for
+//! each one reserves that many job runners of the allocation by setting their compute node's
+//! `reserved_for_job_id`. The server does not give jobs to reserved job runners. Once the
+//! reserved job runners have finished their running jobs, the job starts on their nodes as
+//! described in [`crate::client::multi_node`]. Its CPUs, memory, and GPUs are accounted for by
+//! the reserved job runners; this job runner only counts the nodes. The server releases the
+//! reservations when the job completes, and this job runner returns jobs that are still
+//! waiting for nodes to the ready state when it exits.
 
 use chrono::{DateTime, Utc};
 use log::{self, debug, error, info, warn};
+use regex::Regex;
+use serde_json::Value;
 use std::collections::HashMap;
 use std::fs;
+use std::io::{Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, OnceLock};
 use std::thread;
 use std::time::{Duration, Instant};
 
 use crate::client::apis::configuration::Configuration;
 use crate::client::apis::default_api;
 use crate::client::async_cli_command::AsyncCliCommand;
-use crate::client::commands::recover::format_duration_iso8601;
+use crate::client::commands::recover::{format_duration_iso8601, parse_memory_bytes};
+use crate::client::hpc::HpcType;
+use crate::client::job_condition::{ConditionContext, JobCondition};
+use crate::client::log_paths::{get_job_stderr_path, get_job_stdout_path};
+use crate::client::multi_node::MultiNodeLaunch;
+use crate::client::remote::{FileStager, StagingDirection};
 use crate::client::resource_monitor::{ResourceMonitor, ResourceMonitorConfig};
 use crate::client::utils;
 use crate::config::TorcConfig;
 use crate::memory_utils::memory_string_to_gb;
 use crate::models::{
-    ClaimJobsSortMethod, ComputeNodesResources, JobStatus, ResourceRequirementsModel, ResultModel,
-    WorkflowModel,
+    ClaimJobsSortMethod, ComputeNodeModel, ComputeNodesResources, EventModel, JobLogModel,
+    JobLogStream, JobModel, JobStatus, ResourceRequirementsModel, ResultModel, WorkflowModel,
 };
+use crate::time_utils::duration_string_to_seconds;
 
 /// Rule definition for failure handler (parsed from JSON stored in database)
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -91,15 +133,194 @@ pub struct FailureHandlerRule {
     /// If true, this rule matches any non-zero exit code
     #[serde(default)]
     pub match_all_exit_codes: bool,
+    /// Regex patterns matched against the job's stdout file
+    #[serde(default)]
+    pub stdout_regexes: Vec<String>,
+    /// Regex patterns matched against the job's stderr file
+    #[serde(default)]
+    pub stderr_regexes: Vec<String>,
     pub recovery_script: Option<String>,
     #[serde(default = "default_max_retries")]
     pub max_retries: i32,
+    /// Multiply the job's memory requirement by this factor before retrying
+    pub memory_multiplier: Option<f64>,
+    /// Multiply the job's runtime requirement by this factor before retrying
+    pub runtime_multiplier: Option<f64>,
+    /// Move the job to this scheduler before retrying
+    pub scheduler: Option<String>,
+    /// Seconds to wait before the retried job can be claimed
+    pub retry_delay_seconds: Option<f64>,
+    /// Multiply the retry delay by this factor after each failed attempt
+    pub retry_backoff_multiplier: Option<f64>,
+    /// Upper bound for the retry delay after backoff
+    pub max_retry_delay_seconds: Option<f64>,
+    /// If true, the retry is not claimed by workers on the host where the attempt failed
+    #[serde(default)]
+    pub avoid_failed_node: bool,
+    /// `stdout_regexes` and `stderr_regexes` compiled on first use, without invalid patterns
+    #[serde(skip)]
+    compiled_regexes: OnceLock<(Vec<Regex>, Vec<Regex>)>,
+}
+
+/// Compile failure handler patterns, leaving out and logging invalid ones.
+fn compile_patterns(patterns: &[String]) -> Vec<Regex> {
+    patterns
+        .iter()
+        .filter_map(|pattern| match Regex::new(pattern) {
+            Ok(re) => Some(re),
+            Err(e) => {
+                warn!("Invalid failure handler pattern '{}': {}", pattern, e);
+                None
+            }
+        })
+        .collect()
+}
+
+impl FailureHandlerRule {
+    /// Return true if the rule has stdout or stderr patterns.
+    pub fn has_log_patterns(&self) -> bool {
+        !self.stdout_regexes.is_empty() || !self.stderr_regexes.is_empty()
+    }
+
+    /// Return true if the rule names the exit code in `exit_codes`.
+    fn matches_exit_code_exactly(&self, exit_code: i64) -> bool {
+        self.exit_codes.contains(&(exit_code as i32))
+    }
+
+    /// Return true if the rule applies to any non-zero exit code. Rules without `exit_codes` that
+    /// only match log patterns apply to any non-zero exit code.
+    fn matches_any_exit_code(&self) -> bool {
+        self.match_all_exit_codes || (self.exit_codes.is_empty() && self.has_log_patterns())
+    }
+
+    /// Return true if any of the rule's patterns match the job's output, or if the rule has no
+    /// patterns. Invalid patterns never match. The patterns are compiled once per rule.
+    pub fn matches_logs(&self, stdout: &str, stderr: &str) -> bool {
+        if !self.has_log_patterns() {
+            return true;
+        }
+        let (stdout_regexes, stderr_regexes) = self.compiled_regexes.get_or_init(|| {
+            (
+                compile_patterns(&self.stdout_regexes),
+                compile_patterns(&self.stderr_regexes),
+            )
+        });
+        stdout_regexes.iter().any(|re| re.is_match(stdout))
+            || stderr_regexes.iter().any(|re| re.is_match(stderr))
+    }
+
+    /// Return true if the rule changes the job before it is retried.
+    pub fn has_retry_adjustments(&self) -> bool {
+        self.memory_multiplier.is_some()
+            || self.runtime_multiplier.is_some()
+            || self.scheduler.is_some()
+    }
+
+    /// Return the delay in seconds before retrying a job whose attempt `attempt_id` failed.
+    /// The delay is multiplied by `retry_backoff_multiplier` for each earlier attempt and capped
+    /// at `max_retry_delay_seconds`.
+    pub fn retry_delay(&self, attempt_id: i64) -> Option<f64> {
+        let base = self.retry_delay_seconds?;
+        let exponent = (attempt_id - 1).clamp(0, i32::MAX as i64) as i32;
+        let delay = base * self.retry_backoff_multiplier.unwrap_or(1.0).powi(exponent);
+        Some(match self.max_retry_delay_seconds {
+            Some(max_delay) => delay.min(max_delay),
+            None => delay,
+        })
+    }
+}
+
+/// Find the rule that handles a failure. Rules that list the exit code take precedence over rules
+/// that match any exit code. Within each group, the first rule whose log patterns match wins.
+pub fn find_matching_failure_rule<'a>(
+    rules: &'a [FailureHandlerRule],
+    exit_code: i64,
+    stdout: &str,
+    stderr: &str,
+) -> Option<&'a FailureHandlerRule> {
+    rules
+        .iter()
+        .find(|rule| rule.matches_exit_code_exactly(exit_code) && rule.matches_logs(stdout, stderr))
+        .or_else(|| {
+            rules
+                .iter()
+                .find(|rule| rule.matches_any_exit_code() && rule.matches_logs(stdout, stderr))
+        })
+}
+
+/// Multiply a memory string such as "8g" or "512m" by a factor. The result is rounded up to whole
+/// megabytes.
+fn scale_memory(memory: &str, factor: f64) -> Option<String> {
+    const MB: f64 = 1024.0 * 1024.0;
+    let bytes = parse_memory_bytes(memory)?;
+    let megabytes = (bytes as f64 * factor / MB).ceil() as u64;
+    if megabytes > 0 && megabytes.is_multiple_of(1024) {
+        Some(format!("{}g", megabytes / 1024))
+    } else {
+        Some(format!("{}m", megabytes.max(1)))
+    }
 }
 
 fn default_max_retries() -> i32 {
     3
 }
 
+/// Failure handler patterns are matched against at most this many bytes from the end of the job's
+/// stdout and stderr files, where errors are usually reported.
+const MAX_RECOVERY_LOG_BYTES: u64 = 4 * 1024 * 1024;
+
+/// Read at most `max_bytes` from the end of a log file. Returns the text, the size of the whole
+/// file, and whether the text was truncated. A truncated text starts at the first full line.
+fn read_log_tail(path: &Path, max_bytes: u64) -> std::io::Result<(String, u64, bool)> {
+    let mut file = fs::File::open(path)?;
+    let size = file.metadata()?.len();
+    let is_truncated = size > max_bytes;
+    if is_truncated {
+        file.seek(SeekFrom::Start(size - max_bytes))?;
+    }
+    let mut bytes = Vec::new();
+    file.take(max_bytes).read_to_end(&mut bytes)?;
+    if is_truncated && let Some(pos) = bytes.iter().position(|b| *b == b'\n') {
+        bytes.drain(..=pos);
+    }
+    Ok((
+        String::from_utf8_lossy(&bytes).into_owned(),
+        size,
+        is_truncated,
+    ))
+}
+
+/// Choose `num_nodes` job runners of an allocation to reserve for the multi-node job `job_id`, or
+/// return None if not enough are available. Job runners of an allocation are not marked active
+/// when they start; those that exited have recorded their duration. Nodes already reserved for
+/// the job, such as by an earlier attempt that failed partway, come first, and the job runner
+/// `own_compute_node_id` comes last so that it can keep running other jobs.
+pub fn select_nodes_to_reserve(
+    mut nodes: Vec<ComputeNodeModel>,
+    job_id: i64,
+    own_compute_node_id: i64,
+    num_nodes: usize,
+) -> Option<Vec<ComputeNodeModel>> {
+    nodes.retain(|node| {
+        node.duration_seconds.is_none()
+            && node
+                .reserved_for_job_id
+                .is_none_or(|reserved_for| reserved_for == job_id)
+    });
+    if nodes.len() < num_nodes {
+        return None;
+    }
+    nodes.sort_by_key(|node| {
+        (
+            node.reserved_for_job_id.is_none(),
+            node.id == Some(own_compute_node_id),
+            node.id,
+        )
+    });
+    nodes.truncate(num_nodes);
+    Some(nodes)
+}
+
 /// Result of running the job worker, indicating whether any jobs failed or were terminated.
 #[derive(Debug, Default, Clone)]
 pub struct WorkerResult {
@@ -124,6 +345,99 @@ pub enum RecoveryOutcome {
     Error(String),
 }
 
+/// Resolves the names used in a job's `run_if` condition through the server API.
+struct RunnerConditionContext<'a> {
+    config: &'a Configuration,
+    workflow_id: i64,
+    run_id: i64,
+    job: &'a JobModel,
+}
+
+impl RunnerConditionContext<'_> {
+    /// Find an upstream job of the job being evaluated by name.
+    fn upstream_job(&self, job_name: &str) -> Result<JobModel, String> {
+        let job_id = self.job.id.expect("Job must have an ID");
+        let job = default_api::get_job(self.config, job_id)
+            .map_err(|e| format!("failed to get job {}: {}", job_id, e))?;
+        for upstream_id in job.depends_on_job_ids.unwrap_or_default() {
+            let upstream = default_api::get_job(self.config, upstream_id)
+                .map_err(|e| format!("failed to get job {}: {}", upstream_id, e))?;
+            if upstream.name == job_name {
+                return Ok(upstream);
+            }
+        }
+        Err(format!("'{}' is not an upstream job of this job", job_name))
+    }
+}
+
+impl ConditionContext for RunnerConditionContext<'_> {
+    fn exit_code(&self, job_name: &str) -> Result<i64, String> {
+        let upstream_id = self
+            .upstream_job(job_name)?
+            .id
+            .expect("Job must have an ID");
+        let response = default_api::list_results(
+            self.config,
+            self.workflow_id,
+            Some(upstream_id),
+            Some(self.run_id),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .map_err(|e| format!("failed to list results for job '{}': {}", job_name, e))?;
+        response
+            .items
+            .unwrap_or_default()
+            .into_iter()
+            .max_by_key(|r| r.attempt_id.unwrap_or(1))
+            .map(|r| r.return_code)
+            .ok_or_else(|| format!("job '{}' has no result in run {}", job_name, self.run_id))
+    }
+
+    fn job_status(&self, job_name: &str) -> Result<String, String> {
+        let status = self.upstream_job(job_name)?.status;
+        status
+            .map(|s| s.to_string())
+            .ok_or_else(|| format!("job '{}' has no status", job_name))
+    }
+
+    fn user_data(&self, name: &str) -> Result<Value, String> {
+        let response = default_api::list_user_data(
+            self.config,
+            self.workflow_id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(name),
+            None,
+        )
+        .map_err(|e| format!("failed to list user data '{}': {}", name, e))?;
+        response
+            .items
+            .unwrap_or_default()
+            .into_iter()
+            .find(|ud| ud.name == name)
+            .map(|ud| ud.data.unwrap_or(Value::Null))
+            .ok_or_else(|| format!("user data '{}' does not exist", name))
+    }
+
+    fn file_exists(&self, path: &str) -> bool {
+        match self.job.working_dir.as_deref() {
+            Some(dir) => Path::new(dir).join(path).exists(),
+            None => Path::new(path).exists(),
+        }
+    }
+}
+
 /// Manages parallel job execution on a compute node.
 ///
 /// The JobRunner claims jobs from the server, executes them locally, and reports results.
@@ -175,6 +489,36 @@ pub struct JobRunner {
     had_terminations: bool,
     /// When this job runner started (for calculating duration_seconds)
     start_instant: Instant,
+    /// Copies job input and output files when the worker does not share storage
+    /// with the rest of the workflow.
+    file_stager: Option<FileStager>,
+    /// Stops the heartbeat thread when a message is sent or the sender is dropped.
+    heartbeat_stop: Option<mpsc::Sender<()>>,
+    heartbeat_thread: Option<thread::JoinHandle<()>>,
+    /// Set by the heartbeat thread when the server reports that it declared this compute
+    /// node dead.
+    declared_dead: Arc<AtomicBool>,
+    /// Scheduled compute node of the allocation whose job runners this job runner reserves
+    /// for multi-node jobs.
+    multi_node_allocation_id: Option<i64>,
+    /// Claimed multi-node jobs that wait for their reserved nodes.
+    waiting_multi_node_jobs: Vec<WaitingMultiNodeJob>,
+    /// Parsed rules of the failure handlers used so far, by failure handler ID. Failure
+    /// handlers cannot be changed, so each is fetched and its patterns compiled once.
+    failure_handler_rules: HashMap<i64, Arc<Vec<FailureHandlerRule>>>,
+}
+
+/// A claimed multi-node job and the compute nodes reserved for it, if any yet.
+struct WaitingMultiNodeJob {
+    job: JobModel,
+    rr: ResourceRequirementsModel,
+    reserved_nodes: Vec<ComputeNodeModel>,
+}
+
+/// Interval between heartbeats for a heartbeat timeout, so that the server does not declare a
+/// compute node dead because of one or two lost heartbeats.
+fn heartbeat_interval(timeout_seconds: i64) -> Duration {
+    Duration::from_secs((timeout_seconds / 4).clamp(1, 60) as u64)
 }
 
 impl JobRunner {
@@ -206,8 +550,15 @@ impl JobRunner {
             workflow.compute_node_wait_for_healthy_database_minutes,
             workflow.compute_node_min_time_for_new_jobs_seconds,
             workflow.jobs_sort_method,
+            workflow.enforce_runtime_limits,
+            workflow.runtime_limit_grace_seconds,
         );
         let job_resources: HashMap<i64, ResourceRequirementsModel> = HashMap::new();
+        // The server uses the resources id to identify the claiming compute node.
+        let resources = ComputeNodesResources {
+            id: Some(compute_node_id),
+            ..resources
+        };
         let orig_resources = ComputeNodesResources {
             id: resources.id,
             num_cpus: resources.num_cpus,
@@ -272,9 +623,28 @@ impl JobRunner {
             had_failures: false,
             had_terminations: false,
             start_instant: Instant::now(),
+            file_stager: None,
+            heartbeat_stop: None,
+            heartbeat_thread: None,
+            declared_dead: Arc::new(AtomicBool::new(false)),
+            multi_node_allocation_id: None,
+            waiting_multi_node_jobs: Vec::new(),
+            failure_handler_rules: HashMap::new(),
         }
     }
 
+    /// Run jobs that need more than one node on the job runners of the allocation with the
+    /// given scheduled compute node ID. Only enable this for the head node's job runner of a
+    /// `start_one_worker_per_node` allocation.
+    pub fn enable_multi_node_jobs(&mut self, scheduled_compute_node_id: i64) {
+        self.multi_node_allocation_id = Some(scheduled_compute_node_id);
+    }
+
+    /// Stage each job's input files from, and output files to, another host.
+    pub fn set_file_stager(&mut self, file_stager: FileStager) {
+        self.file_stager = Some(file_stager);
+    }
+
     /// Execute an API call with automatic retries for network errors.
     ///
     /// This is a convenience method that wraps [`utils::send_with_retries`] with
@@ -397,6 +767,7 @@ impl JobRunner {
         }
 
         // Check for and execute on_workflow_start and on_worker_start actions before entering main loop
+        self.start_heartbeat();
         self.execute_workflow_start_actions();
         self.execute_worker_start_actions();
 
@@ -434,8 +805,21 @@ impl JobRunner {
                 }
             }
 
+            // Check before reporting any job results: the server has already failed or reset
+            // this compute node's jobs.
+            if self.declared_dead.load(Ordering::SeqCst) {
+                error!(
+                    "The server declared this compute node dead because it missed heartbeats. \
+                    Killing jobs without reporting results workflow_id={} compute_node_id={}",
+                    self.workflow_id, self.compute_node_id
+                );
+                self.abandon_jobs();
+                break;
+            }
+
             self.check_job_status();
             self.check_and_execute_actions();
+            self.start_waiting_multi_node_jobs();
 
             debug!("Check for new jobs");
             if self.max_parallel_jobs.is_none() {
@@ -478,7 +862,9 @@ impl JobRunner {
             }
 
             // Check if we should exit due to no new jobs being claimed for too long
-            if self.rules.compute_node_wait_for_new_jobs_seconds > 0 && self.running_jobs.is_empty()
+            if self.rules.compute_node_wait_for_new_jobs_seconds > 0
+                && self.running_jobs.is_empty()
+                && self.waiting_multi_node_jobs.is_empty()
             {
                 // Initialize the time if this is the first check
                 if self.last_job_claimed_time.is_none() {
@@ -511,6 +897,7 @@ impl JobRunner {
             }
         }
 
+        self.release_waiting_multi_node_jobs();
         self.execute_worker_complete_actions();
 
         // Shutdown resource monitor if enabled
@@ -519,6 +906,8 @@ impl JobRunner {
             monitor.shutdown();
         }
 
+        self.stop_heartbeat();
+
         // Deactivate compute node and set duration
         self.deactivate_compute_node();
 
@@ -536,6 +925,97 @@ impl JobRunner {
         })
     }
 
+    /// Start the thread that sends heartbeats for this compute node.
+    fn start_heartbeat(&mut self) {
+        let timeout_seconds = self.workflow.worker_heartbeat_timeout_seconds.unwrap_or(0);
+        if timeout_seconds <= 0 {
+            debug!(
+                "Heartbeats are disabled for workflow_id={}",
+                self.workflow_id
+            );
+            return;
+        }
+        let interval = heartbeat_interval(timeout_seconds);
+        info!(
+            "Sending heartbeats workflow_id={} compute_node_id={} interval_s={}",
+            self.workflow_id,
+            self.compute_node_id,
+            interval.as_secs()
+        );
+
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let config = self.config.clone();
+        let compute_node_id = self.compute_node_id;
+        let declared_dead = Arc::clone(&self.declared_dead);
+        let handle = thread::spawn(move || {
+            // Only compute nodes registered as active are checked by the server, so the node
+            // is dead only if it was active before.
+            let mut was_active = false;
+            loop {
+                match default_api::update_compute_node_heartbeat(&config, compute_node_id) {
+                    Ok(node) => {
+                        if node.is_active == Some(true) {
+                            was_active = true;
+                        } else if was_active {
+                            declared_dead.store(true, Ordering::SeqCst);
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Failed to send heartbeat compute_node_id={}: {}",
+                            compute_node_id, e
+                        );
+                    }
+                }
+                if !matches!(
+                    stop_rx.recv_timeout(interval),
+                    Err(mpsc::RecvTimeoutError::Timeout)
+                ) {
+                    break;
+                }
+            }
+        });
+        self.heartbeat_stop = Some(stop_tx);
+        self.heartbeat_thread = Some(handle);
+    }
+
+    /// Stop the heartbeat thread and wait for it to exit.
+    fn stop_heartbeat(&mut self) {
+        if let Some(stop_tx) = self.heartbeat_stop.take() {
+            let _ = stop_tx.send(());
+        }
+        if let Some(handle) = self.heartbeat_thread.take()
+            && handle.join().is_err()
+        {
+            error!("Heartbeat thread panicked");
+        }
+    }
+
+    /// Kill all running jobs without reporting their results to the server.
+    ///
+    /// Used when the server has declared this compute node dead and already failed or reset
+    /// its jobs.
+    fn abandon_jobs(&mut self) {
+        for (job_id, async_job) in self.running_jobs.iter_mut() {
+            info!(
+                "Job abandoning workflow_id={} job_id={}",
+                self.workflow_id, job_id
+            );
+            if let Err(e) = async_job.cancel() {
+                warn!(
+                    "Job SIGKILL failed workflow_id={} job_id={} error={}",
+                    self.workflow_id, job_id, e
+                );
+            }
+            if let Err(e) = async_job.wait_for_completion() {
+                error!("Error waiting for job {}: {}", job_id, e);
+            }
+        }
+        self.running_jobs.clear();
+        self.job_resources.clear();
+    }
+
     /// Deactivate the compute node and set its duration.
     fn deactivate_compute_node(&self) {
         let duration_seconds = self.start_instant.elapsed().as_secs_f64();
@@ -711,8 +1191,13 @@ impl JobRunner {
         let mut completed_jobs = Vec::new();
         let mut job_results = Vec::new();
 
+        if self.rules.enforce_runtime_limits {
+            self.enforce_runtime_limits();
+        }
+
         // First pass: check status and collect completed jobs
         for (job_id, async_job) in self.running_jobs.iter_mut() {
+            async_job.start_container_monitoring(self.resource_monitor.as_ref());
             match async_job.check_status() {
                 Ok(()) => {
                     if async_job.is_complete {
@@ -749,11 +1234,57 @@ impl JobRunner {
                 result.status = JobStatus::Failed;
             }
 
+            // Copy output files back to the staging host
+            if result.return_code == 0
+                && let Some(output_file_ids) =
+                    output_file_ids.as_ref().filter(|ids| !ids.is_empty())
+                && let Some(async_job) = self.running_jobs.get(&job_id)
+                && let Err(e) =
+                    self.stage_files(&async_job.job, output_file_ids, StagingDirection::StageOut)
+            {
+                error!("Output file staging failed for job {}: {}", job_id, e);
+                result.return_code = 1;
+                result.status = JobStatus::Failed;
+            }
+
             self.handle_job_completion(job_id, result);
         }
     }
 
-    /// Validate that all expected output files exist and update their st_mtime
+    /// Signal running jobs that have exceeded their runtime limits.
+    fn enforce_runtime_limits(&mut self) {
+        let grace_period = Duration::from_secs(self.rules.runtime_limit_grace_seconds);
+        for (job_id, async_job) in self.running_jobs.iter_mut() {
+            if let Err(e) = async_job.enforce_runtime_limit(grace_period) {
+                error!(
+                    "Failed to enforce runtime limit workflow_id={} job_id={}: {}",
+                    self.workflow_id, job_id, e
+                );
+            }
+        }
+    }
+
+    /// Apply the runtime limit from the job's resource requirements, if enforcement is enabled.
+    fn set_runtime_limit(&self, async_job: &mut AsyncCliCommand, rr: &ResourceRequirementsModel) {
+        if !self.rules.enforce_runtime_limits {
+            return;
+        }
+        match duration_string_to_seconds(&rr.runtime) {
+            Ok(secs) if secs > 0 => {
+                async_job.set_runtime_limit(Duration::from_secs(secs as u64));
+            }
+            Ok(_) => {}
+            Err(e) => {
+                warn!(
+                    "Cannot enforce runtime limit for job {}: invalid runtime '{}': {}",
+                    async_job.job_id, rr.runtime, e
+                );
+            }
+        }
+    }
+
+    /// Validate that all expected output files exist and update their st_mtime (and content
+    /// hash for files that use checksum change detection)
     fn validate_and_update_output_files(
         &self,
         job_id: i64,
@@ -848,6 +1379,19 @@ impl JobRunner {
                 };
 
             file_model.st_mtime = Some(st_mtime);
+            if file_model.use_checksum == Some(true) {
+                file_model.content_hash =
+                    match utils::compute_file_checksum(Path::new(&file_model.path)) {
+                        Ok(checksum) => Some(checksum),
+                        Err(e) => {
+                            error!(
+                                "Failed to compute checksum for file '{}': {}",
+                                file_model.path, e
+                            );
+                            None
+                        }
+                    };
+            }
             match self.send_with_retries(|| {
                 default_api::update_file(&self.config, file_id, file_model.clone())
             }) {
@@ -880,6 +1424,13 @@ impl JobRunner {
             )
         });
 
+        if self.torc_config.client.run.upload_logs {
+            let attempt_id = job_info
+                .as_ref()
+                .map_or(1, |(_, attempt_id, _)| *attempt_id);
+            self.upload_job_logs(job_id, attempt_id);
+        }
+
         // Check if we should try to recover a failed job
         let mut final_result = result;
         if final_result.status == JobStatus::Failed
@@ -980,6 +1531,62 @@ impl JobRunner {
         self.job_resources.remove(&job_id);
     }
 
+    /// Upload the stdout and stderr of a job attempt to the server. Files larger than
+    /// `upload_logs_max_bytes` are uploaded from their end. Failures are logged and do not affect
+    /// the job.
+    fn upload_job_logs(&self, job_id: i64, attempt_id: i64) {
+        let max_bytes = self.torc_config.client.run.upload_logs_max_bytes;
+        let paths = [
+            (
+                JobLogStream::Stdout,
+                get_job_stdout_path(
+                    &self.output_dir,
+                    self.workflow_id,
+                    job_id,
+                    self.run_id,
+                    attempt_id,
+                ),
+            ),
+            (
+                JobLogStream::Stderr,
+                get_job_stderr_path(
+                    &self.output_dir,
+                    self.workflow_id,
+                    job_id,
+                    self.run_id,
+                    attempt_id,
+                ),
+            ),
+        ];
+        for (stream, path) in paths {
+            let (content, size_bytes, is_truncated) =
+                match read_log_tail(Path::new(&path), max_bytes) {
+                    Ok(log) => log,
+                    Err(e) => {
+                        debug!("Failed to read job output file {}: {}", path, e);
+                        continue;
+                    }
+                };
+            let mut job_log =
+                JobLogModel::new(self.workflow_id, job_id, self.run_id, stream, content);
+            job_log.attempt_id = Some(attempt_id);
+            job_log.size_bytes = Some(size_bytes as i64);
+            job_log.is_truncated = Some(is_truncated);
+            match self
+                .send_with_retries(|| default_api::create_job_log(&self.config, job_log.clone()))
+            {
+                Ok(_) => debug!(
+                    "Uploaded job log workflow_id={} job_id={} attempt_id={} stream={} size_bytes={} truncated={}",
+                    self.workflow_id, job_id, attempt_id, stream, size_bytes, is_truncated
+                ),
+                Err(e) => warn!(
+                    "Failed to upload job log workflow_id={} job_id={} stream={} error={}",
+                    self.workflow_id, job_id, stream, e
+                ),
+            }
+        }
+    }
+
     /// Run a recovery script with environment variables set.
     /// Returns Ok(()) if the recovery script succeeds (exit code 0).
     fn run_recovery_script(
@@ -1031,10 +1638,100 @@ impl JobRunner {
         }
     }
 
+    /// Apply the resource and scheduler changes of a failure handler rule to a job.
+    ///
+    /// Multipliers are applied to a copy of the job's resource requirements so that other jobs
+    /// sharing the original requirements are not affected.
+    fn apply_retry_adjustments(
+        &self,
+        job_id: i64,
+        job_name: &str,
+        rule: &FailureHandlerRule,
+        attempt_id: i64,
+    ) -> Result<(), String> {
+        let mut job = self
+            .send_with_retries(|| default_api::get_job(&self.config, job_id))
+            .map_err(|e| format!("failed to get job: {}", e))?;
+
+        if rule.memory_multiplier.is_some() || rule.runtime_multiplier.is_some() {
+            let rr_id = job
+                .resource_requirements_id
+                .ok_or("job has no resource requirements")?;
+            let rr = self
+                .send_with_retries(|| default_api::get_resource_requirements(&self.config, rr_id))
+                .map_err(|e| format!("failed to get resource requirements {}: {}", rr_id, e))?;
+            let mut new_rr = rr.clone();
+            new_rr.id = None;
+            new_rr.name = format!("{}_attempt_{}", job_name, attempt_id + 1);
+            if let Some(factor) = rule.memory_multiplier {
+                new_rr.memory = scale_memory(&rr.memory, factor)
+                    .ok_or_else(|| format!("invalid memory '{}'", rr.memory))?;
+            }
+            if let Some(factor) = rule.runtime_multiplier {
+                let seconds = duration_string_to_seconds(&rr.runtime)?;
+                // Round up to whole minutes, the resolution of the formatted duration.
+                let minutes = (seconds as f64 * factor / 60.0).ceil() as u64;
+                new_rr.runtime = format_duration_iso8601(minutes * 60);
+            }
+            let created = self
+                .send_with_retries(|| {
+                    default_api::create_resource_requirements(&self.config, new_rr.clone())
+                })
+                .map_err(|e| format!("failed to create resource requirements: {}", e))?;
+            info!(
+                "Job resources adjusted workflow_id={} job_id={} memory={}->{} runtime={}->{}",
+                self.workflow_id, job_id, rr.memory, created.memory, rr.runtime, created.runtime
+            );
+            job.resource_requirements_id = created.id;
+        }
+
+        if let Some(ref scheduler_name) = rule.scheduler {
+            let schedulers = self
+                .send_with_retries(|| {
+                    default_api::list_slurm_schedulers(
+                        &self.config,
+                        self.workflow_id,
+                        None,
+                        None,
+                        None,
+                        None,
+                        Some(scheduler_name),
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                    )
+                })
+                .map_err(|e| format!("failed to list schedulers: {}", e))?;
+            let scheduler_id = schedulers
+                .items
+                .unwrap_or_default()
+                .into_iter()
+                .find(|s| s.name.as_deref() == Some(scheduler_name.as_str()))
+                .and_then(|s| s.id)
+                .ok_or_else(|| format!("scheduler '{}' does not exist", scheduler_name))?;
+            info!(
+                "Job scheduler changed workflow_id={} job_id={} scheduler={}",
+                self.workflow_id, job_id, scheduler_name
+            );
+            job.scheduler_id = Some(scheduler_id);
+        }
+
+        // Clear status so that the update does not change it
+        job.status = None;
+        self.send_with_retries(|| default_api::update_job(&self.config, job_id, job.clone()))
+            .map_err(|e| format!("failed to update job: {}", e))?;
+        Ok(())
+    }
+
     /// Try to recover and retry a failed job based on its failure handler rules.
     /// Returns a `RecoveryOutcome` indicating what happened.
     fn try_recover_job(
-        &self,
+        &mut self,
         job_id: i64,
         job_name: &str,
         exit_code: i64,
@@ -1047,40 +1744,77 @@ impl JobRunner {
             None => return RecoveryOutcome::NoHandler,
         };
 
-        let handler = match self
-            .send_with_retries(|| default_api::get_failure_handler(&self.config, fh_id))
-        {
-            Ok(h) => h,
-            Err(e) => {
-                warn!(
-                    "Failed to fetch failure handler {} for job {}: {}",
-                    fh_id, job_id, e
-                );
-                return RecoveryOutcome::Error(format!("Failed to fetch failure handler: {}", e));
+        let rules = match self.failure_handler_rules.get(&fh_id) {
+            Some(rules) => Arc::clone(rules),
+            None => {
+                let handler = match self
+                    .send_with_retries(|| default_api::get_failure_handler(&self.config, fh_id))
+                {
+                    Ok(h) => h,
+                    Err(e) => {
+                        warn!(
+                            "Failed to fetch failure handler {} for job {}: {}",
+                            fh_id, job_id, e
+                        );
+                        return RecoveryOutcome::Error(format!(
+                            "Failed to fetch failure handler: {}",
+                            e
+                        ));
+                    }
+                };
+
+                // Parse the rules JSON
+                let rules: Vec<FailureHandlerRule> = match serde_json::from_str(&handler.rules) {
+                    Ok(r) => r,
+                    Err(e) => {
+                        warn!(
+                            "Failed to parse failure handler rules for job {}: {}",
+                            job_id, e
+                        );
+                        return RecoveryOutcome::Error(format!(
+                            "Failed to parse failure handler rules: {}",
+                            e
+                        ));
+                    }
+                };
+                let rules = Arc::new(rules);
+                self.failure_handler_rules.insert(fh_id, Arc::clone(&rules));
+                rules
             }
         };
 
-        // Parse the rules JSON
-        let rules: Vec<FailureHandlerRule> = match serde_json::from_str(&handler.rules) {
-            Ok(r) => r,
-            Err(e) => {
-                warn!(
-                    "Failed to parse failure handler rules for job {}: {}",
-                    job_id, e
-                );
-                return RecoveryOutcome::Error(format!(
-                    "Failed to parse failure handler rules: {}",
-                    e
-                ));
-            }
+        // Read the end of the job's output only if a rule needs it
+        let (stdout, stderr) = if rules.iter().any(|rule| rule.has_log_patterns()) {
+            let read = |path: String| {
+                read_log_tail(Path::new(&path), MAX_RECOVERY_LOG_BYTES)
+                    .map(|(text, _, _)| text)
+                    .unwrap_or_else(|e| {
+                        debug!("Failed to read job output file {}: {}", path, e);
+                        String::new()
+                    })
+            };
+            (
+                read(get_job_stdout_path(
+                    &self.output_dir,
+                    self.workflow_id,
+                    job_id,
+                    self.run_id,
+                    attempt_id,
+                )),
+                read(get_job_stderr_path(
+                    &self.output_dir,
+                    self.workflow_id,
+                    job_id,
+                    self.run_id,
+                    attempt_id,
+                )),
+            )
+        } else {
+            (String::new(), String::new())
         };
 
-        // Find a matching rule for this exit code
-        // First check for rules with specific exit_codes, then fall back to match_all_exit_codes
-        let matching_rule = rules
-            .iter()
-            .find(|rule| rule.exit_codes.contains(&(exit_code as i32)))
-            .or_else(|| rules.iter().find(|rule| rule.match_all_exit_codes));
+        // Find a matching rule for this exit code and output
+        let matching_rule = find_matching_failure_rule(&rules, exit_code, &stdout, &stderr);
 
         let rule = match matching_rule {
             Some(r) => r,
@@ -1102,20 +1836,42 @@ impl JobRunner {
             return RecoveryOutcome::MaxRetriesExceeded;
         }
 
+        // Apply resource and scheduler changes before the job becomes ready again so that the
+        // retry is claimed with the new requirements.
+        if rule.has_retry_adjustments()
+            && let Err(e) = self.apply_retry_adjustments(job_id, job_name, rule, attempt_id)
+        {
+            warn!(
+                "Job retry adjustments failed (job will still retry) workflow_id={} job_id={} error={}",
+                self.workflow_id, job_id, e
+            );
+        }
+
         // Call retry_job API first to reserve the retry slot.
         // This ensures we don't run recovery scripts for retries that won't happen.
         // Pass max_retries for server-side validation.
+        let delay_seconds = rule.retry_delay(attempt_id);
+        let avoid_compute_node_id = rule.avoid_failed_node.then_some(self.compute_node_id);
         match self.send_with_retries(|| {
-            default_api::retry_job(&self.config, job_id, self.run_id, rule.max_retries)
+            default_api::retry_job(
+                &self.config,
+                job_id,
+                self.run_id,
+                rule.max_retries,
+                delay_seconds,
+                avoid_compute_node_id,
+            )
         }) {
             Ok(_) => {
                 info!(
-                    "Job retried workflow_id={} job_id={} run_id={} attempt_id={} new_attempt_id={}",
+                    "Job retried workflow_id={} job_id={} run_id={} attempt_id={} new_attempt_id={} delay_seconds={:?} avoid_failed_node={}",
                     self.workflow_id,
                     job_id,
                     self.run_id,
                     attempt_id,
-                    attempt_id + 1
+                    attempt_id + 1,
+                    delay_seconds,
+                    rule.avoid_failed_node
                 );
             }
             Err(e) => {
@@ -1146,6 +1902,11 @@ impl JobRunner {
     }
 
     fn decrement_resources(&mut self, rr: &ResourceRequirementsModel) {
+        if self.is_multi_node_job(rr) {
+            self.resources.num_nodes -= rr.num_nodes;
+            assert!(self.resources.num_nodes >= 0);
+            return;
+        }
         let job_memory_gb = memory_string_to_gb(&rr.memory);
         self.resources.memory_gb -= job_memory_gb;
         self.resources.num_cpus -= rr.num_cpus;
@@ -1156,6 +1917,11 @@ impl JobRunner {
     }
 
     fn increment_resources(&mut self, rr: &ResourceRequirementsModel) {
+        if self.is_multi_node_job(rr) {
+            self.resources.num_nodes += rr.num_nodes;
+            assert!(self.resources.num_nodes <= self.orig_resources.num_nodes);
+            return;
+        }
         let job_memory_gb = memory_string_to_gb(&rr.memory);
         self.resources.memory_gb += job_memory_gb;
         self.resources.num_cpus += rr.num_cpus;
@@ -1165,6 +1931,235 @@ impl JobRunner {
         assert!(self.resources.num_gpus <= self.orig_resources.num_gpus);
     }
 
+    /// Returns true if the job runs on nodes reserved for it. Its CPUs, memory, and GPUs are
+    /// then accounted for by the reserved nodes' job runners.
+    fn is_multi_node_job(&self, rr: &ResourceRequirementsModel) -> bool {
+        self.multi_node_allocation_id.is_some() && rr.num_nodes > 1
+    }
+
+    /// Reserve nodes for the multi-node jobs that are waiting for them and start the jobs
+    /// whose reserved nodes have finished their running jobs.
+    fn start_waiting_multi_node_jobs(&mut self) {
+        let Some(allocation_id) = self.multi_node_allocation_id else {
+            return;
+        };
+        for mut waiting in std::mem::take(&mut self.waiting_multi_node_jobs) {
+            if waiting.reserved_nodes.is_empty() {
+                waiting.reserved_nodes = self.reserve_nodes(allocation_id, &waiting);
+            }
+            if waiting.reserved_nodes.is_empty() || !self.are_reserved_nodes_idle(&waiting) {
+                self.waiting_multi_node_jobs.push(waiting);
+                continue;
+            }
+
+            let job_id = waiting.job.id.expect("Job must have an ID");
+            let mut async_job = AsyncCliCommand::new(waiting.job);
+            async_job.set_multi_node_launch(MultiNodeLaunch {
+                launcher: self.torc_config.client.slurm.multi_node_launcher,
+                hostnames: waiting
+                    .reserved_nodes
+                    .iter()
+                    .map(|node| node.hostname.clone())
+                    .collect(),
+                cpus_per_node: waiting.rr.num_cpus,
+                gpus_per_node: waiting.rr.num_gpus,
+            });
+            info!(
+                "Job starting on reserved nodes workflow_id={} job_id={} compute_node_ids={:?}",
+                self.workflow_id,
+                job_id,
+                waiting
+                    .reserved_nodes
+                    .iter()
+                    .filter_map(|node| node.id)
+                    .collect::<Vec<_>>()
+            );
+            if self.start_claimed_job(async_job, &waiting.rr) {
+                self.job_resources.insert(job_id, waiting.rr);
+            } else {
+                self.increment_resources(&waiting.rr);
+            }
+        }
+    }
+
+    /// Reserve active job runners of the allocation for a multi-node job. Prefers the other
+    /// nodes so that this job runner can keep running jobs. Returns the reserved compute nodes,
+    /// or an empty list if not enough job runners are available yet.
+    fn reserve_nodes(
+        &self,
+        allocation_id: i64,
+        waiting: &WaitingMultiNodeJob,
+    ) -> Vec<ComputeNodeModel> {
+        let job_id = waiting.job.id.expect("Job must have an ID");
+        let num_nodes = waiting.rr.num_nodes as usize;
+        let candidates = match self.send_with_retries(|| {
+            default_api::list_compute_nodes(
+                &self.config,
+                self.workflow_id,
+                None, // offset
+                None, // limit
+                None, // sort_by
+                None, // reverse_sort
+                None, // hostname
+                None, // is_active
+                Some(allocation_id),
+            )
+        }) {
+            Ok(response) => response.items.unwrap_or_default(),
+            Err(e) => {
+                error!(
+                    "Failed to list compute nodes of allocation {}: {}",
+                    allocation_id, e
+                );
+                return Vec::new();
+            }
+        };
+        let allocation_nodes = candidates.len();
+        let Some(candidates) =
+            select_nodes_to_reserve(candidates, job_id, self.compute_node_id, num_nodes)
+        else {
+            debug!(
+                "Job waiting for nodes workflow_id={} job_id={} needed={} allocation_nodes={}",
+                self.workflow_id, job_id, num_nodes, allocation_nodes
+            );
+            return Vec::new();
+        };
+
+        let mut reserved_nodes = Vec::with_capacity(num_nodes);
+        for node in candidates {
+            if node.reserved_for_job_id == Some(job_id) {
+                reserved_nodes.push(node);
+                continue;
+            }
+            let node_id = node.id.expect("Compute node must have an ID");
+            // The server only reserves the node if no other job has reserved it since it was
+            // listed.
+            match self.send_with_retries(|| {
+                default_api::reserve_compute_node(&self.config, node_id, job_id)
+            }) {
+                Ok(node) if node.reserved_for_job_id == Some(job_id) => reserved_nodes.push(node),
+                Ok(node) => {
+                    // Another job runner reserved the node first. Release this job's nodes so
+                    // that the two jobs cannot each hold part of the nodes the other needs.
+                    debug!(
+                        "Compute node {} was reserved for job {:?} before job {}",
+                        node_id, node.reserved_for_job_id, job_id
+                    );
+                    self.release_nodes(job_id, &reserved_nodes);
+                    return Vec::new();
+                }
+                Err(e) => {
+                    // The reservations already made are reused by the next attempt and
+                    // released when the job completes.
+                    error!(
+                        "Failed to reserve compute node {} for job {}: {}",
+                        node_id, job_id, e
+                    );
+                    return Vec::new();
+                }
+            }
+        }
+        info!(
+            "Reserved nodes workflow_id={} job_id={} hostnames={:?}",
+            self.workflow_id,
+            job_id,
+            reserved_nodes
+                .iter()
+                .map(|node| node.hostname.as_str())
+                .collect::<Vec<_>>()
+        );
+        reserved_nodes
+    }
+
+    /// Release the reservations of nodes reserved for the multi-node job `job_id`.
+    fn release_nodes(&self, job_id: i64, nodes: &[ComputeNodeModel]) {
+        for node in nodes {
+            let node_id = node.id.expect("Compute node must have an ID");
+            if let Err(e) = self.send_with_retries(|| {
+                default_api::release_compute_node(&self.config, node_id, job_id)
+            }) {
+                // The server releases the reservation when the job completes.
+                warn!(
+                    "Failed to release compute node {} reserved for job {}: {}",
+                    node_id, job_id, e
+                );
+            }
+        }
+    }
+
+    /// Returns true if none of the nodes reserved for a multi-node job are running jobs.
+    fn are_reserved_nodes_idle(&self, waiting: &WaitingMultiNodeJob) -> bool {
+        for node in &waiting.reserved_nodes {
+            let node_id = node.id.expect("Compute node must have an ID");
+            if node_id == self.compute_node_id {
+                let is_busy = self
+                    .job_resources
+                    .values()
+                    .any(|rr| !self.is_multi_node_job(rr));
+                if is_busy {
+                    return false;
+                }
+                continue;
+            }
+            match self.send_with_retries(|| {
+                default_api::list_jobs(
+                    &self.config,
+                    self.workflow_id,
+                    None,    // status
+                    None,    // needs_file_id
+                    None,    // upstream_job_id
+                    None,    // offset
+                    Some(1), // limit
+                    None,    // sort_by
+                    None,    // reverse_sort
+                    None,    // include_relationships
+                    Some(node_id),
+                )
+            }) {
+                Ok(response) if response.total_count == 0 => {}
+                Ok(_) => return false,
+                Err(e) => {
+                    error!("Failed to list jobs of compute node {}: {}", node_id, e);
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Return multi-node jobs that are still waiting for their nodes to the ready state so that
+    /// another job runner can claim them. The server releases their reservations.
+    fn release_waiting_multi_node_jobs(&mut self) {
+        for waiting in std::mem::take(&mut self.waiting_multi_node_jobs) {
+            let job_id = waiting.job.id.expect("Job must have an ID");
+            match self.send_with_retries(|| default_api::get_job(&self.config, job_id)) {
+                // The job may have been canceled in the meantime.
+                Ok(job) if job.status == Some(JobStatus::Pending) => {}
+                Ok(_) => continue,
+                Err(e) => {
+                    error!("Failed to get job {}: {}", job_id, e);
+                    continue;
+                }
+            }
+            match self.send_with_retries(|| {
+                default_api::manage_status_change(
+                    &self.config,
+                    job_id,
+                    JobStatus::Ready,
+                    self.run_id,
+                    None,
+                )
+            }) {
+                Ok(_) => info!(
+                    "Job released workflow_id={} job_id={}",
+                    self.workflow_id, job_id
+                ),
+                Err(e) => error!("Failed to release job {}: {}", job_id, e),
+            }
+            self.increment_resources(&waiting.rr);
+        }
+    }
+
     /// Update the time_limit in resources based on remaining time until end_time.
     /// This ensures the server only returns jobs whose runtime fits within the remaining allocation time.
     fn update_remaining_time_limit(&mut self) {
@@ -1187,6 +2182,222 @@ impl JobRunner {
         // If end_time is None, leave time_limit as-is (unlimited)
     }
 
+    /// Evaluate the `run_if` condition of a claimed job. If the condition is false, the job is
+    /// completed with `JobStatus::Skipped` without being started. If the condition cannot be
+    /// evaluated, the job is completed with `JobStatus::Failed`.
+    ///
+    /// Returns true if the job was completed here and must not be started.
+    fn skip_job_if_condition_false(&mut self, job: &JobModel) -> bool {
+        let Some(run_if) = job.run_if.as_deref() else {
+            return false;
+        };
+        let job_id = job.id.expect("Job must have an ID");
+        let context = RunnerConditionContext {
+            config: &self.config,
+            workflow_id: self.workflow_id,
+            run_id: self.run_id,
+            job,
+        };
+        let (status, return_code) = match JobCondition::parse(run_if)
+            .and_then(|c| c.evaluate(&context))
+        {
+            Ok(true) => return false,
+            Ok(false) => {
+                info!(
+                    "Job skipped workflow_id={} job_id={} job_name={} run_if={}",
+                    self.workflow_id, job_id, job.name, run_if
+                );
+                (JobStatus::Skipped, 0)
+            }
+            Err(e) => {
+                error!(
+                    "Job condition failed workflow_id={} job_id={} job_name={} run_if={} error={}",
+                    self.workflow_id, job_id, job.name, run_if, e
+                );
+                self.had_failures = true;
+                (JobStatus::Failed, 1)
+            }
+        };
+        self.complete_job_without_running(job, status, return_code);
+        true
+    }
+
+    /// Complete a claimed job that will not be started.
+    fn complete_job_without_running(
+        &mut self,
+        job: &JobModel,
+        status: JobStatus,
+        return_code: i64,
+    ) {
+        let job_id = job.id.expect("Job must have an ID");
+        let result = ResultModel::new(
+            job_id,
+            self.workflow_id,
+            self.run_id,
+            job.attempt_id.unwrap_or(1),
+            self.compute_node_id,
+            return_code,
+            0.0,
+            Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+            status,
+        );
+        match self.send_with_retries(|| {
+            default_api::complete_job(&self.config, job_id, status, self.run_id, result.clone())
+        }) {
+            Ok(_) => {
+                // Dependents of the job may now be ready.
+                self.last_job_claimed_time = Some(Instant::now());
+            }
+            Err(e) => {
+                error!(
+                    "Job complete failed workflow_id={} job_id={} error={}",
+                    self.workflow_id, job_id, e
+                );
+            }
+        }
+    }
+
+    /// Copy the job's input files to this worker if file staging is enabled.
+    ///
+    /// Returns false if staging failed, in which case the job has been completed as failed.
+    fn stage_input_files(&mut self, job: &JobModel) -> bool {
+        let Some(input_file_ids) = job.input_file_ids.as_ref().filter(|ids| !ids.is_empty()) else {
+            return true;
+        };
+        if self.file_stager.is_none() {
+            return true;
+        }
+        if let Err(e) = self.stage_files(job, input_file_ids, StagingDirection::StageIn) {
+            error!(
+                "Input file staging failed workflow_id={} job_id={} error={}",
+                self.workflow_id,
+                job.id.unwrap_or(-1),
+                e
+            );
+            self.had_failures = true;
+            self.complete_job_without_running(job, JobStatus::Failed, 1);
+            return false;
+        }
+        true
+    }
+
+    /// Transfer the given files with the file stager, recording an event for each transfer.
+    ///
+    /// Stops at the first failed transfer.
+    fn stage_files(
+        &self,
+        job: &JobModel,
+        file_ids: &[i64],
+        direction: StagingDirection,
+    ) -> Result<(), String> {
+        let Some(stager) = self.file_stager.as_ref() else {
+            return Ok(());
+        };
+        for file_id in file_ids {
+            let file = self
+                .send_with_retries(|| default_api::get_file(&self.config, *file_id))
+                .map_err(|e| {
+                    format!("Failed to fetch file model for file_id {}: {}", file_id, e)
+                })?;
+            let result = match direction {
+                StagingDirection::StageIn => stager.stage_in(&file.path),
+                StagingDirection::StageOut => stager.stage_out(&file.path),
+            };
+            let mut data = serde_json::json!({
+                "category": "file_staging",
+                "type": direction.as_str(),
+                "job_id": job.id,
+                "job_name": job.name,
+                "file_id": file_id,
+                "path": file.path,
+                "remote_path": stager.remote_path(&file.path),
+                "host": stager.host().to_string(),
+                "compute_node_id": self.compute_node_id,
+                "status": if result.is_ok() { "success" } else { "failed" },
+            });
+            if let Err(e) = &result {
+                data["error"] = Value::String(e.clone());
+            }
+            let event = EventModel::new(self.workflow_id, data);
+            if let Err(e) =
+                self.send_with_retries(|| default_api::create_event(&self.config, event.clone()))
+            {
+                warn!(
+                    "Failed to record file staging event workflow_id={} job_id={}: {}",
+                    self.workflow_id,
+                    job.id.unwrap_or(-1),
+                    e
+                );
+            }
+            result.map_err(|e| format!("{} of '{}' failed: {}", direction, file.path, e))?;
+            info!(
+                "Staged file workflow_id={} job_id={} direction={} path={}",
+                self.workflow_id,
+                job.id.unwrap_or(-1),
+                direction,
+                file.path
+            );
+        }
+        Ok(())
+    }
+
+    /// Mark a claimed job as started and start its process. Returns true if the job is
+    /// running.
+    fn start_claimed_job(
+        &mut self,
+        mut async_job: AsyncCliCommand,
+        job_rr: &ResourceRequirementsModel,
+    ) -> bool {
+        let job_id = async_job.job_id;
+        // Mark job as started in the database before actually starting it
+        match self.send_with_retries(|| {
+            default_api::start_job(
+                &self.config,
+                job_id,
+                self.run_id,
+                self.compute_node_id,
+                None,
+            )
+        }) {
+            Ok(_) => {
+                debug!("Successfully marked job {} as started in database", job_id);
+            }
+            Err(e) => {
+                panic!(
+                    "Failed to mark job {} as started in database after retries: {}",
+                    job_id, e
+                );
+            }
+        }
+
+        self.set_runtime_limit(&mut async_job, job_rr);
+        let attempt_id = async_job.job.attempt_id.unwrap_or(1);
+        match async_job.start(
+            &self.output_dir,
+            self.workflow_id,
+            self.run_id,
+            attempt_id,
+            self.resource_monitor.as_ref(),
+            &self.config.base_path,
+        ) {
+            Ok(()) => {
+                info!(
+                    "Job started workflow_id={} job_id={} run_id={} compute_node_id={} attempt_id={}",
+                    self.workflow_id, job_id, self.run_id, self.compute_node_id, attempt_id
+                );
+                self.running_jobs.insert(job_id, async_job);
+                true
+            }
+            Err(e) => {
+                error!(
+                    "Job start failed workflow_id={} job_id={} error={}",
+                    self.workflow_id, job_id, e
+                );
+                false
+            }
+        }
+    }
+
     fn run_ready_jobs_based_on_resources(&mut self) {
         self.update_remaining_time_limit();
 
@@ -1222,10 +2433,12 @@ impl JobRunner {
 
                 for job in jobs {
                     let job_id = job.id.expect("Job must have an ID");
+                    if self.skip_job_if_condition_false(&job) || !self.stage_input_files(&job) {
+                        continue;
+                    }
                     let rr_id = job
                         .resource_requirements_id
                         .expect("Job must have a resource_requirements_id");
-                    let mut async_job = AsyncCliCommand::new(job);
 
                     let job_rr = match self.send_with_retries(|| {
                         default_api::get_resource_requirements(&self.config, rr_id)
@@ -1240,56 +2453,22 @@ impl JobRunner {
                         }
                     };
 
-                    // Mark job as started in the database before actually starting it
-                    match self.send_with_retries(|| {
-                        default_api::start_job(
-                            &self.config,
-                            job_id,
-                            self.run_id,
-                            self.compute_node_id,
-                            None,
-                        )
-                    }) {
-                        Ok(_) => {
-                            debug!("Successfully marked job {} as started in database", job_id);
-                        }
-                        Err(e) => {
-                            panic!(
-                                "Failed to mark job {} as started in database after retries: {}",
-                                job_id, e
-                            );
-                        }
+                    if self.is_multi_node_job(&job_rr) {
+                        info!(
+                            "Job waiting for nodes workflow_id={} job_id={} num_nodes={}",
+                            self.workflow_id, job_id, job_rr.num_nodes
+                        );
+                        self.decrement_resources(&job_rr);
+                        self.waiting_multi_node_jobs.push(WaitingMultiNodeJob {
+                            job,
+                            rr: job_rr,
+                            reserved_nodes: Vec::new(),
+                        });
+                        continue;
                     }
-
-                    let attempt_id = async_job.job.attempt_id.unwrap_or(1);
-                    match async_job.start(
-                        &self.output_dir,
-                        self.workflow_id,
-                        self.run_id,
-                        attempt_id,
-                        self.resource_monitor.as_ref(),
-                        &self.config.base_path,
-                    ) {
-                        Ok(()) => {
-                            info!(
-                                "Job started workflow_id={} job_id={} run_id={} compute_node_id={} attempt_id={}",
-                                self.workflow_id,
-                                job_id,
-                                self.run_id,
-                                self.compute_node_id,
-                                attempt_id
-                            );
-                            self.running_jobs.insert(job_id, async_job);
-                            self.decrement_resources(&job_rr);
-                            self.job_resources.insert(job_id, job_rr);
-                        }
-                        Err(e) => {
-                            error!(
-                                "Job start failed workflow_id={} job_id={} error={}",
-                                self.workflow_id, job_id, e
-                            );
-                            continue;
-                        }
+                    if self.start_claimed_job(AsyncCliCommand::new(job), &job_rr) {
+                        self.decrement_resources(&job_rr);
+                        self.job_resources.insert(job_id, job_rr);
                     }
                 }
             }
@@ -1337,7 +2516,12 @@ impl JobRunner {
             .expect("max_parallel_jobs must be set")
             - self.running_jobs.len() as i64;
         match self.send_with_retries(|| {
-            default_api::claim_next_jobs(&self.config, self.workflow_id, Some(limit), None)
+            default_api::claim_next_jobs(
+                &self.config,
+                self.workflow_id,
+                Some(limit),
+                Some(serde_json::json!({ "compute_node_id": self.compute_node_id })),
+            )
         }) {
             Ok(response) => {
                 let jobs = response.jobs.unwrap_or_default();
@@ -1359,8 +2543,29 @@ impl JobRunner {
                 // Start each job asynchronously
                 for job in jobs {
                     let job_id = job.id.expect("Job must have an ID");
+                    if self.skip_job_if_condition_false(&job) || !self.stage_input_files(&job) {
+                        continue;
+                    }
+                    let rr_id = job.resource_requirements_id;
                     let mut async_job = AsyncCliCommand::new(job);
 
+                    // Resource requirements are only needed here to enforce runtime limits.
+                    if self.rules.enforce_runtime_limits
+                        && let Some(rr_id) = rr_id
+                    {
+                        match self.send_with_retries(|| {
+                            default_api::get_resource_requirements(&self.config, rr_id)
+                        }) {
+                            Ok(job_rr) => self.set_runtime_limit(&mut async_job, &job_rr),
+                            Err(e) => {
+                                warn!(
+                                    "Cannot enforce runtime limit for job {}: failed to get resource requirements: {}",
+                                    job_id, e
+                                );
+                            }
+                        }
+                    }
+
                     // Mark job as started in the database before actually starting it
                     match self.send_with_retries(|| {
                         default_api::start_job(
@@ -1669,7 +2874,7 @@ impl JobRunner {
     /// Check if this job runner can handle the given action
     /// Job runners can handle:
     /// - run_commands actions (always)
-    /// - schedule_nodes actions (including slurm)
+    /// - schedule_nodes actions (slurm and pbs)
     fn can_handle_action(&self, action: &crate::models::WorkflowActionModel) -> bool {
         let action_type = &action.action_type;
 
@@ -1683,11 +2888,11 @@ impl JobRunner {
                     .and_then(|v| v.as_str())
                     .unwrap_or("");
 
-                // Job runners can handle slurm schedule_nodes using schedule_slurm_nodes_for_action
-                let can_handle = scheduler_type == "slurm";
+                // Job runners can handle HPC schedule_nodes actions using schedule_hpc_nodes
+                let can_handle = HpcType::from_scheduler_type(scheduler_type).is_some();
                 if !can_handle {
                     debug!(
-                        "Cannot handle schedule_nodes action: scheduler_type='{}' (expected 'slurm'). action_config={:?}",
+                        "Cannot handle schedule_nodes action: scheduler_type='{}' (expected an HPC scheduler). action_config={:?}",
                         scheduler_type, action.action_config
                     );
                 }
@@ -1770,21 +2975,30 @@ impl JobRunner {
                     .get("start_one_worker_per_node")
                     .and_then(|v| v.as_bool())
                     .unwrap_or(false);
+                let use_job_array = action_config
+                    .get("use_job_array")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
 
                 let max_parallel_jobs = action_config
                     .get("max_parallel_jobs")
                     .and_then(|v| v.as_i64())
                     .map(|v| v as i32);
+                let dependency =
+                    crate::client::commands::slurm::AllocationDependency::from_action_config(
+                        action_config,
+                    )?;
 
                 info!(
                     "Scheduling {} compute nodes (scheduler_type={}, scheduler_id={})",
                     num_allocations, scheduler_type, scheduler_id
                 );
 
-                if scheduler_type == "slurm" {
-                    // Use the same function as WorkflowManager for Slurm scheduling
-                    match crate::client::commands::slurm::schedule_slurm_nodes(
+                if let Some(hpc_type) = HpcType::from_scheduler_type(scheduler_type) {
+                    // Use the same function as WorkflowManager for HPC scheduling
+                    match crate::client::commands::slurm::schedule_hpc_nodes(
                         &self.config,
+                        hpc_type,
                         self.workflow_id,
                         scheduler_id,
                         num_allocations,
@@ -1793,15 +3007,20 @@ impl JobRunner {
                         self.torc_config.client.slurm.poll_interval,
                         max_parallel_jobs,
                         start_one_worker_per_node,
+                        use_job_array,
+                        dependency.as_ref(),
                         self.torc_config.client.slurm.keep_submission_scripts,
                     ) {
                         Ok(()) => {
-                            info!("Successfully scheduled {} Slurm job(s)", num_allocations);
+                            info!(
+                                "Successfully scheduled {} {} job(s)",
+                                num_allocations, hpc_type
+                            );
                             Ok(())
                         }
                         Err(err) => {
-                            error!("Failed to schedule Slurm nodes: {}", err);
-                            Err(format!("Failed to schedule Slurm nodes: {}", err).into())
+                            error!("Failed to schedule {} nodes: {}", hpc_type, err);
+                            Err(format!("Failed to schedule {} nodes: {}", hpc_type, err).into())
                         }
                     }
                 } else {
@@ -1836,9 +3055,14 @@ struct ComputeNodeRules {
     /// new jobs and wait for running jobs to complete. Default is 300 seconds (5 minutes).
     pub compute_node_min_time_for_new_jobs_seconds: u64,
     pub jobs_sort_method: ClaimJobsSortMethod,
+    /// Terminate jobs that run longer than the runtime in their resource requirements.
+    pub enforce_runtime_limits: bool,
+    /// Seconds between SIGTERM and SIGKILL for jobs that exceed their runtime.
+    pub runtime_limit_grace_seconds: u64,
 }
 
 impl ComputeNodeRules {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         compute_node_expiration_buffer_seconds: Option<i64>,
         compute_node_wait_for_new_jobs_seconds: Option<i64>,
@@ -1846,6 +3070,8 @@ impl ComputeNodeRules {
         compute_node_wait_for_healthy_database_minutes: Option<i64>,
         compute_node_min_time_for_new_jobs_seconds: Option<i64>,
         jobs_sort_method: Option<ClaimJobsSortMethod>,
+        enforce_runtime_limits: Option<bool>,
+        runtime_limit_grace_seconds: Option<i64>,
     ) -> Self {
         ComputeNodeRules {
             compute_node_expiration_buffer_seconds: compute_node_expiration_buffer_seconds
@@ -1859,6 +3085,8 @@ impl ComputeNodeRules {
             compute_node_min_time_for_new_jobs_seconds: compute_node_min_time_for_new_jobs_seconds
                 .unwrap_or(300) as u64,
             jobs_sort_method: jobs_sort_method.unwrap_or(ClaimJobsSortMethod::GpusRuntimeMemory),
+            enforce_runtime_limits: enforce_runtime_limits.unwrap_or(false),
+            runtime_limit_grace_seconds: runtime_limit_grace_seconds.unwrap_or(60).max(0) as u64,
         }
     }
 }
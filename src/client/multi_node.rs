@@ -0,0 +1,133 @@
+//! Launching jobs that span multiple nodes.
+//!
+//! A job whose resource requirements have `num_nodes > 1` runs on the head node's job runner
+//! of a `start_one_worker_per_node` allocation. That job runner reserves `num_nodes` of the
+//! allocation's worker nodes (see [`crate::client::job_runner`]) and starts the job's command
+//! with the configured launcher:
+//!
+//! - **srun** (default): `srun --overlap --nodes=<N> --nodelist=<hosts> --ntasks-per-node=1
+//!   --cpus-per-task=<num_cpus> [--gpus-per-node=<num_gpus>] <command>`. Each node runs one
+//!   task that may use all of the job's CPUs and GPUs on that node.
+//! - **mpirun**: `mpirun -hostfile <hostfile> -np <N> <command>`.
+//! - **none**: the command runs on the head node and starts its own processes on the nodes.
+//!
+//! With every launcher, the job's environment includes `TORC_NUM_NODES`, `TORC_NODE_LIST`
+//! (comma-separated hostnames), and `TORC_HOSTFILE`, a file with one hostname per line.
+
+use crate::config::MultiNodeLauncher;
+
+/// The nodes reserved for a multi-node job and how to start the job on them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiNodeLaunch {
+    pub launcher: MultiNodeLauncher,
+    /// Hostnames of the reserved nodes.
+    pub hostnames: Vec<String>,
+    /// CPUs that the job uses on each node.
+    pub cpus_per_node: i64,
+    /// GPUs that the job uses on each node.
+    pub gpus_per_node: i64,
+}
+
+impl MultiNodeLaunch {
+    /// Return the command string that starts `command` on the reserved nodes.
+    pub fn wrap_command(&self, command: &str, hostfile: &str) -> String {
+        let num_nodes = self.hostnames.len();
+        match self.launcher {
+            MultiNodeLauncher::Srun => {
+                let mut prefix = format!(
+                    "srun --overlap --nodes={} --nodelist={} --ntasks-per-node=1",
+                    num_nodes,
+                    self.hostnames.join(",")
+                );
+                if self.cpus_per_node > 0 {
+                    prefix.push_str(&format!(" --cpus-per-task={}", self.cpus_per_node));
+                }
+                if self.gpus_per_node > 0 {
+                    prefix.push_str(&format!(" --gpus-per-node={}", self.gpus_per_node));
+                }
+                format!("{} {}", prefix, command)
+            }
+            MultiNodeLauncher::Mpirun => format!(
+                "mpirun -hostfile {} -np {} {}",
+                shlex::try_quote(hostfile).unwrap_or_else(|_| hostfile.into()),
+                num_nodes,
+                command
+            ),
+            MultiNodeLauncher::None => command.to_string(),
+        }
+    }
+
+    /// Return the contents of the hostfile: one hostname per line.
+    pub fn hostfile_contents(&self) -> String {
+        self.hostnames
+            .iter()
+            .map(|hostname| format!("{}\n", hostname))
+            .collect()
+    }
+
+    /// Return the environment variables that describe the reserved nodes to the job.
+    pub fn env(&self, hostfile: &str) -> Vec<(String, String)> {
+        vec![
+            (
+                "TORC_NUM_NODES".to_string(),
+                self.hostnames.len().to_string(),
+            ),
+            ("TORC_NODE_LIST".to_string(), self.hostnames.join(",")),
+            ("TORC_HOSTFILE".to_string(), hostfile.to_string()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn launch(launcher: MultiNodeLauncher) -> MultiNodeLaunch {
+        MultiNodeLaunch {
+            launcher,
+            hostnames: vec!["node1".to_string(), "node2".to_string()],
+            cpus_per_node: 36,
+            gpus_per_node: 0,
+        }
+    }
+
+    #[test]
+    fn test_srun_command() {
+        let mut launch = launch(MultiNodeLauncher::Srun);
+        assert_eq!(
+            launch.wrap_command("./mpi_app --steps 10", "/out/hosts"),
+            "srun --overlap --nodes=2 --nodelist=node1,node2 --ntasks-per-node=1 \
+             --cpus-per-task=36 ./mpi_app --steps 10"
+        );
+        launch.gpus_per_node = 4;
+        assert!(
+            launch
+                .wrap_command("./mpi_app", "/out/hosts")
+                .contains("--cpus-per-task=36 --gpus-per-node=4 ./mpi_app")
+        );
+    }
+
+    #[test]
+    fn test_mpirun_command() {
+        let launch = launch(MultiNodeLauncher::Mpirun);
+        assert_eq!(
+            launch.wrap_command("./mpi_app", "/my output/hosts"),
+            "mpirun -hostfile '/my output/hosts' -np 2 ./mpi_app"
+        );
+        assert_eq!(launch.hostfile_contents(), "node1\nnode2\n");
+    }
+
+    #[test]
+    fn test_no_launcher() {
+        let launch = launch(MultiNodeLauncher::None);
+        assert_eq!(launch.wrap_command("./run.sh", "/out/hosts"), "./run.sh");
+        assert_eq!(
+            launch.env("/out/hosts"),
+            vec![
+                ("TORC_NUM_NODES".to_string(), "2".to_string()),
+                ("TORC_NODE_LIST".to_string(), "node1,node2".to_string()),
+                ("TORC_HOSTFILE".to_string(), "/out/hosts".to_string()),
+            ]
+        );
+    }
+}
@@ -24,12 +24,15 @@
 //! ```
 
 pub mod ssh;
+pub mod staging;
 pub mod types;
 pub mod worker_file;
 
 pub use ssh::{
     check_all_connectivity, check_ssh_connectivity, get_remote_torc_version, parallel_execute,
-    scp_download, ssh_execute, ssh_execute_capture, verify_all_versions, verify_version,
+    scp_download, ssh_download, ssh_execute, ssh_execute_capture, ssh_upload, verify_all_versions,
+    verify_version,
 };
+pub use staging::{FileStager, StagingDirection};
 pub use types::{RemoteOperationResult, RemoteWorkerState, WorkerEntry};
 pub use worker_file::{parse_worker_content, parse_worker_file};
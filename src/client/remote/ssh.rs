@@ -1,7 +1,8 @@
 //! SSH utilities for remote worker execution.
 
 use log::{debug, info};
-use std::process::{Command, Output};
+use std::fs::File;
+use std::process::{Command, Output, Stdio};
 use std::sync::mpsc;
 use std::thread;
 
@@ -19,24 +20,8 @@ pub fn ssh_execute(
     timeout_secs: Option<u64>,
 ) -> Result<Output, String> {
     let timeout = timeout_secs.unwrap_or(SSH_CONNECT_TIMEOUT);
-
-    let mut cmd = Command::new("ssh");
-
-    // Add SSH options
-    cmd.arg("-o")
-        .arg(format!("ConnectTimeout={}", timeout))
-        .arg("-o")
-        .arg("BatchMode=yes")
-        .arg("-o")
-        .arg("StrictHostKeyChecking=accept-new");
-
-    // Add port if specified
-    if let Some(port) = worker.port {
-        cmd.arg("-p").arg(port.to_string());
-    }
-
-    // Add target and command
-    cmd.arg(worker.ssh_target()).arg(command);
+    let mut cmd = ssh_command(worker, timeout);
+    cmd.arg(command);
 
     debug!(
         "Executing SSH command on {}: {}",
@@ -167,6 +152,91 @@ pub fn scp_download(
         .map_err(|e| format!("SCP failed for {}: {}", worker.display_name(), e))
 }
 
+/// Build an SSH command to `worker` with the options shared by the SSH helpers.
+fn ssh_command(worker: &WorkerEntry, timeout: u64) -> Command {
+    let mut cmd = Command::new("ssh");
+    cmd.arg("-o")
+        .arg(format!("ConnectTimeout={}", timeout))
+        .arg("-o")
+        .arg("BatchMode=yes")
+        .arg("-o")
+        .arg("StrictHostKeyChecking=accept-new");
+    if let Some(port) = worker.port {
+        cmd.arg("-p").arg(port.to_string());
+    }
+    cmd.arg(worker.ssh_target());
+    cmd
+}
+
+/// Quote a remote path for the remote shell.
+fn quote_remote_path(remote_path: &str) -> Result<String, String> {
+    shlex::try_quote(remote_path)
+        .map(|quoted| quoted.into_owned())
+        .map_err(|e| format!("Invalid remote path '{}': {}", remote_path, e))
+}
+
+/// Copy a local file to a remote host by streaming it to `cat` over SSH.
+///
+/// The remote path is quoted for the remote shell. Unlike scp, whose handling of remote paths
+/// depends on the OpenSSH version, this works for any path. The parent directory of
+/// `remote_path` must already exist on the remote host.
+pub fn ssh_upload(
+    worker: &WorkerEntry,
+    local_path: &str,
+    remote_path: &str,
+    timeout_secs: Option<u64>,
+) -> Result<Output, String> {
+    let timeout = timeout_secs.unwrap_or(300); // Default 5 minutes for file transfers
+    let file =
+        File::open(local_path).map_err(|e| format!("Failed to open '{}': {}", local_path, e))?;
+    let mut cmd = ssh_command(worker, timeout);
+    cmd.arg(format!("cat > {}", quote_remote_path(remote_path)?))
+        .stdin(Stdio::from(file));
+
+    debug!(
+        "SSH upload to {}: {} -> {}",
+        worker.display_name(),
+        local_path,
+        remote_path
+    );
+
+    cmd.output()
+        .map_err(|e| format!("SSH upload failed for {}: {}", worker.display_name(), e))
+}
+
+/// Copy a file from a remote host by streaming it from `cat` over SSH.
+///
+/// The remote path is quoted for the remote shell, as in `ssh_upload`. The local file is removed
+/// if the copy fails.
+pub fn ssh_download(
+    worker: &WorkerEntry,
+    remote_path: &str,
+    local_path: &str,
+    timeout_secs: Option<u64>,
+) -> Result<Output, String> {
+    let timeout = timeout_secs.unwrap_or(300); // Default 5 minutes for file transfers
+    let quoted = quote_remote_path(remote_path)?;
+    let file = File::create(local_path)
+        .map_err(|e| format!("Failed to create '{}': {}", local_path, e))?;
+    let mut cmd = ssh_command(worker, timeout);
+    cmd.arg(format!("cat {}", quoted)).stdout(Stdio::from(file));
+
+    debug!(
+        "SSH download from {}: {} -> {}",
+        worker.display_name(),
+        remote_path,
+        local_path
+    );
+
+    let result = cmd
+        .output()
+        .map_err(|e| format!("SSH download failed for {}: {}", worker.display_name(), e));
+    if !matches!(&result, Ok(output) if output.status.success()) {
+        let _ = std::fs::remove_file(local_path);
+    }
+    result
+}
+
 /// Execute operations in parallel across multiple workers.
 ///
 /// Returns results in the same order as the input workers.
@@ -311,6 +381,16 @@ pub fn check_all_connectivity(workers: &[WorkerEntry], max_parallel: usize) -> R
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_quote_remote_path() {
+        assert_eq!(quote_remote_path("/data/a.csv").unwrap(), "/data/a.csv");
+        assert_eq!(
+            quote_remote_path("/data/my file;rm -rf ~").unwrap(),
+            "'/data/my file;rm -rf ~'"
+        );
+        assert!(quote_remote_path("/data/a\0b").is_err());
+    }
+
     #[test]
     fn test_parse_torc_version() {
         assert_eq!(parse_torc_version("torc 0.7.0"), "0.7.0");
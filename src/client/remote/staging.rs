@@ -0,0 +1,158 @@
+//! File staging for workers without shared storage.
+//!
+//! When a worker is started with `--stage-from`, it copies each job's declared input
+//! files from the staging host before the job runs and copies its declared output
+//! files back to the staging host after the job succeeds.
+
+use log::debug;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::process::Output;
+
+use super::ssh::{ssh_download, ssh_execute_capture, ssh_upload};
+use super::types::WorkerEntry;
+use super::worker_file::parse_worker_content;
+
+/// Direction of a staging transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StagingDirection {
+    /// Copy an input file from the staging host to the worker.
+    StageIn,
+    /// Copy an output file from the worker back to the staging host.
+    StageOut,
+}
+
+impl StagingDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StagingDirection::StageIn => "stage_in",
+            StagingDirection::StageOut => "stage_out",
+        }
+    }
+}
+
+impl fmt::Display for StagingDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Copies job files between a worker and the host that owns the workflow's data.
+#[derive(Debug, Clone)]
+pub struct FileStager {
+    /// Host that holds the canonical copies of the files.
+    host: WorkerEntry,
+    /// Directory on the staging host that relative file paths are resolved against.
+    root: Option<String>,
+}
+
+impl FileStager {
+    /// Create a stager for a staging host given as `[user@]hostname[:port]`.
+    pub fn new(host: &str, root: Option<String>) -> Result<Self, String> {
+        let mut entries = parse_worker_content(host, "--stage-from")?;
+        if entries.len() != 1 {
+            return Err(format!("Invalid staging host '{}'", host));
+        }
+        Ok(Self {
+            host: entries.remove(0),
+            root,
+        })
+    }
+
+    /// The staging host.
+    pub fn host(&self) -> &WorkerEntry {
+        &self.host
+    }
+
+    /// Path of a file on the staging host.
+    ///
+    /// Absolute paths are used as-is; relative paths are resolved against the staging root.
+    pub fn remote_path(&self, path: &str) -> String {
+        match &self.root {
+            Some(root) if !Path::new(path).is_absolute() => {
+                Path::new(root).join(path).to_string_lossy().to_string()
+            }
+            _ => path.to_string(),
+        }
+    }
+
+    /// Copy a file from the staging host to the same path on this worker.
+    pub fn stage_in(&self, path: &str) -> Result<(), String> {
+        if let Some(parent) = Path::new(path).parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create directory '{}': {}", parent.display(), e))?;
+        }
+        let remote_path = self.remote_path(path);
+        debug!(
+            "Staging in {}:{} -> {}",
+            self.host.display_name(),
+            remote_path,
+            path
+        );
+        check_transfer(ssh_download(&self.host, &remote_path, path, None)?)
+    }
+
+    /// Copy a file from this worker to the corresponding path on the staging host.
+    pub fn stage_out(&self, path: &str) -> Result<(), String> {
+        let remote_path = self.remote_path(path);
+        if let Some(parent) = Path::new(&remote_path).parent()
+            && !parent.as_os_str().is_empty()
+        {
+            let parent = parent.to_string_lossy();
+            let quoted = shlex::try_quote(&parent)
+                .map_err(|e| format!("Invalid remote directory '{}': {}", parent, e))?;
+            ssh_execute_capture(&self.host, &format!("mkdir -p {}", quoted))?;
+        }
+        debug!(
+            "Staging out {} -> {}:{}",
+            path,
+            self.host.display_name(),
+            remote_path
+        );
+        check_transfer(ssh_upload(&self.host, path, &remote_path, None)?)
+    }
+}
+
+fn check_transfer(output: Output) -> Result<(), String> {
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_parses_host() {
+        let stager = FileStager::new("alice@data.example.com:2222", None).unwrap();
+        assert_eq!(stager.host().user.as_deref(), Some("alice"));
+        assert_eq!(stager.host().host, "data.example.com");
+        assert_eq!(stager.host().port, Some(2222));
+        assert!(FileStager::new("", None).is_err());
+    }
+
+    #[test]
+    fn test_remote_path() {
+        let stager = FileStager::new("data", Some("/projects/study".to_string())).unwrap();
+        assert_eq!(
+            stager.remote_path("inputs/a.csv"),
+            "/projects/study/inputs/a.csv"
+        );
+        assert_eq!(stager.remote_path("/scratch/b.csv"), "/scratch/b.csv");
+
+        let no_root = FileStager::new("data", None).unwrap();
+        assert_eq!(no_root.remote_path("inputs/a.csv"), "inputs/a.csv");
+    }
+
+    #[test]
+    fn test_staging_direction() {
+        assert_eq!(StagingDirection::StageIn.to_string(), "stage_in");
+        assert_eq!(StagingDirection::StageOut.as_str(), "stage_out");
+    }
+}
@@ -73,7 +73,7 @@ pub struct FailedJobInfo {
     #[serde(default, skip_serializing_if = "is_false")]
     pub likely_timeout: bool,
 
-    /// Reason for timeout detection (e.g., "sigxcpu_152")
+    /// Reason for timeout detection (e.g., "sigxcpu_152", "runtime_limit_124")
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timeout_reason: Option<String>,
 
@@ -945,7 +945,10 @@ pub fn apply_plan_to_spec(plan: &SchedulerPlan, spec: &mut WorkflowSpec) {
                 scheduler_type: Some("slurm".to_string()),
                 num_allocations: Some(pa.num_allocations),
                 start_one_worker_per_node,
+                use_job_array: None,
                 max_parallel_jobs: None,
+                depends_on_schedulers: None,
+                dependency_type: None,
                 persistent: None,
             }
         })
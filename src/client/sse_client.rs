@@ -94,12 +94,12 @@ impl SseConnection {
         // Build request and apply authentication from Configuration
         let mut request = client.get(&url).header("Accept", "text/event-stream");
 
-        // Apply basic authentication if configured
-        if let Some((ref username, ref password)) = config.basic_auth {
-            request = request.basic_auth(username.clone(), password.clone());
         // Apply bearer token authentication if configured
-        } else if let Some(ref token) = config.bearer_access_token {
+        if let Some(ref token) = config.bearer_access_token {
             request = request.bearer_auth(token.clone());
+        // Apply basic authentication if configured
+        } else if let Some((ref username, ref password)) = config.basic_auth {
+            request = request.basic_auth(username.clone(), password.clone());
         // Apply API key authentication if configured
         } else if let Some(ref api_key) = config.api_key {
             // If ApiKey has an optional prefix, include it (e.g., "Token <key>")
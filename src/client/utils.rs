@@ -64,6 +64,42 @@ pub fn shell_command() -> Command {
     }
 }
 
+/// Creates a command that runs a command string with the given shell.
+///
+/// `shell` is a program name or path, optionally followed by arguments (for example,
+/// `"bash -l"` or `"/usr/bin/zsh"`). The flag that introduces the command string is chosen
+/// from the program name: `/C` for `cmd`, `-Command` for PowerShell, and `-c` otherwise.
+/// If `shell` is `None`, this is the same as [`shell_command`].
+pub fn shell_command_for(shell: Option<&str>) -> Result<Command, String> {
+    let Some(shell) = shell else {
+        return Ok(shell_command());
+    };
+    let words = shell_words(shell)?;
+    let mut cmd = Command::new(&words[0]);
+    cmd.args(&words[1..]);
+    Ok(cmd)
+}
+
+/// Splits a shell setting into the program, its arguments, and the flag that introduces the
+/// command string, as described in [`shell_command_for`].
+pub fn shell_words(shell: &str) -> Result<Vec<String>, String> {
+    let mut words = shlex::split(shell).ok_or_else(|| format!("invalid shell '{}'", shell))?;
+    let program = words
+        .first()
+        .ok_or_else(|| "shell must not be empty".to_string())?;
+    let program_name = Path::new(program)
+        .file_stem()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let command_flag = match program_name.as_str() {
+        "cmd" => "/C",
+        "powershell" | "pwsh" => "-Command",
+        _ => "-c",
+    };
+    words.push(command_flag.to_string());
+    Ok(words)
+}
+
 /// Execute an API call with automatic retries for network errors
 ///
 /// This function will immediately return non-network errors, but will retry
@@ -394,6 +430,42 @@ fn parse_dmesg_timestamp(line: &str) -> Option<DateTime<Local>> {
     Local.from_local_datetime(&naive).single()
 }
 
+/// Files larger than this are hashed by sampling instead of reading every byte.
+pub const CHECKSUM_SAMPLE_THRESHOLD_BYTES: u64 = 1024 * 1024 * 1024;
+const CHECKSUM_SAMPLE_BLOCK_BYTES: u64 = 1024 * 1024;
+const CHECKSUM_NUM_SAMPLE_BLOCKS: u64 = 64;
+
+/// Compute a SHA-256 content hash of a file.
+///
+/// Files up to [`CHECKSUM_SAMPLE_THRESHOLD_BYTES`] are hashed in full and the result is
+/// prefixed with `sha256:`. Larger files are hashed from their size plus evenly spaced
+/// 1 MiB blocks (including the first and last) and prefixed with `sha256-sampled:`, which
+/// keeps the cost bounded at the risk of missing edits that fall between sampled blocks.
+pub fn compute_file_checksum(path: &Path) -> std::io::Result<String> {
+    use sha2::{Digest, Sha256};
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = File::open(path)?;
+    let size = file.metadata()?.len();
+    let mut hasher = Sha256::new();
+
+    if size <= CHECKSUM_SAMPLE_THRESHOLD_BYTES {
+        std::io::copy(&mut file, &mut hasher)?;
+        return Ok(format!("sha256:{:x}", hasher.finalize()));
+    }
+
+    hasher.update(size.to_le_bytes());
+    let last_offset = size - CHECKSUM_SAMPLE_BLOCK_BYTES;
+    let mut buffer = vec![0u8; CHECKSUM_SAMPLE_BLOCK_BYTES as usize];
+    for i in 0..CHECKSUM_NUM_SAMPLE_BLOCKS {
+        let offset = last_offset * i / (CHECKSUM_NUM_SAMPLE_BLOCKS - 1);
+        file.seek(SeekFrom::Start(offset))?;
+        file.read_exact(&mut buffer)?;
+        hasher.update(&buffer);
+    }
+    Ok(format!("sha256-sampled:{:x}", hasher.finalize()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -443,4 +515,23 @@ mod tests {
         assert!(!filtered.contains("old message 1"));
         assert!(!filtered.contains("old message 2"));
     }
+
+    #[test]
+    fn test_compute_file_checksum() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.txt");
+        std::fs::write(&path, "hello").unwrap();
+        let checksum = compute_file_checksum(&path).unwrap();
+        assert_eq!(
+            checksum,
+            "sha256:2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+
+        // Same content, different mtime: same checksum
+        std::fs::write(&path, "hello").unwrap();
+        assert_eq!(compute_file_checksum(&path).unwrap(), checksum);
+
+        std::fs::write(&path, "hellO").unwrap();
+        assert_ne!(compute_file_checksum(&path).unwrap(), checksum);
+    }
 }
@@ -1,6 +1,9 @@
 use crate::client::apis::configuration::Configuration;
 use crate::client::apis::default_api;
+use crate::client::commands::slurm::AllocationDependency;
 use crate::client::errors::TorcError;
+use crate::client::hpc::HpcType;
+use crate::client::utils::compute_file_checksum;
 use crate::config::TorcConfig;
 use log::{self, debug, error, info, warn};
 
@@ -45,6 +48,19 @@ impl WorkflowManager {
         duration.as_secs_f64()
     }
 
+    /// Get the content hash of a file that uses checksum change detection.
+    /// Returns None if the file uses modification times instead.
+    /// Panics if the file cannot be read.
+    fn get_file_checksum(file: &FileModel, path: &Path) -> Option<String> {
+        if file.use_checksum != Some(true) {
+            return None;
+        }
+        match compute_file_checksum(path) {
+            Ok(checksum) => Some(checksum),
+            Err(err) => panic!("Failed to compute checksum for file {}: {}", file.path, err),
+        }
+    }
+
     /// Check if initialization is safe to run without executing.
     /// Returns information about missing input files and existing output files.
     pub fn check_initialization(&self) -> Result<InitializationCheck, TorcError> {
@@ -202,6 +218,9 @@ impl WorkflowManager {
             }
         };
 
+        // Submit the allocations that other allocations depend on first
+        let actions = crate::client::commands::slurm::order_by_allocation_dependencies(actions);
+
         // Filter for schedule_nodes actions
         for action in actions {
             let action_type = &action.action_type;
@@ -216,8 +235,8 @@ impl WorkflowManager {
                     .and_then(|v| v.as_str())
                     .unwrap_or("");
 
-                // Only claim the action if we can execute it (scheduler_type == "slurm")
-                if scheduler_type == "slurm" {
+                // Only claim the action if we can execute it (an HPC scheduler type)
+                if let Some(hpc_type) = HpcType::from_scheduler_type(scheduler_type) {
                     // Claim the action atomically (no compute_node_id since we're on login node)
                     let claimed = match crate::client::utils::claim_action(
                         &self.config,
@@ -240,8 +259,8 @@ impl WorkflowManager {
 
                     // Successfully claimed, now execute
                     info!(
-                        "Scheduling Slurm nodes for on_workflow_start action {}",
-                        action_id
+                        "Scheduling {} nodes for on_workflow_start action {}",
+                        hpc_type, action_id
                     );
 
                     let scheduler_id = action_config
@@ -256,13 +275,25 @@ impl WorkflowManager {
                         .get("start_one_worker_per_node")
                         .and_then(|v| v.as_bool())
                         .unwrap_or(false);
+                    let use_job_array = action_config
+                        .get("use_job_array")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(false);
                     let max_parallel_jobs = action_config
                         .get("max_parallel_jobs")
                         .and_then(|v| v.as_i64())
                         .map(|v| v as i32);
+                    let dependency = AllocationDependency::from_action_config(action_config)
+                        .map_err(|err| {
+                            TorcError::OperationNotAllowed(format!(
+                                "Invalid dependency in action {}: {}",
+                                action_id, err
+                            ))
+                        })?;
 
-                    match crate::client::commands::slurm::schedule_slurm_nodes(
+                    match crate::client::commands::slurm::schedule_hpc_nodes(
                         &self.config,
+                        hpc_type,
                         self.workflow_id,
                         scheduler_id,
                         num_allocations,
@@ -271,28 +302,30 @@ impl WorkflowManager {
                         self.torc_config.client.slurm.poll_interval,
                         max_parallel_jobs,
                         start_one_worker_per_node,
+                        use_job_array,
+                        dependency.as_ref(),
                         self.torc_config.client.slurm.keep_submission_scripts,
                     ) {
                         Ok(()) => {
                             info!(
-                                "Successfully scheduled {} Slurm allocation(s) for on_workflow_start",
-                                num_allocations
+                                "Successfully scheduled {} {} allocation(s) for on_workflow_start",
+                                num_allocations, hpc_type
                             );
                         }
                         Err(err) => {
                             error!(
-                                "Failed to schedule Slurm nodes for action {}: {}",
-                                action_id, err
+                                "Failed to schedule {} nodes for action {}: {}",
+                                hpc_type, action_id, err
                             );
                             return Err(TorcError::OperationNotAllowed(format!(
-                                "Failed to schedule Slurm nodes: {}",
-                                err
+                                "Failed to schedule {} nodes: {}",
+                                hpc_type, err
                             )));
                         }
                     }
                 } else {
                     debug!(
-                        "scheduler_type = {} is not 'slurm', skipping action {} in WorkflowManager (may be handled by job runner)",
+                        "scheduler_type = {} is not an HPC scheduler, skipping action {} in WorkflowManager (may be handled by job runner)",
                         scheduler_type, action_id
                     );
                 }
@@ -366,15 +399,20 @@ impl WorkflowManager {
                     match fs::metadata(file_path) {
                         Ok(metadata) => {
                             let mtime = Self::get_modified_file_time(&metadata);
+                            let checksum = Self::get_file_checksum(&file, file_path);
 
                             // Update the file record if the mtime has changed or is not set
-                            let needs_update = match file.st_mtime {
+                            let mut needs_update = match file.st_mtime {
                                 Some(current_mtime) => (current_mtime - mtime).abs() > 0.01, // Allow for filesystem timestamp precision differences (10ms)
                                 None => true, // Always update if no mtime is set
                             };
+                            if checksum.is_some() && checksum != file.content_hash {
+                                needs_update = true;
+                            }
 
                             if needs_update {
                                 file.st_mtime = Some(mtime);
+                                file.content_hash = checksum;
 
                                 match default_api::update_file(&self.config, file_id, file.clone())
                                 {
@@ -469,6 +507,7 @@ impl WorkflowManager {
                     if let Some(file_id) = file.id {
                         let mut updated_file = file.clone();
                         updated_file.st_mtime = None;
+                        updated_file.content_hash = None;
 
                         match default_api::update_file(&self.config, file_id, updated_file) {
                             Ok(_) => {
@@ -614,6 +653,8 @@ impl WorkflowManager {
 
                     let mut file_changed = false;
                     let mut change_reason = String::new();
+                    // Set when the record needs new stats but downstream jobs are unaffected
+                    let mut record_changed = false;
 
                     let file_exists = file_path.exists();
                     let db_has_mtime = file.st_mtime.is_some();
@@ -625,14 +666,40 @@ impl WorkflowManager {
                                 Ok(metadata) => {
                                     let mtime = Self::get_modified_file_time(&metadata);
                                     let current_mtime = file.st_mtime.unwrap();
-
-                                    if (current_mtime - mtime).abs() > 0.01 {
-                                        file_changed = true;
-                                        change_reason = format!(
-                                            "modified time changed from {} to {}",
-                                            current_mtime, mtime
-                                        );
-                                        file.st_mtime = Some(mtime);
+                                    let mtime_changed = (current_mtime - mtime).abs() > 0.01;
+                                    let checksum = Self::get_file_checksum(&file, file_path);
+
+                                    match (&checksum, &file.content_hash) {
+                                        (Some(checksum), Some(current_checksum)) => {
+                                            // The content hash decides; a new mtime with the
+                                            // same content (e.g., cp -p, rsync) is only recorded.
+                                            if checksum != current_checksum {
+                                                file_changed = true;
+                                                change_reason = format!(
+                                                    "content hash changed from {} to {}",
+                                                    current_checksum, checksum
+                                                );
+                                            } else if mtime_changed {
+                                                record_changed = true;
+                                            }
+                                        }
+                                        _ => {
+                                            if mtime_changed {
+                                                file_changed = true;
+                                                change_reason = format!(
+                                                    "modified time changed from {} to {}",
+                                                    current_mtime, mtime
+                                                );
+                                            } else if checksum.is_some() {
+                                                // Checksums were enabled after the last
+                                                // initialization; record a baseline.
+                                                record_changed = true;
+                                            }
+                                        }
+                                    }
+                                    file.st_mtime = Some(mtime);
+                                    if checksum.is_some() {
+                                        file.content_hash = checksum;
                                     }
                                 }
                                 Err(err) => {
@@ -651,6 +718,7 @@ impl WorkflowManager {
                                     file_changed = true;
                                     change_reason = format!("file appeared with mtime {}", mtime);
                                     file.st_mtime = Some(mtime);
+                                    file.content_hash = Self::get_file_checksum(&file, file_path);
                                 }
                                 Err(err) => {
                                     panic!(
@@ -666,6 +734,7 @@ impl WorkflowManager {
                             change_reason =
                                 format!("file disappeared (was mtime {})", file.st_mtime.unwrap());
                             file.st_mtime = None;
+                            file.content_hash = None;
                         }
                         (false, false) => {
                             // File doesn't exist, database has no mtime - no change
@@ -708,6 +777,21 @@ impl WorkflowManager {
                                 );
                             }
                         }
+                    } else if record_changed && !dry_run {
+                        match default_api::update_file(&self.config, file_id, file.clone()) {
+                            Ok(_) => {
+                                debug!(
+                                    "Updated stats for file {} (id: {}) without content change",
+                                    file.name, file_id
+                                );
+                            }
+                            Err(err) => {
+                                panic!(
+                                    "Failed to update file {} (id: {}): {}",
+                                    file.name, file_id, err
+                                );
+                            }
+                        }
                     }
                 }
                 Err(err) => {
@@ -1,4 +1,6 @@
 use crate::client::apis::{configuration::Configuration, default_api};
+use crate::client::hpc::HpcType;
+use crate::client::job_condition::JobCondition;
 use crate::client::parameter_expansion::{
     ParameterValue, cartesian_product, parse_parameter_value, substitute_parameters, zip_parameters,
 };
@@ -65,6 +67,10 @@ pub struct FileSpec {
     pub name: String,
     /// Path to the file
     pub path: String,
+    /// Detect changes by content hash (SHA-256) instead of modification time.
+    /// Defaults to the workflow's use_file_checksums setting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_checksum: Option<bool>,
     /// Optional parameters for generating multiple files
     /// Supports range notation (e.g., "1:100" or "1:100:5") and lists (e.g., "[1,5,10]")
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -86,6 +92,7 @@ impl FileSpec {
         FileSpec {
             name,
             path,
+            use_checksum: None,
             parameters: None,
             parameter_mode: None,
             use_parameters: None,
@@ -167,7 +174,7 @@ pub struct WorkflowActionSpec {
     /// For schedule_nodes action: scheduler name (will be translated to scheduler_id)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scheduler: Option<String>,
-    /// For schedule_nodes action: scheduler type (e.g., "slurm", "local")
+    /// For schedule_nodes action: scheduler type (e.g., "slurm", "pbs", "local")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scheduler_type: Option<String>,
     /// For schedule_nodes action: number of node allocations to request
@@ -176,9 +183,20 @@ pub struct WorkflowActionSpec {
     /// For schedule_nodes action: whether to start one worker per node
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start_one_worker_per_node: Option<bool>,
+    /// For schedule_nodes action: submit the allocations as one Slurm job array
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_job_array: Option<bool>,
     /// For schedule_nodes action: maximum parallel jobs
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_parallel_jobs: Option<i32>,
+    /// For schedule_nodes action: names of schedulers whose allocations must end before the
+    /// allocations of this action start (Slurm only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depends_on_schedulers: Option<Vec<String>>,
+    /// For schedule_nodes action: Slurm dependency type for depends_on_schedulers,
+    /// "afterok" (default) or "afterany"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependency_type: Option<String>,
     /// Whether the action persists and can be claimed by multiple workers (default: false)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub persistent: Option<bool>,
@@ -219,19 +237,48 @@ impl ResourceRequirementsSpec {
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct FailureHandlerRuleSpec {
-    /// Exit codes that trigger this rule. Can be omitted if match_all_exit_codes is true.
+    /// Exit codes that trigger this rule. Can be omitted if match_all_exit_codes is true or if
+    /// the rule has stdout or stderr patterns.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub exit_codes: Vec<i32>,
     /// If true, this rule matches any non-zero exit code.
     /// Use this for simple retry-on-any-failure behavior.
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub match_all_exit_codes: bool,
+    /// Regex patterns matched against the job's stdout file. If any stdout or stderr patterns
+    /// are set, the rule only matches if one of them matches.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stdout_regexes: Vec<String>,
+    /// Regex patterns matched against the job's stderr file
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stderr_regexes: Vec<String>,
     /// Optional recovery script to run before retrying
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recovery_script: Option<String>,
     /// Maximum number of retry attempts (defaults to 3)
     #[serde(default = "FailureHandlerRuleSpec::default_max_retries")]
     pub max_retries: i32,
+    /// Multiply the job's memory requirement by this factor before retrying
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_multiplier: Option<f64>,
+    /// Multiply the job's runtime requirement by this factor before retrying
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime_multiplier: Option<f64>,
+    /// Name of the scheduler to move the job to before retrying
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduler: Option<String>,
+    /// Seconds to wait before the retried job can be claimed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_delay_seconds: Option<f64>,
+    /// Multiply the retry delay by this factor after each failed attempt (exponential backoff)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_backoff_multiplier: Option<f64>,
+    /// Upper bound for the retry delay after backoff
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_retry_delay_seconds: Option<f64>,
+    /// If true, workers on the host where the attempt failed do not claim the retry
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub avoid_failed_node: bool,
 }
 
 impl FailureHandlerRuleSpec {
@@ -437,6 +484,32 @@ pub struct JobSpec {
     /// Name of the scheduler to use for this job
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scheduler: Option<String>,
+    /// Claim priority. Jobs with higher values are claimed first when the workflow uses the
+    /// `priority` jobs_sort_method (default: 0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// Environment variables to set for the job's process. Values support parameter substitution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env: Option<HashMap<String, String>>,
+    /// Directory in which to run the command (default: the job runner's working directory)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<String>,
+    /// Program used to run the command, such as bash, sh, or zsh (default: bash, or cmd on Windows)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shell: Option<String>,
+    /// Container in which to run the command. The image, bind mounts, and args support
+    /// parameter substitution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<models::JobContainerModel>,
+    /// Condition that must hold for the job to run, such as `exit_code("prep") == 0`. If it is
+    /// false, the job is skipped. Jobs named in `exit_code()` and `status()` are added to
+    /// `depends_on` and user data named in `user_data()` is added to `input_user_data`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_if: Option<String>,
+    /// Name of a workflow concurrency pool. The server limits how many jobs in the pool can be
+    /// pending or running at the same time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concurrency_pool: Option<String>,
     /// Optional parameters for generating multiple jobs
     /// Supports range notation (e.g., "1:100" or "1:100:5") and lists (e.g., "[1,5,10]")
     /// Multiple parameters create a Cartesian product of jobs by default
@@ -475,6 +548,13 @@ impl JobSpec {
             output_user_data: None,
             output_user_data_regexes: None,
             scheduler: None,
+            priority: None,
+            env: None,
+            working_dir: None,
+            shell: None,
+            container: None,
+            run_if: None,
+            concurrency_pool: None,
             parameters: None,
             parameter_mode: None,
             use_parameters: None,
@@ -526,6 +606,43 @@ impl JobSpec {
                 new_spec.scheduler = Some(substitute_parameters(sched_name, &combo));
             }
 
+            if let Some(ref env) = self.env {
+                new_spec.env = Some(
+                    env.iter()
+                        .map(|(k, v)| (k.clone(), substitute_parameters(v, &combo)))
+                        .collect(),
+                );
+            }
+
+            if let Some(ref dir) = self.working_dir {
+                new_spec.working_dir = Some(substitute_parameters(dir, &combo));
+            }
+
+            if let Some(ref container) = self.container {
+                let substitute_all = |values: &Option<Vec<String>>| {
+                    values.as_ref().map(|values| {
+                        values
+                            .iter()
+                            .map(|v| substitute_parameters(v, &combo))
+                            .collect()
+                    })
+                };
+                new_spec.container = Some(models::JobContainerModel {
+                    image: substitute_parameters(&container.image, &combo),
+                    bind_mounts: substitute_all(&container.bind_mounts),
+                    args: substitute_all(&container.args),
+                    ..container.clone()
+                });
+            }
+
+            if let Some(ref run_if) = self.run_if {
+                new_spec.run_if = Some(substitute_parameters(run_if, &combo));
+            }
+
+            if let Some(ref pool) = self.concurrency_pool {
+                new_spec.concurrency_pool = Some(substitute_parameters(pool, &combo));
+            }
+
             // Substitute parameters in name vectors
             if let Some(ref names) = self.depends_on {
                 new_spec.depends_on = Some(
@@ -625,6 +742,58 @@ impl JobSpec {
     }
 }
 
+/// Reference to another spec file whose definitions are merged into a workflow
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IncludeSpec {
+    /// Path to the spec file, relative to the directory of the file that includes it
+    pub path: String,
+    /// String prepended to the names of all jobs, files, user data, resource requirements,
+    /// failure handlers, and schedulers defined in the included file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+}
+
+/// Limit on the number of jobs that can be pending or running at the same time across all
+/// compute nodes, for jobs that reference the pool by name
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConcurrencyPoolSpec {
+    /// Name of the pool
+    pub name: String,
+    /// Maximum number of jobs in the pool that can be pending or running at the same time
+    pub max_concurrent_jobs: i64,
+}
+
+/// Names defined in a spec, used to decide which references are local when prefixing an
+/// included file. Parameterized names such as `job_{i}` match any of their expansions.
+struct LocalNames(Vec<Regex>);
+
+impl LocalNames {
+    fn new<'a>(names: impl Iterator<Item = &'a str>) -> Result<Self, regex::Error> {
+        let placeholder = Regex::new(r"\{[^}]*\}").expect("valid regex");
+        names
+            .map(|name| {
+                let mut pattern = String::from("^");
+                let mut last = 0;
+                for m in placeholder.find_iter(name) {
+                    pattern.push_str(&regex::escape(&name[last..m.start()]));
+                    pattern.push_str(".+");
+                    last = m.end();
+                }
+                pattern.push_str(&regex::escape(&name[last..]));
+                pattern.push('$');
+                Regex::new(&pattern)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(LocalNames)
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.0.iter().any(|re| re.is_match(name))
+    }
+}
+
 /// Specification for a complete workflow
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -637,6 +806,11 @@ pub struct WorkflowSpec {
     /// Description of the workflow (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Other spec files to merge into this workflow. Their jobs, files, user data, resource
+    /// requirements, failure handlers, schedulers, actions, and shared parameters are added
+    /// before this file's own definitions. Resolved (and cleared) by from_spec_file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<Vec<IncludeSpec>>,
     /// Shared parameters that can be used by jobs and files
     /// Jobs/files can reference these by setting use_parameters to parameter names
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -685,6 +859,39 @@ pub struct WorkflowSpec {
     /// Use PendingFailed status for failed jobs (enables AI-assisted recovery)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_pending_failed: Option<bool>,
+    /// Terminate jobs that exceed the runtime in their resource requirements
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforce_runtime_limits: Option<bool>,
+    /// Seconds between SIGTERM and SIGKILL when a job exceeds its runtime (default: 60)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime_limit_grace_seconds: Option<i64>,
+    /// Raise the priority of jobs on the critical path to the highest priority on that path
+    /// (at least 1), so that long dependency chains start first
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inherit_critical_path_priority: Option<bool>,
+    /// Detect file changes by content hash instead of modification time for all files that
+    /// do not set use_checksum themselves
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_file_checksums: Option<bool>,
+    /// Seconds without a heartbeat after which the server considers a compute node dead
+    /// (default: 300; 0 disables dead worker detection)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub worker_heartbeat_timeout_seconds: Option<i64>,
+    /// What to do with the running jobs of a dead compute node: fail (default) or retry
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dead_worker_policy: Option<models::DeadWorkerPolicy>,
+    /// Maximum number of jobs that can be pending or running at the same time across all
+    /// compute nodes (default: unlimited)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_jobs: Option<i64>,
+    /// Named limits on the number of concurrent jobs, such as jobs that use a license server.
+    /// Jobs join a pool by setting concurrency_pool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concurrency_pools: Option<Vec<ConcurrencyPoolSpec>>,
+    /// Maximum allocation units (AU) that the workflow's HPC allocations may cost
+    /// (default: unlimited)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub au_budget: Option<f64>,
 }
 
 impl WorkflowSpec {
@@ -700,6 +907,7 @@ impl WorkflowSpec {
             name,
             user: Some(user),
             description,
+            include: None,
             parameters: None,
             compute_node_expiration_buffer_seconds: None,
             compute_node_wait_for_new_jobs_seconds: None,
@@ -716,6 +924,15 @@ impl WorkflowSpec {
             resource_monitor: None,
             actions: None,
             use_pending_failed: None,
+            enforce_runtime_limits: None,
+            runtime_limit_grace_seconds: None,
+            inherit_critical_path_priority: None,
+            use_file_checksums: None,
+            worker_heartbeat_timeout_seconds: None,
+            dead_worker_policy: None,
+            max_concurrent_jobs: None,
+            concurrency_pools: None,
+            au_budget: None,
         }
     }
 
@@ -725,6 +942,30 @@ impl WorkflowSpec {
         Ok(serde_json::from_value(value)?)
     }
 
+    /// Raise the priority of every job on the critical path to the highest priority found on
+    /// that path, or to 1 if none of them set a higher value. Jobs off the path keep their own
+    /// priority. Operates on the unexpanded spec so that parameterized instances inherit it.
+    pub fn apply_critical_path_priority(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.jobs.is_empty() {
+            return Ok(());
+        }
+        let mut graph = crate::client::workflow_graph::WorkflowGraph::from_spec(self)?;
+        let (path, _) = graph.critical_path()?;
+        let path: HashSet<String> = path.into_iter().collect();
+
+        let path_priority = self
+            .jobs
+            .iter()
+            .filter(|job| path.contains(&job.name))
+            .filter_map(|job| job.priority)
+            .fold(1, i64::max);
+
+        for job in self.jobs.iter_mut().filter(|job| path.contains(&job.name)) {
+            job.priority = Some(job.priority.unwrap_or(0).max(path_priority));
+        }
+        Ok(())
+    }
+
     /// Expand all parameterized jobs and files in this workflow spec
     /// This modifies the spec in-place, replacing parameterized specs with their expanded versions
     ///
@@ -837,12 +1078,15 @@ impl WorkflowSpec {
                         .as_ref()
                         .ok_or("schedule_nodes action requires scheduler")?;
 
-                    // If scheduler_type is slurm, verify that a slurm_scheduler with that name exists
-                    if scheduler_type == "slurm" {
-                        let slurm_schedulers = self
-                            .slurm_schedulers
-                            .as_ref()
-                            .ok_or("schedule_nodes action with scheduler_type=slurm requires slurm_schedulers to be defined")?;
+                    // HPC scheduler types (slurm, pbs) take their allocation settings from
+                    // slurm_schedulers, so verify that a scheduler with that name exists
+                    if HpcType::from_scheduler_type(scheduler_type).is_some() {
+                        let slurm_schedulers = self.slurm_schedulers.as_ref().ok_or_else(|| {
+                            format!(
+                                "schedule_nodes action with scheduler_type={} requires slurm_schedulers to be defined",
+                                scheduler_type
+                            )
+                        })?;
 
                         let scheduler_exists = slurm_schedulers
                             .iter()
@@ -862,6 +1106,184 @@ impl WorkflowSpec {
         Ok(())
     }
 
+    /// Validate the process settings of jobs: environment variable names, working_dir, shell,
+    /// and container.
+    pub fn validate_job_environments(&self) -> Result<(), Box<dyn std::error::Error>> {
+        for job in &self.jobs {
+            if let Some(ref env) = job.env {
+                for key in env.keys() {
+                    if key.is_empty() || key.contains('=') || key.contains('\0') {
+                        return Err(format!(
+                            "Job '{}' has an invalid environment variable name '{}'",
+                            job.name, key
+                        )
+                        .into());
+                    }
+                }
+            }
+            if job.working_dir.as_deref() == Some("") {
+                return Err(format!("Job '{}' has an empty working_dir", job.name).into());
+            }
+            if job
+                .shell
+                .as_deref()
+                .is_some_and(|shell| shell.trim().is_empty())
+            {
+                return Err(format!("Job '{}' has an empty shell", job.name).into());
+            }
+            if let Some(ref container) = job.container {
+                if container.image.trim().is_empty() {
+                    return Err(
+                        format!("Job '{}' has a container with an empty image", job.name).into(),
+                    );
+                }
+                let bind_mounts = container.bind_mounts.iter().flatten();
+                if let Some(mount) = bind_mounts.into_iter().find(|m| m.trim().is_empty()) {
+                    return Err(format!(
+                        "Job '{}' has an invalid container bind mount '{}'",
+                        job.name, mount
+                    )
+                    .into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate the `run_if` conditions of jobs. Jobs referenced by a condition must exist.
+    pub fn validate_job_conditions(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let job_names: HashSet<&str> = self.jobs.iter().map(|j| j.name.as_str()).collect();
+        for job in &self.jobs {
+            let Some(ref run_if) = job.run_if else {
+                continue;
+            };
+            let condition = JobCondition::parse(run_if).map_err(|e| {
+                format!("Job '{}' has an invalid run_if condition: {}", job.name, e)
+            })?;
+            for name in condition.referenced_jobs() {
+                if name == job.name {
+                    return Err(
+                        format!("Job '{}' has a run_if condition on itself", job.name).into(),
+                    );
+                }
+                if !job_names.contains(name.as_str()) {
+                    return Err(format!(
+                        "Job '{}' has a run_if condition on job '{}', which does not exist",
+                        job.name, name
+                    )
+                    .into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate concurrency limits. Pool names must be unique, limits must be at least 1, and
+    /// jobs must reference pools that exist.
+    pub fn validate_concurrency_pools(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(max) = self.max_concurrent_jobs
+            && max < 1
+        {
+            return Err("max_concurrent_jobs must be >= 1".into());
+        }
+        let mut pool_names = HashSet::new();
+        for pool in self.concurrency_pools.iter().flatten() {
+            if pool.name.trim().is_empty() {
+                return Err("Concurrency pool names must not be empty".into());
+            }
+            if !pool_names.insert(pool.name.as_str()) {
+                return Err(format!("Duplicate concurrency pool name '{}'", pool.name).into());
+            }
+            if pool.max_concurrent_jobs < 1 {
+                return Err(format!(
+                    "Concurrency pool '{}' must have max_concurrent_jobs >= 1",
+                    pool.name
+                )
+                .into());
+            }
+        }
+        for job in &self.jobs {
+            if let Some(ref pool) = job.concurrency_pool
+                && !pool_names.contains(pool.as_str())
+            {
+                return Err(format!(
+                    "Job '{}' references concurrency pool '{}', which does not exist",
+                    job.name, pool
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate failure handler rules: patterns must be valid regexes, multipliers must be
+    /// positive, and schedulers must exist.
+    pub fn validate_failure_handlers(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let scheduler_names: HashSet<&str> = self
+            .slurm_schedulers
+            .iter()
+            .flatten()
+            .filter_map(|s| s.name.as_deref())
+            .collect();
+        for handler in self.failure_handlers.iter().flatten() {
+            for rule in &handler.rules {
+                for pattern in rule.stdout_regexes.iter().chain(&rule.stderr_regexes) {
+                    Regex::new(pattern).map_err(|e| {
+                        format!(
+                            "Failure handler '{}' has an invalid pattern '{}': {}",
+                            handler.name, pattern, e
+                        )
+                    })?;
+                }
+                for (field, value) in [
+                    ("memory_multiplier", rule.memory_multiplier),
+                    ("runtime_multiplier", rule.runtime_multiplier),
+                    ("retry_backoff_multiplier", rule.retry_backoff_multiplier),
+                ] {
+                    if value.is_some_and(|v| !(v.is_finite() && v > 0.0)) {
+                        return Err(format!(
+                            "Failure handler '{}' has an invalid {}: it must be a positive number",
+                            handler.name, field
+                        )
+                        .into());
+                    }
+                }
+                for (field, value) in [
+                    ("retry_delay_seconds", rule.retry_delay_seconds),
+                    ("max_retry_delay_seconds", rule.max_retry_delay_seconds),
+                ] {
+                    if value.is_some_and(|v| !(v.is_finite() && v >= 0.0)) {
+                        return Err(format!(
+                            "Failure handler '{}' has an invalid {}: it must be a non-negative number",
+                            handler.name, field
+                        )
+                        .into());
+                    }
+                }
+                if rule.retry_delay_seconds.is_none()
+                    && (rule.retry_backoff_multiplier.is_some()
+                        || rule.max_retry_delay_seconds.is_some())
+                {
+                    return Err(format!(
+                        "Failure handler '{}' sets retry backoff without retry_delay_seconds",
+                        handler.name
+                    )
+                    .into());
+                }
+                if let Some(ref scheduler) = rule.scheduler
+                    && !scheduler_names.contains(scheduler.as_str())
+                {
+                    return Err(format!(
+                        "Failure handler '{}' references scheduler '{}', which does not exist",
+                        handler.name, scheduler
+                    )
+                    .into());
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Validate that multi-node schedulers are properly utilized.
     ///
     /// This validation ensures that when a scheduler allocates multiple nodes (nodes > 1)
@@ -918,9 +1340,9 @@ impl WorkflowSpec {
                 None => continue, // Validation of required fields is done elsewhere
             };
 
-            // Only validate slurm schedulers
+            // Only validate HPC schedulers
             let scheduler_type = action.scheduler_type.as_deref().unwrap_or("");
-            if scheduler_type != "slurm" {
+            if HpcType::from_scheduler_type(scheduler_type).is_none() {
                 continue;
             }
 
@@ -1028,31 +1450,36 @@ impl WorkflowSpec {
         let mut warnings = Vec::new();
 
         // Step 1: Try to parse the spec file
-        let mut spec = match Self::from_spec_file(&path) {
-            Ok(spec) => spec,
-            Err(e) => {
-                return ValidationResult {
-                    valid: false,
-                    errors: vec![format!("Failed to parse specification file: {}", e)],
-                    warnings: vec![],
-                    summary: ValidationSummary {
-                        workflow_name: String::new(),
-                        workflow_description: None,
-                        job_count: 0,
-                        job_count_before_expansion: 0,
-                        file_count: 0,
-                        file_count_before_expansion: 0,
-                        user_data_count: 0,
-                        resource_requirements_count: 0,
-                        slurm_scheduler_count: 0,
-                        action_count: 0,
-                        has_schedule_nodes_action: false,
-                        job_names: vec![],
-                        scheduler_names: vec![],
-                    },
-                };
-            }
+        let failed = |error: String| ValidationResult {
+            valid: false,
+            errors: vec![error],
+            warnings: vec![],
+            summary: ValidationSummary {
+                workflow_name: String::new(),
+                workflow_description: None,
+                job_count: 0,
+                job_count_before_expansion: 0,
+                file_count: 0,
+                file_count_before_expansion: 0,
+                user_data_count: 0,
+                resource_requirements_count: 0,
+                slurm_scheduler_count: 0,
+                action_count: 0,
+                has_schedule_nodes_action: false,
+                job_names: vec![],
+                scheduler_names: vec![],
+            },
         };
+        let mut spec =
+            match Self::spec_file_to_json_value(path.as_ref()).and_then(Self::from_json_value) {
+                Ok(spec) => spec,
+                Err(e) => return failed(format!("Failed to parse specification file: {}", e)),
+            };
+
+        // Step 1.5: Merge included spec files
+        if let Err(e) = spec.resolve_includes(path.as_ref()) {
+            return failed(format!("Failed to include spec file: {}", e));
+        }
 
         // Capture counts before expansion
         let job_count_before_expansion = spec.jobs.len();
@@ -1067,6 +1494,18 @@ impl WorkflowSpec {
         if let Err(e) = spec.validate_actions() {
             errors.push(format!("Action validation failed: {}", e));
         }
+        if let Err(e) = spec.validate_job_environments() {
+            errors.push(format!("Job validation failed: {}", e));
+        }
+        if let Err(e) = spec.validate_job_conditions() {
+            errors.push(format!("Job validation failed: {}", e));
+        }
+        if let Err(e) = spec.validate_concurrency_pools() {
+            errors.push(format!("Concurrency pool validation failed: {}", e));
+        }
+        if let Err(e) = spec.validate_failure_handlers() {
+            errors.push(format!("Failure handler validation failed: {}", e));
+        }
 
         // Step 4: Validate scheduler node requirements
         // This is an error by default (same as create_workflow_from_spec with skip_checks=false)
@@ -1397,7 +1836,9 @@ impl WorkflowSpec {
                     && let Some(ref sched_name) = action.scheduler
                 {
                     let sched_type = action.scheduler_type.as_deref().unwrap_or("");
-                    if sched_type == "slurm" && !scheduler_names_set.contains(sched_name) {
+                    if HpcType::from_scheduler_type(sched_type).is_some()
+                        && !scheduler_names_set.contains(sched_name)
+                    {
                         errors.push(format!(
                             "{} references non-existent slurm scheduler '{}'",
                             action_desc, sched_name
@@ -1545,11 +1986,21 @@ impl WorkflowSpec {
             });
         }
 
+        // Step 1.2: Propagate priority along the critical path (before expansion so that
+        // all instances of a parameterized job inherit it)
+        if spec.inherit_critical_path_priority == Some(true) {
+            spec.apply_critical_path_priority()?;
+        }
+
         // Step 1.25: Expand parameterized jobs and files
         spec.expand_parameters()?;
 
-        // Step 1.4: Validate workflow actions
+        // Step 1.4: Validate workflow actions and job process settings
         spec.validate_actions()?;
+        spec.validate_job_environments()?;
+        spec.validate_job_conditions()?;
+        spec.validate_concurrency_pools()?;
+        spec.validate_failure_handlers()?;
 
         // Step 1.45: Validate scheduler node requirements
         if !skip_checks {
@@ -1698,6 +2149,47 @@ impl WorkflowSpec {
             workflow_model.use_pending_failed = Some(value);
         }
 
+        // Set runtime limit enforcement if present
+        if let Some(value) = spec.enforce_runtime_limits {
+            workflow_model.enforce_runtime_limits = Some(value);
+        }
+        if let Some(value) = spec.runtime_limit_grace_seconds {
+            if value < 0 {
+                return Err("runtime_limit_grace_seconds must be >= 0".into());
+            }
+            workflow_model.runtime_limit_grace_seconds = Some(value);
+        }
+
+        // Set dead worker detection if present
+        if let Some(value) = spec.worker_heartbeat_timeout_seconds {
+            if value < 0 {
+                return Err("worker_heartbeat_timeout_seconds must be >= 0".into());
+            }
+            workflow_model.worker_heartbeat_timeout_seconds = Some(value);
+        }
+        if let Some(value) = spec.dead_worker_policy {
+            workflow_model.dead_worker_policy = Some(value);
+        }
+
+        // Set concurrency limits if present
+        workflow_model.max_concurrent_jobs = spec.max_concurrent_jobs;
+        if let Some(ref pools) = spec.concurrency_pools {
+            workflow_model.concurrency_pools = Some(
+                pools
+                    .iter()
+                    .map(|pool| (pool.name.clone(), pool.max_concurrent_jobs))
+                    .collect(),
+            );
+        }
+
+        // Set the allocation-unit budget if present
+        if let Some(value) = spec.au_budget {
+            if value <= 0.0 {
+                return Err("au_budget must be > 0".into());
+            }
+            workflow_model.au_budget = Some(value);
+        }
+
         let created_workflow = default_api::create_workflow(config, workflow_model)
             .map_err(|e| format!("Failed to create workflow: {:?}", e))?;
 
@@ -1727,6 +2219,13 @@ impl WorkflowSpec {
                     name: file_spec.name.clone(),
                     path: file_spec.path.clone(),
                     st_mtime: None, // Not included in specification
+                    use_checksum: Some(
+                        file_spec
+                            .use_checksum
+                            .or(spec.use_file_checksums)
+                            .unwrap_or(false),
+                    ),
+                    content_hash: None,
                 };
 
                 let created_file = default_api::create_file(config, file_model)
@@ -1997,7 +2496,8 @@ impl WorkflowSpec {
                             .ok_or("schedule_nodes action requires 'scheduler' field")?;
 
                         // Translate scheduler to scheduler_id
-                        let scheduler_id = if scheduler_type == "slurm" {
+                        let scheduler_id = if HpcType::from_scheduler_type(scheduler_type).is_some()
+                        {
                             slurm_scheduler_to_id
                                 .get(scheduler)
                                 .ok_or(format!("Slurm scheduler '{}' not found", scheduler))?
@@ -2017,6 +2517,49 @@ impl WorkflowSpec {
                         if let Some(max_parallel_jobs) = action_spec.max_parallel_jobs {
                             config["max_parallel_jobs"] = serde_json::json!(max_parallel_jobs);
                         }
+                        if action_spec.use_job_array == Some(true) {
+                            if HpcType::from_scheduler_type(scheduler_type) != Some(HpcType::Slurm)
+                            {
+                                return Err(format!(
+                                    "use_job_array is only supported for Slurm schedulers, not '{}'",
+                                    scheduler_type
+                                )
+                                .into());
+                            }
+                            config["use_job_array"] = serde_json::json!(true);
+                        }
+                        if let Some(ref depends_on) = action_spec.depends_on_schedulers
+                            && !depends_on.is_empty()
+                        {
+                            if HpcType::from_scheduler_type(scheduler_type) != Some(HpcType::Slurm)
+                            {
+                                return Err(format!(
+                                    "depends_on_schedulers is only supported for Slurm schedulers, not '{}'",
+                                    scheduler_type
+                                )
+                                .into());
+                            }
+                            let scheduler_ids = depends_on
+                                .iter()
+                                .map(|name| {
+                                    slurm_scheduler_to_id.get(name).copied().ok_or(format!(
+                                        "Slurm scheduler '{}' in depends_on_schedulers not found",
+                                        name
+                                    ))
+                                })
+                                .collect::<Result<Vec<i64>, String>>()?;
+                            config["depends_on_scheduler_ids"] = serde_json::json!(scheduler_ids);
+                        }
+                        if let Some(ref dependency_type) = action_spec.dependency_type {
+                            if !matches!(dependency_type.as_str(), "afterok" | "afterany") {
+                                return Err(format!(
+                                    "Invalid dependency_type '{}'. Expected 'afterok' or 'afterany'",
+                                    dependency_type
+                                )
+                                .into());
+                            }
+                            config["dependency_type"] = serde_json::json!(dependency_type);
+                        }
                         config
                     }
                     _ => {
@@ -2248,6 +2791,15 @@ impl WorkflowSpec {
                 if job_spec.supports_termination.is_some() {
                     job_model.supports_termination = job_spec.supports_termination;
                 }
+                if job_spec.priority.is_some() {
+                    job_model.priority = job_spec.priority;
+                }
+                job_model.env = job_spec.env.clone();
+                job_model.working_dir = job_spec.working_dir.clone();
+                job_model.shell = job_spec.shell.clone();
+                job_model.container = job_spec.container.clone();
+                job_model.run_if = job_spec.run_if.clone();
+                job_model.concurrency_pool = job_spec.concurrency_pool.clone();
 
                 // Map file names and regexes to IDs
                 let input_file_ids = Self::resolve_names_and_regexes(
@@ -2444,6 +2996,87 @@ impl WorkflowSpec {
         }
     }
 
+    /// Convert a KDL env block to a JSON object of environment variables
+    #[cfg(feature = "client")]
+    fn kdl_env_to_json(
+        node: &KdlNode,
+    ) -> Result<Option<serde_json::Value>, Box<dyn std::error::Error>> {
+        let Some(children) = node.children() else {
+            return Ok(None);
+        };
+
+        let mut env = serde_json::Map::new();
+        for child in children.nodes() {
+            let var_name = child.name().value().to_string();
+            let var_value = child
+                .entries()
+                .first()
+                .and_then(|e| e.value().as_string())
+                .ok_or_else(|| {
+                    format!(
+                        "Environment variable '{}' must have a string value",
+                        var_name
+                    )
+                })?
+                .to_string();
+            env.insert(var_name, serde_json::Value::String(var_value));
+        }
+
+        if env.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(serde_json::Value::Object(env)))
+        }
+    }
+
+    /// Convert a KDL container node to a JSON object
+    #[cfg(feature = "client")]
+    fn kdl_container_to_json(
+        node: &KdlNode,
+    ) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        let mut container = serde_json::Map::new();
+        for child in node.children().iter().flat_map(|c| c.nodes()) {
+            let key = child.name().value();
+            match key {
+                "image" | "runtime" => {
+                    let value = child
+                        .entries()
+                        .first()
+                        .and_then(|e| e.value().as_string())
+                        .ok_or_else(|| format!("container {} must be a string", key))?;
+                    container.insert(
+                        key.to_string(),
+                        serde_json::Value::String(value.to_string()),
+                    );
+                }
+                "bind_mounts" | "args" => {
+                    let values: Vec<serde_json::Value> = child
+                        .entries()
+                        .iter()
+                        .filter_map(|e| {
+                            e.value()
+                                .as_string()
+                                .map(|s| serde_json::Value::String(s.to_string()))
+                        })
+                        .collect();
+                    container.insert(key.to_string(), serde_json::Value::Array(values));
+                }
+                "gpus" => {
+                    let value = child
+                        .entries()
+                        .first()
+                        .and_then(|e| e.value().as_bool())
+                        .ok_or("container gpus must be a boolean")?;
+                    container.insert(key.to_string(), serde_json::Value::Bool(value));
+                }
+                _ => {
+                    return Err(format!("Unknown container property '{}'", key).into());
+                }
+            }
+        }
+        Ok(serde_json::Value::Object(container))
+    }
+
     /// Convert a KDL job node to a JSON object
     #[cfg(feature = "client")]
     fn kdl_job_to_json(node: &KdlNode) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
@@ -2565,29 +3198,83 @@ impl WorkflowSpec {
                             );
                         }
                     }
-                    "parameters" => {
-                        if let Some(params) = Self::kdl_parameters_to_json(child)? {
-                            obj.insert("parameters".to_string(), params);
+                    "priority" => {
+                        if let Some(v) =
+                            child.entries().first().and_then(|e| e.value().as_integer())
+                        {
+                            obj.insert(
+                                "priority".to_string(),
+                                serde_json::Value::Number(serde_json::Number::from(v as i64)),
+                            );
                         }
                     }
-                    "parameter_mode" => {
+                    "env" => {
+                        if let Some(env) = Self::kdl_env_to_json(child)? {
+                            obj.insert("env".to_string(), env);
+                        }
+                    }
+                    "working_dir" => {
                         if let Some(v) = child.entries().first().and_then(|e| e.value().as_string())
                         {
                             obj.insert(
-                                "parameter_mode".to_string(),
+                                "working_dir".to_string(),
                                 serde_json::Value::String(v.to_string()),
                             );
                         }
                     }
-                    "use_parameters" => {
-                        let param_names: Vec<serde_json::Value> = child
-                            .entries()
-                            .iter()
-                            .filter_map(|e| {
-                                e.value()
-                                    .as_string()
-                                    .map(|s| serde_json::Value::String(s.to_string()))
-                            })
+                    "shell" => {
+                        if let Some(v) = child.entries().first().and_then(|e| e.value().as_string())
+                        {
+                            obj.insert(
+                                "shell".to_string(),
+                                serde_json::Value::String(v.to_string()),
+                            );
+                        }
+                    }
+                    "container" => {
+                        obj.insert("container".to_string(), Self::kdl_container_to_json(child)?);
+                    }
+                    "run_if" => {
+                        if let Some(v) = child.entries().first().and_then(|e| e.value().as_string())
+                        {
+                            obj.insert(
+                                "run_if".to_string(),
+                                serde_json::Value::String(v.to_string()),
+                            );
+                        }
+                    }
+                    "concurrency_pool" => {
+                        if let Some(v) = child.entries().first().and_then(|e| e.value().as_string())
+                        {
+                            obj.insert(
+                                "concurrency_pool".to_string(),
+                                serde_json::Value::String(v.to_string()),
+                            );
+                        }
+                    }
+                    "parameters" => {
+                        if let Some(params) = Self::kdl_parameters_to_json(child)? {
+                            obj.insert("parameters".to_string(), params);
+                        }
+                    }
+                    "parameter_mode" => {
+                        if let Some(v) = child.entries().first().and_then(|e| e.value().as_string())
+                        {
+                            obj.insert(
+                                "parameter_mode".to_string(),
+                                serde_json::Value::String(v.to_string()),
+                            );
+                        }
+                    }
+                    "use_parameters" => {
+                        let param_names: Vec<serde_json::Value> = child
+                            .entries()
+                            .iter()
+                            .filter_map(|e| {
+                                e.value()
+                                    .as_string()
+                                    .map(|s| serde_json::Value::String(s.to_string()))
+                            })
                             .collect();
                         if !param_names.is_empty() {
                             obj.insert(
@@ -2642,6 +3329,55 @@ impl WorkflowSpec {
         Ok(serde_json::Value::Object(obj))
     }
 
+    /// Convert a KDL concurrency_pool node to a JSON object
+    /// Format: concurrency_pool "license" max_concurrent_jobs=20
+    #[cfg(feature = "client")]
+    fn kdl_concurrency_pool_to_json(
+        node: &KdlNode,
+    ) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        let name = node
+            .entries()
+            .first()
+            .filter(|e| e.name().is_none())
+            .and_then(|e| e.value().as_string())
+            .ok_or("concurrency_pool must have a name")?;
+        let max_concurrent_jobs = node
+            .get("max_concurrent_jobs")
+            .and_then(|e| e.as_integer())
+            .ok_or_else(|| format!("concurrency_pool '{}' must set max_concurrent_jobs", name))?;
+        Ok(serde_json::json!({
+            "name": name,
+            "max_concurrent_jobs": max_concurrent_jobs as i64,
+        }))
+    }
+
+    /// Convert a KDL include node to a JSON object
+    /// Format: include "common.kdl" prefix="prep_"
+    #[cfg(feature = "client")]
+    fn kdl_include_to_json(
+        node: &KdlNode,
+    ) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        let path = node
+            .entries()
+            .first()
+            .filter(|e| e.name().is_none())
+            .and_then(|e| e.value().as_string())
+            .ok_or("include must have a path")?;
+
+        let mut obj = serde_json::Map::new();
+        obj.insert(
+            "path".to_string(),
+            serde_json::Value::String(path.to_string()),
+        );
+        if let Some(prefix) = node.get("prefix").and_then(|e| e.as_string()) {
+            obj.insert(
+                "prefix".to_string(),
+                serde_json::Value::String(prefix.to_string()),
+            );
+        }
+        Ok(serde_json::Value::Object(obj))
+    }
+
     /// Convert a KDL file node to a JSON object
     #[cfg(feature = "client")]
     fn kdl_file_to_json(node: &KdlNode) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
@@ -2676,6 +3412,11 @@ impl WorkflowSpec {
                             );
                         }
                     }
+                    "use_checksum" => {
+                        if let Some(v) = child.entries().first().and_then(|e| e.value().as_bool()) {
+                            obj.insert("use_checksum".to_string(), serde_json::Value::Bool(v));
+                        }
+                    }
                     "parameters" => {
                         if let Some(params) = Self::kdl_parameters_to_json(child)? {
                             obj.insert("parameters".to_string(), params);
@@ -2953,6 +3694,7 @@ impl WorkflowSpec {
         let mut job_names: Vec<serde_json::Value> = Vec::new();
         let mut job_name_regexes: Vec<serde_json::Value> = Vec::new();
         let mut commands: Vec<serde_json::Value> = Vec::new();
+        let mut depends_on_schedulers: Vec<serde_json::Value> = Vec::new();
 
         if let Some(children) = node.children() {
             for child in children.nodes() {
@@ -3038,6 +3780,11 @@ impl WorkflowSpec {
                             );
                         }
                     }
+                    "use_job_array" => {
+                        if let Some(v) = child.entries().first().and_then(|e| e.value().as_bool()) {
+                            obj.insert("use_job_array".to_string(), serde_json::Value::Bool(v));
+                        }
+                    }
                     "max_parallel_jobs" => {
                         if let Some(v) =
                             child.entries().first().and_then(|e| e.value().as_integer())
@@ -3048,6 +3795,24 @@ impl WorkflowSpec {
                             );
                         }
                     }
+                    "depends_on_schedulers" => {
+                        // Parse as multiple string arguments: depends_on_schedulers "a" "b"
+                        for e in child.entries().iter() {
+                            if let Some(s) = e.value().as_string() {
+                                depends_on_schedulers
+                                    .push(serde_json::Value::String(s.to_string()));
+                            }
+                        }
+                    }
+                    "dependency_type" => {
+                        if let Some(v) = child.entries().first().and_then(|e| e.value().as_string())
+                        {
+                            obj.insert(
+                                "dependency_type".to_string(),
+                                serde_json::Value::String(v.to_string()),
+                            );
+                        }
+                    }
                     "persistent" => {
                         if let Some(v) = child.entries().first().and_then(|e| e.value().as_bool()) {
                             obj.insert("persistent".to_string(), serde_json::Value::Bool(v));
@@ -3071,6 +3836,12 @@ impl WorkflowSpec {
         if !commands.is_empty() {
             obj.insert("commands".to_string(), serde_json::Value::Array(commands));
         }
+        if !depends_on_schedulers.is_empty() {
+            obj.insert(
+                "depends_on_schedulers".to_string(),
+                serde_json::Value::Array(depends_on_schedulers),
+            );
+        }
 
         Ok(serde_json::Value::Object(obj))
     }
@@ -3208,6 +3979,60 @@ impl WorkflowSpec {
                                         );
                                     }
                                 }
+                                key @ ("stdout_regexes" | "stderr_regexes") => {
+                                    let patterns: Vec<serde_json::Value> = rule_child
+                                        .entries()
+                                        .iter()
+                                        .filter_map(|e| {
+                                            e.value()
+                                                .as_string()
+                                                .map(|s| serde_json::Value::String(s.to_string()))
+                                        })
+                                        .collect();
+                                    if !patterns.is_empty() {
+                                        rule_obj.insert(
+                                            key.to_string(),
+                                            serde_json::Value::Array(patterns),
+                                        );
+                                    }
+                                }
+                                key @ ("memory_multiplier"
+                                | "runtime_multiplier"
+                                | "retry_delay_seconds"
+                                | "retry_backoff_multiplier"
+                                | "max_retry_delay_seconds") => {
+                                    if let Some(v) = rule_child.entries().first().and_then(|e| {
+                                        e.value()
+                                            .as_float()
+                                            .or_else(|| e.value().as_integer().map(|i| i as f64))
+                                    }) {
+                                        rule_obj.insert(key.to_string(), serde_json::json!(v));
+                                    }
+                                }
+                                "avoid_failed_node" => {
+                                    if let Some(v) = rule_child
+                                        .entries()
+                                        .first()
+                                        .and_then(|e| e.value().as_bool())
+                                    {
+                                        rule_obj.insert(
+                                            "avoid_failed_node".to_string(),
+                                            serde_json::Value::Bool(v),
+                                        );
+                                    }
+                                }
+                                "scheduler" => {
+                                    if let Some(v) = rule_child
+                                        .entries()
+                                        .first()
+                                        .and_then(|e| e.value().as_string())
+                                    {
+                                        rule_obj.insert(
+                                            "scheduler".to_string(),
+                                            serde_json::Value::String(v.to_string()),
+                                        );
+                                    }
+                                }
                                 "recovery_script" => {
                                     if let Some(v) = rule_child
                                         .entries()
@@ -3294,6 +4119,8 @@ impl WorkflowSpec {
         let mut failure_handlers: Vec<serde_json::Value> = Vec::new();
         let mut slurm_schedulers: Vec<serde_json::Value> = Vec::new();
         let mut actions: Vec<serde_json::Value> = Vec::new();
+        let mut includes: Vec<serde_json::Value> = Vec::new();
+        let mut concurrency_pools: Vec<serde_json::Value> = Vec::new();
 
         for node in doc.nodes() {
             match node.name().value() {
@@ -3360,6 +4187,9 @@ impl WorkflowSpec {
                         obj.insert("parameters".to_string(), params);
                     }
                 }
+                "include" => {
+                    includes.push(Self::kdl_include_to_json(node)?);
+                }
                 "job" => {
                     jobs.push(Self::kdl_job_to_json(node)?);
                 }
@@ -3398,6 +4228,71 @@ impl WorkflowSpec {
                         obj.insert("use_pending_failed".to_string(), serde_json::Value::Bool(v));
                     }
                 }
+                "enforce_runtime_limits" => {
+                    if let Some(v) = node.entries().first().and_then(|e| e.value().as_bool()) {
+                        obj.insert(
+                            "enforce_runtime_limits".to_string(),
+                            serde_json::Value::Bool(v),
+                        );
+                    }
+                }
+                "runtime_limit_grace_seconds" => {
+                    if let Some(v) = node.entries().first().and_then(|e| e.value().as_integer()) {
+                        obj.insert(
+                            "runtime_limit_grace_seconds".to_string(),
+                            serde_json::Value::Number(serde_json::Number::from(v as i64)),
+                        );
+                    }
+                }
+                "inherit_critical_path_priority" => {
+                    if let Some(v) = node.entries().first().and_then(|e| e.value().as_bool()) {
+                        obj.insert(
+                            "inherit_critical_path_priority".to_string(),
+                            serde_json::Value::Bool(v),
+                        );
+                    }
+                }
+                "use_file_checksums" => {
+                    if let Some(v) = node.entries().first().and_then(|e| e.value().as_bool()) {
+                        obj.insert("use_file_checksums".to_string(), serde_json::Value::Bool(v));
+                    }
+                }
+                "worker_heartbeat_timeout_seconds" => {
+                    if let Some(v) = node.entries().first().and_then(|e| e.value().as_integer()) {
+                        obj.insert(
+                            "worker_heartbeat_timeout_seconds".to_string(),
+                            serde_json::Value::Number(serde_json::Number::from(v as i64)),
+                        );
+                    }
+                }
+                "dead_worker_policy" => {
+                    if let Some(v) = node.entries().first().and_then(|e| e.value().as_string()) {
+                        obj.insert(
+                            "dead_worker_policy".to_string(),
+                            serde_json::Value::String(v.to_string()),
+                        );
+                    }
+                }
+                "max_concurrent_jobs" => {
+                    if let Some(v) = node.entries().first().and_then(|e| e.value().as_integer()) {
+                        obj.insert(
+                            "max_concurrent_jobs".to_string(),
+                            serde_json::Value::Number(serde_json::Number::from(v as i64)),
+                        );
+                    }
+                }
+                "concurrency_pool" => {
+                    concurrency_pools.push(Self::kdl_concurrency_pool_to_json(node)?);
+                }
+                "au_budget" => {
+                    if let Some(v) = node.entries().first().and_then(|e| {
+                        e.value()
+                            .as_float()
+                            .or_else(|| e.value().as_integer().map(|i| i as f64))
+                    }) {
+                        obj.insert("au_budget".to_string(), serde_json::json!(v));
+                    }
+                }
                 _ => {
                     // Ignore unknown nodes
                 }
@@ -3433,6 +4328,15 @@ impl WorkflowSpec {
         if !actions.is_empty() {
             obj.insert("actions".to_string(), serde_json::Value::Array(actions));
         }
+        if !includes.is_empty() {
+            obj.insert("include".to_string(), serde_json::Value::Array(includes));
+        }
+        if !concurrency_pools.is_empty() {
+            obj.insert(
+                "concurrency_pools".to_string(),
+                serde_json::Value::Array(concurrency_pools),
+            );
+        }
 
         Ok(serde_json::Value::Object(obj))
     }
@@ -3444,8 +4348,8 @@ impl WorkflowSpec {
 
         // Helper to escape strings for KDL
         fn kdl_escape(s: &str) -> String {
-            // Use raw strings for multi-line or strings with special chars
-            if s.contains('\n') || s.contains('"') || s.contains('\\') {
+            // Use raw strings for multi-line strings
+            if s.contains('\n') {
                 // Count the number of # needed for raw string
                 let mut hashes = 0;
                 loop {
@@ -3459,7 +4363,7 @@ impl WorkflowSpec {
                 // KDL raw string format: r#"..."# where # count can vary
                 format!("r{}\"{}\"{}", delimiter, s, delimiter)
             } else {
-                format!("\"{}\"", s)
+                format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
             }
         }
 
@@ -3471,6 +4375,16 @@ impl WorkflowSpec {
         if let Some(ref desc) = self.description {
             lines.push(format!("description {}", kdl_escape(desc)));
         }
+        for include in self.include.iter().flatten() {
+            match include.prefix {
+                Some(ref prefix) => lines.push(format!(
+                    "include {} prefix={}",
+                    kdl_escape(&include.path),
+                    kdl_escape(prefix)
+                )),
+                None => lines.push(format!("include {}", kdl_escape(&include.path))),
+            }
+        }
         if let Some(val) = self.compute_node_expiration_buffer_seconds {
             lines.push(format!("compute_node_expiration_buffer_seconds {}", val));
         }
@@ -3493,10 +4407,51 @@ impl WorkflowSpec {
             let method_str = match method {
                 models::ClaimJobsSortMethod::GpusRuntimeMemory => "gpus_runtime_memory",
                 models::ClaimJobsSortMethod::GpusMemoryRuntime => "gpus_memory_runtime",
+                models::ClaimJobsSortMethod::Priority => "priority",
                 models::ClaimJobsSortMethod::None => "none",
             };
             lines.push(format!("jobs_sort_method \"{}\"", method_str));
         }
+        if let Some(val) = self.enforce_runtime_limits {
+            lines.push(format!(
+                "enforce_runtime_limits {}",
+                if val { "#true" } else { "#false" }
+            ));
+        }
+        if let Some(val) = self.runtime_limit_grace_seconds {
+            lines.push(format!("runtime_limit_grace_seconds {}", val));
+        }
+        if let Some(val) = self.inherit_critical_path_priority {
+            lines.push(format!(
+                "inherit_critical_path_priority {}",
+                if val { "#true" } else { "#false" }
+            ));
+        }
+        if let Some(val) = self.use_file_checksums {
+            lines.push(format!(
+                "use_file_checksums {}",
+                if val { "#true" } else { "#false" }
+            ));
+        }
+        if let Some(val) = self.worker_heartbeat_timeout_seconds {
+            lines.push(format!("worker_heartbeat_timeout_seconds {}", val));
+        }
+        if let Some(ref policy) = self.dead_worker_policy {
+            lines.push(format!("dead_worker_policy \"{}\"", policy));
+        }
+        if let Some(val) = self.max_concurrent_jobs {
+            lines.push(format!("max_concurrent_jobs {}", val));
+        }
+        for pool in self.concurrency_pools.iter().flatten() {
+            lines.push(format!(
+                "concurrency_pool {} max_concurrent_jobs={}",
+                kdl_escape(&pool.name),
+                pool.max_concurrent_jobs
+            ));
+        }
+        if let Some(val) = self.au_budget {
+            lines.push(format!("au_budget {:?}", val));
+        }
 
         // Parameters
         if let Some(ref params) = self.parameters
@@ -3606,8 +4561,9 @@ impl WorkflowSpec {
             .unwrap_or(false);
         let has_mode = file.parameter_mode.is_some();
         let has_use_params = file.use_parameters.is_some();
+        let has_use_checksum = file.use_checksum.is_some();
 
-        if !has_params && !has_mode && !has_use_params {
+        if !has_params && !has_mode && !has_use_params && !has_use_checksum {
             // Simple form: file "name" path="value"
             lines.push(format!(
                 "file {} path={}",
@@ -3617,6 +4573,12 @@ impl WorkflowSpec {
         } else {
             lines.push(format!("file {} {{", escape(&file.name)));
             lines.push(format!("    path {}", escape(&file.path)));
+            if let Some(val) = file.use_checksum {
+                lines.push(format!(
+                    "    use_checksum {}",
+                    if val { "#true" } else { "#false" }
+                ));
+            }
             if let Some(ref params) = file.parameters
                 && !params.is_empty()
             {
@@ -3749,9 +4711,22 @@ impl WorkflowSpec {
                 if val { "#true" } else { "#false" }
             ));
         }
+        if let Some(val) = action.use_job_array {
+            lines.push(format!(
+                "    use_job_array {}",
+                if val { "#true" } else { "#false" }
+            ));
+        }
         if let Some(max) = action.max_parallel_jobs {
             lines.push(format!("    max_parallel_jobs {}", max));
         }
+        if let Some(ref schedulers) = action.depends_on_schedulers {
+            let names: Vec<String> = schedulers.iter().map(|name| escape(name)).collect();
+            lines.push(format!("    depends_on_schedulers {}", names.join(" ")));
+        }
+        if let Some(ref dependency_type) = action.dependency_type {
+            lines.push(format!("    dependency_type {}", escape(dependency_type)));
+        }
         if let Some(val) = action.persistent {
             lines.push(format!(
                 "    persistent {}",
@@ -3816,6 +4791,59 @@ impl WorkflowSpec {
         if let Some(ref sched) = job.scheduler {
             lines.push(format!("    scheduler {}", escape(sched)));
         }
+        if let Some(priority) = job.priority {
+            lines.push(format!("    priority {}", priority));
+        }
+        if let Some(ref env) = job.env
+            && !env.is_empty()
+        {
+            let mut vars: Vec<_> = env.iter().collect();
+            vars.sort();
+            lines.push("    env {".to_string());
+            for (key, value) in vars {
+                lines.push(format!("        {} {}", escape(key), escape(value)));
+            }
+            lines.push("    }".to_string());
+        }
+        if let Some(ref dir) = job.working_dir {
+            lines.push(format!("    working_dir {}", escape(dir)));
+        }
+        if let Some(ref shell) = job.shell {
+            lines.push(format!("    shell {}", escape(shell)));
+        }
+        if let Some(ref container) = job.container {
+            lines.push("    container {".to_string());
+            lines.push(format!("        image {}", escape(&container.image)));
+            lines.push(format!(
+                "        runtime {}",
+                escape(&container.runtime.to_string())
+            ));
+            if let Some(ref mounts) = container.bind_mounts
+                && !mounts.is_empty()
+            {
+                let mounts: Vec<String> = mounts.iter().map(|m| escape(m)).collect();
+                lines.push(format!("        bind_mounts {}", mounts.join(" ")));
+            }
+            if let Some(gpus) = container.gpus {
+                lines.push(format!(
+                    "        gpus {}",
+                    if gpus { "#true" } else { "#false" }
+                ));
+            }
+            if let Some(ref args) = container.args
+                && !args.is_empty()
+            {
+                let args: Vec<String> = args.iter().map(|a| escape(a)).collect();
+                lines.push(format!("        args {}", args.join(" ")));
+            }
+            lines.push("    }".to_string());
+        }
+        if let Some(ref run_if) = job.run_if {
+            lines.push(format!("    run_if {}", escape(run_if)));
+        }
+        if let Some(ref pool) = job.concurrency_pool {
+            lines.push(format!("    concurrency_pool {}", escape(pool)));
+        }
         if let Some(ref params) = job.parameters
             && !params.is_empty()
         {
@@ -3831,10 +4859,20 @@ impl WorkflowSpec {
     /// Deserialize a WorkflowSpec from a specification file (JSON, JSON5, YAML, or KDL)
     /// All formats are first converted to serde_json::Value, then to WorkflowSpec,
     /// ensuring consistent behavior across all file formats.
+    ///
+    /// Files listed in `include` are loaded and merged into the returned spec.
     pub fn from_spec_file<P: AsRef<Path>>(
         path: P,
     ) -> Result<WorkflowSpec, Box<dyn std::error::Error>> {
-        let path_ref = path.as_ref();
+        let mut spec = Self::from_json_value(Self::spec_file_to_json_value(path.as_ref())?)?;
+        spec.resolve_includes(path.as_ref())?;
+        Ok(spec)
+    }
+
+    /// Parse a spec file of any supported format into a serde_json::Value
+    fn spec_file_to_json_value(
+        path_ref: &Path,
+    ) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
         let file_content = fs::read_to_string(path_ref)?;
 
         // Determine file type based on extension
@@ -3872,7 +4910,7 @@ impl WorkflowSpec {
             }
         };
 
-        Self::from_json_value(json_value)
+        Ok(json_value)
     }
 
     /// Deserialize a WorkflowSpec from string content with a specified format
@@ -3902,6 +4940,258 @@ impl WorkflowSpec {
         Self::from_json_value(json_value)
     }
 
+    /// Load every file listed in `include` (recursively) and merge its definitions into this
+    /// spec. `path` is the file this spec was read from; include paths are relative to its
+    /// directory. A file that is reached again with the same effective prefix is merged only
+    /// once, so several files can share a common include. Returns an error on include cycles.
+    pub fn resolve_includes(&mut self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        if self.include.is_none() {
+            return Ok(());
+        }
+        let root = fs::canonicalize(path)
+            .map_err(|e| format!("Cannot resolve spec file {}: {}", path.display(), e))?;
+        let mut stack = vec![root.clone()];
+        let mut merged = HashSet::new();
+        self.merge_includes(&root, "", &mut stack, &mut merged)
+    }
+
+    fn merge_includes(
+        &mut self,
+        path: &Path,
+        outer_prefix: &str,
+        stack: &mut Vec<std::path::PathBuf>,
+        merged: &mut HashSet<(std::path::PathBuf, String)>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(includes) = self.include.take() else {
+            return Ok(());
+        };
+        let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+
+        let mut combined = WorkflowSpec::default();
+        for include in includes {
+            let include_path = fs::canonicalize(base_dir.join(&include.path)).map_err(|e| {
+                format!(
+                    "Cannot include '{}' from {}: {}",
+                    include.path,
+                    path.display(),
+                    e
+                )
+            })?;
+            if let Some(pos) = stack.iter().position(|p| p == &include_path) {
+                let cycle: Vec<String> = stack[pos..]
+                    .iter()
+                    .chain(std::iter::once(&include_path))
+                    .map(|p| p.display().to_string())
+                    .collect();
+                return Err(format!("Include cycle detected: {}", cycle.join(" -> ")).into());
+            }
+
+            let prefix = include.prefix.unwrap_or_default();
+            let effective_prefix = format!("{}{}", outer_prefix, prefix);
+            if !merged.insert((include_path.clone(), effective_prefix.clone())) {
+                continue;
+            }
+
+            let mut included = Self::from_included_file(&include_path).map_err(|e| {
+                format!(
+                    "Failed to parse included file {}: {}",
+                    include_path.display(),
+                    e
+                )
+            })?;
+            stack.push(include_path.clone());
+            included.merge_includes(&include_path, &effective_prefix, stack, merged)?;
+            stack.pop();
+            if !prefix.is_empty() {
+                included.apply_name_prefix(&prefix)?;
+            }
+            combined.append_definitions(included);
+        }
+
+        // Included definitions come first so that this file's own definitions follow them
+        // and its shared parameters take precedence.
+        combined.append_definitions(WorkflowSpec {
+            jobs: std::mem::take(&mut self.jobs),
+            files: self.files.take(),
+            user_data: self.user_data.take(),
+            resource_requirements: self.resource_requirements.take(),
+            failure_handlers: self.failure_handlers.take(),
+            slurm_schedulers: self.slurm_schedulers.take(),
+            actions: self.actions.take(),
+            parameters: self.parameters.take(),
+            ..Default::default()
+        });
+        self.jobs = combined.jobs;
+        self.files = combined.files;
+        self.user_data = combined.user_data;
+        self.resource_requirements = combined.resource_requirements;
+        self.failure_handlers = combined.failure_handlers;
+        self.slurm_schedulers = combined.slurm_schedulers;
+        self.actions = combined.actions;
+        self.parameters = combined.parameters;
+        Ok(())
+    }
+
+    /// Parse an included spec file. Unlike a top-level spec, an included file may omit
+    /// `name` and `jobs`, so that it can hold only shared definitions.
+    fn from_included_file(path: &Path) -> Result<WorkflowSpec, Box<dyn std::error::Error>> {
+        let mut value = Self::spec_file_to_json_value(path)?;
+        if let Some(obj) = value.as_object_mut() {
+            obj.entry("name")
+                .or_insert_with(|| serde_json::Value::String(String::new()));
+            obj.entry("jobs")
+                .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+        }
+        Self::from_json_value(value)
+    }
+
+    /// Append the definitions of `other` to this spec. Shared parameters in `other` replace
+    /// those with the same name.
+    fn append_definitions(&mut self, other: WorkflowSpec) {
+        fn append<T>(target: &mut Option<Vec<T>>, items: Option<Vec<T>>) {
+            if let Some(items) = items {
+                target.get_or_insert_with(Vec::new).extend(items);
+            }
+        }
+
+        self.jobs.extend(other.jobs);
+        append(&mut self.files, other.files);
+        append(&mut self.user_data, other.user_data);
+        append(&mut self.resource_requirements, other.resource_requirements);
+        append(&mut self.failure_handlers, other.failure_handlers);
+        append(&mut self.slurm_schedulers, other.slurm_schedulers);
+        append(&mut self.actions, other.actions);
+        if let Some(parameters) = other.parameters {
+            self.parameters
+                .get_or_insert_with(HashMap::new)
+                .extend(parameters);
+        }
+    }
+
+    /// Prepend `prefix` to the names of everything defined in this spec and to every
+    /// reference to those names: job dependencies, file and user data references (including
+    /// `${files.*}` and `${user_data.*}` variables), resource requirements, failure handlers,
+    /// schedulers, and action job lists. References to names that this spec does not define
+    /// are left unchanged. Regexes are prefixed only when they are anchored with `^`.
+    fn apply_name_prefix(&mut self, prefix: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let job_names = LocalNames::new(self.jobs.iter().map(|j| j.name.as_str()))?;
+        let file_names = LocalNames::new(self.files.iter().flatten().map(|f| f.name.as_str()))?;
+        let user_data_names = LocalNames::new(
+            self.user_data
+                .iter()
+                .flatten()
+                .filter_map(|ud| ud.name.as_deref()),
+        )?;
+        let resource_req_names = LocalNames::new(
+            self.resource_requirements
+                .iter()
+                .flatten()
+                .map(|rr| rr.name.as_str()),
+        )?;
+        let failure_handler_names = LocalNames::new(
+            self.failure_handlers
+                .iter()
+                .flatten()
+                .map(|fh| fh.name.as_str()),
+        )?;
+        let scheduler_names = LocalNames::new(
+            self.slurm_schedulers
+                .iter()
+                .flatten()
+                .filter_map(|s| s.name.as_deref()),
+        )?;
+
+        let prefix_name = |name: &mut String| *name = format!("{}{}", prefix, name);
+        let prefix_refs = |names: &mut Option<Vec<String>>, local: &LocalNames| {
+            for name in names.iter_mut().flatten() {
+                if local.contains(name) {
+                    prefix_name(name);
+                }
+            }
+        };
+        let prefix_ref = |name: &mut Option<String>, local: &LocalNames| {
+            if let Some(name) = name
+                && local.contains(name)
+            {
+                prefix_name(name);
+            }
+        };
+        let prefix_regexes = |regexes: &mut Option<Vec<String>>| {
+            for regex in regexes.iter_mut().flatten() {
+                if let Some(rest) = regex.strip_prefix('^') {
+                    *regex = format!("^{}{}", regex::escape(prefix), rest);
+                }
+            }
+        };
+        let variable_re =
+            Regex::new(r"\$\{(files|user_data)\.(input|output)\.([^}]+)\}").expect("valid regex");
+        let prefix_variables = |text: &str| -> String {
+            variable_re
+                .replace_all(text, |caps: &regex::Captures| {
+                    let local = if &caps[1] == "files" {
+                        &file_names
+                    } else {
+                        &user_data_names
+                    };
+                    if local.contains(&caps[3]) {
+                        format!("${{{}.{}.{}{}}}", &caps[1], &caps[2], prefix, &caps[3])
+                    } else {
+                        caps[0].to_string()
+                    }
+                })
+                .into_owned()
+        };
+
+        for job in &mut self.jobs {
+            prefix_name(&mut job.name);
+            job.command = prefix_variables(&job.command);
+            if let Some(script) = job.invocation_script.as_mut() {
+                *script = prefix_variables(script);
+            }
+            prefix_refs(&mut job.depends_on, &job_names);
+            prefix_refs(&mut job.input_files, &file_names);
+            prefix_refs(&mut job.output_files, &file_names);
+            prefix_refs(&mut job.input_user_data, &user_data_names);
+            prefix_refs(&mut job.output_user_data, &user_data_names);
+            prefix_regexes(&mut job.depends_on_regexes);
+            prefix_regexes(&mut job.input_file_regexes);
+            prefix_regexes(&mut job.output_file_regexes);
+            prefix_regexes(&mut job.input_user_data_regexes);
+            prefix_regexes(&mut job.output_user_data_regexes);
+            prefix_ref(&mut job.resource_requirements, &resource_req_names);
+            prefix_ref(&mut job.failure_handler, &failure_handler_names);
+            prefix_ref(&mut job.scheduler, &scheduler_names);
+        }
+        for file in self.files.iter_mut().flatten() {
+            prefix_name(&mut file.name);
+        }
+        for ud in self.user_data.iter_mut().flatten() {
+            if let Some(name) = ud.name.as_mut() {
+                prefix_name(name);
+            }
+        }
+        for rr in self.resource_requirements.iter_mut().flatten() {
+            prefix_name(&mut rr.name);
+        }
+        for fh in self.failure_handlers.iter_mut().flatten() {
+            prefix_name(&mut fh.name);
+            for rule in &mut fh.rules {
+                prefix_ref(&mut rule.scheduler, &scheduler_names);
+            }
+        }
+        for scheduler in self.slurm_schedulers.iter_mut().flatten() {
+            if let Some(name) = scheduler.name.as_mut() {
+                prefix_name(name);
+            }
+        }
+        for action in self.actions.iter_mut().flatten() {
+            prefix_refs(&mut action.jobs, &job_names);
+            prefix_regexes(&mut action.job_name_regexes);
+            prefix_ref(&mut action.scheduler, &scheduler_names);
+        }
+        Ok(())
+    }
+
     /// Perform variable substitution on job commands and invocation scripts
     /// Supported variables:
     /// - ${files.input.NAME} - input file (automatically adds to input_files)
@@ -3998,6 +5288,30 @@ impl WorkflowSpec {
                     job.output_user_data = Some(combined);
                 }
             }
+
+            // Jobs and user data referenced by the run_if condition must be available before
+            // the condition is evaluated
+            if let Some(run_if) = &job.run_if {
+                let condition = JobCondition::parse(run_if).map_err(|e| {
+                    format!("Job '{}' has an invalid run_if condition: {}", job.name, e)
+                })?;
+                let referenced_jobs = condition.referenced_jobs();
+                if !referenced_jobs.is_empty() {
+                    let mut combined = job.depends_on.clone().unwrap_or_default();
+                    combined.extend(referenced_jobs);
+                    combined.sort();
+                    combined.dedup();
+                    job.depends_on = Some(combined);
+                }
+                let referenced_user_data = condition.referenced_user_data();
+                if !referenced_user_data.is_empty() {
+                    let mut combined = job.input_user_data.clone().unwrap_or_default();
+                    combined.extend(referenced_user_data);
+                    combined.sort();
+                    combined.dedup();
+                    job.input_user_data = Some(combined);
+                }
+            }
         }
 
         Ok(())
@@ -4489,6 +5803,7 @@ job "train_lr{lr:.4f}_bs{batch_size}" {
             name: "test_workflow".to_string(),
             description: Some("Test workflow with parameters".to_string()),
             user: Some("test_user".to_string()),
+            include: None,
             compute_node_expiration_buffer_seconds: None,
             compute_node_wait_for_healthy_database_minutes: None,
             compute_node_ignore_workflow_completion: None,
@@ -4513,6 +5828,13 @@ job "train_lr{lr:.4f}_bs{batch_size}" {
                 input_user_data_regexes: None,
                 output_user_data: None,
                 output_user_data_regexes: None,
+                priority: None,
+                env: None,
+                working_dir: None,
+                shell: None,
+                container: None,
+                run_if: None,
+                concurrency_pool: None,
                 parameters: Some({
                     let mut params = HashMap::new();
                     params.insert("i".to_string(), "1:3".to_string());
@@ -4540,6 +5862,15 @@ job "train_lr{lr:.4f}_bs{batch_size}" {
             actions: None,
             failure_handlers: None,
             use_pending_failed: None,
+            enforce_runtime_limits: None,
+            runtime_limit_grace_seconds: None,
+            inherit_critical_path_priority: None,
+            use_file_checksums: None,
+            worker_heartbeat_timeout_seconds: None,
+            dead_worker_policy: None,
+            max_concurrent_jobs: None,
+            concurrency_pools: None,
+            au_budget: None,
         };
 
         spec.expand_parameters()
@@ -5167,4 +6498,514 @@ jobs:
         // Default should be product mode: 2 * 2 = 4 combinations
         assert_eq!(spec.jobs.len(), 4);
     }
+
+    #[test]
+    fn test_apply_critical_path_priority() {
+        // prep -> work_{i} (x3) -> collect is the critical path; side is off the path
+        let yaml_content = r#"
+name: test_critical_path_priority
+inherit_critical_path_priority: true
+jobs:
+  - name: prep
+    command: prep.sh
+  - name: work_{i}
+    command: work.sh {i}
+    depends_on: [prep]
+    priority: 5
+    parameters:
+      i: "1:3"
+  - name: collect
+    command: collect.sh
+    depends_on_regexes: ["^work_.*"]
+  - name: side
+    command: side.sh
+    priority: 2
+"#;
+
+        let mut spec = WorkflowSpec::from_spec_file_content(yaml_content, "yaml")
+            .expect("Failed to parse YAML workflow spec");
+        assert_eq!(spec.inherit_critical_path_priority, Some(true));
+
+        spec.apply_critical_path_priority()
+            .expect("Failed to apply critical path priority");
+        spec.expand_parameters()
+            .expect("Failed to expand parameters");
+
+        let priorities: HashMap<&str, Option<i64>> = spec
+            .jobs
+            .iter()
+            .map(|job| (job.name.as_str(), job.priority))
+            .collect();
+        assert_eq!(priorities["prep"], Some(5));
+        assert_eq!(priorities["work_1"], Some(5));
+        assert_eq!(priorities["work_3"], Some(5));
+        assert_eq!(priorities["collect"], Some(5));
+        assert_eq!(priorities["side"], Some(2));
+    }
+
+    #[test]
+    fn test_critical_path_priority_defaults_to_one() {
+        let mut spec = WorkflowSpec {
+            name: "test".to_string(),
+            jobs: vec![
+                JobSpec::new("a".to_string(), "a.sh".to_string()),
+                JobSpec {
+                    depends_on: Some(vec!["a".to_string()]),
+                    ..JobSpec::new("b".to_string(), "b.sh".to_string())
+                },
+            ],
+            ..Default::default()
+        };
+
+        spec.apply_critical_path_priority()
+            .expect("Failed to apply critical path priority");
+
+        assert!(spec.jobs.iter().all(|job| job.priority == Some(1)));
+    }
+
+    #[test]
+    fn test_env_and_working_dir_substitution() {
+        let mut job = JobSpec::new("job_{i}".to_string(), "python train.py".to_string());
+        let mut env = HashMap::new();
+        env.insert("SEED".to_string(), "{i}".to_string());
+        env.insert("MODE".to_string(), "train".to_string());
+        job.env = Some(env);
+        job.working_dir = Some("runs/run_{i:03d}".to_string());
+        job.shell = Some("sh".to_string());
+
+        let mut params = HashMap::new();
+        params.insert("i".to_string(), "1:2".to_string());
+        job.parameters = Some(params);
+
+        let expanded = job.expand().expect("Failed to expand job");
+
+        let env = expanded[1].env.as_ref().unwrap();
+        assert_eq!(env["SEED"], "2");
+        assert_eq!(env["MODE"], "train");
+        assert_eq!(expanded[1].working_dir, Some("runs/run_002".to_string()));
+        assert_eq!(expanded[1].shell, Some("sh".to_string()));
+    }
+
+    #[test]
+    fn test_env_working_dir_shell_yaml() {
+        let yaml_content = r#"
+name: env_test
+jobs:
+  - name: job
+    command: echo $GREETING
+    env:
+      GREETING: hello
+    working_dir: /tmp
+    shell: sh
+"#;
+
+        let spec = WorkflowSpec::from_spec_file_content(yaml_content, "yaml")
+            .expect("Failed to parse YAML workflow spec");
+
+        let job = &spec.jobs[0];
+        assert_eq!(job.env.as_ref().unwrap()["GREETING"], "hello");
+        assert_eq!(job.working_dir, Some("/tmp".to_string()));
+        assert_eq!(job.shell, Some("sh".to_string()));
+    }
+
+    #[test]
+    fn test_kdl_include_round_trip() {
+        let kdl_content = r#"
+name "include_test"
+include "shared/resources.kdl"
+include "stage.yaml" prefix="prep_"
+"#;
+        let spec =
+            WorkflowSpec::from_spec_file_content(kdl_content, "kdl").expect("Failed to parse KDL");
+        let includes = spec.include.clone().expect("include should be set");
+        assert_eq!(includes.len(), 2);
+        assert_eq!(includes[0].path, "shared/resources.kdl");
+        assert_eq!(includes[0].prefix, None);
+        assert_eq!(includes[1].prefix.as_deref(), Some("prep_"));
+
+        let reparsed = WorkflowSpec::from_spec_file_content(&spec.to_kdl_str(), "kdl")
+            .expect("Failed to reparse KDL");
+        assert_eq!(reparsed.include, spec.include);
+    }
+
+    #[test]
+    fn test_env_working_dir_shell_kdl_round_trip() {
+        let kdl_content = r#"
+name "env_test"
+
+job "job" {
+    command "echo $GREETING"
+    env {
+        GREETING "hello"
+        OMP_NUM_THREADS "4"
+    }
+    working_dir "/tmp"
+    shell "bash -l"
+}
+"#;
+
+        let spec = WorkflowSpec::from_spec_file_content(kdl_content, "kdl")
+            .expect("Failed to parse KDL workflow spec");
+        let job = &spec.jobs[0];
+        assert_eq!(job.env.as_ref().unwrap()["GREETING"], "hello");
+        assert_eq!(job.env.as_ref().unwrap()["OMP_NUM_THREADS"], "4");
+        assert_eq!(job.working_dir, Some("/tmp".to_string()));
+        assert_eq!(job.shell, Some("bash -l".to_string()));
+
+        let reparsed = WorkflowSpec::from_spec_file_content(&spec.to_kdl_str(), "kdl")
+            .expect("Failed to parse serialized KDL workflow spec");
+        assert_eq!(reparsed.jobs[0].env, job.env);
+        assert_eq!(reparsed.jobs[0].working_dir, job.working_dir);
+        assert_eq!(reparsed.jobs[0].shell, job.shell);
+    }
+
+    #[test]
+    fn test_validate_job_environments() {
+        let mut job = JobSpec::new("job".to_string(), "echo".to_string());
+        let mut env = HashMap::new();
+        env.insert("BAD=NAME".to_string(), "value".to_string());
+        job.env = Some(env);
+        let spec = WorkflowSpec {
+            name: "test".to_string(),
+            jobs: vec![job],
+            ..Default::default()
+        };
+        assert!(spec.validate_job_environments().is_err());
+
+        let spec = WorkflowSpec {
+            name: "test".to_string(),
+            jobs: vec![JobSpec {
+                shell: Some(" ".to_string()),
+                ..JobSpec::new("job".to_string(), "echo".to_string())
+            }],
+            ..Default::default()
+        };
+        assert!(spec.validate_job_environments().is_err());
+    }
+
+    #[test]
+    fn test_run_if_kdl_round_trip() {
+        let content = r#"
+name "conditions"
+job "prep" {
+    command "echo prep"
+}
+job "repair" {
+    command "echo repair"
+    run_if "exit_code(\"prep\") != 0"
+}
+"#;
+        let spec = WorkflowSpec::from_spec_file_content(content, "kdl")
+            .expect("Failed to parse KDL workflow spec");
+        assert_eq!(
+            spec.jobs[1].run_if.as_deref(),
+            Some(r#"exit_code("prep") != 0"#)
+        );
+
+        let reparsed = WorkflowSpec::from_spec_file_content(&spec.to_kdl_str(), "kdl")
+            .expect("Failed to parse serialized KDL workflow spec");
+        assert_eq!(reparsed.jobs[1].run_if, spec.jobs[1].run_if);
+    }
+
+    #[test]
+    fn test_run_if_expansion_and_dependencies() {
+        let mut check = JobSpec::new("check_{i}".to_string(), "echo".to_string());
+        let mut params = HashMap::new();
+        params.insert("i".to_string(), "1:2".to_string());
+        check.parameters = Some(params.clone());
+        let mut work = JobSpec::new("work_{i}".to_string(), "echo".to_string());
+        work.parameters = Some(params);
+        work.run_if =
+            Some(r#"exit_code("check_{i}") == 0 && user_data("settings").enabled"#.to_string());
+        let mut spec = WorkflowSpec {
+            name: "test".to_string(),
+            jobs: vec![check, work],
+            ..Default::default()
+        };
+        spec.expand_parameters().unwrap();
+        spec.validate_job_conditions().unwrap();
+        spec.substitute_variables().unwrap();
+
+        let work_2 = spec.jobs.iter().find(|j| j.name == "work_2").unwrap();
+        assert_eq!(
+            work_2.run_if.as_deref(),
+            Some(r#"exit_code("check_2") == 0 && user_data("settings").enabled"#)
+        );
+        assert_eq!(work_2.depends_on, Some(vec!["check_2".to_string()]));
+        assert_eq!(work_2.input_user_data, Some(vec!["settings".to_string()]));
+    }
+
+    #[test]
+    fn test_validate_job_conditions() {
+        let spec_with = |run_if: &str| WorkflowSpec {
+            name: "test".to_string(),
+            jobs: vec![
+                JobSpec::new("prep".to_string(), "echo".to_string()),
+                JobSpec {
+                    run_if: Some(run_if.to_string()),
+                    ..JobSpec::new("job".to_string(), "echo".to_string())
+                },
+            ],
+            ..Default::default()
+        };
+        assert!(
+            spec_with(r#"status("prep") == "completed""#)
+                .validate_job_conditions()
+                .is_ok()
+        );
+        assert!(
+            spec_with(r#"exit_code("prep") =="#)
+                .validate_job_conditions()
+                .is_err()
+        );
+        assert!(
+            spec_with(r#"exit_code("missing") == 0"#)
+                .validate_job_conditions()
+                .is_err()
+        );
+        assert!(
+            spec_with(r#"exit_code("job") == 0"#)
+                .validate_job_conditions()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_kdl_failure_handler_log_patterns_and_adjustments() {
+        let kdl_content = r#"
+name "failure_handler_rules"
+
+slurm_scheduler "long" {
+    account "project"
+    walltime "48:00:00"
+}
+
+failure_handler "escalate" {
+    rule {
+        exit_codes 1
+        stderr_regexes "CUDA out of memory" "oom-kill"
+        memory_multiplier 2
+        runtime_multiplier 1.5
+        scheduler "long"
+        retry_delay_seconds 30
+        retry_backoff_multiplier 2.0
+        max_retry_delay_seconds 600
+        avoid_failed_node #true
+        max_retries 2
+    }
+}
+
+job "train" {
+    command "python train.py"
+    failure_handler "escalate"
+}
+"#;
+
+        let spec = WorkflowSpec::from_spec_file_content(kdl_content, "kdl")
+            .expect("Failed to parse KDL workflow spec");
+        let rule = &spec.failure_handlers.as_ref().unwrap()[0].rules[0];
+        assert_eq!(rule.exit_codes, vec![1]);
+        assert_eq!(rule.stderr_regexes, vec!["CUDA out of memory", "oom-kill"]);
+        assert!(rule.stdout_regexes.is_empty());
+        assert_eq!(rule.memory_multiplier, Some(2.0));
+        assert_eq!(rule.runtime_multiplier, Some(1.5));
+        assert_eq!(rule.scheduler.as_deref(), Some("long"));
+        assert_eq!(rule.retry_delay_seconds, Some(30.0));
+        assert_eq!(rule.retry_backoff_multiplier, Some(2.0));
+        assert_eq!(rule.max_retry_delay_seconds, Some(600.0));
+        assert!(rule.avoid_failed_node);
+        spec.validate_failure_handlers()
+            .expect("Failure handlers should be valid");
+    }
+
+    #[test]
+    fn test_dead_worker_settings_kdl_round_trip() {
+        let content = r#"
+name "heartbeats"
+worker_heartbeat_timeout_seconds 120
+dead_worker_policy "retry"
+job "work" {
+    command "echo work"
+}
+"#;
+        let spec = WorkflowSpec::from_spec_file_content(content, "kdl")
+            .expect("Failed to parse KDL workflow spec");
+        assert_eq!(spec.worker_heartbeat_timeout_seconds, Some(120));
+        assert_eq!(
+            spec.dead_worker_policy,
+            Some(models::DeadWorkerPolicy::Retry)
+        );
+
+        let reparsed = WorkflowSpec::from_spec_file_content(&spec.to_kdl_str(), "kdl")
+            .expect("Failed to parse serialized KDL workflow spec");
+        assert_eq!(reparsed.worker_heartbeat_timeout_seconds, Some(120));
+        assert_eq!(
+            reparsed.dead_worker_policy,
+            Some(models::DeadWorkerPolicy::Retry)
+        );
+
+        let yaml = "name: heartbeats\ndead_worker_policy: fail\njobs:\n  - name: work\n    command: echo work\n";
+        let spec = WorkflowSpec::from_spec_file_content(yaml, "yaml")
+            .expect("Failed to parse YAML workflow spec");
+        assert_eq!(
+            spec.dead_worker_policy,
+            Some(models::DeadWorkerPolicy::Fail)
+        );
+        assert_eq!(spec.worker_heartbeat_timeout_seconds, None);
+    }
+
+    #[test]
+    fn test_concurrency_pools_kdl_round_trip() {
+        let content = r#"
+name "pools"
+max_concurrent_jobs 200
+concurrency_pool "license" max_concurrent_jobs=20
+job "solve" {
+    command "./solve"
+    concurrency_pool "license"
+}
+"#;
+        let spec = WorkflowSpec::from_spec_file_content(content, "kdl")
+            .expect("Failed to parse KDL workflow spec");
+        let expected_pools = vec![ConcurrencyPoolSpec {
+            name: "license".to_string(),
+            max_concurrent_jobs: 20,
+        }];
+        assert_eq!(spec.max_concurrent_jobs, Some(200));
+        assert_eq!(spec.concurrency_pools.as_ref(), Some(&expected_pools));
+        assert_eq!(spec.jobs[0].concurrency_pool.as_deref(), Some("license"));
+        spec.validate_concurrency_pools().unwrap();
+
+        let reparsed = WorkflowSpec::from_spec_file_content(&spec.to_kdl_str(), "kdl")
+            .expect("Failed to parse serialized KDL workflow spec");
+        assert_eq!(reparsed.max_concurrent_jobs, Some(200));
+        assert_eq!(reparsed.concurrency_pools, Some(expected_pools));
+        assert_eq!(
+            reparsed.jobs[0].concurrency_pool,
+            spec.jobs[0].concurrency_pool
+        );
+    }
+
+    #[test]
+    fn test_validate_concurrency_pools() {
+        let spec_with = |pools: &str, job_pool: &str| {
+            let yaml = format!(
+                "name: pools\n{}jobs:\n  - name: work\n    command: echo work\n{}",
+                pools, job_pool
+            );
+            WorkflowSpec::from_spec_file_content(&yaml, "yaml").unwrap()
+        };
+        let pools = "concurrency_pools:\n  - name: db\n    max_concurrent_jobs: 2\n";
+        spec_with(pools, "    concurrency_pool: db\n")
+            .validate_concurrency_pools()
+            .unwrap();
+        assert!(
+            spec_with(pools, "    concurrency_pool: license\n")
+                .validate_concurrency_pools()
+                .is_err()
+        );
+        assert!(
+            spec_with(
+                "concurrency_pools:\n  - name: db\n    max_concurrent_jobs: 0\n",
+                ""
+            )
+            .validate_concurrency_pools()
+            .is_err()
+        );
+        assert!(
+            spec_with(
+                "concurrency_pools:\n  - {name: db, max_concurrent_jobs: 1}\n  - {name: db, max_concurrent_jobs: 2}\n",
+                ""
+            )
+            .validate_concurrency_pools()
+            .is_err()
+        );
+        assert!(
+            spec_with("max_concurrent_jobs: 0\n", "")
+                .validate_concurrency_pools()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_au_budget_kdl_round_trip() {
+        let content = r#"
+name "budget"
+au_budget 1500
+job "solve" {
+    command "./solve"
+}
+"#;
+        let spec = WorkflowSpec::from_spec_file_content(content, "kdl")
+            .expect("Failed to parse KDL workflow spec");
+        assert_eq!(spec.au_budget, Some(1500.0));
+
+        let reparsed = WorkflowSpec::from_spec_file_content(&spec.to_kdl_str(), "kdl")
+            .expect("Failed to parse serialized KDL workflow spec");
+        assert_eq!(reparsed.au_budget, Some(1500.0));
+
+        let yaml = "name: budget\nau_budget: 250.5\njobs:\n  - name: work\n    command: echo\n";
+        let spec = WorkflowSpec::from_spec_file_content(yaml, "yaml").unwrap();
+        assert_eq!(spec.au_budget, Some(250.5));
+    }
+
+    #[test]
+    fn test_use_job_array_kdl_round_trip() {
+        let content = r#"
+name "array"
+job "solve" {
+    command "./solve"
+}
+slurm_scheduler "short" {
+    account "proj"
+    walltime "01:00:00"
+}
+action {
+    trigger_type "on_workflow_start"
+    action_type "schedule_nodes"
+    scheduler "short"
+    scheduler_type "slurm"
+    num_allocations 200
+    use_job_array #true
+}
+"#;
+        let spec = WorkflowSpec::from_spec_file_content(content, "kdl")
+            .expect("Failed to parse KDL workflow spec");
+        let actions = spec.actions.as_ref().expect("Expected actions");
+        assert_eq!(actions[0].use_job_array, Some(true));
+
+        let reparsed = WorkflowSpec::from_spec_file_content(&spec.to_kdl_str(), "kdl")
+            .expect("Failed to parse serialized KDL workflow spec");
+        assert_eq!(reparsed.actions.unwrap()[0].use_job_array, Some(true));
+    }
+
+    #[test]
+    fn test_depends_on_schedulers_kdl_round_trip() {
+        let content = r#"
+name "chained"
+job "solve" {
+    command "./solve"
+}
+action {
+    trigger_type "on_workflow_start"
+    action_type "schedule_nodes"
+    scheduler "post"
+    scheduler_type "slurm"
+    depends_on_schedulers "pre" "solve"
+    dependency_type "afterany"
+}
+"#;
+        let spec = WorkflowSpec::from_spec_file_content(content, "kdl")
+            .expect("Failed to parse KDL workflow spec");
+        let expected = Some(vec!["pre".to_string(), "solve".to_string()]);
+        let actions = spec.actions.as_ref().expect("Expected actions");
+        assert_eq!(actions[0].depends_on_schedulers, expected);
+        assert_eq!(actions[0].dependency_type.as_deref(), Some("afterany"));
+
+        let reparsed = WorkflowSpec::from_spec_file_content(&spec.to_kdl_str(), "kdl")
+            .expect("Failed to parse serialized KDL workflow spec");
+        let actions = reparsed.actions.unwrap();
+        assert_eq!(actions[0].depends_on_schedulers, expected);
+        assert_eq!(actions[0].dependency_type.as_deref(), Some("afterany"));
+    }
 }
@@ -30,8 +30,8 @@ mod server;
 
 pub use client::{
     ClientConfig, ClientHpcConfig, ClientRunConfig, ClientSlurmConfig, HpcPartitionConfig,
-    HpcProfileConfig, HpcProfileOverride,
+    HpcProfileConfig, HpcProfileOverride, MultiNodeLauncher,
 };
 pub use dash::DashConfig;
 pub use loader::{ConfigPaths, TorcConfig};
-pub use server::{ServerConfig, ServerLoggingConfig};
+pub use server::{NotificationSinkConfig, NotificationSinkType, ServerConfig, ServerLoggingConfig};
@@ -65,6 +65,12 @@ pub struct ClientRunConfig {
 
     /// Number of GPUs available
     pub num_gpus: Option<i64>,
+
+    /// Upload job stdout and stderr to the server when jobs complete
+    pub upload_logs: bool,
+
+    /// Maximum bytes uploaded per log file; larger files are uploaded from their end
+    pub upload_logs_max_bytes: u64,
 }
 
 impl Default for ClientRunConfig {
@@ -76,6 +82,8 @@ impl Default for ClientRunConfig {
             num_cpus: None,
             memory_gb: None,
             num_gpus: None,
+            upload_logs: false,
+            upload_logs_max_bytes: 1024 * 1024,
         }
     }
 }
@@ -94,6 +102,9 @@ pub struct ClientSlurmConfig {
     /// If false (default), jobs with a scheduler_id mismatch will be claimed
     /// if no matching jobs are available.
     pub strict_scheduler_match: bool,
+
+    /// Launcher for jobs that need more than one node
+    pub multi_node_launcher: MultiNodeLauncher,
 }
 
 impl Default for ClientSlurmConfig {
@@ -102,6 +113,30 @@ impl Default for ClientSlurmConfig {
             poll_interval: 30,
             keep_submission_scripts: false,
             strict_scheduler_match: false,
+            multi_node_launcher: MultiNodeLauncher::Srun,
+        }
+    }
+}
+
+/// How the job runner starts a job on the nodes reserved for it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MultiNodeLauncher {
+    /// Start one task per node with `srun`
+    #[default]
+    Srun,
+    /// Start one process per node with `mpirun` and a hostfile
+    Mpirun,
+    /// Run the command on the head node; it starts its own processes on the nodes
+    None,
+}
+
+impl std::fmt::Display for MultiNodeLauncher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MultiNodeLauncher::Srun => write!(f, "srun"),
+            MultiNodeLauncher::Mpirun => write!(f, "mpirun"),
+            MultiNodeLauncher::None => write!(f, "none"),
         }
     }
 }
@@ -235,6 +270,14 @@ pub struct HpcPartitionConfig {
     /// Maximum wall time in seconds
     pub max_walltime_secs: u64,
 
+    /// Maximum nodes per job (if limited)
+    #[serde(default)]
+    pub max_nodes: Option<u32>,
+
+    /// Minimum nodes per job (if required)
+    #[serde(default)]
+    pub min_nodes: Option<u32>,
+
     /// GPUs per node (if any)
     #[serde(default)]
     pub gpus_per_node: Option<u32>,
@@ -254,6 +297,10 @@ pub struct HpcPartitionConfig {
     /// Whether partition must be explicitly requested
     #[serde(default)]
     pub requires_explicit_request: bool,
+
+    /// Default QoS for the partition
+    #[serde(default)]
+    pub default_qos: Option<String>,
 }
 
 #[cfg(test)]
@@ -206,6 +206,12 @@ output_dir = "output"
 # memory_gb = 32.0
 # num_gpus = 1
 
+# Upload job stdout and stderr to the server when jobs complete
+upload_logs = false
+
+# Maximum bytes uploaded per log file; larger files are uploaded from their end
+upload_logs_max_bytes = 1048576
+
 [client.slurm]
 # Poll interval in seconds for Slurm job runners
 poll_interval = 30
@@ -213,6 +219,9 @@ poll_interval = 30
 # Keep submission scripts after job submission (useful for debugging)
 keep_submission_scripts = false
 
+# Launcher for jobs that need more than one node: srun, mpirun, or none
+multi_node_launcher = "srun"
+
 [client.hpc]
 # Default account to use for HPC jobs (applies to all profiles)
 # default_account = "my_project"
@@ -262,6 +271,24 @@ require_auth = false
 # Interval for background job completion processing (seconds)
 completion_check_interval_secs = 30.0
 
+# Interval for detecting compute nodes that stopped sending heartbeats (seconds)
+worker_check_interval_secs = 30.0
+
+# Retry limit for jobs of dead compute nodes under the retry policy
+dead_worker_max_retries = 3
+
+# Interval for firing recurring workflow schedules (seconds)
+schedule_check_interval_secs = 30.0
+
+# Interval for reloading API tokens from the database (seconds)
+api_token_refresh_interval_secs = 10.0
+
+# Maximum size of an uploaded job log (bytes)
+job_log_max_bytes = 10485760
+
+# torc CLI that the server runs to reinitialize and submit scheduled workflows
+torc_bin = "torc"
+
 # Log level: error, warn, info, debug, trace
 log_level = "info"
 
@@ -276,6 +303,14 @@ json_logs = false
 # These users are automatically added to the system "admin" group on startup
 # admin_users = ["alice", "bob"]
 
+# Notification sinks for workflow events (one [[server.notifications]] table per sink)
+# [[server.notifications]]
+# type = "slack"  # webhook, slack, or command
+# url = "https://hooks.slack.com/services/..."
+# event_types = ["workflow_completed", "job_failed"]
+# min_severity = "info"
+# first_per_run = true
+
 [dash]
 # Host to bind to
 host = "127.0.0.1"
@@ -333,6 +368,24 @@ completion_check_interval_secs = 5
             errors.push("server.completion_check_interval_secs must be positive".to_string());
         }
 
+        if self.server.worker_check_interval_secs <= 0.0 {
+            errors.push("server.worker_check_interval_secs must be positive".to_string());
+        }
+
+        if self.server.schedule_check_interval_secs <= 0.0 {
+            errors.push("server.schedule_check_interval_secs must be positive".to_string());
+        }
+
+        if self.server.api_token_refresh_interval_secs <= 0.0 {
+            errors.push("server.api_token_refresh_interval_secs must be positive".to_string());
+        }
+
+        for (i, sink) in self.server.notifications.iter().enumerate() {
+            if let Err(e) = sink.validate() {
+                errors.push(format!("server.notifications[{}]: {}", i, e));
+            }
+        }
+
         // Validate dash config
         if self.dash.port == 0 {
             errors.push("dash.port cannot be 0".to_string());
@@ -1,5 +1,6 @@
 //! Server configuration for torc-server
 
+use crate::models::EventSeverity;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -41,12 +42,36 @@ pub struct ServerConfig {
     /// Interval in seconds for background job completion processing
     pub completion_check_interval_secs: f64,
 
+    /// Interval in seconds for background detection of compute nodes that stopped sending
+    /// heartbeats
+    pub worker_check_interval_secs: f64,
+
+    /// Retry limit for jobs of dead compute nodes under the `retry` dead worker policy; jobs
+    /// whose attempt_id has reached it are failed instead of returned to ready
+    pub dead_worker_max_retries: u32,
+
+    /// Interval in seconds for background firing of recurring workflow schedules
+    pub schedule_check_interval_secs: f64,
+
+    /// Interval in seconds for reloading API tokens from the database, so that tokens created or
+    /// revoked through other server instances take effect
+    pub api_token_refresh_interval_secs: f64,
+
+    /// Maximum size in bytes of an uploaded job log; larger uploads are rejected
+    pub job_log_max_bytes: u64,
+
+    /// Path to the torc CLI that runs scheduled workflows
+    pub torc_bin: String,
+
     /// Logging configuration
     pub logging: ServerLoggingConfig,
 
     /// List of admin users (members of the system admin group)
     /// These users can create and manage access groups
     pub admin_users: Vec<String>,
+
+    /// Sinks that receive notifications of workflow events
+    pub notifications: Vec<NotificationSinkConfig>,
 }
 
 impl Default for ServerConfig {
@@ -63,8 +88,15 @@ impl Default for ServerConfig {
             credential_cache_ttl_secs: 60,
             enforce_access_control: false,
             completion_check_interval_secs: 30.0,
+            worker_check_interval_secs: 30.0,
+            dead_worker_max_retries: 3,
+            schedule_check_interval_secs: 30.0,
+            api_token_refresh_interval_secs: 10.0,
+            job_log_max_bytes: 10 * 1024 * 1024,
+            torc_bin: "torc".to_string(),
             logging: ServerLoggingConfig::default(),
             admin_users: Vec::new(),
+            notifications: Vec::new(),
         }
     }
 }
@@ -81,6 +113,116 @@ pub struct ServerLoggingConfig {
     pub json_logs: bool,
 }
 
+/// Kind of destination for event notifications
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationSinkType {
+    /// POST the event as JSON to a URL
+    #[default]
+    Webhook,
+    /// POST a Slack-compatible `{"text": ...}` message to an incoming-webhook URL
+    Slack,
+    /// Run a shell command with the event as JSON on stdin
+    Command,
+}
+
+impl std::fmt::Display for NotificationSinkType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationSinkType::Webhook => write!(f, "webhook"),
+            NotificationSinkType::Slack => write!(f, "slack"),
+            NotificationSinkType::Command => write!(f, "command"),
+        }
+    }
+}
+
+/// A destination for workflow event notifications and the events it receives
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationSinkConfig {
+    /// Name used in log messages (defaults to the sink type)
+    pub name: Option<String>,
+
+    /// Kind of sink
+    #[serde(rename = "type")]
+    pub sink_type: NotificationSinkType,
+
+    /// URL of webhook and slack sinks
+    pub url: Option<String>,
+
+    /// Shell command of command sinks
+    pub command: Option<String>,
+
+    /// Only notify for these workflows (all workflows if empty)
+    pub workflow_ids: Vec<i64>,
+
+    /// Only notify for these event types, such as "job_failed" (all types if empty)
+    pub event_types: Vec<String>,
+
+    /// Only notify for events with at least this severity
+    pub min_severity: EventSeverity,
+
+    /// Only notify for the first matching event of each workflow run
+    pub first_per_run: bool,
+
+    /// Number of times to retry a failed HTTP delivery
+    pub max_retries: u32,
+
+    /// Seconds to wait before the first retry; doubles after each retry
+    pub retry_delay_secs: f64,
+
+    /// Timeout in seconds for each HTTP request or command
+    pub timeout_secs: f64,
+}
+
+impl Default for NotificationSinkConfig {
+    fn default() -> Self {
+        Self {
+            name: None,
+            sink_type: NotificationSinkType::default(),
+            url: None,
+            command: None,
+            workflow_ids: Vec::new(),
+            event_types: Vec::new(),
+            min_severity: EventSeverity::Info,
+            first_per_run: false,
+            max_retries: 3,
+            retry_delay_secs: 1.0,
+            timeout_secs: 10.0,
+        }
+    }
+}
+
+impl NotificationSinkConfig {
+    /// Check that the sink has the settings that its type requires
+    pub fn validate(&self) -> Result<(), String> {
+        match self.sink_type {
+            NotificationSinkType::Webhook | NotificationSinkType::Slack => match &self.url {
+                Some(url) if url.starts_with("http://") || url.starts_with("https://") => {}
+                Some(url) => {
+                    return Err(format!(
+                        "{} sink url must start with http:// or https://, got '{}'",
+                        self.sink_type, url
+                    ));
+                }
+                None => return Err(format!("{} sink requires a url", self.sink_type)),
+            },
+            NotificationSinkType::Command => {
+                if self.command.as_deref().is_none_or(|c| c.trim().is_empty()) {
+                    return Err("command sink requires a command".to_string());
+                }
+            }
+        }
+        if self.retry_delay_secs < 0.0 {
+            return Err("retry_delay_secs cannot be negative".to_string());
+        }
+        if self.timeout_secs <= 0.0 {
+            return Err("timeout_secs must be positive".to_string());
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,6 +239,67 @@ mod tests {
         assert!(!config.require_auth);
         assert!(!config.enforce_access_control);
         assert_eq!(config.completion_check_interval_secs, 30.0);
+        assert_eq!(config.worker_check_interval_secs, 30.0);
+        assert_eq!(config.dead_worker_max_retries, 3);
+        assert_eq!(config.schedule_check_interval_secs, 30.0);
+        assert_eq!(config.api_token_refresh_interval_secs, 10.0);
+        assert_eq!(config.job_log_max_bytes, 10 * 1024 * 1024);
+        assert_eq!(config.torc_bin, "torc");
+        assert!(config.notifications.is_empty());
+    }
+
+    #[test]
+    fn test_notification_sinks_from_toml() {
+        let config: ServerConfig = toml::from_str(
+            r#"
+            [[notifications]]
+            type = "slack"
+            url = "https://hooks.example.com/T000"
+            event_types = ["workflow_completed", "job_failed"]
+            first_per_run = true
+
+            [[notifications]]
+            type = "command"
+            command = "logger -t torc"
+            min_severity = "error"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.notifications.len(), 2);
+        let slack = &config.notifications[0];
+        assert_eq!(slack.sink_type, NotificationSinkType::Slack);
+        assert!(slack.first_per_run);
+        assert_eq!(slack.min_severity, EventSeverity::Info);
+        assert_eq!(slack.max_retries, 3);
+        assert!(slack.validate().is_ok());
+        let command = &config.notifications[1];
+        assert_eq!(command.sink_type, NotificationSinkType::Command);
+        assert_eq!(command.min_severity, EventSeverity::Error);
+        assert!(command.validate().is_ok());
+    }
+
+    #[test]
+    fn test_notification_sink_validation() {
+        let webhook = NotificationSinkConfig::default();
+        assert!(webhook.validate().unwrap_err().contains("requires a url"));
+
+        let webhook = NotificationSinkConfig {
+            url: Some("hooks.example.com".to_string()),
+            ..Default::default()
+        };
+        assert!(webhook.validate().unwrap_err().contains("http://"));
+
+        let command = NotificationSinkConfig {
+            sink_type: NotificationSinkType::Command,
+            command: Some(" ".to_string()),
+            ..Default::default()
+        };
+        assert!(
+            command
+                .validate()
+                .unwrap_err()
+                .contains("requires a command")
+        );
     }
 
     #[test]
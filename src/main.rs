@@ -21,7 +21,9 @@ use torc::client::commands::reports::handle_report_commands;
 use torc::client::commands::resource_requirements::handle_resource_requirements_commands;
 use torc::client::commands::results::handle_result_commands;
 use torc::client::commands::scheduled_compute_nodes::handle_scheduled_compute_node_commands;
+use torc::client::commands::schedules::handle_schedule_commands;
 use torc::client::commands::slurm::handle_slurm_commands;
+use torc::client::commands::tokens::handle_token_commands;
 use torc::client::commands::user_data::handle_user_data_commands;
 use torc::client::commands::watch::{WatchArgs, run_watch};
 use torc::client::commands::workflows::handle_workflow_commands;
@@ -118,6 +120,7 @@ fn main() {
             .unwrap_or_else(|_| "unknown".to_string());
         config.basic_auth = Some((username, Some(password)));
     }
+    config.bearer_access_token = cli.api_token.clone();
 
     // Check server version for commands that communicate with the server
     // Skip for local-only commands or if --skip-version-check is set
@@ -152,6 +155,8 @@ fn main() {
             poll_interval,
             output_dir,
             skip_checks,
+            stage_from,
+            stage_root,
         } => {
             let workflow_id = if is_spec_file(workflow_spec_or_id) {
                 // Create workflow from spec file
@@ -211,6 +216,9 @@ fn main() {
                 cpu_affinity_cpus_per_job: None,
                 log_level: log_level.clone(),
                 password,
+                api_token: config.bearer_access_token.clone(),
+                stage_from: stage_from.clone(),
+                stage_root: stage_root.clone(),
             };
 
             run_jobs_cmd::run(&args);
@@ -358,6 +366,7 @@ fn main() {
             use torc::client::commands::slurm::{
                 WalltimeStrategy, generate_schedulers_for_workflow,
             };
+            use torc::client::hpc::cost::total_au;
 
             // Load the workflow spec
             let mut spec = match WorkflowSpec::from_spec_file(workflow_spec) {
@@ -434,6 +443,12 @@ fn main() {
                     for warning in &result.warnings {
                         eprintln!("  Warning: {}", warning);
                     }
+                    if !result.cost_estimates.is_empty() {
+                        eprintln!(
+                            "Estimated cost if all allocations run for their full walltime: {:.2} AU",
+                            total_au(&result.cost_estimates)
+                        );
+                    }
                 }
                 Err(e) => {
                     eprintln!("Error: {}", e);
@@ -682,6 +697,9 @@ fn main() {
         Commands::FailureHandlers { command } => {
             handle_failure_handler_commands(&config, command, &format);
         }
+        Commands::Schedules { command } => {
+            handle_schedule_commands(&config, command, &format);
+        }
         Commands::Events { command } => {
             handle_event_commands(&config, command, &format);
         }
@@ -712,6 +730,9 @@ fn main() {
         Commands::AccessGroups { command } => {
             handle_access_group_commands(&config, command, &format);
         }
+        Commands::Tokens { command } => {
+            handle_token_commands(&config, command, &format);
+        }
         Commands::Config { command } => {
             handle_config_commands(command);
         }
@@ -294,6 +294,17 @@ pub struct ComputeNodeModel {
     #[serde(rename = "scheduler")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scheduler: Option<serde_json::Value>,
+
+    /// Time of the most recent heartbeat from the compute node's job runner (RFC3339)
+    #[serde(rename = "last_heartbeat")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_heartbeat: Option<String>,
+
+    /// ID of the multi-node job that this compute node's job runner is reserved for. A reserved
+    /// job runner does not claim jobs; the job runs on its node instead.
+    #[serde(rename = "reserved_for_job_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reserved_for_job_id: Option<i64>,
 }
 
 impl ComputeNodeModel {
@@ -326,6 +337,8 @@ impl ComputeNodeModel {
             scheduler_config_id: None,
             compute_node_type,
             scheduler,
+            last_heartbeat: None,
+            reserved_for_job_id: None,
         }
     }
 }
@@ -373,6 +386,18 @@ impl std::string::ToString for ComputeNodeModel {
                     .join(",")
                 }),
             // Skipping non-primitive type scheduler in query parameter serialization
+            self.last_heartbeat.as_ref().map(|last_heartbeat| {
+                ["last_heartbeat".to_string(), last_heartbeat.to_string()].join(",")
+            }),
+            self.reserved_for_job_id
+                .as_ref()
+                .map(|reserved_for_job_id| {
+                    [
+                        "reserved_for_job_id".to_string(),
+                        reserved_for_job_id.to_string(),
+                    ]
+                    .join(",")
+                }),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
@@ -405,6 +430,8 @@ impl std::str::FromStr for ComputeNodeModel {
             pub scheduler_config_id: Vec<i64>,
             pub compute_node_type: Vec<String>,
             pub scheduler: Vec<serde_json::Value>,
+            pub last_heartbeat: Vec<String>,
+            pub reserved_for_job_id: Vec<i64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -472,6 +499,12 @@ impl std::str::FromStr for ComputeNodeModel {
                         <serde_json::Value as std::str::FromStr>::from_str(val)
                             .map_err(|x| x.to_string())?,
                     ),
+                    "last_heartbeat" => intermediate_rep.last_heartbeat.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "reserved_for_job_id" => intermediate_rep.reserved_for_job_id.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     _ => {
                         return std::result::Result::Err(
                             "Unexpected key while parsing ComputeNodeModel".to_string(),
@@ -537,6 +570,8 @@ impl std::str::FromStr for ComputeNodeModel {
                 .next()
                 .ok_or_else(|| "compute_node_type missing in ComputeNodeModel".to_string())?,
             scheduler: intermediate_rep.scheduler.into_iter().next(),
+            last_heartbeat: intermediate_rep.last_heartbeat.into_iter().next(),
+            reserved_for_job_id: intermediate_rep.reserved_for_job_id.into_iter().next(),
         })
     }
 }
@@ -1694,6 +1729,16 @@ pub struct FileModel {
     #[serde(rename = "st_mtime")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub st_mtime: Option<f64>,
+
+    /// Detect changes to this file by content hash instead of modification time
+    #[serde(rename = "use_checksum")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_checksum: Option<bool>,
+
+    /// Content hash of the file, recorded when use_checksum is enabled
+    #[serde(rename = "content_hash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
 }
 
 impl FileModel {
@@ -1705,6 +1750,8 @@ impl FileModel {
             name,
             path,
             st_mtime: None,
+            use_checksum: Some(false),
+            content_hash: None,
         }
     }
 }
@@ -1727,6 +1774,12 @@ impl std::string::ToString for FileModel {
             self.st_mtime
                 .as_ref()
                 .map(|st_mtime| ["st_mtime".to_string(), st_mtime.to_string()].join(",")),
+            self.use_checksum.as_ref().map(|use_checksum| {
+                ["use_checksum".to_string(), use_checksum.to_string()].join(",")
+            }),
+            self.content_hash.as_ref().map(|content_hash| {
+                ["content_hash".to_string(), content_hash.to_string()].join(",")
+            }),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
@@ -1749,6 +1802,8 @@ impl std::str::FromStr for FileModel {
             pub name: Vec<String>,
             pub path: Vec<String>,
             pub st_mtime: Vec<f64>,
+            pub use_checksum: Vec<bool>,
+            pub content_hash: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1785,6 +1840,12 @@ impl std::str::FromStr for FileModel {
                     "st_mtime" => intermediate_rep.st_mtime.push(
                         <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    "use_checksum" => intermediate_rep.use_checksum.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "content_hash" => intermediate_rep.content_hash.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     _ => {
                         return std::result::Result::Err(
                             "Unexpected key while parsing FileModel".to_string(),
@@ -1816,6 +1877,8 @@ impl std::str::FromStr for FileModel {
                 .next()
                 .ok_or_else(|| "path missing in FileModel".to_string())?,
             st_mtime: intermediate_rep.st_mtime.into_iter().next(),
+            use_checksum: intermediate_rep.use_checksum.into_iter().next(),
+            content_hash: intermediate_rep.content_hash.into_iter().next(),
         })
     }
 }
@@ -2016,6 +2079,392 @@ impl ListFailureHandlersResponse {
     }
 }
 
+/// What a workflow schedule does when it fires while the previous run is still active.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+    Hash,
+    Default,
+)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum ScheduleOverlapPolicy {
+    /// Drop the firing and wait for the next scheduled time.
+    #[serde(rename = "skip")]
+    #[default]
+    Skip,
+    /// Start the new run as soon as the previous run finishes.
+    #[serde(rename = "queue")]
+    Queue,
+}
+
+impl std::fmt::Display for ScheduleOverlapPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ScheduleOverlapPolicy::Skip => write!(f, "skip"),
+            ScheduleOverlapPolicy::Queue => write!(f, "queue"),
+        }
+    }
+}
+
+impl std::str::FromStr for ScheduleOverlapPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "skip" => std::result::Result::Ok(ScheduleOverlapPolicy::Skip),
+            "queue" => std::result::Result::Ok(ScheduleOverlapPolicy::Queue),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct WorkflowScheduleModel {
+    /// Database ID of this record.
+    #[serde(rename = "id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Database ID of the workflow this record is associated with.
+    #[serde(rename = "workflow_id")]
+    pub workflow_id: i64,
+
+    /// Cron expression for the run times, with an optional leading seconds field
+    #[serde(rename = "cron_expression")]
+    pub cron_expression: String,
+
+    /// IANA time zone in which the cron expression is evaluated (defaults to UTC)
+    #[serde(rename = "timezone")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
+    /// What to do when the schedule fires while the previous run is still active
+    #[serde(rename = "overlap_policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overlap_policy: Option<models::ScheduleOverlapPolicy>,
+
+    /// Absolute path of an existing directory on the server host in which the server runs the
+    /// reinitialize and submit commands
+    #[serde(rename = "working_directory")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_directory: Option<String>,
+
+    /// Paused schedules do not fire
+    #[serde(rename = "is_paused")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_paused: Option<bool>,
+
+    /// Time of the next firing (RFC3339), set by the server
+    #[serde(rename = "next_run_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_run_time: Option<String>,
+
+    /// Time of the most recent firing (RFC3339), set by the server
+    #[serde(rename = "last_run_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_run_time: Option<String>,
+}
+
+impl WorkflowScheduleModel {
+    #[allow(clippy::new_without_default)]
+    pub fn new(workflow_id: i64, cron_expression: String) -> WorkflowScheduleModel {
+        WorkflowScheduleModel {
+            id: None,
+            workflow_id,
+            cron_expression,
+            timezone: None,
+            overlap_policy: None,
+            working_directory: None,
+            is_paused: None,
+            next_run_time: None,
+            last_run_time: None,
+        }
+    }
+}
+
+/// Converts the WorkflowScheduleModel value to the Query Parameters representation (style=form, explode=false)
+impl std::string::ToString for WorkflowScheduleModel {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            self.id
+                .as_ref()
+                .map(|id| ["id".to_string(), id.to_string()].join(",")),
+            Some("workflow_id".to_string()),
+            Some(self.workflow_id.to_string()),
+            Some("cron_expression".to_string()),
+            Some(self.cron_expression.to_string()),
+            self.timezone
+                .as_ref()
+                .map(|timezone| ["timezone".to_string(), timezone.to_string()].join(",")),
+            // Skipping non-primitive type overlap_policy in query parameter serialization
+            self.working_directory.as_ref().map(|working_directory| {
+                [
+                    "working_directory".to_string(),
+                    working_directory.to_string(),
+                ]
+                .join(",")
+            }),
+            self.is_paused
+                .as_ref()
+                .map(|is_paused| ["is_paused".to_string(), is_paused.to_string()].join(",")),
+            self.next_run_time.as_ref().map(|next_run_time| {
+                ["next_run_time".to_string(), next_run_time.to_string()].join(",")
+            }),
+            self.last_run_time.as_ref().map(|last_run_time| {
+                ["last_run_time".to_string(), last_run_time.to_string()].join(",")
+            }),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ListWorkflowSchedulesResponse {
+    #[serde(rename = "items")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<WorkflowScheduleModel>>,
+
+    #[serde(rename = "offset")]
+    pub offset: i64,
+
+    #[serde(rename = "max_limit")]
+    pub max_limit: i64,
+
+    #[serde(rename = "count")]
+    pub count: i64,
+
+    #[serde(rename = "total_count")]
+    pub total_count: i64,
+
+    #[serde(rename = "has_more")]
+    pub has_more: bool,
+}
+
+impl ListWorkflowSchedulesResponse {
+    #[allow(clippy::new_without_default)]
+    pub fn new(
+        offset: i64,
+        max_limit: i64,
+        count: i64,
+        total_count: i64,
+        has_more: bool,
+    ) -> ListWorkflowSchedulesResponse {
+        ListWorkflowSchedulesResponse {
+            items: None,
+            offset,
+            max_limit,
+            count,
+            total_count,
+            has_more,
+        }
+    }
+}
+
+/// Output stream of a job's log.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+    Hash,
+    Default,
+)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum JobLogStream {
+    /// Standard output of the job
+    #[serde(rename = "stdout")]
+    #[default]
+    Stdout,
+    /// Standard error of the job
+    #[serde(rename = "stderr")]
+    Stderr,
+}
+
+impl std::fmt::Display for JobLogStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            JobLogStream::Stdout => write!(f, "stdout"),
+            JobLogStream::Stderr => write!(f, "stderr"),
+        }
+    }
+}
+
+impl std::str::FromStr for JobLogStream {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "stdout" => std::result::Result::Ok(JobLogStream::Stdout),
+            "stderr" => std::result::Result::Ok(JobLogStream::Stderr),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct JobLogModel {
+    /// Database ID of this record.
+    #[serde(rename = "id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Database ID of the workflow this record is associated with.
+    #[serde(rename = "workflow_id")]
+    pub workflow_id: i64,
+
+    /// Database ID of the job that wrote the log
+    #[serde(rename = "job_id")]
+    pub job_id: i64,
+
+    /// Workflow run in which the job wrote the log
+    #[serde(rename = "run_id")]
+    pub run_id: i64,
+
+    /// Attempt of the job that wrote the log (defaults to 1)
+    #[serde(rename = "attempt_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempt_id: Option<i64>,
+
+    #[serde(rename = "stream")]
+    pub stream: models::JobLogStream,
+
+    /// Text of the log, or its end if the log was truncated
+    #[serde(rename = "content")]
+    pub content: String,
+
+    /// Size in bytes of the whole log file on the worker (defaults to the size of content)
+    #[serde(rename = "size_bytes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<i64>,
+
+    /// True if content holds only the end of the log
+    #[serde(rename = "is_truncated")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_truncated: Option<bool>,
+
+    /// Time at which the log was uploaded (RFC3339), set by the server
+    #[serde(rename = "timestamp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+}
+
+impl JobLogModel {
+    #[allow(clippy::new_without_default)]
+    pub fn new(
+        workflow_id: i64,
+        job_id: i64,
+        run_id: i64,
+        stream: models::JobLogStream,
+        content: String,
+    ) -> JobLogModel {
+        JobLogModel {
+            id: None,
+            workflow_id,
+            job_id,
+            run_id,
+            attempt_id: None,
+            stream,
+            content,
+            size_bytes: None,
+            is_truncated: None,
+            timestamp: None,
+        }
+    }
+}
+
+/// Converts the JobLogModel value to the Query Parameters representation (style=form, explode=false)
+impl std::string::ToString for JobLogModel {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            self.id
+                .as_ref()
+                .map(|id| ["id".to_string(), id.to_string()].join(",")),
+            Some("workflow_id".to_string()),
+            Some(self.workflow_id.to_string()),
+            Some("job_id".to_string()),
+            Some(self.job_id.to_string()),
+            Some("run_id".to_string()),
+            Some(self.run_id.to_string()),
+            self.attempt_id
+                .as_ref()
+                .map(|attempt_id| ["attempt_id".to_string(), attempt_id.to_string()].join(",")),
+            // Skipping non-primitive type stream in query parameter serialization
+            Some("content".to_string()),
+            Some(self.content.to_string()),
+            self.size_bytes
+                .as_ref()
+                .map(|size_bytes| ["size_bytes".to_string(), size_bytes.to_string()].join(",")),
+            self.is_truncated.as_ref().map(|is_truncated| {
+                ["is_truncated".to_string(), is_truncated.to_string()].join(",")
+            }),
+            self.timestamp
+                .as_ref()
+                .map(|timestamp| ["timestamp".to_string(), timestamp.to_string()].join(",")),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ListJobLogsResponse {
+    #[serde(rename = "items")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<JobLogModel>>,
+
+    #[serde(rename = "offset")]
+    pub offset: i64,
+
+    #[serde(rename = "max_limit")]
+    pub max_limit: i64,
+
+    #[serde(rename = "count")]
+    pub count: i64,
+
+    #[serde(rename = "total_count")]
+    pub total_count: i64,
+
+    #[serde(rename = "has_more")]
+    pub has_more: bool,
+}
+
+impl ListJobLogsResponse {
+    #[allow(clippy::new_without_default)]
+    pub fn new(
+        offset: i64,
+        max_limit: i64,
+        count: i64,
+        total_count: i64,
+        has_more: bool,
+    ) -> ListJobLogsResponse {
+        ListJobLogsResponse {
+            items: None,
+            offset,
+            max_limit,
+            count,
+            total_count,
+            has_more,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct GetDotGraphResponse {
@@ -2695,35 +3144,129 @@ impl std::convert::TryFrom<header::IntoHeaderValue<Vec<IsCompleteResponse>>>
     }
 }
 
-#[cfg(feature = "server")]
-impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<Vec<IsCompleteResponse>>
-{
-    type Error = String;
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<Vec<IsCompleteResponse>>
+{
+    type Error = String;
+
+    fn try_from(hdr_values: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_values.to_str() {
+            std::result::Result::Ok(hdr_values) => {
+                let hdr_values : std::vec::Vec<IsCompleteResponse> = hdr_values
+                .split(',')
+                .filter_map(|hdr_value| match hdr_value.trim() {
+                    "" => std::option::Option::None,
+                    hdr_value => std::option::Option::Some({
+                        match <IsCompleteResponse as std::str::FromStr>::from_str(hdr_value) {
+                            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+                            std::result::Result::Err(err) => std::result::Result::Err(
+                                format!("Unable to convert header value '{}' into IsCompleteResponse - {}",
+                                    hdr_value, err))
+                        }
+                    })
+                }).collect::<std::result::Result<std::vec::Vec<_>, String>>()?;
+
+                std::result::Result::Ok(header::IntoHeaderValue(hdr_values))
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to parse header: {:?} as a string - {}",
+                hdr_values, e
+            )),
+        }
+    }
+}
+
+/// Program used to run a job's container.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+    Hash,
+    Default,
+)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum ContainerRuntime {
+    #[serde(rename = "apptainer")]
+    #[default]
+    Apptainer,
+    #[serde(rename = "singularity")]
+    Singularity,
+    #[serde(rename = "podman")]
+    Podman,
+    #[serde(rename = "docker")]
+    Docker,
+}
+
+impl std::fmt::Display for ContainerRuntime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ContainerRuntime::Apptainer => write!(f, "apptainer"),
+            ContainerRuntime::Singularity => write!(f, "singularity"),
+            ContainerRuntime::Podman => write!(f, "podman"),
+            ContainerRuntime::Docker => write!(f, "docker"),
+        }
+    }
+}
+
+impl std::str::FromStr for ContainerRuntime {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "apptainer" => std::result::Result::Ok(ContainerRuntime::Apptainer),
+            "singularity" => std::result::Result::Ok(ContainerRuntime::Singularity),
+            "podman" => std::result::Result::Ok(ContainerRuntime::Podman),
+            "docker" => std::result::Result::Ok(ContainerRuntime::Docker),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+/// Container in which a job's command runs.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct JobContainerModel {
+    /// Container image, such as `docker://python:3.12`, a path to a `.sif` file, or `python:3.12`
+    #[serde(rename = "image")]
+    pub image: String,
+
+    /// Program used to run the container. Defaults to apptainer.
+    #[serde(rename = "runtime")]
+    #[serde(default)]
+    pub runtime: ContainerRuntime,
+
+    /// Host paths to mount in the container, as `path` or `host_path:container_path[:options]`
+    #[serde(rename = "bind_mounts")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bind_mounts: Option<Vec<String>>,
 
-    fn try_from(hdr_values: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
-        match hdr_values.to_str() {
-            std::result::Result::Ok(hdr_values) => {
-                let hdr_values : std::vec::Vec<IsCompleteResponse> = hdr_values
-                .split(',')
-                .filter_map(|hdr_value| match hdr_value.trim() {
-                    "" => std::option::Option::None,
-                    hdr_value => std::option::Option::Some({
-                        match <IsCompleteResponse as std::str::FromStr>::from_str(hdr_value) {
-                            std::result::Result::Ok(value) => std::result::Result::Ok(value),
-                            std::result::Result::Err(err) => std::result::Result::Err(
-                                format!("Unable to convert header value '{}' into IsCompleteResponse - {}",
-                                    hdr_value, err))
-                        }
-                    })
-                }).collect::<std::result::Result<std::vec::Vec<_>, String>>()?;
+    /// Make the host's NVIDIA GPUs available in the container
+    #[serde(rename = "gpus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gpus: Option<bool>,
 
-                std::result::Result::Ok(header::IntoHeaderValue(hdr_values))
-            }
-            std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Unable to parse header: {:?} as a string - {}",
-                hdr_values, e
-            )),
+    /// Extra arguments passed to the runtime before the image
+    #[serde(rename = "args")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub args: Option<Vec<String>>,
+}
+
+impl JobContainerModel {
+    #[allow(clippy::new_without_default)]
+    pub fn new(image: String) -> JobContainerModel {
+        JobContainerModel {
+            image,
+            runtime: ContainerRuntime::default(),
+            bind_mounts: None,
+            gpus: None,
+            args: None,
         }
     }
 }
@@ -2812,6 +3355,53 @@ pub struct JobModel {
     #[serde(rename = "attempt_id")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attempt_id: Option<i64>,
+
+    /// Claim priority. Jobs with higher values are claimed first when the priority sort method is used.
+    #[serde(rename = "priority")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+
+    /// Environment variables to set for the job's process
+    #[serde(rename = "env")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env: Option<std::collections::HashMap<String, String>>,
+
+    /// Directory in which to run the job's command. Defaults to the job runner's working directory.
+    #[serde(rename = "working_dir")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<String>,
+
+    /// Program used to run the job's command, such as bash, sh, or zsh. Defaults to bash (cmd on Windows).
+    #[serde(rename = "shell")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shell: Option<String>,
+
+    /// Container in which to run the job's command
+    #[serde(rename = "container")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<JobContainerModel>,
+
+    /// Condition that must hold for the job to run. If it is false, the job is skipped.
+    #[serde(rename = "run_if")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_if: Option<String>,
+
+    /// Name of the workflow's concurrency pool that limits how many jobs like this one can be
+    /// pending or running at the same time.
+    #[serde(rename = "concurrency_pool")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concurrency_pool: Option<String>,
+
+    /// Time (RFC 3339) before which a retried job will not be claimed. Set by the server.
+    #[serde(rename = "not_before")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_before: Option<String>,
+
+    /// Host where the previous attempt failed. Workers on this host do not claim the job.
+    /// Set by the server.
+    #[serde(rename = "excluded_hostname")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excluded_hostname: Option<String>,
 }
 
 impl JobModel {
@@ -2836,6 +3426,15 @@ impl JobModel {
             scheduler_id: None,
             failure_handler_id: None,
             attempt_id: Some(1),
+            priority: Some(0),
+            env: None,
+            working_dir: None,
+            shell: None,
+            container: None,
+            run_if: None,
+            concurrency_pool: None,
+            not_before: None,
+            excluded_hostname: None,
         }
     }
 }
@@ -2953,6 +3552,33 @@ impl std::string::ToString for JobModel {
             self.scheduler_id.as_ref().map(|scheduler_id| {
                 ["scheduler_id".to_string(), scheduler_id.to_string()].join(",")
             }),
+            self.priority
+                .as_ref()
+                .map(|priority| ["priority".to_string(), priority.to_string()].join(",")),
+            // Skipping map env in query parameter serialization
+            self.working_dir
+                .as_ref()
+                .map(|working_dir| ["working_dir".to_string(), working_dir.to_string()].join(",")),
+            self.shell
+                .as_ref()
+                .map(|shell| ["shell".to_string(), shell.to_string()].join(",")),
+            // Skipping non-primitive type container in query parameter serialization
+            self.run_if
+                .as_ref()
+                .map(|run_if| ["run_if".to_string(), run_if.to_string()].join(",")),
+            self.concurrency_pool.as_ref().map(|concurrency_pool| {
+                ["concurrency_pool".to_string(), concurrency_pool.to_string()].join(",")
+            }),
+            self.not_before
+                .as_ref()
+                .map(|not_before| ["not_before".to_string(), not_before.to_string()].join(",")),
+            self.excluded_hostname.as_ref().map(|excluded_hostname| {
+                [
+                    "excluded_hostname".to_string(),
+                    excluded_hostname.to_string(),
+                ]
+                .join(",")
+            }),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
@@ -2988,6 +3614,15 @@ impl std::str::FromStr for JobModel {
             pub scheduler_id: Vec<i64>,
             pub failure_handler_id: Vec<i64>,
             pub attempt_id: Vec<i64>,
+            pub priority: Vec<i64>,
+            pub env: Vec<std::collections::HashMap<String, String>>,
+            pub working_dir: Vec<String>,
+            pub shell: Vec<String>,
+            pub container: Vec<JobContainerModel>,
+            pub run_if: Vec<String>,
+            pub concurrency_pool: Vec<String>,
+            pub not_before: Vec<String>,
+            pub excluded_hostname: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3083,6 +3718,39 @@ impl std::str::FromStr for JobModel {
                     "attempt_id" => intermediate_rep.attempt_id.push(
                         <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    "priority" => intermediate_rep.priority.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "env" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in JobModel"
+                                .to_string(),
+                        );
+                    }
+                    "working_dir" => intermediate_rep.working_dir.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "shell" => intermediate_rep.shell.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "container" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in JobModel"
+                                .to_string(),
+                        );
+                    }
+                    "run_if" => intermediate_rep.run_if.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "concurrency_pool" => intermediate_rep.concurrency_pool.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "not_before" => intermediate_rep.not_before.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "excluded_hostname" => intermediate_rep.excluded_hostname.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     _ => {
                         return std::result::Result::Err(
                             "Unexpected key while parsing JobModel".to_string(),
@@ -3130,6 +3798,15 @@ impl std::str::FromStr for JobModel {
             scheduler_id: intermediate_rep.scheduler_id.into_iter().next(),
             failure_handler_id: intermediate_rep.failure_handler_id.into_iter().next(),
             attempt_id: intermediate_rep.attempt_id.into_iter().next(),
+            priority: intermediate_rep.priority.into_iter().next(),
+            env: intermediate_rep.env.into_iter().next(),
+            working_dir: intermediate_rep.working_dir.into_iter().next(),
+            shell: intermediate_rep.shell.into_iter().next(),
+            container: intermediate_rep.container.into_iter().next(),
+            run_if: intermediate_rep.run_if.into_iter().next(),
+            concurrency_pool: intermediate_rep.concurrency_pool.into_iter().next(),
+            not_before: intermediate_rep.not_before.into_iter().next(),
+            excluded_hostname: intermediate_rep.excluded_hostname.into_iter().next(),
         })
     }
 }
@@ -3279,6 +3956,8 @@ pub enum JobStatus {
     Disabled,
     #[serde(rename = "pending_failed")]
     PendingFailed,
+    #[serde(rename = "skipped")]
+    Skipped,
 }
 
 impl std::fmt::Display for JobStatus {
@@ -3295,6 +3974,7 @@ impl std::fmt::Display for JobStatus {
             JobStatus::Terminated => write!(f, "terminated"),
             JobStatus::Disabled => write!(f, "disabled"),
             JobStatus::PendingFailed => write!(f, "pending_failed"),
+            JobStatus::Skipped => write!(f, "skipped"),
         }
     }
 }
@@ -3315,6 +3995,7 @@ impl std::str::FromStr for JobStatus {
             "terminated" => std::result::Result::Ok(JobStatus::Terminated),
             "disabled" => std::result::Result::Ok(JobStatus::Disabled),
             "pending_failed" => std::result::Result::Ok(JobStatus::PendingFailed),
+            "skipped" => std::result::Result::Ok(JobStatus::Skipped),
             _ => std::result::Result::Err(format!("Value not valid: {}", s)),
         }
     }
@@ -3338,6 +4019,7 @@ impl JobStatus {
             JobStatus::Terminated => 8,
             JobStatus::Disabled => 9,
             JobStatus::PendingFailed => 10,
+            JobStatus::Skipped => 11,
         }
     }
 
@@ -3355,6 +4037,7 @@ impl JobStatus {
             8 => Ok(JobStatus::Terminated),
             9 => Ok(JobStatus::Disabled),
             10 => Ok(JobStatus::PendingFailed),
+            11 => Ok(JobStatus::Skipped),
             _ => Err(format!("Invalid JobStatus integer value: {}", value)),
         }
     }
@@ -3385,6 +4068,7 @@ impl JobStatusMap {
             map.insert(JobStatus::Terminated, 8);
             map.insert(JobStatus::Disabled, 9);
             map.insert(JobStatus::PendingFailed, 10);
+            map.insert(JobStatus::Skipped, 11);
             map
         })
     }
@@ -3405,6 +4089,7 @@ impl JobStatusMap {
             map.insert(8, JobStatus::Terminated);
             map.insert(9, JobStatus::Disabled);
             map.insert(10, JobStatus::PendingFailed);
+            map.insert(11, JobStatus::Skipped);
             map
         })
     }
@@ -3529,7 +4214,7 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 impl JobStatus {
     /// Returns true if the job status indicates the job has finished executing
     /// and reached a terminal state that can be set via complete_job API.
-    /// This includes: Completed, Failed, Canceled, Terminated, PendingFailed
+    /// This includes: Completed, Failed, Canceled, Terminated, PendingFailed, Skipped
     pub fn is_terminal(&self) -> bool {
         matches!(
             self,
@@ -3538,17 +4223,22 @@ impl JobStatus {
                 | JobStatus::Canceled
                 | JobStatus::Terminated
                 | JobStatus::PendingFailed
+                | JobStatus::Skipped
         )
     }
 
     /// Returns true if the job status indicates the workflow can progress.
     /// PendingFailed is NOT considered complete for workflow progression purposes
     /// because it's awaiting AI classification.
-    /// Complete statuses: Completed, Failed, Canceled, Terminated
+    /// Complete statuses: Completed, Failed, Canceled, Terminated, Skipped
     pub fn is_complete(&self) -> bool {
         matches!(
             self,
-            JobStatus::Completed | JobStatus::Failed | JobStatus::Canceled | JobStatus::Terminated
+            JobStatus::Completed
+                | JobStatus::Failed
+                | JobStatus::Canceled
+                | JobStatus::Terminated
+                | JobStatus::Skipped
         )
     }
 }
@@ -3564,6 +4254,7 @@ mod tests {
         assert!(JobStatus::Failed.is_complete());
         assert!(JobStatus::Canceled.is_complete());
         assert!(JobStatus::Terminated.is_complete());
+        assert!(JobStatus::Skipped.is_complete());
 
         // PendingFailed is NOT complete (workflow cannot progress)
         assert!(!JobStatus::PendingFailed.is_complete());
@@ -3585,6 +4276,7 @@ mod tests {
         assert!(JobStatus::Canceled.is_terminal());
         assert!(JobStatus::Terminated.is_terminal());
         assert!(JobStatus::PendingFailed.is_terminal());
+        assert!(JobStatus::Skipped.is_terminal());
 
         // Test non-terminal statuses (still executing or not started)
         assert!(!JobStatus::Uninitialized.is_terminal());
@@ -3609,6 +4301,7 @@ mod tests {
         assert_eq!(JobStatus::Terminated.to_int(), 8);
         assert_eq!(JobStatus::Disabled.to_int(), 9);
         assert_eq!(JobStatus::PendingFailed.to_int(), 10);
+        assert_eq!(JobStatus::Skipped.to_int(), 11);
     }
 
     #[test]
@@ -3625,9 +4318,10 @@ mod tests {
         assert_eq!(JobStatus::from_int(8).unwrap(), JobStatus::Terminated);
         assert_eq!(JobStatus::from_int(9).unwrap(), JobStatus::Disabled);
         assert_eq!(JobStatus::from_int(10).unwrap(), JobStatus::PendingFailed);
+        assert_eq!(JobStatus::from_int(11).unwrap(), JobStatus::Skipped);
 
         // Test invalid integer
-        assert!(JobStatus::from_int(11).is_err());
+        assert!(JobStatus::from_int(12).is_err());
         assert!(JobStatus::from_int(-1).is_err());
     }
 
@@ -3657,6 +4351,8 @@ mod tests {
             JobStatus::Canceled,
             JobStatus::Terminated,
             JobStatus::Disabled,
+            JobStatus::PendingFailed,
+            JobStatus::Skipped,
         ];
 
         for variant in &variants {
@@ -7601,6 +8297,8 @@ pub enum ClaimJobsSortMethod {
     GpusRuntimeMemory,
     #[serde(rename = "gpus_memory_runtime")]
     GpusMemoryRuntime,
+    #[serde(rename = "priority")]
+    Priority,
     #[serde(rename = "none")]
     #[default]
     None,
@@ -7611,6 +8309,7 @@ impl std::fmt::Display for ClaimJobsSortMethod {
         match *self {
             ClaimJobsSortMethod::GpusRuntimeMemory => write!(f, "gpus_runtime_memory"),
             ClaimJobsSortMethod::GpusMemoryRuntime => write!(f, "gpus_memory_runtime"),
+            ClaimJobsSortMethod::Priority => write!(f, "priority"),
             ClaimJobsSortMethod::None => write!(f, "none"),
         }
     }
@@ -7627,6 +8326,7 @@ impl std::str::FromStr for ClaimJobsSortMethod {
             "gpus_memory_runtime" => {
                 std::result::Result::Ok(ClaimJobsSortMethod::GpusMemoryRuntime)
             }
+            "priority" => std::result::Result::Ok(ClaimJobsSortMethod::Priority),
             "none" => std::result::Result::Ok(ClaimJobsSortMethod::None),
             _ => std::result::Result::Err(format!("Value not valid: {}", s)),
         }
@@ -8794,6 +9494,12 @@ pub struct ScheduledComputeNodesModel {
 
     #[serde(rename = "status")]
     pub status: String,
+
+    /// Index of the allocation within a Slurm job array. The array's job ID is stored in
+    /// `scheduler_id`. Not set for allocations that were not submitted as a job array.
+    #[serde(rename = "array_task_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub array_task_id: Option<i64>,
 }
 
 impl ScheduledComputeNodesModel {
@@ -8812,6 +9518,16 @@ impl ScheduledComputeNodesModel {
             scheduler_config_id,
             scheduler_type,
             status,
+            array_task_id: None,
+        }
+    }
+
+    /// ID of the allocation in the HPC scheduler. Tasks of a Slurm job array are identified
+    /// as `<array_job_id>_<array_task_id>`.
+    pub fn hpc_job_id(&self) -> String {
+        match self.array_task_id {
+            Some(task_id) => format!("{}_{}", self.scheduler_id, task_id),
+            None => self.scheduler_id.to_string(),
         }
     }
 }
@@ -8835,6 +9551,9 @@ impl std::string::ToString for ScheduledComputeNodesModel {
             Some(self.scheduler_type.to_string()),
             Some("status".to_string()),
             Some(self.status.to_string()),
+            self.array_task_id.as_ref().map(|array_task_id| {
+                ["array_task_id".to_string(), array_task_id.to_string()].join(",")
+            }),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
@@ -8858,6 +9577,7 @@ impl std::str::FromStr for ScheduledComputeNodesModel {
             pub scheduler_config_id: Vec<i64>,
             pub scheduler_type: Vec<String>,
             pub status: Vec<String>,
+            pub array_task_id: Vec<i64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -8897,6 +9617,9 @@ impl std::str::FromStr for ScheduledComputeNodesModel {
                     "status" => intermediate_rep.status.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    "array_task_id" => intermediate_rep.array_task_id.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     _ => {
                         return std::result::Result::Err(
                             "Unexpected key while parsing ScheduledComputeNodesModel".to_string(),
@@ -8941,6 +9664,7 @@ impl std::str::FromStr for ScheduledComputeNodesModel {
                 .into_iter()
                 .next()
                 .ok_or_else(|| "status missing in ScheduledComputeNodesModel".to_string())?,
+            array_task_id: intermediate_rep.array_task_id.into_iter().next(),
         })
     }
 }
@@ -9676,6 +10400,52 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
     }
 }
 
+/// What the server does with the jobs of a compute node that stops sending heartbeats.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+    Hash,
+    Default,
+)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum DeadWorkerPolicy {
+    /// Record the jobs as failed, which triggers their failure handlers.
+    #[serde(rename = "fail")]
+    #[default]
+    Fail,
+    /// Return the jobs to ready so that another compute node runs them.
+    #[serde(rename = "retry")]
+    Retry,
+}
+
+impl std::fmt::Display for DeadWorkerPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            DeadWorkerPolicy::Fail => write!(f, "fail"),
+            DeadWorkerPolicy::Retry => write!(f, "retry"),
+        }
+    }
+}
+
+impl std::str::FromStr for DeadWorkerPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "fail" => std::result::Result::Ok(DeadWorkerPolicy::Fail),
+            "retry" => std::result::Result::Ok(DeadWorkerPolicy::Retry),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct WorkflowModel {
@@ -9748,6 +10518,48 @@ pub struct WorkflowModel {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_pending_failed: Option<bool>,
 
+    /// Terminate jobs that run longer than the runtime in their resource requirements. Jobs that
+    /// support termination receive SIGTERM at the limit; all jobs receive SIGKILL after the grace
+    /// period. Terminated jobs are recorded as failed with return code 124.
+    #[serde(rename = "enforce_runtime_limits")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforce_runtime_limits: Option<bool>,
+
+    /// Number of seconds to wait after sending SIGTERM to a job that exceeded its runtime before
+    /// sending SIGKILL. Default is 60 seconds.
+    #[serde(rename = "runtime_limit_grace_seconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime_limit_grace_seconds: Option<i64>,
+
+    /// Number of seconds without a heartbeat after which the server considers a compute node
+    /// dead. Its running jobs are handled according to dead_worker_policy. Set to 0 to disable
+    /// dead worker detection. Default is 300 seconds.
+    #[serde(rename = "worker_heartbeat_timeout_seconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub worker_heartbeat_timeout_seconds: Option<i64>,
+
+    #[serde(rename = "dead_worker_policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dead_worker_policy: Option<models::DeadWorkerPolicy>,
+
+    /// Maximum number of jobs in the workflow that can be pending or running at the same time,
+    /// across all compute nodes. Unlimited if not set.
+    #[serde(rename = "max_concurrent_jobs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_jobs: Option<i64>,
+
+    /// Named concurrency pools. Maps each pool name to the maximum number of jobs in the pool
+    /// that can be pending or running at the same time, across all compute nodes.
+    #[serde(rename = "concurrency_pools")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concurrency_pools: Option<std::collections::HashMap<String, i64>>,
+
+    /// Maximum allocation units (AU) that HPC allocations of the workflow may cost. Clients do
+    /// not submit allocations whose estimated cost would exceed it. Unlimited if not set.
+    #[serde(rename = "au_budget")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub au_budget: Option<f64>,
+
     #[serde(rename = "status_id")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_id: Option<i64>,
@@ -9771,6 +10583,13 @@ impl WorkflowModel {
             resource_monitor_config: None,
             slurm_defaults: None,
             use_pending_failed: Some(false),
+            enforce_runtime_limits: Some(false),
+            runtime_limit_grace_seconds: Some(60),
+            worker_heartbeat_timeout_seconds: Some(300),
+            dead_worker_policy: None,
+            max_concurrent_jobs: None,
+            concurrency_pools: None,
+            au_budget: None,
             status_id: None,
         }
     }
@@ -9848,6 +10667,47 @@ impl std::string::ToString for WorkflowModel {
                 ]
                 .join(",")
             }),
+            self.enforce_runtime_limits
+                .as_ref()
+                .map(|enforce_runtime_limits| {
+                    [
+                        "enforce_runtime_limits".to_string(),
+                        enforce_runtime_limits.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.runtime_limit_grace_seconds
+                .as_ref()
+                .map(|runtime_limit_grace_seconds| {
+                    [
+                        "runtime_limit_grace_seconds".to_string(),
+                        runtime_limit_grace_seconds.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.worker_heartbeat_timeout_seconds.as_ref().map(
+                |worker_heartbeat_timeout_seconds| {
+                    [
+                        "worker_heartbeat_timeout_seconds".to_string(),
+                        worker_heartbeat_timeout_seconds.to_string(),
+                    ]
+                    .join(",")
+                },
+            ),
+            // Skipping non-primitive type dead_worker_policy in query parameter serialization
+            self.max_concurrent_jobs
+                .as_ref()
+                .map(|max_concurrent_jobs| {
+                    [
+                        "max_concurrent_jobs".to_string(),
+                        max_concurrent_jobs.to_string(),
+                    ]
+                    .join(",")
+                }),
+            // Skipping map concurrency_pools in query parameter serialization
+            self.au_budget
+                .as_ref()
+                .map(|au_budget| ["au_budget".to_string(), au_budget.to_string()].join(",")),
             self.status_id
                 .as_ref()
                 .map(|status_id| ["status_id".to_string(), status_id.to_string()].join(",")),
@@ -9882,6 +10742,12 @@ impl std::str::FromStr for WorkflowModel {
             pub resource_monitor_config: Vec<String>,
             pub slurm_defaults: Vec<String>,
             pub use_pending_failed: Vec<bool>,
+            pub enforce_runtime_limits: Vec<bool>,
+            pub runtime_limit_grace_seconds: Vec<i64>,
+            pub worker_heartbeat_timeout_seconds: Vec<i64>,
+            pub dead_worker_policy: Vec<models::DeadWorkerPolicy>,
+            pub max_concurrent_jobs: Vec<i64>,
+            pub au_budget: Vec<f64>,
             pub status_id: Vec<i64>,
         }
 
@@ -9955,6 +10821,35 @@ impl std::str::FromStr for WorkflowModel {
                     "use_pending_failed" => intermediate_rep.use_pending_failed.push(
                         <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    "enforce_runtime_limits" => intermediate_rep.enforce_runtime_limits.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "runtime_limit_grace_seconds" => {
+                        intermediate_rep.runtime_limit_grace_seconds.push(
+                            <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                        )
+                    }
+                    "worker_heartbeat_timeout_seconds" => {
+                        intermediate_rep.worker_heartbeat_timeout_seconds.push(
+                            <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                        )
+                    }
+                    "dead_worker_policy" => intermediate_rep.dead_worker_policy.push(
+                        <models::DeadWorkerPolicy as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    "max_concurrent_jobs" => intermediate_rep.max_concurrent_jobs.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "concurrency_pools" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in WorkflowModel"
+                                .to_string(),
+                        );
+                    }
+                    "au_budget" => intermediate_rep.au_budget.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     _ => {
                         return std::result::Result::Err(
                             "Unexpected key while parsing WorkflowModel".to_string(),
@@ -10006,6 +10901,19 @@ impl std::str::FromStr for WorkflowModel {
             resource_monitor_config: intermediate_rep.resource_monitor_config.into_iter().next(),
             slurm_defaults: intermediate_rep.slurm_defaults.into_iter().next(),
             use_pending_failed: intermediate_rep.use_pending_failed.into_iter().next(),
+            enforce_runtime_limits: intermediate_rep.enforce_runtime_limits.into_iter().next(),
+            runtime_limit_grace_seconds: intermediate_rep
+                .runtime_limit_grace_seconds
+                .into_iter()
+                .next(),
+            worker_heartbeat_timeout_seconds: intermediate_rep
+                .worker_heartbeat_timeout_seconds
+                .into_iter()
+                .next(),
+            dead_worker_policy: intermediate_rep.dead_worker_policy.into_iter().next(),
+            max_concurrent_jobs: intermediate_rep.max_concurrent_jobs.into_iter().next(),
+            concurrency_pools: None,
+            au_budget: intermediate_rep.au_budget.into_iter().next(),
             status_id: intermediate_rep.status_id.into_iter().next(),
         })
     }
@@ -11062,3 +11970,109 @@ pub struct AccessCheckResponse {
     #[serde(rename = "workflow_id")]
     pub workflow_id: i64,
 }
+
+// ============================================================================
+// API Token Models for bearer authentication
+// ============================================================================
+
+/// API token model - a server-issued credential for bearer authentication.
+/// The plaintext token is only returned in the response to its creation.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ApiTokenModel {
+    /// Database ID
+    #[serde(rename = "id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Descriptive name of the token (e.g., "slurm-runner" or "ci")
+    #[serde(rename = "name")]
+    pub name: String,
+
+    /// User that the token authenticates as. Defaults to the requesting user.
+    #[serde(rename = "owner")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+
+    /// Scopes granted to the token: "read" (GET requests) and/or "write" (all other requests).
+    /// Defaults to both.
+    #[serde(rename = "scopes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+
+    /// Timestamp when the token was created
+    #[serde(rename = "created_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    /// Timestamp (UTC, "YYYY-MM-DD HH:MM:SS") after which the token is no longer accepted.
+    /// Tokens without an expiration never expire.
+    #[serde(rename = "expires_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+
+    /// Whether the token has been revoked
+    #[serde(rename = "revoked")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revoked: Option<bool>,
+
+    /// Plaintext token. Only set in the response to token creation.
+    #[serde(rename = "token")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+impl ApiTokenModel {
+    #[allow(clippy::new_without_default)]
+    pub fn new(name: String) -> ApiTokenModel {
+        ApiTokenModel {
+            id: None,
+            name,
+            owner: None,
+            scopes: None,
+            created_at: None,
+            expires_at: None,
+            revoked: None,
+            token: None,
+        }
+    }
+}
+
+/// Response for listing API tokens
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ListApiTokensResponse {
+    /// List of API tokens
+    #[serde(rename = "items")]
+    pub items: Vec<ApiTokenModel>,
+
+    /// Offset used for pagination
+    #[serde(rename = "offset")]
+    pub offset: i64,
+
+    /// Limit used for pagination
+    #[serde(rename = "limit")]
+    pub limit: i64,
+
+    /// Total count of records
+    #[serde(rename = "total_count")]
+    pub total_count: i64,
+
+    /// Whether there are more records
+    #[serde(rename = "has_more")]
+    pub has_more: bool,
+}
+
+impl ListApiTokensResponse {
+    #[allow(clippy::new_without_default)]
+    pub fn new(items: Vec<ApiTokenModel>, offset: i64, limit: i64, total_count: i64) -> Self {
+        let has_more = offset + (items.len() as i64) < total_count;
+        ListApiTokensResponse {
+            items,
+            offset,
+            limit,
+            total_count,
+            has_more,
+        }
+    }
+}
@@ -4,6 +4,7 @@ use crate::client::commands::get_env_user_name;
 use crate::client::commands::select_workflow_interactively;
 use crate::client::job_runner::JobRunner;
 use crate::client::log_paths::get_job_runner_log_file;
+use crate::client::remote::FileStager;
 use crate::client::utils::detect_nvidia_gpus;
 use crate::client::workflow_manager::WorkflowManager;
 use crate::config::TorcConfig;
@@ -88,6 +89,16 @@ pub struct Args {
     /// Password for authentication (can also use TORC_PASSWORD env var)
     #[arg(long, env = "TORC_PASSWORD", hide_env_values = true)]
     pub password: Option<String>,
+    /// API token for bearer authentication (can also use TORC_API_TOKEN env var)
+    #[arg(long, env = "TORC_API_TOKEN", hide_env_values = true)]
+    pub api_token: Option<String>,
+    /// Copy job input files from, and output files back to, this host ([user@]hostname[:port])
+    /// over SSH. Use on workers that do not share a filesystem with the workflow's data.
+    #[arg(long)]
+    pub stage_from: Option<String>,
+    /// Directory on the staging host that relative file paths are resolved against
+    #[arg(long, requires = "stage_from")]
+    pub stage_root: Option<String>,
 }
 
 pub fn run(args: &Args) {
@@ -103,6 +114,13 @@ pub fn run(args: &Args) {
         let username = get_env_user_name();
         config.basic_auth = Some((username, Some(password.clone())));
     }
+    config.bearer_access_token = args.api_token.clone();
+    let file_stager = args.stage_from.as_ref().map(|host| {
+        FileStager::new(host, args.stage_root.clone()).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        })
+    });
     let user = get_env_user_name();
     let workflow_id = args.workflow_id.unwrap_or_else(|| {
         select_workflow_interactively(&config, &user).unwrap_or_else(|e| {
@@ -284,6 +302,10 @@ pub fn run(args: &Args) {
         false,
         unique_label,
     );
+    if let Some(stager) = file_stager {
+        info!("Staging job files from {}", stager.host());
+        job_runner.set_file_stager(stager);
+    }
 
     match job_runner.run_worker() {
         Ok(result) => {
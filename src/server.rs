@@ -4,15 +4,18 @@
 //! authentication, routing, and database operations.
 
 pub mod api;
+pub mod api_token_store;
 pub mod api_types;
 pub mod auth;
 pub mod authorization;
 pub mod context;
 pub mod credential_cache;
 pub mod dashboard;
+pub mod db;
 pub mod event_broadcast;
 pub mod header;
 pub mod htpasswd;
+pub mod metrics;
 pub mod routing;
 
 // Re-exports from api_types (OpenAPI-generated)
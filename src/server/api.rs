@@ -1,7 +1,7 @@
 //! Common API module with shared imports and traits
 
+use crate::server::db::DbPool;
 use log::{error, info};
-use sqlx::sqlite::SqlitePool;
 use std::sync::Arc;
 use swagger::ApiError;
 use tokio::sync::Mutex;
@@ -12,12 +12,12 @@ pub const MAX_RECORD_TRANSFER_COUNT: i64 = 10_000;
 /// Shared server context that all API modules can use
 #[derive(Clone)]
 pub struct ApiContext {
-    pub pool: Arc<SqlitePool>,
+    pub pool: Arc<DbPool>,
     pub lock: Arc<Mutex<()>>,
 }
 
 impl ApiContext {
-    pub fn new(pool: SqlitePool) -> Self {
+    pub fn new(pool: DbPool) -> Self {
         Self {
             pool: Arc::new(pool),
             lock: Arc::new(Mutex::new(())),
@@ -64,10 +64,12 @@ impl PaginationInfo {
 
 // Re-export submodules
 pub mod access_groups;
+pub mod api_tokens;
 pub mod compute_nodes;
 pub mod events;
 pub mod failure_handlers;
 pub mod files;
+pub mod job_logs;
 pub mod jobs;
 pub mod remote_workers;
 pub mod resource_requirements;
@@ -76,14 +78,17 @@ pub mod schedulers;
 pub mod sql_query_builder;
 pub mod user_data;
 pub mod workflow_actions;
+pub mod workflow_schedules;
 pub mod workflows;
 
 // Re-export API traits and implementations
 pub use access_groups::AccessGroupsApiImpl;
+pub use api_tokens::ApiTokensApiImpl;
 pub use compute_nodes::{ComputeNodesApi, ComputeNodesApiImpl};
 pub use events::{EventsApi, EventsApiImpl};
 pub use failure_handlers::{FailureHandlersApi, FailureHandlersApiImpl};
 pub use files::{FilesApi, FilesApiImpl};
+pub use job_logs::{JobLogsApi, JobLogsApiImpl};
 pub use jobs::{JobsApi, JobsApiImpl};
 pub use remote_workers::{RemoteWorkersApi, RemoteWorkersApiImpl};
 pub use resource_requirements::{ResourceRequirementsApi, ResourceRequirementsApiImpl};
@@ -92,4 +97,5 @@ pub use schedulers::{SchedulersApi, SchedulersApiImpl};
 pub use sql_query_builder::SqlQueryBuilder;
 pub use user_data::{UserDataApi, UserDataApiImpl};
 pub use workflow_actions::{WorkflowActionsApi, WorkflowActionsApiImpl};
+pub use workflow_schedules::{WorkflowSchedulesApi, WorkflowSchedulesApiImpl};
 pub use workflows::{WorkflowsApi, WorkflowsApiImpl};
@@ -57,14 +57,14 @@ impl AccessGroupsApiImpl {
     ) -> Result<bool, ApiError> {
         // Check 1: Is the user the owner of the workflow?
         let is_owner: bool = match sqlx::query(
-            "SELECT EXISTS(SELECT 1 FROM workflow WHERE id = $1 AND user = $2) as is_owner",
+            "SELECT EXISTS(SELECT 1 FROM workflow WHERE id = $1 AND \"user\" = $2) as is_owner",
         )
         .bind(workflow_id)
         .bind(user_name)
         .fetch_one(self.context.pool.as_ref())
         .await
         {
-            Ok(row) => row.get::<i32, _>("is_owner") == 1,
+            Ok(row) => row.get::<bool, _>("is_owner"),
             Err(e) => {
                 return Err(database_error(e));
             }
@@ -90,7 +90,7 @@ impl AccessGroupsApiImpl {
         .fetch_one(self.context.pool.as_ref())
         .await
         {
-            Ok(row) => row.get::<i32, _>("has_access") == 1,
+            Ok(row) => row.get::<bool, _>("has_access"),
             Err(e) => {
                 return Err(database_error(e));
             }
@@ -647,7 +647,7 @@ impl AccessGroupsApiImpl {
                 .fetch_one(self.context.pool.as_ref())
                 .await
             {
-                Ok(row) => row.get::<i32, _>("exists_flag") == 1,
+                Ok(row) => row.get::<bool, _>("exists_flag"),
                 Err(e) => {
                     return Err(database_error(e));
                 }
@@ -784,7 +784,7 @@ impl AccessGroupsApiImpl {
                 .fetch_one(self.context.pool.as_ref())
                 .await
             {
-                Ok(row) => row.get::<i32, _>("exists_flag") == 1,
+                Ok(row) => row.get::<bool, _>("exists_flag"),
                 Err(e) => {
                     return Err(database_error(e));
                 }
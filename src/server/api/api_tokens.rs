@@ -0,0 +1,386 @@
+//! API token endpoints for bearer authentication
+
+use chrono::NaiveDateTime;
+use log::{debug, error, info};
+use serde_json::json;
+use sqlx::Row;
+use swagger::{ApiError, Has, XSpanIdString};
+
+use super::{ApiContext, MAX_RECORD_TRANSFER_COUNT, SqlQueryBuilder, database_error};
+use crate::models;
+use crate::server::api_token_store::{
+    ApiTokenEntry, ApiTokenStore, EXPIRES_AT_FORMAT, SCOPE_READ, SCOPE_WRITE,
+};
+use crate::server::api_types::{
+    CreateApiTokenResponse, ListApiTokensApiResponse, RevokeApiTokenResponse,
+};
+use crate::server::db::DbRow;
+
+const API_TOKEN_COLUMNS: &str = "id, name, owner, scopes, created_at, expires_at, revoked";
+
+/// Implementation of API tokens API for the server
+#[derive(Clone)]
+pub struct ApiTokensApiImpl {
+    pub context: ApiContext,
+    /// Active tokens shared with the authentication middleware
+    pub store: ApiTokenStore,
+}
+
+impl ApiTokensApiImpl {
+    pub fn new(context: ApiContext) -> Self {
+        Self {
+            context,
+            store: ApiTokenStore::new(),
+        }
+    }
+
+    fn row_to_model(row: &DbRow) -> models::ApiTokenModel {
+        let scopes: String = row.get("scopes");
+        let revoked: i64 = row.get("revoked");
+        models::ApiTokenModel {
+            id: Some(row.get("id")),
+            name: row.get("name"),
+            owner: Some(row.get("owner")),
+            scopes: Some(ApiTokenStore::parse_scopes(&scopes)),
+            created_at: row.get("created_at"),
+            expires_at: row.get("expires_at"),
+            revoked: Some(revoked != 0),
+            token: None,
+        }
+    }
+
+    /// Sync the in-memory store with the database: add active tokens and remove revoked ones.
+    /// Returns the number of active tokens loaded.
+    pub async fn load_tokens(&self) -> Result<usize, ApiError> {
+        let records = match sqlx::query(
+            "SELECT id, owner, token_hash, scopes, expires_at, revoked FROM api_token",
+        )
+        .fetch_all(self.context.pool.as_ref())
+        .await
+        {
+            Ok(rows) => rows,
+            Err(e) => {
+                error!("Failed to load API tokens: {}", e);
+                return Err(database_error(e));
+            }
+        };
+
+        let mut active = Vec::new();
+        let mut revoked_ids = Vec::new();
+        for row in &records {
+            let id: i64 = row.get("id");
+            let revoked: i64 = row.get("revoked");
+            if revoked != 0 {
+                revoked_ids.push(id);
+                continue;
+            }
+            let scopes: String = row.get("scopes");
+            let expires_at: Option<String> = row.get("expires_at");
+            active.push((
+                row.get("token_hash"),
+                ApiTokenEntry {
+                    id,
+                    owner: row.get("owner"),
+                    scopes: ApiTokenStore::parse_scopes(&scopes),
+                    expires_at: expires_at
+                        .as_deref()
+                        .and_then(ApiTokenStore::parse_expires_at),
+                },
+            ));
+        }
+
+        let count = active.len();
+        self.store.sync(active, &revoked_ids);
+        Ok(count)
+    }
+
+    /// Get an API token by ID (used internally for ownership checks)
+    pub async fn get_api_token(&self, id: i64) -> Result<Option<models::ApiTokenModel>, ApiError> {
+        let query = format!("SELECT {} FROM api_token WHERE id = $1", API_TOKEN_COLUMNS);
+        match sqlx::query(&query)
+            .bind(id)
+            .fetch_optional(self.context.pool.as_ref())
+            .await
+        {
+            Ok(row) => Ok(row.as_ref().map(Self::row_to_model)),
+            Err(e) => Err(database_error(e)),
+        }
+    }
+
+    /// Create a token for body.owner, which the caller must have already resolved and authorized.
+    pub async fn create_api_token<C>(
+        &self,
+        body: models::ApiTokenModel,
+        context: &C,
+    ) -> Result<CreateApiTokenResponse, ApiError>
+    where
+        C: Has<XSpanIdString> + Send + Sync,
+    {
+        debug!(
+            "create_api_token({}, {:?}) - X-Span-ID: {:?}",
+            body.name,
+            body.owner,
+            context.get().0.clone()
+        );
+
+        let unprocessable = |message: String| {
+            Ok(CreateApiTokenResponse::UnprocessableContentErrorResponse(
+                models::ErrorResponse::new(json!({
+                    "error": "UnprocessableContent",
+                    "message": message
+                })),
+            ))
+        };
+
+        if body.name.trim().is_empty() {
+            return unprocessable("Token name cannot be empty".to_string());
+        }
+        let owner = match body.owner {
+            Some(ref owner) if !owner.is_empty() => owner.clone(),
+            _ => return unprocessable("Token owner is required".to_string()),
+        };
+
+        let scopes = body
+            .scopes
+            .clone()
+            .unwrap_or_else(|| vec![SCOPE_READ.to_string(), SCOPE_WRITE.to_string()]);
+        if scopes.is_empty() {
+            return unprocessable("At least one scope is required".to_string());
+        }
+        if let Some(scope) = scopes
+            .iter()
+            .find(|s| s.as_str() != SCOPE_READ && s.as_str() != SCOPE_WRITE)
+        {
+            return unprocessable(format!(
+                "Invalid scope '{}'. Valid scopes are '{}' and '{}'",
+                scope, SCOPE_READ, SCOPE_WRITE
+            ));
+        }
+
+        let expires_at = match body.expires_at.as_deref() {
+            Some(value) => match ApiTokenStore::parse_expires_at(value) {
+                Some(dt) if dt > chrono::Utc::now().naive_utc() => Some(dt),
+                Some(_) => {
+                    return unprocessable(format!("Expiration time {} is in the past", value));
+                }
+                None => {
+                    return unprocessable(format!(
+                        "Invalid expiration time '{}'. Expected 'YYYY-MM-DD HH:MM:SS' (UTC) or RFC 3339",
+                        value
+                    ));
+                }
+            },
+            None => None,
+        };
+        let (row, token) = self
+            .insert_token(&body.name, &owner, scopes, expires_at)
+            .await?;
+        let mut model = Self::row_to_model(&row);
+        model.token = Some(token);
+        Ok(CreateApiTokenResponse::SuccessfulResponse(model))
+    }
+
+    /// Generate a token, store its hash, and add it to the in-memory store.
+    /// Returns the database row and the plaintext token.
+    async fn insert_token(
+        &self,
+        name: &str,
+        owner: &str,
+        scopes: Vec<String>,
+        expires_at: Option<NaiveDateTime>,
+    ) -> Result<(DbRow, String), ApiError> {
+        let expires_at_str = expires_at.map(|dt| dt.format(EXPIRES_AT_FORMAT).to_string());
+
+        let token = match ApiTokenStore::generate_token() {
+            Ok(token) => token,
+            Err(e) => {
+                error!("Failed to generate API token: {}", e);
+                return Err(ApiError("Failed to generate API token".to_string()));
+            }
+        };
+        let token_hash = ApiTokenStore::hash_token(&token);
+        let scopes_str = scopes.join(",");
+
+        let query = format!(
+            r#"
+            INSERT INTO api_token (name, owner, token_hash, scopes, expires_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING {}
+            "#,
+            API_TOKEN_COLUMNS
+        );
+        let row = match sqlx::query(&query)
+            .bind(name)
+            .bind(owner)
+            .bind(&token_hash)
+            .bind(&scopes_str)
+            .bind(&expires_at_str)
+            .fetch_one(self.context.pool.as_ref())
+            .await
+        {
+            Ok(row) => row,
+            Err(e) => return Err(database_error(e)),
+        };
+
+        self.store.insert(
+            token_hash,
+            ApiTokenEntry {
+                id: row.get("id"),
+                owner: owner.to_string(),
+                scopes,
+                expires_at,
+            },
+        );
+        info!(
+            "Created API token id={} name={} owner={}",
+            row.get::<i64, _>("id"),
+            name,
+            owner
+        );
+
+        Ok((row, token))
+    }
+
+    /// Issue a read/write token on behalf of the server, such as for a scheduled workflow run.
+    /// Returns the plaintext token.
+    pub async fn issue_token(
+        &self,
+        name: &str,
+        owner: &str,
+        expires_at: NaiveDateTime,
+    ) -> Result<String, ApiError> {
+        let scopes = vec![SCOPE_READ.to_string(), SCOPE_WRITE.to_string()];
+        let (_, token) = self
+            .insert_token(name, owner, scopes, Some(expires_at))
+            .await?;
+        Ok(token)
+    }
+
+    /// Revoke all active tokens of the owner with the given name, such as the token of a
+    /// schedule's previous run. Returns the number of revoked tokens.
+    pub async fn revoke_tokens_named(&self, name: &str, owner: &str) -> Result<usize, ApiError> {
+        let records = match sqlx::query(
+            r#"
+            UPDATE api_token SET revoked = 1
+            WHERE name = $1 AND owner = $2 AND revoked = 0
+            RETURNING id
+            "#,
+        )
+        .bind(name)
+        .bind(owner)
+        .fetch_all(self.context.pool.as_ref())
+        .await
+        {
+            Ok(rows) => rows,
+            Err(e) => return Err(database_error(e)),
+        };
+
+        for row in &records {
+            let id: i64 = row.get("id");
+            self.store.remove_by_id(id);
+            info!("Revoked API token id={} name={} owner={}", id, name, owner);
+        }
+        Ok(records.len())
+    }
+
+    /// List API tokens. If owner is set, only that user's tokens are returned.
+    pub async fn list_api_tokens<C>(
+        &self,
+        owner: Option<String>,
+        offset: i64,
+        limit: i64,
+        context: &C,
+    ) -> Result<ListApiTokensApiResponse, ApiError>
+    where
+        C: Has<XSpanIdString> + Send + Sync,
+    {
+        debug!(
+            "list_api_tokens({:?}, {}, {}) - X-Span-ID: {:?}",
+            owner,
+            offset,
+            limit,
+            context.get().0.clone()
+        );
+
+        let effective_limit = std::cmp::min(limit, MAX_RECORD_TRANSFER_COUNT);
+
+        let mut builder =
+            SqlQueryBuilder::new(format!("SELECT {} FROM api_token", API_TOKEN_COLUMNS));
+        let mut count_query = "SELECT COUNT(*) as total FROM api_token".to_string();
+        if owner.is_some() {
+            builder = builder.with_where("owner = $1".to_string());
+            count_query.push_str(" WHERE owner = $1");
+        }
+        let query = builder
+            .with_pagination_and_sorting(offset, effective_limit, None, None, "id")
+            .build();
+
+        let mut sql = sqlx::query(&query);
+        let mut count_sql = sqlx::query(&count_query);
+        if let Some(ref owner) = owner {
+            sql = sql.bind(owner);
+            count_sql = count_sql.bind(owner);
+        }
+
+        let records = match sql.fetch_all(self.context.pool.as_ref()).await {
+            Ok(rows) => rows,
+            Err(e) => return Err(database_error(e)),
+        };
+        let items: Vec<models::ApiTokenModel> = records.iter().map(Self::row_to_model).collect();
+
+        let total_count: i64 = match count_sql.fetch_one(self.context.pool.as_ref()).await {
+            Ok(row) => row.get("total"),
+            Err(e) => return Err(database_error(e)),
+        };
+
+        let response =
+            models::ListApiTokensResponse::new(items, offset, effective_limit, total_count);
+        Ok(ListApiTokensApiResponse::SuccessfulResponse(response))
+    }
+
+    /// Revoke an API token. The token record is kept for auditing.
+    pub async fn revoke_api_token<C>(
+        &self,
+        id: i64,
+        context: &C,
+    ) -> Result<RevokeApiTokenResponse, ApiError>
+    where
+        C: Has<XSpanIdString> + Send + Sync,
+    {
+        debug!(
+            "revoke_api_token({}) - X-Span-ID: {:?}",
+            id,
+            context.get().0.clone()
+        );
+
+        match sqlx::query("UPDATE api_token SET revoked = 1 WHERE id = $1")
+            .bind(id)
+            .execute(self.context.pool.as_ref())
+            .await
+        {
+            Ok(result) => {
+                if result.rows_affected() == 0 {
+                    return Ok(RevokeApiTokenResponse::NotFoundErrorResponse(
+                        models::ErrorResponse::new(json!({
+                            "error": "NotFound",
+                            "message": format!("API token not found with ID: {}", id)
+                        })),
+                    ));
+                }
+            }
+            Err(e) => return Err(database_error(e)),
+        }
+        self.store.remove_by_id(id);
+        info!("Revoked API token with id: {}", id);
+
+        match self.get_api_token(id).await? {
+            Some(token) => Ok(RevokeApiTokenResponse::SuccessfulResponse(token)),
+            None => Ok(RevokeApiTokenResponse::NotFoundErrorResponse(
+                models::ErrorResponse::new(json!({
+                    "error": "NotFound",
+                    "message": format!("API token not found with ID: {}", id)
+                })),
+            )),
+        }
+    }
+}
@@ -9,12 +9,21 @@ use swagger::{ApiError, Has, XSpanIdString};
 
 use crate::server::api_types::{
     CreateComputeNodeResponse, DeleteComputeNodeResponse, DeleteComputeNodesResponse,
-    GetComputeNodeResponse, ListComputeNodesResponse, UpdateComputeNodeResponse,
+    GetComputeNodeResponse, ListComputeNodesResponse, ReleaseComputeNodeResponse,
+    ReserveComputeNodeResponse, UpdateComputeNodeHeartbeatResponse, UpdateComputeNodeResponse,
 };
 
 use crate::models;
 
 use super::{ApiContext, MAX_RECORD_TRANSFER_COUNT, SqlQueryBuilder, database_error};
+use crate::server::db;
+
+/// Convert a stored heartbeat time (Unix milliseconds) to the RFC3339 string exposed by the API.
+fn heartbeat_to_rfc3339(value: Option<i64>) -> Option<String> {
+    value
+        .and_then(chrono::DateTime::from_timestamp_millis)
+        .map(|t| t.to_rfc3339())
+}
 
 /// Trait defining compute node-related API operations
 #[async_trait]
@@ -63,6 +72,29 @@ pub trait ComputeNodesApi<C> {
         context: &C,
     ) -> Result<UpdateComputeNodeResponse, ApiError>;
 
+    /// Record a heartbeat from a compute node.
+    async fn update_compute_node_heartbeat(
+        &self,
+        id: i64,
+        context: &C,
+    ) -> Result<UpdateComputeNodeHeartbeatResponse, ApiError>;
+
+    /// Reserve a compute node for a job.
+    async fn reserve_compute_node(
+        &self,
+        id: i64,
+        job_id: i64,
+        context: &C,
+    ) -> Result<ReserveComputeNodeResponse, ApiError>;
+
+    /// Release a compute node reserved for a job.
+    async fn release_compute_node(
+        &self,
+        id: i64,
+        job_id: i64,
+        context: &C,
+    ) -> Result<ReleaseComputeNodeResponse, ApiError>;
+
     /// Delete a compute node.
     async fn delete_compute_node(
         &self,
@@ -106,6 +138,7 @@ where
             .scheduler
             .as_ref()
             .and_then(|s| serde_json::to_string(s).ok());
+        let is_active = body.is_active.map(i64::from);
 
         match sqlx::query!(
             r#"INSERT INTO compute_node (
@@ -124,14 +157,14 @@ where
                 ,compute_node_type
                 ,scheduler
             ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
-            RETURNING rowid
+            RETURNING id
             "#,
             body.workflow_id,
             body.hostname,
             body.pid,
             body.start_time,
             body.duration_seconds,
-            body.is_active,
+            is_active,
             body.num_cpus,
             body.memory_gb,
             body.num_gpus,
@@ -212,7 +245,8 @@ where
         let record = match sqlx::query(
             r#"
             SELECT id, workflow_id, hostname, pid, start_time, duration_seconds, is_active,
-                   num_cpus, memory_gb, num_gpus, num_nodes, time_limit, scheduler_config_id, compute_node_type, scheduler
+                   num_cpus, memory_gb, num_gpus, num_nodes, time_limit, scheduler_config_id, compute_node_type, scheduler,
+                   last_heartbeat, reserved_for_job_id
             FROM compute_node
             WHERE id = $1
             "#,
@@ -236,7 +270,7 @@ where
             }
         };
 
-        let is_active_val: i64 = record.get("is_active");
+        let is_active_val: Option<i64> = record.get("is_active");
         let duration_seconds: Option<f64> = record.get("duration_seconds");
         let time_limit: Option<String> = record.get("time_limit");
         let scheduler_str: Option<String> = record.get("scheduler");
@@ -251,7 +285,7 @@ where
             pid: record.get("pid"),
             start_time: record.get("start_time"),
             duration_seconds,
-            is_active: if is_active_val == 1 {
+            is_active: if is_active_val == Some(1) {
                 Some(true)
             } else {
                 Some(false)
@@ -264,6 +298,8 @@ where
             scheduler_config_id: record.get("scheduler_config_id"),
             compute_node_type: record.get("compute_node_type"),
             scheduler,
+            last_heartbeat: heartbeat_to_rfc3339(record.get("last_heartbeat")),
+            reserved_for_job_id: record.get("reserved_for_job_id"),
         };
 
         Ok(GetComputeNodeResponse::SuccessfulResponse(
@@ -315,6 +351,8 @@ where
                 ,scheduler_config_id
                 ,compute_node_type
                 ,scheduler
+                ,last_heartbeat
+                ,reserved_for_job_id
             FROM compute_node"
             .to_string();
 
@@ -328,7 +366,10 @@ where
         }
         if scheduled_compute_node_id.is_some() {
             // Filter by scheduler.scheduler_id in the JSON field
-            where_conditions.push("json_extract(scheduler, '$.scheduler_id') = ?".to_string());
+            where_conditions.push(format!(
+                "{} = ?",
+                db::json_extract_integer("scheduler", "scheduler_id")
+            ));
         }
         let where_clause = where_conditions.join(" AND ");
 
@@ -362,7 +403,7 @@ where
 
         let mut items: Vec<models::ComputeNodeModel> = Vec::new();
         for record in records {
-            let is_active_val: i64 = record.get("is_active");
+            let is_active_val: Option<i64> = record.get("is_active");
             let duration_seconds: Option<f64> = record.get("duration_seconds");
             let time_limit: Option<String> = record.get("time_limit");
             let scheduler_str: Option<String> = record.get("scheduler");
@@ -377,7 +418,7 @@ where
                 pid: record.get("pid"),
                 start_time: record.get("start_time"),
                 duration_seconds,
-                is_active: if is_active_val == 1 {
+                is_active: if is_active_val == Some(1) {
                     Some(true)
                 } else {
                     Some(false)
@@ -390,6 +431,8 @@ where
                 scheduler_config_id: record.get("scheduler_config_id"),
                 compute_node_type: record.get("compute_node_type"),
                 scheduler,
+                last_heartbeat: heartbeat_to_rfc3339(record.get("last_heartbeat")),
+                reserved_for_job_id: record.get("reserved_for_job_id"),
             });
         }
 
@@ -400,6 +443,12 @@ where
                 .build();
 
         let mut count_sqlx_query = sqlx::query(&count_query).bind(workflow_id);
+        if let Some(ref h) = hostname {
+            count_sqlx_query = count_sqlx_query.bind(h);
+        }
+        if let Some(active) = is_active {
+            count_sqlx_query = count_sqlx_query.bind(if active { 1i64 } else { 0i64 });
+        }
         if let Some(scn_id) = scheduled_compute_node_id {
             count_sqlx_query = count_sqlx_query.bind(scn_id);
         }
@@ -488,7 +537,8 @@ where
                 ,scheduler_config_id = COALESCE($12, scheduler_config_id)
                 ,compute_node_type = COALESCE($13, compute_node_type)
                 ,scheduler = COALESCE($14, scheduler)
-            WHERE id = $15
+                ,reserved_for_job_id = COALESCE($15, reserved_for_job_id)
+            WHERE id = $16
             "#,
         )
         .bind(body.workflow_id)
@@ -505,6 +555,7 @@ where
         .bind(body.scheduler_config_id)
         .bind(body.compute_node_type)
         .bind(scheduler_json)
+        .bind(body.reserved_for_job_id)
         .bind(id)
         .execute(self.context.pool.as_ref())
         .await
@@ -537,6 +588,153 @@ where
         ))
     }
 
+    /// Record a heartbeat from a compute node.
+    ///
+    /// The response includes `is_active`, which is false if the server has already declared
+    /// the compute node dead.
+    async fn update_compute_node_heartbeat(
+        &self,
+        id: i64,
+        context: &C,
+    ) -> Result<UpdateComputeNodeHeartbeatResponse, ApiError> {
+        debug!(
+            "update_compute_node_heartbeat({}) - X-Span-ID: {:?}",
+            id,
+            context.get().0.clone()
+        );
+
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        let result = match sqlx::query("UPDATE compute_node SET last_heartbeat = $1 WHERE id = $2")
+            .bind(now_ms)
+            .bind(id)
+            .execute(self.context.pool.as_ref())
+            .await
+        {
+            Ok(result) => result,
+            Err(e) => {
+                error!("Database error: {}", e);
+                return Err(database_error(e));
+            }
+        };
+
+        if result.rows_affected() == 0 {
+            let error_response = models::ErrorResponse::new(serde_json::json!({
+                "message": format!("Compute node not found with ID: {}", id)
+            }));
+            return Ok(UpdateComputeNodeHeartbeatResponse::NotFoundErrorResponse(
+                error_response,
+            ));
+        }
+
+        match self.get_compute_node(id, context).await? {
+            GetComputeNodeResponse::SuccessfulResponse(compute_node) => Ok(
+                UpdateComputeNodeHeartbeatResponse::SuccessfulResponse(compute_node),
+            ),
+            GetComputeNodeResponse::NotFoundErrorResponse(err) => Ok(
+                UpdateComputeNodeHeartbeatResponse::NotFoundErrorResponse(err),
+            ),
+            GetComputeNodeResponse::DefaultErrorResponse(_) => {
+                Err(ApiError("Failed to get compute node".to_string()))
+            }
+        }
+    }
+
+    /// Reserve a compute node for a job.
+    ///
+    /// The reservation is only made if the compute node is not reserved for another job, so
+    /// job runners racing for the same node cannot both reserve it. The response contains the
+    /// compute node with its current reservation, which the caller must check.
+    async fn reserve_compute_node(
+        &self,
+        id: i64,
+        job_id: i64,
+        context: &C,
+    ) -> Result<ReserveComputeNodeResponse, ApiError> {
+        debug!(
+            "reserve_compute_node({}, {}) - X-Span-ID: {:?}",
+            id,
+            job_id,
+            context.get().0.clone()
+        );
+
+        let result = match sqlx::query(
+            r#"
+            UPDATE compute_node
+            SET reserved_for_job_id = $1
+            WHERE id = $2 AND (reserved_for_job_id IS NULL OR reserved_for_job_id = $1)
+            "#,
+        )
+        .bind(job_id)
+        .bind(id)
+        .execute(self.context.pool.as_ref())
+        .await
+        {
+            Ok(result) => result,
+            Err(e) => {
+                error!("Database error: {}", e);
+                return Err(database_error(e));
+            }
+        };
+        if result.rows_affected() == 0 {
+            debug!(
+                "Compute node {} was not reserved for job {} because it does not exist or is reserved for another job",
+                id, job_id
+            );
+        }
+
+        match self.get_compute_node(id, context).await? {
+            GetComputeNodeResponse::SuccessfulResponse(compute_node) => {
+                Ok(ReserveComputeNodeResponse::SuccessfulResponse(compute_node))
+            }
+            GetComputeNodeResponse::NotFoundErrorResponse(err) => {
+                Ok(ReserveComputeNodeResponse::NotFoundErrorResponse(err))
+            }
+            GetComputeNodeResponse::DefaultErrorResponse(_) => {
+                Err(ApiError("Failed to get compute node".to_string()))
+            }
+        }
+    }
+
+    /// Release a compute node reserved for a job. A compute node reserved for another job is
+    /// left unchanged.
+    async fn release_compute_node(
+        &self,
+        id: i64,
+        job_id: i64,
+        context: &C,
+    ) -> Result<ReleaseComputeNodeResponse, ApiError> {
+        debug!(
+            "release_compute_node({}, {}) - X-Span-ID: {:?}",
+            id,
+            job_id,
+            context.get().0.clone()
+        );
+
+        if let Err(e) = sqlx::query(
+            "UPDATE compute_node SET reserved_for_job_id = NULL WHERE id = $1 AND reserved_for_job_id = $2",
+        )
+        .bind(id)
+        .bind(job_id)
+        .execute(self.context.pool.as_ref())
+        .await
+        {
+            error!("Database error: {}", e);
+            return Err(database_error(e));
+        }
+
+        match self.get_compute_node(id, context).await? {
+            GetComputeNodeResponse::SuccessfulResponse(compute_node) => {
+                Ok(ReleaseComputeNodeResponse::SuccessfulResponse(compute_node))
+            }
+            GetComputeNodeResponse::NotFoundErrorResponse(err) => {
+                Ok(ReleaseComputeNodeResponse::NotFoundErrorResponse(err))
+            }
+            GetComputeNodeResponse::DefaultErrorResponse(_) => {
+                Err(ApiError("Failed to get compute node".to_string()))
+            }
+        }
+    }
+
     /// Delete a compute node.
     async fn delete_compute_node(
         &self,
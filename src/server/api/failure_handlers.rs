@@ -210,7 +210,7 @@ where
 
         // Get total count
         let total_count = match sqlx::query!(
-            r#"SELECT COUNT(*) as total FROM failure_handler WHERE workflow_id = $1"#,
+            r#"SELECT COUNT(*) as "total!" FROM failure_handler WHERE workflow_id = $1"#,
             workflow_id
         )
         .fetch_one(self.context.pool.as_ref())
@@ -105,6 +105,7 @@ where
             context.get().0.clone()
         );
 
+        let use_checksum = file.use_checksum.map(i64::from);
         let result = match sqlx::query!(
             r#"
             INSERT INTO file
@@ -112,15 +113,19 @@ where
                 workflow_id,
                 name,
                 path,
-                st_mtime
+                st_mtime,
+                use_checksum,
+                content_hash
             )
-            VALUES ($1, $2, $3, $4)
-            RETURNING rowid
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id
             "#,
             file.workflow_id,
             file.name,
             file.path,
             file.st_mtime,
+            use_checksum,
+            file.content_hash,
         )
         .fetch_one(self.context.pool.as_ref())
         .await
@@ -181,7 +186,11 @@ where
         );
 
         let record = match sqlx::query!(
-            "SELECT id, workflow_id, name, path, st_mtime FROM file WHERE id = $1",
+            r#"
+            SELECT id, workflow_id, name, path, st_mtime, use_checksum, content_hash
+            FROM file
+            WHERE id = $1
+            "#,
             id
         )
         .fetch_optional(self.context.pool.as_ref())
@@ -205,6 +214,8 @@ where
             name: record.name,
             path: record.path,
             st_mtime: record.st_mtime,
+            use_checksum: record.use_checksum.map(|v| v != 0),
+            content_hash: record.content_hash,
         };
 
         debug!(
@@ -255,12 +266,15 @@ where
                     ,f.name
                     ,f.path
                     ,f.st_mtime
+                    ,f.use_checksum
+                    ,f.content_hash
                 FROM file f
                 JOIN job_output_file jof ON f.id = jof.file_id
             "
             .to_string()
         } else {
-            "SELECT id, workflow_id, name, path, st_mtime FROM file".to_string()
+            "SELECT id, workflow_id, name, path, st_mtime, use_checksum, content_hash FROM file"
+                .to_string()
         };
 
         // Build WHERE clause conditions
@@ -328,6 +342,8 @@ where
                 name: record.get("name"),
                 path: record.get("path"),
                 st_mtime: record.get("st_mtime"),
+                use_checksum: record.get::<Option<i64>, _>("use_checksum").map(|v| v != 0),
+                content_hash: record.get("content_hash"),
             });
         }
 
@@ -457,7 +473,8 @@ where
         };
 
         // Update the file record using COALESCE to only update non-null fields
-        // Exception: st_mtime should always be updated, even if null
+        // Exception: st_mtime and content_hash should always be updated, even if null
+        let use_checksum = body.use_checksum.map(i64::from);
         let result = match sqlx::query!(
             r#"
             UPDATE file
@@ -465,13 +482,17 @@ where
                 workflow_id = COALESCE($1, workflow_id),
                 name = COALESCE($2, name),
                 path = COALESCE($3, path),
-                st_mtime = $4
-            WHERE id = $5
+                st_mtime = $4,
+                use_checksum = COALESCE($5, use_checksum),
+                content_hash = $6
+            WHERE id = $7
             "#,
             body.workflow_id,
             body.name,
             body.path,
             body.st_mtime,
+            use_checksum,
+            body.content_hash,
             id,
         )
         .execute(self.context.pool.as_ref())
@@ -583,7 +604,7 @@ impl FilesApiImpl {
     /// Find all file IDs produced by a job, as shown in the table job_output_file,
     /// where the job status is JobStatus::Completed.
     async fn find_job_produced_files(&self, workflow_id: i64) -> Result<Vec<i64>, ApiError> {
-        let completed_status = models::JobStatus::Completed.to_int();
+        let completed_status = i64::from(models::JobStatus::Completed.to_int());
 
         let rows = match sqlx::query!(
             r#"
@@ -0,0 +1,342 @@
+//! Job log-related API endpoints
+
+#![allow(clippy::too_many_arguments)]
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use log::{debug, error, info};
+use sqlx::Row;
+use swagger::{ApiError, Has, XSpanIdString};
+
+use crate::server::api_types::{CreateJobLogResponse, GetJobLogResponse, ListJobLogsResponse};
+
+use crate::models;
+
+use super::{ApiContext, MAX_RECORD_TRANSFER_COUNT, database_error};
+use crate::server::db::DbRow;
+
+const SELECT_COLUMNS: &str = "id, workflow_id, job_id, run_id, attempt_id, stream, content, size_bytes, is_truncated, \
+    created_at";
+
+/// Columns of list responses, which leave out the content; clients fetch it per log.
+const LIST_COLUMNS: &str =
+    "id, workflow_id, job_id, run_id, attempt_id, stream, size_bytes, is_truncated, created_at";
+
+fn row_to_model(record: &DbRow) -> models::JobLogModel {
+    models::JobLogModel {
+        id: Some(record.get("id")),
+        workflow_id: record.get("workflow_id"),
+        job_id: record.get("job_id"),
+        run_id: record.get("run_id"),
+        attempt_id: Some(record.get("attempt_id")),
+        stream: record
+            .get::<String, _>("stream")
+            .parse::<models::JobLogStream>()
+            .unwrap_or_default(),
+        // Rows selected with LIST_COLUMNS have no content.
+        content: record.try_get("content").unwrap_or_default(),
+        size_bytes: Some(record.get("size_bytes")),
+        is_truncated: Some(record.get::<i64, _>("is_truncated") != 0),
+        timestamp: DateTime::from_timestamp_millis(record.get("created_at"))
+            .map(|t| t.to_rfc3339()),
+    }
+}
+
+fn not_found_error(message: String) -> models::ErrorResponse {
+    models::ErrorResponse::new(serde_json::json!({ "message": message }))
+}
+
+/// Trait defining job log-related API operations
+#[async_trait]
+pub trait JobLogsApi<C> {
+    /// Store the log of one output stream of a job attempt, replacing a previous upload.
+    async fn create_job_log(
+        &self,
+        body: models::JobLogModel,
+        context: &C,
+    ) -> Result<CreateJobLogResponse, ApiError>;
+
+    /// Retrieve a job log by ID.
+    async fn get_job_log(&self, id: i64, context: &C) -> Result<GetJobLogResponse, ApiError>;
+
+    /// Retrieve the job logs of a workflow without their content.
+    async fn list_job_logs(
+        &self,
+        workflow_id: i64,
+        job_id: Option<i64>,
+        run_id: Option<i64>,
+        attempt_id: Option<i64>,
+        stream: Option<models::JobLogStream>,
+        offset: i64,
+        limit: i64,
+        context: &C,
+    ) -> Result<ListJobLogsResponse, ApiError>;
+}
+
+/// Implementation of job logs API for the server
+#[derive(Clone)]
+pub struct JobLogsApiImpl {
+    pub context: ApiContext,
+    /// Uploads with more content than this are rejected.
+    pub max_content_bytes: u64,
+}
+
+impl JobLogsApiImpl {
+    pub fn new(context: ApiContext, max_content_bytes: u64) -> Self {
+        Self {
+            context,
+            max_content_bytes,
+        }
+    }
+
+    async fn fetch_job_log(&self, id: i64) -> Result<Option<models::JobLogModel>, ApiError> {
+        let query = format!("SELECT {} FROM job_log WHERE id = $1", SELECT_COLUMNS);
+        match sqlx::query(&query)
+            .bind(id)
+            .fetch_optional(self.context.pool.as_ref())
+            .await
+        {
+            Ok(record) => Ok(record.as_ref().map(row_to_model)),
+            Err(e) => {
+                error!("Database error: {}", e);
+                Err(database_error(e))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<C> JobLogsApi<C> for JobLogsApiImpl
+where
+    C: Has<XSpanIdString> + Send + Sync,
+{
+    /// Store the log of one output stream of a job attempt, replacing a previous upload.
+    async fn create_job_log(
+        &self,
+        body: models::JobLogModel,
+        context: &C,
+    ) -> Result<CreateJobLogResponse, ApiError> {
+        debug!(
+            "create_job_log(workflow_id={}, job_id={}, run_id={}, attempt_id={:?}, stream={}, {} bytes) - X-Span-ID: {:?}",
+            body.workflow_id,
+            body.job_id,
+            body.run_id,
+            body.attempt_id,
+            body.stream,
+            body.content.len(),
+            context.get().0.clone()
+        );
+
+        if body.content.len() as u64 > self.max_content_bytes {
+            return Ok(CreateJobLogResponse::UnprocessableContentErrorResponse(
+                models::ErrorResponse::new(serde_json::json!({
+                    "message": format!(
+                        "Job log content of {} bytes exceeds the server limit of {} bytes",
+                        body.content.len(),
+                        self.max_content_bytes
+                    )
+                })),
+            ));
+        }
+
+        let job_workflow_id = match sqlx::query("SELECT workflow_id FROM job WHERE id = $1")
+            .bind(body.job_id)
+            .fetch_optional(self.context.pool.as_ref())
+            .await
+        {
+            Ok(record) => record.map(|r| r.get::<i64, _>("workflow_id")),
+            Err(e) => {
+                error!("Database error: {}", e);
+                return Err(database_error(e));
+            }
+        };
+        if job_workflow_id != Some(body.workflow_id) {
+            return Ok(CreateJobLogResponse::NotFoundErrorResponse(
+                not_found_error(format!(
+                    "Job {} not found in workflow {}",
+                    body.job_id, body.workflow_id
+                )),
+            ));
+        }
+
+        // PostgreSQL text cannot hold NUL characters.
+        let content = body.content.replace('\0', "");
+        let size_bytes = body.size_bytes.unwrap_or(content.len() as i64);
+        let attempt_id = body.attempt_id.unwrap_or(1);
+        let is_truncated = body.is_truncated.unwrap_or(false);
+        let result = match sqlx::query(
+            r#"
+            INSERT INTO job_log
+                (workflow_id, job_id, run_id, attempt_id, stream, content, size_bytes,
+                 is_truncated, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (job_id, run_id, attempt_id, stream) DO UPDATE
+            SET content = excluded.content,
+                size_bytes = excluded.size_bytes,
+                is_truncated = excluded.is_truncated,
+                created_at = excluded.created_at
+            RETURNING id
+            "#,
+        )
+        .bind(body.workflow_id)
+        .bind(body.job_id)
+        .bind(body.run_id)
+        .bind(attempt_id)
+        .bind(body.stream.to_string())
+        .bind(&content)
+        .bind(size_bytes)
+        .bind(if is_truncated { 1i64 } else { 0i64 })
+        .bind(chrono::Utc::now().timestamp_millis())
+        .fetch_one(self.context.pool.as_ref())
+        .await
+        {
+            Ok(result) => result,
+            Err(e) => {
+                error!("Database error: {}", e);
+                return Err(database_error(e));
+            }
+        };
+        let id: i64 = result.get("id");
+        info!(
+            "Stored job log with ID: {} workflow_id={} job_id={} run_id={} attempt_id={} stream={} bytes={}",
+            id,
+            body.workflow_id,
+            body.job_id,
+            body.run_id,
+            attempt_id,
+            body.stream,
+            content.len()
+        );
+
+        match self.fetch_job_log(id).await? {
+            Some(job_log) => Ok(CreateJobLogResponse::SuccessfulResponse(job_log)),
+            None => Err(ApiError(format!(
+                "Job log {} disappeared after creation",
+                id
+            ))),
+        }
+    }
+
+    /// Retrieve a job log by ID.
+    async fn get_job_log(&self, id: i64, context: &C) -> Result<GetJobLogResponse, ApiError> {
+        debug!(
+            "get_job_log({}) - X-Span-ID: {:?}",
+            id,
+            context.get().0.clone()
+        );
+
+        match self.fetch_job_log(id).await? {
+            Some(job_log) => Ok(GetJobLogResponse::SuccessfulResponse(job_log)),
+            None => Ok(GetJobLogResponse::NotFoundErrorResponse(not_found_error(
+                format!("Job log not found with ID: {}", id),
+            ))),
+        }
+    }
+
+    /// Retrieve the job logs of a workflow without their content.
+    async fn list_job_logs(
+        &self,
+        workflow_id: i64,
+        job_id: Option<i64>,
+        run_id: Option<i64>,
+        attempt_id: Option<i64>,
+        stream: Option<models::JobLogStream>,
+        offset: i64,
+        limit: i64,
+        context: &C,
+    ) -> Result<ListJobLogsResponse, ApiError> {
+        debug!(
+            "list_job_logs({}, {:?}, {:?}, {:?}, {:?}, {}, {}) - X-Span-ID: {:?}",
+            workflow_id,
+            job_id,
+            run_id,
+            attempt_id,
+            stream,
+            offset,
+            limit,
+            context.get().0.clone()
+        );
+
+        let limit = std::cmp::min(limit, MAX_RECORD_TRANSFER_COUNT);
+        let mut conditions = vec!["workflow_id = $1".to_string()];
+        let mut next_param = 2;
+        let mut add_condition = |column: &str| {
+            conditions.push(format!("{} = ${}", column, next_param));
+            next_param += 1;
+        };
+        if job_id.is_some() {
+            add_condition("job_id");
+        }
+        if run_id.is_some() {
+            add_condition("run_id");
+        }
+        if attempt_id.is_some() {
+            add_condition("attempt_id");
+        }
+        if stream.is_some() {
+            add_condition("stream");
+        }
+        let where_clause = conditions.join(" AND ");
+
+        let query = format!(
+            "SELECT {} FROM job_log WHERE {} ORDER BY job_id, run_id, attempt_id, stream \
+            LIMIT ${} OFFSET ${}",
+            LIST_COLUMNS,
+            where_clause,
+            next_param,
+            next_param + 1
+        );
+        let count_query = format!(
+            "SELECT COUNT(*) as total FROM job_log WHERE {}",
+            where_clause
+        );
+        let stream = stream.map(|s| s.to_string());
+
+        let mut sql = sqlx::query(&query).bind(workflow_id);
+        let mut count_sql = sqlx::query(&count_query).bind(workflow_id);
+        for value in [job_id, run_id, attempt_id].into_iter().flatten() {
+            sql = sql.bind(value);
+            count_sql = count_sql.bind(value);
+        }
+        if let Some(stream) = &stream {
+            sql = sql.bind(stream.clone());
+            count_sql = count_sql.bind(stream.clone());
+        }
+
+        let records = match sql
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(self.context.pool.as_ref())
+            .await
+        {
+            Ok(records) => records,
+            Err(e) => {
+                error!("Database error: {}", e);
+                return Err(database_error(e));
+            }
+        };
+        let items: Vec<models::JobLogModel> = records.iter().map(row_to_model).collect();
+        let count = items.len() as i64;
+
+        let total_count: i64 = match count_sql.fetch_one(self.context.pool.as_ref()).await {
+            Ok(row) => row.get("total"),
+            Err(e) => {
+                error!("Database error: {}", e);
+                return Err(database_error(e));
+            }
+        };
+
+        let has_more = offset + count < total_count;
+
+        Ok(ListJobLogsResponse::SuccessfulResponse(
+            models::ListJobLogsResponse {
+                items: Some(items),
+                offset,
+                max_limit: MAX_RECORD_TRANSFER_COUNT,
+                count,
+                total_count,
+                has_more,
+            },
+        ))
+    }
+}
@@ -3,10 +3,11 @@
 #![allow(clippy::too_many_arguments)]
 
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use log::{debug, error, info};
 use sha2::{Digest, Sha256};
 use sqlx::Row;
+use std::collections::HashMap;
 use swagger::{ApiError, Has, XSpanIdString};
 use tracing::instrument;
 
@@ -20,6 +21,50 @@ use crate::server::api_types::{
 use crate::models::{self as models, JobStatus};
 
 use super::{ApiContext, MAX_RECORD_TRANSFER_COUNT, SqlQueryBuilder, database_error};
+use crate::server::db::{self, Db};
+
+/// Serialize a job's environment variables for storage in the `job.env` column.
+pub fn env_to_json(env: &Option<HashMap<String, String>>) -> Option<String> {
+    env.as_ref()
+        .filter(|env| !env.is_empty())
+        .and_then(|env| serde_json::to_string(env).ok())
+}
+
+/// Parse the `job.env` column into a job's environment variables.
+pub fn env_from_json(value: Option<String>) -> Option<HashMap<String, String>> {
+    value.and_then(|value| match serde_json::from_str(&value) {
+        Ok(env) => Some(env),
+        Err(e) => {
+            error!("Failed to parse job env '{}': {}", value, e);
+            None
+        }
+    })
+}
+
+/// Serialize a job's container settings for the `job.container` column.
+pub fn container_to_json(container: &Option<models::JobContainerModel>) -> Option<String> {
+    container
+        .as_ref()
+        .and_then(|container| serde_json::to_string(container).ok())
+}
+
+/// Parse the `job.container` column into a job's container settings.
+pub fn container_from_json(value: Option<String>) -> Option<models::JobContainerModel> {
+    value.and_then(|value| match serde_json::from_str(&value) {
+        Ok(container) => Some(container),
+        Err(e) => {
+            error!("Failed to parse job container '{}': {}", value, e);
+            None
+        }
+    })
+}
+
+/// Convert a job's not_before column (Unix milliseconds) to an RFC 3339 timestamp.
+pub fn not_before_to_rfc3339(value: Option<i64>) -> Option<String> {
+    value
+        .and_then(DateTime::from_timestamp_millis)
+        .map(|t| t.to_rfc3339())
+}
 
 /// Trait defining job-related API operations
 #[async_trait]
@@ -140,11 +185,16 @@ pub trait JobsApi<C> {
     /// - Job must be in Failed or Terminated status
     /// - run_id must match the workflow's current run_id
     /// - attempt_id must be less than max_retries
+    ///
+    /// If delay_seconds is set, the job is not claimed until the delay has passed. If
+    /// avoid_compute_node_id is set, workers on that compute node's host do not claim the job.
     async fn retry_job(
         &self,
         id: i64,
         run_id: i64,
         max_retries: i32,
+        delay_seconds: Option<f64>,
+        avoid_compute_node_id: Option<i64>,
         context: &C,
     ) -> Result<RetryJobResponse, ApiError>;
 }
@@ -170,7 +220,7 @@ impl JobsApiImpl {
         table_name: &str,
     ) -> Result<(), ApiError>
     where
-        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+        E: sqlx::Executor<'e, Database = Db>,
     {
         let sql = format!(
             "INSERT INTO {} (job_id, file_id, workflow_id) VALUES ($1, $2, $3)",
@@ -198,7 +248,7 @@ impl JobsApiImpl {
         table_name: &str,
     ) -> Result<(), ApiError>
     where
-        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+        E: sqlx::Executor<'e, Database = Db>,
     {
         let sql = format!(
             "INSERT INTO {} (job_id, user_data_id) VALUES ($1, $2)",
@@ -225,7 +275,7 @@ impl JobsApiImpl {
         workflow_id: i64,
     ) -> Result<(), ApiError>
     where
-        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+        E: sqlx::Executor<'e, Database = Db>,
     {
         match sqlx::query!(
             r#"
@@ -251,9 +301,10 @@ impl JobsApiImpl {
             r#"
                 SELECT id, workflow_id, name, command, resource_requirements_id, invocation_script,
                        status, cancel_on_blocking_job_failure, supports_termination, scheduler_id,
-                       failure_handler_id, attempt_id
+                       failure_handler_id, attempt_id, priority, env, working_dir, shell, container,
+                       run_if, concurrency_pool, not_before, excluded_hostname
                 FROM job
-                WHERE id = ?
+                WHERE id = $1
             "#,
         )
         .bind(id)
@@ -270,8 +321,8 @@ impl JobsApiImpl {
             }
         };
 
-        let status_int: i32 = record.get("status");
-        let status = match JobStatus::from_int(status_int) {
+        let status_int: i64 = record.get("status");
+        let status = match JobStatus::from_int(status_int as i32) {
             Ok(s) => s,
             Err(e) => {
                 error!(
@@ -401,6 +452,15 @@ impl JobsApiImpl {
             schedule_compute_nodes: None, // This field is not stored in the database
             failure_handler_id: record.try_get("failure_handler_id").ok(),
             attempt_id: record.try_get("attempt_id").ok(),
+            priority: record.try_get("priority").ok(),
+            env: env_from_json(record.try_get("env").ok().flatten()),
+            working_dir: record.try_get("working_dir").ok().flatten(),
+            shell: record.try_get("shell").ok().flatten(),
+            container: container_from_json(record.try_get("container").ok().flatten()),
+            run_if: record.try_get("run_if").ok().flatten(),
+            concurrency_pool: record.try_get("concurrency_pool").ok().flatten(),
+            not_before: not_before_to_rfc3339(record.try_get("not_before").ok().flatten()),
+            excluded_hostname: record.try_get("excluded_hostname").ok().flatten(),
         })
     }
 
@@ -409,11 +469,11 @@ impl JobsApiImpl {
         &self,
         workflow_id: i64,
     ) -> Result<ResetJobStatusResponse, ApiError> {
-        let uninitialized_status = JobStatus::Uninitialized.to_int();
-        let failed_status = JobStatus::Failed.to_int();
-        let canceled_status = JobStatus::Canceled.to_int();
-        let terminated_status = JobStatus::Terminated.to_int();
-        let pending_failed_status = JobStatus::PendingFailed.to_int();
+        let uninitialized_status = i64::from(JobStatus::Uninitialized.to_int());
+        let failed_status = i64::from(JobStatus::Failed.to_int());
+        let canceled_status = i64::from(JobStatus::Canceled.to_int());
+        let terminated_status = i64::from(JobStatus::Terminated.to_int());
+        let pending_failed_status = i64::from(JobStatus::PendingFailed.to_int());
 
         // Get jobs with failed/canceled/terminated/pending_failed status. The status field is
         // the source of truth - we don't check return_code since status should always be
@@ -462,7 +522,7 @@ impl JobsApiImpl {
 
             // Reset the job status
             match sqlx::query!(
-                "UPDATE job SET status = $1 WHERE id = $2",
+                "UPDATE job SET status = $1, not_before = NULL, excluded_hostname = NULL WHERE id = $2",
                 uninitialized_status,
                 job_id
             )
@@ -475,7 +535,7 @@ impl JobsApiImpl {
 
                         // Clear active_compute_node_id for the reset job
                         if let Err(e) = sqlx::query!(
-                            "UPDATE job_internal SET active_compute_node_id = NULL WHERE job_id = ?",
+                            "UPDATE job_internal SET active_compute_node_id = NULL WHERE job_id = $1",
                             job_id
                         )
                         .execute(self.context.pool.as_ref())
@@ -534,7 +594,7 @@ impl JobsApiImpl {
             job_id
         );
 
-        let uninitialized_status = JobStatus::Uninitialized.to_int();
+        let uninitialized_status = i64::from(JobStatus::Uninitialized.to_int());
 
         // Begin a transaction with immediate lock to ensure atomicity
         // SQLx automatically uses BEGIN IMMEDIATE for SQLite when the first write occurs
@@ -547,7 +607,7 @@ impl JobsApiImpl {
         };
 
         // Get the workflow_id for the given job_id first
-        let workflow_id = match sqlx::query!("SELECT workflow_id FROM job WHERE id = ?", job_id)
+        let workflow_id = match sqlx::query!("SELECT workflow_id FROM job WHERE id = $1", job_id)
             .fetch_optional(&mut *tx)
             .await
         {
@@ -572,8 +632,8 @@ impl JobsApiImpl {
                     jbb.job_id,
                     0 as level
                 FROM job_depends_on jbb
-                WHERE jbb.depends_on_job_id = ?
-                  AND jbb.workflow_id = ?
+                WHERE jbb.depends_on_job_id = $1
+                  AND jbb.workflow_id = $2
 
                 UNION ALL
 
@@ -583,12 +643,12 @@ impl JobsApiImpl {
                     dj.level + 1 as level
                 FROM downstream_jobs dj
                 JOIN job_depends_on jbb ON jbb.depends_on_job_id = dj.job_id
-                WHERE jbb.workflow_id = ?
+                WHERE jbb.workflow_id = $3
                   AND dj.level < 100  -- Prevent infinite loops
             )
             UPDATE job
-            SET status = ?
-            WHERE workflow_id = ?
+            SET status = $4
+            WHERE workflow_id = $5
               AND id IN (SELECT DISTINCT job_id FROM downstream_jobs)
             "#,
             job_id,
@@ -802,9 +862,13 @@ where
         let invocation_script = job.invocation_script.clone();
         let cancel_on_blocking_job_failure = job.cancel_on_blocking_job_failure.unwrap_or(true);
         let supports_termination = job.supports_termination.unwrap_or(false);
+        let priority = job.priority.unwrap_or(0);
+        let env = env_to_json(&job.env);
+        let container = container_to_json(&job.container);
         let status = JobStatus::Uninitialized;
-        let status_int = status.to_int();
+        let status_int = i64::from(status.to_int());
         job.status = Some(status);
+        job.priority = Some(priority);
 
         // Begin a transaction to ensure job and all relationships are created atomically
         let mut tx = match self.context.pool.begin().await {
@@ -827,10 +891,17 @@ where
                 invocation_script,
                 status,
                 scheduler_id,
-                failure_handler_id
+                failure_handler_id,
+                priority,
+                env,
+                working_dir,
+                shell,
+                container,
+                run_if,
+                concurrency_pool
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
-            RETURNING rowid
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+            RETURNING id
             "#,
             job.workflow_id,
             job.name,
@@ -842,6 +913,13 @@ where
             status_int,
             job.scheduler_id,
             job.failure_handler_id,
+            priority,
+            env,
+            job.working_dir,
+            job.shell,
+            container,
+            job.run_if,
+            job.concurrency_pool,
         )
         .fetch_all(&mut *tx)
         .await
@@ -1001,9 +1079,13 @@ where
             let invocation_script = job.invocation_script.clone();
             let cancel_on_blocking_job_failure = job.cancel_on_blocking_job_failure.unwrap_or(true);
             let supports_termination = job.supports_termination.unwrap_or(false);
+            let priority = job.priority.unwrap_or(0);
+            let env = env_to_json(&job.env);
+            let container = container_to_json(&job.container);
             let status = JobStatus::Uninitialized;
-            let status_int = status.to_int();
+            let status_int = i64::from(status.to_int());
             job.status = Some(status);
+            job.priority = Some(priority);
 
             // Insert the job
             let job_result = match sqlx::query!(
@@ -1019,10 +1101,17 @@ where
                     invocation_script,
                     status,
                     scheduler_id,
-                    failure_handler_id
+                    failure_handler_id,
+                    priority,
+                    env,
+                    working_dir,
+                    shell,
+                    container,
+                    run_if,
+                    concurrency_pool
                 )
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
-                RETURNING rowid
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+                RETURNING id
                 "#,
                 job.workflow_id,
                 job.name,
@@ -1034,6 +1123,13 @@ where
                 status_int,
                 job.scheduler_id,
                 job.failure_handler_id,
+                priority,
+                env,
+                job.working_dir,
+                job.shell,
+                container,
+                job.run_if,
+                job.concurrency_pool,
             )
             .fetch_one(&mut *transaction)
             .await
@@ -1311,11 +1407,11 @@ where
         );
 
         // Build base query
-        let base_query = "SELECT id, workflow_id, name, command, resource_requirements_id, invocation_script, status, cancel_on_blocking_job_failure, supports_termination, scheduler_id, failure_handler_id, attempt_id FROM job".to_string();
+        let base_query = "SELECT id, workflow_id, name, command, resource_requirements_id, invocation_script, status, cancel_on_blocking_job_failure, supports_termination, scheduler_id, failure_handler_id, attempt_id, priority, env, working_dir, shell, container, run_if, concurrency_pool, not_before, excluded_hostname FROM job".to_string();
 
         // Build WHERE clause conditions
         let mut where_conditions = vec!["workflow_id = ?".to_string()];
-        let mut bind_values: Vec<Box<dyn sqlx::Encode<'_, sqlx::Sqlite> + Send>> =
+        let mut bind_values: Vec<Box<dyn sqlx::Encode<'_, Db> + Send>> =
             vec![Box::new(workflow_id)];
 
         if let Some(job_status) = &status {
@@ -1398,8 +1494,8 @@ where
                 }
             } else {
                 // Create job model without relationships for better performance
-                let status_int: i32 = record.get("status");
-                let status = match JobStatus::from_int(status_int) {
+                let status_int: i64 = record.get("status");
+                let status = match JobStatus::from_int(status_int as i32) {
                     Ok(s) => s,
                     Err(e) => {
                         error!(
@@ -1431,6 +1527,15 @@ where
                     schedule_compute_nodes: None,
                     failure_handler_id: record.try_get("failure_handler_id").ok(),
                     attempt_id: record.try_get("attempt_id").ok(),
+                    priority: record.try_get("priority").ok(),
+                    env: env_from_json(record.try_get("env").ok().flatten()),
+                    working_dir: record.try_get("working_dir").ok().flatten(),
+                    shell: record.try_get("shell").ok().flatten(),
+                    container: container_from_json(record.try_get("container").ok().flatten()),
+                    run_if: record.try_get("run_if").ok().flatten(),
+                    concurrency_pool: record.try_get("concurrency_pool").ok().flatten(),
+                    not_before: not_before_to_rfc3339(record.try_get("not_before").ok().flatten()),
+                    excluded_hostname: record.try_get("excluded_hostname").ok().flatten(),
                 });
             }
         }
@@ -1724,7 +1829,9 @@ where
         }
 
         // Update the job (only non-relationship fields)
-        let status_int = body.status.map(|s| s.to_int());
+        let status_int = body.status.map(|s| i64::from(s.to_int()));
+        let env = env_to_json(&body.env);
+        let container = container_to_json(&body.container);
 
         let result = match sqlx::query!(
             r#"
@@ -1738,7 +1845,14 @@ where
                 ,supports_termination = COALESCE($6, supports_termination)
                 ,resource_requirements_id = COALESCE($7, resource_requirements_id)
                 ,scheduler_id = COALESCE($8, scheduler_id)
-            WHERE id = $9
+                ,priority = COALESCE($9, priority)
+                ,env = COALESCE($10, env)
+                ,working_dir = COALESCE($11, working_dir)
+                ,shell = COALESCE($12, shell)
+                ,container = COALESCE($13, container)
+                ,run_if = COALESCE($14, run_if)
+                ,concurrency_pool = COALESCE($15, concurrency_pool)
+            WHERE id = $16
         "#,
             body.name,
             status_int,
@@ -1748,6 +1862,13 @@ where
             body.supports_termination,
             body.resource_requirements_id,
             body.scheduler_id,
+            body.priority,
+            env,
+            body.working_dir,
+            body.shell,
+            container,
+            body.run_if,
+            body.concurrency_pool,
             id,
         )
         .execute(self.context.pool.as_ref())
@@ -1832,7 +1953,7 @@ where
             context.get().0.clone()
         );
 
-        let status_int = status.to_int();
+        let status_int = i64::from(status.to_int());
 
         let result = match sqlx::query!(
             r#"
@@ -1922,7 +2043,7 @@ where
         };
 
         let mut jobs_to_reinitialize = Vec::new();
-        let uninitialized_status = models::JobStatus::Uninitialized.to_int();
+        let uninitialized_status = i64::from(models::JobStatus::Uninitialized.to_int());
 
         // First pass: identify jobs with changed inputs
         for job_row in &jobs {
@@ -2124,12 +2245,12 @@ where
         }
 
         // Update all jobs with the given workflow_id that are not already uninitialized
-        let uninitialized_status = JobStatus::Uninitialized.to_int();
+        let uninitialized_status = i64::from(JobStatus::Uninitialized.to_int());
 
         let result = match sqlx::query!(
             r#"
             UPDATE job
-            SET status = $1
+            SET status = $1, not_before = NULL, excluded_hostname = NULL
             WHERE workflow_id = $2 AND status != $1
             "#,
             uninitialized_status,
@@ -2148,7 +2269,7 @@ where
 
         // Clear active_compute_node_id for all jobs in the workflow
         if let Err(e) = sqlx::query!(
-            "UPDATE job_internal SET active_compute_node_id = NULL WHERE job_id IN (SELECT id FROM job WHERE workflow_id = ?)",
+            "UPDATE job_internal SET active_compute_node_id = NULL WHERE job_id IN (SELECT id FROM job WHERE workflow_id = $1)",
             id
         )
         .execute(self.context.pool.as_ref())
@@ -2181,19 +2302,54 @@ where
         id: i64,
         run_id: i64,
         max_retries: i32,
+        delay_seconds: Option<f64>,
+        avoid_compute_node_id: Option<i64>,
         context: &C,
     ) -> Result<RetryJobResponse, ApiError> {
         debug!(
-            "retry_job({}, {}, {}) - X-Span-ID: {:?}",
+            "retry_job({}, {}, {}, {:?}, {:?}) - X-Span-ID: {:?}",
             id,
             run_id,
             max_retries,
+            delay_seconds,
+            avoid_compute_node_id,
             context.get().0.clone()
         );
 
-        // Use a transaction with BEGIN IMMEDIATE to prevent race conditions
+        if let Some(delay) = delay_seconds
+            && !(delay.is_finite() && delay >= 0.0)
+        {
+            let error_response = models::ErrorResponse::new(serde_json::json!({
+                "message": format!("delay_seconds must be a non-negative number: {}", delay)
+            }));
+            return Ok(RetryJobResponse::UnprocessableContentErrorResponse(
+                error_response,
+            ));
+        }
+
+        // A job never moves to another workflow, so its workflow can be looked up before
+        // taking the workflow's write lock.
+        let workflow_id: i64 = match sqlx::query_scalar("SELECT workflow_id FROM job WHERE id = $1")
+            .bind(id)
+            .fetch_optional(self.context.pool.as_ref())
+            .await
+        {
+            Ok(Some(workflow_id)) => workflow_id,
+            Ok(None) => {
+                let error_response = models::ErrorResponse::new(serde_json::json!({
+                    "message": format!("Job not found with ID: {}", id)
+                }));
+                return Ok(RetryJobResponse::NotFoundErrorResponse(error_response));
+            }
+            Err(e) => {
+                error!("Database error: {}", e);
+                return Err(database_error(e));
+            }
+        };
+
+        // Use a write-locked transaction to prevent race conditions
         // where multiple processes might try to retry the same job simultaneously.
-        let mut tx = match self.context.pool.begin().await {
+        let mut tx = match db::begin_write(&self.context.pool, workflow_id).await {
             Ok(tx) => tx,
             Err(e) => {
                 error!("Failed to begin transaction: {}", e);
@@ -2201,24 +2357,19 @@ where
             }
         };
 
-        // Acquire write lock immediately to prevent concurrent retries
-        if let Err(e) = sqlx::query("BEGIN IMMEDIATE").execute(&mut *tx).await {
-            // SQLite may return an error if already in a transaction, which is fine
-            debug!("BEGIN IMMEDIATE returned (expected in transaction): {}", e);
-        }
-
         // Get the job and verify it's in a retryable state
         // Using sqlx::query instead of sqlx::query! to handle nullable columns properly
         let job_record = match sqlx::query(
             r#"
             SELECT j.id, j.workflow_id, j.name, j.command, j.status, j.failure_handler_id, j.attempt_id,
                    j.invocation_script, j.cancel_on_blocking_job_failure, j.supports_termination,
-                   j.resource_requirements_id, j.scheduler_id,
-                   ws.run_id as workflow_run_id
+                   j.resource_requirements_id, j.scheduler_id, j.priority,
+                   j.env, j.working_dir, j.shell, j.container, j.run_if,
+                   j.concurrency_pool, ws.run_id as workflow_run_id
             FROM job j
             JOIN workflow w ON j.workflow_id = w.id
             JOIN workflow_status ws ON w.status_id = ws.id
-            WHERE j.id = ?
+            WHERE j.id = $1
             "#,
         )
         .bind(id)
@@ -2240,10 +2391,9 @@ where
 
         // Extract fields from the row
         let job_id: i64 = job_record.get("id");
-        let workflow_id: i64 = job_record.get("workflow_id");
         let name: String = job_record.get("name");
         let command: String = job_record.get("command");
-        let status_int: i32 = job_record.get("status");
+        let status_int: i64 = job_record.get("status");
         let failure_handler_id: Option<i64> = job_record.get("failure_handler_id");
         let attempt_id: i64 = job_record.get("attempt_id");
         let invocation_script: Option<String> = job_record.get("invocation_script");
@@ -2252,6 +2402,13 @@ where
         let supports_termination: Option<bool> = job_record.get("supports_termination");
         let resource_requirements_id: Option<i64> = job_record.get("resource_requirements_id");
         let scheduler_id: Option<i64> = job_record.get("scheduler_id");
+        let priority: i64 = job_record.get("priority");
+        let env: Option<String> = job_record.get("env");
+        let working_dir: Option<String> = job_record.get("working_dir");
+        let shell: Option<String> = job_record.get("shell");
+        let container: Option<String> = job_record.get("container");
+        let run_if: Option<String> = job_record.get("run_if");
+        let concurrency_pool: Option<String> = job_record.get("concurrency_pool");
         let workflow_run_id: i64 = job_record.get("workflow_run_id");
 
         // Verify run_id matches
@@ -2271,7 +2428,7 @@ where
         // Note: Running is allowed because the job runner may call retry_job before complete_job
         // when handling failure recovery (the job has finished locally but the server hasn't been
         // notified yet).
-        let current_status = match JobStatus::from_int(status_int) {
+        let current_status = match JobStatus::from_int(status_int as i32) {
             Ok(s) => s,
             Err(e) => {
                 error!("Failed to parse job status: {}", e);
@@ -2310,17 +2467,54 @@ where
         // Get current attempt_id and increment
         let new_attempt = attempt_id + 1;
 
+        // The job is not claimable until the delay has passed.
+        let now_ms = Utc::now().timestamp_millis();
+        let not_before = delay_seconds
+            .filter(|delay| *delay > 0.0)
+            .map(|delay| now_ms + (delay * 1000.0).ceil() as i64);
+
+        // Workers on the host of the failed attempt do not claim the job.
+        let excluded_hostname: Option<String> = match avoid_compute_node_id {
+            Some(compute_node_id) => {
+                match sqlx::query_scalar("SELECT hostname FROM compute_node WHERE id = $1")
+                    .bind(compute_node_id)
+                    .fetch_optional(&mut *tx)
+                    .await
+                {
+                    Ok(Some(hostname)) => Some(hostname),
+                    Ok(None) => {
+                        let error_response = models::ErrorResponse::new(serde_json::json!({
+                            "message": format!(
+                                "Compute node not found with ID: {}",
+                                compute_node_id
+                            )
+                        }));
+                        return Ok(RetryJobResponse::UnprocessableContentErrorResponse(
+                            error_response,
+                        ));
+                    }
+                    Err(e) => {
+                        error!("Database error: {}", e);
+                        return Err(database_error(e));
+                    }
+                }
+            }
+            None => None,
+        };
+
         // Update job status to Ready and increment attempt_id
         let ready_status = JobStatus::Ready.to_int();
         if let Err(e) = sqlx::query(
             r#"
             UPDATE job
-            SET status = ?, attempt_id = ?
-            WHERE id = ?
+            SET status = $1, attempt_id = $2, not_before = $3, excluded_hostname = $4
+            WHERE id = $5
             "#,
         )
         .bind(ready_status)
         .bind(new_attempt)
+        .bind(not_before)
+        .bind(&excluded_hostname)
         .bind(id)
         .execute(&mut *tx)
         .await
@@ -2337,10 +2531,10 @@ where
             "previous_attempt": attempt_id,
             "new_attempt": new_attempt,
             "run_id": run_id,
+            "not_before": not_before_to_rfc3339(not_before),
+            "excluded_hostname": excluded_hostname,
             "message": format!("Job with name = {} retried: attempt {} -> {}", name, attempt_id, new_attempt),
         });
-        let timestamp = Utc::now().timestamp_millis();
-
         if let Err(e) = sqlx::query(
             r#"
             INSERT INTO event (workflow_id, timestamp, data)
@@ -2348,7 +2542,7 @@ where
             "#,
         )
         .bind(workflow_id)
-        .bind(timestamp)
+        .bind(now_ms)
         .bind(event_data.to_string())
         .execute(&mut *tx)
         .await
@@ -2389,6 +2583,15 @@ where
             scheduler_id,
             failure_handler_id,
             attempt_id: Some(new_attempt),
+            priority: Some(priority),
+            env: env_from_json(env),
+            working_dir,
+            shell,
+            container: container_from_json(container),
+            run_if,
+            concurrency_pool,
+            not_before: not_before_to_rfc3339(not_before),
+            excluded_hostname,
         };
 
         Ok(RetryJobResponse::SuccessfulResponse(job_model))
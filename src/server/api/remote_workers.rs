@@ -90,13 +90,14 @@ where
             }
         };
 
-        // Insert workers (ignore duplicates using INSERT OR IGNORE)
+        // Insert workers (ignore duplicates using ON CONFLICT DO NOTHING)
         let mut created_workers = Vec::new();
         for worker in &workers {
             match sqlx::query!(
                 r#"
-                INSERT OR IGNORE INTO remote_worker (worker, workflow_id)
+                INSERT INTO remote_worker (worker, workflow_id)
                 VALUES ($1, $2)
+                ON CONFLICT DO NOTHING
                 "#,
                 worker,
                 workflow_id,
@@ -161,7 +162,7 @@ where
 
         // Fetch all workers for the workflow
         let records = match sqlx::query(
-            "SELECT worker, workflow_id FROM remote_worker WHERE workflow_id = ? ORDER BY worker",
+            "SELECT worker, workflow_id FROM remote_worker WHERE workflow_id = $1 ORDER BY worker",
         )
         .bind(workflow_id)
         .fetch_all(self.context.pool.as_ref())
@@ -155,7 +155,7 @@ where
                 ,runtime_s
             )
             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-            RETURNING rowid
+            RETURNING id
         "#,
             body.workflow_id,
             body.name,
@@ -15,6 +15,7 @@ use crate::server::api_types::{
 use crate::models;
 
 use super::{ApiContext, MAX_RECORD_TRANSFER_COUNT, SqlQueryBuilder, database_error};
+use crate::server::db::Db;
 
 /// Trait defining result-related API operations
 #[async_trait]
@@ -99,7 +100,7 @@ where
             body,
             context.get().0.clone()
         );
-        let status = body.status.to_int();
+        let status = i64::from(body.status.to_int());
 
         let attempt_id = body.attempt_id.unwrap_or(1);
         let result = match sqlx::query!(
@@ -121,7 +122,7 @@ where
                 ,avg_cpu_percent
             )
             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
-            RETURNING rowid
+            RETURNING id
         "#,
             body.job_id,
             body.workflow_id,
@@ -301,7 +302,7 @@ where
         let col_prefix = if show_all_results { "" } else { "r." };
 
         let mut where_conditions = vec![format!("{}workflow_id = ?", col_prefix)];
-        let mut bind_values: Vec<Box<dyn sqlx::Encode<'_, sqlx::Sqlite> + Send>> =
+        let mut bind_values: Vec<Box<dyn sqlx::Encode<'_, Db> + Send>> =
             vec![Box::new(workflow_id)];
 
         if let Some(j_id) = job_id {
@@ -483,7 +484,7 @@ where
             }
         };
 
-        let status_int = body.status.to_int();
+        let status_int = i64::from(body.status.to_int());
 
         let result = match sqlx::query!(
             r#"
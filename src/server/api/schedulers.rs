@@ -190,6 +190,15 @@ impl SchedulersApiImpl {
     }
 }
 
+/// Split an HPC job ID filter into the scheduler ID and, for tasks of a Slurm job array
+/// ("<array_job_id>_<array_task_id>"), the array task ID.
+fn parse_hpc_job_id(hpc_job_id: &str) -> (i64, Option<i64>) {
+    match hpc_job_id.split_once('_') {
+        Some((job_id, task_id)) => (job_id.parse().unwrap_or(0), task_id.parse().ok()),
+        None => (hpc_job_id.parse().unwrap_or(0), None),
+    }
+}
+
 #[async_trait]
 impl<C> SchedulersApi<C> for SchedulersApiImpl
 where
@@ -216,7 +225,7 @@ where
                 ,num_cpus
             )
             VALUES ($1, $2, $3)
-            RETURNING rowid
+            RETURNING id
         "#,
             body.workflow_id,
             body.memory,
@@ -256,15 +265,17 @@ where
                 ,scheduler_config_id
                 ,scheduler_type
                 ,status
+                ,array_task_id
             )
-            VALUES ($1, $2, $3, $4, $5)
-            RETURNING rowid
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id
         "#,
             body.workflow_id,
             body.scheduler_id,
             body.scheduler_config_id,
             body.scheduler_type,
             body.status,
+            body.array_task_id,
         )
         .fetch_one(self.context.pool.as_ref())
         .await
@@ -309,7 +320,7 @@ where
                 ,extra
             )
             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
-            RETURNING rowid
+            RETURNING id
         "#,
             body.workflow_id,
             body.name,
@@ -527,6 +538,7 @@ where
         let record = match sqlx::query(
             r#"
             SELECT id, workflow_id, scheduler_id, scheduler_config_id, scheduler_type, status
+                ,array_task_id
             FROM scheduled_compute_node
             WHERE id = $1
             "#,
@@ -557,6 +569,7 @@ where
             scheduler_config_id: record.get("scheduler_config_id"),
             scheduler_type: record.get("scheduler_type"),
             status: record.get("status"),
+            array_task_id: record.get("array_task_id"),
         };
 
         Ok(GetScheduledComputeNodeResponse::HTTP(
@@ -756,12 +769,17 @@ where
         );
 
         // Build base query
-        let base_query = "SELECT id, workflow_id, scheduler_id, scheduler_config_id, scheduler_type, status FROM scheduled_compute_node".to_string();
+        let base_query = "SELECT id, workflow_id, scheduler_id, scheduler_config_id, scheduler_type, status, array_task_id FROM scheduled_compute_node".to_string();
 
         let mut where_conditions = vec!["workflow_id = ?".to_string()];
 
-        if scheduler_id.is_some() {
+        // Tasks of a Slurm job array are filtered as <array_job_id>_<array_task_id>.
+        let hpc_job_id = scheduler_id.as_deref().map(parse_hpc_job_id);
+        if let Some((_, array_task_id)) = hpc_job_id {
             where_conditions.push("scheduler_id = ?".to_string());
+            if array_task_id.is_some() {
+                where_conditions.push("array_task_id = ?".to_string());
+            }
         }
         if scheduler_config_id.is_some() {
             where_conditions.push("scheduler_config_id = ?".to_string());
@@ -783,8 +801,11 @@ where
         // Execute the query with dynamic parameter binding
         let mut sqlx_query = sqlx::query(&query).bind(workflow_id);
 
-        if let Some(ref sched_id) = scheduler_id {
-            sqlx_query = sqlx_query.bind(sched_id.parse::<i64>().unwrap_or(0));
+        if let Some((sched_id, array_task_id)) = hpc_job_id {
+            sqlx_query = sqlx_query.bind(sched_id);
+            if let Some(task_id) = array_task_id {
+                sqlx_query = sqlx_query.bind(task_id);
+            }
         }
         if let Some(ref sched_config_id) = scheduler_config_id {
             sqlx_query = sqlx_query.bind(sched_config_id.parse::<i64>().unwrap_or(0));
@@ -810,6 +831,7 @@ where
                 scheduler_config_id: record.get("scheduler_config_id"),
                 scheduler_type: record.get("scheduler_type"),
                 status: record.get("status"),
+                array_task_id: record.get("array_task_id"),
             });
         }
 
@@ -822,8 +844,11 @@ where
 
         let mut count_sqlx_query = sqlx::query(&count_query).bind(workflow_id);
 
-        if let Some(ref sched_id) = scheduler_id {
-            count_sqlx_query = count_sqlx_query.bind(sched_id.parse::<i64>().unwrap_or(0));
+        if let Some((sched_id, array_task_id)) = hpc_job_id {
+            count_sqlx_query = count_sqlx_query.bind(sched_id);
+            if let Some(task_id) = array_task_id {
+                count_sqlx_query = count_sqlx_query.bind(task_id);
+            }
         }
         if let Some(ref sched_config_id) = scheduler_config_id {
             count_sqlx_query = count_sqlx_query.bind(sched_config_id.parse::<i64>().unwrap_or(0));
@@ -1083,7 +1108,8 @@ where
                 ,scheduler_config_id = COALESCE($3, scheduler_config_id)
                 ,scheduler_type = COALESCE($4, scheduler_type)
                 ,status = COALESCE($5, status)
-            WHERE id = $6
+                ,array_task_id = COALESCE($6, array_task_id)
+            WHERE id = $7
             "#,
         )
         .bind(body.workflow_id)
@@ -1091,6 +1117,7 @@ where
         .bind(body.scheduler_config_id)
         .bind(body.scheduler_type)
         .bind(body.status)
+        .bind(body.array_task_id)
         .bind(id)
         .execute(self.context.pool.as_ref())
         .await
@@ -20,6 +20,9 @@ impl SqlQueryBuilder {
         }
     }
 
+    /// Set the WHERE clause. Conditions use `?` placeholders, which `build` numbers as
+    /// `$1`, `$2`, ... so that the query runs on both SQLite and PostgreSQL. Bind the
+    /// values in the order that the placeholders appear.
     pub fn with_where(mut self, where_clause: String) -> Self {
         self.where_clause = Some(where_clause);
         self
@@ -57,7 +60,7 @@ impl SqlQueryBuilder {
 
         if let Some(where_clause) = self.where_clause {
             query.push_str(" WHERE ");
-            query.push_str(&where_clause);
+            query.push_str(&number_placeholders(&where_clause));
         }
 
         if let Some(order_by) = self.order_by_clause {
@@ -78,3 +81,25 @@ impl SqlQueryBuilder {
         query
     }
 }
+
+/// Replace each `?` placeholder outside of string literals with a numbered `$N` placeholder.
+fn number_placeholders(sql: &str) -> String {
+    let mut result = String::with_capacity(sql.len() + 8);
+    let mut in_literal = false;
+    let mut index = 0;
+    for c in sql.chars() {
+        match c {
+            '\'' => {
+                in_literal = !in_literal;
+                result.push(c);
+            }
+            '?' if !in_literal => {
+                index += 1;
+                result.push('$');
+                result.push_str(&index.to_string());
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
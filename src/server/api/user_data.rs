@@ -262,7 +262,7 @@ where
 
         // Query the user_data table for the specified ID
         let row = match sqlx::query(
-            "SELECT id, workflow_id, name, is_ephemeral, data FROM user_data WHERE id = ?",
+            "SELECT id, workflow_id, name, is_ephemeral, data FROM user_data WHERE id = $1",
         )
         .bind(id)
         .fetch_optional(&*self.context.pool)
@@ -383,7 +383,7 @@ where
 
         // Add name filter
         if name.is_some() {
-            where_conditions.push("ud.name LIKE ?".to_string());
+            where_conditions.push("LOWER(ud.name) LIKE LOWER(?)".to_string());
         }
 
         // Add is_ephemeral filter
@@ -435,12 +435,16 @@ where
 
         let mut items: Vec<models::UserDataModel> = Vec::new();
         for record in records {
+            let data_str: Option<String> = record.get("data");
+            let data = data_str
+                .and_then(|s| serde_json::from_str(&s).ok())
+                .unwrap_or_default();
             items.push(models::UserDataModel {
                 id: Some(record.get("id")),
                 workflow_id: record.get("workflow_id"),
                 name: record.get("name"),
                 is_ephemeral: Some(record.get::<i64, _>("is_ephemeral") != 0), // Convert INTEGER to bool
-                data: Some(record.get("data")),
+                data: Some(data),
             });
         }
 
@@ -683,7 +687,7 @@ impl UserDataApiImpl {
     /// the job's status is JobStatus::Completed (meaning the job completed successfully and should have created
     /// the user_data) but the user_data field is NULL.
     async fn find_missing_job_created_data(&self, workflow_id: i64) -> Result<Vec<i64>, ApiError> {
-        let completed_status = models::JobStatus::Completed.to_int();
+        let completed_status = i64::from(models::JobStatus::Completed.to_int());
 
         let rows = match sqlx::query!(
             r#"
@@ -59,7 +59,10 @@ fn validate_action_config(
                 "scheduler_type",
                 "num_allocations",
                 "start_one_worker_per_node",
+                "use_job_array",
                 "max_parallel_jobs",
+                "depends_on_scheduler_ids",
+                "dependency_type",
             ];
 
             // Check for unsupported fields
@@ -100,6 +103,12 @@ fn validate_action_config(
                 return Err("'start_one_worker_per_node' must be a boolean".to_string());
             }
 
+            if let Some(use_job_array) = config_obj.get("use_job_array")
+                && !use_job_array.is_boolean()
+            {
+                return Err("'use_job_array' must be a boolean".to_string());
+            }
+
             if let Some(max_parallel_jobs) = config_obj.get("max_parallel_jobs")
                 && !max_parallel_jobs.is_i64()
                 && !max_parallel_jobs.is_u64()
@@ -107,6 +116,21 @@ fn validate_action_config(
                 return Err("'max_parallel_jobs' must be an integer".to_string());
             }
 
+            if let Some(depends_on_scheduler_ids) = config_obj.get("depends_on_scheduler_ids") {
+                let ids = depends_on_scheduler_ids
+                    .as_array()
+                    .ok_or("'depends_on_scheduler_ids' must be an array")?;
+                if ids.iter().any(|id| !id.is_i64() && !id.is_u64()) {
+                    return Err("'depends_on_scheduler_ids' must contain integers".to_string());
+                }
+            }
+
+            if let Some(dependency_type) = config_obj.get("dependency_type")
+                && !matches!(dependency_type.as_str(), Some("afterok") | Some("afterany"))
+            {
+                return Err("'dependency_type' must be 'afterok' or 'afterany'".to_string());
+            }
+
             Ok(())
         }
         _ => {
@@ -224,7 +248,8 @@ where
 
         let result = sqlx::query(
             "INSERT INTO workflow_action (workflow_id, trigger_type, action_type, action_config, job_ids, trigger_count, required_triggers, executed, persistent, is_recovery)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+             RETURNING id"
         )
         .bind(body.workflow_id)
         .bind(&body.trigger_type)
@@ -236,12 +261,12 @@ where
         .bind(if body.executed { 1 } else { 0 })
         .bind(if body.persistent { 1 } else { 0 })
         .bind(if body.is_recovery { 1 } else { 0 })
-        .execute(self.context.pool.as_ref())
+        .fetch_one(self.context.pool.as_ref())
         .await;
 
         match result {
-            Ok(result) => {
-                let id = result.last_insert_rowid();
+            Ok(row) => {
+                let id: i64 = row.get("id");
                 debug!("Created workflow action with id={}", id);
 
                 body.id = Some(id);
@@ -275,7 +300,7 @@ where
         let rows = sqlx::query(
             "SELECT id, workflow_id, trigger_type, action_type, action_config, job_ids, trigger_count, required_triggers, executed, executed_at, executed_by, persistent, is_recovery
              FROM workflow_action
-             WHERE workflow_id = ?
+             WHERE workflow_id = $1
              ORDER BY id"
         )
         .bind(workflow_id)
@@ -306,11 +331,11 @@ where
                             job_ids,
                             trigger_count: row.get("trigger_count"),
                             required_triggers: row.get("required_triggers"),
-                            executed: row.get::<i32, _>("executed") != 0,
+                            executed: row.get::<i64, _>("executed") != 0,
                             executed_at: row.get("executed_at"),
                             executed_by: row.get("executed_by"),
-                            persistent: row.get::<i32, _>("persistent") != 0,
-                            is_recovery: row.get::<i32, _>("is_recovery") != 0,
+                            persistent: row.get::<i64, _>("persistent") != 0,
+                            is_recovery: row.get::<i64, _>("is_recovery") != 0,
                         })
                     })
                     .collect();
@@ -361,11 +386,14 @@ where
                 (String::new(), Ok(Vec::new()))
             } else {
                 // Build IN clause with placeholders
-                let placeholders = types.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+                let placeholders = (2..types.len() + 2)
+                    .map(|i| format!("${}", i))
+                    .collect::<Vec<_>>()
+                    .join(", ");
                 let query_str = format!(
                     "SELECT id, workflow_id, trigger_type, action_type, action_config, job_ids, trigger_count, required_triggers, executed, executed_at, executed_by, persistent, is_recovery
                      FROM workflow_action
-                     WHERE workflow_id = ? AND trigger_count >= required_triggers AND executed = 0 AND trigger_type IN ({})
+                     WHERE workflow_id = $1 AND trigger_count >= required_triggers AND executed = 0 AND trigger_type IN ({})
                      ORDER BY id",
                     placeholders
                 );
@@ -384,7 +412,7 @@ where
             // No filter - get all pending actions
             let query_str = "SELECT id, workflow_id, trigger_type, action_type, action_config, job_ids, trigger_count, required_triggers, executed, executed_at, executed_by, persistent, is_recovery
                  FROM workflow_action
-                 WHERE workflow_id = ? AND trigger_count >= required_triggers AND executed = 0
+                 WHERE workflow_id = $1 AND trigger_count >= required_triggers AND executed = 0
                  ORDER BY id".to_string();
             (
                 query_str.clone(),
@@ -421,11 +449,11 @@ where
                             job_ids,
                             trigger_count: row.get("trigger_count"),
                             required_triggers: row.get("required_triggers"),
-                            executed: row.get::<i32, _>("executed") != 0,
+                            executed: row.get::<i64, _>("executed") != 0,
                             executed_at: row.get("executed_at"),
                             executed_by: row.get("executed_by"),
-                            persistent: row.get::<i32, _>("persistent") != 0,
-                            is_recovery: row.get::<i32, _>("is_recovery") != 0,
+                            persistent: row.get::<i64, _>("persistent") != 0,
+                            is_recovery: row.get::<i64, _>("is_recovery") != 0,
                         })
                     })
                     .collect();
@@ -473,7 +501,7 @@ where
 
         // Verify action exists and belongs to this workflow
         let action_check = sqlx::query(
-            "SELECT workflow_id, executed, persistent FROM workflow_action WHERE id = ?",
+            "SELECT workflow_id, executed, persistent FROM workflow_action WHERE id = $1",
         )
         .bind(action_id)
         .fetch_optional(self.context.pool.as_ref())
@@ -482,8 +510,8 @@ where
         let is_persistent = match action_check {
             Ok(Some(record)) => {
                 let workflow_id_col: i64 = record.get("workflow_id");
-                let executed_col: i32 = record.get("executed");
-                let persistent_col: i32 = record.get("persistent");
+                let executed_col: i64 = record.get("executed");
+                let persistent_col: i64 = record.get("persistent");
 
                 if workflow_id_col != workflow_id {
                     let error_response = models::ErrorResponse::new(serde_json::json!({
@@ -529,8 +557,8 @@ where
             // A more robust implementation would use a separate workflow_action_claims table
             sqlx::query(
                 "UPDATE workflow_action
-                 SET executed_at = ?
-                 WHERE id = ?",
+                 SET executed_at = $1
+                 WHERE id = $2",
             )
             .bind(&now)
             .bind(action_id)
@@ -539,8 +567,8 @@ where
         } else {
             sqlx::query(
                 "UPDATE workflow_action
-                 SET executed = 1, executed_at = ?, executed_by = ?
-                 WHERE id = ? AND executed = 0",
+                 SET executed = 1, executed_at = $1, executed_by = $2
+                 WHERE id = $3 AND executed = 0",
             )
             .bind(&now)
             .bind(compute_node_id)
@@ -597,7 +625,7 @@ impl WorkflowActionsApiImpl {
         // Get all actions of this trigger type for this workflow that haven't reached required triggers yet
         let actions = match sqlx::query(
             "SELECT id, job_ids, trigger_count, required_triggers FROM workflow_action
-             WHERE workflow_id = ? AND trigger_type = ? AND trigger_count < required_triggers",
+             WHERE workflow_id = $1 AND trigger_type = $2 AND trigger_count < required_triggers",
         )
         .bind(workflow_id)
         .bind(trigger_type)
@@ -682,7 +710,7 @@ impl WorkflowActionsApiImpl {
             if trigger_increment > 0 {
                 // Increment trigger_count by the calculated amount
                 match sqlx::query(
-                    "UPDATE workflow_action SET trigger_count = trigger_count + ? WHERE id = ?",
+                    "UPDATE workflow_action SET trigger_count = trigger_count + $1 WHERE id = $2",
                 )
                 .bind(trigger_increment)
                 .bind(action_id)
@@ -735,7 +763,7 @@ impl WorkflowActionsApiImpl {
 
         for job_id in job_ids {
             let job_status =
-                match sqlx::query_scalar::<_, i64>("SELECT status FROM job WHERE id = ?")
+                match sqlx::query_scalar::<_, i64>("SELECT status FROM job WHERE id = $1")
                     .bind(job_id)
                     .fetch_optional(self.context.pool.as_ref())
                     .await
@@ -761,12 +789,14 @@ impl WorkflowActionsApiImpl {
                         || job_status == JobStatus::Failed.to_int() as i64
                         || job_status == JobStatus::Canceled.to_int() as i64
                         || job_status == JobStatus::Terminated.to_int() as i64
+                        || job_status == JobStatus::Skipped.to_int() as i64
                 }
                 "on_jobs_complete" => {
                     job_status == JobStatus::Completed.to_int() as i64
                         || job_status == JobStatus::Failed.to_int() as i64
                         || job_status == JobStatus::Canceled.to_int() as i64
                         || job_status == JobStatus::Terminated.to_int() as i64
+                        || job_status == JobStatus::Skipped.to_int() as i64
                 }
                 _ => false,
             };
@@ -792,7 +822,7 @@ impl WorkflowActionsApiImpl {
         );
 
         // First, delete all recovery actions (ephemeral actions created during recovery)
-        match sqlx::query("DELETE FROM workflow_action WHERE workflow_id = ? AND is_recovery = 1")
+        match sqlx::query("DELETE FROM workflow_action WHERE workflow_id = $1 AND is_recovery = 1")
             .bind(workflow_id)
             .execute(self.context.pool.as_ref())
             .await
@@ -817,7 +847,7 @@ impl WorkflowActionsApiImpl {
 
         // Reset executed flags for all remaining (non-recovery) actions
         match sqlx::query(
-            "UPDATE workflow_action SET executed = 0, executed_by = NULL WHERE workflow_id = ?",
+            "UPDATE workflow_action SET executed = 0, executed_by = NULL WHERE workflow_id = $1",
         )
         .bind(workflow_id)
         .execute(self.context.pool.as_ref())
@@ -840,7 +870,7 @@ impl WorkflowActionsApiImpl {
 
         // Get all actions for this workflow
         let actions = match sqlx::query(
-            "SELECT id, trigger_type, job_ids FROM workflow_action WHERE workflow_id = ?",
+            "SELECT id, trigger_type, job_ids FROM workflow_action WHERE workflow_id = $1",
         )
         .bind(workflow_id)
         .fetch_all(self.context.pool.as_ref())
@@ -891,7 +921,7 @@ impl WorkflowActionsApiImpl {
             };
 
             // Update the trigger_count for this action
-            match sqlx::query("UPDATE workflow_action SET trigger_count = ? WHERE id = ?")
+            match sqlx::query("UPDATE workflow_action SET trigger_count = $1 WHERE id = $2")
                 .bind(trigger_count)
                 .bind(action_id)
                 .execute(self.context.pool.as_ref())
@@ -0,0 +1,514 @@
+//! Workflow schedule-related API endpoints
+
+#![allow(clippy::too_many_arguments)]
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use croner::Cron;
+use log::{debug, error, info};
+use sqlx::Row;
+use swagger::{ApiError, Has, XSpanIdString};
+
+use crate::server::api_types::{
+    CreateWorkflowScheduleResponse, DeleteWorkflowScheduleResponse, GetWorkflowScheduleResponse,
+    ListWorkflowSchedulesResponse, UpdateWorkflowScheduleResponse,
+};
+
+use crate::models;
+
+use super::{ApiContext, MAX_RECORD_TRANSFER_COUNT, database_error};
+use crate::server::db::DbRow;
+
+const DEFAULT_TIMEZONE: &str = "UTC";
+
+const SELECT_COLUMNS: &str = "id, workflow_id, cron_expression, timezone, overlap_policy, \
+    working_directory, is_paused, next_run_time, last_run_time";
+
+/// Return the first time after `after` that matches a cron expression in a time zone.
+///
+/// The expression has the standard five fields or an extra leading seconds field.
+pub fn next_run_time(
+    cron_expression: &str,
+    timezone: &str,
+    after: DateTime<Utc>,
+) -> Result<DateTime<Utc>, String> {
+    let cron = Cron::new(cron_expression)
+        .with_seconds_optional()
+        .parse()
+        .map_err(|e| format!("Invalid cron expression '{}': {}", cron_expression, e))?;
+    let tz: chrono_tz::Tz = timezone
+        .parse()
+        .map_err(|_| format!("Invalid timezone '{}'", timezone))?;
+    cron.find_next_occurrence(&after.with_timezone(&tz), false)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|e| {
+            format!(
+                "Cron expression '{}' has no upcoming run time: {}",
+                cron_expression, e
+            )
+        })
+}
+
+/// Convert a stored run time (Unix milliseconds) to the RFC3339 string exposed by the API.
+fn run_time_to_rfc3339(value: Option<i64>) -> Option<String> {
+    value
+        .and_then(DateTime::from_timestamp_millis)
+        .map(|t| t.to_rfc3339())
+}
+
+fn row_to_model(record: &DbRow) -> models::WorkflowScheduleModel {
+    models::WorkflowScheduleModel {
+        id: Some(record.get("id")),
+        workflow_id: record.get("workflow_id"),
+        cron_expression: record.get("cron_expression"),
+        timezone: Some(record.get("timezone")),
+        overlap_policy: record
+            .get::<String, _>("overlap_policy")
+            .parse::<models::ScheduleOverlapPolicy>()
+            .ok(),
+        working_directory: record.get("working_directory"),
+        is_paused: Some(record.get::<i64, _>("is_paused") != 0),
+        next_run_time: run_time_to_rfc3339(record.get("next_run_time")),
+        last_run_time: run_time_to_rfc3339(record.get("last_run_time")),
+    }
+}
+
+/// Trait defining workflow schedule-related API operations
+#[async_trait]
+pub trait WorkflowSchedulesApi<C> {
+    /// Store a workflow schedule. `created_by` is the authenticated user creating it.
+    async fn create_workflow_schedule(
+        &self,
+        body: models::WorkflowScheduleModel,
+        created_by: Option<String>,
+        context: &C,
+    ) -> Result<CreateWorkflowScheduleResponse, ApiError>;
+
+    /// Retrieve a workflow schedule by ID.
+    async fn get_workflow_schedule(
+        &self,
+        id: i64,
+        context: &C,
+    ) -> Result<GetWorkflowScheduleResponse, ApiError>;
+
+    /// Retrieve workflow schedules, optionally for one workflow. If `accessible_workflow_ids`
+    /// is set, only schedules of those workflows are returned.
+    async fn list_workflow_schedules(
+        &self,
+        workflow_id: Option<i64>,
+        accessible_workflow_ids: Option<Vec<i64>>,
+        offset: i64,
+        limit: i64,
+        context: &C,
+    ) -> Result<ListWorkflowSchedulesResponse, ApiError>;
+
+    /// Update a workflow schedule.
+    async fn update_workflow_schedule(
+        &self,
+        id: i64,
+        body: models::WorkflowScheduleModel,
+        context: &C,
+    ) -> Result<UpdateWorkflowScheduleResponse, ApiError>;
+
+    /// Delete a workflow schedule.
+    async fn delete_workflow_schedule(
+        &self,
+        id: i64,
+        body: Option<serde_json::Value>,
+        context: &C,
+    ) -> Result<DeleteWorkflowScheduleResponse, ApiError>;
+}
+
+/// Implementation of workflow schedules API for the server
+#[derive(Clone)]
+pub struct WorkflowSchedulesApiImpl {
+    pub context: ApiContext,
+}
+
+impl WorkflowSchedulesApiImpl {
+    pub fn new(context: ApiContext) -> Self {
+        Self { context }
+    }
+
+    async fn fetch_schedule(
+        &self,
+        id: i64,
+    ) -> Result<Option<models::WorkflowScheduleModel>, ApiError> {
+        let query = format!(
+            "SELECT {} FROM workflow_schedule WHERE id = $1",
+            SELECT_COLUMNS
+        );
+        match sqlx::query(&query)
+            .bind(id)
+            .fetch_optional(self.context.pool.as_ref())
+            .await
+        {
+            Ok(record) => Ok(record.as_ref().map(row_to_model)),
+            Err(e) => {
+                error!("Database error: {}", e);
+                Err(database_error(e))
+            }
+        }
+    }
+}
+
+fn not_found_error(id: i64) -> models::ErrorResponse {
+    models::ErrorResponse::new(serde_json::json!({
+        "message": format!("Workflow schedule not found with ID: {}", id)
+    }))
+}
+
+fn invalid_schedule_error(message: String) -> models::ErrorResponse {
+    models::ErrorResponse::new(serde_json::json!({ "message": message }))
+}
+
+/// Check that a working directory is an absolute path to an existing directory on this host.
+pub fn validate_working_directory(working_directory: &str) -> Result<(), String> {
+    let path = std::path::Path::new(working_directory);
+    if !path.is_absolute() {
+        return Err(format!(
+            "Working directory '{}' must be an absolute path",
+            working_directory
+        ));
+    }
+    if !path.is_dir() {
+        return Err(format!(
+            "Working directory '{}' is not an existing directory on the server host",
+            working_directory
+        ));
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl<C> WorkflowSchedulesApi<C> for WorkflowSchedulesApiImpl
+where
+    C: Has<XSpanIdString> + Send + Sync,
+{
+    /// Store a workflow schedule.
+    async fn create_workflow_schedule(
+        &self,
+        body: models::WorkflowScheduleModel,
+        created_by: Option<String>,
+        context: &C,
+    ) -> Result<CreateWorkflowScheduleResponse, ApiError> {
+        debug!(
+            "create_workflow_schedule({:?}, {:?}) - X-Span-ID: {:?}",
+            body,
+            created_by,
+            context.get().0.clone()
+        );
+
+        if let Some(dir) = &body.working_directory
+            && let Err(message) = validate_working_directory(dir)
+        {
+            return Ok(
+                CreateWorkflowScheduleResponse::UnprocessableContentErrorResponse(
+                    invalid_schedule_error(message),
+                ),
+            );
+        }
+
+        let timezone = body
+            .timezone
+            .clone()
+            .unwrap_or_else(|| DEFAULT_TIMEZONE.to_string());
+        let next = match next_run_time(&body.cron_expression, &timezone, Utc::now()) {
+            Ok(next) => next,
+            Err(message) => {
+                return Ok(
+                    CreateWorkflowScheduleResponse::UnprocessableContentErrorResponse(
+                        invalid_schedule_error(message),
+                    ),
+                );
+            }
+        };
+        let overlap_policy = body.overlap_policy.unwrap_or_default();
+        let is_paused = body.is_paused.unwrap_or(false);
+
+        let result = match sqlx::query(
+            r#"
+            INSERT INTO workflow_schedule
+                (workflow_id, cron_expression, timezone, overlap_policy, working_directory,
+                 is_paused, next_run_time, created_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING id
+            "#,
+        )
+        .bind(body.workflow_id)
+        .bind(&body.cron_expression)
+        .bind(&timezone)
+        .bind(overlap_policy.to_string())
+        .bind(&body.working_directory)
+        .bind(if is_paused { 1i64 } else { 0i64 })
+        .bind(next.timestamp_millis())
+        .bind(&created_by)
+        .fetch_one(self.context.pool.as_ref())
+        .await
+        {
+            Ok(result) => result,
+            Err(e) => {
+                error!("Database error: {}", e);
+                return Err(database_error(e));
+            }
+        };
+        let id: i64 = result.get("id");
+        info!(
+            "Created workflow schedule with ID: {} for workflow {} ('{}' {}), next run at {}",
+            id,
+            body.workflow_id,
+            body.cron_expression,
+            timezone,
+            next.to_rfc3339()
+        );
+
+        match self.fetch_schedule(id).await? {
+            Some(schedule) => Ok(CreateWorkflowScheduleResponse::SuccessfulResponse(schedule)),
+            None => Err(ApiError(format!(
+                "Workflow schedule {} disappeared after creation",
+                id
+            ))),
+        }
+    }
+
+    /// Retrieve a workflow schedule by ID.
+    async fn get_workflow_schedule(
+        &self,
+        id: i64,
+        context: &C,
+    ) -> Result<GetWorkflowScheduleResponse, ApiError> {
+        debug!(
+            "get_workflow_schedule({}) - X-Span-ID: {:?}",
+            id,
+            context.get().0.clone()
+        );
+
+        match self.fetch_schedule(id).await? {
+            Some(schedule) => Ok(GetWorkflowScheduleResponse::SuccessfulResponse(schedule)),
+            None => Ok(GetWorkflowScheduleResponse::NotFoundErrorResponse(
+                not_found_error(id),
+            )),
+        }
+    }
+
+    /// Retrieve workflow schedules, optionally for one workflow.
+    async fn list_workflow_schedules(
+        &self,
+        workflow_id: Option<i64>,
+        accessible_workflow_ids: Option<Vec<i64>>,
+        offset: i64,
+        limit: i64,
+        context: &C,
+    ) -> Result<ListWorkflowSchedulesResponse, ApiError> {
+        debug!(
+            "list_workflow_schedules({:?}, {}, {}) - X-Span-ID: {:?}",
+            workflow_id,
+            offset,
+            limit,
+            context.get().0.clone()
+        );
+
+        let limit = std::cmp::min(limit, MAX_RECORD_TRANSFER_COUNT);
+        // Workflow IDs are integers, so the access filter can be inlined
+        let mut conditions = Vec::new();
+        if workflow_id.is_some() {
+            conditions.push("workflow_id = $3".to_string());
+        }
+        if let Some(ids) = &accessible_workflow_ids {
+            if ids.is_empty() {
+                conditions.push("1 = 0".to_string());
+            } else {
+                let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+                conditions.push(format!("workflow_id IN ({})", ids.join(", ")));
+            }
+        }
+        let where_clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+        let query = format!(
+            "SELECT {} FROM workflow_schedule {} ORDER BY id LIMIT $1 OFFSET $2",
+            SELECT_COLUMNS, where_clause
+        );
+        let mut sql = sqlx::query(&query).bind(limit).bind(offset);
+        if let Some(workflow_id) = workflow_id {
+            sql = sql.bind(workflow_id);
+        }
+        let records = match sql.fetch_all(self.context.pool.as_ref()).await {
+            Ok(records) => records,
+            Err(e) => {
+                error!("Database error: {}", e);
+                return Err(database_error(e));
+            }
+        };
+        let items: Vec<models::WorkflowScheduleModel> = records.iter().map(row_to_model).collect();
+        let count = items.len() as i64;
+
+        let count_query = format!(
+            "SELECT COUNT(*) as total FROM workflow_schedule {}",
+            where_clause.replace("$3", "$1")
+        );
+        let mut count_sql = sqlx::query(&count_query);
+        if let Some(workflow_id) = workflow_id {
+            count_sql = count_sql.bind(workflow_id);
+        }
+        let total_count: i64 = match count_sql.fetch_one(self.context.pool.as_ref()).await {
+            Ok(row) => row.get("total"),
+            Err(e) => {
+                error!("Database error: {}", e);
+                return Err(database_error(e));
+            }
+        };
+
+        let has_more = offset + count < total_count;
+
+        Ok(ListWorkflowSchedulesResponse::SuccessfulResponse(
+            models::ListWorkflowSchedulesResponse {
+                items: Some(items),
+                offset,
+                max_limit: MAX_RECORD_TRANSFER_COUNT,
+                count,
+                total_count,
+                has_more,
+            },
+        ))
+    }
+
+    /// Update a workflow schedule.
+    ///
+    /// The next run time is recomputed from the current time when the cron expression or time
+    /// zone changes or when a paused schedule is resumed, so missed firings are not replayed.
+    async fn update_workflow_schedule(
+        &self,
+        id: i64,
+        body: models::WorkflowScheduleModel,
+        context: &C,
+    ) -> Result<UpdateWorkflowScheduleResponse, ApiError> {
+        debug!(
+            "update_workflow_schedule({}, {:?}) - X-Span-ID: {:?}",
+            id,
+            body,
+            context.get().0.clone()
+        );
+
+        let existing = match self.fetch_schedule(id).await? {
+            Some(schedule) => schedule,
+            None => {
+                return Ok(UpdateWorkflowScheduleResponse::NotFoundErrorResponse(
+                    not_found_error(id),
+                ));
+            }
+        };
+        if let Some(dir) = &body.working_directory
+            && let Err(message) = validate_working_directory(dir)
+        {
+            return Ok(
+                UpdateWorkflowScheduleResponse::UnprocessableContentErrorResponse(
+                    invalid_schedule_error(message),
+                ),
+            );
+        }
+        let existing_timezone = existing
+            .timezone
+            .clone()
+            .unwrap_or_else(|| DEFAULT_TIMEZONE.to_string());
+        let timezone = body.timezone.clone().unwrap_or(existing_timezone.clone());
+        let was_paused = existing.is_paused.unwrap_or(false);
+        let is_paused = body.is_paused.unwrap_or(was_paused);
+        let needs_next_run_time = body.cron_expression != existing.cron_expression
+            || timezone != existing_timezone
+            || (was_paused && !is_paused);
+
+        let next = match next_run_time(&body.cron_expression, &timezone, Utc::now()) {
+            Ok(next) => next,
+            Err(message) => {
+                return Ok(
+                    UpdateWorkflowScheduleResponse::UnprocessableContentErrorResponse(
+                        invalid_schedule_error(message),
+                    ),
+                );
+            }
+        };
+        let next_run_time_ms = if needs_next_run_time {
+            Some(next.timestamp_millis())
+        } else {
+            None
+        };
+
+        if let Err(e) = sqlx::query(
+            r#"
+            UPDATE workflow_schedule
+            SET cron_expression = $1,
+                timezone = $2,
+                overlap_policy = COALESCE($3, overlap_policy),
+                working_directory = COALESCE($4, working_directory),
+                is_paused = $5,
+                next_run_time = COALESCE($6, next_run_time)
+            WHERE id = $7
+            "#,
+        )
+        .bind(&body.cron_expression)
+        .bind(&timezone)
+        .bind(body.overlap_policy.map(|p| p.to_string()))
+        .bind(&body.working_directory)
+        .bind(if is_paused { 1i64 } else { 0i64 })
+        .bind(next_run_time_ms)
+        .bind(id)
+        .execute(self.context.pool.as_ref())
+        .await
+        {
+            error!("Database error: {}", e);
+            return Err(database_error(e));
+        }
+
+        info!(
+            "Updated workflow schedule {} ('{}' {}, paused={})",
+            id, body.cron_expression, timezone, is_paused
+        );
+        match self.fetch_schedule(id).await? {
+            Some(schedule) => Ok(UpdateWorkflowScheduleResponse::SuccessfulResponse(schedule)),
+            None => Ok(UpdateWorkflowScheduleResponse::NotFoundErrorResponse(
+                not_found_error(id),
+            )),
+        }
+    }
+
+    /// Delete a workflow schedule.
+    async fn delete_workflow_schedule(
+        &self,
+        id: i64,
+        _body: Option<serde_json::Value>,
+        context: &C,
+    ) -> Result<DeleteWorkflowScheduleResponse, ApiError> {
+        debug!(
+            "delete_workflow_schedule({}) - X-Span-ID: {:?}",
+            id,
+            context.get().0.clone()
+        );
+
+        let schedule = match self.fetch_schedule(id).await? {
+            Some(schedule) => schedule,
+            None => {
+                return Ok(DeleteWorkflowScheduleResponse::NotFoundErrorResponse(
+                    not_found_error(id),
+                ));
+            }
+        };
+
+        match sqlx::query("DELETE FROM workflow_schedule WHERE id = $1")
+            .bind(id)
+            .execute(self.context.pool.as_ref())
+            .await
+        {
+            Ok(_) => {
+                info!("Deleted workflow schedule with ID: {}", id);
+                Ok(DeleteWorkflowScheduleResponse::SuccessfulResponse(schedule))
+            }
+            Err(e) => {
+                error!("Database error: {}", e);
+                Err(database_error(e))
+            }
+        }
+    }
+}
@@ -6,6 +6,7 @@ use async_trait::async_trait;
 use chrono::Utc;
 use log::{debug, error, info};
 use sqlx::Row;
+use std::collections::HashMap;
 use swagger::{ApiError, Has, XSpanIdString};
 
 use crate::server::api_types::{
@@ -19,6 +20,30 @@ use crate::server::api_types::{
 use crate::models;
 
 use super::{ApiContext, MAX_RECORD_TRANSFER_COUNT, SqlQueryBuilder, database_error};
+use crate::server::db::Db;
+
+/// Serialize a workflow's concurrency pools for storage in the `workflow.concurrency_pools`
+/// column.
+pub fn concurrency_pools_to_json(pools: &Option<HashMap<String, i64>>) -> Option<String> {
+    pools
+        .as_ref()
+        .filter(|pools| !pools.is_empty())
+        .and_then(|pools| serde_json::to_string(pools).ok())
+}
+
+/// Parse the `workflow.concurrency_pools` column into a workflow's concurrency pools.
+pub fn concurrency_pools_from_json(value: Option<String>) -> Option<HashMap<String, i64>> {
+    value.and_then(|value| match serde_json::from_str(&value) {
+        Ok(pools) => Some(pools),
+        Err(e) => {
+            error!(
+                "Failed to parse workflow concurrency_pools '{}': {}",
+                value, e
+            );
+            None
+        }
+    })
+}
 
 /// Trait defining workflow-related API operations
 #[async_trait]
@@ -197,7 +222,7 @@ where
             INSERT INTO workflow_status
             (run_id, is_archived, is_canceled, has_detected_need_to_run_completion_script)
             VALUES (0, 0, 0, 0)
-            RETURNING rowid
+            RETURNING id
             "#
         )
         .fetch_all(&mut *tx)
@@ -231,9 +256,13 @@ where
         let compute_node_min_time_for_new_jobs_seconds = body
             .compute_node_min_time_for_new_jobs_seconds
             .unwrap_or(300);
+        let worker_heartbeat_timeout_seconds = body.worker_heartbeat_timeout_seconds.unwrap_or(300);
+        let dead_worker_policy_str = body.dead_worker_policy.unwrap_or_default().to_string();
+        let concurrency_pools = concurrency_pools_to_json(&body.concurrency_pools);
 
         // Then, create the workflow record
         let use_pending_failed_int = body.use_pending_failed.map(|v| if v { 1 } else { 0 });
+        let enforce_runtime_limits_int = body.enforce_runtime_limits.map(|v| if v { 1 } else { 0 });
 
         let workflow_result = match sqlx::query!(
             r#"
@@ -241,7 +270,7 @@ where
             (
                 name,
                 description,
-                user,
+                "user",
                 timestamp,
                 compute_node_expiration_buffer_seconds,
                 compute_node_wait_for_new_jobs_seconds,
@@ -252,10 +281,17 @@ where
                 resource_monitor_config,
                 slurm_defaults,
                 use_pending_failed,
+                enforce_runtime_limits,
+                runtime_limit_grace_seconds,
+                worker_heartbeat_timeout_seconds,
+                dead_worker_policy,
+                max_concurrent_jobs,
+                concurrency_pools,
+                au_budget,
                 status_id
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
-            RETURNING rowid
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21)
+            RETURNING id
             "#,
             body.name,
             body.description,
@@ -270,6 +306,13 @@ where
             body.resource_monitor_config,
             body.slurm_defaults,
             use_pending_failed_int,
+            enforce_runtime_limits_int,
+            body.runtime_limit_grace_seconds,
+            worker_heartbeat_timeout_seconds,
+            dead_worker_policy_str,
+            body.max_concurrent_jobs,
+            concurrency_pools,
+            body.au_budget,
             status_result[0].id,
         )
         .fetch_all(&mut *tx)
@@ -353,11 +396,11 @@ where
         let result = match sqlx::query!(
             r#"
             UPDATE workflow_status
-            SET run_id = ?,
-                has_detected_need_to_run_completion_script = ?,
-                is_canceled = ?,
-                is_archived = ?
-            WHERE id = ?
+            SET run_id = $1,
+                has_detected_need_to_run_completion_script = $2,
+                is_canceled = $3,
+                is_archived = $4
+            WHERE id = $5
             "#,
             current_status.run_id,
             has_detected_need_to_run_completion_script_int,
@@ -418,9 +461,9 @@ where
         }
 
         // Cancel all running and pending jobs in the workflow
-        let submitted_status = models::JobStatus::Running.to_int();
-        let submitted_pending_status = models::JobStatus::Pending.to_int();
-        let canceled_status = models::JobStatus::Canceled.to_int();
+        let submitted_status = i64::from(models::JobStatus::Running.to_int());
+        let submitted_pending_status = i64::from(models::JobStatus::Pending.to_int());
+        let canceled_status = i64::from(models::JobStatus::Canceled.to_int());
 
         match sqlx::query!(
             r#"
@@ -515,6 +558,15 @@ where
                     resource_monitor_config: row.resource_monitor_config,
                     slurm_defaults: row.slurm_defaults,
                     use_pending_failed: row.use_pending_failed.map(|v| v != 0),
+                    enforce_runtime_limits: row.enforce_runtime_limits.map(|v| v != 0),
+                    runtime_limit_grace_seconds: row.runtime_limit_grace_seconds,
+                    worker_heartbeat_timeout_seconds: row.worker_heartbeat_timeout_seconds,
+                    dead_worker_policy: row
+                        .dead_worker_policy
+                        .and_then(|p| p.parse::<models::DeadWorkerPolicy>().ok()),
+                    max_concurrent_jobs: row.max_concurrent_jobs,
+                    concurrency_pools: concurrency_pools_from_json(row.concurrency_pools),
+                    au_budget: row.au_budget,
                     status_id: Some(row.status_id),
                 },
             )),
@@ -561,7 +613,7 @@ where
 
         // Query the workflow_status table for the specified workflow ID
         let row = match sqlx::query!(
-            "SELECT id, run_id, has_detected_need_to_run_completion_script, is_canceled, is_archived FROM workflow_status WHERE id = ?",
+            "SELECT id, run_id, has_detected_need_to_run_completion_script, is_canceled, is_archived FROM workflow_status WHERE id = $1",
             id
         )
         .fetch_optional(&*self.context.pool)
@@ -638,16 +690,17 @@ where
         // Check if any jobs exist that are NOT in complete states
         let completed_status = models::JobStatus::Completed.to_int();
         let failed_status = models::JobStatus::Failed.to_int();
-        let canceled_status = models::JobStatus::Canceled.to_int();
+        let canceled_status = i64::from(models::JobStatus::Canceled.to_int());
         let terminated_status = models::JobStatus::Terminated.to_int();
         let disabled_status = models::JobStatus::Disabled.to_int();
+        let skipped_status = models::JobStatus::Skipped.to_int();
 
         let has_incomplete_jobs = match sqlx::query(
             r#"
             SELECT 1 as found
             FROM job
             WHERE workflow_id = $1
-            AND status NOT IN ($2, $3, $4, $5, $6)
+            AND status NOT IN ($2, $3, $4, $5, $6, $7)
             LIMIT 1
             "#,
         )
@@ -657,6 +710,7 @@ where
         .bind(canceled_status)
         .bind(terminated_status)
         .bind(disabled_status)
+        .bind(skipped_status)
         .fetch_optional(self.context.pool.as_ref())
         .await
         {
@@ -767,7 +821,7 @@ where
             SELECT
                 w.id
                 ,w.name
-                ,w.user
+                ,w.\"user\"
                 ,w.description
                 ,w.timestamp
                 ,w.compute_node_expiration_buffer_seconds
@@ -778,6 +832,11 @@ where
                 ,w.jobs_sort_method
                 ,w.resource_monitor_config
                 ,w.slurm_defaults
+                ,w.worker_heartbeat_timeout_seconds
+                ,w.dead_worker_policy
+                ,w.max_concurrent_jobs
+                ,w.concurrency_pools
+                ,w.au_budget
                 ,w.status_id
             FROM workflow w
             INNER JOIN workflow_status ws ON w.status_id = ws.id
@@ -788,7 +847,7 @@ where
             SELECT
                 id
                 ,name
-                ,user
+                ,\"user\"
                 ,description
                 ,timestamp
                 ,compute_node_expiration_buffer_seconds
@@ -799,6 +858,11 @@ where
                 ,jobs_sort_method
                 ,resource_monitor_config
                 ,slurm_defaults
+                ,worker_heartbeat_timeout_seconds
+                ,dead_worker_policy
+                ,max_concurrent_jobs
+                ,concurrency_pools
+                ,au_budget
                 ,status_id
             FROM workflow
             "
@@ -807,7 +871,7 @@ where
 
         // Build WHERE clause conditions
         let mut where_conditions = Vec::new();
-        let mut bind_values: Vec<Box<dyn sqlx::Encode<'_, sqlx::Sqlite> + Send>> = Vec::new();
+        let mut bind_values: Vec<Box<dyn sqlx::Encode<'_, Db> + Send>> = Vec::new();
 
         // Use table prefix when joining with workflow_status
         let table_prefix = if is_archived.is_some() { "w." } else { "" };
@@ -818,12 +882,12 @@ where
         }
 
         if let Some(workflow_user) = &user {
-            where_conditions.push(format!("{}user = ?", table_prefix));
+            where_conditions.push(format!("{}\"user\" = ?", table_prefix));
             bind_values.push(Box::new(workflow_user.clone()));
         }
 
         if let Some(workflow_description) = &description {
-            where_conditions.push(format!("{}description LIKE ?", table_prefix));
+            where_conditions.push(format!("LOWER({}description) LIKE LOWER(?)", table_prefix));
             bind_values.push(Box::new(format!("%{}%", workflow_description)));
         }
 
@@ -942,6 +1006,35 @@ where
                     .ok()
                     .flatten()
                     .map(|v| v != 0),
+                enforce_runtime_limits: record
+                    .try_get::<Option<i64>, _>("enforce_runtime_limits")
+                    .ok()
+                    .flatten()
+                    .map(|v| v != 0),
+                runtime_limit_grace_seconds: record
+                    .try_get::<Option<i64>, _>("runtime_limit_grace_seconds")
+                    .ok()
+                    .flatten(),
+                worker_heartbeat_timeout_seconds: record
+                    .try_get::<Option<i64>, _>("worker_heartbeat_timeout_seconds")
+                    .ok()
+                    .flatten(),
+                dead_worker_policy: record
+                    .try_get::<Option<String>, _>("dead_worker_policy")
+                    .ok()
+                    .flatten()
+                    .and_then(|p| p.parse::<models::DeadWorkerPolicy>().ok()),
+                max_concurrent_jobs: record
+                    .try_get::<Option<i64>, _>("max_concurrent_jobs")
+                    .ok()
+                    .flatten(),
+                concurrency_pools: concurrency_pools_from_json(
+                    record
+                        .try_get::<Option<String>, _>("concurrency_pools")
+                        .ok()
+                        .flatten(),
+                ),
+                au_budget: record.try_get::<Option<f64>, _>("au_budget").ok().flatten(),
                 status_id: Some(record.get("status_id")),
             });
         }
@@ -955,7 +1048,9 @@ where
         let count_query = if where_clause.is_empty() {
             count_base_query.to_string()
         } else {
-            format!("{} WHERE {}", count_base_query, where_clause)
+            SqlQueryBuilder::new(count_base_query.to_string())
+                .with_where(where_clause)
+                .build()
         };
 
         let mut count_sqlx_query = sqlx::query(&count_query);
@@ -1031,12 +1126,17 @@ where
 
         // Convert enum to string for database storage
         let jobs_sort_method_str = body.jobs_sort_method.map(|m| m.to_string());
+        let dead_worker_policy_str = body.dead_worker_policy.map(|p| p.to_string());
+        let concurrency_pools = concurrency_pools_to_json(&body.concurrency_pools);
 
         // Convert boolean to integer for SQLite if provided
         let compute_node_ignore_workflow_completion_int = body
             .compute_node_ignore_workflow_completion
             .map(|val| if val { 1 } else { 0 });
         let use_pending_failed_int = body.use_pending_failed.map(|val| if val { 1 } else { 0 });
+        let enforce_runtime_limits_int = body
+            .enforce_runtime_limits
+            .map(|val| if val { 1 } else { 0 });
 
         // Update the workflow record using COALESCE to only update non-null fields
         let result = match sqlx::query!(
@@ -1045,14 +1145,21 @@ where
             SET
                 name = COALESCE($1, name),
                 description = COALESCE($2, description),
-                user = COALESCE($3, user),
+                "user" = COALESCE($3, "user"),
                 compute_node_expiration_buffer_seconds = COALESCE($4, compute_node_expiration_buffer_seconds),
                 compute_node_wait_for_new_jobs_seconds = COALESCE($5, compute_node_wait_for_new_jobs_seconds),
                 compute_node_ignore_workflow_completion = COALESCE($6, compute_node_ignore_workflow_completion),
                 compute_node_wait_for_healthy_database_minutes = COALESCE($7, compute_node_wait_for_healthy_database_minutes),
                 jobs_sort_method = COALESCE($8, jobs_sort_method),
-                use_pending_failed = COALESCE($9, use_pending_failed)
-            WHERE id = $10
+                use_pending_failed = COALESCE($9, use_pending_failed),
+                enforce_runtime_limits = COALESCE($10, enforce_runtime_limits),
+                runtime_limit_grace_seconds = COALESCE($11, runtime_limit_grace_seconds),
+                worker_heartbeat_timeout_seconds = COALESCE($12, worker_heartbeat_timeout_seconds),
+                dead_worker_policy = COALESCE($13, dead_worker_policy),
+                max_concurrent_jobs = COALESCE($14, max_concurrent_jobs),
+                concurrency_pools = COALESCE($15, concurrency_pools),
+                au_budget = COALESCE($16, au_budget)
+            WHERE id = $17
             "#,
             body.name,
             body.description,
@@ -1063,6 +1170,13 @@ where
             body.compute_node_wait_for_healthy_database_minutes,
             jobs_sort_method_str,
             use_pending_failed_int,
+            enforce_runtime_limits_int,
+            body.runtime_limit_grace_seconds,
+            body.worker_heartbeat_timeout_seconds,
+            dead_worker_policy_str,
+            body.max_concurrent_jobs,
+            concurrency_pools,
+            body.au_budget,
             id
         )
         .execute(self.context.pool.as_ref())
@@ -1152,11 +1266,11 @@ where
         let result = match sqlx::query!(
             r#"
             UPDATE workflow_status 
-            SET run_id = ?, 
-                has_detected_need_to_run_completion_script = ?, 
-                is_canceled = ?, 
-                is_archived = ?
-            WHERE id = ?
+            SET run_id = $1, 
+                has_detected_need_to_run_completion_script = $2, 
+                is_canceled = $3, 
+                is_archived = $4
+            WHERE id = $5
             "#,
             body.run_id,
             has_detected_need_to_run_completion_script_int,
@@ -1312,11 +1426,11 @@ where
         }
 
         // Check if any jobs are in running or SubmittedPending status
-        let submitted_status = models::JobStatus::Running.to_int();
-        let submitted_pending_status = models::JobStatus::Pending.to_int();
+        let submitted_status = i64::from(models::JobStatus::Running.to_int());
+        let submitted_pending_status = i64::from(models::JobStatus::Pending.to_int());
 
         let has_active_jobs = match sqlx::query_scalar::<_, i64>(
-            "SELECT id FROM job WHERE workflow_id = ? AND (status = ? OR status = ?) LIMIT 1",
+            "SELECT id FROM job WHERE workflow_id = $1 AND (status = $2 OR status = $3) LIMIT 1",
         )
         .bind(id)
         .bind(submitted_status)
@@ -1348,7 +1462,7 @@ where
 
         // Check if any scheduled compute nodes are in pending or active status
         let has_active_scheduled_nodes = match sqlx::query_scalar::<_, i64>(
-            "SELECT id FROM scheduled_compute_node WHERE workflow_id = ? AND (status = 'pending' OR status = 'active') LIMIT 1",
+            "SELECT id FROM scheduled_compute_node WHERE workflow_id = $1 AND (status = 'pending' OR status = 'active') LIMIT 1",
         )
         .bind(id)
         .fetch_optional(self.context.pool.as_ref())
@@ -1393,7 +1507,7 @@ where
             SET has_detected_need_to_run_completion_script = 0,
                 is_canceled = 0,
                 is_archived = 0
-            WHERE id = ?
+            WHERE id = $1
             "#,
             id
         )
@@ -1468,8 +1582,8 @@ where
             FROM job_depends_on jb
             INNER JOIN job j1 ON jb.job_id = j1.id
             INNER JOIN job j2 ON jb.depends_on_job_id = j2.id
-            WHERE jb.workflow_id = ?
-            LIMIT ? OFFSET ?
+            WHERE jb.workflow_id = $1
+            LIMIT $2 OFFSET $3
             "#,
             workflow_id,
             limit_val,
@@ -1486,7 +1600,7 @@ where
 
         // Get total count
         let total_count = match sqlx::query_scalar::<_, i64>(
-            "SELECT COUNT(*) FROM job_depends_on WHERE workflow_id = ?",
+            "SELECT COUNT(*) FROM job_depends_on WHERE workflow_id = $1",
         )
         .bind(workflow_id)
         .fetch_one(self.context.pool.as_ref())
@@ -1559,9 +1673,9 @@ where
             LEFT JOIN job producer ON jof.job_id = producer.id
             LEFT JOIN job_input_file jif ON f.id = jif.file_id
             LEFT JOIN job consumer ON jif.job_id = consumer.id
-            WHERE f.workflow_id = ?
+            WHERE f.workflow_id = $1
                 AND (jof.job_id IS NOT NULL OR jif.job_id IS NOT NULL)
-            LIMIT ? OFFSET ?
+            LIMIT $2 OFFSET $3
             "#,
             workflow_id,
             limit_val,
@@ -1585,9 +1699,9 @@ where
                 FROM file f
                 LEFT JOIN job_output_file jof ON f.id = jof.file_id
                 LEFT JOIN job_input_file jif ON f.id = jif.file_id
-                WHERE f.workflow_id = ?
+                WHERE f.workflow_id = $1
                     AND (jof.job_id IS NOT NULL OR jif.job_id IS NOT NULL)
-            )
+            ) AS relationships
             "#,
         )
         .bind(workflow_id)
@@ -1659,9 +1773,9 @@ where
             LEFT JOIN job producer ON joud.job_id = producer.id
             LEFT JOIN job_input_user_data jiud ON ud.id = jiud.user_data_id
             LEFT JOIN job consumer ON jiud.job_id = consumer.id
-            WHERE ud.workflow_id = ?
+            WHERE ud.workflow_id = $1
                 AND (joud.job_id IS NOT NULL OR jiud.job_id IS NOT NULL)
-            LIMIT ? OFFSET ?
+            LIMIT $2 OFFSET $3
             "#,
             workflow_id,
             limit_val,
@@ -1685,9 +1799,9 @@ where
                 FROM user_data ud
                 LEFT JOIN job_output_user_data joud ON ud.id = joud.user_data_id
                 LEFT JOIN job_input_user_data jiud ON ud.id = jiud.user_data_id
-                WHERE ud.workflow_id = ?
+                WHERE ud.workflow_id = $1
                     AND (joud.job_id IS NOT NULL OR jiud.job_id IS NOT NULL)
-            )
+            ) AS relationships
             "#,
         )
         .bind(workflow_id)
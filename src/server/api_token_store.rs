@@ -0,0 +1,282 @@
+//! In-memory store of server-issued API tokens.
+//!
+//! The authentication middleware runs synchronously for every request, so it cannot query the
+//! database. Active tokens are loaded into this store at startup, kept in sync by the API token
+//! endpoints, and periodically refreshed from the database so that tokens created or revoked
+//! through other server instances sharing the database take effect here too.
+//!
+//! Security considerations:
+//! - Plaintext tokens are never stored; entries are keyed by the SHA-256 hash of the token
+//! - Expired tokens are rejected at lookup time
+//! - Revoked tokens are removed from the store and can never be re-added
+
+use chrono::{NaiveDateTime, Utc};
+use parking_lot::RwLock;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Prefix for all server-issued tokens. Makes tokens easy to identify in logs and secret scanners.
+pub const API_TOKEN_PREFIX: &str = "torc_";
+
+/// Scope that permits read-only (GET/HEAD) requests.
+pub const SCOPE_READ: &str = "read";
+
+/// Scope that permits all other requests.
+pub const SCOPE_WRITE: &str = "write";
+
+/// Timestamp format used for token expiration, matching SQLite's datetime('now').
+pub const EXPIRES_AT_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// An active token as seen by the authentication middleware.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiTokenEntry {
+    /// Database ID of the token
+    pub id: i64,
+    /// User that the token authenticates as
+    pub owner: String,
+    /// Scopes granted to the token
+    pub scopes: Vec<String>,
+    /// Expiration time (UTC), if any
+    pub expires_at: Option<NaiveDateTime>,
+}
+
+impl ApiTokenEntry {
+    /// Return true if the token grants access for the given HTTP method.
+    pub fn allows_method(&self, method: &hyper::Method) -> bool {
+        let required = if method == hyper::Method::GET || method == hyper::Method::HEAD {
+            SCOPE_READ
+        } else {
+            SCOPE_WRITE
+        };
+        self.scopes.iter().any(|s| s == required)
+    }
+
+    fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= Utc::now().naive_utc())
+    }
+}
+
+#[derive(Default)]
+struct TokenMap {
+    /// Active tokens keyed by token hash
+    active: HashMap<String, ApiTokenEntry>,
+    /// IDs of revoked tokens. Revocation is permanent, so a refresh that read a token before it
+    /// was revoked must not add it back.
+    revoked_ids: HashSet<i64>,
+}
+
+/// Thread-safe store of active API tokens keyed by token hash.
+#[derive(Clone, Default)]
+pub struct ApiTokenStore {
+    tokens: Arc<RwLock<TokenMap>>,
+}
+
+impl ApiTokenStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generate a new random plaintext token.
+    pub fn generate_token() -> Result<String, getrandom::Error> {
+        let mut bytes = [0u8; 32];
+        getrandom::getrandom(&mut bytes)?;
+        let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        Ok(format!("{}{}", API_TOKEN_PREFIX, hex))
+    }
+
+    /// Hash a plaintext token for storage and lookup.
+    pub fn hash_token(token: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(token.as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// Parse a comma-separated scopes column.
+    pub fn parse_scopes(scopes: &str) -> Vec<String> {
+        scopes
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    /// Parse an expiration timestamp. Accepts the SQLite datetime format and RFC 3339.
+    pub fn parse_expires_at(value: &str) -> Option<NaiveDateTime> {
+        NaiveDateTime::parse_from_str(value, EXPIRES_AT_FORMAT)
+            .ok()
+            .or_else(|| {
+                chrono::DateTime::parse_from_rfc3339(value)
+                    .ok()
+                    .map(|dt| dt.naive_utc())
+            })
+    }
+
+    /// Add or replace a token. Ignored if the token has been revoked.
+    pub fn insert(&self, token_hash: String, entry: ApiTokenEntry) {
+        let mut tokens = self.tokens.write();
+        if !tokens.revoked_ids.contains(&entry.id) {
+            tokens.active.insert(token_hash, entry);
+        }
+    }
+
+    /// Remove a revoked token by its database ID.
+    pub fn remove_by_id(&self, id: i64) {
+        let mut tokens = self.tokens.write();
+        tokens.revoked_ids.insert(id);
+        tokens.active.retain(|_, entry| entry.id != id);
+    }
+
+    /// Apply a snapshot of the database: add the active tokens and remove the revoked ones.
+    /// Tokens created after the snapshot was read are kept.
+    pub fn sync(&self, active: Vec<(String, ApiTokenEntry)>, revoked_ids: &[i64]) {
+        let mut tokens = self.tokens.write();
+        tokens.revoked_ids.extend(revoked_ids.iter().copied());
+        let TokenMap {
+            active: current,
+            revoked_ids,
+        } = &mut *tokens;
+        current.retain(|_, entry| !revoked_ids.contains(&entry.id));
+        for (token_hash, entry) in active {
+            if !revoked_ids.contains(&entry.id) {
+                current.insert(token_hash, entry);
+            }
+        }
+    }
+
+    /// Look up a plaintext token. Returns None if the token is unknown or expired.
+    pub fn lookup(&self, token: &str) -> Option<ApiTokenEntry> {
+        let key = Self::hash_token(token);
+        let tokens = self.tokens.read();
+        match tokens.active.get(&key) {
+            Some(entry) if !entry.is_expired() => Some(entry.clone()),
+            _ => None,
+        }
+    }
+
+    /// Get the number of tokens in the store.
+    pub fn len(&self) -> usize {
+        self.tokens.read().active.len()
+    }
+
+    /// Check if the store is empty.
+    pub fn is_empty(&self) -> bool {
+        self.tokens.read().active.is_empty()
+    }
+}
+
+impl std::fmt::Debug for ApiTokenStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApiTokenStore")
+            .field("entries", &self.tokens.read().active.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: i64, scopes: &[&str], expires_at: Option<NaiveDateTime>) -> ApiTokenEntry {
+        ApiTokenEntry {
+            id,
+            owner: "alice".to_string(),
+            scopes: scopes.iter().map(|s| s.to_string()).collect(),
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn test_generate_token_is_unique_and_prefixed() {
+        let a = ApiTokenStore::generate_token().unwrap();
+        let b = ApiTokenStore::generate_token().unwrap();
+        assert!(a.starts_with(API_TOKEN_PREFIX));
+        assert_eq!(a.len(), API_TOKEN_PREFIX.len() + 64);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_lookup_and_remove() {
+        let store = ApiTokenStore::new();
+        let token = ApiTokenStore::generate_token().unwrap();
+        store.insert(
+            ApiTokenStore::hash_token(&token),
+            entry(1, &[SCOPE_READ], None),
+        );
+
+        assert_eq!(store.lookup(&token).unwrap().owner, "alice");
+        assert!(store.lookup("torc_wrong").is_none());
+
+        store.remove_by_id(1);
+        assert!(store.lookup(&token).is_none());
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn test_sync() {
+        let store = ApiTokenStore::new();
+        let local = ApiTokenStore::generate_token().unwrap();
+        let remote = ApiTokenStore::generate_token().unwrap();
+        let revoked = ApiTokenStore::generate_token().unwrap();
+        store.insert(
+            ApiTokenStore::hash_token(&local),
+            entry(1, &[SCOPE_READ], None),
+        );
+        store.insert(
+            ApiTokenStore::hash_token(&revoked),
+            entry(2, &[SCOPE_READ], None),
+        );
+
+        // Token 3 was created and token 2 revoked through another server.
+        store.sync(
+            vec![(
+                ApiTokenStore::hash_token(&remote),
+                entry(3, &[SCOPE_READ], None),
+            )],
+            &[2],
+        );
+        assert!(store.lookup(&local).is_some());
+        assert!(store.lookup(&remote).is_some());
+        assert!(store.lookup(&revoked).is_none());
+
+        // A snapshot read before token 1 was revoked must not restore it.
+        store.remove_by_id(1);
+        store.sync(
+            vec![(
+                ApiTokenStore::hash_token(&local),
+                entry(1, &[SCOPE_READ], None),
+            )],
+            &[],
+        );
+        assert!(store.lookup(&local).is_none());
+        assert_eq!(store.len(), 1);
+    }
+
+    #[test]
+    fn test_expired_token_rejected() {
+        let store = ApiTokenStore::new();
+        let past = Utc::now().naive_utc() - chrono::Duration::seconds(1);
+        store.insert(
+            ApiTokenStore::hash_token("expired"),
+            entry(1, &[SCOPE_READ], Some(past)),
+        );
+        assert!(store.lookup("expired").is_none());
+    }
+
+    #[test]
+    fn test_scopes() {
+        let read_only = entry(1, &[SCOPE_READ], None);
+        assert!(read_only.allows_method(&hyper::Method::GET));
+        assert!(!read_only.allows_method(&hyper::Method::POST));
+
+        let read_write = entry(2, &[SCOPE_READ, SCOPE_WRITE], None);
+        assert!(read_write.allows_method(&hyper::Method::DELETE));
+
+        assert_eq!(
+            ApiTokenStore::parse_scopes("read, write,"),
+            vec!["read".to_string(), "write".to_string()]
+        );
+    }
+}
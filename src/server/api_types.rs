@@ -141,6 +141,114 @@ pub enum DeleteFailureHandlerResponse {
     DefaultErrorResponse(models::ErrorResponse),
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum CreateWorkflowScheduleResponse {
+    /// Successful response
+    SuccessfulResponse(models::WorkflowScheduleModel),
+    /// Forbidden - user does not have access
+    ForbiddenErrorResponse(models::ErrorResponse),
+    /// Not found error response
+    NotFoundErrorResponse(models::ErrorResponse),
+    /// Invalid cron expression or time zone
+    UnprocessableContentErrorResponse(models::ErrorResponse),
+    /// Default error response
+    DefaultErrorResponse(models::ErrorResponse),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum GetWorkflowScheduleResponse {
+    /// Successful response
+    SuccessfulResponse(models::WorkflowScheduleModel),
+    /// Forbidden - user does not have access
+    ForbiddenErrorResponse(models::ErrorResponse),
+    /// Not found error response
+    NotFoundErrorResponse(models::ErrorResponse),
+    /// Default error response
+    DefaultErrorResponse(models::ErrorResponse),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ListWorkflowSchedulesResponse {
+    /// Successful response
+    SuccessfulResponse(models::ListWorkflowSchedulesResponse),
+    /// Forbidden - user does not have access
+    ForbiddenErrorResponse(models::ErrorResponse),
+    /// Default error response
+    DefaultErrorResponse(models::ErrorResponse),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum UpdateWorkflowScheduleResponse {
+    /// Successful response
+    SuccessfulResponse(models::WorkflowScheduleModel),
+    /// Forbidden - user does not have access
+    ForbiddenErrorResponse(models::ErrorResponse),
+    /// Not found error response
+    NotFoundErrorResponse(models::ErrorResponse),
+    /// Invalid cron expression or time zone
+    UnprocessableContentErrorResponse(models::ErrorResponse),
+    /// Default error response
+    DefaultErrorResponse(models::ErrorResponse),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum DeleteWorkflowScheduleResponse {
+    /// Successful response
+    SuccessfulResponse(models::WorkflowScheduleModel),
+    /// Forbidden - user does not have access
+    ForbiddenErrorResponse(models::ErrorResponse),
+    /// Not found error response
+    NotFoundErrorResponse(models::ErrorResponse),
+    /// Default error response
+    DefaultErrorResponse(models::ErrorResponse),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum CreateJobLogResponse {
+    /// Successful response
+    SuccessfulResponse(models::JobLogModel),
+    /// Forbidden - user does not have access
+    ForbiddenErrorResponse(models::ErrorResponse),
+    /// Not found error response
+    NotFoundErrorResponse(models::ErrorResponse),
+    /// Unprocessable content error response
+    UnprocessableContentErrorResponse(models::ErrorResponse),
+    /// Default error response
+    DefaultErrorResponse(models::ErrorResponse),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum GetJobLogResponse {
+    /// Successful response
+    SuccessfulResponse(models::JobLogModel),
+    /// Forbidden - user does not have access
+    ForbiddenErrorResponse(models::ErrorResponse),
+    /// Not found error response
+    NotFoundErrorResponse(models::ErrorResponse),
+    /// Default error response
+    DefaultErrorResponse(models::ErrorResponse),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ListJobLogsResponse {
+    /// Successful response
+    SuccessfulResponse(models::ListJobLogsResponse),
+    /// Forbidden - user does not have access
+    ForbiddenErrorResponse(models::ErrorResponse),
+    /// Not found error response
+    NotFoundErrorResponse(models::ErrorResponse),
+    /// Default error response
+    DefaultErrorResponse(models::ErrorResponse),
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 pub enum RetryJobResponse {
@@ -793,6 +901,39 @@ pub enum UpdateComputeNodeResponse {
     DefaultErrorResponse(models::ErrorResponse),
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum UpdateComputeNodeHeartbeatResponse {
+    /// Successful response
+    SuccessfulResponse(models::ComputeNodeModel),
+    /// Not found error response
+    NotFoundErrorResponse(models::ErrorResponse),
+    /// Default error response
+    DefaultErrorResponse(models::ErrorResponse),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ReserveComputeNodeResponse {
+    /// Successful response
+    SuccessfulResponse(models::ComputeNodeModel),
+    /// Not found error response
+    NotFoundErrorResponse(models::ErrorResponse),
+    /// Default error response
+    DefaultErrorResponse(models::ErrorResponse),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ReleaseComputeNodeResponse {
+    /// Successful response
+    SuccessfulResponse(models::ComputeNodeModel),
+    /// Not found error response
+    NotFoundErrorResponse(models::ErrorResponse),
+    /// Default error response
+    DefaultErrorResponse(models::ErrorResponse),
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 pub enum UpdateEventResponse {
@@ -1329,6 +1470,41 @@ pub enum CheckWorkflowAccessResponse {
     DefaultErrorResponse(models::ErrorResponse),
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum CreateApiTokenResponse {
+    /// Successful response
+    SuccessfulResponse(models::ApiTokenModel),
+    /// Forbidden error response
+    ForbiddenErrorResponse(models::ErrorResponse),
+    /// Unprocessable content error response
+    UnprocessableContentErrorResponse(models::ErrorResponse),
+    /// Default error response
+    DefaultErrorResponse(models::ErrorResponse),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ListApiTokensApiResponse {
+    /// Successful response
+    SuccessfulResponse(models::ListApiTokensResponse),
+    /// Default error response
+    DefaultErrorResponse(models::ErrorResponse),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum RevokeApiTokenResponse {
+    /// Successful response
+    SuccessfulResponse(models::ApiTokenModel),
+    /// Forbidden error response
+    ForbiddenErrorResponse(models::ErrorResponse),
+    /// Not found error response
+    NotFoundErrorResponse(models::ErrorResponse),
+    /// Default error response
+    DefaultErrorResponse(models::ErrorResponse),
+}
+
 /// API
 #[async_trait]
 #[allow(clippy::too_many_arguments, clippy::ptr_arg)]
@@ -1413,6 +1589,68 @@ pub trait Api<C: Send + Sync> {
         context: &C,
     ) -> Result<DeleteFailureHandlerResponse, ApiError>;
 
+    /// Store a workflow schedule.
+    async fn create_workflow_schedule(
+        &self,
+        body: models::WorkflowScheduleModel,
+        context: &C,
+    ) -> Result<CreateWorkflowScheduleResponse, ApiError>;
+
+    /// Retrieve a workflow schedule by ID.
+    async fn get_workflow_schedule(
+        &self,
+        id: i64,
+        context: &C,
+    ) -> Result<GetWorkflowScheduleResponse, ApiError>;
+
+    /// Retrieve workflow schedules, optionally for one workflow.
+    async fn list_workflow_schedules(
+        &self,
+        workflow_id: Option<i64>,
+        offset: Option<i64>,
+        limit: Option<i64>,
+        context: &C,
+    ) -> Result<ListWorkflowSchedulesResponse, ApiError>;
+
+    /// Update a workflow schedule. Use this to pause or resume it.
+    async fn update_workflow_schedule(
+        &self,
+        id: i64,
+        body: models::WorkflowScheduleModel,
+        context: &C,
+    ) -> Result<UpdateWorkflowScheduleResponse, ApiError>;
+
+    /// Delete a workflow schedule.
+    async fn delete_workflow_schedule(
+        &self,
+        id: i64,
+        body: Option<serde_json::Value>,
+        context: &C,
+    ) -> Result<DeleteWorkflowScheduleResponse, ApiError>;
+
+    /// Store the stdout or stderr log of a job attempt.
+    async fn create_job_log(
+        &self,
+        body: models::JobLogModel,
+        context: &C,
+    ) -> Result<CreateJobLogResponse, ApiError>;
+
+    /// Retrieve a job log by ID.
+    async fn get_job_log(&self, id: i64, context: &C) -> Result<GetJobLogResponse, ApiError>;
+
+    /// Retrieve the uploaded job logs of a workflow.
+    async fn list_job_logs(
+        &self,
+        workflow_id: i64,
+        job_id: Option<i64>,
+        run_id: Option<i64>,
+        attempt_id: Option<i64>,
+        stream: Option<models::JobLogStream>,
+        offset: Option<i64>,
+        limit: Option<i64>,
+        context: &C,
+    ) -> Result<ListJobLogsResponse, ApiError>;
+
     /// Store one resource requirements record.
     async fn create_resource_requirements(
         &self,
@@ -1919,6 +2157,29 @@ pub trait Api<C: Send + Sync> {
         context: &C,
     ) -> Result<UpdateComputeNodeResponse, ApiError>;
 
+    /// Record a heartbeat from a compute node.
+    async fn update_compute_node_heartbeat(
+        &self,
+        id: i64,
+        context: &C,
+    ) -> Result<UpdateComputeNodeHeartbeatResponse, ApiError>;
+
+    /// Reserve a compute node for a job.
+    async fn reserve_compute_node(
+        &self,
+        id: i64,
+        job_id: i64,
+        context: &C,
+    ) -> Result<ReserveComputeNodeResponse, ApiError>;
+
+    /// Release a compute node reserved for a job.
+    async fn release_compute_node(
+        &self,
+        id: i64,
+        job_id: i64,
+        context: &C,
+    ) -> Result<ReleaseComputeNodeResponse, ApiError>;
+
     /// Update an event.
     async fn update_event(
         &self,
@@ -2186,6 +2447,8 @@ pub trait Api<C: Send + Sync> {
         id: i64,
         run_id: i64,
         max_retries: i32,
+        delay_seconds: Option<f64>,
+        avoid_compute_node_id: Option<i64>,
         context: &C,
     ) -> Result<RetryJobResponse, ApiError>;
 
@@ -2298,6 +2561,30 @@ pub trait Api<C: Send + Sync> {
         context: &C,
     ) -> Result<CheckWorkflowAccessResponse, ApiError>;
 
+    // API Tokens API
+
+    /// Create an API token. The plaintext token is only returned in this response.
+    async fn create_api_token(
+        &self,
+        body: models::ApiTokenModel,
+        context: &C,
+    ) -> Result<CreateApiTokenResponse, ApiError>;
+
+    /// List API tokens visible to the requesting user.
+    async fn list_api_tokens(
+        &self,
+        offset: Option<i64>,
+        limit: Option<i64>,
+        context: &C,
+    ) -> Result<ListApiTokensApiResponse, ApiError>;
+
+    /// Revoke an API token.
+    async fn revoke_api_token(
+        &self,
+        id: i64,
+        context: &C,
+    ) -> Result<RevokeApiTokenResponse, ApiError>;
+
     /// Subscribe to the event broadcast channel for SSE streaming.
     /// Returns a broadcast receiver that will receive all future events.
     fn subscribe_to_events(&self) -> broadcast::Receiver<BroadcastEvent>;
@@ -2731,6 +3018,26 @@ pub trait ApiNoContext<C: Send + Sync> {
         body: models::ComputeNodeModel,
     ) -> Result<UpdateComputeNodeResponse, ApiError>;
 
+    /// Record a heartbeat from a compute node.
+    async fn update_compute_node_heartbeat(
+        &self,
+        id: i64,
+    ) -> Result<UpdateComputeNodeHeartbeatResponse, ApiError>;
+
+    /// Reserve a compute node for a job.
+    async fn reserve_compute_node(
+        &self,
+        id: i64,
+        job_id: i64,
+    ) -> Result<ReserveComputeNodeResponse, ApiError>;
+
+    /// Release a compute node reserved for a job.
+    async fn release_compute_node(
+        &self,
+        id: i64,
+        job_id: i64,
+    ) -> Result<ReleaseComputeNodeResponse, ApiError>;
+
     /// Update an event.
     async fn update_event(
         &self,
@@ -2963,6 +3270,8 @@ pub trait ApiNoContext<C: Send + Sync> {
         id: i64,
         run_id: i64,
         max_retries: i32,
+        delay_seconds: Option<f64>,
+        avoid_compute_node_id: Option<i64>,
     ) -> Result<RetryJobResponse, ApiError>;
 
     /// Get ready jobs that fit within the specified resource constraints.
@@ -3858,6 +4167,35 @@ impl<T: Api<C> + Send + Sync, C: Clone + Send + Sync> ApiNoContext<C> for Contex
         self.api().update_compute_node(id, body, &context).await
     }
 
+    /// Record a heartbeat from a compute node.
+    async fn update_compute_node_heartbeat(
+        &self,
+        id: i64,
+    ) -> Result<UpdateComputeNodeHeartbeatResponse, ApiError> {
+        let context = self.context().clone();
+        self.api().update_compute_node_heartbeat(id, &context).await
+    }
+
+    /// Reserve a compute node for a job.
+    async fn reserve_compute_node(
+        &self,
+        id: i64,
+        job_id: i64,
+    ) -> Result<ReserveComputeNodeResponse, ApiError> {
+        let context = self.context().clone();
+        self.api().reserve_compute_node(id, job_id, &context).await
+    }
+
+    /// Release a compute node reserved for a job.
+    async fn release_compute_node(
+        &self,
+        id: i64,
+        job_id: i64,
+    ) -> Result<ReleaseComputeNodeResponse, ApiError> {
+        let context = self.context().clone();
+        self.api().release_compute_node(id, job_id, &context).await
+    }
+
     /// Update an event.
     async fn update_event(
         &self,
@@ -4212,10 +4550,19 @@ impl<T: Api<C> + Send + Sync, C: Clone + Send + Sync> ApiNoContext<C> for Contex
         id: i64,
         run_id: i64,
         max_retries: i32,
+        delay_seconds: Option<f64>,
+        avoid_compute_node_id: Option<i64>,
     ) -> Result<RetryJobResponse, ApiError> {
         let context = self.context().clone();
         self.api()
-            .retry_job(id, run_id, max_retries, &context)
+            .retry_job(
+                id,
+                run_id,
+                max_retries,
+                delay_seconds,
+                avoid_compute_node_id,
+                &context,
+            )
             .await
     }
 
@@ -1,3 +1,4 @@
+use super::api_token_store::ApiTokenStore;
 use super::credential_cache::CredentialCache;
 use super::htpasswd::HtpasswdFile;
 use serde::{Deserialize, Serialize};
@@ -144,6 +145,7 @@ where
     htpasswd: Option<HtpasswdFile>,
     require_auth: bool,
     credential_cache: Option<CredentialCache>,
+    api_tokens: Option<ApiTokenStore>,
     marker: PhantomData<RC>,
 }
 
@@ -180,9 +182,16 @@ where
             htpasswd,
             require_auth,
             credential_cache,
+            api_tokens: None,
             marker: PhantomData,
         }
     }
+
+    /// Accept server-issued API tokens as bearer credentials.
+    pub fn with_api_tokens(mut self, api_tokens: ApiTokenStore) -> Self {
+        self.api_tokens = Some(api_tokens);
+        self
+    }
 }
 
 impl<Inner, RC, Target> Service<Target> for MakeHtpasswdAuthenticator<Inner, RC>
@@ -204,13 +213,12 @@ where
         let htpasswd = self.htpasswd.clone();
         let require_auth = self.require_auth;
         let credential_cache = self.credential_cache.clone();
+        let api_tokens = self.api_tokens.clone();
         Box::pin(self.inner.call(target).map(move |s| {
-            Ok(HtpasswdAuthenticatorService::new(
-                s?,
-                htpasswd,
-                require_auth,
-                credential_cache,
-            ))
+            Ok(
+                HtpasswdAuthenticatorService::new(s?, htpasswd, require_auth, credential_cache)
+                    .with_api_tokens(api_tokens),
+            )
         }))
     }
 }
@@ -226,6 +234,7 @@ where
     htpasswd: Option<HtpasswdFile>,
     require_auth: bool,
     credential_cache: Option<CredentialCache>,
+    api_tokens: Option<ApiTokenStore>,
     marker: PhantomData<RC>,
 }
 
@@ -245,10 +254,17 @@ where
             htpasswd,
             require_auth,
             credential_cache,
+            api_tokens: None,
             marker: PhantomData,
         }
     }
 
+    /// Set the store of API tokens accepted as bearer credentials.
+    pub fn with_api_tokens(mut self, api_tokens: Option<ApiTokenStore>) -> Self {
+        self.api_tokens = api_tokens;
+        self
+    }
+
     /// Verify credentials with caching.
     /// First checks the cache, then falls back to bcrypt verification.
     /// Caches successful verifications.
@@ -286,6 +302,7 @@ where
             htpasswd: self.htpasswd.clone(),
             require_auth: self.require_auth,
             credential_cache: self.credential_cache.clone(),
+            api_tokens: self.api_tokens.clone(),
             marker: PhantomData,
         }
     }
@@ -296,8 +313,18 @@ where
     RC: RcBound,
     RC::Result: Send + 'static,
 {
-    fn bearer_authorization(&self, _token: &Bearer) -> Result<Authorization, swagger::ApiError> {
-        // Bearer tokens not supported in basic auth mode
+    fn bearer_authorization(&self, token: &Bearer) -> Result<Authorization, swagger::ApiError> {
+        if let Some(entry) = self
+            .api_tokens
+            .as_ref()
+            .and_then(|tokens| tokens.lookup(&token.token))
+        {
+            return Ok(Authorization {
+                subject: entry.owner,
+                scopes: Scopes::Some(entry.scopes.into_iter().collect()),
+                issuer: None,
+            });
+        }
         if self.require_auth {
             Err(swagger::ApiError(
                 "Unauthorized: Authentication required".to_string(),
@@ -398,59 +425,100 @@ where
     fn call(&mut self, req: (Request<B>, RC)) -> Self::Future {
         let (request, context) = req;
 
+        // Server-issued API tokens are sent as bearer credentials and take precedence over
+        // basic auth.
+        let bearer_auth: Option<Bearer> = from_headers(request.headers());
+        let token_entry = match (&bearer_auth, &self.api_tokens) {
+            (Some(bearer), Some(tokens)) => tokens.lookup(&bearer.token),
+            _ => None,
+        };
+        if let Some(ref entry) = token_entry
+            && !entry.allows_method(request.method())
+        {
+            log::warn!(
+                "API token {} for user '{}' does not permit {} requests",
+                entry.id,
+                entry.owner,
+                request.method()
+            );
+            let response = hyper::Response::builder()
+                .status(hyper::StatusCode::FORBIDDEN)
+                .body(hyper::Body::from(
+                    "Forbidden: API token scope does not permit this request",
+                ))
+                .unwrap();
+            return futures::future::Either::Left(futures::future::ready(Ok(response.into())));
+        }
+
         // Try to extract Basic auth from headers
         let basic_auth: Option<Basic> = from_headers(request.headers());
 
-        let authorization = match &self.htpasswd {
-            Some(htpasswd) => {
-                // We have an htpasswd file, verify credentials
-                match basic_auth {
-                    Some(basic) => {
-                        // Get password, treating None as empty string
-                        let password = basic.password.as_deref().unwrap_or("");
-
-                        if self.verify_with_cache(htpasswd, &basic.username, password) {
-                            log::debug!("User '{}' authenticated successfully", basic.username);
-                            Some(swagger::auth::Authorization {
-                                subject: basic.username.clone(),
-                                scopes: Scopes::All,
-                                issuer: None,
-                            })
-                        } else {
-                            log::warn!("Authentication failed for user '{}'", basic.username);
-                            None
+        let authorization = if let Some(entry) = token_entry {
+            log::debug!(
+                "User '{}' authenticated with API token {}",
+                entry.owner,
+                entry.id
+            );
+            Some(swagger::auth::Authorization {
+                subject: entry.owner,
+                scopes: Scopes::Some(entry.scopes.into_iter().collect()),
+                issuer: None,
+            })
+        } else if bearer_auth.is_some() {
+            log::warn!("Authentication failed: invalid, expired, or revoked API token");
+            None
+        } else {
+            match &self.htpasswd {
+                Some(htpasswd) => {
+                    // We have an htpasswd file, verify credentials
+                    match basic_auth {
+                        Some(basic) => {
+                            // Get password, treating None as empty string
+                            let password = basic.password.as_deref().unwrap_or("");
+
+                            if self.verify_with_cache(htpasswd, &basic.username, password) {
+                                log::debug!("User '{}' authenticated successfully", basic.username);
+                                Some(swagger::auth::Authorization {
+                                    subject: basic.username.clone(),
+                                    scopes: Scopes::All,
+                                    issuer: None,
+                                })
+                            } else {
+                                log::warn!("Authentication failed for user '{}'", basic.username);
+                                None
+                            }
                         }
-                    }
-                    None => {
-                        // No credentials provided
-                        if self.require_auth {
-                            log::warn!("Authentication required but no credentials provided");
-                            None
-                        } else {
-                            // Allow anonymous access (backward compatible)
-                            log::debug!("No credentials provided, allowing anonymous access");
-                            Some(swagger::auth::Authorization {
-                                subject: "anonymous".to_string(),
-                                scopes: Scopes::All,
-                                issuer: None,
-                            })
+                        None => {
+                            // No credentials provided
+                            if self.require_auth {
+                                log::warn!("Authentication required but no credentials provided");
+                                None
+                            } else {
+                                // Allow anonymous access (backward compatible)
+                                log::debug!("No credentials provided, allowing anonymous access");
+                                Some(swagger::auth::Authorization {
+                                    subject: "anonymous".to_string(),
+                                    scopes: Scopes::All,
+                                    issuer: None,
+                                })
+                            }
                         }
                     }
                 }
-            }
-            None => {
-                // No htpasswd file configured
-                if self.require_auth {
-                    log::warn!("Authentication required but no htpasswd file configured");
-                    None
-                } else {
-                    // Allow all (backward compatible mode)
-                    log::debug!("No authentication configured, allowing request");
-                    Some(swagger::auth::Authorization {
-                        subject: "anonymous".to_string(),
-                        scopes: Scopes::All,
-                        issuer: None,
-                    })
+                None => {
+                    // No htpasswd file configured
+                    if self.require_auth {
+                        log::warn!("Authentication required but no htpasswd file configured");
+                        None
+                    } else {
+                        // Allow all (backward compatible mode)
+                        log::debug!("No authentication configured, allowing request");
+                        Some(swagger::auth::Authorization {
+                            subject: "anonymous".to_string(),
+                            scopes: Scopes::All,
+                            issuer: None,
+                        })
+                    }
                 }
             }
         };
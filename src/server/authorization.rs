@@ -3,9 +3,9 @@
 //! This module provides authorization utilities that can be used by API handlers
 //! to enforce access control based on user identity and group memberships.
 
+use crate::server::db::DbPool;
 use log::{debug, warn};
 use sqlx::Row;
-use sqlx::sqlite::SqlitePool;
 use std::sync::Arc;
 use swagger::auth::Authorization;
 
@@ -29,7 +29,7 @@ impl AccessCheckResult {
 /// Authorization service for checking user permissions
 #[derive(Clone)]
 pub struct AuthorizationService {
-    pool: Arc<SqlitePool>,
+    pool: Arc<DbPool>,
     /// If true, authorization checks are enforced
     /// If false, all access is allowed (for backward compatibility)
     enforce_access_control: bool,
@@ -37,7 +37,7 @@ pub struct AuthorizationService {
 
 impl AuthorizationService {
     /// Create a new authorization service
-    pub fn new(pool: Arc<SqlitePool>, enforce_access_control: bool) -> Self {
+    pub fn new(pool: Arc<DbPool>, enforce_access_control: bool) -> Self {
         Self {
             pool,
             enforce_access_control,
@@ -89,7 +89,7 @@ impl AuthorizationService {
 
         // Check if workflow exists and get owner
         let workflow_owner: Option<String> =
-            match sqlx::query("SELECT user FROM workflow WHERE id = $1")
+            match sqlx::query("SELECT \"user\" FROM workflow WHERE id = $1")
                 .bind(workflow_id)
                 .fetch_optional(self.pool.as_ref())
                 .await
@@ -131,7 +131,7 @@ impl AuthorizationService {
         .fetch_one(self.pool.as_ref())
         .await
         {
-            Ok(row) => row.get::<i32, _>("has_access") == 1,
+            Ok(row) => row.get::<bool, _>("has_access"),
             Err(e) => {
                 warn!("Database error checking group access: {}", e);
                 return AccessCheckResult::Denied(format!("Database error: {}", e));
@@ -216,7 +216,7 @@ impl AuthorizationService {
             r#"
             SELECT DISTINCT w.id
             FROM workflow w
-            WHERE w.user = $1
+            WHERE w."user" = $1
             UNION
             SELECT DISTINCT wag.workflow_id
             FROM workflow_access_group wag
@@ -274,6 +274,13 @@ impl AuthorizationService {
             return AccessCheckResult::Allowed;
         }
 
+        self.check_system_admin(auth).await
+    }
+
+    /// Check if a user is a system administrator, even when access control is not enforced
+    ///
+    /// Use this for operations that let a user act as another user, such as issuing API tokens.
+    pub async fn check_system_admin(&self, auth: &Option<Authorization>) -> AccessCheckResult {
         let user_name = match Self::get_username(auth) {
             Some(name) => name,
             None => {
@@ -297,7 +304,7 @@ impl AuthorizationService {
         .fetch_one(self.pool.as_ref())
         .await
         {
-            Ok(row) => row.get::<i32, _>("is_admin") == 1,
+            Ok(row) => row.get::<bool, _>("is_admin"),
             Err(e) => {
                 warn!("Database error checking admin status: {}", e);
                 return AccessCheckResult::Denied(format!("Database error: {}", e));
@@ -348,7 +355,7 @@ impl AuthorizationService {
                 .fetch_optional(self.pool.as_ref())
                 .await
             {
-                Ok(Some(row)) => row.get::<i32, _>("is_system") == 1,
+                Ok(Some(row)) => row.get::<i64, _>("is_system") == 1,
                 Ok(None) => {
                     return AccessCheckResult::NotFound(format!(
                         "Group not found with ID: {}",
@@ -388,7 +395,7 @@ impl AuthorizationService {
         .fetch_one(self.pool.as_ref())
         .await
         {
-            Ok(row) => row.get::<i32, _>("is_admin") == 1,
+            Ok(row) => row.get::<bool, _>("is_admin"),
             Err(e) => {
                 warn!("Database error checking group admin status: {}", e);
                 return AccessCheckResult::Denied(format!("Database error: {}", e));
@@ -433,7 +440,7 @@ impl AuthorizationService {
 
         // Check if user owns the workflow
         let workflow_owner: Option<String> =
-            match sqlx::query("SELECT user FROM workflow WHERE id = $1")
+            match sqlx::query("SELECT \"user\" FROM workflow WHERE id = $1")
                 .bind(workflow_id)
                 .fetch_optional(self.pool.as_ref())
                 .await
@@ -472,7 +479,7 @@ impl AuthorizationService {
             .fetch_optional(self.pool.as_ref())
             .await
         {
-            Ok(Some(row)) => Ok(row.get::<i32, _>("is_system") == 1),
+            Ok(Some(row)) => Ok(row.get::<i64, _>("is_system") == 1),
             Ok(None) => Err(format!("Group not found with ID: {}", group_id)),
             Err(e) => Err(format!("Database error: {}", e)),
         }
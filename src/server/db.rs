@@ -0,0 +1,186 @@
+//! Database backend selection
+//!
+//! The server stores its state in SQLite by default. Building with the `postgres` cargo
+//! feature switches every server component to PostgreSQL instead. The rest of the server
+//! refers to the backend only through the aliases and helpers in this module, so SQL that
+//! is shared between the backends must stay portable: numbered placeholders (`$1`),
+//! `ON CONFLICT` upserts, `RETURNING id`, and a quoted `"user"` column.
+//!
+//! The `sqlx::query!` macros are checked against `DATABASE_URL` at compile time, so a
+//! `postgres` build needs `DATABASE_URL` to point at a PostgreSQL database that has the
+//! migrations in `migrations/postgres` applied.
+
+#[cfg(feature = "postgres")]
+mod backend {
+    pub use sqlx::Postgres as Db;
+    pub use sqlx::postgres::PgPool as DbPool;
+    pub use sqlx::postgres::PgRow as DbRow;
+
+    /// Name of the backend, used in log messages.
+    pub const BACKEND_NAME: &str = "PostgreSQL";
+}
+
+#[cfg(not(feature = "postgres"))]
+mod backend {
+    pub use sqlx::Sqlite as Db;
+    pub use sqlx::sqlite::SqlitePool as DbPool;
+    pub use sqlx::sqlite::SqliteRow as DbRow;
+
+    /// Name of the backend, used in log messages.
+    pub const BACKEND_NAME: &str = "SQLite";
+}
+
+pub use backend::{BACKEND_NAME, Db, DbPool, DbRow};
+
+/// A transaction on the configured database backend.
+pub type DbTransaction<'c> = sqlx::Transaction<'c, Db>;
+
+/// A single connection to the configured database backend.
+pub type DbConnection = <Db as sqlx::Database>::Connection;
+
+/// First key of the PostgreSQL advisory locks that serialize the writers of one workflow,
+/// which SQLite serializes with `BEGIN IMMEDIATE`. The second key is the workflow ID.
+#[cfg(feature = "postgres")]
+const WRITE_LOCK_NAMESPACE: i32 = 0x746f_7263; // "torc"
+
+/// Connect to the database and run the migrations for the configured backend.
+///
+/// `database_url` is a `sqlite:` URL for the default build or a `postgres://` URL when
+/// the `postgres` feature is enabled.
+#[cfg(not(feature = "postgres"))]
+pub async fn connect(database_url: &str) -> Result<DbPool, sqlx::Error> {
+    use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions};
+    use std::str::FromStr;
+
+    // WAL journal mode gives better concurrency and foreign key constraints must be
+    // enabled per connection.
+    let connect_options = SqliteConnectOptions::from_str(database_url)?
+        .journal_mode(SqliteJournalMode::Wal)
+        .foreign_keys(true)
+        .create_if_missing(true)
+        .busy_timeout(std::time::Duration::from_secs(45));
+
+    let pool = SqlitePoolOptions::new()
+        .connect_with(connect_options)
+        .await?;
+    sqlx::migrate!("./migrations").run(&pool).await?;
+    Ok(pool)
+}
+
+/// Connect to the database and run the migrations for the configured backend.
+///
+/// `database_url` is a `sqlite:` URL for the default build or a `postgres://` URL when
+/// the `postgres` feature is enabled.
+#[cfg(feature = "postgres")]
+pub async fn connect(database_url: &str) -> Result<DbPool, sqlx::Error> {
+    use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
+    use std::str::FromStr;
+
+    let connect_options = PgConnectOptions::from_str(database_url)?;
+    let pool = PgPoolOptions::new()
+        .max_connections(32)
+        .acquire_timeout(std::time::Duration::from_secs(45))
+        .connect_with(connect_options)
+        .await?;
+    sqlx::migrate!("./migrations/postgres").run(&pool).await?;
+    Ok(pool)
+}
+
+/// Convert the `--database` command-line value into a database URL.
+///
+/// SQLite builds accept a file path. Values that already carry a URL scheme are used as is.
+pub fn database_url_from_arg(database: &str) -> String {
+    if database.contains("://") || database.starts_with("sqlite:") {
+        database.to_string()
+    } else {
+        format!("sqlite:{}", database)
+    }
+}
+
+/// Begin a transaction that holds the write lock of a workflow until it commits or rolls back.
+///
+/// SQLite uses `BEGIN IMMEDIATE`, which locks the whole database. PostgreSQL takes a
+/// transaction-scoped advisory lock for the workflow instead, so writers of different
+/// workflows run concurrently. Code that writes outside these transactions relies on row
+/// locks and on guards in its `WHERE` clauses.
+pub async fn begin_write(
+    pool: &DbPool,
+    workflow_id: i64,
+) -> Result<DbTransaction<'static>, sqlx::Error> {
+    #[cfg(feature = "postgres")]
+    {
+        let mut tx = pool.begin().await?;
+        lock_for_write(&mut tx, workflow_id).await?;
+        Ok(tx)
+    }
+    #[cfg(not(feature = "postgres"))]
+    {
+        let _ = workflow_id;
+        pool.begin_with("BEGIN IMMEDIATE").await
+    }
+}
+
+/// Start a transaction on a raw connection that holds the write lock of a workflow, the
+/// equivalent of `begin_write`. The caller ends it with `COMMIT` or `ROLLBACK`.
+pub async fn begin_immediate(conn: &mut DbConnection, workflow_id: i64) -> Result<(), sqlx::Error> {
+    #[cfg(feature = "postgres")]
+    {
+        sqlx::query("BEGIN").execute(&mut *conn).await?;
+        lock_for_write(conn, workflow_id).await
+    }
+    #[cfg(not(feature = "postgres"))]
+    {
+        let _ = workflow_id;
+        sqlx::query("BEGIN IMMEDIATE").execute(&mut *conn).await?;
+        Ok(())
+    }
+}
+
+/// Take the advisory lock of a workflow. IDs that differ only above 32 bits share a lock,
+/// which serializes their writers but is otherwise harmless.
+#[cfg(feature = "postgres")]
+async fn lock_for_write(conn: &mut DbConnection, workflow_id: i64) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT pg_advisory_xact_lock($1, $2)")
+        .bind(WRITE_LOCK_NAMESPACE)
+        .bind(workflow_id as i32)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+/// Suffix for a `SELECT` that picks jobs to claim. On PostgreSQL it locks the selected rows of
+/// `table` until the transaction ends and skips rows that another transaction has locked, so
+/// writers that do not take the workflow lock cannot change a job while it is being claimed.
+/// SQLite needs nothing because `BEGIN IMMEDIATE` already excludes other writers.
+///
+/// `table` must be a trusted identifier; it is interpolated into the SQL.
+pub fn claim_row_lock_clause(table: &str) -> String {
+    if cfg!(feature = "postgres") {
+        format!("FOR UPDATE OF {} SKIP LOCKED", table)
+    } else {
+        String::new()
+    }
+}
+
+/// Return true if the error message describes lock contention that is worth retrying.
+pub fn is_lock_error_message(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("database is locked")
+        || message.contains("database is busy")
+        || message.contains("sqlite_busy")
+        // PostgreSQL serialization failure (40001) and deadlock (40P01)
+        || message.contains("could not serialize access")
+        || message.contains("deadlock detected")
+}
+
+/// SQL expression that extracts an integer from a top-level key of a JSON document stored
+/// as text.
+///
+/// `column` and `key` must be trusted identifiers; they are interpolated into the SQL.
+pub fn json_extract_integer(column: &str, key: &str) -> String {
+    if cfg!(feature = "postgres") {
+        format!("({}::jsonb ->> '{}')::BIGINT", column, key)
+    } else {
+        format!("json_extract({}, '$.{}')", column, key)
+    }
+}
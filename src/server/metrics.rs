@@ -0,0 +1,563 @@
+//! Prometheus metrics for the server.
+//!
+//! The server records request counts and latencies per API operation, database lock retries
+//! of the background unblock task, unblock task latencies, and the number of connected SSE
+//! clients. [`MakeMetricsService`] wraps the API service to time each request and serves the
+//! metrics in the Prometheus text format at `GET /metrics`, together with per-workflow job
+//! counts of non-archived workflows that are read from the database on each scrape.
+//!
+//! When access control is enforced, only system administrators may read the metrics.
+
+use crate::models::JobStatus;
+use crate::server::authorization::AuthorizationService;
+use crate::server::db::DbPool;
+use futures::StreamExt;
+use futures::future::BoxFuture;
+use hyper::header::CONTENT_TYPE;
+use hyper::service::Service;
+use hyper::{Body, Method, Request, Response, StatusCode};
+use log::error;
+use parking_lot::Mutex;
+use sqlx::Row;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use swagger::auth::Authorization;
+use swagger::{Has, RequestParser};
+
+use super::routing::ApiRequestParser;
+
+/// Path at which the metrics are served.
+pub const METRICS_PATH: &str = "/metrics";
+
+/// Content type of the Prometheus text exposition format.
+const METRICS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Operation ID of the SSE event stream; its responses stay open while a client is connected.
+const EVENTS_STREAM_OPERATION: &str = "SubscribeToEventsStream";
+
+/// Upper bounds (in seconds) of the latency histogram buckets.
+const LATENCY_BUCKETS: [f64; 12] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+];
+
+/// Cumulative latency histogram in the Prometheus sense.
+#[derive(Debug, Default, Clone)]
+struct Histogram {
+    /// Number of observations less than or equal to each bound in `LATENCY_BUCKETS`
+    buckets: [u64; LATENCY_BUCKETS.len()],
+    count: u64,
+    sum: f64,
+}
+
+impl Histogram {
+    fn observe(&mut self, seconds: f64) {
+        for (bound, bucket) in LATENCY_BUCKETS.iter().zip(self.buckets.iter_mut()) {
+            if seconds <= *bound {
+                *bucket += 1;
+            }
+        }
+        self.count += 1;
+        self.sum += seconds;
+    }
+
+    /// Write the `_bucket`, `_sum`, and `_count` series of this histogram.
+    fn write(&self, out: &mut String, name: &str, labels: &[(&str, &str)]) {
+        for (bound, bucket) in LATENCY_BUCKETS.iter().zip(self.buckets.iter()) {
+            let le = bound.to_string();
+            let mut bucket_labels = labels.to_vec();
+            bucket_labels.push(("le", &le));
+            let _ = writeln!(
+                out,
+                "{}_bucket{} {}",
+                name,
+                format_labels(&bucket_labels),
+                bucket
+            );
+        }
+        let mut bucket_labels = labels.to_vec();
+        bucket_labels.push(("le", "+Inf"));
+        let _ = writeln!(
+            out,
+            "{}_bucket{} {}",
+            name,
+            format_labels(&bucket_labels),
+            self.count
+        );
+        let _ = writeln!(out, "{}_sum{} {}", name, format_labels(labels), self.sum);
+        let _ = writeln!(
+            out,
+            "{}_count{} {}",
+            name,
+            format_labels(labels),
+            self.count
+        );
+    }
+}
+
+/// Request statistics of one API operation.
+#[derive(Debug, Default)]
+struct OperationStats {
+    /// Number of responses by HTTP status code
+    responses: BTreeMap<u16, u64>,
+    latency: Histogram,
+}
+
+#[derive(Debug, Default)]
+struct MetricsState {
+    operations: Mutex<BTreeMap<&'static str, OperationStats>>,
+    database_lock_retries: AtomicU64,
+    unblock_task: Mutex<Histogram>,
+    unblock_delay: Mutex<Histogram>,
+    sse_subscribers: AtomicI64,
+}
+
+/// Thread-safe collection of server metrics, shared by the request middleware and the
+/// background tasks.
+#[derive(Debug, Clone, Default)]
+pub struct ServerMetrics {
+    state: Arc<MetricsState>,
+}
+
+/// Number of jobs of one workflow in one status.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkflowJobCount {
+    pub workflow_id: i64,
+    pub status: JobStatus,
+    pub count: i64,
+}
+
+/// Keeps an SSE client counted as connected until dropped.
+#[derive(Debug)]
+pub struct SseSubscriberGuard {
+    state: Arc<MetricsState>,
+}
+
+impl Drop for SseSubscriberGuard {
+    fn drop(&mut self) {
+        self.state.sse_subscribers.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl ServerMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a completed API request.
+    pub fn observe_request(&self, operation: &'static str, status: u16, latency: Duration) {
+        let mut operations = self.state.operations.lock();
+        let stats = operations.entry(operation).or_default();
+        *stats.responses.entry(status).or_default() += 1;
+        stats.latency.observe(latency.as_secs_f64());
+    }
+
+    /// Record a retry of a database transaction that failed because the database was locked.
+    pub fn record_database_lock_retry(&self) {
+        self.state
+            .database_lock_retries
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record the duration of one pass of the background unblock task.
+    pub fn observe_unblock_task(&self, duration: Duration) {
+        self.state
+            .unblock_task
+            .lock()
+            .observe(duration.as_secs_f64());
+    }
+
+    /// Record the time from the job completion that triggered an unblock pass until the pass
+    /// finished.
+    pub fn observe_unblock_delay(&self, delay: Duration) {
+        self.state.unblock_delay.lock().observe(delay.as_secs_f64());
+    }
+
+    /// Count an SSE client as connected until the returned guard is dropped.
+    pub fn sse_subscriber(&self) -> SseSubscriberGuard {
+        self.state.sse_subscribers.fetch_add(1, Ordering::Relaxed);
+        SseSubscriberGuard {
+            state: self.state.clone(),
+        }
+    }
+
+    /// Number of currently connected SSE clients.
+    pub fn sse_subscriber_count(&self) -> i64 {
+        self.state.sse_subscribers.load(Ordering::Relaxed)
+    }
+
+    /// Render all metrics in the Prometheus text exposition format.
+    pub fn render(&self, job_counts: &[WorkflowJobCount]) -> String {
+        let mut out = String::new();
+
+        let operations = self.state.operations.lock();
+        write_header(
+            &mut out,
+            "torc_http_requests_total",
+            "counter",
+            "Number of API requests by operation and HTTP status code.",
+        );
+        for (operation, stats) in operations.iter() {
+            for (status, count) in &stats.responses {
+                let status = status.to_string();
+                let _ = writeln!(
+                    out,
+                    "torc_http_requests_total{} {}",
+                    format_labels(&[("operation", operation), ("status", &status)]),
+                    count
+                );
+            }
+        }
+        write_header(
+            &mut out,
+            "torc_http_request_duration_seconds",
+            "histogram",
+            "Latency of API requests by operation.",
+        );
+        for (operation, stats) in operations.iter() {
+            stats.latency.write(
+                &mut out,
+                "torc_http_request_duration_seconds",
+                &[("operation", operation)],
+            );
+        }
+        drop(operations);
+
+        write_header(
+            &mut out,
+            "torc_database_lock_retries_total",
+            "counter",
+            "Number of unblock transactions retried because the database was locked.",
+        );
+        let _ = writeln!(
+            out,
+            "torc_database_lock_retries_total {}",
+            self.state.database_lock_retries.load(Ordering::Relaxed)
+        );
+
+        write_header(
+            &mut out,
+            "torc_unblock_task_duration_seconds",
+            "histogram",
+            "Duration of the passes of the background task that unblocks jobs.",
+        );
+        self.state
+            .unblock_task
+            .lock()
+            .write(&mut out, "torc_unblock_task_duration_seconds", &[]);
+        write_header(
+            &mut out,
+            "torc_unblock_delay_seconds",
+            "histogram",
+            "Time from a job completion until the background task finished unblocking its dependents.",
+        );
+        self.state
+            .unblock_delay
+            .lock()
+            .write(&mut out, "torc_unblock_delay_seconds", &[]);
+
+        write_header(
+            &mut out,
+            "torc_sse_subscribers",
+            "gauge",
+            "Number of clients connected to workflow event streams.",
+        );
+        let _ = writeln!(out, "torc_sse_subscribers {}", self.sse_subscriber_count());
+
+        write_header(
+            &mut out,
+            "torc_workflow_jobs",
+            "gauge",
+            "Number of jobs by workflow and status.",
+        );
+        for job_count in job_counts {
+            let workflow_id = job_count.workflow_id.to_string();
+            let status = job_count.status.to_string();
+            let _ = writeln!(
+                out,
+                "torc_workflow_jobs{} {}",
+                format_labels(&[("workflow_id", &workflow_id), ("status", &status)]),
+                job_count.count
+            );
+        }
+
+        out
+    }
+}
+
+fn write_header(out: &mut String, name: &str, metric_type: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, metric_type);
+}
+
+/// Format label pairs as `{name="value",...}`, or an empty string if there are none.
+fn format_labels(labels: &[(&str, &str)]) -> String {
+    if labels.is_empty() {
+        return String::new();
+    }
+    let pairs: Vec<String> = labels
+        .iter()
+        .map(|(name, value)| {
+            let value = value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            format!("{}=\"{}\"", name, value)
+        })
+        .collect();
+    format!("{{{}}}", pairs.join(","))
+}
+
+/// Count the jobs of every non-archived workflow by status.
+///
+/// Only statuses that have jobs are returned, so the number of series grows with the active
+/// workflows and the statuses their jobs are in rather than with every workflow ever created.
+pub async fn workflow_job_counts(pool: &DbPool) -> Result<Vec<WorkflowJobCount>, sqlx::Error> {
+    let rows = sqlx::query(
+        r#"
+        SELECT j.workflow_id, j.status, COUNT(*) AS count
+        FROM job j
+        JOIN workflow w ON w.id = j.workflow_id
+        JOIN workflow_status ws ON ws.id = w.status_id
+        WHERE ws.is_archived = 0
+        GROUP BY j.workflow_id, j.status
+        ORDER BY j.workflow_id, j.status
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut result = Vec::with_capacity(rows.len());
+    for row in rows {
+        let workflow_id: i64 = row.get("workflow_id");
+        let status: i64 = row.get("status");
+        let count: i64 = row.get("count");
+        match JobStatus::from_int(status as i32) {
+            Ok(status) => result.push(WorkflowJobCount {
+                workflow_id,
+                status,
+                count,
+            }),
+            Err(e) => error!("Skipping job count with invalid status {}: {}", status, e),
+        }
+    }
+    Ok(result)
+}
+
+/// MakeService wrapper that records request metrics and serves `GET /metrics`.
+#[derive(Clone)]
+pub struct MakeMetricsService<T> {
+    inner: T,
+    metrics: ServerMetrics,
+    pool: Arc<DbPool>,
+    authorization_service: AuthorizationService,
+}
+
+impl<T> MakeMetricsService<T> {
+    pub fn new(
+        inner: T,
+        metrics: ServerMetrics,
+        pool: Arc<DbPool>,
+        authorization_service: AuthorizationService,
+    ) -> Self {
+        MakeMetricsService {
+            inner,
+            metrics,
+            pool,
+            authorization_service,
+        }
+    }
+}
+
+impl<Inner, Target> Service<Target> for MakeMetricsService<Inner>
+where
+    Inner: Service<Target>,
+    Inner::Future: Send + 'static,
+{
+    type Response = MetricsService<Inner::Response>;
+    type Error = Inner::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, target: Target) -> Self::Future {
+        let metrics = self.metrics.clone();
+        let pool = self.pool.clone();
+        let authorization_service = self.authorization_service.clone();
+        let future = self.inner.call(target);
+        Box::pin(async move {
+            Ok(MetricsService {
+                inner: future.await?,
+                metrics,
+                pool,
+                authorization_service,
+            })
+        })
+    }
+}
+
+/// Service that records request metrics and serves `GET /metrics`.
+#[derive(Clone)]
+pub struct MetricsService<T> {
+    inner: T,
+    metrics: ServerMetrics,
+    pool: Arc<DbPool>,
+    authorization_service: AuthorizationService,
+}
+
+impl<T, C> Service<(Request<Body>, C)> for MetricsService<T>
+where
+    T: Service<(Request<Body>, C), Response = Response<Body>>,
+    T::Future: Send + 'static,
+    C: Has<Option<Authorization>> + Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = T::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: (Request<Body>, C)) -> Self::Future {
+        let (request, context) = req;
+        let metrics = self.metrics.clone();
+
+        if request.method() == Method::GET && request.uri().path() == METRICS_PATH {
+            let pool = self.pool.clone();
+            let authorization_service = self.authorization_service.clone();
+            return Box::pin(async move {
+                let access = authorization_service
+                    .check_admin_access(context.get())
+                    .await;
+                if !access.is_allowed() {
+                    return Ok(Response::builder()
+                        .status(StatusCode::FORBIDDEN)
+                        .body(Body::from("Metrics are only available to administrators"))
+                        .expect("Unable to create Forbidden response"));
+                }
+                Ok(metrics_response(&metrics, &pool).await)
+            });
+        }
+
+        let operation = ApiRequestParser::parse_operation_id(&request);
+        let start = Instant::now();
+        let future = self.inner.call((request, context));
+        Box::pin(async move {
+            let response = future.await?;
+            let Some(operation) = operation else {
+                // Dashboard assets and unknown paths are not API operations.
+                return Ok(response);
+            };
+            metrics.observe_request(operation, response.status().as_u16(), start.elapsed());
+            if operation == EVENTS_STREAM_OPERATION && response.status() == StatusCode::OK {
+                // The client stays connected until hyper drops the streaming body.
+                let guard = metrics.sse_subscriber();
+                let (parts, body) = response.into_parts();
+                let body = Body::wrap_stream(body.map(move |chunk| {
+                    let _ = &guard;
+                    chunk
+                }));
+                return Ok(Response::from_parts(parts, body));
+            }
+            Ok(response)
+        })
+    }
+}
+
+async fn metrics_response(metrics: &ServerMetrics, pool: &DbPool) -> Response<Body> {
+    match workflow_job_counts(pool).await {
+        Ok(job_counts) => Response::builder()
+            .status(StatusCode::OK)
+            .header(CONTENT_TYPE, METRICS_CONTENT_TYPE)
+            .body(Body::from(metrics.render(&job_counts)))
+            .expect("Unable to create metrics response"),
+        Err(e) => {
+            error!("Database error counting jobs for metrics: {}", e);
+            Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body(Body::from("Database error"))
+                .expect("Unable to create Error response")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_requests_and_histograms() {
+        let metrics = ServerMetrics::new();
+        metrics.observe_request("GetWorkflow", 200, Duration::from_millis(3));
+        metrics.observe_request("GetWorkflow", 200, Duration::from_millis(200));
+        metrics.observe_request("GetWorkflow", 404, Duration::from_millis(1));
+        metrics.record_database_lock_retry();
+        metrics.observe_unblock_task(Duration::from_millis(20));
+        metrics.observe_unblock_delay(Duration::from_secs(2));
+
+        let text = metrics.render(&[]);
+        assert!(
+            text.contains("torc_http_requests_total{operation=\"GetWorkflow\",status=\"200\"} 2\n")
+        );
+        assert!(
+            text.contains("torc_http_requests_total{operation=\"GetWorkflow\",status=\"404\"} 1\n")
+        );
+        assert!(text.contains(
+            "torc_http_request_duration_seconds_bucket{operation=\"GetWorkflow\",le=\"0.005\"} 2\n"
+        ));
+        assert!(text.contains(
+            "torc_http_request_duration_seconds_bucket{operation=\"GetWorkflow\",le=\"0.25\"} 3\n"
+        ));
+        assert!(text.contains(
+            "torc_http_request_duration_seconds_bucket{operation=\"GetWorkflow\",le=\"+Inf\"} 3\n"
+        ));
+        assert!(
+            text.contains(
+                "torc_http_request_duration_seconds_count{operation=\"GetWorkflow\"} 3\n"
+            )
+        );
+        assert!(text.contains("torc_database_lock_retries_total 1\n"));
+        assert!(text.contains("torc_unblock_task_duration_seconds_bucket{le=\"0.025\"} 1\n"));
+        assert!(text.contains("torc_unblock_delay_seconds_bucket{le=\"1\"} 0\n"));
+        assert!(text.contains("torc_unblock_delay_seconds_bucket{le=\"2.5\"} 1\n"));
+        assert!(text.contains("# TYPE torc_http_request_duration_seconds histogram\n"));
+    }
+
+    #[test]
+    fn test_sse_subscriber_guard() {
+        let metrics = ServerMetrics::new();
+        let first = metrics.sse_subscriber();
+        let second = metrics.sse_subscriber();
+        assert_eq!(metrics.sse_subscriber_count(), 2);
+        drop(first);
+        assert_eq!(metrics.sse_subscriber_count(), 1);
+        drop(second);
+        assert!(metrics.render(&[]).contains("torc_sse_subscribers 0\n"));
+    }
+
+    #[test]
+    fn test_render_workflow_job_counts() {
+        let metrics = ServerMetrics::new();
+        let text = metrics.render(&[WorkflowJobCount {
+            workflow_id: 7,
+            status: JobStatus::Running,
+            count: 3,
+        }]);
+        assert!(text.contains("torc_workflow_jobs{workflow_id=\"7\",status=\"running\"} 3\n"));
+    }
+
+    #[test]
+    fn test_label_escaping() {
+        assert_eq!(
+            format_labels(&[("name", "a\"b\\c\nd")]),
+            "{name=\"a\\\"b\\\\c\\nd\"}"
+        );
+        assert_eq!(format_labels(&[]), "");
+    }
+}
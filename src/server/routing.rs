@@ -25,12 +25,13 @@ type ServiceFuture =
 use crate::server::api_types::{
     AddUserToGroupResponse, AddWorkflowToGroupResponse, Api, CancelWorkflowResponse,
     CheckWorkflowAccessResponse, ClaimActionResponse, ClaimJobsBasedOnResources,
-    ClaimNextJobsResponse, CompleteJobResponse, CreateAccessGroupResponse,
+    ClaimNextJobsResponse, CompleteJobResponse, CreateAccessGroupResponse, CreateApiTokenResponse,
     CreateComputeNodeResponse, CreateEventResponse, CreateFailureHandlerResponse,
-    CreateFileResponse, CreateJobResponse, CreateJobsResponse, CreateLocalSchedulerResponse,
-    CreateRemoteWorkersResponse, CreateResourceRequirementsResponse, CreateResultResponse,
-    CreateScheduledComputeNodeResponse, CreateSlurmSchedulerResponse, CreateUserDataResponse,
-    CreateWorkflowActionResponse, CreateWorkflowResponse, DeleteAccessGroupResponse,
+    CreateFileResponse, CreateJobLogResponse, CreateJobResponse, CreateJobsResponse,
+    CreateLocalSchedulerResponse, CreateRemoteWorkersResponse, CreateResourceRequirementsResponse,
+    CreateResultResponse, CreateScheduledComputeNodeResponse, CreateSlurmSchedulerResponse,
+    CreateUserDataResponse, CreateWorkflowActionResponse, CreateWorkflowResponse,
+    CreateWorkflowScheduleResponse, DeleteAccessGroupResponse,
     DeleteAllResourceRequirementsResponse, DeleteAllUserDataResponse, DeleteComputeNodeResponse,
     DeleteComputeNodesResponse, DeleteEventResponse, DeleteEventsResponse,
     DeleteFailureHandlerResponse, DeleteFileResponse, DeleteFilesResponse, DeleteJobResponse,
@@ -38,27 +39,31 @@ use crate::server::api_types::{
     DeleteRemoteWorkerResponse, DeleteResourceRequirementsResponse, DeleteResultResponse,
     DeleteResultsResponse, DeleteScheduledComputeNodeResponse, DeleteScheduledComputeNodesResponse,
     DeleteSlurmSchedulerResponse, DeleteSlurmSchedulersResponse, DeleteUserDataResponse,
-    DeleteWorkflowResponse, GetAccessGroupResponse, GetComputeNodeResponse, GetDotGraphResponse,
-    GetEventResponse, GetFailureHandlerResponse, GetFileResponse, GetJobResponse,
-    GetLocalSchedulerResponse, GetPendingActionsResponse, GetReadyJobRequirementsResponse,
-    GetResourceRequirementsResponse, GetResultResponse, GetScheduledComputeNodeResponse,
-    GetSlurmSchedulerResponse, GetUserDataResponse, GetVersionResponse, GetWorkflowActionsResponse,
-    GetWorkflowResponse, GetWorkflowStatusResponse, InitializeJobsResponse,
+    DeleteWorkflowResponse, DeleteWorkflowScheduleResponse, GetAccessGroupResponse,
+    GetComputeNodeResponse, GetDotGraphResponse, GetEventResponse, GetFailureHandlerResponse,
+    GetFileResponse, GetJobLogResponse, GetJobResponse, GetLocalSchedulerResponse,
+    GetPendingActionsResponse, GetReadyJobRequirementsResponse, GetResourceRequirementsResponse,
+    GetResultResponse, GetScheduledComputeNodeResponse, GetSlurmSchedulerResponse,
+    GetUserDataResponse, GetVersionResponse, GetWorkflowActionsResponse, GetWorkflowResponse,
+    GetWorkflowScheduleResponse, GetWorkflowStatusResponse, InitializeJobsResponse,
     IsWorkflowCompleteResponse, IsWorkflowUninitializedResponse, ListAccessGroupsApiResponse,
-    ListComputeNodesResponse, ListEventsResponse, ListFailureHandlersResponse, ListFilesResponse,
-    ListGroupMembersResponse, ListJobDependenciesResponse, ListJobFileRelationshipsResponse,
-    ListJobIdsResponse, ListJobUserDataRelationshipsResponse, ListJobsResponse,
+    ListApiTokensApiResponse, ListComputeNodesResponse, ListEventsResponse,
+    ListFailureHandlersResponse, ListFilesResponse, ListGroupMembersResponse,
+    ListJobDependenciesResponse, ListJobFileRelationshipsResponse, ListJobIdsResponse,
+    ListJobLogsResponse, ListJobUserDataRelationshipsResponse, ListJobsResponse,
     ListLocalSchedulersResponse, ListMissingUserDataResponse, ListRemoteWorkersResponse,
     ListRequiredExistingFilesResponse, ListResourceRequirementsResponse, ListResultsResponse,
     ListScheduledComputeNodesResponse, ListSlurmSchedulersResponse, ListUserDataResponse,
-    ListUserGroupsApiResponse, ListWorkflowGroupsResponse, ListWorkflowsResponse,
-    ManageStatusChangeResponse, PingResponse, ProcessChangedJobInputsResponse,
-    RemoveUserFromGroupResponse, RemoveWorkflowFromGroupResponse, ResetJobStatusResponse,
-    ResetWorkflowStatusResponse, RetryJobResponse, StartJobResponse, UpdateComputeNodeResponse,
-    UpdateEventResponse, UpdateFileResponse, UpdateJobResponse, UpdateLocalSchedulerResponse,
+    ListUserGroupsApiResponse, ListWorkflowGroupsResponse, ListWorkflowSchedulesResponse,
+    ListWorkflowsResponse, ManageStatusChangeResponse, PingResponse,
+    ProcessChangedJobInputsResponse, ReleaseComputeNodeResponse, RemoveUserFromGroupResponse,
+    RemoveWorkflowFromGroupResponse, ReserveComputeNodeResponse, ResetJobStatusResponse,
+    ResetWorkflowStatusResponse, RetryJobResponse, RevokeApiTokenResponse, StartJobResponse,
+    UpdateComputeNodeHeartbeatResponse, UpdateComputeNodeResponse, UpdateEventResponse,
+    UpdateFileResponse, UpdateJobResponse, UpdateLocalSchedulerResponse,
     UpdateResourceRequirementsResponse, UpdateResultResponse, UpdateScheduledComputeNodeResponse,
     UpdateSlurmSchedulerResponse, UpdateUserDataResponse, UpdateWorkflowResponse,
-    UpdateWorkflowStatusResponse,
+    UpdateWorkflowScheduleResponse, UpdateWorkflowStatusResponse,
 };
 
 mod paths {
@@ -136,7 +141,21 @@ mod paths {
             // Retry job route (index 65)
             r"^/torc-service/v1/jobs/(?P<id>[^/?#]*)/retry/(?P<run_id>[^/?#]*)$",
             // SSE events stream route (index 66)
-            r"^/torc-service/v1/workflows/(?P<id>[^/?#]*)/events/stream$"
+            r"^/torc-service/v1/workflows/(?P<id>[^/?#]*)/events/stream$",
+            // API token routes (indices 67-68)
+            r"^/torc-service/v1/api_tokens$",
+            r"^/torc-service/v1/api_tokens/(?P<id>[^/?#]*)$",
+            // Compute node heartbeat route (index 69)
+            r"^/torc-service/v1/compute_nodes/(?P<id>[^/?#]*)/heartbeat$",
+            // Workflow schedule routes (indices 70-71)
+            r"^/torc-service/v1/workflow_schedules$",
+            r"^/torc-service/v1/workflow_schedules/(?P<id>[^/?#]*)$",
+            // Job log routes (indices 72-74)
+            r"^/torc-service/v1/job_logs$",
+            r"^/torc-service/v1/job_logs/(?P<id>[^/?#]*)$",
+            r"^/torc-service/v1/job_logs/(?P<id>[^/?#]*)/content$",
+            // Compute node reservation route (index 75)
+            r"^/torc-service/v1/compute_nodes/(?P<id>[^/?#]*)/reservation/(?P<job_id>[^/?#]*)$"
         ])
         .expect("Unable to create global regex set");
     }
@@ -486,6 +505,51 @@ regex::Regex::new(
             regex::Regex::new(r"^/torc-service/v1/workflows/(?P<id>[^/?#]*)/events/stream$")
                 .expect("Unable to create regex for WORKFLOWS_ID_EVENTS_STREAM");
     }
+    // API tokens
+    pub(crate) static ID_API_TOKENS: usize = 67;
+    pub(crate) static ID_API_TOKENS_ID: usize = 68;
+    lazy_static! {
+        pub static ref REGEX_API_TOKENS_ID: regex::Regex =
+            regex::Regex::new(r"^/torc-service/v1/api_tokens/(?P<id>[^/?#]*)$")
+                .expect("Unable to create regex for API_TOKENS_ID");
+    }
+    // Compute node heartbeat
+    pub(crate) static ID_COMPUTE_NODES_ID_HEARTBEAT: usize = 69;
+    lazy_static! {
+        pub static ref REGEX_COMPUTE_NODES_ID_HEARTBEAT: regex::Regex =
+            regex::Regex::new(r"^/torc-service/v1/compute_nodes/(?P<id>[^/?#]*)/heartbeat$")
+                .expect("Unable to create regex for COMPUTE_NODES_ID_HEARTBEAT");
+    }
+    // Workflow schedules
+    pub(crate) static ID_WORKFLOW_SCHEDULES: usize = 70;
+    pub(crate) static ID_WORKFLOW_SCHEDULES_ID: usize = 71;
+    lazy_static! {
+        pub static ref REGEX_WORKFLOW_SCHEDULES_ID: regex::Regex =
+            regex::Regex::new(r"^/torc-service/v1/workflow_schedules/(?P<id>[^/?#]*)$")
+                .expect("Unable to create regex for WORKFLOW_SCHEDULES_ID");
+    }
+    // Job logs
+    pub(crate) static ID_JOB_LOGS: usize = 72;
+    pub(crate) static ID_JOB_LOGS_ID: usize = 73;
+    lazy_static! {
+        pub static ref REGEX_JOB_LOGS_ID: regex::Regex =
+            regex::Regex::new(r"^/torc-service/v1/job_logs/(?P<id>[^/?#]*)$")
+                .expect("Unable to create regex for JOB_LOGS_ID");
+    }
+    pub(crate) static ID_JOB_LOGS_ID_CONTENT: usize = 74;
+    lazy_static! {
+        pub static ref REGEX_JOB_LOGS_ID_CONTENT: regex::Regex =
+            regex::Regex::new(r"^/torc-service/v1/job_logs/(?P<id>[^/?#]*)/content$")
+                .expect("Unable to create regex for JOB_LOGS_ID_CONTENT");
+    }
+    // Compute node reservation
+    pub(crate) static ID_COMPUTE_NODES_ID_RESERVATION_JOB_ID: usize = 75;
+    lazy_static! {
+        pub static ref REGEX_COMPUTE_NODES_ID_RESERVATION_JOB_ID: regex::Regex = regex::Regex::new(
+            r"^/torc-service/v1/compute_nodes/(?P<id>[^/?#]*)/reservation/(?P<job_id>[^/?#]*)$"
+        )
+        .expect("Unable to create regex for COMPUTE_NODES_ID_RESERVATION_JOB_ID");
+    }
 }
 
 pub struct MakeService<T, C>
@@ -14677,9 +14741,54 @@ where
                             .body(Body::from("Missing required query parameter max_retries"))
                             .expect("Unable to create Bad Request response for missing query parameter max_retries")),
                     };
+                    let param_delay_seconds = query_params
+                        .iter()
+                        .filter(|e| e.0 == "delay_seconds")
+                        .map(|e| e.1.clone())
+                        .next();
+                    let param_delay_seconds = match param_delay_seconds {
+                        Some(param_delay_seconds) => {
+                            let param_delay_seconds =
+                                <f64 as std::str::FromStr>::from_str(&param_delay_seconds);
+                            match param_delay_seconds {
+                            Ok(param_delay_seconds) => Some(param_delay_seconds),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter delay_seconds - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter delay_seconds")),
+                        }
+                        }
+                        None => None,
+                    };
+                    let param_avoid_compute_node_id = query_params
+                        .iter()
+                        .filter(|e| e.0 == "avoid_compute_node_id")
+                        .map(|e| e.1.clone())
+                        .next();
+                    let param_avoid_compute_node_id = match param_avoid_compute_node_id {
+                        Some(param_avoid_compute_node_id) => {
+                            let param_avoid_compute_node_id =
+                                <i64 as std::str::FromStr>::from_str(&param_avoid_compute_node_id);
+                            match param_avoid_compute_node_id {
+                            Ok(param_avoid_compute_node_id) => Some(param_avoid_compute_node_id),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter avoid_compute_node_id - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter avoid_compute_node_id")),
+                        }
+                        }
+                        None => None,
+                    };
 
                     let result = api_impl
-                        .retry_job(param_id, param_run_id, param_max_retries, &context)
+                        .retry_job(
+                            param_id,
+                            param_run_id,
+                            param_max_retries,
+                            param_delay_seconds,
+                            param_avoid_compute_node_id,
+                            &context,
+                        )
                         .await;
                     let mut response = Response::new(Body::empty());
                     response.headers_mut().insert(
@@ -16486,122 +16595,1722 @@ where
                 // ============================================================================
 
                 // ============================================================================
-                // End of Access Groups routes
+                // Workflow Schedules routes
                 // ============================================================================
-                _ if path.matched(paths::ID_ACCESS_GROUPS) => method_not_allowed(),
-                _ if path.matched(paths::ID_ACCESS_GROUPS_ID) => method_not_allowed(),
-                _ if path.matched(paths::ID_ACCESS_GROUPS_ID_MEMBERS) => method_not_allowed(),
-                _ if path.matched(paths::ID_ACCESS_GROUPS_ID_MEMBERS_USER_NAME) => {
-                    method_not_allowed()
-                }
-                _ if path.matched(paths::ID_USERS_USER_NAME_GROUPS) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID_ACCESS_GROUPS) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID_ACCESS_GROUPS_GROUP_ID) => {
-                    method_not_allowed()
-                }
-                _ if path.matched(paths::ID_ACCESS_CHECK_WORKFLOW_ID_USER_NAME) => {
-                    method_not_allowed()
-                }
-                _ if path.matched(paths::ID_FAILURE_HANDLERS) => method_not_allowed(),
-                _ if path.matched(paths::ID_FAILURE_HANDLERS_ID) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID_FAILURE_HANDLERS) => method_not_allowed(),
-                _ if path.matched(paths::ID_BULK_JOBS) => method_not_allowed(),
-                _ if path.matched(paths::ID_COMPUTE_NODES) => method_not_allowed(),
-                _ if path.matched(paths::ID_COMPUTE_NODES_ID) => method_not_allowed(),
-                _ if path.matched(paths::ID_EVENTS) => method_not_allowed(),
-                _ if path.matched(paths::ID_EVENTS_ID) => method_not_allowed(),
-                _ if path.matched(paths::ID_FILES) => method_not_allowed(),
-                _ if path.matched(paths::ID_FILES_ID) => method_not_allowed(),
-                _ if path.matched(paths::ID_JOBS) => method_not_allowed(),
-                _ if path.matched(paths::ID_JOBS_ID) => method_not_allowed(),
-                _ if path.matched(paths::ID_JOBS_ID_COMPLETE_JOB_STATUS_REV_RUN_ID) => {
-                    method_not_allowed()
-                }
-                _ if path.matched(paths::ID_JOBS_ID_MANAGE_STATUS_CHANGE_STATUS_REV_RUN_ID) => {
-                    method_not_allowed()
-                }
-                _ if path.matched(paths::ID_JOBS_ID_START_JOB_REV_RUN_ID_COMPUTE_NODE_ID) => {
-                    method_not_allowed()
-                }
-                _ if path.matched(paths::ID_JOBS_ID_RETRY_RUN_ID) => method_not_allowed(),
-                _ if path.matched(paths::ID_LOCAL_SCHEDULERS) => method_not_allowed(),
-                _ if path.matched(paths::ID_LOCAL_SCHEDULERS_ID) => method_not_allowed(),
-                _ if path.matched(paths::ID_PING) => method_not_allowed(),
-                _ if path.matched(paths::ID_RESOURCE_REQUIREMENTS) => method_not_allowed(),
-                _ if path.matched(paths::ID_RESOURCE_REQUIREMENTS_ID) => method_not_allowed(),
-                _ if path.matched(paths::ID_RESULTS) => method_not_allowed(),
-                _ if path.matched(paths::ID_RESULTS_ID) => method_not_allowed(),
-                _ if path.matched(paths::ID_SCHEDULED_COMPUTE_NODES) => method_not_allowed(),
-                _ if path.matched(paths::ID_SCHEDULED_COMPUTE_NODES_ID) => method_not_allowed(),
-                _ if path.matched(paths::ID_SLURM_SCHEDULERS) => method_not_allowed(),
-                _ if path.matched(paths::ID_SLURM_SCHEDULERS_ID) => method_not_allowed(),
-                _ if path.matched(paths::ID_USER_DATA) => method_not_allowed(),
-                _ if path.matched(paths::ID_USER_DATA_ID) => method_not_allowed(),
-                _ if path.matched(paths::ID_VERSION) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID_CANCEL) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID_DOT_GRAPH_NAME) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID_EVENTS_AFTER_TIMESTAMP_TIMESTAMP) => {
-                    method_not_allowed()
-                }
-                _ if path.matched(paths::ID_WORKFLOWS_ID_INITIALIZE_JOBS) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID_IS_COMPLETE) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID_JOB_IDS) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID_LATEST_EVENT_TIMESTAMP) => {
-                    method_not_allowed()
-                }
-                _ if path.matched(paths::ID_WORKFLOWS_ID_MISSING_USER_DATA) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID_PREPARE_JOBS_FOR_SCHEDULING) => {
-                    method_not_allowed()
-                }
-                _ if path.matched(paths::ID_WORKFLOWS_ID_CLAIM_JOBS_BASED_ON_RESOURCES) => {
-                    method_not_allowed()
-                }
-                _ if path.matched(paths::ID_WORKFLOWS_ID_CLAIM_NEXT_JOBS) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID_PROCESS_CHANGED_JOB_INPUTS) => {
-                    method_not_allowed()
-                }
-                _ if path.matched(paths::ID_WORKFLOWS_ID_READY_JOB_REQUIREMENTS) => {
-                    method_not_allowed()
-                }
-                _ if path.matched(paths::ID_WORKFLOWS_ID_REQUIRED_EXISTING_FILES) => {
-                    method_not_allowed()
-                }
-                _ if path.matched(paths::ID_WORKFLOWS_ID_RESET_JOB_STATUS) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID_RESET_STATUS) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID_STATUS) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID_REMOTE_WORKERS) => method_not_allowed(),
-                _ if path.matched(paths::ID_WORKFLOWS_ID_REMOTE_WORKERS_WORKER) => {
-                    method_not_allowed()
-                }
-                _ if path.matched(paths::ID_WORKFLOWS_ID_EVENTS_STREAM) => method_not_allowed(),
-                // Serve dashboard for non-API routes, 404 otherwise
-                _ => {
-                    // Try to serve dashboard assets for non-API paths
-                    if let Some(response) = crate::server::dashboard::serve_dashboard(uri.path()) {
-                        Ok(response)
-                    } else {
-                        Ok(Response::builder()
-                            .status(StatusCode::NOT_FOUND)
-                            .body(Body::empty())
-                            .expect("Unable to create Not Found response"))
+
+                // CreateWorkflowSchedule - POST /workflow_schedules
+                hyper::Method::POST if path.matched(paths::ID_WORKFLOW_SCHEDULES) => {
+                    // Body parameters (note that non-incremental parsing is used here)
+                    let result = body.into_raw().await;
+                    match result {
+                        Ok(body) => {
+                            let mut unused_elements = Vec::new();
+                            let param_body: Option<models::WorkflowScheduleModel> = if !body
+                                .is_empty()
+                            {
+                                let deserializer = &mut serde_json::Deserializer::from_slice(&body);
+                                match serde_ignored::deserialize(deserializer, |path| {
+                                    warn!("Ignoring unknown field in body: {}", path);
+                                    unused_elements.push(path.to_string());
+                                }) {
+                                    Ok(param_body) => param_body,
+                                    Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse body parameter body - doesn't match schema: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid body parameter body due to schema")),
+                                }
+                            } else {
+                                None
+                            };
+                            let param_body = match param_body {
+                                Some(param_body) => param_body,
+                                None => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from("Missing required body parameter body"))
+                                    .expect("Unable to create Bad Request response for missing body parameter body")),
+                            };
+
+                            let result = api_impl
+                                .create_workflow_schedule(param_body, &context)
+                                .await;
+                            let mut response = Response::new(Body::empty());
+                            response.headers_mut().insert(
+                                HeaderName::from_static("x-span-id"),
+                                HeaderValue::from_str(
+                                    (&context as &dyn Has<XSpanIdString>)
+                                        .get()
+                                        .0
+                                        .clone()
+                                        .as_str(),
+                                )
+                                .expect("Unable to create X-Span-ID header value"),
+                            );
+
+                            if !unused_elements.is_empty() {
+                                response.headers_mut().insert(
+                                    HeaderName::from_static("warning"),
+                                    HeaderValue::from_str(
+                                        format!(
+                                            "Ignoring unknown fields in body: {:?}",
+                                            unused_elements
+                                        )
+                                        .as_str(),
+                                    )
+                                    .expect("Unable to create Warning header value"),
+                                );
+                            }
+                            match result {
+                                Ok(rsp) => match rsp {
+                                    CreateWorkflowScheduleResponse::SuccessfulResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(200)
+                                            .expect("Unable to turn 200 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    CreateWorkflowScheduleResponse::ForbiddenErrorResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(403)
+                                            .expect("Unable to turn 403 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    CreateWorkflowScheduleResponse::NotFoundErrorResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(404)
+                                            .expect("Unable to turn 404 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    CreateWorkflowScheduleResponse::UnprocessableContentErrorResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(422)
+                                            .expect("Unable to turn 422 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    CreateWorkflowScheduleResponse::DefaultErrorResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(500)
+                                            .expect("Unable to turn 500 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                },
+                                Err(_) => {
+                                    *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                    *response.body_mut() = Body::from("An internal error occurred");
+                                }
+                            }
+                            Ok(response)
+                        }
+                        Err(e) => Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Unable to read body: {}", e)))
+                            .expect(
+                                "Unable to create Bad Request response due to unable to read body",
+                            )),
                     }
                 }
-            }
-        }
-        Box::pin(run(self.api_impl.clone(), req))
-    }
-}
 
-/// Request parser for `Api`.
-pub struct ApiRequestParser;
-impl<T> RequestParser<T> for ApiRequestParser {
-    fn parse_operation_id(request: &Request<T>) -> Option<&'static str> {
-        let path = paths::GLOBAL_REGEX_SET.matches(request.uri().path());
-        match *request.method() {
-            // CreateComputeNode - POST /compute_nodes
-            hyper::Method::POST if path.matched(paths::ID_COMPUTE_NODES) => {
+                // ListWorkflowSchedules - GET /workflow_schedules
+                hyper::Method::GET if path.matched(paths::ID_WORKFLOW_SCHEDULES) => {
+                    let query_params =
+                        form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes())
+                            .collect::<Vec<_>>();
+                    let param_workflow_id = query_params
+                        .iter()
+                        .filter(|e| e.0 == "workflow_id")
+                        .map(|e| e.1.clone())
+                        .next();
+                    let param_workflow_id = match param_workflow_id {
+                        Some(param_workflow_id) => {
+                            let param_workflow_id =
+                                <i64 as std::str::FromStr>::from_str(&param_workflow_id);
+                            match param_workflow_id {
+                                Ok(param_workflow_id) => Some(param_workflow_id),
+                                Err(e) => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Couldn't parse query parameter workflow_id - doesn't match schema: {}", e)))
+                                    .expect("Unable to create Bad Request response for invalid query parameter workflow_id")),
+                            }
+                        }
+                        None => None,
+                    };
+                    let param_offset = query_params
+                        .iter()
+                        .filter(|e| e.0 == "offset")
+                        .map(|e| e.1.clone())
+                        .next();
+                    let param_offset = match param_offset {
+                        Some(param_offset) => {
+                            let param_offset = <i64 as std::str::FromStr>::from_str(&param_offset);
+                            match param_offset {
+                                Ok(param_offset) => Some(param_offset),
+                                Err(e) => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Couldn't parse query parameter offset - doesn't match schema: {}", e)))
+                                    .expect("Unable to create Bad Request response for invalid query parameter offset")),
+                            }
+                        }
+                        None => None,
+                    };
+                    let param_limit = query_params
+                        .iter()
+                        .filter(|e| e.0 == "limit")
+                        .map(|e| e.1.clone())
+                        .next();
+                    let param_limit = match param_limit {
+                        Some(param_limit) => {
+                            let param_limit = <i64 as std::str::FromStr>::from_str(&param_limit);
+                            match param_limit {
+                                Ok(param_limit) => Some(param_limit),
+                                Err(e) => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Couldn't parse query parameter limit - doesn't match schema: {}", e)))
+                                    .expect("Unable to create Bad Request response for invalid query parameter limit")),
+                            }
+                        }
+                        None => None,
+                    };
+
+                    let result = api_impl
+                        .list_workflow_schedules(
+                            param_workflow_id,
+                            param_offset,
+                            param_limit,
+                            &context,
+                        )
+                        .await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => {
+                            match rsp {
+                                ListWorkflowSchedulesResponse::SuccessfulResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(200)
+                                        .expect("Unable to turn 200 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                ListWorkflowSchedulesResponse::ForbiddenErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(403)
+                                        .expect("Unable to turn 403 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                ListWorkflowSchedulesResponse::DefaultErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(500)
+                                        .expect("Unable to turn 500 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                            }
+                        }
+                        Err(_) => {
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+                    Ok(response)
+                }
+
+                // GetWorkflowSchedule - GET /workflow_schedules/{id}
+                hyper::Method::GET if path.matched(paths::ID_WORKFLOW_SCHEDULES_ID) => {
+                    let path: &str = uri.path();
+                    let path_params = paths::REGEX_WORKFLOW_SCHEDULES_ID.captures(path)
+                        .unwrap_or_else(|| panic!("Path {} matched RE WORKFLOW_SCHEDULES_ID in set but failed match against \"{}\"", path, paths::REGEX_WORKFLOW_SCHEDULES_ID.as_str()));
+
+                    let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
+                        Ok(param_id) => match param_id.parse::<i64>() {
+                            Ok(param_id) => param_id,
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse path parameter id: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid path parameter")),
+                        },
+                        Err(_) => return Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["id"])))
+                            .expect("Unable to create Bad Request response for invalid percent decode"))
+                    };
+
+                    let result = api_impl.get_workflow_schedule(param_id, &context).await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => {
+                            match rsp {
+                                GetWorkflowScheduleResponse::SuccessfulResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(200)
+                                        .expect("Unable to turn 200 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                GetWorkflowScheduleResponse::ForbiddenErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(403)
+                                        .expect("Unable to turn 403 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                GetWorkflowScheduleResponse::NotFoundErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(404)
+                                        .expect("Unable to turn 404 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                GetWorkflowScheduleResponse::DefaultErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(500)
+                                        .expect("Unable to turn 500 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                            }
+                        }
+                        Err(_) => {
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+                    Ok(response)
+                }
+
+                // UpdateWorkflowSchedule - PUT /workflow_schedules/{id}
+                hyper::Method::PUT if path.matched(paths::ID_WORKFLOW_SCHEDULES_ID) => {
+                    let path: &str = uri.path();
+                    let path_params = paths::REGEX_WORKFLOW_SCHEDULES_ID.captures(path)
+                        .unwrap_or_else(|| panic!("Path {} matched RE WORKFLOW_SCHEDULES_ID in set but failed match against \"{}\"", path, paths::REGEX_WORKFLOW_SCHEDULES_ID.as_str()));
+
+                    let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
+                        Ok(param_id) => match param_id.parse::<i64>() {
+                            Ok(param_id) => param_id,
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse path parameter id: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid path parameter")),
+                        },
+                        Err(_) => return Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["id"])))
+                            .expect("Unable to create Bad Request response for invalid percent decode"))
+                    };
+
+                    // Body parameters (note that non-incremental parsing is used here)
+                    let result = body.into_raw().await;
+                    match result {
+                        Ok(body) => {
+                            let mut unused_elements = Vec::new();
+                            let param_body: Option<models::WorkflowScheduleModel> = if !body
+                                .is_empty()
+                            {
+                                let deserializer = &mut serde_json::Deserializer::from_slice(&body);
+                                match serde_ignored::deserialize(deserializer, |path| {
+                                    warn!("Ignoring unknown field in body: {}", path);
+                                    unused_elements.push(path.to_string());
+                                }) {
+                                    Ok(param_body) => param_body,
+                                    Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse body parameter body - doesn't match schema: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid body parameter body due to schema")),
+                                }
+                            } else {
+                                None
+                            };
+                            let param_body = match param_body {
+                                Some(param_body) => param_body,
+                                None => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from("Missing required body parameter body"))
+                                    .expect("Unable to create Bad Request response for missing body parameter body")),
+                            };
+
+                            let result = api_impl
+                                .update_workflow_schedule(param_id, param_body, &context)
+                                .await;
+                            let mut response = Response::new(Body::empty());
+                            response.headers_mut().insert(
+                                HeaderName::from_static("x-span-id"),
+                                HeaderValue::from_str(
+                                    (&context as &dyn Has<XSpanIdString>)
+                                        .get()
+                                        .0
+                                        .clone()
+                                        .as_str(),
+                                )
+                                .expect("Unable to create X-Span-ID header value"),
+                            );
+
+                            if !unused_elements.is_empty() {
+                                response.headers_mut().insert(
+                                    HeaderName::from_static("warning"),
+                                    HeaderValue::from_str(
+                                        format!(
+                                            "Ignoring unknown fields in body: {:?}",
+                                            unused_elements
+                                        )
+                                        .as_str(),
+                                    )
+                                    .expect("Unable to create Warning header value"),
+                                );
+                            }
+                            match result {
+                                Ok(rsp) => match rsp {
+                                    UpdateWorkflowScheduleResponse::SuccessfulResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(200)
+                                            .expect("Unable to turn 200 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    UpdateWorkflowScheduleResponse::ForbiddenErrorResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(403)
+                                            .expect("Unable to turn 403 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    UpdateWorkflowScheduleResponse::NotFoundErrorResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(404)
+                                            .expect("Unable to turn 404 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    UpdateWorkflowScheduleResponse::UnprocessableContentErrorResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(422)
+                                            .expect("Unable to turn 422 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    UpdateWorkflowScheduleResponse::DefaultErrorResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(500)
+                                            .expect("Unable to turn 500 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                },
+                                Err(_) => {
+                                    *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                    *response.body_mut() = Body::from("An internal error occurred");
+                                }
+                            }
+                            Ok(response)
+                        }
+                        Err(e) => Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Unable to read body: {}", e)))
+                            .expect(
+                                "Unable to create Bad Request response due to unable to read body",
+                            )),
+                    }
+                }
+
+                // DeleteWorkflowSchedule - DELETE /workflow_schedules/{id}
+                hyper::Method::DELETE if path.matched(paths::ID_WORKFLOW_SCHEDULES_ID) => {
+                    let path: &str = uri.path();
+                    let path_params = paths::REGEX_WORKFLOW_SCHEDULES_ID.captures(path)
+                        .unwrap_or_else(|| panic!("Path {} matched RE WORKFLOW_SCHEDULES_ID in set but failed match against \"{}\"", path, paths::REGEX_WORKFLOW_SCHEDULES_ID.as_str()));
+
+                    let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
+                        Ok(param_id) => match param_id.parse::<i64>() {
+                            Ok(param_id) => param_id,
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse path parameter id: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid path parameter")),
+                        },
+                        Err(_) => return Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["id"])))
+                            .expect("Unable to create Bad Request response for invalid percent decode"))
+                    };
+
+                    // Body parameters (note that non-incremental parsing is used here)
+                    let result = body.into_raw().await;
+                    match result {
+                        Ok(body) => {
+                            let param_body: Option<serde_json::Value> = if !body.is_empty() {
+                                match serde_json::from_slice(&body) {
+                                    Ok(param_body) => param_body,
+                                    Err(_) => None,
+                                }
+                            } else {
+                                None
+                            };
+
+                            let result = api_impl
+                                .delete_workflow_schedule(param_id, param_body, &context)
+                                .await;
+                            let mut response = Response::new(Body::empty());
+                            response.headers_mut().insert(
+                                HeaderName::from_static("x-span-id"),
+                                HeaderValue::from_str(
+                                    (&context as &dyn Has<XSpanIdString>)
+                                        .get()
+                                        .0
+                                        .clone()
+                                        .as_str(),
+                                )
+                                .expect("Unable to create X-Span-ID header value"),
+                            );
+
+                            match result {
+                                Ok(rsp) => match rsp {
+                                    DeleteWorkflowScheduleResponse::SuccessfulResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(200)
+                                            .expect("Unable to turn 200 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    DeleteWorkflowScheduleResponse::ForbiddenErrorResponse(
+                                        body,
+                                    ) => {
+                                        *response.status_mut() = StatusCode::from_u16(403)
+                                            .expect("Unable to turn 403 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    DeleteWorkflowScheduleResponse::NotFoundErrorResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(404)
+                                            .expect("Unable to turn 404 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    DeleteWorkflowScheduleResponse::DefaultErrorResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(500)
+                                            .expect("Unable to turn 500 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                },
+                                Err(_) => {
+                                    *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                    *response.body_mut() = Body::from("An internal error occurred");
+                                }
+                            }
+                            Ok(response)
+                        }
+                        Err(e) => Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Unable to read body: {}", e)))
+                            .expect(
+                                "Unable to create Bad Request response due to unable to read body",
+                            )),
+                    }
+                }
+
+                // ============================================================================
+                // End of Workflow Schedules routes
+                // ============================================================================
+
+                // ============================================================================
+                // Job Logs routes
+                // ============================================================================
+
+                // CreateJobLog - POST /job_logs
+                hyper::Method::POST if path.matched(paths::ID_JOB_LOGS) => {
+                    // Body parameters (note that non-incremental parsing is used here)
+                    let result = body.into_raw().await;
+                    match result {
+                        Ok(body) => {
+                            let mut unused_elements = Vec::new();
+                            let param_body: Option<models::JobLogModel> = if !body.is_empty() {
+                                let deserializer = &mut serde_json::Deserializer::from_slice(&body);
+                                match serde_ignored::deserialize(deserializer, |path| {
+                                    warn!("Ignoring unknown field in body: {}", path);
+                                    unused_elements.push(path.to_string());
+                                }) {
+                                    Ok(param_body) => param_body,
+                                    Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse body parameter body - doesn't match schema: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid body parameter body due to schema")),
+                                }
+                            } else {
+                                None
+                            };
+                            let param_body = match param_body {
+                                Some(param_body) => param_body,
+                                None => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from("Missing required body parameter body"))
+                                    .expect("Unable to create Bad Request response for missing body parameter body")),
+                            };
+
+                            let result = api_impl.create_job_log(param_body, &context).await;
+                            let mut response = Response::new(Body::empty());
+                            response.headers_mut().insert(
+                                HeaderName::from_static("x-span-id"),
+                                HeaderValue::from_str(
+                                    (&context as &dyn Has<XSpanIdString>)
+                                        .get()
+                                        .0
+                                        .clone()
+                                        .as_str(),
+                                )
+                                .expect("Unable to create X-Span-ID header value"),
+                            );
+
+                            if !unused_elements.is_empty() {
+                                response.headers_mut().insert(
+                                    HeaderName::from_static("warning"),
+                                    HeaderValue::from_str(
+                                        format!(
+                                            "Ignoring unknown fields in body: {:?}",
+                                            unused_elements
+                                        )
+                                        .as_str(),
+                                    )
+                                    .expect("Unable to create Warning header value"),
+                                );
+                            }
+                            match result {
+                                Ok(rsp) => match rsp {
+                                    CreateJobLogResponse::SuccessfulResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(200)
+                                            .expect("Unable to turn 200 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    CreateJobLogResponse::ForbiddenErrorResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(403)
+                                            .expect("Unable to turn 403 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    CreateJobLogResponse::NotFoundErrorResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(404)
+                                            .expect("Unable to turn 404 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    CreateJobLogResponse::UnprocessableContentErrorResponse(
+                                        body,
+                                    ) => {
+                                        *response.status_mut() = StatusCode::from_u16(422)
+                                            .expect("Unable to turn 422 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    CreateJobLogResponse::DefaultErrorResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(500)
+                                            .expect("Unable to turn 500 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                },
+                                Err(_) => {
+                                    *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                    *response.body_mut() = Body::from("An internal error occurred");
+                                }
+                            }
+                            Ok(response)
+                        }
+                        Err(e) => Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Unable to read body: {}", e)))
+                            .expect(
+                                "Unable to create Bad Request response due to unable to read body",
+                            )),
+                    }
+                }
+
+                // ListJobLogs - GET /job_logs
+                hyper::Method::GET if path.matched(paths::ID_JOB_LOGS) => {
+                    let query_params =
+                        form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes())
+                            .collect::<Vec<_>>();
+                    let param_workflow_id = query_params
+                        .iter()
+                        .filter(|e| e.0 == "workflow_id")
+                        .map(|e| e.1.clone())
+                        .next();
+                    let param_workflow_id = match param_workflow_id {
+                        Some(param_workflow_id) => {
+                            let param_workflow_id =
+                                <i64 as std::str::FromStr>::from_str(&param_workflow_id);
+                            match param_workflow_id {
+                                Ok(param_workflow_id) => Some(param_workflow_id),
+                                Err(e) => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Couldn't parse query parameter workflow_id - doesn't match schema: {}", e)))
+                                    .expect("Unable to create Bad Request response for invalid query parameter workflow_id")),
+                            }
+                        }
+                        None => None,
+                    };
+                    let param_workflow_id = match param_workflow_id {
+                        Some(param_workflow_id) => param_workflow_id,
+                        None => return Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from("Missing required query parameter workflow_id"))
+                            .expect("Unable to create Bad Request response for missing query parameter workflow_id")),
+                    };
+                    let param_job_id = query_params
+                        .iter()
+                        .filter(|e| e.0 == "job_id")
+                        .map(|e| e.1.clone())
+                        .next();
+                    let param_job_id = match param_job_id {
+                        Some(param_job_id) => {
+                            let param_job_id = <i64 as std::str::FromStr>::from_str(&param_job_id);
+                            match param_job_id {
+                                Ok(param_job_id) => Some(param_job_id),
+                                Err(e) => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Couldn't parse query parameter job_id - doesn't match schema: {}", e)))
+                                    .expect("Unable to create Bad Request response for invalid query parameter job_id")),
+                            }
+                        }
+                        None => None,
+                    };
+                    let param_run_id = query_params
+                        .iter()
+                        .filter(|e| e.0 == "run_id")
+                        .map(|e| e.1.clone())
+                        .next();
+                    let param_run_id = match param_run_id {
+                        Some(param_run_id) => {
+                            let param_run_id = <i64 as std::str::FromStr>::from_str(&param_run_id);
+                            match param_run_id {
+                                Ok(param_run_id) => Some(param_run_id),
+                                Err(e) => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Couldn't parse query parameter run_id - doesn't match schema: {}", e)))
+                                    .expect("Unable to create Bad Request response for invalid query parameter run_id")),
+                            }
+                        }
+                        None => None,
+                    };
+                    let param_attempt_id = query_params
+                        .iter()
+                        .filter(|e| e.0 == "attempt_id")
+                        .map(|e| e.1.clone())
+                        .next();
+                    let param_attempt_id = match param_attempt_id {
+                        Some(param_attempt_id) => {
+                            let param_attempt_id =
+                                <i64 as std::str::FromStr>::from_str(&param_attempt_id);
+                            match param_attempt_id {
+                                Ok(param_attempt_id) => Some(param_attempt_id),
+                                Err(e) => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Couldn't parse query parameter attempt_id - doesn't match schema: {}", e)))
+                                    .expect("Unable to create Bad Request response for invalid query parameter attempt_id")),
+                            }
+                        }
+                        None => None,
+                    };
+                    let param_stream = query_params
+                        .iter()
+                        .filter(|e| e.0 == "stream")
+                        .map(|e| e.1.clone())
+                        .next();
+                    let param_stream = match param_stream {
+                        Some(param_stream) => {
+                            let param_stream =
+                                <models::JobLogStream as std::str::FromStr>::from_str(
+                                    &param_stream,
+                                );
+                            match param_stream {
+                                Ok(param_stream) => Some(param_stream),
+                                Err(e) => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Couldn't parse query parameter stream - doesn't match schema: {}", e)))
+                                    .expect("Unable to create Bad Request response for invalid query parameter stream")),
+                            }
+                        }
+                        None => None,
+                    };
+                    let param_offset = query_params
+                        .iter()
+                        .filter(|e| e.0 == "offset")
+                        .map(|e| e.1.clone())
+                        .next();
+                    let param_offset = match param_offset {
+                        Some(param_offset) => {
+                            let param_offset = <i64 as std::str::FromStr>::from_str(&param_offset);
+                            match param_offset {
+                                Ok(param_offset) => Some(param_offset),
+                                Err(e) => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Couldn't parse query parameter offset - doesn't match schema: {}", e)))
+                                    .expect("Unable to create Bad Request response for invalid query parameter offset")),
+                            }
+                        }
+                        None => None,
+                    };
+                    let param_limit = query_params
+                        .iter()
+                        .filter(|e| e.0 == "limit")
+                        .map(|e| e.1.clone())
+                        .next();
+                    let param_limit = match param_limit {
+                        Some(param_limit) => {
+                            let param_limit = <i64 as std::str::FromStr>::from_str(&param_limit);
+                            match param_limit {
+                                Ok(param_limit) => Some(param_limit),
+                                Err(e) => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Couldn't parse query parameter limit - doesn't match schema: {}", e)))
+                                    .expect("Unable to create Bad Request response for invalid query parameter limit")),
+                            }
+                        }
+                        None => None,
+                    };
+
+                    let result = api_impl
+                        .list_job_logs(
+                            param_workflow_id,
+                            param_job_id,
+                            param_run_id,
+                            param_attempt_id,
+                            param_stream,
+                            param_offset,
+                            param_limit,
+                            &context,
+                        )
+                        .await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => {
+                            match rsp {
+                                ListJobLogsResponse::SuccessfulResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(200)
+                                        .expect("Unable to turn 200 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                ListJobLogsResponse::ForbiddenErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(403)
+                                        .expect("Unable to turn 403 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                ListJobLogsResponse::NotFoundErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(404)
+                                        .expect("Unable to turn 404 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                ListJobLogsResponse::DefaultErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(500)
+                                        .expect("Unable to turn 500 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                            }
+                        }
+                        Err(_) => {
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+                    Ok(response)
+                }
+
+                // GetJobLog - GET /job_logs/{id}
+                hyper::Method::GET if path.matched(paths::ID_JOB_LOGS_ID) => {
+                    let path: &str = uri.path();
+                    let path_params = paths::REGEX_JOB_LOGS_ID.captures(path)
+                        .unwrap_or_else(|| panic!("Path {} matched RE JOB_LOGS_ID in set but failed match against \"{}\"", path, paths::REGEX_JOB_LOGS_ID.as_str()));
+
+                    let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
+                        Ok(param_id) => match param_id.parse::<i64>() {
+                            Ok(param_id) => param_id,
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse path parameter id: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid path parameter")),
+                        },
+                        Err(_) => return Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["id"])))
+                            .expect("Unable to create Bad Request response for invalid percent decode"))
+                    };
+
+                    let result = api_impl.get_job_log(param_id, &context).await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => {
+                            match rsp {
+                                GetJobLogResponse::SuccessfulResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(200)
+                                        .expect("Unable to turn 200 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                GetJobLogResponse::ForbiddenErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(403)
+                                        .expect("Unable to turn 403 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                GetJobLogResponse::NotFoundErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(404)
+                                        .expect("Unable to turn 404 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                GetJobLogResponse::DefaultErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(500)
+                                        .expect("Unable to turn 500 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                            }
+                        }
+                        Err(_) => {
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+                    Ok(response)
+                }
+
+                // GetJobLogContent - GET /job_logs/{id}/content - Return the log text as plain text
+                hyper::Method::GET if path.matched(paths::ID_JOB_LOGS_ID_CONTENT) => {
+                    let path: &str = uri.path();
+                    let path_params = paths::REGEX_JOB_LOGS_ID_CONTENT.captures(path)
+                        .unwrap_or_else(|| panic!("Path {} matched RE JOB_LOGS_ID_CONTENT in set but failed match against \"{}\"", path, paths::REGEX_JOB_LOGS_ID_CONTENT.as_str()));
+
+                    let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
+                        Ok(param_id) => match param_id.parse::<i64>() {
+                            Ok(param_id) => param_id,
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse path parameter id: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid path parameter")),
+                        },
+                        Err(_) => return Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["id"])))
+                            .expect("Unable to create Bad Request response for invalid percent decode"))
+                    };
+
+                    let result = api_impl.get_job_log(param_id, &context).await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => {
+                            let (status, body) = match rsp {
+                                GetJobLogResponse::SuccessfulResponse(body) => {
+                                    response.headers_mut().insert(
+                                        CONTENT_TYPE,
+                                        HeaderValue::from_static("text/plain; charset=utf-8"),
+                                    );
+                                    *response.body_mut() = Body::from(body.content);
+                                    return Ok(response);
+                                }
+                                GetJobLogResponse::ForbiddenErrorResponse(body) => (403, body),
+                                GetJobLogResponse::NotFoundErrorResponse(body) => (404, body),
+                                GetJobLogResponse::DefaultErrorResponse(body) => (500, body),
+                            };
+                            *response.status_mut() = StatusCode::from_u16(status)
+                                .expect("Unable to turn status into a StatusCode");
+                            response.headers_mut().insert(
+                                CONTENT_TYPE,
+                                HeaderValue::from_str("application/json").expect(
+                                    "Unable to create Content-Type header for application/json",
+                                ),
+                            );
+                            let body = serde_json::to_string(&body)
+                                .expect("impossible to fail to serialize");
+                            *response.body_mut() = Body::from(body);
+                        }
+                        Err(_) => {
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+                    Ok(response)
+                }
+
+                // ============================================================================
+                // End of Job Logs routes
+                // ============================================================================
+
+                // ============================================================================
+                // API Tokens routes
+                // ============================================================================
+
+                // CreateApiToken - POST /api_tokens
+                hyper::Method::POST if path.matched(paths::ID_API_TOKENS) => {
+                    // Body parameters (note that non-incremental parsing is used here)
+                    let result = body.into_raw().await;
+                    match result {
+                        Ok(body) => {
+                            let mut unused_elements = Vec::new();
+                            let param_body: Option<models::ApiTokenModel> = if !body.is_empty() {
+                                let deserializer = &mut serde_json::Deserializer::from_slice(&body);
+                                match serde_ignored::deserialize(deserializer, |path| {
+                                    warn!("Ignoring unknown field in body: {}", path);
+                                    unused_elements.push(path.to_string());
+                                }) {
+                                    Ok(param_body) => param_body,
+                                    Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse body parameter body - doesn't match schema: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid body parameter body due to schema")),
+                                }
+                            } else {
+                                None
+                            };
+                            let param_body = match param_body {
+                                Some(param_body) => param_body,
+                                None => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from("Missing required body parameter body"))
+                                    .expect("Unable to create Bad Request response for missing body parameter body")),
+                            };
+
+                            let result = api_impl.create_api_token(param_body, &context).await;
+                            let mut response = Response::new(Body::empty());
+                            response.headers_mut().insert(
+                                HeaderName::from_static("x-span-id"),
+                                HeaderValue::from_str(
+                                    (&context as &dyn Has<XSpanIdString>)
+                                        .get()
+                                        .0
+                                        .clone()
+                                        .as_str(),
+                                )
+                                .expect("Unable to create X-Span-ID header value"),
+                            );
+
+                            if !unused_elements.is_empty() {
+                                response.headers_mut().insert(
+                                    HeaderName::from_static("warning"),
+                                    HeaderValue::from_str(
+                                        format!(
+                                            "Ignoring unknown fields in body: {:?}",
+                                            unused_elements
+                                        )
+                                        .as_str(),
+                                    )
+                                    .expect("Unable to create Warning header value"),
+                                );
+                            }
+                            match result {
+                                Ok(rsp) => match rsp {
+                                    CreateApiTokenResponse::SuccessfulResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(200)
+                                            .expect("Unable to turn 200 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    CreateApiTokenResponse::ForbiddenErrorResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(403)
+                                            .expect("Unable to turn 403 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    CreateApiTokenResponse::UnprocessableContentErrorResponse(
+                                        body,
+                                    ) => {
+                                        *response.status_mut() = StatusCode::from_u16(422)
+                                            .expect("Unable to turn 422 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                    CreateApiTokenResponse::DefaultErrorResponse(body) => {
+                                        *response.status_mut() = StatusCode::from_u16(500)
+                                            .expect("Unable to turn 500 into a StatusCode");
+                                        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                        let body = serde_json::to_string(&body)
+                                            .expect("impossible to fail to serialize");
+                                        *response.body_mut() = Body::from(body);
+                                    }
+                                },
+                                Err(_) => {
+                                    *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                    *response.body_mut() = Body::from("An internal error occurred");
+                                }
+                            }
+                            Ok(response)
+                        }
+                        Err(e) => Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Unable to read body: {}", e)))
+                            .expect(
+                                "Unable to create Bad Request response due to unable to read body",
+                            )),
+                    }
+                }
+
+                // ListApiTokens - GET /api_tokens
+                hyper::Method::GET if path.matched(paths::ID_API_TOKENS) => {
+                    let query_params =
+                        form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes())
+                            .collect::<Vec<_>>();
+                    let param_offset = query_params
+                        .iter()
+                        .filter(|e| e.0 == "offset")
+                        .map(|e| e.1.clone())
+                        .next();
+                    let param_offset = match param_offset {
+                        Some(param_offset) => {
+                            let param_offset = <i64 as std::str::FromStr>::from_str(&param_offset);
+                            match param_offset {
+                                Ok(param_offset) => Some(param_offset),
+                                Err(e) => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Couldn't parse query parameter offset - doesn't match schema: {}", e)))
+                                    .expect("Unable to create Bad Request response for invalid query parameter offset")),
+                            }
+                        }
+                        None => None,
+                    };
+                    let param_limit = query_params
+                        .iter()
+                        .filter(|e| e.0 == "limit")
+                        .map(|e| e.1.clone())
+                        .next();
+                    let param_limit = match param_limit {
+                        Some(param_limit) => {
+                            let param_limit = <i64 as std::str::FromStr>::from_str(&param_limit);
+                            match param_limit {
+                                Ok(param_limit) => Some(param_limit),
+                                Err(e) => return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Couldn't parse query parameter limit - doesn't match schema: {}", e)))
+                                    .expect("Unable to create Bad Request response for invalid query parameter limit")),
+                            }
+                        }
+                        None => None,
+                    };
+
+                    let result = api_impl
+                        .list_api_tokens(param_offset, param_limit, &context)
+                        .await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => {
+                            match rsp {
+                                ListApiTokensApiResponse::SuccessfulResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(200)
+                                        .expect("Unable to turn 200 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                ListApiTokensApiResponse::DefaultErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(500)
+                                        .expect("Unable to turn 500 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                            }
+                        }
+                        Err(_) => {
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+                    Ok(response)
+                }
+
+                // RevokeApiToken - DELETE /api_tokens/{id}
+                hyper::Method::DELETE if path.matched(paths::ID_API_TOKENS_ID) => {
+                    let path: &str = uri.path();
+                    let path_params = paths::REGEX_API_TOKENS_ID.captures(path)
+                        .unwrap_or_else(|| panic!("Path {} matched RE API_TOKENS_ID in set but failed match against \"{}\"", path, paths::REGEX_API_TOKENS_ID.as_str()));
+
+                    let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
+                        Ok(param_id) => match param_id.parse::<i64>() {
+                            Ok(param_id) => param_id,
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse path parameter id: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid path parameter")),
+                        },
+                        Err(_) => return Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["id"])))
+                            .expect("Unable to create Bad Request response for invalid percent decode"))
+                    };
+
+                    let result = api_impl.revoke_api_token(param_id, &context).await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => {
+                            match rsp {
+                                RevokeApiTokenResponse::SuccessfulResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(200)
+                                        .expect("Unable to turn 200 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                RevokeApiTokenResponse::ForbiddenErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(403)
+                                        .expect("Unable to turn 403 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                RevokeApiTokenResponse::NotFoundErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(404)
+                                        .expect("Unable to turn 404 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                RevokeApiTokenResponse::DefaultErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(500)
+                                        .expect("Unable to turn 500 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                            }
+                        }
+                        Err(_) => {
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+                    Ok(response)
+                }
+
+                // ============================================================================
+                // End of API Tokens routes
+                // ============================================================================
+
+                // UpdateComputeNodeHeartbeat - PUT /compute_nodes/{id}/heartbeat
+                hyper::Method::PUT if path.matched(paths::ID_COMPUTE_NODES_ID_HEARTBEAT) => {
+                    let path: &str = uri.path();
+                    let path_params = paths::REGEX_COMPUTE_NODES_ID_HEARTBEAT.captures(path)
+                        .unwrap_or_else(|| panic!("Path {} matched RE COMPUTE_NODES_ID_HEARTBEAT in set but failed match against \"{}\"", path, paths::REGEX_COMPUTE_NODES_ID_HEARTBEAT.as_str()));
+
+                    let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
+                        Ok(param_id) => match param_id.parse::<i64>() {
+                            Ok(param_id) => param_id,
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse path parameter id: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid path parameter")),
+                        },
+                        Err(_) => return Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["id"])))
+                            .expect("Unable to create Bad Request response for invalid percent decode"))
+                    };
+
+                    let result = api_impl
+                        .update_compute_node_heartbeat(param_id, &context)
+                        .await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => {
+                            match rsp {
+                                UpdateComputeNodeHeartbeatResponse::SuccessfulResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(200)
+                                        .expect("Unable to turn 200 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                UpdateComputeNodeHeartbeatResponse::NotFoundErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(404)
+                                        .expect("Unable to turn 404 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                UpdateComputeNodeHeartbeatResponse::DefaultErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(500)
+                                        .expect("Unable to turn 500 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                            }
+                        }
+                        Err(_) => {
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+                    Ok(response)
+                }
+
+                // ReserveComputeNode - PUT /compute_nodes/{id}/reservation/{job_id}
+                hyper::Method::PUT
+                    if path.matched(paths::ID_COMPUTE_NODES_ID_RESERVATION_JOB_ID) =>
+                {
+                    let path: &str = uri.path();
+                    let path_params = paths::REGEX_COMPUTE_NODES_ID_RESERVATION_JOB_ID.captures(path)
+                        .unwrap_or_else(|| panic!("Path {} matched RE COMPUTE_NODES_ID_RESERVATION_JOB_ID in set but failed match against \"{}\"", path, paths::REGEX_COMPUTE_NODES_ID_RESERVATION_JOB_ID.as_str()));
+
+                    let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
+                        Ok(param_id) => match param_id.parse::<i64>() {
+                            Ok(param_id) => param_id,
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse path parameter id: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid path parameter")),
+                        },
+                        Err(_) => return Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["id"])))
+                            .expect("Unable to create Bad Request response for invalid percent decode"))
+                    };
+
+                    let param_job_id = match percent_encoding::percent_decode(path_params["job_id"].as_bytes()).decode_utf8() {
+                        Ok(param_job_id) => match param_job_id.parse::<i64>() {
+                            Ok(param_job_id) => param_job_id,
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse path parameter job_id: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid path parameter")),
+                        },
+                        Err(_) => return Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["job_id"])))
+                            .expect("Unable to create Bad Request response for invalid percent decode"))
+                    };
+
+                    let result = api_impl
+                        .reserve_compute_node(param_id, param_job_id, &context)
+                        .await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => {
+                            match rsp {
+                                ReserveComputeNodeResponse::SuccessfulResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(200)
+                                        .expect("Unable to turn 200 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                ReserveComputeNodeResponse::NotFoundErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(404)
+                                        .expect("Unable to turn 404 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                ReserveComputeNodeResponse::DefaultErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(500)
+                                        .expect("Unable to turn 500 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                            }
+                        }
+                        Err(_) => {
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+                    Ok(response)
+                }
+
+                // ReleaseComputeNode - DELETE /compute_nodes/{id}/reservation/{job_id}
+                hyper::Method::DELETE
+                    if path.matched(paths::ID_COMPUTE_NODES_ID_RESERVATION_JOB_ID) =>
+                {
+                    let path: &str = uri.path();
+                    let path_params = paths::REGEX_COMPUTE_NODES_ID_RESERVATION_JOB_ID.captures(path)
+                        .unwrap_or_else(|| panic!("Path {} matched RE COMPUTE_NODES_ID_RESERVATION_JOB_ID in set but failed match against \"{}\"", path, paths::REGEX_COMPUTE_NODES_ID_RESERVATION_JOB_ID.as_str()));
+
+                    let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
+                        Ok(param_id) => match param_id.parse::<i64>() {
+                            Ok(param_id) => param_id,
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse path parameter id: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid path parameter")),
+                        },
+                        Err(_) => return Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["id"])))
+                            .expect("Unable to create Bad Request response for invalid percent decode"))
+                    };
+
+                    let param_job_id = match percent_encoding::percent_decode(path_params["job_id"].as_bytes()).decode_utf8() {
+                        Ok(param_job_id) => match param_job_id.parse::<i64>() {
+                            Ok(param_job_id) => param_job_id,
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse path parameter job_id: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid path parameter")),
+                        },
+                        Err(_) => return Ok(Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["job_id"])))
+                            .expect("Unable to create Bad Request response for invalid percent decode"))
+                    };
+
+                    let result = api_impl
+                        .release_compute_node(param_id, param_job_id, &context)
+                        .await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => {
+                            match rsp {
+                                ReleaseComputeNodeResponse::SuccessfulResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(200)
+                                        .expect("Unable to turn 200 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                ReleaseComputeNodeResponse::NotFoundErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(404)
+                                        .expect("Unable to turn 404 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                                ReleaseComputeNodeResponse::DefaultErrorResponse(body) => {
+                                    *response.status_mut() = StatusCode::from_u16(500)
+                                        .expect("Unable to turn 500 into a StatusCode");
+                                    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_str("application/json").expect("Unable to create Content-Type header for application/json"));
+                                    let body = serde_json::to_string(&body)
+                                        .expect("impossible to fail to serialize");
+                                    *response.body_mut() = Body::from(body);
+                                }
+                            }
+                        }
+                        Err(_) => {
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+                    Ok(response)
+                }
+
+                // ============================================================================
+                // End of Access Groups routes
+                // ============================================================================
+                _ if path.matched(paths::ID_ACCESS_GROUPS) => method_not_allowed(),
+                _ if path.matched(paths::ID_ACCESS_GROUPS_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_ACCESS_GROUPS_ID_MEMBERS) => method_not_allowed(),
+                _ if path.matched(paths::ID_ACCESS_GROUPS_ID_MEMBERS_USER_NAME) => {
+                    method_not_allowed()
+                }
+                _ if path.matched(paths::ID_USERS_USER_NAME_GROUPS) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID_ACCESS_GROUPS) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID_ACCESS_GROUPS_GROUP_ID) => {
+                    method_not_allowed()
+                }
+                _ if path.matched(paths::ID_ACCESS_CHECK_WORKFLOW_ID_USER_NAME) => {
+                    method_not_allowed()
+                }
+                _ if path.matched(paths::ID_FAILURE_HANDLERS) => method_not_allowed(),
+                _ if path.matched(paths::ID_FAILURE_HANDLERS_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID_FAILURE_HANDLERS) => method_not_allowed(),
+                _ if path.matched(paths::ID_API_TOKENS) => method_not_allowed(),
+                _ if path.matched(paths::ID_API_TOKENS_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_COMPUTE_NODES_ID_HEARTBEAT) => method_not_allowed(),
+                _ if path.matched(paths::ID_COMPUTE_NODES_ID_RESERVATION_JOB_ID) => {
+                    method_not_allowed()
+                }
+                _ if path.matched(paths::ID_WORKFLOW_SCHEDULES) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOW_SCHEDULES_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_JOB_LOGS) => method_not_allowed(),
+                _ if path.matched(paths::ID_JOB_LOGS_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_JOB_LOGS_ID_CONTENT) => method_not_allowed(),
+                _ if path.matched(paths::ID_BULK_JOBS) => method_not_allowed(),
+                _ if path.matched(paths::ID_COMPUTE_NODES) => method_not_allowed(),
+                _ if path.matched(paths::ID_COMPUTE_NODES_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_EVENTS) => method_not_allowed(),
+                _ if path.matched(paths::ID_EVENTS_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_FILES) => method_not_allowed(),
+                _ if path.matched(paths::ID_FILES_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_JOBS) => method_not_allowed(),
+                _ if path.matched(paths::ID_JOBS_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_JOBS_ID_COMPLETE_JOB_STATUS_REV_RUN_ID) => {
+                    method_not_allowed()
+                }
+                _ if path.matched(paths::ID_JOBS_ID_MANAGE_STATUS_CHANGE_STATUS_REV_RUN_ID) => {
+                    method_not_allowed()
+                }
+                _ if path.matched(paths::ID_JOBS_ID_START_JOB_REV_RUN_ID_COMPUTE_NODE_ID) => {
+                    method_not_allowed()
+                }
+                _ if path.matched(paths::ID_JOBS_ID_RETRY_RUN_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_LOCAL_SCHEDULERS) => method_not_allowed(),
+                _ if path.matched(paths::ID_LOCAL_SCHEDULERS_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_PING) => method_not_allowed(),
+                _ if path.matched(paths::ID_RESOURCE_REQUIREMENTS) => method_not_allowed(),
+                _ if path.matched(paths::ID_RESOURCE_REQUIREMENTS_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_RESULTS) => method_not_allowed(),
+                _ if path.matched(paths::ID_RESULTS_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_SCHEDULED_COMPUTE_NODES) => method_not_allowed(),
+                _ if path.matched(paths::ID_SCHEDULED_COMPUTE_NODES_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_SLURM_SCHEDULERS) => method_not_allowed(),
+                _ if path.matched(paths::ID_SLURM_SCHEDULERS_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_USER_DATA) => method_not_allowed(),
+                _ if path.matched(paths::ID_USER_DATA_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_VERSION) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID_CANCEL) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID_DOT_GRAPH_NAME) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID_EVENTS_AFTER_TIMESTAMP_TIMESTAMP) => {
+                    method_not_allowed()
+                }
+                _ if path.matched(paths::ID_WORKFLOWS_ID_INITIALIZE_JOBS) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID_IS_COMPLETE) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID_JOB_IDS) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID_LATEST_EVENT_TIMESTAMP) => {
+                    method_not_allowed()
+                }
+                _ if path.matched(paths::ID_WORKFLOWS_ID_MISSING_USER_DATA) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID_PREPARE_JOBS_FOR_SCHEDULING) => {
+                    method_not_allowed()
+                }
+                _ if path.matched(paths::ID_WORKFLOWS_ID_CLAIM_JOBS_BASED_ON_RESOURCES) => {
+                    method_not_allowed()
+                }
+                _ if path.matched(paths::ID_WORKFLOWS_ID_CLAIM_NEXT_JOBS) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID_PROCESS_CHANGED_JOB_INPUTS) => {
+                    method_not_allowed()
+                }
+                _ if path.matched(paths::ID_WORKFLOWS_ID_READY_JOB_REQUIREMENTS) => {
+                    method_not_allowed()
+                }
+                _ if path.matched(paths::ID_WORKFLOWS_ID_REQUIRED_EXISTING_FILES) => {
+                    method_not_allowed()
+                }
+                _ if path.matched(paths::ID_WORKFLOWS_ID_RESET_JOB_STATUS) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID_RESET_STATUS) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID_STATUS) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID_REMOTE_WORKERS) => method_not_allowed(),
+                _ if path.matched(paths::ID_WORKFLOWS_ID_REMOTE_WORKERS_WORKER) => {
+                    method_not_allowed()
+                }
+                _ if path.matched(paths::ID_WORKFLOWS_ID_EVENTS_STREAM) => method_not_allowed(),
+                // Serve dashboard for non-API routes, 404 otherwise
+                _ => {
+                    // Try to serve dashboard assets for non-API paths
+                    if let Some(response) = crate::server::dashboard::serve_dashboard(uri.path()) {
+                        Ok(response)
+                    } else {
+                        Ok(Response::builder()
+                            .status(StatusCode::NOT_FOUND)
+                            .body(Body::empty())
+                            .expect("Unable to create Not Found response"))
+                    }
+                }
+            }
+        }
+        Box::pin(run(self.api_impl.clone(), req))
+    }
+}
+
+/// Request parser for `Api`.
+pub struct ApiRequestParser;
+impl<T> RequestParser<T> for ApiRequestParser {
+    fn parse_operation_id(request: &Request<T>) -> Option<&'static str> {
+        let path = paths::GLOBAL_REGEX_SET.matches(request.uri().path());
+        match *request.method() {
+            // CreateComputeNode - POST /compute_nodes
+            hyper::Method::POST if path.matched(paths::ID_COMPUTE_NODES) => {
                 Some("CreateComputeNode")
             }
             // CreateEvent - POST /events
@@ -16907,6 +18616,58 @@ impl<T> RequestParser<T> for ApiRequestParser {
             hyper::Method::GET if path.matched(paths::ID_WORKFLOWS_ID_EVENTS_STREAM) => {
                 Some("SubscribeToEventsStream")
             }
+            // CreateApiToken - POST /api_tokens
+            hyper::Method::POST if path.matched(paths::ID_API_TOKENS) => Some("CreateApiToken"),
+            // ListApiTokens - GET /api_tokens
+            hyper::Method::GET if path.matched(paths::ID_API_TOKENS) => Some("ListApiTokens"),
+            // RevokeApiToken - DELETE /api_tokens/{id}
+            hyper::Method::DELETE if path.matched(paths::ID_API_TOKENS_ID) => {
+                Some("RevokeApiToken")
+            }
+            // UpdateComputeNodeHeartbeat - PUT /compute_nodes/{id}/heartbeat
+            hyper::Method::PUT if path.matched(paths::ID_COMPUTE_NODES_ID_HEARTBEAT) => {
+                Some("UpdateComputeNodeHeartbeat")
+            }
+            // ReserveComputeNode - PUT /compute_nodes/{id}/reservation/{job_id}
+            hyper::Method::PUT if path.matched(paths::ID_COMPUTE_NODES_ID_RESERVATION_JOB_ID) => {
+                Some("ReserveComputeNode")
+            }
+            // ReleaseComputeNode - DELETE /compute_nodes/{id}/reservation/{job_id}
+            hyper::Method::DELETE
+                if path.matched(paths::ID_COMPUTE_NODES_ID_RESERVATION_JOB_ID) =>
+            {
+                Some("ReleaseComputeNode")
+            }
+            // CreateWorkflowSchedule - POST /workflow_schedules
+            hyper::Method::POST if path.matched(paths::ID_WORKFLOW_SCHEDULES) => {
+                Some("CreateWorkflowSchedule")
+            }
+            // ListWorkflowSchedules - GET /workflow_schedules
+            hyper::Method::GET if path.matched(paths::ID_WORKFLOW_SCHEDULES) => {
+                Some("ListWorkflowSchedules")
+            }
+            // GetWorkflowSchedule - GET /workflow_schedules/{id}
+            hyper::Method::GET if path.matched(paths::ID_WORKFLOW_SCHEDULES_ID) => {
+                Some("GetWorkflowSchedule")
+            }
+            // UpdateWorkflowSchedule - PUT /workflow_schedules/{id}
+            hyper::Method::PUT if path.matched(paths::ID_WORKFLOW_SCHEDULES_ID) => {
+                Some("UpdateWorkflowSchedule")
+            }
+            // DeleteWorkflowSchedule - DELETE /workflow_schedules/{id}
+            hyper::Method::DELETE if path.matched(paths::ID_WORKFLOW_SCHEDULES_ID) => {
+                Some("DeleteWorkflowSchedule")
+            }
+            // CreateJobLog - POST /job_logs
+            hyper::Method::POST if path.matched(paths::ID_JOB_LOGS) => Some("CreateJobLog"),
+            // ListJobLogs - GET /job_logs
+            hyper::Method::GET if path.matched(paths::ID_JOB_LOGS) => Some("ListJobLogs"),
+            // GetJobLog - GET /job_logs/{id}
+            hyper::Method::GET if path.matched(paths::ID_JOB_LOGS_ID) => Some("GetJobLog"),
+            // GetJobLogContent - GET /job_logs/{id}/content
+            hyper::Method::GET if path.matched(paths::ID_JOB_LOGS_ID_CONTENT) => {
+                Some("GetJobLogContent")
+            }
             _ => None,
         }
     }
@@ -1623,7 +1623,7 @@ impl App {
                 return;
             }
 
-            let scheduler_id = node.scheduler_id.to_string();
+            let scheduler_id = node.hpc_job_id();
             let node_name = format!("Slurm Job {}", scheduler_id);
 
             // Use job_id of 0 and custom name since this is for a Slurm job, not a Torc job
@@ -470,7 +470,7 @@ fn draw_jobs_table(f: &mut Frame, area: Rect, app: &mut App) {
 
         // Color the status based on its value
         let status_color = match status_str.as_str() {
-            "Completed" => Color::Green,
+            "Completed" | "Skipped" => Color::Green,
             "Running" => Color::Yellow,
             "Failed" => Color::Red,
             "Canceled" | "Terminated" => Color::Magenta,
@@ -813,7 +813,7 @@ fn draw_scheduled_nodes_table(f: &mut Frame, area: Rect, app: &mut App) {
 
     let rows = app.scheduled_nodes.iter().map(|node| {
         let id = node.id.map(|i| i.to_string()).unwrap_or_default();
-        let scheduler_id = node.scheduler_id.to_string();
+        let scheduler_id = node.hpc_job_id();
         let config_id = node.scheduler_config_id.to_string();
         let scheduler_type = node.scheduler_type.clone();
         let status = node.status.clone();
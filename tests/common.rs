@@ -110,27 +110,62 @@ fn wait_for_server_ready(port: u16, timeout_secs: u64) -> Result<(), String> {
     ))
 }
 
-fn start_process(db_url: &str, db_file: NamedTempFile) -> ServerProcess {
-    let port = find_available_port();
+/// Return the database URL for a new test server.
+///
+/// SQLite builds use the temporary file. Builds with the `postgres` feature create a new
+/// database on the server at `TORC_TEST_POSTGRES_URL` (default `postgres://postgres@localhost`).
+fn test_database_url(db_file: &NamedTempFile) -> String {
+    if cfg!(feature = "postgres") {
+        static DB_COUNT: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+        let base_url = std::env::var("TORC_TEST_POSTGRES_URL")
+            .unwrap_or_else(|_| "postgres://postgres@localhost".to_string());
+        format!(
+            "{}/torc_test_{}_{}",
+            base_url.trim_end_matches('/'),
+            std::process::id(),
+            DB_COUNT.fetch_add(1, std::sync::atomic::Ordering::SeqCst)
+        )
+    } else {
+        format!("sqlite:{}", db_file.path().display())
+    }
+}
+
+/// Create the database and run the migrations for the configured backend.
+fn setup_database(db_url: &str) {
     println!("Setting up database with url: {}", db_url);
-    let status = Command::new("sqlx")
+    let mut command = Command::new("sqlx");
+    command
         .arg("--no-dotenv")
         .arg("database")
         .arg("setup")
-        .env("DATABASE_URL", db_url)
-        .status()
-        .expect("failed to execute sqlx");
+        .env("DATABASE_URL", db_url);
+    if cfg!(feature = "postgres") {
+        command.arg("--source").arg("migrations/postgres");
+    }
+    let status = command.status().expect("failed to execute sqlx");
     if !status.success() {
         panic!("sqlx database setup failed with status: {}", status);
     }
-    let status = Command::new("cargo")
-        .arg("build")
-        .arg("--workspace")
-        .status()
-        .expect("Failed to execute cargo build");
+}
+
+/// Build the workspace binaries, including torc-server with the same database backend as
+/// the tests.
+fn build_workspace() {
+    let mut command = Command::new("cargo");
+    command.arg("build").arg("--workspace");
+    if cfg!(feature = "postgres") {
+        command.arg("--features").arg("torc-server/postgres");
+    }
+    let status = command.status().expect("Failed to execute cargo build");
     if !status.success() {
         panic!("cargo build failed with status: {}", status);
     }
+}
+
+fn start_process(db_url: &str, db_file: NamedTempFile) -> ServerProcess {
+    let port = find_available_port();
+    setup_database(db_url);
+    build_workspace();
 
     // Ensure torc-slurm-job-runner binary is in the PATH for tests
     // The binary is built as part of --workspace but we need to ensure it's accessible
@@ -143,6 +178,10 @@ fn start_process(db_url: &str, db_file: NamedTempFile) -> ServerProcess {
             slurm_runner_path
         );
     }
+    // Scheduled workflow runs are started by the server with the torc CLI
+    let torc_bin_path = std::env::current_dir()
+        .expect("Failed to get current dir")
+        .join(get_exe_path("target/debug/torc"));
     eprintln!("Starting server on port {}", port);
     let child = Command::new(get_exe_path("./target/debug/torc-server"))
         .arg("run")
@@ -150,6 +189,12 @@ fn start_process(db_url: &str, db_file: NamedTempFile) -> ServerProcess {
         .arg(port.to_string())
         .arg("--completion-check-interval-secs")
         .arg("0.1")
+        .arg("--worker-check-interval-secs")
+        .arg("0.5")
+        .arg("--schedule-check-interval-secs")
+        .arg("0.5")
+        .arg("--torc-bin")
+        .arg(&torc_bin_path)
         .env("DATABASE_URL", db_url)
         .env("RUST_LOG", "info")
         .stdout(std::process::Stdio::inherit())
@@ -191,7 +236,7 @@ pub fn start_server() -> ServerProcess {
     let _ = env_logger::try_init();
 
     let db_file = NamedTempFile::new().expect("Failed to create temporary file");
-    let url = format!("sqlite:{}", db_file.path().display());
+    let url = test_database_url(&db_file);
     let process = start_process(&url, db_file);
     eprint!(
         "Started server process with database file {:?} on port {}",
@@ -1449,34 +1494,28 @@ fn start_process_with_access_control(
     htpasswd_file: NamedTempFile,
 ) -> AccessControlServerProcess {
     let port = find_available_port();
-    println!("Setting up database with url: {}", db_url);
-    let status = Command::new("sqlx")
-        .arg("--no-dotenv")
-        .arg("database")
-        .arg("setup")
-        .env("DATABASE_URL", db_url)
-        .status()
-        .expect("failed to execute sqlx");
-    if !status.success() {
-        panic!("sqlx database setup failed with status: {}", status);
-    }
-    let status = Command::new("cargo")
-        .arg("build")
-        .arg("--workspace")
-        .status()
-        .expect("Failed to execute cargo build");
-    if !status.success() {
-        panic!("cargo build failed with status: {}", status);
-    }
+    setup_database(db_url);
+    build_workspace();
 
     eprintln!("Starting server with access control on port {}", port);
     let htpasswd_path = htpasswd_file.path().to_string_lossy().to_string();
+    let torc_bin_path = std::env::current_dir()
+        .expect("Failed to get current dir")
+        .join(get_exe_path("target/debug/torc"));
     let child = Command::new(get_exe_path("./target/debug/torc-server"))
         .arg("run")
         .arg("--port")
         .arg(port.to_string())
         .arg("--completion-check-interval-secs")
         .arg("0.1")
+        .arg("--worker-check-interval-secs")
+        .arg("0.5")
+        .arg("--schedule-check-interval-secs")
+        .arg("0.5")
+        .arg("--api-token-refresh-interval-secs")
+        .arg("0.5")
+        .arg("--torc-bin")
+        .arg(&torc_bin_path)
         .arg("--enforce-access-control") // Enable access control enforcement
         .arg("--auth-file")
         .arg(&htpasswd_path)
@@ -1532,6 +1571,16 @@ fn start_process_with_access_control(
     }
 }
 
+/// Operating system account that test servers run as, which is also the only user whose
+/// workflow schedules fire.
+pub fn server_service_user() -> String {
+    ["USER", "LOGNAME"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|user| !user.is_empty())
+        .expect("USER or LOGNAME must be set")
+}
+
 /// Start a test server instance with access control enforcement enabled
 ///
 /// This fixture creates a server where users can only access workflows they own
@@ -1543,6 +1592,7 @@ fn start_process_with_access_control(
 /// - carol, dave (Data team members)
 /// - shared_user (member of both teams)
 /// - owner, api_owner, ml_owner, data_owner, job_owner, etc. (workflow owners)
+/// - the account that runs the server (see `server_service_user`)
 #[fixture]
 #[once]
 pub fn start_server_with_access_control() -> AccessControlServerProcess {
@@ -1581,12 +1631,18 @@ pub fn start_server_with_access_control() -> AccessControlServerProcess {
         "removable_user",
         "outsider",
     ];
+    // Scheduled runs only fire for the account that runs the server
+    let mut test_users = test_users.to_vec();
+    let service_user = server_service_user();
+    if !test_users.contains(&service_user.as_str()) {
+        test_users.push(&service_user);
+    }
 
     let htpasswd_file = create_htpasswd_file(&test_users);
     eprintln!("Created htpasswd file with {} users", test_users.len());
 
     let db_file = NamedTempFile::new().expect("Failed to create temporary file");
-    let url = format!("sqlite:{}", db_file.path().display());
+    let url = test_database_url(&db_file);
     let process = start_process_with_access_control(&url, db_file, htpasswd_file);
     eprint!(
         "Started server with access control, database file {:?} on port {}",
@@ -1594,3 +1650,75 @@ pub fn start_server_with_access_control() -> AccessControlServerProcess {
     );
     process
 }
+
+/// Server process started in its own working directory, where it loads `torc.toml`
+pub struct ConfigFileServerProcess {
+    pub server: ServerProcess,
+    pub working_dir: tempfile::TempDir,
+}
+
+impl std::ops::Deref for ConfigFileServerProcess {
+    type Target = ServerProcess;
+
+    fn deref(&self) -> &Self::Target {
+        &self.server
+    }
+}
+
+/// Start a dedicated server that loads `config_toml` as its project-local `torc.toml`.
+///
+/// Use this for settings that are only available in configuration files. Unlike the `#[once]`
+/// fixtures, the server is stopped when the returned value is dropped.
+pub fn start_server_with_config_file(config_toml: &str) -> ConfigFileServerProcess {
+    let _ = env_logger::try_init();
+
+    let working_dir = tempfile::tempdir().expect("Failed to create temporary directory");
+    std::fs::write(working_dir.path().join("torc.toml"), config_toml)
+        .expect("Failed to write torc.toml");
+    let db_file = NamedTempFile::new().expect("Failed to create temporary file");
+    let db_url = test_database_url(&db_file);
+    let port = find_available_port();
+    setup_database(&db_url);
+    build_workspace();
+
+    let server_path = std::env::current_dir()
+        .expect("Failed to get current dir")
+        .join(get_exe_path("target/debug/torc-server"));
+    eprintln!("Starting server with config file on port {}", port);
+    let child = Command::new(server_path)
+        .arg("run")
+        .arg("--port")
+        .arg(port.to_string())
+        .arg("--completion-check-interval-secs")
+        .arg("0.1")
+        .current_dir(working_dir.path())
+        .env("DATABASE_URL", &db_url)
+        .env("RUST_LOG", "info")
+        .stdout(std::process::Stdio::inherit())
+        .stderr(std::process::Stdio::inherit())
+        .spawn()
+        .expect("failed to start server process");
+
+    let pid = child.id();
+    track_server_pid(pid);
+
+    if let Err(e) = wait_for_server_ready(port, 10) {
+        panic!("Server startup failed: {}", e);
+    }
+
+    eprintln!(
+        "Server with config file ready on port {} (PID: {})",
+        port, pid
+    );
+    let mut config = Configuration::new();
+    config.base_path = get_server_url(port);
+    ConfigFileServerProcess {
+        server: ServerProcess {
+            child,
+            db_file,
+            port,
+            config,
+        },
+        working_dir,
+    }
+}
@@ -0,0 +1,25 @@
+#!/bin/bash
+
+# Fake qdel command for testing
+# Simulates PBS Pro's qdel command
+
+JOBS_FILE="${TMPDIR:-/tmp}/fake_pbs_jobs.txt"
+
+# Check for failure simulation
+if [ -n "$TORC_FAKE_QDEL_FAIL" ]; then
+    echo "qdel: Unauthorized Request" >&2
+    exit 1
+fi
+
+JOB_ID="${1%%.*}"
+
+if ! grep -q "^${JOB_ID}|" "$JOBS_FILE" 2>/dev/null; then
+    echo "qdel: Unknown Job Id ${JOB_ID}.fakepbs" >&2
+    exit 153
+fi
+
+# Deleted jobs disappear from qstat output
+grep -v "^${JOB_ID}|" "$JOBS_FILE" > "${JOBS_FILE}.tmp"
+mv "${JOBS_FILE}.tmp" "$JOBS_FILE"
+
+exit 0
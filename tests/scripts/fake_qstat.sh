@@ -0,0 +1,94 @@
+#!/bin/bash
+
+# Fake qstat command for testing
+# Simulates PBS Pro's qstat command
+
+JOBS_FILE="${TMPDIR:-/tmp}/fake_pbs_jobs.txt"
+
+# Create empty jobs file if it doesn't exist
+if [ ! -f "$JOBS_FILE" ]; then
+    touch "$JOBS_FILE"
+fi
+
+# Check for failure simulation
+if [ -n "$TORC_FAKE_QSTAT_FAIL" ]; then
+    echo "qstat: cannot connect to server fakepbs (errno=111)" >&2
+    exit 1
+fi
+
+USER_FILTER=""
+FULL=false
+JOB_ID=""
+
+while [[ $# -gt 0 ]]; do
+    case $1 in
+        -u)
+            USER_FILTER="$2"
+            shift 2
+            ;;
+        -f)
+            FULL=true
+            shift
+            ;;
+        -x)
+            shift
+            ;;
+        *)
+            JOB_ID="${1%%.*}"
+            shift
+            ;;
+    esac
+done
+
+# Look up a single job
+if [ -n "$JOB_ID" ]; then
+    LINE=$(grep "^${JOB_ID}|" "$JOBS_FILE" 2>/dev/null | head -1)
+    if [ -z "$LINE" ]; then
+        echo "qstat: Unknown Job Id ${JOB_ID}.fakepbs" >&2
+        exit 153
+    fi
+    IFS='|' read -r job_id name state queue account start <<< "$LINE"
+
+    # Allow environment variable to override job state
+    if [ -n "$TORC_FAKE_QSTAT_STATE" ]; then
+        state="$TORC_FAKE_QSTAT_STATE"
+    fi
+
+    if [ "$FULL" = true ]; then
+        echo "Job Id: ${job_id}.fakepbs"
+        echo "    Job_Name = ${name}"
+        echo "    Job_Owner = ${USER}@fakepbs"
+        echo "    job_state = ${state}"
+        echo "    queue = ${queue}"
+        echo "    Account_Name = ${account}"
+        echo "    exec_host = node001/0*4+node002/0*4"
+        echo "    Resource_List.walltime = 01:00:00"
+        echo "    resources_used.walltime = 00:10:00"
+        echo "    stime = ${start}"
+        if [ "$state" = "F" ]; then
+            echo "    obittime = ${start}"
+        fi
+    else
+        echo "Job id            Name             User              Time Use S Queue"
+        echo "----------------  ---------------- ----------------  -------- - -----"
+        printf "%-17s %-16s %-17s %8s %s %s\n" "${job_id}.fakepbs" "$name" "$USER" "00:00:00" "$state" "$queue"
+    fi
+    exit 0
+fi
+
+# List all jobs in the `qstat -u` format
+echo ""
+echo "fakepbs:"
+echo "                                                            Req'd  Req'd   Elap"
+echo "Job ID          Username Queue    Jobname    SessID NDS TSK Memory Time  S Time"
+echo "--------------- -------- -------- ---------- ------ --- --- ------ ----- - -----"
+while IFS='|' read -r job_id name state queue account start; do
+    [ -z "$job_id" ] && continue
+    if [ -n "$TORC_FAKE_QSTAT_STATE" ]; then
+        state="$TORC_FAKE_QSTAT_STATE"
+    fi
+    printf "%-15s %-8s %-8s %-10s %6s %3s %3s %6s %5s %s %5s\n" \
+        "${job_id}.fakepbs" "${USER_FILTER:-$USER}" "$queue" "$name" "--" "1" "4" "8gb" "01:00" "$state" "--"
+done < "$JOBS_FILE"
+
+exit 0
@@ -0,0 +1,46 @@
+#!/bin/bash
+
+# Fake qsub command for testing
+# Simulates PBS Pro's qsub command
+
+# Read job counter from temp file or initialize to 2000
+JOB_COUNTER_FILE="${TMPDIR:-/tmp}/fake_qsub_counter.txt"
+if [ -f "$JOB_COUNTER_FILE" ]; then
+    JOB_ID=$(cat "$JOB_COUNTER_FILE")
+else
+    JOB_ID=2000
+fi
+
+# Increment for next time
+echo $((JOB_ID + 1)) > "$JOB_COUNTER_FILE"
+
+# Check for failure simulation
+if [ -n "$TORC_FAKE_QSUB_FAIL" ]; then
+    echo "qsub: Job rejected by all possible destinations" >&2
+    exit 1
+fi
+
+# Use the job name and queue from the script's directives, if present
+SCRIPT="$1"
+NAME="test_job"
+QUEUE="workq"
+ACCOUNT="test_account"
+if [ -f "$SCRIPT" ]; then
+    SCRIPT_NAME=$(sed -n 's/^#PBS -N \(.*\)$/\1/p' "$SCRIPT" | head -1)
+    SCRIPT_QUEUE=$(sed -n 's/^#PBS -q \(.*\)$/\1/p' "$SCRIPT" | head -1)
+    SCRIPT_ACCOUNT=$(sed -n 's/^#PBS -A \(.*\)$/\1/p' "$SCRIPT" | head -1)
+    [ -n "$SCRIPT_NAME" ] && NAME="$SCRIPT_NAME"
+    [ -n "$SCRIPT_QUEUE" ] && QUEUE="$SCRIPT_QUEUE"
+    [ -n "$SCRIPT_ACCOUNT" ] && ACCOUNT="$SCRIPT_ACCOUNT"
+fi
+
+# PBS prints the full job ID including the server name
+echo "${JOB_ID}.fakepbs"
+
+# Store job info for qstat to use
+JOBS_FILE="${TMPDIR:-/tmp}/fake_pbs_jobs.txt"
+TIMESTAMP=$(date +"%a %b %e %H:%M:%S %Y")
+# Format: job_id|name|state|queue|account|start_time
+echo "${JOB_ID}|${NAME}|Q|${QUEUE}|${ACCOUNT}|${TIMESTAMP}" >> "$JOBS_FILE"
+
+exit 0
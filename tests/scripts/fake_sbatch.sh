@@ -23,10 +23,50 @@ fi
 # Output the standard sbatch success message
 echo "Submitted batch job $JOB_ID"
 
+# Find a job array range and a dependency from the command line or the script
+ARRAY=""
+DEPENDENCY=""
+for arg in "$@"; do
+    case $arg in
+        --array=*)
+            ARRAY="${arg#--array=}"
+            ;;
+        --dependency=*)
+            DEPENDENCY="${arg#--dependency=}"
+            ;;
+        *)
+            if [ -f "$arg" ]; then
+                SCRIPT_ARRAY=$(sed -n 's/^#SBATCH --array=//p' "$arg" | head -1)
+                if [ -z "$ARRAY" ] && [ -n "$SCRIPT_ARRAY" ]; then
+                    ARRAY="$SCRIPT_ARRAY"
+                fi
+                SCRIPT_DEPENDENCY=$(sed -n 's/^#SBATCH --dependency=//p' "$arg" | head -1)
+                if [ -z "$DEPENDENCY" ] && [ -n "$SCRIPT_DEPENDENCY" ]; then
+                    DEPENDENCY="$SCRIPT_DEPENDENCY"
+                fi
+            fi
+            ;;
+    esac
+done
+
+# Record dependencies so that tests can check them
+# Format: job_id|dependency
+if [ -n "$DEPENDENCY" ]; then
+    echo "${JOB_ID}|${DEPENDENCY}" >> "${TMPDIR:-/tmp}/fake_sbatch_dependencies.txt"
+fi
+
 # Store job info for squeue/sacct to use
 JOBS_FILE="${TMPDIR:-/tmp}/fake_slurm_jobs.txt"
 TIMESTAMP=$(date -u +"%Y-%m-%dT%H:%M:%S")
 # Format: job_id|name|state|start_time|end_time|account|partition|qos
-echo "${JOB_ID}|test_job|PENDING|${TIMESTAMP}|Unknown|test_account|debug|normal" >> "$JOBS_FILE"
+# Job array tasks are stored as <job_id>_<task_id>
+if [ -n "$ARRAY" ]; then
+    # Only ranges like 0-9 are supported
+    for ((task = ${ARRAY%-*}; task <= ${ARRAY#*-}; task++)); do
+        echo "${JOB_ID}_${task}|test_job|PENDING|${TIMESTAMP}|Unknown|test_account|debug|normal" >> "$JOBS_FILE"
+    done
+else
+    echo "${JOB_ID}|test_job|PENDING|${TIMESTAMP}|Unknown|test_account|debug|normal" >> "$JOBS_FILE"
+fi
 
 exit 0
@@ -25,15 +25,16 @@ if [ ! -f "$JOBS_FILE" ]; then
     exit 1
 fi
 
-if ! grep -q "^${JOB_ID}|" "$JOBS_FILE" 2>/dev/null; then
+if ! grep -q "^${JOB_ID}[|_]" "$JOBS_FILE" 2>/dev/null; then
     echo "scancel: error: Invalid job id specified" >&2
     exit 1
 fi
 
 # Update job state to CANCELLED in the jobs file
+# The job ID of an array cancels all of its tasks.
 TEMP_FILE="${JOBS_FILE}.tmp"
 while IFS='|' read -r job_id name state start end account partition qos; do
-    if [ "$job_id" = "$JOB_ID" ]; then
+    if [ "$job_id" = "$JOB_ID" ] || [ "${job_id%_*}" = "$JOB_ID" ]; then
         echo "${job_id}|${name}|CANCELLED|${start}|${end}|${account}|${partition}|${qos}"
     else
         echo "${job_id}|${name}|${state}|${start}|${end}|${account}|${partition}|${qos}"
@@ -99,8 +99,8 @@ while IFS='|' read -r job_id name state start end account partition qos; do
         :
     fi
 
-    # Filter by job ID if specified
-    if [ -n "$JOB_ID" ] && [ "$job_id" != "$JOB_ID" ]; then
+    # Filter by job ID if specified. The job ID of an array selects all of its tasks.
+    if [ -n "$JOB_ID" ] && [ "$job_id" != "$JOB_ID" ] && [ "${job_id%_*}" != "$JOB_ID" ]; then
         continue
     fi
 
@@ -127,6 +127,16 @@ while IFS='|' read -r job_id name state start end account partition qos; do
             jobid)
                 echo -n "$job_id"
                 ;;
+            arrayjobid)
+                echo -n "${job_id%_*}"
+                ;;
+            arraytaskid)
+                if [[ "$job_id" == *_* ]]; then
+                    echo -n "${job_id#*_}"
+                else
+                    echo -n "N/A"
+                fi
+                ;;
             name)
                 # Pad name to 20 characters to match typical squeue output
                 printf "%-20s" "$name"
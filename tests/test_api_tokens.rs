@@ -0,0 +1,143 @@
+mod common;
+
+use common::{
+    AccessControlServerProcess, ServerProcess, start_server, start_server_with_access_control,
+};
+use rstest::rstest;
+use torc::client::{Configuration, default_api};
+use torc::models;
+
+/// Build a client configuration that authenticates with an API token only.
+fn token_config(server: &AccessControlServerProcess, token: &str) -> Configuration {
+    let mut config = Configuration::new();
+    config.base_path = server.config.base_path.clone();
+    config.bearer_access_token = Some(token.to_string());
+    config
+}
+
+fn create_token(
+    config: &Configuration,
+    name: &str,
+    owner: Option<&str>,
+    scopes: Option<Vec<&str>>,
+) -> models::ApiTokenModel {
+    let mut token = models::ApiTokenModel::new(name.to_string());
+    token.owner = owner.map(|o| o.to_string());
+    token.scopes = scopes.map(|s| s.iter().map(|x| x.to_string()).collect());
+    default_api::create_api_token(config, token).expect("Failed to create API token")
+}
+
+#[rstest]
+fn test_create_and_use_api_token(start_server_with_access_control: &AccessControlServerProcess) {
+    let server = start_server_with_access_control;
+    let created = create_token(&server.config, "test-create-and-use", None, None);
+
+    assert!(created.id.is_some());
+    assert_eq!(created.owner.as_deref(), Some("alice"));
+    assert_eq!(
+        created.scopes,
+        Some(vec!["read".to_string(), "write".to_string()])
+    );
+    assert_eq!(created.revoked, Some(false));
+    let token = created.token.expect("Token should be returned on create");
+    assert!(token.starts_with("torc_"));
+
+    // The token authenticates as its owner.
+    let config = token_config(server, &token);
+    let workflow =
+        models::WorkflowModel::new("api_token_workflow".to_string(), "alice".to_string());
+    let workflow = default_api::create_workflow(&config, workflow)
+        .expect("Failed to create workflow with API token");
+    default_api::get_workflow(&config, workflow.id.unwrap())
+        .expect("Failed to get workflow with API token");
+
+    // The plaintext token is never returned again.
+    let tokens = default_api::list_api_tokens(&config, None, None).unwrap();
+    let listed = tokens
+        .items
+        .iter()
+        .find(|t| t.id == created.id)
+        .expect("Created token should be listed");
+    assert!(listed.token.is_none());
+}
+
+#[rstest]
+fn test_read_only_api_token(start_server_with_access_control: &AccessControlServerProcess) {
+    let server = start_server_with_access_control;
+    let created = create_token(
+        &server.config,
+        "test-read-only",
+        Some("bob"),
+        Some(vec!["read"]),
+    );
+    assert_eq!(created.owner.as_deref(), Some("bob"));
+    let config = token_config(server, created.token.as_ref().unwrap());
+
+    default_api::list_workflows(&config, None, None, None, None, None, None, None, None)
+        .expect("Read-only token should allow GET requests");
+
+    let workflow =
+        models::WorkflowModel::new("read_only_token_workflow".to_string(), "bob".to_string());
+    assert!(
+        default_api::create_workflow(&config, workflow).is_err(),
+        "Read-only token should not allow POST requests"
+    );
+}
+
+#[rstest]
+fn test_revoke_api_token(start_server_with_access_control: &AccessControlServerProcess) {
+    let server = start_server_with_access_control;
+    let created = create_token(&server.config, "test-revoke", None, None);
+    let id = created.id.unwrap();
+    let config = token_config(server, created.token.as_ref().unwrap());
+
+    let tokens = default_api::list_api_tokens(&config, None, None).unwrap();
+    assert!(tokens.items.iter().any(|t| t.id == Some(id)));
+
+    let revoked = default_api::revoke_api_token(&server.config, id).unwrap();
+    assert_eq!(revoked.revoked, Some(true));
+
+    // A revoked token no longer authenticates as its owner.
+    let tokens = default_api::list_api_tokens(&config, None, None).unwrap();
+    assert!(tokens.items.is_empty());
+
+    // Reloading tokens from the database does not restore it.
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    let tokens = default_api::list_api_tokens(&config, None, None).unwrap();
+    assert!(tokens.items.is_empty());
+
+    // Revoking an unknown token fails.
+    assert!(default_api::revoke_api_token(&server.config, 999_999).is_err());
+}
+
+#[rstest]
+fn test_create_api_token_validation(start_server_with_access_control: &AccessControlServerProcess) {
+    let server = start_server_with_access_control;
+
+    let mut token = models::ApiTokenModel::new("bad-scope".to_string());
+    token.scopes = Some(vec!["admin".to_string()]);
+    assert!(default_api::create_api_token(&server.config, token).is_err());
+
+    let mut token = models::ApiTokenModel::new("expired".to_string());
+    token.expires_at = Some("2000-01-01 00:00:00".to_string());
+    assert!(default_api::create_api_token(&server.config, token).is_err());
+
+    let mut token = models::ApiTokenModel::new("expires-later".to_string());
+    token.expires_at = Some("2999-01-01 00:00:00".to_string());
+    let created = default_api::create_api_token(&server.config, token).unwrap();
+    assert_eq!(created.expires_at.as_deref(), Some("2999-01-01 00:00:00"));
+}
+
+#[rstest]
+fn test_api_tokens_without_access_control(start_server: &ServerProcess) {
+    let config = &start_server.config;
+
+    // Tokens outlive the access control setting, so anonymous users cannot issue tokens for
+    // other users even when it is not enforced.
+    let mut token = models::ApiTokenModel::new("impersonate".to_string());
+    token.owner = Some("alice".to_string());
+    assert!(default_api::create_api_token(config, token).is_err());
+
+    let tokens = default_api::list_api_tokens(config, None, None).unwrap();
+    assert!(tokens.items.is_empty());
+}
@@ -2,12 +2,15 @@ mod common;
 
 use common::{ServerProcess, create_test_job, create_test_workflow, start_server};
 use rstest::rstest;
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use std::thread;
 use std::time::Duration;
 use tempfile::TempDir;
-use torc::client::async_cli_command::AsyncCliCommand;
+use torc::client::async_cli_command::{
+    AsyncCliCommand, RUNTIME_LIMIT_RETURN_CODE, START_FAILURE_RETURN_CODE,
+};
 use torc::models::{JobModel, JobStatus};
 
 /// Helper to create a temporary output directory for job stdio
@@ -728,3 +731,263 @@ fn test_async_cli_command_command_not_found() {
 
     assert!(async_cmd.is_complete);
 }
+
+/// Poll check_status until the job completes or the timeout expires.
+fn wait_for_check_status(async_cmd: &mut AsyncCliCommand, timeout: Duration) {
+    let start = std::time::Instant::now();
+    while !async_cmd.is_complete && start.elapsed() < timeout {
+        async_cmd.check_status().expect("Failed to check status");
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[rstest]
+fn test_async_cli_command_runtime_limit_not_exceeded() {
+    let job = create_test_job_model(1, 1, "sleep 10");
+    let mut async_cmd = AsyncCliCommand::new(job);
+    async_cmd.set_runtime_limit(Duration::from_secs(60));
+
+    let temp_dir = create_temp_output_dir();
+
+    async_cmd
+        .start(
+            temp_dir.path(),
+            1, // workflow_id
+            1, // run_id
+            1, // attempt_id
+            None,
+            "http://localhost:8080/torc-service/v1",
+        )
+        .expect("Failed to start command");
+
+    let signaled = async_cmd
+        .enforce_runtime_limit(Duration::from_secs(1))
+        .expect("Failed to enforce runtime limit");
+    assert!(!signaled);
+    assert!(!async_cmd.exceeded_runtime_limit());
+    assert!(async_cmd.is_running);
+
+    async_cmd.cancel().expect("Failed to cancel");
+    let _ = async_cmd.wait_for_completion();
+}
+
+#[rstest]
+#[cfg(unix)]
+fn test_async_cli_command_runtime_limit_sigterm() {
+    let mut job = create_test_job_model(1, 1, "sleep 10");
+    job.supports_termination = Some(true);
+    let mut async_cmd = AsyncCliCommand::new(job);
+    async_cmd.set_runtime_limit(Duration::from_millis(100));
+
+    let temp_dir = create_temp_output_dir();
+
+    async_cmd
+        .start(
+            temp_dir.path(),
+            1, // workflow_id
+            1, // run_id
+            1, // attempt_id
+            None,
+            "http://localhost:8080/torc-service/v1",
+        )
+        .expect("Failed to start command");
+    thread::sleep(Duration::from_millis(300));
+
+    let signaled = async_cmd
+        .enforce_runtime_limit(Duration::from_secs(30))
+        .expect("Failed to enforce runtime limit");
+    assert!(signaled);
+    assert!(async_cmd.exceeded_runtime_limit());
+
+    wait_for_check_status(&mut async_cmd, Duration::from_secs(5));
+    assert!(async_cmd.is_complete);
+
+    let result = async_cmd.get_result(1, 1, 1, None);
+    assert_eq!(result.return_code, RUNTIME_LIMIT_RETURN_CODE);
+    assert_eq!(result.status, JobStatus::Failed);
+}
+
+#[rstest]
+#[cfg(unix)]
+fn test_async_cli_command_runtime_limit_sigkill_after_grace_period() {
+    // The job ignores SIGTERM, so only SIGKILL after the grace period stops it.
+    let mut job = create_test_job_model(1, 1, "trap '' TERM; sleep 5");
+    job.supports_termination = Some(true);
+    let mut async_cmd = AsyncCliCommand::new(job);
+    async_cmd.set_runtime_limit(Duration::from_millis(100));
+
+    let temp_dir = create_temp_output_dir();
+
+    async_cmd
+        .start(
+            temp_dir.path(),
+            1, // workflow_id
+            1, // run_id
+            1, // attempt_id
+            None,
+            "http://localhost:8080/torc-service/v1",
+        )
+        .expect("Failed to start command");
+    thread::sleep(Duration::from_millis(300));
+
+    let grace_period = Duration::from_millis(500);
+    assert!(
+        async_cmd
+            .enforce_runtime_limit(grace_period)
+            .expect("Failed to enforce runtime limit")
+    );
+    thread::sleep(Duration::from_millis(200));
+    async_cmd.check_status().expect("Failed to check status");
+    assert!(async_cmd.is_running, "Job should ignore SIGTERM");
+
+    // Still within the grace period: no signal is sent.
+    assert!(
+        !async_cmd
+            .enforce_runtime_limit(grace_period)
+            .expect("Failed to enforce runtime limit")
+    );
+
+    thread::sleep(Duration::from_millis(500));
+    assert!(
+        async_cmd
+            .enforce_runtime_limit(grace_period)
+            .expect("Failed to enforce runtime limit")
+    );
+
+    wait_for_check_status(&mut async_cmd, Duration::from_secs(5));
+    assert!(async_cmd.is_complete);
+
+    let result = async_cmd.get_result(1, 1, 1, None);
+    assert_eq!(result.return_code, RUNTIME_LIMIT_RETURN_CODE);
+    assert_eq!(result.status, JobStatus::Failed);
+}
+
+#[rstest]
+fn test_async_cli_command_runtime_limit_sigkill_without_supports_termination() {
+    let job = create_test_job_model(1, 1, "sleep 10");
+    let mut async_cmd = AsyncCliCommand::new(job);
+    async_cmd.set_runtime_limit(Duration::from_millis(100));
+
+    let temp_dir = create_temp_output_dir();
+
+    async_cmd
+        .start(
+            temp_dir.path(),
+            1, // workflow_id
+            1, // run_id
+            1, // attempt_id
+            None,
+            "http://localhost:8080/torc-service/v1",
+        )
+        .expect("Failed to start command");
+    thread::sleep(Duration::from_millis(300));
+
+    assert!(
+        async_cmd
+            .enforce_runtime_limit(Duration::from_secs(30))
+            .expect("Failed to enforce runtime limit")
+    );
+
+    wait_for_check_status(&mut async_cmd, Duration::from_secs(5));
+    assert!(async_cmd.is_complete);
+
+    let result = async_cmd.get_result(1, 1, 1, None);
+    assert_eq!(result.return_code, RUNTIME_LIMIT_RETURN_CODE);
+    assert_eq!(result.status, JobStatus::Failed);
+}
+
+#[rstest]
+#[cfg(unix)]
+fn test_async_cli_command_job_env_and_working_dir() {
+    let mut job = create_test_job_model(1, 1, "echo \"$GREETING $TORC_JOB_ID\"; pwd");
+    job.env = Some(HashMap::from([
+        ("GREETING".to_string(), "hello".to_string()),
+        // Variables set by torc take precedence over the job's env
+        ("TORC_JOB_ID".to_string(), "999".to_string()),
+    ]));
+    let work_dir = TempDir::new().expect("Failed to create temp directory");
+    let work_dir_path = work_dir.path().canonicalize().unwrap();
+    job.working_dir = Some(work_dir_path.to_string_lossy().to_string());
+    let mut async_cmd = AsyncCliCommand::new(job);
+
+    let temp_dir = create_temp_output_dir();
+
+    async_cmd
+        .start(
+            temp_dir.path(),
+            1, // workflow_id
+            1, // run_id
+            1, // attempt_id
+            None,
+            "http://localhost:8080/torc-service/v1",
+        )
+        .expect("Failed to start command");
+    assert_eq!(async_cmd.wait_for_completion().unwrap(), 0);
+
+    let stdout_path = temp_dir.path().join("job_stdio").join("job_wf1_j1_r1_a1.o");
+    let contents = fs::read_to_string(stdout_path).expect("Failed to read stdout");
+    let lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(lines[0], "hello 1");
+    assert_eq!(Path::new(lines[1]), work_dir_path);
+}
+
+#[rstest]
+#[cfg(unix)]
+fn test_async_cli_command_custom_shell() {
+    let mut job = create_test_job_model(1, 1, "echo $0");
+    job.shell = Some("sh".to_string());
+    let mut async_cmd = AsyncCliCommand::new(job);
+
+    let temp_dir = create_temp_output_dir();
+
+    async_cmd
+        .start(
+            temp_dir.path(),
+            1, // workflow_id
+            1, // run_id
+            1, // attempt_id
+            None,
+            "http://localhost:8080/torc-service/v1",
+        )
+        .expect("Failed to start command");
+    assert_eq!(async_cmd.wait_for_completion().unwrap(), 0);
+
+    let stdout_path = temp_dir.path().join("job_stdio").join("job_wf1_j1_r1_a1.o");
+    let contents = fs::read_to_string(stdout_path).expect("Failed to read stdout");
+    assert_eq!(contents.trim(), "sh");
+}
+
+#[rstest]
+#[case::missing_working_dir(None, Some("/nonexistent/torc/working/dir"))]
+#[case::missing_shell(Some("nonexistent_shell_12345"), None)]
+fn test_async_cli_command_start_failure_fails_job(
+    #[case] shell: Option<&str>,
+    #[case] working_dir: Option<&str>,
+) {
+    let mut job = create_test_job_model(1, 1, "echo hello");
+    job.shell = shell.map(str::to_string);
+    job.working_dir = working_dir.map(str::to_string);
+    let mut async_cmd = AsyncCliCommand::new(job);
+
+    let temp_dir = create_temp_output_dir();
+
+    async_cmd
+        .start(
+            temp_dir.path(),
+            1, // workflow_id
+            1, // run_id
+            1, // attempt_id
+            None,
+            "http://localhost:8080/torc-service/v1",
+        )
+        .expect("A job that cannot start should be recorded as failed");
+    assert!(async_cmd.is_complete);
+
+    let result = async_cmd.get_result(1, 1, 1, None);
+    assert_eq!(result.return_code, START_FAILURE_RETURN_CODE);
+    assert_eq!(result.status, JobStatus::Failed);
+
+    let stderr_path = temp_dir.path().join("job_stdio").join("job_wf1_j1_r1_a1.e");
+    let contents = fs::read_to_string(stderr_path).expect("Failed to read stderr");
+    assert!(contents.starts_with("torc: "));
+}
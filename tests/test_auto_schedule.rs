@@ -300,6 +300,7 @@ fn test_create_scheduled_compute_node(start_server: &ServerProcess) {
         scheduler_config_id,
         scheduler_type: "slurm".to_string(),
         status: "pending".to_string(),
+        array_task_id: None,
     };
 
     let created_scn = default_api::create_scheduled_compute_node(config, scn)
@@ -216,6 +216,93 @@ fn test_prepare_jobs_multi_node_resources(start_server: &ServerProcess) {
     );
 }
 
+/// A compute node reserved for a multi-node job does not claim jobs until the job leaves the
+/// pending and running states.
+#[rstest]
+fn test_prepare_jobs_reserved_compute_node(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let jobs = create_multi_node_workflow(config, true);
+    let job = jobs.values().next().expect("Should have at least one job");
+    let workflow_id = job.workflow_id;
+    let resources = models::ComputeNodesResources::new(16, 64.0, 0, 4);
+
+    let claimed =
+        default_api::claim_jobs_based_on_resources(config, workflow_id, &resources, 1, None, None)
+            .expect("claim_jobs_based_on_resources should succeed")
+            .jobs
+            .expect("Server must return jobs array");
+    assert_eq!(claimed.len(), 1);
+    let multi_node_job_id = claimed[0].id.unwrap();
+
+    let compute_node = models::ComputeNodeModel::new(
+        workflow_id,
+        "node2".to_string(),
+        1234,
+        "2026-01-29T00:00:00Z".to_string(),
+        16,
+        64.0,
+        0,
+        1,
+        "slurm".to_string(),
+        None,
+    );
+    let compute_node = default_api::create_compute_node(config, compute_node).unwrap();
+    let compute_node_id = compute_node.id.unwrap();
+    let reserved =
+        default_api::reserve_compute_node(config, compute_node_id, multi_node_job_id).unwrap();
+    assert_eq!(reserved.reserved_for_job_id, Some(multi_node_job_id));
+    assert_eq!(reserved.hostname, "node2");
+
+    // Another job can neither take over nor release the reservation.
+    let other_job_id = jobs
+        .values()
+        .filter_map(|job| job.id)
+        .find(|id| *id != multi_node_job_id)
+        .expect("Should have another job");
+    let node = default_api::reserve_compute_node(config, compute_node_id, other_job_id).unwrap();
+    assert_eq!(node.reserved_for_job_id, Some(multi_node_job_id));
+    let node = default_api::release_compute_node(config, compute_node_id, other_job_id).unwrap();
+    assert_eq!(node.reserved_for_job_id, Some(multi_node_job_id));
+
+    // The job itself can release and reserve the node again.
+    let node =
+        default_api::release_compute_node(config, compute_node_id, multi_node_job_id).unwrap();
+    assert_eq!(node.reserved_for_job_id, None);
+    let node =
+        default_api::reserve_compute_node(config, compute_node_id, multi_node_job_id).unwrap();
+    assert_eq!(node.reserved_for_job_id, Some(multi_node_job_id));
+
+    let mut node_resources = models::ComputeNodesResources::new(16, 64.0, 0, 4);
+    node_resources.id = Some(compute_node_id);
+    let result = default_api::claim_jobs_based_on_resources(
+        config,
+        workflow_id,
+        &node_resources,
+        10,
+        None,
+        None,
+    )
+    .expect("claim_jobs_based_on_resources should succeed");
+    assert!(result.jobs.unwrap_or_default().is_empty());
+    assert!(result.reason.unwrap_or_default().contains("reserved"));
+
+    // Returning the job to the ready queue releases the reservation.
+    default_api::manage_status_change(config, multi_node_job_id, models::JobStatus::Ready, 0, None)
+        .unwrap();
+    let node = default_api::get_compute_node(config, compute_node_id).unwrap();
+    assert_eq!(node.reserved_for_job_id, None);
+    let result = default_api::claim_jobs_based_on_resources(
+        config,
+        workflow_id,
+        &node_resources,
+        10,
+        None,
+        None,
+    )
+    .expect("claim_jobs_based_on_resources should succeed");
+    assert_eq!(result.jobs.unwrap_or_default().len(), 1);
+}
+
 /// Test claim_jobs_based_on_resources with maximum resource constraint
 /// Workflow: 2 jobs, each needing 128 CPUs, 1024.0 GB memory, 8 GPUs, 8 nodes
 /// Resources: 128 CPUs, 1024.0 GB memory, 8 GPUs, 8 nodes total → Exactly matches 1 job's needs
@@ -1653,6 +1740,44 @@ fn test_prepare_jobs_different_sort_methods_different_orders(start_server: &Serv
     }
 }
 
+/// Test ClaimJobsSortMethod::Priority sorting behavior
+/// Jobs with identical resource requirements should be returned by priority (desc)
+#[rstest]
+fn test_prepare_jobs_sort_priority(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let jobs = create_many_jobs_workflow(config, false, 6);
+    let workflow_id = jobs.values().next().unwrap().workflow_id;
+
+    // job_1 -> 10, job_2 -> 20, job_3 -> 0, job_4 -> 10, ...
+    for (name, job) in &jobs {
+        let index: i64 = name.trim_start_matches("job_").parse().unwrap();
+        let mut updated = job.clone();
+        updated.priority = Some((index % 3) * 10);
+        default_api::update_job(config, job.id.unwrap(), updated).expect("Failed to update job");
+    }
+    default_api::initialize_jobs(config, workflow_id, None, None, None)
+        .expect("Failed to initialize jobs");
+
+    let resources = models::ComputeNodesResources::new(100, 500.0, 20, 1);
+    let result = default_api::claim_jobs_based_on_resources(
+        config,
+        workflow_id,
+        &resources,
+        10,
+        Some(models::ClaimJobsSortMethod::Priority),
+        None,
+    )
+    .expect("claim_jobs_based_on_resources should succeed");
+
+    let returned_jobs = result.jobs.expect("Server must return jobs array");
+    assert_eq!(returned_jobs.len(), 6);
+    let priorities: Vec<i64> = returned_jobs
+        .iter()
+        .map(|job| job.priority.expect("Job priority should be present"))
+        .collect();
+    assert_eq!(priorities, vec![20, 20, 10, 10, 0, 0]);
+}
+
 /// Test concurrent job allocation to verify database locking and mutual exclusion
 /// This test simulates multiple clients requesting jobs simultaneously and verifies:
 /// 1. Each job is allocated to exactly one client (no double allocation)
@@ -430,6 +430,48 @@ fn test_prepare_next_jobs_ignores_resources(start_server: &ServerProcess) {
     }
 }
 
+/// Test claim_next_jobs returns the highest-priority jobs first when the workflow
+/// uses the priority sort method
+#[rstest]
+fn test_prepare_next_jobs_priority_sort_method(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let mut workflow =
+        models::WorkflowModel::new("next_jobs_priority".to_string(), "test_user".to_string());
+    workflow.jobs_sort_method = Some(models::ClaimJobsSortMethod::Priority);
+    let workflow_id = default_api::create_workflow(config, workflow)
+        .expect("Failed to create workflow")
+        .id
+        .unwrap();
+
+    for (name, priority) in [("low", 1), ("high", 100), ("default", 0), ("medium", 50)] {
+        let mut job = models::JobModel::new(workflow_id, name.to_string(), "echo".to_string());
+        job.priority = Some(priority);
+        default_api::create_job(config, job).expect("Failed to create job");
+    }
+    default_api::initialize_jobs(config, workflow_id, None, None, None)
+        .expect("Failed to initialize jobs");
+
+    let result = default_api::claim_next_jobs(config, workflow_id, Some(2), None)
+        .expect("claim_next_jobs should succeed");
+    let names: Vec<String> = result
+        .jobs
+        .expect("Server must return jobs array")
+        .into_iter()
+        .map(|job| job.name)
+        .collect();
+    assert_eq!(names, vec!["high", "medium"]);
+
+    let result = default_api::claim_next_jobs(config, workflow_id, Some(10), None)
+        .expect("claim_next_jobs should succeed");
+    let names: Vec<String> = result
+        .jobs
+        .expect("Server must return jobs array")
+        .into_iter()
+        .map(|job| job.name)
+        .collect();
+    assert_eq!(names, vec!["low", "default"]);
+}
+
 /// Test concurrent job allocation from multiple threads to verify database locking
 /// This test simulates multiple clients requesting jobs simultaneously and verifies:
 /// 1. Each job is allocated to exactly one thread (no double allocation)
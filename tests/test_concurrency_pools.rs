@@ -0,0 +1,275 @@
+mod common;
+
+use common::{ServerProcess, create_test_compute_node, run_jobs_cli_command, start_server};
+use rstest::rstest;
+use std::collections::HashMap;
+use std::fs;
+use torc::client::default_api;
+use torc::models::{self, JobStatus};
+
+/// Create a workflow with concurrency limits and initialize its jobs.
+/// `jobs` holds (job name, concurrency pool). Returns (workflow_id, run_id).
+fn create_limited_workflow(
+    config: &torc::client::Configuration,
+    name: &str,
+    max_concurrent_jobs: Option<i64>,
+    pools: &[(&str, i64)],
+    jobs: &[(&str, Option<&str>)],
+) -> (i64, i64) {
+    let mut workflow = models::WorkflowModel::new(name.to_string(), "test_user".to_string());
+    workflow.max_concurrent_jobs = max_concurrent_jobs;
+    if !pools.is_empty() {
+        workflow.concurrency_pools = Some(
+            pools
+                .iter()
+                .map(|(pool, max)| (pool.to_string(), *max))
+                .collect(),
+        );
+    }
+    let workflow_id = default_api::create_workflow(config, workflow)
+        .expect("Failed to create workflow")
+        .id
+        .unwrap();
+
+    for (job_name, pool) in jobs {
+        let mut job =
+            models::JobModel::new(workflow_id, job_name.to_string(), "echo hello".to_string());
+        job.concurrency_pool = pool.map(|p| p.to_string());
+        default_api::create_job(config, job).expect("Failed to create job");
+    }
+    default_api::initialize_jobs(config, workflow_id, None, None, None)
+        .expect("Failed to initialize jobs");
+    let run_id = default_api::get_workflow_status(config, workflow_id)
+        .expect("Failed to get workflow status")
+        .run_id;
+    (workflow_id, run_id)
+}
+
+fn claim_next(config: &torc::client::Configuration, workflow_id: i64) -> Vec<models::JobModel> {
+    default_api::claim_next_jobs(config, workflow_id, Some(100), None)
+        .expect("Failed to claim jobs")
+        .jobs
+        .unwrap_or_default()
+}
+
+fn claim_by_resources(
+    config: &torc::client::Configuration,
+    workflow_id: i64,
+) -> Vec<models::JobModel> {
+    let resources = models::ComputeNodesResources::new(64, 256.0, 0, 1);
+    default_api::claim_jobs_based_on_resources(config, workflow_id, &resources, 100, None, None)
+        .expect("Failed to claim jobs")
+        .jobs
+        .unwrap_or_default()
+}
+
+fn complete_job(
+    config: &torc::client::Configuration,
+    job: &models::JobModel,
+    run_id: i64,
+    compute_node_id: i64,
+) {
+    let job_id = job.id.unwrap();
+    let result = models::ResultModel::new(
+        job_id,
+        job.workflow_id,
+        run_id,
+        1,
+        compute_node_id,
+        0,
+        0.1,
+        chrono::Utc::now().to_rfc3339(),
+        JobStatus::Completed,
+    );
+    default_api::complete_job(config, job_id, result.status, run_id, result)
+        .expect("Failed to complete job");
+}
+
+fn count_in_pool(jobs: &[models::JobModel], pool: Option<&str>) -> usize {
+    jobs.iter()
+        .filter(|job| job.concurrency_pool.as_deref() == pool)
+        .count()
+}
+
+#[rstest]
+fn test_concurrency_pool_limits_claim_next_jobs(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let (workflow_id, run_id) = create_limited_workflow(
+        config,
+        "test_concurrency_pool_limits_claim_next_jobs",
+        None,
+        &[("license", 2)],
+        &[
+            ("licensed_1", Some("license")),
+            ("licensed_2", Some("license")),
+            ("licensed_3", Some("license")),
+            ("licensed_4", Some("license")),
+            ("free_1", None),
+            ("free_2", None),
+        ],
+    );
+    let compute_node_id = create_test_compute_node(config, workflow_id).id.unwrap();
+
+    let workflow = default_api::get_workflow(config, workflow_id).expect("Failed to get workflow");
+    assert_eq!(
+        workflow.concurrency_pools,
+        Some(HashMap::from([("license".to_string(), 2)]))
+    );
+
+    let claimed = claim_next(config, workflow_id);
+    assert_eq!(claimed.len(), 4);
+    assert_eq!(count_in_pool(&claimed, Some("license")), 2);
+    assert_eq!(count_in_pool(&claimed, None), 2);
+
+    // The pool is full, so a second worker gets nothing.
+    assert!(claim_next(config, workflow_id).is_empty());
+
+    // Finishing a job in the pool frees one slot.
+    let licensed: Vec<_> = claimed
+        .iter()
+        .filter(|job| job.concurrency_pool.is_some())
+        .collect();
+    complete_job(config, licensed[0], run_id, compute_node_id);
+    let claimed = claim_next(config, workflow_id);
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].concurrency_pool.as_deref(), Some("license"));
+    assert!(claim_next(config, workflow_id).is_empty());
+}
+
+#[rstest]
+fn test_concurrency_pool_limits_claim_jobs_based_on_resources(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let (workflow_id, run_id) = create_limited_workflow(
+        config,
+        "test_concurrency_pool_limits_claim_jobs_based_on_resources",
+        None,
+        &[("db", 1), ("license", 2)],
+        &[
+            ("db_1", Some("db")),
+            ("db_2", Some("db")),
+            ("licensed_1", Some("license")),
+            ("licensed_2", Some("license")),
+            ("licensed_3", Some("license")),
+        ],
+    );
+    let compute_node_id = create_test_compute_node(config, workflow_id).id.unwrap();
+
+    let claimed = claim_by_resources(config, workflow_id);
+    assert_eq!(claimed.len(), 3);
+    assert_eq!(count_in_pool(&claimed, Some("db")), 1);
+    assert_eq!(count_in_pool(&claimed, Some("license")), 2);
+    assert!(claim_by_resources(config, workflow_id).is_empty());
+
+    let db_job = claimed
+        .iter()
+        .find(|job| job.concurrency_pool.as_deref() == Some("db"))
+        .unwrap();
+    complete_job(config, db_job, run_id, compute_node_id);
+    let claimed = claim_by_resources(config, workflow_id);
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].concurrency_pool.as_deref(), Some("db"));
+}
+
+#[rstest]
+fn test_workflow_max_concurrent_jobs(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let (workflow_id, run_id) = create_limited_workflow(
+        config,
+        "test_workflow_max_concurrent_jobs",
+        Some(3),
+        &[("license", 2)],
+        &[
+            ("licensed_1", Some("license")),
+            ("licensed_2", Some("license")),
+            ("licensed_3", Some("license")),
+            ("free_1", None),
+            ("free_2", None),
+            ("free_3", None),
+        ],
+    );
+    let compute_node_id = create_test_compute_node(config, workflow_id).id.unwrap();
+
+    let claimed = claim_next(config, workflow_id);
+    assert_eq!(claimed.len(), 3);
+    assert!(count_in_pool(&claimed, Some("license")) <= 2);
+    assert!(claim_next(config, workflow_id).is_empty());
+
+    let result = default_api::claim_jobs_based_on_resources(
+        config,
+        workflow_id,
+        &models::ComputeNodesResources::new(64, 256.0, 0, 1),
+        100,
+        None,
+        None,
+    )
+    .expect("Failed to claim jobs");
+    assert!(result.jobs.unwrap_or_default().is_empty());
+    assert!(result.reason.is_some());
+
+    complete_job(config, &claimed[0], run_id, compute_node_id);
+    complete_job(config, &claimed[1], run_id, compute_node_id);
+    assert_eq!(claim_by_resources(config, workflow_id).len(), 2);
+}
+
+/// Jobs in a pool with a limit of 1 run one at a time even when a job runner has room for more.
+#[rstest]
+fn test_concurrency_pool_from_spec(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let work_dir = temp_dir.path();
+    let workflow_name = "test_concurrency_pool_from_spec";
+    let yaml_content = format!(
+        r#"name: {workflow_name}
+user: test_user
+concurrency_pools:
+  - name: serial
+    max_concurrent_jobs: 1
+
+jobs:
+  - name: job_{{i}}
+    command: "echo start >> {dir}/log.txt && sleep 0.5 && echo end >> {dir}/log.txt"
+    concurrency_pool: serial
+    parameters:
+      i: "1:3"
+"#,
+        dir = work_dir.display()
+    );
+    let yaml_path = work_dir.join("pools.yaml");
+    fs::write(&yaml_path, yaml_content).expect("Failed to write YAML file");
+
+    run_jobs_cli_command(
+        &[
+            yaml_path.to_str().unwrap(),
+            "--output-dir",
+            work_dir.to_str().unwrap(),
+            "--poll-interval",
+            "0.1",
+            "--max-parallel-jobs",
+            "3",
+        ],
+        start_server,
+    )
+    .expect("Failed to run jobs");
+
+    let log = fs::read_to_string(work_dir.join("log.txt")).expect("Failed to read log");
+    let lines: Vec<&str> = log.lines().collect();
+    assert_eq!(lines, ["start", "end", "start", "end", "start", "end"]);
+
+    let workflows = default_api::list_workflows(
+        config,
+        None,
+        None,
+        None,
+        None,
+        Some(workflow_name),
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list workflows");
+    let workflow = workflows.items.unwrap().remove(0);
+    assert_eq!(
+        workflow.concurrency_pools,
+        Some(HashMap::from([("serial".to_string(), 1)]))
+    );
+}
@@ -0,0 +1,352 @@
+mod common;
+
+use common::{ServerProcess, run_jobs_cli_command, start_server};
+use rstest::rstest;
+use std::fs;
+use std::thread;
+use std::time::{Duration, Instant};
+use torc::client::default_api;
+use torc::models;
+
+/// Return code that the server records for jobs of a dead compute node.
+const DEAD_WORKER_RETURN_CODE: i64 = -128;
+
+/// Create a workflow with a short heartbeat timeout.
+fn create_workflow(
+    config: &torc::client::Configuration,
+    name: &str,
+    timeout_seconds: i64,
+    policy: models::DeadWorkerPolicy,
+) -> i64 {
+    let mut workflow = models::WorkflowModel::new(name.to_string(), "test_user".to_string());
+    workflow.worker_heartbeat_timeout_seconds = Some(timeout_seconds);
+    workflow.dead_worker_policy = Some(policy);
+    default_api::create_workflow(config, workflow)
+        .expect("Failed to create workflow")
+        .id
+        .unwrap()
+}
+
+/// Create an active compute node, as a job runner does when it starts.
+fn create_active_compute_node(config: &torc::client::Configuration, workflow_id: i64) -> i64 {
+    let mut compute_node = models::ComputeNodeModel::new(
+        workflow_id,
+        "test-host".to_string(),
+        std::process::id() as i64,
+        chrono::Utc::now().to_rfc3339(),
+        8,
+        16.0,
+        0,
+        1,
+        "local".to_string(),
+        None,
+    );
+    compute_node.is_active = Some(true);
+    default_api::create_compute_node(config, compute_node)
+        .expect("Failed to create compute node")
+        .id
+        .unwrap()
+}
+
+/// Create a job, initialize the workflow, and start the job on a new compute node.
+/// Returns (job_id, compute_node_id).
+fn start_job_on_new_compute_node(
+    config: &torc::client::Configuration,
+    workflow_id: i64,
+) -> (i64, i64) {
+    let job = models::JobModel::new(workflow_id, "work".to_string(), "sleep 60".to_string());
+    let job_id = default_api::create_job(config, job)
+        .expect("Failed to create job")
+        .id
+        .unwrap();
+    default_api::initialize_jobs(config, workflow_id, None, None, None)
+        .expect("Failed to initialize jobs");
+    let run_id = default_api::get_workflow_status(config, workflow_id)
+        .expect("Failed to get workflow status")
+        .run_id;
+    let compute_node_id = create_active_compute_node(config, workflow_id);
+    default_api::claim_next_jobs(config, workflow_id, Some(1), None).expect("Failed to claim job");
+    default_api::start_job(config, job_id, run_id, compute_node_id, None)
+        .expect("Failed to start job");
+    (job_id, compute_node_id)
+}
+
+fn wait_for_job_status(
+    config: &torc::client::Configuration,
+    job_id: i64,
+    status: models::JobStatus,
+) -> bool {
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(15) {
+        let job = default_api::get_job(config, job_id).expect("Failed to get job");
+        if job.status == Some(status) {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(200));
+    }
+    false
+}
+
+#[rstest]
+fn test_heartbeat_sets_last_heartbeat(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let workflow_id = create_workflow(
+        config,
+        "test_heartbeat_sets_last_heartbeat",
+        300,
+        models::DeadWorkerPolicy::Fail,
+    );
+    let compute_node_id = create_active_compute_node(config, workflow_id);
+    let compute_node =
+        default_api::get_compute_node(config, compute_node_id).expect("Failed to get node");
+    assert!(compute_node.last_heartbeat.is_none());
+
+    let updated = default_api::update_compute_node_heartbeat(config, compute_node_id)
+        .expect("Failed to send heartbeat");
+    assert!(updated.last_heartbeat.is_some());
+    assert_eq!(updated.is_active, Some(true));
+
+    let fetched =
+        default_api::get_compute_node(config, compute_node_id).expect("Failed to get node");
+    assert_eq!(fetched.last_heartbeat, updated.last_heartbeat);
+
+    assert!(default_api::update_compute_node_heartbeat(config, 999_999_999).is_err());
+}
+
+#[rstest]
+fn test_dead_worker_policy_fail(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let workflow_id = create_workflow(
+        config,
+        "test_dead_worker_policy_fail",
+        1,
+        models::DeadWorkerPolicy::Fail,
+    );
+    let (job_id, compute_node_id) = start_job_on_new_compute_node(config, workflow_id);
+    // A compute node that never sends a heartbeat is not checked.
+    let silent_node_id = create_active_compute_node(config, workflow_id);
+    default_api::update_compute_node_heartbeat(config, compute_node_id)
+        .expect("Failed to send heartbeat");
+
+    assert!(wait_for_job_status(
+        config,
+        job_id,
+        models::JobStatus::Failed
+    ));
+
+    let results = default_api::list_results(
+        config,
+        workflow_id,
+        Some(job_id),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list results");
+    let results = results.items.unwrap_or_default();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].return_code, DEAD_WORKER_RETURN_CODE);
+    assert_eq!(results[0].compute_node_id, compute_node_id);
+
+    let compute_node =
+        default_api::get_compute_node(config, compute_node_id).expect("Failed to get node");
+    assert_eq!(compute_node.is_active, Some(false));
+    let silent_node =
+        default_api::get_compute_node(config, silent_node_id).expect("Failed to get node");
+    assert_eq!(silent_node.is_active, Some(true));
+
+    // A job runner that reconnects learns that it was declared dead.
+    let response = default_api::update_compute_node_heartbeat(config, compute_node_id)
+        .expect("Failed to send heartbeat");
+    assert_eq!(response.is_active, Some(false));
+
+    let events = default_api::list_events(config, workflow_id, None, None, None, None, None, None)
+        .expect("Failed to list events");
+    assert!(events.items.unwrap_or_default().iter().any(|e| {
+        e.data.get("event_type").and_then(|v| v.as_str()) == Some("compute_node_unresponsive")
+    }));
+}
+
+#[rstest]
+fn test_dead_worker_policy_retry(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let workflow_id = create_workflow(
+        config,
+        "test_dead_worker_policy_retry",
+        1,
+        models::DeadWorkerPolicy::Retry,
+    );
+    let (job_id, compute_node_id) = start_job_on_new_compute_node(config, workflow_id);
+    default_api::update_compute_node_heartbeat(config, compute_node_id)
+        .expect("Failed to send heartbeat");
+
+    assert!(wait_for_job_status(
+        config,
+        job_id,
+        models::JobStatus::Ready
+    ));
+
+    let job = default_api::get_job(config, job_id).expect("Failed to get job");
+    assert_eq!(job.attempt_id, Some(2));
+    let list_job_results = || {
+        default_api::list_results(
+            config,
+            workflow_id,
+            Some(job_id),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .expect("Failed to list results")
+        .items
+        .unwrap_or_default()
+    };
+    assert!(list_job_results().is_empty());
+
+    let compute_node =
+        default_api::get_compute_node(config, compute_node_id).expect("Failed to get node");
+    assert_eq!(compute_node.is_active, Some(false));
+
+    // The next attempt is retried as well, and the one after it reaches the default limit of 3.
+    for expected_status in [models::JobStatus::Ready, models::JobStatus::Failed] {
+        let run_id = default_api::get_workflow_status(config, workflow_id)
+            .expect("Failed to get workflow status")
+            .run_id;
+        let compute_node_id = create_active_compute_node(config, workflow_id);
+        default_api::claim_next_jobs(config, workflow_id, Some(1), None)
+            .expect("Failed to claim job");
+        default_api::start_job(config, job_id, run_id, compute_node_id, None)
+            .expect("Failed to start job");
+        default_api::update_compute_node_heartbeat(config, compute_node_id)
+            .expect("Failed to send heartbeat");
+        assert!(wait_for_job_status(config, job_id, expected_status));
+    }
+
+    let job = default_api::get_job(config, job_id).expect("Failed to get job");
+    assert_eq!(job.attempt_id, Some(3));
+    let results = list_job_results();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].return_code, DEAD_WORKER_RETURN_CODE);
+    assert_eq!(results[0].attempt_id, Some(3));
+}
+
+#[rstest]
+fn test_dead_worker_detection_disabled(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let workflow_id = create_workflow(
+        config,
+        "test_dead_worker_detection_disabled",
+        0,
+        models::DeadWorkerPolicy::Fail,
+    );
+    let (job_id, compute_node_id) = start_job_on_new_compute_node(config, workflow_id);
+    default_api::update_compute_node_heartbeat(config, compute_node_id)
+        .expect("Failed to send heartbeat");
+
+    thread::sleep(Duration::from_secs(3));
+
+    let job = default_api::get_job(config, job_id).expect("Failed to get job");
+    assert_eq!(job.status, Some(models::JobStatus::Running));
+    let compute_node =
+        default_api::get_compute_node(config, compute_node_id).expect("Failed to get node");
+    assert_eq!(compute_node.is_active, Some(true));
+}
+
+/// Job runners send heartbeats often enough that jobs longer than the timeout complete.
+#[rstest]
+fn test_job_runner_sends_heartbeats(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let work_dir = temp_dir.path();
+    let workflow_name = "test_job_runner_sends_heartbeats";
+    let yaml_content = format!(
+        r#"name: {workflow_name}
+user: test_user
+worker_heartbeat_timeout_seconds: 4
+dead_worker_policy: retry
+
+jobs:
+  - name: long
+    command: sleep 6
+"#
+    );
+    let yaml_path = work_dir.join("heartbeats.yaml");
+    fs::write(&yaml_path, yaml_content).expect("Failed to write YAML file");
+
+    run_jobs_cli_command(
+        &[
+            yaml_path.to_str().unwrap(),
+            "--output-dir",
+            work_dir.to_str().unwrap(),
+            "--poll-interval",
+            "0.1",
+        ],
+        start_server,
+    )
+    .expect("Failed to run jobs");
+
+    let workflows = default_api::list_workflows(
+        config,
+        None,
+        None,
+        None,
+        None,
+        Some(workflow_name),
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list workflows");
+    let workflow = workflows.items.unwrap().remove(0);
+    assert_eq!(workflow.worker_heartbeat_timeout_seconds, Some(4));
+    assert_eq!(
+        workflow.dead_worker_policy,
+        Some(models::DeadWorkerPolicy::Retry)
+    );
+    let workflow_id = workflow.id.unwrap();
+
+    let jobs = default_api::list_jobs(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list jobs");
+    let job = &jobs.items.unwrap()[0];
+    assert_eq!(job.status, Some(models::JobStatus::Completed));
+
+    let compute_nodes = default_api::list_compute_nodes(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list compute nodes");
+    let compute_nodes = compute_nodes.items.unwrap();
+    assert!(!compute_nodes.is_empty());
+    assert!(compute_nodes.iter().all(|n| n.last_heartbeat.is_some()));
+}
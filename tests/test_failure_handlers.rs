@@ -0,0 +1,290 @@
+mod common;
+
+use common::{ServerProcess, run_jobs_cli_command, start_server};
+use rstest::rstest;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use torc::client::apis::default_api;
+use torc::client::job_runner::{FailureHandlerRule, find_matching_failure_rule};
+use torc::client::workflow_spec::WorkflowSpec;
+use torc::models::{JobModel, JobStatus};
+
+fn create_failure_handler_spec(work_dir: &Path, workflow_name: &str) -> String {
+    let yaml_content = format!(
+        r#"name: {workflow_name}
+user: test_user
+description: Test failure handler log patterns and retry adjustments
+
+resource_requirements:
+  - name: small
+    num_cpus: 1
+    memory: 100m
+    runtime: PT10M
+
+failure_handlers:
+  - name: escalate
+    rules:
+      - exit_codes: [1]
+        stderr_regexes:
+          - "CUDA out of memory"
+        memory_multiplier: 2
+        runtime_multiplier: 1.5
+        max_retries: 3
+      - stdout_regexes:
+          - "license server .* busy"
+        max_retries: 2
+
+jobs:
+  - name: oom_job
+    command: bash -c 'if [ "$TORC_ATTEMPT_ID" -lt 3 ]; then echo "CUDA out of memory" >&2; exit 1; fi'
+    resource_requirements: small
+    failure_handler: escalate
+
+  - name: license_job
+    command: bash -c 'if [ "$TORC_ATTEMPT_ID" = 1 ]; then echo "license server flexlm busy"; exit 7; fi'
+    resource_requirements: small
+    failure_handler: escalate
+
+  - name: unmatched_job
+    command: bash -c 'echo "segmentation fault" >&2; exit 1'
+    resource_requirements: small
+    failure_handler: escalate
+"#
+    );
+    let yaml_path = work_dir.join(format!("{}.yaml", workflow_name));
+    fs::write(&yaml_path, yaml_content).expect("Failed to write YAML file");
+    yaml_path.to_str().unwrap().to_string()
+}
+
+fn list_jobs_by_name(
+    config: &torc::client::Configuration,
+    workflow_id: i64,
+) -> HashMap<String, JobModel> {
+    let jobs = default_api::list_jobs(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list jobs");
+    jobs.items
+        .unwrap()
+        .into_iter()
+        .map(|job| (job.name.clone(), job))
+        .collect()
+}
+
+fn rule(json: serde_json::Value) -> FailureHandlerRule {
+    serde_json::from_value(json).expect("Failed to parse rule")
+}
+
+#[test]
+fn test_find_matching_failure_rule() {
+    let rules = vec![
+        rule(serde_json::json!({"exit_codes": [1], "stderr_regexes": ["CUDA out of memory"]})),
+        rule(serde_json::json!({"exit_codes": [1], "stdout_regexes": ["NetCDF: HDF error"]})),
+        rule(serde_json::json!({"stderr_regexes": ["license server .* busy"]})),
+        rule(serde_json::json!({"exit_codes": [2]})),
+        rule(serde_json::json!({"match_all_exit_codes": true, "stderr_regexes": ["retry me"]})),
+    ];
+    let find = |exit_code, stdout, stderr| {
+        find_matching_failure_rule(&rules, exit_code, stdout, stderr)
+            .map(|r| rules.iter().position(|x| std::ptr::eq(x, r)).unwrap())
+    };
+
+    assert_eq!(find(1, "", "RuntimeError: CUDA out of memory"), Some(0));
+    assert_eq!(find(1, "NetCDF: HDF error", ""), Some(1));
+    assert_eq!(find(1, "", "segmentation fault"), None);
+    // Pattern-only rules match any non-zero exit code.
+    assert_eq!(find(42, "", "license server flexlm busy"), Some(2));
+    // Exact exit code matches take precedence over pattern-only rules.
+    assert_eq!(find(2, "", "license server flexlm busy"), Some(3));
+    assert_eq!(find(3, "", "please retry me"), Some(4));
+    assert_eq!(find(3, "", ""), None);
+}
+
+#[test]
+fn test_find_matching_failure_rule_invalid_regex() {
+    let rules = vec![
+        rule(serde_json::json!({"exit_codes": [1], "stderr_regexes": ["("]})),
+        rule(serde_json::json!({"exit_codes": [1]})),
+    ];
+    let matched = find_matching_failure_rule(&rules, 1, "", "(").unwrap();
+    assert!(matched.stderr_regexes.is_empty());
+}
+
+#[test]
+fn test_retry_delay_backoff() {
+    let no_delay = rule(serde_json::json!({"match_all_exit_codes": true}));
+    assert_eq!(no_delay.retry_delay(1), None);
+
+    let constant =
+        rule(serde_json::json!({"match_all_exit_codes": true, "retry_delay_seconds": 5}));
+    assert_eq!(constant.retry_delay(1), Some(5.0));
+    assert_eq!(constant.retry_delay(3), Some(5.0));
+
+    let backoff = rule(serde_json::json!({
+        "match_all_exit_codes": true,
+        "retry_delay_seconds": 10,
+        "retry_backoff_multiplier": 2,
+        "max_retry_delay_seconds": 60,
+    }));
+    assert_eq!(backoff.retry_delay(1), Some(10.0));
+    assert_eq!(backoff.retry_delay(2), Some(20.0));
+    assert_eq!(backoff.retry_delay(3), Some(40.0));
+    assert_eq!(backoff.retry_delay(4), Some(60.0));
+}
+
+#[rstest]
+fn test_failure_handler_retry_delay(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let work_dir = temp_dir.path();
+    let workflow_name = "failure_handler_retry_delay";
+    let yaml_content = format!(
+        r#"name: {workflow_name}
+user: test_user
+
+failure_handlers:
+  - name: delayed_retry
+    rules:
+      - match_all_exit_codes: true
+        retry_delay_seconds: 2
+        max_retries: 2
+
+jobs:
+  - name: flaky_job
+    command: bash -c 'if [ "$TORC_ATTEMPT_ID" = 1 ]; then exit 3; fi'
+    failure_handler: delayed_retry
+"#
+    );
+    let spec_path = work_dir.join(format!("{}.yaml", workflow_name));
+    fs::write(&spec_path, yaml_content).expect("Failed to write YAML file");
+
+    let workflow_id = WorkflowSpec::create_workflow_from_spec(
+        config,
+        spec_path.to_str().unwrap(),
+        "test_user",
+        false,
+        false,
+    )
+    .expect("Failed to create workflow");
+    let workflow_id_str = workflow_id.to_string();
+    let args = [
+        workflow_id_str.as_str(),
+        "--output-dir",
+        work_dir.to_str().unwrap(),
+        "--poll-interval",
+        "0.1",
+        "--max-parallel-jobs",
+        "1",
+    ];
+    let start = Instant::now();
+    run_jobs_cli_command(&args, start_server).expect("Failed to run jobs");
+    assert!(start.elapsed() >= Duration::from_secs(2));
+
+    let job = &list_jobs_by_name(config, workflow_id)["flaky_job"];
+    assert_eq!(job.status, Some(JobStatus::Completed));
+    assert_eq!(job.attempt_id, Some(2));
+    // The successful attempt clears the retry delay.
+    assert!(job.not_before.is_none());
+}
+
+#[rstest]
+fn test_failure_handler_log_patterns_and_adjustments(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let work_dir = temp_dir.path();
+    let workflow_name = "failure_handler_adjustments";
+    let spec_path = create_failure_handler_spec(work_dir, workflow_name);
+
+    let workflow_id =
+        WorkflowSpec::create_workflow_from_spec(config, &spec_path, "test_user", false, false)
+            .expect("Failed to create workflow");
+    let workflow_id_str = workflow_id.to_string();
+    let args = [
+        workflow_id_str.as_str(),
+        "--output-dir",
+        work_dir.to_str().unwrap(),
+        "--poll-interval",
+        "0.1",
+    ];
+    run_jobs_cli_command(&args, start_server).expect("Failed to run jobs");
+
+    let jobs = list_jobs_by_name(config, workflow_id);
+    assert_eq!(jobs["oom_job"].status, Some(JobStatus::Completed));
+    assert_eq!(jobs["license_job"].status, Some(JobStatus::Completed));
+    assert_eq!(jobs["unmatched_job"].status, Some(JobStatus::Failed));
+
+    // Each retry of oom_job gets its own resource requirements with compounded multipliers.
+    let rr = default_api::get_resource_requirements(
+        config,
+        jobs["oom_job"].resource_requirements_id.unwrap(),
+    )
+    .expect("Failed to get resource requirements");
+    assert_eq!(rr.name, "oom_job_attempt_3");
+    assert_eq!(rr.memory, "400m");
+    assert_eq!(rr.runtime, "PT23M");
+
+    // Jobs without adjustments keep the shared resource requirements.
+    for name in ["license_job", "unmatched_job"] {
+        let rr = default_api::get_resource_requirements(
+            config,
+            jobs[name].resource_requirements_id.unwrap(),
+        )
+        .expect("Failed to get resource requirements");
+        assert_eq!(rr.name, "small");
+        assert_eq!(rr.memory, "100m");
+    }
+}
+
+#[test]
+fn test_validate_failure_handlers() {
+    let base = r#"
+name: validate_failure_handlers
+jobs:
+  - name: job1
+    command: echo hello
+    failure_handler: handler
+failure_handlers:
+  - name: handler
+    rules:
+"#;
+    let parse = |rule: &str| {
+        WorkflowSpec::from_spec_file_content(&format!("{}{}", base, rule), "yaml")
+            .expect("Failed to parse spec")
+    };
+
+    let valid =
+        parse("      - stderr_regexes: [\"CUDA out of memory\"]\n        memory_multiplier: 2\n");
+    assert!(valid.validate_failure_handlers().is_ok());
+
+    let bad_regex = parse("      - stderr_regexes: [\"(\"]\n");
+    let err = bad_regex.validate_failure_handlers().unwrap_err();
+    assert!(err.to_string().contains("invalid pattern"));
+
+    let bad_multiplier = parse("      - exit_codes: [1]\n        runtime_multiplier: 0\n");
+    let err = bad_multiplier.validate_failure_handlers().unwrap_err();
+    assert!(err.to_string().contains("runtime_multiplier"));
+
+    let bad_backoff = parse("      - exit_codes: [1]\n        retry_backoff_multiplier: 2\n");
+    let err = bad_backoff.validate_failure_handlers().unwrap_err();
+    assert!(err.to_string().contains("retry_delay_seconds"));
+
+    let bad_delay = parse("      - exit_codes: [1]\n        retry_delay_seconds: -5\n");
+    let err = bad_delay.validate_failure_handlers().unwrap_err();
+    assert!(err.to_string().contains("retry_delay_seconds"));
+
+    let bad_scheduler = parse("      - exit_codes: [1]\n        scheduler: missing\n");
+    let err = bad_scheduler.validate_failure_handlers().unwrap_err();
+    assert!(err.to_string().contains("missing"));
+}
@@ -0,0 +1,671 @@
+mod common;
+
+use common::{ServerProcess, start_server};
+use rstest::rstest;
+use serial_test::serial;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+use torc::client::commands::orphan_detection::cleanup_orphaned_jobs;
+use torc::client::commands::slurm::schedule_hpc_nodes;
+use torc::client::hpc::common::{HpcJobStatus, HpcType};
+use torc::client::hpc::create_hpc_interface;
+use torc::client::hpc::fake_interface::FakeHpcInterface;
+use torc::client::hpc::hpc_interface::HpcInterface;
+use torc::client::job_runner::select_nodes_to_reserve;
+use torc::client::{Configuration, default_api};
+use torc::models;
+
+#[rstest]
+#[serial]
+fn test_create_hpc_interface_fake() {
+    let state_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    unsafe {
+        env::set_var("TORC_FAKE_HPC_DIR", state_dir.path());
+    }
+    let interface = create_hpc_interface(HpcType::Fake);
+    unsafe {
+        env::remove_var("TORC_FAKE_HPC_DIR");
+    }
+    assert!(interface.is_ok(), "Failed to create fake HPC interface");
+}
+
+#[rstest]
+fn test_fake_hpc_allocation_lifecycle() {
+    let state_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let interface = FakeHpcInterface::with_state_dir(state_dir.path()).unwrap();
+    let script = write_script(
+        state_dir.path(),
+        "lifecycle",
+        "#FAKE --job-name=lifecycle\n#FAKE --queue-delay=1\nsleep 1\necho hello\n",
+    );
+
+    let (return_code, job_id, _) = interface.submit(&script).expect("Submit failed");
+    assert_eq!(return_code, 0);
+    assert_eq!(job_id, "1");
+
+    let info = interface.get_status(&job_id).unwrap();
+    assert_eq!(info.name, "lifecycle");
+    assert_eq!(info.status, HpcJobStatus::Queued);
+    assert_eq!(
+        interface.get_statuses().unwrap().get(&job_id),
+        Some(&HpcJobStatus::Queued)
+    );
+
+    wait_for_status(&interface, &job_id, HpcJobStatus::Running, 10);
+    wait_for_status(&interface, &job_id, HpcJobStatus::Complete, 10);
+    assert!(interface.get_statuses().unwrap().is_empty());
+
+    let stats = interface.get_job_stats(&job_id).unwrap();
+    assert_eq!(stats.state, "COMPLETED");
+    assert!(stats.end.is_some());
+
+    let output = fs::read_to_string(state_dir.path().join("1.o")).unwrap();
+    assert_eq!(output.trim(), "hello");
+}
+
+#[rstest]
+fn test_fake_hpc_allocation_environment() {
+    let state_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let interface = FakeHpcInterface::with_state_dir(state_dir.path()).unwrap();
+    let script = write_script(
+        state_dir.path(),
+        "environment",
+        "#FAKE --queue-delay=0\n#FAKE --nodes=3\n#FAKE --walltime=01:00:00\nenv | grep TORC_FAKE_HPC_ | sort\n",
+    );
+
+    let (_, job_id, _) = interface.submit(&script).expect("Submit failed");
+    wait_for_status(&interface, &job_id, HpcJobStatus::Complete, 10);
+
+    let output = fs::read_to_string(state_dir.path().join(format!("{}.o", job_id))).unwrap();
+    let vars: HashMap<&str, &str> = output.lines().filter_map(|l| l.split_once('=')).collect();
+    assert_eq!(vars.get("TORC_FAKE_HPC_JOB_ID"), Some(&job_id.as_str()));
+    assert_eq!(vars.get("TORC_FAKE_HPC_NUM_NODES"), Some(&"3"));
+    let end_time: i64 = vars["TORC_FAKE_HPC_END_TIME"].parse().unwrap();
+    let expected = chrono::Utc::now().timestamp() + 3600;
+    assert!((end_time - expected).abs() < 30);
+    assert_eq!(interface.list_active_nodes(&job_id).unwrap().len(), 3);
+}
+
+#[rstest]
+fn test_fake_hpc_walltime_expiry() {
+    let state_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let interface = FakeHpcInterface::with_state_dir(state_dir.path()).unwrap();
+    // The script traps SIGTERM like the job runner does.
+    let script = write_script(
+        state_dir.path(),
+        "walltime",
+        "#FAKE --queue-delay=0\n#FAKE --walltime=0:0:2\ntrap 'echo terminated; exit 0' TERM\nsleep 60 &\nwait\n",
+    );
+
+    let (_, job_id, _) = interface.submit(&script).expect("Submit failed");
+    wait_for_status(&interface, &job_id, HpcJobStatus::Complete, 15);
+
+    let stats = interface.get_job_stats(&job_id).unwrap();
+    assert_eq!(stats.state, "TIMEOUT");
+    let output = fs::read_to_string(state_dir.path().join(format!("{}.o", job_id))).unwrap();
+    assert_eq!(output.trim(), "terminated");
+}
+
+#[rstest]
+fn test_fake_hpc_node_failure() {
+    let state_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let interface = FakeHpcInterface::with_state_dir(state_dir.path()).unwrap();
+    let script = write_script(
+        state_dir.path(),
+        "node_failure",
+        "#FAKE --queue-delay=0\n#FAKE --node-failure-after=1\ntrap 'echo terminated' TERM\nsleep 60\n",
+    );
+
+    let (_, job_id, _) = interface.submit(&script).expect("Submit failed");
+    wait_for_status(&interface, &job_id, HpcJobStatus::Complete, 15);
+
+    let stats = interface.get_job_stats(&job_id).unwrap();
+    assert_eq!(stats.state, "NODE_FAIL");
+    // Processes are killed without a chance to clean up.
+    let output = fs::read_to_string(state_dir.path().join(format!("{}.o", job_id))).unwrap();
+    assert!(output.is_empty());
+}
+
+#[rstest]
+fn test_fake_hpc_cancel_queued_job() {
+    let state_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let interface = FakeHpcInterface::with_state_dir(state_dir.path()).unwrap();
+    let script = write_script(
+        state_dir.path(),
+        "cancel",
+        "#FAKE --queue-delay=60\necho should not run\n",
+    );
+
+    let (_, job_id, _) = interface.submit(&script).expect("Submit failed");
+    assert_eq!(
+        interface.get_status(&job_id).unwrap().status,
+        HpcJobStatus::Queued
+    );
+
+    assert_eq!(interface.cancel_job(&job_id).unwrap(), 0);
+    wait_for_status(&interface, &job_id, HpcJobStatus::Complete, 5);
+    assert_eq!(interface.get_job_stats(&job_id).unwrap().state, "CANCELLED");
+    assert!(!state_dir.path().join(format!("{}.start", job_id)).exists());
+
+    // Unknown jobs behave like jobs that have left the queue.
+    assert_eq!(
+        interface.get_status("9999").unwrap().status,
+        HpcJobStatus::None
+    );
+    assert_ne!(interface.cancel_job("9999").unwrap(), 0);
+}
+
+#[rstest]
+fn test_fake_hpc_create_submission_script() {
+    let state_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let interface = FakeHpcInterface::with_state_dir(state_dir.path()).unwrap();
+    let script_path = state_dir.path().join("submission.sh");
+
+    let mut config = HashMap::new();
+    config.insert("account".to_string(), "test_account".to_string());
+    config.insert("walltime".to_string(), "04:00:00".to_string());
+    config.insert("nodes".to_string(), "2".to_string());
+
+    interface
+        .create_submission_script(
+            "worker_1",
+            "http://localhost:8080/torc-service/v1",
+            7,
+            "output",
+            5,
+            Some(4),
+            &script_path,
+            &config,
+            true,
+        )
+        .expect("Failed to create submission script");
+
+    let script = fs::read_to_string(&script_path).unwrap();
+    let directives = FakeHpcInterface::parse_directives(&script);
+    assert_eq!(directives["job-name"], "worker_1");
+    assert_eq!(directives["walltime"], "04:00:00");
+    assert_eq!(directives["nodes"], "2");
+    assert_eq!(directives["account"], "test_account");
+    assert_eq!(directives["output"], "output/slurm_output_wf7_sl%j.o");
+    assert!(script.contains(
+        "torc-slurm-job-runner $TORC_URL 7 output --hpc-type fake --poll-interval 5 --max-parallel-jobs 4 --is-subtask --worker-per-node &"
+    ));
+    assert!(script.contains("TORC_FAKE_HPC_NODE_ID=$node_id"));
+}
+
+/// Run a workflow end to end in simulated allocations that start real job runners.
+#[rstest]
+#[serial]
+fn test_fake_hpc_schedule_nodes_runs_workflow(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let _fake = FakeHpc::new(&[("TORC_FAKE_HPC_QUEUE_DELAY", "1")]);
+    let (workflow_id, scheduler_id) =
+        create_workflow_with_scheduler(config, "test_fake_hpc_runs_workflow", "echo done");
+    let output_dir = tempfile::tempdir().expect("Failed to create temp dir");
+
+    schedule_hpc_nodes(
+        config,
+        HpcType::Fake,
+        workflow_id,
+        scheduler_id,
+        1,
+        "worker",
+        output_dir.path().to_str().unwrap(),
+        1,
+        None,
+        false,
+        false,
+        None,
+        false,
+    )
+    .expect("Failed to schedule fake nodes");
+
+    let nodes = list_scheduled_nodes(config, workflow_id);
+    assert_eq!(nodes.len(), 1);
+    assert_eq!(nodes[0].scheduler_type, "fake");
+
+    wait_until(60, || {
+        let jobs = default_api::list_jobs(
+            config,
+            workflow_id,
+            Some(models::JobStatus::Completed),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        jobs.items.unwrap_or_default().len() == 2
+    });
+    wait_until(30, || {
+        list_scheduled_nodes(config, workflow_id)[0].status == "complete"
+    });
+}
+
+/// Allocations that could push a workflow over its AU budget are not submitted.
+#[rstest]
+#[serial]
+fn test_fake_hpc_schedule_nodes_enforces_au_budget(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let _fake = FakeHpc::new(&[("TORC_FAKE_HPC_QUEUE_DELAY", "1")]);
+    let (workflow_id, scheduler_id) =
+        create_workflow_with_scheduler(config, "test_fake_hpc_au_budget", "echo done");
+    // A one-node, one-hour allocation costs 1 AU with the default charge factors.
+    let mut workflow = default_api::get_workflow(config, workflow_id).unwrap();
+    workflow.au_budget = Some(1.5);
+    default_api::update_workflow(config, workflow_id, workflow).unwrap();
+    let output_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let schedule = |num_hpc_jobs| {
+        schedule_hpc_nodes(
+            config,
+            HpcType::Fake,
+            workflow_id,
+            scheduler_id,
+            num_hpc_jobs,
+            "worker",
+            output_dir.path().to_str().unwrap(),
+            1,
+            None,
+            false,
+            false,
+            None,
+            false,
+        )
+    };
+
+    let err = schedule(2).expect_err("Two allocations should exceed the budget");
+    assert!(err.to_string().contains("AU budget"), "{}", err);
+    assert!(list_scheduled_nodes(config, workflow_id).is_empty());
+
+    schedule(1).expect("Failed to schedule fake nodes");
+    // The first allocation commits its full walltime until it completes.
+    assert!(schedule(1).is_err());
+    assert_eq!(list_scheduled_nodes(config, workflow_id).len(), 1);
+
+    // An allocation whose walltime cannot be parsed fails the check instead of counting as free.
+    let mut scheduler = default_api::get_slurm_scheduler(config, scheduler_id).unwrap();
+    let walltime = std::mem::replace(&mut scheduler.walltime, "bogus".to_string());
+    default_api::update_slurm_scheduler(config, scheduler_id, scheduler.clone()).unwrap();
+    workflow = default_api::get_workflow(config, workflow_id).unwrap();
+    workflow.au_budget = Some(100.0);
+    default_api::update_workflow(config, workflow_id, workflow).unwrap();
+    let mut other = scheduler.clone();
+    other.id = None;
+    other.walltime = "00:10:00".to_string();
+    let other_id = default_api::create_slurm_scheduler(config, other)
+        .unwrap()
+        .id
+        .unwrap();
+    let err = schedule_hpc_nodes(
+        config,
+        HpcType::Fake,
+        workflow_id,
+        other_id,
+        1,
+        "worker",
+        output_dir.path().to_str().unwrap(),
+        1,
+        None,
+        false,
+        false,
+        None,
+        false,
+    )
+    .expect_err("An unparseable walltime should fail the budget check");
+    assert!(
+        err.to_string().contains("Cannot estimate the AU cost"),
+        "{}",
+        err
+    );
+    scheduler.walltime = walltime;
+    default_api::update_slurm_scheduler(config, scheduler_id, scheduler).unwrap();
+
+    wait_until(30, || {
+        list_scheduled_nodes(config, workflow_id)[0].status == "complete"
+    });
+}
+
+/// A node failure kills the job runner, and orphan detection fails its running jobs.
+#[rstest]
+#[serial]
+fn test_fake_hpc_node_failure_orphans_jobs(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let _fake = FakeHpc::new(&[
+        ("TORC_FAKE_HPC_QUEUE_DELAY", "1"),
+        ("TORC_FAKE_HPC_NODE_FAILURE_AFTER", "4"),
+        ("TORC_FAKE_HPC_CPUS_PER_NODE", "2"),
+    ]);
+    let (workflow_id, scheduler_id) =
+        create_workflow_with_scheduler(config, "test_fake_hpc_node_failure", "sleep 120");
+    let output_dir = tempfile::tempdir().expect("Failed to create temp dir");
+
+    schedule_hpc_nodes(
+        config,
+        HpcType::Fake,
+        workflow_id,
+        scheduler_id,
+        1,
+        "worker",
+        output_dir.path().to_str().unwrap(),
+        1,
+        None,
+        false,
+        false,
+        None,
+        false,
+    )
+    .expect("Failed to schedule fake nodes");
+
+    let interface = FakeHpcInterface::new().unwrap();
+    let hpc_job_id = list_scheduled_nodes(config, workflow_id)[0]
+        .scheduler_id
+        .to_string();
+    wait_for_status(&interface, &hpc_job_id, HpcJobStatus::Complete, 30);
+    assert_eq!(
+        interface.get_job_stats(&hpc_job_id).unwrap().state,
+        "NODE_FAIL"
+    );
+
+    let result = cleanup_orphaned_jobs(config, workflow_id, false).expect("Cleanup failed");
+    assert_eq!(result.slurm_jobs_failed, 2);
+    let failed = default_api::list_jobs(
+        config,
+        workflow_id,
+        Some(models::JobStatus::Failed),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(failed.items.unwrap_or_default().len(), 2);
+}
+
+/// The head node's job runner of a per-node allocation runs a multi-node job on reserved
+/// nodes and releases them when the job completes.
+#[rstest]
+#[serial]
+fn test_fake_hpc_multi_node_job(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let _fake = FakeHpc::new(&[
+        ("TORC_FAKE_HPC_QUEUE_DELAY", "1"),
+        ("TORC_CLIENT__SLURM__MULTI_NODE_LAUNCHER", "none"),
+    ]);
+    let output_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let nodes_file = output_dir.path().join("nodes.txt");
+    let (workflow_id, scheduler_id) =
+        create_workflow_with_scheduler(config, "test_fake_hpc_multi_node_job", "echo single node");
+    let mut scheduler = default_api::get_slurm_scheduler(config, scheduler_id).unwrap();
+    scheduler.nodes = 2;
+    default_api::update_slurm_scheduler(config, scheduler_id, scheduler).unwrap();
+
+    let mut rr = models::ResourceRequirementsModel::new(workflow_id, "mpi".to_string());
+    rr.num_cpus = 1;
+    rr.num_nodes = 2;
+    rr.memory = "100m".to_string();
+    rr.runtime = "PT5M".to_string();
+    let rr = default_api::create_resource_requirements(config, rr).unwrap();
+    let mut job = models::JobModel::new(
+        workflow_id,
+        "mpi_job".to_string(),
+        format!(
+            "echo \"$TORC_NUM_NODES $(wc -l < $TORC_HOSTFILE)\" > {}",
+            nodes_file.display()
+        ),
+    );
+    job.resource_requirements_id = rr.id;
+    let job = default_api::create_job(config, job).unwrap();
+    default_api::initialize_jobs(config, workflow_id, None, None, None).unwrap();
+
+    schedule_hpc_nodes(
+        config,
+        HpcType::Fake,
+        workflow_id,
+        scheduler_id,
+        1,
+        "worker",
+        output_dir.path().to_str().unwrap(),
+        1,
+        None,
+        true,
+        false,
+        None,
+        false,
+    )
+    .expect("Failed to schedule fake nodes");
+
+    wait_until(60, || {
+        default_api::get_job(config, job.id.unwrap())
+            .unwrap()
+            .status
+            == Some(models::JobStatus::Completed)
+    });
+    assert_eq!(fs::read_to_string(&nodes_file).unwrap().trim(), "2 2");
+    wait_until(30, || {
+        list_scheduled_nodes(config, workflow_id)[0].status == "complete"
+    });
+
+    let compute_nodes = default_api::list_compute_nodes(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap()
+    .items
+    .unwrap_or_default();
+    assert_eq!(compute_nodes.len(), 2);
+    assert!(
+        compute_nodes
+            .iter()
+            .all(|node| node.reserved_for_job_id.is_none())
+    );
+    let completed = default_api::list_jobs(
+        config,
+        workflow_id,
+        Some(models::JobStatus::Completed),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(completed.items.unwrap_or_default().len(), 3);
+}
+
+fn create_workflow_with_scheduler(config: &Configuration, name: &str, command: &str) -> (i64, i64) {
+    let workflow = models::WorkflowModel::new(name.to_string(), "test_user".to_string());
+    let workflow = default_api::create_workflow(config, workflow).unwrap();
+    let workflow_id = workflow.id.unwrap();
+
+    let mut rr = models::ResourceRequirementsModel::new(workflow_id, "small".to_string());
+    rr.num_cpus = 1;
+    rr.memory = "100m".to_string();
+    rr.runtime = "PT5M".to_string();
+    let rr = default_api::create_resource_requirements(config, rr).unwrap();
+
+    for i in 0..2 {
+        let mut job = models::JobModel::new(workflow_id, format!("job_{}", i), command.to_string());
+        job.resource_requirements_id = rr.id;
+        default_api::create_job(config, job).unwrap();
+    }
+    default_api::initialize_jobs(config, workflow_id, None, None, None).unwrap();
+
+    let scheduler = models::SlurmSchedulerModel {
+        id: None,
+        workflow_id,
+        name: Some("fake".to_string()),
+        account: "test_account".to_string(),
+        gres: None,
+        mem: None,
+        nodes: 1,
+        ntasks_per_node: None,
+        partition: None,
+        qos: None,
+        tmp: None,
+        walltime: "01:00:00".to_string(),
+        extra: None,
+    };
+    let scheduler = default_api::create_slurm_scheduler(config, scheduler).unwrap();
+    (workflow_id, scheduler.id.unwrap())
+}
+
+fn list_scheduled_nodes(
+    config: &Configuration,
+    workflow_id: i64,
+) -> Vec<models::ScheduledComputeNodesModel> {
+    default_api::list_scheduled_compute_nodes(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap()
+    .items
+    .unwrap_or_default()
+}
+
+fn write_script(dir: &Path, name: &str, body: &str) -> std::path::PathBuf {
+    let path = dir.join(format!("{}.sh", name));
+    fs::write(&path, format!("#!/bin/bash\n{}", body)).unwrap();
+    path
+}
+
+fn wait_for_status(
+    interface: &FakeHpcInterface,
+    job_id: &str,
+    expected: HpcJobStatus,
+    timeout_secs: u64,
+) {
+    wait_until(timeout_secs, || {
+        interface.get_status(job_id).unwrap().status == expected
+    });
+}
+
+fn wait_until(timeout_secs: u64, mut condition: impl FnMut() -> bool) {
+    let start = Instant::now();
+    while !condition() {
+        assert!(
+            start.elapsed() < Duration::from_secs(timeout_secs),
+            "Condition not met within {} seconds",
+            timeout_secs
+        );
+        thread::sleep(Duration::from_millis(200));
+    }
+}
+
+/// Configures the fake scheduler through environment variables and puts the job runner on
+/// PATH for the lifetime of the guard.
+struct FakeHpc {
+    _state_dir: tempfile::TempDir,
+    vars: Vec<String>,
+    original_path: String,
+}
+
+impl FakeHpc {
+    fn new(vars: &[(&str, &str)]) -> Self {
+        let state_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let bin_dir = env::current_dir().unwrap().join("target/debug");
+        let original_path = env::var("PATH").unwrap_or_default();
+        unsafe {
+            env::set_var("TORC_FAKE_HPC_DIR", state_dir.path());
+            env::set_var("PATH", format!("{}:{}", bin_dir.display(), original_path));
+            for (name, value) in vars {
+                env::set_var(name, value);
+            }
+        }
+        Self {
+            _state_dir: state_dir,
+            vars: vars.iter().map(|(name, _)| name.to_string()).collect(),
+            original_path,
+        }
+    }
+}
+
+impl Drop for FakeHpc {
+    fn drop(&mut self) {
+        unsafe {
+            env::remove_var("TORC_FAKE_HPC_DIR");
+            env::set_var("PATH", &self.original_path);
+            for name in &self.vars {
+                env::remove_var(name);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_select_nodes_to_reserve() {
+    let node = |id: i64, reserved_for_job_id: Option<i64>, exited: bool| {
+        let mut node = models::ComputeNodeModel::new(
+            1,
+            format!("node{}", id),
+            1,
+            chrono::Utc::now().to_rfc3339(),
+            8,
+            16.0,
+            0,
+            1,
+            "slurm".to_string(),
+            None,
+        );
+        node.id = Some(id);
+        node.reserved_for_job_id = reserved_for_job_id;
+        node.duration_seconds = exited.then_some(60.0);
+        node
+    };
+    let ids = |nodes: Option<Vec<models::ComputeNodeModel>>| {
+        nodes.map(|nodes| nodes.into_iter().map(|n| n.id.unwrap()).collect::<Vec<_>>())
+    };
+
+    // The own node comes last; exited nodes and nodes reserved for other jobs are skipped.
+    let nodes = vec![
+        node(1, None, false),
+        node(2, None, false),
+        node(3, None, true),
+        node(4, Some(99), false),
+        node(5, None, false),
+    ];
+    assert_eq!(
+        ids(select_nodes_to_reserve(nodes.clone(), 7, 1, 2)),
+        Some(vec![2, 5])
+    );
+    assert_eq!(ids(select_nodes_to_reserve(nodes, 7, 1, 4)), None);
+
+    // Nodes left reserved for the job by an earlier attempt are reused first.
+    let nodes = vec![
+        node(1, None, false),
+        node(2, None, false),
+        node(3, Some(7), false),
+    ];
+    assert_eq!(
+        ids(select_nodes_to_reserve(nodes, 7, 1, 2)),
+        Some(vec![3, 2])
+    );
+}
@@ -3,12 +3,19 @@
 use rstest::rstest;
 use std::collections::HashMap;
 use torc::client::commands::slurm::{
-    GroupByStrategy, WalltimeStrategy, generate_schedulers_for_workflow, parse_memory_mb,
-    parse_walltime_secs, secs_to_walltime,
+    GroupByStrategy, SlurmDependencyType, WalltimeStrategy, chain_scheduler_actions,
+    generate_schedulers_for_workflow, parse_memory_mb, parse_walltime_secs, secs_to_walltime,
+};
+use torc::client::hpc::cost::{
+    AllocationCost, AllocationShape, AuUsage, check_au_budget, compute_node_au, gres_requests_gpus,
+    sacct_au, total_au,
 };
 use torc::client::hpc::kestrel::kestrel_profile;
-use torc::client::hpc::{HpcDetection, HpcPartition, HpcProfile, HpcProfileRegistry};
+use torc::client::hpc::{
+    ChargeFactors, HpcDetection, HpcPartition, HpcProfile, HpcProfileRegistry,
+};
 use torc::client::workflow_spec::{JobSpec, ResourceRequirementsSpec, WorkflowSpec};
+use torc::models::ComputeNodeModel;
 use torc::time_utils::duration_string_to_seconds;
 
 // ============== Utility Function Tests ==============
@@ -1167,6 +1174,100 @@ fn test_generate_schedulers_per_resource_requirement() {
     assert_eq!(finalize_action.trigger_type, "on_jobs_ready");
 }
 
+#[rstest]
+fn test_chain_scheduler_actions() {
+    // setup -> process_{i} (parameterized) -> finalize
+    let mut spec = WorkflowSpec {
+        name: "chained_workflow".to_string(),
+        jobs: vec![
+            JobSpec {
+                name: "setup".to_string(),
+                command: "echo setup".to_string(),
+                resource_requirements: Some("small".to_string()),
+                ..Default::default()
+            },
+            JobSpec {
+                name: "process_{i}".to_string(),
+                command: "echo process {i}".to_string(),
+                resource_requirements: Some("medium".to_string()),
+                depends_on: Some(vec!["setup".to_string()]),
+                parameters: Some(HashMap::from([("i".to_string(), "1:3".to_string())])),
+                ..Default::default()
+            },
+            JobSpec {
+                name: "finalize".to_string(),
+                command: "echo finalize".to_string(),
+                resource_requirements: Some("small".to_string()),
+                depends_on_regexes: Some(vec!["process_.*".to_string()]),
+                ..Default::default()
+            },
+        ],
+        resource_requirements: Some(vec![
+            ResourceRequirementsSpec {
+                name: "small".to_string(),
+                num_cpus: 2,
+                num_gpus: 0,
+                num_nodes: 1,
+                memory: "4g".to_string(),
+                runtime: "PT30M".to_string(),
+            },
+            ResourceRequirementsSpec {
+                name: "medium".to_string(),
+                num_cpus: 8,
+                num_gpus: 0,
+                num_nodes: 1,
+                memory: "16g".to_string(),
+                runtime: "PT2H".to_string(),
+            },
+        ]),
+        ..Default::default()
+    };
+
+    let profile = kestrel_profile();
+    generate_schedulers_for_workflow(
+        &mut spec,
+        &profile,
+        "testaccount",
+        false,
+        GroupByStrategy::ResourceRequirements,
+        WalltimeStrategy::MaxJobRuntime,
+        1.5,
+        true,
+        false,
+    )
+    .unwrap();
+
+    let num_chained = chain_scheduler_actions(&mut spec, SlurmDependencyType::AfterAny).unwrap();
+    assert_eq!(num_chained, 2);
+
+    // All allocations are submitted at workflow start; each stage waits for the previous one.
+    let actions = spec.actions.as_ref().unwrap();
+    let action = |scheduler: &str| {
+        actions
+            .iter()
+            .find(|a| a.scheduler.as_deref() == Some(scheduler))
+            .unwrap()
+    };
+    for action in actions {
+        assert_eq!(action.trigger_type, "on_workflow_start");
+    }
+    assert_eq!(action("small_scheduler").depends_on_schedulers, None);
+    assert_eq!(
+        action("medium_deferred_scheduler").depends_on_schedulers,
+        Some(vec!["small_scheduler".to_string()])
+    );
+    assert_eq!(
+        action("small_deferred_scheduler").depends_on_schedulers,
+        Some(vec!["medium_deferred_scheduler".to_string()])
+    );
+    assert_eq!(
+        action("small_deferred_scheduler")
+            .dependency_type
+            .as_deref(),
+        Some("afterany")
+    );
+}
+
 /// Test that num_allocations is auto-calculated based on job count and partition capacity
 #[test]
 fn test_generate_schedulers_auto_calculates_allocations() {
@@ -1660,6 +1761,212 @@ fn test_parse_sinfo_string_incomplete_lines() {
     assert_eq!(result[0].name, "partition");
 }
 
+// ============== Slurm Profile Discovery Tests ==============
+
+use torc::client::commands::hpc::{
+    SlurmClusterInfo, create_registry_with_config_public, parse_scontrol_partitions_string,
+    profile_to_toml, write_profile_to_config,
+};
+use torc::config::TorcConfig;
+
+const SINFO_FIXTURE: &str = include_str!("fixtures/slurm/sinfo.txt");
+const SCONTROL_FIXTURE: &str = include_str!("fixtures/slurm/scontrol_show_partition.txt");
+
+fn fixture_cluster_info() -> SlurmClusterInfo {
+    SlurmClusterInfo {
+        cluster_name: Some("testcluster".to_string()),
+        hostname: Some("login1.hpc.example.org".to_string()),
+        sinfo_output: SINFO_FIXTURE.to_string(),
+        scontrol_output: SCONTROL_FIXTURE.to_string(),
+    }
+}
+
+/// Test parsing recorded `scontrol show partition` output
+#[rstest]
+fn test_parse_scontrol_partitions_string() {
+    let partitions = parse_scontrol_partitions_string(SCONTROL_FIXTURE);
+    assert_eq!(partitions.len(), 7);
+
+    let debug = &partitions["debug"];
+    assert_eq!(debug.max_nodes, Some(2));
+    assert_eq!(debug.total_nodes, Some(4));
+    assert_eq!(debug.default_qos.as_deref(), Some("debug"));
+
+    let standard = &partitions["standard"];
+    assert_eq!(standard.max_nodes, None); // UNLIMITED
+    assert_eq!(standard.total_nodes, Some(128));
+    assert_eq!(standard.default_qos, None); // N/A
+    assert_eq!(standard.oversubscribe.as_deref(), Some("EXCLUSIVE"));
+
+    let wide = &partitions["wide"];
+    assert_eq!(wide.min_nodes, Some(16));
+    assert_eq!(wide.max_nodes, Some(64));
+
+    assert!(parse_scontrol_partitions_string("").is_empty());
+}
+
+/// Test building a complete profile from recorded sinfo/scontrol output
+#[rstest]
+fn test_discover_profile_from_fixtures() {
+    let info = fixture_cluster_info();
+    assert_eq!(info.default_profile_name(), "testcluster");
+
+    let profile = info.build_profile("Test Cluster", true).unwrap();
+    assert_eq!(profile.display_name, "Test Cluster");
+    assert_eq!(
+        profile.detect_env_var.as_deref(),
+        Some("SLURM_CLUSTER_NAME=testcluster")
+    );
+    assert_eq!(
+        profile.detect_hostname.as_deref(),
+        Some(r".*\.hpc\.example\.org")
+    );
+
+    let names: Vec<&str> = profile.partitions.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec!["bigmem", "debug", "gpu", "shared", "standard", "wide"]
+    );
+    let partition = |name: &str| profile.partitions.iter().find(|p| p.name == name).unwrap();
+
+    // Two node types: minimum memory, node count from scontrol TotalNodes
+    let standard = partition("standard");
+    assert_eq!(standard.cpus_per_node, 64);
+    assert_eq!(standard.memory_mb, 256000);
+    assert_eq!(standard.max_walltime_secs, 2 * 24 * 3600);
+    assert_eq!(standard.max_nodes, Some(128));
+    assert_eq!(standard.min_nodes, None);
+    assert!(!standard.shared);
+
+    // Per-job node limit caps the node count
+    let debug = partition("debug");
+    assert_eq!(debug.max_nodes, Some(2));
+    assert_eq!(debug.max_walltime_secs, 3600);
+    assert_eq!(debug.default_qos.as_deref(), Some("debug"));
+
+    let wide = partition("wide");
+    assert_eq!(wide.min_nodes, Some(16));
+    assert_eq!(wide.max_nodes, Some(64));
+
+    let gpu = partition("gpu");
+    assert_eq!(gpu.gpus_per_node, Some(4));
+    assert_eq!(gpu.gpu_type.as_deref(), Some("a100"));
+    assert_eq!(gpu.max_nodes, Some(12));
+    assert!(gpu.shared);
+
+    let shared = partition("shared");
+    assert!(shared.shared); // OverSubscribe=FORCE:4
+    assert_eq!(shared.max_nodes, Some(1));
+
+    // Standby partitions are kept unless skipped
+    let all = info.build_profile("Test Cluster", false).unwrap();
+    assert_eq!(all.partitions.len(), 7);
+}
+
+/// Test that node counts fall back to sinfo when scontrol output is missing
+#[rstest]
+fn test_discover_profile_without_scontrol() {
+    let info = SlurmClusterInfo {
+        sinfo_output: SINFO_FIXTURE.to_string(),
+        ..Default::default()
+    };
+    assert_eq!(info.default_profile_name(), "unknown");
+
+    let profile = info.build_profile("Unknown", false).unwrap();
+    assert!(profile.detect_env_var.is_none());
+    assert!(profile.detect_hostname.is_none());
+
+    let standard = profile
+        .partitions
+        .iter()
+        .find(|p| p.name == "standard")
+        .unwrap();
+    assert_eq!(standard.max_nodes, Some(128)); // 120 + 8 nodes from sinfo
+    assert_eq!(standard.default_qos, None);
+
+    let empty = SlurmClusterInfo::default();
+    assert!(empty.build_profile("Empty", false).is_err());
+}
+
+/// Test writing a discovered profile into a config file and loading it back
+#[rstest]
+fn test_write_discovered_profile_to_config() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("torc").join("config.toml");
+    let profile = fixture_cluster_info()
+        .build_profile("Test Cluster", true)
+        .unwrap();
+
+    // Existing content and comments are preserved when adding a profile
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(
+        &path,
+        "# My settings\n[client]\napi_url = \"http://example.org:9090/torc-service/v1\"\n",
+    )
+    .unwrap();
+    let replaced = write_profile_to_config(&path, "testcluster", &profile, false).unwrap();
+    assert!(!replaced);
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(contents.starts_with("# My settings\n"));
+    assert!(contents.contains(&profile_to_toml("testcluster", &profile)));
+
+    let config = TorcConfig::load_from_files(std::slice::from_ref(&path)).unwrap();
+    assert_eq!(
+        config.client.api_url,
+        "http://example.org:9090/torc-service/v1"
+    );
+    let loaded = &config.client.hpc.custom_profiles["testcluster"];
+    assert_eq!(loaded.partitions.len(), profile.partitions.len());
+    assert_eq!(loaded.detect_env_var, profile.detect_env_var);
+    assert_eq!(loaded.detect_hostname, profile.detect_hostname);
+
+    // The loaded profile carries node limits and detection rules
+    let registry = create_registry_with_config_public(&config.client.hpc);
+    let hpc_profile = registry.get("testcluster").unwrap();
+    assert!(hpc_profile.detection.iter().any(|d| matches!(
+        d,
+        HpcDetection::EnvVar { name, value } if name == "SLURM_CLUSTER_NAME" && value == "testcluster"
+    )));
+    let wide = hpc_profile.get_partition("wide").unwrap();
+    assert_eq!(wide.min_nodes, Some(16));
+    assert_eq!(wide.max_nodes, Some(64));
+    assert_eq!(
+        hpc_profile
+            .get_partition("debug")
+            .unwrap()
+            .default_qos
+            .as_deref(),
+        Some("debug")
+    );
+
+    // Existing profiles are only replaced with force
+    let err = write_profile_to_config(&path, "testcluster", &profile, false).unwrap_err();
+    assert!(err.contains("already exists"));
+
+    let mut contents = std::fs::read_to_string(&path).unwrap();
+    contents.push_str("\n# Server settings\n[server]\nport = 9090 # shared port\n");
+    std::fs::write(&path, contents).unwrap();
+    let updated = fixture_cluster_info()
+        .build_profile("Renamed Cluster", false)
+        .unwrap();
+    let replaced = write_profile_to_config(&path, "testcluster", &updated, true).unwrap();
+    assert!(replaced);
+    // Replacing a profile keeps the comments of the rest of the file
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(contents.starts_with("# My settings\n"));
+    assert!(contents.contains("# Server settings\n[server]\nport = 9090 # shared port\n"));
+    assert_eq!(contents.matches("# HPC profile discovered").count(), 1);
+    assert!(contents.contains(&profile_to_toml("testcluster", &updated)));
+    let config = TorcConfig::load_from_files(std::slice::from_ref(&path)).unwrap();
+    let loaded = &config.client.hpc.custom_profiles["testcluster"];
+    assert_eq!(loaded.display_name, "Renamed Cluster");
+    assert_eq!(loaded.partitions.len(), 7);
+    assert_eq!(
+        config.client.api_url,
+        "http://example.org:9090/torc-service/v1"
+    );
+}
+
 /// Test that GPU constraints are considered in jobs-per-node calculation.
 /// When GPUs are the limiting factor, we should allocate more nodes.
 #[rstest]
@@ -2042,3 +2349,207 @@ fn test_slurm_defaults_json_roundtrip() {
     assert_eq!(string_map.get("reservation"), Some(&"special".to_string()));
     assert_eq!(string_map.get("extra"), Some(&"--nice=100".to_string()));
 }
+
+// ============== AU Cost Tests ==============
+
+#[rstest]
+fn test_charge_factors_from_profile() {
+    let factors = ChargeFactors::from_profile(&kestrel_profile());
+    assert_eq!(
+        factors,
+        ChargeFactors {
+            cpu: 10.0,
+            gpu: 100.0
+        }
+    );
+    assert_eq!(factors.for_allocation(false), 10.0);
+    assert_eq!(factors.for_allocation(true), 100.0);
+    assert_eq!(
+        ChargeFactors::default(),
+        ChargeFactors {
+            cpu: 1.0,
+            gpu: 10.0
+        }
+    );
+}
+
+#[rstest]
+fn test_gres_requests_gpus() {
+    assert!(gres_requests_gpus(Some("gpu:2")));
+    assert!(gres_requests_gpus(Some("gpu:h100:4")));
+    assert!(gres_requests_gpus(Some("gpu")));
+    assert!(gres_requests_gpus(Some("tmpfs:10G,gpu:1")));
+    assert!(!gres_requests_gpus(Some("gpu:0")));
+    assert!(!gres_requests_gpus(Some("tmpfs:10G")));
+    assert!(!gres_requests_gpus(None));
+}
+
+#[rstest]
+fn test_allocation_shape_estimated_au() {
+    let factors = ChargeFactors {
+        cpu: 10.0,
+        gpu: 100.0,
+    };
+    let cpu = AllocationShape::new(2, "4:00:00", None);
+    assert_eq!(cpu.estimated_au(&factors).unwrap(), 80.0);
+    let gpu = AllocationShape::new(1, "0:30:00", Some("gpu:4"));
+    assert_eq!(gpu.estimated_au(&factors).unwrap(), 50.0);
+    assert!(
+        AllocationShape::new(1, "invalid", None)
+            .estimated_au(&factors)
+            .is_err()
+    );
+}
+
+#[rstest]
+fn test_generate_schedulers_estimates_cost() {
+    let jobs: Vec<JobSpec> = (0..8)
+        .map(|i| JobSpec {
+            name: format!("gpu_job_{}", i),
+            command: "python train.py".to_string(),
+            resource_requirements: Some("gpu_training".to_string()),
+            ..Default::default()
+        })
+        .collect();
+    let mut spec = WorkflowSpec {
+        name: "gpu_cost_test".to_string(),
+        jobs,
+        resource_requirements: Some(vec![ResourceRequirementsSpec {
+            name: "gpu_training".to_string(),
+            num_cpus: 32,
+            num_gpus: 2,
+            num_nodes: 1,
+            memory: "90g".to_string(),
+            runtime: "PT1H".to_string(),
+        }]),
+        ..Default::default()
+    };
+
+    let result = generate_schedulers_for_workflow(
+        &mut spec,
+        &kestrel_profile(),
+        "testaccount",
+        false,
+        GroupByStrategy::ResourceRequirements,
+        WalltimeStrategy::MaxJobRuntime,
+        1.5,
+        true,
+        false,
+    )
+    .unwrap();
+
+    assert_eq!(result.cost_estimates.len(), 1);
+    let estimate = &result.cost_estimates[0];
+    let scheduler = &spec.slurm_schedulers.as_ref().unwrap()[0];
+    let hours = parse_walltime_secs(&scheduler.walltime).unwrap() as f64 / 3600.0;
+    assert!(estimate.uses_gpus);
+    assert_eq!(estimate.nodes, scheduler.nodes);
+    assert_eq!(estimate.num_allocations, 1);
+    assert_eq!(
+        estimate.au_per_allocation,
+        scheduler.nodes as f64 * hours * 100.0
+    );
+    assert_eq!(total_au(&result.cost_estimates), estimate.au_per_allocation);
+}
+
+#[rstest]
+fn test_sacct_au() {
+    let sacct_json: serde_json::Value = serde_json::from_str(
+        r#"{
+            "jobs": [
+                {
+                    "job_id": 1001,
+                    "allocation_nodes": 2,
+                    "time": {"elapsed": 5400},
+                    "tres": {"allocated": [
+                        {"type": "cpu", "name": "", "count": 208},
+                        {"type": "node", "name": "", "count": 2}
+                    ]}
+                },
+                {
+                    "job_id": 1002,
+                    "time": {"elapsed": 1800},
+                    "tres": {"allocated": [
+                        {"type": "node", "name": "", "count": 1},
+                        {"type": "gres", "name": "gpu", "count": 4}
+                    ]}
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let factors = ChargeFactors {
+        cpu: 10.0,
+        gpu: 100.0,
+    };
+    // 2 nodes × 1.5 h × 10 + 1 node × 0.5 h × 100
+    assert_eq!(sacct_au(&sacct_json, &factors), 80.0);
+    assert_eq!(sacct_au(&serde_json::json!({}), &factors), 0.0);
+}
+
+fn compute_node(
+    hostname: &str,
+    slurm_job_id: &str,
+    duration: f64,
+    num_gpus: i64,
+) -> ComputeNodeModel {
+    let mut node = ComputeNodeModel::new(
+        1,
+        hostname.to_string(),
+        1234,
+        "2026-01-01T00:00:00Z".to_string(),
+        104,
+        240.0,
+        num_gpus,
+        1,
+        "slurm".to_string(),
+        Some(serde_json::json!({"scheduler_id": 1, "slurm_job_id": slurm_job_id})),
+    );
+    node.duration_seconds = Some(duration);
+    node
+}
+
+#[rstest]
+fn test_compute_node_au() {
+    let factors = ChargeFactors {
+        cpu: 10.0,
+        gpu: 100.0,
+    };
+    let nodes = vec![
+        // One worker per node on two nodes of the same allocation
+        compute_node("x1000c0s0b0n0", "1001", 3600.0, 0),
+        compute_node("x1000c0s0b0n1", "1001", 1800.0, 0),
+        compute_node("x3100c0s0b0n0", "1002", 900.0, 4),
+    ];
+    let costs = compute_node_au(&nodes, &factors);
+    assert_eq!(costs.len(), 2);
+    assert_eq!(costs["1001"], 20.0);
+    assert_eq!(costs["1002"], 25.0);
+}
+
+#[rstest]
+fn test_au_usage_and_budget() {
+    let allocation = |status: &str, estimated_au: f64, actual_au: Option<f64>| AllocationCost {
+        hpc_job_id: "1".to_string(),
+        scheduler_config_id: 1,
+        status: status.to_string(),
+        estimated_au,
+        actual_au,
+    };
+    let allocations = vec![
+        // Completed allocations count with their actual cost.
+        allocation("complete", 40.0, Some(12.5)),
+        // Running and pending allocations count with their full estimate.
+        allocation("active", 40.0, Some(5.0)),
+        allocation("pending", 40.0, None),
+    ];
+    let usage = AuUsage::from_allocations(&allocations);
+    assert_eq!(usage.num_allocations, 3);
+    assert_eq!(usage.consumed_au, 17.5);
+    assert_eq!(usage.committed_au, 92.5);
+
+    assert!(check_au_budget(None, usage.committed_au, 1000.0).is_ok());
+    assert!(check_au_budget(Some(100.0), usage.committed_au, 7.5).is_ok());
+    let err = check_au_budget(Some(100.0), usage.committed_au, 40.0).unwrap_err();
+    assert!(err.contains("exceed the workflow's AU budget"), "{}", err);
+}
@@ -0,0 +1,265 @@
+mod common;
+
+use common::{ServerProcess, create_test_workflow, run_jobs_cli_command, start_server};
+use rstest::rstest;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use torc::client::{apis::default_api, workflow_manager::WorkflowManager};
+use torc::config::TorcConfig;
+use torc::models::{self, JobStatus};
+
+fn create_conditions_spec(work_dir: &Path, workflow_name: &str) -> String {
+    fs::write(work_dir.join("present.txt"), "present").expect("Failed to write file");
+    let yaml_content = format!(
+        r#"name: {workflow_name}
+user: test_user
+description: Test run_if conditions
+
+user_data:
+  - name: settings
+    data:
+      mode: quick
+      retries: 2
+
+jobs:
+  - name: prep
+    command: echo "prep"
+
+  - name: rerun_prep
+    command: echo "rerun prep"
+    run_if: exit_code("prep") != 0
+
+  - name: after_rerun
+    command: echo "after rerun"
+    depends_on:
+      - rerun_prep
+
+  - name: after_skip
+    command: echo "after skip"
+    run_if: status("rerun_prep") == "skipped"
+
+  - name: quick_mode
+    command: echo "quick mode"
+    run_if: user_data("settings").mode == "quick" && user_data("settings").retries >= 2
+
+  - name: full_mode
+    command: echo "full mode"
+    run_if: user_data("settings").mode == "full"
+
+  - name: file_present
+    command: echo "file present"
+    working_dir: {work_dir}
+    run_if: file_exists("present.txt") && !file_exists("absent.txt")
+
+  - name: bad_condition
+    command: echo "bad condition"
+    run_if: user_data("settings").mode > 3
+"#,
+        work_dir = work_dir.display()
+    );
+    let yaml_path = work_dir.join(format!("{}.yaml", workflow_name));
+    fs::write(&yaml_path, yaml_content).expect("Failed to write YAML file");
+    yaml_path.to_str().unwrap().to_string()
+}
+
+fn get_workflow_id(config: &torc::client::Configuration, workflow_name: &str) -> i64 {
+    let workflows = default_api::list_workflows(
+        config,
+        None,
+        None,
+        None,
+        None,
+        Some(workflow_name),
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list workflows");
+    workflows
+        .items
+        .as_ref()
+        .and_then(|items| items.first())
+        .and_then(|w| w.id)
+        .expect("Workflow not found")
+}
+
+fn get_job_statuses(
+    config: &torc::client::Configuration,
+    workflow_id: i64,
+) -> HashMap<String, JobStatus> {
+    let jobs = default_api::list_jobs(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list jobs");
+    jobs.items
+        .unwrap()
+        .into_iter()
+        .map(|job| (job.name, job.status.unwrap()))
+        .collect()
+}
+
+#[rstest]
+#[case::resources(None)]
+#[case::parallelism(Some(4))]
+fn test_run_if_conditions(start_server: &ServerProcess, #[case] max_parallel_jobs: Option<i64>) {
+    let config = &start_server.config;
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let work_dir = temp_dir.path();
+    let workflow_name = format!(
+        "run_if_conditions_{}",
+        max_parallel_jobs.map_or("resources".to_string(), |n| n.to_string())
+    );
+    let spec_path = create_conditions_spec(work_dir, &workflow_name);
+
+    let mut args = vec![
+        spec_path,
+        "--output-dir".to_string(),
+        work_dir.to_str().unwrap().to_string(),
+        "--poll-interval".to_string(),
+        "0.1".to_string(),
+    ];
+    if let Some(max_jobs) = max_parallel_jobs {
+        args.push("--max-parallel-jobs".to_string());
+        args.push(max_jobs.to_string());
+    }
+    let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    run_jobs_cli_command(&args_refs, start_server).expect("Failed to run jobs");
+
+    let workflow_id = get_workflow_id(config, &workflow_name);
+    let statuses = get_job_statuses(config, workflow_id);
+
+    assert_eq!(statuses["prep"], JobStatus::Completed);
+    assert_eq!(statuses["rerun_prep"], JobStatus::Skipped);
+    assert_eq!(statuses["after_rerun"], JobStatus::Completed);
+    assert_eq!(statuses["after_skip"], JobStatus::Completed);
+    assert_eq!(statuses["quick_mode"], JobStatus::Completed);
+    assert_eq!(statuses["full_mode"], JobStatus::Skipped);
+    assert_eq!(statuses["file_present"], JobStatus::Completed);
+    assert_eq!(statuses["bad_condition"], JobStatus::Failed);
+
+    let completion = default_api::is_workflow_complete(config, workflow_id)
+        .expect("Failed to check workflow completion");
+    assert!(completion.is_complete);
+}
+
+#[rstest]
+fn test_run_if_adds_dependencies(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let workflow_name = "run_if_dependencies";
+    let spec_path = create_conditions_spec(temp_dir.path(), workflow_name);
+
+    let workflow_id = torc::client::workflow_spec::WorkflowSpec::create_workflow_from_spec(
+        config,
+        &spec_path,
+        "test_user",
+        false,
+        false,
+    )
+    .expect("Failed to create workflow");
+
+    let jobs = default_api::list_jobs(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list jobs")
+    .items
+    .unwrap();
+    let ids: HashMap<String, i64> = jobs
+        .iter()
+        .map(|job| (job.name.clone(), job.id.unwrap()))
+        .collect();
+
+    let rerun_prep = default_api::get_job(config, ids["rerun_prep"]).expect("Failed to get job");
+    assert_eq!(rerun_prep.depends_on_job_ids, Some(vec![ids["prep"]]));
+    assert_eq!(
+        rerun_prep.run_if.as_deref(),
+        Some(r#"exit_code("prep") != 0"#)
+    );
+    let after_skip = default_api::get_job(config, ids["after_skip"]).expect("Failed to get job");
+    assert_eq!(after_skip.depends_on_job_ids, Some(vec![ids["rerun_prep"]]));
+    let quick_mode = default_api::get_job(config, ids["quick_mode"]).expect("Failed to get job");
+    assert_eq!(quick_mode.input_user_data_ids.map(|ids| ids.len()), Some(1));
+}
+
+#[rstest]
+fn test_complete_job_skipped_unblocks_dependents(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let workflow = create_test_workflow(config, "test_complete_job_skipped");
+    let workflow_id = workflow.id.unwrap();
+
+    let mut job1 = models::JobModel::new(workflow_id, "job1".to_string(), "echo 1".to_string());
+    job1.run_if = Some("false".to_string());
+    let job1_id = default_api::create_job(config, job1)
+        .expect("Failed to create job1")
+        .id
+        .unwrap();
+    let mut job2 = models::JobModel::new(workflow_id, "job2".to_string(), "echo 2".to_string());
+    job2.depends_on_job_ids = Some(vec![job1_id]);
+    let job2_id = default_api::create_job(config, job2)
+        .expect("Failed to create job2")
+        .id
+        .unwrap();
+
+    let torc_config = TorcConfig::load().unwrap_or_default();
+    let manager = WorkflowManager::new(config.clone(), torc_config, workflow);
+    manager.initialize(true).expect("Failed to start workflow");
+    assert_eq!(
+        default_api::get_job(config, job2_id).unwrap().status,
+        Some(JobStatus::Blocked)
+    );
+
+    let result = models::ResultModel::new(
+        job1_id,
+        workflow_id,
+        1, // run_id
+        1, // attempt_id
+        1, // compute_node_id
+        0, // return_code
+        0.0,
+        chrono::Utc::now().to_rfc3339(),
+        JobStatus::Skipped,
+    );
+    default_api::complete_job(config, job1_id, JobStatus::Skipped, 1, result)
+        .expect("Failed to complete job1");
+    assert_eq!(
+        default_api::get_job(config, job1_id).unwrap().status,
+        Some(JobStatus::Skipped)
+    );
+
+    // Dependents are unblocked by a background task on the server.
+    let start = Instant::now();
+    loop {
+        let status = default_api::get_job(config, job2_id).unwrap().status;
+        if status == Some(JobStatus::Ready) {
+            break;
+        }
+        assert!(
+            start.elapsed() < Duration::from_secs(10),
+            "job2 was not unblocked by the skipped job: {:?}",
+            status
+        );
+        std::thread::sleep(Duration::from_millis(100));
+    }
+}
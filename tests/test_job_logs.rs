@@ -0,0 +1,334 @@
+mod common;
+
+use common::{
+    AccessControlServerProcess, ServerProcess, get_exe_path, start_server,
+    start_server_with_access_control,
+};
+use reqwest::StatusCode;
+use reqwest::blocking::Client;
+use rstest::rstest;
+use std::process::Command;
+use torc::client::{Configuration, default_api};
+use torc::models;
+
+fn create_workflow_with_job(config: &Configuration, name: &str, user: &str) -> (i64, i64) {
+    let workflow = models::WorkflowModel::new(name.to_string(), user.to_string());
+    let workflow_id = default_api::create_workflow(config, workflow)
+        .expect("Failed to create workflow")
+        .id
+        .unwrap();
+    let job = models::JobModel::new(workflow_id, "work".to_string(), "echo hello".to_string());
+    let job_id = default_api::create_job(config, job)
+        .expect("Failed to create job")
+        .id
+        .unwrap();
+    (workflow_id, job_id)
+}
+
+fn create_job_log(
+    config: &Configuration,
+    workflow_id: i64,
+    job_id: i64,
+    attempt_id: i64,
+    stream: models::JobLogStream,
+    content: &str,
+) -> models::JobLogModel {
+    let mut job_log = models::JobLogModel::new(workflow_id, job_id, 1, stream, content.to_string());
+    job_log.attempt_id = Some(attempt_id);
+    default_api::create_job_log(config, job_log).expect("Failed to create job log")
+}
+
+#[rstest]
+fn test_create_get_and_list_job_logs(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let (workflow_id, job_id) = create_workflow_with_job(config, "job_logs_api", "test_user");
+
+    let stdout = create_job_log(
+        config,
+        workflow_id,
+        job_id,
+        1,
+        models::JobLogStream::Stdout,
+        "hello\n",
+    );
+    assert_eq!(stdout.content, "hello\n");
+    assert_eq!(stdout.attempt_id, Some(1));
+    assert_eq!(stdout.size_bytes, Some(6));
+    assert_eq!(stdout.is_truncated, Some(false));
+    assert!(stdout.timestamp.is_some());
+    create_job_log(
+        config,
+        workflow_id,
+        job_id,
+        1,
+        models::JobLogStream::Stderr,
+        "warning\n",
+    );
+    create_job_log(
+        config,
+        workflow_id,
+        job_id,
+        2,
+        models::JobLogStream::Stdout,
+        "retry\n",
+    );
+
+    // A second upload of the same attempt and stream replaces the first.
+    let replaced = create_job_log(
+        config,
+        workflow_id,
+        job_id,
+        1,
+        models::JobLogStream::Stdout,
+        "hello again\n",
+    );
+    assert_eq!(replaced.id, stdout.id);
+    let fetched = default_api::get_job_log(config, stdout.id.unwrap()).expect("Failed to get log");
+    assert_eq!(fetched.content, "hello again\n");
+    assert_eq!(fetched.stream, models::JobLogStream::Stdout);
+
+    let all = default_api::list_job_logs(config, workflow_id, None, None, None, None, None, None)
+        .expect("Failed to list job logs");
+    assert_eq!(all.total_count, 3);
+    assert!(!all.has_more);
+    // Lists leave out the content, which is fetched per log.
+    assert!(all.items.unwrap().iter().all(|log| log.content.is_empty()));
+
+    let stdout_logs = default_api::list_job_logs(
+        config,
+        workflow_id,
+        Some(job_id),
+        Some(1),
+        None,
+        Some(models::JobLogStream::Stdout),
+        None,
+        None,
+    )
+    .expect("Failed to list job logs");
+    let attempts: Vec<i64> = stdout_logs
+        .items
+        .unwrap()
+        .iter()
+        .map(|log| log.attempt_id.unwrap())
+        .collect();
+    assert_eq!(attempts, vec![1, 2]);
+
+    let page = default_api::list_job_logs(
+        config,
+        workflow_id,
+        None,
+        None,
+        Some(1),
+        None,
+        Some(1),
+        Some(1),
+    )
+    .expect("Failed to list job logs");
+    assert_eq!(page.count, 1);
+    assert_eq!(page.total_count, 2);
+    assert!(!page.has_more);
+
+    let content = default_api::get_job_log_content(config, stdout.id.unwrap())
+        .expect("Failed to get log content");
+    assert_eq!(content, "hello again\n");
+}
+
+#[rstest]
+fn test_job_log_errors(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let (workflow_id, _) = create_workflow_with_job(config, "job_logs_errors_1", "test_user");
+    let (_, other_job_id) = create_workflow_with_job(config, "job_logs_errors_2", "test_user");
+
+    // The job must belong to the workflow of the log.
+    let job_log = models::JobLogModel::new(
+        workflow_id,
+        other_job_id,
+        1,
+        models::JobLogStream::Stdout,
+        "hello\n".to_string(),
+    );
+    assert!(default_api::create_job_log(config, job_log).is_err());
+
+    // Content above the server limit (10 MiB by default) is rejected.
+    let (workflow_id, job_id) = create_workflow_with_job(config, "job_logs_errors_3", "test_user");
+    let job_log = models::JobLogModel::new(
+        workflow_id,
+        job_id,
+        1,
+        models::JobLogStream::Stdout,
+        "x".repeat(10 * 1024 * 1024 + 1),
+    );
+    let response = Client::new()
+        .post(format!("{}/job_logs", config.base_path))
+        .json(&job_log)
+        .send()
+        .expect("Failed to upload job log");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    assert!(default_api::get_job_log(config, 999_999_999).is_err());
+    let response = Client::new()
+        .get(format!("{}/job_logs/999999999/content", config.base_path))
+        .send()
+        .expect("Failed to request log content");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[rstest]
+fn test_job_logs_with_access_control(
+    start_server_with_access_control: &AccessControlServerProcess,
+) {
+    let config = &start_server_with_access_control.config;
+    let (workflow_id, job_id) = create_workflow_with_job(config, "job_logs_access", "alice");
+    let job_log = create_job_log(
+        config,
+        workflow_id,
+        job_id,
+        1,
+        models::JobLogStream::Stdout,
+        "secret\n",
+    );
+
+    let content = default_api::get_job_log_content(config, job_log.id.unwrap())
+        .expect("Owner should read the log");
+    assert_eq!(content, "secret\n");
+
+    let mut other_config = config.clone();
+    other_config.basic_auth = Some(("carol".to_string(), Some("password".to_string())));
+    assert!(default_api::get_job_log(&other_config, job_log.id.unwrap()).is_err());
+    assert!(
+        default_api::list_job_logs(
+            &other_config,
+            workflow_id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None
+        )
+        .is_err()
+    );
+    let response = Client::new()
+        .get(format!(
+            "{}/job_logs/{}/content",
+            config.base_path,
+            job_log.id.unwrap()
+        ))
+        .basic_auth("carol", Some("password"))
+        .send()
+        .expect("Failed to request log content");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+/// Run a workflow spec with the job runner, with log upload enabled through the environment.
+fn run_with_log_upload(server: &ServerProcess, spec_path: &str, output_dir: &str, max_bytes: &str) {
+    let mut cmd = Command::new(get_exe_path("./target/debug/torc"));
+    cmd.args([
+        "--url",
+        &server.config.base_path,
+        "run",
+        spec_path,
+        "--output-dir",
+        output_dir,
+        "--poll-interval",
+        "0.1",
+    ]);
+    cmd.env("TORC_CLIENT__RUN__UPLOAD_LOGS", "true");
+    cmd.env("TORC_CLIENT__RUN__UPLOAD_LOGS_MAX_BYTES", max_bytes);
+    let output = cmd.output().expect("Failed to run torc");
+    assert!(
+        output.status.success(),
+        "torc run failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn find_workflow_id(config: &Configuration, name: &str) -> i64 {
+    default_api::list_workflows(config, None, None, None, None, Some(name), None, None, None)
+        .expect("Failed to list workflows")
+        .items
+        .unwrap()
+        .into_iter()
+        .find(|w| w.name == name)
+        .expect("Workflow not found")
+        .id
+        .unwrap()
+}
+
+#[rstest]
+fn test_job_runner_uploads_logs(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let spec_path = temp_dir.path().join("workflow.yaml");
+    std::fs::write(
+        &spec_path,
+        r#"name: job_logs_upload
+jobs:
+  - name: short
+    command: "echo out; echo err >&2"
+  - name: long
+    command: "for i in $(seq 1 200); do echo line_$i; done"
+"#,
+    )
+    .expect("Failed to write workflow spec");
+    run_with_log_upload(
+        start_server,
+        spec_path.to_str().unwrap(),
+        temp_dir.path().to_str().unwrap(),
+        "100",
+    );
+
+    let workflow_id = find_workflow_id(config, "job_logs_upload");
+    let logs = default_api::list_job_logs(config, workflow_id, None, None, None, None, None, None)
+        .expect("Failed to list job logs")
+        .items
+        .unwrap();
+    let jobs = default_api::list_jobs(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list jobs")
+    .items
+    .unwrap();
+    let job_id = |name: &str| {
+        jobs.iter()
+            .find(|job| job.name == name)
+            .and_then(|job| job.id)
+            .unwrap()
+    };
+    let find_log = |name: &str, stream: models::JobLogStream| {
+        logs.iter()
+            .find(|log| log.job_id == job_id(name) && log.stream == stream)
+            .unwrap_or_else(|| panic!("Missing {} log of job {}", stream, name))
+    };
+    let content = |log: &models::JobLogModel| {
+        default_api::get_job_log_content(config, log.id.unwrap())
+            .expect("Failed to get log content")
+    };
+
+    let short_stdout = find_log("short", models::JobLogStream::Stdout);
+    assert_eq!(content(short_stdout), "out\n");
+    assert_eq!(short_stdout.is_truncated, Some(false));
+    assert_eq!(
+        content(find_log("short", models::JobLogStream::Stderr)),
+        "err\n"
+    );
+
+    // The long log is cut to its last full lines.
+    let long_stdout = find_log("long", models::JobLogStream::Stdout);
+    assert_eq!(long_stdout.is_truncated, Some(true));
+    assert!(long_stdout.size_bytes.unwrap() > 100);
+    let long_content = content(long_stdout);
+    assert!(long_content.len() <= 100);
+    assert!(long_content.starts_with("line_"));
+    assert!(long_content.ends_with("line_200\n"));
+}
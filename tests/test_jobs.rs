@@ -1107,8 +1107,8 @@ fn test_retry_job_from_running_status(start_server: &ServerProcess) {
     // Call retry_job while job is still in Running status
     // This simulates the job runner detecting a failure and wanting to retry
     // before it has called complete_job
-    let retried_job =
-        default_api::retry_job(config, job_id, run_id, 3).expect("retry_job should succeed");
+    let retried_job = default_api::retry_job(config, job_id, run_id, 3, None, None)
+        .expect("retry_job should succeed");
 
     // Verify job is now Ready with incremented attempt_id
     assert_eq!(retried_job.status.unwrap(), JobStatus::Ready);
@@ -1162,14 +1162,174 @@ fn test_retry_job_from_failed_status(start_server: &ServerProcess) {
     assert_eq!(job_before.status.unwrap(), JobStatus::Failed);
 
     // Call retry_job
-    let retried_job =
-        default_api::retry_job(config, job_id, run_id, 3).expect("retry_job should succeed");
+    let retried_job = default_api::retry_job(config, job_id, run_id, 3, None, None)
+        .expect("retry_job should succeed");
 
     // Verify job is now Ready with incremented attempt_id
     assert_eq!(retried_job.status.unwrap(), JobStatus::Ready);
     assert_eq!(retried_job.attempt_id.unwrap(), 2);
 }
 
+/// Create a workflow with one running job and return (workflow_id, job_id, run_id).
+fn create_running_job(config: &torc::client::Configuration, name: &str) -> (i64, i64, i64) {
+    let workflow = create_test_workflow(config, name);
+    let workflow_id = workflow.id.unwrap();
+    let job = create_test_job(config, workflow_id, "test_retry_job");
+    let job_id = job.id.unwrap();
+    default_api::initialize_jobs(config, workflow_id, Some(false), Some(false), None)
+        .expect("Failed to initialize jobs");
+    let run_id = default_api::get_workflow_status(config, workflow_id)
+        .expect("Failed to get workflow status")
+        .run_id;
+    default_api::manage_status_change(config, job_id, JobStatus::Running, run_id, None)
+        .expect("Failed to set job to Running");
+    (workflow_id, job_id, run_id)
+}
+
+fn claim_next_job_ids(
+    config: &torc::client::Configuration,
+    workflow_id: i64,
+    compute_node_id: i64,
+) -> Vec<i64> {
+    default_api::claim_next_jobs(
+        config,
+        workflow_id,
+        Some(10),
+        Some(json!({ "compute_node_id": compute_node_id })),
+    )
+    .expect("Failed to claim jobs")
+    .jobs
+    .unwrap_or_default()
+    .into_iter()
+    .map(|job| job.id.unwrap())
+    .collect()
+}
+
+/// Test that a retry delay keeps the job from being claimed until the delay has passed.
+#[rstest]
+fn test_retry_job_with_delay(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let (workflow_id, job_id, run_id) = create_running_job(config, "test_retry_delay_workflow");
+    let compute_node_id = create_test_compute_node(config, workflow_id).id.unwrap();
+
+    let retried_job = default_api::retry_job(config, job_id, run_id, 3, Some(1.5), None)
+        .expect("retry_job should succeed");
+    assert_eq!(retried_job.status.unwrap(), JobStatus::Ready);
+    assert!(retried_job.not_before.is_some());
+    assert_eq!(
+        default_api::get_job(config, job_id).unwrap().not_before,
+        retried_job.not_before
+    );
+
+    assert!(claim_next_job_ids(config, workflow_id, compute_node_id).is_empty());
+    let resources = models::ComputeNodesResources::new(8, 16.0, 0, 1);
+    let claimed =
+        default_api::claim_jobs_based_on_resources(config, workflow_id, &resources, 10, None, None)
+            .expect("Failed to claim jobs");
+    assert!(claimed.jobs.unwrap_or_default().is_empty());
+
+    std::thread::sleep(std::time::Duration::from_millis(1600));
+    assert_eq!(
+        claim_next_job_ids(config, workflow_id, compute_node_id),
+        vec![job_id]
+    );
+}
+
+/// Test that a retry that avoids the failed node is only claimed by workers on other hosts.
+#[rstest]
+fn test_retry_job_avoids_failed_node(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let (workflow_id, job_id, run_id) = create_running_job(config, "test_retry_avoid_workflow");
+    let failed_node_id = create_test_compute_node(config, workflow_id).id.unwrap();
+    let same_host_node_id = create_test_compute_node(config, workflow_id).id.unwrap();
+    let other_node = models::ComputeNodeModel::new(
+        workflow_id,
+        "other-host".to_string(),
+        std::process::id() as i64,
+        chrono::Utc::now().to_rfc3339(),
+        8,
+        16.0,
+        0,
+        1,
+        "local".to_string(),
+        None,
+    );
+    let other_node_id = default_api::create_compute_node(config, other_node)
+        .expect("Failed to create compute node")
+        .id
+        .unwrap();
+
+    let retried_job = default_api::retry_job(config, job_id, run_id, 3, None, Some(failed_node_id))
+        .expect("retry_job should succeed");
+    assert_eq!(retried_job.excluded_hostname.as_deref(), Some("test-host"));
+    assert!(retried_job.not_before.is_none());
+
+    // Workers on the failed host do not claim the job, even through a new compute node.
+    assert!(claim_next_job_ids(config, workflow_id, failed_node_id).is_empty());
+    let mut resources = models::ComputeNodesResources::new(8, 16.0, 0, 1);
+    resources.id = Some(same_host_node_id);
+    let claimed =
+        default_api::claim_jobs_based_on_resources(config, workflow_id, &resources, 10, None, None)
+            .expect("Failed to claim jobs");
+    assert!(claimed.jobs.unwrap_or_default().is_empty());
+
+    resources.id = Some(other_node_id);
+    let claimed =
+        default_api::claim_jobs_based_on_resources(config, workflow_id, &resources, 10, None, None)
+            .expect("Failed to claim jobs");
+    let claimed_ids: Vec<i64> = claimed
+        .jobs
+        .unwrap_or_default()
+        .into_iter()
+        .map(|job| job.id.unwrap())
+        .collect();
+    assert_eq!(claimed_ids, vec![job_id]);
+}
+
+/// Test that resetting a workflow clears the delay and excluded host of an earlier retry, so the
+/// job can be claimed right away on the host where it failed.
+#[rstest]
+fn test_retry_holds_cleared_on_reset(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let (workflow_id, job_id, run_id) = create_running_job(config, "test_retry_reset_workflow");
+    let failed_node_id = create_test_compute_node(config, workflow_id).id.unwrap();
+
+    let retried_job = default_api::retry_job(
+        config,
+        job_id,
+        run_id,
+        3,
+        Some(3600.0),
+        Some(failed_node_id),
+    )
+    .expect("retry_job should succeed");
+    assert!(retried_job.not_before.is_some());
+    assert!(retried_job.excluded_hostname.is_some());
+    assert!(claim_next_job_ids(config, workflow_id, failed_node_id).is_empty());
+
+    default_api::reset_job_status(config, workflow_id, Some(false), None)
+        .expect("Failed to reset job status");
+    let job = default_api::get_job(config, job_id).expect("Failed to get job");
+    assert!(job.not_before.is_none());
+    assert!(job.excluded_hostname.is_none());
+
+    default_api::initialize_jobs(config, workflow_id, Some(true), Some(false), None)
+        .expect("Failed to reinitialize jobs");
+    assert_eq!(
+        claim_next_job_ids(config, workflow_id, failed_node_id),
+        vec![job_id]
+    );
+}
+
+/// Test that retry_job rejects an invalid delay.
+#[rstest]
+fn test_retry_job_invalid_delay(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let (_, job_id, run_id) = create_running_job(config, "test_retry_invalid_delay_workflow");
+    let result = default_api::retry_job(config, job_id, run_id, 3, Some(-1.0), None);
+    assert!(result.is_err(), "retry_job should reject a negative delay");
+}
+
 /// Test that retry_job rejects jobs in invalid states (e.g., Ready, Completed).
 #[rstest]
 fn test_retry_job_invalid_status(start_server: &ServerProcess) {
@@ -1195,7 +1355,7 @@ fn test_retry_job_invalid_status(start_server: &ServerProcess) {
     assert_eq!(job_before.status.unwrap(), JobStatus::Ready);
 
     // Try to retry a Ready job - should fail
-    let result = default_api::retry_job(config, job_id, run_id, 3);
+    let result = default_api::retry_job(config, job_id, run_id, 3, None, None);
     assert!(
         result.is_err(),
         "retry_job should fail for job in Ready status"
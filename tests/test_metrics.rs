@@ -0,0 +1,232 @@
+mod common;
+
+use common::{
+    AccessControlServerProcess, ServerProcess, create_test_compute_node, start_server,
+    start_server_with_access_control,
+};
+use reqwest::StatusCode;
+use reqwest::blocking::{Client, RequestBuilder};
+use rstest::rstest;
+use std::thread;
+use std::time::{Duration, Instant};
+use torc::client::{Configuration, default_api};
+use torc::models;
+
+fn metrics_request(server: &ServerProcess) -> RequestBuilder {
+    Client::new().get(format!("http://localhost:{}/metrics", server.port))
+}
+
+fn get_metrics(server: &ServerProcess) -> String {
+    let response = metrics_request(server)
+        .send()
+        .expect("Failed to request metrics");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("")
+            .starts_with("text/plain; version=0.0.4")
+    );
+    response.text().expect("Failed to read metrics")
+}
+
+/// Return the value of the sample with exactly this name and label set.
+fn sample_value(metrics: &str, series: &str) -> Option<f64> {
+    metrics.lines().find_map(|line| {
+        let value = line.strip_prefix(series)?.strip_prefix(' ')?;
+        value.parse().ok()
+    })
+}
+
+/// Poll the metrics until `series` satisfies `condition` and return its value.
+fn wait_for_sample(server: &ServerProcess, series: &str, condition: impl Fn(f64) -> bool) -> f64 {
+    let start = Instant::now();
+    loop {
+        if let Some(value) = sample_value(&get_metrics(server), series)
+            && condition(value)
+        {
+            return value;
+        }
+        assert!(
+            start.elapsed() < Duration::from_secs(10),
+            "Timed out waiting for metric {}",
+            series
+        );
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
+fn complete_job(config: &Configuration, workflow_id: i64, job_id: i64) {
+    let run_id = default_api::get_workflow_status(config, workflow_id)
+        .expect("Failed to get workflow status")
+        .run_id;
+    let compute_node_id = create_test_compute_node(config, workflow_id).id.unwrap();
+    default_api::manage_status_change(config, job_id, models::JobStatus::Running, run_id, None)
+        .expect("Failed to set job to running");
+    let result = models::ResultModel::new(
+        job_id,
+        workflow_id,
+        run_id,
+        1,
+        compute_node_id,
+        0,
+        1.0,
+        chrono::Utc::now().to_rfc3339(),
+        models::JobStatus::Completed,
+    );
+    default_api::complete_job(config, job_id, models::JobStatus::Completed, run_id, result)
+        .expect("Failed to complete job");
+}
+
+#[rstest]
+fn test_metrics_requests_and_job_counts(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let workflow =
+        models::WorkflowModel::new("metrics_workflow".to_string(), "test_user".to_string());
+    let workflow_id = default_api::create_workflow(config, workflow)
+        .expect("Failed to create workflow")
+        .id
+        .unwrap();
+    let mut job_ids: Vec<i64> = Vec::new();
+    for name in ["first", "second", "third"] {
+        let mut job =
+            models::JobModel::new(workflow_id, name.to_string(), "echo hello".to_string());
+        if name == "second" {
+            job.depends_on_job_ids = Some(vec![job_ids[0]]);
+        }
+        job_ids.push(
+            default_api::create_job(config, job)
+                .expect("Failed to create job")
+                .id
+                .unwrap(),
+        );
+    }
+    default_api::initialize_jobs(config, workflow_id, None, None, None)
+        .expect("Failed to initialize jobs");
+    assert!(default_api::get_job(config, 999_999_999).is_err());
+
+    let metrics = get_metrics(start_server);
+    assert!(
+        sample_value(
+            &metrics,
+            r#"torc_http_requests_total{operation="CreateJob",status="200"}"#
+        )
+        .unwrap()
+            >= 3.0
+    );
+    assert!(
+        sample_value(
+            &metrics,
+            r#"torc_http_requests_total{operation="GetJob",status="404"}"#
+        )
+        .unwrap()
+            >= 1.0
+    );
+    assert!(
+        sample_value(
+            &metrics,
+            r#"torc_http_request_duration_seconds_count{operation="InitializeJobs"}"#
+        )
+        .unwrap()
+            >= 1.0
+    );
+    assert!(metrics.contains("# TYPE torc_http_request_duration_seconds histogram"));
+    assert!(metrics.contains("torc_database_lock_retries_total "));
+    // The metrics endpoint itself is not an API operation.
+    assert!(!metrics.contains("operation=\"Metrics\""));
+
+    let jobs_series = |status: &str| {
+        format!(
+            r#"torc_workflow_jobs{{workflow_id="{}",status="{}"}}"#,
+            workflow_id, status
+        )
+    };
+    assert_eq!(sample_value(&metrics, &jobs_series("ready")), Some(2.0));
+    assert_eq!(sample_value(&metrics, &jobs_series("blocked")), Some(1.0));
+    // Statuses without jobs have no series.
+    assert_eq!(sample_value(&metrics, &jobs_series("completed")), None);
+
+    // Completing the first job lets the background task unblock the second one.
+    let unblock_passes = sample_value(&metrics, "torc_unblock_task_duration_seconds_count")
+        .expect("Missing unblock task histogram");
+    complete_job(config, workflow_id, job_ids[0]);
+    wait_for_sample(
+        start_server,
+        "torc_unblock_task_duration_seconds_count",
+        |v| v > unblock_passes,
+    );
+    wait_for_sample(start_server, &jobs_series("ready"), |v| v == 2.0);
+    let metrics = get_metrics(start_server);
+    assert_eq!(sample_value(&metrics, &jobs_series("completed")), Some(1.0));
+    assert_eq!(sample_value(&metrics, &jobs_series("blocked")), None);
+    assert!(sample_value(&metrics, "torc_unblock_delay_seconds_count").unwrap() >= 1.0);
+
+    // Archived workflows are not counted.
+    let mut status = default_api::get_workflow_status(config, workflow_id)
+        .expect("Failed to get workflow status");
+    status.is_archived = Some(true);
+    default_api::update_workflow_status(config, workflow_id, status)
+        .expect("Failed to archive workflow");
+    let metrics = get_metrics(start_server);
+    assert!(!metrics.contains(&format!(r#"workflow_id="{}""#, workflow_id)));
+}
+
+#[rstest]
+fn test_metrics_sse_subscribers(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let workflow = models::WorkflowModel::new("metrics_sse".to_string(), "test_user".to_string());
+    let workflow_id = default_api::create_workflow(config, workflow)
+        .expect("Failed to create workflow")
+        .id
+        .unwrap();
+    assert_eq!(
+        sample_value(&get_metrics(start_server), "torc_sse_subscribers"),
+        Some(0.0)
+    );
+
+    let stream = Client::new()
+        .get(format!(
+            "{}/workflows/{}/events/stream",
+            config.base_path, workflow_id
+        ))
+        .send()
+        .expect("Failed to open event stream");
+    assert_eq!(stream.status(), StatusCode::OK);
+    wait_for_sample(start_server, "torc_sse_subscribers", |v| v == 1.0);
+
+    // Closing the connection disconnects the subscriber.
+    drop(stream);
+    wait_for_sample(start_server, "torc_sse_subscribers", |v| v == 0.0);
+}
+
+#[rstest]
+fn test_metrics_require_admin_with_access_control(
+    start_server_with_access_control: &AccessControlServerProcess,
+) {
+    let server = &start_server_with_access_control.server;
+
+    let response = metrics_request(server)
+        .basic_auth("alice", Some("password"))
+        .send()
+        .expect("Failed to request metrics");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        response
+            .text()
+            .unwrap()
+            .contains("torc_http_requests_total")
+    );
+
+    let response = metrics_request(server)
+        .basic_auth("carol", Some("password"))
+        .send()
+        .expect("Failed to request metrics");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = metrics_request(server)
+        .send()
+        .expect("Failed to request metrics");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
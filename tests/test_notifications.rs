@@ -0,0 +1,256 @@
+mod common;
+
+use common::{create_test_compute_node, start_server_with_config_file};
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use torc::client::{Configuration, default_api};
+use torc::models;
+
+/// HTTP endpoint that records the JSON bodies POSTed to it. The first `failures` requests get
+/// a 500 response; the rest get 200 and are recorded.
+struct WebhookReceiver {
+    url: String,
+    attempts: Arc<AtomicUsize>,
+    bodies: Arc<Mutex<Vec<Value>>>,
+}
+
+impl WebhookReceiver {
+    fn start(failures: usize) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind webhook receiver");
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let bodies = Arc::new(Mutex::new(Vec::new()));
+        let (thread_attempts, thread_bodies) = (attempts.clone(), bodies.clone());
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                let body = read_request_body(&stream);
+                let status = if thread_attempts.fetch_add(1, Ordering::SeqCst) < failures {
+                    "500 Internal Server Error"
+                } else {
+                    thread_bodies.lock().unwrap().push(body);
+                    "200 OK"
+                };
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    status
+                );
+            }
+        });
+        Self {
+            url,
+            attempts,
+            bodies,
+        }
+    }
+
+    /// Wait until `count` bodies have been recorded and return them.
+    fn wait_for_bodies(&self, count: usize) -> Vec<Value> {
+        let start = Instant::now();
+        loop {
+            let bodies = self.bodies.lock().unwrap().clone();
+            if bodies.len() >= count {
+                return bodies;
+            }
+            assert!(
+                start.elapsed() < Duration::from_secs(30),
+                "Timed out waiting for {} webhook requests at {}, got {}",
+                count,
+                self.url,
+                bodies.len()
+            );
+            thread::sleep(Duration::from_millis(100));
+        }
+    }
+}
+
+fn read_request_body(stream: &std::net::TcpStream) -> Value {
+    let mut reader = BufReader::new(stream);
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+            break;
+        }
+        if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+            content_length = value.trim().parse().unwrap_or(0);
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader
+        .read_exact(&mut body)
+        .expect("Failed to read request body");
+    serde_json::from_slice(&body).unwrap_or(Value::Null)
+}
+
+fn create_workflow_with_jobs(
+    config: &Configuration,
+    name: &str,
+    num_jobs: usize,
+) -> (i64, Vec<i64>) {
+    let workflow = models::WorkflowModel::new(name.to_string(), "test_user".to_string());
+    let workflow_id = default_api::create_workflow(config, workflow)
+        .expect("Failed to create workflow")
+        .id
+        .unwrap();
+    let job_ids = (0..num_jobs)
+        .map(|i| {
+            let job =
+                models::JobModel::new(workflow_id, format!("job{}", i), "echo hello".to_string());
+            default_api::create_job(config, job)
+                .expect("Failed to create job")
+                .id
+                .unwrap()
+        })
+        .collect();
+    default_api::initialize_jobs(config, workflow_id, None, None, None)
+        .expect("Failed to initialize jobs");
+    (workflow_id, job_ids)
+}
+
+/// Run a job through running to completion with the given return code.
+fn finish_job(config: &Configuration, workflow_id: i64, job_id: i64, return_code: i64) {
+    let run_id = default_api::get_workflow_status(config, workflow_id)
+        .expect("Failed to get workflow status")
+        .run_id;
+    let compute_node_id = create_test_compute_node(config, workflow_id).id.unwrap();
+    let status = if return_code == 0 {
+        models::JobStatus::Completed
+    } else {
+        models::JobStatus::Failed
+    };
+    default_api::manage_status_change(config, job_id, models::JobStatus::Running, run_id, None)
+        .expect("Failed to set job to running");
+    let result = models::ResultModel::new(
+        job_id,
+        workflow_id,
+        run_id,
+        1,
+        compute_node_id,
+        return_code,
+        1.0,
+        chrono::Utc::now().to_rfc3339(),
+        status,
+    );
+    default_api::complete_job(config, job_id, status, run_id, result)
+        .expect("Failed to complete job");
+}
+
+#[test]
+fn test_webhook_and_slack_notifications() {
+    let webhook = WebhookReceiver::start(1);
+    let slack = WebhookReceiver::start(0);
+    let server = start_server_with_config_file(&format!(
+        r#"
+[[server.notifications]]
+name = "completion-webhook"
+type = "webhook"
+url = "{}"
+event_types = ["workflow_completed"]
+retry_delay_secs = 0.1
+
+[[server.notifications]]
+type = "slack"
+url = "{}"
+event_types = ["job_failed"]
+"#,
+        webhook.url, slack.url
+    ));
+    let config = &server.config;
+    let (workflow_id, job_ids) = create_workflow_with_jobs(config, "test_webhook_notifications", 2);
+
+    finish_job(config, workflow_id, job_ids[0], 3);
+    finish_job(config, workflow_id, job_ids[1], 0);
+
+    // The first delivery fails and is retried.
+    let bodies = webhook.wait_for_bodies(1);
+    assert_eq!(webhook.attempts.load(Ordering::SeqCst), 2);
+    assert_eq!(bodies[0]["event_type"], "workflow_completed");
+    assert_eq!(bodies[0]["workflow_id"].as_i64(), Some(workflow_id));
+    assert_eq!(bodies[0]["severity"], "warning");
+    assert_eq!(bodies[0]["data"]["num_failed_jobs"].as_i64(), Some(1));
+
+    let bodies = slack.wait_for_bodies(1);
+    let text = bodies[0]["text"]
+        .as_str()
+        .expect("Slack payload has no text");
+    assert!(text.contains("job_failed"), "{}", text);
+    assert!(text.contains("job0"), "{}", text);
+    assert!(text.contains("return code 3"), "{}", text);
+    thread::sleep(Duration::from_millis(500));
+    assert_eq!(slack.attempts.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_command_notification_filters() {
+    // Fresh databases number workflows from 1, so the sink only sees the second workflow.
+    let server = start_server_with_config_file(
+        r#"
+[[server.notifications]]
+type = "command"
+command = "cat > event_${TORC_WORKFLOW_ID}_$$.json"
+workflow_ids = [2]
+event_types = ["job_failed", "job_completed"]
+min_severity = "error"
+first_per_run = true
+"#,
+    );
+    let config = &server.config;
+    let working_dir = server.working_dir.path();
+    let event_files = |workflow_id: i64| -> Vec<Value> {
+        let mut events: Vec<Value> = std::fs::read_dir(working_dir)
+            .unwrap()
+            .filter_map(|entry| {
+                let path = entry.unwrap().path();
+                let name = path.file_name()?.to_str()?.to_string();
+                if !name.starts_with(&format!("event_{}_", workflow_id)) {
+                    return None;
+                }
+                serde_json::from_str(&std::fs::read_to_string(&path).ok()?).ok()
+            })
+            .collect();
+        events.sort_by_key(|e| e["timestamp"].as_i64());
+        events
+    };
+
+    let (ignored_workflow_id, job_ids) =
+        create_workflow_with_jobs(config, "test_command_ignored", 1);
+    assert_eq!(ignored_workflow_id, 1);
+    finish_job(config, ignored_workflow_id, job_ids[0], 1);
+
+    let (workflow_id, job_ids) = create_workflow_with_jobs(config, "test_command_notified", 4);
+    assert_eq!(workflow_id, 2);
+    // job_completed is below the minimum severity.
+    finish_job(config, workflow_id, job_ids[0], 0);
+    finish_job(config, workflow_id, job_ids[1], 1);
+    // Only the first failure of a run is delivered.
+    finish_job(config, workflow_id, job_ids[2], 1);
+    thread::sleep(Duration::from_secs(2));
+    let events = event_files(workflow_id);
+    assert_eq!(events.len(), 1, "{:?}", events);
+    assert_eq!(events[0]["event_type"], "job_failed");
+    assert_eq!(events[0]["data"]["job_id"].as_i64(), Some(job_ids[1]));
+    assert!(event_files(ignored_workflow_id).is_empty());
+
+    // A new run re-arms the sink.
+    default_api::initialize_jobs(config, workflow_id, None, None, None)
+        .expect("Failed to reinitialize jobs");
+    finish_job(config, workflow_id, job_ids[3], 2);
+    let start = Instant::now();
+    while event_files(workflow_id).len() < 2 {
+        assert!(
+            start.elapsed() < Duration::from_secs(30),
+            "Timed out waiting for the notification of the second run"
+        );
+        thread::sleep(Duration::from_millis(100));
+    }
+    let events = event_files(workflow_id);
+    assert_eq!(events[1]["data"]["job_id"].as_i64(), Some(job_ids[3]));
+    assert_eq!(events[1]["data"]["return_code"].as_i64(), Some(2));
+}
@@ -0,0 +1,428 @@
+mod common;
+
+use common::{ServerProcess, create_test_workflow, start_server};
+use rstest::rstest;
+use serial_test::serial;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use torc::client::commands::orphan_detection::cleanup_orphaned_jobs;
+use torc::client::commands::slurm::schedule_hpc_nodes;
+use torc::client::default_api;
+use torc::client::hpc::common::{HpcJobStatus, HpcType};
+use torc::client::hpc::create_hpc_interface;
+use torc::client::hpc::hpc_interface::HpcInterface;
+use torc::client::hpc::pbs_interface::PbsInterface;
+use torc::models;
+
+#[rstest]
+fn test_create_hpc_interface_pbs() {
+    let interface = create_hpc_interface(HpcType::Pbs);
+    assert!(interface.is_ok(), "Failed to create PBS interface");
+}
+
+#[rstest]
+#[serial]
+fn test_pbs_submit_job_success() {
+    let _fake = FakePbs::new();
+    let interface = PbsInterface::new().expect("Failed to create PbsInterface");
+
+    let script_path = env::temp_dir().join("test_pbs_submit.sh");
+    fs::write(&script_path, "#!/bin/bash\n#PBS -N my_job\necho 'test'\n")
+        .expect("Failed to write test script");
+
+    let (return_code, job_id, stderr) = interface.submit(&script_path).expect("Submit failed");
+    assert_eq!(return_code, 0);
+    assert_eq!(stderr, "");
+    // The server suffix is stripped so that the ID fits in scheduler_id
+    assert_eq!(job_id, "2000");
+}
+
+#[rstest]
+#[serial]
+fn test_pbs_get_status_queued_and_running() {
+    let _fake = FakePbs::new();
+    let interface = PbsInterface::new().expect("Failed to create PbsInterface");
+    let job_id = submit_test_job(&interface, "status_job");
+
+    let info = interface.get_status(&job_id).expect("Failed to get status");
+    assert_eq!(info.job_id, job_id);
+    assert_eq!(info.name, "status_job");
+    assert_eq!(info.status, HpcJobStatus::Queued);
+
+    unsafe {
+        env::set_var("TORC_FAKE_QSTAT_STATE", "R");
+    }
+    let info = interface.get_status(&job_id).expect("Failed to get status");
+    assert_eq!(info.status, HpcJobStatus::Running);
+
+    unsafe {
+        env::set_var("TORC_FAKE_QSTAT_STATE", "F");
+    }
+    let info = interface.get_status(&job_id).expect("Failed to get status");
+    assert_eq!(info.status, HpcJobStatus::Complete);
+}
+
+#[rstest]
+#[serial]
+fn test_pbs_get_status_unknown_job() {
+    let _fake = FakePbs::new();
+    let interface = PbsInterface::new().expect("Failed to create PbsInterface");
+
+    let info = interface.get_status("99999").expect("Failed to get status");
+    assert_eq!(info.status, HpcJobStatus::None);
+}
+
+#[rstest]
+#[serial]
+fn test_pbs_get_statuses() {
+    let _fake = FakePbs::new();
+    let interface = PbsInterface::new().expect("Failed to create PbsInterface");
+    let job1 = submit_test_job(&interface, "job1");
+    let job2 = submit_test_job(&interface, "job2");
+
+    let statuses = interface.get_statuses().expect("Failed to get statuses");
+    assert_eq!(statuses.len(), 2);
+    assert_eq!(statuses.get(&job1), Some(&HpcJobStatus::Queued));
+    assert_eq!(statuses.get(&job2), Some(&HpcJobStatus::Queued));
+}
+
+#[rstest]
+#[serial]
+fn test_pbs_cancel_job() {
+    let _fake = FakePbs::new();
+    let interface = PbsInterface::new().expect("Failed to create PbsInterface");
+    let job_id = submit_test_job(&interface, "cancel_job");
+
+    let ret = interface.cancel_job(&job_id).expect("Failed to cancel job");
+    assert_eq!(ret, 0);
+
+    let info = interface.get_status(&job_id).expect("Failed to get status");
+    assert_eq!(info.status, HpcJobStatus::None);
+}
+
+#[rstest]
+#[serial]
+fn test_pbs_get_job_stats() {
+    let _fake = FakePbs::new();
+    let interface = PbsInterface::new().expect("Failed to create PbsInterface");
+    let job_id = submit_test_job(&interface, "stats_job");
+
+    unsafe {
+        env::set_var("TORC_FAKE_QSTAT_STATE", "F");
+    }
+    let stats = interface
+        .get_job_stats(&job_id)
+        .expect("Failed to get job stats");
+    assert_eq!(stats.hpc_job_id, job_id);
+    assert_eq!(stats.name, "stats_job");
+    assert_eq!(stats.state, "F");
+    assert_eq!(stats.account, "test_account");
+    assert_eq!(stats.partition, "workq");
+    assert!(stats.end.is_some(), "Finished jobs should have an end time");
+}
+
+#[rstest]
+#[serial]
+fn test_pbs_list_active_nodes() {
+    let _fake = FakePbs::new();
+    unsafe {
+        env::remove_var("TORC_FAKE_QSUB");
+    }
+    let interface = PbsInterface::new().expect("Failed to create PbsInterface");
+    let job_id = submit_test_job(&interface, "nodes_job");
+
+    let nodes = interface
+        .list_active_nodes(&job_id)
+        .expect("Failed to list nodes");
+    assert_eq!(nodes, vec!["node001".to_string(), "node002".to_string()]);
+}
+
+#[test]
+fn test_pbs_create_submission_script() {
+    let interface = PbsInterface::new().expect("Failed to create PbsInterface");
+    let script_path = env::temp_dir().join("test_pbs_submission_script.sh");
+
+    let mut config = HashMap::new();
+    config.insert("account".to_string(), "test_account".to_string());
+    config.insert("walltime".to_string(), "1-02:00:00".to_string());
+    config.insert("nodes".to_string(), "2".to_string());
+    config.insert("mem".to_string(), "240G".to_string());
+    config.insert("gres".to_string(), "gpu:4".to_string());
+    config.insert("partition".to_string(), "gpu".to_string());
+    config.insert("qos".to_string(), "high".to_string());
+    config.insert("extra".to_string(), "-l place=excl".to_string());
+
+    interface
+        .create_submission_script(
+            "worker_1",
+            "http://localhost:8080/torc-service/v1",
+            12345,
+            "/tmp/output",
+            5,
+            Some(8),
+            &script_path,
+            &config,
+            false,
+        )
+        .expect("Failed to create submission script");
+
+    let script = fs::read_to_string(&script_path).expect("Failed to read submission script");
+    assert!(script.starts_with("#!/bin/bash\n"));
+    assert!(script.contains("#PBS -A test_account\n"));
+    assert!(script.contains("#PBS -N worker_1\n"));
+    assert!(script.contains("#PBS -l walltime=26:00:00\n"));
+    assert!(script.contains("#PBS -l select=2:mem=240gb:ngpus=4\n"));
+    assert!(script.contains("#PBS -q gpu\n"));
+    assert!(script.contains("#PBS -l place=excl\n"));
+    assert!(!script.contains("qos"), "PBS has no QOS directive");
+    assert!(script.contains("cd \"$PBS_O_WORKDIR\""));
+    assert!(script.contains(
+        "torc-slurm-job-runner $TORC_URL 12345 /tmp/output --hpc-type pbs --poll-interval 5 --max-parallel-jobs 8"
+    ));
+    assert!(!script.contains("pbsdsh"));
+
+    let _ = fs::remove_file(&script_path);
+}
+
+#[test]
+fn test_pbs_create_submission_script_one_worker_per_node() {
+    let interface = PbsInterface::new().expect("Failed to create PbsInterface");
+    let script_path = env::temp_dir().join("test_pbs_submission_script_pbsdsh.sh");
+
+    let mut config = HashMap::new();
+    config.insert("account".to_string(), "test_account".to_string());
+    config.insert("walltime".to_string(), "04:00:00".to_string());
+    config.insert("nodes".to_string(), "4".to_string());
+
+    interface
+        .create_submission_script(
+            "worker_2",
+            "http://localhost:8080/torc-service/v1",
+            1,
+            "output",
+            30,
+            None,
+            &script_path,
+            &config,
+            true,
+        )
+        .expect("Failed to create submission script");
+
+    let script = fs::read_to_string(&script_path).expect("Failed to read submission script");
+    assert!(script.contains("#PBS -l select=4\n"));
+    assert!(script.contains("pbsdsh -u"));
+    assert!(script.contains("--is-subtask --worker-per-node"));
+
+    let _ = fs::remove_file(&script_path);
+}
+
+#[test]
+fn test_pbs_walltime_conversion() {
+    assert_eq!(
+        PbsInterface::to_pbs_walltime("01:00:00").unwrap(),
+        "01:00:00"
+    );
+    assert_eq!(
+        PbsInterface::to_pbs_walltime("1-00:00:00").unwrap(),
+        "24:00:00"
+    );
+    assert_eq!(
+        PbsInterface::to_pbs_walltime("2-12:30:00").unwrap(),
+        "60:30:00"
+    );
+    assert_eq!(PbsInterface::to_pbs_walltime("30:00").unwrap(), "00:30:00");
+    assert_eq!(PbsInterface::to_pbs_walltime("90").unwrap(), "01:30:00");
+    assert!(PbsInterface::to_pbs_walltime("abc").is_err());
+}
+
+#[test]
+fn test_pbs_status_mapping() {
+    assert_eq!(PbsInterface::map_status("Q"), HpcJobStatus::Queued);
+    assert_eq!(PbsInterface::map_status("H"), HpcJobStatus::Queued);
+    assert_eq!(PbsInterface::map_status("R"), HpcJobStatus::Running);
+    assert_eq!(PbsInterface::map_status("E"), HpcJobStatus::Running);
+    assert_eq!(PbsInterface::map_status("F"), HpcJobStatus::Complete);
+    assert_eq!(PbsInterface::map_status("C"), HpcJobStatus::Complete);
+    assert_eq!(PbsInterface::map_status("Z"), HpcJobStatus::Unknown);
+}
+
+#[test]
+fn test_pbs_qstat_parsing() {
+    // Torque `qstat -u` output
+    let output = "\
+server.example.com:
+                                                                         Req'd    Req'd       Elap
+Job ID                  Username    Queue    Jobname          SessID  NDS   TSK   Memory   Time    S   Time
+----------------------- ----------- -------- ---------------- ------ ----- ------ --------- --------- - ---------
+4807.server.example.com user        batch    worker_1          12345     1      4       --   01:00:00 R  00:10:11
+4808.server.example.com user        batch    worker_2             --     1      4       --   01:00:00 Q       --
+";
+    let jobs = PbsInterface::parse_qstat_table(output);
+    assert_eq!(
+        jobs,
+        vec![
+            ("4807".to_string(), "R".to_string()),
+            ("4808".to_string(), "Q".to_string()),
+        ]
+    );
+
+    let full = "Job Id: 4807.server\n    Job_Name = worker_1\n    exec_host = node1/0*8+node2/0*8+\n\tnode3/0*8\n    job_state = R\n";
+    let attributes = PbsInterface::parse_qstat_full(full);
+    assert_eq!(attributes.get("Job_Name").unwrap(), "worker_1");
+    assert_eq!(attributes.get("job_state").unwrap(), "R");
+    assert_eq!(
+        attributes.get("exec_host").unwrap(),
+        "node1/0*8+node2/0*8+node3/0*8"
+    );
+}
+
+#[rstest]
+#[serial]
+fn test_schedule_pbs_nodes_and_cleanup(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let workflow = create_test_workflow(config, "test_schedule_pbs_nodes");
+    let workflow_id = workflow.id.unwrap();
+
+    let scheduler = models::SlurmSchedulerModel {
+        id: None,
+        workflow_id,
+        name: Some("pbs_scheduler".to_string()),
+        account: "test_account".to_string(),
+        gres: None,
+        mem: Some("8G".to_string()),
+        nodes: 1,
+        ntasks_per_node: None,
+        partition: Some("workq".to_string()),
+        qos: None,
+        tmp: None,
+        walltime: "01:00:00".to_string(),
+        extra: None,
+    };
+    let scheduler =
+        default_api::create_slurm_scheduler(config, scheduler).expect("Failed to create scheduler");
+
+    let _fake = FakePbs::new();
+    let output_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    schedule_hpc_nodes(
+        config,
+        HpcType::Pbs,
+        workflow_id,
+        scheduler.id.unwrap(),
+        2,
+        "worker",
+        output_dir.path().to_str().unwrap(),
+        30,
+        None,
+        false,
+        false,
+        None,
+        false,
+    )
+    .expect("Failed to schedule PBS nodes");
+
+    let nodes = default_api::list_scheduled_compute_nodes(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list scheduled compute nodes")
+    .items
+    .unwrap();
+    assert_eq!(nodes.len(), 2);
+    for node in &nodes {
+        assert_eq!(node.scheduler_type, "pbs");
+        assert_eq!(node.status, "pending");
+        assert!(node.scheduler_id >= 2000);
+    }
+
+    // Queued allocations are left alone.
+    let result = cleanup_orphaned_jobs(config, workflow_id, false).expect("Cleanup failed");
+    assert_eq!(result.pending_allocations_cleaned, 0);
+
+    // Once an allocation disappears from qstat, its pending record is cleaned up.
+    let interface = PbsInterface::new().expect("Failed to create PbsInterface");
+    interface
+        .cancel_job(&nodes[0].scheduler_id.to_string())
+        .expect("Failed to cancel job");
+    let result = cleanup_orphaned_jobs(config, workflow_id, false).expect("Cleanup failed");
+    assert_eq!(result.pending_allocations_cleaned, 1);
+}
+
+fn submit_test_job(interface: &PbsInterface, name: &str) -> String {
+    let script_path = env::temp_dir().join(format!("test_pbs_{}.sh", name));
+    fs::write(&script_path, format!("#!/bin/bash\n#PBS -N {}\n", name))
+        .expect("Failed to write test script");
+    let (return_code, job_id, _) = interface.submit(&script_path).expect("Submit failed");
+    assert_eq!(return_code, 0);
+    job_id
+}
+
+/// Puts fake qsub/qstat/qdel commands on PATH for the lifetime of the guard.
+struct FakePbs {
+    _bin_dir: tempfile::TempDir,
+    original_path: String,
+}
+
+impl FakePbs {
+    fn new() -> Self {
+        cleanup_fake_pbs_state();
+        let scripts_dir = env::current_dir()
+            .expect("Failed to get current directory")
+            .join("tests/scripts");
+        let bin_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        for name in ["qsub", "qstat", "qdel"] {
+            let script: PathBuf = scripts_dir.join(format!("fake_{}.sh", name));
+            assert!(script.exists(), "{:?} not found", script);
+            std::os::unix::fs::symlink(&script, bin_dir.path().join(name))
+                .expect("Failed to create symlink");
+        }
+
+        let original_path = env::var("PATH").unwrap_or_default();
+        unsafe {
+            env::set_var(
+                "PATH",
+                format!("{}:{}", bin_dir.path().display(), original_path),
+            );
+            // Skips the real walltime lookup in get_job_end_time
+            env::set_var("TORC_FAKE_QSUB", bin_dir.path().join("qsub"));
+        }
+
+        Self {
+            _bin_dir: bin_dir,
+            original_path,
+        }
+    }
+}
+
+impl Drop for FakePbs {
+    fn drop(&mut self) {
+        unsafe {
+            env::set_var("PATH", &self.original_path);
+        }
+        cleanup_fake_pbs_state();
+    }
+}
+
+fn cleanup_fake_pbs_state() {
+    let tmpdir = env::var("TMPDIR").unwrap_or_else(|_| "/tmp".to_string());
+    let _ = fs::remove_file(format!("{}/fake_qsub_counter.txt", tmpdir));
+    let _ = fs::remove_file(format!("{}/fake_pbs_jobs.txt", tmpdir));
+
+    unsafe {
+        env::remove_var("TORC_FAKE_QSUB");
+        env::remove_var("TORC_FAKE_QSTAT");
+        env::remove_var("TORC_FAKE_QDEL");
+        env::remove_var("TORC_FAKE_QSUB_FAIL");
+        env::remove_var("TORC_FAKE_QSTAT_FAIL");
+        env::remove_var("TORC_FAKE_QDEL_FAIL");
+        env::remove_var("TORC_FAKE_QSTAT_STATE");
+    }
+}
@@ -1,10 +1,15 @@
 mod common;
 
-use common::{ServerProcess, create_test_workflow, run_cli_command, start_server};
+use common::{
+    ServerProcess, create_test_workflow, run_cli_command, run_jobs_cli_command, start_server,
+};
 use rstest::rstest;
+use std::fs;
 use std::io::Write;
 use tempfile::NamedTempFile;
 use torc::client::default_api;
+use torc::client::workflow_spec::WorkflowSpec;
+use torc::models::JobStatus;
 
 // ============================================================================
 // API Tests
@@ -513,6 +518,102 @@ fn test_worker_with_ipv6_addresses(start_server: &ServerProcess) {
     assert_eq!(listed.len(), 4);
 }
 
+// ============================================================================
+// File Staging Tests
+// ============================================================================
+
+#[rstest]
+fn test_file_staging_failure_fails_job(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let work_dir = temp_dir.path();
+    let input_path = work_dir.join("input.txt");
+    fs::write(&input_path, "data").expect("Failed to write input file");
+
+    let spec = format!(
+        r#"name: file_staging_failure
+user: test_user
+
+files:
+  - name: input
+    path: {input_path}
+
+jobs:
+  - name: staged_job
+    command: cat {input_path}
+    input_files:
+      - input
+  - name: unstaged_job
+    command: echo hello
+"#,
+        input_path = input_path.display()
+    );
+    let spec_path = work_dir.join("file_staging_failure.yaml");
+    fs::write(&spec_path, spec).expect("Failed to write spec file");
+    let workflow_id = WorkflowSpec::create_workflow_from_spec(
+        config,
+        spec_path.to_str().unwrap(),
+        "test_user",
+        false,
+        false,
+    )
+    .expect("Failed to create workflow");
+
+    // The staging host cannot be resolved, so staging the input file fails.
+    let workflow_id_str = workflow_id.to_string();
+    let args = [
+        workflow_id_str.as_str(),
+        "--output-dir",
+        work_dir.to_str().unwrap(),
+        "--poll-interval",
+        "0.1",
+        "--stage-from",
+        "torc-staging.invalid",
+    ];
+    run_jobs_cli_command(&args, start_server).expect("Failed to run jobs");
+
+    let jobs = default_api::list_jobs(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list jobs")
+    .items
+    .unwrap();
+    for job in &jobs {
+        let expected = match job.name.as_str() {
+            "staged_job" => JobStatus::Failed,
+            _ => JobStatus::Completed,
+        };
+        assert_eq!(job.status, Some(expected), "job {}", job.name);
+    }
+
+    let events: Vec<_> =
+        default_api::list_events(config, workflow_id, None, None, None, None, None, None)
+            .expect("Failed to list events")
+            .items
+            .unwrap()
+            .into_iter()
+            .filter(|event| event.data["category"] == "file_staging")
+            .collect();
+    assert_eq!(events.len(), 1);
+    let data = &events[0].data;
+    assert_eq!(data["type"], "stage_in");
+    assert_eq!(data["job_name"], "staged_job");
+    assert_eq!(data["path"], input_path.to_str().unwrap());
+    assert_eq!(data["host"], "torc-staging.invalid");
+    assert_eq!(data["status"], "failed");
+    assert!(data["error"].is_string());
+}
+
 // ============================================================================
 // Worker File Parsing Tests (Unit Tests)
 // ============================================================================
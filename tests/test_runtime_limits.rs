@@ -0,0 +1,223 @@
+mod common;
+
+use common::{ServerProcess, run_jobs_cli_command, start_server};
+use rstest::rstest;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use torc::client::async_cli_command::RUNTIME_LIMIT_RETURN_CODE;
+use torc::client::default_api;
+use torc::models;
+
+fn create_runtime_limit_spec(work_dir: &Path, workflow_name: &str) -> String {
+    let yaml_content = format!(
+        r#"name: {workflow_name}
+user: test_user
+description: Test per-job runtime limits
+enforce_runtime_limits: true
+runtime_limit_grace_seconds: 1
+
+jobs:
+  - name: quick
+    command: echo "quick job"
+    resource_requirements: short
+
+  - name: overrun_kill
+    command: sleep 30
+    resource_requirements: short
+
+  - name: overrun_term
+    command: sleep 30
+    supports_termination: true
+    resource_requirements: short
+
+  - name: ignores_term
+    command: "trap '' TERM; sleep 30"
+    supports_termination: true
+    resource_requirements: short
+
+resource_requirements:
+  - name: short
+    num_cpus: 1
+    num_gpus: 0
+    num_nodes: 1
+    memory: 1m
+    runtime: PT1S
+"#
+    );
+    let yaml_path = work_dir.join(format!("{}.yaml", workflow_name));
+    fs::write(&yaml_path, yaml_content).expect("Failed to write YAML file");
+    yaml_path.to_str().unwrap().to_string()
+}
+
+fn get_workflow_id(config: &torc::client::Configuration, workflow_name: &str) -> i64 {
+    let workflows = default_api::list_workflows(
+        config,
+        None,
+        None,
+        None,
+        None,
+        Some(workflow_name),
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list workflows");
+    workflows
+        .items
+        .as_ref()
+        .and_then(|items| items.first())
+        .and_then(|w| w.id)
+        .expect("Workflow not found")
+}
+
+/// Return (status, return_code) for each job, keyed by job name.
+fn get_job_outcomes(
+    config: &torc::client::Configuration,
+    workflow_id: i64,
+) -> HashMap<String, (models::JobStatus, i64)> {
+    let jobs = default_api::list_jobs(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list jobs");
+    let results = default_api::list_results(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list results");
+    let return_codes: HashMap<i64, i64> = results
+        .items
+        .unwrap()
+        .iter()
+        .map(|r| (r.job_id, r.return_code))
+        .collect();
+
+    jobs.items
+        .unwrap()
+        .into_iter()
+        .map(|job| {
+            let return_code = *return_codes
+                .get(&job.id.unwrap())
+                .expect("Job should have a result");
+            (job.name, (job.status.unwrap(), return_code))
+        })
+        .collect()
+}
+
+#[rstest]
+#[case::resources(None)]
+#[case::parallelism(Some(4))]
+fn test_runtime_limits_enforced(
+    start_server: &ServerProcess,
+    #[case] max_parallel_jobs: Option<i64>,
+) {
+    let config = &start_server.config;
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let work_dir = temp_dir.path();
+    let workflow_name = format!(
+        "runtime_limits_enforced_{}",
+        max_parallel_jobs.map_or("resources".to_string(), |n| n.to_string())
+    );
+    let spec_path = create_runtime_limit_spec(work_dir, &workflow_name);
+
+    let mut args = vec![
+        spec_path,
+        "--output-dir".to_string(),
+        work_dir.to_str().unwrap().to_string(),
+        "--poll-interval".to_string(),
+        "0.1".to_string(),
+    ];
+    if let Some(max_jobs) = max_parallel_jobs {
+        args.push("--max-parallel-jobs".to_string());
+        args.push(max_jobs.to_string());
+    } else {
+        args.extend(["--num-cpus", "4", "--memory-gb", "1.0"].map(String::from));
+    }
+    let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+
+    let start = std::time::Instant::now();
+    run_jobs_cli_command(&args_refs, start_server).expect("Failed to run jobs");
+    assert!(
+        start.elapsed().as_secs() < 25,
+        "Jobs exceeding their runtime should have been terminated"
+    );
+
+    let workflow_id = get_workflow_id(config, &workflow_name);
+    let outcomes = get_job_outcomes(config, workflow_id);
+
+    assert_eq!(outcomes["quick"], (models::JobStatus::Completed, 0));
+    for name in ["overrun_kill", "overrun_term", "ignores_term"] {
+        assert_eq!(
+            outcomes[name],
+            (models::JobStatus::Failed, RUNTIME_LIMIT_RETURN_CODE),
+            "Job {} should have failed with the runtime limit return code",
+            name
+        );
+    }
+}
+
+#[rstest]
+fn test_runtime_limits_not_enforced_by_default(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let work_dir = temp_dir.path();
+    let workflow_name = "runtime_limits_not_enforced";
+    let yaml_content = format!(
+        r#"name: {workflow_name}
+user: test_user
+
+jobs:
+  - name: overrun
+    command: sleep 2
+    resource_requirements: short
+
+resource_requirements:
+  - name: short
+    num_cpus: 1
+    memory: 1m
+    runtime: PT1S
+"#
+    );
+    let yaml_path = work_dir.join("not_enforced.yaml");
+    fs::write(&yaml_path, yaml_content).expect("Failed to write YAML file");
+
+    run_jobs_cli_command(
+        &[
+            yaml_path.to_str().unwrap(),
+            "--output-dir",
+            work_dir.to_str().unwrap(),
+            "--poll-interval",
+            "0.1",
+        ],
+        start_server,
+    )
+    .expect("Failed to run jobs");
+
+    let workflow_id = get_workflow_id(config, workflow_name);
+    let workflow = default_api::get_workflow(config, workflow_id).expect("Failed to get workflow");
+    assert_eq!(workflow.enforce_runtime_limits, Some(false));
+    assert_eq!(workflow.runtime_limit_grace_seconds, Some(60));
+
+    let outcomes = get_job_outcomes(config, workflow_id);
+    assert_eq!(outcomes["overrun"], (models::JobStatus::Completed, 0));
+}
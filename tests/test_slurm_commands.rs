@@ -11,8 +11,13 @@ use std::env;
 use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
+use torc::client::commands::slurm::{
+    AllocationDependency, SlurmDependencyType, order_by_allocation_dependencies, sacct_hpc_job_id,
+    schedule_hpc_nodes,
+};
 use torc::client::config::TorcConfig;
 use torc::client::default_api;
+use torc::client::hpc::HpcType;
 use torc::client::hpc::common::HpcJobStatus;
 use torc::client::hpc::hpc_interface::HpcInterface;
 use torc::client::hpc::slurm_interface::SlurmInterface;
@@ -483,6 +488,11 @@ fn test_create_submission_script_with_srun() {
         "Should have srun prefix when start_one_worker_per_node is true"
     );
 
+    assert!(
+        script_content.contains("--worker-per-node"),
+        "Should mark the job runners as one worker per node"
+    );
+
     assert!(
         script_content.contains("unset SLURM_MEM_PER_CPU SLURM_MEM_PER_GPU"),
         "Should unset conflicting Slurm memory variables before srun"
@@ -497,6 +507,134 @@ fn test_create_submission_script_with_srun() {
     let _ = fs::remove_file(&script_path);
 }
 
+#[test]
+fn test_create_submission_script_job_array() {
+    let interface = SlurmInterface::new().expect("Failed to create SlurmInterface");
+
+    let temp_dir = env::temp_dir();
+    let script_path = temp_dir.join("test_submission_script_array.sh");
+
+    let mut config = std::collections::HashMap::new();
+    config.insert("account".to_string(), "test_account".to_string());
+    config.insert("walltime".to_string(), "01:00:00".to_string());
+    config.insert("array".to_string(), "0-4".to_string());
+
+    interface
+        .create_submission_script(
+            "test_job",
+            "http://localhost:8080/torc-service/v1",
+            12345,
+            "/tmp/output",
+            5,
+            None,
+            &script_path,
+            &config,
+            false,
+        )
+        .expect("Failed to create submission script");
+
+    let script_content =
+        fs::read_to_string(&script_path).expect("Failed to read submission script");
+
+    assert!(
+        script_content.contains("#SBATCH --array=0-4"),
+        "Should have array directive"
+    );
+    assert!(
+        script_content.contains("#SBATCH --output=/tmp/output/slurm_output_wf12345_sl%A_%a.o"),
+        "Array task output files should be named by array job ID and task ID"
+    );
+    assert!(
+        script_content.contains("#SBATCH --error=/tmp/output/slurm_output_wf12345_sl%A_%a.e"),
+        "Array task error files should be named by array job ID and task ID"
+    );
+
+    let _ = fs::remove_file(&script_path);
+}
+
+#[rstest]
+#[serial]
+fn test_get_statuses_job_array() {
+    cleanup_fake_slurm_state();
+    let (_, _, _, scancel) = setup_fake_slurm_commands();
+
+    let interface = SlurmInterface::new().expect("Failed to create SlurmInterface");
+
+    let temp_dir = env::temp_dir();
+    let script_path = temp_dir.join("test_job_array.sh");
+    fs::write(
+        &script_path,
+        "#!/bin/bash\n#SBATCH --array=0-2\necho 'test'\n",
+    )
+    .expect("Failed to write test script");
+
+    let (return_code, array_job_id, _) = interface
+        .submit(&script_path)
+        .expect("Failed to submit job array");
+    assert_eq!(return_code, 0);
+
+    // Each array task has its own status entry
+    let statuses = interface.get_statuses().expect("Failed to get statuses");
+    for task_id in 0..3 {
+        let task_job_id = format!("{}_{}", array_job_id, task_id);
+        assert_eq!(
+            statuses.get(&task_job_id),
+            Some(&HpcJobStatus::Queued),
+            "Missing status for array task {}",
+            task_job_id
+        );
+    }
+    assert!(
+        !statuses.contains_key(&array_job_id),
+        "Array job ID itself should not be reported as a job"
+    );
+
+    let task_job_id = format!("{}_1", array_job_id);
+    let job_info = interface
+        .get_status(&task_job_id)
+        .expect("Failed to get status");
+    assert_eq!(job_info.job_id, task_job_id);
+    assert_eq!(job_info.status, HpcJobStatus::Queued);
+
+    // Canceling the array job ID cancels all of its tasks
+    let output = std::process::Command::new(scancel)
+        .arg(&array_job_id)
+        .output()
+        .expect("Failed to run scancel");
+    assert!(output.status.success(), "scancel should succeed");
+    let statuses = interface.get_statuses().expect("Failed to get statuses");
+    for task_id in 0..3 {
+        let task_job_id = format!("{}_{}", array_job_id, task_id);
+        assert_ne!(statuses.get(&task_job_id), Some(&HpcJobStatus::Queued));
+    }
+
+    cleanup_fake_slurm_state();
+}
+
+#[test]
+fn test_sacct_hpc_job_id() {
+    // Slurm 23+ reports the task ID as an object
+    let array_task = json!({
+        "job_id": 1006,
+        "array": {"job_id": 1000, "task_id": {"set": true, "infinite": false, "number": 6}}
+    });
+    assert_eq!(sacct_hpc_job_id(&array_task), Some("1000_6".to_string()));
+
+    // Older versions report it as a number
+    let old_array_task = json!({"job_id": 1003, "array": {"job_id": 1000, "task_id": 3}});
+    assert_eq!(
+        sacct_hpc_job_id(&old_array_task),
+        Some("1000_3".to_string())
+    );
+
+    // Jobs that are not part of an array have an array job ID of 0
+    let job = json!({
+        "job_id": 2000,
+        "array": {"job_id": 0, "task_id": {"set": false, "infinite": false, "number": 0}}
+    });
+    assert_eq!(sacct_hpc_job_id(&job), Some("2000".to_string()));
+}
+
 #[rstest]
 #[serial]
 fn test_status_mapping() {
@@ -1707,9 +1845,11 @@ fn cleanup_fake_slurm_state() {
     let tmpdir = env::var("TMPDIR").unwrap_or_else(|_| "/tmp".to_string());
     let counter_file = format!("{}/fake_sbatch_counter.txt", tmpdir);
     let jobs_file = format!("{}/fake_slurm_jobs.txt", tmpdir);
+    let dependencies_file = format!("{}/fake_sbatch_dependencies.txt", tmpdir);
 
     let _ = fs::remove_file(&counter_file);
     let _ = fs::remove_file(&jobs_file);
+    let _ = fs::remove_file(&dependencies_file);
 
     // Clear failure simulation env vars
     unsafe {
@@ -2187,3 +2327,401 @@ fn test_slurm_generate_no_merge_large_allocations() {
 
     // Temp file is automatically cleaned up when workflow_file goes out of scope
 }
+
+#[rstest]
+#[serial]
+fn test_schedule_nodes_job_array(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    cleanup_fake_slurm_state();
+    setup_fake_slurm_commands();
+
+    let workflow = create_test_workflow(config, "test_schedule_nodes_job_array");
+    let workflow_id = workflow.id.unwrap();
+    let scheduler = create_test_slurm_scheduler(config, workflow_id);
+    let output_dir = tempfile::tempdir().expect("Failed to create temp dir");
+
+    schedule_hpc_nodes(
+        config,
+        HpcType::Slurm,
+        workflow_id,
+        scheduler.id.unwrap(),
+        3,
+        "worker",
+        output_dir.path().to_str().unwrap(),
+        30,
+        None,
+        false,
+        true, // use_job_array
+        None,
+        false,
+    )
+    .expect("Failed to schedule job array");
+
+    // One sbatch call creates one scheduled compute node per array task
+    let response = default_api::list_scheduled_compute_nodes(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list scheduled compute nodes");
+    let nodes = response.items.unwrap();
+    assert_eq!(nodes.len(), 3);
+    let array_job_id = nodes[0].scheduler_id;
+    assert_ne!(array_job_id, 0, "Array job ID should be recorded");
+    let mut task_ids: Vec<i64> = nodes
+        .iter()
+        .map(|node| {
+            assert_eq!(node.scheduler_id, array_job_id);
+            assert_eq!(node.status, "pending");
+            node.array_task_id.expect("Array task ID should be set")
+        })
+        .collect();
+    task_ids.sort();
+    assert_eq!(task_ids, vec![0, 1, 2]);
+
+    // Job runners find their scheduled compute node by <array_job_id>_<task_id>
+    let task_job_id = format!("{}_2", array_job_id);
+    let response = default_api::list_scheduled_compute_nodes(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        Some(&task_job_id),
+        None,
+        None,
+    )
+    .expect("Failed to list scheduled compute nodes");
+    let nodes = response.items.unwrap();
+    assert_eq!(nodes.len(), 1);
+    assert_eq!(nodes[0].array_task_id, Some(2));
+    assert_eq!(nodes[0].hpc_job_id(), task_job_id);
+
+    // All tasks are queued in Slurm under their own IDs
+    let interface = SlurmInterface::new().expect("Failed to create SlurmInterface");
+    let statuses = interface.get_statuses().expect("Failed to get statuses");
+    for task_id in 0..3 {
+        assert_eq!(
+            statuses.get(&format!("{}_{}", array_job_id, task_id)),
+            Some(&HpcJobStatus::Queued)
+        );
+    }
+
+    cleanup_fake_slurm_state();
+}
+
+#[rstest]
+#[serial]
+fn test_schedule_nodes_with_dependency(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    cleanup_fake_slurm_state();
+    setup_fake_slurm_commands();
+
+    let workflow = create_test_workflow(config, "test_schedule_nodes_with_dependency");
+    let workflow_id = workflow.id.unwrap();
+    let upstream_id = create_test_slurm_scheduler(config, workflow_id).id.unwrap();
+    let downstream_id = create_test_slurm_scheduler(config, workflow_id).id.unwrap();
+    let output_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let output = output_dir.path().to_str().unwrap();
+    let dependency = AllocationDependency {
+        dependency_type: SlurmDependencyType::AfterOk,
+        scheduler_ids: vec![upstream_id],
+    };
+
+    // Submits the downstream allocation and returns its submission script
+    let schedule_downstream = || {
+        schedule_hpc_nodes(
+            config,
+            HpcType::Slurm,
+            workflow_id,
+            downstream_id,
+            1,
+            "worker",
+            output,
+            30,
+            None,
+            false,
+            false,
+            Some(&dependency),
+            true, // keep_submission_scripts
+        )
+        .expect("Failed to schedule dependent allocation");
+        let node = list_scheduled_nodes_for_scheduler(config, workflow_id, downstream_id)
+            .pop()
+            .unwrap();
+        fs::read_to_string(
+            output_dir
+                .path()
+                .join(format!("worker_{}.sh", node.id.unwrap())),
+        )
+        .expect("Failed to read submission script")
+    };
+
+    // Without upstream allocations, there is nothing to wait for.
+    let script = schedule_downstream();
+    assert!(!script.contains("--dependency"), "{}", script);
+
+    // The tasks of a job array appear once in the dependency.
+    schedule_hpc_nodes(
+        config,
+        HpcType::Slurm,
+        workflow_id,
+        upstream_id,
+        2,
+        "worker",
+        output,
+        30,
+        None,
+        false,
+        true, // use_job_array
+        None,
+        false,
+    )
+    .expect("Failed to schedule upstream allocations");
+    let upstream_nodes = list_scheduled_nodes_for_scheduler(config, workflow_id, upstream_id);
+    let array_job_id = upstream_nodes[0].scheduler_id;
+    let script = schedule_downstream();
+    assert!(
+        script.contains(&format!("#SBATCH --dependency=afterok:{}\n", array_job_id)),
+        "{}",
+        script
+    );
+    assert!(script.contains("#SBATCH --kill-on-invalid-dep=yes\n"));
+
+    // Completed allocations are not part of the dependency.
+    for node in upstream_nodes {
+        let mut updated_node = node.clone();
+        updated_node.status = "complete".to_string();
+        default_api::update_scheduled_compute_node(config, node.id.unwrap(), updated_node)
+            .expect("Failed to update scheduled compute node");
+    }
+    let script = schedule_downstream();
+    assert!(!script.contains("--dependency"), "{}", script);
+
+    // Only Slurm supports dependencies.
+    let result = schedule_hpc_nodes(
+        config,
+        HpcType::Pbs,
+        workflow_id,
+        downstream_id,
+        1,
+        "worker",
+        output,
+        30,
+        None,
+        false,
+        false,
+        Some(&dependency),
+        false,
+    );
+    assert!(result.is_err());
+
+    cleanup_fake_slurm_state();
+}
+
+#[rstest]
+#[serial]
+fn test_start_workflow_with_chained_allocations(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    cleanup_fake_slurm_state();
+    setup_fake_slurm_commands();
+
+    // The dependent action comes first; the upstream allocation must still be submitted first.
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let spec_path = temp_dir.path().join("chained.yaml");
+    let yaml_content = r#"
+name: chained_allocations
+jobs:
+  - name: setup
+    command: echo setup
+    scheduler: pre
+  - name: process
+    command: echo process
+    depends_on: [setup]
+    scheduler: post
+slurm_schedulers:
+  - name: pre
+    account: test_account
+    walltime: "01:00:00"
+    nodes: 1
+  - name: post
+    account: test_account
+    walltime: "01:00:00"
+    nodes: 1
+actions:
+  - trigger_type: on_workflow_start
+    action_type: schedule_nodes
+    scheduler: post
+    scheduler_type: slurm
+    depends_on_schedulers: [pre]
+  - trigger_type: on_workflow_start
+    action_type: schedule_nodes
+    scheduler: pre
+    scheduler_type: slurm
+"#;
+    fs::write(&spec_path, yaml_content).expect("Failed to write YAML file");
+    let workflow_id = torc::client::workflow_spec::WorkflowSpec::create_workflow_from_spec(
+        config,
+        &spec_path,
+        "test_user",
+        false,
+        false,
+    )
+    .expect("Failed to create workflow");
+
+    let workflow = default_api::get_workflow(config, workflow_id).expect("Failed to get workflow");
+    let workflow_manager = WorkflowManager::new(config.clone(), TorcConfig::default(), workflow);
+    workflow_manager
+        .start(false)
+        .expect("Failed to start workflow");
+
+    let schedulers = default_api::list_slurm_schedulers(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list Slurm schedulers")
+    .items
+    .unwrap();
+    let scheduler_id = |name: &str| {
+        schedulers
+            .iter()
+            .find(|s| s.name.as_deref() == Some(name))
+            .and_then(|s| s.id)
+            .unwrap()
+    };
+    let pre_job_id = list_scheduled_nodes_for_scheduler(config, workflow_id, scheduler_id("pre"))
+        [0]
+    .scheduler_id;
+    let post_job_id = list_scheduled_nodes_for_scheduler(config, workflow_id, scheduler_id("post"))
+        [0]
+    .scheduler_id;
+
+    let tmpdir = env::var("TMPDIR").unwrap_or_else(|_| "/tmp".to_string());
+    let dependencies = fs::read_to_string(format!("{}/fake_sbatch_dependencies.txt", tmpdir))
+        .expect("Failed to read recorded dependencies");
+    assert_eq!(
+        dependencies,
+        format!("{}|afterok:{}\n", post_job_id, pre_job_id)
+    );
+
+    cleanup_fake_slurm_state();
+}
+
+/// List the scheduled compute nodes of a scheduler, ordered by ID
+fn list_scheduled_nodes_for_scheduler(
+    config: &torc::client::Configuration,
+    workflow_id: i64,
+    scheduler_config_id: i64,
+) -> Vec<models::ScheduledComputeNodesModel> {
+    let response = default_api::list_scheduled_compute_nodes(
+        config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list scheduled compute nodes");
+    let mut nodes: Vec<_> = response
+        .items
+        .unwrap()
+        .into_iter()
+        .filter(|node| node.scheduler_config_id == scheduler_config_id)
+        .collect();
+    nodes.sort_by_key(|node| node.id);
+    nodes
+}
+
+#[rstest]
+fn test_allocation_dependency_from_action_config() {
+    let dependency = AllocationDependency::from_action_config(&json!({
+        "scheduler_id": 3,
+        "depends_on_scheduler_ids": [1, 2],
+        "dependency_type": "afterany",
+    }))
+    .unwrap();
+    assert_eq!(
+        dependency,
+        Some(AllocationDependency {
+            dependency_type: SlurmDependencyType::AfterAny,
+            scheduler_ids: vec![1, 2],
+        })
+    );
+
+    // afterok is the default
+    let dependency =
+        AllocationDependency::from_action_config(&json!({"depends_on_scheduler_ids": [1]}))
+            .unwrap()
+            .unwrap();
+    assert_eq!(dependency.dependency_type, SlurmDependencyType::AfterOk);
+
+    assert_eq!(
+        AllocationDependency::from_action_config(&json!({"scheduler_id": 3})).unwrap(),
+        None
+    );
+    assert!(
+        AllocationDependency::from_action_config(&json!({
+            "depends_on_scheduler_ids": [1],
+            "dependency_type": "after",
+        }))
+        .is_err()
+    );
+}
+
+#[rstest]
+fn test_order_by_allocation_dependencies() {
+    let action = |scheduler_id: i64, depends_on: &[i64]| {
+        models::WorkflowActionModel::new(
+            1,
+            "on_workflow_start".to_string(),
+            "schedule_nodes".to_string(),
+            json!({
+                "scheduler_type": "slurm",
+                "scheduler_id": scheduler_id,
+                "depends_on_scheduler_ids": depends_on,
+            }),
+        )
+    };
+    let actions = vec![
+        action(3, &[2]),
+        action(2, &[1]),
+        action(1, &[]),
+        action(4, &[]),
+    ];
+    let order: Vec<i64> = order_by_allocation_dependencies(actions)
+        .iter()
+        .map(|a| a.action_config["scheduler_id"].as_i64().unwrap())
+        .collect();
+    assert_eq!(order, vec![1, 4, 2, 3]);
+
+    // Actions in a cycle keep their order at the end
+    let actions = vec![action(1, &[2]), action(2, &[1]), action(3, &[])];
+    let order: Vec<i64> = order_by_allocation_dependencies(actions)
+        .iter()
+        .map(|a| a.action_config["scheduler_id"].as_i64().unwrap())
+        .collect();
+    assert_eq!(order, vec![3, 1, 2]);
+}
@@ -105,6 +105,83 @@ fn test_create_workflow_action_schedule_nodes(start_server: &ServerProcess) {
     assert_eq!(result.action_type.as_str(), "schedule_nodes");
 }
 
+#[rstest]
+fn test_create_workflow_action_schedule_nodes_job_array(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let workflow = create_test_workflow(config, "action_schedule_job_array_workflow");
+    let workflow_id = workflow.id.unwrap();
+
+    let action_body = |use_job_array: serde_json::Value| {
+        json!({
+            "workflow_id": workflow_id,
+            "trigger_type": "on_workflow_start",
+            "action_type": "schedule_nodes",
+            "action_config": {
+                "scheduler_type": "slurm",
+                "scheduler_id": 1,
+                "num_allocations": 100,
+                "use_job_array": use_job_array,
+            },
+        })
+    };
+
+    let result = default_api::create_workflow_action(config, workflow_id, action_body(json!(true)))
+        .expect("Failed to create schedule_nodes action with use_job_array");
+    assert_eq!(result.action_config["use_job_array"], json!(true));
+
+    let result =
+        default_api::create_workflow_action(config, workflow_id, action_body(json!("yes")));
+    assert!(result.is_err(), "use_job_array must be a boolean");
+}
+
+#[rstest]
+fn test_create_workflow_action_schedule_nodes_dependency(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let workflow = create_test_workflow(config, "action_schedule_dependency_workflow");
+    let workflow_id = workflow.id.unwrap();
+
+    let action_body = |depends_on: serde_json::Value, dependency_type: serde_json::Value| {
+        json!({
+            "workflow_id": workflow_id,
+            "trigger_type": "on_workflow_start",
+            "action_type": "schedule_nodes",
+            "action_config": {
+                "scheduler_type": "slurm",
+                "scheduler_id": 2,
+                "num_allocations": 1,
+                "depends_on_scheduler_ids": depends_on,
+                "dependency_type": dependency_type,
+            },
+        })
+    };
+
+    let result = default_api::create_workflow_action(
+        config,
+        workflow_id,
+        action_body(json!([1]), json!("afterany")),
+    )
+    .expect("Failed to create schedule_nodes action with a dependency");
+    assert_eq!(result.action_config["depends_on_scheduler_ids"], json!([1]));
+    assert_eq!(result.action_config["dependency_type"], json!("afterany"));
+
+    let result = default_api::create_workflow_action(
+        config,
+        workflow_id,
+        action_body(json!(1), json!("afterok")),
+    );
+    assert!(result.is_err(), "depends_on_scheduler_ids must be an array");
+
+    let result = default_api::create_workflow_action(
+        config,
+        workflow_id,
+        action_body(json!([1]), json!("aftercorr")),
+    );
+    assert!(
+        result.is_err(),
+        "dependency_type must be afterok or afterany"
+    );
+}
+
 #[rstest]
 fn test_get_workflow_actions(start_server: &ServerProcess) {
     let config = &start_server.config;
@@ -8,12 +8,14 @@
 //! - Optional results and events export/import
 //! - ID remapping during import
 //! - Status reset on import
+//! - Job process settings (env, working_dir, shell)
 
 mod common;
 
 use common::{ServerProcess, run_cli_with_json, start_server};
 use rstest::rstest;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::fs;
 use tempfile::NamedTempFile;
 use torc::client::apis::default_api;
@@ -82,6 +84,9 @@ fn create_test_workflow_with_dependencies(
     job1.input_file_ids = Some(vec![input_file_id]);
     job1.output_file_ids = Some(vec![intermediate_file_id]);
     job1.input_user_data_ids = Some(vec![config_data_id]);
+    job1.env = Some(HashMap::from([("MODE".to_string(), "fast".to_string())]));
+    job1.working_dir = Some("/tmp".to_string());
+    job1.shell = Some("sh".to_string());
     let created_job1 = default_api::create_job(config, job1).expect("Failed to create job1");
     let job1_id = created_job1.id.unwrap();
 
@@ -205,8 +210,13 @@ fn test_export_import_basic(start_server: &ServerProcess) {
             == 1
     );
 
+    assert_eq!(process_input_job.env.as_ref().unwrap()["MODE"], "fast");
+    assert_eq!(process_input_job.working_dir.as_deref(), Some("/tmp"));
+    assert_eq!(process_input_job.shell.as_deref(), Some("sh"));
+
     let finalize_job = imported_jobs.iter().find(|j| j.name == "finalize").unwrap();
     assert!(finalize_job.depends_on_job_ids.as_ref().unwrap().len() == 1);
+    assert_eq!(finalize_job.env, None);
 
     // Verify all jobs have uninitialized status (default behavior resets status)
     for job in &imported_jobs {
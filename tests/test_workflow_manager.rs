@@ -2735,3 +2735,121 @@ fn test_reinitialize_with_file_change_depends_on_complete_job(start_server: &Ser
         "postprocess should be Blocked (waiting for work1 to complete)"
     );
 }
+
+/// Helper to create a file on disk and register it with checksum change detection
+fn create_checksum_file(
+    config: &Configuration,
+    workflow_id: i64,
+    temp_dir: &TempDir,
+    name: &str,
+) -> models::FileModel {
+    let path = temp_dir.path().join(format!("{}.txt", name));
+    fs::write(&path, "original content").unwrap();
+    let mut file = models::FileModel::new(
+        workflow_id,
+        name.to_string(),
+        path.to_str().unwrap().to_string(),
+    );
+    file.use_checksum = Some(true);
+    default_api::create_file(config, file).expect("Failed to create file")
+}
+
+/// Set a file's modification time relative to its current value
+fn shift_mtime(path: &Path, seconds: i64) {
+    let file = fs::File::options().write(true).open(path).unwrap();
+    let mtime = file.metadata().unwrap().modified().unwrap();
+    let new_mtime = if seconds >= 0 {
+        mtime + Duration::from_secs(seconds as u64)
+    } else {
+        mtime - Duration::from_secs((-seconds) as u64)
+    };
+    file.set_modified(new_mtime).unwrap();
+}
+
+#[rstest]
+fn test_process_changed_files_checksum_ignores_mtime_only_change(start_server: &ServerProcess) {
+    let config = start_server.config.clone();
+    let (manager, workflow) =
+        create_test_workflow_manager(config.clone(), "test_checksum_mtime_only");
+    let workflow_id = workflow.id.unwrap();
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let file = create_checksum_file(&config, workflow_id, &temp_dir, "checksum_input");
+    let file_id = file.id.unwrap();
+
+    let (job_id, _) = execute_workflow_with_job(
+        &config,
+        &manager,
+        workflow_id,
+        "checksum_job",
+        "echo 'checksum'",
+        Some(vec![file_id]),
+    )
+    .expect("Failed to execute workflow");
+
+    let initialized = default_api::get_file(&config, file_id).expect("Failed to get file");
+    let original_hash = initialized
+        .content_hash
+        .clone()
+        .expect("Hash should be set");
+    assert!(original_hash.starts_with("sha256:"));
+
+    // Rewrite identical content with a different mtime, as a restore would
+    let path = Path::new(&file.path);
+    fs::write(path, "original content").unwrap();
+    shift_mtime(path, 60);
+
+    manager
+        .process_changed_files(false)
+        .expect("Failed to process changed files");
+
+    let job = default_api::get_job(&config, job_id).expect("Failed to get job");
+    assert_eq!(job.status.unwrap(), models::JobStatus::Completed);
+    let file_after = default_api::get_file(&config, file_id).expect("Failed to get file");
+    assert_eq!(file_after.content_hash, Some(original_hash));
+    assert!(file_after.st_mtime.unwrap() > initialized.st_mtime.unwrap());
+}
+
+#[rstest]
+fn test_process_changed_files_checksum_detects_edit_with_same_mtime(start_server: &ServerProcess) {
+    let config = start_server.config.clone();
+    let (manager, workflow) =
+        create_test_workflow_manager(config.clone(), "test_checksum_same_mtime");
+    let workflow_id = workflow.id.unwrap();
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let file = create_checksum_file(&config, workflow_id, &temp_dir, "checksum_edit");
+    let file_id = file.id.unwrap();
+
+    let (job_id, _) = execute_workflow_with_job(
+        &config,
+        &manager,
+        workflow_id,
+        "checksum_edit_job",
+        "echo 'checksum'",
+        Some(vec![file_id]),
+    )
+    .expect("Failed to execute workflow");
+
+    let initialized = default_api::get_file(&config, file_id).expect("Failed to get file");
+
+    // Edit the content but keep the recorded mtime
+    let path = Path::new(&file.path);
+    let mtime = fs::metadata(path).unwrap().modified().unwrap();
+    fs::write(path, "edited content!!").unwrap();
+    fs::File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(mtime)
+        .unwrap();
+
+    manager
+        .process_changed_files(false)
+        .expect("Failed to process changed files");
+
+    let job = default_api::get_job(&config, job_id).expect("Failed to get job");
+    assert_eq!(job.status.unwrap(), models::JobStatus::Uninitialized);
+    let file_after = default_api::get_file(&config, file_id).expect("Failed to get file");
+    assert_ne!(file_after.content_hash, initialized.content_hash);
+}
@@ -0,0 +1,458 @@
+mod common;
+
+use common::{
+    AccessControlServerProcess, ServerProcess, run_cli_with_json, server_service_user,
+    start_server, start_server_with_access_control,
+};
+use rstest::rstest;
+use serde_json::json;
+use std::thread;
+use std::time::{Duration, Instant};
+use torc::client::default_api;
+use torc::models;
+
+/// Create a workflow with one job and an on_workflow_start schedule_nodes action, so that
+/// `torc workflows submit` accepts it.
+fn create_submittable_workflow(
+    config: &torc::client::Configuration,
+    name: &str,
+    user: &str,
+) -> i64 {
+    let workflow = models::WorkflowModel::new(name.to_string(), user.to_string());
+    let workflow_id = default_api::create_workflow(config, workflow)
+        .expect("Failed to create workflow")
+        .id
+        .unwrap();
+    let job = models::JobModel::new(workflow_id, "work".to_string(), "echo hello".to_string());
+    default_api::create_job(config, job).expect("Failed to create job");
+    let action_body = json!({
+        "workflow_id": workflow_id,
+        "trigger_type": "on_workflow_start",
+        "action_type": "schedule_nodes",
+        "action_config": {"scheduler_type": "local", "scheduler_id": 1},
+    });
+    default_api::create_workflow_action(config, workflow_id, action_body)
+        .expect("Failed to create workflow action");
+    workflow_id
+}
+
+fn create_workflow(config: &torc::client::Configuration, name: &str, user: &str) -> i64 {
+    let workflow = models::WorkflowModel::new(name.to_string(), user.to_string());
+    default_api::create_workflow(config, workflow)
+        .expect("Failed to create workflow")
+        .id
+        .unwrap()
+}
+
+fn get_run_id(config: &torc::client::Configuration, workflow_id: i64) -> i64 {
+    default_api::get_workflow_status(config, workflow_id)
+        .expect("Failed to get workflow status")
+        .run_id
+}
+
+/// Return the events of the workflow with the given event type.
+fn schedule_events(
+    config: &torc::client::Configuration,
+    workflow_id: i64,
+    event_type: &str,
+) -> Vec<models::EventModel> {
+    default_api::list_events(config, workflow_id, None, None, None, None, None, None)
+        .expect("Failed to list events")
+        .items
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|e| e.data.get("event_type").and_then(|v| v.as_str()) == Some(event_type))
+        .collect()
+}
+
+fn config_with_user(
+    config: &torc::client::Configuration,
+    user: &str,
+) -> torc::client::Configuration {
+    let mut config = config.clone();
+    config.basic_auth = Some((user.to_string(), Some("password".to_string())));
+    config
+}
+
+fn assert_forbidden<T: std::fmt::Debug, E: std::fmt::Debug>(
+    result: Result<T, torc::client::apis::Error<E>>,
+    operation: &str,
+) {
+    match result {
+        Err(torc::client::apis::Error::ResponseError(response)) => {
+            assert_eq!(response.status.as_u16(), 403, "{}", operation);
+        }
+        other => panic!("Expected 403 for {}, got {:?}", operation, other),
+    }
+}
+
+fn wait_for_event(
+    config: &torc::client::Configuration,
+    workflow_id: i64,
+    event_type: &str,
+    timeout: Duration,
+) -> models::EventModel {
+    let start = Instant::now();
+    loop {
+        if let Some(event) = schedule_events(config, workflow_id, event_type)
+            .into_iter()
+            .next()
+        {
+            return event;
+        }
+        assert!(
+            start.elapsed() < timeout,
+            "Timed out waiting for {} event of workflow {}",
+            event_type,
+            workflow_id
+        );
+        thread::sleep(Duration::from_millis(200));
+    }
+}
+
+#[rstest]
+fn test_workflow_schedule_crud(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let workflow_id = create_workflow(config, "test_workflow_schedule_crud", "test_user");
+
+    let mut schedule = models::WorkflowScheduleModel::new(workflow_id, "0 0 1 1 *".to_string());
+    schedule.timezone = Some("America/Denver".to_string());
+    let created =
+        default_api::create_workflow_schedule(config, schedule).expect("Failed to create schedule");
+    let schedule_id = created.id.unwrap();
+    assert_eq!(created.workflow_id, workflow_id);
+    assert_eq!(created.timezone.as_deref(), Some("America/Denver"));
+    assert_eq!(
+        created.overlap_policy,
+        Some(models::ScheduleOverlapPolicy::Skip)
+    );
+    assert_eq!(created.is_paused, Some(false));
+    assert!(created.last_run_time.is_none());
+
+    // Midnight on January 1 in Denver is 07:00 UTC.
+    let next_run_time = chrono::DateTime::parse_from_rfc3339(&created.next_run_time.unwrap())
+        .expect("next_run_time is not RFC3339")
+        .with_timezone(&chrono::Utc);
+    assert_eq!(
+        next_run_time.format("%m-%d %H:%M:%S").to_string(),
+        "01-01 07:00:00"
+    );
+    assert!(next_run_time > chrono::Utc::now());
+
+    let fetched =
+        default_api::get_workflow_schedule(config, schedule_id).expect("Failed to get schedule");
+    assert_eq!(fetched.cron_expression, "0 0 1 1 *");
+
+    let listed = default_api::list_workflow_schedules(config, Some(workflow_id), None, None)
+        .expect("Failed to list schedules");
+    assert_eq!(listed.total_count, 1);
+    assert_eq!(listed.items.unwrap()[0].id, Some(schedule_id));
+
+    let mut update = fetched.clone();
+    update.cron_expression = "30 6 * * *".to_string();
+    update.overlap_policy = Some(models::ScheduleOverlapPolicy::Queue);
+    let updated = default_api::update_workflow_schedule(config, schedule_id, update)
+        .expect("Failed to update schedule");
+    assert_eq!(updated.cron_expression, "30 6 * * *");
+    assert_eq!(
+        updated.overlap_policy,
+        Some(models::ScheduleOverlapPolicy::Queue)
+    );
+    assert_ne!(updated.next_run_time, fetched.next_run_time);
+
+    default_api::delete_workflow_schedule(config, schedule_id, None)
+        .expect("Failed to delete schedule");
+    assert!(default_api::get_workflow_schedule(config, schedule_id).is_err());
+}
+
+#[rstest]
+fn test_workflow_schedule_invalid_input(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let workflow_id = create_workflow(config, "test_workflow_schedule_invalid_input", "test_user");
+
+    let schedule = models::WorkflowScheduleModel::new(workflow_id, "not a cron".to_string());
+    match default_api::create_workflow_schedule(config, schedule) {
+        Err(torc::client::apis::Error::ResponseError(response)) => {
+            assert_eq!(response.status.as_u16(), 422);
+        }
+        other => panic!(
+            "Expected 422 for an invalid cron expression, got {:?}",
+            other
+        ),
+    }
+
+    let mut schedule = models::WorkflowScheduleModel::new(workflow_id, "0 2 * * *".to_string());
+    schedule.timezone = Some("Mars/Olympus_Mons".to_string());
+    match default_api::create_workflow_schedule(config, schedule) {
+        Err(torc::client::apis::Error::ResponseError(response)) => {
+            assert_eq!(response.status.as_u16(), 422);
+        }
+        other => panic!("Expected 422 for an invalid timezone, got {:?}", other),
+    }
+
+    let schedule = models::WorkflowScheduleModel::new(999_999_999, "0 2 * * *".to_string());
+    match default_api::create_workflow_schedule(config, schedule) {
+        Err(torc::client::apis::Error::ResponseError(response)) => {
+            assert_eq!(response.status.as_u16(), 404);
+        }
+        other => panic!("Expected 404 for a missing workflow, got {:?}", other),
+    }
+}
+
+#[rstest]
+fn test_workflow_schedule_fires_and_skips_overlap(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let workflow_id = create_submittable_workflow(
+        config,
+        "test_workflow_schedule_fires_and_skips_overlap",
+        "test_user",
+    );
+    let run_id = get_run_id(config, workflow_id);
+
+    // Fire every second. Nothing runs the job, so the first run stays active and later
+    // firings are skipped.
+    let schedule = models::WorkflowScheduleModel::new(workflow_id, "* * * * * *".to_string());
+    let schedule_id = default_api::create_workflow_schedule(config, schedule)
+        .expect("Failed to create schedule")
+        .id
+        .unwrap();
+
+    let fired = wait_for_event(
+        config,
+        workflow_id,
+        "schedule_fired",
+        Duration::from_secs(60),
+    );
+    assert!(schedule_events(config, workflow_id, "schedule_failed").is_empty());
+    assert_eq!(fired.data["schedule_id"].as_i64(), Some(schedule_id));
+    assert_eq!(fired.data["run_id"].as_i64(), Some(run_id + 1));
+    assert_eq!(get_run_id(config, workflow_id), run_id + 1);
+
+    let skipped = wait_for_event(
+        config,
+        workflow_id,
+        "schedule_skipped",
+        Duration::from_secs(30),
+    );
+    assert_eq!(skipped.data["schedule_id"].as_i64(), Some(schedule_id));
+    assert_eq!(get_run_id(config, workflow_id), run_id + 1);
+
+    let schedule =
+        default_api::get_workflow_schedule(config, schedule_id).expect("Failed to get schedule");
+    assert!(schedule.last_run_time.is_some());
+    default_api::delete_workflow_schedule(config, schedule_id, None)
+        .expect("Failed to delete schedule");
+}
+
+#[rstest]
+fn test_workflow_schedule_records_failed_submission(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    // Without a schedule_nodes action, `torc workflows submit` fails after the reinitialize.
+    let workflow_id = create_workflow(
+        config,
+        "test_workflow_schedule_records_failed_submission",
+        "test_user",
+    );
+    let job = models::JobModel::new(workflow_id, "work".to_string(), "echo hello".to_string());
+    default_api::create_job(config, job).expect("Failed to create job");
+    let run_id = get_run_id(config, workflow_id);
+
+    let schedule = models::WorkflowScheduleModel::new(workflow_id, "* * * * * *".to_string());
+    let schedule_id = default_api::create_workflow_schedule(config, schedule)
+        .expect("Failed to create schedule")
+        .id
+        .unwrap();
+
+    let failed = wait_for_event(
+        config,
+        workflow_id,
+        "schedule_failed",
+        Duration::from_secs(60),
+    );
+    assert_eq!(failed.data["schedule_id"].as_i64(), Some(schedule_id));
+    assert_eq!(failed.data["command"].as_str(), Some("submit"));
+    assert_eq!(get_run_id(config, workflow_id), run_id + 1);
+    default_api::delete_workflow_schedule(config, schedule_id, None)
+        .expect("Failed to delete schedule");
+}
+
+#[rstest]
+fn test_workflow_schedule_cli_pause_resume(start_server: &ServerProcess) {
+    let config = &start_server.config;
+    let workflow_id = create_workflow(
+        config,
+        "test_workflow_schedule_cli_pause_resume",
+        "test_user",
+    );
+    let workflow_id_str = workflow_id.to_string();
+
+    let created = run_cli_with_json(
+        &[
+            "schedules",
+            "create",
+            &workflow_id_str,
+            "0 3 * * *",
+            "--overlap-policy",
+            "queue",
+        ],
+        start_server,
+        None,
+    )
+    .expect("Failed to create schedule");
+    let schedule_id = created["id"].as_i64().unwrap();
+    assert_eq!(created["overlap_policy"], "queue");
+    assert!(created["working_directory"].as_str().is_some());
+    let id_str = schedule_id.to_string();
+
+    let paused = run_cli_with_json(&["schedules", "pause", &id_str], start_server, None)
+        .expect("Failed to pause schedule");
+    assert_eq!(paused["is_paused"], true);
+
+    let listed = run_cli_with_json(&["schedules", "list", &workflow_id_str], start_server, None)
+        .expect("Failed to list schedules");
+    let schedules = listed["schedules"].as_array().unwrap();
+    assert_eq!(schedules.len(), 1);
+    assert_eq!(schedules[0]["is_paused"], true);
+
+    let resumed = run_cli_with_json(&["schedules", "resume", &id_str], start_server, None)
+        .expect("Failed to resume schedule");
+    assert_eq!(resumed["is_paused"], false);
+    assert!(resumed["next_run_time"].as_str().is_some());
+
+    run_cli_with_json(&["schedules", "delete", &id_str], start_server, None)
+        .expect("Failed to delete schedule");
+    let listed = default_api::list_workflow_schedules(config, Some(workflow_id), None, None)
+        .expect("Failed to list schedules");
+    assert_eq!(listed.total_count, 0);
+}
+
+#[rstest]
+fn test_workflow_schedules_with_access_control(
+    start_server_with_access_control: &AccessControlServerProcess,
+) {
+    let config = &start_server_with_access_control.config;
+    let workflow_id = create_workflow(config, "schedules_access", "alice");
+    let run_id = get_run_id(config, workflow_id);
+    let schedule = models::WorkflowScheduleModel::new(workflow_id, "* * * * * *".to_string());
+    let created =
+        default_api::create_workflow_schedule(config, schedule).expect("Failed to create schedule");
+    let schedule_id = created.id.unwrap();
+
+    let outsider = config_with_user(config, "outsider");
+    let schedule = models::WorkflowScheduleModel::new(workflow_id, "0 2 * * *".to_string());
+    assert_forbidden(
+        default_api::create_workflow_schedule(&outsider, schedule),
+        "create",
+    );
+    assert_forbidden(
+        default_api::get_workflow_schedule(&outsider, schedule_id),
+        "get",
+    );
+    assert_forbidden(
+        default_api::list_workflow_schedules(&outsider, Some(workflow_id), None, None),
+        "list",
+    );
+    assert_forbidden(
+        default_api::update_workflow_schedule(&outsider, schedule_id, created.clone()),
+        "update",
+    );
+    assert_forbidden(
+        default_api::delete_workflow_schedule(&outsider, schedule_id, None),
+        "delete",
+    );
+    let listed = default_api::list_workflow_schedules(&outsider, None, None, None)
+        .expect("Failed to list schedules");
+    assert!(
+        listed
+            .items
+            .unwrap_or_default()
+            .iter()
+            .all(|s| s.workflow_id != workflow_id)
+    );
+
+    // The server runs scheduled commands as its own account, so alice's schedule is refused.
+    let refused = wait_for_event(
+        config,
+        workflow_id,
+        "schedule_failed",
+        Duration::from_secs(60),
+    );
+    assert_eq!(refused.data["schedule_id"].as_i64(), Some(schedule_id));
+    assert!(
+        refused.data["message"]
+            .as_str()
+            .unwrap()
+            .contains("created by 'alice'")
+    );
+    assert_eq!(get_run_id(config, workflow_id), run_id);
+    default_api::delete_workflow_schedule(config, schedule_id, None)
+        .expect("Failed to delete schedule");
+
+    // A schedule created by the server's account fires with a token issued to that account.
+    let service_user = server_service_user();
+    let service = config_with_user(config, &service_user);
+    let workflow_id = create_submittable_workflow(&service, "schedules_service", &service_user);
+    let run_id = get_run_id(&service, workflow_id);
+    let schedule = models::WorkflowScheduleModel::new(workflow_id, "* * * * * *".to_string());
+    let schedule_id = default_api::create_workflow_schedule(&service, schedule)
+        .expect("Failed to create schedule")
+        .id
+        .unwrap();
+    wait_for_event(
+        &service,
+        workflow_id,
+        "schedule_fired",
+        Duration::from_secs(60),
+    );
+    assert!(schedule_events(&service, workflow_id, "schedule_failed").is_empty());
+    assert_eq!(get_run_id(&service, workflow_id), run_id + 1);
+
+    // Once no job of the run is active, the schedule fires again and revokes the previous
+    // run's token.
+    let jobs = default_api::list_jobs(
+        &service,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list jobs");
+    for job in jobs.items.unwrap_or_default() {
+        default_api::manage_status_change(
+            &service,
+            job.id.unwrap(),
+            models::JobStatus::Uninitialized,
+            run_id + 1,
+            None,
+        )
+        .expect("Failed to reset job");
+    }
+    let start = Instant::now();
+    while schedule_events(&service, workflow_id, "schedule_fired").len() < 2 {
+        assert!(
+            start.elapsed() < Duration::from_secs(60),
+            "Schedule did not fire a second time"
+        );
+        thread::sleep(Duration::from_millis(200));
+    }
+    let token_name = format!("schedule-{}", schedule_id);
+    let tokens: Vec<_> = default_api::list_api_tokens(&service, None, None)
+        .expect("Failed to list API tokens")
+        .items
+        .into_iter()
+        .filter(|t| t.name == token_name)
+        .collect();
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(
+        tokens.iter().filter(|t| t.revoked == Some(false)).count(),
+        1
+    );
+    default_api::delete_workflow_schedule(&service, schedule_id, None)
+        .expect("Failed to delete schedule");
+}
@@ -1,5 +1,6 @@
 mod common;
 
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
@@ -1341,6 +1342,13 @@ fn test_json_field_name_compatibility() {
         output_user_data: Some(vec!["out_data".to_string()]),
         output_user_data_regexes: None,
         scheduler: Some("sched".to_string()),
+        priority: None,
+        env: Some(HashMap::from([("KEY".to_string(), "value".to_string())])),
+        working_dir: Some("work".to_string()),
+        shell: Some("sh".to_string()),
+        container: None,
+        run_if: None,
+        concurrency_pool: None,
         parameters: None,
         parameter_mode: None,
         use_parameters: None,
@@ -1362,6 +1370,9 @@ fn test_json_field_name_compatibility() {
     assert!(json.get("input_user_data").is_some());
     assert!(json.get("output_user_data").is_some());
     assert!(json.get("scheduler").is_some());
+    assert!(json.get("env").is_some());
+    assert!(json.get("working_dir").is_some());
+    assert!(json.get("shell").is_some());
 }
 
 #[rstest]
@@ -3066,6 +3077,267 @@ fn test_validate_spec_no_warning_with_scheduler_assignments() {
     );
 }
 
+// =============================================================================
+// Spec Include Tests
+// =============================================================================
+
+/// Test that included files contribute jobs, resources, schedulers, and parameters
+#[test]
+fn test_include_merges_shared_definitions() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::create_dir(temp_dir.path().join("shared")).unwrap();
+    fs::write(
+        temp_dir.path().join("shared/common.yaml"),
+        r#"
+parameters:
+  dataset: "['a','b']"
+resource_requirements:
+  - name: small
+    num_cpus: 1
+    memory: 1g
+    runtime: PT10M
+slurm_schedulers:
+  - name: short
+    account: myproject
+    walltime: "01:00:00"
+jobs:
+  - name: preprocess
+    command: ./preprocess.sh
+    resource_requirements: small
+"#,
+    )
+    .unwrap();
+    let main_path = temp_dir.path().join("main.yaml");
+    fs::write(
+        &main_path,
+        r#"
+name: composed
+include:
+  - path: shared/common.yaml
+parameters:
+  dataset: "['c']"
+jobs:
+  - name: analyze
+    command: ./analyze.sh
+    depends_on: [preprocess]
+    resource_requirements: small
+    scheduler: short
+"#,
+    )
+    .unwrap();
+
+    let spec = WorkflowSpec::from_spec_file(&main_path).expect("Failed to load spec");
+    assert_eq!(spec.name, "composed");
+    assert!(spec.include.is_none());
+    let job_names: Vec<&str> = spec.jobs.iter().map(|j| j.name.as_str()).collect();
+    assert_eq!(job_names, vec!["preprocess", "analyze"]);
+    assert_eq!(spec.resource_requirements.as_ref().unwrap().len(), 1);
+    assert_eq!(
+        spec.slurm_schedulers.as_ref().unwrap()[0].name.as_deref(),
+        Some("short")
+    );
+    // The including file's parameters take precedence
+    assert_eq!(spec.parameters.as_ref().unwrap()["dataset"], "['c']");
+
+    let result = WorkflowSpec::validate_spec(&main_path);
+    assert!(result.valid, "Unexpected errors: {:?}", result.errors);
+    assert_eq!(result.summary.job_count, 2);
+}
+
+/// Test that a prefix renames included definitions and the references between them
+#[test]
+fn test_include_with_prefix() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("stage.yaml"),
+        r#"
+files:
+  - name: data
+    path: output/data.csv
+resource_requirements:
+  - name: small
+    num_cpus: 1
+    memory: 1g
+    runtime: PT10M
+jobs:
+  - name: setup
+    command: ./setup.sh ${files.output.data}
+    resource_requirements: small
+  - name: work_{i}
+    command: ./work.sh {i} ${files.input.data}
+    depends_on: [setup]
+    parameters:
+      i: "1:2"
+  - name: summarize
+    command: ./summarize.sh
+    depends_on_regexes: ["^work_.*"]
+    depends_on: [external_job]
+"#,
+    )
+    .unwrap();
+    let main_path = temp_dir.path().join("main.yaml");
+    fs::write(
+        &main_path,
+        r#"
+name: prefixed
+include:
+  - path: stage.yaml
+    prefix: first_
+  - path: stage.yaml
+    prefix: second_
+jobs:
+  - name: external_job
+    command: echo external
+  - name: report
+    command: ./report.sh
+    depends_on: [first_summarize, second_summarize]
+"#,
+    )
+    .unwrap();
+
+    let spec = WorkflowSpec::from_spec_file(&main_path).expect("Failed to load spec");
+    let first_work = spec
+        .jobs
+        .iter()
+        .find(|j| j.name == "first_work_{i}")
+        .unwrap();
+    assert_eq!(first_work.depends_on, Some(vec!["first_setup".to_string()]));
+    assert_eq!(
+        first_work.command,
+        "./work.sh {i} ${files.input.first_data}"
+    );
+    let first_setup = spec.jobs.iter().find(|j| j.name == "first_setup").unwrap();
+    assert_eq!(
+        first_setup.resource_requirements.as_deref(),
+        Some("first_small")
+    );
+    let summarize = spec
+        .jobs
+        .iter()
+        .find(|j| j.name == "second_summarize")
+        .unwrap();
+    assert_eq!(
+        summarize.depends_on_regexes,
+        Some(vec!["^second_work_.*".to_string()])
+    );
+    // References to names the included file does not define are left alone
+    assert_eq!(summarize.depends_on, Some(vec!["external_job".to_string()]));
+
+    let result = WorkflowSpec::validate_spec(&main_path);
+    assert!(result.valid, "Unexpected errors: {:?}", result.errors);
+    assert_eq!(result.summary.job_count, 10);
+    assert_eq!(result.summary.resource_requirements_count, 2);
+}
+
+/// Test that a file included by several files is merged only once
+#[test]
+fn test_include_shared_file_merged_once() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("resources.json5"),
+        r#"{resource_requirements: [{name: "small", num_cpus: 1, memory: "1g", runtime: "PT10M"}]}"#,
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("prep.yaml"),
+        "include:\n  - path: resources.json5\njobs:\n  - name: prep\n    command: echo prep\n    resource_requirements: small\n",
+    )
+    .unwrap();
+    let main_path = temp_dir.path().join("main.kdl");
+    fs::write(
+        &main_path,
+        r#"
+name "diamond"
+include "resources.json5"
+include "prep.yaml"
+job "main" {
+    command "echo main"
+    depends_on "prep"
+    resource_requirements "small"
+}
+"#,
+    )
+    .unwrap();
+
+    let spec = WorkflowSpec::from_spec_file(&main_path).expect("Failed to load spec");
+    assert_eq!(spec.resource_requirements.as_ref().unwrap().len(), 1);
+    assert_eq!(spec.jobs.len(), 2);
+
+    let result = WorkflowSpec::validate_spec(&main_path);
+    assert!(result.valid, "Unexpected errors: {:?}", result.errors);
+}
+
+/// Test that validate_spec reports include cycles
+#[test]
+fn test_validate_spec_include_cycle() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let a_path = temp_dir.path().join("a.yaml");
+    fs::write(
+        &a_path,
+        "name: cyclic\ninclude:\n  - path: b.yaml\njobs:\n  - name: a\n    command: echo a\n",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("b.yaml"),
+        "include:\n  - path: a.yaml\n    prefix: again_\n",
+    )
+    .unwrap();
+
+    let result = WorkflowSpec::validate_spec(&a_path);
+    assert!(!result.valid, "Expected validation to fail");
+    let error_text = result.errors.join(" ");
+    assert!(
+        error_text.contains("Include cycle detected") && error_text.contains("b.yaml"),
+        "Expected include cycle error, got: {}",
+        error_text
+    );
+    assert!(WorkflowSpec::from_spec_file(&a_path).is_err());
+}
+
+/// Test that validate_spec reports missing and invalid included files
+#[test]
+fn test_validate_spec_include_errors() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let main_path = temp_dir.path().join("main.yaml");
+    fs::write(
+        &main_path,
+        "name: broken\ninclude:\n  - path: missing.yaml\njobs: []\n",
+    )
+    .unwrap();
+    let result = WorkflowSpec::validate_spec(&main_path);
+    assert!(!result.valid);
+    assert!(
+        result.errors[0].contains("Cannot include 'missing.yaml'"),
+        "Unexpected errors: {:?}",
+        result.errors
+    );
+
+    fs::write(temp_dir.path().join("bad.yaml"), "jobs:\n  - command: 5\n").unwrap();
+    fs::write(
+        &main_path,
+        "name: broken\ninclude:\n  - path: bad.yaml\njobs: []\n",
+    )
+    .unwrap();
+    let result = WorkflowSpec::validate_spec(&main_path);
+    assert!(!result.valid);
+    assert!(
+        result.errors[0].contains("Failed to parse included file")
+            && result.errors[0].contains("bad.yaml"),
+        "Unexpected errors: {:?}",
+        result.errors
+    );
+}
+
 // =============================================================================
 // Subgraph Workflow Tests
 // =============================================================================
@@ -3993,3 +4265,167 @@ fn test_subgraph_workflow_execution_plan_spec_vs_database() {
 
     eprintln!("✓ Execution plan from spec matches execution plan from database");
 }
+
+/// Test that a job container parses from YAML and KDL and round-trips through KDL
+#[test]
+fn test_container_spec_parses_in_yaml_and_kdl() {
+    use torc::models::ContainerRuntime;
+
+    let yaml = r#"
+name: containers
+jobs:
+  - name: train_{i}
+    command: python train.py --index {i}
+    parameters:
+      i: "1:2"
+    container:
+      image: docker://python:3.12
+      runtime: podman
+      bind_mounts: [/data, "/scratch/{i}:/scratch"]
+      gpus: true
+      args: [--network=none]
+"#;
+    let kdl = r#"
+name "containers"
+job "train_{i}" {
+    command "python train.py --index {i}"
+    parameters {
+        i "1:2"
+    }
+    container {
+        image "docker://python:3.12"
+        runtime "podman"
+        bind_mounts "/data" "/scratch/{i}:/scratch"
+        gpus #true
+        args "--network=none"
+    }
+}
+"#;
+    for (content, format) in [(yaml, "yaml"), (kdl, "kdl")] {
+        let mut spec = WorkflowSpec::from_spec_file_content(content, format)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", format, e));
+        spec.expand_parameters().expect("Failed to expand");
+        assert_eq!(spec.jobs.len(), 2, "{}", format);
+        let container = spec.jobs[1].container.as_ref().expect("container");
+        assert_eq!(container.image, "docker://python:3.12");
+        assert_eq!(container.runtime, ContainerRuntime::Podman);
+        assert_eq!(
+            container.bind_mounts,
+            Some(vec!["/data".to_string(), "/scratch/2:/scratch".to_string()])
+        );
+        assert_eq!(container.gpus, Some(true));
+        assert_eq!(container.args, Some(vec!["--network=none".to_string()]));
+
+        let round_trip = WorkflowSpec::from_spec_file_content(&spec.to_kdl_str(), "kdl")
+            .expect("Failed to parse serialized KDL");
+        assert_eq!(round_trip.jobs[1].container, spec.jobs[1].container);
+    }
+
+    // The runtime defaults to Apptainer.
+    let spec = WorkflowSpec::from_spec_file_content(
+        "name: c\njobs:\n  - name: a\n    command: hostname\n    container:\n      image: a.sif\n",
+        "yaml",
+    )
+    .unwrap();
+    assert_eq!(
+        spec.jobs[0].container.as_ref().unwrap().runtime,
+        ContainerRuntime::Apptainer
+    );
+}
+
+/// Test that container settings are validated
+#[test]
+fn test_validate_spec_container_errors() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let cases = [
+        ("image: \"\"", "empty image"),
+        (
+            "image: a.sif\n      bind_mounts: [\"\"]",
+            "invalid container bind mount",
+        ),
+        ("image: a.sif\n      runtime: lxc", "unknown variant"),
+    ];
+    for (i, (container, expected)) in cases.iter().enumerate() {
+        let path = temp_dir.path().join(format!("spec{}.yaml", i));
+        fs::write(
+            &path,
+            format!(
+                "name: c\njobs:\n  - name: a\n    command: hostname\n    container:\n      {}\n",
+                container
+            ),
+        )
+        .unwrap();
+        let result = WorkflowSpec::validate_spec(&path);
+        assert!(!result.valid, "Expected errors for {}", container);
+        assert!(
+            result.errors.iter().any(|e| e.contains(expected)),
+            "Expected '{}' in {:?}",
+            expected,
+            result.errors
+        );
+    }
+}
+
+/// Test that a job's container is stored by the server
+#[rstest]
+fn test_create_workflow_with_container(start_server: &ServerProcess) {
+    use torc::models::ContainerRuntime;
+
+    let temp_file = NamedTempFile::with_suffix(".yaml").expect("Failed to create temp file");
+    fs::write(
+        temp_file.path(),
+        r#"
+name: container_workflow
+jobs:
+  - name: in_container
+    command: python -c 'print(1)'
+    container:
+      image: python.sif
+      bind_mounts: [/data]
+      gpus: false
+  - name: on_host
+    command: hostname
+"#,
+    )
+    .unwrap();
+    let workflow_id = WorkflowSpec::create_workflow_from_spec(
+        &start_server.config,
+        temp_file.path(),
+        "test_user",
+        false,
+        false,
+    )
+    .expect("Failed to create workflow");
+
+    let jobs = default_api::list_jobs(
+        &start_server.config,
+        workflow_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("Failed to list jobs")
+    .items
+    .unwrap();
+    let by_name: HashMap<_, _> = jobs.iter().map(|j| (j.name.as_str(), j)).collect();
+    assert!(by_name["on_host"].container.is_none());
+    let listed = by_name["in_container"]
+        .container
+        .as_ref()
+        .expect("container");
+    assert_eq!(listed.image, "python.sif");
+    assert_eq!(listed.runtime, ContainerRuntime::Apptainer);
+    assert_eq!(listed.bind_mounts, Some(vec!["/data".to_string()]));
+
+    let job = default_api::get_job(&start_server.config, by_name["in_container"].id.unwrap())
+        .expect("Failed to get job");
+    assert_eq!(job.container.as_ref(), Some(listed));
+}
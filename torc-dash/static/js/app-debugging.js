@@ -100,7 +100,7 @@ Object.assign(TorcDashboard.prototype, {
             return;
         }
 
-        const statusNames = ['Uninitialized', 'Blocked', 'Ready', 'Pending', 'Running', 'Completed', 'Failed', 'Canceled', 'Terminated', 'Disabled'];
+        const statusNames = ['Uninitialized', 'Blocked', 'Ready', 'Pending', 'Running', 'Completed', 'Failed', 'Canceled', 'Terminated', 'Disabled', 'PendingFailed', 'Skipped'];
 
         container.innerHTML = `
             <table class="data-table">
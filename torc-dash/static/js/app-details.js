@@ -359,7 +359,7 @@ Object.assign(TorcDashboard.prototype, {
     },
 
     renderTableBodyRows(items, tabType, jobNameMap) {
-        const statusNames = ['Uninitialized', 'Blocked', 'Ready', 'Pending', 'Running', 'Completed', 'Failed', 'Canceled', 'Terminated', 'Disabled'];
+        const statusNames = ['Uninitialized', 'Blocked', 'Ready', 'Pending', 'Running', 'Completed', 'Failed', 'Canceled', 'Terminated', 'Disabled', 'PendingFailed', 'Skipped'];
 
         switch (tabType) {
             case 'jobs':
@@ -505,7 +505,7 @@ Object.assign(TorcDashboard.prototype, {
             let itemValue = this.getFieldValue(item, field, tabType, jobNameMap);
 
             if (field === 'status') {
-                const statusNames = ['uninitialized', 'blocked', 'ready', 'pending', 'running', 'completed', 'failed', 'canceled', 'terminated', 'disabled'];
+                const statusNames = ['uninitialized', 'blocked', 'ready', 'pending', 'running', 'completed', 'failed', 'canceled', 'terminated', 'disabled', 'pending_failed', 'skipped'];
                 const filterStatusName = value.toLowerCase();
                 let itemStatusName;
                 if (typeof item.status === 'number') {
@@ -566,7 +566,7 @@ Object.assign(TorcDashboard.prototype, {
     },
 
     getSearchableText(item, tabType, jobNameMap) {
-        const statusNames = ['Uninitialized', 'Blocked', 'Ready', 'Pending', 'Running', 'Completed', 'Failed', 'Canceled', 'Terminated', 'Disabled'];
+        const statusNames = ['Uninitialized', 'Blocked', 'Ready', 'Pending', 'Running', 'Completed', 'Failed', 'Canceled', 'Terminated', 'Disabled', 'PendingFailed', 'Skipped'];
         const parts = [];
         if (item.id != null) parts.push(String(item.id));
         if (item.name) parts.push(item.name);
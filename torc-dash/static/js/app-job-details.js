@@ -144,7 +144,7 @@ Object.assign(TorcDashboard.prototype, {
     },
 
     renderJobDetailsSummary(job) {
-        const statusNames = ['Uninitialized', 'Blocked', 'Ready', 'Pending', 'Running', 'Completed', 'Failed', 'Canceled', 'Terminated', 'Disabled'];
+        const statusNames = ['Uninitialized', 'Blocked', 'Ready', 'Pending', 'Running', 'Completed', 'Failed', 'Canceled', 'Terminated', 'Disabled', 'PendingFailed', 'Skipped'];
         const summaryEl = document.getElementById('job-details-summary');
 
         summaryEl.innerHTML = `
@@ -188,7 +188,7 @@ Object.assign(TorcDashboard.prototype, {
         }
 
         const data = this.jobDetailsData;
-        const statusNames = ['Uninitialized', 'Blocked', 'Ready', 'Pending', 'Running', 'Completed', 'Failed', 'Canceled', 'Terminated', 'Disabled'];
+        const statusNames = ['Uninitialized', 'Blocked', 'Ready', 'Pending', 'Running', 'Completed', 'Failed', 'Canceled', 'Terminated', 'Disabled', 'PendingFailed', 'Skipped'];
 
         switch (tabName) {
             case 'results':
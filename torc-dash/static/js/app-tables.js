@@ -12,7 +12,7 @@ Object.assign(TorcDashboard.prototype, {
             return `${controls}<div class="placeholder-message">No jobs in this workflow</div>`;
         }
 
-        const statusNames = ['Uninitialized', 'Blocked', 'Ready', 'Pending', 'Running', 'Completed', 'Failed', 'Canceled', 'Terminated', 'Disabled'];
+        const statusNames = ['Uninitialized', 'Blocked', 'Ready', 'Pending', 'Running', 'Completed', 'Failed', 'Canceled', 'Terminated', 'Disabled', 'PendingFailed', 'Skipped'];
 
         return `
             ${controls}
@@ -127,7 +127,7 @@ Object.assign(TorcDashboard.prototype, {
             });
         }
 
-        const statusNames = ['Uninitialized', 'Blocked', 'Ready', 'Pending', 'Running', 'Completed', 'Failed', 'Canceled', 'Terminated', 'Disabled'];
+        const statusNames = ['Uninitialized', 'Blocked', 'Ready', 'Pending', 'Running', 'Completed', 'Failed', 'Canceled', 'Terminated', 'Disabled', 'PendingFailed', 'Skipped'];
 
         return `
             ${controls}
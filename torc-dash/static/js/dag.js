@@ -23,6 +23,8 @@ class DAGVisualizer {
         7: '#6f42c1', // canceled
         8: '#adb5bd', // terminated
         9: '#e9ecef', // disabled
+        10: '#e83e8c', // pending_failed
+        11: '#8fd19e', // skipped
     };
 
     static statusNames = {
@@ -36,6 +38,8 @@ class DAGVisualizer {
         7: 'Canceled',
         8: 'Terminated',
         9: 'Disabled',
+        10: 'PendingFailed',
+        11: 'Skipped',
     };
 
     initialize() {
@@ -47,6 +47,10 @@ struct Args {
     /// Password for API authentication (uses USER env var as username)
     #[arg(long, env = "TORC_PASSWORD")]
     password: Option<String>,
+
+    /// API token for bearer authentication
+    #[arg(long, env = "TORC_API_TOKEN", hide_env_values = true)]
+    api_token: Option<String>,
 }
 
 fn main() -> Result<()> {
@@ -77,7 +81,8 @@ fn main() -> Result<()> {
         TorcMcpServer::with_auth(args.api_url, args.output_dir, Some(username), args.password)
     } else {
         TorcMcpServer::new(args.api_url, args.output_dir)
-    };
+    }
+    .with_api_token(args.api_token);
 
     // Build runtime and run the async portion
     // Use multi-threaded runtime to properly support spawn_blocking for the
@@ -44,6 +44,12 @@ impl TorcMcpServer {
 
         Self { config, output_dir }
     }
+
+    /// Authenticate with an API token (bearer authentication).
+    pub fn with_api_token(mut self, api_token: Option<String>) -> Self {
+        self.config.bearer_access_token = api_token;
+        self
+    }
 }
 
 // Tool parameter types
@@ -540,12 +546,13 @@ USE CASES:
 
     /// Read job stdout or stderr logs.
     #[tool(
-        description = "Read job execution logs (stdout or stderr). Optionally return only the last N lines."
+        description = "Read job execution logs (stdout or stderr). Falls back to logs uploaded to the server when the log file is not on this machine. Optionally return only the last N lines."
     )]
     async fn get_job_logs(
         &self,
         #[tool(aggr)] params: GetJobLogsParams,
     ) -> Result<CallToolResult, McpError> {
+        let config = self.config.clone();
         let output_dir = self.output_dir.clone();
         let workflow_id = params.workflow_id;
         let job_id = params.job_id;
@@ -555,6 +562,7 @@ USE CASES:
         let tail_lines = params.tail_lines;
         tokio::task::spawn_blocking(move || {
             tools::get_job_logs(
+                &config,
                 &output_dir,
                 workflow_id,
                 job_id,
@@ -10,7 +10,7 @@ use torc::client::apis::default_api;
 use torc::client::commands::pagination::jobs::{JobListParams, paginate_jobs};
 use torc::client::commands::pagination::results::{ResultListParams, paginate_results};
 use torc::client::log_paths;
-use torc::models::{JobStatus, ResourceRequirementsModel};
+use torc::models::{JobLogStream, JobStatus, ResourceRequirementsModel};
 
 /// Helper to create an internal error
 fn internal_error(msg: String) -> McpError {
@@ -111,8 +111,11 @@ pub fn get_job_details(config: &Configuration, job_id: i64) -> Result<CallToolRe
     )]))
 }
 
-/// Read job logs.
+/// Read job logs. If the log file is not on this machine, read the log that the job runner
+/// uploaded to the server.
+#[allow(clippy::too_many_arguments)]
 pub fn get_job_logs(
+    config: &Configuration,
     output_dir: &Path,
     workflow_id: i64,
     job_id: i64,
@@ -121,18 +124,47 @@ pub fn get_job_logs(
     log_type: &str,
     tail_lines: Option<usize>,
 ) -> Result<CallToolResult, McpError> {
-    let log_path = match log_type.to_lowercase().as_str() {
-        "stdout" => {
-            log_paths::get_job_stdout_path(output_dir, workflow_id, job_id, run_id, attempt_id)
-        }
-        "stderr" => {
-            log_paths::get_job_stderr_path(output_dir, workflow_id, job_id, run_id, attempt_id)
-        }
+    let (log_path, stream) = match log_type.to_lowercase().as_str() {
+        "stdout" => (
+            log_paths::get_job_stdout_path(output_dir, workflow_id, job_id, run_id, attempt_id),
+            JobLogStream::Stdout,
+        ),
+        "stderr" => (
+            log_paths::get_job_stderr_path(output_dir, workflow_id, job_id, run_id, attempt_id),
+            JobLogStream::Stderr,
+        ),
         _ => return Err(invalid_params("log_type must be 'stdout' or 'stderr'")),
     };
 
-    let content = fs::read_to_string(&log_path)
-        .map_err(|e| internal_error(format!("Failed to read log file {}: {}", log_path, e)))?;
+    let content = match fs::read_to_string(&log_path) {
+        Ok(content) => content,
+        Err(e) => {
+            let uploaded = default_api::list_job_logs(
+                config,
+                workflow_id,
+                Some(job_id),
+                Some(run_id),
+                Some(attempt_id),
+                Some(stream),
+                None,
+                None,
+            )
+            .ok()
+            .and_then(|response| response.items)
+            .and_then(|items| items.into_iter().next())
+            .and_then(|job_log| job_log.id)
+            .and_then(|id| default_api::get_job_log_content(config, id).ok());
+            match uploaded {
+                Some(content) => content,
+                None => {
+                    return Err(internal_error(format!(
+                        "Failed to read log file {} and no uploaded log was found on the server: {}",
+                        log_path, e
+                    )));
+                }
+            }
+        }
+    };
 
     let output = if let Some(n) = tail_lines {
         let lines: Vec<&str> = content.lines().collect();
@@ -3,16 +3,15 @@
 use anyhow::Result;
 use clap::{Args, Parser, builder::styling};
 use dotenvy::dotenv;
-use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions};
 use std::env;
 use std::path::PathBuf;
-use std::str::FromStr;
 use torc::config::TorcConfig;
 use tracing::info;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 use tracing_timing::{Builder, Histogram};
 
 mod logging;
+mod notifications;
 mod server;
 mod service;
 
@@ -39,7 +38,8 @@ struct ServerConfig {
     #[arg(short, long, default_value_t = 1)]
     threads: u32,
 
-    /// Path to the SQLite database file. If not specified, uses DATABASE_URL environment variable
+    /// Path to the SQLite database file or a database URL (postgres://... with the `postgres`
+    /// feature). If not specified, uses DATABASE_URL environment variable
     #[arg(short, long)]
     database: Option<String>,
 
@@ -82,6 +82,38 @@ struct ServerConfig {
     #[arg(short, long, env = "TORC_COMPLETION_CHECK_INTERVAL_SECS")]
     completion_check_interval_secs: Option<f64>,
 
+    /// Interval in seconds for background task that detects compute nodes that stopped sending
+    /// heartbeats and fails or resets their running jobs. Defaults to 30s
+    #[arg(long, env = "TORC_WORKER_CHECK_INTERVAL_SECS")]
+    worker_check_interval_secs: Option<f64>,
+
+    /// Retry limit for jobs of dead compute nodes under the `retry` dead worker policy. A job is
+    /// returned to ready only while its attempt_id is less than this value and is failed
+    /// otherwise, as with failure handler rules. Defaults to 3
+    #[arg(long, env = "TORC_DEAD_WORKER_MAX_RETRIES")]
+    dead_worker_max_retries: Option<u32>,
+
+    /// Interval in seconds for background task that fires recurring workflow schedules.
+    /// Defaults to 30s
+    #[arg(long, env = "TORC_SCHEDULE_CHECK_INTERVAL_SECS")]
+    schedule_check_interval_secs: Option<f64>,
+
+    /// Interval in seconds for background task that reloads API tokens from the database, so
+    /// that tokens created or revoked through other servers sharing the database take effect.
+    /// Defaults to 10s
+    #[arg(long, env = "TORC_API_TOKEN_REFRESH_INTERVAL_SECS")]
+    api_token_refresh_interval_secs: Option<f64>,
+
+    /// Maximum size in bytes of an uploaded job log; larger uploads are rejected.
+    /// Defaults to 10 MiB
+    #[arg(long, env = "TORC_JOB_LOG_MAX_BYTES")]
+    job_log_max_bytes: Option<u64>,
+
+    /// Path to the torc CLI that the server runs to reinitialize and submit scheduled workflows.
+    /// Defaults to "torc" (found on PATH)
+    #[arg(long, env = "TORC_BIN")]
+    torc_bin: Option<String>,
+
     /// Users to add to the admin group (can be specified multiple times).
     /// These users can create and manage access groups.
     #[arg(long = "admin-user", env = "TORC_ADMIN_USERS")]
@@ -184,6 +216,21 @@ fn daemonize_process(_pid_file: &std::path::Path) -> Result<()> {
 /// Default completion check interval for `run` command (30 seconds)
 const DEFAULT_RUN_INTERVAL_SECS: f64 = 30.0;
 
+/// Default interval for dead worker detection (30 seconds)
+const DEFAULT_WORKER_CHECK_INTERVAL_SECS: f64 = 30.0;
+
+/// Default retry limit for jobs of dead compute nodes
+const DEFAULT_DEAD_WORKER_MAX_RETRIES: u32 = 3;
+
+/// Default interval for firing workflow schedules (30 seconds)
+const DEFAULT_SCHEDULE_CHECK_INTERVAL_SECS: f64 = 30.0;
+
+/// Default interval for reloading API tokens (10 seconds)
+const DEFAULT_API_TOKEN_REFRESH_INTERVAL_SECS: f64 = 10.0;
+
+/// Default maximum size of an uploaded job log (10 MiB)
+const DEFAULT_JOB_LOG_MAX_BYTES: u64 = 10 * 1024 * 1024;
+
 /// Create custom server, wire it to the autogenerated router,
 /// and pass it to the web server.
 fn main() -> Result<()> {
@@ -286,6 +333,24 @@ fn run_server(cli_config: ServerConfig) -> Result<()> {
         completion_check_interval_secs: cli_config
             .completion_check_interval_secs
             .or(Some(server_file_config.completion_check_interval_secs)),
+        worker_check_interval_secs: cli_config
+            .worker_check_interval_secs
+            .or(Some(server_file_config.worker_check_interval_secs)),
+        dead_worker_max_retries: cli_config
+            .dead_worker_max_retries
+            .or(Some(server_file_config.dead_worker_max_retries)),
+        schedule_check_interval_secs: cli_config
+            .schedule_check_interval_secs
+            .or(Some(server_file_config.schedule_check_interval_secs)),
+        api_token_refresh_interval_secs: cli_config
+            .api_token_refresh_interval_secs
+            .or(Some(server_file_config.api_token_refresh_interval_secs)),
+        job_log_max_bytes: cli_config
+            .job_log_max_bytes
+            .or(Some(server_file_config.job_log_max_bytes)),
+        torc_bin: cli_config
+            .torc_bin
+            .or_else(|| Some(server_file_config.torc_bin.clone())),
         admin_users: cli_config.admin_users,
     };
 
@@ -369,7 +434,7 @@ fn run_server(cli_config: ServerConfig) -> Result<()> {
 
     // Use database path from command line if provided, otherwise fall back to DATABASE_URL env var
     let database_url = if let Some(db_path) = &config.database {
-        format!("sqlite:{}", db_path)
+        torc::server::db::database_url_from_arg(db_path)
     } else {
         env::var("DATABASE_URL").expect("DATABASE_URL must be set or --database must be provided")
     };
@@ -381,18 +446,6 @@ fn run_server(cli_config: ServerConfig) -> Result<()> {
         .build()?;
 
     runtime.block_on(async {
-        // Configure SQLite connection with WAL journal mode for better concurrency
-        // and foreign key constraints enabled
-        let connect_options = SqliteConnectOptions::from_str(&database_url)?
-            .journal_mode(SqliteJournalMode::Wal)
-            .foreign_keys(true)
-            .create_if_missing(true)
-            .busy_timeout(std::time::Duration::from_secs(45));
-
-        let pool = SqlitePoolOptions::new()
-            .connect_with(connect_options)
-            .await?;
-
         let version = env!("CARGO_PKG_VERSION");
         let git_hash = env!("GIT_HASH");
         let git_dirty = env!("GIT_DIRTY");
@@ -400,15 +453,13 @@ fn run_server(cli_config: ServerConfig) -> Result<()> {
             "Starting torc-server version={} ({}{})",
             version, git_hash, git_dirty
         );
-        info!("Connected to database: {}", database_url);
-        info!("Database configured with WAL journal mode and foreign key constraints");
 
-        // Run embedded migrations
-        info!("Running database migrations...");
-        sqlx::migrate!("../migrations")
-            .run(&pool)
+        // Connect and run the embedded migrations for the configured backend
+        info!("Connecting to {} database and running migrations...", torc::server::db::BACKEND_NAME);
+        let pool = torc::server::db::connect(&database_url)
             .await
-            .expect("Failed to run migrations");
+            .expect("Failed to connect to the database and run migrations");
+        info!("Connected to database: {}", database_url);
         info!("Database migrations completed successfully");
 
         // Load htpasswd file if provided
@@ -450,6 +501,22 @@ fn run_server(cli_config: ServerConfig) -> Result<()> {
         let completion_check_interval_secs = config
             .completion_check_interval_secs
             .unwrap_or(DEFAULT_RUN_INTERVAL_SECS);
+        let worker_check_interval_secs = config
+            .worker_check_interval_secs
+            .unwrap_or(DEFAULT_WORKER_CHECK_INTERVAL_SECS);
+        let dead_worker_max_retries = config
+            .dead_worker_max_retries
+            .unwrap_or(DEFAULT_DEAD_WORKER_MAX_RETRIES);
+        let schedule_check_interval_secs = config
+            .schedule_check_interval_secs
+            .unwrap_or(DEFAULT_SCHEDULE_CHECK_INTERVAL_SECS);
+        let api_token_refresh_interval_secs = config
+            .api_token_refresh_interval_secs
+            .unwrap_or(DEFAULT_API_TOKEN_REFRESH_INTERVAL_SECS);
+        let job_log_max_bytes = config
+            .job_log_max_bytes
+            .unwrap_or(DEFAULT_JOB_LOG_MAX_BYTES);
+        let torc_bin = config.torc_bin.unwrap_or_else(|| "torc".to_string());
 
         if config.enforce_access_control {
             info!("Access control is ENABLED - users can only access their own workflows and workflows shared via access groups");
@@ -466,6 +533,15 @@ fn run_server(cli_config: ServerConfig) -> Result<()> {
             info!("Admin users configured: {:?}", admin_users);
         }
 
+        // Notification sinks can only be configured in the config file
+        let notification_sinks = server_file_config.notifications.clone();
+        for (i, sink) in notification_sinks.iter().enumerate() {
+            if let Err(e) = sink.validate() {
+                eprintln!("Error: invalid server.notifications[{}]: {}", i, e);
+                std::process::exit(1);
+            }
+        }
+
         server::create(
             &addr,
             config.https,
@@ -475,7 +551,14 @@ fn run_server(cli_config: ServerConfig) -> Result<()> {
             config.credential_cache_ttl_secs,
             config.enforce_access_control,
             completion_check_interval_secs,
+            worker_check_interval_secs,
+            dead_worker_max_retries,
+            schedule_check_interval_secs,
+            api_token_refresh_interval_secs,
+            job_log_max_bytes,
+            torc_bin,
             admin_users,
+            notification_sinks,
         )
         .await;
         Ok(())
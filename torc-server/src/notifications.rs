@@ -0,0 +1,243 @@
+//! Notification sinks that push workflow events to external services.
+//!
+//! The dispatcher subscribes to the server's event broadcaster and forwards each event that
+//! passes a sink's filters to that sink: an HTTP webhook that receives the event as JSON, a
+//! Slack-compatible incoming webhook, or a local command that reads the event on stdin.
+
+use log::{debug, error, info, warn};
+use std::collections::HashSet;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::broadcast::error::RecvError;
+use torc::config::{NotificationSinkConfig, NotificationSinkType};
+use torc::server::event_broadcast::{BroadcastEvent, EventBroadcaster};
+
+/// Event types that start a new run of a workflow. They reset `first_per_run` sinks.
+const NEW_RUN_EVENT_TYPES: [&str; 2] = ["workflow_started", "workflow_reinitialized"];
+
+/// A configured sink and the workflows it has notified during their current run.
+struct Sink {
+    name: String,
+    config: NotificationSinkConfig,
+    notified_workflows: HashSet<i64>,
+}
+
+impl Sink {
+    fn new(config: NotificationSinkConfig) -> Self {
+        let name = config
+            .name
+            .clone()
+            .unwrap_or_else(|| config.sink_type.to_string());
+        Self {
+            name,
+            config,
+            notified_workflows: HashSet::new(),
+        }
+    }
+
+    /// Return true if the event passes the sink's workflow, event type, and severity filters.
+    fn matches(&self, event: &BroadcastEvent) -> bool {
+        (self.config.workflow_ids.is_empty()
+            || self.config.workflow_ids.contains(&event.workflow_id))
+            && (self.config.event_types.is_empty()
+                || self.config.event_types.contains(&event.event_type))
+            && event.severity >= self.config.min_severity
+    }
+}
+
+/// Start a background task that delivers broadcast events to the configured sinks.
+///
+/// Does nothing if no sinks are configured.
+pub fn start_notification_dispatcher(
+    broadcaster: &EventBroadcaster,
+    sink_configs: Vec<NotificationSinkConfig>,
+) {
+    if sink_configs.is_empty() {
+        return;
+    }
+    let mut sinks: Vec<Sink> = sink_configs.into_iter().map(Sink::new).collect();
+    for sink in &sinks {
+        info!(
+            "Notification sink '{}' ({}) enabled",
+            sink.name, sink.config.sink_type
+        );
+    }
+
+    let mut receiver = broadcaster.subscribe();
+    let client = reqwest::Client::new();
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => dispatch(&client, &mut sinks, event),
+                Err(RecvError::Lagged(count)) => {
+                    warn!(
+                        "Notification dispatcher fell behind and dropped {} events",
+                        count
+                    );
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+/// Hand the event to each matching sink. Deliveries run in their own tasks so that a slow
+/// sink does not hold up the others.
+fn dispatch(client: &reqwest::Client, sinks: &mut [Sink], event: BroadcastEvent) {
+    let is_new_run = NEW_RUN_EVENT_TYPES.contains(&event.event_type.as_str());
+    for sink in sinks.iter_mut() {
+        if is_new_run {
+            sink.notified_workflows.remove(&event.workflow_id);
+        }
+        if !sink.matches(&event) {
+            continue;
+        }
+        if sink.config.first_per_run && !sink.notified_workflows.insert(event.workflow_id) {
+            debug!(
+                "Notification sink '{}' already notified workflow {} during this run",
+                sink.name, event.workflow_id
+            );
+            continue;
+        }
+
+        let client = client.clone();
+        let name = sink.name.clone();
+        let config = sink.config.clone();
+        let event = event.clone();
+        tokio::spawn(async move {
+            let result = match config.sink_type {
+                NotificationSinkType::Webhook => {
+                    let payload = serde_json::to_value(&event).unwrap_or_default();
+                    post_with_retry(&client, &config, &payload).await
+                }
+                NotificationSinkType::Slack => {
+                    let payload = serde_json::json!({ "text": format_message(&event) });
+                    post_with_retry(&client, &config, &payload).await
+                }
+                NotificationSinkType::Command => run_command(&config, &event).await,
+            };
+            match result {
+                Ok(()) => debug!(
+                    "Notification sink '{}' delivered {} event of workflow {}",
+                    name, event.event_type, event.workflow_id
+                ),
+                Err(e) => error!(
+                    "Notification sink '{}' failed to deliver {} event of workflow {}: {}",
+                    name, event.event_type, event.workflow_id, e
+                ),
+            }
+        });
+    }
+}
+
+/// POST the payload to the sink's URL, retrying with exponential backoff.
+async fn post_with_retry(
+    client: &reqwest::Client,
+    config: &NotificationSinkConfig,
+    payload: &serde_json::Value,
+) -> Result<(), String> {
+    let url = config.url.as_deref().unwrap_or_default();
+    let mut delay = Duration::from_secs_f64(config.retry_delay_secs);
+    let mut attempt = 0;
+    loop {
+        let result = client
+            .post(url)
+            .timeout(Duration::from_secs_f64(config.timeout_secs))
+            .json(payload)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        match result {
+            Ok(_) => return Ok(()),
+            Err(e) if attempt < config.max_retries => {
+                attempt += 1;
+                warn!(
+                    "POST to {} failed ({}); retry {} of {} in {:?}",
+                    url, e, attempt, config.max_retries, delay
+                );
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            }
+            Err(e) => return Err(format!("{} (after {} attempts)", e, attempt + 1)),
+        }
+    }
+}
+
+/// Run the sink's command through the shell with the event as JSON on stdin.
+async fn run_command(
+    config: &NotificationSinkConfig,
+    event: &BroadcastEvent,
+) -> Result<(), String> {
+    let command_line = config.command.as_deref().unwrap_or_default();
+    let mut command = if cfg!(windows) {
+        let mut command = tokio::process::Command::new("cmd");
+        command.arg("/C");
+        command
+    } else {
+        let mut command = tokio::process::Command::new("sh");
+        command.arg("-c");
+        command
+    };
+    command
+        .arg(command_line)
+        .env("TORC_EVENT_TYPE", &event.event_type)
+        .env("TORC_EVENT_SEVERITY", event.severity.to_string())
+        .env("TORC_WORKFLOW_ID", event.workflow_id.to_string())
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::piped())
+        .kill_on_drop(true);
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("could not start '{}': {}", command_line, e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        let payload = serde_json::to_vec(event).unwrap_or_default();
+        // The command may exit without reading its input.
+        let _ = stdin.write_all(&payload).await;
+    }
+    let timeout = Duration::from_secs_f64(config.timeout_secs);
+    let output = tokio::time::timeout(timeout, child.wait_with_output())
+        .await
+        .map_err(|_| format!("'{}' timed out after {:?}", command_line, timeout))?
+        .map_err(|e| format!("'{}' failed: {}", command_line, e))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "'{}' exited with {}: {}",
+            command_line,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+/// Format a one-line, human-readable summary of an event for chat services.
+fn format_message(event: &BroadcastEvent) -> String {
+    let data = &event.data;
+    let detail = if let Some(message) = data.get("message").and_then(|v| v.as_str()) {
+        message.to_string()
+    } else if let Some(job_name) = data.get("job_name").and_then(|v| v.as_str()) {
+        let mut detail = format!(
+            "job {} (ID {}) {}",
+            job_name,
+            data.get("job_id")
+                .and_then(|v| v.as_i64())
+                .unwrap_or_default(),
+            data.get("status")
+                .and_then(|v| v.as_str())
+                .unwrap_or("changed status")
+        );
+        if let Some(return_code) = data.get("return_code").and_then(|v| v.as_i64()) {
+            detail.push_str(&format!(" with return code {}", return_code));
+        }
+        detail
+    } else {
+        event.event_type.replace('_', " ")
+    };
+    format!(
+        "*{}* in workflow {} ({}): {}",
+        event.event_type, event.workflow_id, event.severity, detail
+    )
+}
@@ -8,7 +8,7 @@ use chrono::{DateTime, Utc};
 use futures::{Stream, StreamExt, TryFutureExt, TryStreamExt, future};
 use hyper::server::conn::Http;
 use hyper::service::Service;
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 use sqlx::Row;
 use std::collections::hash_set::Union;
 use std::future::Future;
@@ -25,11 +25,14 @@ use swagger::{Has, XSpanIdString};
 use tokio::net::TcpListener;
 use torc::models;
 use torc::server::api::AccessGroupsApiImpl;
+use torc::server::api::ApiTokensApiImpl;
 use torc::server::api::ComputeNodesApi;
 use torc::server::api::EventsApi;
 use torc::server::api::FailureHandlersApi;
 use torc::server::api::FailureHandlersApiImpl;
 use torc::server::api::FilesApi;
+use torc::server::api::JobLogsApi;
+use torc::server::api::JobLogsApiImpl;
 use torc::server::api::JobsApi;
 use torc::server::api::RemoteWorkersApi;
 use torc::server::api::ResourceRequirementsApi;
@@ -37,19 +40,26 @@ use torc::server::api::ResultsApi;
 use torc::server::api::SchedulersApi;
 use torc::server::api::UserDataApi;
 use torc::server::api::WorkflowActionsApi;
+use torc::server::api::WorkflowSchedulesApi;
+use torc::server::api::WorkflowSchedulesApiImpl;
 use torc::server::api::WorkflowsApi;
 use torc::server::api::database_error;
+use torc::server::api::jobs;
+use torc::server::api::workflow_schedules;
+use torc::server::api::workflows::concurrency_pools_from_json;
+use torc::server::api_token_store::ApiTokenStore;
 use torc::server::api_types::*;
 use torc::server::auth::MakeHtpasswdAuthenticator;
 use torc::server::authorization::{AccessCheckResult, AuthorizationService};
 use torc::server::event_broadcast::{BroadcastEvent, EventBroadcaster};
 use torc::server::htpasswd::HtpasswdFile;
+use torc::server::metrics::{MakeMetricsService, ServerMetrics};
 use tracing::instrument;
 
 #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "ios")))]
 use openssl::ssl::{Ssl, SslAcceptor, SslAcceptorBuilder, SslFiletype, SslMethod};
 
-use sqlx::sqlite::SqlitePool;
+use torc::server::db::{self, Db, DbPool, DbTransaction};
 
 const TORC_VERSION: &str = env!("CARGO_PKG_VERSION");
 const GIT_HASH: &str = env!("GIT_HASH");
@@ -62,6 +72,10 @@ fn full_version() -> String {
 
 const MAX_RECORD_TRANSFER_COUNT: i64 = 10_000;
 
+/// Return code recorded for jobs whose compute node stopped sending heartbeats.
+/// Matches the code that `torc` uses for orphaned jobs.
+const DEAD_WORKER_RETURN_CODE: i64 = -128;
+
 /// Process optional offset and limit parameters and return concrete values.
 /// Returns (offset, limit) where:
 /// - offset defaults to 0 if not provided
@@ -89,7 +103,7 @@ fn process_pagination_params(
 ///
 /// Creates the "admin" system group if it doesn't exist and ensures
 /// all configured admin users are members with admin role.
-async fn sync_admin_group(pool: &SqlitePool, admin_users: &[String]) -> Result<(), sqlx::Error> {
+async fn sync_admin_group(pool: &DbPool, admin_users: &[String]) -> Result<(), sqlx::Error> {
     // Create admin group if it doesn't exist
     sqlx::query(
         r#"
@@ -157,13 +171,20 @@ async fn sync_admin_group(pool: &SqlitePool, admin_users: &[String]) -> Result<(
 pub async fn create(
     addr: &str,
     https: bool,
-    pool: SqlitePool,
+    pool: DbPool,
     htpasswd: Option<HtpasswdFile>,
     require_auth: bool,
     credential_cache_ttl_secs: u64,
     enforce_access_control: bool,
     completion_check_interval_secs: f64,
+    worker_check_interval_secs: f64,
+    dead_worker_max_retries: u32,
+    schedule_check_interval_secs: f64,
+    api_token_refresh_interval_secs: f64,
+    job_log_max_bytes: u64,
+    torc_bin: String,
     admin_users: Vec<String>,
+    notification_sinks: Vec<torc::config::NotificationSinkConfig>,
 ) -> u16 {
     // Resolve hostname to socket address (supports both hostnames and IP addresses)
     let addr = tokio::net::lookup_host(addr)
@@ -180,6 +201,17 @@ pub async fn create(
         .local_addr()
         .expect("Failed to get local address");
     let actual_port = actual_addr.port();
+    // Scheduled runs reach this server through its own API with the torc CLI.
+    let api_host = if actual_addr.ip().is_unspecified() {
+        format!("localhost:{}", actual_port)
+    } else {
+        actual_addr.to_string()
+    };
+    let api_url = format!(
+        "{}://{}/torc-service/v1",
+        if https { "https" } else { "http" },
+        api_host
+    );
 
     // Print the actual port in a machine-parseable format for tools like torc-dash
     // This MUST be printed before any other output so it can be reliably parsed
@@ -195,7 +227,17 @@ pub async fn create(
         );
     }
 
-    let server = Server::new(pool.clone(), enforce_access_control);
+    let server = Server::new(pool.clone(), enforce_access_control, job_log_max_bytes);
+    crate::notifications::start_notification_dispatcher(
+        server.get_event_broadcaster(),
+        notification_sinks,
+    );
+
+    match server.load_api_tokens().await {
+        Ok(count) => info!("Loaded {} active API tokens", count),
+        Err(e) => error!("Failed to load API tokens: {}", e),
+    }
+    let api_token_store = server.api_token_store();
 
     // Spawn background task for deferred job unblocking
     let server_clone = server.clone();
@@ -203,14 +245,48 @@ pub async fn create(
         background_unblock_task(server_clone, completion_check_interval_secs).await;
     });
 
+    // Spawn background task for dead worker detection
+    let server_clone = server.clone();
+    tokio::spawn(async move {
+        background_dead_worker_task(
+            server_clone,
+            worker_check_interval_secs,
+            dead_worker_max_retries,
+        )
+        .await;
+    });
+
+    // Spawn background task for recurring workflow schedules
+    let server_clone = server.clone();
+    let launcher = ScheduleLauncher::new(torc_bin, api_url);
+    tokio::spawn(async move {
+        background_schedule_task(server_clone, schedule_check_interval_secs, launcher).await;
+    });
+
+    // Spawn background task that picks up tokens created or revoked by other server instances
+    let server_clone = server.clone();
+    tokio::spawn(async move {
+        background_api_token_refresh_task(server_clone, api_token_refresh_interval_secs).await;
+    });
+
+    let metrics = server.metrics();
     let service = MakeService::new(server);
 
+    let pool = Arc::new(pool);
+    let service = MakeMetricsService::new(
+        service,
+        metrics,
+        pool.clone(),
+        AuthorizationService::new(pool, enforce_access_control),
+    );
+
     let service = MakeHtpasswdAuthenticator::with_cache_ttl(
         service,
         htpasswd,
         require_auth,
         credential_cache_ttl_secs,
-    );
+    )
+    .with_api_tokens(api_token_store);
 
     #[allow(unused_mut)]
     let mut service = torc::server::context::MakeAddContext::<_, EmptyContext>::new(service);
@@ -311,9 +387,23 @@ where
         // Update our checkpoint before processing
         last_checked_time = completion_time;
 
+        let start = std::time::Instant::now();
         if let Err(e) = process_pending_unblocks(&server).await {
             error!("Error processing pending unblocks: {}", e);
         }
+        server.metrics.observe_unblock_task(start.elapsed());
+        // The first pass after startup is not triggered by a completion.
+        if completion_time > 1 {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(completion_time);
+            server
+                .metrics
+                .observe_unblock_delay(std::time::Duration::from_millis(
+                    now.saturating_sub(completion_time),
+                ));
+        }
     }
 }
 
@@ -322,23 +412,25 @@ async fn process_pending_unblocks<C>(server: &Server<C>) -> Result<(), ApiError>
 where
     C: Has<XSpanIdString> + Send + Sync,
 {
-    let completed_status = models::JobStatus::Completed.to_int();
-    let failed_status = models::JobStatus::Failed.to_int();
-    let canceled_status = models::JobStatus::Canceled.to_int();
-    let terminated_status = models::JobStatus::Terminated.to_int();
+    let completed_status = i64::from(models::JobStatus::Completed.to_int());
+    let failed_status = i64::from(models::JobStatus::Failed.to_int());
+    let canceled_status = i64::from(models::JobStatus::Canceled.to_int());
+    let terminated_status = i64::from(models::JobStatus::Terminated.to_int());
+    let skipped_status = i64::from(models::JobStatus::Skipped.to_int());
 
     // Find all workflows with unprocessed completions
     let workflows = match sqlx::query!(
         r#"
         SELECT DISTINCT workflow_id
         FROM job
-        WHERE status IN (?, ?, ?, ?)
+        WHERE status IN ($1, $2, $3, $4, $5)
           AND unblocking_processed = 0
         "#,
         completed_status,
         failed_status,
         canceled_status,
-        terminated_status
+        terminated_status,
+        skipped_status
     )
     .fetch_all(server.pool.as_ref())
     .await
@@ -375,12 +467,9 @@ where
     Ok(())
 }
 
-/// Check if an error is a SQLite database lock error
+/// Check if an error is a database lock or serialization error
 fn is_database_lock_error(error: &ApiError) -> bool {
-    let error_str = error.0.to_lowercase();
-    error_str.contains("database is locked")
-        || error_str.contains("database is busy")
-        || error_str.contains("sqlite_busy")
+    db::is_lock_error_message(&error.0)
 }
 
 /// Process all pending unblocks for a specific workflow
@@ -402,6 +491,7 @@ where
             Ok(()) => return Ok(()),
             Err(e) => {
                 if is_database_lock_error(&e) && attempt < MAX_RETRIES - 1 {
+                    server.metrics.record_database_lock_retry();
                     debug!(
                         "Database locked for workflow {}, retrying in {}ms (attempt {}/{})",
                         workflow_id,
@@ -431,10 +521,11 @@ async fn process_workflow_unblocks_inner<C>(
 where
     C: Has<XSpanIdString> + Send + Sync,
 {
-    let completed_status = models::JobStatus::Completed.to_int();
-    let failed_status = models::JobStatus::Failed.to_int();
-    let canceled_status = models::JobStatus::Canceled.to_int();
-    let terminated_status = models::JobStatus::Terminated.to_int();
+    let completed_status = i64::from(models::JobStatus::Completed.to_int());
+    let failed_status = i64::from(models::JobStatus::Failed.to_int());
+    let canceled_status = i64::from(models::JobStatus::Canceled.to_int());
+    let terminated_status = i64::from(models::JobStatus::Terminated.to_int());
+    let skipped_status = i64::from(models::JobStatus::Skipped.to_int());
 
     let mut tx = match server.pool.begin().await {
         Ok(tx) => tx,
@@ -454,15 +545,16 @@ where
         FROM job j
         JOIN result r ON j.id = r.job_id
         JOIN workflow_status ws ON j.workflow_id = ws.id AND r.run_id = ws.run_id
-        WHERE j.workflow_id = ?
-          AND j.status IN (?, ?, ?, ?)
+        WHERE j.workflow_id = $1
+          AND j.status IN ($2, $3, $4, $5, $6)
           AND j.unblocking_processed = 0
         "#,
         workflow_id,
         completed_status,
         failed_status,
         canceled_status,
-        terminated_status
+        terminated_status,
+        skipped_status
     )
     .fetch_all(&mut *tx)
     .await
@@ -601,10 +693,988 @@ where
     Ok(())
 }
 
+/// Background task that periodically looks for compute nodes that stopped sending heartbeats.
+///
+/// Job runners send a heartbeat while they run. A compute node whose last heartbeat is older
+/// than its workflow's `worker_heartbeat_timeout_seconds` is marked inactive, and its running
+/// jobs are failed or returned to ready according to the workflow's `dead_worker_policy`.
+/// Under the `retry` policy, jobs whose `attempt_id` has reached `max_retries` are failed.
+/// Compute nodes that have never sent a heartbeat are ignored.
+async fn background_dead_worker_task<C>(server: Server<C>, interval_seconds: f64, max_retries: u32)
+where
+    C: Has<XSpanIdString> + Send + Sync,
+{
+    info!(
+        "Starting background dead worker checker with interval = {} seconds",
+        interval_seconds
+    );
+
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs_f64(interval_seconds));
+    loop {
+        interval.tick().await;
+        if let Err(e) = process_dead_workers(&server, max_retries).await {
+            error!("Error processing dead workers: {}", e);
+        }
+    }
+}
+
+/// Find compute nodes whose heartbeats have timed out and handle their jobs.
+async fn process_dead_workers<C>(server: &Server<C>, max_retries: u32) -> Result<(), ApiError>
+where
+    C: Has<XSpanIdString> + Send + Sync,
+{
+    let now_ms = Utc::now().timestamp_millis();
+    let records = match sqlx::query(
+        r#"
+        SELECT cn.id, cn.workflow_id, cn.hostname, cn.last_heartbeat, w.dead_worker_policy
+        FROM compute_node cn
+        JOIN workflow w ON cn.workflow_id = w.id
+        WHERE cn.is_active = 1
+          AND cn.last_heartbeat IS NOT NULL
+          AND w.worker_heartbeat_timeout_seconds > 0
+          AND cn.last_heartbeat < $1 - w.worker_heartbeat_timeout_seconds * 1000
+        "#,
+    )
+    .bind(now_ms)
+    .fetch_all(server.pool.as_ref())
+    .await
+    {
+        Ok(records) => records,
+        Err(e) => {
+            error!("Database error finding unresponsive compute nodes: {}", e);
+            return Err(ApiError("Database error".to_string()));
+        }
+    };
+
+    for record in records {
+        let compute_node_id: i64 = record.get("id");
+        let workflow_id: i64 = record.get("workflow_id");
+        let hostname: String = record.get("hostname");
+        let last_heartbeat: i64 = record.get("last_heartbeat");
+        let policy = record
+            .get::<Option<String>, _>("dead_worker_policy")
+            .and_then(|p| p.parse::<models::DeadWorkerPolicy>().ok())
+            .unwrap_or_default();
+        if let Err(e) = handle_dead_compute_node(
+            server,
+            workflow_id,
+            compute_node_id,
+            &hostname,
+            last_heartbeat,
+            policy,
+            max_retries,
+        )
+        .await
+        {
+            error!(
+                "Error handling dead compute node {} in workflow {}: {}",
+                compute_node_id, workflow_id, e
+            );
+            // Continue with the other compute nodes
+        }
+    }
+
+    Ok(())
+}
+
+/// Mark a compute node inactive and fail or reset the jobs that it was running.
+async fn handle_dead_compute_node<C>(
+    server: &Server<C>,
+    workflow_id: i64,
+    compute_node_id: i64,
+    hostname: &str,
+    last_heartbeat: i64,
+    policy: models::DeadWorkerPolicy,
+    max_retries: u32,
+) -> Result<(), ApiError>
+where
+    C: Has<XSpanIdString> + Send + Sync,
+{
+    // The compute node may have deactivated itself since the query; only one caller wins.
+    match sqlx::query("UPDATE compute_node SET is_active = 0 WHERE id = $1 AND is_active = 1")
+        .bind(compute_node_id)
+        .execute(server.pool.as_ref())
+        .await
+    {
+        Ok(result) if result.rows_affected() == 0 => return Ok(()),
+        Ok(_) => {}
+        Err(e) => return Err(database_error(e)),
+    }
+
+    let run_id: i64 = match sqlx::query_scalar("SELECT run_id FROM workflow_status WHERE id = $1")
+        .bind(workflow_id)
+        .fetch_one(server.pool.as_ref())
+        .await
+    {
+        Ok(run_id) => run_id,
+        Err(e) => return Err(database_error(e)),
+    };
+
+    let running_status = i64::from(models::JobStatus::Running.to_int());
+    let jobs = match sqlx::query(
+        r#"
+        SELECT j.id, j.name, j.command, j.attempt_id
+        FROM job j
+        JOIN job_internal ji ON j.id = ji.job_id
+        WHERE ji.active_compute_node_id = $1
+          AND j.status = $2
+        "#,
+    )
+    .bind(compute_node_id)
+    .bind(running_status)
+    .fetch_all(server.pool.as_ref())
+    .await
+    {
+        Ok(jobs) => jobs,
+        Err(e) => return Err(database_error(e)),
+    };
+
+    let last_heartbeat_str = DateTime::from_timestamp_millis(last_heartbeat)
+        .map(|t| t.to_rfc3339())
+        .unwrap_or_default();
+    warn!(
+        "Compute node is unresponsive workflow_id={} compute_node_id={} hostname={} \
+        last_heartbeat={} running_jobs={} policy={}",
+        workflow_id,
+        compute_node_id,
+        hostname,
+        last_heartbeat_str,
+        jobs.len(),
+        policy
+    );
+
+    let mut job_ids = Vec::new();
+    let mut failed_job_ids = Vec::new();
+    for record in &jobs {
+        let job_id: i64 = record.get("id");
+        let job_name: String = record.get("name");
+        let attempt_id: i64 = record.get("attempt_id");
+        let fail = match policy {
+            models::DeadWorkerPolicy::Fail => true,
+            models::DeadWorkerPolicy::Retry => attempt_id >= i64::from(max_retries),
+        };
+        let result = if fail {
+            let mut job = models::JobModel::new(workflow_id, job_name, record.get("command"));
+            job.id = Some(job_id);
+            job.status = Some(models::JobStatus::Failed);
+            fail_job_of_dead_compute_node(server, &job, run_id, attempt_id, compute_node_id).await
+        } else {
+            // The next attempt starts from ready, as with retry_job.
+            let ready_status = i64::from(models::JobStatus::Ready.to_int());
+            sqlx::query(
+                r#"
+                UPDATE job
+                SET status = $1, attempt_id = $2
+                WHERE id = $3 AND status = $4 AND attempt_id = $5
+                "#,
+            )
+            .bind(ready_status)
+            .bind(attempt_id + 1)
+            .bind(job_id)
+            .bind(running_status)
+            .bind(attempt_id)
+            .execute(server.pool.as_ref())
+            .await
+            .map(|result| result.rows_affected() > 0)
+            .map_err(database_error)
+        };
+        match result {
+            Ok(true) => {}
+            // The job finished or was reset since it was found.
+            Ok(false) => continue,
+            Err(e) => {
+                error!(
+                    "Failed to apply dead worker policy {} to job_id={}: {}",
+                    policy, job_id, e
+                );
+                continue;
+            }
+        }
+        if let Err(e) =
+            sqlx::query("UPDATE job_internal SET active_compute_node_id = NULL WHERE job_id = $1")
+                .bind(job_id)
+                .execute(server.pool.as_ref())
+                .await
+        {
+            error!(
+                "Failed to clear active_compute_node_id for job_id={}: {}",
+                job_id, e
+            );
+        }
+        if fail {
+            failed_job_ids.push(job_id);
+        }
+        job_ids.push(job_id);
+    }
+
+    let message = format!(
+        "Compute node {} on {} stopped sending heartbeats; {} running jobs set to ready, {} set \
+        to failed",
+        compute_node_id,
+        hostname,
+        job_ids.len() - failed_job_ids.len(),
+        failed_job_ids.len()
+    );
+    let data = serde_json::json!({
+        "compute_node_id": compute_node_id,
+        "hostname": hostname,
+        "last_heartbeat": last_heartbeat_str,
+        "dead_worker_policy": policy.to_string(),
+        "job_ids": job_ids,
+        "failed_job_ids": failed_job_ids,
+    });
+    let mut event_data = data.clone();
+    event_data["event_type"] = serde_json::json!("compute_node_unresponsive");
+    event_data["message"] = serde_json::json!(message);
+    let now_ms = Utc::now().timestamp_millis();
+    if let Err(e) =
+        sqlx::query("INSERT INTO event (workflow_id, timestamp, data) VALUES ($1, $2, $3)")
+            .bind(workflow_id)
+            .bind(now_ms)
+            .bind(event_data.to_string())
+            .execute(server.pool.as_ref())
+            .await
+    {
+        error!(
+            "Failed to create event for unresponsive compute node {}: {}",
+            compute_node_id, e
+        );
+    }
+    server.event_broadcaster.broadcast(BroadcastEvent {
+        workflow_id,
+        timestamp: now_ms,
+        event_type: "compute_node_unresponsive".to_string(),
+        severity: models::EventSeverity::Error,
+        data,
+    });
+
+    if !failed_job_ids.is_empty()
+        && let Err(e) = server
+            .workflow_actions_api
+            .check_and_trigger_actions(workflow_id, "on_jobs_complete", Some(failed_job_ids))
+            .await
+    {
+        error!(
+            "Failed to check_and_trigger_actions for on_jobs_complete: {}",
+            e
+        );
+    }
+
+    Ok(())
+}
+
+/// Record a failed result for a job whose compute node died and set the job to failed.
+///
+/// This mirrors `complete_job`, using the return code that orphan detection uses for jobs
+/// whose job runner went away. The status change and the result are written in one transaction,
+/// and only if the job is still running the same attempt of the current run. Returns false if
+/// the job finished, was retried, or was reset since the dead compute node was found.
+async fn fail_job_of_dead_compute_node<C>(
+    server: &Server<C>,
+    job: &models::JobModel,
+    run_id: i64,
+    attempt_id: i64,
+    compute_node_id: i64,
+) -> Result<bool, ApiError>
+where
+    C: Has<XSpanIdString> + Send + Sync,
+{
+    let job_id = job
+        .id
+        .ok_or_else(|| ApiError("Job ID is required".to_string()))?;
+    let failed_status = i64::from(models::JobStatus::Failed.to_int());
+    let running_status = i64::from(models::JobStatus::Running.to_int());
+    let completion_time = Utc::now().to_rfc3339();
+
+    let mut tx = db::begin_write(server.pool.as_ref(), job.workflow_id)
+        .await
+        .map_err(database_error)?;
+
+    // Set unblocking_processed = 0 so the background task processes the failure.
+    match sqlx::query(
+        r#"
+        UPDATE job
+        SET status = $1, unblocking_processed = 0
+        WHERE id = $2
+          AND status = $3
+          AND attempt_id = $4
+          AND EXISTS (SELECT 1 FROM workflow_status WHERE id = $5 AND run_id = $6)
+        "#,
+    )
+    .bind(failed_status)
+    .bind(job_id)
+    .bind(running_status)
+    .bind(attempt_id)
+    .bind(job.workflow_id)
+    .bind(run_id)
+    .execute(&mut *tx)
+    .await
+    {
+        Ok(result) if result.rows_affected() == 0 => {
+            let _ = tx.rollback().await;
+            debug!(
+                "Job {} is no longer running attempt {} of run {}; leaving it alone",
+                job_id, attempt_id, run_id
+            );
+            return Ok(false);
+        }
+        Ok(_) => {}
+        Err(e) => {
+            let _ = tx.rollback().await;
+            return Err(database_error(e));
+        }
+    }
+
+    let result_id: i64 = match sqlx::query_scalar(
+        r#"
+        INSERT INTO result
+        (
+            job_id
+            ,workflow_id
+            ,run_id
+            ,attempt_id
+            ,compute_node_id
+            ,return_code
+            ,exec_time_minutes
+            ,completion_time
+            ,status
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING id
+        "#,
+    )
+    .bind(job_id)
+    .bind(job.workflow_id)
+    .bind(run_id)
+    .bind(attempt_id)
+    .bind(compute_node_id)
+    .bind(DEAD_WORKER_RETURN_CODE)
+    .bind(0.0f64)
+    .bind(&completion_time)
+    .bind(failed_status)
+    .fetch_one(&mut *tx)
+    .await
+    {
+        Ok(id) => id,
+        Err(e) => {
+            let _ = tx.rollback().await;
+            return Err(database_error(e));
+        }
+    };
+
+    if let Err(e) = sqlx::query(
+        r#"
+        INSERT INTO workflow_result (workflow_id, job_id, result_id)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (workflow_id, job_id) DO UPDATE SET result_id = excluded.result_id
+        "#,
+    )
+    .bind(job.workflow_id)
+    .bind(job_id)
+    .bind(result_id)
+    .execute(&mut *tx)
+    .await
+    {
+        let _ = tx.rollback().await;
+        return Err(database_error(e));
+    }
+
+    if let Err(e) = tx.commit().await {
+        return Err(database_error(e));
+    }
+    server.signal_job_completion();
+
+    server.event_broadcaster.broadcast(BroadcastEvent {
+        workflow_id: job.workflow_id,
+        timestamp: Utc::now().timestamp_millis(),
+        event_type: "job_failed".to_string(),
+        severity: models::EventSeverity::Error,
+        data: serde_json::json!({
+            "job_id": job_id,
+            "job_name": job.name,
+            "status": models::JobStatus::Failed.to_string(),
+            "return_code": DEAD_WORKER_RETURN_CODE,
+        }),
+    });
+    Ok(true)
+}
+
+/// Settings that the schedule task uses to start scheduled workflow runs with the torc CLI.
+#[derive(Clone)]
+pub struct ScheduleLauncher {
+    /// Path to the torc executable
+    torc_bin: String,
+    /// URL of this server's API, passed to the CLI with --url
+    api_url: String,
+    /// Operating system account that runs the CLI commands. Only schedules created by the user
+    /// of the same name are fired.
+    service_user: Option<String>,
+    /// Schedules whose reinitialize and submit commands are still running
+    in_flight: Arc<std::sync::Mutex<std::collections::HashSet<i64>>>,
+}
+
+impl ScheduleLauncher {
+    pub fn new(torc_bin: String, api_url: String) -> Self {
+        Self {
+            torc_bin,
+            api_url,
+            service_user: ["USER", "LOGNAME"]
+                .iter()
+                .filter_map(|name| std::env::var(name).ok())
+                .find(|user| !user.is_empty()),
+            in_flight: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+        }
+    }
+}
+
+/// Background task that fires workflow schedules whose next run time has passed.
+async fn background_schedule_task<C>(
+    server: Server<C>,
+    interval_seconds: f64,
+    launcher: ScheduleLauncher,
+) where
+    C: Has<XSpanIdString> + Clone + Send + Sync + 'static,
+{
+    info!(
+        "Starting background schedule checker with interval = {} seconds, torc_bin = {}, api_url = {}, service_user = {}",
+        interval_seconds,
+        launcher.torc_bin,
+        launcher.api_url,
+        launcher.service_user.as_deref().unwrap_or("unknown")
+    );
+
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs_f64(interval_seconds));
+    loop {
+        interval.tick().await;
+        if let Err(e) = process_due_schedules(&server, &launcher).await {
+            error!("Error processing workflow schedules: {}", e);
+        }
+    }
+}
+
+/// Background task that periodically reloads API tokens from the database.
+async fn background_api_token_refresh_task<C>(server: Server<C>, interval_seconds: f64)
+where
+    C: Has<XSpanIdString> + Clone + Send + Sync + 'static,
+{
+    info!(
+        "Starting background API token refresh with interval = {} seconds",
+        interval_seconds
+    );
+
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs_f64(interval_seconds));
+    // The first tick completes immediately; tokens were just loaded at startup.
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        if let Err(e) = server.load_api_tokens().await {
+            error!("Error refreshing API tokens: {}", e);
+        }
+    }
+}
+
+/// Fire, skip, or hold each unpaused schedule that is due.
+async fn process_due_schedules<C>(
+    server: &Server<C>,
+    launcher: &ScheduleLauncher,
+) -> Result<(), ApiError>
+where
+    C: Has<XSpanIdString> + Clone + Send + Sync + 'static,
+{
+    let now = Utc::now();
+    let records = match sqlx::query(
+        r#"
+        SELECT id, workflow_id, cron_expression, timezone, overlap_policy, working_directory,
+            created_by, next_run_time
+        FROM workflow_schedule
+        WHERE is_paused = 0 AND next_run_time IS NOT NULL AND next_run_time <= $1
+        ORDER BY next_run_time
+        "#,
+    )
+    .bind(now.timestamp_millis())
+    .fetch_all(server.pool.as_ref())
+    .await
+    {
+        Ok(records) => records,
+        Err(e) => {
+            error!("Database error finding due workflow schedules: {}", e);
+            return Err(ApiError("Database error".to_string()));
+        }
+    };
+
+    for record in records {
+        let schedule_id: i64 = record.get("id");
+        let workflow_id: i64 = record.get("workflow_id");
+        let cron_expression: String = record.get("cron_expression");
+        let timezone: String = record.get("timezone");
+        let policy = record
+            .get::<String, _>("overlap_policy")
+            .parse::<models::ScheduleOverlapPolicy>()
+            .unwrap_or_default();
+        let working_directory: Option<String> = record.get("working_directory");
+        let created_by: Option<String> = record.get("created_by");
+        let due_run_time: i64 = record.get("next_run_time");
+
+        // Missed run times collapse into one firing, so the next run is computed from now.
+        let next_run_time =
+            match workflow_schedules::next_run_time(&cron_expression, &timezone, now) {
+                Ok(next) => Some(next.timestamp_millis()),
+                Err(e) => {
+                    error!(
+                        "Workflow schedule {} will not fire again: {}",
+                        schedule_id, e
+                    );
+                    None
+                }
+            };
+
+        let launching = launcher
+            .in_flight
+            .lock()
+            .map(|in_flight| in_flight.contains(&schedule_id))
+            .unwrap_or(false);
+        if launching || is_workflow_run_active(server, workflow_id).await? {
+            match policy {
+                models::ScheduleOverlapPolicy::Skip => {
+                    if !claim_schedule_run(server, schedule_id, due_run_time, next_run_time, None)
+                        .await?
+                    {
+                        continue;
+                    }
+                    let message = format!(
+                        "Skipped scheduled run of workflow {} because the previous run is still active",
+                        workflow_id
+                    );
+                    info!("Workflow schedule {}: {}", schedule_id, message);
+                    record_schedule_event(
+                        server,
+                        workflow_id,
+                        "schedule_skipped",
+                        models::EventSeverity::Warning,
+                        &message,
+                        serde_json::json!({ "schedule_id": schedule_id }),
+                    )
+                    .await;
+                }
+                models::ScheduleOverlapPolicy::Queue => {
+                    debug!(
+                        "Workflow schedule {} is waiting for the previous run of workflow {} to finish",
+                        schedule_id, workflow_id
+                    );
+                }
+            }
+            continue;
+        }
+
+        // Another server sharing the database may have claimed this run time first.
+        if !claim_schedule_run(
+            server,
+            schedule_id,
+            due_run_time,
+            next_run_time,
+            Some(now.timestamp_millis()),
+        )
+        .await?
+        {
+            continue;
+        }
+        if let Ok(mut in_flight) = launcher.in_flight.lock() {
+            in_flight.insert(schedule_id);
+        }
+        info!(
+            "Workflow schedule {} fired for workflow {} ('{}' {})",
+            schedule_id, workflow_id, cron_expression, timezone
+        );
+        let server = server.clone();
+        let launcher = launcher.clone();
+        tokio::spawn(async move {
+            launch_scheduled_run(
+                &server,
+                &launcher,
+                schedule_id,
+                workflow_id,
+                working_directory,
+                created_by,
+            )
+            .await;
+            if let Ok(mut in_flight) = launcher.in_flight.lock() {
+                in_flight.remove(&schedule_id);
+            }
+        });
+    }
+
+    Ok(())
+}
+
+/// Return true if any job of the workflow's current run is blocked, ready, pending, or running.
+async fn is_workflow_run_active<C>(server: &Server<C>, workflow_id: i64) -> Result<bool, ApiError>
+where
+    C: Has<XSpanIdString> + Send + Sync,
+{
+    match sqlx::query(
+        r#"
+        SELECT COUNT(*) AS active
+        FROM job
+        WHERE workflow_id = $1 AND status IN ($2, $3, $4, $5)
+        "#,
+    )
+    .bind(workflow_id)
+    .bind(models::JobStatus::Blocked.to_int())
+    .bind(models::JobStatus::Ready.to_int())
+    .bind(models::JobStatus::Pending.to_int())
+    .bind(models::JobStatus::Running.to_int())
+    .fetch_one(server.pool.as_ref())
+    .await
+    {
+        Ok(row) => Ok(row.get::<i64, _>("active") > 0),
+        Err(e) => {
+            error!(
+                "Database error checking active jobs of workflow {}: {}",
+                workflow_id, e
+            );
+            Err(ApiError("Database error".to_string()))
+        }
+    }
+}
+
+/// Advance a schedule past its due run time. Returns false if the run time was already claimed,
+/// such as by another server sharing the database.
+async fn claim_schedule_run<C>(
+    server: &Server<C>,
+    schedule_id: i64,
+    due_run_time: i64,
+    next_run_time: Option<i64>,
+    last_run_time: Option<i64>,
+) -> Result<bool, ApiError>
+where
+    C: Has<XSpanIdString> + Send + Sync,
+{
+    match sqlx::query(
+        r#"
+        UPDATE workflow_schedule
+        SET next_run_time = $1, last_run_time = COALESCE($2, last_run_time)
+        WHERE id = $3 AND next_run_time = $4
+        "#,
+    )
+    .bind(next_run_time)
+    .bind(last_run_time)
+    .bind(schedule_id)
+    .bind(due_run_time)
+    .execute(server.pool.as_ref())
+    .await
+    {
+        Ok(result) => Ok(result.rows_affected() == 1),
+        Err(e) => {
+            error!(
+                "Database error updating workflow schedule {}: {}",
+                schedule_id, e
+            );
+            Err(ApiError("Database error".to_string()))
+        }
+    }
+}
+
+/// Lifetime of the API token issued to the CLI commands of a scheduled run. Jobs submitted by the
+/// run inherit the token, so it must outlive the run rather than just the commands. The token is
+/// revoked when the schedule next fires, so each schedule has at most one active token.
+const SCHEDULED_RUN_TOKEN_LIFETIME_DAYS: i64 = 7;
+
+/// Decide whether a due schedule may run and return the API token for its commands.
+///
+/// The commands run as the server's operating system account, so a schedule only fires if it
+/// was created by the user of that name and the user can still access the workflow. Schedules
+/// without a recorded creator fire only when access control is not enforced, and then without a
+/// token.
+async fn authorize_scheduled_run<C>(
+    server: &Server<C>,
+    launcher: &ScheduleLauncher,
+    schedule_id: i64,
+    workflow_id: i64,
+    created_by: Option<&str>,
+    working_directory: Option<&str>,
+) -> Result<Option<String>, String>
+where
+    C: Has<XSpanIdString> + Send + Sync,
+{
+    if let Some(dir) = working_directory {
+        workflow_schedules::validate_working_directory(dir)?;
+    }
+
+    let Some(user) = created_by else {
+        if server.authorization_service.is_enforced() {
+            return Err(
+                "the schedule has no recorded creator and access control is enforced".to_string(),
+            );
+        }
+        return Ok(None);
+    };
+
+    if launcher.service_user.as_deref() != Some(user) {
+        return Err(format!(
+            "the schedule was created by '{}' but the server runs commands as '{}'",
+            user,
+            launcher
+                .service_user
+                .as_deref()
+                .unwrap_or("an unknown account")
+        ));
+    }
+
+    let auth = Some(Authorization {
+        subject: user.to_string(),
+        scopes: swagger::auth::Scopes::Some(std::collections::BTreeSet::new()),
+        issuer: None,
+    });
+    match server
+        .authorization_service
+        .check_workflow_access(&auth, workflow_id)
+        .await
+    {
+        AccessCheckResult::Allowed => {}
+        AccessCheckResult::Denied(reason) | AccessCheckResult::NotFound(reason) => {
+            return Err(format!("'{}' cannot access the workflow: {}", user, reason));
+        }
+    }
+
+    // A schedule only fires after its previous run has finished, so that run's token is no
+    // longer needed.
+    let token_name = format!("schedule-{}", schedule_id);
+    server
+        .api_tokens_api
+        .revoke_tokens_named(&token_name, user)
+        .await
+        .map_err(|e| format!("could not revoke the previous API token: {}", e.0))?;
+
+    let expires_at =
+        (Utc::now() + chrono::Duration::days(SCHEDULED_RUN_TOKEN_LIFETIME_DAYS)).naive_utc();
+    server
+        .api_tokens_api
+        .issue_token(&token_name, user, expires_at)
+        .await
+        .map(Some)
+        .map_err(|e| format!("could not issue an API token: {}", e.0))
+}
+
+/// Reinitialize the workflow (bumping its run_id) and submit it, as a user would with
+/// `torc workflows reinitialize` and `torc workflows submit`, and record the outcome as an event.
+async fn launch_scheduled_run<C>(
+    server: &Server<C>,
+    launcher: &ScheduleLauncher,
+    schedule_id: i64,
+    workflow_id: i64,
+    working_directory: Option<String>,
+    created_by: Option<String>,
+) where
+    C: Has<XSpanIdString> + Send + Sync,
+{
+    let token = match authorize_scheduled_run(
+        server,
+        launcher,
+        schedule_id,
+        workflow_id,
+        created_by.as_deref(),
+        working_directory.as_deref(),
+    )
+    .await
+    {
+        Ok(token) => token,
+        Err(reason) => {
+            let message = format!(
+                "Refused scheduled run of workflow {}: {}",
+                workflow_id, reason
+            );
+            error!("Workflow schedule {}: {}", schedule_id, message);
+            record_schedule_event(
+                server,
+                workflow_id,
+                "schedule_failed",
+                models::EventSeverity::Error,
+                &message,
+                serde_json::json!({ "schedule_id": schedule_id }),
+            )
+            .await;
+            return;
+        }
+    };
+
+    for subcommand in ["reinitialize", "submit"] {
+        let mut command = tokio::process::Command::new(&launcher.torc_bin);
+        command
+            .arg("--url")
+            .arg(&launcher.api_url)
+            .arg("--format")
+            .arg("json")
+            .arg("workflows")
+            .arg(subcommand)
+            .arg(workflow_id.to_string())
+            .stdin(std::process::Stdio::null());
+        if let Some(dir) = &working_directory {
+            command.current_dir(dir);
+        }
+        if let Some(token) = &token {
+            command.env("TORC_API_TOKEN", token);
+        }
+
+        let failure = match command.output().await {
+            Ok(output) if output.status.success() => None,
+            Ok(output) => Some(format!(
+                "exited with {}: {} {}",
+                output.status,
+                String::from_utf8_lossy(&output.stdout).trim(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )),
+            Err(e) => Some(format!("could not be started: {}", e)),
+        };
+        if let Some(reason) = failure {
+            let message = format!(
+                "Scheduled run of workflow {} failed: torc workflows {} {}",
+                workflow_id, subcommand, reason
+            );
+            error!("Workflow schedule {}: {}", schedule_id, message);
+            record_schedule_event(
+                server,
+                workflow_id,
+                "schedule_failed",
+                models::EventSeverity::Error,
+                &message,
+                serde_json::json!({ "schedule_id": schedule_id, "command": subcommand }),
+            )
+            .await;
+            return;
+        }
+    }
+
+    let run_id = sqlx::query(
+        "SELECT ws.run_id FROM workflow w JOIN workflow_status ws ON w.status_id = ws.id WHERE w.id = $1",
+    )
+        .bind(workflow_id)
+        .fetch_optional(server.pool.as_ref())
+        .await
+        .ok()
+        .flatten()
+        .map(|row| row.get::<i64, _>("run_id"));
+    let message = format!(
+        "Started scheduled run {} of workflow {}",
+        run_id.map_or_else(|| "?".to_string(), |id| id.to_string()),
+        workflow_id
+    );
+    info!("Workflow schedule {}: {}", schedule_id, message);
+    record_schedule_event(
+        server,
+        workflow_id,
+        "schedule_fired",
+        models::EventSeverity::Info,
+        &message,
+        serde_json::json!({ "schedule_id": schedule_id, "run_id": run_id }),
+    )
+    .await;
+}
+
+/// Store a schedule event and broadcast it to SSE clients.
+async fn record_schedule_event<C>(
+    server: &Server<C>,
+    workflow_id: i64,
+    event_type: &str,
+    severity: models::EventSeverity,
+    message: &str,
+    data: serde_json::Value,
+) where
+    C: Has<XSpanIdString> + Send + Sync,
+{
+    let mut data = data;
+    data["message"] = serde_json::json!(message);
+    let mut event_data = data.clone();
+    event_data["event_type"] = serde_json::json!(event_type);
+    let now_ms = Utc::now().timestamp_millis();
+    if let Err(e) =
+        sqlx::query("INSERT INTO event (workflow_id, timestamp, data) VALUES ($1, $2, $3)")
+            .bind(workflow_id)
+            .bind(now_ms)
+            .bind(event_data.to_string())
+            .execute(server.pool.as_ref())
+            .await
+    {
+        error!(
+            "Failed to create {} event for workflow {}: {}",
+            event_type, workflow_id, e
+        );
+    }
+    server.event_broadcaster.broadcast(BroadcastEvent {
+        workflow_id,
+        timestamp: now_ms,
+        event_type: event_type.to_string(),
+        severity,
+        data,
+    });
+}
+
+/// Remaining capacity under a workflow's concurrency limits while jobs are being claimed.
+///
+/// Pending and running jobs count against the limits. Jobs in pools that the workflow does not
+/// define are only limited by `max_concurrent_jobs`.
+struct ConcurrencySlots {
+    /// Remaining slots under the workflow's `max_concurrent_jobs`, or None if unlimited.
+    workflow: Option<i64>,
+    /// Remaining slots in each concurrency pool.
+    pools: std::collections::HashMap<String, i64>,
+}
+
+impl ConcurrencySlots {
+    /// Pools that cannot accept any more jobs, sorted by name.
+    fn full_pools(&self) -> Vec<&str> {
+        let mut pools: Vec<&str> = self
+            .pools
+            .iter()
+            .filter(|(_, remaining)| **remaining <= 0)
+            .map(|(name, _)| name.as_str())
+            .collect();
+        pools.sort_unstable();
+        pools
+    }
+
+    /// SQL condition that excludes jobs in full pools. Pool names are bound as parameters
+    /// starting at `first_param`, in the order returned by `full_pools`.
+    fn full_pools_clause(&self, column: &str, first_param: usize) -> String {
+        let count = self.full_pools().len();
+        if count == 0 {
+            return String::new();
+        }
+        let params: Vec<String> = (first_param..first_param + count)
+            .map(|i| format!("${}", i))
+            .collect();
+        format!(
+            "AND ({column} IS NULL OR {column} NOT IN ({}))",
+            params.join(", ")
+        )
+    }
+
+    fn is_workflow_full(&self) -> bool {
+        self.workflow.is_some_and(|remaining| remaining <= 0)
+    }
+
+    /// Return true if a job in `pool` can be claimed without exceeding a limit.
+    fn has_slot(&self, pool: Option<&str>) -> bool {
+        !self.is_workflow_full()
+            && pool
+                .and_then(|pool| self.pools.get(pool))
+                .is_none_or(|remaining| *remaining > 0)
+    }
+
+    /// Record that a job in `pool` was claimed.
+    fn take(&mut self, pool: Option<&str>) {
+        if let Some(remaining) = self.workflow.as_mut() {
+            *remaining -= 1;
+        }
+        if let Some(remaining) = pool.and_then(|pool| self.pools.get_mut(pool)) {
+            *remaining -= 1;
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Server<C> {
     marker: PhantomData<C>,
-    pool: Arc<SqlitePool>,
+    pool: Arc<DbPool>,
     /// Timestamp (Unix millis) of the last job completion. Used by the background
     /// unblock task to skip processing when no new completions have occurred.
     last_completion_time: Arc<AtomicU64>,
@@ -616,11 +1686,15 @@ pub struct Server<C> {
     authorization_service: AuthorizationService,
     /// Event broadcaster for SSE clients
     event_broadcaster: EventBroadcaster,
+    /// Metrics served at `/metrics`
+    metrics: ServerMetrics,
     access_groups_api: AccessGroupsApiImpl,
+    api_tokens_api: ApiTokensApiImpl,
     compute_nodes_api: ComputeNodesApiImpl,
     events_api: EventsApiImpl,
     failure_handlers_api: FailureHandlersApiImpl,
     files_api: FilesApiImpl,
+    job_logs_api: JobLogsApiImpl,
     jobs_api: JobsApiImpl,
     remote_workers_api: RemoteWorkersApiImpl,
     resource_requirements_api: ResourceRequirementsApiImpl,
@@ -628,11 +1702,12 @@ pub struct Server<C> {
     schedulers_api: SchedulersApiImpl,
     user_data_api: UserDataApiImpl,
     workflow_actions_api: WorkflowActionsApiImpl,
+    workflow_schedules_api: WorkflowSchedulesApiImpl,
     workflows_api: WorkflowsApiImpl,
 }
 
 impl<C> Server<C> {
-    pub fn new(pool: SqlitePool, enforce_access_control: bool) -> Self {
+    pub fn new(pool: DbPool, enforce_access_control: bool, job_log_max_bytes: u64) -> Self {
         let pool_arc = Arc::new(pool);
         let api_context = ApiContext::new(pool_arc.as_ref().clone());
         let authorization_service =
@@ -649,11 +1724,14 @@ impl<C> Server<C> {
             )),
             authorization_service,
             event_broadcaster: EventBroadcaster::new(512),
+            metrics: ServerMetrics::new(),
             access_groups_api: AccessGroupsApiImpl::new(api_context.clone()),
+            api_tokens_api: ApiTokensApiImpl::new(api_context.clone()),
             compute_nodes_api: ComputeNodesApiImpl::new(api_context.clone()),
             events_api: EventsApiImpl::new(api_context.clone()),
             failure_handlers_api: FailureHandlersApiImpl::new(api_context.clone()),
             files_api: FilesApiImpl::new(api_context.clone()),
+            job_logs_api: JobLogsApiImpl::new(api_context.clone(), job_log_max_bytes),
             jobs_api: JobsApiImpl::new(api_context.clone()),
             remote_workers_api: RemoteWorkersApiImpl::new(api_context.clone()),
             resource_requirements_api: ResourceRequirementsApiImpl::new(api_context.clone()),
@@ -661,6 +1739,7 @@ impl<C> Server<C> {
             schedulers_api: SchedulersApiImpl::new(api_context.clone()),
             user_data_api: UserDataApiImpl::new(api_context.clone()),
             workflow_actions_api: WorkflowActionsApiImpl::new(api_context.clone()),
+            workflow_schedules_api: WorkflowSchedulesApiImpl::new(api_context.clone()),
             workflows_api: WorkflowsApiImpl::new(api_context.clone()),
         }
     }
@@ -679,6 +1758,62 @@ impl<C> Server<C> {
         &self.event_broadcaster
     }
 
+    /// Get the metrics shared with the metrics middleware.
+    pub fn metrics(&self) -> ServerMetrics {
+        self.metrics.clone()
+    }
+
+    /// Get the store of active API tokens, shared with the authentication middleware.
+    pub fn api_token_store(&self) -> ApiTokenStore {
+        self.api_tokens_api.store.clone()
+    }
+
+    /// Load active API tokens from the database into the token store.
+    pub async fn load_api_tokens(&self) -> Result<usize, ApiError> {
+        self.api_tokens_api.load_tokens().await
+    }
+
+    /// Broadcast a workflow_completed event. The severity is Warning if any job failed.
+    async fn broadcast_workflow_completed(&self, workflow_id: i64) {
+        let failed_status = i64::from(models::JobStatus::Failed.to_int());
+        let num_failed_jobs: i64 =
+            match sqlx::query("SELECT COUNT(*) FROM job WHERE workflow_id = $1 AND status = $2")
+                .bind(workflow_id)
+                .bind(failed_status)
+                .fetch_one(self.pool.as_ref())
+                .await
+            {
+                Ok(row) => row.get(0),
+                Err(e) => {
+                    error!(
+                        "Failed to count failed jobs of workflow {}: {}",
+                        workflow_id, e
+                    );
+                    0
+                }
+            };
+        let severity = if num_failed_jobs > 0 {
+            models::EventSeverity::Warning
+        } else {
+            models::EventSeverity::Info
+        };
+        self.event_broadcaster.broadcast(BroadcastEvent {
+            workflow_id,
+            timestamp: Utc::now().timestamp_millis(),
+            event_type: "workflow_completed".to_string(),
+            severity,
+            data: serde_json::json!({
+                "category": "workflow",
+                "type": "workflow_completed",
+                "num_failed_jobs": num_failed_jobs,
+                "message": format!(
+                    "workflow {} completed with {} failed jobs",
+                    workflow_id, num_failed_jobs
+                ),
+            }),
+        });
+    }
+
     /// Create an association between a job and a file.
     /// The table name must be job_input_file or job_output_file.
     async fn add_job_file_association(
@@ -765,10 +1900,10 @@ impl<C> Server<C> {
         workflow_id: i64,
     ) -> Result<(), ApiError>
     where
-        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+        E: sqlx::Executor<'e, Database = Db>,
     {
         match sqlx::query!(
-            r#"INSERT OR IGNORE INTO job_depends_on (job_id, depends_on_job_id, workflow_id)
+            r#"INSERT INTO job_depends_on (job_id, depends_on_job_id, workflow_id)
             SELECT
                 i.job_id AS job_id
                 ,o.job_id AS depends_on_job_id
@@ -776,6 +1911,7 @@ impl<C> Server<C> {
             FROM job_input_file i
             JOIN job_output_file o ON i.file_id = o.file_id
             WHERE i.workflow_id = $1
+            ON CONFLICT DO NOTHING
             "#,
             workflow_id
         )
@@ -798,10 +1934,10 @@ impl<C> Server<C> {
         workflow_id: i64,
     ) -> Result<(), ApiError>
     where
-        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+        E: sqlx::Executor<'e, Database = Db>,
     {
         match sqlx::query!(
-            r#"INSERT OR IGNORE INTO job_depends_on (job_id, depends_on_job_id, workflow_id)
+            r#"INSERT INTO job_depends_on (job_id, depends_on_job_id, workflow_id)
             SELECT
                 i.job_id AS job_id
                 ,o.job_id AS depends_on_job_id
@@ -810,6 +1946,7 @@ impl<C> Server<C> {
             JOIN job_output_user_data o ON i.user_data_id = o.user_data_id
             JOIN job j ON i.job_id = j.id
             WHERE j.workflow_id = $1
+            ON CONFLICT DO NOTHING
             "#,
             workflow_id
         )
@@ -831,9 +1968,9 @@ impl<C> Server<C> {
         workflow_id: i64,
     ) -> Result<(), ApiError>
     where
-        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+        E: sqlx::Executor<'e, Database = Db>,
     {
-        let uninitialized_status = models::JobStatus::Uninitialized.to_int();
+        let uninitialized_status = i64::from(models::JobStatus::Uninitialized.to_int());
 
         // Use a recursive CTE to find all jobs that should be uninitialized
         // based on the transitive closure of job dependencies
@@ -853,13 +1990,70 @@ impl<C> Server<C> {
                 WHERE jbb.workflow_id = $1
             )
             UPDATE job
-            SET status = $2
-            WHERE workflow_id = $1
-            AND id IN (SELECT job_id FROM jobs_to_uninitialize)
+            SET status = $2
+            WHERE workflow_id = $1
+            AND id IN (SELECT job_id FROM jobs_to_uninitialize)
+            "#,
+            workflow_id,
+            uninitialized_status
+        )
+        .execute(executor)
+        .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                error!("Database error: {}", e);
+                Err(ApiError("Database error".to_string()))
+            }
+        }
+    }
+
+    /// Clear the retry backoff and excluded host of the workflow's uninitialized jobs.
+    async fn clear_uninitialized_retry_holds<'e, E>(
+        &self,
+        executor: E,
+        workflow_id: i64,
+    ) -> Result<(), ApiError>
+    where
+        E: sqlx::Executor<'e, Database = Db>,
+    {
+        let uninitialized_status = i64::from(models::JobStatus::Uninitialized.to_int());
+        match sqlx::query(
+            r#"
+            UPDATE job
+            SET not_before = NULL, excluded_hostname = NULL
+            WHERE workflow_id = $1
+            AND status = $2
+            AND (not_before IS NOT NULL OR excluded_hostname IS NOT NULL)
+            "#,
+        )
+        .bind(workflow_id)
+        .bind(uninitialized_status)
+        .execute(executor)
+        .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                error!("Database error: {}", e);
+                Err(ApiError("Database error".to_string()))
+            }
+        }
+    }
+
+    /// Clear the retry backoff and excluded host of one job.
+    async fn clear_retry_holds<'e, E>(&self, executor: E, job_id: i64) -> Result<(), ApiError>
+    where
+        E: sqlx::Executor<'e, Database = Db>,
+    {
+        match sqlx::query(
+            r#"
+            UPDATE job
+            SET not_before = NULL, excluded_hostname = NULL
+            WHERE id = $1
+            AND (not_before IS NOT NULL OR excluded_hostname IS NOT NULL)
             "#,
-            workflow_id,
-            uninitialized_status
         )
+        .bind(job_id)
         .execute(executor)
         .await
         {
@@ -881,14 +2075,15 @@ impl<C> Server<C> {
         only_uninitialized: bool,
     ) -> Result<(), ApiError>
     where
-        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+        E: sqlx::Executor<'e, Database = Db>,
     {
-        let uninitialized_status = models::JobStatus::Uninitialized.to_int();
-        let blocked_status = models::JobStatus::Blocked.to_int();
-        let completed_status = models::JobStatus::Completed.to_int();
-        let failed_status = models::JobStatus::Failed.to_int();
-        let canceled_status = models::JobStatus::Canceled.to_int();
-        let terminated_status = models::JobStatus::Terminated.to_int();
+        let uninitialized_status = i64::from(models::JobStatus::Uninitialized.to_int());
+        let blocked_status = i64::from(models::JobStatus::Blocked.to_int());
+        let completed_status = i64::from(models::JobStatus::Completed.to_int());
+        let failed_status = i64::from(models::JobStatus::Failed.to_int());
+        let canceled_status = i64::from(models::JobStatus::Canceled.to_int());
+        let terminated_status = i64::from(models::JobStatus::Terminated.to_int());
+        let skipped_status = i64::from(models::JobStatus::Skipped.to_int());
 
         let sql = if only_uninitialized {
             r#"
@@ -901,7 +2096,7 @@ impl<C> Server<C> {
                 FROM job_depends_on jbb
                 JOIN job j ON jbb.depends_on_job_id = j.id
                 WHERE jbb.workflow_id = $2
-                AND j.status NOT IN ($4, $5, $6, $7)
+                AND j.status NOT IN ($4, $5, $6, $7, $8)
             )
             "#
         } else {
@@ -914,7 +2109,7 @@ impl<C> Server<C> {
                 FROM job_depends_on jbb
                 JOIN job j ON jbb.depends_on_job_id = j.id
                 WHERE jbb.workflow_id = $2
-                AND j.status NOT IN ($3, $4, $5, $6)
+                AND j.status NOT IN ($3, $4, $5, $6, $7)
             )
             "#
         };
@@ -928,6 +2123,7 @@ impl<C> Server<C> {
                 .bind(failed_status)
                 .bind(canceled_status)
                 .bind(terminated_status)
+                .bind(skipped_status)
         } else {
             sqlx::query(sql)
                 .bind(blocked_status)
@@ -936,6 +2132,7 @@ impl<C> Server<C> {
                 .bind(failed_status)
                 .bind(canceled_status)
                 .bind(terminated_status)
+                .bind(skipped_status)
         };
 
         match query.execute(executor).await {
@@ -1016,20 +2213,21 @@ impl<C> Server<C> {
         workflow_id: i64,
     ) -> Result<(), ApiError>
     where
-        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+        E: sqlx::Executor<'e, Database = Db>,
     {
-        let disabled = models::JobStatus::Disabled.to_int();
-        let blocked = models::JobStatus::Blocked.to_int();
-        let completed = models::JobStatus::Completed.to_int();
-        let failed = models::JobStatus::Failed.to_int();
-        let canceled = models::JobStatus::Canceled.to_int();
-        let ready = models::JobStatus::Ready.to_int();
+        let disabled = i64::from(models::JobStatus::Disabled.to_int());
+        let blocked = i64::from(models::JobStatus::Blocked.to_int());
+        let completed = i64::from(models::JobStatus::Completed.to_int());
+        let failed = i64::from(models::JobStatus::Failed.to_int());
+        let canceled = i64::from(models::JobStatus::Canceled.to_int());
+        let skipped = i64::from(models::JobStatus::Skipped.to_int());
+        let ready = i64::from(models::JobStatus::Ready.to_int());
         match sqlx::query!(
             r#"
             UPDATE job
             SET status = $1
             WHERE workflow_id = $2
-            AND status NOT IN ($3, $4, $5, $6, $7, $8)
+            AND status NOT IN ($3, $4, $5, $6, $7, $8, $9)
             "#,
             ready,
             workflow_id,
@@ -1038,6 +2236,7 @@ impl<C> Server<C> {
             completed,
             failed,
             canceled,
+            skipped,
             ready,
         )
         .execute(executor)
@@ -1051,6 +2250,143 @@ impl<C> Server<C> {
         }
     }
 
+    /// Look up the hostname of the compute node claiming jobs, if the worker identified itself.
+    /// Jobs whose previous attempt failed on this host are not returned to it.
+    async fn claiming_hostname(
+        conn: &mut db::DbConnection,
+        compute_node_id: Option<i64>,
+    ) -> Result<Option<String>, ApiError> {
+        let Some(compute_node_id) = compute_node_id else {
+            return Ok(None);
+        };
+        sqlx::query_scalar("SELECT hostname FROM compute_node WHERE id = $1")
+            .bind(compute_node_id)
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(|e| {
+                error!("Database error looking up compute node hostname: {}", e);
+                ApiError("Database error".to_string())
+            })
+    }
+
+    /// Compute the remaining capacity under a workflow's concurrency limits. Must be called
+    /// inside the claiming transaction so that concurrent claims cannot exceed the limits.
+    async fn concurrency_slots(
+        conn: &mut db::DbConnection,
+        workflow_id: i64,
+    ) -> Result<ConcurrencySlots, ApiError> {
+        let row = sqlx::query(
+            "SELECT max_concurrent_jobs, concurrency_pools FROM workflow WHERE id = $1",
+        )
+        .bind(workflow_id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!(
+                "Database error looking up workflow concurrency limits: {}",
+                e
+            );
+            ApiError("Database error".to_string())
+        })?;
+        let Some(row) = row else {
+            return Ok(ConcurrencySlots {
+                workflow: None,
+                pools: std::collections::HashMap::new(),
+            });
+        };
+        let max_concurrent_jobs: Option<i64> = row.get("max_concurrent_jobs");
+        let pools = concurrency_pools_from_json(row.get("concurrency_pools")).unwrap_or_default();
+        if max_concurrent_jobs.is_none() && pools.is_empty() {
+            return Ok(ConcurrencySlots {
+                workflow: None,
+                pools,
+            });
+        }
+
+        let counts = sqlx::query(
+            r#"
+            SELECT concurrency_pool, COUNT(*) AS num_jobs
+            FROM job
+            WHERE workflow_id = $1 AND status IN ($2, $3)
+            GROUP BY concurrency_pool
+            "#,
+        )
+        .bind(workflow_id)
+        .bind(i64::from(models::JobStatus::Pending.to_int()))
+        .bind(i64::from(models::JobStatus::Running.to_int()))
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("Database error counting active jobs: {}", e);
+            ApiError("Database error".to_string())
+        })?;
+
+        let mut slots = ConcurrencySlots {
+            workflow: max_concurrent_jobs,
+            pools,
+        };
+        for row in counts {
+            let pool: Option<String> = row.get("concurrency_pool");
+            let num_jobs: i64 = row.get("num_jobs");
+            if let Some(remaining) = slots.workflow.as_mut() {
+                *remaining -= num_jobs;
+            }
+            if let Some(remaining) = pool.and_then(|pool| slots.pools.get_mut(&pool)) {
+                *remaining -= num_jobs;
+            }
+        }
+        Ok(slots)
+    }
+
+    /// Return the ID of the multi-node job that a compute node is reserved for. Reservations
+    /// for jobs that are no longer pending or running are ignored.
+    async fn reserving_job_id(&self, compute_node_id: i64) -> Result<Option<i64>, ApiError> {
+        sqlx::query_scalar(
+            r#"
+            SELECT job.id
+            FROM compute_node
+            JOIN job ON job.id = compute_node.reserved_for_job_id
+            WHERE compute_node.id = $1 AND job.status IN ($2, $3)
+            "#,
+        )
+        .bind(compute_node_id)
+        .bind(i64::from(models::JobStatus::Pending.to_int()))
+        .bind(i64::from(models::JobStatus::Running.to_int()))
+        .fetch_optional(self.pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("Database error looking up compute node reservation: {}", e);
+            ApiError("Database error".to_string())
+        })
+    }
+
+    /// Release the compute nodes reserved for a multi-node job.
+    async fn release_compute_node_reservations(&self, job_id: i64) {
+        match sqlx::query(
+            "UPDATE compute_node SET reserved_for_job_id = NULL WHERE reserved_for_job_id = $1",
+        )
+        .bind(job_id)
+        .execute(self.pool.as_ref())
+        .await
+        {
+            Ok(result) => {
+                if result.rows_affected() > 0 {
+                    debug!(
+                        "Released {} compute node reservations for job_id={}",
+                        result.rows_affected(),
+                        job_id
+                    );
+                }
+            }
+            Err(e) => {
+                error!(
+                    "Failed to release compute node reservations for job_id={}: {}",
+                    job_id, e
+                );
+            }
+        }
+    }
+
     /// Validate that the provided run_id matches the workflow's current run_id.
     ///
     /// # Arguments
@@ -1062,7 +2398,7 @@ impl<C> Server<C> {
     /// * `Err(String)` with an error message if validation fails
     async fn validate_run_id(&self, workflow_id: i64, provided_run_id: i64) -> Result<(), String> {
         let workflow_status = match sqlx::query!(
-            "SELECT run_id FROM workflow_status WHERE id = ?",
+            "SELECT run_id FROM workflow_status WHERE id = $1",
             workflow_id
         )
         .fetch_optional(self.pool.as_ref())
@@ -1135,7 +2471,7 @@ impl<C> Server<C> {
 
         // 1. Look up current job status from database
         let current_job =
-            match sqlx::query!("SELECT status, workflow_id FROM job WHERE id = ?", job_id)
+            match sqlx::query!("SELECT status, workflow_id FROM job WHERE id = $1", job_id)
                 .fetch_optional(self.pool.as_ref())
                 .await
             {
@@ -1185,7 +2521,7 @@ impl<C> Server<C> {
         // 3. For terminal statuses, check if result exists
         if new_status.is_complete() {
             let result_record = match sqlx::query!(
-                "SELECT return_code FROM result WHERE job_id = ? AND run_id = ?",
+                "SELECT return_code FROM result WHERE job_id = $1 AND run_id = $2",
                 job_id,
                 run_id
             )
@@ -1213,12 +2549,12 @@ impl<C> Server<C> {
 
         // 4. Update the job status in the database
         // If transitioning to a complete status, mark as needing unblock processing
-        let new_status_int = new_status.to_int();
+        let new_status_int = i64::from(new_status.to_int());
 
         if new_status.is_complete() {
             // Set unblocking_processed = 0 so background task will process this
             match sqlx::query!(
-                "UPDATE job SET status = ?, unblocking_processed = 0 WHERE id = ?",
+                "UPDATE job SET status = $1, unblocking_processed = 0 WHERE id = $2",
                 new_status_int,
                 job_id
             )
@@ -1254,7 +2590,7 @@ impl<C> Server<C> {
         } else {
             // For non-complete statuses, just update status
             match sqlx::query!(
-                "UPDATE job SET status = ? WHERE id = ?",
+                "UPDATE job SET status = $1 WHERE id = $2",
                 new_status_int,
                 job_id
             )
@@ -1310,17 +2646,18 @@ impl<C> Server<C> {
     /// * `Ok(Vec<i64>)` - IDs of jobs that became ready
     /// * `Err(ApiError)` if there's a database error
     async fn unblock_jobs_fast_path_tx(
-        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        tx: &mut DbTransaction<'_>,
         workflow_id: i64,
         completed_job_id: i64,
         workflow_has_failures: bool,
     ) -> Result<Vec<i64>, ApiError> {
-        let completed_status = models::JobStatus::Completed.to_int();
-        let failed_status = models::JobStatus::Failed.to_int();
-        let canceled_status = models::JobStatus::Canceled.to_int();
-        let terminated_status = models::JobStatus::Terminated.to_int();
-        let ready_status = models::JobStatus::Ready.to_int();
-        let blocked_status = models::JobStatus::Blocked.to_int();
+        let completed_status = i64::from(models::JobStatus::Completed.to_int());
+        let failed_status = i64::from(models::JobStatus::Failed.to_int());
+        let canceled_status = i64::from(models::JobStatus::Canceled.to_int());
+        let terminated_status = i64::from(models::JobStatus::Terminated.to_int());
+        let skipped_status = i64::from(models::JobStatus::Skipped.to_int());
+        let ready_status = i64::from(models::JobStatus::Ready.to_int());
+        let blocked_status = i64::from(models::JobStatus::Blocked.to_int());
 
         // Only run the expensive cancellation query if we know there are failed jobs.
         // The workflow_has_failures flag is tracked in memory to avoid database queries.
@@ -1328,22 +2665,22 @@ impl<C> Server<C> {
             let _canceled = sqlx::query!(
                 r#"
                 UPDATE job
-                SET status = ?
-                WHERE workflow_id = ?
-                  AND status = ?
-                  AND cancel_on_blocking_job_failure = 1
+                SET status = $1
+                WHERE workflow_id = $2
+                  AND status = $3
+                  AND cancel_on_blocking_job_failure = true
                   AND id IN (
                       SELECT jbb.job_id
                       FROM job_depends_on jbb
-                      WHERE jbb.depends_on_job_id = ?
-                        AND jbb.workflow_id = ?
+                      WHERE jbb.depends_on_job_id = $4
+                        AND jbb.workflow_id = $5
                         AND NOT EXISTS (
                             SELECT 1
                             FROM job_depends_on jbb2
                             JOIN job j2 ON jbb2.depends_on_job_id = j2.id
                             WHERE jbb2.job_id = jbb.job_id
-                              AND jbb2.depends_on_job_id != ?
-                              AND j2.status NOT IN (?, ?, ?, ?)
+                              AND jbb2.depends_on_job_id != $6
+                              AND j2.status NOT IN ($7, $8, $9, $10, $11)
                         )
                   )
                   AND EXISTS (
@@ -1353,8 +2690,8 @@ impl<C> Server<C> {
                       JOIN result r_fail ON j_fail.id = r_fail.job_id
                       JOIN workflow_status ws ON j_fail.workflow_id = ws.id AND r_fail.run_id = ws.run_id
                       WHERE jbb_fail.job_id = job.id
-                        AND jbb_fail.workflow_id = ?
-                        AND j_fail.status IN (?, ?, ?)
+                        AND jbb_fail.workflow_id = $12
+                        AND j_fail.status IN ($13, $14, $15)
                         AND r_fail.return_code != 0
                   )
                 "#,
@@ -1368,6 +2705,7 @@ impl<C> Server<C> {
                 failed_status,
                 canceled_status,
                 terminated_status,
+                skipped_status,
                 workflow_id,
                 failed_status,
                 canceled_status,
@@ -1382,21 +2720,21 @@ impl<C> Server<C> {
         let updated_jobs = match sqlx::query!(
             r#"
             UPDATE job
-            SET status = ?
-            WHERE workflow_id = ?
-              AND status = ?
+            SET status = $1
+            WHERE workflow_id = $2
+              AND status = $3
               AND id IN (
                   SELECT jbb.job_id
                   FROM job_depends_on jbb
-                  WHERE jbb.depends_on_job_id = ?
-                    AND jbb.workflow_id = ?
+                  WHERE jbb.depends_on_job_id = $4
+                    AND jbb.workflow_id = $5
                     AND NOT EXISTS (
                         SELECT 1
                         FROM job_depends_on jbb2
                         JOIN job j2 ON jbb2.depends_on_job_id = j2.id
                         WHERE jbb2.job_id = jbb.job_id
-                          AND jbb2.depends_on_job_id != ?
-                          AND j2.status NOT IN (?, ?, ?, ?)
+                          AND jbb2.depends_on_job_id != $6
+                          AND j2.status NOT IN ($7, $8, $9, $10, $11)
                     )
               )
             RETURNING id
@@ -1410,7 +2748,8 @@ impl<C> Server<C> {
             completed_status,
             failed_status,
             canceled_status,
-            terminated_status
+            terminated_status,
+            skipped_status
         )
         .fetch_all(&mut **tx)
         .await
@@ -1445,16 +2784,17 @@ impl<C> Server<C> {
     /// * `Ok(Vec<i64>)` - IDs of jobs that became ready
     /// * `Err(ApiError)` if there's a database error
     async fn unblock_jobs_slow_path_tx(
-        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        tx: &mut DbTransaction<'_>,
         workflow_id: i64,
         completed_job_id: i64,
     ) -> Result<Vec<i64>, ApiError> {
-        let completed_status = models::JobStatus::Completed.to_int();
-        let failed_status = models::JobStatus::Failed.to_int();
-        let canceled_status = models::JobStatus::Canceled.to_int();
-        let terminated_status = models::JobStatus::Terminated.to_int();
-        let ready_status = models::JobStatus::Ready.to_int();
-        let blocked_status = models::JobStatus::Blocked.to_int();
+        let completed_status = i64::from(models::JobStatus::Completed.to_int());
+        let failed_status = i64::from(models::JobStatus::Failed.to_int());
+        let canceled_status = i64::from(models::JobStatus::Canceled.to_int());
+        let terminated_status = i64::from(models::JobStatus::Terminated.to_int());
+        let skipped_status = i64::from(models::JobStatus::Skipped.to_int());
+        let ready_status = i64::from(models::JobStatus::Ready.to_int());
+        let blocked_status = i64::from(models::JobStatus::Blocked.to_int());
 
         // Use a recursive CTE to find all jobs that need status updates (including cascading
         // cancellations). The CTE propagates return codes through the dependency chain to
@@ -1468,15 +2808,15 @@ impl<C> Server<C> {
                     -- Cancel if: ANY dependency has failed (status is failed/canceled/terminated with non-zero return code)
                     -- AND cancel_on_blocking_job_failure = true. We check ALL failed dependencies, not just the current one.
                     CASE
-                        WHEN j.cancel_on_blocking_job_failure != 0 AND EXISTS (
+                        WHEN j.cancel_on_blocking_job_failure = true AND EXISTS (
                             SELECT 1
                             FROM job_depends_on jbb_dep
                             JOIN job j_dep ON jbb_dep.depends_on_job_id = j_dep.id
                             JOIN result r_dep ON j_dep.id = r_dep.job_id
                             JOIN workflow_status ws ON j_dep.workflow_id = ws.id AND r_dep.run_id = ws.run_id
                             WHERE jbb_dep.job_id = jbb.job_id
-                              AND jbb_dep.workflow_id = ?
-                              AND j_dep.status IN (?, ?, ?)
+                              AND jbb_dep.workflow_id = $1
+                              AND j_dep.status IN ($2, $3, $4)
                               AND r_dep.return_code != 0
                         ) THEN 1
                         ELSE 0
@@ -1484,17 +2824,17 @@ impl<C> Server<C> {
                     0 as level
                 FROM job_depends_on jbb
                 JOIN job j ON jbb.job_id = j.id
-                WHERE jbb.depends_on_job_id = ?
-                  AND jbb.workflow_id = ?
-                  AND j.status = ?
+                WHERE jbb.depends_on_job_id = $5
+                  AND jbb.workflow_id = $6
+                  AND j.status = $7
                   -- Only process if no other incomplete blocking jobs exist
                   AND NOT EXISTS (
                       SELECT 1
                       FROM job_depends_on jbb2
                       JOIN job j2 ON jbb2.depends_on_job_id = j2.id
                       WHERE jbb2.job_id = jbb.job_id
-                        AND jbb2.depends_on_job_id != ?
-                        AND j2.status NOT IN (?, ?, ?, ?)
+                        AND jbb2.depends_on_job_id != $8
+                        AND j2.status NOT IN ($9, $10, $11, $12, $13)
                   )
 
                 UNION ALL
@@ -1504,7 +2844,7 @@ impl<C> Server<C> {
                     jbb.job_id,
                     -- Propagate cancellation: if parent is canceled and child has cancel_on_blocking_job_failure = true
                     CASE
-                        WHEN jtp.should_cancel = 1 AND j.cancel_on_blocking_job_failure != 0 THEN 1
+                        WHEN jtp.should_cancel = 1 AND j.cancel_on_blocking_job_failure = true THEN 1
                         ELSE 0
                     END as should_cancel,
                     jtp.level + 1 as level
@@ -1512,8 +2852,8 @@ impl<C> Server<C> {
                 JOIN job_depends_on jbb ON jbb.depends_on_job_id = jtp.job_id
                 JOIN job j ON jbb.job_id = j.id
                 WHERE jtp.should_cancel = 1  -- Only cascade from jobs that will be canceled
-                  AND jbb.workflow_id = ?
-                  AND j.status = ?
+                  AND jbb.workflow_id = $14
+                  AND j.status = $15
                   AND jtp.level < 100  -- Prevent infinite loops
                   -- Only process if no other incomplete blocking jobs exist
                   AND NOT EXISTS (
@@ -1522,7 +2862,7 @@ impl<C> Server<C> {
                       JOIN job j2 ON jbb2.depends_on_job_id = j2.id
                       WHERE jbb2.job_id = jbb.job_id
                         AND jbb2.depends_on_job_id != jtp.job_id
-                        AND j2.status NOT IN (?, ?, ?, ?)
+                        AND j2.status NOT IN ($16, $17, $18, $19, $20)
                   )
             )
             SELECT
@@ -1546,12 +2886,14 @@ impl<C> Server<C> {
         .bind(failed_status)
         .bind(canceled_status)
         .bind(terminated_status)
+        .bind(skipped_status)
         .bind(workflow_id)           // Recursive case: workflow_id
         .bind(blocked_status)        // Recursive case: only process blocked jobs
         .bind(completed_status)      // Recursive case: complete statuses
         .bind(failed_status)
         .bind(canceled_status)
         .bind(terminated_status)
+        .bind(skipped_status)
         .fetch_all(&mut **tx)
         .await
         {
@@ -1593,7 +2935,7 @@ impl<C> Server<C> {
 
             // Update job status
             match sqlx::query!(
-                "UPDATE job SET status = ? WHERE id = ? AND workflow_id = ? AND status = ?",
+                "UPDATE job SET status = $1 WHERE id = $2 AND workflow_id = $3 AND status = $4",
                 new_status,
                 job_id,
                 workflow_id,
@@ -1654,7 +2996,7 @@ impl<C> Server<C> {
     /// * `Ok(Vec<i64>)` - IDs of jobs that became ready (for triggering actions)
     /// * `Err(ApiError)` if there's a database error
     async fn unblock_jobs_waiting_for_tx(
-        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        tx: &mut DbTransaction<'_>,
         workflow_id: i64,
         completed_job_id: i64,
         return_code: i64,
@@ -1665,11 +3007,12 @@ impl<C> Server<C> {
             completed_job_id, workflow_id, return_code, workflow_has_failures
         );
 
-        let completed_status = models::JobStatus::Completed.to_int();
-        let failed_status = models::JobStatus::Failed.to_int();
-        let canceled_status = models::JobStatus::Canceled.to_int();
-        let terminated_status = models::JobStatus::Terminated.to_int();
-        let blocked_status = models::JobStatus::Blocked.to_int();
+        let completed_status = i64::from(models::JobStatus::Completed.to_int());
+        let failed_status = i64::from(models::JobStatus::Failed.to_int());
+        let canceled_status = i64::from(models::JobStatus::Canceled.to_int());
+        let terminated_status = i64::from(models::JobStatus::Terminated.to_int());
+        let skipped_status = i64::from(models::JobStatus::Skipped.to_int());
+        let blocked_status = i64::from(models::JobStatus::Blocked.to_int());
 
         // Quick pre-check: Are there ANY blocked jobs that depend on this completed job
         // AND have no other incomplete dependencies? If not, skip the expensive CTE.
@@ -1681,18 +3024,18 @@ impl<C> Server<C> {
                 SELECT 1
                 FROM job_depends_on jbb
                 JOIN job j ON jbb.job_id = j.id
-                WHERE jbb.depends_on_job_id = ?
-                  AND jbb.workflow_id = ?
-                  AND j.status = ?
+                WHERE jbb.depends_on_job_id = $1
+                  AND jbb.workflow_id = $2
+                  AND j.status = $3
                   AND NOT EXISTS (
                       SELECT 1
                       FROM job_depends_on jbb2
                       JOIN job j2 ON jbb2.depends_on_job_id = j2.id
                       WHERE jbb2.job_id = jbb.job_id
-                        AND jbb2.depends_on_job_id != ?
-                        AND j2.status NOT IN (?, ?, ?, ?)
+                        AND jbb2.depends_on_job_id != $4
+                        AND j2.status NOT IN ($5, $6, $7, $8, $9)
                   )
-            ) as has_jobs
+            ) as "has_jobs!: bool"
             "#,
             completed_job_id,
             workflow_id,
@@ -1701,12 +3044,13 @@ impl<C> Server<C> {
             completed_status,
             failed_status,
             canceled_status,
-            terminated_status
+            terminated_status,
+            skipped_status
         )
         .fetch_one(&mut **tx)
         .await
         {
-            Ok(row) => row.has_jobs != 0,
+            Ok(row) => row.has_jobs,
             Err(e) => {
                 debug!("Pre-check query failed: {}", e);
                 // If pre-check fails, fall through to full query
@@ -1837,23 +3181,24 @@ impl<C> Server<C> {
             job_id, workflow_id
         );
 
-        let completed_status = models::JobStatus::Completed.to_int();
-        let failed_status = models::JobStatus::Failed.to_int();
-        let uninitialized_status = models::JobStatus::Uninitialized.to_int();
+        let completed_status = i64::from(models::JobStatus::Completed.to_int());
+        let failed_status = i64::from(models::JobStatus::Failed.to_int());
+        let skipped_status = i64::from(models::JobStatus::Skipped.to_int());
+        let uninitialized_status = i64::from(models::JobStatus::Uninitialized.to_int());
 
         // Update downstream jobs to uninitialized in a single query using a subquery
         let result = match sqlx::query!(
             r#"
             UPDATE job
-            SET status = ?
-            WHERE workflow_id = ?
+            SET status = $1
+            WHERE workflow_id = $2
             AND id IN (
                 SELECT DISTINCT jbb.job_id
                 FROM job_depends_on jbb
                 JOIN job j ON jbb.job_id = j.id
-                WHERE jbb.depends_on_job_id = ?
-                AND jbb.workflow_id = ?
-                AND j.status IN (?, ?)
+                WHERE jbb.depends_on_job_id = $3
+                AND jbb.workflow_id = $4
+                AND j.status IN ($5, $6, $7)
             )
             "#,
             uninitialized_status,
@@ -1861,7 +3206,8 @@ impl<C> Server<C> {
             job_id,
             workflow_id,
             completed_status,
-            failed_status
+            failed_status,
+            skipped_status
         )
         .execute(self.pool.as_ref())
         .await
@@ -1898,7 +3244,7 @@ impl<C> Server<C> {
             job_id
         );
 
-        let uninitialized_status = models::JobStatus::Uninitialized.to_int();
+        let uninitialized_status = i64::from(models::JobStatus::Uninitialized.to_int());
 
         // Begin a transaction with immediate lock to ensure atomicity
         // SQLx automatically uses BEGIN IMMEDIATE for SQLite when the first write occurs
@@ -1911,7 +3257,7 @@ impl<C> Server<C> {
         };
 
         // Get the workflow_id for the given job_id first
-        let workflow_id = match sqlx::query!("SELECT workflow_id FROM job WHERE id = ?", job_id)
+        let workflow_id = match sqlx::query!("SELECT workflow_id FROM job WHERE id = $1", job_id)
             .fetch_optional(&mut *tx)
             .await
         {
@@ -1936,8 +3282,8 @@ impl<C> Server<C> {
                     jbb.job_id,
                     0 as level
                 FROM job_depends_on jbb
-                WHERE jbb.depends_on_job_id = ?
-                  AND jbb.workflow_id = ?
+                WHERE jbb.depends_on_job_id = $1
+                  AND jbb.workflow_id = $2
 
                 UNION ALL
 
@@ -1947,12 +3293,12 @@ impl<C> Server<C> {
                     dj.level + 1 as level
                 FROM downstream_jobs dj
                 JOIN job_depends_on jbb ON jbb.depends_on_job_id = dj.job_id
-                WHERE jbb.workflow_id = ?
+                WHERE jbb.workflow_id = $3
                   AND dj.level < 100  -- Prevent infinite loops
             )
             UPDATE job
-            SET status = ?
-            WHERE workflow_id = ?
+            SET status = $4
+            WHERE workflow_id = $5
               AND id IN (SELECT DISTINCT job_id FROM downstream_jobs)
             "#,
             job_id,
@@ -2032,11 +3378,13 @@ use torc::server::{
     ListResourceRequirementsResponse, ListResultsResponse, ListScheduledComputeNodesResponse,
     ListSlurmSchedulersResponse, ListUserDataResponse, ListWorkflowsResponse,
     ManageStatusChangeResponse, PingResponse, ProcessChangedJobInputsResponse,
-    ResetJobStatusResponse, ResetWorkflowStatusResponse, RetryJobResponse, StartJobResponse,
-    UpdateComputeNodeResponse, UpdateEventResponse, UpdateFileResponse, UpdateJobResponse,
-    UpdateLocalSchedulerResponse, UpdateResourceRequirementsResponse, UpdateResultResponse,
-    UpdateScheduledComputeNodeResponse, UpdateSlurmSchedulerResponse, UpdateUserDataResponse,
-    UpdateWorkflowResponse, UpdateWorkflowStatusResponse,
+    ReleaseComputeNodeResponse, ReserveComputeNodeResponse, ResetJobStatusResponse,
+    ResetWorkflowStatusResponse, RetryJobResponse, StartJobResponse,
+    UpdateComputeNodeHeartbeatResponse, UpdateComputeNodeResponse, UpdateEventResponse,
+    UpdateFileResponse, UpdateJobResponse, UpdateLocalSchedulerResponse,
+    UpdateResourceRequirementsResponse, UpdateResultResponse, UpdateScheduledComputeNodeResponse,
+    UpdateSlurmSchedulerResponse, UpdateUserDataResponse, UpdateWorkflowResponse,
+    UpdateWorkflowStatusResponse,
 };
 use torc::time_utils::duration_string_to_seconds;
 
@@ -2321,15 +3669,347 @@ where
             .await
     }
 
-    /// Delete a failure handler.
-    async fn delete_failure_handler(
+    /// Delete a failure handler.
+    async fn delete_failure_handler(
+        &self,
+        id: i64,
+        body: Option<serde_json::Value>,
+        context: &C,
+    ) -> Result<DeleteFailureHandlerResponse, ApiError> {
+        self.failure_handlers_api
+            .delete_failure_handler(id, body, context)
+            .await
+    }
+
+    /// Store a workflow schedule.
+    async fn create_workflow_schedule(
+        &self,
+        body: models::WorkflowScheduleModel,
+        context: &C,
+    ) -> Result<CreateWorkflowScheduleResponse, ApiError> {
+        match self
+            .check_workflow_access_for_context(body.workflow_id, context)
+            .await
+        {
+            AccessCheckResult::Allowed => {}
+            AccessCheckResult::Denied(reason) => {
+                return Ok(CreateWorkflowScheduleResponse::ForbiddenErrorResponse(
+                    models::ErrorResponse::new(serde_json::json!({
+                        "error": "Forbidden",
+                        "message": reason
+                    })),
+                ));
+            }
+            AccessCheckResult::NotFound(reason) => {
+                return Ok(CreateWorkflowScheduleResponse::NotFoundErrorResponse(
+                    models::ErrorResponse::new(serde_json::json!({
+                        "error": "NotFound",
+                        "message": reason
+                    })),
+                ));
+            }
+        }
+
+        // Check if workflow exists
+        let workflow_exists = self
+            .workflows_api
+            .does_workflow_exist(body.workflow_id, context)
+            .await?;
+        if !workflow_exists {
+            let error_response = models::ErrorResponse::new(serde_json::json!({
+                "message": format!("Workflow not found with ID: {}", body.workflow_id)
+            }));
+            return Ok(CreateWorkflowScheduleResponse::NotFoundErrorResponse(
+                error_response,
+            ));
+        }
+
+        // Scheduled runs act on behalf of the user that created the schedule
+        let auth: Option<Authorization> = Has::<Option<Authorization>>::get(context).clone();
+        let created_by = AuthorizationService::get_username(&auth).map(|u| u.to_string());
+        self.workflow_schedules_api
+            .create_workflow_schedule(body, created_by, context)
+            .await
+    }
+
+    /// Retrieve a workflow schedule by ID.
+    async fn get_workflow_schedule(
+        &self,
+        id: i64,
+        context: &C,
+    ) -> Result<GetWorkflowScheduleResponse, ApiError> {
+        let response = self
+            .workflow_schedules_api
+            .get_workflow_schedule(id, context)
+            .await?;
+        let GetWorkflowScheduleResponse::SuccessfulResponse(schedule) = &response else {
+            return Ok(response);
+        };
+        match self
+            .check_workflow_access_for_context(schedule.workflow_id, context)
+            .await
+        {
+            AccessCheckResult::Allowed => Ok(response),
+            AccessCheckResult::Denied(reason) => {
+                Ok(GetWorkflowScheduleResponse::ForbiddenErrorResponse(
+                    models::ErrorResponse::new(serde_json::json!({
+                        "error": "Forbidden",
+                        "message": reason
+                    })),
+                ))
+            }
+            AccessCheckResult::NotFound(reason) => {
+                Ok(GetWorkflowScheduleResponse::NotFoundErrorResponse(
+                    models::ErrorResponse::new(serde_json::json!({
+                        "error": "NotFound",
+                        "message": reason
+                    })),
+                ))
+            }
+        }
+    }
+
+    /// Retrieve workflow schedules, optionally for one workflow.
+    async fn list_workflow_schedules(
+        &self,
+        workflow_id: Option<i64>,
+        offset: Option<i64>,
+        limit: Option<i64>,
+        context: &C,
+    ) -> Result<ListWorkflowSchedulesResponse, ApiError> {
+        if let Some(workflow_id) = workflow_id {
+            match self
+                .check_workflow_access_for_context(workflow_id, context)
+                .await
+            {
+                // A missing workflow has no schedules
+                AccessCheckResult::Allowed | AccessCheckResult::NotFound(_) => {}
+                AccessCheckResult::Denied(reason) => {
+                    return Ok(ListWorkflowSchedulesResponse::ForbiddenErrorResponse(
+                        models::ErrorResponse::new(serde_json::json!({
+                            "error": "Forbidden",
+                            "message": reason
+                        })),
+                    ));
+                }
+            }
+        }
+
+        // Without a workflow filter, only return schedules of accessible workflows
+        let auth: Option<Authorization> = Has::<Option<Authorization>>::get(context).clone();
+        let accessible_workflow_ids = match self
+            .authorization_service
+            .get_accessible_workflow_ids(&auth)
+            .await
+        {
+            Ok(ids) => ids,
+            Err(e) => {
+                error!("Failed to get accessible workflows: {}", e);
+                return Err(ApiError("Database error".to_string()));
+            }
+        };
+
+        let (offset, limit) = process_pagination_params(offset, limit)?;
+        self.workflow_schedules_api
+            .list_workflow_schedules(workflow_id, accessible_workflow_ids, offset, limit, context)
+            .await
+    }
+
+    /// Update a workflow schedule.
+    async fn update_workflow_schedule(
+        &self,
+        id: i64,
+        body: models::WorkflowScheduleModel,
+        context: &C,
+    ) -> Result<UpdateWorkflowScheduleResponse, ApiError> {
+        let existing = self
+            .workflow_schedules_api
+            .get_workflow_schedule(id, context)
+            .await?;
+        let GetWorkflowScheduleResponse::SuccessfulResponse(existing) = existing else {
+            return Ok(UpdateWorkflowScheduleResponse::NotFoundErrorResponse(
+                models::ErrorResponse::new(serde_json::json!({
+                    "message": format!("Workflow schedule not found with ID: {}", id)
+                })),
+            ));
+        };
+        match self
+            .check_workflow_access_for_context(existing.workflow_id, context)
+            .await
+        {
+            AccessCheckResult::Allowed => {}
+            AccessCheckResult::Denied(reason) => {
+                return Ok(UpdateWorkflowScheduleResponse::ForbiddenErrorResponse(
+                    models::ErrorResponse::new(serde_json::json!({
+                        "error": "Forbidden",
+                        "message": reason
+                    })),
+                ));
+            }
+            AccessCheckResult::NotFound(reason) => {
+                return Ok(UpdateWorkflowScheduleResponse::NotFoundErrorResponse(
+                    models::ErrorResponse::new(serde_json::json!({
+                        "error": "NotFound",
+                        "message": reason
+                    })),
+                ));
+            }
+        }
+
+        self.workflow_schedules_api
+            .update_workflow_schedule(id, body, context)
+            .await
+    }
+
+    /// Delete a workflow schedule.
+    async fn delete_workflow_schedule(
+        &self,
+        id: i64,
+        body: Option<serde_json::Value>,
+        context: &C,
+    ) -> Result<DeleteWorkflowScheduleResponse, ApiError> {
+        let existing = self
+            .workflow_schedules_api
+            .get_workflow_schedule(id, context)
+            .await?;
+        let GetWorkflowScheduleResponse::SuccessfulResponse(existing) = existing else {
+            return Ok(DeleteWorkflowScheduleResponse::NotFoundErrorResponse(
+                models::ErrorResponse::new(serde_json::json!({
+                    "message": format!("Workflow schedule not found with ID: {}", id)
+                })),
+            ));
+        };
+        match self
+            .check_workflow_access_for_context(existing.workflow_id, context)
+            .await
+        {
+            AccessCheckResult::Allowed => {}
+            AccessCheckResult::Denied(reason) => {
+                return Ok(DeleteWorkflowScheduleResponse::ForbiddenErrorResponse(
+                    models::ErrorResponse::new(serde_json::json!({
+                        "error": "Forbidden",
+                        "message": reason
+                    })),
+                ));
+            }
+            AccessCheckResult::NotFound(reason) => {
+                return Ok(DeleteWorkflowScheduleResponse::NotFoundErrorResponse(
+                    models::ErrorResponse::new(serde_json::json!({
+                        "error": "NotFound",
+                        "message": reason
+                    })),
+                ));
+            }
+        }
+
+        self.workflow_schedules_api
+            .delete_workflow_schedule(id, body, context)
+            .await
+    }
+
+    /// Store the stdout or stderr log of a job attempt.
+    async fn create_job_log(
+        &self,
+        body: models::JobLogModel,
+        context: &C,
+    ) -> Result<CreateJobLogResponse, ApiError> {
+        match self
+            .check_workflow_access_for_context(body.workflow_id, context)
+            .await
+        {
+            AccessCheckResult::Allowed => {}
+            AccessCheckResult::Denied(reason) => {
+                return Ok(CreateJobLogResponse::ForbiddenErrorResponse(
+                    models::ErrorResponse::new(serde_json::json!({
+                        "error": "Forbidden",
+                        "message": reason
+                    })),
+                ));
+            }
+            AccessCheckResult::NotFound(reason) => {
+                return Ok(CreateJobLogResponse::NotFoundErrorResponse(
+                    models::ErrorResponse::new(serde_json::json!({
+                        "error": "NotFound",
+                        "message": reason
+                    })),
+                ));
+            }
+        }
+        self.job_logs_api.create_job_log(body, context).await
+    }
+
+    /// Retrieve a job log by ID.
+    async fn get_job_log(&self, id: i64, context: &C) -> Result<GetJobLogResponse, ApiError> {
+        let response = self.job_logs_api.get_job_log(id, context).await?;
+        let GetJobLogResponse::SuccessfulResponse(job_log) = &response else {
+            return Ok(response);
+        };
+        match self
+            .check_workflow_access_for_context(job_log.workflow_id, context)
+            .await
+        {
+            AccessCheckResult::Allowed => Ok(response),
+            AccessCheckResult::Denied(reason) => Ok(GetJobLogResponse::ForbiddenErrorResponse(
+                models::ErrorResponse::new(serde_json::json!({
+                    "error": "Forbidden",
+                    "message": reason
+                })),
+            )),
+            AccessCheckResult::NotFound(reason) => Ok(GetJobLogResponse::NotFoundErrorResponse(
+                models::ErrorResponse::new(serde_json::json!({
+                    "error": "NotFound",
+                    "message": reason
+                })),
+            )),
+        }
+    }
+
+    /// Retrieve the uploaded job logs of a workflow.
+    async fn list_job_logs(
         &self,
-        id: i64,
-        body: Option<serde_json::Value>,
+        workflow_id: i64,
+        job_id: Option<i64>,
+        run_id: Option<i64>,
+        attempt_id: Option<i64>,
+        stream: Option<models::JobLogStream>,
+        offset: Option<i64>,
+        limit: Option<i64>,
         context: &C,
-    ) -> Result<DeleteFailureHandlerResponse, ApiError> {
-        self.failure_handlers_api
-            .delete_failure_handler(id, body, context)
+    ) -> Result<ListJobLogsResponse, ApiError> {
+        match self
+            .check_workflow_access_for_context(workflow_id, context)
+            .await
+        {
+            AccessCheckResult::Allowed => {}
+            AccessCheckResult::Denied(reason) => {
+                return Ok(ListJobLogsResponse::ForbiddenErrorResponse(
+                    models::ErrorResponse::new(serde_json::json!({
+                        "error": "Forbidden",
+                        "message": reason
+                    })),
+                ));
+            }
+            AccessCheckResult::NotFound(reason) => {
+                return Ok(ListJobLogsResponse::NotFoundErrorResponse(
+                    models::ErrorResponse::new(serde_json::json!({
+                        "error": "NotFound",
+                        "message": reason
+                    })),
+                ));
+            }
+        }
+        let (offset, limit) = process_pagination_params(offset, limit)?;
+        self.job_logs_api
+            .list_job_logs(
+                workflow_id,
+                job_id,
+                run_id,
+                attempt_id,
+                stream,
+                offset,
+                limit,
+                context,
+            )
             .await
     }
 
@@ -3757,6 +5437,14 @@ where
             return Err(e);
         }
 
+        // Step 2b: Jobs that will run again start without the backoff or host exclusion of an
+        // earlier retry.
+        if let Err(e) = self.clear_uninitialized_retry_holds(&mut *tx, id).await {
+            error!("Failed to clear retry holds: {}", e);
+            let _ = tx.rollback().await;
+            return Err(e);
+        }
+
         // Step 3: Initialize blocked jobs to blocked status
         if let Err(e) = self
             .initialize_blocked_jobs_to_blocked(&mut *tx, id, only_uninit)
@@ -3777,11 +5465,12 @@ where
         // TODO: helper function
         // Step 5: Delete workflow_result records for jobs that are not complete
         // This is done after steps 1-4 to be future-proof in case those steps reset job completion statuses
-        // Complete statuses are: Completed (5), Failed (6), Canceled (7), Terminated (8)
-        let completed_status = models::JobStatus::Completed.to_int();
-        let failed_status = models::JobStatus::Failed.to_int();
-        let canceled_status = models::JobStatus::Canceled.to_int();
-        let terminated_status = models::JobStatus::Terminated.to_int();
+        // Complete statuses are: Completed (5), Failed (6), Canceled (7), Terminated (8), Skipped (11)
+        let completed_status = i64::from(models::JobStatus::Completed.to_int());
+        let failed_status = i64::from(models::JobStatus::Failed.to_int());
+        let canceled_status = i64::from(models::JobStatus::Canceled.to_int());
+        let terminated_status = i64::from(models::JobStatus::Terminated.to_int());
+        let skipped_status = i64::from(models::JobStatus::Skipped.to_int());
 
         match sqlx::query!(
             r#"
@@ -3790,14 +5479,15 @@ where
               AND job_id IN (
                 SELECT id FROM job
                 WHERE workflow_id = $1
-                  AND status NOT IN ($2, $3, $4, $5)
+                  AND status NOT IN ($2, $3, $4, $5, $6)
               )
             "#,
             id,
             completed_status,
             failed_status,
             canceled_status,
-            terminated_status
+            terminated_status,
+            skipped_status
         )
         .execute(&mut *tx)
         .await
@@ -3934,7 +5624,7 @@ where
         // These are worker-lifecycle events that workers can claim when they start/complete
         for trigger_type in &["on_worker_start", "on_worker_complete"] {
             match sqlx::query(
-                "UPDATE workflow_action SET trigger_count = required_triggers WHERE workflow_id = ? AND trigger_type = ?"
+                "UPDATE workflow_action SET trigger_count = required_triggers WHERE workflow_id = $1 AND trigger_type = $2"
             )
             .bind(id)
             .bind(trigger_type)
@@ -4079,6 +5769,41 @@ where
         Ok(result)
     }
 
+    /// Record a heartbeat from a compute node.
+    async fn update_compute_node_heartbeat(
+        &self,
+        id: i64,
+        context: &C,
+    ) -> Result<UpdateComputeNodeHeartbeatResponse, ApiError> {
+        self.compute_nodes_api
+            .update_compute_node_heartbeat(id, context)
+            .await
+    }
+
+    /// Reserve a compute node for a job.
+    async fn reserve_compute_node(
+        &self,
+        id: i64,
+        job_id: i64,
+        context: &C,
+    ) -> Result<ReserveComputeNodeResponse, ApiError> {
+        self.compute_nodes_api
+            .reserve_compute_node(id, job_id, context)
+            .await
+    }
+
+    /// Release a compute node reserved for a job.
+    async fn release_compute_node(
+        &self,
+        id: i64,
+        job_id: i64,
+        context: &C,
+    ) -> Result<ReleaseComputeNodeResponse, ApiError> {
+        self.compute_nodes_api
+            .release_compute_node(id, job_id, context)
+            .await
+    }
+
     /// Update an event.
     async fn update_event(
         &self,
@@ -4343,6 +6068,21 @@ where
             ));
         }
 
+        // The job runner of a reserved compute node waits while a multi-node job runs on its node.
+        if let Some(compute_node_id) = body.id
+            && let Some(job_id) = self.reserving_job_id(compute_node_id).await?
+        {
+            return Ok(ClaimJobsBasedOnResources::SuccessfulResponse(
+                models::ClaimJobsBasedOnResources {
+                    jobs: Some(vec![]),
+                    reason: Some(format!(
+                        "Compute node is reserved for multi-node job {}",
+                        job_id
+                    )),
+                },
+            ));
+        }
+
         self.prepare_ready_jobs(
             id,
             body,
@@ -4410,8 +6150,7 @@ where
             ApiError("Database connection error".to_string())
         })?;
 
-        sqlx::query("BEGIN IMMEDIATE")
-            .execute(&mut *conn)
+        db::begin_immediate(&mut conn, workflow_id)
             .await
             .map_err(|e| {
                 error!("Failed to begin immediate transaction: {}", e);
@@ -4424,7 +6163,7 @@ where
         );
 
         // First check if the workflow exists
-        let workflow_exists = sqlx::query("SELECT id FROM workflow WHERE id = $1")
+        let workflow_row = sqlx::query("SELECT id, jobs_sort_method FROM workflow WHERE id = $1")
             .bind(workflow_id)
             .fetch_optional(&mut *conn)
             .await
@@ -4433,7 +6172,7 @@ where
                 ApiError("Database error".to_string())
             })?;
 
-        if workflow_exists.is_none() {
+        let Some(workflow_row) = workflow_row else {
             // Rollback the transaction since we're returning early
             let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
 
@@ -4441,11 +6180,40 @@ where
                 "message": format!("Workflow not found with ID: {}", workflow_id)
             }));
             return Ok(ClaimNextJobsResponse::DefaultErrorResponse(error_response));
+        };
+
+        // Only the priority sort method applies here; the others sort by resource requirements.
+        let sort_method = workflow_row
+            .get::<String, _>("jobs_sort_method")
+            .parse::<models::ClaimJobsSortMethod>()
+            .unwrap_or_default();
+        let order_by_clause = match sort_method {
+            models::ClaimJobsSortMethod::Priority => "ORDER BY priority DESC, id",
+            _ => "",
+        };
+
+        let mut slots = match Self::concurrency_slots(&mut conn, workflow_id).await {
+            Ok(slots) => slots,
+            Err(e) => {
+                let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
+                return Err(e);
+            }
+        };
+        if slots.is_workflow_full() {
+            let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
+            debug!(
+                "claim_next_jobs: workflow {} is at max_concurrent_jobs",
+                workflow_id
+            );
+            return Ok(ClaimNextJobsResponse::SuccessfulResponse(
+                models::ClaimNextJobsResponse { jobs: Some(vec![]) },
+            ));
         }
 
         // Query the job table directly for ready jobs using the indexed status column
-        let ready_status = models::JobStatus::Ready.to_int();
-        let query = r#"
+        let ready_status = i64::from(models::JobStatus::Ready.to_int());
+        let query = format!(
+            r#"
             SELECT
                 id as job_id,
                 workflow_id,
@@ -4456,22 +6224,54 @@ where
                 supports_termination,
                 resource_requirements_id,
                 failure_handler_id,
-                attempt_id
+                attempt_id,
+                priority,
+                env,
+                working_dir,
+                shell,
+                container,
+                run_if,
+                concurrency_pool
             FROM job
             WHERE workflow_id = $1 AND status = $2
-            LIMIT $3
-            "#;
+            AND (not_before IS NULL OR not_before <= $3)
+            AND (excluded_hostname IS NULL OR $4 IS NULL OR excluded_hostname <> $4)
+            {}
+            {}
+            LIMIT $5
+            {}
+            "#,
+            slots.full_pools_clause("concurrency_pool", 6),
+            order_by_clause,
+            db::claim_row_lock_clause("job")
+        );
+
+        // Workers identify themselves so that jobs excluded from their host are not returned.
+        let compute_node_id = body
+            .as_ref()
+            .and_then(|body| body.get("compute_node_id"))
+            .and_then(|id| id.as_i64());
+        let hostname = match Self::claiming_hostname(&mut conn, compute_node_id).await {
+            Ok(hostname) => hostname,
+            Err(e) => {
+                let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
+                return Err(e);
+            }
+        };
 
-        let rows = sqlx::query(query)
+        let mut query = sqlx::query(&query)
             .bind(workflow_id)
             .bind(ready_status)
-            .bind(job_limit)
-            .fetch_all(&mut *conn)
-            .await
-            .map_err(|e| {
-                error!("Database error in claim_next_jobs: {}", e);
-                ApiError("Database error".to_string())
-            })?;
+            .bind(Utc::now().timestamp_millis())
+            .bind(&hostname)
+            .bind(job_limit);
+        for pool in slots.full_pools() {
+            query = query.bind(pool.to_string());
+        }
+        let rows = query.fetch_all(&mut *conn).await.map_err(|e| {
+            error!("Database error in claim_next_jobs: {}", e);
+            ApiError("Database error".to_string())
+        })?;
 
         debug!(
             "claim_next_jobs: Found {} jobs for workflow {}",
@@ -4485,6 +6285,15 @@ where
         // Process all returned jobs (all are guaranteed to be in Ready status)
         for row in rows {
             let job_id: i64 = row.get("job_id");
+            let concurrency_pool: Option<String> = row.get("concurrency_pool");
+            if !slots.has_slot(concurrency_pool.as_deref()) {
+                debug!(
+                    "Skipping job {} - concurrency limit reached for pool {:?}",
+                    job_id, concurrency_pool
+                );
+                continue;
+            }
+            slots.take(concurrency_pool.as_deref());
             job_ids_to_update.push(job_id);
 
             let job = models::JobModel {
@@ -4506,18 +6315,54 @@ where
                 scheduler_id: None,
                 failure_handler_id: row.get("failure_handler_id"),
                 attempt_id: row.get("attempt_id"),
+                priority: row.get("priority"),
+                env: jobs::env_from_json(row.get("env")),
+                working_dir: row.get("working_dir"),
+                shell: row.get("shell"),
+                container: jobs::container_from_json(row.get("container")),
+                run_if: row.get("run_if"),
+                concurrency_pool,
+                not_before: None,
+                excluded_hostname: None,
             };
 
             selected_jobs.push(job);
         }
 
-        // Query output file and user_data relationships for all selected jobs
+        // Query file and user_data relationships for all selected jobs
+        let mut input_files_map: std::collections::HashMap<i64, Vec<i64>> =
+            std::collections::HashMap::new();
         let mut output_files_map: std::collections::HashMap<i64, Vec<i64>> =
             std::collections::HashMap::new();
         let mut output_user_data_map: std::collections::HashMap<i64, Vec<i64>> =
             std::collections::HashMap::new();
 
         if !job_ids_to_update.is_empty() {
+            // Query input files (used by workers that stage files) of the claimed jobs only.
+            // SAFETY: job_ids are i64 from database query results, so the list cannot inject SQL.
+            let claimed_job_ids = job_ids_to_update
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            let input_files = sqlx::query(&format!(
+                "SELECT job_id, file_id FROM job_input_file WHERE workflow_id = $1 AND job_id IN ({})",
+                claimed_job_ids
+            ))
+            .bind(workflow_id)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| {
+                error!("Failed to query input files: {}", e);
+                ApiError("Database query error".to_string())
+            })?;
+
+            for row in input_files {
+                let job_id: i64 = row.get("job_id");
+                let file_id: i64 = row.get("file_id");
+                input_files_map.entry(job_id).or_default().push(file_id);
+            }
+
             // Query output files
             let output_files =
                 sqlx::query("SELECT job_id, file_id FROM job_output_file WHERE workflow_id = $1")
@@ -4559,9 +6404,10 @@ where
             }
         }
 
-        // Populate the output file and user_data IDs in the selected jobs
+        // Populate the file and user_data IDs in the selected jobs
         for job in &mut selected_jobs {
             if let Some(job_id) = job.id {
+                job.input_file_ids = input_files_map.get(&job_id).cloned();
                 job.output_file_ids = output_files_map.get(&job_id).cloned();
                 job.output_user_data_ids = output_user_data_map.get(&job_id).cloned();
             }
@@ -5027,6 +6873,13 @@ where
             }
         };
 
+        if !matches!(
+            status,
+            models::JobStatus::Pending | models::JobStatus::Running
+        ) {
+            self.release_compute_node_reservations(id).await;
+        }
+
         // Handle reversion from complete to uninitialized
         if current_status.is_complete() && status == models::JobStatus::Uninitialized {
             // Current status is complete and new status is Uninitialized
@@ -5121,7 +6974,7 @@ where
 
         // Set active_compute_node_id to track which compute node is running this job
         match sqlx::query!(
-            "UPDATE job_internal SET active_compute_node_id = ? WHERE job_id = ?",
+            "UPDATE job_internal SET active_compute_node_id = $1 WHERE job_id = $2",
             compute_node_id,
             id
         )
@@ -5230,7 +7083,7 @@ where
 
         // Clear active_compute_node_id since the job is no longer running
         match sqlx::query!(
-            "UPDATE job_internal SET active_compute_node_id = NULL WHERE job_id = ?",
+            "UPDATE job_internal SET active_compute_node_id = NULL WHERE job_id = $1",
             id
         )
         .execute(self.pool.as_ref())
@@ -5247,6 +7100,7 @@ where
                 // Continue anyway - this is not critical
             }
         }
+        self.release_compute_node_reservations(id).await;
 
         // Capture return_code before moving result to create_result
         let result_return_code = result.return_code;
@@ -5272,9 +7126,9 @@ where
             }
         };
 
-        // 3. Add/update workflow_result record using INSERT OR REPLACE for atomic upsert.
+        // 3. Add/update workflow_result record using an atomic upsert.
         // This handles the case where a job is being re-run or a result is being replaced.
-        // The table has PRIMARY KEY (workflow_id, job_id), so conflicts are automatically resolved.
+        // The table has PRIMARY KEY (workflow_id, job_id), so conflicts replace the result_id.
         let workflow_id = job.workflow_id;
         let result_id_value = result_id.ok_or_else(|| {
             error!("Result ID is missing after creating result");
@@ -5283,8 +7137,9 @@ where
 
         match sqlx::query!(
             r#"
-            INSERT OR REPLACE INTO workflow_result (workflow_id, job_id, result_id)
-            VALUES (?, ?, ?)
+            INSERT INTO workflow_result (workflow_id, job_id, result_id)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (workflow_id, job_id) DO UPDATE SET result_id = excluded.result_id
             "#,
             workflow_id,
             id,
@@ -5311,10 +7166,19 @@ where
         // 4. Call manage_job_status_change for validation and side effects
         self.manage_job_status_change(&job, run_id).await?;
 
+        // A successful attempt ends any retry backoff or host exclusion.
+        if status == models::JobStatus::Completed
+            && let Err(e) = self.clear_retry_holds(self.pool.as_ref(), id).await
+        {
+            error!("Failed to clear retry holds for job_id={}: {}", id, e);
+        }
+
         // 5. Broadcast job completion event to SSE clients (ephemeral, not persisted to DB)
         let event_type = format!("job_{}", status.to_string().to_lowercase());
         let severity = match status {
-            models::JobStatus::Completed => models::EventSeverity::Info,
+            models::JobStatus::Completed | models::JobStatus::Skipped => {
+                models::EventSeverity::Info
+            }
             models::JobStatus::Failed => models::EventSeverity::Error,
             models::JobStatus::Terminated | models::JobStatus::Canceled => {
                 models::EventSeverity::Warning
@@ -5375,6 +7239,9 @@ where
                         "Workflow {} is complete, triggering on_workflow_complete actions",
                         workflow_id
                     );
+                    if !completion_status.is_canceled {
+                        self.broadcast_workflow_completed(workflow_id).await;
+                    }
                     if let Err(e) = self
                         .workflow_actions_api
                         .check_and_trigger_actions(workflow_id, "on_workflow_complete", None)
@@ -5404,18 +7271,29 @@ where
         id: i64,
         run_id: i64,
         max_retries: i32,
+        delay_seconds: Option<f64>,
+        avoid_compute_node_id: Option<i64>,
         context: &C,
     ) -> Result<RetryJobResponse, ApiError> {
         debug!(
-            "retry_job({}, {}, {}) - X-Span-ID: {:?}",
+            "retry_job({}, {}, {}, {:?}, {:?}) - X-Span-ID: {:?}",
             id,
             run_id,
             max_retries,
+            delay_seconds,
+            avoid_compute_node_id,
             Has::<XSpanIdString>::get(context).0.clone()
         );
         let result = self
             .jobs_api
-            .retry_job(id, run_id, max_retries, context)
+            .retry_job(
+                id,
+                run_id,
+                max_retries,
+                delay_seconds,
+                avoid_compute_node_id,
+                context,
+            )
             .await?;
 
         Ok(result)
@@ -5435,6 +7313,7 @@ where
     ///    - None: No sorting applied
     ///    - GpusRuntimeMemory: Sort by num_gpus DESC, runtime_s DESC, memory_bytes DESC
     ///    - GpusMemoryRuntime: Sort by num_gpus DESC, memory_bytes DESC, runtime_s DESC
+    ///    - Priority: Sort by priority DESC, then as GpusRuntimeMemory
     /// 4. Loops through returned records and accumulates resource consumption
     /// 5. Selects jobs that can fit within total available resources
     /// 6. Atomically updates selected jobs to "pending" status
@@ -5487,8 +7366,7 @@ where
             ApiError("Database connection error".to_string())
         })?;
 
-        sqlx::query("BEGIN IMMEDIATE")
-            .execute(&mut *conn)
+        db::begin_immediate(&mut conn, workflow_id)
             .await
             .map_err(|e| {
                 error!("Failed to begin immediate transaction: {}", e);
@@ -5551,7 +7429,29 @@ where
 
         let memory_bytes = (resources.memory_gb * 1024.0 * 1024.0 * 1024.0) as i64;
 
-        let ready_status = models::JobStatus::Ready.to_int();
+        // Jobs count against concurrency limits from the time they are claimed.
+        let mut slots = match Self::concurrency_slots(&mut conn, workflow_id).await {
+            Ok(slots) => slots,
+            Err(e) => {
+                let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
+                return Err(e);
+            }
+        };
+        if slots.is_workflow_full() {
+            let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
+            debug!(
+                "get_ready_jobs: workflow {} is at max_concurrent_jobs",
+                workflow_id
+            );
+            return Ok(ClaimJobsBasedOnResources::SuccessfulResponse(
+                models::ClaimJobsBasedOnResources {
+                    jobs: Some(vec![]),
+                    reason: Some("Workflow is at max_concurrent_jobs".to_string()),
+                },
+            ));
+        }
+
+        let ready_status = i64::from(models::JobStatus::Ready.to_int());
         let order_by_clause = match actual_sort_method {
             models::ClaimJobsSortMethod::None => "",
             models::ClaimJobsSortMethod::GpusRuntimeMemory => {
@@ -5560,6 +7460,9 @@ where
             models::ClaimJobsSortMethod::GpusMemoryRuntime => {
                 "ORDER BY rr.num_gpus DESC, rr.memory_bytes DESC, rr.runtime_s DESC"
             }
+            models::ClaimJobsSortMethod::Priority => {
+                "ORDER BY job.priority DESC, rr.num_gpus DESC, rr.runtime_s DESC, rr.memory_bytes DESC"
+            }
         };
 
         // Query with scheduler filter
@@ -5576,6 +7479,13 @@ where
                 job.supports_termination,
                 job.failure_handler_id,
                 job.attempt_id,
+                job.priority,
+                job.env,
+                job.working_dir,
+                job.shell,
+                job.container,
+                job.run_if,
+                job.concurrency_pool,
                 rr.id AS resource_requirements_id,
                 rr.memory_bytes,
                 rr.num_cpus,
@@ -5592,14 +7502,31 @@ where
             AND rr.num_nodes <= $6
             AND rr.runtime_s <= $7
             AND (job.scheduler_id IS NULL OR job.scheduler_id = $8)
+            AND (job.not_before IS NULL OR job.not_before <= $9)
+            AND (job.excluded_hostname IS NULL OR $10 IS NULL OR job.excluded_hostname <> $10)
+            {}
+            {}
+            LIMIT $11
             {}
-            LIMIT $9
             "#,
-            order_by_clause
+            slots.full_pools_clause("job.concurrency_pool", 12),
+            order_by_clause,
+            db::claim_row_lock_clause("job")
         );
 
+        // Retried jobs may be delayed or excluded from the host of the failed attempt.
+        // Workers identify their compute node through the resources id.
+        let now_ms = Utc::now().timestamp_millis();
+        let hostname = match Self::claiming_hostname(&mut conn, resources.id).await {
+            Ok(hostname) => hostname,
+            Err(e) => {
+                let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
+                return Err(e);
+            }
+        };
+
         // First try with scheduler filter
-        let mut rows = sqlx::query(&query_with_scheduler)
+        let mut query = sqlx::query(&query_with_scheduler)
             .bind(workflow_id)
             .bind(ready_status)
             .bind(memory_bytes)
@@ -5608,13 +7535,16 @@ where
             .bind(resources.num_nodes)
             .bind(time_limit_seconds)
             .bind(resources.scheduler_config_id)
-            .bind(limit)
-            .fetch_all(&mut *conn)
-            .await
-            .map_err(|e| {
-                error!("Database error in get_ready_jobs: {}", e);
-                ApiError("Database error".to_string())
-            })?;
+            .bind(now_ms)
+            .bind(&hostname)
+            .bind(limit);
+        for pool in slots.full_pools() {
+            query = query.bind(pool.to_string());
+        }
+        let mut rows = query.fetch_all(&mut *conn).await.map_err(|e| {
+            error!("Database error in get_ready_jobs: {}", e);
+            ApiError("Database error".to_string())
+        })?;
 
         // If no jobs found with scheduler filter and strict_scheduler_match is false,
         // retry without the scheduler filter
@@ -5633,6 +7563,13 @@ where
                     job.supports_termination,
                     job.failure_handler_id,
                     job.attempt_id,
+                    job.priority,
+                    job.env,
+                    job.working_dir,
+                    job.shell,
+                    job.container,
+                    job.run_if,
+                    job.concurrency_pool,
                     rr.id AS resource_requirements_id,
                     rr.memory_bytes,
                     rr.num_cpus,
@@ -5648,13 +7585,19 @@ where
                 AND rr.num_gpus <= $5
                 AND rr.num_nodes <= $6
                 AND rr.runtime_s <= $7
+                AND (job.not_before IS NULL OR job.not_before <= $8)
+                AND (job.excluded_hostname IS NULL OR $9 IS NULL OR job.excluded_hostname <> $9)
+                {}
+                {}
+                LIMIT $10
                 {}
-                LIMIT $8
                 "#,
-                order_by_clause
+                slots.full_pools_clause("job.concurrency_pool", 11),
+                order_by_clause,
+                db::claim_row_lock_clause("job")
             );
 
-            rows = sqlx::query(&query_without_scheduler)
+            let mut query = sqlx::query(&query_without_scheduler)
                 .bind(workflow_id)
                 .bind(ready_status)
                 .bind(memory_bytes)
@@ -5662,16 +7605,19 @@ where
                 .bind(resources.num_gpus)
                 .bind(resources.num_nodes)
                 .bind(time_limit_seconds)
-                .bind(limit)
-                .fetch_all(&mut *conn)
-                .await
-                .map_err(|e| {
-                    error!(
-                        "Database error in get_ready_jobs (no scheduler filter): {}",
-                        e
-                    );
-                    ApiError("Database error".to_string())
-                })?;
+                .bind(now_ms)
+                .bind(&hostname)
+                .bind(limit);
+            for pool in slots.full_pools() {
+                query = query.bind(pool.to_string());
+            }
+            rows = query.fetch_all(&mut *conn).await.map_err(|e| {
+                error!(
+                    "Database error in get_ready_jobs (no scheduler filter): {}",
+                    e
+                );
+                ApiError("Database error".to_string())
+            })?;
 
             if !rows.is_empty() {
                 info!(
@@ -5707,6 +7653,16 @@ where
             let job_cpus: i64 = row.get("num_cpus");
             let job_gpus: i64 = row.get("num_gpus");
             let job_nodes: i64 = row.get("num_nodes");
+            let concurrency_pool: Option<String> = row.get("concurrency_pool");
+
+            if !slots.has_slot(concurrency_pool.as_deref()) {
+                debug!(
+                    "Skipping job {} - concurrency limit reached for pool {:?}",
+                    row.get::<i64, _>("job_id"),
+                    concurrency_pool
+                );
+                continue;
+            }
 
             // Check if this job would exceed resource limits
             // For multi-node jobs (num_nodes > 1), check if we have enough dedicated nodes
@@ -5730,6 +7686,7 @@ where
                 if job_nodes > 1 {
                     consumed_nodes += job_nodes;
                 }
+                slots.take(concurrency_pool.as_deref());
 
                 let job_id: i64 = row.get("job_id");
                 job_ids_to_update.push(job_id);
@@ -5765,6 +7722,15 @@ where
                     scheduler_id: None,
                     failure_handler_id: row.get("failure_handler_id"),
                     attempt_id: row.get("attempt_id"),
+                    priority: row.get("priority"),
+                    env: jobs::env_from_json(row.get("env")),
+                    working_dir: row.get("working_dir"),
+                    shell: row.get("shell"),
+                    container: jobs::container_from_json(row.get("container")),
+                    run_if: row.get("run_if"),
+                    concurrency_pool,
+                    not_before: None,
+                    excluded_hostname: None,
                 };
 
                 selected_jobs.push(job);
@@ -5797,13 +7763,40 @@ where
             }
         }
 
-        // Query output file and user_data relationships for all selected jobs
+        // Query file and user_data relationships for all selected jobs
+        let mut input_files_map: std::collections::HashMap<i64, Vec<i64>> =
+            std::collections::HashMap::new();
         let mut output_files_map: std::collections::HashMap<i64, Vec<i64>> =
             std::collections::HashMap::new();
         let mut output_user_data_map: std::collections::HashMap<i64, Vec<i64>> =
             std::collections::HashMap::new();
 
         if !job_ids_to_update.is_empty() {
+            // Query input files (used by workers that stage files) of the claimed jobs only.
+            // SAFETY: job_ids are i64 from database query results, so the list cannot inject SQL.
+            let claimed_job_ids = job_ids_to_update
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            let input_files = sqlx::query(&format!(
+                "SELECT job_id, file_id FROM job_input_file WHERE workflow_id = $1 AND job_id IN ({})",
+                claimed_job_ids
+            ))
+            .bind(workflow_id)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| {
+                error!("Failed to query input files: {}", e);
+                ApiError("Database query error".to_string())
+            })?;
+
+            for row in input_files {
+                let job_id: i64 = row.get("job_id");
+                let file_id: i64 = row.get("file_id");
+                input_files_map.entry(job_id).or_default().push(file_id);
+            }
+
             // Query output files
             let output_files =
                 sqlx::query("SELECT job_id, file_id FROM job_output_file WHERE workflow_id = $1")
@@ -5845,9 +7838,10 @@ where
             }
         }
 
-        // Populate the output file and user_data IDs in the selected jobs
+        // Populate the file and user_data IDs in the selected jobs
         for job in &mut selected_jobs {
             if let Some(job_id) = job.id {
+                job.input_file_ids = input_files_map.get(&job_id).cloned();
                 job.output_file_ids = output_files_map.get(&job_id).cloned();
                 job.output_user_data_ids = output_user_data_map.get(&job_id).cloned();
             }
@@ -6209,6 +8203,99 @@ where
             .await
     }
 
+    // API Tokens API
+
+    async fn create_api_token(
+        &self,
+        body: models::ApiTokenModel,
+        context: &C,
+    ) -> Result<CreateApiTokenResponse, ApiError> {
+        let auth: Option<Authorization> = Has::<Option<Authorization>>::get(context).clone();
+        let requester = AuthorizationService::get_username(&auth).map(|s| s.to_string());
+        let mut body = body;
+        if body.owner.is_none() {
+            body.owner = requester.clone();
+        }
+
+        // Only system administrators can create tokens for other users. This holds even when
+        // access control is not enforced, because the token stays valid after it is enabled.
+        if body.owner != requester {
+            match self.authorization_service.check_system_admin(&auth).await {
+                AccessCheckResult::Allowed => {}
+                AccessCheckResult::Denied(reason) | AccessCheckResult::NotFound(reason) => {
+                    return Ok(CreateApiTokenResponse::ForbiddenErrorResponse(
+                        models::ErrorResponse::new(serde_json::json!({
+                            "error": "Forbidden",
+                            "message": reason
+                        })),
+                    ));
+                }
+            }
+        }
+
+        self.api_tokens_api.create_api_token(body, context).await
+    }
+
+    async fn list_api_tokens(
+        &self,
+        offset: Option<i64>,
+        limit: Option<i64>,
+        context: &C,
+    ) -> Result<ListApiTokensApiResponse, ApiError> {
+        let (offset, limit) = process_pagination_params(offset, limit)?;
+
+        // Administrators see all tokens; other users only see their own.
+        let auth: Option<Authorization> = Has::<Option<Authorization>>::get(context).clone();
+        let owner = match self.authorization_service.check_system_admin(&auth).await {
+            AccessCheckResult::Allowed => None,
+            _ => Some(
+                AuthorizationService::get_username(&auth)
+                    .unwrap_or("anonymous")
+                    .to_string(),
+            ),
+        };
+
+        self.api_tokens_api
+            .list_api_tokens(owner, offset, limit, context)
+            .await
+    }
+
+    async fn revoke_api_token(
+        &self,
+        id: i64,
+        context: &C,
+    ) -> Result<RevokeApiTokenResponse, ApiError> {
+        let token = match self.api_tokens_api.get_api_token(id).await? {
+            Some(token) => token,
+            None => {
+                return Ok(RevokeApiTokenResponse::NotFoundErrorResponse(
+                    models::ErrorResponse::new(serde_json::json!({
+                        "error": "NotFound",
+                        "message": format!("API token not found with ID: {}", id)
+                    })),
+                ));
+            }
+        };
+
+        // Users can revoke their own tokens; administrators can revoke any token
+        let auth: Option<Authorization> = Has::<Option<Authorization>>::get(context).clone();
+        if token.owner.as_deref() != AuthorizationService::get_username(&auth) {
+            match self.authorization_service.check_system_admin(&auth).await {
+                AccessCheckResult::Allowed => {}
+                AccessCheckResult::Denied(reason) | AccessCheckResult::NotFound(reason) => {
+                    return Ok(RevokeApiTokenResponse::ForbiddenErrorResponse(
+                        models::ErrorResponse::new(serde_json::json!({
+                            "error": "Forbidden",
+                            "message": reason
+                        })),
+                    ));
+                }
+            }
+        }
+
+        self.api_tokens_api.revoke_api_token(id, context).await
+    }
+
     /// Subscribe to the event broadcast channel for SSE streaming.
     fn subscribe_to_events(&self) -> tokio::sync::broadcast::Receiver<BroadcastEvent> {
         self.event_broadcaster.subscribe()
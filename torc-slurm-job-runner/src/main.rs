@@ -23,7 +23,7 @@ mod unix_main {
     use torc::client::apis::default_api;
     use torc::client::commands::slurm::{create_compute_node, create_node_resources};
     use torc::client::hpc::hpc_interface::HpcInterface;
-    use torc::client::hpc::slurm_interface::SlurmInterface;
+    use torc::client::hpc::{HpcType, create_hpc_interface};
     use torc::client::job_runner::JobRunner;
     use torc::client::log_paths::{
         get_slurm_dmesg_log_file, get_slurm_env_log_file, get_slurm_job_runner_log_file,
@@ -67,9 +67,22 @@ mod unix_main {
         #[arg(long, default_value = "false")]
         is_subtask: bool,
 
+        /// Set to true if the allocation starts one job runner on each node. The job runner on
+        /// the head node then runs the multi-node jobs on nodes reserved for them.
+        #[arg(long, default_value = "false")]
+        worker_per_node: bool,
+
         /// Wait this number of minutes if the database is offline
         #[arg(long, default_value = "20")]
         wait_for_healthy_database_minutes: u64,
+
+        /// HPC scheduler that started this allocation (slurm, pbs, or fake)
+        #[arg(long, default_value = "slurm")]
+        hpc_type: HpcType,
+
+        /// API token for bearer authentication
+        #[arg(long, env = "TORC_API_TOKEN", hide_env_values = true)]
+        api_token: Option<String>,
     }
 
     pub fn main() {
@@ -78,11 +91,11 @@ mod unix_main {
         // Record start time for dmesg filtering (with 60-minute buffer)
         let dmesg_cutoff = Local::now() - chrono::Duration::minutes(60);
 
-        // Create Slurm interface to get environment info
-        let slurm_interface = match SlurmInterface::new() {
+        // Create the HPC interface to get environment info
+        let slurm_interface = match create_hpc_interface(args.hpc_type) {
             Ok(interface) => interface,
             Err(e) => {
-                eprintln!("Error creating Slurm interface: {}", e);
+                eprintln!("Error creating {} interface: {}", args.hpc_type, e);
                 std::process::exit(1);
             }
         };
@@ -120,7 +133,7 @@ mod unix_main {
             .into_string()
             .expect("Hostname is not valid UTF-8");
 
-        info!("Starting Slurm job runner");
+        info!("Starting {} job runner", args.hpc_type);
         info!("Job ID: {}", job_id);
         info!("Node ID: {}", node_id);
         info!("Task PID: {}", task_pid);
@@ -128,7 +141,7 @@ mod unix_main {
         info!("Output directory: {}", args.output_dir.display());
         info!("Log file: {}", log_file_path);
 
-        // Capture SLURM environment variables for debugging
+        // Capture scheduler environment variables (SLURM_*, PBS_*) for debugging
         let slurm_env_path = get_slurm_env_log_file(
             args.output_dir.clone(),
             args.workflow_id,
@@ -136,11 +149,15 @@ mod unix_main {
             &node_id,
             task_pid,
         );
-        utils::capture_env_vars(std::path::Path::new(&slurm_env_path), "SLURM");
+        utils::capture_env_vars(
+            std::path::Path::new(&slurm_env_path),
+            &args.hpc_type.to_string().to_uppercase(),
+        );
 
         // Set up configuration
         let mut config = Configuration::new();
         config.base_path = args.url;
+        config.bearer_access_token = args.api_token.clone();
 
         // First, ping the server to ensure we can connect
         match utils::send_with_retries(
@@ -187,7 +204,7 @@ mod unix_main {
 
         // All compute nodes get the scheduled compute node
         let scheduled_compute_node =
-            get_scheduled_compute_node(&config, args.workflow_id, &slurm_interface);
+            get_scheduled_compute_node(&config, args.workflow_id, slurm_interface.as_ref());
 
         if slurm_interface.is_head_node()
             && let Some(ref node) = scheduled_compute_node
@@ -200,13 +217,24 @@ mod unix_main {
             .as_ref()
             .map(|node| node.scheduler_config_id);
 
-        let resources =
-            create_node_resources(&slurm_interface, scheduler_config_id, args.is_subtask);
-        let job_id_int: i64 = job_id.parse().unwrap_or(0);
+        let mut resources = create_node_resources(
+            slurm_interface.as_ref(),
+            scheduler_config_id,
+            args.is_subtask,
+        );
+        if args.worker_per_node && !slurm_interface.is_head_node() {
+            // Only the head node's job runner claims multi-node jobs.
+            resources.num_nodes = 1;
+        }
+        // Tasks of a job array are recorded by their "<array_job_id>_<array_task_id>" string.
+        let slurm_job_id = job_id
+            .parse::<i64>()
+            .map(serde_json::Value::from)
+            .unwrap_or_else(|_| serde_json::Value::String(job_id.clone()));
         let scheduler = serde_json::json!({
             "scheduler_id": scheduler_id,
-            "type": "slurm",
-            "slurm_job_id": job_id_int,
+            "type": args.hpc_type.to_string(),
+            "slurm_job_id": slurm_job_id,
         });
         let compute_node =
             create_compute_node(&config, args.workflow_id, &resources, &hostname, scheduler);
@@ -241,6 +269,12 @@ mod unix_main {
             args.is_subtask,
             unique_label,
         );
+        if args.worker_per_node
+            && slurm_interface.is_head_node()
+            && let Some(id) = scheduler_id.flatten()
+        {
+            job_runner.enable_multi_node_jobs(id);
+        }
 
         // Register SIGTERM signal handler
         // When Slurm is about to reach walltime, it sends SIGTERM to this process.
@@ -310,12 +344,12 @@ mod unix_main {
         }
     }
 
-    /// Get the scheduled compute node for a Slurm job ID.
+    /// Get the scheduled compute node for an HPC job ID.
     /// Returns the node model if successfully found, None otherwise.
     fn get_scheduled_compute_node(
         config: &Configuration,
         workflow_id: i64,
-        slurm_interface: &SlurmInterface,
+        slurm_interface: &dyn HpcInterface,
     ) -> Option<torc::models::ScheduledComputeNodesModel> {
         let job_id = slurm_interface.get_current_job_id();
         debug!(